@@ -2,15 +2,17 @@
 //!
 //! 감사 로그를 SQLite에 저장하고 조회하는 기능을 제공합니다.
 
+use super::report::{AuditReport, AuditReportOptions};
 use super::types::{AuditAction, AuditEntry, AuditId, AuditQuery, AuditResult, AuditStatistics};
 use crate::event::{EventBus, EventCategory, EventListener, ForgeEvent};
+use crate::storage::StorageBackend;
 use async_trait::async_trait;
 use rusqlite::{params, Connection, OptionalExtension};
 use serde_json::Value;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 // ============================================================================
 // AuditLogger
@@ -79,6 +81,9 @@ pub struct AuditLogger {
 
     /// 설정
     config: AuditLoggerConfig,
+
+    /// 기록을 복제할 공유 저장소 (Postgres 등)
+    backend: Option<Arc<dyn StorageBackend>>,
 }
 
 impl AuditLogger {
@@ -95,18 +100,14 @@ impl AuditLogger {
         }
 
         let conn = Connection::open(&config.db_path)?;
+        Self::init_tables(&conn)?;
 
         let logger = Self {
             db: Mutex::new(conn),
             config,
+            backend: None,
         };
 
-        // 테이블 초기화
-        tokio::task::block_in_place(|| {
-            let rt = tokio::runtime::Handle::current();
-            rt.block_on(logger.init_tables())
-        })?;
-
         info!(
             db_path = %logger.config.db_path.display(),
             "Audit logger initialized"
@@ -118,6 +119,7 @@ impl AuditLogger {
     /// 인메모리 로거 생성 (테스트용)
     pub fn in_memory() -> crate::Result<Self> {
         let conn = Connection::open_in_memory()?;
+        Self::init_tables(&conn)?;
 
         let logger = Self {
             db: Mutex::new(conn),
@@ -125,20 +127,25 @@ impl AuditLogger {
                 db_path: PathBuf::from(":memory:"),
                 ..Default::default()
             },
+            backend: None,
         };
 
-        tokio::task::block_in_place(|| {
-            let rt = tokio::runtime::Handle::current();
-            rt.block_on(logger.init_tables())
-        })?;
-
         Ok(logger)
     }
 
-    /// 테이블 초기화
-    async fn init_tables(&self) -> crate::Result<()> {
-        let db = self.db.lock().await;
+    /// 기록을 공유 저장소에도 복제
+    ///
+    /// 조회와 리포트는 로컬 DB 기준이며, 복제본은 팀 단위 SQL 분석용입니다.
+    /// 복제 실패는 경고만 남기고 기록 자체에는 영향을 주지 않습니다.
+    pub fn with_backend(mut self, backend: Arc<dyn StorageBackend>) -> Self {
+        self.backend = Some(backend);
+        self
+    }
 
+    /// 테이블 초기화
+    ///
+    /// 연결을 `Mutex`에 넣기 전에 동기로 실행하므로 런타임 종류와 무관합니다.
+    fn init_tables(db: &Connection) -> crate::Result<()> {
         db.execute(
             r#"
             CREATE TABLE IF NOT EXISTS audit_log (
@@ -223,6 +230,14 @@ impl AuditLogger {
             "Audit entry logged"
         );
 
+        if let Some(backend) = self.backend.clone() {
+            tokio::task::spawn_blocking(move || {
+                if let Err(e) = backend.record_audit_entry(&entry) {
+                    warn!("Failed to replicate audit entry {}: {}", entry.id, e);
+                }
+            });
+        }
+
         Ok(id)
     }
 
@@ -383,6 +398,16 @@ impl AuditLogger {
         })
     }
 
+    /// 기간 내 항목으로 권한 감사 리포트 생성
+    pub async fn report(&self, options: &AuditReportOptions) -> crate::Result<AuditReport> {
+        let mut query = AuditQuery::new();
+        query.from = Some(options.since);
+        query.to = options.until;
+
+        let entries = self.query(&query).await?;
+        Ok(AuditReport::from_entries(&entries, options))
+    }
+
     /// 오래된 로그 정리
     pub async fn cleanup(&self, days: u32) -> crate::Result<u64> {
         let db = self.db.lock().await;
@@ -588,6 +613,9 @@ fn parse_action(s: &str) -> AuditAction {
         "file_delete" => AuditAction::FileDelete,
         "command_executed" => AuditAction::CommandExecuted,
         "command_blocked" => AuditAction::CommandBlocked,
+        "secret_redacted" => AuditAction::SecretRedacted,
+        "oversight_blocked" => AuditAction::OversightBlocked,
+        "oversight_flagged" => AuditAction::OversightFlagged,
         "session_started" => AuditAction::SessionStarted,
         "session_ended" => AuditAction::SessionEnded,
         "config_changed" => AuditAction::ConfigChanged,
@@ -671,4 +699,33 @@ mod tests {
         let stats = logger.statistics().await.unwrap();
         assert_eq!(stats.total_entries, 2);
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_audit_report() {
+        let logger = AuditLogger::in_memory().unwrap();
+        let write = crate::permission::PermissionAction::FileWrite {
+            path: "../outside.txt".to_string(),
+        };
+
+        logger
+            .log(AuditEntry::permission_decision("write", &write, false, "deny"))
+            .await
+            .unwrap();
+        logger
+            .log(
+                AuditEntry::for_action("write", &write, true)
+                    .with_data(serde_json::json!({ "workingDir": "/work/repo" })),
+            )
+            .await
+            .unwrap();
+
+        let since = chrono::Utc::now() - chrono::Duration::days(7);
+        let report = logger
+            .report(&AuditReportOptions::since(since))
+            .await
+            .unwrap();
+        assert_eq!(report.total_entries, 2);
+        assert_eq!(report.permissions.denied, 1);
+        assert_eq!(report.outside_repo_files[0].target, "/work/outside.txt");
+    }
 }
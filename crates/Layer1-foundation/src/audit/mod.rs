@@ -48,9 +48,18 @@
 //! let stats = logger.statistics().await?;
 //! println!("Total entries: {}", stats.total_entries);
 //!
-//! // 5. EventBus 연동 (자동 감사 로깅)
+//! // 5. 권한 감사 리포트 (JSON/HTML)
+//! let report = logger
+//!     .report(&AuditReportOptions::since(Utc::now() - Duration::days(7)))
+//!     .await?;
+//! std::fs::write("audit.html", report.to_html())?;
+//!
+//! // 6. EventBus 연동 (자동 감사 로깅)
 //! use forge_foundation::event::global_event_bus;
 //! AuditEventListener::register(Arc::new(logger), &global_event_bus()).await;
+//!
+//! // 7. 공유 저장소(Postgres)로 복제 - 여러 머신의 감사 기록을 한 곳에서 분석
+//! let logger = AuditLogger::new()?.with_backend(open_backend(Some(&storage_config), &data_dir)?);
 //! ```
 //!
 //! ## 감사 대상 이벤트
@@ -61,12 +70,18 @@
 //! | Tool | 도구 시작/성공/실패 | 0-4 |
 //! | File | 읽기/쓰기/삭제 | 1-7 |
 //! | Command | 실행/차단 | 6-8 |
+//! | Security | 비밀값 가림, 감독 차단/표시 | 4-8 |
 //! | Session | 시작/종료 | 0 |
 //! | Error | 에러 발생 | 5 |
 
 pub mod logger;
+pub mod report;
 pub mod types;
 
 // Re-exports
 pub use logger::{AuditEventListener, AuditLogger, AuditLoggerConfig};
+pub use report::{
+    AuditReport, AuditReportOptions, PermissionSummary, ReportItem, ToolPermissionCount,
+    DEFAULT_RISKY_COMMAND_LEVEL,
+};
 pub use types::{AuditAction, AuditEntry, AuditId, AuditQuery, AuditResult, AuditStatistics};
@@ -0,0 +1,453 @@
+//! Audit Report - 권한 감사 리포트
+//!
+//! `AuditLogger`에 기록된 항목을 기간별로 집계해 컴플라이언스 검토용 리포트를 만듭니다.
+//!
+//! - 권한 승인/거부 (도구별)
+//! - 위험 명령 실행 (명령 분석기 위험도 기준)
+//! - 저장소 밖 파일 수정 (쓰기/삭제)
+//! - 차단된 호출 (명령 차단, 감독 차단)
+//!
+//! JSON(`to_json`)과 단독 HTML 문서(`to_html`)로 내보낼 수 있습니다.
+
+use super::types::{AuditAction, AuditEntry, AuditResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Component, Path, PathBuf};
+
+/// 위험 명령으로 분류하는 기본 위험도 (명령 분석기의 "위험" 이상)
+pub const DEFAULT_RISKY_COMMAND_LEVEL: u8 = 7;
+
+// ============================================================================
+// AuditReportOptions
+// ============================================================================
+
+/// 리포트 생성 옵션
+#[derive(Debug, Clone)]
+pub struct AuditReportOptions {
+    /// 시작 시간
+    pub since: DateTime<Utc>,
+
+    /// 종료 시간 (None이면 생성 시점)
+    pub until: Option<DateTime<Utc>>,
+
+    /// 저장소 루트 (None이면 항목에 기록된 작업 디렉토리)
+    pub repo_root: Option<PathBuf>,
+
+    /// 위험 명령 최소 위험도
+    pub min_command_risk: u8,
+}
+
+impl AuditReportOptions {
+    /// `since` 이후 항목으로 리포트
+    pub fn since(since: DateTime<Utc>) -> Self {
+        Self {
+            since,
+            until: None,
+            repo_root: None,
+            min_command_risk: DEFAULT_RISKY_COMMAND_LEVEL,
+        }
+    }
+
+    pub fn with_until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn with_repo_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.repo_root = Some(root.into());
+        self
+    }
+
+    pub fn with_min_command_risk(mut self, level: u8) -> Self {
+        self.min_command_risk = level.min(10);
+        self
+    }
+}
+
+// ============================================================================
+// AuditReport
+// ============================================================================
+
+/// 리포트 항목 (감사 엔트리 요약)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportItem {
+    pub timestamp: DateTime<Utc>,
+    pub session_id: Option<String>,
+    pub actor: String,
+    pub action: AuditAction,
+    pub result: AuditResult,
+    pub target: String,
+    pub risk_level: u8,
+    pub description: String,
+}
+
+impl ReportItem {
+    fn from_entry(entry: &AuditEntry, target: String) -> Self {
+        Self {
+            timestamp: entry.timestamp,
+            session_id: entry.session_id.clone(),
+            actor: entry.actor.clone(),
+            action: entry.action,
+            result: entry.result,
+            target,
+            risk_level: entry.risk_level,
+            description: entry.description.clone(),
+        }
+    }
+}
+
+/// 도구별 권한 결정 수
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolPermissionCount {
+    pub tool: String,
+    pub granted: usize,
+    pub denied: usize,
+}
+
+/// 권한 결정 요약
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionSummary {
+    pub granted: usize,
+    pub denied: usize,
+    /// 도구별 (거부가 많은 순)
+    pub by_tool: Vec<ToolPermissionCount>,
+}
+
+/// 권한 감사 리포트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditReport {
+    pub generated_at: DateTime<Utc>,
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+
+    /// 기간 내 전체 항목 수
+    pub total_entries: usize,
+
+    /// 권한 승인/거부
+    pub permissions: PermissionSummary,
+
+    /// 위험 명령 실행 (위험도 높은 순)
+    pub risky_commands: Vec<ReportItem>,
+
+    /// 저장소 밖 파일 수정 (시간순)
+    pub outside_repo_files: Vec<ReportItem>,
+
+    /// 차단된 호출 (시간순)
+    pub blocked: Vec<ReportItem>,
+}
+
+impl AuditReport {
+    /// 기간 내 엔트리에서 리포트 생성
+    pub fn from_entries(entries: &[AuditEntry], options: &AuditReportOptions) -> Self {
+        let generated_at = Utc::now();
+        let until = options.until.unwrap_or(generated_at);
+        let entries: Vec<&AuditEntry> = entries
+            .iter()
+            .filter(|e| e.timestamp >= options.since && e.timestamp <= until)
+            .collect();
+
+        let mut permissions = PermissionSummary::default();
+        let mut by_tool: BTreeMap<&str, ToolPermissionCount> = BTreeMap::new();
+        let mut risky_commands = Vec::new();
+        let mut outside_repo_files = Vec::new();
+        let mut blocked = Vec::new();
+
+        for entry in &entries {
+            let target = entry.target.clone().unwrap_or_default();
+            match entry.action {
+                AuditAction::PermissionGranted | AuditAction::PermissionDenied => {
+                    let count = by_tool.entry(entry.actor.as_str()).or_insert_with(|| {
+                        ToolPermissionCount {
+                            tool: entry.actor.clone(),
+                            ..Default::default()
+                        }
+                    });
+                    if entry.action == AuditAction::PermissionGranted {
+                        permissions.granted += 1;
+                        count.granted += 1;
+                    } else {
+                        permissions.denied += 1;
+                        count.denied += 1;
+                    }
+                }
+                AuditAction::CommandExecuted if entry.risk_level >= options.min_command_risk => {
+                    risky_commands.push(ReportItem::from_entry(entry, target));
+                }
+                AuditAction::FileWrite | AuditAction::FileDelete => {
+                    if let Some(path) = outside_repo(entry, options.repo_root.as_deref()) {
+                        outside_repo_files
+                            .push(ReportItem::from_entry(entry, path.display().to_string()));
+                    }
+                }
+                AuditAction::CommandBlocked | AuditAction::OversightBlocked => {
+                    blocked.push(ReportItem::from_entry(entry, target));
+                }
+                _ => {}
+            }
+        }
+
+        permissions.by_tool = by_tool.into_values().collect();
+        permissions
+            .by_tool
+            .sort_by(|a, b| b.denied.cmp(&a.denied).then(b.granted.cmp(&a.granted)));
+        risky_commands.sort_by(|a, b| {
+            b.risk_level
+                .cmp(&a.risk_level)
+                .then(a.timestamp.cmp(&b.timestamp))
+        });
+        outside_repo_files.sort_by_key(|item| item.timestamp);
+        blocked.sort_by_key(|item| item.timestamp);
+
+        Self {
+            generated_at,
+            since: options.since,
+            until,
+            total_entries: entries.len(),
+            permissions,
+            risky_commands,
+            outside_repo_files,
+            blocked,
+        }
+    }
+
+    /// 주의가 필요한 항목이 없는지 (거부/위험 명령/저장소 밖 수정/차단 모두 0)
+    pub fn is_clean(&self) -> bool {
+        self.permissions.denied == 0
+            && self.risky_commands.is_empty()
+            && self.outside_repo_files.is_empty()
+            && self.blocked.is_empty()
+    }
+
+    /// JSON 내보내기
+    pub fn to_json(&self) -> crate::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// 단독 HTML 문서 내보내기
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let period = format!(
+            "{} – {}",
+            self.since.format("%Y-%m-%d %H:%M UTC"),
+            self.until.format("%Y-%m-%d %H:%M UTC")
+        );
+
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        let _ = writeln!(
+            html,
+            "<title>ForgeCode permission audit: {}</title>",
+            period
+        );
+        html.push_str(
+            "<style>\
+             body{font-family:system-ui,sans-serif;margin:2rem;color:#222}\
+             table{border-collapse:collapse;margin-bottom:2rem;width:100%}\
+             th,td{border:1px solid #ccc;padding:4px 8px;text-align:left;font-size:14px}\
+             th{background:#f4f4f4}td.risk{text-align:right}code{word-break:break-all}\
+             </style>\n</head>\n<body>\n",
+        );
+        html.push_str("<h1>Permission audit report</h1>\n");
+        let _ = writeln!(
+            html,
+            "<p>Period: {}<br>Generated: {}<br>Audit entries: {}</p>",
+            period,
+            self.generated_at.format("%Y-%m-%d %H:%M UTC"),
+            self.total_entries
+        );
+
+        let _ = writeln!(
+            html,
+            "<h2>Permission decisions</h2>\n<p>Granted: {} · Denied: {}</p>",
+            self.permissions.granted, self.permissions.denied
+        );
+        if !self.permissions.by_tool.is_empty() {
+            html.push_str("<table>\n<tr><th>Tool</th><th>Granted</th><th>Denied</th></tr>\n");
+            for count in &self.permissions.by_tool {
+                let _ = writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    escape_html(&count.tool),
+                    count.granted,
+                    count.denied
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        write_items_html(&mut html, "Risky commands executed", &self.risky_commands);
+        write_items_html(
+            &mut html,
+            "Files modified outside the repository",
+            &self.outside_repo_files,
+        );
+        write_items_html(&mut html, "Blocked calls", &self.blocked);
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+fn write_items_html(html: &mut String, title: &str, items: &[ReportItem]) {
+    let _ = writeln!(html, "<h2>{} ({})</h2>", title, items.len());
+    if items.is_empty() {
+        html.push_str("<p>None.</p>\n");
+        return;
+    }
+
+    html.push_str(
+        "<table>\n<tr><th>Time (UTC)</th><th>Session</th><th>Tool</th><th>Target</th>\
+         <th>Risk</th><th>Result</th></tr>\n",
+    );
+    for item in items {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td><code>{}</code></td>\
+             <td class=\"risk\">{}</td><td>{}</td></tr>",
+            item.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            escape_html(item.session_id.as_deref().unwrap_or("-")),
+            escape_html(&item.actor),
+            escape_html(&item.target),
+            item.risk_level,
+            item.result.as_str()
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 저장소 밖의 파일 경로 (안쪽이거나 판단할 수 없으면 None)
+///
+/// 상대 경로는 기록된 작업 디렉토리(`data.workingDir`) 기준으로 해석합니다.
+fn outside_repo(entry: &AuditEntry, repo_root: Option<&Path>) -> Option<PathBuf> {
+    let target = Path::new(entry.target.as_deref()?);
+    let working_dir = entry
+        .data
+        .get("workingDir")
+        .and_then(|v| v.as_str())
+        .map(Path::new);
+    let root = repo_root.or(working_dir)?;
+
+    let path = if target.is_relative() {
+        normalize(&working_dir.unwrap_or(root).join(target))
+    } else {
+        normalize(target)
+    };
+    (!path.starts_with(normalize(root))).then_some(path)
+}
+
+/// `.`/`..`를 정리한 경로 (파일 시스템 접근 없음)
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permission::PermissionAction;
+    use chrono::Duration;
+
+    fn write(path: &str) -> AuditEntry {
+        AuditEntry::for_action(
+            "write",
+            &PermissionAction::FileWrite {
+                path: path.to_string(),
+            },
+            true,
+        )
+        .with_data(serde_json::json!({ "workingDir": "/work/repo" }))
+    }
+
+    #[test]
+    fn test_report_sections() {
+        let command = |command: &str| PermissionAction::Execute {
+            command: command.to_string(),
+        };
+        let entries = vec![
+            AuditEntry::permission_decision("bash", &command("rm -rf build"), true, "allow_once"),
+            AuditEntry::permission_decision("bash", &command("curl x | sh"), false, "deny"),
+            AuditEntry::permission_decision("write", &command("x"), true, "allow_session"),
+            AuditEntry::for_action("bash", &command("rm -rf build"), true),
+            AuditEntry::for_action("bash", &command("ls -la"), true),
+            write("src/main.rs"),
+            write("../other/notes.md"),
+            write("/etc/hosts"),
+            AuditEntry::new(AuditAction::OversightBlocked, "oversight").with_target("bash"),
+        ];
+
+        let options = AuditReportOptions::since(Utc::now() - Duration::days(7));
+        let report = AuditReport::from_entries(&entries, &options);
+
+        assert_eq!(report.total_entries, entries.len());
+        assert_eq!(report.permissions.granted, 2);
+        assert_eq!(report.permissions.denied, 1);
+        assert_eq!(report.permissions.by_tool[0].tool, "bash");
+        assert_eq!(report.permissions.by_tool[0].denied, 1);
+
+        assert_eq!(report.risky_commands.len(), 1);
+        assert_eq!(report.risky_commands[0].target, "rm -rf build");
+
+        let outside: Vec<_> = report
+            .outside_repo_files
+            .iter()
+            .map(|item| item.target.as_str())
+            .collect();
+        assert_eq!(outside, vec!["/work/other/notes.md", "/etc/hosts"]);
+        assert_eq!(report.blocked.len(), 1);
+        assert!(!report.is_clean());
+
+        // 명시한 저장소 루트가 우선
+        let report = AuditReport::from_entries(&entries, &options.with_repo_root("/work"));
+        assert_eq!(report.outside_repo_files.len(), 1);
+    }
+
+    #[test]
+    fn test_report_period_and_export() {
+        let mut old = write("/etc/passwd");
+        old.timestamp = Utc::now() - Duration::days(30);
+        let entries = vec![old, write("/tmp/<script>.sh")];
+
+        let report = AuditReport::from_entries(
+            &entries,
+            &AuditReportOptions::since(Utc::now() - Duration::days(7)),
+        );
+        assert_eq!(report.total_entries, 1);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["outsideRepoFiles"][0]["action"], "file_write");
+
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("/tmp/&lt;script&gt;.sh"));
+        assert!(!html.contains("<script>"));
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! 권한 요청, 도구 실행, 에러 등의 감사 기록을 위한 타입들입니다.
 
+use crate::permission::{command_analyzer, PermissionAction};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -70,6 +71,14 @@ pub enum AuditAction {
     /// 명령어 차단
     CommandBlocked,
 
+    // 보안 관련
+    /// 비밀값 가림 (도구 결과/메시지)
+    SecretRedacted,
+    /// 감독 에이전트가 도구 호출 차단
+    OversightBlocked,
+    /// 감독 에이전트가 도구 호출 표시 (실행은 됨)
+    OversightFlagged,
+
     // 세션 관련
     /// 세션 시작
     SessionStarted,
@@ -103,6 +112,9 @@ impl AuditAction {
             Self::FileDelete => "file_delete",
             Self::CommandExecuted => "command_executed",
             Self::CommandBlocked => "command_blocked",
+            Self::SecretRedacted => "secret_redacted",
+            Self::OversightBlocked => "oversight_blocked",
+            Self::OversightFlagged => "oversight_flagged",
             Self::SessionStarted => "session_started",
             Self::SessionEnded => "session_ended",
             Self::ConfigChanged => "config_changed",
@@ -125,6 +137,9 @@ impl AuditAction {
             Self::FileDelete => 7,
             Self::CommandExecuted => 6,
             Self::CommandBlocked => 8,
+            Self::SecretRedacted => 4,
+            Self::OversightBlocked => 8,
+            Self::OversightFlagged => 5,
             Self::SessionStarted => 0,
             Self::SessionEnded => 0,
             Self::ConfigChanged => 3,
@@ -296,6 +311,86 @@ impl AuditEntry {
         self.risk_level = level.min(10);
         self
     }
+
+    /// 권한이 필요한 도구 실행의 감사 엔트리
+    ///
+    /// 명령은 `CommandExecuted`(명령 분석기 위험도), 파일 쓰기/삭제는
+    /// `FileWrite`/`FileDelete`, 그 외는 도구 성공/실패로 기록합니다.
+    pub fn for_action(tool: &str, action: &PermissionAction, success: bool) -> Self {
+        let entry = match action {
+            PermissionAction::Execute { command } => {
+                Self::new(AuditAction::CommandExecuted, tool)
+                    .with_target(command)
+                    .with_risk_level(command_analyzer().analyze(command).risk_score)
+            }
+            PermissionAction::FileWrite { path } => {
+                Self::new(AuditAction::FileWrite, tool).with_target(path)
+            }
+            PermissionAction::FileDelete { path } => {
+                Self::new(AuditAction::FileDelete, tool).with_target(path)
+            }
+            PermissionAction::FileReadSensitive { path } => {
+                Self::new(AuditAction::FileRead, tool).with_target(path)
+            }
+            PermissionAction::Network { .. } | PermissionAction::Custom { .. } => {
+                Self::new(tool_action(success), tool).with_target(action_target(action))
+            }
+        };
+
+        entry
+            .with_result(if success {
+                AuditResult::Success
+            } else {
+                AuditResult::Failure
+            })
+            .with_description(action.description())
+    }
+
+    /// 권한 결정 감사 엔트리
+    ///
+    /// `decision`은 결정 방식 (`allow_once`, `allow_session`, `deny`, `policy` 등)
+    pub fn permission_decision(
+        tool: &str,
+        action: &PermissionAction,
+        granted: bool,
+        decision: &str,
+    ) -> Self {
+        let (audit_action, result) = if granted {
+            (AuditAction::PermissionGranted, AuditResult::Success)
+        } else {
+            (AuditAction::PermissionDenied, AuditResult::Denied)
+        };
+
+        Self::new(audit_action, tool)
+            .with_result(result)
+            .with_target(action_target(action))
+            .with_description(action.description())
+            .with_data(serde_json::json!({
+                "action": action,
+                "decision": decision,
+            }))
+            .with_tag("permission")
+    }
+}
+
+/// 권한 액션의 대상 (명령, 경로, URL, 이름)
+fn action_target(action: &PermissionAction) -> &str {
+    match action {
+        PermissionAction::Execute { command } => command,
+        PermissionAction::FileWrite { path }
+        | PermissionAction::FileDelete { path }
+        | PermissionAction::FileReadSensitive { path } => path,
+        PermissionAction::Network { url } => url,
+        PermissionAction::Custom { name, .. } => name,
+    }
+}
+
+fn tool_action(success: bool) -> AuditAction {
+    if success {
+        AuditAction::ToolSucceeded
+    } else {
+        AuditAction::ToolFailed
+    }
 }
 
 // ============================================================================
@@ -4,13 +4,14 @@
 
 use crate::storage::JsonStore;
 use crate::Result;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// 제한 설정 파일명
 pub const LIMITS_FILE: &str = "limits.json";
 
 /// 세션별 제한
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SessionLimits {
     /// 세션당 최대 입력 토큰
     pub max_input_tokens: Option<u64>,
@@ -131,7 +132,7 @@ impl SessionLimits {
 }
 
 /// 일별 제한
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DailyLimits {
     /// 일별 최대 토큰
     pub max_tokens: Option<u64>,
@@ -204,7 +205,7 @@ impl DailyLimits {
 }
 
 /// 월별 제한
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MonthlyLimits {
     /// 월별 최대 토큰
     pub max_tokens: Option<u64>,
@@ -329,7 +330,7 @@ impl LimitCheckResult {
 }
 
 /// 통합 제한 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LimitsConfig {
     /// 제한 활성화 여부
     pub enabled: bool,
@@ -9,7 +9,7 @@ mod limits;
 // Forge (통합 설정)
 pub use forge::{
     AutoSaveConfig, CacheSettings, CustomColors, EditorConfig, ExperimentalConfig, ForgeConfig,
-    GitConfig, SecurityConfig, ThemeConfig, FORGE_CONFIG_FILE,
+    GitConfig, SecurityConfig, StorageConfig, ThemeConfig, FORGE_CONFIG_FILE,
 };
 
 // Limits
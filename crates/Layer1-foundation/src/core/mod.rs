@@ -51,7 +51,7 @@ pub mod types;
 // ============================================================================
 
 // Message & Role
-pub use types::{Message, MessageRole};
+pub use types::{ImageAttachment, Message, MessageRole};
 
 // Tool Call
 pub use types::ToolCall;
@@ -76,7 +76,10 @@ pub use types::{ModelHint, SessionInfo};
 // ============================================================================
 
 // Tool trait & related
-pub use traits::{Tool, ToolContext, ToolExecutionResult, ToolMeta};
+pub use traits::{
+    CancellationToken, DocumentFormatter, DocumentSync, OutputControl, Tool, ToolContext,
+    ToolExecutionResult, ToolMeta, ToolOutputSink, ToolProgress,
+};
 
 // ToolResult alias (traits::ToolExecutionResult의 별칭)
 pub use traits::ToolResult;
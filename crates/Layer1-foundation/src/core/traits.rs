@@ -28,13 +28,15 @@
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 
-use crate::permission::{PermissionAction, PermissionDef, PermissionStatus};
+use crate::permission::{ConfirmationPrompt, PermissionAction, PermissionDef, PermissionStatus};
 use crate::Result;
 use async_trait::async_trait;
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
+pub use tokio_util::sync::CancellationToken;
+
 // ============================================================================
 // Tool Trait - 도구 인터페이스
 // ============================================================================
@@ -191,6 +193,14 @@ pub trait Tool: Send + Sync {
     /// 입력 파라미터를 분석하여 필요한 권한을 반환합니다.
     fn required_permission(&self, input: &Value) -> Option<PermissionAction>;
 
+    /// 이 도구 호출에 필요한 모든 권한 액션 (요청 순서대로)
+    ///
+    /// 기본값은 `required_permission` 하나입니다.
+    /// 실행 중 권한을 여러 번 요청하는 도구는 재정의합니다.
+    fn required_permissions(&self, input: &Value) -> Vec<PermissionAction> {
+        self.required_permission(input).into_iter().collect()
+    }
+
     /// Layer1에 권한 정의 등록
     fn register_permissions(&self) {
         let meta = self.meta();
@@ -226,8 +236,132 @@ pub trait ToolContext: Send + Sync {
         action: PermissionAction,
     ) -> Result<bool>;
 
+    /// 구조화된 프롬프트로 권한 요청 (diff 미리보기 등 포함)
+    ///
+    /// 기본 구현은 프롬프트의 설명과 액션으로 `request_permission`을 호출합니다.
+    async fn request_confirmation(&self, prompt: ConfirmationPrompt) -> Result<bool> {
+        self.request_permission(&prompt.tool, &prompt.description, prompt.action)
+            .await
+    }
+
     /// Shell 설정 가져오기
     fn shell_config(&self) -> &dyn ShellConfig;
+
+    /// 실행 중 출력 수신자 (없으면 완료 후 결과만 반환)
+    fn output_sink(&self) -> Option<&dyn ToolOutputSink> {
+        None
+    }
+
+    /// 파일 변경을 언어 서버에 알리는 동기화 핸들 (LSP가 꺼져 있으면 None)
+    fn document_sync(&self) -> Option<&dyn DocumentSync> {
+        None
+    }
+
+    /// 파일 쓰기 후 적용할 포맷터 (프로젝트 설정에서 꺼져 있으면 None)
+    fn formatter(&self) -> Option<&dyn DocumentFormatter> {
+        None
+    }
+
+    /// 협력적 취소 토큰 (타임아웃/사용자 중단 시 취소됨)
+    fn cancellation_token(&self) -> Option<&CancellationToken> {
+        None
+    }
+
+    /// 취소 요청 여부
+    fn is_cancelled(&self) -> bool {
+        self.cancellation_token()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// 취소될 때까지 대기 (`tokio::select!`용, 토큰이 없으면 완료되지 않음)
+    async fn cancelled(&self) {
+        match self.cancellation_token() {
+            Some(token) => token.cancelled().await,
+            None => std::future::pending().await,
+        }
+    }
+}
+
+/// 출력 조각을 받은 뒤의 실행 제어
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputControl {
+    /// 계속 실행
+    Continue,
+    /// 실행 중단 (사유)
+    Abort(String),
+}
+
+/// 오래 걸리는 도구의 진행 상황 (MCP `notifications/progress` 등)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolProgress {
+    /// 지금까지 진행량 (단조 증가)
+    pub progress: f64,
+    /// 전체량 (모르면 None)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    /// 현재 단계 설명
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ToolProgress {
+    /// 진행률 (전체량을 알 때만, 0~100)
+    pub fn percent(&self) -> Option<u8> {
+        self.total
+            .filter(|total| *total > 0.0)
+            .map(|total| (self.progress / total * 100.0).clamp(0.0, 100.0).round() as u8)
+    }
+}
+
+impl std::fmt::Display for ToolProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.percent(), self.total) {
+            (Some(percent), Some(total)) => {
+                write!(f, "{}% ({}/{})", percent, self.progress, total)?
+            }
+            _ => write!(f, "{}", self.progress)?,
+        }
+        match self.message.as_deref().filter(|m| !m.is_empty()) {
+            Some(message) => write!(f, " - {}", message),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 실행 중인 도구의 출력을 받는 수신자
+///
+/// bash처럼 오래 걸리는 도구가 stdout/stderr를 줄 단위로 전달합니다.
+/// `OutputControl::Abort`를 반환하면 도구는 프로세스를 종료합니다.
+pub trait ToolOutputSink: Send + Sync {
+    /// 출력 조각 수신
+    fn on_output(&self, chunk: &str, is_stderr: bool) -> OutputControl;
+
+    /// 진행 상황 수신 (기본: 한 줄 출력으로 전달)
+    fn on_progress(&self, progress: &ToolProgress) -> OutputControl {
+        self.on_output(&format!("progress {}\n", progress), false)
+    }
+}
+
+/// 도구가 파일을 쓰거나 지운 뒤 실행 중인 언어 서버에 알리는 인터페이스
+///
+/// Layer2의 `LspManager`가 구현합니다 (`didOpen`/`didChange`/`didSave`/`didClose`).
+/// 알림은 최선 노력이며 실패해도 도구 결과에 영향을 주지 않습니다.
+#[async_trait]
+pub trait DocumentSync: Send + Sync {
+    /// 파일 내용이 바뀜 (새 파일 포함)
+    async fn file_changed(&self, path: &std::path::Path, content: &str);
+
+    /// 파일이 삭제됨
+    async fn file_removed(&self, _path: &std::path::Path) {}
+}
+
+/// Write/Edit 도구가 쓴 내용을 프로젝트 스타일로 맞추는 포맷터
+///
+/// Layer2의 `AutoFormatter`가 구현합니다 (외부 포맷터 또는 `textDocument/formatting`).
+#[async_trait]
+pub trait DocumentFormatter: Send + Sync {
+    /// 포맷된 내용 (`None`이면 이 파일은 포맷 대상이 아님)
+    async fn format(&self, path: &std::path::Path, content: &str) -> Result<Option<String>>;
 }
 
 // ============================================================================
@@ -371,6 +505,8 @@ pub struct ChatRequest {
     pub model: String,
     pub messages: Vec<ChatMessage>,
     pub max_tokens: Option<u32>,
+    /// 커스텀 stop sequence (빈 목록이면 사용 안 함)
+    pub stop_sequences: Vec<String>,
     pub temperature: Option<f32>,
     pub tools: Option<Vec<Value>>,
     pub stream: bool,
@@ -576,6 +712,27 @@ pub enum PermissionResponse {
     DenyPermanent,
 }
 
+impl PermissionResponse {
+    /// 허용 응답인지
+    pub fn is_allowed(&self) -> bool {
+        matches!(
+            self,
+            Self::AllowOnce | Self::AllowSession | Self::AllowPermanent
+        )
+    }
+
+    /// 감사 로그용 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AllowOnce => "allow_once",
+            Self::AllowSession => "allow_session",
+            Self::AllowPermanent => "allow_permanent",
+            Self::Deny => "deny",
+            Self::DenyPermanent => "deny_permanent",
+        }
+    }
+}
+
 /// 권한 UI 델리게이트
 ///
 /// Layer4-CLI에서 구현합니다.
@@ -591,6 +748,20 @@ pub trait PermissionDelegate: Send + Sync {
         risk_score: u8,
     ) -> PermissionResponse;
 
+    /// 구조화된 프롬프트 표시
+    ///
+    /// 위험 요인, 미리보기, 대안을 직접 렌더링하려면 구현합니다.
+    /// 기본 구현은 `request_permission`으로 위임합니다.
+    async fn confirm(&self, prompt: &ConfirmationPrompt) -> PermissionResponse {
+        self.request_permission(
+            &prompt.tool,
+            &prompt.action,
+            &prompt.description,
+            prompt.risk_score,
+        )
+        .await
+    }
+
     /// 알림 표시 (정보성)
     fn notify(&self, message: &str);
 
@@ -630,4 +801,23 @@ mod tests {
         assert_eq!(result.output, "output");
         assert!(result.metadata.contains_key("key"));
     }
+
+    #[test]
+    fn test_tool_progress_display() {
+        let progress = ToolProgress {
+            progress: 3.0,
+            total: Some(8.0),
+            message: Some("Indexing pages".to_string()),
+        };
+        assert_eq!(progress.percent(), Some(38));
+        assert_eq!(progress.to_string(), "38% (3/8) - Indexing pages");
+
+        let progress: ToolProgress = serde_json::from_value(serde_json::json!({
+            "progressToken": "call-1",
+            "progress": 42
+        }))
+        .unwrap();
+        assert_eq!(progress.percent(), None);
+        assert_eq!(progress.to_string(), "42");
+    }
 }
@@ -8,6 +8,7 @@
 //! - `ToolCall`: LLM이 요청한 도구 호출
 //! - `ToolResult`: 도구 실행 결과
 //! - `Message`: 대화 메시지
+//! - `ImageAttachment`: 메시지에 첨부되는 이미지 (vision 모델용)
 //! - `MessageRole`: 메시지 역할 (system, user, assistant, tool)
 //! - `TokenUsage`: 토큰 사용량
 //! - `StreamEvent`: 스트리밍 이벤트
@@ -74,6 +75,46 @@ pub struct Message {
     /// 도구 실행 결과 (tool 역할인 경우)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_result: Option<ToolResultMessage>,
+
+    /// 첨부 이미지 (user 역할, vision 모델인 경우에만 전송)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<ImageAttachment>,
+}
+
+/// 메시지 첨부 이미지
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageAttachment {
+    /// MIME 타입 (image/png, image/jpeg, image/gif, image/webp)
+    pub media_type: String,
+
+    /// Base64 인코딩된 이미지 데이터
+    pub data: String,
+
+    /// 원본 경로나 설명 (표시용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl ImageAttachment {
+    /// 새 이미지 첨부 생성
+    pub fn new(media_type: impl Into<String>, data: impl Into<String>) -> Self {
+        Self {
+            media_type: media_type.into(),
+            data: data.into(),
+            source: None,
+        }
+    }
+
+    /// 원본 경로/설명 설정
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// data URL 형식 (data:image/png;base64,...)
+    pub fn data_url(&self) -> String {
+        format!("data:{};base64,{}", self.media_type, self.data)
+    }
 }
 
 impl Message {
@@ -85,6 +126,7 @@ impl Message {
             content: content.into(),
             tool_calls: None,
             tool_result: None,
+            images: Vec::new(),
         }
     }
 
@@ -96,6 +138,7 @@ impl Message {
             content: content.into(),
             tool_calls: None,
             tool_result: None,
+            images: Vec::new(),
         }
     }
 
@@ -107,6 +150,7 @@ impl Message {
             content: content.into(),
             tool_calls: None,
             tool_result: None,
+            images: Vec::new(),
         }
     }
 
@@ -118,6 +162,7 @@ impl Message {
             content: content.into(),
             tool_calls: Some(tool_calls),
             tool_result: None,
+            images: Vec::new(),
         }
     }
 
@@ -133,6 +178,15 @@ impl Message {
                 content: content.into(),
                 is_error,
             }),
+            images: Vec::new(),
+        }
+    }
+
+    /// 이미지가 첨부된 사용자 메시지 생성
+    pub fn user_with_images(content: impl Into<String>, images: Vec<ImageAttachment>) -> Self {
+        Self {
+            images,
+            ..Self::user(content)
         }
     }
 
@@ -88,6 +88,9 @@ pub enum Error {
     #[error("Skill error: {0}")]
     Skill(String),
 
+    #[error("Capability unavailable: {capability} - {reason}")]
+    CapabilityUnavailable { capability: String, reason: String },
+
     // ========================================================================
     // 실행 관련
     // ========================================================================
@@ -148,6 +151,7 @@ impl Error {
                 | Error::NotFound(_)
                 | Error::InvalidInput(_)
                 | Error::Validation(_)
+                | Error::CapabilityUnavailable { .. }
                 | Error::Cancelled
         )
     }
@@ -167,6 +171,17 @@ impl Error {
             message: message.into(),
         }
     }
+
+    /// 서브시스템 사용 불가 에러 생성 헬퍼
+    pub fn capability_unavailable(
+        capability: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Error::CapabilityUnavailable {
+            capability: capability.into(),
+            reason: reason.into(),
+        }
+    }
 }
 
 // ============================================================================
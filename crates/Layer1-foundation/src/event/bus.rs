@@ -1,14 +1,33 @@
 //! Event Bus - 이벤트 브로드캐스트 시스템
 //!
 //! 비동기 이벤트 발행/구독 시스템을 제공합니다.
+//!
+//! ## 필터
+//!
+//! `EventFilter`는 카테고리, 심각도, 이벤트 타입 glob, 세션 ID 조건을 AND로 묶고
+//! `and`/`or`로 조합할 수 있습니다. 필터는 버스에서 전달 전에 평가됩니다.
+//!
+//! ```ignore
+//! let filter = EventFilter::new()
+//!     .with_event_type_glob("tool.*")
+//!     .with_min_severity(EventSeverity::Warning)
+//!     .or(EventFilter::new().with_categories(vec![EventCategory::Error]));
+//! ```
+//!
+//! ## 전달 방식
+//!
+//! - `Delivery::Inline` - `publish` 안에서 바로 호출 (기본)
+//! - `Delivery::Queued` - 리스너별 bounded 큐 + 전용 태스크.
+//!   느린 UI 리스너가 가득 차도 `OverflowPolicy`에 따라 버리므로
+//!   발행자(와 감사 로그 등 다른 리스너)를 막지 않습니다.
 
-use super::types::{EventCategory, ForgeEvent};
+use super::types::{EventCategory, EventSeverity, ForgeEvent};
 use async_trait::async_trait;
-use std::collections::HashMap;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
-use tokio::sync::{broadcast, RwLock};
-use tracing::{debug, trace};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, Notify, RwLock};
+use tracing::{debug, trace, warn};
 
 // ============================================================================
 // EventListener Trait
@@ -43,6 +62,16 @@ pub trait EventListener: Send + Sync {
         None
     }
 
+    /// 기본 필터 (구독 시 필터가 없을 때 사용, `categories`보다 우선)
+    fn filter(&self) -> Option<EventFilter> {
+        None
+    }
+
+    /// 전달 방식 (구독 시 지정하지 않으면 사용)
+    fn delivery(&self) -> Delivery {
+        Delivery::Inline
+    }
+
     /// 이벤트 처리
     async fn on_event(&self, event: &ForgeEvent);
 }
@@ -64,7 +93,19 @@ pub struct EventFilter {
     pub sources: Option<Vec<String>>,
 
     /// 최소 심각도
-    pub min_severity: Option<super::types::EventSeverity>,
+    pub min_severity: Option<EventSeverity>,
+
+    /// 이벤트 타입 glob 패턴 (예: "tool.*", "*.failed")
+    pub event_type_globs: Option<Vec<glob::Pattern>>,
+
+    /// 세션 ID 필터
+    pub session_ids: Option<Vec<String>>,
+
+    /// 모두 만족해야 하는 하위 필터 (AND)
+    pub all_of: Vec<EventFilter>,
+
+    /// 하나 이상 만족해야 하는 하위 필터 (OR)
+    pub any_of: Vec<EventFilter>,
 }
 
 impl EventFilter {
@@ -91,6 +132,46 @@ impl EventFilter {
         self
     }
 
+    /// 최소 심각도 설정
+    pub fn with_min_severity(mut self, severity: EventSeverity) -> Self {
+        self.min_severity = Some(severity);
+        self
+    }
+
+    /// 이벤트 타입 glob 추가 (잘못된 패턴은 문자 그대로 매칭)
+    pub fn with_event_type_glob(mut self, pattern: &str) -> Self {
+        let pattern = glob::Pattern::new(pattern).unwrap_or_else(|e| {
+            warn!("Invalid event type glob '{}': {}", pattern, e);
+            glob::Pattern::new(&glob::Pattern::escape(pattern)).unwrap_or_default()
+        });
+        self.event_type_globs
+            .get_or_insert_with(Vec::new)
+            .push(pattern);
+        self
+    }
+
+    /// 세션 ID 필터 추가
+    pub fn with_session(mut self, session_id: impl Into<String>) -> Self {
+        self.session_ids
+            .get_or_insert_with(Vec::new)
+            .push(session_id.into());
+        self
+    }
+
+    /// 두 필터를 모두 만족 (AND)
+    pub fn and(mut self, other: EventFilter) -> Self {
+        self.all_of.push(other);
+        self
+    }
+
+    /// 둘 중 하나를 만족 (OR)
+    pub fn or(self, other: EventFilter) -> Self {
+        Self {
+            any_of: vec![self, other],
+            ..Self::default()
+        }
+    }
+
     /// 이벤트가 필터를 통과하는지 확인
     pub fn matches(&self, event: &ForgeEvent) -> bool {
         // 카테고리 체크
@@ -122,10 +203,180 @@ impl EventFilter {
             }
         }
 
+        // 이벤트 타입 glob 체크
+        if let Some(ref globs) = self.event_type_globs {
+            if !globs.iter().any(|g| g.matches(&event.event_type)) {
+                return false;
+            }
+        }
+
+        // 세션 체크 (세션 없는 이벤트는 통과하지 않음)
+        if let Some(ref sessions) = self.session_ids {
+            match &event.session_id {
+                Some(id) if sessions.contains(id) => {}
+                _ => return false,
+            }
+        }
+
+        // 조합 필터
+        if !self.all_of.iter().all(|f| f.matches(event)) {
+            return false;
+        }
+        if !self.any_of.is_empty() && !self.any_of.iter().any(|f| f.matches(event)) {
+            return false;
+        }
+
         true
     }
 }
 
+// ============================================================================
+// Delivery - 리스너별 전달 방식
+// ============================================================================
+
+/// 큐가 가득 찼을 때 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// 새 이벤트를 버림
+    DropNewest,
+    /// 가장 오래된 이벤트를 버리고 새 이벤트 추가 (UI처럼 최신 상태가 중요한 경우)
+    DropOldest,
+    /// 자리가 날 때까지 발행자가 대기 (이벤트 유실 불가)
+    Block,
+}
+
+/// 리스너 전달 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// `publish` 안에서 바로 호출
+    Inline,
+    /// 리스너 전용 bounded 큐와 태스크로 전달
+    Queued {
+        /// 큐 용량
+        capacity: usize,
+        /// 가득 찼을 때 처리
+        overflow: OverflowPolicy,
+    },
+}
+
+impl Delivery {
+    /// 큐 전달 (용량, 처리 방식)
+    pub fn queued(capacity: usize, overflow: OverflowPolicy) -> Self {
+        Delivery::Queued {
+            capacity: capacity.max(1),
+            overflow,
+        }
+    }
+}
+
+/// 리스너 전달 통계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListenerStats {
+    /// 리스너가 처리한 이벤트 수
+    pub delivered: u64,
+    /// 큐가 가득 차서 버린 이벤트 수
+    pub dropped: u64,
+    /// 현재 큐에 쌓인 이벤트 수
+    pub queued: usize,
+}
+
+/// 리스너 전용 bounded 큐
+struct ListenerQueue {
+    events: Mutex<VecDeque<ForgeEvent>>,
+    capacity: usize,
+    overflow: OverflowPolicy,
+    /// 이벤트 추가 알림 (소비 태스크)
+    available: Notify,
+    /// 자리 생김 알림 (Block 정책의 발행자)
+    space: Notify,
+    dropped: AtomicU64,
+    closed: AtomicBool,
+}
+
+impl ListenerQueue {
+    fn new(capacity: usize, overflow: OverflowPolicy) -> Self {
+        Self {
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            overflow,
+            available: Notify::new(),
+            space: Notify::new(),
+            dropped: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.events.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// 이벤트 추가 (정책에 따라 버리거나 대기)
+    async fn push(&self, event: ForgeEvent) {
+        loop {
+            let space = self.space.notified();
+            tokio::pin!(space);
+            space.as_mut().enable();
+
+            {
+                let mut events = self.events.lock().unwrap_or_else(|e| e.into_inner());
+                if events.len() < self.capacity {
+                    events.push_back(event);
+                    self.available.notify_one();
+                    return;
+                }
+                match self.overflow {
+                    OverflowPolicy::DropNewest => {
+                        self.dropped.fetch_add(1, Ordering::Relaxed);
+                        return;
+                    }
+                    OverflowPolicy::DropOldest => {
+                        events.pop_front();
+                        events.push_back(event);
+                        self.dropped.fetch_add(1, Ordering::Relaxed);
+                        self.available.notify_one();
+                        return;
+                    }
+                    OverflowPolicy::Block => {}
+                }
+            }
+
+            if self.closed.load(Ordering::SeqCst) {
+                return;
+            }
+            space.await;
+        }
+    }
+
+    /// 이벤트 꺼내기 (닫히고 비면 None)
+    async fn pop(&self) -> Option<ForgeEvent> {
+        loop {
+            let available = self.available.notified();
+            tokio::pin!(available);
+            available.as_mut().enable();
+
+            let next = self
+                .events
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .pop_front();
+            if let Some(event) = next {
+                self.space.notify_one();
+                return Some(event);
+            }
+            if self.closed.load(Ordering::SeqCst) {
+                return None;
+            }
+            available.await;
+        }
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.available.notify_waiters();
+        self.space.notify_waiters();
+    }
+}
+
 // ============================================================================
 // EventBus
 // ============================================================================
@@ -157,6 +408,22 @@ impl Default for EventBusConfig {
 struct RegisteredListener {
     listener: Arc<dyn EventListener>,
     filter: Option<EventFilter>,
+    /// Queued 전달일 때의 큐
+    queue: Option<Arc<ListenerQueue>>,
+    delivered: Arc<AtomicU64>,
+}
+
+impl RegisteredListener {
+    /// 필터 평가 (구독 필터 → 리스너 필터 → 리스너 카테고리)
+    fn accepts(&self, event: &ForgeEvent) -> bool {
+        if let Some(filter) = &self.filter {
+            return filter.matches(event);
+        }
+        match self.listener.categories() {
+            Some(cats) => cats.contains(&event.category),
+            None => true,
+        }
+    }
 }
 
 /// 이벤트 버스
@@ -230,17 +497,57 @@ impl EventBus {
         &self,
         listener: Arc<dyn EventListener>,
         filter: Option<EventFilter>,
+    ) -> ListenerId {
+        let delivery = listener.delivery();
+        self.subscribe_with_delivery(listener, filter, delivery)
+            .await
+    }
+
+    /// 필터와 전달 방식을 지정해 리스너 등록
+    pub async fn subscribe_with_delivery(
+        &self,
+        listener: Arc<dyn EventListener>,
+        filter: Option<EventFilter>,
+        delivery: Delivery,
     ) -> ListenerId {
         let id = ListenerId::new(self.listener_counter.fetch_add(1, Ordering::SeqCst));
 
         debug!(
             listener_name = listener.name(),
             listener_id = %id,
+            ?delivery,
             "Registering event listener"
         );
 
+        let filter = filter.or_else(|| listener.filter());
+        let delivered = Arc::new(AtomicU64::new(0));
+        let queue = match delivery {
+            Delivery::Inline => None,
+            Delivery::Queued { capacity, overflow } => {
+                let queue = Arc::new(ListenerQueue::new(capacity.max(1), overflow));
+                let worker_queue = Arc::clone(&queue);
+                let worker_listener = Arc::clone(&listener);
+                let worker_delivered = Arc::clone(&delivered);
+                tokio::spawn(async move {
+                    while let Some(event) = worker_queue.pop().await {
+                        worker_listener.on_event(&event).await;
+                        worker_delivered.fetch_add(1, Ordering::Relaxed);
+                    }
+                });
+                Some(queue)
+            }
+        };
+
         let mut listeners = self.listeners.write().await;
-        listeners.insert(id, RegisteredListener { listener, filter });
+        listeners.insert(
+            id,
+            RegisteredListener {
+                listener,
+                filter,
+                queue,
+                delivered,
+            },
+        );
 
         id
     }
@@ -248,13 +555,32 @@ impl EventBus {
     /// 리스너 해제
     pub async fn unsubscribe(&self, id: ListenerId) -> bool {
         let mut listeners = self.listeners.write().await;
-        let removed = listeners.remove(&id).is_some();
+        let removed = listeners.remove(&id);
 
-        if removed {
+        if let Some(registered) = &removed {
+            // 남은 이벤트는 처리 후 전달 태스크 종료
+            if let Some(queue) = &registered.queue {
+                queue.close();
+            }
             debug!(listener_id = %id, "Unregistered event listener");
         }
 
-        removed
+        removed.is_some()
+    }
+
+    /// 리스너 전달 통계
+    pub async fn listener_stats(&self, id: ListenerId) -> Option<ListenerStats> {
+        let listeners = self.listeners.read().await;
+        let registered = listeners.get(&id)?;
+        Some(ListenerStats {
+            delivered: registered.delivered.load(Ordering::Relaxed),
+            dropped: registered
+                .queue
+                .as_ref()
+                .map(|q| q.dropped.load(Ordering::Relaxed))
+                .unwrap_or(0),
+            queued: registered.queue.as_ref().map(|q| q.len()).unwrap_or(0),
+        })
     }
 
     /// 이벤트 발행
@@ -287,27 +613,24 @@ impl EventBus {
         // 등록된 리스너들에게 전달
         let listeners = self.listeners.read().await;
         for (id, registered) in listeners.iter() {
-            // 필터 체크
-            let should_deliver = match &registered.filter {
-                Some(filter) => filter.matches(&event),
-                None => {
-                    // 리스너의 카테고리 필터 체크
-                    match registered.listener.categories() {
-                        Some(cats) => cats.contains(&event.category),
-                        None => true,
-                    }
-                }
-            };
+            // 필터 체크 (전달 전에 평가 - 큐에는 통과한 이벤트만 쌓임)
+            if !registered.accepts(&event) {
+                continue;
+            }
 
-            if should_deliver {
-                trace!(
-                    listener_id = %id,
-                    listener_name = registered.listener.name(),
-                    event_type = %event.event_type,
-                    "Delivering event to listener"
-                );
+            trace!(
+                listener_id = %id,
+                listener_name = registered.listener.name(),
+                event_type = %event.event_type,
+                "Delivering event to listener"
+            );
 
-                registered.listener.on_event(&event).await;
+            match &registered.queue {
+                Some(queue) => queue.push(event.clone()).await,
+                None => {
+                    registered.listener.on_event(&event).await;
+                    registered.delivered.fetch_add(1, Ordering::Relaxed);
+                }
             }
         }
     }
@@ -473,4 +796,131 @@ mod tests {
         let history = bus.history(None).await;
         assert_eq!(history.len(), 5);
     }
+
+    #[test]
+    fn test_composable_filter() {
+        let session_tools = EventFilter::new()
+            .with_event_type_glob("tool.*")
+            .with_session("s1");
+        let errors = EventFilter::new().with_min_severity(EventSeverity::Error);
+        let filter = session_tools.or(errors);
+
+        let tool_s1 = ForgeEvent::new("tool.completed", EventCategory::Tool).with_session("s1");
+        let tool_s2 = ForgeEvent::new("tool.completed", EventCategory::Tool).with_session("s2");
+        let tool_none = ForgeEvent::new("tool.completed", EventCategory::Tool);
+        let error_s2 = ForgeEvent::new("system.crashed", EventCategory::System)
+            .with_severity(EventSeverity::Error)
+            .with_session("s2");
+
+        assert!(filter.matches(&tool_s1));
+        assert!(!filter.matches(&tool_s2));
+        assert!(!filter.matches(&tool_none));
+        assert!(filter.matches(&error_s2));
+
+        let failed_tools = EventFilter::new()
+            .with_categories(vec![EventCategory::Tool])
+            .and(EventFilter::new().with_event_type_glob("*.failed"));
+        assert!(failed_tools.matches(&ForgeEvent::new("tool.failed", EventCategory::Tool)));
+        assert!(!failed_tools.matches(&tool_s1));
+
+        // 잘못된 glob은 문자 그대로 매칭
+        let literal = EventFilter::new().with_event_type_glob("tool.[");
+        assert!(literal.matches(&ForgeEvent::new("tool.[", EventCategory::Tool)));
+        assert!(!literal.matches(&tool_s1));
+    }
+
+    /// 신호가 올 때까지 처리하지 않는 리스너
+    struct GatedListener {
+        gate: Arc<Notify>,
+        seen: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl EventListener for GatedListener {
+        fn name(&self) -> &str {
+            "gated"
+        }
+
+        async fn on_event(&self, event: &ForgeEvent) {
+            self.gate.notified().await;
+            self.seen.lock().unwrap().push(event.event_type.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queued_listener_does_not_block() {
+        let bus = EventBus::new();
+        let gate = Arc::new(Notify::new());
+        let slow = Arc::new(GatedListener {
+            gate: Arc::clone(&gate),
+            seen: Mutex::new(Vec::new()),
+        });
+        let audit = Arc::new(TestListener::new("audit"));
+
+        let slow_id = bus
+            .subscribe_with_delivery(
+                slow.clone(),
+                None,
+                Delivery::queued(2, OverflowPolicy::DropOldest),
+            )
+            .await;
+        bus.subscribe(audit.clone()).await;
+
+        // 느린 리스너가 첫 이벤트에서 멈춰 있어도 발행은 바로 끝남
+        bus.publish(ForgeEvent::new("ui.0", EventCategory::System))
+            .await;
+        tokio::task::yield_now().await;
+        for i in 1..5 {
+            bus.publish(ForgeEvent::new(format!("ui.{}", i), EventCategory::System))
+                .await;
+        }
+        assert_eq!(audit.call_count(), 5);
+
+        // 첫 이벤트는 처리 중, 큐에는 최신 2개만 남음
+        let stats = bus.listener_stats(slow_id).await.unwrap();
+        assert_eq!(stats.queued, 2);
+        assert_eq!(stats.dropped, 2);
+
+        for _ in 0..3 {
+            gate.notify_one();
+            tokio::task::yield_now().await;
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(*slow.seen.lock().unwrap(), vec!["ui.0", "ui.3", "ui.4"]);
+        assert_eq!(bus.listener_stats(slow_id).await.unwrap().delivered, 3);
+    }
+
+    #[tokio::test]
+    async fn test_queued_drop_newest_and_block() {
+        let full = ListenerQueue::new(1, OverflowPolicy::DropNewest);
+        full.push(ForgeEvent::new("a", EventCategory::System)).await;
+        full.push(ForgeEvent::new("b", EventCategory::System)).await;
+        assert_eq!(full.len(), 1);
+        assert_eq!(full.dropped.load(Ordering::Relaxed), 1);
+        assert_eq!(full.pop().await.unwrap().event_type, "a");
+
+        // Block: 자리가 날 때까지 대기하고 유실 없음
+        let queue = Arc::new(ListenerQueue::new(1, OverflowPolicy::Block));
+        queue
+            .push(ForgeEvent::new("a", EventCategory::System))
+            .await;
+        let producer = {
+            let queue = Arc::clone(&queue);
+            tokio::spawn(async move {
+                queue
+                    .push(ForgeEvent::new("b", EventCategory::System))
+                    .await;
+            })
+        };
+        tokio::task::yield_now().await;
+        assert!(!producer.is_finished());
+
+        assert_eq!(queue.pop().await.unwrap().event_type, "a");
+        producer.await.unwrap();
+        assert_eq!(queue.pop().await.unwrap().event_type, "b");
+        assert_eq!(queue.dropped.load(Ordering::Relaxed), 0);
+
+        queue.close();
+        assert!(queue.pop().await.is_none());
+    }
 }
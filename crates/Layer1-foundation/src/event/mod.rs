@@ -63,11 +63,14 @@ pub use bus::{
     init_global_event_bus,
     publish,
     // EventBus
+    Delivery,
     EventBus,
     EventBusConfig,
     EventFilter,
     EventListener,
     ListenerId,
+    ListenerStats,
+    OverflowPolicy,
 };
 
 pub use types::{
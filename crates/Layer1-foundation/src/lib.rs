@@ -4,7 +4,7 @@
 //! - Core: 핵심 Trait 정의 (Tool, Provider, Task, PermissionDelegate)
 //! - Permission: 권한 관리 (동적 등록 + 런타임 + 보안 분석)
 //! - Registry: MCP, Provider, Model, Shell 등록
-//! - Storage: SQLite (런타임), JsonStore (범용)
+//! - Storage: StorageBackend (SQLite/Postgres 런타임), JsonStore (범용)
 //! - Config: 통합 설정 (ForgeConfig, Limits 등)
 //!
 //! ## 아키텍처
@@ -48,6 +48,7 @@ pub use error::{Error, Result};
 // ============================================================================
 pub use core::{
     // Types - Message & Role (types.rs)
+    ImageAttachment,
     Message,
     MessageRole,
     // Types - Tool Call (types.rs)
@@ -65,10 +66,16 @@ pub use core::{
     SessionInfo,
     ToolSource,
     // Traits - Tool (traits.rs)
+    CancellationToken,
+    DocumentFormatter,
+    DocumentSync,
+    OutputControl,
     Tool,
     ToolContext,
     ToolExecutionResult,
     ToolMeta,
+    ToolOutputSink,
+    ToolProgress,
     // ToolResult = ToolExecutionResult (하위 호환성)
     ToolResult,
     // Traits - Provider (traits.rs)
@@ -112,6 +119,7 @@ pub use config::{
     MonthlyLimits,
     SecurityConfig,
     SessionLimits,
+    StorageConfig,
     ThemeConfig,
     FORGE_CONFIG_FILE,
 };
@@ -122,22 +130,35 @@ pub use config::{
 pub use permission::{
     // Categories
     categories as permission_categories,
+    // Egress (네트워크 도메인 허용/차단)
+    check_egress,
     // Security (명령어/경로 분석)
     command_analyzer,
     dangerous_commands,
+    egress_client,
+    egress_policy,
+    egress_redirect_policy,
     path_analyzer,
     // Types (동적 등록)
     register as register_permission,
     register_all as register_permissions,
     registry as permission_registry,
     sensitive_paths,
+    set_egress_policy,
+    // Bundle (서명된 프리셋)
+    BundleSigningKey,
     CommandAnalysis,
     CommandAnalyzer,
     CommandRisk,
+    // Prompt (확인 프롬프트)
+    ConfirmOption,
+    ConfirmationPrompt,
+    EgressPolicy,
     PathAnalyzer,
     // Runtime (서비스)
     Permission,
     PermissionAction,
+    PermissionBundle,
     // Settings (JSON 저장)
     PermissionActionType,
     PermissionDef,
@@ -147,8 +168,19 @@ pub use permission::{
     PermissionScope,
     PermissionService,
     PermissionSettings,
+    PermissionSimulation,
     PermissionStatus,
+    PromptActionKind,
+    PromptCatalog,
+    PromptMessage,
+    PromptPreview,
+    PromptRisk,
+    // Delegate (원격 승인)
+    RemoteDelegate,
+    RemoteEndpoint,
+    RequiredPermission,
     SensitivePath,
+    TrustedBundleKeys,
     PERMISSIONS_FILE,
 };
 
@@ -193,17 +225,29 @@ pub mod provider_store {
 // Storage (저장소)
 // ============================================================================
 pub use storage::{
+    // Backend (SQLite / Postgres)
+    open_backend,
     // JSON (범용)
     JsonStore,
     // SQLite (런타임 데이터)
     MessageRecord,
+    ModelUsageStats,
+    ScheduleRunRecord,
+    ScheduledTaskRecord,
     SessionRecord,
     Storage,
+    StorageBackend,
     TokenUsageRecord,
     ToolExecutionRecord,
+    ToolMetrics,
+    ToolUsageStats,
     UsageSummary,
 };
 
+// Postgres (공유 런타임 데이터)
+#[cfg(feature = "postgres")]
+pub use storage::PostgresStorage;
+
 // ============================================================================
 // Event (이벤트 시스템)
 // ============================================================================
@@ -212,6 +256,7 @@ pub use event::{
     global_event_bus,
     init_global_event_bus,
     // Bus
+    Delivery,
     EventBus,
     EventBusConfig,
     // Types
@@ -222,6 +267,8 @@ pub use event::{
     EventSeverity,
     ForgeEvent,
     ListenerId,
+    ListenerStats,
+    OverflowPolicy,
 };
 
 // ============================================================================
@@ -237,6 +284,9 @@ pub use audit::{
     AuditLogger,
     AuditLoggerConfig,
     AuditQuery,
+    // Report
+    AuditReport,
+    AuditReportOptions,
     AuditResult,
     AuditStatistics,
 };
@@ -276,6 +326,10 @@ pub use cache::{
 // Tokenizer (모델별 토큰 계산)
 // ============================================================================
 pub use tokenizer::{
+    // Factory
+    tokenizer_factory,
+    // Calibration
+    CalibrationEvent,
     // Estimators
     ClaudeEstimator,
     // Dynamic (Ollama, vLLM, LM Studio 등)
@@ -0,0 +1,435 @@
+//! Permission 설정 번들 (서명된 JSON)
+//!
+//! 팀이 검증한 권한 프리셋("frontend dev", "infra locked-down" 등)을
+//! 하나의 파일로 배포하고 한 번에 적용하기 위한 형식입니다.
+//!
+//! - `PermissionSettings::export_bundle()`: Ed25519 키로 서명한 번들 생성
+//! - `PermissionSettings::import_bundle()`: 서명과 서명자(신뢰 키 목록) 확인 후 설정 반환
+//!
+//! 서명 대상은 `signature`를 뺀 번들의 정규화 JSON(키 정렬, 집합 배열 정렬)이므로
+//! 파일의 들여쓰기나 항목 순서가 바뀌어도 검증됩니다.
+//!
+//! ```json
+//! {
+//!   "format": "forgecode-permission-bundle",
+//!   "version": 1,
+//!   "name": "frontend-dev",
+//!   "description": "npm/pnpm scripts, writes under src/",
+//!   "createdAt": "2026-10-15T09:00:00Z",
+//!   "settings": { "grants": [...], "denies": [...] },
+//!   "signature": { "algorithm": "ed25519", "publicKey": "...", "value": "..." }
+//! }
+//! ```
+
+use super::settings::PermissionSettings;
+use crate::storage::JsonStore;
+use crate::{Error, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair, UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+/// 번들 형식 식별자
+pub const BUNDLE_FORMAT: &str = "forgecode-permission-bundle";
+
+/// 번들 형식 버전
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// 서명 키 파일명 (PKCS#8, base64)
+pub const BUNDLE_SIGNING_KEY_FILE: &str = "bundle-signing.key";
+
+/// 신뢰하는 번들 서명 키 목록 파일명
+pub const TRUSTED_BUNDLE_KEYS_FILE: &str = "trusted-bundle-keys.json";
+
+const SIGNATURE_ALGORITHM: &str = "ed25519";
+
+// ============================================================================
+// PermissionBundle
+// ============================================================================
+
+/// 번들 서명
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleSignature {
+    /// 서명 알고리즘 (`ed25519`)
+    pub algorithm: String,
+
+    /// 서명자 공개 키 (base64)
+    pub public_key: String,
+
+    /// 서명 값 (base64)
+    pub value: String,
+}
+
+/// 서명된 권한 설정 번들
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionBundle {
+    /// 형식 식별자 (`forgecode-permission-bundle`)
+    pub format: String,
+
+    /// 형식 버전
+    pub version: u32,
+
+    /// 프리셋 이름 (예: "frontend-dev")
+    pub name: String,
+
+    /// 설명
+    #[serde(default)]
+    pub description: String,
+
+    /// 생성 시간
+    pub created_at: DateTime<Utc>,
+
+    /// 권한 설정
+    pub settings: PermissionSettings,
+
+    /// 서명
+    pub signature: BundleSignature,
+}
+
+impl PermissionBundle {
+    /// JSON 파싱 (서명은 확인하지 않음, `PermissionSettings::import_bundle` 사용)
+    pub fn from_json(json: &str) -> Result<Self> {
+        let bundle: Self = serde_json::from_str(json)
+            .map_err(|e| Error::Config(format!("Invalid permission bundle: {}", e)))?;
+        if bundle.format != BUNDLE_FORMAT {
+            return Err(Error::Config(format!(
+                "Not a permission bundle (format '{}')",
+                bundle.format
+            )));
+        }
+        if bundle.version > BUNDLE_VERSION {
+            return Err(Error::Config(format!(
+                "Unsupported permission bundle version {} (supported: {})",
+                bundle.version, BUNDLE_VERSION
+            )));
+        }
+        Ok(bundle)
+    }
+
+    /// JSON 직렬화
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// 서명자 키 지문 (공개 키 앞 16자)
+    pub fn signer(&self) -> String {
+        key_fingerprint(&self.signature.public_key)
+    }
+
+    /// 서명 확인 (서명자 신뢰 여부는 확인하지 않음)
+    pub fn verify_signature(&self) -> Result<()> {
+        if self.signature.algorithm != SIGNATURE_ALGORITHM {
+            return Err(Error::Validation(format!(
+                "Unsupported bundle signature algorithm '{}'",
+                self.signature.algorithm
+            )));
+        }
+
+        let public_key = decode(&self.signature.public_key, "public key")?;
+        let signature = decode(&self.signature.value, "signature")?;
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(&self.signed_payload()?, &signature)
+            .map_err(|_| {
+                Error::Validation(format!(
+                    "Permission bundle '{}' has an invalid signature (modified after signing?)",
+                    self.name
+                ))
+            })
+    }
+
+    /// 서명 대상 바이트 (`signature`를 제외한 정규화 JSON)
+    fn signed_payload(&self) -> Result<Vec<u8>> {
+        let mut value = serde_json::to_value(self)?;
+        if let Value::Object(map) = &mut value {
+            map.remove("signature");
+        }
+        Ok(canonical_json(&value).into_bytes())
+    }
+}
+
+/// 정규화 JSON: 객체 키 정렬, 배열 항목 정렬 (번들의 배열은 모두 집합)
+fn canonical_json(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            let fields: Vec<String> = entries
+                .into_iter()
+                .map(|(key, value)| {
+                    format!("{}:{}", Value::String(key.clone()), canonical_json(value))
+                })
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        Value::Array(items) => {
+            let mut items: Vec<String> = items.iter().map(canonical_json).collect();
+            items.sort();
+            format!("[{}]", items.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+fn decode(value: &str, what: &str) -> Result<Vec<u8>> {
+    BASE64
+        .decode(value.trim())
+        .map_err(|e| Error::Validation(format!("Invalid bundle {} encoding: {}", what, e)))
+}
+
+fn key_fingerprint(public_key: &str) -> String {
+    public_key.trim().chars().take(16).collect()
+}
+
+// ============================================================================
+// Signing / trusted keys
+// ============================================================================
+
+/// 번들 서명 키 (Ed25519)
+pub struct BundleSigningKey {
+    pkcs8: Vec<u8>,
+    key_pair: Ed25519KeyPair,
+}
+
+impl BundleSigningKey {
+    /// 새 키 생성
+    pub fn generate() -> Result<Self> {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+            .map_err(|_| Error::Config("Failed to generate a bundle signing key".to_string()))?;
+        Self::from_pkcs8(pkcs8.as_ref())
+    }
+
+    /// PKCS#8 바이트에서 로드
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<Self> {
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8)
+            .map_err(|e| Error::Config(format!("Invalid bundle signing key: {}", e)))?;
+        Ok(Self {
+            pkcs8: pkcs8.to_vec(),
+            key_pair,
+        })
+    }
+
+    /// 키 파일 로드 (base64 PKCS#8)
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to read signing key {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_pkcs8(&decode(&content, "signing key")?)
+    }
+
+    /// 키 파일 로드, 없으면 생성해서 저장
+    pub fn load_or_generate(path: &Path) -> Result<Self> {
+        if path.exists() {
+            return Self::load(path);
+        }
+
+        let key = Self::generate()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, BASE64.encode(&key.pkcs8))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(key)
+    }
+
+    /// 기본 키 파일 경로 (`~/.config/forgecode/bundle-signing.key`)
+    pub fn default_path() -> Result<std::path::PathBuf> {
+        Ok(JsonStore::global()?.file_path(BUNDLE_SIGNING_KEY_FILE))
+    }
+
+    /// 공개 키 (base64) - 받는 쪽의 신뢰 키 목록에 추가
+    pub fn public_key(&self) -> String {
+        BASE64.encode(self.key_pair.public_key().as_ref())
+    }
+
+    fn sign(&self, payload: &[u8]) -> String {
+        BASE64.encode(self.key_pair.sign(payload).as_ref())
+    }
+}
+
+/// 신뢰하는 번들 서명 키 목록
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedBundleKeys {
+    /// 공개 키 (base64)
+    #[serde(default)]
+    pub keys: Vec<String>,
+}
+
+impl TrustedBundleKeys {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 글로벌 목록 로드 (`~/.config/forgecode/trusted-bundle-keys.json`)
+    pub fn load() -> Result<Self> {
+        Ok(JsonStore::global()?.load_or_default(TRUSTED_BUNDLE_KEYS_FILE))
+    }
+
+    /// 글로벌 목록 저장
+    pub fn save(&self) -> Result<()> {
+        JsonStore::global()?.save(TRUSTED_BUNDLE_KEYS_FILE, self)
+    }
+
+    /// 키 추가 (이미 있으면 false)
+    pub fn trust(&mut self, public_key: impl Into<String>) -> bool {
+        let key = public_key.into().trim().to_string();
+        if self.contains(&key) {
+            return false;
+        }
+        self.keys.push(key);
+        true
+    }
+
+    pub fn contains(&self, public_key: &str) -> bool {
+        self.keys.iter().any(|k| k.trim() == public_key.trim())
+    }
+}
+
+// ============================================================================
+// PermissionSettings export/import
+// ============================================================================
+
+impl PermissionSettings {
+    /// 서명된 번들로 내보내기
+    pub fn export_bundle(
+        &self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        key: &BundleSigningKey,
+    ) -> Result<PermissionBundle> {
+        let mut bundle = PermissionBundle {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            name: name.into(),
+            description: description.into(),
+            created_at: Utc::now(),
+            settings: self.clone(),
+            signature: BundleSignature {
+                algorithm: SIGNATURE_ALGORITHM.to_string(),
+                public_key: key.public_key(),
+                value: String::new(),
+            },
+        };
+        bundle.signature.value = key.sign(&bundle.signed_payload()?);
+        Ok(bundle)
+    }
+
+    /// 번들 가져오기
+    ///
+    /// 서명이 유효하고 서명자가 `trusted`에 있어야 합니다.
+    /// 설정을 적용(병합/저장)하는 것은 호출자의 몫입니다.
+    pub fn import_bundle(json: &str, trusted: &TrustedBundleKeys) -> Result<PermissionBundle> {
+        let bundle = PermissionBundle::from_json(json)?;
+        bundle.verify_signature()?;
+        if !trusted.contains(&bundle.signature.public_key) {
+            return Err(Error::PermissionDenied(format!(
+                "Permission bundle '{}' is signed by an untrusted key {}… (public key: {})",
+                bundle.name,
+                bundle.signer(),
+                bundle.signature.public_key
+            )));
+        }
+        Ok(bundle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permission::{PermissionActionType, PermissionDeny, PermissionGrant};
+
+    fn preset() -> PermissionSettings {
+        let mut settings = PermissionSettings::new();
+        settings.add_grant(PermissionGrant {
+            tool: "bash".to_string(),
+            action_type: PermissionActionType::Execute,
+            pattern: Some("npm run *".to_string()),
+        });
+        settings.add_grant(PermissionGrant {
+            tool: "write".to_string(),
+            action_type: PermissionActionType::FileWrite,
+            pattern: Some("src/**".to_string()),
+        });
+        settings.add_deny(PermissionDeny {
+            tool: "bash".to_string(),
+            pattern: "rm -rf *".to_string(),
+            reason: None,
+        });
+        settings.auto_approve_tools.insert("read".to_string());
+        settings
+    }
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let key = BundleSigningKey::generate().unwrap();
+        let bundle = preset()
+            .export_bundle("frontend-dev", "npm scripts", &key)
+            .unwrap();
+        let json = bundle.to_json().unwrap();
+
+        let mut trusted = TrustedBundleKeys::new();
+        assert!(trusted.trust(key.public_key()));
+        assert!(!trusted.trust(key.public_key()));
+
+        let imported = PermissionSettings::import_bundle(&json, &trusted).unwrap();
+        assert_eq!(imported.name, "frontend-dev");
+        assert_eq!(imported.settings.grants, preset().grants);
+        assert_eq!(imported.settings.denies, preset().denies);
+        assert!(imported.settings.is_auto_approved("read"));
+
+        // 항목 순서/들여쓰기가 달라도 검증됨
+        let value: Value = serde_json::from_str(&json).unwrap();
+        let compact = serde_json::to_string(&value).unwrap();
+        assert!(PermissionSettings::import_bundle(&compact, &trusted).is_ok());
+    }
+
+    #[test]
+    fn test_bundle_rejects_tampering_and_untrusted_keys() {
+        let key = BundleSigningKey::generate().unwrap();
+        let json = preset()
+            .export_bundle("infra-locked-down", "", &key)
+            .unwrap()
+            .to_json()
+            .unwrap();
+
+        // 신뢰하지 않는 키
+        let err = PermissionSettings::import_bundle(&json, &TrustedBundleKeys::new()).unwrap_err();
+        assert!(err.to_string().contains("untrusted key"));
+
+        // 서명 후 수정
+        let mut trusted = TrustedBundleKeys::new();
+        trusted.trust(key.public_key());
+        let tampered = json.replace("npm run *", "*");
+        let err = PermissionSettings::import_bundle(&tampered, &trusted).unwrap_err();
+        assert!(err.to_string().contains("invalid signature"));
+
+        // 번들이 아닌 JSON
+        assert!(PermissionSettings::import_bundle(r#"{"grants": []}"#, &trusted).is_err());
+    }
+
+    #[test]
+    fn test_signing_key_file() {
+        let dir = std::env::temp_dir().join(format!("forge-bundle-key-{}", std::process::id()));
+        let path = dir.join("keys").join(BUNDLE_SIGNING_KEY_FILE);
+
+        let created = BundleSigningKey::load_or_generate(&path).unwrap();
+        let loaded = BundleSigningKey::load_or_generate(&path).unwrap();
+        assert_eq!(created.public_key(), loaded.public_key());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
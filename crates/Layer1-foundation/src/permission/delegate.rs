@@ -0,0 +1,464 @@
+//! Permission Delegate - 외부 엔드포인트로 권한 승인 위임
+//!
+//! 사람이 터미널 앞에 없는 헤드리스 실행(CI 에이전트 등)에서 권한 요청을
+//! 외부 승인 서비스(Slack 봇, 보안 서비스 등)로 전달하는 `PermissionDelegate` 구현입니다.
+//!
+//! - HTTP 웹훅: `ConfirmationPrompt`를 JSON으로 POST (선택적으로 Bearer 토큰)
+//! - Unix 소켓: 요청 JSON 한 줄을 쓰고 응답 JSON 한 줄을 읽음
+//! - 타임아웃, 연결 실패, 잘못된 응답, 프롬프트에 없는 선택지는 모두 거부 (default-deny)
+//!
+//! ## 프로토콜
+//!
+//! 요청:
+//! ```json
+//! { "id": "…", "prompt": { "tool": "bash", "action": { … }, "risk_score": 7, … } }
+//! ```
+//!
+//! 응답 (`decision`은 `ConfirmOption`과 같은 값):
+//! ```json
+//! { "decision": "allow_once", "reason": "approved by @alice" }
+//! ```
+//!
+//! ```ignore
+//! let delegate = RemoteDelegate::new(RemoteEndpoint::parse("https://approvals.internal/forge")?)
+//!     .with_token(token)
+//!     .with_timeout(Duration::from_secs(120));
+//! let ctx = AgentContext::builder().permission_delegate(Arc::new(delegate));
+//! ```
+
+use super::prompt::{ConfirmOption, ConfirmationPrompt};
+use super::service::PermissionAction;
+use crate::core::{PermissionDelegate, PermissionResponse};
+use crate::{Error, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// 엔드포인트 환경 변수 (`https://…` 또는 `unix:/path/to.sock`)
+pub const PERMISSION_ENDPOINT_ENV: &str = "FORGE_PERMISSION_ENDPOINT";
+
+/// HTTP 웹훅 Bearer 토큰 환경 변수
+pub const PERMISSION_TOKEN_ENV: &str = "FORGE_PERMISSION_TOKEN";
+
+/// 응답 대기 시간(초) 환경 변수
+pub const PERMISSION_TIMEOUT_ENV: &str = "FORGE_PERMISSION_TIMEOUT";
+
+/// 기본 응답 대기 시간 (사람이 승인할 시간을 고려)
+pub const DEFAULT_REMOTE_TIMEOUT: Duration = Duration::from_secs(300);
+
+// ============================================================================
+// RemoteEndpoint
+// ============================================================================
+
+/// 승인 요청을 받을 엔드포인트
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteEndpoint {
+    /// HTTP(S) 웹훅
+    Http { url: String },
+
+    /// Unix 도메인 소켓
+    Unix(PathBuf),
+}
+
+impl RemoteEndpoint {
+    /// `http(s)://…` 또는 `unix:/path` 파싱
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+        if let Some(path) = spec.strip_prefix("unix:") {
+            let path = path.strip_prefix("//").unwrap_or(path);
+            if path.is_empty() {
+                return Err(Error::Config("Empty unix socket path".to_string()));
+            }
+            return Ok(Self::Unix(PathBuf::from(path)));
+        }
+        if spec.starts_with("http://") || spec.starts_with("https://") {
+            return Ok(Self::Http {
+                url: spec.to_string(),
+            });
+        }
+        Err(Error::Config(format!(
+            "Invalid permission endpoint '{}': expected http(s):// URL or unix:PATH",
+            spec
+        )))
+    }
+}
+
+impl std::fmt::Display for RemoteEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Http { url } => write!(f, "{}", url),
+            Self::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+// ============================================================================
+// Protocol
+// ============================================================================
+
+/// 엔드포인트로 보내는 승인 요청
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteApprovalRequest {
+    /// 요청 ID (로그/응답 대조용)
+    pub id: String,
+
+    /// 세션 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+
+    /// 권한 확인 프롬프트
+    pub prompt: ConfirmationPrompt,
+}
+
+/// 엔드포인트의 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteApprovalResponse {
+    /// 선택된 결정
+    pub decision: ConfirmOption,
+
+    /// 결정 사유 (감사 로그용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+// ============================================================================
+// RemoteDelegate
+// ============================================================================
+
+/// 외부 엔드포인트로 권한 결정을 위임하는 델리게이트
+///
+/// 응답을 받지 못하면 항상 `PermissionResponse::Deny`를 반환합니다.
+pub struct RemoteDelegate {
+    endpoint: RemoteEndpoint,
+    token: Option<String>,
+    timeout: Duration,
+    session_id: Option<String>,
+    client: reqwest::Client,
+}
+
+impl RemoteDelegate {
+    /// 새 델리게이트 생성
+    pub fn new(endpoint: RemoteEndpoint) -> Self {
+        Self {
+            endpoint,
+            token: None,
+            timeout: DEFAULT_REMOTE_TIMEOUT,
+            session_id: None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// 환경 변수에서 생성 (`FORGE_PERMISSION_ENDPOINT`가 없으면 None)
+    pub fn from_env() -> Result<Option<Self>> {
+        let Some(spec) = std::env::var(PERMISSION_ENDPOINT_ENV)
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+
+        let mut delegate = Self::new(RemoteEndpoint::parse(&spec)?);
+        if let Ok(token) = std::env::var(PERMISSION_TOKEN_ENV) {
+            delegate = delegate.with_token(token);
+        }
+        if let Ok(secs) = std::env::var(PERMISSION_TIMEOUT_ENV) {
+            let secs = secs.trim().parse::<u64>().map_err(|_| {
+                Error::Config(format!("Invalid {}: '{}'", PERMISSION_TIMEOUT_ENV, secs))
+            })?;
+            delegate = delegate.with_timeout(Duration::from_secs(secs));
+        }
+        Ok(Some(delegate))
+    }
+
+    /// HTTP 웹훅 Bearer 토큰 설정
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// 응답 대기 시간 설정
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 요청에 포함할 세션 ID 설정
+    pub fn with_session_id(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = Some(session_id.into());
+        self
+    }
+
+    /// 엔드포인트
+    pub fn endpoint(&self) -> &RemoteEndpoint {
+        &self.endpoint
+    }
+
+    /// 응답 대기 시간
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// 엔드포인트에 결정 요청
+    ///
+    /// 타임아웃, 전송 실패, 잘못된 응답은 `Err`입니다.
+    pub async fn decide(&self, prompt: &ConfirmationPrompt) -> Result<RemoteApprovalResponse> {
+        let request = RemoteApprovalRequest {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: self.session_id.clone(),
+            prompt: prompt.clone(),
+        };
+
+        tokio::time::timeout(self.timeout, self.send(&request))
+            .await
+            .map_err(|_| {
+                Error::Timeout(format!(
+                    "No permission decision from {} within {}s",
+                    self.endpoint,
+                    self.timeout.as_secs()
+                ))
+            })?
+    }
+
+    async fn send(&self, request: &RemoteApprovalRequest) -> Result<RemoteApprovalResponse> {
+        match &self.endpoint {
+            RemoteEndpoint::Http { url } => {
+                let mut builder = self.client.post(url).json(request);
+                if let Some(token) = &self.token {
+                    builder = builder.bearer_auth(token);
+                }
+                let response = builder
+                    .send()
+                    .await
+                    .and_then(|r| r.error_for_status())
+                    .map_err(|e| Error::Http(e.to_string()))?;
+                response
+                    .json()
+                    .await
+                    .map_err(|e| Error::Http(format!("Invalid permission response: {}", e)))
+            }
+            RemoteEndpoint::Unix(path) => send_unix(path, request).await,
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn send_unix(
+    path: &std::path::Path,
+    request: &RemoteApprovalRequest,
+) -> Result<RemoteApprovalResponse> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let mut stream = tokio::net::UnixStream::connect(path).await?;
+    let mut line = serde_json::to_string(request)?;
+    line.push('\n');
+    stream.write_all(line.as_bytes()).await?;
+
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response).await?;
+    if response.trim().is_empty() {
+        return Err(Error::Internal(format!(
+            "Permission socket {} closed without a decision",
+            path.display()
+        )));
+    }
+    Ok(serde_json::from_str(&response)?)
+}
+
+#[cfg(not(unix))]
+async fn send_unix(
+    path: &std::path::Path,
+    _request: &RemoteApprovalRequest,
+) -> Result<RemoteApprovalResponse> {
+    Err(Error::Config(format!(
+        "Unix socket endpoints are not supported on this platform: {}",
+        path.display()
+    )))
+}
+
+#[async_trait]
+impl PermissionDelegate for RemoteDelegate {
+    async fn request_permission(
+        &self,
+        tool_name: &str,
+        action: &PermissionAction,
+        description: &str,
+        risk_score: u8,
+    ) -> PermissionResponse {
+        self.confirm(&ConfirmationPrompt::for_action(
+            tool_name,
+            action,
+            description,
+            risk_score,
+        ))
+        .await
+    }
+
+    async fn confirm(&self, prompt: &ConfirmationPrompt) -> PermissionResponse {
+        match self.decide(prompt).await {
+            Ok(response) if prompt.options.contains(&response.decision) => {
+                info!(
+                    "Remote permission decision for {}: {:?} ({})",
+                    prompt.tool,
+                    response.decision,
+                    response.reason.as_deref().unwrap_or("no reason given")
+                );
+                response.decision.to_response()
+            }
+            Ok(response) => {
+                warn!(
+                    "Remote permission decision {:?} was not offered for {}; denying",
+                    response.decision, prompt.tool
+                );
+                PermissionResponse::Deny
+            }
+            Err(e) => {
+                warn!(
+                    "Remote permission request for {} failed, denying: {}",
+                    prompt.tool, e
+                );
+                PermissionResponse::Deny
+            }
+        }
+    }
+
+    fn notify(&self, message: &str) {
+        info!("{}", message);
+    }
+
+    fn show_error(&self, error: &str) {
+        warn!("{}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompt() -> ConfirmationPrompt {
+        ConfirmationPrompt::for_action(
+            "bash",
+            &PermissionAction::Execute {
+                command: "cargo publish".to_string(),
+            },
+            "Publish crate",
+            7,
+        )
+    }
+
+    #[test]
+    fn test_endpoint_parse() {
+        assert_eq!(
+            RemoteEndpoint::parse("https://approvals.example.com/forge").unwrap(),
+            RemoteEndpoint::Http {
+                url: "https://approvals.example.com/forge".to_string()
+            }
+        );
+        assert_eq!(
+            RemoteEndpoint::parse("unix:/run/forge.sock").unwrap(),
+            RemoteEndpoint::Unix(PathBuf::from("/run/forge.sock"))
+        );
+        assert_eq!(
+            RemoteEndpoint::parse("unix:///run/forge.sock").unwrap(),
+            RemoteEndpoint::Unix(PathBuf::from("/run/forge.sock"))
+        );
+        assert!(RemoteEndpoint::parse("ftp://example.com").is_err());
+        assert!(RemoteEndpoint::parse("unix:").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_http_decision_and_default_deny() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/approve", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let bodies = [
+                r#"{"decision":"allow_session","reason":"ok"}"#,
+                r#"{"decision":"maybe"}"#,
+            ];
+            for body in bodies {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 16 * 1024];
+                let n = socket.read(&mut buf).await.unwrap();
+                assert!(String::from_utf8_lossy(&buf[..n]).contains("Bearer secret"));
+                let reply = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                socket.write_all(reply.as_bytes()).await.unwrap();
+            }
+        });
+
+        let delegate = RemoteDelegate::new(RemoteEndpoint::parse(&url).unwrap())
+            .with_token("secret")
+            .with_timeout(Duration::from_secs(5));
+        assert_eq!(
+            delegate.confirm(&prompt()).await,
+            PermissionResponse::AllowSession
+        );
+        // 알 수 없는 결정은 거부
+        assert_eq!(delegate.confirm(&prompt()).await, PermissionResponse::Deny);
+
+        // 연결 실패도 거부
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        drop(listener);
+        let unreachable = RemoteDelegate::new(RemoteEndpoint::parse(&url).unwrap());
+        assert_eq!(
+            unreachable.confirm(&prompt()).await,
+            PermissionResponse::Deny
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_unix_socket_and_timeout() {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+        let path =
+            std::env::temp_dir().join(format!("forge-approvals-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = tokio::net::UnixListener::bind(&path).unwrap();
+        tokio::spawn(async move {
+            // 두 요청에는 allow_permanent로 응답하고, 세 번째부터는 응답하지 않음 (타임아웃)
+            for respond in [true, true, false] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).await.unwrap();
+                let request: RemoteApprovalRequest = serde_json::from_str(&line).unwrap();
+                assert_eq!(request.prompt.tool, "bash");
+                if respond {
+                    reader
+                        .get_mut()
+                        .write_all(b"{\"decision\":\"allow_permanent\"}\n")
+                        .await
+                        .unwrap();
+                } else {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+            }
+        });
+
+        let delegate = RemoteDelegate::new(RemoteEndpoint::Unix(path.clone()))
+            .with_timeout(Duration::from_millis(300));
+        assert_eq!(
+            delegate.confirm(&prompt()).await,
+            PermissionResponse::AllowPermanent
+        );
+
+        let restricted = prompt().with_options([ConfirmOption::AllowOnce, ConfirmOption::Deny]);
+        assert_eq!(
+            delegate.confirm(&restricted).await,
+            PermissionResponse::Deny
+        );
+
+        assert_eq!(delegate.confirm(&prompt()).await, PermissionResponse::Deny);
+        assert!(delegate.decide(&prompt()).await.is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+}
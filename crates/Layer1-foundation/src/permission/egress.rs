@@ -0,0 +1,177 @@
+//! Network Egress - 네트워크 요청의 도메인 허용/차단
+//!
+//! `network` 권한 카테고리의 공통 HTTP 계층입니다. HTTP 도구, MCP SSE 서버,
+//! LLM 프로바이더는 `egress_client()`로 클라이언트를 만들고, 프로세스 전역
+//! `EgressPolicy`(설정의 `security.network`)가 모든 연결을 검사합니다.
+//!
+//! - 호스트 이름은 이름 해석(DNS) 단계에서 검사하므로 리다이렉트 대상도 걸러집니다
+//! - IP 리터럴과 프록시를 거치는 요청은 `check_egress`와 리다이렉트 정책이 검사합니다
+//!
+//! ```ignore
+//! set_egress_policy(EgressPolicy::new(vec!["*.github.com".into()], vec![]));
+//! check_egress("https://api.github.com/repos")?;    // Ok
+//! check_egress("https://example.com").unwrap_err(); // PermissionDenied
+//! let client = egress_client().build()?;
+//! ```
+
+use super::settings::{domain_matches, host_of};
+use crate::{Error, Result};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::sync::{Arc, RwLock};
+
+/// 기본 리다이렉트 최대 횟수
+const DEFAULT_MAX_REDIRECTS: usize = 10;
+
+/// 프로세스 전역 정책
+static EGRESS_POLICY: RwLock<EgressPolicy> = RwLock::new(EgressPolicy::unrestricted());
+
+/// 네트워크 요청의 도메인 허용/차단 규칙
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EgressPolicy {
+    /// 허용 도메인 패턴 (비어 있으면 차단 목록 외 모두 허용)
+    pub allowed_domains: Vec<String>,
+
+    /// 차단 도메인 패턴 (허용보다 우선)
+    pub blocked_domains: Vec<String>,
+}
+
+impl EgressPolicy {
+    /// 제한 없는 정책
+    pub const fn unrestricted() -> Self {
+        Self {
+            allowed_domains: Vec::new(),
+            blocked_domains: Vec::new(),
+        }
+    }
+
+    /// 허용/차단 도메인 패턴으로 생성 (`example.com`, `*.example.com`, `*`)
+    pub fn new(allowed_domains: Vec<String>, blocked_domains: Vec<String>) -> Self {
+        Self {
+            allowed_domains,
+            blocked_domains,
+        }
+    }
+
+    /// 제한이 없는지
+    pub fn is_unrestricted(&self) -> bool {
+        self.allowed_domains.is_empty() && self.blocked_domains.is_empty()
+    }
+
+    /// 호스트(또는 URL)에 연결할 수 있는지
+    pub fn allows(&self, host: &str) -> bool {
+        if self.blocked_domains.iter().any(|p| domain_matches(p, host)) {
+            return false;
+        }
+        self.allowed_domains.is_empty()
+            || self.allowed_domains.iter().any(|p| domain_matches(p, host))
+    }
+
+    /// 연결할 수 없으면 `PermissionDenied`
+    pub fn check(&self, url: &str) -> Result<()> {
+        if self.allows(url) {
+            return Ok(());
+        }
+        Err(Error::PermissionDenied(format!(
+            "Network access to '{}' is blocked by the egress policy (security.network)",
+            host_of(url)
+        )))
+    }
+}
+
+/// 전역 정책 설정 (이후의 모든 요청에 적용)
+pub fn set_egress_policy(policy: EgressPolicy) {
+    *EGRESS_POLICY.write().unwrap_or_else(|e| e.into_inner()) = policy;
+}
+
+/// 현재 전역 정책
+pub fn egress_policy() -> EgressPolicy {
+    EGRESS_POLICY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// 전역 정책으로 URL(또는 호스트) 검사
+pub fn check_egress(url: &str) -> Result<()> {
+    EGRESS_POLICY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .check(url)
+}
+
+/// 전역 정책을 따르는 HTTP 클라이언트 빌더
+///
+/// 리다이렉트는 최대 10번이며, 바꾸려면 `.redirect(egress_redirect_policy(n))`를 씁니다.
+pub fn egress_client() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .dns_resolver(Arc::new(EgressResolver))
+        .redirect(egress_redirect_policy(DEFAULT_MAX_REDIRECTS))
+}
+
+/// 최대 `max`번 따라가며 차단된 호스트로는 이동하지 않는 리다이렉트 정책
+pub fn egress_redirect_policy(max: usize) -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(move |attempt| {
+        if attempt.previous().len() > max {
+            return attempt.error(format!("Too many redirects (max {})", max));
+        }
+        match check_egress(attempt.url().as_str()) {
+            Ok(()) => attempt.follow(),
+            Err(e) => attempt.error(e),
+        }
+    })
+}
+
+/// 이름 해석 전에 전역 정책을 검사하는 DNS 리졸버
+struct EgressResolver;
+
+impl Resolve for EgressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            check_egress(&host)?;
+            let addrs = tokio::net::lookup_host((host.as_str(), 0)).await?;
+            Ok(Box::new(addrs.collect::<Vec<_>>().into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy() {
+        assert!(EgressPolicy::default().allows("https://example.com"));
+
+        let policy = EgressPolicy::new(
+            vec!["*.github.com".to_string(), "localhost".to_string()],
+            vec!["gist.github.com".to_string()],
+        );
+        assert!(policy.allows("https://api.github.com/repos"));
+        assert!(policy.allows("http://localhost:11434/api/tags"));
+        assert!(!policy.allows("https://gist.github.com/x"));
+        assert!(!policy.allows("https://example.com"));
+
+        let err = policy.check("https://example.com/a?b").unwrap_err();
+        assert!(err.to_string().contains("'example.com'"));
+
+        let blocklist = EgressPolicy::new(vec![], vec!["*.tracker.test".to_string()]);
+        assert!(blocklist.allows("https://example.com"));
+        assert!(!blocklist.allows("https://a.tracker.test"));
+    }
+
+    #[tokio::test]
+    async fn test_client_enforces_global_policy() {
+        // 차단 목록만 추가 (같은 바이너리의 다른 테스트는 영향 없음)
+        set_egress_policy(EgressPolicy::new(vec![], vec!["blocked.test".to_string()]));
+
+        let client = egress_client().build().unwrap();
+        let err = client.get("http://blocked.test/").send().await.unwrap_err();
+        assert!(format!("{:?}", err).contains("egress policy"));
+        assert!(check_egress("http://blocked.test/").is_err());
+        assert!(check_egress("http://127.0.0.1/").is_ok());
+
+        set_egress_policy(EgressPolicy::unrestricted());
+        assert!(egress_policy().is_unrestricted());
+    }
+}
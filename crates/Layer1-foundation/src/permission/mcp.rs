@@ -0,0 +1,185 @@
+//! MCP 서버별 권한 범위
+//!
+//! MCP 도구 호출은 `PermissionAction::Custom { name: "mcp.<server>", details: "<tool>" }`
+//! 액션을 요구하고, 서버가 연결될 때 다음 권한 정의가 자동 등록됩니다.
+//!
+//! - `mcp.<server>.*`: 서버 전체 (위험도 6)
+//! - `mcp.<server>.<tool>`: 도구별 (이름으로 추정: 조회 2, 삭제 8, 그 외 6)
+//!
+//! 허용/거부 설정의 `tool`에 범위 이름을 쓰면 서버 단위로 적용됩니다.
+//! 한 서버만 허용해도 다른 서버는 여전히 확인을 거칩니다.
+//!
+//! ```json
+//! { "tool": "mcp.github.*", "actionType": { "custom": "mcp.github" } }
+//! { "tool": "mcp.notion.search", "actionType": { "custom": "mcp.notion" } }
+//! ```
+
+use super::service::PermissionAction;
+use super::types::{categories, registry, PermissionDef};
+
+/// MCP 서버 전체 권한의 기본 위험도
+pub const MCP_SERVER_RISK: u8 = 6;
+
+/// 조회성 도구 이름 접두사
+const READ_PREFIXES: &[&str] = &[
+    "get", "list", "read", "search", "fetch", "query", "find", "describe", "view", "show",
+];
+
+/// 파괴적 도구 이름 접두사
+const DESTRUCTIVE_PREFIXES: &[&str] = &["delete", "remove", "drop", "destroy", "purge", "reset"];
+
+/// MCP 도구 호출에 필요한 액션
+pub fn mcp_action(server: &str, tool: &str) -> PermissionAction {
+    PermissionAction::Custom {
+        name: format!("{}.{}", categories::MCP, server),
+        details: tool.to_string(),
+    }
+}
+
+/// 도구별 권한 이름 (`mcp.<server>.<tool>`)
+pub fn mcp_permission_name(server: &str, tool: &str) -> String {
+    format!("{}.{}.{}", categories::MCP, server, tool)
+}
+
+/// 서버 전체 권한 이름 (`mcp.<server>.*`)
+pub fn mcp_server_scope(server: &str) -> String {
+    format!("{}.{}.*", categories::MCP, server)
+}
+
+/// 도구 이름으로 위험도 추정
+pub fn mcp_tool_risk(tool: &str) -> u8 {
+    let verb = tool
+        .split(['_', '-', '.'])
+        .next()
+        .unwrap_or(tool)
+        .to_ascii_lowercase();
+
+    if READ_PREFIXES.contains(&verb.as_str()) {
+        2
+    } else if DESTRUCTIVE_PREFIXES.contains(&verb.as_str()) {
+        8
+    } else {
+        MCP_SERVER_RISK
+    }
+}
+
+/// 서버의 권한 정의 (서버 전체 + 도구별)
+///
+/// `tools`: (도구 이름, 설명)
+pub fn mcp_server_permissions<'a>(
+    server: &str,
+    tools: impl IntoIterator<Item = (&'a str, Option<&'a str>)>,
+) -> Vec<PermissionDef> {
+    let mut defs = vec![
+        PermissionDef::new(mcp_server_scope(server), categories::MCP)
+            .risk_level(MCP_SERVER_RISK)
+            .description(format!("Use every tool of MCP server {}", server)),
+    ];
+
+    defs.extend(tools.into_iter().map(|(tool, description)| {
+        let description = match description {
+            Some(desc) if !desc.trim().is_empty() => format!("{} ({})", desc.trim(), server),
+            _ => format!("Call MCP tool {} from {}", tool, server),
+        };
+        PermissionDef::new(mcp_permission_name(server, tool), categories::MCP)
+            .risk_level(mcp_tool_risk(tool))
+            .description(description)
+    }));
+    defs
+}
+
+/// 서버 연결 시 권한 정의를 전역 레지스트리에 등록 (이전 정의는 교체)
+pub fn register_mcp_server<'a>(
+    server: &str,
+    tools: impl IntoIterator<Item = (&'a str, Option<&'a str>)>,
+) -> usize {
+    unregister_mcp_server(server);
+    let defs = mcp_server_permissions(server, tools);
+    let count = defs.len();
+    registry().register_all(defs);
+    count
+}
+
+/// 서버 연결 해제 시 권한 정의 제거
+pub fn unregister_mcp_server(server: &str) -> usize {
+    registry().unregister_prefix(&format!("{}.{}.", categories::MCP, server))
+}
+
+/// 허용/거부 항목의 `tool` 값이 MCP 범위로서 `action`을 포함하는지
+///
+/// - `mcp.*`: 모든 MCP 서버
+/// - `mcp.<server>.*`: 해당 서버의 모든 도구
+/// - `mcp.<server>.<tool>`: 해당 도구
+pub fn mcp_scope_covers(scope: &str, action: &PermissionAction) -> bool {
+    let PermissionAction::Custom { name, details } = action else {
+        return false;
+    };
+    let Some(server) = name.strip_prefix("mcp.") else {
+        return false;
+    };
+
+    match scope.strip_prefix("mcp.") {
+        Some("*") => true,
+        Some(rest) => match rest.strip_prefix(server).and_then(|r| r.strip_prefix('.')) {
+            Some(tool) => tool == "*" || tool == details,
+            None => false,
+        },
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mcp_scope_covers() {
+        let action = mcp_action("github", "create_issue");
+
+        assert!(mcp_scope_covers("mcp.*", &action));
+        assert!(mcp_scope_covers("mcp.github.*", &action));
+        assert!(mcp_scope_covers("mcp.github.create_issue", &action));
+        assert!(!mcp_scope_covers("mcp.github.list_issues", &action));
+        assert!(!mcp_scope_covers("mcp.notion.*", &action));
+        assert!(!mcp_scope_covers("mcp.git.*", &action));
+        assert!(!mcp_scope_covers("bash", &action));
+        assert!(!mcp_scope_covers(
+            "mcp.*",
+            &PermissionAction::Execute {
+                command: "ls".to_string()
+            }
+        ));
+    }
+
+    #[test]
+    fn test_register_mcp_server() {
+        let count = register_mcp_server(
+            "notion-test",
+            [("read", Some("Read a page")), ("delete_page", None)],
+        );
+        assert_eq!(count, 3);
+
+        let reg = registry();
+        assert_eq!(
+            reg.get("mcp.notion-test.*").unwrap().risk_level,
+            MCP_SERVER_RISK
+        );
+        assert_eq!(reg.get("mcp.notion-test.read").unwrap().risk_level, 2);
+        assert!(!reg
+            .get("mcp.notion-test.read")
+            .unwrap()
+            .needs_confirmation());
+        assert_eq!(
+            reg.get("mcp.notion-test.delete_page").unwrap().risk_level,
+            8
+        );
+
+        // 재연결 시 도구 목록 교체
+        register_mcp_server("notion-test", [("search", None)]);
+        assert!(!reg.exists("mcp.notion-test.read"));
+        assert!(reg.exists("mcp.notion-test.search"));
+
+        assert_eq!(unregister_mcp_server("notion-test"), 2);
+        assert!(!reg.exists("mcp.notion-test.*"));
+    }
+}
@@ -5,6 +5,11 @@
 //! - `settings`: JSON 설정 저장/로드 (PermissionSettings)
 //! - `security`: 위험 명령어/민감 경로 분석 (CommandAnalyzer, PathAnalyzer)
 //! - `oversight`: 다중 에이전트 보안 감독 (OversightAgent)
+//! - `prompt`: 구조화된 권한 확인 프롬프트 (ConfirmationPrompt)
+//! - `delegate`: 외부 엔드포인트(웹훅/Unix 소켓) 권한 승인 위임 (RemoteDelegate)
+//! - `egress`: 네트워크 요청의 도메인 허용/차단 (EgressPolicy, egress_client)
+//! - `bundle`: 서명된 권한 프리셋 내보내기/가져오기 (PermissionBundle)
+//! - `mcp`: MCP 서버별 권한 범위 (`mcp.<server>.*`, register_mcp_server)
 //!
 //! ## 사용 예시
 //!
@@ -32,7 +37,12 @@
 //! }
 //! ```
 
+mod bundle;
+mod delegate;
+mod egress;
+mod mcp;
 pub mod oversight;
+mod prompt;
 pub mod security;
 mod service;
 mod settings;
@@ -40,12 +50,45 @@ mod types;
 
 // Service (런타임 권한 관리)
 pub use service::{
-    Permission, PermissionAction, PermissionScope, PermissionService, PermissionStatus,
+    GrantCoverage, Permission, PermissionAction, PermissionScope, PermissionService,
+    PermissionSimulation, PermissionStatus, RequiredPermission,
+};
+
+// Bundle (서명된 권한 프리셋)
+pub use bundle::{
+    BundleSignature, BundleSigningKey, PermissionBundle, TrustedBundleKeys, BUNDLE_FORMAT,
+    BUNDLE_SIGNING_KEY_FILE, BUNDLE_VERSION, TRUSTED_BUNDLE_KEYS_FILE,
+};
+
+// Delegate (원격 권한 승인)
+pub use delegate::{
+    RemoteApprovalRequest, RemoteApprovalResponse, RemoteDelegate, RemoteEndpoint,
+    DEFAULT_REMOTE_TIMEOUT, PERMISSION_ENDPOINT_ENV, PERMISSION_TIMEOUT_ENV, PERMISSION_TOKEN_ENV,
+};
+
+// Egress (네트워크 도메인 허용/차단)
+pub use egress::{
+    check_egress, egress_client, egress_policy, egress_redirect_policy, set_egress_policy,
+    EgressPolicy,
+};
+
+// MCP (서버별 권한 범위)
+pub use mcp::{
+    mcp_action, mcp_permission_name, mcp_scope_covers, mcp_server_permissions, mcp_server_scope,
+    mcp_tool_risk, register_mcp_server, unregister_mcp_server, MCP_SERVER_RISK,
+};
+
+// Prompt (권한 확인 프롬프트)
+pub use prompt::{
+    ConfirmOption, ConfirmationPrompt, PromptActionKind, PromptCatalog, PromptMessage,
+    PromptPreview, PromptRisk,
 };
 
 // Settings (JSON 저장/로드)
 pub use settings::{
-    PermissionActionType, PermissionDeny, PermissionGrant, PermissionSettings, PERMISSIONS_FILE,
+    domain_matches, ManagedPolicy, PermissionActionType, PermissionDeny, PermissionGrant,
+    PermissionPaths, PermissionSettings, LOCAL_PERMISSIONS_FILE, MANAGED_PERMISSIONS_FILE,
+    PERMISSIONS_FILE,
 };
 
 // Types (동적 권한 등록)
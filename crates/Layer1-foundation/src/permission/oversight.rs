@@ -15,6 +15,23 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::Instant;
 
+/// ForgeCode 내장 도구 이름 → 위험 패턴에서 쓰는 분류 이름
+const TOOL_ALIASES: [(&str, &str); 4] = [
+    ("web_fetch", "fetch"),
+    ("web_search", "fetch"),
+    ("http_request", "fetch"),
+    ("download", "curl"),
+];
+
+/// 분류 이름 (별칭이 없으면 그대로)
+fn canonical_tool(name: &str) -> &str {
+    TOOL_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map(|(_, canonical)| *canonical)
+        .unwrap_or(name)
+}
+
 /// 소스 태그 - 명령/데이터의 출처 구분
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SourceTag {
@@ -253,7 +270,9 @@ impl OversightAgent {
         // 2. 외부 소스에서 코드 실행 검사
         if !self.config.allow_external_code_execution
             && request.source == SourceTag::External
-            && self.code_execution_tools.contains(&request.tool_name)
+            && self
+                .code_execution_tools
+                .contains(canonical_tool(&request.tool_name))
         {
             return ValidationResult::Deny {
                 reason: "Code execution from external source is not allowed".to_string(),
@@ -295,7 +314,11 @@ impl OversightAgent {
         }
 
         // 5. 낮은 신뢰 소스에서 위험 도구 사용
-        if request.source.trust_level() < 5 && self.dangerous_tools.contains(&request.tool_name) {
+        if request.source.trust_level() < 5
+            && self
+                .dangerous_tools
+                .contains(canonical_tool(&request.tool_name))
+        {
             if self.config.auto_sandbox {
                 return ValidationResult::Sandbox {
                     restrictions: vec![
@@ -320,6 +343,11 @@ impl OversightAgent {
         ValidationResult::Allow
     }
 
+    /// 검증 없이 호출만 기록 (신뢰할 수 있는 호출도 이후 패턴 탐지에 쓰이도록)
+    pub fn observe_tool_call(&mut self, request: &ToolCallRequest) {
+        self.record_call(request.clone());
+    }
+
     /// 위험 패턴 탐지
     fn detect_risk_pattern(&self, request: &ToolCallRequest) -> Option<&RiskPattern> {
         // 최근 호출 + 현재 요청으로 패턴 검사
@@ -327,9 +355,9 @@ impl OversightAgent {
             .recent_calls
             .iter()
             .filter(|c| c.session_id == request.session_id)
-            .map(|c| c.tool_name.as_str())
+            .map(|c| canonical_tool(&c.tool_name))
             .collect();
-        recent_tools.push(&request.tool_name);
+        recent_tools.push(canonical_tool(&request.tool_name));
 
         for pattern in &self.risk_patterns {
             if self.matches_pattern(&recent_tools, &pattern.tool_sequence) {
@@ -447,7 +475,7 @@ impl SourceAnalyzer {
     }
 
     /// 의심스러운 패턴 검사 (Control-flow hijacking 시도)
-    fn contains_suspicious_patterns(content: &str) -> bool {
+    pub fn contains_suspicious_patterns(content: &str) -> bool {
         let suspicious_patterns = [
             // 가짜 에러 메시지로 실행 유도
             "you must run",
@@ -571,4 +599,26 @@ mod tests {
             "This is a normal file content with no suspicious patterns"
         ));
     }
+
+    #[test]
+    fn test_builtin_tool_aliases() {
+        let mut agent = OversightAgent::new();
+        let call = |tool: &str| ToolCallRequest {
+            tool_name: tool.to_string(),
+            arguments: serde_json::json!({}),
+            source: SourceTag::System,
+            session_id: "test".to_string(),
+            timestamp: Instant::now(),
+            call_chain: vec![],
+        };
+
+        // http_request → bash는 fetch_to_execute 패턴
+        agent.observe_tool_call(&call("http_request"));
+        let result = agent.validate_tool_call(&call("bash"));
+        assert!(
+            matches!(result, ValidationResult::Deny { reason } if reason.contains("fetch_to_execute"))
+        );
+        assert_eq!(canonical_tool("download"), "curl");
+        assert_eq!(canonical_tool("grep"), "grep");
+    }
 }
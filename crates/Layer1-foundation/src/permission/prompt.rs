@@ -0,0 +1,775 @@
+//! Permission Prompt - 구조화된 권한 확인 프롬프트
+//!
+//! 권한 요청을 미리 포맷된 문자열 대신 구조화된 객체로 표현합니다.
+//! TUI, CLI, 외부 프론트엔드(IDE 등)는 같은 `ConfirmationPrompt`를 받아 각자의 방식으로 렌더링합니다.
+//!
+//! - 작업(action)과 대상(target), 위험 요인, 미리보기(명령어/diff), 대안, 선택지
+//! - 모든 문구는 메시지 키(`permission.*`)와 인자를 가지므로 `PromptCatalog`로 번역 가능
+//! - serde 직렬화 지원 (JSON으로 외부 프론트엔드에 전달)
+//!
+//! ```ignore
+//! let prompt = ConfirmationPrompt::for_action("edit", &action, "Edit file: src/main.rs", 5)
+//!     .with_preview(PromptPreview::diff("src/main.rs", diff));
+//! println!("{}", prompt.display());
+//!
+//! let ko = PromptCatalog::from_json(r#"{ "permission.option.deny": "거부" }"#)?;
+//! println!("{}", prompt.render(&ko));
+//! ```
+
+use super::security::{analyzer, path_analyzer};
+use super::service::{PermissionAction, PermissionScope};
+use crate::core::PermissionResponse;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+// ============================================================================
+// PromptMessage / PromptCatalog
+// ============================================================================
+
+/// 번역 가능한 문구
+///
+/// `key`로 번역 템플릿을 찾고, 템플릿의 `{name}`을 `args`로 치환합니다.
+/// 번역이 없으면 `text`(기본 영어 문구)를 사용합니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptMessage {
+    /// 메시지 키 (예: `permission.factor.sensitive_path`)
+    pub key: String,
+
+    /// 템플릿 인자
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub args: BTreeMap<String, String>,
+
+    /// 기본 문구
+    pub text: String,
+}
+
+impl PromptMessage {
+    /// 새 문구 생성
+    pub fn new(key: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            args: BTreeMap::new(),
+            text: text.into(),
+        }
+    }
+
+    /// 인자 추가
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.args.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// 메시지 키 → 번역 템플릿
+///
+/// 비어 있으면 모든 문구가 기본 영어로 렌더링됩니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PromptCatalog {
+    messages: HashMap<String, String>,
+}
+
+impl PromptCatalog {
+    /// 빈 카탈로그 (기본 영어)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `{ "key": "template" }` 형식의 JSON에서 로드
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// 번역 추가
+    pub fn with(mut self, key: impl Into<String>, template: impl Into<String>) -> Self {
+        self.messages.insert(key.into(), template.into());
+        self
+    }
+
+    /// 고정 문구 (인자 없음)
+    pub fn text(&self, key: &str, default: &str) -> String {
+        self.messages
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| default.to_string())
+    }
+
+    /// 문구 렌더링
+    pub fn message(&self, message: &PromptMessage) -> String {
+        match self.messages.get(&message.key) {
+            Some(template) => message
+                .args
+                .iter()
+                .fold(template.clone(), |text, (name, value)| {
+                    text.replace(&format!("{{{}}}", name), value)
+                }),
+            None => message.text.clone(),
+        }
+    }
+}
+
+// ============================================================================
+// 분류
+// ============================================================================
+
+/// 요청된 작업 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptActionKind {
+    Execute,
+    FileWrite,
+    FileDelete,
+    FileReadSensitive,
+    Network,
+    Custom,
+}
+
+impl PromptActionKind {
+    /// 권한 액션에서 종류 결정
+    pub fn of(action: &PermissionAction) -> Self {
+        match action {
+            PermissionAction::Execute { .. } => Self::Execute,
+            PermissionAction::FileWrite { .. } => Self::FileWrite,
+            PermissionAction::FileDelete { .. } => Self::FileDelete,
+            PermissionAction::FileReadSensitive { .. } => Self::FileReadSensitive,
+            PermissionAction::Network { .. } => Self::Network,
+            PermissionAction::Custom { .. } => Self::Custom,
+        }
+    }
+
+    /// 메시지 키
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Execute => "permission.action.execute",
+            Self::FileWrite => "permission.action.file_write",
+            Self::FileDelete => "permission.action.file_delete",
+            Self::FileReadSensitive => "permission.action.file_read_sensitive",
+            Self::Network => "permission.action.network",
+            Self::Custom => "permission.action.custom",
+        }
+    }
+
+    /// 기본 라벨
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Execute => "Run command",
+            Self::FileWrite => "Write file",
+            Self::FileDelete => "Delete file",
+            Self::FileReadSensitive => "Read sensitive file",
+            Self::Network => "Network request",
+            Self::Custom => "Use tool",
+        }
+    }
+}
+
+/// 위험 수준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptRisk {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl PromptRisk {
+    /// 위험 점수(0-10)에서 수준 결정
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            0..=3 => Self::Low,
+            4..=6 => Self::Medium,
+            7..=8 => Self::High,
+            _ => Self::Critical,
+        }
+    }
+
+    /// 메시지 키
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Low => "permission.risk.low",
+            Self::Medium => "permission.risk.medium",
+            Self::High => "permission.risk.high",
+            Self::Critical => "permission.risk.critical",
+        }
+    }
+
+    /// 기본 라벨
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Low => "Low Risk",
+            Self::Medium => "Medium Risk",
+            Self::High => "High Risk",
+            Self::Critical => "DANGEROUS",
+        }
+    }
+
+    /// 위험 표시 이모지
+    pub fn indicator(&self) -> &'static str {
+        match self {
+            Self::Low => "🟢",
+            Self::Medium => "🟡",
+            Self::High => "🟠",
+            Self::Critical => "🔴",
+        }
+    }
+}
+
+/// 작업 미리보기
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PromptPreview {
+    /// 실행될 명령어
+    Command { command: String },
+    /// 파일 변경 (unified diff)
+    Diff { path: String, diff: String },
+}
+
+impl PromptPreview {
+    /// 명령어 미리보기
+    pub fn command(command: impl Into<String>) -> Self {
+        Self::Command {
+            command: command.into(),
+        }
+    }
+
+    /// diff 미리보기
+    pub fn diff(path: impl Into<String>, diff: impl Into<String>) -> Self {
+        Self::Diff {
+            path: path.into(),
+            diff: diff.into(),
+        }
+    }
+
+    /// 미리보기 본문
+    pub fn body(&self) -> &str {
+        match self {
+            Self::Command { command } => command,
+            Self::Diff { diff, .. } => diff,
+        }
+    }
+}
+
+// ============================================================================
+// ConfirmOption
+// ============================================================================
+
+/// 사용자 선택지
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmOption {
+    AllowOnce,
+    AllowSession,
+    AllowPermanent,
+    Deny,
+    DenyPermanent,
+}
+
+impl ConfirmOption {
+    /// 모든 선택지
+    pub const ALL: [ConfirmOption; 5] = [
+        Self::AllowOnce,
+        Self::AllowSession,
+        Self::AllowPermanent,
+        Self::Deny,
+        Self::DenyPermanent,
+    ];
+
+    /// 사용자 입력 파싱
+    pub fn from_input(input: &str) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "y" | "yes" | "1" => Some(Self::AllowOnce),
+            "s" | "session" | "2" => Some(Self::AllowSession),
+            "a" | "always" | "permanent" | "3" => Some(Self::AllowPermanent),
+            "n" | "no" | "deny" | "0" => Some(Self::Deny),
+            "x" | "never" | "4" => Some(Self::DenyPermanent),
+            _ => None,
+        }
+    }
+
+    /// 텍스트 입력 단축키 (`from_input`과 일치)
+    pub fn shortcut(&self) -> char {
+        match self {
+            Self::AllowOnce => 'y',
+            Self::AllowSession => 's',
+            Self::AllowPermanent => 'a',
+            Self::Deny => 'n',
+            Self::DenyPermanent => 'x',
+        }
+    }
+
+    /// 메시지 키
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::AllowOnce => "permission.option.allow_once",
+            Self::AllowSession => "permission.option.allow_session",
+            Self::AllowPermanent => "permission.option.allow_permanent",
+            Self::Deny => "permission.option.deny",
+            Self::DenyPermanent => "permission.option.deny_permanent",
+        }
+    }
+
+    /// 기본 라벨
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::AllowOnce => "Allow once",
+            Self::AllowSession => "Allow for session",
+            Self::AllowPermanent => "Always allow",
+            Self::Deny => "Deny",
+            Self::DenyPermanent => "Always deny",
+        }
+    }
+
+    /// 허용 범위 (거부는 None)
+    pub fn to_scope(self) -> Option<PermissionScope> {
+        match self {
+            Self::AllowOnce => Some(PermissionScope::Once),
+            Self::AllowSession => Some(PermissionScope::Session),
+            Self::AllowPermanent => Some(PermissionScope::Permanent),
+            Self::Deny | Self::DenyPermanent => None,
+        }
+    }
+
+    /// 델리게이트 응답으로 변환
+    pub fn to_response(self) -> PermissionResponse {
+        match self {
+            Self::AllowOnce => PermissionResponse::AllowOnce,
+            Self::AllowSession => PermissionResponse::AllowSession,
+            Self::AllowPermanent => PermissionResponse::AllowPermanent,
+            Self::Deny => PermissionResponse::Deny,
+            Self::DenyPermanent => PermissionResponse::DenyPermanent,
+        }
+    }
+}
+
+// ============================================================================
+// ConfirmationPrompt
+// ============================================================================
+
+/// 구조화된 권한 확인 프롬프트
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfirmationPrompt {
+    /// 권한을 요청한 도구
+    pub tool: String,
+
+    /// 요청된 권한
+    pub action: PermissionAction,
+
+    /// 작업 종류
+    pub kind: PromptActionKind,
+
+    /// 작업 대상 (명령어, 파일 경로, URL 등)
+    pub target: String,
+
+    /// 도구가 제공한 설명
+    pub description: String,
+
+    /// 작업이 실제로 무엇을 하는지에 대한 설명 (예: LLM 요약)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+
+    /// 위험 수준
+    pub risk: PromptRisk,
+
+    /// 위험 점수 (0-10)
+    pub risk_score: u8,
+
+    /// 위험 요인
+    pub risk_factors: Vec<PromptMessage>,
+
+    /// 미리보기 (명령어/diff)
+    pub preview: Option<PromptPreview>,
+
+    /// 더 안전한 대안
+    pub alternatives: Vec<PromptMessage>,
+
+    /// 선택지
+    pub options: Vec<ConfirmOption>,
+}
+
+impl ConfirmationPrompt {
+    /// 권한 액션에서 프롬프트 생성
+    ///
+    /// 명령어/경로 분석기로 위험 요인과 대안을 채우고, 명령어는 미리보기로 표시합니다.
+    pub fn for_action(
+        tool: &str,
+        action: &PermissionAction,
+        description: &str,
+        risk_score: u8,
+    ) -> Self {
+        let kind = PromptActionKind::of(action);
+        let target = match action {
+            PermissionAction::Execute { command } => command.clone(),
+            PermissionAction::FileWrite { path }
+            | PermissionAction::FileDelete { path }
+            | PermissionAction::FileReadSensitive { path } => path.clone(),
+            PermissionAction::Network { url } => url.clone(),
+            PermissionAction::Custom { name, details } => format!("{}: {}", name, details),
+        };
+
+        let mut prompt = Self {
+            tool: tool.to_string(),
+            action: action.clone(),
+            kind,
+            target,
+            description: description.to_string(),
+            explanation: None,
+            risk: PromptRisk::from_score(risk_score),
+            risk_score,
+            risk_factors: Vec::new(),
+            preview: None,
+            alternatives: Vec::new(),
+            options: ConfirmOption::ALL.to_vec(),
+        };
+
+        match action {
+            PermissionAction::Execute { command } => {
+                if let Some(reason) = analyzer().analyze(command).reason {
+                    prompt.risk_factors.push(
+                        PromptMessage::new("permission.factor.command", reason.clone())
+                            .with_arg("reason", reason),
+                    );
+                }
+                prompt.alternatives = command_alternatives(command);
+                prompt.preview = Some(PromptPreview::command(command));
+            }
+            PermissionAction::FileDelete { path } => {
+                prompt.risk_factors.push(PromptMessage::new(
+                    "permission.factor.irreversible",
+                    "Deleted files cannot be recovered",
+                ));
+                prompt.push_sensitive_path(path);
+            }
+            PermissionAction::FileWrite { path } | PermissionAction::FileReadSensitive { path } => {
+                prompt.push_sensitive_path(path);
+            }
+            PermissionAction::Network { url } => {
+                let host = url_host(url);
+                prompt.risk_factors.push(
+                    PromptMessage::new(
+                        "permission.factor.network",
+                        format!("Contacts an external host: {}", host),
+                    )
+                    .with_arg("host", host),
+                );
+            }
+            PermissionAction::Custom { .. } => {}
+        }
+
+        prompt
+    }
+
+    fn push_sensitive_path(&mut self, path: &str) {
+        if let Some(sensitive) = path_analyzer().analyze(path) {
+            self.risk_factors.push(
+                PromptMessage::new(
+                    "permission.factor.sensitive_path",
+                    format!("Sensitive path: {}", sensitive.description),
+                )
+                .with_arg("description", sensitive.description.clone()),
+            );
+        }
+    }
+
+    /// 위험 요인 추가
+    pub fn with_risk_factor(mut self, factor: PromptMessage) -> Self {
+        self.risk_factors.push(factor);
+        self
+    }
+
+    /// 대안 추가
+    pub fn with_alternative(mut self, alternative: PromptMessage) -> Self {
+        self.alternatives.push(alternative);
+        self
+    }
+
+    /// 미리보기 설정
+    pub fn with_preview(mut self, preview: PromptPreview) -> Self {
+        self.preview = Some(preview);
+        self
+    }
+
+    /// 설명 설정
+    pub fn with_explanation(mut self, explanation: impl Into<String>) -> Self {
+        self.explanation = Some(explanation.into());
+        self
+    }
+
+    /// 선택지 설정
+    pub fn with_options(mut self, options: impl IntoIterator<Item = ConfirmOption>) -> Self {
+        self.options = options.into_iter().collect();
+        self
+    }
+
+    /// 한 줄 제목 (`Run command: cargo test`)
+    pub fn headline(&self, catalog: &PromptCatalog) -> String {
+        format!(
+            "{}: {}",
+            catalog.text(self.kind.key(), self.kind.label()),
+            first_line(&self.target)
+        )
+    }
+
+    /// 위험 수준 라벨 (`High Risk (7/10)`)
+    pub fn risk_label(&self, catalog: &PromptCatalog) -> String {
+        format!(
+            "{} ({}/10)",
+            catalog.text(self.risk.key(), self.risk.label()),
+            self.risk_score
+        )
+    }
+
+    /// 텍스트로 렌더링 (CLI용)
+    pub fn render(&self, catalog: &PromptCatalog) -> String {
+        let mut out = format!(
+            "{} {} - {}\n",
+            self.risk.indicator(),
+            self.risk_label(catalog),
+            self.headline(catalog)
+        );
+        if !self.description.is_empty() {
+            out.push_str(&format!("{}\n", self.description));
+        }
+
+        let mut section = |key: &str, default: &str, lines: Vec<String>| {
+            if lines.is_empty() {
+                return;
+            }
+            out.push_str(&format!("\n{}:\n", catalog.text(key, default)));
+            for line in lines {
+                out.push_str(&format!("  {}\n", line));
+            }
+        };
+
+        section(
+            "permission.heading.explanation",
+            "What this does",
+            self.explanation
+                .iter()
+                .flat_map(|explanation| explanation.lines().map(String::from))
+                .collect(),
+        );
+        section(
+            "permission.heading.risk_factors",
+            "Risk factors",
+            self.risk_factors
+                .iter()
+                .map(|factor| format!("- {}", catalog.message(factor)))
+                .collect(),
+        );
+        section(
+            "permission.heading.preview",
+            "Preview",
+            self.preview
+                .iter()
+                .flat_map(|preview| preview.body().lines().map(String::from))
+                .collect(),
+        );
+        section(
+            "permission.heading.alternatives",
+            "Alternatives",
+            self.alternatives
+                .iter()
+                .map(|alternative| format!("- {}", catalog.message(alternative)))
+                .collect(),
+        );
+        section(
+            "permission.heading.options",
+            "Options",
+            self.options
+                .iter()
+                .map(|option| {
+                    format!(
+                        "[{}] {}",
+                        option.shortcut(),
+                        catalog.text(option.key(), option.label())
+                    )
+                })
+                .collect(),
+        );
+
+        out
+    }
+
+    /// 기본 영어로 렌더링
+    pub fn display(&self) -> String {
+        self.render(&PromptCatalog::default())
+    }
+}
+
+/// 명령어별 더 안전한 대안
+fn command_alternatives(command: &str) -> Vec<PromptMessage> {
+    let mut alternatives = Vec::new();
+    let words: Vec<&str> = command.split_whitespace().collect();
+
+    if words.windows(2).any(|w| w == ["git", "push"])
+        && words.iter().any(|w| *w == "--force" || *w == "-f")
+    {
+        alternatives.push(PromptMessage::new(
+            "permission.alternative.force_with_lease",
+            "Use `git push --force-with-lease` to avoid overwriting others' commits",
+        ));
+    }
+    if words.first() == Some(&"rm")
+        && words
+            .iter()
+            .any(|w| w.starts_with('-') && !w.starts_with("--") && w.contains(['r', 'R']))
+    {
+        alternatives.push(PromptMessage::new(
+            "permission.alternative.remove_specific",
+            "Delete specific files instead of removing the directory recursively",
+        ));
+    }
+    if words.contains(&"sudo") {
+        alternatives.push(PromptMessage::new(
+            "permission.alternative.without_sudo",
+            "Run without sudo if elevated privileges are not required",
+        ));
+    }
+    let piped_to_shell = command
+        .split('|')
+        .skip(1)
+        .any(|part| matches!(part.split_whitespace().next(), Some("sh" | "bash" | "zsh")));
+    if piped_to_shell {
+        alternatives.push(PromptMessage::new(
+            "permission.alternative.inspect_script",
+            "Download the script and review it before running",
+        ));
+    }
+
+    alternatives
+}
+
+/// URL의 호스트 부분
+fn url_host(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    rest.split(['/', '?', '#'])
+        .next()
+        .unwrap_or(rest)
+        .to_string()
+}
+
+/// 첫 줄만 (여러 줄이면 … 표시)
+fn first_line(text: &str) -> String {
+    let mut lines = text.trim().lines();
+    let first = lines.next().unwrap_or("").to_string();
+    if lines.next().is_some() {
+        format!("{} …", first)
+    } else {
+        first
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_for_command() {
+        let action = PermissionAction::Execute {
+            command: "git push --force origin main".into(),
+        };
+        let prompt = ConfirmationPrompt::for_action("bash", &action, "Push changes", 7);
+
+        assert_eq!(prompt.kind, PromptActionKind::Execute);
+        assert_eq!(prompt.target, "git push --force origin main");
+        assert_eq!(prompt.risk, PromptRisk::High);
+        assert_eq!(
+            prompt.preview,
+            Some(PromptPreview::command("git push --force origin main"))
+        );
+        assert_eq!(
+            prompt.alternatives[0].key,
+            "permission.alternative.force_with_lease"
+        );
+        assert_eq!(prompt.options, ConfirmOption::ALL.to_vec());
+        assert_eq!(prompt.explanation, None);
+
+        let text = prompt
+            .with_explanation("Overwrites the remote main branch with your local history.")
+            .display();
+        assert!(text.starts_with("🟠 High Risk (7/10) - Run command: git push --force origin main"));
+        assert!(text.contains(
+            "What this does:\n  Overwrites the remote main branch with your local history.\n"
+        ));
+        assert!(text.contains("Alternatives:\n  - Use `git push --force-with-lease`"));
+        assert!(text.contains("  [x] Always deny\n"));
+    }
+
+    #[test]
+    fn test_prompt_factors_and_serialization() {
+        let delete = ConfirmationPrompt::for_action(
+            "delete",
+            &PermissionAction::FileDelete {
+                path: "notes.txt".into(),
+            },
+            "",
+            8,
+        );
+        assert_eq!(delete.risk_factors[0].key, "permission.factor.irreversible");
+        assert!(delete.alternatives.is_empty());
+
+        let network = ConfirmationPrompt::for_action(
+            "http_request",
+            &PermissionAction::Network {
+                url: "https://api.example.com/v1?q=1".into(),
+            },
+            "",
+            6,
+        )
+        .with_preview(PromptPreview::diff("a.txt", "-old\n+new"));
+        assert_eq!(network.risk_factors[0].args["host"], "api.example.com");
+
+        let json = serde_json::to_value(&network).unwrap();
+        assert!(json.get("explanation").is_none());
+        assert_eq!(json["kind"], "network");
+        assert_eq!(json["risk"], "medium");
+        assert_eq!(json["preview"]["type"], "diff");
+        assert_eq!(json["options"][0], "allow_once");
+        let back: ConfirmationPrompt = serde_json::from_value(json).unwrap();
+        assert_eq!(back, network);
+    }
+
+    #[test]
+    fn test_localized_render() {
+        let prompt = ConfirmationPrompt::for_action(
+            "http_request",
+            &PermissionAction::Network {
+                url: "https://example.com".into(),
+            },
+            "",
+            2,
+        )
+        .with_options([ConfirmOption::AllowOnce, ConfirmOption::Deny]);
+        let catalog = PromptCatalog::from_json(
+            r#"{
+                "permission.action.network": "네트워크 요청",
+                "permission.risk.low": "낮은 위험",
+                "permission.factor.network": "외부 호스트 접속: {host}",
+                "permission.option.deny": "거부"
+            }"#,
+        )
+        .unwrap();
+
+        let text = prompt.render(&catalog);
+        assert!(text.contains("낮은 위험 (2/10) - 네트워크 요청: https://example.com"));
+        assert!(text.contains("- 외부 호스트 접속: example.com"));
+        assert!(text.contains("[n] 거부"));
+        assert!(text.contains("[y] Allow once"));
+    }
+
+    #[test]
+    fn test_confirm_option_mapping() {
+        for option in ConfirmOption::ALL {
+            assert_eq!(
+                ConfirmOption::from_input(&option.shortcut().to_string()),
+                Some(option)
+            );
+        }
+        assert_eq!(
+            ConfirmOption::DenyPermanent.to_response(),
+            PermissionResponse::DenyPermanent
+        );
+        assert_eq!(ConfirmOption::DenyPermanent.to_scope(), None);
+    }
+}
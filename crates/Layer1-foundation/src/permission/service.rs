@@ -3,11 +3,17 @@
 //! Manages runtime permission grants and integrates with persistent storage.
 //! This is a pure data management layer - UI/CLI interaction is handled elsewhere.
 
-use super::settings::PermissionSettings;
-use crate::Result;
+use super::mcp::mcp_scope_covers;
+use super::settings::{ManagedPolicy, PermissionActionType, PermissionPaths, PermissionSettings};
+use crate::{Result, Tool};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::RwLock;
+use std::time::Duration;
 
 /// Types of permission actions
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -44,6 +50,21 @@ impl PermissionAction {
             Self::Custom { name, details } => format!("{}: {}", name, details),
         }
     }
+
+    /// File path for file actions
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Self::FileWrite { path }
+            | Self::FileDelete { path }
+            | Self::FileReadSensitive { path } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Whether the action only reads (allowed in read-only mode)
+    pub fn is_read(&self) -> bool {
+        matches!(self, Self::FileReadSensitive { .. })
+    }
 }
 
 /// A granted permission
@@ -52,6 +73,85 @@ pub struct Permission {
     pub tool_name: String,
     pub action: PermissionAction,
     pub scope: PermissionScope,
+
+    /// Actions covered besides `action` itself
+    #[serde(default)]
+    pub coverage: GrantCoverage,
+}
+
+impl Permission {
+    /// Grant for exactly `action`
+    pub fn new(
+        tool_name: impl Into<String>,
+        action: PermissionAction,
+        scope: PermissionScope,
+    ) -> Self {
+        Self {
+            tool_name: tool_name.into(),
+            action,
+            scope,
+            coverage: GrantCoverage::Exact,
+        }
+    }
+
+    /// Cover every action of the same kind ("allow bash for 30 minutes")
+    pub fn for_any(mut self) -> Self {
+        self.coverage = GrantCoverage::Tool;
+        self
+    }
+
+    /// Cover file actions of the same kind under `dir` ("allow write under src/ only")
+    ///
+    /// Relative directories are resolved against the working directory when
+    /// the grant is checked.
+    pub fn under(mut self, dir: impl AsRef<Path>) -> Self {
+        self.coverage = GrantCoverage::Path(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Whether the grant has expired
+    pub fn is_expired(&self) -> bool {
+        self.scope.is_expired()
+    }
+
+    /// Whether the grant covers `action` of `tool_name`
+    ///
+    /// A grant for an MCP scope (`mcp.github.*`) applies to every tool in that scope.
+    /// Relative paths are resolved against `working_dir`.
+    pub fn covers(&self, tool_name: &str, action: &PermissionAction, working_dir: &Path) -> bool {
+        let in_scope = self.tool_name == tool_name || mcp_scope_covers(&self.tool_name, action);
+        if !in_scope || self.is_expired() {
+            return false;
+        }
+        let same_kind =
+            PermissionActionType::from(&self.action) == PermissionActionType::from(action);
+        match &self.coverage {
+            GrantCoverage::Exact => &self.action == action,
+            GrantCoverage::Tool => same_kind,
+            GrantCoverage::Path(dir) => {
+                let dir = resolve(dir, working_dir);
+                same_kind
+                    && action
+                        .path()
+                        .is_some_and(|path| resolve(Path::new(path), working_dir).starts_with(&dir))
+            }
+        }
+    }
+}
+
+/// Actions a grant covers
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum GrantCoverage {
+    /// Only the granted action
+    #[default]
+    Exact,
+
+    /// Every action of the same kind for the tool
+    Tool,
+
+    /// File actions of the same kind on paths under a directory
+    Path(PathBuf),
 }
 
 /// Scope of a granted permission
@@ -65,10 +165,66 @@ pub enum PermissionScope {
 
     /// Saved permanently
     Permanent,
+
+    /// Valid for current session until `expires_at`
+    Timed { expires_at: DateTime<Utc> },
+}
+
+impl PermissionScope {
+    /// Timed scope expiring `duration` from now
+    pub fn for_duration(duration: Duration) -> Self {
+        let duration = chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::MAX);
+        Self::Timed {
+            expires_at: Utc::now()
+                .checked_add_signed(duration)
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+        }
+    }
+
+    /// Whether a timed scope has expired
+    pub fn is_expired(&self) -> bool {
+        matches!(self, Self::Timed { expires_at } if *expires_at <= Utc::now())
+    }
+
+    /// Time left for timed scopes
+    pub fn remaining(&self) -> Option<Duration> {
+        match self {
+            Self::Timed { expires_at } => {
+                Some((*expires_at - Utc::now()).to_std().unwrap_or_default())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `path` resolved against `base`, with `..` and symlinks resolved
+///
+/// Each existing prefix is canonicalized before the next component is applied,
+/// so `dir/link/..` follows the link like the OS does and a symlink cannot
+/// point a granted directory elsewhere. Components that do not exist yet (new
+/// files) are applied lexically.
+fn resolve(path: &Path, base: &Path) -> PathBuf {
+    let mut resolved = PathBuf::new();
+    for component in base.join(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            other => {
+                resolved.push(other);
+                if let Ok(canonical) = resolved.canonicalize() {
+                    resolved = canonical;
+                }
+            }
+        }
+    }
+    resolved
 }
 
 /// Permission check result
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PermissionStatus {
     /// Permission granted
     Granted,
@@ -83,11 +239,73 @@ pub enum PermissionStatus {
     AutoApproved,
 }
 
+/// A permission a tool call would need, with its current status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequiredPermission {
+    /// Action the call would perform
+    pub action: PermissionAction,
+
+    /// Current status of the action
+    pub status: PermissionStatus,
+}
+
+impl RequiredPermission {
+    /// Whether the action would run without asking
+    pub fn is_granted(&self) -> bool {
+        matches!(
+            self.status,
+            PermissionStatus::Granted | PermissionStatus::AutoApproved
+        )
+    }
+}
+
+/// Permissions a hypothetical tool call would need (see `PermissionService::simulate`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionSimulation {
+    /// Tool name
+    pub tool: String,
+
+    /// Required permissions, in the order the tool asks for them
+    pub required: Vec<RequiredPermission>,
+}
+
+impl PermissionSimulation {
+    /// Create from already checked permissions
+    pub fn new(tool: impl Into<String>, required: Vec<RequiredPermission>) -> Self {
+        Self {
+            tool: tool.into(),
+            required,
+        }
+    }
+
+    /// Whether the call would run without any approval
+    pub fn is_allowed(&self) -> bool {
+        self.required.iter().all(RequiredPermission::is_granted)
+    }
+
+    /// Whether any required permission is denied (the call would fail)
+    pub fn is_denied(&self) -> bool {
+        self.required
+            .iter()
+            .any(|r| r.status == PermissionStatus::Denied)
+    }
+
+    /// Permissions the user would be asked for
+    pub fn pending(&self) -> impl Iterator<Item = &RequiredPermission> {
+        self.required
+            .iter()
+            .filter(|r| r.status == PermissionStatus::Unknown)
+    }
+}
+
 /// Permission service managing grants and queries
 ///
 /// This service handles:
-/// - Session grants (in-memory, cleared on restart)
+/// - Session grants (in-memory, cleared on restart), optionally time-limited
 /// - Permanent grants (loaded from/saved to JSON storage)
+/// - Path-scoped and tool-wide grants (`GrantCoverage`)
+/// - An optional managed (organization) policy that local settings cannot override
+/// - Read-only (safe) mode, which denies every non-read action
 /// - Permission checking and granting
 pub struct PermissionService {
     /// Session grants (in-memory only)
@@ -95,15 +313,21 @@ pub struct PermissionService {
 
     /// Persistent settings (loaded from storage)
     settings: RwLock<PermissionSettings>,
+
+    /// Managed policy (never saved back)
+    managed: RwLock<Option<ManagedPolicy>>,
+
+    /// Read-only mode (never saved)
+    read_only: AtomicBool,
+
+    /// Directory relative paths are resolved against (the agent's working directory)
+    working_dir: RwLock<Option<PathBuf>>,
 }
 
 impl PermissionService {
     /// Create a new permission service with default settings
     pub fn new() -> Self {
-        Self {
-            session_grants: RwLock::new(HashSet::new()),
-            settings: RwLock::new(PermissionSettings::default()),
-        }
+        Self::with_settings(PermissionSettings::default())
     }
 
     /// Create with loaded settings
@@ -111,13 +335,44 @@ impl PermissionService {
         Self {
             session_grants: RwLock::new(HashSet::new()),
             settings: RwLock::new(settings),
+            managed: RwLock::new(None),
+            read_only: AtomicBool::new(false),
+            working_dir: RwLock::new(None),
+        }
+    }
+
+    /// Create with loaded settings and a managed policy
+    pub fn with_policy(settings: PermissionSettings, managed: Option<ManagedPolicy>) -> Self {
+        let service = Self::with_settings(settings);
+        if let Ok(mut policy) = service.managed.write() {
+            *policy = managed;
         }
+        service
     }
 
-    /// Load settings from storage (global + project merged)
+    /// Load settings from storage
+    ///
+    /// User, project and local settings are merged; the managed policy is
+    /// loaded on top. Fails if the managed policy exists but cannot be read.
     pub fn load() -> Result<Self> {
-        let settings = PermissionSettings::load()?;
-        Ok(Self::with_settings(settings))
+        Self::load_from(&PermissionPaths::discover())
+    }
+
+    /// Load settings from explicit layer paths
+    pub fn load_from(paths: &PermissionPaths) -> Result<Self> {
+        let managed = match &paths.managed {
+            Some(path) => ManagedPolicy::load_from(path)?,
+            None => None,
+        };
+        Ok(Self::with_policy(
+            PermissionSettings::load_from(paths),
+            managed,
+        ))
+    }
+
+    /// Managed policy, if one is installed
+    pub fn managed_policy(&self) -> Option<ManagedPolicy> {
+        self.managed.read().ok().and_then(|p| p.clone())
     }
 
     /// Create with auto-approve enabled for all tools
@@ -127,37 +382,122 @@ impl PermissionService {
         Self::with_settings(settings)
     }
 
+    /// Enable read-only mode (builder form of `set_read_only`)
+    pub fn read_only(self) -> Self {
+        self.set_read_only(true);
+        self
+    }
+
+    /// Enable or disable read-only (safe) mode
+    ///
+    /// While enabled, every write, delete, execute, network and custom action
+    /// is denied regardless of auto-approve settings and grants. Only reads
+    /// go through the normal checks.
+    pub fn set_read_only(&self, enabled: bool) {
+        self.read_only.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Whether read-only mode is enabled
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::SeqCst)
+    }
+
+    /// Set the directory relative paths in path-scoped grants are resolved against
+    ///
+    /// The agent context sets this to its working directory; until then the
+    /// process current directory is used.
+    pub fn set_working_dir(&self, dir: impl AsRef<Path>) {
+        if let Ok(mut working_dir) = self.working_dir.write() {
+            *working_dir = Some(dir.as_ref().to_path_buf());
+        }
+    }
+
+    /// Directory relative paths are resolved against
+    pub fn working_dir(&self) -> PathBuf {
+        self.working_dir
+            .read()
+            .ok()
+            .and_then(|dir| dir.clone())
+            .unwrap_or_else(|| std::env::current_dir().unwrap_or_default())
+    }
+
     /// Check permission status for an action
     pub fn check(&self, tool_name: &str, action: &PermissionAction) -> PermissionStatus {
-        // 1. Check deny list first
+        // 0. Read-only mode overrides everything else
+        if self.is_read_only() && !action.is_read() {
+            return PermissionStatus::Denied;
+        }
+
+        let managed = self.managed.read().ok();
+        let managed = managed.as_ref().and_then(|p| p.as_ref());
+
+        // 1. Check deny lists first (managed policy, then merged settings)
+        if managed.is_some_and(|p| p.settings.is_denied(tool_name, action)) {
+            return PermissionStatus::Denied;
+        }
         if let Ok(settings) = self.settings.read() {
             if settings.is_denied(tool_name, action) {
                 return PermissionStatus::Denied;
             }
 
             // 2. Check auto-approve
-            if settings.is_auto_approved(tool_name) {
+            if managed.is_some_and(|p| p.settings.is_auto_approved(tool_name)) {
+                return PermissionStatus::AutoApproved;
+            }
+            if !managed.is_some_and(|p| p.disable_auto_approve)
+                && settings.is_auto_approved(tool_name)
+            {
                 return PermissionStatus::AutoApproved;
             }
 
             // 3. Check permanent grants
-            if settings.is_granted(tool_name, action) {
+            if managed.is_some_and(|p| p.settings.is_granted(tool_name, action)) {
+                return PermissionStatus::Granted;
+            }
+            if !managed.is_some_and(|p| p.managed_grants_only)
+                && settings.is_granted(tool_name, action)
+            {
                 return PermissionStatus::Granted;
             }
         }
 
-        // 4. Check session grants
+        // 4. Check session grants (expired timed grants are skipped)
         if let Ok(grants) = self.session_grants.read() {
-            for grant in grants.iter() {
-                if grant.tool_name == tool_name && &grant.action == action {
-                    return PermissionStatus::Granted;
-                }
+            let working_dir = self.working_dir();
+            if grants
+                .iter()
+                .any(|grant| grant.covers(tool_name, action, &working_dir))
+            {
+                return PermissionStatus::Granted;
             }
         }
 
         PermissionStatus::Unknown
     }
 
+    /// Permissions a call of `tool` with `args` would need, without running it
+    ///
+    /// Nothing is requested or granted; each action is checked as `check` would.
+    pub fn simulate(&self, tool: &dyn Tool, args: &Value) -> PermissionSimulation {
+        self.simulate_actions(tool.name(), tool.required_permissions(args))
+    }
+
+    /// Check the actions a call of `tool_name` would perform, as `simulate` does
+    pub fn simulate_actions(
+        &self,
+        tool_name: &str,
+        actions: impl IntoIterator<Item = PermissionAction>,
+    ) -> PermissionSimulation {
+        let required = actions
+            .into_iter()
+            .map(|action| RequiredPermission {
+                status: self.check(tool_name, &action),
+                action,
+            })
+            .collect();
+        PermissionSimulation::new(tool_name, required)
+    }
+
     /// Check if an action is permitted (convenience method)
     pub fn is_permitted(&self, tool_name: &str, action: &PermissionAction) -> bool {
         matches!(
@@ -167,12 +507,17 @@ impl PermissionService {
     }
 
     /// Grant a permission
+    ///
+    /// Timed grants live with the session grants until they expire.
     pub fn grant(&self, permission: Permission) {
         match permission.scope {
             PermissionScope::Once => {
                 // Once permissions are not stored
             }
-            PermissionScope::Session => {
+            PermissionScope::Session | PermissionScope::Timed { .. } => {
+                if permission.is_expired() {
+                    return;
+                }
                 if let Ok(mut grants) = self.session_grants.write() {
                     grants.insert(permission);
                 }
@@ -187,11 +532,7 @@ impl PermissionService {
 
     /// Grant and save permanent permission
     pub fn grant_permanent(&self, tool_name: &str, action: PermissionAction) -> Result<()> {
-        let permission = Permission {
-            tool_name: tool_name.to_string(),
-            action,
-            scope: PermissionScope::Permanent,
-        };
+        let permission = Permission::new(tool_name, action, PermissionScope::Permanent);
 
         if let Ok(mut settings) = self.settings.write() {
             settings.add_permission(&permission);
@@ -203,15 +544,26 @@ impl PermissionService {
 
     /// Grant session permission
     pub fn grant_session(&self, tool_name: &str, action: PermissionAction) {
-        let permission = Permission {
-            tool_name: tool_name.to_string(),
+        self.grant(Permission::new(tool_name, action, PermissionScope::Session));
+    }
+
+    /// Grant permission for `duration`
+    pub fn grant_for(&self, tool_name: &str, action: PermissionAction, duration: Duration) {
+        self.grant(Permission::new(
+            tool_name,
             action,
-            scope: PermissionScope::Session,
-        };
+            PermissionScope::for_duration(duration),
+        ));
+    }
 
-        if let Ok(mut grants) = self.session_grants.write() {
-            grants.insert(permission);
-        }
+    /// Remove expired timed grants, returning them
+    pub fn prune_expired(&self) -> Vec<Permission> {
+        let Ok(mut grants) = self.session_grants.write() else {
+            return Vec::new();
+        };
+        let expired: Vec<Permission> = grants.iter().filter(|g| g.is_expired()).cloned().collect();
+        grants.retain(|g| !g.is_expired());
+        expired
     }
 
     /// Clear all session grants
@@ -221,11 +573,11 @@ impl PermissionService {
         }
     }
 
-    /// Get all session grants
+    /// Get all active session grants (including unexpired timed grants)
     pub fn session_grants(&self) -> Vec<Permission> {
         self.session_grants
             .read()
-            .map(|g| g.iter().cloned().collect())
+            .map(|g| g.iter().filter(|p| !p.is_expired()).cloned().collect())
             .unwrap_or_default()
     }
 
@@ -238,7 +590,21 @@ impl PermissionService {
     }
 
     /// Check if auto-approve is enabled for a tool
+    ///
+    /// Always false in read-only mode.
     pub fn is_auto_approved(&self, tool_name: &str) -> bool {
+        if self.is_read_only() {
+            return false;
+        }
+        let managed = self.managed_policy();
+        if let Some(policy) = &managed {
+            if policy.settings.is_auto_approved(tool_name) {
+                return true;
+            }
+            if policy.disable_auto_approve {
+                return false;
+            }
+        }
         self.settings
             .read()
             .map(|s| s.is_auto_approved(tool_name))
@@ -266,12 +632,20 @@ impl PermissionService {
         Ok(())
     }
 
-    /// Reload settings from storage
+    /// Reload settings and the managed policy from storage
     pub fn reload(&self) -> Result<()> {
-        let new_settings = PermissionSettings::load()?;
+        let paths = PermissionPaths::discover();
+        let new_managed = match &paths.managed {
+            Some(path) => ManagedPolicy::load_from(path)?,
+            None => None,
+        };
+        let new_settings = PermissionSettings::load_from(&paths);
         if let Ok(mut settings) = self.settings.write() {
             *settings = new_settings;
         }
+        if let Ok(mut managed) = self.managed.write() {
+            *managed = new_managed;
+        }
         Ok(())
     }
 
@@ -367,4 +741,257 @@ mod tests {
         // Now granted
         assert_eq!(service.check("bash", &action), PermissionStatus::Granted);
     }
+
+    #[test]
+    fn test_timed_and_path_scoped_grants() {
+        let service = PermissionService::new();
+        let command = |command: &str| PermissionAction::Execute {
+            command: command.to_string(),
+        };
+        let write = |path: &str| PermissionAction::FileWrite {
+            path: path.to_string(),
+        };
+
+        // "allow bash for 30 minutes"
+        service.grant(
+            Permission::new(
+                "bash",
+                command("*"),
+                PermissionScope::for_duration(Duration::from_secs(1800)),
+            )
+            .for_any(),
+        );
+        assert!(service.is_permitted("bash", &command("cargo test")));
+        assert!(!service.is_permitted("bash", &write("src/lib.rs")));
+        let remaining = service.session_grants()[0].scope.remaining().unwrap();
+        assert!(remaining > Duration::from_secs(1790));
+
+        // Expired grants are ignored and pruned
+        let expired = Permission::new(
+            "bash",
+            command("*"),
+            PermissionScope::Timed {
+                expires_at: Utc::now() - chrono::Duration::seconds(1),
+            },
+        )
+        .for_any();
+        assert!(!expired.covers("bash", &command("ls"), Path::new("/")));
+        service.clear_session();
+        if let Ok(mut grants) = service.session_grants.write() {
+            grants.insert(expired);
+        }
+        assert!(!service.is_permitted("bash", &command("ls")));
+        assert!(service.session_grants().is_empty());
+        assert_eq!(service.prune_expired().len(), 1);
+
+        // "allow write under src/ only"
+        let root = std::env::temp_dir().join("forge-grant-scope");
+        service.grant(
+            Permission::new("write", write("src"), PermissionScope::Session)
+                .under(root.join("src")),
+        );
+        let path = |p: &str| root.join(p).to_string_lossy().to_string();
+        assert!(service.is_permitted("write", &write(&path("src/a/b.rs"))));
+        assert!(!service.is_permitted("write", &write(&path("src/../Cargo.toml"))));
+        assert!(!service.is_permitted("write", &write(&path("src2/main.rs"))));
+        assert!(!service.is_permitted(
+            "write",
+            &PermissionAction::FileDelete {
+                path: path("src/a.rs")
+            }
+        ));
+    }
+
+    #[test]
+    fn test_path_grant_resolution() {
+        let root = std::env::temp_dir().join(format!("forge-grant-resolve-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("granted/sub")).unwrap();
+        let write = |path: &str| PermissionAction::FileWrite {
+            path: path.to_string(),
+        };
+
+        let service = PermissionService::new();
+        service.set_working_dir(&root);
+        service.grant(
+            Permission::new("write", write("granted"), PermissionScope::Session).under("granted"),
+        );
+
+        // Relative paths resolve against the working directory, not the process cwd
+        assert!(service.is_permitted("write", &write("granted/new.rs")));
+        assert!(service.is_permitted("write", &write("./granted/sub/../new.rs")));
+        assert!(!service.is_permitted("write", &write("granted/../outside.rs")));
+        assert!(!service.is_permitted("write", &write("granted/sub/../../outside.rs")));
+        assert!(service.is_permitted(
+            "write",
+            &write(&root.join("granted/sub/a.rs").to_string_lossy())
+        ));
+
+        // A symlink inside the granted directory cannot point out of it
+        #[cfg(unix)]
+        {
+            std::fs::create_dir_all(root.join("outside/inner")).unwrap();
+            std::os::unix::fs::symlink(root.join("outside/inner"), root.join("granted/link"))
+                .unwrap();
+            assert!(!service.is_permitted("write", &write("granted/link/a.rs")));
+            // `..` after a symlink leaves the link target, as the OS resolves it
+            assert!(!service.is_permitted("write", &write("granted/link/../a.rs")));
+        }
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_managed_policy_precedence() {
+        use crate::permission::{PermissionActionType, PermissionDeny, PermissionGrant};
+
+        let push = PermissionAction::Execute {
+            command: "git push".to_string(),
+        };
+        let ls = PermissionAction::Execute {
+            command: "ls".to_string(),
+        };
+
+        // Local settings: auto-approve everything, grant all bash commands
+        let mut settings = PermissionSettings::default();
+        settings.auto_approve = true;
+        settings.add_grant(PermissionGrant {
+            tool: "bash".to_string(),
+            action_type: PermissionActionType::Execute,
+            pattern: None,
+        });
+
+        let mut policy = ManagedPolicy::default();
+        policy.settings.add_deny(PermissionDeny {
+            tool: "bash".to_string(),
+            pattern: "git push".to_string(),
+            reason: Some("Org policy".to_string()),
+        });
+        policy.disable_auto_approve = true;
+
+        let service = PermissionService::with_policy(settings.clone(), Some(policy.clone()));
+        service.grant_session("bash", push.clone());
+        assert_eq!(service.check("bash", &push), PermissionStatus::Denied);
+        assert_eq!(service.check("bash", &ls), PermissionStatus::Granted);
+        assert!(!service.is_auto_approved("bash"));
+
+        // Only managed grants count
+        policy.managed_grants_only = true;
+        let service = PermissionService::with_policy(settings, Some(policy));
+        assert_eq!(service.check("bash", &ls), PermissionStatus::Unknown);
+    }
+
+    #[test]
+    fn test_read_only_mode() {
+        let service = PermissionService::with_auto_approve();
+        let command = PermissionAction::Execute {
+            command: "ls".to_string(),
+        };
+        let read = PermissionAction::FileReadSensitive {
+            path: "/home/user/.env".to_string(),
+        };
+        service.grant_session("bash", command.clone());
+        assert!(service.is_permitted("bash", &command));
+
+        service.set_read_only(true);
+        assert!(service.is_read_only());
+        assert!(!service.is_auto_approved("bash"));
+        assert_eq!(service.check("bash", &command), PermissionStatus::Denied);
+        for action in [
+            PermissionAction::FileWrite {
+                path: "/tmp/a.txt".to_string(),
+            },
+            PermissionAction::FileDelete {
+                path: "/tmp/a.txt".to_string(),
+            },
+            PermissionAction::Network {
+                url: "https://example.com".to_string(),
+            },
+            PermissionAction::Custom {
+                name: "forgecmd.privileged".to_string(),
+                details: "sudo ls".to_string(),
+            },
+        ] {
+            assert!(!service.is_permitted("any", &action), "{:?}", action);
+        }
+        // Reads still follow the normal checks
+        assert!(service.is_permitted("read", &read));
+
+        service.set_read_only(false);
+        assert!(service.is_permitted("bash", &command));
+        assert!(PermissionService::new().read_only().is_read_only());
+    }
+
+    #[test]
+    fn test_simulate() {
+        use crate::{ToolMeta, ToolResult};
+        use async_trait::async_trait;
+
+        /// Copies `from` to `to`: reads a sensitive file and writes another
+        struct CopyTool;
+
+        #[async_trait]
+        impl Tool for CopyTool {
+            fn name(&self) -> &str {
+                "copy"
+            }
+
+            fn meta(&self) -> ToolMeta {
+                ToolMeta::new("copy")
+            }
+
+            fn schema(&self) -> Value {
+                serde_json::json!({ "type": "object" })
+            }
+
+            async fn execute(
+                &self,
+                _input: Value,
+                _context: &dyn crate::ToolContext,
+            ) -> Result<ToolResult> {
+                Ok(ToolResult::success(""))
+            }
+
+            fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+                self.required_permissions(input).into_iter().next()
+            }
+
+            fn required_permissions(&self, input: &Value) -> Vec<PermissionAction> {
+                vec![
+                    PermissionAction::FileReadSensitive {
+                        path: input["from"].as_str().unwrap_or_default().to_string(),
+                    },
+                    PermissionAction::FileWrite {
+                        path: input["to"].as_str().unwrap_or_default().to_string(),
+                    },
+                ]
+            }
+        }
+
+        let service = PermissionService::new();
+        let args = serde_json::json!({ "from": "/work/.env", "to": "/work/.env.bak" });
+        service.grant_session(
+            "copy",
+            PermissionAction::FileReadSensitive {
+                path: "/work/.env".to_string(),
+            },
+        );
+
+        let simulation = service.simulate(&CopyTool, &args);
+        assert_eq!(simulation.tool, "copy");
+        assert_eq!(simulation.required.len(), 2);
+        assert!(simulation.required[0].is_granted());
+        assert_eq!(simulation.required[1].status, PermissionStatus::Unknown);
+        assert!(!simulation.is_allowed());
+        assert!(!simulation.is_denied());
+        assert_eq!(simulation.pending().count(), 1);
+
+        // Simulating grants nothing
+        assert!(service
+            .simulate(&CopyTool, &args)
+            .pending()
+            .any(|r| r.action.path() == Some("/work/.env.bak")));
+
+        service.set_read_only(true);
+        assert!(service.simulate(&CopyTool, &args).is_denied());
+    }
 }
@@ -1,18 +1,40 @@
 //! Permission 설정 저장/로드
 //!
 //! 영구 권한(permanent grants)을 JSON으로 관리
+//!
+//! ## 설정 레이어
+//!
+//! 뒤의 레이어일수록 우선합니다.
+//!
+//! 1. 사용자: `~/.forgecode/permissions.json` (`JsonStore::global`)
+//! 2. 프로젝트: `.forgecode/permissions.json` (저장소에 커밋)
+//! 3. 로컬: `.forgecode/permissions.local.json` (커밋하지 않는 개인 설정)
+//! 4. 관리(조직) 정책: `managed-permissions.json` (`ManagedPolicy`)
+//!
+//! 1~3은 하나의 `PermissionSettings`로 병합되고, 거부 패턴은 어느 레이어에
+//! 있든 허용보다 우선합니다. 관리 정책은 병합하지 않고 따로 보관하여
+//! 하위 레이어에서 덮어쓸 수 없습니다.
 
-use super::service::{Permission, PermissionAction, PermissionScope};
+use super::mcp::{mcp_action, mcp_scope_covers, mcp_server_scope};
+use super::service::{GrantCoverage, Permission, PermissionAction, PermissionScope};
 use crate::storage::JsonStore;
-use crate::Result;
+use crate::{Error, Result};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
 /// 설정 파일명
 pub const PERMISSIONS_FILE: &str = "permissions.json";
 
+/// 로컬(개인) 설정 파일명
+pub const LOCAL_PERMISSIONS_FILE: &str = "permissions.local.json";
+
+/// 관리 정책 파일명
+pub const MANAGED_PERMISSIONS_FILE: &str = "managed-permissions.json";
+
 /// Permission 설정 파일 구조
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PermissionSettings {
     /// 영구 허용된 권한들
@@ -32,11 +54,118 @@ pub struct PermissionSettings {
     pub auto_approve_tools: HashSet<String>,
 }
 
+/// 관리(조직) 정책 파일 구조
+///
+/// 관리자가 시스템 경로(`PermissionPaths::managed_default`)에 배포하며,
+/// 사용자·프로젝트·로컬 설정으로 덮어쓸 수 없습니다.
+/// - `denies`: 모든 허용, 자동 승인, 세션 승인보다 우선
+/// - `disableAutoApprove`: 하위 레이어의 자동 승인 무시
+/// - `managedGrantsOnly`: 하위 레이어의 영구 허용 무시 (세션 승인은 유지)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedPolicy {
+    /// 정책 권한 (grants, denies, autoApprove, autoApproveTools)
+    #[serde(flatten)]
+    pub settings: PermissionSettings,
+
+    /// 하위 레이어의 자동 승인 금지
+    #[serde(default)]
+    pub disable_auto_approve: bool,
+
+    /// 정책의 grants만 영구 허용으로 인정
+    #[serde(default)]
+    pub managed_grants_only: bool,
+}
+
+impl ManagedPolicy {
+    /// 정책 파일 로드 (파일이 없으면 None)
+    ///
+    /// 파일이 있지만 읽거나 파싱할 수 없으면 에러를 반환합니다.
+    /// 정책이 조용히 무시되지 않도록 호출자는 실행을 중단해야 합니다.
+    pub fn load_from(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to read managed policy {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        serde_json::from_str(&content).map(Some).map_err(|e| {
+            Error::Config(format!(
+                "Failed to parse managed policy {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+}
+
+/// 권한 설정 파일 경로 (레이어별)
+#[derive(Debug, Clone, Default)]
+pub struct PermissionPaths {
+    /// 사용자 설정
+    pub user: Option<PathBuf>,
+
+    /// 프로젝트 설정
+    pub project: Option<PathBuf>,
+
+    /// 로컬 설정
+    pub local: Option<PathBuf>,
+
+    /// 관리 정책
+    pub managed: Option<PathBuf>,
+}
+
+impl PermissionPaths {
+    /// 기본 경로 (글로벌 설정 + 현재 디렉토리 프로젝트 + 시스템 관리 정책)
+    pub fn discover() -> Self {
+        let mut paths = std::env::current_dir()
+            .map(Self::for_project)
+            .unwrap_or_default();
+        paths.user = JsonStore::global()
+            .ok()
+            .map(|store| store.file_path(PERMISSIONS_FILE));
+        paths
+    }
+
+    /// `root` 프로젝트의 경로 (사용자 설정 제외)
+    pub fn for_project(root: impl Into<PathBuf>) -> Self {
+        let store = JsonStore::project(root);
+        Self {
+            user: None,
+            project: Some(store.file_path(PERMISSIONS_FILE)),
+            local: Some(store.file_path(LOCAL_PERMISSIONS_FILE)),
+            managed: Some(Self::managed_default()),
+        }
+    }
+
+    /// 플랫폼별 관리 정책 경로
+    ///
+    /// - Linux: `/etc/forgecode/managed-permissions.json`
+    /// - macOS: `/Library/Application Support/ForgeCode/managed-permissions.json`
+    /// - Windows: `%ProgramData%\ForgeCode\managed-permissions.json`
+    pub fn managed_default() -> PathBuf {
+        #[cfg(target_os = "macos")]
+        let dir = PathBuf::from("/Library/Application Support/ForgeCode");
+        #[cfg(windows)]
+        let dir = PathBuf::from(
+            std::env::var("ProgramData").unwrap_or_else(|_| r"C:\ProgramData".to_string()),
+        )
+        .join("ForgeCode");
+        #[cfg(not(any(target_os = "macos", windows)))]
+        let dir = PathBuf::from("/etc/forgecode");
+        dir.join(MANAGED_PERMISSIONS_FILE)
+    }
+}
+
 /// 저장용 권한 구조
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PermissionGrant {
-    /// 도구 이름 (예: "bash", "file_write")
+    /// 도구 이름 (예: "bash", "file_write") 또는 MCP 범위 (예: "mcp.github.*")
     pub tool: String,
 
     /// 액션 타입
@@ -48,10 +177,10 @@ pub struct PermissionGrant {
 }
 
 /// 거부 패턴
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PermissionDeny {
-    /// 도구 이름
+    /// 도구 이름 또는 MCP 범위 (예: "mcp.github.*")
     pub tool: String,
 
     /// 패턴
@@ -63,7 +192,7 @@ pub struct PermissionDeny {
 }
 
 /// 액션 타입 (저장용 간소화 버전)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PermissionActionType {
     Execute,
@@ -104,13 +233,32 @@ impl PermissionSettings {
         Ok(store.load_or_default(PERMISSIONS_FILE))
     }
 
-    /// 글로벌 + 프로젝트 병합 로드
+    /// 로컬 설정 로드
+    pub fn load_local() -> Result<Self> {
+        let store = JsonStore::current_project()?;
+        Ok(store.load_or_default(LOCAL_PERMISSIONS_FILE))
+    }
+
+    /// 글로벌 + 프로젝트 + 로컬 병합 로드
     pub fn load() -> Result<Self> {
-        let mut settings = Self::load_global().unwrap_or_default();
-        if let Ok(project) = Self::load_project() {
-            settings.merge(project);
-        }
-        Ok(settings)
+        Ok(Self::load_from(&PermissionPaths::discover()))
+    }
+
+    /// 사용자 → 프로젝트 → 로컬 순으로 병합 로드 (관리 정책 제외)
+    ///
+    /// 없거나 잘못된 파일은 건너뜁니다.
+    pub fn load_from(paths: &PermissionPaths) -> Self {
+        [&paths.user, &paths.project, &paths.local]
+            .into_iter()
+            .flatten()
+            .filter_map(|path| {
+                let content = std::fs::read_to_string(path).ok()?;
+                serde_json::from_str::<Self>(&content).ok()
+            })
+            .fold(Self::default(), |mut settings, layer| {
+                settings.merge(layer);
+                settings
+            })
     }
 
     /// 글로벌 설정 저장
@@ -125,21 +273,46 @@ impl PermissionSettings {
         store.save(PERMISSIONS_FILE, self)
     }
 
+    /// 로컬 설정 저장
+    pub fn save_local(&self) -> Result<()> {
+        let store = JsonStore::current_project()?;
+        store.save(LOCAL_PERMISSIONS_FILE, self)
+    }
+
     /// 권한 추가
     pub fn add_grant(&mut self, grant: PermissionGrant) {
         self.grants.insert(grant);
     }
 
     /// Permission에서 Grant 생성 및 추가
+    ///
+    /// 도구 전체 허용은 패턴 없이, 경로 허용은 `dir/**` 패턴으로 저장합니다.
     pub fn add_permission(&mut self, permission: &Permission) {
+        let pattern = match &permission.coverage {
+            GrantCoverage::Exact => Self::extract_pattern(&permission.action),
+            GrantCoverage::Tool => None,
+            GrantCoverage::Path(dir) => Some(format!(
+                "{}/**",
+                dir.to_string_lossy().trim_end_matches(['/', '\\'])
+            )),
+        };
         let grant = PermissionGrant {
             tool: permission.tool_name.clone(),
             action_type: PermissionActionType::from(&permission.action),
-            pattern: Self::extract_pattern(&permission.action),
+            pattern,
         };
         self.grants.insert(grant);
     }
 
+    /// MCP 서버의 모든 도구 허용 (`mcp.<server>.*`)
+    pub fn allow_mcp_server(&mut self, server: &str) {
+        self.add_grant(PermissionGrant {
+            tool: mcp_server_scope(server),
+            action_type: PermissionActionType::from(&mcp_action(server, "*")),
+            pattern: None,
+        });
+    }
+
     /// 거부 패턴 추가
     pub fn add_deny(&mut self, deny: PermissionDeny) {
         self.denies.insert(deny);
@@ -151,10 +324,11 @@ impl PermissionSettings {
         let pattern = Self::extract_pattern(action);
 
         for grant in &self.grants {
-            if grant.tool == tool && grant.action_type == action_type {
+            let scoped = mcp_scope_covers(&grant.tool, action);
+            if scoped || (grant.tool == tool && grant.action_type == action_type) {
                 if let (Some(grant_pattern), Some(ref action_pattern)) = (&grant.pattern, &pattern)
                 {
-                    if Self::pattern_matches(grant_pattern, action_pattern) {
+                    if Self::action_matches(action, grant_pattern, action_pattern) {
                         return true;
                     }
                 } else if grant.pattern.is_none() {
@@ -171,9 +345,9 @@ impl PermissionSettings {
         let pattern = Self::extract_pattern(action);
 
         for deny in &self.denies {
-            if deny.tool == tool {
+            if deny.tool == tool || mcp_scope_covers(&deny.tool, action) {
                 if let Some(ref action_pattern) = pattern {
-                    if Self::pattern_matches(&deny.pattern, action_pattern) {
+                    if Self::action_matches(action, &deny.pattern, action_pattern) {
                         return true;
                     }
                 }
@@ -202,11 +376,11 @@ impl PermissionSettings {
             .iter()
             .filter_map(|grant| {
                 let action = Self::grant_to_action(grant)?;
-                Some(Permission {
-                    tool_name: grant.tool.clone(),
+                Some(Permission::new(
+                    grant.tool.clone(),
                     action,
-                    scope: PermissionScope::Permanent,
-                })
+                    PermissionScope::Permanent,
+                ))
             })
             .collect()
     }
@@ -239,6 +413,14 @@ impl PermissionSettings {
         })
     }
 
+    /// Network 액션은 도메인 규칙, 나머지는 경로/명령어 패턴으로 비교
+    fn action_matches(action: &PermissionAction, pattern: &str, value: &str) -> bool {
+        match action {
+            PermissionAction::Network { .. } => domain_matches(pattern, value),
+            _ => Self::pattern_matches(pattern, value),
+        }
+    }
+
     fn pattern_matches(pattern: &str, value: &str) -> bool {
         if pattern == "**" || pattern == "*" {
             return true;
@@ -255,6 +437,42 @@ impl PermissionSettings {
     }
 }
 
+/// 도메인 패턴 매칭
+///
+/// - `*` : 모든 도메인
+/// - `*.example.com` : example.com 및 모든 하위 도메인
+/// - `example.com` : 정확히 일치 (대소문자 무시)
+///
+/// 값이 URL이면 호스트만 비교합니다.
+pub fn domain_matches(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.trim().to_ascii_lowercase();
+    let host = host_of(value).to_ascii_lowercase();
+
+    if pattern == "*" || pattern == "**" {
+        return true;
+    }
+    if let Some(base) = pattern.strip_prefix("*.") {
+        return host == base || host.ends_with(&format!(".{}", base));
+    }
+    host == pattern
+}
+
+/// URL 또는 호스트 문자열에서 호스트 부분 추출
+pub(super) fn host_of(value: &str) -> &str {
+    let rest = value.split_once("://").map(|(_, r)| r).unwrap_or(value);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    let authority = authority.rsplit_once('@').map(|(_, h)| h).unwrap_or(authority);
+
+    if authority.starts_with('[') {
+        // IPv6 리터럴: [::1]:8080
+        return authority
+            .split_once(']')
+            .map(|(h, _)| &h[1..])
+            .unwrap_or(authority);
+    }
+    authority.split(':').next().unwrap_or(authority)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,4 +525,120 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_network_domain_rules() {
+        let mut settings = PermissionSettings::new();
+
+        settings.add_grant(PermissionGrant {
+            tool: "http_request".to_string(),
+            action_type: PermissionActionType::Network,
+            pattern: Some("*.github.com".to_string()),
+        });
+        settings.add_deny(PermissionDeny {
+            tool: "http_request".to_string(),
+            pattern: "evil.example".to_string(),
+            reason: None,
+        });
+
+        let network = |url: &str| PermissionAction::Network {
+            url: url.to_string(),
+        };
+
+        assert!(settings.is_granted("http_request", &network("api.github.com")));
+        assert!(settings.is_granted("http_request", &network("https://github.com/x")));
+        assert!(!settings.is_granted("http_request", &network("github.com.evil.example")));
+        assert!(settings.is_denied("http_request", &network("https://EVIL.example:8443/path")));
+        assert!(!settings.is_denied("http_request", &network("sub.evil.example")));
+    }
+
+    #[test]
+    fn test_mcp_server_scope() {
+        let mut settings = PermissionSettings::new();
+        settings.allow_mcp_server("github");
+        settings.add_deny(PermissionDeny {
+            tool: "mcp.github.delete_repo".to_string(),
+            pattern: "*".to_string(),
+            reason: None,
+        });
+
+        let github = mcp_action("github", "create_issue");
+        assert!(settings.is_granted("mcp_github_create_issue", &github));
+        assert!(!settings.is_granted("mcp_notion_read", &mcp_action("notion", "read")));
+
+        let delete = mcp_action("github", "delete_repo");
+        assert!(settings.is_denied("mcp_github_delete_repo", &delete));
+        assert!(!settings.is_denied("mcp_github_create_issue", &github));
+
+        // JSON으로 저장/로드해도 유지
+        let json = serde_json::to_string(&settings).unwrap();
+        let loaded: PermissionSettings = serde_json::from_str(&json).unwrap();
+        assert!(loaded.is_granted("mcp_github_list_issues", &mcp_action("github", "list_issues")));
+    }
+
+    #[test]
+    fn test_layered_load() {
+        let root = std::env::temp_dir().join(format!("forge-perm-layers-{}", std::process::id()));
+        let store = JsonStore::project(&root);
+        let user = root.join("user.json");
+        let managed = root.join("managed.json");
+        std::fs::create_dir_all(store.base_dir()).unwrap();
+
+        let grant_json = |tool: &str| {
+            serde_json::json!({ "grants": [{ "tool": tool, "actionType": "execute" }] }).to_string()
+        };
+        std::fs::write(&user, grant_json("user_tool")).unwrap();
+        std::fs::write(
+            store.file_path(PERMISSIONS_FILE),
+            grant_json("project_tool"),
+        )
+        .unwrap();
+        std::fs::write(
+            store.file_path(LOCAL_PERMISSIONS_FILE),
+            r#"{ "denies": [{ "tool": "bash", "pattern": "git push" }] }"#,
+        )
+        .unwrap();
+
+        let mut paths = PermissionPaths::for_project(&root);
+        paths.user = Some(user);
+        paths.managed = Some(managed.clone());
+
+        let settings = PermissionSettings::load_from(&paths);
+        let ls = PermissionAction::Execute {
+            command: "ls".to_string(),
+        };
+        assert!(settings.is_granted("user_tool", &ls));
+        assert!(settings.is_granted("project_tool", &ls));
+        assert!(settings.is_denied(
+            "bash",
+            &PermissionAction::Execute {
+                command: "git push".to_string()
+            }
+        ));
+
+        // 관리 정책: 없으면 None, 잘못된 파일은 에러
+        assert!(ManagedPolicy::load_from(&managed).unwrap().is_none());
+        std::fs::write(&managed, r#"{ "disableAutoApprove": true, "denies": [] }"#).unwrap();
+        assert!(
+            ManagedPolicy::load_from(&managed)
+                .unwrap()
+                .unwrap()
+                .disable_auto_approve
+        );
+        std::fs::write(&managed, "{ not json").unwrap();
+        assert!(ManagedPolicy::load_from(&managed).is_err());
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_domain_matches() {
+        assert!(domain_matches("*", "anything.io"));
+        assert!(domain_matches(
+            "localhost",
+            "http://user@localhost:3000/api"
+        ));
+        assert!(domain_matches("::1", "http://[::1]:8080/"));
+        assert!(!domain_matches("*.example.com", "notexample.com"));
+    }
 }
@@ -89,6 +89,18 @@ impl PermissionRegistry {
         }
     }
 
+    /// 이름이 `prefix`로 시작하는 권한 제거 (제거된 개수 반환)
+    pub fn unregister_prefix(&self, prefix: &str) -> usize {
+        self.definitions
+            .write()
+            .map(|mut defs| {
+                let before = defs.len();
+                defs.retain(|name, _| !name.starts_with(prefix));
+                before - defs.len()
+            })
+            .unwrap_or(0)
+    }
+
     /// 권한 조회
     pub fn get(&self, name: &str) -> Option<PermissionDef> {
         self.definitions.read().ok()?.get(name).cloned()
@@ -1,3 +1,5 @@
 mod server;
 
 pub use server::{McpConfig, McpConfigFile, McpServer, McpTransport, MCP_FILE};
+
+pub(crate) use server::expand_env_var;
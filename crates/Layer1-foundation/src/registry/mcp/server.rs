@@ -1,5 +1,6 @@
 use crate::storage::JsonStore;
 use crate::Result;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -8,7 +9,7 @@ use std::path::PathBuf;
 pub const MCP_FILE: &str = "mcp.json";
 
 /// MCP 서버 타입 (전송 방식)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum McpTransport {
     /// 로컬 프로세스 (stdin/stdout)
@@ -33,7 +34,7 @@ impl Default for McpTransport {
 ///   "env": { "KEY": "value" }
 /// }
 /// ```
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct McpServer {
     /// 전송 타입 (기본: stdio)
     #[serde(rename = "type", default)]
@@ -178,7 +179,7 @@ impl McpServer {
 ///   }
 /// }
 /// ```
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct McpConfig {
     /// MCP 서버들 (이름 -> 설정)
     #[serde(default)]
@@ -336,7 +337,7 @@ impl McpConfig {
 ///   }
 /// }
 /// ```
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct McpConfigFile {
     #[serde(default, rename = "mcpServers")]
     pub mcp_servers: McpConfig,
@@ -345,7 +346,7 @@ pub struct McpConfigFile {
 /// 환경변수 확장
 /// - ${VAR}: 환경변수 값
 /// - ${VAR:-default}: 환경변수가 없으면 기본값
-fn expand_env_var(value: &str) -> String {
+pub(crate) fn expand_env_var(value: &str) -> String {
     let mut result = value.to_string();
 
     // ${VAR:-default} 패턴
@@ -0,0 +1,316 @@
+//! Storage Backend - 런타임 데이터 저장소 추상화
+//!
+//! 세션, 메시지, 토큰 사용량, 도구 실행, 감사 기록을 저장하는 백엔드 trait입니다.
+//! - `Storage`: 로컬 SQLite (기본)
+//! - `PostgresStorage`: 여러 머신이 공유하는 Postgres (`storage.backend = "postgres"`,
+//!   `postgres` 기능으로 빌드한 경우)
+//!
+//! 예약 작업(`scheduled_tasks`)은 해당 머신의 스케줄러만 쓰는 데이터이므로
+//! trait에 포함하지 않고 항상 로컬 SQLite(`Storage`)에 저장합니다.
+
+use super::db::{
+    MessageRecord, ModelUsageStats, SessionRecord, Storage, TokenUsageRecord, ToolExecutionRecord,
+    ToolMetrics, ToolUsageStats, UsageSummary,
+};
+#[cfg(feature = "postgres")]
+use super::postgres::PostgresStorage;
+use crate::audit::AuditEntry;
+use crate::config::StorageConfig;
+use crate::{Error, Result};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// 런타임 데이터 저장소 백엔드
+///
+/// 시간 범위 인자(`since`, `until`)는 RFC3339 문자열이며 `[since, until)` 구간입니다.
+pub trait StorageBackend: Send + Sync {
+    // ========================================================================
+    // Sessions
+    // ========================================================================
+
+    /// Create a new session
+    fn create_session(&self, session: &SessionRecord) -> Result<()>;
+
+    /// Update session metadata
+    fn update_session(&self, session: &SessionRecord) -> Result<()>;
+
+    /// Get a session by ID
+    fn get_session(&self, id: &str) -> Result<Option<SessionRecord>>;
+
+    /// Get all sessions (most recently updated first), optionally limited
+    fn get_sessions(&self, limit: Option<u32>) -> Result<Vec<SessionRecord>>;
+
+    /// Delete a session and all related data
+    fn delete_session(&self, id: &str) -> Result<()>;
+
+    // ========================================================================
+    // Messages
+    // ========================================================================
+
+    /// Save a message (and update session stats)
+    fn save_message(&self, message: &MessageRecord) -> Result<()>;
+
+    /// Get messages for a session
+    fn get_messages(&self, session_id: &str) -> Result<Vec<MessageRecord>>;
+
+    /// Get recent messages (oldest first)
+    fn get_recent_messages(&self, session_id: &str, limit: u32) -> Result<Vec<MessageRecord>>;
+
+    // ========================================================================
+    // Token Usage
+    // ========================================================================
+
+    /// Record token usage
+    fn record_usage(&self, usage: &TokenUsageRecord) -> Result<()>;
+
+    /// Get usage summary
+    fn get_usage_summary(&self, since: Option<&str>) -> Result<UsageSummary>;
+
+    /// Get token usage history (most recent first)
+    fn get_token_usage_history(&self, limit: u32) -> Result<Vec<TokenUsageRecord>>;
+
+    /// Get usage by provider/model
+    fn get_usage_by_provider(&self) -> Result<Vec<(String, String, UsageSummary)>>;
+
+    // ========================================================================
+    // Tool Executions
+    // ========================================================================
+
+    /// Record tool execution start
+    fn start_tool_execution(&self, execution: &ToolExecutionRecord) -> Result<i64>;
+
+    /// Complete tool execution
+    fn complete_tool_execution(
+        &self,
+        id: i64,
+        output: Option<&str>,
+        status: &str,
+        error: Option<&str>,
+        duration_ms: Option<i64>,
+    ) -> Result<()>;
+
+    /// Record a finished tool execution in one step
+    fn record_tool_execution(&self, execution: &ToolExecutionRecord) -> Result<i64>;
+
+    /// Get tool executions for a session
+    fn get_tool_executions(&self, session_id: &str) -> Result<Vec<ToolExecutionRecord>>;
+
+    /// Get recent tool executions across all sessions
+    fn get_recent_tool_executions(&self, limit: u32) -> Result<Vec<ToolExecutionRecord>>;
+
+    /// Get failed tool executions
+    fn get_failed_tool_executions(&self, limit: u32) -> Result<Vec<ToolExecutionRecord>>;
+
+    // ========================================================================
+    // Usage Statistics (date range)
+    // ========================================================================
+
+    /// Get token usage grouped by provider/model within a date range
+    fn get_model_usage_between(&self, since: &str, until: &str) -> Result<Vec<ModelUsageStats>>;
+
+    /// Get the most used tools within a date range
+    fn get_tool_usage_between(
+        &self,
+        since: &str,
+        until: &str,
+        limit: u32,
+    ) -> Result<Vec<ToolUsageStats>>;
+
+    /// Get per-tool success metrics within a date range
+    fn get_tool_metrics_between(&self, since: &str, until: &str) -> Result<Vec<ToolMetrics>>;
+
+    /// Count sessions that recorded token usage within a date range
+    fn count_active_sessions_between(&self, since: &str, until: &str) -> Result<i64>;
+
+    // ========================================================================
+    // Audit
+    // ========================================================================
+
+    /// 감사 기록 복제
+    ///
+    /// 감사 로그 원본은 `AuditLogger`의 로컬 DB에 있으므로 기본 구현은 아무것도
+    /// 하지 않습니다. 공유 백엔드는 팀 단위 분석을 위해 복사본을 저장합니다.
+    fn record_audit_entry(&self, _entry: &AuditEntry) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// 설정에 따라 저장소 백엔드 열기
+///
+/// `storage.backend`가 `postgres`면 `url`로 연결하고, 설정이 없거나 `sqlite`면
+/// `data_dir`의 `forgecode.db`를 엽니다.
+pub fn open_backend(
+    config: Option<&StorageConfig>,
+    data_dir: &PathBuf,
+) -> Result<Arc<dyn StorageBackend>> {
+    let Some(config) = config.filter(|c| !c.is_local()) else {
+        return Ok(Arc::new(Storage::new(data_dir)?));
+    };
+    match config.backend.trim().to_lowercase().as_str() {
+        "postgres" | "postgresql" => {
+            let url = config.resolved_url().ok_or_else(|| {
+                Error::Config("storage.url is required for the postgres backend".to_string())
+            })?;
+            connect_postgres(url)
+        }
+        other => Err(Error::Config(format!(
+            "Unknown storage backend '{}' (expected sqlite or postgres)",
+            other
+        ))),
+    }
+}
+
+/// Postgres 백엔드 연결
+#[cfg(feature = "postgres")]
+fn connect_postgres(url: String) -> Result<Arc<dyn StorageBackend>> {
+    Ok(Arc::new(PostgresStorage::connect(url)?))
+}
+
+/// `postgres` 기능 없이 빌드된 경우
+#[cfg(not(feature = "postgres"))]
+fn connect_postgres(_url: String) -> Result<Arc<dyn StorageBackend>> {
+    Err(Error::Config(
+        "The postgres storage backend is not available - rebuild with the `postgres` feature"
+            .to_string(),
+    ))
+}
+
+impl StorageBackend for Storage {
+    fn create_session(&self, session: &SessionRecord) -> Result<()> {
+        Storage::create_session(self, session)
+    }
+
+    fn update_session(&self, session: &SessionRecord) -> Result<()> {
+        Storage::update_session(self, session)
+    }
+
+    fn get_session(&self, id: &str) -> Result<Option<SessionRecord>> {
+        Storage::get_session(self, id)
+    }
+
+    fn get_sessions(&self, limit: Option<u32>) -> Result<Vec<SessionRecord>> {
+        Storage::get_sessions(self, limit)
+    }
+
+    fn delete_session(&self, id: &str) -> Result<()> {
+        Storage::delete_session(self, id)
+    }
+
+    fn save_message(&self, message: &MessageRecord) -> Result<()> {
+        Storage::save_message(self, message)
+    }
+
+    fn get_messages(&self, session_id: &str) -> Result<Vec<MessageRecord>> {
+        Storage::get_messages(self, session_id)
+    }
+
+    fn get_recent_messages(&self, session_id: &str, limit: u32) -> Result<Vec<MessageRecord>> {
+        Storage::get_recent_messages(self, session_id, limit)
+    }
+
+    fn record_usage(&self, usage: &TokenUsageRecord) -> Result<()> {
+        Storage::record_usage(self, usage)
+    }
+
+    fn get_usage_summary(&self, since: Option<&str>) -> Result<UsageSummary> {
+        Storage::get_usage_summary(self, since)
+    }
+
+    fn get_token_usage_history(&self, limit: u32) -> Result<Vec<TokenUsageRecord>> {
+        Storage::get_token_usage_history(self, limit)
+    }
+
+    fn get_usage_by_provider(&self) -> Result<Vec<(String, String, UsageSummary)>> {
+        Storage::get_usage_by_provider(self)
+    }
+
+    fn start_tool_execution(&self, execution: &ToolExecutionRecord) -> Result<i64> {
+        Storage::start_tool_execution(self, execution)
+    }
+
+    fn complete_tool_execution(
+        &self,
+        id: i64,
+        output: Option<&str>,
+        status: &str,
+        error: Option<&str>,
+        duration_ms: Option<i64>,
+    ) -> Result<()> {
+        Storage::complete_tool_execution(self, id, output, status, error, duration_ms)
+    }
+
+    fn record_tool_execution(&self, execution: &ToolExecutionRecord) -> Result<i64> {
+        Storage::record_tool_execution(self, execution)
+    }
+
+    fn get_tool_executions(&self, session_id: &str) -> Result<Vec<ToolExecutionRecord>> {
+        Storage::get_tool_executions(self, session_id)
+    }
+
+    fn get_recent_tool_executions(&self, limit: u32) -> Result<Vec<ToolExecutionRecord>> {
+        Storage::get_recent_tool_executions(self, limit)
+    }
+
+    fn get_failed_tool_executions(&self, limit: u32) -> Result<Vec<ToolExecutionRecord>> {
+        Storage::get_failed_tool_executions(self, limit)
+    }
+
+    fn get_model_usage_between(&self, since: &str, until: &str) -> Result<Vec<ModelUsageStats>> {
+        Storage::get_model_usage_between(self, since, until)
+    }
+
+    fn get_tool_usage_between(
+        &self,
+        since: &str,
+        until: &str,
+        limit: u32,
+    ) -> Result<Vec<ToolUsageStats>> {
+        Storage::get_tool_usage_between(self, since, until, limit)
+    }
+
+    fn get_tool_metrics_between(&self, since: &str, until: &str) -> Result<Vec<ToolMetrics>> {
+        Storage::get_tool_metrics_between(self, since, until)
+    }
+
+    fn count_active_sessions_between(&self, since: &str, until: &str) -> Result<i64> {
+        Storage::count_active_sessions_between(self, since, until)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_backend() {
+        let data_dir =
+            std::env::temp_dir().join(format!("forge-storage-backend-{}", std::process::id()));
+
+        let backend = open_backend(None, &data_dir).unwrap();
+        backend
+            .create_session(&SessionRecord {
+                id: "s1".to_string(),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(backend.get_session("s1").unwrap().is_some());
+        assert!(data_dir.join("forgecode.db").exists());
+
+        let missing_url = StorageConfig {
+            backend: "postgres".to_string(),
+            url: None,
+        };
+        assert!(matches!(
+            open_backend(Some(&missing_url), &data_dir),
+            Err(Error::Config(_))
+        ));
+
+        let unknown = StorageConfig {
+            backend: "mysql".to_string(),
+            url: None,
+        };
+        assert!(open_backend(Some(&unknown), &data_dir).is_err());
+
+        std::fs::remove_dir_all(&data_dir).ok();
+    }
+}
@@ -5,6 +5,7 @@
 //! - Messages: 메시지 기록
 //! - Token Usage: 토큰 사용량 추적
 //! - Tool Executions: 도구 실행 로그
+//! - Schedules: 예약 작업 (cron) 및 실행 기록
 //!
 //! 설정 데이터는 JSON (storage/json/)에서 관리
 //!
@@ -13,6 +14,9 @@
 //! Database schema is versioned. Migrations run automatically on startup.
 //! - Version 1: Initial schema (sessions, messages, token_usage, tool_executions)
 //! - Version 2: Add context_tokens and thinking_tokens columns
+//! - Version 3: Add latency_ms column to token_usage
+//! - Version 4: Add output_bytes and retries columns to tool_executions
+//! - Version 5: Add scheduled_tasks and schedule_runs tables
 
 use crate::{Error, Result};
 use rusqlite::{params, Connection, OptionalExtension};
@@ -22,9 +26,12 @@ use std::sync::{Arc, Mutex};
 use tracing::{debug, info, warn};
 
 /// Current schema version
-const CURRENT_SCHEMA_VERSION: i32 = 2;
+const CURRENT_SCHEMA_VERSION: i32 = 5;
 
 /// Storage service for persisting runtime data
+///
+/// 복제본은 같은 연결을 공유합니다.
+#[derive(Clone)]
 pub struct Storage {
     conn: Arc<Mutex<Connection>>,
 }
@@ -209,6 +216,9 @@ impl Storage {
         for version in (current_version + 1)..=CURRENT_SCHEMA_VERSION {
             match version {
                 2 => self.migrate_v2(&conn)?,
+                3 => self.migrate_v3(&conn)?,
+                4 => self.migrate_v4(&conn)?,
+                5 => self.migrate_v5(&conn)?,
                 _ => {
                     warn!("Unknown migration version: {}", version);
                 }
@@ -247,6 +257,67 @@ impl Storage {
         Ok(())
     }
 
+    /// Migration to version 3: Add per-request latency tracking
+    fn migrate_v3(&self, conn: &Connection) -> Result<()> {
+        let _ = conn.execute("ALTER TABLE token_usage ADD COLUMN latency_ms INTEGER", []);
+
+        Ok(())
+    }
+
+    /// Migration to version 4: Add per-tool output size and retry tracking
+    fn migrate_v4(&self, conn: &Connection) -> Result<()> {
+        let _ = conn.execute(
+            "ALTER TABLE tool_executions ADD COLUMN output_bytes INTEGER",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE tool_executions ADD COLUMN retries INTEGER DEFAULT 0",
+            [],
+        );
+        let _ = conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_tool_executions_tool ON tool_executions(tool_name, created_at)",
+            [],
+        );
+
+        Ok(())
+    }
+
+    /// Migration to version 5: Add recurring task schedules and their run history
+    fn migrate_v5(&self, conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS scheduled_tasks (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL UNIQUE,
+                cron TEXT NOT NULL,
+                prompt TEXT NOT NULL,
+                working_directory TEXT,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL,
+                last_run_at TEXT,
+                next_run_at TEXT
+            );
+
+            CREATE TABLE IF NOT EXISTS schedule_runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                schedule_id TEXT NOT NULL,
+                session_id TEXT,
+                status TEXT NOT NULL,
+                output_text TEXT,
+                error_message TEXT,
+                started_at TEXT NOT NULL,
+                completed_at TEXT
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_schedule_runs_schedule
+                ON schedule_runs(schedule_id, started_at);
+            "#,
+        )
+        .map_err(|e| Error::Storage(format!("Failed to create schedule tables: {}", e)))?;
+
+        Ok(())
+    }
+
     // ========================================================================
     // Session Operations
     // ========================================================================
@@ -574,8 +645,9 @@ impl Storage {
         conn.execute(
             r#"
             INSERT INTO token_usage (session_id, provider, model, input_tokens, output_tokens,
-                                     cache_read_tokens, cache_write_tokens, thinking_tokens, cost_cents, recorded_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+                                     cache_read_tokens, cache_write_tokens, thinking_tokens, cost_cents,
+                                     latency_ms, recorded_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
             "#,
             params![
                 usage.session_id,
@@ -587,6 +659,7 @@ impl Storage {
                 usage.cache_write_tokens,
                 usage.thinking_tokens,
                 usage.cost_cents,
+                usage.latency_ms,
                 now,
             ],
         )
@@ -699,16 +772,64 @@ impl Storage {
                 status = ?3,
                 error_message = ?4,
                 duration_ms = ?5,
-                completed_at = ?6
+                completed_at = ?6,
+                output_bytes = ?7
             WHERE id = ?1
             "#,
-            params![id, output, status, error, duration_ms, now],
+            params![
+                id,
+                output,
+                status,
+                error,
+                duration_ms,
+                now,
+                output.map(|o| o.len() as i64)
+            ],
         )
         .map_err(|e| Error::Storage(format!("Failed to complete tool execution: {}", e)))?;
 
         Ok(())
     }
 
+    /// Record a finished tool execution in one step
+    ///
+    /// `created_at`/`completed_at`이 없으면 현재 시각을 사용합니다.
+    pub fn record_tool_execution(&self, execution: &ToolExecutionRecord) -> Result<i64> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+        let now = chrono::Utc::now().to_rfc3339();
+
+        conn.execute(
+            r#"
+            INSERT INTO tool_executions (session_id, message_id, tool_name, tool_call_id,
+                                         input_json, output_text, status, error_message,
+                                         duration_ms, output_bytes, retries,
+                                         created_at, completed_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+            "#,
+            params![
+                execution.session_id,
+                execution.message_id,
+                execution.tool_name,
+                execution.tool_call_id,
+                execution.input_json,
+                execution.output_text,
+                execution.status,
+                execution.error_message,
+                execution.duration_ms,
+                execution.output_bytes,
+                execution.retries,
+                execution.created_at.as_deref().unwrap_or(&now),
+                execution.completed_at.as_deref().unwrap_or(&now),
+            ],
+        )
+        .map_err(|e| Error::Storage(format!("Failed to record tool execution: {}", e)))?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
     /// Get tool executions for a session
     pub fn get_tool_executions(&self, session_id: &str) -> Result<Vec<ToolExecutionRecord>> {
         let conn = self
@@ -720,7 +841,8 @@ impl Storage {
             .prepare(
                 r#"
                 SELECT id, session_id, message_id, tool_name, tool_call_id, input_json,
-                       output_text, status, error_message, duration_ms, created_at, completed_at
+                       output_text, status, error_message, duration_ms, output_bytes,
+                       COALESCE(retries, 0), created_at, completed_at
                 FROM tool_executions
                 WHERE session_id = ?1
                 ORDER BY created_at DESC
@@ -741,8 +863,10 @@ impl Storage {
                     status: row.get(7)?,
                     error_message: row.get(8)?,
                     duration_ms: row.get(9)?,
-                    created_at: row.get(10)?,
-                    completed_at: row.get(11)?,
+                    output_bytes: row.get(10)?,
+                    retries: row.get(11)?,
+                    created_at: row.get(12)?,
+                    completed_at: row.get(13)?,
                 })
             })
             .map_err(|e| Error::Storage(format!("Failed to query tool executions: {}", e)))?
@@ -763,7 +887,8 @@ impl Storage {
             .prepare(
                 r#"
                 SELECT id, session_id, message_id, tool_name, tool_call_id, input_json,
-                       output_text, status, error_message, duration_ms, created_at, completed_at
+                       output_text, status, error_message, duration_ms, output_bytes,
+                       COALESCE(retries, 0), created_at, completed_at
                 FROM tool_executions
                 ORDER BY created_at DESC
                 LIMIT ?1
@@ -784,8 +909,10 @@ impl Storage {
                     status: row.get(7)?,
                     error_message: row.get(8)?,
                     duration_ms: row.get(9)?,
-                    created_at: row.get(10)?,
-                    completed_at: row.get(11)?,
+                    output_bytes: row.get(10)?,
+                    retries: row.get(11)?,
+                    created_at: row.get(12)?,
+                    completed_at: row.get(13)?,
                 })
             })
             .map_err(|e| Error::Storage(format!("Failed to query tool executions: {}", e)))?
@@ -806,7 +933,8 @@ impl Storage {
             .prepare(
                 r#"
                 SELECT id, session_id, message_id, tool_name, tool_call_id, input_json,
-                       output_text, status, error_message, duration_ms, created_at, completed_at
+                       output_text, status, error_message, duration_ms, output_bytes,
+                       COALESCE(retries, 0), created_at, completed_at
                 FROM tool_executions
                 WHERE status IN ('error', 'timeout', 'cancelled')
                 ORDER BY created_at DESC
@@ -828,8 +956,10 @@ impl Storage {
                     status: row.get(7)?,
                     error_message: row.get(8)?,
                     duration_ms: row.get(9)?,
-                    created_at: row.get(10)?,
-                    completed_at: row.get(11)?,
+                    output_bytes: row.get(10)?,
+                    retries: row.get(11)?,
+                    created_at: row.get(12)?,
+                    completed_at: row.get(13)?,
                 })
             })
             .map_err(|e| Error::Storage(format!("Failed to query failed executions: {}", e)))?
@@ -850,7 +980,8 @@ impl Storage {
             .prepare(
                 r#"
                 SELECT session_id, provider, model, input_tokens, output_tokens,
-                       cache_read_tokens, cache_write_tokens, COALESCE(thinking_tokens, 0), cost_cents
+                       cache_read_tokens, cache_write_tokens, COALESCE(thinking_tokens, 0), cost_cents,
+                       latency_ms
                 FROM token_usage
                 ORDER BY recorded_at DESC
                 LIMIT ?1
@@ -870,6 +1001,7 @@ impl Storage {
                     cache_write_tokens: row.get(6)?,
                     thinking_tokens: row.get(7)?,
                     cost_cents: row.get(8)?,
+                    latency_ms: row.get(9)?,
                 })
             })
             .map_err(|e| Error::Storage(format!("Failed to query token usage: {}", e)))?
@@ -918,6 +1050,367 @@ impl Storage {
 
         Ok(results)
     }
+
+    // ========================================================================
+    // Usage Statistics (date range)
+    // ========================================================================
+    //
+    // 범위는 RFC3339 문자열 `[since, until)` - 기록 시각이 모두 UTC RFC3339이므로
+    // 문자열 비교로 충분
+
+    /// Get token usage grouped by provider/model within a date range
+    pub fn get_model_usage_between(&self, since: &str, until: &str) -> Result<Vec<ModelUsageStats>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT provider, model,
+                       COALESCE(SUM(input_tokens), 0), COALESCE(SUM(output_tokens), 0),
+                       COALESCE(SUM(cache_read_tokens), 0), COALESCE(SUM(cache_write_tokens), 0),
+                       COALESCE(SUM(cost_cents), 0), COUNT(*),
+                       AVG(latency_ms), COUNT(latency_ms)
+                FROM token_usage
+                WHERE recorded_at >= ?1 AND recorded_at < ?2
+                GROUP BY provider, model
+                ORDER BY SUM(input_tokens + output_tokens) DESC
+                "#,
+            )
+            .map_err(|e| Error::Storage(format!("Failed to prepare query: {}", e)))?;
+
+        let results = stmt
+            .query_map(params![since, until], |row| {
+                Ok(ModelUsageStats {
+                    provider: row.get(0)?,
+                    model: row.get(1)?,
+                    input_tokens: row.get(2)?,
+                    output_tokens: row.get(3)?,
+                    cache_read_tokens: row.get(4)?,
+                    cache_write_tokens: row.get(5)?,
+                    cost_cents: row.get(6)?,
+                    request_count: row.get(7)?,
+                    avg_latency_ms: row.get(8)?,
+                    latency_samples: row.get(9)?,
+                })
+            })
+            .map_err(|e| Error::Storage(format!("Failed to query model usage: {}", e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Get the most used tools within a date range
+    pub fn get_tool_usage_between(
+        &self,
+        since: &str,
+        until: &str,
+        limit: u32,
+    ) -> Result<Vec<ToolUsageStats>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT tool_name, COUNT(*),
+                       SUM(CASE WHEN status IN ('error', 'timeout', 'cancelled') THEN 1 ELSE 0 END),
+                       AVG(duration_ms)
+                FROM tool_executions
+                WHERE created_at >= ?1 AND created_at < ?2
+                GROUP BY tool_name
+                ORDER BY COUNT(*) DESC, tool_name ASC
+                LIMIT ?3
+                "#,
+            )
+            .map_err(|e| Error::Storage(format!("Failed to prepare query: {}", e)))?;
+
+        let results = stmt
+            .query_map(params![since, until, limit], |row| {
+                Ok(ToolUsageStats {
+                    tool_name: row.get(0)?,
+                    call_count: row.get(1)?,
+                    error_count: row.get(2)?,
+                    avg_duration_ms: row.get(3)?,
+                })
+            })
+            .map_err(|e| Error::Storage(format!("Failed to query tool usage: {}", e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Get per-tool success metrics within a date range
+    ///
+    /// 실패가 많은 도구부터 정렬합니다.
+    pub fn get_tool_metrics_between(&self, since: &str, until: &str) -> Result<Vec<ToolMetrics>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT t.tool_name, COUNT(*),
+                       SUM(CASE WHEN t.status = 'success' THEN 1 ELSE 0 END),
+                       SUM(CASE WHEN t.status IN ('error', 'cancelled') THEN 1 ELSE 0 END),
+                       SUM(CASE WHEN t.status = 'timeout' THEN 1 ELSE 0 END),
+                       COALESCE(SUM(t.retries), 0),
+                       AVG(t.duration_ms), MAX(t.duration_ms), AVG(t.output_bytes),
+                       (SELECT e.error_message FROM tool_executions e
+                        WHERE e.tool_name = t.tool_name
+                          AND e.status IN ('error', 'timeout', 'cancelled')
+                          AND e.error_message IS NOT NULL
+                          AND e.created_at >= ?1 AND e.created_at < ?2
+                        ORDER BY e.created_at DESC LIMIT 1)
+                FROM tool_executions t
+                WHERE t.created_at >= ?1 AND t.created_at < ?2
+                GROUP BY t.tool_name
+                ORDER BY SUM(CASE WHEN t.status = 'success' THEN 0 ELSE 1 END) DESC,
+                         COUNT(*) DESC, t.tool_name ASC
+                "#,
+            )
+            .map_err(|e| Error::Storage(format!("Failed to prepare query: {}", e)))?;
+
+        let results = stmt
+            .query_map(params![since, until], |row| {
+                Ok(ToolMetrics {
+                    tool_name: row.get(0)?,
+                    call_count: row.get(1)?,
+                    success_count: row.get(2)?,
+                    error_count: row.get(3)?,
+                    timeout_count: row.get(4)?,
+                    retry_count: row.get(5)?,
+                    avg_duration_ms: row.get(6)?,
+                    max_duration_ms: row.get(7)?,
+                    avg_output_bytes: row.get(8)?,
+                    last_error: row.get(9)?,
+                })
+            })
+            .map_err(|e| Error::Storage(format!("Failed to query tool metrics: {}", e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Count sessions that recorded token usage within a date range
+    pub fn count_active_sessions_between(&self, since: &str, until: &str) -> Result<i64> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        conn.query_row(
+            r#"
+            SELECT COUNT(DISTINCT session_id)
+            FROM token_usage
+            WHERE recorded_at >= ?1 AND recorded_at < ?2
+            "#,
+            params![since, until],
+            |row| row.get(0),
+        )
+        .map_err(|e| Error::Storage(format!("Failed to count sessions: {}", e)))
+    }
+
+    // ========================================================================
+    // Schedule Operations
+    // ========================================================================
+
+    /// Create or update a scheduled task
+    pub fn save_scheduled_task(&self, task: &ScheduledTaskRecord) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        conn.execute(
+            r#"
+            INSERT INTO scheduled_tasks (id, name, cron, prompt, working_directory, enabled,
+                                         created_at, last_run_at, next_run_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                cron = excluded.cron,
+                prompt = excluded.prompt,
+                working_directory = excluded.working_directory,
+                enabled = excluded.enabled,
+                last_run_at = excluded.last_run_at,
+                next_run_at = excluded.next_run_at
+            "#,
+            params![
+                task.id,
+                task.name,
+                task.cron,
+                task.prompt,
+                task.working_directory,
+                task.enabled,
+                task.created_at,
+                task.last_run_at,
+                task.next_run_at,
+            ],
+        )
+        .map_err(|e| Error::Storage(format!("Failed to save scheduled task: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Get a scheduled task by ID or name
+    pub fn get_scheduled_task(&self, id_or_name: &str) -> Result<Option<ScheduledTaskRecord>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        conn.query_row(
+            r#"
+            SELECT id, name, cron, prompt, working_directory, enabled,
+                   created_at, last_run_at, next_run_at
+            FROM scheduled_tasks WHERE id = ?1 OR name = ?1
+            "#,
+            params![id_or_name],
+            Self::scheduled_task_from_row,
+        )
+        .optional()
+        .map_err(|e| Error::Storage(format!("Failed to get scheduled task: {}", e)))
+    }
+
+    /// List all scheduled tasks (by name)
+    pub fn get_scheduled_tasks(&self) -> Result<Vec<ScheduledTaskRecord>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT id, name, cron, prompt, working_directory, enabled,
+                       created_at, last_run_at, next_run_at
+                FROM scheduled_tasks ORDER BY name
+                "#,
+            )
+            .map_err(|e| Error::Storage(format!("Failed to prepare query: {}", e)))?;
+
+        let tasks = stmt
+            .query_map([], Self::scheduled_task_from_row)
+            .map_err(|e| Error::Storage(format!("Failed to query scheduled tasks: {}", e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(tasks)
+    }
+
+    /// Delete a scheduled task and its run history
+    pub fn delete_scheduled_task(&self, id: &str) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        conn.execute(
+            "DELETE FROM schedule_runs WHERE schedule_id = ?1",
+            params![id],
+        )
+        .map_err(|e| Error::Storage(format!("Failed to delete schedule runs: {}", e)))?;
+        conn.execute("DELETE FROM scheduled_tasks WHERE id = ?1", params![id])
+            .map_err(|e| Error::Storage(format!("Failed to delete scheduled task: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn scheduled_task_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<ScheduledTaskRecord> {
+        Ok(ScheduledTaskRecord {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            cron: row.get(2)?,
+            prompt: row.get(3)?,
+            working_directory: row.get(4)?,
+            enabled: row.get(5)?,
+            created_at: row.get(6)?,
+            last_run_at: row.get(7)?,
+            next_run_at: row.get(8)?,
+        })
+    }
+
+    /// Record a finished schedule run
+    pub fn record_schedule_run(&self, run: &ScheduleRunRecord) -> Result<i64> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        conn.execute(
+            r#"
+            INSERT INTO schedule_runs (schedule_id, session_id, status, output_text,
+                                       error_message, started_at, completed_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+            params![
+                run.schedule_id,
+                run.session_id,
+                run.status,
+                run.output_text,
+                run.error_message,
+                run.started_at,
+                run.completed_at,
+            ],
+        )
+        .map_err(|e| Error::Storage(format!("Failed to record schedule run: {}", e)))?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Get recent runs of a scheduled task (newest first)
+    pub fn get_schedule_runs(
+        &self,
+        schedule_id: &str,
+        limit: u32,
+    ) -> Result<Vec<ScheduleRunRecord>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|_| Error::Internal("Lock poisoned".to_string()))?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT id, schedule_id, session_id, status, output_text, error_message,
+                       started_at, completed_at
+                FROM schedule_runs
+                WHERE schedule_id = ?1
+                ORDER BY started_at DESC, id DESC
+                LIMIT ?2
+                "#,
+            )
+            .map_err(|e| Error::Storage(format!("Failed to prepare query: {}", e)))?;
+
+        let runs = stmt
+            .query_map(params![schedule_id, limit], |row| {
+                Ok(ScheduleRunRecord {
+                    id: row.get(0)?,
+                    schedule_id: row.get(1)?,
+                    session_id: row.get(2)?,
+                    status: row.get(3)?,
+                    output_text: row.get(4)?,
+                    error_message: row.get(5)?,
+                    started_at: row.get(6)?,
+                    completed_at: row.get(7)?,
+                })
+            })
+            .map_err(|e| Error::Storage(format!("Failed to query schedule runs: {}", e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(runs)
+    }
 }
 
 // ============================================================================
@@ -1009,6 +1502,8 @@ pub struct TokenUsageRecord {
     /// Thinking/reasoning tokens (for extended thinking models)
     pub thinking_tokens: i64,
     pub cost_cents: i64,
+    /// Time from request start to response (ms)
+    pub latency_ms: Option<i64>,
 }
 
 /// Usage summary
@@ -1020,6 +1515,65 @@ pub struct UsageSummary {
     pub request_count: i64,
 }
 
+/// Token usage aggregated per provider/model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelUsageStats {
+    pub provider: String,
+    pub model: String,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cache_read_tokens: i64,
+    pub cache_write_tokens: i64,
+    pub cost_cents: i64,
+    pub request_count: i64,
+    /// Average request latency (None if no latency was recorded)
+    pub avg_latency_ms: Option<f64>,
+    /// Number of requests with a recorded latency
+    pub latency_samples: i64,
+}
+
+/// Tool execution counts aggregated per tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolUsageStats {
+    pub tool_name: String,
+    pub call_count: i64,
+    pub error_count: i64,
+    pub avg_duration_ms: Option<f64>,
+}
+
+/// Per-tool success metrics (for tuning tool configs)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolMetrics {
+    pub tool_name: String,
+    pub call_count: i64,
+    pub success_count: i64,
+    /// Failed or cancelled executions
+    pub error_count: i64,
+    pub timeout_count: i64,
+    /// Recovery retries summed over all executions
+    pub retry_count: i64,
+    pub avg_duration_ms: Option<f64>,
+    pub max_duration_ms: Option<i64>,
+    pub avg_output_bytes: Option<f64>,
+    /// Most recent error message in the range
+    pub last_error: Option<String>,
+}
+
+impl ToolMetrics {
+    /// Failed executions (errors + timeouts)
+    pub fn failure_count(&self) -> i64 {
+        self.error_count + self.timeout_count
+    }
+
+    /// Success rate (0.0 - 1.0)
+    pub fn success_rate(&self) -> f64 {
+        if self.call_count == 0 {
+            return 0.0;
+        }
+        self.success_count as f64 / self.call_count as f64
+    }
+}
+
 /// Tool execution record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolExecutionRecord {
@@ -1033,10 +1587,45 @@ pub struct ToolExecutionRecord {
     pub status: String,
     pub error_message: Option<String>,
     pub duration_ms: Option<i64>,
+    /// Size of the full output in bytes (output_text may be truncated)
+    pub output_bytes: Option<i64>,
+    /// Recovery retries before the final result
+    pub retries: i64,
     pub created_at: Option<String>,
     pub completed_at: Option<String>,
 }
 
+/// Scheduled (recurring) task record
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScheduledTaskRecord {
+    pub id: String,
+    /// Unique, user-facing name
+    pub name: String,
+    /// Cron expression (5 fields or @daily-style macro)
+    pub cron: String,
+    /// Prompt or skill invocation (`/name args`)
+    pub prompt: String,
+    pub working_directory: Option<String>,
+    pub enabled: bool,
+    pub created_at: String,
+    pub last_run_at: Option<String>,
+    pub next_run_at: Option<String>,
+}
+
+/// Schedule run record
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScheduleRunRecord {
+    pub id: Option<i64>,
+    pub schedule_id: String,
+    pub session_id: Option<String>,
+    /// success, error
+    pub status: String,
+    pub output_text: Option<String>,
+    pub error_message: Option<String>,
+    pub started_at: String,
+    pub completed_at: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1094,4 +1683,189 @@ mod tests {
         assert_eq!(messages.len(), 1);
         assert_eq!(messages[0].content, "Hello!");
     }
+
+    #[test]
+    fn test_usage_stats_between() {
+        let storage = Storage::in_memory().expect("Failed to create storage");
+        assert_eq!(storage.get_schema_version().unwrap(), CURRENT_SCHEMA_VERSION);
+
+        for id in ["s1", "s2"] {
+            storage
+                .create_session(&SessionRecord {
+                    id: id.to_string(),
+                    ..Default::default()
+                })
+                .expect("Failed to create session");
+        }
+
+        for (session, latency) in [("s1", Some(1000)), ("s1", Some(3000)), ("s2", None)] {
+            storage
+                .record_usage(&TokenUsageRecord {
+                    session_id: Some(session.to_string()),
+                    provider: "anthropic".to_string(),
+                    model: "claude-sonnet-4".to_string(),
+                    input_tokens: 100,
+                    output_tokens: 10,
+                    cache_read_tokens: 50,
+                    cache_write_tokens: 0,
+                    thinking_tokens: 0,
+                    cost_cents: 1,
+                    latency_ms: latency,
+                })
+                .expect("Failed to record usage");
+        }
+
+        for status in ["success", "error"] {
+            let id = storage
+                .start_tool_execution(&ToolExecutionRecord {
+                    id: None,
+                    session_id: None,
+                    message_id: None,
+                    tool_name: "bash".to_string(),
+                    tool_call_id: "call".to_string(),
+                    input_json: "{}".to_string(),
+                    output_text: None,
+                    status: "running".to_string(),
+                    error_message: None,
+                    duration_ms: None,
+                    output_bytes: None,
+                    retries: 0,
+                    created_at: None,
+                    completed_at: None,
+                })
+                .expect("Failed to start tool execution");
+            storage
+                .complete_tool_execution(id, None, status, None, Some(200))
+                .expect("Failed to complete tool execution");
+        }
+
+        let since = "2000-01-01T00:00:00+00:00";
+        let until = "2100-01-01T00:00:00+00:00";
+
+        let models = storage.get_model_usage_between(since, until).unwrap();
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].input_tokens, 300);
+        assert_eq!(models[0].cache_read_tokens, 150);
+        assert_eq!(models[0].request_count, 3);
+        assert_eq!(models[0].latency_samples, 2);
+        assert_eq!(models[0].avg_latency_ms, Some(2000.0));
+
+        let tools = storage.get_tool_usage_between(since, until, 10).unwrap();
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].call_count, 2);
+        assert_eq!(tools[0].error_count, 1);
+
+        assert_eq!(storage.count_active_sessions_between(since, until).unwrap(), 2);
+        assert!(storage
+            .get_model_usage_between(since, "2001-01-01T00:00:00+00:00")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_tool_metrics_between() {
+        let storage = Storage::in_memory().expect("Failed to create storage");
+
+        let executions = [
+            ("read", "success", None, 10, 0),
+            ("read", "success", None, 30, 0),
+            ("bash", "success", None, 100, 1),
+            ("bash", "error", Some("exit code 1"), 300, 2),
+            ("bash", "timeout", Some("timed out after 120s"), 120_000, 0),
+        ];
+        for (tool, status, error, duration, retries) in executions {
+            storage
+                .record_tool_execution(&ToolExecutionRecord {
+                    id: None,
+                    session_id: None,
+                    message_id: None,
+                    tool_name: tool.to_string(),
+                    tool_call_id: "call".to_string(),
+                    input_json: "{}".to_string(),
+                    output_text: Some("out".to_string()),
+                    status: status.to_string(),
+                    error_message: error.map(str::to_string),
+                    duration_ms: Some(duration),
+                    output_bytes: Some(2048),
+                    retries,
+                    created_at: None,
+                    completed_at: None,
+                })
+                .expect("Failed to record tool execution");
+        }
+
+        let recent = storage.get_recent_tool_executions(10).unwrap();
+        assert_eq!(recent.len(), 5);
+        assert_eq!(recent[0].output_bytes, Some(2048));
+
+        let metrics = storage
+            .get_tool_metrics_between("2000-01-01T00:00:00+00:00", "2100-01-01T00:00:00+00:00")
+            .unwrap();
+        assert_eq!(metrics.len(), 2);
+
+        // 실패가 많은 도구가 먼저
+        let bash = &metrics[0];
+        assert_eq!(bash.tool_name, "bash");
+        assert_eq!(bash.call_count, 3);
+        assert_eq!(bash.success_count, 1);
+        assert_eq!(bash.error_count, 1);
+        assert_eq!(bash.timeout_count, 1);
+        assert_eq!(bash.failure_count(), 2);
+        assert_eq!(bash.retry_count, 3);
+        assert_eq!(bash.max_duration_ms, Some(120_000));
+        assert_eq!(bash.avg_output_bytes, Some(2048.0));
+        assert!(bash.last_error.is_some());
+
+        let read = &metrics[1];
+        assert_eq!(read.success_rate(), 1.0);
+        assert_eq!(read.avg_duration_ms, Some(20.0));
+        assert_eq!(read.last_error, None);
+    }
+
+    #[test]
+    fn test_schedule_operations() {
+        let storage = Storage::in_memory().expect("Failed to create storage");
+
+        let mut task = ScheduledTaskRecord {
+            id: "sched-1".to_string(),
+            name: "nightly-deps".to_string(),
+            cron: "0 3 * * *".to_string(),
+            prompt: "/update-deps".to_string(),
+            enabled: true,
+            created_at: "2026-01-01T00:00:00+00:00".to_string(),
+            ..Default::default()
+        };
+        storage
+            .save_scheduled_task(&task)
+            .expect("Failed to save scheduled task");
+
+        task.next_run_at = Some("2026-01-02T03:00:00+00:00".to_string());
+        storage.save_scheduled_task(&task).unwrap();
+
+        let by_name = storage.get_scheduled_task("nightly-deps").unwrap().unwrap();
+        assert_eq!(by_name.id, "sched-1");
+        assert_eq!(by_name.next_run_at, task.next_run_at);
+        assert_eq!(storage.get_scheduled_tasks().unwrap().len(), 1);
+
+        for (status, started_at) in [
+            ("error", "2026-01-02T03:00:00+00:00"),
+            ("success", "2026-01-03T03:00:00+00:00"),
+        ] {
+            storage
+                .record_schedule_run(&ScheduleRunRecord {
+                    schedule_id: "sched-1".to_string(),
+                    status: status.to_string(),
+                    started_at: started_at.to_string(),
+                    ..Default::default()
+                })
+                .expect("Failed to record schedule run");
+        }
+        let runs = storage.get_schedule_runs("sched-1", 10).unwrap();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].status, "success");
+
+        storage.delete_scheduled_task("sched-1").unwrap();
+        assert!(storage.get_scheduled_task("sched-1").unwrap().is_none());
+        assert!(storage.get_schedule_runs("sched-1", 10).unwrap().is_empty());
+    }
 }
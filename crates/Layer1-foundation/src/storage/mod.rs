@@ -1,15 +1,28 @@
 //! Storage module for ForgeCode
 //!
-//! - `db`: SQLite - 런타임 데이터 (세션, 메시지, 토큰 사용량)
+//! - `backend`: StorageBackend - 런타임 데이터 저장소 추상화 (SQLite / Postgres)
+//! - `db`: SQLite - 런타임 데이터 (세션, 메시지, 토큰 사용량, 예약 작업)
+//! - `postgres`: Postgres - 여러 머신이 공유하는 런타임 데이터 (`postgres` 기능)
 //! - `json`: JSON - 범용 파일 저장/로드
 
+mod backend;
 mod db;
 mod json;
+#[cfg(feature = "postgres")]
+mod postgres;
+
+// Backend (SQLite / Postgres)
+pub use backend::{open_backend, StorageBackend};
 
 // SQLite Storage (런타임 데이터)
 pub use db::{
-    MessageRecord, SessionRecord, Storage, TokenUsageRecord, ToolExecutionRecord, UsageSummary,
+    MessageRecord, ModelUsageStats, ScheduleRunRecord, ScheduledTaskRecord, SessionRecord, Storage,
+    TokenUsageRecord, ToolExecutionRecord, ToolMetrics, ToolUsageStats, UsageSummary,
 };
 
+// Postgres Storage (공유 런타임 데이터)
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresStorage;
+
 // JSON Storage (범용)
 pub use json::JsonStore;
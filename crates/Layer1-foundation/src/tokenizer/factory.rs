@@ -17,6 +17,42 @@ pub fn factory() -> &'static TokenizerFactory {
     TOKENIZER_FACTORY.get_or_init(TokenizerFactory::new)
 }
 
+/// 보정 계수 상한 (잘못된 보고로 추정이 무한히 커지지 않도록)
+const MAX_CORRECTION_FACTOR: f32 = 4.0;
+
+/// 토큰 추정 보정 이벤트
+///
+/// Provider가 보고한 실제 토큰 수가 로컬 추정보다 클 때 기록합니다.
+/// (예: 추정상 여유가 있었는데 context-length 에러가 발생한 경우)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationEvent {
+    /// 모델 ID
+    pub model_id: String,
+    /// 로컬 추정 토큰 수
+    pub estimated_tokens: usize,
+    /// Provider가 보고한 토큰 수 (하한값)
+    pub actual_tokens: usize,
+}
+
+impl CalibrationEvent {
+    /// 새 이벤트 생성
+    pub fn new(model_id: impl Into<String>, estimated_tokens: usize, actual_tokens: usize) -> Self {
+        Self {
+            model_id: model_id.into(),
+            estimated_tokens,
+            actual_tokens,
+        }
+    }
+
+    /// 실제/추정 비율
+    pub fn ratio(&self) -> f32 {
+        if self.estimated_tokens == 0 {
+            return 1.0;
+        }
+        self.actual_tokens as f32 / self.estimated_tokens as f32
+    }
+}
+
 /// 토크나이저 팩토리
 ///
 /// 모델 ID를 기반으로 적절한 토크나이저를 생성하고 캐싱합니다.
@@ -25,6 +61,8 @@ pub struct TokenizerFactory {
     model_configs: RwLock<HashMap<String, ModelTokenConfig>>,
     /// 캐시된 토크나이저
     cache: RwLock<HashMap<TokenizerType, Arc<dyn Tokenizer>>>,
+    /// 모델별 보정 계수 (CalibrationEvent로 학습)
+    corrections: RwLock<HashMap<String, f32>>,
 }
 
 impl TokenizerFactory {
@@ -33,6 +71,7 @@ impl TokenizerFactory {
         let factory = Self {
             model_configs: RwLock::new(HashMap::new()),
             cache: RwLock::new(HashMap::new()),
+            corrections: RwLock::new(HashMap::new()),
         };
         factory.register_defaults();
         factory
@@ -67,6 +106,31 @@ impl TokenizerFactory {
         configs.insert(config.model_id.clone(), config);
     }
 
+    /// 보정 이벤트 기록 - 갱신된 보정 계수 반환
+    ///
+    /// 보고된 토큰 수는 하한값이므로 계수는 줄어들지 않습니다.
+    pub fn record_calibration(&self, event: &CalibrationEvent) -> f32 {
+        let mut corrections = self.corrections.write().unwrap();
+        let factor = corrections.entry(event.model_id.clone()).or_insert(1.0);
+        *factor = factor.max(event.ratio()).min(MAX_CORRECTION_FACTOR);
+        *factor
+    }
+
+    /// 모델의 보정 계수 (기록이 없으면 1.0)
+    pub fn correction_factor(&self, model_id: &str) -> f32 {
+        self.corrections
+            .read()
+            .unwrap()
+            .get(model_id)
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    /// 보정 계수를 적용한 토큰 수
+    pub fn corrected(&self, model_id: &str, tokens: usize) -> usize {
+        (tokens as f32 * self.correction_factor(model_id)).round() as usize
+    }
+
     /// 모델 ID에서 토크나이저 타입 추론
     fn get_tokenizer_type(&self, model_id: &str) -> TokenizerType {
         let configs = self.model_configs.read().unwrap();
@@ -229,9 +293,10 @@ impl Default for TokenizerFactory {
 // 편의 함수
 // ============================================================================
 
-/// 모델 ID로 토큰 수 계산
+/// 모델 ID로 토큰 수 계산 (보정 계수 적용)
 pub fn count_tokens(model_id: &str, text: &str) -> usize {
-    factory().for_model(model_id).count(text).total
+    let factory = factory();
+    factory.corrected(model_id, factory.for_model(model_id).count(text).total)
 }
 
 /// 모델 ID로 토큰 예산 가져오기
@@ -248,7 +313,7 @@ pub fn truncate_for_model(model_id: &str, text: &str, max_tokens: usize) -> Stri
 pub fn check_limit(model_id: &str, text: &str) -> (bool, usize, usize) {
     let budget = factory().budget_for_model(model_id);
     let tokenizer = factory().for_model(model_id);
-    let count = tokenizer.count(text).total;
+    let count = factory().corrected(model_id, tokenizer.count(text).total);
     let available = budget.available_input();
 
     (count <= available, count, available)
@@ -309,6 +374,24 @@ mod tests {
         assert!(used < available);
     }
 
+    #[test]
+    fn test_calibration() {
+        let factory = TokenizerFactory::new();
+        assert_eq!(factory.correction_factor("gpt-4o"), 1.0);
+
+        let event = CalibrationEvent::new("gpt-4o", 100_000, 130_000);
+        assert_eq!(factory.record_calibration(&event), 1.3);
+        assert_eq!(factory.corrected("gpt-4o", 1_000), 1_300);
+
+        // 더 작은 비율은 무시, 상한 적용
+        factory.record_calibration(&CalibrationEvent::new("gpt-4o", 100_000, 110_000));
+        assert_eq!(factory.correction_factor("gpt-4o"), 1.3);
+        factory.record_calibration(&CalibrationEvent::new("gpt-4o", 1_000, 100_000));
+        assert_eq!(factory.correction_factor("gpt-4o"), MAX_CORRECTION_FACTOR);
+
+        assert_eq!(factory.correction_factor("claude-sonnet-4-20250514"), 1.0);
+    }
+
     #[test]
     fn test_caching() {
         let factory = TokenizerFactory::new();
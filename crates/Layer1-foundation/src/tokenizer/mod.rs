@@ -51,7 +51,7 @@ pub use dynamic::{DynamicTokenizerRegistry, ModelFamily, OllamaTokenizer, OpenAI
 pub use estimator::{
     ClaudeApiConfig, ClaudeEstimator, GeminiEstimator, LlamaEstimator, TiktokenEstimator,
 };
-pub use factory::TokenizerFactory;
+pub use factory::{factory as tokenizer_factory, CalibrationEvent, TokenizerFactory};
 pub use traits::Tokenizer;
 pub use types::{
     EncodingResult, ModelTokenConfig, TokenBudget, TokenCount, TokenDistribution, TokenizerError,
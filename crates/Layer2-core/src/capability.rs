@@ -0,0 +1,406 @@
+//! Capability Matrix - 선택적 서브시스템 가용성 추적
+//!
+//! LSP, MCP, RepoMap, Git은 모두 선택적 서브시스템입니다.
+//! 초기화에 실패해도 Agent는 중단되지 않고 기능을 줄여 계속 동작하며,
+//! 각 서브시스템의 상태는 `CapabilityMatrix`에 기록됩니다.
+//!
+//! ## 상태
+//!
+//! | 상태 | 의미 | 의존 도구 |
+//! |------|------|-----------|
+//! | `Available` | 정상 동작 | 실행 |
+//! | `Degraded` | 일부만 동작 (예: MCP 서버 일부 연결 실패) | 실행 (가능한 범위에서) |
+//! | `Unavailable` | 초기화 실패 | `Error::CapabilityUnavailable` 반환 |
+//! | `Disabled` | 설정으로 비활성화 | `Error::CapabilityUnavailable` 반환 |
+//! | `NotApplicable` | 이 프로젝트에 해당 없음 (저장소 아님, 언어 서버 미설치) | `Error::CapabilityUnavailable` 반환 |
+//!
+//! `Degraded`와 `Unavailable`만 기능 저하로 경고하고, `Disabled`와 `NotApplicable`은
+//! 정상 상태로 취급합니다.
+//!
+//! ## 서브시스템별 저하 동작
+//!
+//! | 서브시스템 | 실패 원인 | 저하 동작 |
+//! |------------|-----------|-----------|
+//! | LSP | 언어 서버 실행 실패 | 정의/참조 조회 불가, grep/read로 대체 |
+//! | MCP | 서버 연결 실패 | 해당 서버 도구 미등록, builtin 도구는 정상 |
+//! | RepoMap | 작업 디렉토리 읽기 실패 | 저장소 맵 없이 진행 |
+//! | Git | git 미설치 | 체크포인트/자동 커밋 비활성화 |
+//!
+//! 도구는 `ToolMeta::category`로 의존 서브시스템을 선언합니다
+//! (`"lsp"`, `"mcp"`, `"repomap"`, `"git"`).
+
+use crate::git::GitOps;
+use crate::lsp::LspServerConfig;
+use forge_foundation::{Error, ToolMeta};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+// ============================================================================
+// Capability
+// ============================================================================
+
+/// 선택적 서브시스템
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Capability {
+    Lsp,
+    Mcp,
+    RepoMap,
+    Git,
+}
+
+impl Capability {
+    /// 모든 서브시스템 (표시 순서)
+    pub const ALL: [Capability; 4] = [
+        Capability::Lsp,
+        Capability::Mcp,
+        Capability::RepoMap,
+        Capability::Git,
+    ];
+
+    /// 식별자 (도구 카테고리와 동일)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Capability::Lsp => "lsp",
+            Capability::Mcp => "mcp",
+            Capability::RepoMap => "repomap",
+            Capability::Git => "git",
+        }
+    }
+
+    /// 카테고리 문자열로부터 변환
+    pub fn from_category(category: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.name() == category)
+    }
+
+    /// 도구가 의존하는 서브시스템
+    pub fn for_tool(meta: &ToolMeta) -> Option<Self> {
+        Self::from_category(&meta.category).or_else(|| {
+            meta.name
+                .starts_with("mcp_")
+                .then_some(Capability::Mcp)
+        })
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// ============================================================================
+// CapabilityStatus
+// ============================================================================
+
+/// 서브시스템 상태
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapabilityStatus {
+    /// 정상 동작
+    Available,
+
+    /// 일부 기능만 동작
+    Degraded { reason: String },
+
+    /// 초기화 실패
+    Unavailable { reason: String },
+
+    /// 설정으로 비활성화
+    Disabled,
+
+    /// 이 프로젝트에 해당 없음 (실패가 아님)
+    NotApplicable { reason: String },
+}
+
+impl CapabilityStatus {
+    /// 의존 도구를 실행할 수 있는지
+    pub fn is_usable(&self) -> bool {
+        matches!(self, CapabilityStatus::Available | CapabilityStatus::Degraded { .. })
+    }
+
+    /// 사용자에게 알려야 하는 상태인지 (Degraded / Unavailable)
+    pub fn is_degraded(&self) -> bool {
+        matches!(
+            self,
+            CapabilityStatus::Degraded { .. } | CapabilityStatus::Unavailable { .. }
+        )
+    }
+
+    /// 상태 사유
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            CapabilityStatus::Degraded { reason }
+            | CapabilityStatus::Unavailable { reason }
+            | CapabilityStatus::NotApplicable { reason } => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// 상태 레이블
+    pub fn label(&self) -> &'static str {
+        match self {
+            CapabilityStatus::Available => "available",
+            CapabilityStatus::Degraded { .. } => "degraded",
+            CapabilityStatus::Unavailable { .. } => "unavailable",
+            CapabilityStatus::Disabled => "disabled",
+            CapabilityStatus::NotApplicable { .. } => "not applicable",
+        }
+    }
+}
+
+// ============================================================================
+// CapabilityMatrix
+// ============================================================================
+
+/// 서브시스템 가용성 매트릭스
+///
+/// 기록되지 않은 서브시스템은 `Disabled`로 취급합니다.
+#[derive(Debug, Default)]
+pub struct CapabilityMatrix {
+    statuses: RwLock<HashMap<Capability, CapabilityStatus>>,
+}
+
+impl CapabilityMatrix {
+    /// 빈 매트릭스 생성 (모두 Disabled)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 상태 설정
+    pub fn set(&self, capability: Capability, status: CapabilityStatus) {
+        self.statuses.write().insert(capability, status);
+    }
+
+    /// 정상 동작으로 표시
+    pub fn mark_available(&self, capability: Capability) {
+        self.set(capability, CapabilityStatus::Available);
+    }
+
+    /// 일부 기능만 동작으로 표시
+    pub fn mark_degraded(&self, capability: Capability, reason: impl Into<String>) {
+        self.set(
+            capability,
+            CapabilityStatus::Degraded {
+                reason: reason.into(),
+            },
+        );
+    }
+
+    /// 사용 불가로 표시
+    pub fn mark_unavailable(&self, capability: Capability, reason: impl Into<String>) {
+        self.set(
+            capability,
+            CapabilityStatus::Unavailable {
+                reason: reason.into(),
+            },
+        );
+    }
+
+    /// 비활성화로 표시
+    pub fn mark_disabled(&self, capability: Capability) {
+        self.set(capability, CapabilityStatus::Disabled);
+    }
+
+    /// 상태 조회
+    pub fn status(&self, capability: Capability) -> CapabilityStatus {
+        self.statuses
+            .read()
+            .get(&capability)
+            .cloned()
+            .unwrap_or(CapabilityStatus::Disabled)
+    }
+
+    /// 의존 도구를 실행할 수 있는지
+    pub fn is_usable(&self, capability: Capability) -> bool {
+        self.status(capability).is_usable()
+    }
+
+    /// 사용 가능 여부 확인 - 불가하면 구조화된 에러 반환
+    pub fn require(&self, capability: Capability) -> forge_foundation::Result<()> {
+        match self.status(capability) {
+            status if status.is_usable() => Ok(()),
+            CapabilityStatus::Unavailable { reason }
+            | CapabilityStatus::NotApplicable { reason } => {
+                Err(Error::capability_unavailable(capability.name(), reason))
+            }
+            _ => Err(Error::capability_unavailable(
+                capability.name(),
+                "disabled in configuration",
+            )),
+        }
+    }
+
+    /// 전체 상태 (표시 순서)
+    pub fn all(&self) -> Vec<(Capability, CapabilityStatus)> {
+        Capability::ALL
+            .into_iter()
+            .map(|c| (c, self.status(c)))
+            .collect()
+    }
+
+    /// 저하된 서브시스템 목록 (Degraded / Unavailable)
+    pub fn degraded(&self) -> Vec<(Capability, CapabilityStatus)> {
+        self.all()
+            .into_iter()
+            .filter(|(_, status)| status.is_degraded())
+            .collect()
+    }
+
+    /// 한 줄 요약 (예: "lsp unavailable, mcp degraded"). 저하된 것이 없으면 None
+    pub fn summary(&self) -> Option<String> {
+        let degraded = self.degraded();
+        if degraded.is_empty() {
+            return None;
+        }
+
+        Some(
+            degraded
+                .iter()
+                .map(|(c, status)| format!("{} {}", c, status.label()))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+}
+
+// ============================================================================
+// Probes
+// ============================================================================
+
+/// Git 가용성 확인 (git 설치 + 저장소 여부)
+///
+/// 저장소가 아닌 디렉토리는 실패가 아니라 `NotApplicable`입니다.
+pub fn probe_git(dir: &Path) -> CapabilityStatus {
+    if which::which("git").is_err() {
+        return CapabilityStatus::Unavailable {
+            reason: "git executable not found".to_string(),
+        };
+    }
+
+    if !GitOps::is_repo(dir) {
+        return CapabilityStatus::NotApplicable {
+            reason: format!("{} is not a git repository", dir.display()),
+        };
+    }
+
+    CapabilityStatus::Available
+}
+
+/// LSP 가용성 확인 (설정된 언어 서버 중 설치된 것이 있는지)
+///
+/// 설치된 언어 서버가 없으면 `NotApplicable`입니다.
+pub fn probe_lsp(configs: &[LspServerConfig]) -> CapabilityStatus {
+    if configs.is_empty() {
+        return CapabilityStatus::Disabled;
+    }
+
+    let installed = configs
+        .iter()
+        .filter(|c| c.is_installed())
+        .count();
+
+    if installed == 0 {
+        let commands: Vec<_> = configs.iter().map(|c| c.command.as_str()).collect();
+        return CapabilityStatus::NotApplicable {
+            reason: format!("no language server installed ({})", commands.join(", ")),
+        };
+    }
+
+    CapabilityStatus::Available
+}
+
+/// RepoMap 가용성 확인 (작업 디렉토리를 읽을 수 있는지)
+pub fn probe_repomap(dir: &Path) -> CapabilityStatus {
+    match std::fs::read_dir(dir) {
+        Ok(_) => CapabilityStatus::Available,
+        Err(e) => CapabilityStatus::Unavailable {
+            reason: format!("cannot read {}: {}", dir.display(), e),
+        },
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_defaults_to_disabled() {
+        let matrix = CapabilityMatrix::new();
+        assert_eq!(matrix.status(Capability::Lsp), CapabilityStatus::Disabled);
+        assert!(!matrix.is_usable(Capability::Lsp));
+        assert!(matrix.summary().is_none());
+    }
+
+    #[test]
+    fn test_matrix_require_and_summary() {
+        let matrix = CapabilityMatrix::new();
+        matrix.mark_available(Capability::Git);
+        matrix.mark_degraded(Capability::Mcp, "server 'db' failed");
+        matrix.mark_unavailable(Capability::Lsp, "rust-analyzer not found");
+
+        assert!(matrix.require(Capability::Git).is_ok());
+        assert!(matrix.require(Capability::Mcp).is_ok());
+
+        match matrix.require(Capability::Lsp) {
+            Err(Error::CapabilityUnavailable { capability, reason }) => {
+                assert_eq!(capability, "lsp");
+                assert_eq!(reason, "rust-analyzer not found");
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+        assert!(matches!(
+            matrix.require(Capability::RepoMap),
+            Err(Error::CapabilityUnavailable { .. })
+        ));
+
+        assert_eq!(
+            matrix.summary().as_deref(),
+            Some("lsp unavailable, mcp degraded")
+        );
+    }
+
+    #[test]
+    fn test_capability_for_tool() {
+        let lsp_tool = ToolMeta::new("goto_definition").category("lsp");
+        let mcp_tool = ToolMeta::new("mcp_github_search");
+        let fs_tool = ToolMeta::new("read").category("filesystem");
+
+        assert_eq!(Capability::for_tool(&lsp_tool), Some(Capability::Lsp));
+        assert_eq!(Capability::for_tool(&mcp_tool), Some(Capability::Mcp));
+        assert_eq!(Capability::for_tool(&fs_tool), None);
+    }
+
+    #[test]
+    fn test_probes() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(probe_repomap(dir.path()), CapabilityStatus::Available);
+        assert!(!probe_repomap(&dir.path().join("missing")).is_usable());
+
+        let missing = LspServerConfig {
+            language_id: "none".to_string(),
+            command: "forge-nonexistent-language-server".to_string(),
+            args: vec![],
+            root_patterns: vec![],
+            initialization_options: None,
+            extensions: vec![],
+            install_hint: None,
+        };
+        let lsp = probe_lsp(&[missing]);
+        assert!(matches!(lsp, CapabilityStatus::NotApplicable { .. }));
+        assert!(!lsp.is_usable());
+        assert!(!lsp.is_degraded());
+        assert_eq!(probe_lsp(&[]), CapabilityStatus::Disabled);
+
+        // 저장소가 아닌 디렉토리는 경고 대상이 아님
+        if which::which("git").is_ok() {
+            let git = probe_git(dir.path());
+            assert!(matches!(git, CapabilityStatus::NotApplicable { .. }));
+            assert!(!git.is_degraded());
+        }
+    }
+}
@@ -206,12 +206,32 @@ pub fn merge_configs(earlier: ForgeConfig, later: ForgeConfig) -> ForgeConfig {
             merged
         },
 
+        // 데이터베이스 연결: 병합
+        databases: {
+            let mut merged = earlier.databases;
+            merged.extend(later.databases);
+            merged
+        },
+
         // 권한: 병합 (패턴들 추가)
         permissions: merge_permissions(earlier.permissions, later.permissions),
 
         // Shell: later 우선 (부분 오버라이드)
         shell: merge_shell(earlier.shell, later.shell),
 
+        // 도구 타임아웃: later 우선, 도구별 설정은 병합
+        tools: super::types::ToolsConfigSection {
+            timeout: later.tools.timeout,
+            timeouts: {
+                let mut merged = earlier.tools.timeouts;
+                merged.extend(later.tools.timeouts);
+                merged
+            },
+        },
+
+        // 자동 포맷: later 우선
+        format: later.format,
+
         // 테마: later 우선
         theme: later.theme,
 
@@ -228,6 +248,9 @@ pub fn merge_configs(earlier: ForgeConfig, later: ForgeConfig) -> ForgeConfig {
         // Security 설정: later 우선
         security: later.security,
 
+        // 규칙 파일 설정: later 우선
+        rules: later.rules,
+
         // Extra: 병합
         extra: {
             let mut merged = earlier.extra;
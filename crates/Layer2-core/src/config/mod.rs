@@ -36,13 +36,17 @@
 //! ```
 
 mod loader;
+mod rules;
 mod types;
 mod workflow;
 
-pub use loader::{load_config_from_file, merge_configs, strip_json_comments, ConfigLoader};
+pub use loader::{
+    load_config_from_file, merge_configs, strip_json_comments, ConfigLoader, CONFIG_DIR_NAME,
+};
+pub use rules::{RuleFile, RuleScope, RuleSet, RuleSource, RulesConfig, RulesLoader, SkippedRule};
 pub use types::{
-    ForgeConfig, McpServerConfig as ConfigMcpServer, ModelConfig, PermissionConfig, ProviderConfig,
-    ShellConfigSection, ThemeConfig,
+    ForgeConfig, FormatConfig, FormatterCommand, McpServerConfig as ConfigMcpServer, ModelConfig,
+    PermissionConfig, ProviderConfig, ShellConfigSection, ThemeConfig, ToolsConfigSection,
 };
 pub use workflow::{
     // Helper
@@ -53,12 +57,15 @@ pub use workflow::{
     AutoStageConfig,
     AutoTagConfig,
     // Security
+    AuditSecurityConfig,
     CommandSecurityConfig,
     EnvSecurityConfig,
     GitConfig,
     GitHooksConfig,
     GitWorkflowConfig,
     NetworkSecurityConfig,
+    OversightSecurityConfig,
     PathSecurityConfig,
+    SecretScanConfig,
     SecurityConfig,
 };
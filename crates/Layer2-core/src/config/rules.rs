@@ -0,0 +1,546 @@
+//! Rule Files - 기존 에이전트 규칙 파일 수집
+//!
+//! 팀이 이미 사용 중인 규칙 파일(AGENTS.md, CLAUDE.md, .cursorrules 등)을
+//! 찾아 시스템 프롬프트에 포함합니다. ForgeCode 설정으로 옮기지 않아도
+//! 같은 규칙이 적용됩니다.
+//!
+//! ## 검색 위치
+//!
+//! 1. User-level: `~/.forgecode/FORGE.md`
+//! 2. Project-level: 저장소 루트(`.git`이 있는 디렉토리)부터 작업 디렉토리까지
+//!
+//! ## 우선순위 (높은 → 낮은)
+//!
+//! - 작업 디렉토리에 가까운 파일이 상위 디렉토리 파일보다 우선
+//! - 같은 디렉토리에서는 `FORGECODE.md`/`.forgecode/FORGE.md` > `AGENTS.md` > `CLAUDE.md` > Cursor 규칙
+//! - User-level 파일이 가장 낮음
+//!
+//! 토큰 예산(`RulesConfig::max_tokens`)은 우선순위 순으로 배분되며,
+//! 예산을 넘는 낮은 우선순위 파일은 잘리거나 제외됩니다.
+//! 내용이 같은 파일(심볼릭 링크 등)은 한 번만 포함됩니다.
+
+use super::loader::CONFIG_DIR_NAME;
+use forge_foundation::{TiktokenEstimator, Tokenizer};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// 잘라서 포함할 때 남아 있어야 하는 최소 토큰 수
+const MIN_PARTIAL_TOKENS: usize = 200;
+
+// ============================================================================
+// RuleSource
+// ============================================================================
+
+/// 규칙 파일 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSource {
+    /// ForgeCode 전용 (`FORGECODE.md`, `.forgecode/FORGE.md`)
+    Forge,
+    /// `AGENTS.md`
+    Agents,
+    /// `CLAUDE.md`, `.claude/CLAUDE.md`
+    Claude,
+    /// `.cursorrules`, `.cursor/rules/*.mdc`
+    Cursor,
+}
+
+impl RuleSource {
+    /// 같은 디렉토리 안에서의 우선순위 순서
+    pub const ALL: [RuleSource; 4] = [
+        RuleSource::Forge,
+        RuleSource::Agents,
+        RuleSource::Claude,
+        RuleSource::Cursor,
+    ];
+
+    /// 표시 이름
+    pub fn label(&self) -> &'static str {
+        match self {
+            RuleSource::Forge => "ForgeCode",
+            RuleSource::Agents => "AGENTS.md",
+            RuleSource::Claude => "CLAUDE.md",
+            RuleSource::Cursor => "Cursor rules",
+        }
+    }
+
+    /// 디렉토리 안의 규칙 파일 경로 (존재하는 것만, 우선순위 순)
+    fn files_in(&self, dir: &Path) -> Vec<PathBuf> {
+        let candidates: Vec<PathBuf> = match self {
+            RuleSource::Forge => vec![
+                dir.join("FORGECODE.md"),
+                dir.join(CONFIG_DIR_NAME).join("FORGE.md"),
+            ],
+            RuleSource::Agents => vec![dir.join("AGENTS.md")],
+            RuleSource::Claude => vec![dir.join("CLAUDE.md"), dir.join(".claude").join("CLAUDE.md")],
+            RuleSource::Cursor => {
+                let mut files = vec![dir.join(".cursorrules")];
+                if let Ok(entries) = std::fs::read_dir(dir.join(".cursor").join("rules")) {
+                    let mut mdc: Vec<PathBuf> = entries
+                        .filter_map(|e| e.ok().map(|e| e.path()))
+                        .filter(|p| p.extension().is_some_and(|e| e == "mdc" || e == "md"))
+                        .collect();
+                    mdc.sort();
+                    files.extend(mdc);
+                }
+                files
+            }
+        };
+
+        candidates.into_iter().filter(|p| p.is_file()).collect()
+    }
+}
+
+// ============================================================================
+// RulesConfig
+// ============================================================================
+
+/// 규칙 파일 수집 설정
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RulesConfig {
+    /// 규칙 파일 수집 활성화
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// 규칙 파일 전체 토큰 예산
+    #[serde(default = "default_max_tokens")]
+    pub max_tokens: usize,
+
+    /// 수집할 규칙 파일 종류 (기본: 전체)
+    #[serde(default = "default_sources")]
+    pub sources: Vec<RuleSource>,
+
+    /// 사용자 레벨 규칙 포함 (`~/.forgecode/FORGE.md`)
+    #[serde(default = "default_true")]
+    pub include_user: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_tokens() -> usize {
+    8_000
+}
+
+fn default_sources() -> Vec<RuleSource> {
+    RuleSource::ALL.to_vec()
+}
+
+impl Default for RulesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_tokens: default_max_tokens(),
+            sources: default_sources(),
+            include_user: true,
+        }
+    }
+}
+
+// ============================================================================
+// RuleFile / RuleSet
+// ============================================================================
+
+/// 규칙 파일 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleScope {
+    /// 사용자 전역
+    User,
+    /// 프로젝트 (0 = 작업 디렉토리, 1 = 상위 디렉토리, ...)
+    Project { depth: usize },
+}
+
+/// 수집된 규칙 파일
+#[derive(Debug, Clone)]
+pub struct RuleFile {
+    /// 파일 경로
+    pub path: PathBuf,
+
+    /// 표시용 경로 (작업 디렉토리 기준)
+    pub display_path: String,
+
+    /// 파일 종류
+    pub source: RuleSource,
+
+    /// 범위
+    pub scope: RuleScope,
+
+    /// 포함된 내용 (예산에 맞게 잘렸을 수 있음)
+    pub content: String,
+
+    /// 포함된 내용의 토큰 수
+    pub tokens: usize,
+
+    /// 예산 때문에 잘렸는지
+    pub truncated: bool,
+}
+
+/// 제외된 규칙 파일
+#[derive(Debug, Clone)]
+pub struct SkippedRule {
+    /// 파일 경로
+    pub path: PathBuf,
+
+    /// 제외 사유
+    pub reason: String,
+}
+
+/// 규칙 파일 수집 결과 (우선순위 높은 순)
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    /// 포함된 파일
+    pub files: Vec<RuleFile>,
+
+    /// 제외된 파일
+    pub skipped: Vec<SkippedRule>,
+}
+
+impl RuleSet {
+    /// 포함된 규칙이 없는지
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// 포함된 규칙의 총 토큰 수
+    pub fn total_tokens(&self) -> usize {
+        self.files.iter().map(|f| f.tokens).sum()
+    }
+
+    /// 시스템 프롬프트 섹션 (규칙이 없으면 None)
+    pub fn to_prompt_section(&self) -> Option<String> {
+        if self.files.is_empty() {
+            return None;
+        }
+
+        let mut section = String::from(
+            "## Project Rules\n\n\
+             The following rule files were found for this project. Follow them.\n\
+             When rules conflict, earlier sections take precedence over later ones.\n",
+        );
+
+        for file in &self.files {
+            section.push_str(&format!("\n### {} ({})\n\n", file.source.label(), file.display_path));
+            section.push_str(file.content.trim());
+            if file.truncated {
+                section.push_str("\n\n[... truncated to fit the rule token budget]");
+            }
+            section.push('\n');
+        }
+
+        Some(section)
+    }
+}
+
+// ============================================================================
+// RulesLoader
+// ============================================================================
+
+/// 규칙 파일 로더
+pub struct RulesLoader {
+    working_dir: PathBuf,
+    home_dir: Option<PathBuf>,
+    config: RulesConfig,
+}
+
+impl RulesLoader {
+    /// 새 로더 생성 (기본 설정)
+    pub fn new(working_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            working_dir: working_dir.into(),
+            home_dir: dirs::home_dir(),
+            config: RulesConfig::default(),
+        }
+    }
+
+    /// 설정 지정
+    pub fn with_config(mut self, config: RulesConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// 사용자 홈 디렉토리 지정 (None이면 사용자 레벨 규칙 생략)
+    pub fn with_home_dir(mut self, home_dir: Option<PathBuf>) -> Self {
+        self.home_dir = home_dir;
+        self
+    }
+
+    /// 프로젝트 디렉토리 목록 (작업 디렉토리 → 저장소 루트)
+    fn project_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+        let mut current = Some(self.working_dir.as_path());
+
+        while let Some(dir) = current {
+            // 홈 디렉토리는 사용자 레벨로 따로 처리
+            if self.home_dir.as_deref() == Some(dir) && !dirs.is_empty() {
+                break;
+            }
+            dirs.push(dir.to_path_buf());
+            if dir.join(".git").exists() {
+                return dirs;
+            }
+            current = dir.parent();
+        }
+
+        // 저장소가 아니면 작업 디렉토리만
+        dirs.truncate(1);
+        dirs
+    }
+
+    /// 후보 파일 목록 (우선순위 높은 순)
+    fn candidates(&self) -> Vec<(PathBuf, RuleSource, RuleScope)> {
+        let mut candidates = Vec::new();
+
+        for (depth, dir) in self.project_dirs().iter().enumerate() {
+            for source in RuleSource::ALL {
+                if !self.config.sources.contains(&source) {
+                    continue;
+                }
+                for path in source.files_in(dir) {
+                    candidates.push((path, source, RuleScope::Project { depth }));
+                }
+            }
+        }
+
+        if self.config.include_user && self.config.sources.contains(&RuleSource::Forge) {
+            if let Some(home) = &self.home_dir {
+                let user_rules = home.join(CONFIG_DIR_NAME).join("FORGE.md");
+                if user_rules.is_file() && !candidates.iter().any(|(p, _, _)| *p == user_rules) {
+                    candidates.push((user_rules, RuleSource::Forge, RuleScope::User));
+                }
+            }
+        }
+
+        candidates
+    }
+
+    fn display_path(&self, path: &Path, scope: RuleScope) -> String {
+        match scope {
+            RuleScope::User => match &self.home_dir {
+                Some(home) => path
+                    .strip_prefix(home)
+                    .map(|p| format!("~/{}", p.display()))
+                    .unwrap_or_else(|_| path.display().to_string()),
+                None => path.display().to_string(),
+            },
+            RuleScope::Project { .. } => {
+                let base = self.project_dirs().pop().unwrap_or_else(|| self.working_dir.clone());
+                path.strip_prefix(&base)
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|_| path.display().to_string())
+            }
+        }
+    }
+
+    /// 규칙 파일 수집
+    pub fn load(&self) -> RuleSet {
+        let mut set = RuleSet::default();
+        if !self.config.enabled {
+            return set;
+        }
+
+        let tokenizer = TiktokenEstimator::cl100k();
+        let mut remaining = self.config.max_tokens;
+        let mut seen: Vec<String> = Vec::new();
+
+        for (path, source, scope) in self.candidates() {
+            let content = match std::fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    warn!("Failed to read rule file {}: {}", path.display(), e);
+                    set.skipped.push(SkippedRule {
+                        path,
+                        reason: format!("unreadable: {}", e),
+                    });
+                    continue;
+                }
+            };
+
+            let content = match source {
+                RuleSource::Cursor => strip_frontmatter(&content).trim().to_string(),
+                _ => content.trim().to_string(),
+            };
+            if content.is_empty() {
+                continue;
+            }
+
+            // 같은 내용 (예: CLAUDE.md -> AGENTS.md 심볼릭 링크)은 한 번만
+            if seen.contains(&content) {
+                set.skipped.push(SkippedRule {
+                    path,
+                    reason: "duplicate content".to_string(),
+                });
+                continue;
+            }
+
+            let tokens = tokenizer.count(&content).total;
+            let (content, tokens, truncated) = if tokens <= remaining {
+                (content, tokens, false)
+            } else if remaining >= MIN_PARTIAL_TOKENS {
+                let partial = tokenizer.truncate(&content, remaining);
+                let partial_tokens = tokenizer.count(&partial).total;
+                (partial, partial_tokens, true)
+            } else {
+                set.skipped.push(SkippedRule {
+                    path,
+                    reason: format!("token budget exceeded ({} tokens)", tokens),
+                });
+                continue;
+            };
+
+            debug!("Loaded rule file {} ({} tokens)", path.display(), tokens);
+            remaining = remaining.saturating_sub(tokens);
+            seen.push(content.clone());
+            set.files.push(RuleFile {
+                display_path: self.display_path(&path, scope),
+                path,
+                source,
+                scope,
+                content,
+                tokens,
+                truncated,
+            });
+        }
+
+        set
+    }
+}
+
+/// Cursor `.mdc` 규칙의 YAML frontmatter 제거
+fn strip_frontmatter(content: &str) -> &str {
+    let Some(rest) = content.strip_prefix("---") else {
+        return content;
+    };
+    match rest.find("\n---") {
+        Some(end) => rest[end + 4..].trim_start_matches(['\r', '\n']),
+        None => content,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn loader(dir: &Path) -> RulesLoader {
+        RulesLoader::new(dir).with_home_dir(None)
+    }
+
+    #[test]
+    fn test_precedence_within_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".cursorrules"), "cursor rule").unwrap();
+        fs::write(dir.path().join("CLAUDE.md"), "claude rule").unwrap();
+        fs::write(dir.path().join("AGENTS.md"), "agents rule").unwrap();
+        fs::create_dir_all(dir.path().join(".forgecode")).unwrap();
+        fs::write(dir.path().join(".forgecode/FORGE.md"), "forge rule").unwrap();
+
+        let set = loader(dir.path()).load();
+        let sources: Vec<_> = set.files.iter().map(|f| f.source).collect();
+        assert_eq!(
+            sources,
+            vec![RuleSource::Forge, RuleSource::Agents, RuleSource::Claude, RuleSource::Cursor]
+        );
+
+        let section = set.to_prompt_section().unwrap();
+        assert!(section.find("forge rule").unwrap() < section.find("cursor rule").unwrap());
+        assert!(section.contains("(AGENTS.md)"));
+    }
+
+    #[test]
+    fn test_nearer_directory_wins_and_stops_at_repo_root() {
+        let outer = tempfile::tempdir().unwrap();
+        let repo = outer.path().join("repo");
+        let sub = repo.join("crates/app");
+        fs::create_dir_all(&sub).unwrap();
+        fs::create_dir_all(repo.join(".git")).unwrap();
+
+        fs::write(outer.path().join("AGENTS.md"), "outside repo").unwrap();
+        fs::write(repo.join("AGENTS.md"), "root rule").unwrap();
+        fs::write(sub.join("AGENTS.md"), "crate rule").unwrap();
+
+        let set = loader(&sub).load();
+        let contents: Vec<_> = set.files.iter().map(|f| f.content.as_str()).collect();
+        assert_eq!(contents, vec!["crate rule", "root rule"]);
+        assert_eq!(set.files[0].scope, RuleScope::Project { depth: 0 });
+        assert_eq!(set.files[1].display_path, "AGENTS.md");
+    }
+
+    #[test]
+    fn test_duplicates_and_disabled_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("AGENTS.md"), "same rules\n").unwrap();
+        fs::write(dir.path().join("CLAUDE.md"), "same rules").unwrap();
+        fs::create_dir_all(dir.path().join(".cursor/rules")).unwrap();
+        fs::write(
+            dir.path().join(".cursor/rules/style.mdc"),
+            "---\ndescription: style\nglobs: *.rs\n---\nUse snake_case",
+        )
+        .unwrap();
+
+        let set = loader(dir.path()).load();
+        assert_eq!(set.files.len(), 2);
+        assert_eq!(set.files[1].content, "Use snake_case");
+        assert_eq!(set.skipped.len(), 1);
+        assert_eq!(set.skipped[0].reason, "duplicate content");
+
+        let config = RulesConfig {
+            sources: vec![RuleSource::Claude],
+            ..Default::default()
+        };
+        let set = loader(dir.path()).with_config(config).load();
+        assert_eq!(set.files.len(), 1);
+        assert_eq!(set.files[0].source, RuleSource::Claude);
+
+        let disabled = RulesConfig {
+            enabled: false,
+            ..Default::default()
+        };
+        assert!(loader(dir.path()).with_config(disabled).load().is_empty());
+    }
+
+    #[test]
+    fn test_token_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let long: String = (0..400).map(|i| format!("rule number {}\n", i)).collect();
+        fs::write(dir.path().join("AGENTS.md"), &long).unwrap();
+        fs::write(dir.path().join(".cursorrules"), "low priority rule").unwrap();
+
+        let config = RulesConfig {
+            max_tokens: 300,
+            ..Default::default()
+        };
+        let set = loader(dir.path()).with_config(config).load();
+
+        assert_eq!(set.files.len(), 1);
+        assert!(set.files[0].truncated);
+        assert!(set.total_tokens() <= 300);
+        assert!(set.skipped[0].reason.starts_with("token budget exceeded"));
+        assert!(set.to_prompt_section().unwrap().contains("truncated"));
+    }
+
+    #[test]
+    fn test_user_rules_lowest_precedence() {
+        let home = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        fs::create_dir_all(home.path().join(".forgecode")).unwrap();
+        fs::write(home.path().join(".forgecode/FORGE.md"), "user rule").unwrap();
+        fs::write(project.path().join("AGENTS.md"), "project rule").unwrap();
+
+        let set = RulesLoader::new(project.path())
+            .with_home_dir(Some(home.path().to_path_buf()))
+            .load();
+        assert_eq!(set.files.len(), 2);
+        assert_eq!(set.files[1].scope, RuleScope::User);
+        assert_eq!(set.files[1].display_path, "~/.forgecode/FORGE.md");
+
+        assert!(RuleSet::default().to_prompt_section().is_none());
+    }
+}
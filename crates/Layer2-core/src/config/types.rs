@@ -2,9 +2,11 @@
 //!
 //! Claude Code 호환 설정 스키마
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::rules::RulesConfig;
 use super::workflow::{GitConfig, SecurityConfig};
 
 // ============================================================================
@@ -12,7 +14,7 @@ use super::workflow::{GitConfig, SecurityConfig};
 // ============================================================================
 
 /// ForgeCode 통합 설정 (Claude Code 호환)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ForgeConfig {
     // ========================================================================
@@ -41,6 +43,14 @@ pub struct ForgeConfig {
     #[serde(default, rename = "mcpServers")]
     pub mcp_servers: HashMap<String, McpServerConfig>,
 
+    // ========================================================================
+    // 데이터베이스 연결 설정
+    // ========================================================================
+    /// `sql_query` 도구용 이름 → 연결 문자열
+    /// (예: `"app": "postgres://user@localhost/app"`, `"local": "data/dev.sqlite"`)
+    #[serde(default)]
+    pub databases: HashMap<String, String>,
+
     // ========================================================================
     // 권한 설정
     // ========================================================================
@@ -55,6 +65,20 @@ pub struct ForgeConfig {
     #[serde(default)]
     pub shell: ShellConfigSection,
 
+    // ========================================================================
+    // 도구 실행 설정
+    // ========================================================================
+    /// 도구 실행 타임아웃
+    #[serde(default)]
+    pub tools: ToolsConfigSection,
+
+    // ========================================================================
+    // 자동 포맷 설정
+    // ========================================================================
+    /// Write/Edit 후 자동 포맷
+    #[serde(default)]
+    pub format: FormatConfig,
+
     // ========================================================================
     // UI/테마 설정
     // ========================================================================
@@ -76,6 +100,13 @@ pub struct ForgeConfig {
     #[serde(default)]
     pub security: SecurityConfig,
 
+    // ========================================================================
+    // 규칙 파일 설정
+    // ========================================================================
+    /// 규칙 파일 수집 (AGENTS.md, CLAUDE.md, .cursorrules 등)
+    #[serde(default)]
+    pub rules: RulesConfig,
+
     // ========================================================================
     // 일반 설정
     // ========================================================================
@@ -119,11 +150,15 @@ impl Default for ForgeConfig {
             model: None,
             models: HashMap::new(),
             mcp_servers: HashMap::new(),
+            databases: HashMap::new(),
             permissions: PermissionConfig::default(),
             shell: ShellConfigSection::default(),
+            tools: ToolsConfigSection::default(),
+            format: FormatConfig::default(),
             theme: ThemeConfig::default(),
             git: GitConfig::default(),
             security: SecurityConfig::default(),
+            rules: RulesConfig::default(),
             auto_context: true,
             streaming: true,
             save_history: true,
@@ -166,7 +201,7 @@ impl ForgeConfig {
 // ============================================================================
 
 /// Provider 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProviderConfig {
     /// API 키
@@ -211,7 +246,7 @@ impl Default for ProviderConfig {
 // ============================================================================
 
 /// 모델별 설정
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelConfig {
     /// Temperature
@@ -236,7 +271,7 @@ pub struct ModelConfig {
 // ============================================================================
 
 /// MCP 서버 설정 (Claude Code 호환)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct McpServerConfig {
     /// 명령어 (stdio transport)
@@ -266,6 +301,22 @@ pub struct McpServerConfig {
     /// 타임아웃 (초)
     #[serde(default = "default_mcp_timeout")]
     pub timeout: u64,
+
+    /// 노출할 도구 (비어 있으면 전부, 끝의 `*`는 접두사 매칭)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include_tools: Vec<String>,
+
+    /// 숨길 도구 (`includeTools`보다 우선)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_tools: Vec<String>,
+
+    /// 연결 시 도구 목록을 미루고 처음 필요할 때 가져옴
+    #[serde(default)]
+    pub lazy_tools: bool,
+
+    /// 도구를 직접 노출하지 않고 `search_mcp_tools`로 찾게 함 (드물게 쓰는 서버용)
+    #[serde(default)]
+    pub collapse_tools: bool,
 }
 
 fn default_mcp_timeout() -> u64 {
@@ -282,6 +333,15 @@ impl McpServerConfig {
     pub fn is_sse(&self) -> bool {
         self.url.is_some()
     }
+
+    /// 도구 노출 정책
+    pub fn tool_policy(&self) -> crate::mcp::McpToolPolicy {
+        crate::mcp::McpToolPolicy::default()
+            .with_include(self.include_tools.iter().cloned())
+            .with_exclude(self.exclude_tools.iter().cloned())
+            .with_lazy(self.lazy_tools)
+            .with_collapsed(self.collapse_tools)
+    }
 }
 
 impl Default for McpServerConfig {
@@ -294,6 +354,10 @@ impl Default for McpServerConfig {
             enabled: true,
             auto_connect: false,
             timeout: default_mcp_timeout(),
+            include_tools: Vec::new(),
+            exclude_tools: Vec::new(),
+            lazy_tools: false,
+            collapse_tools: false,
         }
     }
 }
@@ -303,7 +367,7 @@ impl Default for McpServerConfig {
 // ============================================================================
 
 /// 권한 설정
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PermissionConfig {
     /// 자동 승인 패턴
@@ -328,7 +392,7 @@ pub struct PermissionConfig {
 }
 
 /// 권한 패턴
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PermissionPattern {
     /// Tool 이름 (또는 "*")
@@ -348,7 +412,7 @@ pub struct PermissionPattern {
 // ============================================================================
 
 /// Shell 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ShellConfigSection {
     /// 사용할 셸
@@ -388,12 +452,161 @@ impl Default for ShellConfigSection {
     }
 }
 
+// ============================================================================
+// ToolsConfigSection - 도구 실행 설정
+// ============================================================================
+
+/// 도구 실행 설정
+///
+/// ```json
+/// { "tools": { "timeout": 600, "timeouts": { "web_fetch": 60 } } }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolsConfigSection {
+    /// 기본 실행 타임아웃 (초, 0이면 제한 없음)
+    #[serde(default = "default_tool_timeout")]
+    pub timeout: u64,
+
+    /// 도구별 실행 타임아웃 (초, 0이면 제한 없음)
+    #[serde(default)]
+    pub timeouts: HashMap<String, u64>,
+}
+
+fn default_tool_timeout() -> u64 {
+    600
+}
+
+impl Default for ToolsConfigSection {
+    fn default() -> Self {
+        Self {
+            timeout: default_tool_timeout(),
+            timeouts: HashMap::new(),
+        }
+    }
+}
+
+// ============================================================================
+// FormatConfig - 자동 포맷 설정
+// ============================================================================
+
+/// 자동 포맷 설정 (Write/Edit 도구가 파일을 쓴 직후 적용)
+///
+/// 확장자별 외부 포맷터가 우선이고, 없으면 실행 중인 언어 서버의
+/// `textDocument/formatting`을 사용합니다.
+///
+/// ```json
+/// {
+///   "format": {
+///     "enabled": true,
+///     "formatters": {
+///       "rs": { "command": "rustfmt" },
+///       "ts": { "command": "prettier" },
+///       "py": { "command": "black", "args": ["-q", "-"] }
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FormatConfig {
+    /// 자동 포맷 사용 (기본: 꺼짐)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 외부 포맷터가 없는 파일에 언어 서버 포맷 사용
+    #[serde(default = "default_true")]
+    pub lsp: bool,
+
+    /// 확장자 → 외부 포맷터 (stdin으로 받아 stdout으로 출력)
+    #[serde(default)]
+    pub formatters: HashMap<String, FormatterCommand>,
+
+    /// 들여쓰기 크기 (언어 서버 포맷용)
+    #[serde(default = "default_tab_size")]
+    pub tab_size: u32,
+
+    /// 탭 대신 공백 사용 (언어 서버 포맷용)
+    #[serde(default = "default_true")]
+    pub insert_spaces: bool,
+}
+
+fn default_tab_size() -> u32 {
+    4
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lsp: true,
+            formatters: HashMap::new(),
+            tab_size: default_tab_size(),
+            insert_spaces: true,
+        }
+    }
+}
+
+impl FormatConfig {
+    /// 파일 확장자의 외부 포맷터
+    pub fn formatter_for(&self, path: &std::path::Path) -> Option<&FormatterCommand> {
+        let extension = path.extension()?.to_str()?;
+        self.formatters.iter().find_map(|(key, formatter)| {
+            key.trim_start_matches('.')
+                .eq_ignore_ascii_case(extension)
+                .then_some(formatter)
+        })
+    }
+}
+
+/// 외부 포맷터 명령
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FormatterCommand {
+    /// 실행 파일 (PATH에서 검색)
+    pub command: String,
+
+    /// 인자 (`{file}`은 파일 경로로 치환, 생략하면 알려진 포맷터의 기본 인자)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+}
+
+impl FormatterCommand {
+    /// 실제 인자 (`{file}` 치환 포함)
+    pub fn resolved_args(&self, path: &std::path::Path) -> Vec<String> {
+        let args = match &self.args {
+            Some(args) => args.clone(),
+            None => default_formatter_args(&self.command),
+        };
+        let file = path.to_string_lossy();
+        args.into_iter()
+            .map(|arg| arg.replace("{file}", &file))
+            .collect()
+    }
+}
+
+/// 알려진 포맷터의 stdin → stdout 인자
+fn default_formatter_args(command: &str) -> Vec<String> {
+    let name = std::path::Path::new(command)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(command);
+    let args: &[&str] = match name {
+        "rustfmt" => &["--edition", "2021"],
+        "prettier" => &["--stdin-filepath", "{file}"],
+        "black" => &["--quiet", "--stdin-filename", "{file}", "-"],
+        "ruff" => &["format", "--stdin-filename", "{file}", "-"],
+        "clang-format" => &["--assume-filename={file}"],
+        _ => &[],
+    };
+    args.iter().map(|s| s.to_string()).collect()
+}
+
 // ============================================================================
 // ThemeConfig - 테마 설정
 // ============================================================================
 
 /// 테마 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ThemeConfig {
     /// 컬러 스킴
@@ -475,6 +688,27 @@ mod tests {
         assert!(config.is_stdio());
         assert!(!config.is_sse());
         assert_eq!(config.command, Some("node".to_string()));
+        assert_eq!(config.tool_policy(), crate::mcp::McpToolPolicy::default());
+    }
+
+    #[test]
+    fn test_mcp_server_tool_policy() {
+        let json = r#"{
+            "url": "https://mcp.notion.com/sse",
+            "includeTools": ["search*", "get_page"],
+            "excludeTools": ["search_archive"],
+            "lazyTools": true,
+            "collapseTools": true
+        }"#;
+
+        let policy = serde_json::from_str::<McpServerConfig>(json)
+            .unwrap()
+            .tool_policy();
+        assert!(policy.lazy && policy.collapsed);
+        assert!(policy.allows("search_pages"));
+        assert!(policy.allows("get_page"));
+        assert!(!policy.allows("search_archive"));
+        assert!(!policy.allows("delete_page"));
     }
 
     #[test]
@@ -510,6 +744,44 @@ mod tests {
         std::env::remove_var("ANTHROPIC_API_KEY");
     }
 
+    #[test]
+    fn test_format_config_parse() {
+        let json = r#"{
+            "format": {
+                "enabled": true,
+                "formatters": {
+                    "rs": { "command": "rustfmt" },
+                    ".py": { "command": "black", "args": ["-q", "-"] },
+                    "ts": { "command": "/usr/local/bin/prettier" }
+                }
+            }
+        }"#;
+        let config: ForgeConfig = serde_json::from_str(json).unwrap();
+        let format = &config.format;
+        assert!(format.enabled);
+        assert!(format.lsp);
+        assert_eq!(format.tab_size, 4);
+
+        let path = std::path::Path::new("src/app.ts");
+        let rustfmt = format
+            .formatter_for(std::path::Path::new("src/main.rs"))
+            .unwrap();
+        assert_eq!(rustfmt.resolved_args(path), vec!["--edition", "2021"]);
+        let black = format
+            .formatter_for(std::path::Path::new("tool.PY"))
+            .unwrap();
+        assert_eq!(black.resolved_args(path), vec!["-q", "-"]);
+        let prettier = format.formatter_for(path).unwrap();
+        assert_eq!(
+            prettier.resolved_args(path),
+            vec!["--stdin-filepath", "src/app.ts"]
+        );
+        assert!(format
+            .formatter_for(std::path::Path::new("README"))
+            .is_none());
+        assert!(!ForgeConfig::default().format.enabled);
+    }
+
     #[test]
     fn test_git_config_parse() {
         let json = r#"{
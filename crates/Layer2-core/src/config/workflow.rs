@@ -28,19 +28,26 @@
 //!     "paths": {
 //!       "allowed": ["./", "/tmp"],
 //!       "denied": ["~/.ssh", "~/.aws"]
+//!     },
+//!     "secrets": {
+//!       "patterns": { "internal_token": "itk_[a-z0-9]{32}" },
+//!       "tools": ["read", "grep", "bash"]
 //!     }
 //!   }
 //! }
 //! ```
 
+use forge_foundation::EgressPolicy;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 // ============================================================================
 // Git Workflow Configuration
 // ============================================================================
 
 /// Git 워크플로우 설정
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GitConfig {
     /// 워크플로우 자동화
@@ -69,7 +76,7 @@ pub struct GitConfig {
 }
 
 /// Git 워크플로우 자동화 설정
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GitWorkflowConfig {
     /// 자동 커밋
@@ -90,7 +97,7 @@ pub struct GitWorkflowConfig {
 }
 
 /// 자동 커밋 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AutoCommitConfig {
     /// 활성화 여부
@@ -144,7 +151,7 @@ impl Default for AutoCommitConfig {
 }
 
 /// 자동 푸시 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AutoPushConfig {
     /// 활성화 여부
@@ -195,7 +202,7 @@ impl Default for AutoPushConfig {
 }
 
 /// 자동 태그 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AutoTagConfig {
     /// 활성화 여부
@@ -244,7 +251,7 @@ impl Default for AutoTagConfig {
 }
 
 /// 자동 스테이지 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AutoStageConfig {
     /// 활성화 여부
@@ -281,7 +288,7 @@ impl Default for AutoStageConfig {
 }
 
 /// Git Hooks 설정
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GitHooksConfig {
     /// pre-commit 시 실행할 명령어
@@ -318,7 +325,7 @@ fn default_hook_timeout() -> u64 {
 // ============================================================================
 
 /// 보안 설정
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SecurityConfig {
     /// 환경 변수 보안
@@ -336,10 +343,22 @@ pub struct SecurityConfig {
     /// 네트워크 보안
     #[serde(default)]
     pub network: NetworkSecurityConfig,
+
+    /// 비밀값 가리기
+    #[serde(default)]
+    pub secrets: SecretScanConfig,
+
+    /// 도구 호출 감독
+    #[serde(default)]
+    pub oversight: OversightSecurityConfig,
+
+    /// 감사 로그
+    #[serde(default)]
+    pub audit: AuditSecurityConfig,
 }
 
 /// 환경 변수 보안 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EnvSecurityConfig {
     /// 차단할 환경 변수 패턴 (glob)
@@ -479,7 +498,7 @@ impl EnvSecurityConfig {
 }
 
 /// 경로 보안 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PathSecurityConfig {
     /// 허용된 경로 (상대/절대)
@@ -527,7 +546,7 @@ impl Default for PathSecurityConfig {
 }
 
 /// 명령어 보안 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CommandSecurityConfig {
     /// 확인이 필요한 명령어 패턴
@@ -579,14 +598,17 @@ impl Default for CommandSecurityConfig {
 }
 
 /// 네트워크 보안 설정
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 도메인 규칙은 `network` 권한 카테고리 전체(HTTP 도구, MCP SSE 서버, 프로바이더 base URL)에
+/// 공통 HTTP 계층(`egress_client`)으로 적용됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkSecurityConfig {
-    /// 허용된 도메인 (빈 배열이면 모두 허용)
+    /// 허용된 도메인 패턴 (`example.com`, `*.example.com`; 빈 배열이면 모두 허용)
     #[serde(default)]
     pub allowed_domains: Vec<String>,
 
-    /// 차단된 도메인
+    /// 차단된 도메인 패턴 (허용보다 우선)
     #[serde(default)]
     pub blocked_domains: Vec<String>,
 
@@ -610,6 +632,125 @@ impl Default for NetworkSecurityConfig {
     }
 }
 
+impl NetworkSecurityConfig {
+    /// 도메인 규칙을 네트워크 egress 정책으로 변환
+    pub fn egress_policy(&self) -> EgressPolicy {
+        EgressPolicy::new(self.allowed_domains.clone(), self.blocked_domains.clone())
+    }
+}
+
+/// 비밀값 가리기 설정
+///
+/// 도구 결과와 LLM으로 보내는 메시지의 API 키, 개인 키, JWT 등을 `[REDACTED:종류]`로 바꿉니다.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretScanConfig {
+    /// 가리기 활성화
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// 추가 패턴 (종류 → 정규식), 기본 패턴에 더해짐
+    #[serde(default)]
+    pub patterns: BTreeMap<String, String>,
+
+    /// 엔트로피 휴리스틱 (대소문자/숫자가 섞인 긴 무작위 문자열)
+    #[serde(default = "default_true")]
+    pub entropy: bool,
+
+    /// 엔트로피 검사 최소 길이
+    #[serde(default = "default_secret_min_length")]
+    pub min_length: usize,
+
+    /// 최소 엔트로피 (문자당 bits)
+    #[serde(default = "default_secret_min_entropy")]
+    pub min_entropy: f64,
+
+    /// 결과를 가릴 도구
+    #[serde(default = "default_secret_tools")]
+    pub tools: Vec<String>,
+
+    /// LLM으로 보내는 메시지도 가림
+    #[serde(default = "default_true")]
+    pub redact_messages: bool,
+}
+
+impl Default for SecretScanConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            patterns: BTreeMap::new(),
+            entropy: true,
+            min_length: default_secret_min_length(),
+            min_entropy: default_secret_min_entropy(),
+            tools: default_secret_tools(),
+            redact_messages: true,
+        }
+    }
+}
+
+fn default_secret_min_length() -> usize {
+    20
+}
+
+fn default_secret_min_entropy() -> f64 {
+    4.0
+}
+
+fn default_secret_tools() -> Vec<String> {
+    vec!["read".to_string(), "grep".to_string(), "bash".to_string()]
+}
+
+/// 도구 호출 감독 설정 (`OversightAgent`)
+///
+/// 웹/HTTP 결과처럼 신뢰할 수 없는 콘텐츠를 읽은 세션의 도구 호출을 검사해
+/// 차단하거나 감사 로그에 표시합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OversightSecurityConfig {
+    /// 감독 활성화
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 외부 콘텐츠를 읽은 뒤의 코드 실행 허용 (끄면 차단)
+    #[serde(default)]
+    pub allow_external_code_execution: bool,
+
+    /// 결과를 외부 콘텐츠로 취급할 도구 (`mcp_` 도구는 결과에 의심 패턴이 있을 때만)
+    #[serde(default = "default_untrusted_tools")]
+    pub untrusted_tools: Vec<String>,
+}
+
+impl Default for OversightSecurityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            allow_external_code_execution: false,
+            untrusted_tools: default_untrusted_tools(),
+        }
+    }
+}
+
+fn default_untrusted_tools() -> Vec<String> {
+    vec![
+        "web_fetch".to_string(),
+        "web_search".to_string(),
+        "http_request".to_string(),
+        "download".to_string(),
+    ]
+}
+
+/// 감사 로그 설정
+///
+/// 켜면 권한 결정, 명령 실행, 파일 쓰기/삭제를 `AuditLogger`에 기록합니다.
+/// `forge audit report`가 이 기록을 집계합니다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditSecurityConfig {
+    /// 감사 로그 기록
+    #[serde(default)]
+    pub enabled: bool,
+}
+
 // ============================================================================
 // 헬퍼 함수
 // ============================================================================
@@ -5,9 +5,11 @@
 //!
 //! ## 기능
 //! - Provider (LLM) 호출
-//! - Tool 실행 (권한 검사 포함)
+//! - Tool 실행 (권한 검사, 도구별 타임아웃/취소 포함)
 //! - Task 관리 (로그 및 종료)
 //! - MCP 브릿지 통합
+//! - 서브시스템 가용성 추적 (LSP/MCP/RepoMap/Git 실패 시 기능 저하 모드)
+//! - 감사 로그 (권한 결정, 명령 실행, 파일 쓰기/삭제)
 //! - 에러 처리 및 복구
 //!
 //! ## 사용 예시
@@ -29,10 +31,27 @@
 //! let logs = ctx.get_task_logs(task_result.task_id).await;
 //! ```
 
-use crate::mcp::{McpBridge, McpClient, McpTransportConfig};
-use crate::tool::{RuntimeContext, ToolRegistry};
-use forge_foundation::{Error, PermissionAction, PermissionService, PermissionStatus, Result, Tool, ToolResult};
+use crate::capability::{probe_git, probe_lsp, probe_repomap, Capability, CapabilityMatrix};
+use crate::lsp::{load_lsp_configs, DiagnosticsStore, DiagnosticsTool, LspManager, WhoCallsTool};
+use crate::repomap::RepoAnalyzer;
+use crate::mcp::{
+    McpBridge, McpClient, McpPromptSkill, McpResource, McpResourceContent, McpResourceTool,
+    McpRestartPolicy, McpRoot, McpToolPolicy, McpToolSearchTool, McpTransportConfig, ServerStatus,
+};
+use crate::registry::DynamicToolRegistry;
+use crate::skill::{DryRunRecorder, SkillPlan};
+use crate::tool::{
+    record_audit, AutoFormatter, MiddlewareChain, OutputGovernor, RuntimeContext, ToolMiddleware,
+    ToolOutcome, ToolRegistry, ToolTimeouts,
+};
+use forge_foundation::audit::{AuditEntry, AuditLogger};
+use forge_foundation::{
+    CancellationToken, DocumentFormatter, DocumentSync, Error, ImageAttachment, PermissionAction,
+    PermissionDelegate, PermissionService, PermissionSimulation, PermissionStatus,
+    RequiredPermission, Result, Tool, ToolOutputSink, ToolResult,
+};
 use serde_json::Value;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -43,6 +62,12 @@ use tracing::{debug, error, info, warn};
 // Configuration
 // ============================================================================
 
+/// MCP 헬스 모니터 점검 간격
+pub const MCP_HEALTH_MONITOR_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 직접 등록할 MCP 도구 수 기본 한도 (넘치는 서버는 `search_mcp_tools` 뒤로 접힘)
+pub const DEFAULT_MCP_TOOL_LIMIT: usize = 40;
+
 /// Agent Context 설정
 #[derive(Debug, Clone)]
 pub struct AgentContextConfig {
@@ -66,6 +91,12 @@ pub struct AgentContextConfig {
 
     /// 도구 실행 전 권한 확인
     pub check_permissions: bool,
+
+    /// 도구 출력 크기 제한
+    pub output_governor: OutputGovernor,
+
+    /// 직접 등록할 MCP 도구 수 한도
+    pub mcp_tool_limit: usize,
 }
 
 impl Default for AgentContextConfig {
@@ -78,6 +109,8 @@ impl Default for AgentContextConfig {
             auto_connect_mcp: false,
             enable_lsp: false,
             check_permissions: true,
+            output_governor: OutputGovernor::default(),
+            mcp_tool_limit: DEFAULT_MCP_TOOL_LIMIT,
         }
     }
 }
@@ -103,6 +136,18 @@ pub struct McpToolStats {
 // Tool Execution Result
 // ============================================================================
 
+/// 도구 실행 종료 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToolExecutionStatus {
+    /// 도구가 끝까지 실행됨 (성공/실패는 `success`)
+    #[default]
+    Completed,
+    /// 도구별 타임아웃으로 중단됨
+    TimedOut,
+    /// 취소 토큰으로 중단됨
+    Cancelled,
+}
+
 /// 도구 실행 결과 (통합)
 #[derive(Debug, Clone)]
 pub struct ToolExecutionResult {
@@ -126,10 +171,43 @@ pub struct ToolExecutionResult {
 
     /// 권한이 부여되었는지
     pub permission_granted: bool,
+
+    /// 첨부 이미지 (vision 모델용, 도구 metadata `images`)
+    pub images: Vec<ImageAttachment>,
+
+    /// 종료 상태
+    pub status: ToolExecutionStatus,
+}
+
+impl ToolExecutionResult {
+    /// 타임아웃으로 중단되었는지
+    pub fn is_timed_out(&self) -> bool {
+        self.status == ToolExecutionStatus::TimedOut
+    }
+
+    /// 취소되었는지
+    pub fn is_cancelled(&self) -> bool {
+        self.status == ToolExecutionStatus::Cancelled
+    }
+}
+
+/// 도구 결과 metadata의 `images` 배열 추출
+fn result_images(result: &ToolResult) -> Vec<ImageAttachment> {
+    result
+        .metadata
+        .get("images")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// `mcp_tool_registry`에서 서버 도구의 제공자 이름
+fn mcp_tool_provider(server_name: &str) -> String {
+    format!("mcp:{}", server_name)
 }
 
 impl From<ToolResult> for ToolExecutionResult {
     fn from(result: ToolResult) -> Self {
+        let images = result_images(&result);
         Self {
             tool_name: String::new(),
             success: result.success,
@@ -138,6 +216,8 @@ impl From<ToolResult> for ToolExecutionResult {
             duration_ms: 0,
             permission_required: false,
             permission_granted: false,
+            images,
+            status: ToolExecutionStatus::Completed,
         }
     }
 }
@@ -159,11 +239,38 @@ pub struct AgentContext {
     /// 권한 서비스
     permissions: Option<Arc<PermissionService>>,
 
+    /// 권한 델리게이트 (도구가 권한을 요청할 때 승인 위임)
+    permission_delegate: std::sync::Mutex<Option<Arc<dyn PermissionDelegate>>>,
+
     /// MCP 브릿지
     mcp_bridge: Arc<RwLock<McpBridge>>,
 
+    /// 접힌 서버에서 `search_mcp_tools`로 로드한 도구
+    mcp_loaded_tools: Arc<std::sync::Mutex<HashSet<String>>>,
+
+    /// MCP 서버에 알려줄 프로젝트 디렉토리 (기본: 작업 디렉토리)
+    mcp_roots: std::sync::RwLock<Vec<McpRoot>>,
+
+    /// 서버별 MCP 도구 목록 (제공자 `mcp:<server>`, 변경 이벤트 구독용)
+    mcp_tool_registry: Arc<DynamicToolRegistry>,
+
     /// 실행 통계
     stats: Arc<RwLock<ExecutionStats>>,
+
+    /// 서브시스템 가용성
+    capabilities: Arc<CapabilityMatrix>,
+
+    /// dry-run 레코더 (설정 시 권한이 필요한 도구 호출은 기록만 하고 실행하지 않음)
+    dry_run: std::sync::Mutex<Option<Arc<DryRunRecorder>>>,
+
+    /// 감사 로거 (권한이 필요한 도구 실행과 권한 결정 기록)
+    audit_logger: Option<Arc<AuditLogger>>,
+
+    /// LSP 매니저 (`enable_lsp`일 때만, 진단 버퍼 포함)
+    lsp: Option<Arc<LspManager>>,
+
+    /// Write/Edit 후 자동 포맷 (프로젝트 설정 `format.enabled`일 때만)
+    formatter: Option<Arc<AutoFormatter>>,
 }
 
 /// 실행 통계
@@ -193,23 +300,139 @@ impl AgentContext {
 
     /// 기본 설정으로 생성
     pub fn new() -> Self {
-        Self {
-            config: AgentContextConfig::default(),
-            tools: Arc::new(RwLock::new(ToolRegistry::with_builtins())),
-            permissions: None,
-            mcp_bridge: Arc::new(RwLock::new(McpBridge::new())),
-            stats: Arc::new(RwLock::new(ExecutionStats::default())),
-        }
+        Self::with_config(AgentContextConfig::default())
     }
 
     /// 설정과 함께 생성
     pub fn with_config(config: AgentContextConfig) -> Self {
-        Self {
+        let mcp_roots = vec![McpRoot::from_path(&config.working_directory)];
+        let mut registry = ToolRegistry::with_builtins();
+        let lsp = register_lsp(&config, &mut registry);
+        let formatter = load_formatter(&config, &lsp);
+        let ctx = Self {
             config,
-            tools: Arc::new(RwLock::new(ToolRegistry::with_builtins())),
+            tools: Arc::new(RwLock::new(registry)),
             permissions: None,
+            permission_delegate: std::sync::Mutex::new(None),
             mcp_bridge: Arc::new(RwLock::new(McpBridge::new())),
+            mcp_loaded_tools: Arc::default(),
+            mcp_roots: std::sync::RwLock::new(mcp_roots),
+            mcp_tool_registry: Arc::default(),
             stats: Arc::new(RwLock::new(ExecutionStats::default())),
+            capabilities: Arc::new(CapabilityMatrix::new()),
+            dry_run: std::sync::Mutex::new(None),
+            audit_logger: None,
+            lsp,
+            formatter,
+        };
+        ctx.probe_capabilities();
+        ctx
+    }
+
+    // ========================================================================
+    // Dry-run
+    // ========================================================================
+
+    /// dry-run 모드 설정/해제
+    ///
+    /// 설정된 동안 권한이 필요한 도구 호출은 레코더에 기록되고
+    /// "실행되지 않음" 결과를 반환합니다. 읽기 전용 호출은 그대로 실행됩니다.
+    pub fn set_dry_run(&self, recorder: Option<Arc<DryRunRecorder>>) {
+        *self.dry_run.lock().unwrap_or_else(|e| e.into_inner()) = recorder;
+    }
+
+    /// 현재 dry-run 레코더
+    pub fn dry_run_recorder(&self) -> Option<Arc<DryRunRecorder>> {
+        self.dry_run.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// dry-run 모드 여부
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run_recorder().is_some()
+    }
+
+    // ========================================================================
+    // Permission Delegate
+    // ========================================================================
+
+    /// 권한 델리게이트 설정/해제
+    ///
+    /// 설정된 동안 도구가 요청한 미확정(Unknown) 권한은 델리게이트가 결정합니다.
+    /// 없으면 권한 서비스에 이미 있는 허용만 적용됩니다.
+    pub fn set_permission_delegate(&self, delegate: Option<Arc<dyn PermissionDelegate>>) {
+        *self.permission_delegate.lock().unwrap_or_else(|e| e.into_inner()) = delegate;
+    }
+
+    /// 현재 권한 델리게이트
+    pub fn permission_delegate(&self) -> Option<Arc<dyn PermissionDelegate>> {
+        self.permission_delegate
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    // ========================================================================
+    // LSP
+    // ========================================================================
+
+    /// LSP 매니저 (`enable_lsp`가 꺼져 있으면 `None`)
+    pub fn lsp_manager(&self) -> Option<Arc<LspManager>> {
+        self.lsp.clone()
+    }
+
+    /// Write/Edit 후 자동 포맷터 (프로젝트 설정에서 꺼져 있으면 `None`)
+    pub fn formatter(&self) -> Option<Arc<AutoFormatter>> {
+        self.formatter.clone()
+    }
+
+    /// 문서별 LSP 진단 버퍼 (편집 후 피드백용)
+    pub fn diagnostics_store(&self) -> Option<Arc<DiagnosticsStore>> {
+        self.lsp.as_ref().map(|lsp| lsp.diagnostics())
+    }
+
+    /// 작업 디렉토리의 RepoMap 분석기 (LSP가 켜져 있으면 언어 서버 심볼 사용)
+    ///
+    /// 프로젝트 증분 인덱스(`.forgecode/cache/repomap.db`)를 사용합니다.
+    pub fn repo_analyzer(&self) -> RepoAnalyzer {
+        let analyzer =
+            RepoAnalyzer::with_defaults(&self.config.working_directory).with_project_cache();
+        match &self.lsp {
+            Some(lsp) => analyzer.with_lsp(Arc::clone(lsp)),
+            None => analyzer,
+        }
+    }
+
+    // ========================================================================
+    // Capabilities
+    // ========================================================================
+
+    /// 서브시스템 가용성 매트릭스
+    pub fn capabilities(&self) -> Arc<CapabilityMatrix> {
+        Arc::clone(&self.capabilities)
+    }
+
+    /// LSP/RepoMap/Git 가용성 확인
+    ///
+    /// 실패해도 에러를 반환하지 않고 상태만 기록합니다 (기능 저하 모드).
+    /// MCP 상태는 서버 연결 시 기록됩니다.
+    pub fn probe_capabilities(&self) {
+        let dir = &self.config.working_directory;
+
+        if self.config.enable_lsp {
+            let configs = match &self.lsp {
+                Some(lsp) => lsp.configs().to_vec(),
+                None => load_lsp_configs(dir),
+            };
+            self.capabilities.set(Capability::Lsp, probe_lsp(&configs));
+        } else {
+            self.capabilities.mark_disabled(Capability::Lsp);
+        }
+
+        self.capabilities.set(Capability::RepoMap, probe_repomap(dir));
+        self.capabilities.set(Capability::Git, probe_git(dir));
+
+        if let Some(summary) = self.capabilities.summary() {
+            warn!("Running with reduced capability: {}", summary);
         }
     }
 
@@ -219,16 +442,143 @@ impl AgentContext {
 
     /// 도구 실행
     pub async fn execute_tool(&self, name: &str, input: Value) -> Result<ToolExecutionResult> {
+        self.execute_tool_with_sink(name, input, None).await
+    }
+
+    /// 도구 실행 (실행 중 출력을 `sink`로 전달)
+    pub async fn execute_tool_with_sink(
+        &self,
+        name: &str,
+        input: Value,
+        sink: Option<Arc<dyn ToolOutputSink>>,
+    ) -> Result<ToolExecutionResult> {
+        self.execute_tool_cancellable(name, input, sink, CancellationToken::new())
+            .await
+    }
+
+    /// 도구 실행 (`cancel`을 취소하면 실행 중인 도구가 중단됨)
+    ///
+    /// 도구별 타임아웃(`ToolRegistry::timeout_for`)이 지나도 `cancel`이 취소되며,
+    /// 결과의 `status`가 `TimedOut`/`Cancelled`로 설정됩니다.
+    pub async fn execute_tool_cancellable(
+        &self,
+        name: &str,
+        input: Value,
+        sink: Option<Arc<dyn ToolOutputSink>>,
+        cancel: CancellationToken,
+    ) -> Result<ToolExecutionResult> {
+        self.run_tool(name, input, sink, cancel, None).await
+    }
+
+    /// 등록된 도구 대신 `runner`로 실행
+    ///
+    /// 권한 확인, 미들웨어, 감사 로그, 통계는 등록된 `name` 도구와 똑같이 적용되고
+    /// 실제 실행만 `runner`가 맡습니다 (예: TaskManager로 보내는 bash).
+    /// 실행 시간은 `runner`가 관리하므로 도구별 타임아웃은 적용하지 않습니다.
+    pub async fn execute_tool_via(
+        &self,
+        name: &str,
+        input: Value,
+        runner: Arc<dyn Tool>,
+    ) -> Result<ToolExecutionResult> {
+        self.run_tool(name, input, None, CancellationToken::new(), Some(runner))
+            .await
+    }
+
+    async fn run_tool(
+        &self,
+        name: &str,
+        input: Value,
+        sink: Option<Arc<dyn ToolOutputSink>>,
+        cancel: CancellationToken,
+        runner: Option<Arc<dyn Tool>>,
+    ) -> Result<ToolExecutionResult> {
         let start = std::time::Instant::now();
 
         // 도구 조회
-        let tool = {
+        let (tool, timeout, middleware) = {
             let tools = self.tools.read().await;
-            tools.get(name).ok_or_else(|| {
-                Error::NotFound(format!("Tool '{}' not found", name))
-            })?
+            match tools.get(name) {
+                Some(tool) => (tool, tools.timeout_for(name), tools.middleware().clone()),
+                None => {
+                    // 연결 실패한 MCP 서버의 도구는 등록되지 않음
+                    if name.starts_with("mcp_") {
+                        if let Some(reason) = self.capabilities.status(Capability::Mcp).reason() {
+                            return Err(Error::capability_unavailable(
+                                Capability::Mcp.name(),
+                                reason,
+                            ));
+                        }
+                    }
+                    return Err(Error::NotFound(format!("Tool '{}' not found", name)));
+                }
+            }
         };
 
+        // 의존 서브시스템 확인
+        let meta = tool.meta();
+        if let Some(capability) = Capability::for_tool(&meta) {
+            self.capabilities.require(capability)?;
+        }
+
+        // 읽기 전용 모드: 명령어 실행과 네트워크 도구는 권한이 필요 없는 호출도 차단
+        // (안전으로 분류된 `cargo test`, `npm test`도 build.rs나 npm 스크립트를 실행함)
+        let read_only_refusal = if !self.is_read_only() {
+            None
+        } else if matches!(meta.category.as_str(), "network" | "web") {
+            Some("makes network requests")
+        } else if meta.category == "execute"
+            || matches!(
+                tool.required_permission(&input),
+                Some(PermissionAction::Execute { .. })
+            )
+        {
+            Some("runs commands")
+        } else {
+            None
+        };
+        if let Some(refusal) = read_only_refusal {
+            let mut stats = self.stats.write().await;
+            stats.permission_denials += 1;
+
+            return Ok(ToolExecutionResult {
+                tool_name: name.to_string(),
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "'{}' {}, which is not allowed in read-only mode",
+                    name, refusal
+                )),
+                duration_ms: start.elapsed().as_millis() as u64,
+                permission_required: true,
+                permission_granted: false,
+                images: Vec::new(),
+                status: ToolExecutionStatus::Completed,
+            });
+        }
+
+        // dry-run: 권한이 필요한 호출은 기록만 함
+        if let Some(recorder) = self.dry_run_recorder() {
+            if let Some(action) = tool.required_permission(&input) {
+                let step = recorder.record(name, &input, &action);
+                debug!("Dry-run recorded '{}': {}", name, step.summary);
+                return Ok(ToolExecutionResult {
+                    tool_name: name.to_string(),
+                    success: true,
+                    output: format!(
+                        "[dry-run] Not executed: {}. Recorded in the plan; continue as if it succeeded.",
+                        action.description()
+                    ),
+                    error: None,
+                    duration_ms: start.elapsed().as_millis() as u64,
+                    permission_required: true,
+                    permission_granted: false,
+                    images: Vec::new(),
+                    status: ToolExecutionStatus::Completed,
+                });
+            }
+        }
+
         // 권한 확인
         let permission_required = tool.required_permission(&input).is_some();
         let mut permission_granted = !permission_required;
@@ -243,6 +593,10 @@ impl AgentContext {
                         }
                         PermissionStatus::Denied => {
                             permission_granted = false;
+                            self.audit(AuditEntry::permission_decision(
+                                name, &action, false, "policy",
+                            ))
+                            .await;
                             let mut stats = self.stats.write().await;
                             stats.permission_denials += 1;
 
@@ -257,6 +611,8 @@ impl AgentContext {
                                 duration_ms: start.elapsed().as_millis() as u64,
                                 permission_required,
                                 permission_granted,
+                                images: Vec::new(),
+                                status: ToolExecutionStatus::Completed,
                             });
                         }
                         PermissionStatus::Unknown => {
@@ -272,18 +628,68 @@ impl AgentContext {
         }
 
         // RuntimeContext 생성
-        let runtime_ctx = RuntimeContext::new(
+        let mut runtime_ctx = RuntimeContext::new(
             &self.config.session_id,
             self.config.working_directory.clone(),
             self.permissions.clone().unwrap_or_else(|| Arc::new(PermissionService::new())),
-        );
+        )
+        .with_output_sink(sink)
+        .with_cancellation(cancel)
+        .with_audit_logger(self.audit_logger.clone())
+        .with_document_sync(self.lsp.clone().map(|lsp| lsp as Arc<dyn DocumentSync>))
+        .with_formatter(self.formatter.clone().map(|f| f as Arc<dyn DocumentFormatter>));
+        if let Some(delegate) = self.permission_delegate() {
+            runtime_ctx = runtime_ctx.with_permission_delegate(delegate);
+        }
 
-        // 도구 실행
+        // 도구 실행 (미들웨어, 타임아웃/취소 적용)
         debug!("Executing tool '{}' with input: {:?}", name, input);
 
-        let result = tool.execute(input, &runtime_ctx).await;
+        let audited_action = self
+            .audit_logger
+            .as_ref()
+            .and_then(|_| tool.required_permission(&input));
+        let (executor, timeout) = match &runner {
+            Some(runner) => (runner.as_ref(), None),
+            None => (tool.as_ref(), timeout),
+        };
+        let outcome = middleware.execute(executor, input, &runtime_ctx, timeout).await;
         let duration_ms = start.elapsed().as_millis() as u64;
 
+        let result = match outcome {
+            ToolOutcome::Finished(result) => result,
+            interrupted => {
+                let status = match interrupted {
+                    ToolOutcome::TimedOut(_) => ToolExecutionStatus::TimedOut,
+                    _ => ToolExecutionStatus::Cancelled,
+                };
+                warn!("Tool '{}' interrupted: {:?}", name, status);
+                {
+                    let mut stats = self.stats.write().await;
+                    stats.tool_executions += 1;
+                    stats.tool_failures += 1;
+                    stats.total_duration_ms += duration_ms;
+                }
+                return Ok(ToolExecutionResult {
+                    tool_name: name.to_string(),
+                    success: false,
+                    output: String::new(),
+                    error: interrupted.interruption(),
+                    duration_ms,
+                    permission_required,
+                    permission_granted,
+                    images: Vec::new(),
+                    status,
+                });
+            }
+        };
+
+        if let Some(action) = &audited_action {
+            let success = matches!(&result, Ok(r) if r.success);
+            self.audit(AuditEntry::for_action(name, action, success).with_duration(duration_ms))
+                .await;
+        }
+
         // 통계 업데이트
         {
             let mut stats = self.stats.write().await;
@@ -299,12 +705,14 @@ impl AgentContext {
         match result {
             Ok(tool_result) => Ok(ToolExecutionResult {
                 tool_name: name.to_string(),
+                images: result_images(&tool_result),
                 success: tool_result.success,
-                output: tool_result.output,
+                output: self.config.output_governor.apply(name, tool_result.output),
                 error: tool_result.error,
                 duration_ms,
                 permission_required,
                 permission_granted,
+                status: ToolExecutionStatus::Completed,
             }),
             Err(e) => {
                 error!("Tool '{}' execution failed: {}", name, e);
@@ -316,11 +724,26 @@ impl AgentContext {
                     duration_ms,
                     permission_required,
                     permission_granted,
+                    images: Vec::new(),
+                    status: ToolExecutionStatus::Completed,
                 })
             }
         }
     }
 
+    /// 감사 로그 기록 (로거가 없으면 무시)
+    async fn audit(&self, entry: AuditEntry) {
+        if let Some(logger) = &self.audit_logger {
+            record_audit(
+                logger,
+                entry,
+                &self.config.session_id,
+                &self.config.working_directory,
+            )
+            .await;
+        }
+    }
+
     /// 여러 도구 병렬 실행
     pub async fn execute_tools_parallel(
         &self,
@@ -351,8 +774,9 @@ impl AgentContext {
             .collect()
     }
 
-    /// 도구 스키마 조회
+    /// 도구 스키마 조회 (지연 로딩 MCP 서버는 이때 도구 목록을 가져옴)
     pub async fn get_tool_schemas(&self) -> Vec<Value> {
+        self.load_pending_mcp_tools().await;
         let tools = self.tools.read().await;
         tools.schemas()
     }
@@ -365,12 +789,32 @@ impl AgentContext {
         info!("Registered tool: {}", name);
     }
 
+    /// 도구 미들웨어 추가
+    pub async fn add_tool_middleware(&self, middleware: Arc<dyn ToolMiddleware>) {
+        let mut tools = self.tools.write().await;
+        info!("Added tool middleware: {}", middleware.name());
+        tools.add_middleware(middleware);
+    }
+
     /// 도구 존재 여부 확인
     pub async fn has_tool(&self, name: &str) -> bool {
         let tools = self.tools.read().await;
         tools.contains(name)
     }
 
+    /// 도구 조회
+    pub async fn get_tool(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        let tools = self.tools.read().await;
+        tools.get(name)
+    }
+
+    /// Sub-agent용 도구 레지스트리 (허용/금지 목록이 적용된 뷰)
+    pub async fn subagent_tools(&self, config: &forge_task::SubAgentConfig) -> ToolRegistry {
+        self.load_pending_mcp_tools().await;
+        let tools = self.tools.read().await;
+        tools.for_subagent(config)
+    }
+
     // ========================================================================
     // Permission Management
     // ========================================================================
@@ -394,6 +838,54 @@ impl AgentContext {
         }
     }
 
+    /// 읽기 전용 모드 여부 (권한 서비스 설정)
+    pub fn is_read_only(&self) -> bool {
+        self.permissions.as_ref().is_some_and(|p| p.is_read_only())
+    }
+
+    /// 가상 도구 호출에 필요한 권한 (실행하거나 요청하지 않음)
+    ///
+    /// 권한 서비스가 없거나 권한 검사가 꺼져 있으면 모두 허용된 것으로 봅니다.
+    pub async fn simulate_tool(&self, name: &str, input: &Value) -> Result<PermissionSimulation> {
+        let tool = self
+            .tools
+            .read()
+            .await
+            .get(name)
+            .ok_or_else(|| Error::NotFound(format!("Tool '{}' not found", name)))?;
+
+        Ok(match &self.permissions {
+            Some(permissions) if self.config.check_permissions => {
+                permissions.simulate(tool.as_ref(), input)
+            }
+            _ => PermissionSimulation::new(
+                name,
+                tool.required_permissions(input)
+                    .into_iter()
+                    .map(|action| RequiredPermission {
+                        action,
+                        status: PermissionStatus::Granted,
+                    })
+                    .collect(),
+            ),
+        })
+    }
+
+    /// 계획의 실행 단계별 필요한 권한 (등록되지 않은 도구의 단계는 건너뜀)
+    pub async fn simulate_plan(&self, plan: &SkillPlan) -> Vec<PermissionSimulation> {
+        let mut simulations = Vec::new();
+        for step in plan.executable_steps() {
+            let Some(tool) = &step.tool else {
+                continue;
+            };
+            match self.simulate_tool(tool, &step.input).await {
+                Ok(simulation) => simulations.push(simulation),
+                Err(e) => debug!("Cannot simulate plan step '{}': {}", step.summary, e),
+            }
+        }
+        simulations
+    }
+
     // ========================================================================
     // Context Information
     // ========================================================================
@@ -436,38 +928,74 @@ impl AgentContext {
         name: &str,
         config: McpTransportConfig,
     ) -> Result<()> {
-        let mut client = McpClient::new(name);
+        self.connect_mcp_server_with_policy(name, config, McpToolPolicy::default())
+            .await
+    }
+
+    /// 도구 노출 정책과 함께 MCP 서버 연결
+    ///
+    /// `lazy`면 `tools/list`를 처음 필요할 때까지 미루고, `collapsed`면
+    /// 도구를 직접 등록하지 않고 `search_mcp_tools`로만 찾게 합니다.
+    pub async fn connect_mcp_server_with_policy(
+        &self,
+        name: &str,
+        config: McpTransportConfig,
+        policy: McpToolPolicy,
+    ) -> Result<()> {
+        let mut client = McpClient::new(name)
+            .with_lazy_tools(policy.lazy)
+            .with_roots(self.mcp_roots());
         client.connect(&config).await?;
 
         // Bridge에 추가
-        let bridge = self.mcp_bridge.read().await;
-        bridge.add_server_with_config(client, config).await;
+        {
+            let bridge = self.mcp_bridge.read().await;
+            bridge.add_server_with_policy(client, config, policy).await;
+        }
 
         // 도구 등록
         self.refresh_mcp_tools().await?;
 
+        if !self.capabilities.status(Capability::Mcp).is_degraded() {
+            self.capabilities.mark_available(Capability::Mcp);
+        }
+
         info!("Connected MCP server '{}' and registered tools", name);
         Ok(())
     }
 
+    /// MCP 서버에 알려주는 프로젝트 디렉토리
+    pub fn mcp_roots(&self) -> Vec<McpRoot> {
+        self.mcp_roots
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 프로젝트 디렉토리 변경 (연결된 모든 MCP 서버에 `roots/list_changed` 알림)
+    pub async fn set_mcp_roots(&self, roots: Vec<McpRoot>) {
+        *self.mcp_roots.write().unwrap_or_else(|e| e.into_inner()) = roots.clone();
+        let bridge = self.mcp_bridge.read().await;
+        bridge.set_roots(roots).await;
+    }
+
     /// MCP 서버 연결 해제
     pub async fn disconnect_mcp_server(&self, name: &str) -> Result<()> {
-        let bridge = self.mcp_bridge.read().await;
-        bridge.remove_server(name).await;
+        {
+            let bridge = self.mcp_bridge.read().await;
+            bridge.remove_server(name).await;
+        }
 
-        // 해당 서버의 도구들 제거
-        let mut tools = self.tools.write().await;
+        // 해당 서버의 도구들 제거 (검색으로 로드한 도구 포함)
         let prefix = format!("mcp_{}_", name);
-        let to_remove: Vec<String> = tools
-            .names()
-            .iter()
-            .filter(|n| n.starts_with(&prefix))
-            .map(|n| n.to_string())
-            .collect();
-
-        for tool_name in to_remove {
-            tools.remove(&tool_name);
-        }
+        self.mcp_loaded_tools
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|tool_name| !tool_name.starts_with(&prefix));
+        self.mcp_tool_registry
+            .sync_provider(&mcp_tool_provider(name), Vec::new())
+            .await?;
+        self.refresh_mcp_tools().await?;
 
         info!("Disconnected MCP server '{}' and removed tools", name);
         Ok(())
@@ -476,43 +1004,110 @@ impl AgentContext {
     /// MCP 도구 새로고침 (모든 연결된 서버의 도구를 ToolRegistry에 등록)
     ///
     /// 이 메서드는:
-    /// 1. 기존 MCP 도구를 모두 제거
-    /// 2. 연결된 모든 MCP 서버에서 도구를 가져옴
-    /// 3. ToolRegistry에 새로 등록
+    /// 1. 연결된 MCP 서버에서 정책(include/exclude)을 적용한 도구를 가져옴
+    ///    (아직 목록을 가져오지 않은 지연 로딩 서버는 건너뜀)
+    /// 2. `collapsed` 서버와 도구 수 한도(`mcp_tool_limit`)를 넘기는 서버는 접음
+    /// 3. 기존 MCP 도구를 모두 제거하고 새로 등록 (접힌 서버는 검색으로 로드한 도구만)
+    /// 4. 접힌 서버가 있으면 `search_mcp_tools` 도구 등록 (없으면 제거)
+    /// 5. 서버가 있으면 `resource_read` 도구 등록 (없으면 제거)
+    /// 6. 서버별 전체 도구 목록을 `mcp_tool_registry`에 동기화
     pub async fn refresh_mcp_tools(&self) -> Result<()> {
         let bridge = self.mcp_bridge.read().await;
+        let mut servers = bridge.list_servers().await;
+        servers.sort();
+        let pending = bridge.pending_tool_servers().await;
+        let loaded = self
+            .mcp_loaded_tools
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+
+        let mut direct: Vec<(String, Vec<Arc<dyn Tool>>)> = Vec::new();
+        let mut synced: Vec<(String, Vec<Arc<dyn Tool>>)> = Vec::new();
+        let mut collapsed = Vec::new();
+        let mut direct_count = 0;
+        for server_name in &servers {
+            if pending.contains(server_name) {
+                // 지연 로딩: 처음 필요할 때 가져옴
+                if bridge.tool_policy(server_name).await.is_some_and(|p| p.collapsed) {
+                    collapsed.push(server_name.clone());
+                }
+                continue;
+            }
+
+            let server_tools = bridge.get_server_tools(server_name).await;
+            synced.push((server_name.clone(), server_tools.clone()));
+            let policy = bridge.tool_policy(server_name).await.unwrap_or_default();
+            let fits = direct_count + server_tools.len() <= self.config.mcp_tool_limit;
+            if !policy.collapsed && fits {
+                direct_count += server_tools.len();
+                direct.push((server_name.clone(), server_tools));
+                continue;
+            }
+
+            if !policy.collapsed {
+                info!(
+                    "MCP server '{}' has {} tools; collapsing behind {}",
+                    server_name,
+                    server_tools.len(),
+                    McpToolSearchTool::NAME
+                );
+            }
+            collapsed.push(server_name.clone());
+            let found: Vec<Arc<dyn Tool>> = server_tools
+                .into_iter()
+                .filter(|tool| loaded.contains(&tool.meta().name))
+                .collect();
+            direct.push((server_name.clone(), found));
+        }
+
+        for (server_name, server_tools) in synced {
+            self.mcp_tool_registry
+                .sync_provider(&mcp_tool_provider(&server_name), server_tools)
+                .await?;
+        }
+
         let mut tools = self.tools.write().await;
 
-        // 기존 MCP 도구 제거
+        // 기존 MCP 도구 제거 후 서버별로 등록
         tools.remove_all_mcp_tools();
-
-        // 서버별로 도구 등록
-        for server_name in bridge.list_servers().await {
-            let server_tools = bridge.get_server_tools(&server_name).await;
+        for (server_name, server_tools) in direct {
             if !server_tools.is_empty() {
                 tools.add_mcp_tools(&server_name, server_tools);
             }
         }
 
+        if collapsed.is_empty() {
+            tools.remove(McpToolSearchTool::NAME);
+        } else {
+            tools.register(Arc::new(McpToolSearchTool::new(
+                Arc::clone(&self.mcp_bridge),
+                Arc::downgrade(&self.tools),
+                collapsed,
+                Arc::clone(&self.mcp_loaded_tools),
+            )));
+        }
+
+        if servers.is_empty() {
+            tools.remove(McpResourceTool::NAME);
+        } else if !tools.contains(McpResourceTool::NAME) {
+            tools.register(Arc::new(McpResourceTool::new(Arc::clone(&self.mcp_bridge))));
+        }
+
         let count = tools.mcp_tool_count();
         debug!("Refreshed {} MCP tools in registry", count);
         Ok(())
     }
 
-    /// 특정 MCP 서버의 도구만 새로고침
+    /// 특정 MCP 서버의 도구만 새로고침 (지연 로딩 서버는 이때 목록을 가져옴)
+    ///
+    /// 도구 수 한도와 접힘은 전체 서버 기준이라 등록은 `refresh_mcp_tools`로 다시 합니다.
     pub async fn refresh_mcp_server_tools(&self, server_name: &str) -> Result<()> {
-        let bridge = self.mcp_bridge.read().await;
-        let mut tools = self.tools.write().await;
-
-        // 해당 서버의 기존 도구 제거
-        tools.remove_mcp_tools(server_name);
-
-        // 새 도구 등록
-        let server_tools = bridge.get_server_tools(server_name).await;
-        let tool_count = server_tools.len();
-        if !server_tools.is_empty() {
-            tools.add_mcp_tools(server_name, server_tools);
-        }
+        let tool_count = {
+            let bridge = self.mcp_bridge.read().await;
+            bridge.get_server_tools(server_name).await.len()
+        };
+        self.refresh_mcp_tools().await?;
 
         debug!(
             "Refreshed MCP tools for server '{}': {} tools",
@@ -522,6 +1117,79 @@ impl AgentContext {
         Ok(())
     }
 
+    /// 서버별 MCP 도구 목록 (`tools/list_changed`마다 동기화되며, `subscribe`로 변경 구독)
+    pub fn mcp_tool_registry(&self) -> &Arc<DynamicToolRegistry> {
+        &self.mcp_tool_registry
+    }
+
+    /// `notifications/tools/list_changed` 처리 (도구 목록을 다시 가져와 재등록)
+    pub async fn handle_mcp_tools_changed(&self, server_name: &str) -> Result<()> {
+        let tool_count = {
+            let bridge = self.mcp_bridge.read().await;
+            bridge.reload_server_tools(server_name).await?
+        };
+        self.refresh_mcp_tools().await?;
+
+        info!(
+            "MCP server '{}' changed its tools; {} tools now available",
+            server_name, tool_count
+        );
+        Ok(())
+    }
+
+    /// MCP 도구 변경 알림을 받아 도구를 다시 동기화하는 태스크 시작
+    ///
+    /// 컨텍스트가 사라지면 종료합니다. 알림을 놓치면 전체를 새로고침합니다.
+    pub fn start_mcp_tool_sync(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let ctx = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut changed = match ctx.upgrade() {
+                Some(ctx) => ctx.mcp_bridge.read().await.subscribe_tools_changed(),
+                None => return,
+            };
+            loop {
+                let server = match changed.recv().await {
+                    Ok(server) => Some(server),
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => None,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                };
+                let Some(ctx) = ctx.upgrade() else {
+                    break;
+                };
+                let result = match &server {
+                    Some(server) => ctx.handle_mcp_tools_changed(server).await,
+                    None => ctx.refresh_mcp_tools().await,
+                };
+                if let Err(e) = result {
+                    warn!("Failed to sync changed MCP tools: {}", e);
+                }
+            }
+        })
+    }
+
+    /// 지연 로딩 서버의 도구 목록 가져오기 (접힌 서버는 검색할 때까지 미룸)
+    async fn load_pending_mcp_tools(&self) {
+        let loaded = {
+            let bridge = self.mcp_bridge.read().await;
+            let mut loaded = false;
+            for server_name in bridge.pending_tool_servers().await {
+                if bridge.tool_policy(&server_name).await.is_some_and(|p| p.collapsed) {
+                    continue;
+                }
+                let count = bridge.get_server_tools(&server_name).await.len();
+                debug!("Loaded {} tools from lazy MCP server '{}'", count, server_name);
+                loaded = true;
+            }
+            loaded
+        };
+
+        if loaded {
+            if let Err(e) = self.refresh_mcp_tools().await {
+                warn!("Failed to register lazily loaded MCP tools: {}", e);
+            }
+        }
+    }
+
     /// MCP 도구 통계
     pub async fn mcp_tool_stats(&self) -> McpToolStats {
         let tools = self.tools.read().await;
@@ -540,6 +1208,35 @@ impl AgentContext {
         bridge.list_servers().await
     }
 
+    /// 연결된 MCP 서버들의 리소스 목록 (서버 이름, 리소스)
+    pub async fn list_mcp_resources(&self) -> Vec<(String, McpResource)> {
+        let bridge = self.mcp_bridge.read().await;
+        bridge.list_resources().await
+    }
+
+    /// MCP 리소스 읽기
+    pub async fn read_mcp_resource(
+        &self,
+        server: &str,
+        uri: &str,
+    ) -> Result<Vec<McpResourceContent>> {
+        let bridge = self.mcp_bridge.read().await;
+        bridge.read_resource(server, uri).await
+    }
+
+    /// 연결된 MCP 서버들의 프롬프트를 슬래시 명령어 스킬로 (`/<server>:<prompt>`)
+    pub async fn mcp_prompt_skills(&self) -> Vec<McpPromptSkill> {
+        let bridge = self.mcp_bridge.read().await;
+        bridge
+            .list_prompts()
+            .await
+            .into_iter()
+            .map(|(server, prompt)| {
+                McpPromptSkill::new(server, prompt, Arc::clone(&self.mcp_bridge))
+            })
+            .collect()
+    }
+
     /// MCP 서버 상태 조회
     pub async fn mcp_server_status(&self, name: &str) -> Option<crate::mcp::ServerStatus> {
         let bridge = self.mcp_bridge.read().await;
@@ -558,6 +1255,31 @@ impl AgentContext {
         bridge.health_check_all().await;
     }
 
+    /// MCP 서버 상태 구독 (상태 패널용 전체 스냅샷)
+    pub async fn subscribe_mcp_status(&self) -> tokio::sync::watch::Receiver<Vec<ServerStatus>> {
+        let bridge = self.mcp_bridge.read().await;
+        bridge.subscribe_status()
+    }
+
+    /// 백그라운드 MCP 헬스 모니터 시작
+    ///
+    /// `interval`마다 모든 서버를 점검하고 죽은 stdio 서버를 재시작 정책에 따라
+    /// 재시작합니다. 컨텍스트가 해제되면 종료됩니다.
+    pub fn start_mcp_health_monitor(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let bridge = Arc::downgrade(&self.mcp_bridge);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let Some(bridge) = bridge.upgrade() else {
+                    break;
+                };
+                bridge.read().await.health_check_all().await;
+            }
+        })
+    }
+
     /// MCP 유휴 연결 정리
     pub async fn cleanup_mcp_idle(&self) {
         let bridge = self.mcp_bridge.read().await;
@@ -585,8 +1307,12 @@ impl Default for AgentContext {
 pub struct AgentContextBuilder {
     config: AgentContextConfig,
     permissions: Option<Arc<PermissionService>>,
+    permission_delegate: Option<Arc<dyn PermissionDelegate>>,
     additional_tools: Vec<Arc<dyn Tool>>,
+    tool_timeouts: ToolTimeouts,
+    tool_middleware: MiddlewareChain,
     mcp_bridge: McpBridge,
+    audit_logger: Option<Arc<AuditLogger>>,
 }
 
 impl AgentContextBuilder {
@@ -594,8 +1320,12 @@ impl AgentContextBuilder {
         Self {
             config: AgentContextConfig::default(),
             permissions: None,
+            permission_delegate: None,
             additional_tools: Vec::new(),
+            tool_timeouts: ToolTimeouts::default(),
+            tool_middleware: MiddlewareChain::new(),
             mcp_bridge: McpBridge::new(),
+            audit_logger: None,
         }
     }
 
@@ -629,6 +1359,12 @@ impl AgentContextBuilder {
         self
     }
 
+    /// 권한 델리게이트 설정 (대화형/원격 권한 승인)
+    pub fn with_permission_delegate(mut self, delegate: Arc<dyn PermissionDelegate>) -> Self {
+        self.permission_delegate = Some(delegate);
+        self
+    }
+
     /// 권한 확인 비활성화
     pub fn disable_permission_check(mut self) -> Self {
         self.config.check_permissions = false;
@@ -653,6 +1389,24 @@ impl AgentContextBuilder {
         self
     }
 
+    /// 도구별 실행 타임아웃 설정
+    pub fn with_tool_timeouts(mut self, timeouts: ToolTimeouts) -> Self {
+        self.tool_timeouts = timeouts;
+        self
+    }
+
+    /// 도구 미들웨어 추가
+    pub fn with_tool_middleware(mut self, middleware: Arc<dyn ToolMiddleware>) -> Self {
+        self.tool_middleware.push(middleware);
+        self
+    }
+
+    /// 감사 로거 설정 (권한 결정, 명령 실행, 파일 쓰기/삭제 기록)
+    pub fn with_audit_logger(mut self, logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(logger);
+        self
+    }
+
     /// MCP 브릿지 설정
     pub fn with_mcp_bridge(mut self, bridge: McpBridge) -> Self {
         self.mcp_bridge = bridge;
@@ -665,32 +1419,76 @@ impl AgentContextBuilder {
         self
     }
 
+    /// MCP 서버 자동 재시작 정책 설정
+    pub fn with_mcp_restart_policy(mut self, policy: McpRestartPolicy) -> Self {
+        self.mcp_bridge = self.mcp_bridge.with_restart_policy(policy);
+        self
+    }
+
+    /// 직접 등록할 MCP 도구 수 한도 설정
+    pub fn with_mcp_tool_limit(mut self, limit: usize) -> Self {
+        self.config.mcp_tool_limit = limit;
+        self
+    }
+
     /// 빌드
     pub fn build(self) -> AgentContext {
         let mut registry = ToolRegistry::with_builtins();
+        registry.set_timeouts(self.tool_timeouts);
+        registry.set_middleware(self.tool_middleware);
 
         // 추가 도구 등록
         for tool in self.additional_tools {
             registry.register(tool);
         }
 
-        AgentContext {
+        let lsp = register_lsp(&self.config, &mut registry);
+        let formatter = load_formatter(&self.config, &lsp);
+        let mcp_roots = vec![McpRoot::from_path(&self.config.working_directory)];
+        // 경로 범위 권한의 상대 경로는 에이전트 작업 디렉토리 기준
+        if let Some(permissions) = &self.permissions {
+            permissions.set_working_dir(&self.config.working_directory);
+        }
+        let ctx = AgentContext {
             config: self.config,
             tools: Arc::new(RwLock::new(registry)),
             permissions: self.permissions,
+            permission_delegate: std::sync::Mutex::new(self.permission_delegate),
             mcp_bridge: Arc::new(RwLock::new(self.mcp_bridge)),
+            mcp_loaded_tools: Arc::default(),
+            mcp_roots: std::sync::RwLock::new(mcp_roots),
+            mcp_tool_registry: Arc::default(),
             stats: Arc::new(RwLock::new(ExecutionStats::default())),
-        }
+            capabilities: Arc::new(CapabilityMatrix::new()),
+            dry_run: std::sync::Mutex::new(None),
+            audit_logger: self.audit_logger,
+            lsp,
+            formatter,
+        };
+        ctx.probe_capabilities();
+        ctx
     }
 
     /// 비동기 빌드 (MCP 서버 연결 포함)
     pub async fn build_async(self, mcp_configs: Vec<(&str, McpTransportConfig)>) -> Result<AgentContext> {
         let ctx = self.build();
 
-        // MCP 서버 연결
+        // MCP 서버 연결 (실패한 서버는 건너뛰고 기능 저하 모드로 기록)
+        let total = mcp_configs.len();
+        let mut failures = Vec::new();
         for (name, config) in mcp_configs {
             if let Err(e) = ctx.connect_mcp_server(name, config).await {
                 warn!("Failed to connect MCP server '{}': {}", name, e);
+                failures.push(format!("{}: {}", name, e));
+            }
+        }
+
+        if !failures.is_empty() {
+            let reason = format!("failed to connect {}", failures.join("; "));
+            if failures.len() == total {
+                ctx.capabilities.mark_unavailable(Capability::Mcp, reason);
+            } else {
+                ctx.capabilities.mark_degraded(Capability::Mcp, reason);
             }
         }
 
@@ -704,6 +1502,25 @@ impl Default for AgentContextBuilder {
     }
 }
 
+/// LSP가 켜져 있으면 매니저를 만들고 `diagnostics` 도구 등록
+fn register_lsp(config: &AgentContextConfig, registry: &mut ToolRegistry) -> Option<Arc<LspManager>> {
+    if !config.enable_lsp {
+        return None;
+    }
+    let lsp = Arc::new(LspManager::with_configs(load_lsp_configs(
+        &config.working_directory,
+    )));
+    registry.register(Arc::new(DiagnosticsTool::new(Arc::clone(&lsp))));
+    registry.register(Arc::new(WhoCallsTool::new(Arc::clone(&lsp))));
+    Some(lsp)
+}
+
+/// 프로젝트 설정의 자동 포맷터 (`format.enabled`일 때만, LSP가 켜져 있으면 언어 서버 포맷 포함)
+fn load_formatter(config: &AgentContextConfig, lsp: &Option<Arc<LspManager>>) -> Option<Arc<AutoFormatter>> {
+    AutoFormatter::from_project(&config.working_directory)
+        .map(|formatter| Arc::new(formatter.with_lsp(lsp.clone())))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -739,6 +1556,19 @@ mod tests {
         assert!(names.contains(&"bash"));
     }
 
+    #[tokio::test]
+    async fn test_lsp_registers_diagnostics_tool() {
+        let ctx = AgentContext::new();
+        assert!(ctx.diagnostics_store().is_none());
+        assert!(!ctx.has_tool(DiagnosticsTool::NAME).await);
+
+        let ctx = AgentContext::builder().enable_lsp().build();
+        assert!(ctx.lsp_manager().is_some());
+        assert!(ctx.diagnostics_store().is_some());
+        assert!(ctx.has_tool(DiagnosticsTool::NAME).await);
+        assert!(ctx.has_tool(WhoCallsTool::NAME).await);
+    }
+
     #[tokio::test]
     async fn test_context_has_tool() {
         let ctx = AgentContext::new();
@@ -771,6 +1601,189 @@ mod tests {
         assert_eq!(stats.tool_executions, 1);
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_project_format_config_loads_formatter() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".forgecode")).unwrap();
+        std::fs::write(
+            dir.path().join(".forgecode/settings.json"),
+            r#"{ "format": { "enabled": true, "formatters": { "txt": { "command": "tr", "args": ["a-z", "A-Z"] } } } }"#,
+        )
+        .unwrap();
+
+        let ctx = AgentContext::builder()
+            .working_directory(dir.path().to_path_buf())
+            .build();
+        let formatter = ctx.formatter().unwrap();
+
+        let formatted = formatter
+            .format(&dir.path().join("notes.txt"), "hello\n")
+            .await
+            .unwrap();
+        assert_eq!(formatted.as_deref(), Some("HELLO\n"));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_records_mutating_calls() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "hello").unwrap();
+        let ctx = AgentContext::builder()
+            .working_directory(dir.path().to_path_buf())
+            .build();
+
+        let recorder = Arc::new(DryRunRecorder::new());
+        ctx.set_dry_run(Some(recorder.clone()));
+
+        let target = dir.path().join("out.txt");
+        let write = ctx
+            .execute_tool(
+                "write",
+                serde_json::json!({ "file_path": target.to_string_lossy(), "content": "x" }),
+            )
+            .await
+            .unwrap();
+        assert!(write.success);
+        assert!(write.output.starts_with("[dry-run]"));
+        assert!(!target.exists());
+
+        // 읽기 전용 호출은 그대로 실행
+        let read = ctx
+            .execute_tool(
+                "read",
+                serde_json::json!({ "file_path": dir.path().join("notes.txt").to_string_lossy() }),
+            )
+            .await
+            .unwrap();
+        assert!(read.output.contains("hello"));
+
+        let steps = recorder.take();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].tool.as_deref(), Some("write"));
+
+        ctx.set_dry_run(None);
+        assert!(!ctx.is_dry_run());
+    }
+
+    struct FixedDelegate(forge_foundation::PermissionResponse);
+
+    #[async_trait::async_trait]
+    impl PermissionDelegate for FixedDelegate {
+        async fn request_permission(
+            &self,
+            _tool_name: &str,
+            _action: &PermissionAction,
+            _description: &str,
+            _risk_score: u8,
+        ) -> forge_foundation::PermissionResponse {
+            self.0
+        }
+
+        fn notify(&self, _message: &str) {}
+
+        fn show_error(&self, _error: &str) {}
+    }
+
+    #[tokio::test]
+    async fn test_permission_delegate_decides_unknown() {
+        use forge_foundation::PermissionResponse;
+
+        let dir = tempfile::tempdir().unwrap();
+        let permissions = Arc::new(PermissionService::new());
+        let ctx = AgentContext::builder()
+            .working_directory(dir.path().to_path_buf())
+            .with_permission_service(permissions.clone())
+            .with_permission_delegate(Arc::new(FixedDelegate(PermissionResponse::Deny)))
+            .build();
+        assert_eq!(permissions.working_dir(), dir.path());
+
+        let target = dir.path().join("out.txt");
+        let input = serde_json::json!({ "file_path": target.to_string_lossy(), "content": "x" });
+        let denied = ctx.execute_tool("write", input.clone()).await.unwrap();
+        assert!(!denied.success);
+        assert!(!target.exists());
+
+        ctx.set_permission_delegate(Some(Arc::new(FixedDelegate(
+            PermissionResponse::AllowSession,
+        ))));
+        let allowed = ctx.execute_tool("write", input).await.unwrap();
+        assert!(allowed.success);
+        assert!(target.exists());
+        assert_eq!(permissions.session_grants().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_read_only_blocks_network_tools() {
+        use crate::tool::builtin::{HttpRequestConfig, HttpRequestTool};
+
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = AgentContext::builder()
+            .working_directory(dir.path().to_path_buf())
+            .with_permission_service(Arc::new(PermissionService::with_auto_approve().read_only()))
+            .build();
+        ctx.register_tool(Arc::new(HttpRequestTool::with_config(HttpRequestConfig {
+            allowed_domains: vec!["127.0.0.1".to_string()],
+            ..Default::default()
+        })))
+        .await;
+
+        // 허용 도메인이라 권한이 필요 없어도 읽기 전용 모드에서는 실행하지 않음
+        let input = serde_json::json!({ "url": "http://127.0.0.1:9/" });
+        let tool = ctx.get_tool("http_request").await.unwrap();
+        assert!(tool.required_permission(&input).is_none());
+
+        let result = ctx.execute_tool("http_request", input).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only mode"));
+        assert_eq!(ctx.stats().await.permission_denials, 1);
+    }
+
+    #[tokio::test]
+    async fn test_read_only_blocks_commands() {
+        use crate::tool::builtin::process::ProcessStartTool;
+
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = AgentContext::builder()
+            .working_directory(dir.path().to_path_buf())
+            .with_permission_service(Arc::new(PermissionService::with_auto_approve().read_only()))
+            .build();
+        ctx.register_tool(Arc::new(ProcessStartTool::new())).await;
+
+        // 안전으로 분류되어 권한이 필요 없는 명령어도 읽기 전용 모드에서는 실행하지 않음
+        let input = serde_json::json!({ "command": "cargo test" });
+        let bash = ctx.get_tool("bash").await.unwrap();
+        assert!(bash.required_permission(&input).is_none());
+
+        let result = ctx.execute_tool("bash", input.clone()).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only mode"));
+
+        let result = ctx.execute_tool("process_start", input).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only mode"));
+        assert_eq!(ctx.stats().await.permission_denials, 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_audit_logger_records_actions() {
+        use forge_foundation::audit::AuditAction;
+
+        let dir = tempfile::tempdir().unwrap();
+        let logger = Arc::new(AuditLogger::in_memory().unwrap());
+        let ctx = AgentContext::builder()
+            .working_directory(dir.path().to_path_buf())
+            .with_permission_service(Arc::new(PermissionService::with_auto_approve()))
+            .with_audit_logger(logger.clone())
+            .build();
+
+        let target = dir.path().join("audited.txt");
+        let input = serde_json::json!({ "file_path": target.to_string_lossy(), "content": "x" });
+        assert!(ctx.execute_tool("write", input).await.unwrap().success);
+
+        let entries = logger.recent(10).await.unwrap();
+        assert!(entries.iter().any(|e| e.action == AuditAction::FileWrite));
+    }
+
     #[test]
     fn test_builder() {
         let ctx = AgentContext::builder()
@@ -782,4 +1795,223 @@ mod tests {
         assert_eq!(ctx.session_id(), "test-session");
         assert!(!ctx.config.check_permissions);
     }
+
+    #[tokio::test]
+    async fn test_failed_mcp_returns_capability_unavailable() {
+        let config = McpTransportConfig::Stdio {
+            command: "forge-nonexistent-mcp-server".to_string(),
+            args: vec![],
+            env: Default::default(),
+        };
+
+        let ctx = AgentContext::builder()
+            .disable_permission_check()
+            .build_async(vec![("broken", config)])
+            .await
+            .unwrap();
+
+        // 다른 도구는 계속 사용 가능
+        assert!(ctx.has_tool("read").await);
+        assert!(matches!(
+            ctx.capabilities().status(Capability::Mcp),
+            crate::capability::CapabilityStatus::Unavailable { .. }
+        ));
+
+        let result = ctx
+            .execute_tool("mcp_broken_query", serde_json::json!({}))
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::CapabilityUnavailable { ref capability, .. }) if capability == "mcp"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mcp_tool_limit_without_servers() {
+        let ctx = AgentContext::builder().with_mcp_tool_limit(10).build();
+        assert_eq!(ctx.config.mcp_tool_limit, 10);
+
+        ctx.refresh_mcp_tools().await.unwrap();
+        assert!(!ctx.has_tool(McpToolSearchTool::NAME).await);
+        assert!(!ctx.has_tool(McpResourceTool::NAME).await);
+        assert!(!ctx.get_tool_schemas().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mcp_roots_follow_working_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = AgentContext::builder()
+            .working_directory(dir.path().to_path_buf())
+            .build();
+        assert_eq!(ctx.mcp_roots(), vec![McpRoot::from_path(dir.path())]);
+
+        let other = tempfile::tempdir().unwrap();
+        let roots = vec![McpRoot::from_path(dir.path()), McpRoot::from_path(other.path())];
+        ctx.set_mcp_roots(roots.clone()).await;
+        assert_eq!(ctx.mcp_roots(), roots);
+    }
+
+    #[tokio::test]
+    async fn test_mcp_tool_sync_stops_with_context() {
+        let ctx = Arc::new(AgentContext::new());
+        assert!(ctx.handle_mcp_tools_changed("missing").await.is_err());
+        assert!(ctx.mcp_tool_registry().is_empty().await);
+
+        // 컨텍스트가 사라지면 알림 채널이 닫혀 태스크도 끝남
+        let sync = ctx.start_mcp_tool_sync();
+        drop(ctx);
+        tokio::time::timeout(Duration::from_secs(1), sync)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disabled_lsp_blocks_dependent_tool() {
+        use async_trait::async_trait;
+        use forge_foundation::{ToolContext, ToolMeta};
+
+        struct DefinitionTool;
+
+        #[async_trait]
+        impl Tool for DefinitionTool {
+            fn meta(&self) -> ToolMeta {
+                ToolMeta::new("goto_definition").category("lsp")
+            }
+
+            fn name(&self) -> &str {
+                "goto_definition"
+            }
+
+            fn schema(&self) -> Value {
+                serde_json::json!({"type": "object"})
+            }
+
+            async fn execute(&self, _input: Value, _ctx: &dyn ToolContext) -> Result<ToolResult> {
+                Ok(ToolResult::success("ok"))
+            }
+
+            fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+                None
+            }
+        }
+
+        let ctx = AgentContext::builder()
+            .disable_permission_check()
+            .with_tool(Arc::new(DefinitionTool))
+            .build();
+
+        let result = ctx.execute_tool("goto_definition", serde_json::json!({})).await;
+        assert!(matches!(result, Err(Error::CapabilityUnavailable { .. })));
+
+        ctx.capabilities().mark_available(Capability::Lsp);
+        let result = ctx.execute_tool("goto_definition", serde_json::json!({})).await;
+        assert!(result.unwrap().success);
+    }
+
+    #[tokio::test]
+    async fn test_tool_timeout_and_cancellation() {
+        use async_trait::async_trait;
+        use forge_foundation::{ToolContext, ToolMeta};
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        /// 취소될 때까지 대기하는 도구
+        struct HangingTool {
+            saw_cancel: Arc<AtomicBool>,
+        }
+
+        #[async_trait]
+        impl Tool for HangingTool {
+            fn meta(&self) -> ToolMeta {
+                ToolMeta::new("hang")
+            }
+
+            fn name(&self) -> &str {
+                "hang"
+            }
+
+            fn schema(&self) -> Value {
+                serde_json::json!({"type": "object"})
+            }
+
+            async fn execute(&self, _input: Value, ctx: &dyn ToolContext) -> Result<ToolResult> {
+                ctx.cancelled().await;
+                self.saw_cancel.store(true, Ordering::SeqCst);
+                Ok(ToolResult::success("stopped"))
+            }
+
+            fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+                None
+            }
+        }
+
+        let saw_cancel = Arc::new(AtomicBool::new(false));
+        let ctx = AgentContext::builder()
+            .disable_permission_check()
+            .with_tool(Arc::new(HangingTool {
+                saw_cancel: saw_cancel.clone(),
+            }))
+            .with_tool_timeouts(
+                ToolTimeouts::default().with_tool("hang", Some(Duration::from_millis(50))),
+            )
+            .build();
+
+        let result = ctx.execute_tool("hang", serde_json::json!({})).await.unwrap();
+        assert!(result.is_timed_out());
+        assert!(!result.success);
+        assert_eq!(result.error.as_deref(), Some("Timeout after 50ms"));
+        assert!(saw_cancel.load(Ordering::SeqCst));
+
+        // 외부 취소
+        let cancel = CancellationToken::new();
+        let trigger = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            trigger.cancel();
+        });
+        let result = ctx
+            .execute_tool_cancellable("hang", serde_json::json!({}), None, cancel)
+            .await
+            .unwrap();
+        assert!(result.is_cancelled());
+        assert_eq!(ctx.stats().await.tool_failures, 2);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_plan() {
+        use crate::skill::{PlanStep, SkillPlan};
+
+        let permissions = Arc::new(PermissionService::new());
+        let ctx = AgentContext::builder()
+            .with_permission_service(permissions.clone())
+            .build();
+        let write = PermissionAction::FileWrite {
+            path: "notes.md".to_string(),
+        };
+        let plan = SkillPlan::new("commit")
+            .with_step(PlanStep::prompt("Write notes"))
+            .with_step(PlanStep::from_tool_call(
+                "write",
+                &serde_json::json!({ "file_path": "notes.md", "content": "x" }),
+                &write,
+            ))
+            .with_step(PlanStep::from_tool_call(
+                "missing_tool",
+                &serde_json::json!({}),
+                &write,
+            ));
+
+        let simulations = ctx.simulate_plan(&plan).await;
+        assert_eq!(simulations.len(), 1);
+        assert_eq!(simulations[0].tool, "write");
+        assert_eq!(simulations[0].pending().count(), 1);
+
+        permissions.grant_session("write", simulations[0].required[0].action.clone());
+        let simulations = ctx.simulate_plan(&plan).await;
+        assert!(simulations[0].is_allowed());
+        assert!(ctx
+            .simulate_tool("missing_tool", &serde_json::json!({}))
+            .await
+            .is_err());
+    }
 }
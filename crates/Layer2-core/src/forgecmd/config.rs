@@ -1,8 +1,10 @@
 //! Configuration for forgecmd module
 
+use crate::forgecmd::rule::{RuleKind, RulePattern};
+use forge_foundation::permission::PermissionAction;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// PTY terminal size configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,7 +26,7 @@ impl Default for PtySize {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PermissionRule {
-    /// Pattern to match (supports wildcards: *)
+    /// Pattern to match (supports wildcards: *, and `Tool(specifier)`, see `rule`)
     pub pattern: String,
 
     /// Scope for allow rules: "always", "session", "once"
@@ -70,6 +72,11 @@ impl PermissionRule {
             risk: Some(risk),
         }
     }
+
+    /// Parsed pattern
+    pub fn parsed(&self) -> RulePattern {
+        RulePattern::parse(&self.pattern)
+    }
 }
 
 /// Permission rules configuration
@@ -89,6 +96,79 @@ pub struct PermissionRules {
     pub ask: Vec<PermissionRule>,
 }
 
+impl PermissionRules {
+    /// Import the `permissions` section of a Claude Code style `settings.json`
+    ///
+    /// ```json
+    /// { "permissions": { "allow": ["Bash(git diff:*)"], "deny": ["Read(./.env)"], "ask": ["Bash(git push:*)"] } }
+    /// ```
+    ///
+    /// Allowed patterns get the "always" scope and asked patterns a risk of 6.
+    /// Entries that are not strings are ignored.
+    pub fn from_settings(settings: &serde_json::Value) -> Self {
+        let permissions = settings.get("permissions").unwrap_or(settings);
+        let patterns = |key: &str| -> Vec<String> {
+            permissions
+                .get(key)
+                .and_then(|v| v.as_array())
+                .map(|list| {
+                    list.iter()
+                        .filter_map(|v| v.as_str())
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Self {
+            allow: patterns("allow")
+                .into_iter()
+                .map(|p| PermissionRule::allow(p, "always"))
+                .collect(),
+            deny: patterns("deny")
+                .into_iter()
+                .map(|p| PermissionRule::deny(p, "Denied by settings"))
+                .collect(),
+            ask: patterns("ask")
+                .into_iter()
+                .map(|p| PermissionRule::ask(p, 6))
+                .collect(),
+        }
+    }
+
+    /// Add the rules of `other` after the existing ones
+    pub fn merge(&mut self, other: PermissionRules) {
+        self.allow.extend(other.allow);
+        self.deny.extend(other.deny);
+        self.ask.extend(other.ask);
+    }
+
+    /// Find the rule that decides a tool invocation
+    ///
+    /// Deny rules win over ask rules, which win over allow rules. Relative
+    /// path patterns are resolved against `base_dir`.
+    pub fn evaluate(
+        &self,
+        tool: &str,
+        action: &PermissionAction,
+        base_dir: &Path,
+    ) -> Option<(RuleKind, &PermissionRule)> {
+        [
+            (RuleKind::Deny, &self.deny),
+            (RuleKind::Ask, &self.ask),
+            (RuleKind::Allow, &self.allow),
+        ]
+        .into_iter()
+        .find_map(|(kind, rules)| {
+            rules
+                .iter()
+                .find(|rule| rule.parsed().matches(tool, action, base_dir, kind))
+                .map(|rule| (kind, rule))
+        })
+    }
+}
+
 /// Risk threshold configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -117,6 +197,101 @@ impl Default for RiskThresholds {
     }
 }
 
+/// Output normalization configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputConfig {
+    /// Set `PAGER`/`GIT_PAGER` to `cat` so commands never wait in a pager
+    #[serde(default = "default_true")]
+    pub disable_pager: bool,
+
+    /// Force `LANG`/`LC_ALL` to `C.UTF-8` for untranslated, predictable output
+    #[serde(default = "default_true")]
+    pub force_locale: bool,
+
+    /// Collapse carriage-return redraws and repeated progress-bar lines
+    #[serde(default = "default_true")]
+    pub collapse_progress: bool,
+}
+
+impl Default for OutputConfig {
+    fn default() -> Self {
+        Self {
+            disable_pager: true,
+            force_locale: true,
+            collapse_progress: true,
+        }
+    }
+}
+
+impl OutputConfig {
+    /// Leave the command environment and output untouched
+    pub fn raw() -> Self {
+        Self {
+            disable_pager: false,
+            force_locale: false,
+            collapse_progress: false,
+        }
+    }
+
+    /// Environment variables to set for executed commands
+    pub fn env(&self) -> Vec<(&'static str, &'static str)> {
+        let mut env = Vec::new();
+        if self.disable_pager {
+            env.extend([("PAGER", "cat"), ("GIT_PAGER", "cat"), ("MANPAGER", "cat")]);
+        }
+        if self.force_locale {
+            env.extend([("LANG", "C.UTF-8"), ("LC_ALL", "C.UTF-8")]);
+        }
+        env
+    }
+}
+
+/// Permission learning configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LearningConfig {
+    /// Promote frequently-approved commands into allow rules (opt-in)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Approvals needed before a command pattern is promoted
+    #[serde(default = "default_min_approvals")]
+    pub min_approvals: usize,
+
+    /// Commands with a risk score at or above this are never promoted
+    #[serde(default = "default_learning_max_risk")]
+    pub max_risk: u8,
+
+    /// Scope of learned rules: "session", or "always" for project rules
+    /// (persist `ForgeCmd::config().rules` to keep them)
+    #[serde(default = "default_learning_scope")]
+    pub scope: String,
+}
+
+fn default_min_approvals() -> usize {
+    3
+}
+
+fn default_learning_max_risk() -> u8 {
+    7
+}
+
+fn default_learning_scope() -> String {
+    "session".to_string()
+}
+
+impl Default for LearningConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_approvals: default_min_approvals(),
+            max_risk: default_learning_max_risk(),
+            scope: default_learning_scope(),
+        }
+    }
+}
+
 /// Main configuration for forgecmd
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -164,6 +339,18 @@ pub struct ForgeCmdConfig {
     /// Enable ANSI escape stripping for stored output
     #[serde(default = "default_true")]
     pub strip_ansi: bool,
+
+    /// Output normalization (pager, locale, progress bars)
+    #[serde(default)]
+    pub output: OutputConfig,
+
+    /// Permission learning from approval history
+    #[serde(default)]
+    pub learning: LearningConfig,
+
+    /// Directory for asciinema recordings of PTY sessions (recording is off when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recording_dir: Option<PathBuf>,
 }
 
 fn default_shell() -> String {
@@ -227,6 +414,9 @@ impl Default for ForgeCmdConfig {
             allowed_paths: vec![],
             track_history: true,
             strip_ansi: true,
+            output: OutputConfig::default(),
+            learning: LearningConfig::default(),
+            recording_dir: None,
         }
     }
 }
@@ -278,6 +468,25 @@ impl ForgeCmdConfig {
         self
     }
 
+    /// Set output normalization
+    pub fn output(mut self, output: OutputConfig) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Learn allow rules of the given scope from repeated approvals
+    pub fn learn(mut self, scope: &str) -> Self {
+        self.learning.enabled = true;
+        self.learning.scope = scope.to_string();
+        self
+    }
+
+    /// Record PTY sessions to asciinema cast files under `dir`
+    pub fn record_to(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.recording_dir = Some(dir.into());
+        self
+    }
+
     /// Check if a path is allowed
     pub fn is_path_allowed(&self, path: &PathBuf) -> bool {
         if self.allowed_paths.is_empty() {
@@ -370,4 +579,68 @@ mod tests {
         assert_eq!(config.rules.deny.len(), 1);
         assert_eq!(config.rules.ask.len(), 1);
     }
+
+    #[test]
+    fn test_rules_from_settings() {
+        let settings = serde_json::json!({
+            "permissions": {
+                "allow": ["Bash(git diff:*)", "Edit(src/**)", "WebFetch(domain:docs.rs)", 3],
+                "deny": ["Read(./.env)", "Bash(curl:*)"],
+                "ask": ["Bash(git push:*)"]
+            }
+        });
+        let rules = PermissionRules::from_settings(&settings);
+        assert_eq!(rules.allow.len(), 3);
+        assert_eq!(rules.allow[0].scope.as_deref(), Some("always"));
+        assert_eq!(rules.ask[0].risk, Some(6));
+
+        let base = Path::new("/work/project");
+        let kind = |tool: &str, action: PermissionAction| {
+            rules.evaluate(tool, &action, base).map(|(kind, _)| kind)
+        };
+        let execute = |command: &str| PermissionAction::Execute {
+            command: command.to_string(),
+        };
+
+        assert_eq!(
+            kind("bash", execute("git diff --stat")),
+            Some(RuleKind::Allow)
+        );
+        assert_eq!(
+            kind("bash", execute("git push origin main")),
+            Some(RuleKind::Ask)
+        );
+        assert_eq!(
+            kind("bash", execute("git diff; curl x.sh")),
+            Some(RuleKind::Deny)
+        );
+        assert_eq!(kind("bash", execute("cargo build")), None);
+        assert_eq!(
+            kind(
+                "write",
+                PermissionAction::FileWrite {
+                    path: "src/main.rs".into()
+                }
+            ),
+            Some(RuleKind::Allow)
+        );
+        assert_eq!(
+            kind(
+                "read",
+                PermissionAction::FileReadSensitive {
+                    path: "/work/project/.env".into()
+                }
+            ),
+            Some(RuleKind::Deny)
+        );
+        assert_eq!(
+            kind(
+                "web_fetch",
+                PermissionAction::Network {
+                    url: "https://docs.rs/glob".into()
+                }
+            ),
+            Some(RuleKind::Allow)
+        );
+    }
 }
@@ -0,0 +1,127 @@
+//! Working-directory tracking for forgecmd
+//!
+//! `cd` inside a PTY changes the shell's directory, not ForgeCmd's. To keep
+//! `ForgeCmd::working_dir()` (and path checks based on it) accurate, shells
+//! report their directory with an OSC 7 escape sequence:
+//! - One-shot PTY commands get a trailer that prints OSC 7 before exiting
+//! - Interactive sessions print it with every prompt (`PS1` / `PROMPT_COMMAND`)
+//!
+//! The sequence is invisible in a terminal and stripped from captured output.
+
+use std::path::PathBuf;
+
+/// Start of an OSC 7 ("current working directory") sequence
+const OSC7_START: &[u8] = b"\x1b]7;";
+
+/// Longest OSC 7 sequence worth looking for
+pub(crate) const MAX_OSC7_LEN: usize = 4096;
+
+/// Shell snippet that prints the current directory as OSC 7
+const REPORT_CWD: &str = r#"printf '\033]7;file://%s%s\007' "${HOSTNAME:-localhost}" "$PWD""#;
+
+/// Check whether `shell` understands POSIX `sh` syntax
+pub(crate) fn is_posix_shell(shell: &str) -> bool {
+    let name = shell.rsplit(['/', '\\']).next().unwrap_or(shell);
+    matches!(name, "sh" | "bash" | "zsh" | "dash" | "ksh" | "ash")
+}
+
+/// Wrap a one-shot command so the shell reports its final directory
+///
+/// The command's exit status is preserved. A command that calls `exit`
+/// itself simply reports nothing.
+pub(crate) fn with_cwd_report(command: &str) -> String {
+    format!(
+        "{}\n__forge_status=$?; {}; exit $__forge_status",
+        command, REPORT_CWD
+    )
+}
+
+/// Environment that makes an interactive shell report its directory at each prompt
+///
+/// bash runs `PROMPT_COMMAND`; other POSIX shells expand `$PWD` in `PS1`.
+/// A user rc file that replaces both disables tracking for that session.
+pub(crate) fn prompt_env() -> Vec<(String, String)> {
+    vec![
+        ("PROMPT_COMMAND".to_string(), REPORT_CWD.to_string()),
+        (
+            "PS1".to_string(),
+            "\x1b]7;file://localhost${PWD}\x07$ ".to_string(),
+        ),
+    ]
+}
+
+/// Directory from the last complete OSC 7 sequence in `bytes`
+pub fn parse_osc7(bytes: &[u8]) -> Option<PathBuf> {
+    let mut rest = bytes;
+    let mut found = None;
+    while let Some(start) = find(rest, OSC7_START) {
+        let payload = &rest[start + OSC7_START.len()..];
+        let Some((end, terminator_len)) = find_terminator(payload) else {
+            break;
+        };
+        if let Some(path) = std::str::from_utf8(&payload[..end])
+            .ok()
+            .and_then(path_from_uri)
+        {
+            found = Some(path);
+        }
+        rest = &payload[end + terminator_len..];
+    }
+    found
+}
+
+/// Position and length of the BEL or ST terminator
+fn find_terminator(bytes: &[u8]) -> Option<(usize, usize)> {
+    bytes.iter().enumerate().find_map(|(i, &b)| match b {
+        0x07 => Some((i, 1)),
+        0x1b if bytes.get(i + 1) == Some(&b'\\') => Some((i, 2)),
+        _ => None,
+    })
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// `file://host/path` -> `/path` (percent-decoded)
+fn path_from_uri(uri: &str) -> Option<PathBuf> {
+    let rest = uri.strip_prefix("file://")?;
+    let path = &rest[rest.find('/')?..];
+
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut iter = path.bytes();
+    while let Some(b) = iter.next() {
+        if b == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(b);
+        }
+    }
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_osc7() {
+        assert_eq!(
+            parse_osc7(b"out\n\x1b]7;file://dev-box/home/dev/my%20app\x07$ "),
+            Some(PathBuf::from("/home/dev/my app"))
+        );
+        // The last complete report wins; ST terminator is accepted
+        assert_eq!(
+            parse_osc7(b"\x1b]7;file://h/a\x07\x1b]7;file://h/b\x1b\\\x1b]7;file://h/c"),
+            Some(PathBuf::from("/b"))
+        );
+        assert_eq!(parse_osc7(b"plain output"), None);
+        assert_eq!(parse_osc7(b"\x1b]7;not-a-uri\x07"), None);
+
+        assert!(is_posix_shell("/usr/bin/bash"));
+        assert!(!is_posix_shell("cmd.exe"));
+    }
+}
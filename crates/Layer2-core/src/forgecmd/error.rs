@@ -3,6 +3,7 @@
 //! ForgeCmdError는 PTY/Shell 실행 관련 세부 에러를 관리합니다.
 //! forge_foundation::Error와의 변환을 지원합니다.
 
+use crate::forgecmd::prompt::InputPrompt;
 use forge_foundation::permission::PermissionAction;
 use forge_foundation::Error as FoundationError;
 use std::io;
@@ -22,6 +23,10 @@ pub enum ForgeCmdError {
     #[error("PTY session already running")]
     SessionAlreadyRunning,
 
+    /// Named session is already running
+    #[error("PTY session '{0}' is already running")]
+    SessionExists(String),
+
     /// Failed to create PTY
     #[error("Failed to create PTY: {0}")]
     PtyCreationFailed(String),
@@ -38,6 +43,14 @@ pub enum ForgeCmdError {
     #[error("Command timed out after {0} seconds")]
     Timeout(u64),
 
+    /// Command stopped at an interactive prompt
+    #[error("Command is waiting for input: {prompt}")]
+    NeedsInput {
+        prompt: InputPrompt,
+        /// Output up to the prompt
+        output: String,
+    },
+
     /// Forbidden command blocked
     #[error("Forbidden command blocked: {0}")]
     ForbiddenCommand(String),
@@ -85,7 +98,10 @@ pub enum ForgeCmdError {
 impl ForgeCmdError {
     /// Check if this error requires user interaction
     pub fn requires_user_action(&self) -> bool {
-        matches!(self, Self::PermissionRequired { .. })
+        matches!(
+            self,
+            Self::PermissionRequired { .. } | Self::NeedsInput { .. }
+        )
     }
 
     /// Check if this is a security-related error
@@ -111,6 +127,9 @@ impl ForgeCmdError {
             Self::Timeout(secs) => {
                 format!("Command timed out after {} seconds", secs)
             }
+            Self::NeedsInput { prompt, .. } => {
+                format!("Command is waiting for input: {}", prompt.text)
+            }
             _ => self.to_string(),
         }
     }
@@ -129,6 +148,9 @@ impl From<ForgeCmdError> for FoundationError {
             ForgeCmdError::SessionAlreadyRunning => {
                 FoundationError::Tool("PTY session already running".to_string())
             }
+            ForgeCmdError::SessionExists(name) => {
+                FoundationError::Tool(format!("PTY session '{}' already running", name))
+            }
             ForgeCmdError::PtyCreationFailed(msg) => {
                 FoundationError::Tool(format!("PTY creation failed: {}", msg))
             }
@@ -142,6 +164,9 @@ impl From<ForgeCmdError> for FoundationError {
             ForgeCmdError::Timeout(secs) => {
                 FoundationError::Timeout(format!("Command timed out after {}s", secs))
             }
+            ForgeCmdError::NeedsInput { prompt, .. } => {
+                FoundationError::Tool(format!("Command is waiting for input: {}", prompt.text))
+            }
             ForgeCmdError::ForbiddenCommand(cmd) => {
                 FoundationError::PermissionDenied(format!("Forbidden command: {}", cmd))
             }
@@ -218,6 +243,19 @@ mod tests {
         };
         assert!(err.requires_user_action());
 
+        let err = ForgeCmdError::NeedsInput {
+            prompt: InputPrompt {
+                kind: crate::forgecmd::prompt::PromptKind::Password,
+                text: "Password:".into(),
+            },
+            output: String::new(),
+        };
+        assert!(err.requires_user_action());
+        assert_eq!(
+            err.user_message(),
+            "Command is waiting for input: Password:"
+        );
+
         let err = ForgeCmdError::ForbiddenCommand("rm -rf /".into());
         assert!(!err.requires_user_action());
     }
@@ -0,0 +1,326 @@
+//! Command explanations for forgecmd confirmation prompts
+//!
+//! `RiskAnalysis` says *how* risky a command is; an explanation says *what*
+//! it will do. `ForgeCmd::explain` combines:
+//! - A static effects pass (files touched, network access, destructiveness)
+//! - An optional `CommandExplainer` (usually a cheap LLM) for a plain-language summary
+//!
+//! The result is attached to the `ConfirmationPrompt` so the user sees it
+//! before approving a risky command.
+
+use crate::forgecmd::filter::{CommandCategory, RiskAnalysis};
+use async_trait::async_trait;
+use forge_foundation::permission::{ConfirmationPrompt, PromptMessage};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How long to wait for an explainer before showing the prompt without a summary
+pub const EXPLAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of files listed in the prompt
+const MAX_LISTED_FILES: usize = 5;
+
+/// Commands whose arguments are files they modify
+const FILE_COMMANDS: &[&str] = &[
+    "rm", "rmdir", "mv", "cp", "touch", "mkdir", "chmod", "chown", "chgrp", "ln", "tee",
+    "truncate", "shred", "unlink", "install",
+];
+
+/// Commands that always destroy data
+const DESTRUCTIVE_COMMANDS: &[&str] = &[
+    "rm", "rmdir", "shred", "truncate", "unlink", "dd", "mkfs", "wipefs", "fdisk",
+];
+
+/// Commands that always use the network
+const NETWORK_COMMANDS: &[&str] = &[
+    "curl", "wget", "ssh", "scp", "sftp", "rsync", "ftp", "telnet", "nc", "ncat", "ping", "dig",
+    "nslookup",
+];
+
+/// Tools that use the network for some subcommands
+const NETWORK_SUBCOMMANDS: &[(&str, &[&str])] = &[
+    (
+        "git",
+        &["push", "pull", "fetch", "clone", "ls-remote", "submodule"],
+    ),
+    ("npm", &["install", "i", "ci", "add", "publish", "update"]),
+    ("pnpm", &["install", "i", "add", "publish", "update"]),
+    ("yarn", &["install", "add", "publish", "upgrade"]),
+    ("pip", &["install", "download"]),
+    ("pip3", &["install", "download"]),
+    (
+        "cargo",
+        &["install", "publish", "update", "fetch", "search"],
+    ),
+    ("docker", &["pull", "push", "login"]),
+    ("go", &["get", "install"]),
+];
+
+/// Source of plain-language command explanations
+///
+/// Implementations are expected to be cheap (a small model, a cache, ...);
+/// `ForgeCmd::explain` bounds each call with [`EXPLAIN_TIMEOUT`].
+#[async_trait]
+pub trait CommandExplainer: Send + Sync {
+    /// Explain what `command` will do, in one or two sentences
+    async fn explain(
+        &self,
+        command: &str,
+        analysis: &RiskAnalysis,
+    ) -> forge_foundation::Result<String>;
+}
+
+/// Statically inferred effects of a command
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandEffects {
+    /// Files and directories the command writes, moves or deletes
+    pub files: Vec<String>,
+
+    /// Whether the command accesses the network
+    pub network: bool,
+
+    /// Whether the command deletes or overwrites data
+    pub destructive: bool,
+}
+
+impl CommandEffects {
+    /// Infer effects from the command text
+    ///
+    /// This is a heuristic over each pipeline segment; commands hidden behind
+    /// scripts or `eval` are not seen.
+    pub fn of(command: &str) -> Self {
+        let mut effects = Self::default();
+        let segments = command
+            .split("&&")
+            .flat_map(|s| s.split("||"))
+            .flat_map(|s| s.split([';', '|', '\n']));
+        for segment in segments {
+            let words = shlex::split(segment)
+                .unwrap_or_else(|| segment.split_whitespace().map(String::from).collect());
+            effects.add_segment(&words);
+        }
+        effects
+    }
+
+    fn add_segment(&mut self, words: &[String]) {
+        let words: Vec<&str> = words
+            .iter()
+            .map(String::as_str)
+            .skip_while(|w| *w == "sudo" || w.contains('='))
+            .collect();
+        let Some(&program) = words.first() else {
+            return;
+        };
+        let program = program.rsplit('/').next().unwrap_or(program);
+
+        // Redirection targets: `> file`, `>file`, `>> file` (but not `2>&1`)
+        let mut args = Vec::new();
+        let mut iter = words.iter().skip(1);
+        while let Some(&word) = iter.next() {
+            let redirect = word.trim_start_matches(|c: char| c.is_ascii_digit());
+            if let Some(target) = redirect.strip_prefix('>') {
+                let append = target.starts_with('>');
+                let target = target.trim_start_matches('>');
+                let target = if target.is_empty() {
+                    iter.next().copied().unwrap_or_default()
+                } else {
+                    target
+                };
+                if !target.is_empty() && !target.starts_with('&') && target != "/dev/null" {
+                    self.push_file(target);
+                    self.destructive |= !append;
+                }
+            } else if !redirect.starts_with('<') {
+                args.push(word);
+            }
+        }
+
+        if FILE_COMMANDS.contains(&program) {
+            for arg in args.iter().filter(|a| !a.starts_with('-')) {
+                self.push_file(arg);
+            }
+        }
+        if program == "dd" {
+            for target in args.iter().filter_map(|a| a.strip_prefix("of=")) {
+                self.push_file(target);
+            }
+        }
+        if program == "sed" && args.iter().any(|a| a.starts_with("-i")) {
+            // The first non-flag argument is the script
+            for arg in args.iter().filter(|a| !a.starts_with('-')).skip(1) {
+                self.push_file(arg);
+            }
+        }
+
+        let subcommand = args.iter().find(|a| !a.starts_with('-')).copied();
+        self.destructive |= DESTRUCTIVE_COMMANDS.contains(&program)
+            || program.starts_with("mkfs.")
+            || (program == "git"
+                && (matches!(subcommand, Some("clean"))
+                    || (matches!(subcommand, Some("reset")) && args.contains(&"--hard"))
+                    || (matches!(subcommand, Some("push"))
+                        && args.iter().any(|a| *a == "--force" || *a == "-f"))));
+
+        self.network |= NETWORK_COMMANDS.contains(&program)
+            || NETWORK_SUBCOMMANDS.iter().any(|(tool, subcommands)| {
+                *tool == program && subcommand.is_some_and(|s| subcommands.contains(&s))
+            });
+    }
+
+    fn push_file(&mut self, file: &str) {
+        if !self.files.iter().any(|f| f == file) {
+            self.files.push(file.to_string());
+        }
+    }
+
+    /// Check whether the command has any notable effect
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && !self.network && !self.destructive
+    }
+}
+
+/// What a command will do, for display before it is approved
+#[derive(Debug, Clone)]
+pub struct CommandExplanation {
+    /// The command being explained
+    pub command: String,
+
+    /// Risk analysis from the command filter
+    pub analysis: RiskAnalysis,
+
+    /// Statically inferred effects
+    pub effects: CommandEffects,
+
+    /// Plain-language summary from the explainer (None if unavailable)
+    pub summary: Option<String>,
+}
+
+impl CommandExplanation {
+    /// Explanation without an explainer summary
+    pub fn new(command: &str, analysis: RiskAnalysis) -> Self {
+        Self {
+            command: command.to_string(),
+            effects: CommandEffects::of(command),
+            analysis,
+            summary: None,
+        }
+    }
+
+    /// Set the summary
+    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Whether an explainer should be asked about this command
+    ///
+    /// Read-only commands run without confirmation, so explaining them is wasted work.
+    pub fn worth_explaining(&self) -> bool {
+        self.analysis.requires_confirmation
+            || !matches!(self.analysis.category, CommandCategory::ReadOnly)
+    }
+
+    /// Effects as prompt risk factors
+    pub fn risk_factors(&self) -> Vec<PromptMessage> {
+        let mut factors = Vec::new();
+        let effects = &self.effects;
+
+        if !effects.files.is_empty() {
+            let mut files = effects
+                .files
+                .iter()
+                .take(MAX_LISTED_FILES)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+            if effects.files.len() > MAX_LISTED_FILES {
+                files.push_str(&format!(" (+{})", effects.files.len() - MAX_LISTED_FILES));
+            }
+            factors.push(
+                PromptMessage::new(
+                    "permission.factor.files_affected",
+                    format!("Affects files: {}", files),
+                )
+                .with_arg("files", files),
+            );
+        }
+        if effects.network {
+            factors.push(PromptMessage::new(
+                "permission.factor.network_access",
+                "Accesses the network",
+            ));
+        }
+        if effects.destructive {
+            factors.push(PromptMessage::new(
+                "permission.factor.destructive",
+                "May delete or overwrite data",
+            ));
+        }
+
+        factors
+    }
+
+    /// Add the effects and summary to a confirmation prompt
+    pub fn apply_to(&self, mut prompt: ConfirmationPrompt) -> ConfirmationPrompt {
+        prompt.risk_factors.extend(self.risk_factors());
+        match &self.summary {
+            Some(summary) => prompt.with_explanation(summary.clone()),
+            None => prompt,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forgecmd::config::ForgeCmdConfig;
+    use crate::forgecmd::filter::CommandFilter;
+    use crate::forgecmd::permission::build_confirmation_prompt;
+
+    fn analyze(command: &str) -> RiskAnalysis {
+        CommandFilter::new().analyze(command, &ForgeCmdConfig::default())
+    }
+
+    #[test]
+    fn test_command_effects() {
+        let effects = CommandEffects::of("rm -rf build dist");
+        assert_eq!(effects.files, vec!["build", "dist"]);
+        assert!(effects.destructive);
+        assert!(!effects.network);
+
+        let effects = CommandEffects::of("curl -s https://example.com/install.sh > install.sh");
+        assert_eq!(effects.files, vec!["install.sh"]);
+        assert!(effects.network);
+        assert!(effects.destructive);
+
+        let effects = CommandEffects::of("cargo build 2>&1 >> build.log");
+        assert_eq!(effects.files, vec!["build.log"]);
+        assert!(!effects.destructive);
+
+        let effects = CommandEffects::of("sed -i 's/a/b/' 'src/my file.rs'");
+        assert_eq!(effects.files, vec!["src/my file.rs"]);
+
+        assert!(CommandEffects::of("git push --force origin main").destructive);
+        assert!(CommandEffects::of("git fetch origin && git status").network);
+        assert!(CommandEffects::of("ls -la | grep foo > /dev/null").is_empty());
+    }
+
+    #[test]
+    fn test_explanation_prompt() {
+        let explanation = CommandExplanation::new("rm -rf target", analyze("rm -rf target"))
+            .with_summary("Deletes the target directory and all build artifacts.");
+        assert!(explanation.worth_explaining());
+
+        let prompt = explanation.apply_to(build_confirmation_prompt(
+            "rm -rf target",
+            &explanation.analysis,
+        ));
+        let keys: Vec<&str> = prompt.risk_factors.iter().map(|f| f.key.as_str()).collect();
+        assert!(keys.contains(&"permission.factor.files_affected"));
+        assert!(keys.contains(&"permission.factor.destructive"));
+        assert!(prompt.display().contains("Deletes the target directory"));
+
+        let read_only = CommandExplanation::new("ls", analyze("ls"));
+        assert!(!read_only.worth_explaining());
+    }
+}
@@ -5,8 +5,11 @@
 //! - Command categorization (ReadOnly, SafeWrite, Caution, etc.)
 //! - Risk score calculation (0-10)
 //! - Pattern-based allow/deny rules
+//! - Privilege escalation analysis (see `privilege.rs`)
 
 use crate::forgecmd::config::{pattern_matches, ForgeCmdConfig, RiskThresholds};
+use crate::forgecmd::privilege::{detect_escalation, Escalation};
+use crate::forgecmd::rule::RuleKind;
 use regex::Regex;
 use std::collections::HashSet;
 
@@ -31,6 +34,9 @@ pub enum CommandCategory {
     /// Interactive programs - special handling (vim, htop)
     Interactive,
 
+    /// Privilege escalation - confirm every time (sudo, doas, su, runas)
+    Privileged,
+
     /// Unknown - requires analysis
     Unknown,
 }
@@ -162,7 +168,7 @@ impl CommandFilter {
 
         // 2. Check deny rules
         for rule in &config.rules.deny {
-            if pattern_matches(&rule.pattern, cmd_trimmed) {
+            if rule.parsed().matches_command(cmd_trimmed, RuleKind::Deny) {
                 let reason = rule
                     .reason
                     .clone()
@@ -173,9 +179,14 @@ impl CommandFilter {
             }
         }
 
-        // 3. Check allow rules
+        // 3. Privilege escalation (allow rules cannot pre-approve it)
+        if let Some(escalation) = detect_escalation(cmd_trimmed) {
+            return self.analyze_escalation(&escalation, config);
+        }
+
+        // 4. Check allow rules
         for rule in &config.rules.allow {
-            if pattern_matches(&rule.pattern, cmd_trimmed) {
+            if rule.parsed().matches_command(cmd_trimmed, RuleKind::Allow) {
                 let risk = match rule.scope.as_deref() {
                     Some("always") => 0,
                     Some("session") => 2,
@@ -187,9 +198,9 @@ impl CommandFilter {
             }
         }
 
-        // 4. Check ask rules
+        // 5. Check ask rules
         for rule in &config.rules.ask {
-            if pattern_matches(&rule.pattern, cmd_trimmed) {
+            if rule.parsed().matches_command(cmd_trimmed, RuleKind::Ask) {
                 let risk = rule.risk.unwrap_or(6);
                 return RiskAnalysis::new(CommandCategory::Caution, risk)
                     .with_reason("Requires confirmation")
@@ -197,12 +208,65 @@ impl CommandFilter {
             }
         }
 
-        // 5. Categorize by command type
+        // 6. Categorize by command type
         self.categorize_command(cmd_trimmed)
     }
 
+    /// Analyze a command that escalates privileges
+    ///
+    /// The risk is that of the elevated command plus 3 (at least 6); an
+    /// elevated shell is 9. A forbidden elevated command stays forbidden.
+    fn analyze_escalation(&self, escalation: &Escalation, config: &ForgeCmdConfig) -> RiskAnalysis {
+        let risk = match &escalation.command {
+            Some(command) => {
+                let inner = self.analyze(command, config);
+                if inner.category == CommandCategory::Forbidden {
+                    return inner;
+                }
+                (inner.risk_score + 3).clamp(6, 9)
+            }
+            None => 9,
+        };
+        RiskAnalysis::new(CommandCategory::Privileged, risk).with_reason(escalation.describe())
+    }
+
     /// Categorize a command based on built-in rules
+    ///
+    /// Chained commands (`a && b`, `a; b`, `a & b`) take the riskiest part.
     fn categorize_command(&self, command: &str) -> RiskAnalysis {
+        let commands = chained_commands(command);
+        if commands.len() > 1 {
+            if let Some(analysis) = self.match_dangerous_pattern(command) {
+                return analysis;
+            }
+            return commands
+                .into_iter()
+                .map(|command| self.categorize_simple_command(command))
+                .max_by_key(|analysis| analysis.risk_score)
+                .expect("at least two commands");
+        }
+
+        let analysis = self.categorize_simple_command(command);
+        if analysis.category == CommandCategory::ReadOnly && redirects_to_file(command) {
+            return RiskAnalysis::new(CommandCategory::SafeWrite, 3)
+                .with_reason("Redirects output to a file");
+        }
+        analysis
+    }
+
+    /// Dangerous pattern matching the whole command
+    fn match_dangerous_pattern(&self, command: &str) -> Option<RiskAnalysis> {
+        self.dangerous_patterns
+            .iter()
+            .find(|pattern| pattern_matches(pattern, command))
+            .map(|pattern| {
+                RiskAnalysis::new(CommandCategory::Dangerous, 8)
+                    .with_reason(format!("Matches dangerous pattern: {}", pattern))
+            })
+    }
+
+    /// Categorize a single (non-chained) command
+    fn categorize_simple_command(&self, command: &str) -> RiskAnalysis {
         let first_word = extract_first_word(command);
 
         // Check read-only
@@ -224,11 +288,8 @@ impl CommandFilter {
         }
 
         // Check dangerous patterns
-        for pattern in &self.dangerous_patterns {
-            if pattern_matches(pattern, command) {
-                return RiskAnalysis::new(CommandCategory::Dangerous, 8)
-                    .with_reason(format!("Matches dangerous pattern: {}", pattern));
-            }
+        if let Some(analysis) = self.match_dangerous_pattern(command) {
+            return analysis;
         }
 
         // Check specific commands
@@ -267,11 +328,6 @@ impl CommandFilter {
                 }
             }
 
-            // Elevated privileges
-            "sudo" | "su" | "doas" => {
-                RiskAnalysis::new(CommandCategory::Dangerous, 8).with_reason("Elevated privileges")
-            }
-
             // Default - unknown
             _ => RiskAnalysis::new(CommandCategory::Unknown, 4).with_reason("Unknown command"),
         }
@@ -403,6 +459,9 @@ pub fn decide_permission(
             }
         }
 
+        // Never auto-approved, no matter the score
+        CommandCategory::Privileged => PermissionDecision::AskUser,
+
         _ => {
             if analysis.risk_score <= thresholds.auto_approve {
                 PermissionDecision::Allow
@@ -566,6 +625,42 @@ fn extract_first_word(command: &str) -> &str {
     command.split_whitespace().next().unwrap_or("")
 }
 
+/// Commands chained with `&&`, `||`, `;`, `|`, `&` or a newline
+///
+/// The `&` of `2>&1` and `&>` is a redirect, not a separator.
+fn chained_commands(command: &str) -> Vec<&str> {
+    let bytes = command.as_bytes();
+    let mut commands = Vec::new();
+    let mut start = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+        let separator = match byte {
+            b';' | b'|' | b'\n' => true,
+            b'&' => (i == 0 || bytes[i - 1] != b'>') && bytes.get(i + 1) != Some(&b'>'),
+            _ => false,
+        };
+        if separator {
+            commands.push(&command[start..i]);
+            start = i + 1;
+        }
+    }
+    commands.push(&command[start..]);
+    commands
+        .into_iter()
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+        .collect()
+}
+
+/// Whether the command redirects output into a file (`> f`, `>> f`, `&> f`)
+///
+/// Descriptor duplication (`2>&1`) and `/dev/null` are not writes.
+fn redirects_to_file(command: &str) -> bool {
+    command.match_indices('>').any(|(i, _)| {
+        let target = command[i + 1..].trim_start_matches('>').trim_start();
+        !command[..i].ends_with('>') && !target.starts_with('&') && !target.starts_with("/dev/null")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -604,6 +699,35 @@ mod tests {
         assert_eq!(analysis.category, CommandCategory::Caution);
     }
 
+    #[test]
+    fn test_privileged_commands() {
+        let filter = CommandFilter::new();
+        let config = ForgeCmdConfig::default().allow("sudo *", "always");
+
+        let analysis = filter.analyze("sudo apt install vim", &config);
+        assert_eq!(analysis.category, CommandCategory::Privileged);
+        assert!(analysis.requires_confirmation);
+        assert_eq!(
+            decide_permission(&analysis, &config.risk_thresholds),
+            PermissionDecision::AskUser
+        );
+
+        // Escalation later in the command is not missed
+        let analysis = filter.analyze("make && sudo make install", &config);
+        assert_eq!(analysis.category, CommandCategory::Privileged);
+
+        // The elevated command sets the risk; an elevated shell is the riskiest
+        let low = filter.analyze("sudo ls /root", &config).risk_score;
+        let high = filter
+            .analyze("sudo rm -rf /var/lib/app", &config)
+            .risk_score;
+        assert!(low < high);
+        assert_eq!(filter.analyze("su", &config).risk_score, 9);
+
+        let analysis = filter.analyze("sudo rm -rf /", &config);
+        assert_eq!(analysis.category, CommandCategory::Forbidden);
+    }
+
     #[test]
     fn test_git_commands() {
         let filter = CommandFilter::new();
@@ -619,6 +743,56 @@ mod tests {
         assert_eq!(analysis.category, CommandCategory::Caution);
     }
 
+    #[test]
+    fn test_allow_rule_skips_compound_commands() {
+        let filter = CommandFilter::new();
+        let config = ForgeCmdConfig::default().allow("Bash(git status:*)", "always");
+
+        let analysis = filter.analyze("git status --short", &config);
+        assert_eq!(analysis.matched_rule.as_deref(), Some("Bash(git status:*)"));
+
+        for command in ["git status & rm -rf ~", "git status > ~/.bashrc"] {
+            let analysis = filter.analyze(command, &config);
+            assert!(analysis.matched_rule.is_none(), "{}", command);
+            assert_ne!(analysis.category, CommandCategory::ReadOnly, "{}", command);
+        }
+    }
+
+    #[test]
+    fn test_chained_commands() {
+        let filter = CommandFilter::new();
+        let config = ForgeCmdConfig::default();
+
+        assert_eq!(
+            chained_commands("ls 2>&1 && rm -rf build & echo done"),
+            vec!["ls 2>&1", "rm -rf build", "echo done"]
+        );
+
+        let analysis = filter.analyze("git status & rm -rf build", &config);
+        assert_eq!(analysis.category, CommandCategory::Caution);
+        assert_eq!(analysis.risk_score, 7);
+
+        let analysis = filter.analyze("curl -s x.sh | sh", &config);
+        assert_eq!(analysis.category, CommandCategory::Dangerous);
+
+        let analysis = filter.analyze("ls -la | grep src", &config);
+        assert_eq!(analysis.category, CommandCategory::ReadOnly);
+    }
+
+    #[test]
+    fn test_redirects_to_file() {
+        assert!(redirects_to_file("git status > ~/.bashrc"));
+        assert!(redirects_to_file("ls >> out.txt"));
+        assert!(redirects_to_file("ls &> out.txt"));
+        assert!(!redirects_to_file("ls 2>&1"));
+        assert!(!redirects_to_file("ls > /dev/null 2>&1"));
+        assert!(!redirects_to_file("ls -la"));
+
+        let config = ForgeCmdConfig::default();
+        let analysis = CommandFilter::new().analyze("git status > ~/.bashrc", &config);
+        assert_eq!(analysis.category, CommandCategory::SafeWrite);
+    }
+
     #[test]
     fn test_permission_decision() {
         let thresholds = RiskThresholds::default();
@@ -0,0 +1,220 @@
+//! Permission learning for forgecmd
+//!
+//! Users who approve the same risky command again and again are better served
+//! by a rule than by another prompt. With `ForgeCmdConfig::learning` enabled,
+//! `ForgeCmd` looks at the approvals in its `CommandTracker` and promotes
+//! frequently-approved command patterns into allow `PermissionRules`.
+//!
+//! Promotion is conservative:
+//! - Compound commands (`&&`, `|`, `;`, redirections, substitutions) are never learned
+//! - `program subcommand` commands generalize to `program subcommand *`;
+//!   anything else is learned only as the exact command
+//! - Wrappers (`sudo`, `env`, `sh`, ...) are never generalized
+//! - Dangerous and privileged commands, and commands at or above
+//!   `LearningConfig::max_risk`, are never promoted
+//!
+//! Every promotion is written to the audit log.
+
+use crate::forgecmd::config::{ForgeCmdConfig, PermissionRule};
+use crate::forgecmd::rule::RuleKind;
+use crate::forgecmd::tracker::CommandRecord;
+use forge_foundation::audit::{AuditAction, AuditEntry, AuditResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Tracker value of `approved_by` for commands the user confirmed
+pub const APPROVED_BY_USER: &str = "user";
+
+/// Audit actor for learned rules
+const AUDIT_ACTOR: &str = "forgecmd.learning";
+
+/// Shell syntax that makes a command compound
+const COMPOUND_MARKERS: &[&str] = &["&&", "||", ";", "|", ">", "<", "`", "$(", "&", "\n"];
+
+/// Programs that run their arguments as another command
+const WRAPPERS: &[&str] = &[
+    "sudo", "doas", "su", "env", "sh", "bash", "zsh", "eval", "exec", "xargs", "nohup", "nice",
+    "time", "timeout", "watch",
+];
+
+/// A command pattern promoted into an allow rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LearnedRule {
+    /// Rule pattern (`git push *` or an exact command)
+    pub pattern: String,
+
+    /// Rule scope ("session" or "always")
+    pub scope: String,
+
+    /// Number of approvals that led to the rule
+    pub approvals: usize,
+
+    /// Highest risk score among the approved commands
+    pub max_risk: u8,
+}
+
+impl LearnedRule {
+    /// The allow rule to add to `PermissionRules`
+    pub fn to_rule(&self) -> PermissionRule {
+        PermissionRule::allow(&self.pattern, &self.scope)
+    }
+
+    /// Audit log entry recording the promotion
+    pub fn audit_entry(&self, session_id: &str) -> AuditEntry {
+        AuditEntry::new(AuditAction::ConfigChanged, AUDIT_ACTOR)
+            .with_result(AuditResult::Success)
+            .with_session(session_id)
+            .with_target(&self.pattern)
+            .with_description(format!(
+                "Promoted '{}' to a {} allow rule after {} approvals",
+                self.pattern, self.scope, self.approvals
+            ))
+            .with_data(serde_json::json!({
+                "pattern": self.pattern,
+                "scope": self.scope,
+                "approvals": self.approvals,
+                "maxRisk": self.max_risk,
+            }))
+            .with_risk_level(self.max_risk)
+            .with_tag("permission")
+            .with_tag("learned-rule")
+    }
+}
+
+/// Pattern a command is learned as, or None if it must not be learned
+pub fn learned_pattern(command: &str) -> Option<String> {
+    let command = command.trim();
+    if command.is_empty() || COMPOUND_MARKERS.iter().any(|m| command.contains(m)) {
+        return None;
+    }
+
+    let words = shlex::split(command)?;
+    let is_subcommand = |word: &str| {
+        !word.is_empty()
+            && word
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            && !word.starts_with('-')
+    };
+
+    match words.as_slice() {
+        [program, subcommand, _, ..]
+            if is_subcommand(subcommand) && !WRAPPERS.contains(&program.as_str()) =>
+        {
+            Some(format!("{} {} *", program, subcommand))
+        }
+        _ => Some(words.join(" ")),
+    }
+}
+
+/// Rules to promote from the approved commands in `records`
+///
+/// Patterns already covered by an allow or deny rule are skipped.
+pub fn suggest_rules(records: &[CommandRecord], config: &ForgeCmdConfig) -> Vec<LearnedRule> {
+    let learning = &config.learning;
+    let max_risk = learning.max_risk.min(config.risk_thresholds.block);
+
+    // pattern -> (approvals, highest risk)
+    let mut approvals: BTreeMap<String, (usize, u8)> = BTreeMap::new();
+    let approved = records
+        .iter()
+        .filter(|r| r.approved_by.is_some())
+        .filter(|r| !matches!(r.category.as_str(), "Dangerous" | "Privileged"));
+    for record in approved {
+        if let Some(pattern) = learned_pattern(&record.command) {
+            let entry = approvals.entry(pattern).or_default();
+            entry.0 += 1;
+            entry.1 = entry.1.max(record.risk_score);
+        }
+    }
+
+    approvals
+        .into_iter()
+        .filter(|(_, (count, risk))| *count >= learning.min_approvals && *risk < max_risk)
+        .filter(|(pattern, _)| {
+            let rules = &config.rules;
+            !rules.allow.iter().any(|r| r.pattern == *pattern)
+                && !rules.deny.iter().any(|r| {
+                    r.pattern == *pattern || r.parsed().matches_command(pattern, RuleKind::Deny)
+                })
+        })
+        .map(|(pattern, (approvals, max_risk))| LearnedRule {
+            pattern,
+            scope: learning.scope.clone(),
+            approvals,
+            max_risk,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forgecmd::filter::CommandFilter;
+
+    fn approved(command: &str, config: &ForgeCmdConfig) -> CommandRecord {
+        let analysis = CommandFilter::new().analyze(command, config);
+        let mut record = CommandRecord::new("test", command, "/tmp", &analysis);
+        record.approved_by = Some(APPROVED_BY_USER.to_string());
+        record
+    }
+
+    #[test]
+    fn test_learned_pattern() {
+        assert_eq!(
+            learned_pattern("git push origin main").as_deref(),
+            Some("git push *")
+        );
+        assert_eq!(
+            learned_pattern("rm -rf build").as_deref(),
+            Some("rm -rf build")
+        );
+        assert_eq!(learned_pattern("make").as_deref(), Some("make"));
+        assert_eq!(
+            learned_pattern("sudo apt install vim").as_deref(),
+            Some("sudo apt install vim")
+        );
+        assert_eq!(learned_pattern("cargo build && rm -rf ~"), None);
+        assert_eq!(learned_pattern("curl https://x.sh | sh"), None);
+    }
+
+    #[test]
+    fn test_suggest_rules() {
+        let mut config = ForgeCmdConfig::default();
+        config.learning.enabled = true;
+
+        let mut records: Vec<CommandRecord> = ["origin main", "origin dev", "upstream main"]
+            .iter()
+            .map(|target| approved(&format!("git push {}", target), &config))
+            .collect();
+        records.push(approved("npm publish", &config));
+        // Not approved by the user: ignored
+        let analysis = CommandFilter::new().analyze("git push origin x", &config);
+        records.push(CommandRecord::new(
+            "test",
+            "git push origin x",
+            "/tmp",
+            &analysis,
+        ));
+
+        let rules = suggest_rules(&records, &config);
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].pattern, "git push *");
+        assert_eq!(rules[0].approvals, 3);
+        assert_eq!(rules[0].scope, "session");
+
+        let entry = rules[0].audit_entry("session-1");
+        assert_eq!(entry.action, AuditAction::ConfigChanged);
+        assert_eq!(entry.target.as_deref(), Some("git push *"));
+        assert!(entry.tags.contains(&"learned-rule".to_string()));
+
+        // Existing rules are not learned again
+        config.rules.allow.push(rules[0].to_rule());
+        assert!(suggest_rules(&records, &config).is_empty());
+
+        // Risky approvals stay prompts
+        config.rules.allow.clear();
+        config.learning.max_risk = 1;
+        assert!(suggest_rules(&records, &config).is_empty());
+    }
+}
@@ -7,7 +7,16 @@
 //! ## Features
 //!
 //! - **PTY Support**: Full pseudo-terminal for interactive commands (vim, htop, etc.)
+//! - **Named Sessions**: Background PTY sessions (REPLs, dev servers) that survive between calls
+//! - **Prompt Detection**: Commands waiting for input (password, `[y/N]`, pager) raise `NeedsInput`
 //! - **Permission Control**: 5-level risk classification with Layer1 integration
+//! - **Rule Patterns**: Claude Code `settings.json` syntax (`Bash(git push:*)`, `Edit(src/**)`, `WebFetch(domain:github.com)`)
+//! - **Privilege Escalation**: `sudo`/`doas`/`su`/`runas` anywhere in a command are confirmed and audited every time
+//! - **Permission Learning**: Opt-in promotion of repeatedly approved commands into audited allow rules
+//! - **Explanations**: Files affected, network access and an optional LLM summary in confirmation prompts
+//! - **Recording**: Opt-in asciinema v2 casts of every PTY session, for audits and bug reports
+//! - **Working Directory Tracking**: `cd` in a PTY (OSC 7) updates `working_dir()` and path checks
+//! - **Output Normalization**: ANSI, redrawn lines and progress bars removed; no pager, `LANG=C.UTF-8`
 //! - **Command Tracking**: Full history with timing, output, and risk analysis
 //! - **Security**: Forbidden command detection, environment filtering
 //!
@@ -26,6 +35,12 @@
 //! let result = forge_cmd.execute("ls -la").await?;
 //! println!("Exit code: {:?}", result.exit_code);
 //! println!("Output: {}", result.stdout);
+//!
+//! // Keep a dev server running and check on it in a later turn
+//! forge_cmd.start_session("server", "npm run dev")?;
+//! let server = forge_cmd.session("server")?;
+//! server.wait_for_output("ready", Duration::from_secs(30));
+//! println!("{}", server.read());
 //! ```
 //!
 //! ## Architecture
@@ -49,24 +64,53 @@
 
 // Submodules
 pub mod config;
+pub mod cwd;
 pub mod error;
+pub mod explain;
 pub mod filter;
+pub mod learning;
+pub mod output;
 pub mod permission;
+pub mod privilege;
+pub mod prompt;
+pub mod recording;
+pub mod rule;
+pub mod session;
 pub mod shell;
 pub mod tracker;
 
 // Re-exports
-pub use config::{ForgeCmdConfig, PermissionRule, PermissionRules, PtySize, RiskThresholds};
+pub use config::{
+    ForgeCmdConfig, LearningConfig, OutputConfig, PermissionRule, PermissionRules, PtySize,
+    RiskThresholds,
+};
+pub use cwd::parse_osc7;
 pub use error::{CommandResult, ForgeCmdError};
+pub use explain::{CommandEffects, CommandExplainer, CommandExplanation, EXPLAIN_TIMEOUT};
 pub use filter::{CommandCategory, CommandFilter, PermissionDecision, RiskAnalysis};
+pub use learning::{learned_pattern, LearnedRule, APPROVED_BY_USER};
+pub use output::{collapse_progress, normalize_output};
 pub use permission::{CheckResult, ConfirmOption, ConfirmationPrompt, PermissionChecker};
-pub use shell::{execute_simple, PtySession, SpawnedCommand};
+pub use privilege::{detect_escalation, Escalation, EscalationTool, PRIVILEGED_PERMISSION};
+pub use prompt::{detect_prompt, InputPrompt, PromptKind};
+pub use recording::{
+    export_recording, list_recordings, recording_text, CastHeader, CastRecorder, ExportFormat,
+    RecordingInfo,
+};
+pub use rule::{RuleKind, RulePattern};
+pub use session::{BackgroundSession, MAX_SESSION_OUTPUT};
+pub use shell::{execute_simple, PtyEvent, PtySession, SpawnedCommand};
 pub use tracker::{CommandRecord, CommandTracker, ExecutionStatus, TrackerStats};
 
+use crate::forgecmd::shell::execute_simple_normalized;
+use crate::tool::security::PathValidator;
+use forge_foundation::audit::{AuditEntry, AuditId, AuditLogger, AuditResult};
 use forge_foundation::permission::{
     categories, register, PermissionDef, PermissionScope, PermissionService,
 };
-use std::path::PathBuf;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -128,6 +172,14 @@ pub fn register_permissions() {
             .requires_confirmation(true),
     );
 
+    // Privilege escalation (sudo, doas, su, runas)
+    register(
+        PermissionDef::new(PRIVILEGED_PERMISSION, categories::EXECUTE)
+            .risk_level(8)
+            .description("Run command with elevated privileges (sudo, doas, su, runas)")
+            .requires_confirmation(true),
+    );
+
     // Forbidden commands (always blocked)
     register(
         PermissionDef::new("forgecmd.forbidden", categories::EXECUTE)
@@ -145,6 +197,7 @@ pub fn permission_name_for_category(category: CommandCategory) -> &'static str {
         CommandCategory::Dangerous => "forgecmd.dangerous",
         CommandCategory::Forbidden => "forgecmd.forbidden",
         CommandCategory::Interactive => "forgecmd.interactive",
+        CommandCategory::Privileged => PRIVILEGED_PERMISSION,
         CommandCategory::Unknown => "forgecmd.execute",
     }
 }
@@ -163,6 +216,15 @@ pub struct ForgeCmd {
     /// PTY session (lazy initialized)
     session: Option<PtySession>,
 
+    /// Named background sessions
+    sessions: HashMap<String, BackgroundSession>,
+
+    /// Summarizes commands for confirmation prompts (optional)
+    explainer: Option<Arc<dyn CommandExplainer>>,
+
+    /// Audit log for learned permission rules (optional)
+    audit_logger: Option<Arc<AuditLogger>>,
+
     /// Configuration
     config: ForgeCmdConfig,
 
@@ -192,6 +254,9 @@ impl ForgeCmd {
             ),
             tracker: CommandTracker::new(&session_id),
             session: None,
+            sessions: HashMap::new(),
+            explainer: None,
+            audit_logger: None,
             config,
             working_dir,
             session_id,
@@ -215,6 +280,9 @@ impl ForgeCmd {
             ),
             tracker: CommandTracker::new(&session_id),
             session: None,
+            sessions: HashMap::new(),
+            explainer: None,
+            audit_logger: None,
             config,
             working_dir,
             session_id,
@@ -227,13 +295,18 @@ impl ForgeCmd {
     /// Returns an error if permission is denied or confirmation is required.
     pub async fn execute(&mut self, command: &str) -> Result<CommandResult, ForgeCmdError> {
         // 1. Check permission
+        self.ensure_allowed(command)?;
+
+        // 2. Permission granted, execute
+        self.execute_internal(command, None).await
+    }
+
+    /// Check permission, returning an error unless the command is allowed
+    fn ensure_allowed(&mut self, command: &str) -> Result<(), ForgeCmdError> {
         let check_result = self.permission_checker.check_permission(command)?;
 
         match check_result {
-            CheckResult::Allowed { .. } => {
-                // Permission granted, execute
-                self.execute_internal(command).await
-            }
+            CheckResult::Allowed { .. } => self.check_paths(command),
             CheckResult::NeedsConfirmation { analysis } => {
                 // Confirmation required
                 Err(ForgeCmdError::PermissionRequired {
@@ -260,6 +333,39 @@ impl ForgeCmd {
         }
     }
 
+    /// Check the working directory and the files a command touches against `allowed_paths`
+    ///
+    /// Relative paths are resolved against the effective working directory,
+    /// which follows `cd` in PTY sessions.
+    fn check_paths(&mut self, command: &str) -> Result<(), ForgeCmdError> {
+        if self.config.allowed_paths.is_empty() {
+            return Ok(());
+        }
+
+        let validator = self.path_validator();
+        let outside = std::iter::once(self.working_dir.clone())
+            .chain(
+                CommandEffects::of(command)
+                    .files
+                    .iter()
+                    .filter_map(|file| validator.resolve(Path::new(file))),
+            )
+            .find(|path| !self.config.is_path_allowed(path));
+
+        match outside {
+            Some(path) => {
+                let reason = format!("Path outside allowed directories: {}", path.display());
+                self.tracker.record_denied(
+                    command,
+                    self.working_dir.to_string_lossy().as_ref(),
+                    &reason,
+                );
+                Err(ForgeCmdError::PermissionDenied(reason))
+            }
+            None => Ok(()),
+        }
+    }
+
     /// Execute with explicit user confirmation
     ///
     /// Use this when the user has already approved the command.
@@ -272,7 +378,12 @@ impl ForgeCmd {
         self.permission_checker.grant(command, scope);
 
         // Execute
-        self.execute_internal(command).await
+        let result = self.execute_internal(command, Some(APPROVED_BY_USER)).await;
+
+        if self.config.learning.enabled {
+            self.learn_from_approvals().await;
+        }
+        result
     }
 
     /// Execute without permission checks (use with caution!)
@@ -282,16 +393,28 @@ impl ForgeCmd {
         &mut self,
         command: &str,
     ) -> Result<CommandResult, ForgeCmdError> {
-        self.execute_internal(command).await
+        self.execute_internal(command, None).await
     }
 
     /// Internal execution (after permission checks)
-    async fn execute_internal(&mut self, command: &str) -> Result<CommandResult, ForgeCmdError> {
+    async fn execute_internal(
+        &mut self,
+        command: &str,
+        approved_by: Option<&str>,
+    ) -> Result<CommandResult, ForgeCmdError> {
         let analysis = self.permission_checker.analyze(command);
         let working_dir_str = self.working_dir.to_string_lossy().to_string();
 
+        // Privileged commands are audited before they run
+        let audit_entry = self
+            .audit_privileged(command, &analysis, approved_by)
+            .await?;
+
         // Start tracking
         let record_id = self.tracker.start(command, &working_dir_str, &analysis);
+        if let Some(approved_by) = approved_by {
+            self.tracker.approve(&record_id, approved_by);
+        }
 
         // Execute based on category
         let result = match analysis.category {
@@ -305,59 +428,313 @@ impl ForgeCmd {
             }
         };
 
-        // Update tracker
-        match &result {
+        self.track_result(&record_id, &result);
+        if let Some(entry) = audit_entry {
+            self.audit_privileged_result(entry, &result).await;
+        }
+        result
+    }
+
+    /// Write the mandatory audit entry for a privileged command
+    ///
+    /// Returns the entry (None for unprivileged commands). The entry always
+    /// goes to the `forge::audit` tracing target; if an audit logger is set
+    /// and fails to store it, the command is not run.
+    async fn audit_privileged(
+        &self,
+        command: &str,
+        analysis: &RiskAnalysis,
+        approved_by: Option<&str>,
+    ) -> Result<Option<AuditEntry>, ForgeCmdError> {
+        if analysis.category != CommandCategory::Privileged {
+            return Ok(None);
+        }
+        let Some(escalation) = detect_escalation(command) else {
+            return Ok(None);
+        };
+
+        let entry =
+            escalation.audit_entry(command, &self.session_id, approved_by, analysis.risk_score);
+        tracing::info!(
+            target: "forge::audit",
+            command,
+            user = escalation.target_user(),
+            tool = escalation.tool.as_str(),
+            approved_by,
+            "Privileged command"
+        );
+        if let Some(logger) = &self.audit_logger {
+            logger.log(entry.clone()).await.map_err(|e| {
+                ForgeCmdError::PermissionDenied(format!(
+                    "Privileged command not run, audit log failed: {}",
+                    e
+                ))
+            })?;
+        }
+        Ok(Some(entry))
+    }
+
+    /// Record how an audited privileged command ended
+    async fn audit_privileged_result(
+        &self,
+        mut entry: AuditEntry,
+        result: &Result<CommandResult, ForgeCmdError>,
+    ) {
+        entry.id = AuditId::new();
+        entry.timestamp = chrono::Utc::now();
+        let entry = match result {
+            Ok(output) if output.success() => entry
+                .with_result(AuditResult::Success)
+                .with_duration(output.duration_ms),
+            Ok(output) => entry
+                .with_error(format!("Exit code {:?}", output.exit_code))
+                .with_duration(output.duration_ms),
+            Err(e) => entry.with_error(e.to_string()),
+        };
+
+        tracing::info!(
+            target: "forge::audit",
+            command = entry.target.as_deref().unwrap_or_default(),
+            result = entry.result.as_str(),
+            "Privileged command finished"
+        );
+        if let Some(logger) = &self.audit_logger {
+            if let Err(e) = logger.log(entry).await {
+                tracing::warn!("Failed to audit privileged command result: {}", e);
+            }
+        }
+    }
+
+    /// Execute a command in the PTY, streaming events to `on_event`
+    ///
+    /// Permission-checked like `execute`. A command that stops at a prompt
+    /// raises `PtyEvent::NeedsInput`; see `PtySession::execute_streaming`.
+    pub async fn execute_interactive<F>(
+        &mut self,
+        command: &str,
+        on_event: F,
+    ) -> Result<CommandResult, ForgeCmdError>
+    where
+        F: FnMut(&PtyEvent) -> Option<String>,
+    {
+        self.ensure_allowed(command)?;
+
+        let analysis = self.permission_checker.analyze(command);
+        let working_dir_str = self.working_dir.to_string_lossy().to_string();
+        let record_id = self.tracker.start(command, &working_dir_str, &analysis);
+
+        let timeout = Duration::from_secs(self.config.timeout);
+        let result = self
+            .pty_session()
+            .and_then(|session| session.execute_streaming(command, timeout, on_event));
+        self.sync_pty_working_dir();
+
+        self.track_result(&record_id, &result);
+        result
+    }
+
+    /// Record the outcome of a tracked command
+    fn track_result(&mut self, record_id: &str, result: &Result<CommandResult, ForgeCmdError>) {
+        match result {
             Ok(cmd_result) => {
                 let exit_code = cmd_result.exit_code.unwrap_or(-1);
                 if cmd_result.success() {
                     self.tracker.complete_success(
-                        &record_id,
+                        record_id,
                         exit_code,
                         &cmd_result.stdout,
                         &cmd_result.stderr,
                     );
                 } else {
                     self.tracker.complete_failed(
-                        &record_id,
+                        record_id,
                         exit_code,
                         &cmd_result.stdout,
                         &cmd_result.stderr,
                     );
                 }
             }
+            Err(ForgeCmdError::Timeout { .. }) => {
+                self.tracker.complete_timeout(record_id, "", "");
+            }
+            Err(ForgeCmdError::NeedsInput { prompt, output }) => {
+                self.tracker
+                    .complete_failed(record_id, -1, output, &prompt.text);
+            }
             Err(e) => {
-                if matches!(e, ForgeCmdError::Timeout { .. }) {
-                    self.tracker.complete_timeout(&record_id, "", "");
-                } else {
-                    self.tracker.mark_denied(&record_id, &e.to_string());
-                }
+                self.tracker.mark_denied(record_id, &e.to_string());
             }
         }
-
-        result
     }
 
     /// Execute using PTY session
     async fn execute_with_pty(&mut self, command: &str) -> Result<CommandResult, ForgeCmdError> {
-        // Initialize session if needed
-        if self.session.is_none() {
-            self.session = Some(PtySession::new(
-                self.config.clone(),
-                self.working_dir.clone(),
-            )?);
+        let result = self.pty_session()?.execute(command);
+        self.sync_pty_working_dir();
+        result
+    }
+
+    /// Adopt the directory the PTY session ended up in
+    fn sync_pty_working_dir(&mut self) {
+        if let Some(dir) = self.session.as_ref().map(|s| s.working_dir().clone()) {
+            self.working_dir = dir;
+        }
+    }
+
+    /// PTY session (initialized on first use)
+    fn pty_session(&mut self) -> Result<&mut PtySession, ForgeCmdError> {
+        match self.session {
+            Some(ref mut session) => Ok(session),
+            None => {
+                let mut session = PtySession::new(self.config.clone(), self.working_dir.clone())?;
+                session.set_recorder(self.recorder(recording::MAIN_RECORDING, None));
+                Ok(self.session.insert(session))
+            }
         }
+    }
 
-        let session = self.session.as_mut().unwrap();
-        session.execute(command)
+    /// Recorder for a new PTY, if recording is enabled
+    fn recorder(&self, name: &str, command: Option<&str>) -> Option<CastRecorder> {
+        Self::start_recording(&self.config, &self.session_id, name, command)
+    }
+
+    fn start_recording(
+        config: &ForgeCmdConfig,
+        session_id: &str,
+        name: &str,
+        command: Option<&str>,
+    ) -> Option<CastRecorder> {
+        let dir = config.recording_dir.as_ref()?;
+        let mut header = CastHeader::new(config, format!("forgecmd {} ({})", name, session_id));
+        if let Some(command) = command {
+            header = header.with_command(command);
+        }
+        match CastRecorder::for_session(dir, session_id, name, &header) {
+            Ok(recorder) => Some(recorder),
+            Err(e) => {
+                tracing::warn!("Failed to start terminal recording for '{}': {}", name, e);
+                None
+            }
+        }
+    }
+
+    /// Recordings in the configured recording directory (all agent sessions)
+    ///
+    /// Empty when recording is disabled. Use `export_recording` to save one
+    /// as a cast file or plain-text transcript.
+    pub fn recordings(&self) -> Result<Vec<RecordingInfo>, ForgeCmdError> {
+        match &self.config.recording_dir {
+            Some(dir) => list_recordings(dir),
+            None => Ok(Vec::new()),
+        }
     }
 
     /// Execute using simple process spawn
     async fn execute_simple(&self, command: &str) -> Result<CommandResult, ForgeCmdError> {
-        execute_simple(
-            command,
+        execute_simple_normalized(command, &self.working_dir, &self.config)
+    }
+
+    /// Get a named session, starting an interactive shell if it does not exist
+    ///
+    /// The session keeps running between calls, so shell state (working
+    /// directory, variables, a REPL started inside it) carries over.
+    /// Input written directly to the session is not permission-checked;
+    /// use `run_in_session` for agent-provided commands.
+    pub fn session(&mut self, name: &str) -> Result<&mut BackgroundSession, ForgeCmdError> {
+        match self.sessions.entry(name.to_string()) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                let recorder = Self::start_recording(
+                    &self.config,
+                    &self.session_id,
+                    name,
+                    Some(&self.config.shell),
+                );
+                let session = BackgroundSession::spawn_recorded(
+                    name,
+                    None,
+                    &self.config,
+                    &self.working_dir,
+                    recorder,
+                )?;
+                Ok(entry.insert(session))
+            }
+        }
+    }
+
+    /// Start a named session running `command` (e.g. a dev server)
+    ///
+    /// The command is permission-checked like `execute`. Fails if a session
+    /// with the same name is still running; an exited one is replaced.
+    pub fn start_session(
+        &mut self,
+        name: &str,
+        command: &str,
+    ) -> Result<&mut BackgroundSession, ForgeCmdError> {
+        if let Some(existing) = self.sessions.get_mut(name) {
+            if existing.is_running() {
+                return Err(ForgeCmdError::SessionExists(name.to_string()));
+            }
+        }
+        self.ensure_allowed(command)?;
+
+        // A session being replaced stops (and finishes its recording) first
+        self.sessions.remove(name);
+        let session = BackgroundSession::spawn_recorded(
+            name,
+            Some(command),
+            &self.config,
             &self.working_dir,
-            Duration::from_secs(self.config.timeout),
-        )
+            self.recorder(name, Some(command)),
+        )?;
+        Ok(self.sessions.entry(name.to_string()).or_insert(session))
+    }
+
+    /// Send a permission-checked command line to a named session
+    ///
+    /// Starts an interactive shell session if `name` does not exist yet.
+    /// Read the output afterwards with `read` or `wait_for_output`.
+    pub fn run_in_session(&mut self, name: &str, command: &str) -> Result<(), ForgeCmdError> {
+        // Relative paths in the command are relative to where that shell is now
+        self.session(name)?;
+        self.sync_working_dir(name);
+        self.ensure_allowed(command)?;
+        self.session(name)?.send_line(command)
+    }
+
+    /// Adopt the directory a named session's shell last reported
+    ///
+    /// Returns the (possibly unchanged) effective working directory.
+    pub fn sync_working_dir(&mut self, name: &str) -> &PathBuf {
+        let cwd = self
+            .sessions
+            .get(name)
+            .and_then(BackgroundSession::cwd)
+            .filter(|dir| dir.is_dir());
+        if let Some(dir) = cwd {
+            if let Some(ref mut session) = self.session {
+                session.set_working_dir(dir.clone());
+            }
+            self.working_dir = dir;
+        }
+        &self.working_dir
+    }
+
+    /// Get an existing named session
+    pub fn get_session(&mut self, name: &str) -> Option<&mut BackgroundSession> {
+        self.sessions.get_mut(name)
+    }
+
+    /// Names of the open sessions (sorted)
+    pub fn session_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.sessions.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Close a named session, killing its process
+    pub fn close_session(&mut self, name: &str) -> bool {
+        self.sessions.remove(name).is_some()
     }
 
     /// Check if a command would be allowed (without executing)
@@ -376,6 +753,91 @@ impl ForgeCmd {
         permission::build_confirmation_prompt(command, &analysis)
     }
 
+    /// Set the audit log that records learned permission rules
+    pub fn set_audit_logger(&mut self, logger: Arc<AuditLogger>) {
+        self.audit_logger = Some(logger);
+    }
+
+    /// Promote frequently-approved commands into allow rules
+    ///
+    /// Called after each confirmed execution when `config.learning` is
+    /// enabled. Each new rule is added to `config().rules` and logged to the
+    /// audit log (and the `forge::audit` tracing target).
+    pub async fn learn_from_approvals(&mut self) -> Vec<LearnedRule> {
+        let learned = learning::suggest_rules(&self.tracker.get_all(), &self.config);
+        if learned.is_empty() {
+            return learned;
+        }
+
+        for rule in &learned {
+            self.config.rules.allow.push(rule.to_rule());
+            if rule.scope == "session" {
+                self.permission_checker
+                    .grant_pattern(&rule.pattern, PermissionScope::Session);
+            }
+
+            tracing::info!(
+                target: "forge::audit",
+                pattern = %rule.pattern,
+                scope = %rule.scope,
+                approvals = rule.approvals,
+                "Learned forgecmd allow rule"
+            );
+            if let Some(logger) = &self.audit_logger {
+                if let Err(e) = logger.log(rule.audit_entry(&self.session_id)).await {
+                    tracing::warn!("Failed to audit learned rule '{}': {}", rule.pattern, e);
+                }
+            }
+        }
+        self.permission_checker.set_config(self.config.clone());
+
+        learned
+    }
+
+    /// Set the explainer used for command summaries
+    pub fn set_explainer(&mut self, explainer: Arc<dyn CommandExplainer>) {
+        self.explainer = Some(explainer);
+    }
+
+    /// Explain what a command will do
+    ///
+    /// Combines the risk analysis with statically inferred effects. The
+    /// explainer (if any) is only asked about commands that are not read-only;
+    /// if it fails or exceeds `EXPLAIN_TIMEOUT`, the summary is left empty.
+    pub async fn explain(&self, command: &str) -> CommandExplanation {
+        let explanation = CommandExplanation::new(command, self.analyze(command));
+        let explainer = match &self.explainer {
+            Some(explainer) if explanation.worth_explaining() => explainer,
+            _ => return explanation,
+        };
+
+        match tokio::time::timeout(
+            EXPLAIN_TIMEOUT,
+            explainer.explain(command, &explanation.analysis),
+        )
+        .await
+        {
+            Ok(Ok(summary)) => explanation.with_summary(summary),
+            Ok(Err(e)) => {
+                tracing::warn!("Failed to explain command: {}", e);
+                explanation
+            }
+            Err(_) => {
+                tracing::warn!("Command explanation timed out: {}", command);
+                explanation
+            }
+        }
+    }
+
+    /// Build a confirmation prompt that includes the command's explanation
+    pub async fn build_explained_prompt(&self, command: &str) -> ConfirmationPrompt {
+        let explanation = self.explain(command).await;
+        explanation.apply_to(permission::build_confirmation_prompt(
+            command,
+            &explanation.analysis,
+        ))
+    }
+
     /// Grant permission for a command
     pub fn grant(&mut self, command: &str, scope: PermissionScope) {
         self.permission_checker.grant(command, scope);
@@ -392,6 +854,8 @@ impl ForgeCmd {
     }
 
     /// Set working directory
+    ///
+    /// Named sessions that are already running keep their own directory.
     pub fn set_working_dir(&mut self, dir: PathBuf) -> Result<(), ForgeCmdError> {
         if !dir.exists() {
             return Err(ForgeCmdError::WorkingDirectory(format!(
@@ -407,10 +871,17 @@ impl ForgeCmd {
     }
 
     /// Get current working directory
+    ///
+    /// Follows `cd` in PTY commands and in named sessions (see `sync_working_dir`).
     pub fn working_dir(&self) -> &PathBuf {
         &self.working_dir
     }
 
+    /// Path validator that resolves relative paths against the working directory
+    pub fn path_validator(&self) -> PathValidator {
+        PathValidator::new().with_base_dir(self.working_dir.clone())
+    }
+
     /// Get command history
     pub fn history(&self) -> Vec<CommandRecord> {
         self.tracker.get_all()
@@ -452,12 +923,13 @@ impl ForgeCmd {
         &self.config
     }
 
-    /// Close the session
+    /// Close the PTY session and all named sessions
     pub fn close(&mut self) {
         if let Some(ref mut session) = self.session {
             session.close();
         }
         self.session = None;
+        self.sessions.clear();
     }
 }
 
@@ -484,6 +956,8 @@ pub struct ForgeCmdBuilder {
     permission_service: Option<Arc<PermissionService>>,
     config: ForgeCmdConfig,
     working_dir: Option<PathBuf>,
+    explainer: Option<Arc<dyn CommandExplainer>>,
+    audit_logger: Option<Arc<AuditLogger>>,
 }
 
 impl ForgeCmdBuilder {
@@ -493,6 +967,8 @@ impl ForgeCmdBuilder {
             permission_service: None,
             config: ForgeCmdConfig::default(),
             working_dir: None,
+            explainer: None,
+            audit_logger: None,
         }
     }
 
@@ -532,6 +1008,18 @@ impl ForgeCmdBuilder {
         self
     }
 
+    /// Set command explainer
+    pub fn explainer(mut self, explainer: Arc<dyn CommandExplainer>) -> Self {
+        self.explainer = Some(explainer);
+        self
+    }
+
+    /// Set audit logger for learned permission rules
+    pub fn audit_logger(mut self, logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(logger);
+        self
+    }
+
     /// Build ForgeCmd instance
     pub fn build(self) -> Result<ForgeCmd, ForgeCmdError> {
         let permission_service = self
@@ -551,6 +1039,9 @@ impl ForgeCmdBuilder {
             ),
             tracker: CommandTracker::new(&session_id),
             session: None,
+            sessions: HashMap::new(),
+            explainer: self.explainer,
+            audit_logger: self.audit_logger,
             config: self.config,
             working_dir,
             session_id,
@@ -617,7 +1108,7 @@ mod tests {
         let cmd = create_forge_cmd();
         let prompt = cmd.build_prompt("rm -rf ./build");
 
-        assert!(!prompt.command.is_empty());
+        assert!(!prompt.target.is_empty());
         assert!(prompt.risk_score > 0);
     }
 
@@ -630,9 +1121,185 @@ mod tests {
         assert!(result.stdout.contains("hello"));
     }
 
+    #[test]
+    #[cfg(not(windows))]
+    fn test_named_sessions() {
+        let mut cmd = ForgeCmdBuilder::new().shell("sh").build().unwrap();
+
+        // Shell state carries over between calls
+        assert!(cmd.run_in_session("shell", "cd /").is_err());
+        cmd.grant("cd /", PermissionScope::Session);
+        cmd.run_in_session("shell", "cd /").unwrap();
+        cmd.session("shell")
+            .unwrap()
+            .read_until_idle(Duration::from_millis(300), Duration::from_secs(5));
+        cmd.run_in_session("shell", "pwd").unwrap();
+        let output = cmd
+            .session("shell")
+            .unwrap()
+            .read_until_idle(Duration::from_millis(300), Duration::from_secs(5));
+        assert!(
+            output.lines().any(|line| line.trim() == "/"),
+            "{:?}",
+            output
+        );
+
+        // A running session name cannot be reused
+        cmd.start_session("echo", "cat").unwrap();
+        assert!(matches!(
+            cmd.start_session("echo", "cat"),
+            Err(ForgeCmdError::SessionExists(_))
+        ));
+        assert!(cmd.start_session("bad", "rm -rf /").is_err());
+        assert_eq!(cmd.session_names(), vec!["echo", "shell"]);
+
+        assert!(cmd.close_session("echo"));
+        assert!(cmd.get_session("echo").is_none());
+        cmd.close();
+        assert!(cmd.session_names().is_empty());
+    }
+
     #[test]
     fn test_session_id() {
         let cmd = create_forge_cmd();
         assert!(cmd.session_id().starts_with("forgecmd-"));
     }
+
+    #[tokio::test]
+    #[cfg(not(windows))]
+    async fn test_session_recording() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ForgeCmdConfig {
+            shell: "sh".to_string(),
+            ..Default::default()
+        }
+        .record_to(dir.path());
+        let mut cmd = ForgeCmdBuilder::new().config(config).build().unwrap();
+
+        cmd.execute_interactive("echo recorded", |_| None)
+            .await
+            .unwrap();
+        cmd.start_session("echo", "cat").unwrap();
+        let session = cmd.session("echo").unwrap();
+        session.send_line("hello").unwrap();
+        assert!(session
+            .wait_for_output("hello", Duration::from_secs(5))
+            .is_some());
+        let echo_path = session.recording_path().unwrap().to_path_buf();
+        cmd.close();
+
+        let recordings = cmd.recordings().unwrap();
+        assert_eq!(recordings.len(), 2);
+        let main = recordings.iter().find(|r| r.name == "main").unwrap();
+        assert_eq!(main.session_id, cmd.session_id());
+        assert!(recording_text(&main.path)
+            .unwrap()
+            .contains("$ echo recorded\nrecorded"));
+        assert!(recording_text(&echo_path).unwrap().contains("hello"));
+    }
+
+    #[tokio::test]
+    #[cfg(not(windows))]
+    async fn test_working_dir_follows_cd() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        std::fs::create_dir(root.join("sub")).unwrap();
+        let config = ForgeCmdConfig {
+            shell: "sh".to_string(),
+            ..Default::default()
+        }
+        .allowed_paths(vec![format!("{}*", root.display())]);
+        let mut cmd = ForgeCmdBuilder::new()
+            .config(config)
+            .working_dir(root.clone())
+            .build()
+            .unwrap();
+
+        // One-shot PTY commands: the next command starts in the new directory
+        cmd.grant("cd sub", PermissionScope::Session);
+        cmd.execute_interactive("cd sub", |_| None).await.unwrap();
+        assert_eq!(cmd.working_dir(), &root.join("sub"));
+        let result = cmd.execute_interactive("pwd", |_| None).await.unwrap();
+        assert_eq!(result.stdout.trim(), root.join("sub").to_string_lossy());
+
+        // Relative paths are checked against the tracked directory
+        cmd.grant("touch ../../outside.txt", PermissionScope::Session);
+        let err = cmd
+            .execute_interactive("touch ../../outside.txt", |_| None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ForgeCmdError::PermissionDenied(_)));
+
+        // Interactive sessions report their directory at every prompt
+        cmd.grant("cd ..", PermissionScope::Session);
+        cmd.run_in_session("shell", "cd ..").unwrap();
+        cmd.session("shell")
+            .unwrap()
+            .read_until_idle(Duration::from_millis(300), Duration::from_secs(5));
+        assert_eq!(cmd.sync_working_dir("shell"), &root);
+        cmd.close();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_learn_from_approvals() {
+        let logger = Arc::new(AuditLogger::in_memory().unwrap());
+        let mut cmd = ForgeCmdBuilder::new()
+            .config(ForgeCmdConfig::default().learn("session"))
+            .audit_logger(logger.clone())
+            .build()
+            .unwrap();
+
+        for word in ["one", "two"] {
+            let command = format!("echo learned {}", word);
+            cmd.execute_with_confirmation(&command, PermissionScope::Once)
+                .await
+                .unwrap();
+        }
+        assert!(cmd.config().rules.allow.is_empty());
+
+        cmd.execute_with_confirmation("echo learned three", PermissionScope::Once)
+            .await
+            .unwrap();
+        let rules = &cmd.config().rules.allow;
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].pattern, "echo learned *");
+        assert_eq!(
+            cmd.analyze("echo learned four").matched_rule.as_deref(),
+            Some("echo learned *")
+        );
+
+        let entries = logger.recent(10).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].target.as_deref(), Some("echo learned *"));
+        assert!(cmd.history().iter().all(|r| r.approved_by.is_some()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_privileged_commands_are_audited() {
+        let logger = Arc::new(AuditLogger::in_memory().unwrap());
+        let mut cmd = ForgeCmdBuilder::new()
+            .audit_logger(logger.clone())
+            .build()
+            .unwrap();
+
+        let err = cmd.execute("sudo -n true").await.unwrap_err();
+        assert!(matches!(err, ForgeCmdError::PermissionRequired { .. }));
+        assert!(logger.recent(10).await.unwrap().is_empty());
+
+        // Runs whether or not sudo is available; both outcomes are audited
+        cmd.execute_with_confirmation("sudo -n true", PermissionScope::Session)
+            .await
+            .unwrap();
+        let entries = logger.recent(10).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| {
+            e.tags
+                .contains(&privilege::PRIVILEGED_AUDIT_TAG.to_string())
+                && e.target.as_deref() == Some("sudo -n true")
+        }));
+        assert!(entries.iter().any(|e| e.result == AuditResult::Pending));
+
+        // The session grant was not cached
+        assert!(cmd.check("sudo -n true").unwrap().needs_confirmation());
+    }
 }
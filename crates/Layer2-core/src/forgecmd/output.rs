@@ -0,0 +1,151 @@
+//! Command output normalization for forgecmd
+//!
+//! Terminal output is written for humans watching a screen, not for a model
+//! reading a transcript. Before output is returned:
+//! - ANSI escape sequences are stripped (`ForgeCmdConfig::strip_ansi`)
+//! - Carriage-return redraws keep only what was finally on screen
+//! - Runs of progress-bar lines collapse to the last one
+//!
+//! The environment is also set up so commands produce less noise in the
+//! first place: no pager (`PAGER=cat`, `GIT_PAGER=cat`) and a fixed UTF-8
+//! locale (`LANG=C.UTF-8`) so messages are untranslated and predictable.
+
+use crate::forgecmd::config::ForgeCmdConfig;
+use crate::forgecmd::shell::strip_ansi;
+use regex::Regex;
+
+lazy_static::lazy_static! {
+    /// Percentages, `[====>   ]`-style bars and block-character bars
+    static ref PROGRESS_LINE: Regex = Regex::new(
+        r"(\b\d{1,3}(\.\d+)?\s?%|\[[=#>\-.\s]{5,}\]|[█▏▎▍▌▋▊▉░▒▓]{3,})"
+    )
+    .unwrap();
+}
+
+/// Normalize command output according to `config`
+pub fn normalize_output(output: &str, config: &ForgeCmdConfig) -> String {
+    match (config.strip_ansi, config.output.collapse_progress) {
+        (true, true) => collapse_progress(&strip_ansi_keep_redraws(output)),
+        (true, false) => strip_ansi(output),
+        (false, true) => collapse_progress(output),
+        (false, false) => output.to_string(),
+    }
+}
+
+/// Strip ANSI sequences but keep the `\r` and backspaces `collapse_progress` needs
+///
+/// `strip_ansi` drops all control characters except newlines.
+fn strip_ansi_keep_redraws(output: &str) -> String {
+    let mut text = String::with_capacity(output.len());
+    for part in output.split_inclusive(['\r', '\u{8}']) {
+        match part.char_indices().last() {
+            Some((i, c)) if c == '\r' || c == '\u{8}' => {
+                text.push_str(&strip_ansi(&part[..i]));
+                text.push(c);
+            }
+            _ => text.push_str(&strip_ansi(part)),
+        }
+    }
+    text
+}
+
+/// Keep only what a terminal would finally show for redrawn lines
+///
+/// Applies `\r` (return to line start) and backspaces, then collapses runs
+/// of consecutive progress lines to the last one.
+pub fn collapse_progress(text: &str) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut previous_progress = false;
+
+    for line in text.split('\n') {
+        let line = render_line(line.strip_suffix('\r').unwrap_or(line));
+        let progress = PROGRESS_LINE.is_match(&line);
+        if progress && previous_progress {
+            if let Some(last) = out.last_mut() {
+                *last = line;
+                continue;
+            }
+        }
+        previous_progress = progress;
+        out.push(line);
+    }
+
+    out.join("\n")
+}
+
+/// Apply carriage returns and backspaces within one line
+fn render_line(line: &str) -> String {
+    if !line.contains(['\r', '\u{8}']) {
+        return line.to_string();
+    }
+
+    let mut screen: Vec<char> = Vec::new();
+    let mut cursor = 0usize;
+    for c in line.chars() {
+        match c {
+            '\r' => cursor = 0,
+            '\u{8}' => cursor = cursor.saturating_sub(1),
+            c => {
+                if cursor < screen.len() {
+                    screen[cursor] = c;
+                } else {
+                    screen.push(c);
+                }
+                cursor += 1;
+            }
+        }
+    }
+    screen
+        .into_iter()
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forgecmd::config::OutputConfig;
+
+    #[test]
+    fn test_collapse_progress() {
+        let output =
+            "Downloading\n 10% [==>       ]\r 55% [=====>    ]\r100% [==========]\nDone\r\n";
+        assert_eq!(
+            collapse_progress(output),
+            "Downloading\n100% [==========]\nDone\n"
+        );
+
+        // One line per update (no \r), as written by tools that detect a non-tty
+        let output = "Compiling\nReceiving objects:  10% (1/10)\nReceiving objects:  50% (5/10)\nReceiving objects: 100% (10/10), done.\nok";
+        assert_eq!(
+            collapse_progress(output),
+            "Compiling\nReceiving objects: 100% (10/10), done.\nok"
+        );
+
+        // Spinners drawn with backspace
+        assert_eq!(
+            collapse_progress("Working |\u{8}/\u{8}-\u{8}done"),
+            "Working done"
+        );
+        // Regular output is untouched
+        assert_eq!(collapse_progress("a\n\nb\n"), "a\n\nb\n");
+    }
+
+    #[test]
+    fn test_output_env_and_config() {
+        let env = OutputConfig::default().env();
+        assert!(env.contains(&("PAGER", "cat")));
+        assert!(env.contains(&("GIT_PAGER", "cat")));
+        assert!(env.contains(&("LANG", "C.UTF-8")));
+        assert!(OutputConfig::raw().env().is_empty());
+
+        let mut config = ForgeCmdConfig::default();
+        let output = "\x1b[32mok\x1b[0m\n  1%\r100%";
+        assert_eq!(normalize_output(output, &config), "ok\n100%");
+
+        config.strip_ansi = false;
+        config.output = OutputConfig::raw();
+        assert_eq!(normalize_output(output, &config), output);
+    }
+}
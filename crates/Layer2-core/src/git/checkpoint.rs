@@ -2,8 +2,41 @@
 //!
 //! Creates restore points before risky operations for easy rollback.
 //! Inspired by Codex's ghost commits and Aider's auto-commit features.
-
-use super::ops::{GitError, GitOps};
+//!
+//! ## Checkpoints and the user's work
+//!
+//! A checkpoint records HEAD, the staging area and a snapshot of the whole
+//! workspace (pinned under `refs/forge/checkpoint/<id>`) without stashing or
+//! otherwise touching the user's uncommitted changes. Rolling back restores
+//! exactly that state: agent edits and commits are undone, while the user's
+//! work in progress from before the checkpoint, staged or not, comes back as it
+//! was. The workspace being replaced is kept under
+//! `refs/forge/checkpoint/before-rollback` until the next rollback.
+//!
+//! ```ignore
+//! let id = manager.create("Before refactoring")?;
+//! manager.user_changes(&id)?;  // WIP that existed at the checkpoint
+//! manager.agent_changes(&id)?; // everything changed since
+//! manager.rollback(&id)?;
+//! ```
+//!
+//! ## Ghost-commit timeline
+//!
+//! After every agent turn that changed files, the workspace is recorded as a
+//! commit under a hidden ref (`refs/forge/ghost/<session>/<turn>`) whose parent
+//! holds the workspace from just before the turn. HEAD, branches, the index and
+//! the stash are never touched, so the timeline is invisible to normal git use.
+//!
+//! ```ignore
+//! let manager = CheckpointManager::new(&dir)?;
+//! manager.record_turn(session, &before_tree, &after_tree, "Add login form")?;
+//! for entry in manager.timeline(session)? {
+//!     println!("{}", entry.summary());
+//! }
+//! manager.rewind(session, 2)?; // workspace as it was before turn 2
+//! ```
+
+use super::ops::{DiffStat, GitError, GitOps};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -58,15 +91,23 @@ pub struct Checkpoint {
     /// Agent turn number (if applicable)
     pub turn: Option<u32>,
 
-    /// Files modified since last checkpoint
+    /// Files with uncommitted changes (the user's work) at creation
     pub modified_files: Vec<PathBuf>,
 
     /// Whether this was an auto-checkpoint
     pub auto_created: bool,
 
-    /// Stash reference (if uncommitted changes were stashed)
+    /// Stash reference (checkpoints from older versions that stashed changes)
     pub stash_ref: Option<String>,
 
+    /// Commit holding the whole workspace (tracked + untracked) at creation
+    #[serde(default)]
+    pub snapshot: Option<String>,
+
+    /// Staging area at creation (None while conflicts were unresolved)
+    #[serde(default)]
+    pub index_tree: Option<String>,
+
     /// Additional metadata
     pub metadata: HashMap<String, String>,
 }
@@ -83,6 +124,8 @@ impl Checkpoint {
             modified_files: Vec::new(),
             auto_created: false,
             stash_ref: None,
+            snapshot: None,
+            index_tree: None,
             metadata: HashMap::new(),
         }
     }
@@ -112,6 +155,80 @@ impl Checkpoint {
     }
 }
 
+// ============================================================================
+// Ghost-Commit Timeline
+// ============================================================================
+
+/// Hidden ref namespace for ghost commits
+pub const GHOST_REF_PREFIX: &str = "refs/forge/ghost";
+
+/// Ref (under a session) holding the workspace overwritten by the last rewind
+pub const REWIND_BACKUP_REF: &str = "rewound";
+
+/// Hidden ref namespace pinning checkpoint snapshots
+pub const CHECKPOINT_REF_PREFIX: &str = "refs/forge/checkpoint";
+
+/// Ref (under [`CHECKPOINT_REF_PREFIX`]) holding the workspace overwritten by the last rollback
+pub const ROLLBACK_BACKUP_REF: &str = "before-rollback";
+
+/// One recorded turn of a session's timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEntry {
+    /// Turn number (1-based, counts only turns that changed files)
+    pub turn: u32,
+
+    /// Ghost commit with the workspace after the turn
+    pub commit: String,
+
+    /// Ghost commit with the workspace before the turn (`commit^`)
+    pub before: String,
+
+    /// What the turn was asked to do (first line of the prompt)
+    pub description: String,
+
+    /// When the turn was recorded
+    pub created_at: DateTime<Utc>,
+
+    /// Files the turn changed
+    pub files: Vec<PathBuf>,
+}
+
+impl TimelineEntry {
+    /// One-line summary (`#2 Add login form (3 files, 14:05)`)
+    pub fn summary(&self) -> String {
+        format!(
+            "#{} {} ({} file{}, {})",
+            self.turn,
+            self.description,
+            self.files.len(),
+            if self.files.len() == 1 { "" } else { "s" },
+            self.created_at
+                .with_timezone(&chrono::Local)
+                .format("%H:%M")
+        )
+    }
+}
+
+/// Ref pinning a checkpoint's snapshot
+fn checkpoint_ref(id: &CheckpointId) -> String {
+    format!("{}/{}", CHECKPOINT_REF_PREFIX, id)
+}
+
+/// Ref prefix of a session's timeline (the id is reduced to ref-safe characters)
+fn session_ref(session: &str) -> String {
+    let session: String = session
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{}/{}", GHOST_REF_PREFIX, session)
+}
+
 // ============================================================================
 // Checkpoint Manager
 // ============================================================================
@@ -199,16 +316,18 @@ impl CheckpointManager {
             checkpoint = checkpoint.with_auto().with_turn(self.current_turn);
         }
 
-        // If there are uncommitted changes, we might want to stash or commit them
-        if status.has_changes() {
-            // For ghost commits: create a temporary commit
-            if auto {
-                // Stash uncommitted changes
-                self.git
-                    .stash(Some(&format!("checkpoint-{}", checkpoint.id)))?;
-                checkpoint.stash_ref = Some(format!("stash@{{0}}"));
-            }
-        }
+        // Snapshot the user's uncommitted work instead of stashing it, so it
+        // stays in place for the agent and comes back intact on rollback
+        let tree = self.git.snapshot_tree()?;
+        let snapshot = self.git.commit_tree(
+            &tree,
+            Some(&checkpoint.commit_hash),
+            &format!("Checkpoint: {}", description),
+        )?;
+        self.git
+            .update_ref(&checkpoint_ref(&checkpoint.id), &snapshot)?;
+        checkpoint.snapshot = Some(snapshot);
+        checkpoint.index_tree = self.git.index_tree().ok();
 
         let id = checkpoint.id.clone();
 
@@ -223,6 +342,14 @@ impl CheckpointManager {
     }
 
     /// Rollback to a specific checkpoint
+    ///
+    /// HEAD, the staging area and the working tree return to their state at
+    /// the checkpoint, including the user's uncommitted work from then. The
+    /// replaced workspace is backed up first (see [`ROLLBACK_BACKUP_REF`]).
+    ///
+    /// For checkpoints with a stash instead of a snapshot, returns
+    /// `MergeConflict` when re-applying the stashed changes conflicts; the
+    /// conflicts are left in the tree to resolve.
     pub fn rollback(&mut self, checkpoint_id: &CheckpointId) -> Result<(), GitError> {
         let checkpoint = self
             .checkpoints
@@ -238,22 +365,58 @@ impl CheckpointManager {
             checkpoint.id, checkpoint.commit_hash
         );
 
-        // Reset to the checkpoint commit
-        self.git.reset(&checkpoint.commit_hash, true)?;
-
-        // If there was a stash, pop it
-        if checkpoint.stash_ref.is_some() {
-            // Note: This might fail if the stash was already popped
-            let _ = self.git.stash_pop();
-        }
+        // Keep whatever is about to be replaced
+        let current = self.git.snapshot_tree()?;
+        let backup = self.git.commit_tree(
+            &current,
+            self.git.head().ok().as_deref(),
+            &format!("Workspace before rollback to {}", checkpoint.description),
+        )?;
+        self.git.update_ref(
+            &format!("{}/{}", CHECKPOINT_REF_PREFIX, ROLLBACK_BACKUP_REF),
+            &backup,
+        )?;
+
+        let popped = match &checkpoint.snapshot {
+            Some(snapshot) => {
+                // Move the branch back without touching files, then restore
+                // the workspace and staging area from the snapshot
+                self.git.reset(&checkpoint.commit_hash, false)?;
+                let tree = self.git.rev_parse(&format!("{}^{{tree}}", snapshot))?;
+                self.git.restore_tree(&tree)?;
+                let index = checkpoint
+                    .index_tree
+                    .as_deref()
+                    .unwrap_or(&checkpoint.commit_hash);
+                self.git.read_index_tree(index)?;
+                Ok(())
+            }
+            None => {
+                // Reset to the checkpoint commit
+                self.git.reset(&checkpoint.commit_hash, true)?;
+
+                // If there was a stash, pop it
+                match checkpoint.stash_ref {
+                    // Note: This might fail if the stash was already popped
+                    Some(_) => self.git.stash_pop(),
+                    None => Ok(()),
+                }
+            }
+        };
 
         // Remove checkpoints after this one
         let idx = self.checkpoints.iter().position(|c| &c.id == checkpoint_id);
         if let Some(idx) = idx {
-            self.checkpoints.truncate(idx + 1);
+            for removed in self.checkpoints.drain(idx + 1..) {
+                let _ = self.git.delete_ref(&checkpoint_ref(&removed.id));
+            }
         }
 
-        Ok(())
+        // The reset succeeded, but the stashed changes conflict with it
+        match popped {
+            Err(GitError::MergeConflict) => Err(GitError::MergeConflict),
+            _ => Ok(()),
+        }
     }
 
     /// Rollback to the last checkpoint
@@ -309,17 +472,43 @@ impl CheckpointManager {
         self.git.diff_commits(&checkpoint.commit_hash, "HEAD")
     }
 
+    /// The user's uncommitted work at the checkpoint (HEAD → snapshot)
+    pub fn user_changes(&self, checkpoint_id: &CheckpointId) -> Result<DiffStat, GitError> {
+        let (checkpoint, snapshot) = self.snapshot_of(checkpoint_id)?;
+        self.git.diff_stat(&checkpoint.commit_hash, snapshot)
+    }
+
+    /// Everything changed in the workspace since the checkpoint (snapshot → now)
+    pub fn agent_changes(&self, checkpoint_id: &CheckpointId) -> Result<DiffStat, GitError> {
+        let (_, snapshot) = self.snapshot_of(checkpoint_id)?;
+        let current = self.git.snapshot_tree()?;
+        self.git.diff_stat(snapshot, &current)
+    }
+
+    fn snapshot_of(&self, checkpoint_id: &CheckpointId) -> Result<(&Checkpoint, &str), GitError> {
+        let checkpoint = self.get(checkpoint_id).ok_or_else(|| {
+            GitError::CommandFailed(format!("Checkpoint not found: {}", checkpoint_id))
+        })?;
+        let snapshot = checkpoint.snapshot.as_deref().ok_or_else(|| {
+            GitError::CommandFailed(format!("Checkpoint has no snapshot: {}", checkpoint_id))
+        })?;
+        Ok((checkpoint, snapshot))
+    }
+
     /// Cleanup old checkpoints beyond max limit
     fn cleanup_old_checkpoints(&mut self) {
         while self.checkpoints.len() > self.max_checkpoints {
             let removed = self.checkpoints.remove(0);
+            let _ = self.git.delete_ref(&checkpoint_ref(&removed.id));
             debug!("Removed old checkpoint: {}", removed.id);
         }
     }
 
     /// Clear all checkpoints
     pub fn clear(&mut self) {
-        self.checkpoints.clear();
+        for checkpoint in self.checkpoints.drain(..) {
+            let _ = self.git.delete_ref(&checkpoint_ref(&checkpoint.id));
+        }
         info!("Cleared all checkpoints");
     }
 
@@ -332,6 +521,155 @@ impl CheckpointManager {
     pub fn set_turn(&mut self, turn: u32) {
         self.current_turn = turn;
     }
+
+    // ========================================================================
+    // Ghost-commit timeline
+    // ========================================================================
+
+    /// Record a turn that changed the workspace from `before_tree` to `after_tree`
+    ///
+    /// Both trees come from `GitOps::snapshot_tree`. Returns `None` when the
+    /// turn left the workspace unchanged.
+    pub fn record_turn(
+        &self,
+        session: &str,
+        before_tree: &str,
+        after_tree: &str,
+        description: &str,
+    ) -> Result<Option<TimelineEntry>, GitError> {
+        if before_tree == after_tree {
+            return Ok(None);
+        }
+
+        let timeline = self.timeline(session)?;
+        let turn = timeline.last().map_or(1, |e| e.turn + 1);
+        let description = description.lines().next().unwrap_or_default().trim();
+
+        // Chain onto the previous turn (or HEAD); the "before" commit is only
+        // needed when the workspace changed in between (e.g. the user edited files)
+        let parent = match timeline.last() {
+            Some(last) => Some(last.commit.clone()),
+            None => self.git.head().ok(),
+        };
+        let parent_tree = match &parent {
+            Some(parent) => Some(self.git.rev_parse(&format!("{}^{{tree}}", parent))?),
+            None => None,
+        };
+        let before = match parent {
+            Some(parent) if parent_tree.as_deref() == Some(before_tree) => parent,
+            parent => self.git.commit_tree(
+                before_tree,
+                parent.as_deref(),
+                &format!("Before turn {}", turn),
+            )?,
+        };
+
+        let message = format!(
+            "{}\n\nForge session {}, turn {}",
+            description, session, turn
+        );
+        let commit = self.git.commit_tree(after_tree, Some(&before), &message)?;
+        self.git
+            .update_ref(&format!("{}/{}", session_ref(session), turn), &commit)?;
+
+        let files = self
+            .git
+            .diff_stat(&before, &commit)?
+            .files
+            .into_iter()
+            .map(|f| PathBuf::from(f.path))
+            .collect();
+        info!("Recorded ghost commit for turn {}: {}", turn, commit);
+
+        Ok(Some(TimelineEntry {
+            turn,
+            commit,
+            before,
+            description: description.to_string(),
+            created_at: Utc::now(),
+            files,
+        }))
+    }
+
+    /// Recorded turns of a session, oldest first
+    pub fn timeline(&self, session: &str) -> Result<Vec<TimelineEntry>, GitError> {
+        let prefix = session_ref(session);
+        let lines = self.git.for_each_ref(
+            &prefix,
+            "%(refname)%00%(objectname)%00%(parent)%00%(committerdate:iso-strict)%00%(subject)",
+        )?;
+
+        let mut entries = Vec::new();
+        for line in lines {
+            let fields: Vec<&str> = line.split('\0').collect();
+            let [name, commit, before, date, subject] = fields[..] else {
+                continue;
+            };
+            // Skips the rewind backup and anything else that is not a turn
+            let Some(turn) = name
+                .strip_prefix(prefix.as_str())
+                .and_then(|rest| rest.strip_prefix('/'))
+                .and_then(|t| t.parse().ok())
+            else {
+                continue;
+            };
+            let files = self
+                .git
+                .diff_stat(before, commit)?
+                .files
+                .into_iter()
+                .map(|f| PathBuf::from(f.path))
+                .collect();
+            entries.push(TimelineEntry {
+                turn,
+                commit: commit.to_string(),
+                before: before.to_string(),
+                description: subject.to_string(),
+                created_at: DateTime::parse_from_rfc3339(date)
+                    .map(|d| d.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+                files,
+            });
+        }
+
+        entries.sort_by_key(|e| e.turn);
+        Ok(entries)
+    }
+
+    /// Restore the workspace to how it was just before `turn`
+    ///
+    /// `turn` and every later turn are dropped from the timeline, so the next
+    /// recorded turn reuses the number. The overwritten workspace is kept under
+    /// `refs/forge/ghost/<session>/rewound` until the next rewind.
+    pub fn rewind(&self, session: &str, turn: u32) -> Result<TimelineEntry, GitError> {
+        let timeline = self.timeline(session)?;
+        let target = timeline
+            .iter()
+            .find(|e| e.turn == turn)
+            .ok_or_else(|| GitError::CommandFailed(format!("No turn {} in the timeline", turn)))?
+            .clone();
+
+        let prefix = session_ref(session);
+        let current = self.git.snapshot_tree()?;
+        let latest = timeline.last().map(|e| e.commit.as_str());
+        let backup = self.git.commit_tree(
+            &current,
+            latest,
+            &format!("Workspace before rewind to turn {}", turn),
+        )?;
+        self.git
+            .update_ref(&format!("{}/{}", prefix, REWIND_BACKUP_REF), &backup)?;
+
+        let tree = self.git.rev_parse(&format!("{}^{{tree}}", target.before))?;
+        self.git.restore_tree(&tree)?;
+
+        for entry in timeline.iter().filter(|e| e.turn >= turn) {
+            self.git.delete_ref(&format!("{}/{}", prefix, entry.turn))?;
+        }
+        info!("Rewound session {} to before turn {}", session, turn);
+
+        Ok(target)
+    }
 }
 
 // ============================================================================
@@ -374,4 +712,153 @@ mod tests {
 
         assert_eq!(checkpoint.metadata.get("key"), Some(&"value".to_string()));
     }
+
+    #[test]
+    fn test_ghost_timeline_and_rewind() {
+        let dir = tempfile::tempdir().unwrap();
+        if !std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(dir.path())
+            .status()
+            .is_ok_and(|s| s.success())
+        {
+            return;
+        }
+        let write = |p: &str, content: &str| std::fs::write(dir.path().join(p), content).unwrap();
+        let read = |p: &str| std::fs::read_to_string(dir.path().join(p)).ok();
+        let manager = CheckpointManager::new(dir.path()).unwrap();
+        let git = GitOps::new(dir.path()).unwrap();
+        let session = "session/1";
+
+        write("a.txt", "1\n");
+        let s0 = git.snapshot_tree().unwrap();
+        write("a.txt", "2\n");
+        let s1 = git.snapshot_tree().unwrap();
+        let first = manager
+            .record_turn(session, &s0, &s1, "Change a\nwith details")
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.turn, 1);
+        assert_eq!(first.description, "Change a");
+        assert!(manager
+            .record_turn(session, &s1, &s1, "noop")
+            .unwrap()
+            .is_none());
+
+        // 턴 사이의 사용자 편집은 다음 턴의 "before"에 남음
+        write("b.txt", "user\n");
+        let s2 = git.snapshot_tree().unwrap();
+        write("a.txt", "3\n");
+        let s3 = git.snapshot_tree().unwrap();
+        manager
+            .record_turn(session, &s2, &s3, "Change a again")
+            .unwrap();
+
+        let timeline = manager.timeline(session).unwrap();
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[1].turn, 2);
+        assert_eq!(timeline[1].files, vec![PathBuf::from("a.txt")]);
+        assert_ne!(timeline[1].before, timeline[0].commit);
+        assert!(timeline[0].summary().starts_with("#1 Change a (1 file, "));
+        assert!(manager.timeline("other").unwrap().is_empty());
+        assert!(git.head().is_err());
+
+        manager.rewind(session, 2).unwrap();
+        assert_eq!(read("a.txt").as_deref(), Some("2\n"));
+        assert_eq!(read("b.txt").as_deref(), Some("user\n"));
+        assert_eq!(manager.timeline(session).unwrap().len(), 1);
+
+        manager.rewind(session, 1).unwrap();
+        assert_eq!(read("a.txt").as_deref(), Some("1\n"));
+        assert!(read("b.txt").is_none());
+        assert!(manager.timeline(session).unwrap().is_empty());
+        assert!(manager.rewind(session, 1).is_err());
+
+        // 덮어쓴 작업 트리는 백업 ref에 남음
+        let backup = git
+            .rev_parse(&format!(
+                "{}/session-1/{}^{{tree}}",
+                GHOST_REF_PREFIX, REWIND_BACKUP_REF
+            ))
+            .unwrap();
+        assert_eq!(backup, s2);
+    }
+
+    #[test]
+    fn test_rollback_keeps_user_work() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args([
+                    "-c",
+                    "user.name=Alice",
+                    "-c",
+                    "user.email=alice@example.com",
+                ])
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .ok()
+                .filter(|o| o.status.success())
+                .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        };
+        if git(&["init", "-q"]).is_none() {
+            return;
+        }
+        let write = |p: &str, content: &str| std::fs::write(dir.path().join(p), content).unwrap();
+        let read = |p: &str| std::fs::read_to_string(dir.path().join(p)).ok();
+        write("a.txt", "1\n");
+        write("b.txt", "1\n");
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+
+        // 사용자 WIP: 스테이징된 수정, 스테이징 안 된 수정, 추적 안 되는 파일
+        write("a.txt", "user staged\n");
+        git(&["add", "a.txt"]);
+        write("b.txt", "user unstaged\n");
+        write("notes.txt", "user untracked\n");
+
+        let mut manager = CheckpointManager::new(dir.path()).unwrap();
+        let id = manager.create("Before agent").unwrap();
+        let checkpoint = manager.get(&id).unwrap();
+        assert!(checkpoint.stash_ref.is_none());
+        assert_eq!(checkpoint.modified_files.len(), 3);
+        // 체크포인트는 작업 트리를 건드리지 않음
+        assert_eq!(read("b.txt").as_deref(), Some("user unstaged\n"));
+        assert_eq!(manager.user_changes(&id).unwrap().files.len(), 3);
+        assert!(manager.agent_changes(&id).unwrap().is_empty());
+
+        // 에이전트: 파일 수정/추가 후 커밋까지
+        write("b.txt", "agent\n");
+        write("new.txt", "agent\n");
+        git(&["add", "-A"]);
+        git(&["commit", "-q", "-m", "agent"]);
+        let agent = manager.agent_changes(&id).unwrap();
+        let paths: Vec<&str> = agent.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["b.txt", "new.txt"]);
+
+        manager.rollback(&id).unwrap();
+        assert_eq!(read("a.txt").as_deref(), Some("user staged\n"));
+        assert_eq!(read("b.txt").as_deref(), Some("user unstaged\n"));
+        assert_eq!(read("notes.txt").as_deref(), Some("user untracked\n"));
+        assert!(read("new.txt").is_none());
+        assert_eq!(git(&["log", "--format=%s"]).unwrap(), "init\n");
+        assert_eq!(
+            git(&["status", "--porcelain"]).unwrap(),
+            "M  a.txt\n M b.txt\n?? notes.txt\n"
+        );
+
+        // 롤백 직전 작업 트리는 백업 ref에 남음
+        let backup = GitOps::new(dir.path())
+            .unwrap()
+            .show_file(
+                &format!("{}/{}", CHECKPOINT_REF_PREFIX, ROLLBACK_BACKUP_REF),
+                "new.txt",
+            )
+            .unwrap();
+        assert_eq!(backup, "agent");
+
+        manager.clear();
+        assert!(git(&["rev-parse", "--verify", "-q", &checkpoint_ref(&id)]).is_none());
+    }
 }
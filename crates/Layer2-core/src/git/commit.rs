@@ -2,9 +2,14 @@
 //!
 //! Provides automatic commit functionality and LLM-based commit message generation.
 //! Inspired by Aider's auto-commit feature.
+//!
+//! AI commits can carry their own author/committer identity, `Co-Authored-By`
+//! trailers and a GPG or SSH signature, so they are easy to tell apart from
+//! human commits and pass signed-commit policies.
 
 use super::ops::{GitError, GitOps};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::path::Path;
 use tracing::{debug, info};
 
@@ -29,6 +34,77 @@ pub enum CommitStyle {
     Custom,
 }
 
+/// Author or committer identity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitIdentity {
+    pub name: String,
+    pub email: String,
+}
+
+impl CommitIdentity {
+    pub fn new(name: impl Into<String>, email: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            email: email.into(),
+        }
+    }
+
+    /// Parse `Name <email>`
+    pub fn parse(s: &str) -> Option<Self> {
+        let (name, rest) = s.trim().split_once('<')?;
+        let email = rest.strip_suffix('>')?.trim();
+        let name = name.trim();
+        if name.is_empty() || email.is_empty() {
+            return None;
+        }
+        Some(Self::new(name, email))
+    }
+}
+
+impl fmt::Display for CommitIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)
+    }
+}
+
+/// Commit signature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "format", rename_all = "lowercase")]
+pub enum CommitSigning {
+    /// OpenPGP; key id (default: `user.signingkey` or the committer email)
+    Gpg { key: Option<String> },
+    /// SSH; public key file or literal key (default: `user.signingkey`)
+    Ssh { key: Option<String> },
+}
+
+impl CommitSigning {
+    /// `-c` overrides selecting the signature format and key
+    pub fn config_args(&self) -> Vec<String> {
+        let (format, key) = match self {
+            Self::Gpg { key } => ("openpgp", key),
+            Self::Ssh { key } => ("ssh", key),
+        };
+        let mut args = vec!["-c".to_string(), format!("gpg.format={}", format)];
+        if let Some(key) = key {
+            args.push("-c".to_string());
+            args.push(format!("user.signingkey={}", key));
+        }
+        args
+    }
+}
+
+/// Identity and signature for [`GitOps::commit_with`]
+///
+/// Unset identities fall back to the git-configured user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitOptions {
+    pub author: Option<CommitIdentity>,
+    pub committer: Option<CommitIdentity>,
+    pub signing: Option<CommitSigning>,
+    /// Skip git's own hooks (`--no-verify`)
+    pub no_verify: bool,
+}
+
 /// Auto-commit configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutoCommitConfig {
@@ -55,6 +131,45 @@ pub struct AutoCommitConfig {
 
     /// Maximum commit message length
     pub max_message_length: usize,
+
+    /// Author of AI commits (default: the git-configured user)
+    #[serde(default)]
+    pub author: Option<CommitIdentity>,
+
+    /// Committer of AI commits (default: the git-configured user)
+    #[serde(default)]
+    pub committer: Option<CommitIdentity>,
+
+    /// Sign auto-commits (including the dirty-files commit)
+    #[serde(default)]
+    pub signing: Option<CommitSigning>,
+
+    /// `Co-Authored-By` trailers added to AI commit messages
+    #[serde(default)]
+    pub co_authors: Vec<CommitIdentity>,
+
+    /// Run the repository's pre-commit hook before auto-commits
+    ///
+    /// The hook runs through [`GitOps::run_pre_commit`] so failures come back
+    /// as a structured report; the commit itself then skips git's hooks.
+    #[serde(default = "default_run_hooks")]
+    pub run_hooks: bool,
+
+    /// Lint commands that must pass before auto-commits (run with `sh -c`)
+    #[serde(default)]
+    pub pre_commit_checks: Vec<String>,
+
+    /// How often the agent may fix failed checks and retry the commit
+    #[serde(default = "default_max_fix_attempts")]
+    pub max_fix_attempts: u32,
+}
+
+fn default_run_hooks() -> bool {
+    true
+}
+
+fn default_max_fix_attempts() -> u32 {
+    2
 }
 
 impl Default for AutoCommitConfig {
@@ -68,6 +183,13 @@ impl Default for AutoCommitConfig {
             commit_dirty_first: true,
             dirty_commit_message: "WIP: uncommitted changes before AI edit".to_string(),
             max_message_length: 72,
+            author: None,
+            committer: None,
+            signing: None,
+            co_authors: Vec::new(),
+            run_hooks: default_run_hooks(),
+            pre_commit_checks: Vec::new(),
+            max_fix_attempts: default_max_fix_attempts(),
         }
     }
 }
@@ -91,6 +213,56 @@ impl AutoCommitConfig {
             ..Default::default()
         }
     }
+
+    /// Author AI commits as `identity` (e.g. `ForgeCode Agent <bot@example.com>`)
+    pub fn with_author(mut self, identity: CommitIdentity) -> Self {
+        self.author = Some(identity);
+        self
+    }
+
+    /// Commit AI commits as `identity`
+    pub fn with_committer(mut self, identity: CommitIdentity) -> Self {
+        self.committer = Some(identity);
+        self
+    }
+
+    /// Sign auto-commits
+    pub fn with_signing(mut self, signing: CommitSigning) -> Self {
+        self.signing = Some(signing);
+        self
+    }
+
+    /// Add a `Co-Authored-By` trailer to AI commit messages
+    pub fn with_co_author(mut self, identity: CommitIdentity) -> Self {
+        if !self.co_authors.contains(&identity) {
+            self.co_authors.push(identity);
+        }
+        self
+    }
+
+    /// Require a lint command to pass before auto-commits
+    pub fn with_pre_commit_check(mut self, command: impl Into<String>) -> Self {
+        self.pre_commit_checks.push(command.into());
+        self
+    }
+
+    /// Options for AI commits (checks already ran, so git's hooks are skipped)
+    pub fn commit_options(&self) -> CommitOptions {
+        CommitOptions {
+            author: self.author.clone(),
+            committer: self.committer.clone(),
+            signing: self.signing.clone(),
+            no_verify: true,
+        }
+    }
+
+    /// Options for the dirty-files commit (the user's own work: signed, not re-authored)
+    fn dirty_commit_options(&self) -> CommitOptions {
+        CommitOptions {
+            signing: self.signing.clone(),
+            ..Default::default()
+        }
+    }
 }
 
 // ============================================================================
@@ -157,6 +329,18 @@ impl CommitGenerator {
             message.push_str("...");
         }
 
+        // Trailers go after truncation so they are never cut off
+        if !self.config.co_authors.is_empty() {
+            message.push_str("\n\n");
+            let trailers: Vec<String> = self
+                .config
+                .co_authors
+                .iter()
+                .map(|identity| format!("Co-Authored-By: {}", identity))
+                .collect();
+            message.push_str(&trailers.join("\n"));
+        }
+
         message
     }
 
@@ -269,6 +453,9 @@ impl CommitGenerator {
     }
 
     /// Auto-commit changes with generated message
+    ///
+    /// Returns `PreCommitFailed` (with nothing committed and the staging area
+    /// as it was) when the pre-commit hook or a configured check fails.
     pub fn auto_commit(&self, context: Option<&str>) -> Result<Option<String>, GitError> {
         if !self.config.enabled {
             return Ok(None);
@@ -296,8 +483,22 @@ impl CommitGenerator {
             self.git.add_all()?;
         }
 
+        // Hooks and lint checks see the staged state
+        let report = self
+            .git
+            .run_pre_commit(self.config.run_hooks, &self.config.pre_commit_checks)?;
+        if !report.passed() {
+            if !status.has_staged {
+                self.git.unstage_all()?;
+            }
+            info!("Auto-commit blocked: {}", report);
+            return Err(GitError::PreCommitFailed(report));
+        }
+
         // Commit
-        let hash = self.git.commit(&message)?;
+        let hash = self
+            .git
+            .commit_with(&message, &self.config.commit_options())?;
 
         info!(
             "Auto-committed: {} - {}",
@@ -321,7 +522,10 @@ impl CommitGenerator {
         }
 
         self.git.add_all()?;
-        let hash = self.git.commit(&self.config.dirty_commit_message)?;
+        let hash = self.git.commit_with(
+            &self.config.dirty_commit_message,
+            &self.config.dirty_commit_options(),
+        )?;
 
         info!("Committed dirty files: {}", hash);
 
@@ -444,6 +648,161 @@ mod tests {
         assert!(summary.contains("4 files"));
     }
 
+    #[test]
+    fn test_commit_identity_and_signing() {
+        let bot = CommitIdentity::parse("ForgeCode Agent <bot@example.com>").unwrap();
+        assert_eq!(
+            bot,
+            CommitIdentity::new("ForgeCode Agent", "bot@example.com")
+        );
+        assert_eq!(bot.to_string(), "ForgeCode Agent <bot@example.com>");
+        assert!(CommitIdentity::parse("no email").is_none());
+        assert!(CommitIdentity::parse("<bot@example.com>").is_none());
+
+        assert_eq!(
+            CommitSigning::Ssh {
+                key: Some("~/.ssh/id_ed25519.pub".into())
+            }
+            .config_args(),
+            vec![
+                "-c",
+                "gpg.format=ssh",
+                "-c",
+                "user.signingkey=~/.ssh/id_ed25519.pub"
+            ]
+        );
+        assert_eq!(
+            CommitSigning::Gpg { key: None }.config_args(),
+            vec!["-c", "gpg.format=openpgp"]
+        );
+
+        let config: AutoCommitConfig = serde_json::from_value(serde_json::json!({
+            "enabled": true,
+            "style": "Simple",
+            "custom_prefix": null,
+            "include_attribution": false,
+            "attribution": "",
+            "commit_dirty_first": false,
+            "dirty_commit_message": "",
+            "max_message_length": 72,
+            "signing": { "format": "gpg", "key": "ABCD1234" }
+        }))
+        .unwrap();
+        assert_eq!(
+            config.signing,
+            Some(CommitSigning::Gpg {
+                key: Some("ABCD1234".into())
+            })
+        );
+        assert!(config.author.is_none() && config.co_authors.is_empty());
+    }
+
+    #[test]
+    fn test_co_author_trailers() {
+        let Some(mut generator) = create_test_generator() else {
+            return;
+        };
+        let alice = CommitIdentity::new("Alice", "alice@example.com");
+        generator.config = AutoCommitConfig::default()
+            .with_co_author(alice.clone())
+            .with_co_author(alice);
+        let diff = "diff --git a/src/lib.rs b/src/lib.rs\n+new line";
+        let message = generator.generate_message(diff, Some(&"x".repeat(100)));
+        assert!(message.ends_with("...\n\nCo-Authored-By: Alice <alice@example.com>"));
+        assert_eq!(message.matches("Co-Authored-By").count(), 1);
+    }
+
+    #[test]
+    fn test_auto_commit_identity() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args([
+                    "-c",
+                    "user.name=Alice",
+                    "-c",
+                    "user.email=alice@example.com",
+                ])
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .ok()
+                .filter(|o| o.status.success())
+                .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        };
+        if git(&["init", "-q"]).is_none() {
+            return;
+        }
+        std::fs::write(dir.path().join("a.txt"), "one\n").unwrap();
+        git(&["add", "a.txt"]);
+
+        let config = AutoCommitConfig::default()
+            .with_author(CommitIdentity::new("ForgeCode Agent", "bot@example.com"))
+            .with_committer(CommitIdentity::new("Alice", "alice@example.com"));
+        let generator = CommitGenerator::new(dir.path(), config).unwrap();
+        assert!(generator.auto_commit(None).unwrap().is_some());
+        assert_eq!(
+            git(&["log", "-1", "--format=%an <%ae>|%cn <%ce>"]).unwrap(),
+            "ForgeCode Agent <bot@example.com>|Alice <alice@example.com>"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_auto_commit_pre_commit_checks() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(["-c", "user.name=Forge", "-c", "user.email=forge@example.com"])
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .ok()
+                .filter(|o| o.status.success())
+                .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        };
+        if git(&["init", "-q"]).is_none() {
+            return;
+        }
+        std::fs::write(dir.path().join("a.txt"), "start\n").unwrap();
+        git(&["add", "a.txt"]);
+        git(&["commit", "-q", "-m", "init"]);
+        let hook = dir.path().join(".git/hooks/pre-commit");
+        std::fs::create_dir_all(hook.parent().unwrap()).unwrap();
+        std::fs::write(
+            &hook,
+            "#!/bin/sh\nif grep -q TODO a.txt; then echo 'a.txt: TODO left'; exit 1; fi\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+        std::fs::write(dir.path().join("a.txt"), "TODO\n").unwrap();
+
+        let config = AutoCommitConfig::default()
+            .with_author(CommitIdentity::new("Forge", "forge@example.com"))
+            .with_committer(CommitIdentity::new("Forge", "forge@example.com"))
+            .with_pre_commit_check("test -f a.txt");
+        let generator = CommitGenerator::new(dir.path(), config).unwrap();
+
+        let Err(GitError::PreCommitFailed(report)) = generator.auto_commit(None) else {
+            panic!("expected the pre-commit hook to block the commit");
+        };
+        assert_eq!(report.checks.len(), 2);
+        assert_eq!(
+            report.to_string(),
+            "1 of 2 pre-commit checks failed: pre-commit hook"
+        );
+        assert!(report.render().contains("a.txt: TODO left"));
+        // 커밋도, 스테이징 변경도 남지 않음
+        assert_eq!(git(&["rev-list", "--count", "HEAD"]).unwrap().trim(), "1");
+        assert!(git(&["diff", "--cached", "--name-only"]).unwrap().is_empty());
+
+        std::fs::write(dir.path().join("a.txt"), "done\n").unwrap();
+        assert!(generator.auto_commit(None).unwrap().is_some());
+        assert_eq!(git(&["rev-list", "--count", "HEAD"]).unwrap().trim(), "2");
+    }
+
     fn create_test_generator() -> Option<CommitGenerator> {
         // Create a mock generator for testing
         // Skip test if not in a git repository
@@ -0,0 +1,346 @@
+//! Merge Conflicts
+//!
+//! Structured view of conflict markers left by a merge, rebase, cherry-pick,
+//! revert or stash pop: per file, each hunk's `ours`, `base` (diff3 style
+//! only) and `theirs` sides, plus hunk-by-hunk resolution.
+//!
+//! ```ignore
+//! for file in git.conflicts()? {
+//!     let content = std::fs::read_to_string(git.root().join(&file.path))?;
+//!     let resolved = resolve_hunk(&content, 0, &ConflictResolution::Theirs)?;
+//!     // ... write it back, then `git.mark_resolved(&file.path)` once no hunks remain
+//! }
+//! ```
+
+use super::ops::GitError;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const OURS_MARKER: &str = "<<<<<<<";
+const BASE_MARKER: &str = "|||||||";
+const SEPARATOR: &str = "=======";
+const THEIRS_MARKER: &str = ">>>>>>>";
+
+/// Operation that stopped with conflicts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictOperation {
+    Merge,
+    Rebase,
+    CherryPick,
+    Revert,
+}
+
+impl ConflictOperation {
+    /// `git <command> --continue` / `--abort`
+    pub fn command(self) -> &'static str {
+        match self {
+            Self::Merge => "merge",
+            Self::Rebase => "rebase",
+            Self::CherryPick => "cherry-pick",
+            Self::Revert => "revert",
+        }
+    }
+}
+
+/// One conflict region in a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConflictHunk {
+    /// First line (the `<<<<<<<` marker, 1-based)
+    pub start_line: usize,
+    /// Last line (the `>>>>>>>` marker, 1-based)
+    pub end_line: usize,
+    /// Label after `<<<<<<<` (e.g. `HEAD`)
+    pub ours_label: String,
+    /// Label after `>>>>>>>` (e.g. the merged branch)
+    pub theirs_label: String,
+    pub ours: String,
+    /// Common ancestor (only with `merge.conflictStyle=diff3`/`zdiff3`)
+    pub base: Option<String>,
+    pub theirs: String,
+}
+
+/// A conflicted file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConflictFile {
+    /// Path relative to the repository root
+    pub path: PathBuf,
+    /// Empty for conflicts without text markers (binary, modify/delete)
+    pub hunks: Vec<ConflictHunk>,
+}
+
+impl ConflictFile {
+    /// Readable listing of every hunk (numbered from 1)
+    pub fn render(&self) -> String {
+        let path = self.path.display();
+        if self.hunks.is_empty() {
+            return format!(
+                "### {}\nNo conflict markers (binary file or modify/delete conflict); \
+                 keep one version with git checkout --ours/--theirs or delete it, then mark it resolved.\n",
+                path
+            );
+        }
+
+        let mut out = String::new();
+        for (i, hunk) in self.hunks.iter().enumerate() {
+            out.push_str(&format!(
+                "### {} — hunk {} of {} (lines {}-{})\n",
+                path,
+                i + 1,
+                self.hunks.len(),
+                hunk.start_line,
+                hunk.end_line
+            ));
+            push_side(&mut out, &format!("ours ({})", hunk.ours_label), &hunk.ours);
+            if let Some(base) = &hunk.base {
+                push_side(&mut out, "base", base);
+            }
+            push_side(
+                &mut out,
+                &format!("theirs ({})", hunk.theirs_label),
+                &hunk.theirs,
+            );
+        }
+        out
+    }
+}
+
+fn push_side(out: &mut String, title: &str, text: &str) {
+    out.push_str(title);
+    out.push_str(":\n```\n");
+    out.push_str(text);
+    if !text.is_empty() && !text.ends_with('\n') {
+        out.push('\n');
+    }
+    out.push_str("```\n");
+}
+
+/// How to resolve a hunk
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictResolution {
+    Ours,
+    Theirs,
+    /// Ours followed by theirs
+    Both,
+    /// The common ancestor (diff3 markers only)
+    Base,
+    /// Hand-merged text
+    Custom(String),
+}
+
+impl ConflictResolution {
+    /// Parse a resolution name (`custom` takes its text from `content`)
+    pub fn parse(name: &str, content: Option<&str>) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "ours" => Some(Self::Ours),
+            "theirs" => Some(Self::Theirs),
+            "both" => Some(Self::Both),
+            "base" => Some(Self::Base),
+            "custom" => content.map(|c| Self::Custom(c.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Short description (`ours`, `custom (3 lines)`)
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Ours => "ours".to_string(),
+            Self::Theirs => "theirs".to_string(),
+            Self::Both => "both".to_string(),
+            Self::Base => "base".to_string(),
+            Self::Custom(text) => format!("custom ({} lines)", text.lines().count()),
+        }
+    }
+}
+
+/// Which side of a hunk is being read
+enum Section {
+    Ours,
+    Base,
+    Theirs,
+}
+
+/// Marker line (`<<<<<<< HEAD`) → label, if `line` is that marker
+fn marker<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let rest = line.strip_prefix(marker)?;
+    if rest.is_empty() {
+        Some("")
+    } else {
+        rest.strip_prefix(' ')
+    }
+}
+
+/// Parse conflict hunks (unterminated regions are ignored)
+pub fn parse_conflicts(content: &str) -> Vec<ConflictHunk> {
+    let mut hunks = Vec::new();
+    let mut current: Option<(ConflictHunk, Section)> = None;
+
+    for (i, line) in content.split_inclusive('\n').enumerate() {
+        let line_no = i + 1;
+        let Some((hunk, section)) = current.as_mut() else {
+            if let Some(label) = marker(line, OURS_MARKER) {
+                current = Some((
+                    ConflictHunk {
+                        start_line: line_no,
+                        end_line: line_no,
+                        ours_label: label.to_string(),
+                        theirs_label: String::new(),
+                        ours: String::new(),
+                        base: None,
+                        theirs: String::new(),
+                    },
+                    Section::Ours,
+                ));
+            }
+            continue;
+        };
+
+        let mut closed = false;
+        match section {
+            Section::Ours | Section::Base if marker(line, SEPARATOR) == Some("") => {
+                *section = Section::Theirs;
+            }
+            Section::Ours if marker(line, BASE_MARKER).is_some() => {
+                hunk.base = Some(String::new());
+                *section = Section::Base;
+            }
+            Section::Ours => hunk.ours.push_str(line),
+            Section::Base => hunk.base.get_or_insert_with(String::new).push_str(line),
+            Section::Theirs => match marker(line, THEIRS_MARKER) {
+                Some(label) => {
+                    hunk.end_line = line_no;
+                    hunk.theirs_label = label.to_string();
+                    closed = true;
+                }
+                None => hunk.theirs.push_str(line),
+            },
+        }
+        if closed {
+            hunks.extend(current.take().map(|(hunk, _)| hunk));
+        }
+    }
+
+    hunks
+}
+
+/// Replace hunk `index` (0-based) of `content` with its resolution
+///
+/// Later hunks keep their content but move up in numbering once resolved.
+pub fn resolve_hunk(
+    content: &str,
+    index: usize,
+    resolution: &ConflictResolution,
+) -> Result<String, GitError> {
+    let hunks = parse_conflicts(content);
+    let hunk = hunks.get(index).ok_or_else(|| {
+        GitError::CommandFailed(format!(
+            "No conflict hunk {} (file has {})",
+            index + 1,
+            hunks.len()
+        ))
+    })?;
+
+    let mut replacement = match resolution {
+        ConflictResolution::Ours => hunk.ours.clone(),
+        ConflictResolution::Theirs => hunk.theirs.clone(),
+        ConflictResolution::Both => format!("{}{}", hunk.ours, hunk.theirs),
+        ConflictResolution::Base => hunk.base.clone().ok_or_else(|| {
+            GitError::CommandFailed(
+                "Hunk has no base section (enable merge.conflictStyle=diff3)".to_string(),
+            )
+        })?,
+        ConflictResolution::Custom(text) => text.clone(),
+    };
+    if !replacement.is_empty() && !replacement.ends_with('\n') {
+        replacement.push('\n');
+    }
+
+    let mut out = String::with_capacity(content.len());
+    for (i, line) in content.split_inclusive('\n').enumerate() {
+        let line_no = i + 1;
+        if line_no == hunk.start_line {
+            out.push_str(&replacement);
+        } else if line_no < hunk.start_line || line_no > hunk.end_line {
+            out.push_str(line);
+        }
+    }
+    Ok(out)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MERGED: &str = "fn main() {\n\
+<<<<<<< HEAD\n    println!(\"ours\");\n\
+=======\n    println!(\"theirs\");\n\
+>>>>>>> feature\n}\n\
+<<<<<<< HEAD\nlet a = 1;\n\
+||||||| base\nlet a = 0;\n\
+=======\nlet a = 2;\n\
+>>>>>>> feature\n";
+
+    #[test]
+    fn test_parse_conflicts() {
+        let hunks = parse_conflicts(MERGED);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!((hunks[0].start_line, hunks[0].end_line), (2, 6));
+        assert_eq!(hunks[0].ours_label, "HEAD");
+        assert_eq!(hunks[0].theirs_label, "feature");
+        assert_eq!(hunks[0].ours, "    println!(\"ours\");\n");
+        assert_eq!(hunks[0].theirs, "    println!(\"theirs\");\n");
+        assert!(hunks[0].base.is_none());
+        assert_eq!(hunks[1].base.as_deref(), Some("let a = 0;\n"));
+
+        // 닫히지 않은 영역, 비슷하지만 다른 줄
+        assert!(parse_conflicts("<<<<<<< HEAD\nonly ours\n").is_empty());
+        assert!(parse_conflicts("<<<<<<<<< not a marker\n").is_empty());
+    }
+
+    #[test]
+    fn test_resolve_hunks() {
+        let first = resolve_hunk(MERGED, 0, &ConflictResolution::Theirs).unwrap();
+        assert!(first.starts_with("fn main() {\n    println!(\"theirs\");\n}\n<<<<<<< HEAD"));
+        assert_eq!(parse_conflicts(&first).len(), 1);
+
+        let done = resolve_hunk(&first, 0, &ConflictResolution::Base).unwrap();
+        assert_eq!(
+            done,
+            "fn main() {\n    println!(\"theirs\");\n}\nlet a = 0;\n"
+        );
+
+        let both = resolve_hunk(MERGED, 1, &ConflictResolution::Both).unwrap();
+        assert!(both.ends_with("}\nlet a = 1;\nlet a = 2;\n"));
+
+        let custom =
+            resolve_hunk(MERGED, 1, &ConflictResolution::Custom("let a = 3;".into())).unwrap();
+        assert!(custom.ends_with("}\nlet a = 3;\n"));
+
+        assert!(resolve_hunk(MERGED, 0, &ConflictResolution::Base).is_err());
+        assert!(resolve_hunk(MERGED, 2, &ConflictResolution::Ours).is_err());
+    }
+
+    #[test]
+    fn test_crlf_and_render() {
+        let crlf = MERGED.replace('\n', "\r\n");
+        let hunks = parse_conflicts(&crlf);
+        assert_eq!(hunks.len(), 2);
+        let resolved = resolve_hunk(&crlf, 0, &ConflictResolution::Ours).unwrap();
+        assert!(resolved.starts_with("fn main() {\r\n    println!(\"ours\");\r\n}\r\n"));
+
+        let file = ConflictFile {
+            path: PathBuf::from("src/main.rs"),
+            hunks: parse_conflicts(MERGED),
+        };
+        let rendered = file.render();
+        assert!(rendered.contains("### src/main.rs — hunk 1 of 2 (lines 2-6)"));
+        assert!(rendered.contains("ours (HEAD):\n```\n    println!(\"ours\");\n```"));
+        assert!(rendered.contains("base:\n```\nlet a = 0;\n```"));
+    }
+}
@@ -0,0 +1,663 @@
+//! Code Host Integration
+//!
+//! Pull request (GitHub) / merge request (GitLab) workflow on top of the
+//! `gh` and `glab` CLIs, which already handle authentication, enterprise
+//! hosts and SSO. The host is detected from the `origin` remote URL.
+//!
+//! ```ignore
+//! let host = CodeHost::detect(&repo_dir)?;
+//! let pr = host.create_pr(&NewPullRequest::new("Fix login redirect").with_body("..."))?;
+//! let diff = host.get_pr_diff(pr.number)?;
+//! host.post_review_comment(pr.number, &ReviewComment::inline("src/auth.rs", 42, "Nit: ..."))?;
+//! ```
+//!
+//! GitLab has no single-call inline comment in `glab`, so inline comments are
+//! posted as regular notes prefixed with `path:line`.
+
+use super::ops::{GitError, GitOps};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::info;
+
+/// Fields requested from `gh` for pull requests
+const GITHUB_PR_FIELDS: &str = "number,title,url,state,headRefName,baseRefName,author,isDraft";
+
+/// Code hosting service of a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HostKind {
+    GitHub,
+    GitLab,
+}
+
+impl HostKind {
+    /// Detect from a remote URL (`https://github.com/o/r.git`, `git@gitlab.example.com:o/r.git`)
+    pub fn from_remote_url(url: &str) -> Option<Self> {
+        let host = remote_host(url)?.to_ascii_lowercase();
+        if host.contains("github") {
+            Some(Self::GitHub)
+        } else if host.contains("gitlab") {
+            Some(Self::GitLab)
+        } else {
+            None
+        }
+    }
+
+    /// Default CLI binary
+    pub fn cli(self) -> &'static str {
+        match self {
+            Self::GitHub => "gh",
+            Self::GitLab => "glab",
+        }
+    }
+
+    /// What the host calls a pull request
+    pub fn request_term(self) -> &'static str {
+        match self {
+            Self::GitHub => "pull request",
+            Self::GitLab => "merge request",
+        }
+    }
+}
+
+/// Host part of a remote URL
+fn remote_host(url: &str) -> Option<&str> {
+    let rest = match url.split_once("://") {
+        Some((_, rest)) => rest,
+        // scp-like syntax: user@host:path
+        None => url.split_once(':')?.0,
+    };
+    let rest = rest.rsplit_once('@').map_or(rest, |(_, host)| host);
+    let host = rest.split(['/', ':']).next()?;
+    (!host.is_empty()).then_some(host)
+}
+
+/// Pull request state filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrState {
+    #[default]
+    Open,
+    Closed,
+    Merged,
+    All,
+}
+
+impl PrState {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "open" | "opened" => Some(Self::Open),
+            "closed" => Some(Self::Closed),
+            "merged" => Some(Self::Merged),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Closed => "closed",
+            Self::Merged => "merged",
+            Self::All => "all",
+        }
+    }
+}
+
+/// A pull request / merge request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequest {
+    /// PR number (GitLab: MR iid)
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    /// `open`, `closed` or `merged`
+    pub state: String,
+    /// Source branch
+    pub head: String,
+    /// Target branch
+    pub base: String,
+    pub author: Option<String>,
+    pub draft: bool,
+}
+
+impl PullRequest {
+    /// Parse `gh ... --json` output (one object)
+    fn from_github(value: &Value) -> Option<Self> {
+        Some(Self {
+            number: value["number"].as_u64()?,
+            title: value["title"].as_str()?.to_string(),
+            url: value["url"].as_str().unwrap_or_default().to_string(),
+            state: value["state"]
+                .as_str()
+                .unwrap_or("open")
+                .to_ascii_lowercase(),
+            head: value["headRefName"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            base: value["baseRefName"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            author: value["author"]["login"].as_str().map(String::from),
+            draft: value["isDraft"].as_bool().unwrap_or(false),
+        })
+    }
+
+    /// Parse `glab ... --output json` output (one object)
+    fn from_gitlab(value: &Value) -> Option<Self> {
+        let state = match value["state"].as_str().unwrap_or("opened") {
+            "opened" => "open".to_string(),
+            other => other.to_string(),
+        };
+        Some(Self {
+            number: value["iid"].as_u64()?,
+            title: value["title"].as_str()?.to_string(),
+            url: value["web_url"].as_str().unwrap_or_default().to_string(),
+            state,
+            head: value["source_branch"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            base: value["target_branch"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            author: value["author"]["username"].as_str().map(String::from),
+            draft: value["draft"].as_bool().unwrap_or(false),
+        })
+    }
+
+    /// Parse a JSON list of pull requests
+    pub fn parse_list(kind: HostKind, json: &str) -> Result<Vec<Self>, GitError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| GitError::HostCommandFailed(format!("unexpected output: {}", e)))?;
+        let parse = match kind {
+            HostKind::GitHub => Self::from_github,
+            HostKind::GitLab => Self::from_gitlab,
+        };
+        Ok(value
+            .as_array()
+            .map(|items| items.iter().filter_map(parse).collect())
+            .unwrap_or_default())
+    }
+
+    /// Parse a single pull request JSON object
+    pub fn parse_one(kind: HostKind, json: &str) -> Result<Self, GitError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| GitError::HostCommandFailed(format!("unexpected output: {}", e)))?;
+        let parsed = match kind {
+            HostKind::GitHub => Self::from_github(&value),
+            HostKind::GitLab => Self::from_gitlab(&value),
+        };
+        parsed.ok_or_else(|| GitError::HostCommandFailed("unexpected output".to_string()))
+    }
+
+    /// One-line summary (`#12 Fix login (feature -> main) [draft]`)
+    pub fn summary(&self) -> String {
+        let mut line = format!(
+            "#{} {} ({} -> {})",
+            self.number, self.title, self.head, self.base
+        );
+        if let Some(author) = &self.author {
+            line.push_str(&format!(" by {}", author));
+        }
+        if self.draft {
+            line.push_str(" [draft]");
+        }
+        if self.state != "open" {
+            line.push_str(&format!(" [{}]", self.state));
+        }
+        line
+    }
+}
+
+/// Pull request to create
+#[derive(Debug, Clone, Default)]
+pub struct NewPullRequest {
+    pub title: String,
+    pub body: String,
+    /// Source branch (default: current branch)
+    pub head: Option<String>,
+    /// Target branch (default: repository default branch)
+    pub base: Option<String>,
+    pub draft: bool,
+}
+
+impl NewPullRequest {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn with_head(mut self, head: impl Into<String>) -> Self {
+        self.head = Some(head.into());
+        self
+    }
+
+    pub fn with_base(mut self, base: impl Into<String>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+
+    pub fn with_draft(mut self, draft: bool) -> Self {
+        self.draft = draft;
+        self
+    }
+}
+
+/// Review comment on a pull request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewComment {
+    pub body: String,
+    /// File for an inline comment
+    pub path: Option<String>,
+    /// Line (in the new version of the file) for an inline comment
+    pub line: Option<u32>,
+}
+
+impl ReviewComment {
+    /// Comment on the pull request as a whole
+    pub fn general(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            path: None,
+            line: None,
+        }
+    }
+
+    /// Comment on a line of a changed file
+    pub fn inline(path: impl Into<String>, line: u32, body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            path: Some(path.into()),
+            line: Some(line),
+        }
+    }
+}
+
+/// Pull request operations for one repository
+#[derive(Debug, Clone)]
+pub struct CodeHost {
+    kind: HostKind,
+    root: PathBuf,
+    cli: String,
+}
+
+impl CodeHost {
+    /// Detect the host from the `origin` remote
+    pub fn detect(repo_dir: &Path) -> Result<Self, GitError> {
+        let repo = GitOps::new(repo_dir)?;
+        let url = repo
+            .remote_url("origin")
+            .map_err(|_| GitError::UnsupportedHost("no 'origin' remote".to_string()))?;
+        let kind = HostKind::from_remote_url(&url).ok_or(GitError::UnsupportedHost(url))?;
+        Ok(Self::new(repo.root(), kind))
+    }
+
+    /// Use a known host (e.g. self-hosted GitLab on a custom domain)
+    pub fn new(repo_dir: &Path, kind: HostKind) -> Self {
+        Self {
+            kind,
+            root: repo_dir.to_path_buf(),
+            cli: kind.cli().to_string(),
+        }
+    }
+
+    /// Use a different CLI binary (path or name)
+    pub fn with_cli(mut self, cli: impl Into<String>) -> Self {
+        self.cli = cli.into();
+        self
+    }
+
+    pub fn kind(&self) -> HostKind {
+        self.kind
+    }
+
+    /// Open a pull request
+    pub fn create_pr(&self, request: &NewPullRequest) -> Result<PullRequest, GitError> {
+        let mut args = match self.kind {
+            HostKind::GitHub => vec![
+                "pr",
+                "create",
+                "--title",
+                &request.title,
+                "--body",
+                &request.body,
+            ],
+            HostKind::GitLab => vec![
+                "mr",
+                "create",
+                "--title",
+                &request.title,
+                "--description",
+                &request.body,
+                "--yes",
+            ],
+        };
+        let (head_flag, base_flag) = match self.kind {
+            HostKind::GitHub => ("--head", "--base"),
+            HostKind::GitLab => ("--source-branch", "--target-branch"),
+        };
+        if let Some(head) = &request.head {
+            args.extend([head_flag, head]);
+        }
+        if let Some(base) = &request.base {
+            args.extend([base_flag, base]);
+        }
+        if request.draft {
+            args.push("--draft");
+        }
+
+        let output = self.run(&args)?;
+        let number = output
+            .lines()
+            .rev()
+            .find_map(number_from_url)
+            .ok_or_else(|| {
+                GitError::HostCommandFailed(format!(
+                    "could not find the new {} in output: {}",
+                    self.kind.request_term(),
+                    output.trim()
+                ))
+            })?;
+        info!("Created {} #{}", self.kind.request_term(), number);
+        self.get_pr(number)
+    }
+
+    /// List pull requests
+    pub fn list_prs(&self, state: PrState, limit: usize) -> Result<Vec<PullRequest>, GitError> {
+        let limit = limit.max(1).to_string();
+        let output = match self.kind {
+            HostKind::GitHub => self.run(&[
+                "pr",
+                "list",
+                "--state",
+                state.as_str(),
+                "--limit",
+                &limit,
+                "--json",
+                GITHUB_PR_FIELDS,
+            ])?,
+            HostKind::GitLab => {
+                let mut args = vec!["mr", "list", "--per-page", &limit, "--output", "json"];
+                match state {
+                    PrState::Open => {}
+                    PrState::Closed => args.push("--closed"),
+                    PrState::Merged => args.push("--merged"),
+                    PrState::All => args.push("--all"),
+                }
+                self.run(&args)?
+            }
+        };
+        PullRequest::parse_list(self.kind, &output)
+    }
+
+    /// Get one pull request
+    pub fn get_pr(&self, number: u64) -> Result<PullRequest, GitError> {
+        let number = number.to_string();
+        let output = match self.kind {
+            HostKind::GitHub => self.run(&["pr", "view", &number, "--json", GITHUB_PR_FIELDS])?,
+            HostKind::GitLab => self.run(&["mr", "view", &number, "--output", "json"])?,
+        };
+        PullRequest::parse_one(self.kind, &output)
+    }
+
+    /// Open pull request whose source is `branch`
+    pub fn pr_for_branch(&self, branch: &str) -> Result<Option<PullRequest>, GitError> {
+        let output = match self.kind {
+            HostKind::GitHub => {
+                self.run(&["pr", "list", "--head", branch, "--json", GITHUB_PR_FIELDS])?
+            }
+            HostKind::GitLab => {
+                self.run(&["mr", "list", "--source-branch", branch, "--output", "json"])?
+            }
+        };
+        Ok(PullRequest::parse_list(self.kind, &output)?
+            .into_iter()
+            .next())
+    }
+
+    /// Unified diff of a pull request
+    pub fn get_pr_diff(&self, number: u64) -> Result<String, GitError> {
+        let number = number.to_string();
+        match self.kind {
+            HostKind::GitHub => self.run(&["pr", "diff", &number]),
+            HostKind::GitLab => self.run(&["mr", "diff", &number, "--raw"]),
+        }
+    }
+
+    /// Post a review comment (inline when `path` and `line` are set)
+    pub fn post_review_comment(
+        &self,
+        number: u64,
+        comment: &ReviewComment,
+    ) -> Result<(), GitError> {
+        let number_str = number.to_string();
+        match (self.kind, &comment.path, comment.line) {
+            (HostKind::GitHub, Some(path), Some(line)) => {
+                let head = self.run(&[
+                    "pr",
+                    "view",
+                    &number_str,
+                    "--json",
+                    "headRefOid",
+                    "--jq",
+                    ".headRefOid",
+                ])?;
+                let endpoint = format!("repos/{{owner}}/{{repo}}/pulls/{}/comments", number);
+                self.run(&[
+                    "api",
+                    &endpoint,
+                    "-f",
+                    &format!("body={}", comment.body),
+                    "-f",
+                    &format!("commit_id={}", head.trim()),
+                    "-f",
+                    &format!("path={}", path),
+                    "-F",
+                    &format!("line={}", line),
+                    "-f",
+                    "side=RIGHT",
+                ])?;
+            }
+            (HostKind::GitHub, _, _) => {
+                self.run(&["pr", "comment", &number_str, "--body", &comment.body])?;
+            }
+            (HostKind::GitLab, path, line) => {
+                let body = match (path, line) {
+                    (Some(path), Some(line)) => format!("`{}:{}` {}", path, line, comment.body),
+                    (Some(path), None) => format!("`{}` {}", path, comment.body),
+                    _ => comment.body.clone(),
+                };
+                self.run(&["mr", "note", &number_str, "--message", &body])?;
+            }
+        }
+        info!("Commented on {} #{}", self.kind.request_term(), number);
+        Ok(())
+    }
+
+    /// Run the host CLI in the repository
+    fn run(&self, args: &[&str]) -> Result<String, GitError> {
+        let output = Command::new(&self.cli)
+            .args(args)
+            .current_dir(&self.root)
+            .env("GH_PROMPT_DISABLED", "1")
+            .env("NO_COLOR", "1")
+            .output()
+            .map_err(|e| match e.kind() {
+                ErrorKind::NotFound => GitError::HostCommandFailed(format!(
+                    "'{}' not found; install the {} CLI and log in",
+                    self.cli,
+                    match self.kind {
+                        HostKind::GitHub => "GitHub",
+                        HostKind::GitLab => "GitLab",
+                    }
+                )),
+                _ => GitError::Io(e),
+            })?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(GitError::HostCommandFailed(stderr.trim().to_string()))
+        }
+    }
+}
+
+/// PR number from user input (`12`, `#12`, `!12` or a PR/MR URL)
+pub fn parse_pr_ref(value: &str) -> Option<u64> {
+    let value = value.trim();
+    value
+        .trim_start_matches(['#', '!'])
+        .parse()
+        .ok()
+        .or_else(|| number_from_url(value))
+}
+
+/// PR number from a URL (`.../pull/12`, `.../-/merge_requests/12`)
+fn number_from_url(line: &str) -> Option<u64> {
+    let url = line.split_whitespace().find(|w| w.starts_with("http"))?;
+    let mut segments = url.trim_end_matches('/').rsplit('/');
+    let number = segments.next()?.parse().ok()?;
+    matches!(segments.next()?, "pull" | "pulls" | "merge_requests").then_some(number)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_detection() {
+        let cases = [
+            ("https://github.com/owner/repo.git", Some(HostKind::GitHub)),
+            ("git@github.com:owner/repo.git", Some(HostKind::GitHub)),
+            (
+                "ssh://git@gitlab.example.com:2222/group/repo.git",
+                Some(HostKind::GitLab),
+            ),
+            (
+                "https://token@gitlab.com/group/sub/repo",
+                Some(HostKind::GitLab),
+            ),
+            ("https://bitbucket.org/owner/repo.git", None),
+            ("/local/path/repo.git", None),
+        ];
+        for (url, expected) in cases {
+            assert_eq!(HostKind::from_remote_url(url), expected, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_number_from_url() {
+        assert_eq!(number_from_url("https://github.com/o/r/pull/12"), Some(12));
+        assert_eq!(
+            number_from_url("!7 Fix https://gitlab.com/g/r/-/merge_requests/7/"),
+            Some(7)
+        );
+        assert_eq!(number_from_url("https://github.com/o/r/issues/3"), None);
+        assert_eq!(number_from_url("Creating pull request..."), None);
+
+        assert_eq!(parse_pr_ref("#42"), Some(42));
+        assert_eq!(parse_pr_ref("!7"), Some(7));
+        assert_eq!(parse_pr_ref("https://github.com/o/r/pull/9"), Some(9));
+        assert_eq!(parse_pr_ref("feature-branch"), None);
+    }
+
+    #[test]
+    fn test_parse_pull_requests() {
+        let github = r#"[{"number": 3, "title": "Add X", "url": "https://github.com/o/r/pull/3",
+            "state": "OPEN", "headRefName": "feat-x", "baseRefName": "main",
+            "author": {"login": "dev"}, "isDraft": true}]"#;
+        let prs = PullRequest::parse_list(HostKind::GitHub, github).unwrap();
+        assert_eq!(prs.len(), 1);
+        assert_eq!(prs[0].state, "open");
+        assert_eq!(prs[0].summary(), "#3 Add X (feat-x -> main) by dev [draft]");
+
+        let gitlab = r#"{"iid": 8, "title": "Fix Y", "web_url": "https://gitlab.com/g/r/-/merge_requests/8",
+            "state": "merged", "source_branch": "fix-y", "target_branch": "main",
+            "author": {"username": "ops"}, "draft": false}"#;
+        let mr = PullRequest::parse_one(HostKind::GitLab, gitlab).unwrap();
+        assert_eq!(mr.number, 8);
+        assert_eq!(mr.summary(), "#8 Fix Y (fix-y -> main) by ops [merged]");
+
+        assert!(PullRequest::parse_one(HostKind::GitHub, "not json").is_err());
+    }
+
+    /// Fake `gh` that records its arguments and answers like the real CLI
+    #[cfg(unix)]
+    fn fake_gh(dir: &Path) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = dir.join("gh");
+        std::fs::write(
+            &script,
+            r#"#!/bin/sh
+echo "$@" >> "$(dirname "$0")/calls.log"
+case "$1 $2" in
+  "pr create") echo "https://github.com/o/r/pull/5" ;;
+  "pr view")
+    if [ "$5" = "headRefOid" ]; then echo "abc123"; else
+    echo '{"number":5,"title":"T","url":"https://github.com/o/r/pull/5","state":"OPEN","headRefName":"h","baseRefName":"main","author":{"login":"me"},"isDraft":false}'
+    fi ;;
+  "pr diff") echo "diff --git a/x b/x" ;;
+  *) : ;;
+esac
+"#,
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_github_workflow_with_cli() {
+        let dir = tempfile::tempdir().unwrap();
+        let gh = fake_gh(dir.path());
+        let host = CodeHost::new(dir.path(), HostKind::GitHub).with_cli(gh.to_string_lossy());
+
+        let pr = host
+            .create_pr(
+                &NewPullRequest::new("T")
+                    .with_body("B")
+                    .with_base("main")
+                    .with_draft(true),
+            )
+            .unwrap();
+        assert_eq!(pr.number, 5);
+        assert_eq!(pr.head, "h");
+        assert!(host.get_pr_diff(5).unwrap().starts_with("diff --git"));
+
+        host.post_review_comment(5, &ReviewComment::general("LGTM"))
+            .unwrap();
+        host.post_review_comment(5, &ReviewComment::inline("src/a.rs", 9, "Nit"))
+            .unwrap();
+
+        let calls = std::fs::read_to_string(dir.path().join("calls.log")).unwrap();
+        let calls: Vec<_> = calls.lines().collect();
+        assert_eq!(calls[0], "pr create --title T --body B --base main --draft");
+        assert!(calls.contains(&"pr comment 5 --body LGTM"));
+        assert!(calls.contains(&"api repos/{owner}/{repo}/pulls/5/comments -f body=Nit -f commit_id=abc123 -f path=src/a.rs -F line=9 -f side=RIGHT"));
+
+        let missing = CodeHost::new(dir.path(), HostKind::GitLab).with_cli("forge-no-such-cli");
+        let err = missing.list_prs(PrState::Open, 10).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+}
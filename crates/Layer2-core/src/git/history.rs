@@ -0,0 +1,258 @@
+//! History Queries
+//!
+//! Structured `git blame` and `git log` results (author, date and commit
+//! subject per line or range) so the agent can find out why code looks the
+//! way it does before changing it, and avoid undoing intentional changes.
+//!
+//! ```ignore
+//! for range in group_blame(&git.blame(Path::new("src/lib.rs"), Some((10, 40)))?) {
+//!     println!("{}", range.summary_line());
+//! }
+//! for commit in git.file_log(Path::new("src/lib.rs"), Some((10, 40)), 5)? {
+//!     println!("{} {}", commit.short_hash, commit.message);
+//! }
+//! ```
+
+use super::ops::LogEntry;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// `git log --format` producing records parsed by [`parse_log`]
+pub(crate) const LOG_FORMAT: &str = "--format=%H%x00%h%x00%s%x00%an%x00%ae%x00%aI%x00%b%x1e";
+
+/// Commit id git blame reports for uncommitted lines
+const UNCOMMITTED: &str = "0000000000000000000000000000000000000000";
+
+/// Blame information for one line
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlameLine {
+    /// Line number in the current file (1-based)
+    pub line: usize,
+    /// Commit that last changed the line (all zeros if uncommitted)
+    pub commit: String,
+    pub author: String,
+    pub author_email: String,
+    /// Author date of the commit
+    pub date: DateTime<Utc>,
+    /// Commit subject
+    pub summary: String,
+    /// Line content (without the newline)
+    pub content: String,
+}
+
+impl BlameLine {
+    /// Whether the line is part of a commit (not a local modification)
+    pub fn is_committed(&self) -> bool {
+        self.commit != UNCOMMITTED
+    }
+}
+
+/// Consecutive lines last changed by the same commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlameRange {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub commit: String,
+    pub author: String,
+    pub date: DateTime<Utc>,
+    pub summary: String,
+}
+
+impl BlameRange {
+    /// `L10-14 1a2b3c4d 2024-03-01 alice: Fix overflow in parser`
+    pub fn summary_line(&self) -> String {
+        let lines = if self.start_line == self.end_line {
+            format!("L{}", self.start_line)
+        } else {
+            format!("L{}-{}", self.start_line, self.end_line)
+        };
+        if self.commit == UNCOMMITTED {
+            return format!("{} (uncommitted changes)", lines);
+        }
+        format!(
+            "{} {} {} {}: {}",
+            lines,
+            &self.commit[..self.commit.len().min(8)],
+            self.date.format("%Y-%m-%d"),
+            self.author,
+            self.summary
+        )
+    }
+}
+
+/// Parse `git blame --line-porcelain` output
+pub fn parse_blame_porcelain(output: &str) -> Vec<BlameLine> {
+    let mut lines = Vec::new();
+    let mut current: Option<BlameLine> = None;
+
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            // Content line ends this line's headers
+            if let Some(mut blame) = current.take() {
+                blame.content = content.to_string();
+                lines.push(blame);
+            }
+            continue;
+        }
+
+        let Some(blame) = current.as_mut() else {
+            // Header: <sha> <orig_line> <final_line> [<count>]
+            let mut parts = line.split(' ');
+            if let (Some(sha), Some(_), Some(final_line)) =
+                (parts.next(), parts.next(), parts.next())
+            {
+                if sha.len() >= 40 && sha.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    current = Some(BlameLine {
+                        line: final_line.parse().unwrap_or(0),
+                        commit: sha.to_string(),
+                        author: String::new(),
+                        author_email: String::new(),
+                        date: DateTime::default(),
+                        summary: String::new(),
+                        content: String::new(),
+                    });
+                }
+            }
+            continue;
+        };
+
+        if let Some(author) = line.strip_prefix("author ") {
+            blame.author = author.to_string();
+        } else if let Some(email) = line.strip_prefix("author-mail ") {
+            blame.author_email = email.trim_matches(['<', '>']).to_string();
+        } else if let Some(time) = line.strip_prefix("author-time ") {
+            blame.date = time
+                .trim()
+                .parse()
+                .ok()
+                .and_then(|t| DateTime::from_timestamp(t, 0))
+                .unwrap_or_default();
+        } else if let Some(summary) = line.strip_prefix("summary ") {
+            blame.summary = summary.to_string();
+        }
+    }
+
+    lines
+}
+
+/// Group consecutive lines from the same commit
+pub fn group_blame(lines: &[BlameLine]) -> Vec<BlameRange> {
+    let mut ranges: Vec<BlameRange> = Vec::new();
+    for line in lines {
+        if let Some(last) = ranges.last_mut() {
+            if last.commit == line.commit && last.end_line + 1 == line.line {
+                last.end_line = line.line;
+                continue;
+            }
+        }
+        ranges.push(BlameRange {
+            start_line: line.line,
+            end_line: line.line,
+            commit: line.commit.clone(),
+            author: line.author.clone(),
+            date: line.date,
+            summary: line.summary.clone(),
+        });
+    }
+    ranges
+}
+
+/// Parse `git log` output written with [`LOG_FORMAT`]
+pub(crate) fn parse_log(output: &str) -> Vec<LogEntry> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let fields: Vec<&str> = record.trim_start_matches('\n').splitn(7, '\0').collect();
+            let [hash, short_hash, message, author_name, author_email, date, body] = fields[..]
+            else {
+                return None;
+            };
+            Some(LogEntry {
+                hash: hash.to_string(),
+                short_hash: short_hash.to_string(),
+                message: message.to_string(),
+                author_name: author_name.to_string(),
+                author_email: author_email.to_string(),
+                date: date.to_string(),
+                body: body.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PORCELAIN: &str = "\
+281029c0c150efa856522c1e1aafdb247b16f21c 1 1 2
+author Alice
+author-mail <alice@example.com>
+author-time 1700000000
+author-tz +0000
+summary Add parser
+boundary
+filename src/lib.rs
+\tfn parse() {
+281029c0c150efa856522c1e1aafdb247b16f21c 2 2
+author Alice
+author-mail <alice@example.com>
+author-time 1700000000
+author-tz +0000
+summary Add parser
+filename src/lib.rs
+\t    todo!()
+0000000000000000000000000000000000000000 3 3 1
+author Not Committed Yet
+author-mail <not.committed.yet>
+author-time 1800000000
+author-tz +0000
+summary Version of src/lib.rs from src/lib.rs
+filename src/lib.rs
+\t}
+";
+
+    #[test]
+    fn test_parse_blame_porcelain() {
+        let lines = parse_blame_porcelain(PORCELAIN);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].line, 1);
+        assert_eq!(lines[0].author, "Alice");
+        assert_eq!(lines[0].author_email, "alice@example.com");
+        assert_eq!(lines[0].summary, "Add parser");
+        assert_eq!(lines[1].content, "    todo!()");
+        assert_eq!(lines[0].date.timestamp(), 1_700_000_000);
+        assert!(lines[1].is_committed());
+        assert!(!lines[2].is_committed());
+    }
+
+    #[test]
+    fn test_group_blame() {
+        let ranges = group_blame(&parse_blame_porcelain(PORCELAIN));
+        assert_eq!(ranges.len(), 2);
+        assert_eq!((ranges[0].start_line, ranges[0].end_line), (1, 2));
+        assert_eq!(
+            ranges[0].summary_line(),
+            "L1-2 281029c0 2023-11-14 Alice: Add parser"
+        );
+        assert_eq!(ranges[1].summary_line(), "L3 (uncommitted changes)");
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let output =
+            "abc\0a\0Fix | pipes\0Bob\0bob@x\x002024-01-01T00:00:00+00:00\0Why:\nbecause\n\x1e\n\
+                      def\0d\0Init\0Ann\0ann@x\x002023-01-01T00:00:00+00:00\0\x1e";
+        let entries = parse_log(output);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].message, "Fix | pipes");
+        assert_eq!(entries[0].body, "Why:\nbecause");
+        assert_eq!(entries[1].hash, "def");
+        assert!(entries[1].body.is_empty());
+        assert!(parse_log("").is_empty());
+    }
+}
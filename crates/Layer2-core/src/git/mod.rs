@@ -5,18 +5,57 @@
 //! - Checkpoint/rollback for recovery
 //! - Diff-based commit message generation
 //! - Ghost commits for turn-by-turn history
+//! - Isolated worktree workspaces for agent runs
+//! - Pull/merge request workflow on GitHub and GitLab
+//! - Structured merge-conflict extraction and hunk-by-hunk resolution
+//! - Blame and log queries for code archaeology
 //!
 //! ## Features
 //!
-//! - **Auto-commit**: Automatically commit AI-generated changes
-//! - **Checkpoints**: Create restore points before risky operations
-//! - **Rollback**: Revert to previous checkpoints
+//! - **Auto-commit**: Automatically commit AI-generated changes, optionally
+//!   signed (GPG/SSH) under a distinct identity with `Co-Authored-By` trailers
+//! - **Pre-commit Checks**: Run the repo's pre-commit hook and lint commands
+//!   before auto-commits and report failures for the agent to fix
+//! - **Checkpoints**: Create restore points before risky operations, keeping
+//!   the user's uncommitted work apart from the agent's changes
+//! - **Rollback**: Revert to previous checkpoints without losing that work
+//! - **Timeline**: Ghost commit per agent turn under hidden refs, rewindable
+//!   to any earlier turn
 //! - **Commit Message Generation**: LLM-based commit messages from diffs
+//! - **Agent Workspaces**: Run the agent in its own worktree + branch and
+//!   present the result as a branch/PR instead of editing the user's tree
+//! - **Code Host**: Create, list and review pull requests via `gh`/`glab`
+//! - **Conflicts**: Ours/base/theirs hunks per file after a merge, rebase or
+//!   checkpoint restore, resolved one hunk at a time
+//! - **History**: Structured blame and per-file/per-range log for "why is
+//!   this code like this" questions
 
 pub mod checkpoint;
 pub mod commit;
+pub mod conflict;
+pub mod forge;
+pub mod history;
 pub mod ops;
+pub mod precommit;
+pub mod worktree;
 
-pub use checkpoint::{Checkpoint, CheckpointId, CheckpointManager};
-pub use commit::{AutoCommitConfig, CommitGenerator, CommitStyle};
-pub use ops::{FileStatus, GitError, GitOps, GitStatus};
+pub use checkpoint::{
+    Checkpoint, CheckpointId, CheckpointManager, TimelineEntry, CHECKPOINT_REF_PREFIX,
+    GHOST_REF_PREFIX,
+};
+pub use commit::{
+    AutoCommitConfig, CommitGenerator, CommitIdentity, CommitOptions, CommitSigning, CommitStyle,
+};
+pub use conflict::{
+    parse_conflicts, resolve_hunk, ConflictFile, ConflictHunk, ConflictOperation,
+    ConflictResolution,
+};
+pub use forge::{
+    parse_pr_ref, CodeHost, HostKind, NewPullRequest, PrState, PullRequest, ReviewComment,
+};
+pub use history::{group_blame, parse_blame_porcelain, BlameLine, BlameRange};
+pub use ops::{
+    DiffStat, FileDiffStat, FileStatus, GitError, GitOps, GitStatus, LogEntry, WorktreeInfo,
+};
+pub use precommit::{PreCommitCheck, PreCommitReport, PRE_COMMIT_HOOK};
+pub use worktree::{AgentWorkspace, WorkspaceOutcome, WORKSPACE_BRANCH_PREFIX};
@@ -2,6 +2,11 @@
 //!
 //! Core Git operations using git2 or shell commands.
 
+use super::commit::CommitOptions;
+use super::conflict::{parse_conflicts, ConflictFile, ConflictOperation};
+use super::history::{parse_blame_porcelain, parse_log, BlameLine, LOG_FORMAT};
+use super::precommit::{PreCommitCheck, PreCommitReport, PRE_COMMIT_HOOK};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use thiserror::Error;
@@ -31,6 +36,15 @@ pub enum GitError {
     #[error("Merge conflict detected")]
     MergeConflict,
 
+    #[error("Unsupported code host: {0}")]
+    UnsupportedHost(String),
+
+    #[error("Code host command failed: {0}")]
+    HostCommandFailed(String),
+
+    #[error("{0}")]
+    PreCommitFailed(PreCommitReport),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -149,13 +163,18 @@ impl GitOps {
 
     /// Run a git command
     fn run_git(&self, args: &[&str]) -> Result<String, GitError> {
+        Ok(self.run_git_raw(args)?.trim().to_string())
+    }
+
+    /// Run a git command, keeping leading whitespace (significant in porcelain output)
+    fn run_git_raw(&self, args: &[&str]) -> Result<String, GitError> {
         let output = Command::new("git")
             .args(args)
             .current_dir(&self.root)
             .output()?;
 
         if output.status.success() {
-            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
             Err(GitError::CommandFailed(stderr.to_string()))
@@ -171,7 +190,7 @@ impl GitOps {
     pub fn status(&self) -> Result<GitStatus, GitError> {
         let branch = self.current_branch().ok();
 
-        let output = self.run_git(&["status", "--porcelain=v1"])?;
+        let output = self.run_git_raw(&["status", "--porcelain=v1"])?;
 
         let mut status = GitStatus {
             branch,
@@ -302,6 +321,102 @@ impl GitOps {
         Ok(hash)
     }
 
+    /// Commit staged changes with a custom author/committer and optional signature
+    pub fn commit_with(&self, message: &str, options: &CommitOptions) -> Result<String, GitError> {
+        let status = self.status()?;
+        if !status.has_staged {
+            return Err(GitError::NothingToCommit);
+        }
+
+        let mut args = options
+            .signing
+            .as_ref()
+            .map(|signing| signing.config_args())
+            .unwrap_or_default();
+        args.extend(["commit".to_string(), "-m".to_string(), message.to_string()]);
+        if let Some(author) = &options.author {
+            args.push(format!("--author={}", author));
+        }
+        if options.signing.is_some() {
+            args.push("-S".to_string());
+        }
+        if options.no_verify {
+            args.push("--no-verify".to_string());
+        }
+        let env: Vec<(&str, &std::ffi::OsStr)> = options
+            .committer
+            .as_ref()
+            .map(|committer| {
+                vec![
+                    ("GIT_COMMITTER_NAME", committer.name.as_ref()),
+                    ("GIT_COMMITTER_EMAIL", committer.email.as_ref()),
+                ]
+            })
+            .unwrap_or_default();
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.run_git_with_env(&args, &env)?;
+
+        let hash = self.run_git(&["rev-parse", "--short", "HEAD"])?;
+        info!("Created commit: {}", hash);
+        Ok(hash)
+    }
+
+    /// Unstage everything (the working tree is not touched)
+    pub fn unstage_all(&self) -> Result<(), GitError> {
+        self.run_git(&["reset", "-q"])?;
+        Ok(())
+    }
+
+    /// Path of a hook (`core.hooksPath` aware), if it exists and is executable
+    pub fn hook_path(&self, name: &str) -> Option<PathBuf> {
+        let path = self
+            .run_git(&["rev-parse", "--git-path", &format!("hooks/{}", name)])
+            .ok()?;
+        let path = self.root.join(path);
+        let meta = std::fs::metadata(&path).ok()?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if meta.permissions().mode() & 0o111 == 0 {
+                return None;
+            }
+        }
+        meta.is_file().then_some(path)
+    }
+
+    /// Run the pre-commit hook (if `run_hook`) and `commands` from the repository root
+    ///
+    /// Every check runs even after a failure so all problems are reported at once.
+    pub fn run_pre_commit(
+        &self,
+        run_hook: bool,
+        commands: &[String],
+    ) -> Result<PreCommitReport, GitError> {
+        let mut report = PreCommitReport::default();
+        if let Some(hook) = run_hook.then(|| self.hook_path("pre-commit")).flatten() {
+            let output = Command::new(&hook).current_dir(&self.root).output()?;
+            report
+                .checks
+                .push(PreCommitCheck::from_output(PRE_COMMIT_HOOK, &output));
+        }
+
+        let (shell, shell_arg) = if cfg!(windows) {
+            ("cmd", "/C")
+        } else {
+            ("sh", "-c")
+        };
+        for command in commands {
+            let output = Command::new(shell)
+                .args([shell_arg, command])
+                .current_dir(&self.root)
+                .output()?;
+            report
+                .checks
+                .push(PreCommitCheck::from_output(command, &output));
+        }
+        Ok(report)
+    }
+
     /// Commit all changes (stage + commit)
     pub fn commit_all(&self, message: &str) -> Result<String, GitError> {
         self.add_all()?;
@@ -336,9 +451,10 @@ impl GitOps {
         Ok(())
     }
 
-    /// Pop stashed changes
+    /// Pop stashed changes (`MergeConflict` when the pop leaves conflicts)
     pub fn stash_pop(&self) -> Result<(), GitError> {
-        self.run_git(&["stash", "pop"])?;
+        self.run_git(&["stash", "pop"])
+            .map_err(|e| self.conflict_or(e))?;
         Ok(())
     }
 
@@ -355,25 +471,56 @@ impl GitOps {
 
     /// Get log entries
     pub fn log(&self, count: usize) -> Result<Vec<LogEntry>, GitError> {
-        let format = "--format=%H|%h|%s|%an|%ae|%aI";
-        let output = self.run_git(&["log", format, "-n", &count.to_string()])?;
+        let output = self.run_git(&["log", LOG_FORMAT, "-n", &count.to_string()])?;
+        Ok(parse_log(&output))
+    }
 
-        let mut entries = Vec::new();
-        for line in output.lines() {
-            let parts: Vec<&str> = line.splitn(6, '|').collect();
-            if parts.len() == 6 {
-                entries.push(LogEntry {
-                    hash: parts[0].to_string(),
-                    short_hash: parts[1].to_string(),
-                    message: parts[2].to_string(),
-                    author_name: parts[3].to_string(),
-                    author_email: parts[4].to_string(),
-                    date: parts[5].to_string(),
-                });
+    /// Commits that touched a file (following renames), or only `lines` of it
+    ///
+    /// `lines` is an inclusive 1-based range traced with `git log -L`.
+    pub fn file_log(
+        &self,
+        path: &Path,
+        lines: Option<(usize, usize)>,
+        count: usize,
+    ) -> Result<Vec<LogEntry>, GitError> {
+        let path = self.repo_path(path);
+        let count = count.to_string();
+        let output = match lines {
+            Some((start, end)) => {
+                let range = format!("-L{},{}:{}", start, end, path);
+                self.run_git(&["log", LOG_FORMAT, "-s", "-n", &count, &range])?
             }
-        }
+            None => self.run_git(&["log", LOG_FORMAT, "--follow", "-n", &count, "--", &path])?,
+        };
+        Ok(parse_log(&output))
+    }
 
-        Ok(entries)
+    /// Who last changed each line of a file (or of `lines`, inclusive and 1-based)
+    pub fn blame(
+        &self,
+        path: &Path,
+        lines: Option<(usize, usize)>,
+    ) -> Result<Vec<BlameLine>, GitError> {
+        let path = self.repo_path(path);
+        let range = lines.map(|(start, end)| format!("-L{},{}", start, end));
+        let mut args = vec!["blame", "--line-porcelain"];
+        args.extend(range.as_deref());
+        args.extend(["--", &path]);
+        Ok(parse_blame_porcelain(&self.run_git(&args)?))
+    }
+
+    /// Path relative to the repository root (absolute paths inside the repository)
+    fn repo_path(&self, path: &Path) -> String {
+        let relative = path
+            .canonicalize()
+            .ok()
+            .zip(self.root.canonicalize().ok())
+            .and_then(|(path, root)| path.strip_prefix(root).ok().map(Path::to_path_buf));
+        relative
+            .unwrap_or_else(|| path.to_path_buf())
+            .to_string_lossy()
+            .replace('\\', "/")
     }
 
     /// Check if there are uncommitted changes
@@ -407,6 +554,330 @@ impl GitOps {
     pub fn merge_base(&self, a: &str, b: &str) -> Result<String, GitError> {
         self.run_git(&["merge-base", a, b])
     }
+
+    /// Snapshot the working tree (tracked + untracked, minus ignored) as a tree object
+    ///
+    /// Uses a temporary index so the user's staging area is left untouched.
+    pub fn snapshot_tree(&self) -> Result<String, GitError> {
+        let index = self.index_path()?;
+        let temp = index.with_file_name(format!("forge-snapshot-{}.index", std::process::id()));
+        if index.exists() {
+            std::fs::copy(&index, &temp)?;
+        }
+
+        let env = [("GIT_INDEX_FILE", temp.as_os_str())];
+        let tree = self
+            .run_git_with_env(&["add", "-A"], &env)
+            .and_then(|_| self.run_git_with_env(&["write-tree"], &env));
+        let _ = std::fs::remove_file(&temp);
+        tree
+    }
+
+    /// Write the current staging area as a tree object (fails while conflicts are unresolved)
+    pub fn index_tree(&self) -> Result<String, GitError> {
+        self.run_git(&["write-tree"])
+    }
+
+    /// Replace the staging area with `tree`; the working tree is not touched
+    pub fn read_index_tree(&self, tree: &str) -> Result<(), GitError> {
+        self.run_git(&["read-tree", tree])?;
+        // read-tree drops cached stat data; refresh so unchanged files don't look modified
+        let _ = self.run_git(&["update-index", "-q", "--refresh"]);
+        Ok(())
+    }
+
+    /// Make the working tree match a snapshot tree
+    ///
+    /// Files missing from `tree` are deleted (ignored files are kept); like
+    /// `snapshot_tree`, the user's staging area is left untouched.
+    pub fn restore_tree(&self, tree: &str) -> Result<(), GitError> {
+        let current = self.snapshot_tree()?;
+        let added = self.run_git(&[
+            "diff",
+            "--name-only",
+            "--no-renames",
+            "--diff-filter=A",
+            "-z",
+            tree,
+            &current,
+        ])?;
+        for path in added.split('\0').filter(|p| !p.is_empty()) {
+            let path = self.root.join(path);
+            match std::fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+            // Drop directories the removal left empty
+            let mut dir = path.parent();
+            while let Some(d) = dir.filter(|d| *d != self.root) {
+                if std::fs::remove_dir(d).is_err() {
+                    break;
+                }
+                dir = d.parent();
+            }
+        }
+
+        let temp = self
+            .index_path()?
+            .with_file_name(format!("forge-restore-{}.index", std::process::id()));
+        let env = [("GIT_INDEX_FILE", temp.as_os_str())];
+        let restored = self
+            .run_git_with_env(&["read-tree", tree], &env)
+            .and_then(|_| self.run_git_with_env(&["checkout-index", "-a", "-f"], &env));
+        let _ = std::fs::remove_file(&temp);
+        restored.map(|_| ())
+    }
+
+    /// Create a commit object for `tree` without touching HEAD, the index or any branch
+    ///
+    /// Authored as Forge so it works without a configured git identity.
+    pub fn commit_tree(
+        &self,
+        tree: &str,
+        parent: Option<&str>,
+        message: &str,
+    ) -> Result<String, GitError> {
+        let mut args = vec!["commit-tree", tree, "-m", message];
+        if let Some(parent) = parent {
+            args.extend(["-p", parent]);
+        }
+        let name = std::ffi::OsStr::new("Forge");
+        let email = std::ffi::OsStr::new("forge@localhost");
+        self.run_git_with_env(
+            &args,
+            &[
+                ("GIT_AUTHOR_NAME", name),
+                ("GIT_AUTHOR_EMAIL", email),
+                ("GIT_COMMITTER_NAME", name),
+                ("GIT_COMMITTER_EMAIL", email),
+            ],
+        )
+    }
+
+    /// Resolve a revision (`HEAD`, `<commit>^{tree}`, a ref name) to an object id
+    pub fn rev_parse(&self, rev: &str) -> Result<String, GitError> {
+        self.run_git(&["rev-parse", "--verify", "--quiet", rev])
+            .map_err(|_| GitError::CommandFailed(format!("Unknown revision: {}", rev)))
+    }
+
+    /// Point a ref (e.g. `refs/forge/...`) at an object
+    pub fn update_ref(&self, name: &str, target: &str) -> Result<(), GitError> {
+        self.run_git(&["update-ref", name, target])?;
+        Ok(())
+    }
+
+    /// Delete a ref
+    pub fn delete_ref(&self, name: &str) -> Result<(), GitError> {
+        self.run_git(&["update-ref", "-d", name])?;
+        Ok(())
+    }
+
+    /// `git for-each-ref --format=<format> <prefix>`, one line per ref
+    pub fn for_each_ref(&self, prefix: &str, format: &str) -> Result<Vec<String>, GitError> {
+        let output = self.run_git(&["for-each-ref", &format!("--format={}", format), prefix])?;
+        Ok(output
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    /// Path of the repository's index file
+    fn index_path(&self) -> Result<PathBuf, GitError> {
+        let index = self.run_git(&["rev-parse", "--git-path", "index"])?;
+        Ok(self.root.join(index))
+    }
+
+    /// Per-file line statistics between two trees or commits
+    pub fn diff_stat(&self, from: &str, to: &str) -> Result<DiffStat, GitError> {
+        let output = self.run_git(&["diff", "--numstat", "--no-renames", from, to])?;
+        Ok(DiffStat::parse_numstat(&output))
+    }
+
+    /// Shared `.git` directory (the main repository's, also from inside a worktree)
+    pub fn common_dir(&self) -> Result<PathBuf, GitError> {
+        let dir = PathBuf::from(self.run_git(&["rev-parse", "--git-common-dir"])?);
+        Ok(if dir.is_absolute() {
+            dir
+        } else {
+            self.root.join(dir)
+        })
+    }
+
+    /// Check if a local branch exists
+    pub fn branch_exists(&self, branch: &str) -> bool {
+        self.run_git(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{}", branch),
+        ])
+        .is_ok()
+    }
+
+    /// Delete a local branch (`force` also deletes unmerged branches)
+    pub fn delete_branch(&self, branch: &str, force: bool) -> Result<(), GitError> {
+        if !self.branch_exists(branch) {
+            return Err(GitError::BranchNotFound(branch.to_string()));
+        }
+        self.run_git(&["branch", if force { "-D" } else { "-d" }, branch])?;
+        Ok(())
+    }
+
+    /// Number of commits reachable from `to` but not from `from`
+    pub fn count_commits(&self, from: &str, to: &str) -> Result<usize, GitError> {
+        let count = self.run_git(&["rev-list", "--count", &format!("{}..{}", from, to)])?;
+        count
+            .parse()
+            .map_err(|_| GitError::CommandFailed(format!("Unexpected rev-list output: {}", count)))
+    }
+
+    /// Create a linked worktree at `path` on a new branch starting at `base`
+    ///
+    /// Returns a `GitOps` rooted at the new worktree.
+    pub fn create_worktree(
+        &self,
+        path: &Path,
+        branch: &str,
+        base: &str,
+    ) -> Result<GitOps, GitError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let path_str = path.to_string_lossy();
+        self.run_git(&["worktree", "add", "-b", branch, &path_str, base])?;
+        info!("Created worktree {} on branch {}", path.display(), branch);
+        Ok(GitOps {
+            root: path.to_path_buf(),
+        })
+    }
+
+    /// List worktrees of this repository (the main working tree first)
+    pub fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>, GitError> {
+        let output = self.run_git(&["worktree", "list", "--porcelain"])?;
+        Ok(WorktreeInfo::parse_porcelain(&output))
+    }
+
+    /// Remove a linked worktree (`force` also discards uncommitted changes)
+    pub fn remove_worktree(&self, path: &Path, force: bool) -> Result<(), GitError> {
+        let path_str = path.to_string_lossy();
+        let mut args = vec!["worktree", "remove"];
+        if force {
+            args.push("--force");
+        }
+        args.push(&path_str);
+        self.run_git(&args)?;
+        info!("Removed worktree {}", path.display());
+        Ok(())
+    }
+
+    /// Push a branch and set its upstream (`git push -u <remote> <branch>`)
+    pub fn push_branch(&self, remote: &str, branch: &str) -> Result<(), GitError> {
+        self.run_git(&["push", "-u", remote, branch])?;
+        info!("Pushed {} to {}", branch, remote);
+        Ok(())
+    }
+
+    /// Merge a branch (`MergeConflict` when it stops with conflicts)
+    pub fn merge(&self, branch: &str) -> Result<(), GitError> {
+        self.run_git(&["merge", "--no-edit", branch])
+            .map_err(|e| self.conflict_or(e))?;
+        Ok(())
+    }
+
+    /// Rebase the current branch (`MergeConflict` when it stops with conflicts)
+    pub fn rebase(&self, onto: &str) -> Result<(), GitError> {
+        self.run_git(&["rebase", onto])
+            .map_err(|e| self.conflict_or(e))?;
+        Ok(())
+    }
+
+    /// Files with unresolved conflicts
+    pub fn conflicted_files(&self) -> Result<Vec<PathBuf>, GitError> {
+        let output = self.run_git(&["diff", "--name-only", "--diff-filter=U"])?;
+        Ok(output
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(PathBuf::from)
+            .collect())
+    }
+
+    /// Conflicted files with their ours/base/theirs hunks
+    pub fn conflicts(&self) -> Result<Vec<ConflictFile>, GitError> {
+        Ok(self
+            .conflicted_files()?
+            .into_iter()
+            .map(|path| {
+                // 바이너리/삭제된 파일은 마커 없음
+                let hunks = std::fs::read_to_string(self.root.join(&path))
+                    .map(|content| parse_conflicts(&content))
+                    .unwrap_or_default();
+                ConflictFile { path, hunks }
+            })
+            .collect())
+    }
+
+    /// Operation waiting for conflicts to be resolved, if any
+    pub fn conflict_operation(&self) -> Result<Option<ConflictOperation>, GitError> {
+        let git_dir = PathBuf::from(self.run_git(&["rev-parse", "--git-dir"])?);
+        let git_dir = if git_dir.is_absolute() {
+            git_dir
+        } else {
+            self.root.join(git_dir)
+        };
+        Ok(
+            if git_dir.join("rebase-merge").exists() || git_dir.join("rebase-apply").exists() {
+                Some(ConflictOperation::Rebase)
+            } else if git_dir.join("MERGE_HEAD").exists() {
+                Some(ConflictOperation::Merge)
+            } else if git_dir.join("CHERRY_PICK_HEAD").exists() {
+                Some(ConflictOperation::CherryPick)
+            } else if git_dir.join("REVERT_HEAD").exists() {
+                Some(ConflictOperation::Revert)
+            } else {
+                None
+            },
+        )
+    }
+
+    /// Mark a file as resolved (`git add`)
+    pub fn mark_resolved(&self, path: &Path) -> Result<(), GitError> {
+        self.run_git(&["add", "--", &path.to_string_lossy()])?;
+        Ok(())
+    }
+
+    /// `MergeConflict` if the repository now has conflicts, otherwise `err`
+    fn conflict_or(&self, err: GitError) -> GitError {
+        match self.conflicted_files() {
+            Ok(files) if !files.is_empty() => GitError::MergeConflict,
+            _ => err,
+        }
+    }
+
+    /// URL of a remote (e.g. `origin`)
+    pub fn remote_url(&self, remote: &str) -> Result<String, GitError> {
+        self.run_git(&["remote", "get-url", remote])
+    }
+
+    /// Run a git command with extra environment variables
+    fn run_git_with_env(
+        &self,
+        args: &[&str],
+        env: &[(&str, &std::ffi::OsStr)],
+    ) -> Result<String, GitError> {
+        let output = Command::new("git")
+            .args(args)
+            .envs(env.iter().copied())
+            .current_dir(&self.root)
+            .output()?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(GitError::CommandFailed(stderr.to_string()))
+        }
+    }
 }
 
 // ============================================================================
@@ -414,7 +885,7 @@ impl GitOps {
 // ============================================================================
 
 /// A git log entry
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEntry {
     pub hash: String,
     pub short_hash: String,
@@ -422,6 +893,128 @@ pub struct LogEntry {
     pub author_name: String,
     pub author_email: String,
     pub date: String,
+    /// Commit message body (after the subject)
+    pub body: String,
+}
+
+// ============================================================================
+// Worktree Info
+// ============================================================================
+
+/// A worktree entry (`git worktree list --porcelain`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorktreeInfo {
+    pub path: PathBuf,
+    /// Checked-out commit (missing for bare repositories)
+    pub head: Option<String>,
+    /// Checked-out branch without `refs/heads/` (None when detached)
+    pub branch: Option<String>,
+    pub locked: bool,
+    /// Directory is gone; `git worktree prune` will clean it up
+    pub prunable: bool,
+}
+
+impl WorktreeInfo {
+    /// Parse `git worktree list --porcelain` output
+    pub fn parse_porcelain(output: &str) -> Vec<Self> {
+        let mut worktrees = Vec::new();
+        let mut current: Option<WorktreeInfo> = None;
+
+        for line in output.lines() {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "worktree" => {
+                    worktrees.extend(current.take());
+                    current = Some(WorktreeInfo {
+                        path: PathBuf::from(value),
+                        ..Default::default()
+                    });
+                }
+                "HEAD" => {
+                    if let Some(wt) = current.as_mut() {
+                        wt.head = Some(value.to_string());
+                    }
+                }
+                "branch" => {
+                    if let Some(wt) = current.as_mut() {
+                        wt.branch = Some(value.trim_start_matches("refs/heads/").to_string());
+                    }
+                }
+                "locked" => {
+                    if let Some(wt) = current.as_mut() {
+                        wt.locked = true;
+                    }
+                }
+                "prunable" => {
+                    if let Some(wt) = current.as_mut() {
+                        wt.prunable = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+        worktrees.extend(current);
+        worktrees
+    }
+}
+
+// ============================================================================
+// Diff Stat
+// ============================================================================
+
+/// Line statistics for a single file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiffStat {
+    pub path: String,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Binary file (no line counts)
+    pub binary: bool,
+}
+
+/// Line statistics for a set of changes (`git diff --numstat`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffStat {
+    pub files: Vec<FileDiffStat>,
+}
+
+impl DiffStat {
+    /// Parse `git diff --numstat` output
+    pub fn parse_numstat(output: &str) -> Self {
+        let files = output
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(3, '\t');
+                let added = parts.next()?;
+                let deleted = parts.next()?;
+                let path = parts.next()?.to_string();
+                let binary = added == "-" && deleted == "-";
+                Some(FileDiffStat {
+                    path,
+                    additions: added.parse().unwrap_or(0),
+                    deletions: deleted.parse().unwrap_or(0),
+                    binary,
+                })
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    /// No files changed
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Total added lines
+    pub fn additions(&self) -> usize {
+        self.files.iter().map(|f| f.additions).sum()
+    }
+
+    /// Total deleted lines
+    pub fn deletions(&self) -> usize {
+        self.files.iter().map(|f| f.deletions).sum()
+    }
 }
 
 // ============================================================================
@@ -455,4 +1048,88 @@ mod tests {
         // Non-existent path should return false
         assert!(!GitOps::is_repo("/nonexistent/path/that/does/not/exist"));
     }
+
+    #[test]
+    fn test_parse_numstat() {
+        let stat = DiffStat::parse_numstat("10\t2\tsrc/lib.rs\n-\t-\tlogo.png\n3\t0\tREADME.md");
+        assert_eq!(stat.files.len(), 3);
+        assert_eq!(stat.additions(), 13);
+        assert_eq!(stat.deletions(), 2);
+        assert!(stat.files[1].binary);
+        assert!(DiffStat::parse_numstat("").is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_tree_and_diff_stat() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+        };
+        if !git(&["init", "-q"]).status.success() {
+            return;
+        }
+        std::fs::write(dir.path().join("a.txt"), "one\ntwo\n").unwrap();
+        git(&["add", "a.txt"]);
+
+        let ops = GitOps::new(dir.path()).unwrap();
+        let before = ops.snapshot_tree().unwrap();
+
+        std::fs::write(dir.path().join("a.txt"), "one\nthree\n").unwrap();
+        std::fs::write(dir.path().join("b.txt"), "new\n").unwrap();
+        let after = ops.snapshot_tree().unwrap();
+
+        let stat = ops.diff_stat(&before, &after).unwrap();
+        assert_eq!(stat.files.len(), 2);
+        assert_eq!(stat.additions(), 2);
+        assert_eq!(stat.deletions(), 1);
+
+        // 실제 인덱스는 변경되지 않음
+        let staged = String::from_utf8(git(&["diff", "--cached", "--name-only"]).stdout).unwrap();
+        assert_eq!(staged.trim(), "a.txt");
+    }
+
+    #[test]
+    fn test_restore_tree_and_refs() {
+        let dir = tempfile::tempdir().unwrap();
+        if !Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(dir.path())
+            .status()
+            .is_ok_and(|s| s.success())
+        {
+            return;
+        }
+        let path = |p: &str| dir.path().join(p);
+        std::fs::write(path("a.txt"), "one\n").unwrap();
+        let ops = GitOps::new(dir.path()).unwrap();
+        let before = ops.snapshot_tree().unwrap();
+
+        std::fs::write(path("a.txt"), "two\n").unwrap();
+        std::fs::create_dir_all(path("src/nested")).unwrap();
+        std::fs::write(path("src/nested/new.rs"), "fn f() {}\n").unwrap();
+        let after = ops.snapshot_tree().unwrap();
+
+        ops.restore_tree(&before).unwrap();
+        assert_eq!(std::fs::read_to_string(path("a.txt")).unwrap(), "one\n");
+        assert!(!path("src").exists());
+        assert_eq!(ops.snapshot_tree().unwrap(), before);
+
+        // 커밋 객체와 ref는 HEAD/브랜치와 무관
+        let commit = ops.commit_tree(&after, None, "ghost").unwrap();
+        ops.update_ref("refs/forge/test/1", &commit).unwrap();
+        assert_eq!(ops.rev_parse("refs/forge/test/1^{tree}").unwrap(), after);
+        assert_eq!(
+            ops.for_each_ref("refs/forge/test", "%(refname) %(subject)")
+                .unwrap(),
+            vec!["refs/forge/test/1 ghost".to_string()]
+        );
+        assert!(ops.head().is_err());
+
+        ops.delete_ref("refs/forge/test/1").unwrap();
+        assert!(ops.rev_parse("refs/forge/test/1").is_err());
+    }
 }
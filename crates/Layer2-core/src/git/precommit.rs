@@ -0,0 +1,165 @@
+//! Pre-commit Checks
+//!
+//! Runs the repository's `pre-commit` hook (honoring `core.hooksPath`) and any
+//! configured lint commands before an auto-commit. Each check's output is
+//! captured so failures can be fed back to the agent to fix before the commit
+//! is retried.
+//!
+//! ```ignore
+//! let report = git.run_pre_commit(true, &["cargo fmt --check".to_string()])?;
+//! if !report.passed() {
+//!     println!("{}", report.render());
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::process::Output;
+
+/// Name of the check that runs the repository's hook
+pub const PRE_COMMIT_HOOK: &str = "pre-commit hook";
+
+/// Output kept per failed check (the tail, where errors usually are)
+const MAX_CHECK_OUTPUT: usize = 4000;
+
+/// Result of one check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreCommitCheck {
+    /// [`PRE_COMMIT_HOOK`] or the lint command
+    pub name: String,
+    pub success: bool,
+    /// Exit code (None if killed by a signal)
+    pub exit_code: Option<i32>,
+    /// Combined stdout and stderr
+    pub output: String,
+}
+
+impl PreCommitCheck {
+    pub(crate) fn from_output(name: &str, output: &Output) -> Self {
+        let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !stderr.trim().is_empty() {
+            if !text.is_empty() && !text.ends_with('\n') {
+                text.push('\n');
+            }
+            text.push_str(&stderr);
+        }
+        Self {
+            name: name.to_string(),
+            success: output.status.success(),
+            exit_code: output.status.code(),
+            output: text.trim_end().to_string(),
+        }
+    }
+
+    /// Last [`MAX_CHECK_OUTPUT`] bytes of the output
+    fn output_tail(&self) -> &str {
+        if self.output.len() <= MAX_CHECK_OUTPUT {
+            return &self.output;
+        }
+        let mut start = self.output.len() - MAX_CHECK_OUTPUT;
+        while !self.output.is_char_boundary(start) {
+            start += 1;
+        }
+        &self.output[start..]
+    }
+}
+
+/// Outcome of all pre-commit checks
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreCommitReport {
+    pub checks: Vec<PreCommitCheck>,
+}
+
+impl PreCommitReport {
+    /// Whether every check passed (also true when nothing ran)
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.success)
+    }
+
+    /// Failed checks
+    pub fn failures(&self) -> impl Iterator<Item = &PreCommitCheck> {
+        self.checks.iter().filter(|c| !c.success)
+    }
+
+    /// Failed checks with their output, as feedback for fixing them
+    pub fn render(&self) -> String {
+        let mut out = format!("{}\n", self);
+        for check in self.failures() {
+            let status = match check.exit_code {
+                Some(code) => format!("exit code {}", code),
+                None => "killed by a signal".to_string(),
+            };
+            out.push_str(&format!("\n### {} ({})\n```\n", check.name, status));
+            if check.output_tail().len() < check.output.len() {
+                out.push_str("...\n");
+            }
+            out.push_str(check.output_tail());
+            out.push_str("\n```\n");
+        }
+        out
+    }
+}
+
+impl fmt::Display for PreCommitReport {
+    /// `2 of 3 pre-commit checks failed: pre-commit hook, cargo fmt --check`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failed: Vec<&str> = self.failures().map(|c| c.name.as_str()).collect();
+        if failed.is_empty() {
+            return write!(
+                f,
+                "{} pre-commit check{} passed",
+                self.checks.len(),
+                if self.checks.len() == 1 { "" } else { "s" }
+            );
+        }
+        write!(
+            f,
+            "{} of {} pre-commit check{} failed: {}",
+            failed.len(),
+            self.checks.len(),
+            if self.checks.len() == 1 { "" } else { "s" },
+            failed.join(", ")
+        )
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(name: &str, success: bool, output: &str) -> PreCommitCheck {
+        PreCommitCheck {
+            name: name.to_string(),
+            success,
+            exit_code: Some(if success { 0 } else { 1 }),
+            output: output.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_report_render() {
+        let report = PreCommitReport {
+            checks: vec![
+                check(PRE_COMMIT_HOOK, false, "trailing whitespace in a.rs"),
+                check("cargo fmt --check", true, ""),
+            ],
+        };
+        assert!(!report.passed());
+        assert_eq!(
+            report.to_string(),
+            "1 of 2 pre-commit checks failed: pre-commit hook"
+        );
+        let rendered = report.render();
+        assert!(rendered.contains("### pre-commit hook (exit code 1)\n```\ntrailing whitespace"));
+        assert!(!rendered.contains("cargo fmt"));
+
+        assert!(PreCommitReport::default().passed());
+        let long = check("lint", false, &"é".repeat(MAX_CHECK_OUTPUT));
+        assert!(long.output_tail().len() <= MAX_CHECK_OUTPUT);
+    }
+}
@@ -0,0 +1,309 @@
+//! Agent Workspaces
+//!
+//! Isolated git worktrees for agent runs. A run (especially a background or
+//! parallel one) works on its own branch in its own worktree, so the user's
+//! checked-out tree is never mutated. When the run finishes, its changes are
+//! committed to the branch and presented for review (diff, merge or PR).
+//!
+//! ```ignore
+//! let workspace = AgentWorkspace::create(&repo_dir, "fix login redirect")?;
+//! // ... run the agent with `workspace.path()` as its working directory ...
+//! let outcome = workspace.finish("Fix login redirect")?;
+//! println!("{}", outcome.summary());
+//! ```
+
+use super::ops::{DiffStat, GitError, GitOps};
+use std::path::{Path, PathBuf};
+
+/// Branch prefix for agent workspaces
+pub const WORKSPACE_BRANCH_PREFIX: &str = "forge/";
+
+/// Directory (inside the shared `.git` dir) that holds agent worktrees
+pub const WORKSPACE_DIR: &str = "forge-worktrees";
+
+/// Maximum length of the name part of a workspace branch
+const MAX_SLUG_LEN: usize = 40;
+
+/// An agent run's isolated worktree + branch
+pub struct AgentWorkspace {
+    /// Main repository
+    repo: GitOps,
+    /// The linked worktree
+    worktree: GitOps,
+    branch: String,
+    /// Commit the branch started from
+    base: String,
+    /// Branch checked out in the main tree when the workspace was created
+    base_branch: Option<String>,
+}
+
+impl AgentWorkspace {
+    /// Create a worktree on a new `forge/<name>-<id>` branch from the current HEAD
+    ///
+    /// The worktree lives in `<git common dir>/forge-worktrees/`, so it never
+    /// shows up in the user's `git status`.
+    pub fn create(repo_dir: &Path, name: &str) -> Result<Self, GitError> {
+        let repo = GitOps::new(repo_dir)?;
+        let base = repo.head()?;
+        let base_branch = repo.current_branch().ok().filter(|b| b != "HEAD");
+
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let dir_name = format!("{}-{}", slugify(name), &id[..8]);
+        let branch = format!("{}{}", WORKSPACE_BRANCH_PREFIX, dir_name);
+        let path = repo.common_dir()?.join(WORKSPACE_DIR).join(&dir_name);
+
+        let worktree = repo.create_worktree(&path, &branch, &base)?;
+        Ok(Self {
+            repo,
+            worktree,
+            branch,
+            base,
+            base_branch,
+        })
+    }
+
+    /// Working directory for the agent run
+    pub fn path(&self) -> &Path {
+        self.worktree.root()
+    }
+
+    /// Workspace branch
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    /// Commit the branch started from
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Commit remaining changes, remove the worktree and report the branch
+    ///
+    /// The branch is kept when it has commits; otherwise it is deleted too.
+    pub fn finish(self, message: &str) -> Result<WorkspaceOutcome, GitError> {
+        if self.worktree.is_dirty()? {
+            self.worktree.commit_all(message)?;
+        }
+
+        let commits = self.repo.count_commits(&self.base, &self.branch)?;
+        let stat = self.repo.diff_stat(&self.base, &self.branch)?;
+
+        // Everything worth keeping is committed; leftovers are build artifacts
+        self.repo.remove_worktree(self.worktree.root(), true)?;
+        if commits == 0 {
+            self.repo.delete_branch(&self.branch, true)?;
+        }
+
+        Ok(WorkspaceOutcome {
+            branch: (commits > 0).then_some(self.branch),
+            base: self.base,
+            base_branch: self.base_branch,
+            commits,
+            stat,
+        })
+    }
+
+    /// Throw the workspace away (worktree and branch)
+    pub fn discard(self) -> Result<(), GitError> {
+        self.repo.remove_worktree(self.worktree.root(), true)?;
+        self.repo.delete_branch(&self.branch, true)
+    }
+
+    /// Agent workspaces of a repository that are still checked out
+    pub fn list(repo_dir: &Path) -> Result<Vec<PathBuf>, GitError> {
+        let repo = GitOps::new(repo_dir)?;
+        Ok(repo
+            .list_worktrees()?
+            .into_iter()
+            .filter(|wt| {
+                wt.branch
+                    .as_deref()
+                    .is_some_and(|b| b.starts_with(WORKSPACE_BRANCH_PREFIX))
+            })
+            .map(|wt| wt.path)
+            .collect())
+    }
+}
+
+/// Result of a finished agent workspace
+#[derive(Debug, Clone)]
+pub struct WorkspaceOutcome {
+    /// Branch with the work (None when the run changed nothing)
+    pub branch: Option<String>,
+    /// Commit the branch started from
+    pub base: String,
+    /// Branch checked out when the workspace was created
+    pub base_branch: Option<String>,
+    /// Commits on the branch
+    pub commits: usize,
+    /// Changes relative to `base`
+    pub stat: DiffStat,
+}
+
+impl WorkspaceOutcome {
+    /// The run produced changes
+    pub fn has_changes(&self) -> bool {
+        self.branch.is_some()
+    }
+
+    /// Human-readable summary with review/merge/PR commands
+    pub fn summary(&self) -> String {
+        let Some(branch) = &self.branch else {
+            return "No changes; workspace removed".to_string();
+        };
+
+        let base = self
+            .base_branch
+            .clone()
+            .unwrap_or_else(|| self.base.chars().take(12).collect());
+        let files = self.stat.files.len();
+        [
+            format!(
+                "Branch {}: {} commit{}, {} file{} changed (+{} -{})",
+                branch,
+                self.commits,
+                if self.commits == 1 { "" } else { "s" },
+                files,
+                if files == 1 { "" } else { "s" },
+                self.stat.additions(),
+                self.stat.deletions()
+            ),
+            format!("  review: git diff {}...{}", base, branch),
+            format!("  merge:  git merge {}", branch),
+            format!(
+                "  PR:     git push -u origin {} && gh pr create --head {}",
+                branch, branch
+            ),
+        ]
+        .join("\n")
+    }
+}
+
+/// Branch-safe name (lowercase ASCII alphanumerics separated by `-`)
+fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= MAX_SLUG_LEN {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "run".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn init_repo(dir: &Path) -> bool {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .is_ok_and(|o| o.status.success())
+        };
+        git(&["init", "-q", "-b", "main"])
+            && git(&["config", "user.email", "forge@example.com"])
+            && git(&["config", "user.name", "Forge"])
+            && std::fs::write(dir.join("a.txt"), "one\n").is_ok()
+            && git(&["add", "a.txt"])
+            && git(&["commit", "-q", "-m", "init"])
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fix login redirect!"), "fix-login-redirect");
+        assert_eq!(slugify("  --  "), "run");
+        assert_eq!(slugify("한글 only"), "only");
+        assert!(slugify(&"x".repeat(100)).len() <= MAX_SLUG_LEN);
+    }
+
+    #[test]
+    fn test_parse_worktree_porcelain() {
+        let output = "worktree /repo\nHEAD abc123\nbranch refs/heads/main\n\n\
+                      worktree /repo/.git/forge-worktrees/fix-1\nHEAD def456\nbranch refs/heads/forge/fix-1\nlocked\n\n\
+                      worktree /tmp/gone\nHEAD 789\ndetached\nprunable gitdir file points to non-existent location\n";
+        let worktrees = super::super::ops::WorktreeInfo::parse_porcelain(output);
+        assert_eq!(worktrees.len(), 3);
+        assert_eq!(worktrees[0].branch.as_deref(), Some("main"));
+        assert_eq!(worktrees[1].branch.as_deref(), Some("forge/fix-1"));
+        assert!(worktrees[1].locked);
+        assert!(worktrees[2].branch.is_none());
+        assert!(worktrees[2].prunable);
+    }
+
+    #[test]
+    fn test_workspace_keeps_user_tree_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        if !init_repo(dir.path()) {
+            return;
+        }
+
+        let workspace = AgentWorkspace::create(dir.path(), "Update a").unwrap();
+        assert!(workspace.branch().starts_with("forge/update-a-"));
+        assert_eq!(
+            AgentWorkspace::list(dir.path()).unwrap(),
+            vec![workspace.path().to_path_buf()]
+        );
+
+        std::fs::write(workspace.path().join("a.txt"), "one\ntwo\n").unwrap();
+        std::fs::write(workspace.path().join("b.txt"), "new\n").unwrap();
+        let branch = workspace.branch().to_string();
+        let path = workspace.path().to_path_buf();
+
+        let outcome = workspace.finish("Update a").unwrap();
+        assert_eq!(outcome.branch.as_deref(), Some(branch.as_str()));
+        assert_eq!(outcome.commits, 1);
+        assert_eq!(outcome.stat.files.len(), 2);
+        assert!(outcome
+            .summary()
+            .contains(&format!("git diff main...{}", branch)));
+
+        // 사용자 작업 트리는 그대로, 작업은 브랜치에만 존재
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("a.txt")).unwrap(),
+            "one\n"
+        );
+        assert!(!path.exists());
+        let repo = GitOps::new(dir.path()).unwrap();
+        assert!(repo.is_dirty().is_ok_and(|dirty| !dirty));
+        assert!(repo.branch_exists(&branch));
+        assert!(AgentWorkspace::list(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_workspace_without_changes_is_cleaned_up() {
+        let dir = tempfile::tempdir().unwrap();
+        if !init_repo(dir.path()) {
+            return;
+        }
+
+        let workspace = AgentWorkspace::create(dir.path(), "noop").unwrap();
+        let branch = workspace.branch().to_string();
+        let outcome = workspace.finish("noop").unwrap();
+        assert!(!outcome.has_changes());
+        assert_eq!(outcome.summary(), "No changes; workspace removed");
+        assert!(!GitOps::new(dir.path()).unwrap().branch_exists(&branch));
+
+        let workspace = AgentWorkspace::create(dir.path(), "discard me").unwrap();
+        std::fs::write(workspace.path().join("a.txt"), "changed\n").unwrap();
+        let branch = workspace.branch().to_string();
+        workspace.discard().unwrap();
+        assert!(!GitOps::new(dir.path()).unwrap().branch_exists(&branch));
+    }
+}
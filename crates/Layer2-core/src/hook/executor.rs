@@ -3,7 +3,10 @@
 //! Hook 액션을 실행하고 결과를 반환합니다.
 //! Prompt와 Agent 액션은 콜백을 통해 Layer3-agent에서 처리됩니다.
 
-use super::types::{BlockReason, HookAction, HookConfig, HookEvent, HookOutcome, HookResult};
+use super::types::{
+    BlockReason, HookAction, HookConfig, HookDecision, HookEvent, HookEventType, HookMutation,
+    HookOutcome, HookPayload, HookResult,
+};
 use std::collections::HashMap;
 use std::future::Future;
 use std::path::PathBuf;
@@ -11,10 +14,14 @@ use std::pin::Pin;
 use std::process::Stdio;
 use std::sync::Arc;
 use std::time::Instant;
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
+/// Hook 명령이 Tool 실행을 차단할 때 사용하는 종료 코드 (Claude Code 호환)
+const BLOCK_EXIT_CODE: i32 = 2;
+
 // ============================================================================
 // Prompt/Agent 액션 타입
 // ============================================================================
@@ -252,8 +259,18 @@ impl HookContext {
     fn setup_event_env(&self, event: &HookEvent) -> HashMap<String, String> {
         let mut env = self.env.clone();
 
+        // 메타데이터 (trigger, agent_type, message 등)
+        for (key, value) in &event.metadata {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            env.insert(format!("HOOK_{}", key.to_uppercase()), value);
+        }
+
         // 이벤트 타입
         env.insert("HOOK_EVENT_TYPE".to_string(), event.event_type.to_string());
+        env.insert("HOOK_SESSION_ID".to_string(), self.session_id.clone());
 
         // Tool 관련
         if let Some(ref tool_name) = event.tool_name {
@@ -415,22 +432,39 @@ impl HookExecutor {
         let timeout =
             std::time::Duration::from_secs((timeout_secs as f64 * ctx.timeout_multiplier) as u64);
 
+        let payload = HookPayload::new(
+            event,
+            ctx.session_id.as_str(),
+            ctx.working_dir.display().to_string(),
+        );
+        let command = render_command(command, &payload);
+        let input = serde_json::to_string(&payload).unwrap_or_default();
+
         debug!("Executing hook command: {}", command);
 
-        // Shell 명령 실행
+        // Shell 명령 실행 (이벤트 JSON은 stdin으로 전달)
         let shell = if cfg!(windows) { "cmd" } else { "sh" };
         let shell_arg = if cfg!(windows) { "/C" } else { "-c" };
 
         let result = tokio::time::timeout(timeout, async {
-            Command::new(shell)
+            let mut child = Command::new(shell)
                 .arg(shell_arg)
-                .arg(command)
+                .arg(&command)
                 .current_dir(&ctx.working_dir)
                 .envs(&env)
+                .stdin(Stdio::piped())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
-                .output()
-                .await
+                .spawn()?;
+
+            // stdin을 읽지 않는 명령도 있으므로 쓰기 실패는 무시
+            if let Some(mut stdin) = child.stdin.take() {
+                tokio::spawn(async move {
+                    let _ = stdin.write_all(input.as_bytes()).await;
+                });
+            }
+
+            child.wait_with_output().await
         })
         .await;
 
@@ -443,7 +477,23 @@ impl HookExecutor {
 
                 if output.status.success() {
                     debug!("Hook command succeeded: {}", stdout.trim());
-                    HookResult::success(stdout, duration)
+                    match parse_hook_output(event, &stdout) {
+                        Ok(mutations) => mutations.into_iter().fold(
+                            HookResult::success(stdout, duration),
+                            HookResult::with_mutation,
+                        ),
+                        Err(reason) => {
+                            warn!("Hook command denied: {}", reason.reason);
+                            HookResult::blocked(reason, duration)
+                        }
+                    }
+                } else if output.status.code() == Some(BLOCK_EXIT_CODE) {
+                    // 종료 코드 2는 blocking 설정과 무관하게 차단 (stderr가 사유)
+                    warn!("Hook command blocked: {}", stderr.trim());
+                    HookResult::blocked(
+                        BlockReason::new("Blocked by hook").with_details(stderr.trim()),
+                        duration,
+                    )
                 } else {
                     let error_msg = if stderr.is_empty() {
                         format!("Command failed with exit code: {:?}", output.status.code())
@@ -685,6 +735,199 @@ impl HookExecutor {
         let event = HookEvent::session_stop();
         self.execute(&event, ctx).await
     }
+
+    /// 이벤트의 Hook을 순서대로 실행하며 판정 누적
+    ///
+    /// 각 Hook은 앞선 Hook이 수정한 이벤트(Tool 입력, 프롬프트)를 받습니다.
+    /// 어느 Hook이든 차단하면 `Err`를 반환합니다.
+    pub async fn decide(
+        &self,
+        mut event: HookEvent,
+        ctx: &HookContext,
+    ) -> Result<HookDecision, BlockReason> {
+        let mut additional_context = Vec::new();
+
+        for matcher in self.config.matchers_for(event.event_type) {
+            if !matcher.matches(&event) {
+                continue;
+            }
+
+            for action in &matcher.hooks {
+                let result = self.execute_action(action, &event, ctx).await;
+                if let HookOutcome::Blocked(reason) = result.outcome {
+                    return Err(reason);
+                }
+
+                for mutation in result.mutations() {
+                    debug!("Hook '{}' applied {:?}", matcher.matcher, mutation);
+                    match mutation {
+                        HookMutation::Context(context) => additional_context.push(context.clone()),
+                        other => event.apply(other),
+                    }
+                }
+            }
+        }
+
+        Ok(HookDecision {
+            event,
+            additional_context,
+        })
+    }
+
+    /// PreToolUse Hook 실행 및 Tool 입력 수정 적용
+    pub async fn run_pre_tool_use(
+        &self,
+        tool_name: &str,
+        input: serde_json::Value,
+        ctx: &HookContext,
+    ) -> Result<HookDecision, BlockReason> {
+        self.decide(HookEvent::pre_tool_use(tool_name, input), ctx)
+            .await
+    }
+
+    /// PromptSubmit Hook 실행 및 프롬프트 수정 적용
+    ///
+    /// 블로킹되면 `Err`를 반환하며 프롬프트는 제출되지 않아야 합니다.
+    /// Hook이 추가한 컨텍스트가 필요하면 [`HookExecutor::decide`]를 사용합니다.
+    pub async fn run_prompt_submit(
+        &self,
+        prompt: &str,
+        ctx: &HookContext,
+    ) -> Result<String, BlockReason> {
+        let decision = self.decide(HookEvent::prompt_submit(prompt), ctx).await?;
+        Ok(decision.prompt().unwrap_or(prompt).to_string())
+    }
+
+    /// PreCompact Hook 실행 (컨텍스트 압축 직전)
+    pub async fn run_pre_compact(&self, trigger: &str, ctx: &HookContext) -> Vec<HookResult> {
+        let event = HookEvent::pre_compact(trigger);
+        self.execute(&event, ctx).await
+    }
+
+    /// SubagentStop Hook 실행
+    pub async fn run_subagent_stop(
+        &self,
+        agent_type: &str,
+        agent_id: &str,
+        ctx: &HookContext,
+    ) -> Vec<HookResult> {
+        let event = HookEvent::subagent_stop(agent_type, agent_id);
+        self.execute(&event, ctx).await
+    }
+
+    /// Notification Hook 실행
+    pub async fn run_notification(
+        &self,
+        kind: &str,
+        message: &str,
+        ctx: &HookContext,
+    ) -> Vec<HookResult> {
+        let event = HookEvent::notification(kind, message);
+        self.execute(&event, ctx).await
+    }
+}
+
+/// Command 템플릿 변수 치환
+///
+/// `{{tool_name}}`, `{{file_path}}`, `{{session_id}}` 등을 [`HookPayload::variable`] 값으로
+/// 치환합니다. 값은 셸 인용되어 삽입되므로 템플릿에서 따로 따옴표로 감쌀 필요가 없으며,
+/// 값이 없는 변수는 빈 문자열이 됩니다.
+fn render_command(template: &str, payload: &HookPayload) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+
+        rendered.push_str(&rest[..start]);
+        let name = rest[start + 2..start + 2 + len].trim();
+        if let Some(value) = payload.variable(name) {
+            rendered.push_str(&shell_quote(&value));
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+
+    rendered.push_str(rest);
+    rendered
+}
+
+/// 셸 인자로 안전하게 인용
+fn shell_quote(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+".contains(c));
+
+    if plain {
+        value.to_string()
+    } else if cfg!(windows) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+/// Hook 명령 stdout의 JSON 판정 해석
+///
+/// stdout 전체가 JSON 객체일 때만 해석하며, 그 외 출력은 일반 로그로 취급합니다.
+/// Claude Code의 `hookSpecificOutput` 형식과 최상위 필드를 모두 허용합니다.
+///
+/// - `decision` / `permissionDecision`이 `block` 또는 `deny`, 또는 `continue: false` → 차단
+/// - `updatedInput` / `tool_input` → Tool 입력 교체 (PreToolUse)
+/// - `prompt` → 프롬프트 교체 (PromptSubmit)
+/// - `additionalContext` → 모델 컨텍스트 추가
+fn parse_hook_output(event: &HookEvent, stdout: &str) -> Result<Vec<HookMutation>, BlockReason> {
+    let Ok(serde_json::Value::Object(output)) = serde_json::from_str(stdout.trim()) else {
+        return Ok(Vec::new());
+    };
+
+    let specific = output
+        .get("hookSpecificOutput")
+        .and_then(serde_json::Value::as_object);
+    let field = |keys: &[&str]| {
+        keys.iter().find_map(|key| {
+            specific
+                .and_then(|s| s.get(*key))
+                .or_else(|| output.get(*key))
+        })
+    };
+    let text = |keys: &[&str]| field(keys).and_then(serde_json::Value::as_str);
+
+    if matches!(
+        text(&["permissionDecision", "decision"]),
+        Some("block" | "deny")
+    ) {
+        let reason = text(&["permissionDecisionReason", "reason"]).unwrap_or("Denied by hook");
+        return Err(BlockReason::new(reason));
+    }
+
+    if output.get("continue").and_then(serde_json::Value::as_bool) == Some(false) {
+        let reason = text(&["stopReason"]).unwrap_or("Stopped by hook");
+        return Err(BlockReason::new(reason));
+    }
+
+    let mut mutations = Vec::new();
+    match event.event_type {
+        HookEventType::PreToolUse => {
+            if let Some(input) = field(&["updatedInput", "tool_input"]).filter(|v| v.is_object()) {
+                mutations.push(HookMutation::ToolInput(input.clone()));
+            }
+        }
+        HookEventType::PromptSubmit => {
+            if let Some(prompt) = text(&["prompt"]) {
+                mutations.push(HookMutation::Prompt(prompt.to_string()));
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(context) = text(&["additionalContext", "additional_context"]) {
+        mutations.push(HookMutation::Context(context.to_string()));
+    }
+
+    Ok(mutations)
 }
 
 impl Default for HookExecutor {
@@ -924,4 +1167,230 @@ mod tests {
         assert!(received.is_ok());
         assert_eq!(received.unwrap().prompt, "Queued prompt");
     }
+
+    #[test]
+    fn test_render_command() {
+        let event = HookEvent::post_tool_use("edit", serde_json::json!({}), "ok");
+        let mut payload = HookPayload::new(&event, "session-1", "/work");
+        payload.file_path = Some("src/it's here.rs".to_string());
+
+        let rendered = render_command(
+            "fmt {{file_path}} --tool={{ tool_name }} {{session_id}}{{missing}} {{",
+            &payload,
+        );
+
+        let quoted = if cfg!(windows) {
+            r#""src/it's here.rs""#
+        } else {
+            r#"'src/it'\''s here.rs'"#
+        };
+        assert_eq!(
+            rendered,
+            format!("fmt {} --tool=edit session-1 {{{{", quoted)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_command_templating_and_stdin() {
+        let cmd = if cfg!(windows) {
+            "echo {{tool_name}} {{session_id}} && more"
+        } else {
+            "echo {{tool_name}} {{session_id}}; cat"
+        };
+
+        let mut config = HookConfig::new();
+        config
+            .pre_tool_use
+            .push(HookMatcher::new("bash").with_action(HookAction::command(cmd)));
+
+        let executor = HookExecutor::new(config);
+        let event = HookEvent::pre_tool_use("bash", serde_json::json!({"command": "ls"}));
+        let ctx = test_context();
+
+        let results = executor.execute(&event, &ctx).await;
+        assert_eq!(results.len(), 1);
+
+        let output = results[0].output.as_ref().unwrap();
+        assert!(output.contains("bash test-session"));
+
+        // stdin으로 받은 이벤트 JSON
+        let payload: HookPayload =
+            serde_json::from_str(output.lines().last().unwrap().trim()).unwrap();
+        assert_eq!(payload.session_id, "test-session");
+        assert_eq!(payload.hook_event_name, "PreToolUse");
+        assert_eq!(
+            payload.tool_input,
+            Some(serde_json::json!({"command": "ls"}))
+        );
+    }
+
+    #[test]
+    fn test_parse_hook_output() {
+        let event = HookEvent::pre_tool_use("bash", serde_json::json!({"command": "rm -rf /"}));
+
+        // 일반 출력은 판정 없음
+        assert!(parse_hook_output(&event, "formatted 3 files")
+            .unwrap()
+            .is_empty());
+
+        let denied = parse_hook_output(&event, r#"{"decision": "block", "reason": "dangerous"}"#);
+        assert_eq!(denied.unwrap_err().reason, "dangerous");
+
+        let stopped = parse_hook_output(&event, r#"{"continue": false, "stopReason": "halt"}"#);
+        assert_eq!(stopped.unwrap_err().reason, "halt");
+
+        // Claude Code hookSpecificOutput 형식
+        let output = r#"{"hookSpecificOutput": {
+            "hookEventName": "PreToolUse",
+            "permissionDecision": "allow",
+            "updatedInput": {"command": "ls"},
+            "additionalContext": "rewritten"
+        }}"#;
+        assert_eq!(
+            parse_hook_output(&event, output).unwrap(),
+            vec![
+                HookMutation::ToolInput(serde_json::json!({"command": "ls"})),
+                HookMutation::Context("rewritten".to_string()),
+            ]
+        );
+
+        // 이벤트와 맞지 않는 수정은 만들지 않음
+        let post = HookEvent::post_tool_use("bash", serde_json::json!({}), "");
+        assert!(parse_hook_output(&post, r#"{"tool_input": {"a": 1}}"#)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_exit_code_two_blocks() {
+        let cmd = if cfg!(windows) {
+            "echo not allowed 1>&2 && exit /b 2"
+        } else {
+            "echo not allowed >&2; exit 2"
+        };
+
+        let mut config = HookConfig::new();
+        config
+            .pre_tool_use
+            .push(HookMatcher::new("bash").with_action(HookAction::command(cmd)));
+
+        let executor = HookExecutor::new(config);
+        let ctx = test_context();
+
+        // blocking 플래그 없이도 종료 코드 2는 차단
+        let reason = executor
+            .check_pre_tool_use("bash", serde_json::json!({}), &ctx)
+            .await
+            .unwrap_err();
+        assert_eq!(reason.details.as_deref(), Some("not allowed"));
+    }
+
+    #[tokio::test]
+    async fn test_pre_tool_use_rewrites_input() {
+        let rewrite = if cfg!(windows) {
+            r#"echo {"tool_input": {"command": "ls -la"}}"#
+        } else {
+            r#"echo '{"tool_input": {"command": "ls -la"}}'"#
+        };
+        let context = if cfg!(windows) {
+            r#"echo {"additionalContext": "listing files"}"#
+        } else {
+            r#"echo '{"additionalContext": "listing files"}'"#
+        };
+
+        let mut config = HookConfig::new();
+        config.pre_tool_use.push(
+            HookMatcher::new("bash")
+                .with_action(HookAction::command(rewrite))
+                .with_action(HookAction::command(context)),
+        );
+
+        let executor = HookExecutor::new(config);
+        let ctx = test_context();
+
+        let decision = executor
+            .run_pre_tool_use("bash", serde_json::json!({"command": "ls"}), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            decision.tool_input(),
+            Some(&serde_json::json!({"command": "ls -la"}))
+        );
+        assert_eq!(decision.additional_context, vec!["listing files"]);
+    }
+
+    #[tokio::test]
+    async fn test_prompt_submit_mutation() {
+        let cmd = if cfg!(windows) {
+            r#"echo {"prompt": "rewritten"}"#
+        } else {
+            r#"echo '{"prompt": "rewritten"}'"#
+        };
+
+        let mut config = HookConfig::new();
+        config
+            .prompt_submit
+            .push(HookMatcher::new("*").with_action(HookAction::command("echo plain log")));
+        config
+            .prompt_submit
+            .push(HookMatcher::new("*").with_action(HookAction::command(cmd)));
+
+        let executor = HookExecutor::new(config);
+        let ctx = test_context();
+
+        let prompt = executor.run_prompt_submit("original", &ctx).await.unwrap();
+        assert_eq!(prompt, "rewritten");
+
+        // 블로킹 Hook 실패 시 프롬프트 제출 차단
+        let block = if cfg!(windows) { "exit /b 1" } else { "exit 1" };
+        let mut config = HookConfig::new();
+        config
+            .prompt_submit
+            .push(HookMatcher::new("*").with_action(HookAction::blocking_command(block)));
+        let executor = HookExecutor::new(config);
+        assert!(executor.run_prompt_submit("original", &ctx).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_extended_events_execute() {
+        let cmd = if cfg!(windows) {
+            "echo %HOOK_MESSAGE%"
+        } else {
+            "echo $HOOK_MESSAGE"
+        };
+
+        let mut config = HookConfig::new();
+        config
+            .notification
+            .push(HookMatcher::new("idle").with_action(HookAction::command(cmd)));
+        config
+            .pre_compact
+            .push(HookMatcher::new("manual").with_action(HookAction::notify("compacting")));
+        config
+            .subagent_stop
+            .push(HookMatcher::new("*").with_action(HookAction::notify("subagent done")));
+
+        let executor = HookExecutor::new(config);
+        let ctx = test_context();
+
+        let results = executor
+            .run_notification("idle", "waiting for input", &ctx)
+            .await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0]
+            .output
+            .as_ref()
+            .unwrap()
+            .contains("waiting for input"));
+
+        assert!(executor.run_pre_compact("auto", &ctx).await.is_empty());
+        assert_eq!(executor.run_pre_compact("manual", &ctx).await.len(), 1);
+        assert_eq!(
+            executor
+                .run_subagent_stop("explore", "agent-1", &ctx)
+                .await
+                .len(),
+            1
+        );
+    }
 }
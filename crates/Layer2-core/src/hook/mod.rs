@@ -13,7 +13,10 @@
 //! - `PostToolUse`: Tool 실행 후
 //! - `SessionStart`: 세션 시작 시
 //! - `SessionStop`: 세션 종료 시
-//! - `PromptSubmit`: 프롬프트 제출 시
+//! - `PromptSubmit`: 프롬프트 제출 시 (`UserPromptSubmit`, 프롬프트 수정 가능)
+//! - `PreCompact`: 컨텍스트 압축 직전
+//! - `SubagentStop`: Subagent 종료 시
+//! - `Notification`: 사용자 알림 발생 시
 //!
 //! ## 액션 타입
 //!
@@ -21,6 +24,9 @@
 //! - `prompt`: LLM에게 프롬프트 전달
 //! - `agent`: Subagent 실행
 //!
+//! `command` 액션은 이벤트 JSON([`HookPayload`])을 stdin으로 받고,
+//! 명령 문자열의 `{{tool_name}}`, `{{file_path}}`, `{{session_id}}` 등을 치환합니다.
+//!
 //! ## 예시
 //!
 //! ```ignore
@@ -47,6 +53,6 @@ pub use executor::{
 };
 pub use loader::{load_hooks_from_dir, load_hooks_from_file, HookLoader};
 pub use types::{
-    BlockReason, HookAction, HookConfig, HookEvent, HookEventType, HookMatcher, HookOutcome,
-    HookResult,
+    BlockReason, HookAction, HookConfig, HookDecision, HookEvent, HookEventType, HookMatcher,
+    HookMutation, HookOutcome, HookPayload, HookResult,
 };
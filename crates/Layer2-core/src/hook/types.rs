@@ -2,6 +2,7 @@
 //!
 //! Claude Code 호환 Hook 타입 시스템
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -11,7 +12,7 @@ use std::collections::HashMap;
 // ============================================================================
 
 /// Hook 이벤트 타입 (Claude Code 호환)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum HookEventType {
     /// Tool 실행 전 (블로킹 가능)
     PreToolUse,
@@ -27,8 +28,8 @@ pub enum HookEventType {
     #[serde(alias = "session_stop")]
     SessionStop,
 
-    /// 프롬프트 제출
-    #[serde(alias = "prompt_submit")]
+    /// 프롬프트 제출 (Claude Code의 `UserPromptSubmit`, 프롬프트 수정 가능)
+    #[serde(alias = "prompt_submit", alias = "UserPromptSubmit")]
     PromptSubmit,
 
     /// 에이전트 완료
@@ -38,6 +39,18 @@ pub enum HookEventType {
     /// 파일 변경
     #[serde(alias = "file_changed")]
     FileChanged,
+
+    /// 컨텍스트 압축 전 (matcher: `manual` / `auto`)
+    #[serde(alias = "pre_compact")]
+    PreCompact,
+
+    /// Subagent 종료 (matcher: agent 타입)
+    #[serde(alias = "subagent_stop")]
+    SubagentStop,
+
+    /// 사용자 알림 (matcher: 알림 종류)
+    #[serde(alias = "notification")]
+    Notification,
 }
 
 impl std::fmt::Display for HookEventType {
@@ -50,6 +63,9 @@ impl std::fmt::Display for HookEventType {
             Self::PromptSubmit => write!(f, "PromptSubmit"),
             Self::AgentComplete => write!(f, "AgentComplete"),
             Self::FileChanged => write!(f, "FileChanged"),
+            Self::PreCompact => write!(f, "PreCompact"),
+            Self::SubagentStop => write!(f, "SubagentStop"),
+            Self::Notification => write!(f, "Notification"),
         }
     }
 }
@@ -166,6 +182,87 @@ impl HookEvent {
         }
     }
 
+    /// PreCompact 이벤트 생성
+    ///
+    /// `trigger`는 압축 계기입니다 (`manual`: 사용자 요청, `auto`: 토큰 한도 도달).
+    pub fn pre_compact(trigger: impl Into<String>) -> Self {
+        Self {
+            event_type: HookEventType::PreCompact,
+            tool_name: None,
+            tool_input: None,
+            tool_output: None,
+            file_path: None,
+            prompt: None,
+            metadata: HashMap::new(),
+        }
+        .with_metadata("trigger", Value::String(trigger.into()))
+    }
+
+    /// SubagentStop 이벤트 생성
+    pub fn subagent_stop(agent_type: impl Into<String>, agent_id: impl Into<String>) -> Self {
+        Self {
+            event_type: HookEventType::SubagentStop,
+            tool_name: None,
+            tool_input: None,
+            tool_output: None,
+            file_path: None,
+            prompt: None,
+            metadata: HashMap::new(),
+        }
+        .with_metadata("agent_type", Value::String(agent_type.into()))
+        .with_metadata("agent_id", Value::String(agent_id.into()))
+    }
+
+    /// Notification 이벤트 생성
+    ///
+    /// `kind`는 알림 종류입니다 (예: `permission_prompt`, `idle`).
+    pub fn notification(kind: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            event_type: HookEventType::Notification,
+            tool_name: None,
+            tool_input: None,
+            tool_output: None,
+            file_path: None,
+            prompt: None,
+            metadata: HashMap::new(),
+        }
+        .with_metadata("notification_type", Value::String(kind.into()))
+        .with_metadata("message", Value::String(message.into()))
+    }
+
+    /// Hook 수정 적용
+    ///
+    /// 이벤트에 맞지 않는 수정(예: PostToolUse의 ToolInput)은 무시합니다.
+    pub fn apply(&mut self, mutation: &HookMutation) {
+        match (self.event_type, mutation) {
+            (HookEventType::PromptSubmit, HookMutation::Prompt(prompt)) => {
+                self.prompt = Some(prompt.clone());
+            }
+            (HookEventType::PreToolUse, HookMutation::ToolInput(input)) => {
+                self.tool_input = Some(input.clone());
+            }
+            _ => {}
+        }
+    }
+
+    /// 매처가 비교할 대상
+    ///
+    /// Tool 이벤트는 tool 이름, PreCompact는 trigger, SubagentStop은 agent 타입,
+    /// Notification은 알림 종류를 사용합니다.
+    pub fn match_target(&self) -> Option<&str> {
+        if let Some(ref tool_name) = self.tool_name {
+            return Some(tool_name);
+        }
+
+        let key = match self.event_type {
+            HookEventType::PreCompact => "trigger",
+            HookEventType::SubagentStop => "agent_type",
+            HookEventType::Notification => "notification_type",
+            _ => return None,
+        };
+        self.metadata.get(key).and_then(Value::as_str)
+    }
+
     /// 메타데이터 추가
     pub fn with_metadata(mut self, key: impl Into<String>, value: Value) -> Self {
         self.metadata.insert(key.into(), value);
@@ -173,12 +270,103 @@ impl HookEvent {
     }
 }
 
+// ============================================================================
+// HookPayload - Command Hook 입력
+// ============================================================================
+
+/// Command Hook의 stdin으로 전달되는 이벤트 JSON
+///
+/// 값이 없는 필드는 생략되며, 이벤트 메타데이터(`trigger`, `agent_type`,
+/// `notification_type`, `message` 등)는 최상위 필드로 펼쳐집니다.
+///
+/// ```json
+/// {
+///   "session_id": "abc123",
+///   "cwd": "/path/to/project",
+///   "hook_event_name": "PreToolUse",
+///   "tool_name": "bash",
+///   "tool_input": {"command": "npm install"}
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HookPayload {
+    /// 세션 ID
+    pub session_id: String,
+
+    /// 작업 디렉토리
+    pub cwd: String,
+
+    /// 이벤트 이름 (`PreToolUse`, `PreCompact` 등)
+    pub hook_event_name: String,
+
+    /// Tool 이름 (Tool 이벤트)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
+
+    /// Tool 입력 파라미터 (Tool 이벤트)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_input: Option<Value>,
+
+    /// Tool 결과 (PostToolUse)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_response: Option<String>,
+
+    /// 파일 경로 (FileChanged)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+
+    /// 프롬프트 (PromptSubmit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+
+    /// 이벤트 메타데이터
+    #[serde(flatten)]
+    pub metadata: HashMap<String, Value>,
+}
+
+impl HookPayload {
+    /// 이벤트와 실행 컨텍스트로 페이로드 생성
+    pub fn new(event: &HookEvent, session_id: impl Into<String>, cwd: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            cwd: cwd.into(),
+            hook_event_name: event.event_type.to_string(),
+            tool_name: event.tool_name.clone(),
+            tool_input: event.tool_input.clone(),
+            tool_response: event.tool_output.clone(),
+            file_path: event.file_path.clone(),
+            prompt: event.prompt.clone(),
+            metadata: event.metadata.clone(),
+        }
+    }
+
+    /// 템플릿 변수 값 조회
+    ///
+    /// `tool_input`처럼 문자열이 아닌 값은 JSON 문자열로 반환합니다.
+    pub fn variable(&self, name: &str) -> Option<String> {
+        match name {
+            "session_id" => Some(self.session_id.clone()),
+            "cwd" => Some(self.cwd.clone()),
+            "hook_event_name" => Some(self.hook_event_name.clone()),
+            "tool_name" => self.tool_name.clone(),
+            "tool_input" => self.tool_input.as_ref().map(Value::to_string),
+            "tool_response" => self.tool_response.clone(),
+            "file_path" => self.file_path.clone(),
+            "prompt" => self.prompt.clone(),
+            other => self.metadata.get(other).map(|value| match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            }),
+        }
+    }
+}
+
 // ============================================================================
 // HookMatcher - 매칭 패턴
 // ============================================================================
 
 /// Hook 매처 (어떤 이벤트에 반응할지)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct HookMatcher {
     /// 매칭 패턴 (tool 이름, "*", 또는 glob 패턴)
     pub matcher: String,
@@ -208,10 +396,10 @@ impl HookMatcher {
             return true;
         }
 
-        match &event.tool_name {
+        match event.match_target() {
             Some(tool_name) => {
                 // 정확한 매칭
-                if self.matcher == *tool_name {
+                if self.matcher == tool_name {
                     return true;
                 }
 
@@ -238,7 +426,7 @@ impl HookMatcher {
 // ============================================================================
 
 /// Hook 액션 (실행할 내용)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type")]
 pub enum HookAction {
     /// Shell 명령어 실행
@@ -350,7 +538,7 @@ impl HookAction {
 // ============================================================================
 
 /// Hook 설정 (hooks.json 형식)
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct HookConfig {
     /// PreToolUse 매처들
     #[serde(rename = "PreToolUse", default)]
@@ -368,8 +556,8 @@ pub struct HookConfig {
     #[serde(rename = "SessionStop", default)]
     pub session_stop: Vec<HookMatcher>,
 
-    /// PromptSubmit 매처들
-    #[serde(rename = "PromptSubmit", default)]
+    /// PromptSubmit 매처들 (`UserPromptSubmit` 키도 허용)
+    #[serde(rename = "PromptSubmit", alias = "UserPromptSubmit", default)]
     pub prompt_submit: Vec<HookMatcher>,
 
     /// AgentComplete 매처들
@@ -379,6 +567,18 @@ pub struct HookConfig {
     /// FileChanged 매처들
     #[serde(rename = "FileChanged", default)]
     pub file_changed: Vec<HookMatcher>,
+
+    /// PreCompact 매처들
+    #[serde(rename = "PreCompact", default)]
+    pub pre_compact: Vec<HookMatcher>,
+
+    /// SubagentStop 매처들
+    #[serde(rename = "SubagentStop", default)]
+    pub subagent_stop: Vec<HookMatcher>,
+
+    /// Notification 매처들
+    #[serde(rename = "Notification", default)]
+    pub notification: Vec<HookMatcher>,
 }
 
 impl HookConfig {
@@ -397,6 +597,9 @@ impl HookConfig {
             HookEventType::PromptSubmit => &self.prompt_submit,
             HookEventType::AgentComplete => &self.agent_complete,
             HookEventType::FileChanged => &self.file_changed,
+            HookEventType::PreCompact => &self.pre_compact,
+            HookEventType::SubagentStop => &self.subagent_stop,
+            HookEventType::Notification => &self.notification,
         }
     }
 
@@ -409,6 +612,9 @@ impl HookConfig {
         self.prompt_submit.extend(other.prompt_submit);
         self.agent_complete.extend(other.agent_complete);
         self.file_changed.extend(other.file_changed);
+        self.pre_compact.extend(other.pre_compact);
+        self.subagent_stop.extend(other.subagent_stop);
+        self.notification.extend(other.notification);
     }
 
     /// 전체 매처 수
@@ -420,6 +626,9 @@ impl HookConfig {
             + self.prompt_submit.len()
             + self.agent_complete.len()
             + self.file_changed.len()
+            + self.pre_compact.len()
+            + self.subagent_stop.len()
+            + self.notification.len()
     }
 
     /// 비어있는지 확인
@@ -484,6 +693,23 @@ impl HookResult {
             outcome: HookOutcome::Blocked(reason),
         }
     }
+
+    /// 파이프라인 값 수정 추가
+    pub fn with_mutation(mut self, mutation: HookMutation) -> Self {
+        match self.outcome {
+            HookOutcome::Modified(ref mut mutations) => mutations.push(mutation),
+            _ => self.outcome = HookOutcome::Modified(vec![mutation]),
+        }
+        self
+    }
+
+    /// 이 Hook이 요청한 수정 목록
+    pub fn mutations(&self) -> &[HookMutation] {
+        match self.outcome {
+            HookOutcome::Modified(ref mutations) => mutations,
+            _ => &[],
+        }
+    }
 }
 
 /// Hook 실행 결과 상태
@@ -495,10 +721,44 @@ pub enum HookOutcome {
     Failed,
     /// 블로킹됨 (PreToolUse에서)
     Blocked(BlockReason),
+    /// 통과 + 파이프라인 값 수정
+    Modified(Vec<HookMutation>),
     /// 스킵됨
     Skipped,
 }
 
+/// Hook이 파이프라인에 적용하는 수정
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookMutation {
+    /// 제출된 프롬프트 교체 (PromptSubmit)
+    Prompt(String),
+    /// Tool 입력 교체 (PreToolUse)
+    ToolInput(Value),
+    /// 모델 컨텍스트에 추가할 내용
+    Context(String),
+}
+
+/// 여러 Hook의 판정을 누적한 결과
+#[derive(Debug, Clone)]
+pub struct HookDecision {
+    /// 수정이 적용된 최종 이벤트
+    pub event: HookEvent,
+    /// 모델 컨텍스트에 추가할 내용
+    pub additional_context: Vec<String>,
+}
+
+impl HookDecision {
+    /// 최종 Tool 입력
+    pub fn tool_input(&self) -> Option<&Value> {
+        self.event.tool_input.as_ref()
+    }
+
+    /// 최종 프롬프트
+    pub fn prompt(&self) -> Option<&str> {
+        self.event.prompt.as_deref()
+    }
+}
+
 /// 블로킹 사유
 #[derive(Debug, Clone)]
 pub struct BlockReason {
@@ -610,4 +870,96 @@ mod tests {
         assert_eq!(config1.pre_tool_use.len(), 2);
         assert_eq!(config1.post_tool_use.len(), 1);
     }
+
+    #[test]
+    fn test_hook_config_parse_extended_events() {
+        let json = r#"{
+            "UserPromptSubmit": [{"matcher": "*", "hooks": [{"type": "command", "command": "true"}]}],
+            "PreCompact": [{"matcher": "auto", "hooks": [{"type": "notify", "message": "compacting"}]}],
+            "SubagentStop": [{"matcher": "explore", "hooks": []}],
+            "Notification": [{"matcher": "*", "hooks": []}]
+        }"#;
+
+        let config: HookConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.prompt_submit.len(), 1);
+        assert_eq!(config.matchers_for(HookEventType::PreCompact).len(), 1);
+        assert_eq!(config.subagent_stop.len(), 1);
+        assert_eq!(config.notification.len(), 1);
+        assert_eq!(config.total_matchers(), 4);
+
+        // 직렬화는 기존 키를 유지
+        let serialized = serde_json::to_value(&config).unwrap();
+        assert!(serialized.get("PromptSubmit").is_some());
+
+        let event_type: HookEventType = serde_json::from_str("\"UserPromptSubmit\"").unwrap();
+        assert_eq!(event_type, HookEventType::PromptSubmit);
+    }
+
+    #[test]
+    fn test_hook_payload() {
+        let event = HookEvent::pre_tool_use("bash", serde_json::json!({"command": "ls"}));
+        let payload = HookPayload::new(&event, "session-1", "/work");
+
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["hook_event_name"], "PreToolUse");
+        assert_eq!(json["tool_input"]["command"], "ls");
+        assert!(json.get("file_path").is_none());
+
+        assert_eq!(payload.variable("tool_name").as_deref(), Some("bash"));
+        assert_eq!(
+            payload.variable("tool_input").as_deref(),
+            Some(r#"{"command":"ls"}"#)
+        );
+        assert_eq!(payload.variable("file_path"), None);
+
+        // 메타데이터는 최상위 필드로 펼쳐짐
+        let payload = HookPayload::new(&HookEvent::pre_compact("auto"), "s", "/work");
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["trigger"], "auto");
+        assert_eq!(payload.variable("trigger").as_deref(), Some("auto"));
+    }
+
+    #[test]
+    fn test_hook_mutations() {
+        let result = HookResult::success("", 0)
+            .with_mutation(HookMutation::ToolInput(
+                serde_json::json!({"command": "ls -la"}),
+            ))
+            .with_mutation(HookMutation::Context("listing".to_string()));
+        assert_eq!(result.mutations().len(), 2);
+        assert!(HookResult::success("", 0).mutations().is_empty());
+
+        let mut event = HookEvent::pre_tool_use("bash", serde_json::json!({"command": "ls"}));
+        for mutation in result.mutations() {
+            event.apply(mutation);
+        }
+        assert_eq!(
+            event.tool_input,
+            Some(serde_json::json!({"command": "ls -la"}))
+        );
+
+        // 이벤트에 맞지 않는 수정은 무시
+        let mut event = HookEvent::post_tool_use("bash", serde_json::json!({}), "out");
+        event.apply(&HookMutation::ToolInput(serde_json::json!({"x": 1})));
+        event.apply(&HookMutation::Prompt("p".to_string()));
+        assert_eq!(event.tool_input, Some(serde_json::json!({})));
+        assert_eq!(event.prompt, None);
+    }
+
+    #[test]
+    fn test_extended_event_matching() {
+        let auto = HookEvent::pre_compact("auto");
+        assert_eq!(auto.event_type.to_string(), "PreCompact");
+        assert!(HookMatcher::new("auto").matches(&auto));
+        assert!(!HookMatcher::new("manual").matches(&auto));
+
+        let stop = HookEvent::subagent_stop("explore", "agent-1");
+        assert_eq!(stop.match_target(), Some("explore"));
+        assert!(HookMatcher::new("exp*").matches(&stop));
+
+        let notification = HookEvent::notification("permission_prompt", "Approve bash?");
+        assert!(HookMatcher::new("permission_prompt").matches(&notification));
+        assert!(HookMatcher::new("*").matches(&HookEvent::session_start()));
+        assert!(!HookMatcher::new("auto").matches(&HookEvent::session_start()));
+    }
 }
@@ -5,11 +5,13 @@
 //! # 주요 모듈
 //!
 //! - `context`: Agent 통합 컨텍스트 (Provider + Tool + Task)
+//! - `capability`: 선택적 서브시스템 가용성 (기능 저하 모드)
 //! - `forgecmd`: PTY 기반 셸 환경 (ForgeCmd)
 //! - `lsp`: 경량 LSP (Language Server Protocol) 연동
 //! - `mcp`: MCP (Model Context Protocol) 브릿지
 //! - `tool`: Tool 시스템 및 Builtin 도구들
 //! - `skill`: Skill 시스템 (슬래시 명령어)
+//! - `subagent`: 파일 기반 서브에이전트 정의 (`agents/*.md`)
 //! - `hook`: Hook 시스템 (Claude Code 호환)
 //! - `config`: 설정 시스템 (Claude Code 호환)
 //! - `plugin`: Plugin 확장 시스템
@@ -44,6 +46,7 @@
 //! ```
 
 // Core modules
+pub mod capability;
 pub mod config;
 pub mod context;
 pub mod forgecmd;
@@ -55,25 +58,35 @@ pub mod plugin;
 pub mod registry;
 pub mod repomap;
 pub mod skill;
+pub mod subagent;
 pub mod tool;
 
 // Re-exports: Agent Context
 pub use context::{
     AgentContext, AgentContextBuilder, AgentContextConfig, ExecutionStats, ToolExecutionResult,
+    ToolExecutionStatus,
 };
 
+// Re-exports: Capability
+pub use capability::{Capability, CapabilityMatrix, CapabilityStatus};
+
 // Re-exports: LSP
 pub use lsp::{
-    create_disabled_lsp_manager, create_lsp_manager, default_lsp_configs, path_to_uri, uri_to_path,
-    DocumentSymbol, Hover, Location, LspClient, LspClientState, LspManager, LspServerConfig,
-    Position, Range, SymbolKind,
+    create_disabled_lsp_manager, create_lsp_manager, default_lsp_configs, format_diagnostics,
+    path_to_uri, uri_to_path, CallDirection, CallHierarchyIncomingCall, CallHierarchyItem,
+    CallHierarchyOutgoingCall, Diagnostic, DiagnosticCounts, DiagnosticSeverity, DiagnosticSource,
+    DiagnosticsStore, DiagnosticsTool, DocumentDiagnostics, DocumentSymbol, Hover, Location,
+    LspClient, LspClientState, LspManager, LspServerConfig, Position, Range, SymbolInformation,
+    SymbolKind, TypeHierarchyItem, WhoCallsTool,
 };
 
 // Re-exports: MCP
 pub use mcp::{
-    McpBridge, McpClient, McpContent, McpPrompt, McpPromptArgument, McpResource, McpServerConfig,
-    McpTool, McpToolAdapter, McpToolCall, McpToolResult, McpTransportConfig, ServerStatus,
-    SseTransport, StdioTransport,
+    McpAuth, McpBridge, McpClient, McpContent, McpOAuthConfig, McpPrompt, McpPromptArgument,
+    McpPromptSkill, McpResource, McpResourceContent, McpResourceTool, McpRestartPolicy, McpRoot,
+    McpServer, McpServerConfig, McpTool, McpToolAdapter, McpToolCall, McpToolPolicy,
+    McpToolResult, McpToolSearchTool, McpTransportConfig, ServerStatus, SseTransport,
+    StdioTransport,
 };
 
 // Re-exports: Tool
@@ -85,22 +98,52 @@ pub use tool::{
     is_safe_extension,
     is_sensitive_path,
     // Tools
+    ArchiveTool,
     BashTool,
+    CodeSearchTool,
+    // Middleware
+    AuditLogMiddleware,
     // Context
     DefaultShellConfig,
+    DownloadTool,
     EditTool,
+    FetchFullOutputTool,
+    GitBlameTool,
+    GitLogTool,
     GlobTool,
     GrepTool,
+    HttpRequestConfig,
+    HttpRequestTool,
+    ListDirectoryTool,
+    ListTodosTool,
+    MiddlewareAction,
+    MiddlewareChain,
+    // Output governor
+    OutputGovernor,
+    OversightMiddleware,
     // Security
     PathValidation,
     PathValidator,
+    PullRequestTool,
     ReadTool,
+    RedactSecretsMiddleware,
+    ResolveConflictTool,
+    // Secret scanning
+    Redaction,
     RuntimeContext,
+    SecretScanner,
+    SqlQueryConfig,
+    SqlQueryTool,
     // Tool trait
     Tool,
     ToolContext,
+    ToolMiddleware,
     // Registry
+    ToolOutcome,
     ToolRegistry,
+    ToolTimeouts,
+    TransferConfig,
+    UploadTool,
     WriteTool,
 };
 
@@ -108,11 +151,30 @@ pub use tool::{
 pub use skill::{
     // Built-in Skills
     CommitSkill,
+    DocsSkill,
+    // Dry-run plan
+    DryRunRecorder,
     ExplainSkill,
+    // Pipelines
+    FailurePolicy,
     FileBasedSkill,
+    FixSkill,
+    PipelineAction,
+    PipelineReport,
+    PipelineStep,
+    PlanStep,
+    PlanStepKind,
+    ResolveConflictsSkill,
     ReviewPrSkill,
+    SkillPlan,
+    TestSkill,
+    TodosSkill,
+    render_plan_approvals,
+    split_dry_run,
     // Traits
     Skill,
+    SkillArgument,
+    SkillArgumentType,
     SkillConfig,
     SkillContext,
     SkillDefinition,
@@ -121,10 +183,15 @@ pub use skill::{
     SkillLoader,
     SkillMetadata,
     SkillOutput,
+    SkillPipeline,
     // Registry
     SkillRegistry,
+    StepOutcome,
 };
 
+// Re-exports: Subagent
+pub use subagent::{FileBasedSubagent, SubagentFileConfig, SubagentLoader};
+
 // Re-exports: Plugin
 pub use plugin::{
     EventBus,
@@ -143,6 +210,16 @@ pub use plugin::{
     // Registry
     PluginRegistry,
     PluginVersion,
+    // Marketplace
+    PackageVerifier,
+    RemoteMarketplace,
+    // Script
+    ScriptPlugin,
+    ScriptRuntime,
+    // WASM
+    VersionReq,
+    WasmPlugin,
+    WasmRuntime,
 };
 
 // Re-exports: Dynamic Registry
@@ -165,8 +242,13 @@ pub use registry::{
     // Snapshot/Rollback
     RegistrySnapshot,
     RegistryStats,
+    SnapshotDiff,
+    SnapshotHistoryEntry,
     SnapshotInfo,
+    SnapshotJournal,
     SnapshotManager,
+    SnapshotRecord,
+    VersionChange,
 };
 
 // Re-exports: Hook (Claude Code compatible)
@@ -177,6 +259,7 @@ pub use hook::{
     HookAction,
     HookConfig,
     HookContext,
+    HookDecision,
     // Types
     HookEvent,
     HookEventType,
@@ -185,7 +268,9 @@ pub use hook::{
     // Loader
     HookLoader,
     HookMatcher,
+    HookMutation,
     HookOutcome,
+    HookPayload,
     HookResult,
 };
 
@@ -198,22 +283,38 @@ pub use config::{
     ConfigMcpServer,
     // Types
     ForgeConfig,
+    FormatConfig,
+    FormatterCommand,
     ModelConfig,
     ProviderConfig,
+    // Rule files (AGENTS.md, CLAUDE.md, .cursorrules)
+    RuleFile,
+    RuleSet,
+    RuleSource,
+    RulesConfig,
+    RulesLoader,
     ShellConfigSection,
     ThemeConfig,
+    ToolsConfigSection,
 };
 
 // Re-exports: Repository Map (AST-based codebase analysis)
 pub use repomap::{
-    DependencyGraph, FileInfo, FileRanker, RepoAnalyzer, RepoMap, RepoMapConfig, SymbolDef,
-    SymbolKind as RepoSymbolKind, SymbolRef, SymbolUsage,
+    CodeChunk, DependencyGraph, Embedder, EmbeddingIndex, ExportFormat as RepoMapExportFormat,
+    FileInfo, FileRanker, HashingEmbedder, LanguageAnalyzer, LanguageRegistry, RepoAnalyzer, RepoMap,
+    RepoMapCache, RepoMapConfig, SearchHit, SymbolDef,
+    SymbolKind as RepoSymbolKind, SymbolRef, SymbolUsage, TodoBacklog, TodoFilter, TodoItem,
+    TodoKind,
 };
 
-// Re-exports: Git Integration (auto-commit, checkpoint, rollback)
+// Re-exports: Git Integration (auto-commit, checkpoint, rollback, agent workspaces, PRs, conflicts, history)
 pub use git::{
-    AutoCommitConfig, Checkpoint, CheckpointId, CheckpointManager, CommitGenerator, CommitStyle,
-    FileStatus, GitError, GitOps, GitStatus,
+    AgentWorkspace, AutoCommitConfig, BlameLine, BlameRange, Checkpoint, CheckpointId,
+    CheckpointManager, CodeHost, CommitGenerator, CommitIdentity, CommitOptions, CommitSigning,
+    CommitStyle, ConflictFile, ConflictHunk, ConflictOperation, ConflictResolution, DiffStat,
+    FileDiffStat, FileStatus, GitError, GitOps, GitStatus, HostKind, LogEntry, NewPullRequest,
+    PreCommitCheck, PreCommitReport, PrState, PullRequest, ReviewComment, TimelineEntry,
+    WorkspaceOutcome, WorktreeInfo, CHECKPOINT_REF_PREFIX, GHOST_REF_PREFIX,
 };
 
 // Re-exports: ForgeCmd (PTY-based shell)
@@ -221,10 +322,18 @@ pub use forgecmd::{
     // Functions
     permission_name_for_category,
     register_permissions,
+    // Named sessions
+    BackgroundSession,
+    // Recording
+    CastRecorder,
     // Permission
     CheckResult,
     // Filter & Analysis
     CommandCategory,
+    // Explanations
+    CommandEffects,
+    CommandExplainer,
+    CommandExplanation,
     CommandFilter,
     // Execution
     CommandRecord,
@@ -237,13 +346,22 @@ pub use forgecmd::{
     ForgeCmdBuilder,
     ForgeCmdConfig,
     ForgeCmdError,
+    InputPrompt,
+    LearnedRule,
+    LearningConfig,
+    OutputConfig,
     PermissionChecker,
     PermissionDecision,
     PermissionRule,
     PermissionRules,
+    PromptKind,
+    PtyEvent,
     PtySession,
+    RecordingInfo,
     RiskAnalysis,
     RiskThresholds,
+    RuleKind,
+    RulePattern,
     TrackerStats,
 };
 
@@ -286,8 +404,8 @@ mod tests {
     #[test]
     fn test_all_tools_count() {
         let tools = all_tools();
-        // 6 filesystem/execute tools + 7 task tools = 13
-        assert_eq!(tools.len(), 13);
+        // 6 filesystem/execute tools + fetch_full_output + list_directory + list_todos + code_search + archive + http_request + download + upload + sql_query + pull_request + resolve_conflict + git_blame + git_log + 8 task tools + 4 process tools = 31
+        assert_eq!(tools.len(), 31);
     }
 
     #[tokio::test]
@@ -1,8 +1,12 @@
 //! LSP Client - 경량 Language Server 클라이언트
 //!
 //! JSON-RPC 2.0 over stdio로 LSP 서버와 통신
-//! 최소 기능만 구현: definition, references, hover
+//! 최소 기능만 구현: definition, references, hover, diagnostics
+//!
+//! 진단은 push(`publishDiagnostics` 알림)와 pull(`textDocument/diagnostic`) 모두
+//! `DiagnosticsStore`에 버퍼됩니다.
 
+use super::diagnostics::{parse_diagnostics, DiagnosticsStore};
 use super::types::*;
 use forge_foundation::Result;
 use serde::{Deserialize, Serialize};
@@ -85,11 +89,16 @@ struct JsonRpcNotification {
     params: Option<Value>,
 }
 
+/// 서버에서 온 메시지 (응답, 알림, 서버 요청)
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 struct JsonRpcResponse {
     jsonrpc: String,
-    id: Option<u64>,
+    id: Option<Value>,
+    #[serde(default)]
+    method: Option<String>,
+    #[serde(default)]
+    params: Option<Value>,
     result: Option<Value>,
     error: Option<JsonRpcError>,
 }
@@ -143,6 +152,12 @@ pub struct LspClient {
     /// 마지막 성공적인 요청 시간
     last_successful_request: RwLock<Option<std::time::Instant>>,
 
+    /// 문서별 진단 버퍼
+    diagnostics: Arc<DiagnosticsStore>,
+
+    /// 열린 문서 (URI -> 버전/내용, 증분 동기화용)
+    documents: Mutex<HashMap<String, OpenDocument>>,
+
     /// 프로세스 상태 모니터 핸들 (kept for future process monitoring)
     #[allow(dead_code)]
     process_monitor_handle: Mutex<Option<tokio::task::JoinHandle<()>>>,
@@ -164,6 +179,8 @@ impl LspClient {
             server_capabilities: RwLock::new(None),
             restart_count: AtomicU64::new(0),
             last_successful_request: RwLock::new(None),
+            diagnostics: Arc::new(DiagnosticsStore::new()),
+            documents: Mutex::new(HashMap::new()),
             process_monitor_handle: Mutex::new(None),
         }
     }
@@ -183,15 +200,28 @@ impl LspClient {
             server_capabilities: RwLock::new(None),
             restart_count: AtomicU64::new(0),
             last_successful_request: RwLock::new(None),
+            diagnostics: Arc::new(DiagnosticsStore::new()),
+            documents: Mutex::new(HashMap::new()),
             process_monitor_handle: Mutex::new(None),
         }
     }
 
+    /// 진단 버퍼 공유 (여러 언어 서버의 진단을 한 곳에 모음)
+    pub fn with_diagnostics(mut self, store: Arc<DiagnosticsStore>) -> Self {
+        self.diagnostics = store;
+        self
+    }
+
     /// 언어 ID
     pub fn language_id(&self) -> &str {
         &self.language_id
     }
 
+    /// 진단 버퍼
+    pub fn diagnostics(&self) -> Arc<DiagnosticsStore> {
+        Arc::clone(&self.diagnostics)
+    }
+
     /// 현재 상태
     pub async fn state(&self) -> LspClientState {
         *self.state.read().await
@@ -247,8 +277,9 @@ impl LspClient {
         // stdin writer 태스크 시작
         self.start_writer(stdin, message_rx);
 
-        // stdout reader 태스크 시작
-        self.start_reader(stdout);
+        // stdout reader 태스크 시작 (서버 요청 응답용 송신자 공유)
+        let reply_tx = self.message_tx.lock().await.clone();
+        self.start_reader(stdout, reply_tx);
 
         // initialize 요청
         let root_uri = path_to_uri(root_path);
@@ -260,11 +291,27 @@ impl LspClient {
                     "definition": { "dynamicRegistration": false },
                     "references": { "dynamicRegistration": false },
                     "hover": { "contentFormat": ["markdown", "plaintext"] },
+                    "documentSymbol": { "hierarchicalDocumentSymbolSupport": true },
+                    "callHierarchy": { "dynamicRegistration": false },
+                    "typeHierarchy": { "dynamicRegistration": false },
+                    "formatting": { "dynamicRegistration": false },
                     "synchronization": {
                         "didOpen": true,
                         "didClose": true,
-                        "didChange": true
+                        "didChange": true,
+                        "didSave": true
+                    },
+                    "publishDiagnostics": {
+                        "relatedInformation": false,
+                        "versionSupport": true
+                    },
+                    "diagnostic": {
+                        "dynamicRegistration": false,
+                        "relatedDocumentSupport": false
                     }
+                },
+                "workspace": {
+                    "symbol": { "dynamicRegistration": false }
                 }
             },
             "initializationOptions": self.config.initialization_options
@@ -495,6 +542,193 @@ impl LspClient {
             .map_err(|e| forge_foundation::Error::Internal(format!("Failed to parse hover: {}", e)))
     }
 
+    // ========================================================================
+    // 심볼 (RepoMap 인덱스용)
+    // ========================================================================
+
+    /// 서버가 `capability`(예: `documentSymbolProvider`)를 지원하는지
+    async fn has_capability(&self, capability: &str) -> bool {
+        self.server_capabilities
+            .read()
+            .await
+            .as_ref()
+            .and_then(|caps| caps.get(capability))
+            .is_some_and(|provider| !provider.is_null() && provider != &Value::Bool(false))
+    }
+
+    /// 문서 심볼 (`textDocument/documentSymbol`)
+    ///
+    /// 평면 응답(`SymbolInformation[]`)은 자식 없는 심볼로 변환합니다.
+    pub async fn document_symbols(&self, uri: &str) -> Result<Vec<DocumentSymbol>> {
+        self.ensure_ready().await?;
+        if !self.has_capability("documentSymbolProvider").await {
+            return Err(forge_foundation::Error::Internal(format!(
+                "{} does not support document symbols",
+                self.config.command
+            )));
+        }
+
+        let params = json!({ "textDocument": { "uri": uri } });
+        let result = self
+            .send_request("textDocument/documentSymbol", Some(params))
+            .await?;
+        Ok(parse_document_symbols(result))
+    }
+
+    /// 워크스페이스 심볼 검색 (`workspace/symbol`, 서버 인덱스 사용)
+    pub async fn workspace_symbols(&self, query: &str) -> Result<Vec<SymbolInformation>> {
+        self.ensure_ready().await?;
+        if !self.has_capability("workspaceSymbolProvider").await {
+            return Err(forge_foundation::Error::Internal(format!(
+                "{} does not support workspace symbols",
+                self.config.command
+            )));
+        }
+
+        let params = json!({ "query": query });
+        let result = self.send_request("workspace/symbol", Some(params)).await?;
+        Ok(match result {
+            Value::Array(items) => items
+                .into_iter()
+                .filter_map(SymbolInformation::from_value)
+                .collect(),
+            _ => Vec::new(),
+        })
+    }
+
+    // ========================================================================
+    // 호출/타입 계층
+    // ========================================================================
+
+    /// 위치의 호출 계층 항목 (`textDocument/prepareCallHierarchy`)
+    pub async fn prepare_call_hierarchy(
+        &self,
+        uri: &str,
+        position: Position,
+    ) -> Result<Vec<CallHierarchyItem>> {
+        self.prepare_hierarchy(
+            "callHierarchyProvider",
+            "textDocument/prepareCallHierarchy",
+            uri,
+            position,
+        )
+        .await
+    }
+
+    /// 이 함수를 호출하는 곳 (`callHierarchy/incomingCalls`)
+    pub async fn incoming_calls(
+        &self,
+        item: &CallHierarchyItem,
+    ) -> Result<Vec<CallHierarchyIncomingCall>> {
+        self.hierarchy_request("callHierarchy/incomingCalls", item)
+            .await
+    }
+
+    /// 이 함수가 호출하는 곳 (`callHierarchy/outgoingCalls`)
+    pub async fn outgoing_calls(
+        &self,
+        item: &CallHierarchyItem,
+    ) -> Result<Vec<CallHierarchyOutgoingCall>> {
+        self.hierarchy_request("callHierarchy/outgoingCalls", item)
+            .await
+    }
+
+    /// 위치의 타입 계층 항목 (`textDocument/prepareTypeHierarchy`)
+    pub async fn prepare_type_hierarchy(
+        &self,
+        uri: &str,
+        position: Position,
+    ) -> Result<Vec<TypeHierarchyItem>> {
+        self.prepare_hierarchy(
+            "typeHierarchyProvider",
+            "textDocument/prepareTypeHierarchy",
+            uri,
+            position,
+        )
+        .await
+    }
+
+    /// 상위 타입 (`typeHierarchy/supertypes`)
+    pub async fn supertypes(&self, item: &TypeHierarchyItem) -> Result<Vec<TypeHierarchyItem>> {
+        self.hierarchy_request("typeHierarchy/supertypes", item)
+            .await
+    }
+
+    /// 하위 타입/구현 (`typeHierarchy/subtypes`)
+    pub async fn subtypes(&self, item: &TypeHierarchyItem) -> Result<Vec<TypeHierarchyItem>> {
+        self.hierarchy_request("typeHierarchy/subtypes", item)
+            .await
+    }
+
+    async fn prepare_hierarchy(
+        &self,
+        capability: &str,
+        method: &str,
+        uri: &str,
+        position: Position,
+    ) -> Result<Vec<CallHierarchyItem>> {
+        self.ensure_ready().await?;
+        if !self.has_capability(capability).await {
+            return Err(forge_foundation::Error::Internal(format!(
+                "{} does not support {}",
+                self.config.command, method
+            )));
+        }
+
+        let params = json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": position.line, "character": position.character }
+        });
+        let result = self.send_request(method, Some(params)).await?;
+        Ok(parse_items(result))
+    }
+
+    async fn hierarchy_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        item: &CallHierarchyItem,
+    ) -> Result<Vec<T>> {
+        self.ensure_ready().await?;
+        let result = self
+            .send_request(method, Some(json!({ "item": item })))
+            .await?;
+        Ok(parse_items(result))
+    }
+
+    // ========================================================================
+    // 포맷
+    // ========================================================================
+
+    /// 서버가 문서 포맷을 지원하는지
+    pub async fn supports_formatting(&self) -> bool {
+        self.has_capability("documentFormattingProvider").await
+    }
+
+    /// 문서 포맷 편집 (`textDocument/formatting`)
+    pub async fn formatting(
+        &self,
+        uri: &str,
+        tab_size: u32,
+        insert_spaces: bool,
+    ) -> Result<Vec<TextEdit>> {
+        self.ensure_ready().await?;
+        if !self.supports_formatting().await {
+            return Err(forge_foundation::Error::Internal(format!(
+                "{} does not support formatting",
+                self.config.command
+            )));
+        }
+
+        let params = json!({
+            "textDocument": { "uri": uri },
+            "options": { "tabSize": tab_size, "insertSpaces": insert_spaces }
+        });
+        let result = self
+            .send_request("textDocument/formatting", Some(params))
+            .await?;
+        Ok(parse_items(result))
+    }
+
     // ========================================================================
     // 문서 동기화 (Agent가 파일 편집 시 호출)
     // ========================================================================
@@ -502,6 +736,10 @@ impl LspClient {
     /// 문서 열기
     pub async fn did_open(&self, uri: &str, language_id: &str, content: &str) -> Result<()> {
         self.ensure_ready().await?;
+        self.documents
+            .lock()
+            .await
+            .insert(uri.to_string(), OpenDocument::new(1, content));
 
         let params = json!({
             "textDocument": {
@@ -517,15 +755,29 @@ impl LspClient {
     }
 
     /// 문서 변경
+    ///
+    /// 서버가 증분 동기화(`TextDocumentSyncKind.Incremental`)를 지원하고 이전 내용을
+    /// 알고 있으면 바뀐 범위만, 아니면 전체 내용을 보냅니다.
     pub async fn did_change(&self, uri: &str, version: i32, content: &str) -> Result<()> {
         self.ensure_ready().await?;
+        let previous = self
+            .documents
+            .lock()
+            .await
+            .insert(uri.to_string(), OpenDocument::new(version, content));
 
+        let change = match previous {
+            Some(previous) if self.text_document_sync_kind().await == 2 => {
+                incremental_change(&previous.content, content)
+            }
+            _ => json!({ "text": content }),
+        };
         let params = json!({
             "textDocument": {
                 "uri": uri,
                 "version": version
             },
-            "contentChanges": [{ "text": content }]
+            "contentChanges": [change]
         });
 
         self.send_notification("textDocument/didChange", Some(params))
@@ -535,6 +787,8 @@ impl LspClient {
     /// 문서 닫기
     pub async fn did_close(&self, uri: &str) -> Result<()> {
         self.ensure_ready().await?;
+        self.documents.lock().await.remove(uri);
+        self.diagnostics.remove(uri);
 
         let params = json!({
             "textDocument": { "uri": uri }
@@ -544,6 +798,106 @@ impl LspClient {
             .await
     }
 
+    /// 문서 내용 동기화 (열려 있지 않으면 열고, 열려 있으면 다음 버전으로 변경)
+    ///
+    /// 동기화한 문서 버전을 반환합니다.
+    pub async fn sync_document(&self, uri: &str, language_id: &str, content: &str) -> Result<i32> {
+        let current = self.documents.lock().await.get(uri).map(|doc| doc.version);
+        match current {
+            Some(version) => {
+                self.did_change(uri, version + 1, content).await?;
+                Ok(version + 1)
+            }
+            None => {
+                self.did_open(uri, language_id, content).await?;
+                Ok(1)
+            }
+        }
+    }
+
+    /// 문서 저장 알림 (서버가 `save`를 요청한 경우만)
+    pub async fn did_save(&self, uri: &str, content: &str) -> Result<()> {
+        self.ensure_ready().await?;
+        let save = self
+            .server_capabilities
+            .read()
+            .await
+            .as_ref()
+            .and_then(|caps| caps.get("textDocumentSync"))
+            .and_then(|sync| sync.get("save"))
+            .cloned();
+        let include_text = match save {
+            None | Some(Value::Null) | Some(Value::Bool(false)) => return Ok(()),
+            Some(save) => save
+                .get("includeText")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+        };
+
+        let mut params = json!({ "textDocument": { "uri": uri } });
+        if include_text {
+            params["text"] = json!(content);
+        }
+        self.send_notification("textDocument/didSave", Some(params))
+            .await
+    }
+
+    /// 열린 문서 버전
+    pub async fn document_version(&self, uri: &str) -> Option<i32> {
+        self.documents.lock().await.get(uri).map(|doc| doc.version)
+    }
+
+    /// 서버의 문서 동기화 방식 (0: 없음, 1: 전체, 2: 증분)
+    async fn text_document_sync_kind(&self) -> u64 {
+        let caps = self.server_capabilities.read().await;
+        match caps.as_ref().and_then(|caps| caps.get("textDocumentSync")) {
+            Some(Value::Number(kind)) => kind.as_u64().unwrap_or(1),
+            Some(sync) => sync.get("change").and_then(Value::as_u64).unwrap_or(1),
+            None => 1,
+        }
+    }
+
+    // ========================================================================
+    // 진단 (Diagnostics)
+    // ========================================================================
+
+    /// 서버가 pull 진단(`textDocument/diagnostic`)을 지원하는지
+    pub async fn supports_pull_diagnostics(&self) -> bool {
+        self.has_capability("diagnosticProvider").await
+    }
+
+    /// pull 진단 요청 (결과는 진단 버퍼에도 저장)
+    ///
+    /// 서버가 변경 없음(`unchanged`)을 응답하면 버퍼된 진단을 반환합니다.
+    pub async fn pull_diagnostics(&self, uri: &str) -> Result<Vec<Diagnostic>> {
+        self.ensure_ready().await?;
+
+        let mut params = json!({ "textDocument": { "uri": uri } });
+        if let Some(previous) = self.diagnostics.result_id(uri) {
+            params["previousResultId"] = json!(previous);
+        }
+
+        let result = self
+            .send_request("textDocument/diagnostic", Some(params))
+            .await?;
+        let version = self.document_version(uri).await;
+        let result_id = result
+            .get("resultId")
+            .and_then(Value::as_str)
+            .map(String::from);
+
+        if result.get("kind").and_then(Value::as_str) == Some("unchanged") {
+            if let Some(doc) = self.diagnostics.touch(uri, version) {
+                return Ok(doc.diagnostics);
+            }
+        }
+
+        let diagnostics = parse_diagnostics(result.get("items"));
+        self.diagnostics
+            .store_pulled(uri, version, result_id, diagnostics.clone());
+        Ok(diagnostics)
+    }
+
     // ========================================================================
     // 내부 메서드
     // ========================================================================
@@ -690,8 +1044,16 @@ impl LspClient {
     }
 
     /// stdout reader 시작 (tokio 태스크)
-    fn start_reader(&self, stdout: tokio::process::ChildStdout) {
+    ///
+    /// 응답은 대기 중인 요청으로, `publishDiagnostics` 알림은 진단 버퍼로 보내고
+    /// 서버 요청(`workspace/configuration` 등)에는 빈 결과로 응답합니다.
+    fn start_reader(
+        &self,
+        stdout: tokio::process::ChildStdout,
+        reply_tx: Option<mpsc::Sender<String>>,
+    ) {
         let pending = Arc::clone(&self.pending_responses);
+        let diagnostics = Arc::clone(&self.diagnostics);
         let language_id = self.language_id.clone();
 
         tokio::spawn(async move {
@@ -761,8 +1123,42 @@ impl LspClient {
 
                 // JSON 파싱
                 match serde_json::from_slice::<JsonRpcResponse>(&body) {
+                    Ok(JsonRpcResponse {
+                        id: Some(id),
+                        method: Some(method),
+                        params,
+                        ..
+                    }) => {
+                        // 서버 요청 - 처리하지 않는 기능이므로 빈 결과로 응답
+                        trace!("LSP server request {} received for {}", method, language_id);
+                        if let Some(tx) = &reply_tx {
+                            let reply = json!({
+                                "jsonrpc": "2.0",
+                                "id": id,
+                                "result": server_request_result(&method, params.as_ref())
+                            })
+                            .to_string();
+                            let _ = tx
+                                .send(format!("Content-Length: {}\r\n\r\n{}", reply.len(), reply))
+                                .await;
+                        }
+                    }
+                    Ok(JsonRpcResponse {
+                        id: None,
+                        method: Some(method),
+                        params,
+                        ..
+                    }) => {
+                        if method == "textDocument/publishDiagnostics" {
+                            if let Some(params) = params {
+                                diagnostics.handle_publish(&params);
+                            }
+                        } else {
+                            trace!("LSP notification {} received for {}", method, language_id);
+                        }
+                    }
                     Ok(response) => {
-                        if let Some(id) = response.id {
+                        if let Some(id) = response.id.as_ref().and_then(Value::as_u64) {
                             trace!("LSP response {} received for {}", id, language_id);
 
                             // pending에서 sender 찾아서 응답 전송
@@ -778,9 +1174,6 @@ impl LspClient {
                                 };
                                 let _ = sender.send(result);
                             }
-                        } else {
-                            // 알림 (id 없음) - 현재는 무시
-                            trace!("LSP notification received for {}", language_id);
                         }
                     }
                     Err(e) => {
@@ -818,6 +1211,106 @@ impl LspClient {
     }
 }
 
+/// 열린 문서 상태
+struct OpenDocument {
+    version: i32,
+    content: String,
+}
+
+impl OpenDocument {
+    fn new(version: i32, content: &str) -> Self {
+        Self {
+            version,
+            content: content.to_string(),
+        }
+    }
+}
+
+/// 이전/새 내용의 공통 앞뒤를 제외한 범위 변경 (`TextDocumentContentChangeEvent`)
+fn incremental_change(old: &str, new: &str) -> Value {
+    let prefix: usize = old
+        .chars()
+        .zip(new.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let suffix: usize = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .scan(0, |total, len| {
+            *total += len;
+            (*total <= max_suffix).then_some(len)
+        })
+        .sum();
+
+    let start = position_at(old, prefix);
+    let end = position_at(old, old.len() - suffix);
+    json!({
+        "range": {
+            "start": { "line": start.line, "character": start.character },
+            "end": { "line": end.line, "character": end.character }
+        },
+        "text": &new[prefix..new.len() - suffix]
+    })
+}
+
+/// 바이트 오프셋의 LSP 위치 (UTF-16 컬럼)
+fn position_at(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line = before.matches('\n').count() as u32;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let character = before[line_start..].encode_utf16().count() as u32;
+    Position::new(line, character)
+}
+
+/// 배열 응답 파싱 (null은 빈 목록, 알 수 없는 항목 무시)
+fn parse_items<T: serde::de::DeserializeOwned>(value: Value) -> Vec<T> {
+    match value {
+        Value::Array(items) => items
+            .into_iter()
+            .filter_map(|item| serde_json::from_value(item).ok())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// `textDocument/documentSymbol` 응답 파싱 (계층/평면 형식 모두, 알 수 없는 항목 무시)
+fn parse_document_symbols(value: Value) -> Vec<DocumentSymbol> {
+    let Value::Array(items) = value else {
+        return Vec::new();
+    };
+    items
+        .into_iter()
+        .filter_map(|item| {
+            if item.get("location").is_some() {
+                SymbolInformation::from_value(item).map(DocumentSymbol::from)
+            } else {
+                serde_json::from_value(item).ok()
+            }
+        })
+        .collect()
+}
+
+/// 서버 요청에 대한 기본 응답
+///
+/// `workspace/configuration`은 항목 수만큼 `null`, 그 외는 `null`
+fn server_request_result(method: &str, params: Option<&Value>) -> Value {
+    match method {
+        "workspace/configuration" => {
+            let items = params
+                .and_then(|p| p.get("items"))
+                .and_then(Value::as_array)
+                .map_or(0, Vec::len);
+            Value::Array(vec![Value::Null; items])
+        }
+        _ => Value::Null,
+    }
+}
+
 impl Drop for LspClient {
     fn drop(&mut self) {
         // 프로세스 종료는 shutdown()에서 처리
@@ -841,12 +1334,118 @@ mod tests {
             args: vec![],
             root_patterns: vec!["Cargo.toml".to_string()],
             initialization_options: None,
+            extensions: vec![],
+            install_hint: None,
         };
 
         let client = LspClient::new(config);
         assert_eq!(client.language_id(), "rust");
     }
 
+    #[test]
+    fn test_server_request_result() {
+        let params = json!({ "items": [{ "section": "rust-analyzer" }, { "section": "files" }] });
+        assert_eq!(
+            server_request_result("workspace/configuration", Some(&params)),
+            json!([null, null])
+        );
+        assert_eq!(
+            server_request_result("window/workDoneProgress/create", None),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_incremental_change() {
+        let change = incremental_change("fn main() {\n    let x = 1;\n}\n", "fn main() {\n    let x = 42;\n}\n");
+        assert_eq!(change["range"]["start"], json!({ "line": 1, "character": 12 }));
+        assert_eq!(change["range"]["end"], json!({ "line": 1, "character": 13 }));
+        assert_eq!(change["text"], "42");
+
+        // 삽입: 공통 앞뒤가 겹치지 않음
+        let change = incremental_change("aa", "aaa");
+        assert_eq!(change["range"]["start"], change["range"]["end"]);
+        assert_eq!(change["text"], "a");
+
+        // UTF-16 컬럼 (한글은 1, 이모지는 2 코드 유닛)
+        let change = incremental_change("// 한🙂x\n", "// 한🙂y\n");
+        assert_eq!(change["range"]["start"], json!({ "line": 0, "character": 6 }));
+        assert_eq!(change["text"], "y");
+
+        let change = incremental_change("same", "same");
+        assert_eq!(change["text"], "");
+    }
+
+    #[test]
+    fn test_parse_hierarchy_items() {
+        let range = json!({ "start": { "line": 9, "character": 0 }, "end": { "line": 12, "character": 1 } });
+        let calls: Vec<CallHierarchyIncomingCall> = parse_items(json!([{
+            "from": {
+                "name": "run",
+                "kind": 12,
+                "uri": "file:///src/main.rs",
+                "range": range,
+                "selectionRange": range,
+                "data": { "id": 7 }
+            },
+            "fromRanges": [range]
+        }]));
+        assert_eq!(calls[0].from.name, "run");
+        assert_eq!(calls[0].from_ranges.len(), 1);
+
+        // data는 후속 요청에 그대로 전달
+        let item = serde_json::to_value(&calls[0].from).unwrap();
+        assert_eq!(item["selectionRange"]["start"]["line"], 9);
+        assert_eq!(item["data"]["id"], 7);
+
+        assert!(parse_items::<CallHierarchyItem>(Value::Null).is_empty());
+    }
+
+    #[test]
+    fn test_parse_document_symbols() {
+        let range = json!({ "start": { "line": 1, "character": 0 }, "end": { "line": 4, "character": 1 } });
+        let hierarchical = json!([
+            { "name": "main", "kind": 12, "range": range, "selectionRange": range },
+            { "name": "broken", "kind": 99, "range": range }
+        ]);
+        let symbols = parse_document_symbols(hierarchical);
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "main");
+
+        let flat = json!([{
+            "name": "Config",
+            "kind": 5,
+            "location": { "uri": "file:///app.py", "range": range },
+            "containerName": "app"
+        }]);
+        let symbols = parse_document_symbols(flat);
+        assert_eq!(symbols[0].kind, SymbolKind::Class);
+        assert_eq!(symbols[0].range.end.line, 4);
+        assert!(parse_document_symbols(Value::Null).is_empty());
+    }
+
+    #[test]
+    fn test_parse_server_messages() {
+        let notification: JsonRpcResponse = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": "file:///a.rs", "diagnostics": [] }
+        }))
+        .unwrap();
+        assert!(notification.id.is_none());
+        assert_eq!(notification.method.as_deref(), Some("textDocument/publishDiagnostics"));
+
+        // 서버 요청 ID는 문자열일 수도 있음
+        let request: JsonRpcResponse = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "id": "cfg-1",
+            "method": "workspace/configuration",
+            "params": { "items": [] }
+        }))
+        .unwrap();
+        assert_eq!(request.id, Some(json!("cfg-1")));
+    }
+
     #[tokio::test]
     async fn test_client_state() {
         let config = LspServerConfig {
@@ -855,6 +1454,8 @@ mod tests {
             args: vec![],
             root_patterns: vec![],
             initialization_options: None,
+            extensions: vec![],
+            install_hint: None,
         };
 
         let client = LspClient::new(config);
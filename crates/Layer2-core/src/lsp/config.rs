@@ -0,0 +1,214 @@
+//! LSP Config File - 사용자 정의 언어 서버 (`lsp.json`)
+//!
+//! 재컴파일 없이 언어 서버를 추가하거나 기본 설정을 바꿉니다.
+//! 글로벌(`~/.config/forgecode/lsp.json`) → 프로젝트(`.forgecode/lsp.json`) 순으로
+//! 기본 설정 위에 적용됩니다.
+//!
+//! ```json
+//! {
+//!   "servers": {
+//!     "elixir": {
+//!       "command": "elixir-ls",
+//!       "extensions": ["ex", "exs"],
+//!       "root_patterns": ["mix.exs"]
+//!     },
+//!     "python": { "command": "pyright-langserver", "args": ["--stdio"] },
+//!     "java": { "disabled": true }
+//!   }
+//! }
+//! ```
+//!
+//! 기본 서버와 같은 언어는 지정한 필드만 덮어쓰고, 새 언어는 `command`가 필요합니다.
+
+use super::types::{default_lsp_configs, LspServerConfig};
+use forge_foundation::storage::JsonStore;
+use forge_foundation::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::warn;
+
+/// LSP 설정 파일 이름
+pub const LSP_FILE: &str = "lsp.json";
+
+/// `lsp.json` 파일 구조
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LspConfigFile {
+    /// 언어 ID -> 서버 설정
+    #[serde(default)]
+    pub servers: BTreeMap<String, LspServerEntry>,
+}
+
+/// 서버 항목 (생략한 필드는 기본 설정 유지)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LspServerEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_patterns: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initialization_options: Option<Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub install_hint: Option<String>,
+
+    /// 이 언어의 서버 사용 안 함
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+impl LspConfigFile {
+    /// 파일에서 로드
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// 서버 설정 목록에 적용
+    pub fn apply(&self, configs: &mut Vec<LspServerConfig>) {
+        for (language_id, entry) in &self.servers {
+            if entry.disabled {
+                configs.retain(|c| &c.language_id != language_id);
+                continue;
+            }
+
+            if let Some(config) = configs.iter_mut().find(|c| &c.language_id == language_id) {
+                entry.patch(config);
+                continue;
+            }
+
+            match &entry.command {
+                Some(command) => {
+                    let mut config = LspServerConfig {
+                        language_id: language_id.clone(),
+                        command: command.clone(),
+                        args: vec![],
+                        root_patterns: vec![],
+                        initialization_options: None,
+                        extensions: vec![],
+                        install_hint: None,
+                    };
+                    entry.patch(&mut config);
+                    configs.push(config);
+                }
+                None => warn!(
+                    "Ignoring LSP server '{}' in {}: missing \"command\"",
+                    language_id, LSP_FILE
+                ),
+            }
+        }
+    }
+}
+
+impl LspServerEntry {
+    /// 지정한 필드만 덮어쓰기
+    fn patch(&self, config: &mut LspServerConfig) {
+        if let Some(command) = &self.command {
+            config.command = command.clone();
+        }
+        if let Some(args) = &self.args {
+            config.args = args.clone();
+        }
+        if let Some(patterns) = &self.root_patterns {
+            config.root_patterns = patterns.clone();
+        }
+        if let Some(extensions) = &self.extensions {
+            config.extensions = extensions.clone();
+        }
+        if let Some(options) = &self.initialization_options {
+            config.initialization_options = Some(options.clone());
+        }
+        if let Some(hint) = &self.install_hint {
+            config.install_hint = Some(hint.clone());
+        }
+    }
+}
+
+/// 기본 설정 + 글로벌/프로젝트 `lsp.json`
+///
+/// 읽을 수 없는 파일은 경고만 남기고 건너뜁니다.
+pub fn load_lsp_configs(project_root: &Path) -> Vec<LspServerConfig> {
+    let mut configs = default_lsp_configs();
+
+    let stores = [
+        JsonStore::global().ok(),
+        Some(JsonStore::project(project_root)),
+    ];
+    for store in stores.into_iter().flatten() {
+        match store.load_optional::<LspConfigFile>(LSP_FILE) {
+            Ok(Some(file)) => file.apply(&mut configs),
+            Ok(None) => {}
+            Err(e) => warn!(
+                "Failed to load {}: {}",
+                store.file_path(LSP_FILE).display(),
+                e
+            ),
+        }
+    }
+
+    configs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_overrides_and_additions() {
+        let file: LspConfigFile = serde_json::from_str(
+            r#"{
+                "servers": {
+                    "elixir": { "command": "elixir-ls", "extensions": ["ex", ".exs"], "root_patterns": ["mix.exs"] },
+                    "python": { "command": "pyright-langserver", "args": ["--stdio"] },
+                    "java": { "disabled": true },
+                    "nix": { "extensions": ["nix"] }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let mut configs = default_lsp_configs();
+        file.apply(&mut configs);
+
+        let find = |id: &str| configs.iter().find(|c| c.language_id == id);
+        let elixir = find("elixir").unwrap();
+        assert_eq!(elixir.command, "elixir-ls");
+        assert!(elixir.handles_extension("exs"));
+
+        let python = find("python").unwrap();
+        assert_eq!(python.command, "pyright-langserver");
+        assert_eq!(python.args, vec!["--stdio"]);
+        assert!(!python.root_patterns.is_empty());
+
+        assert!(find("java").is_none());
+        assert!(find("nix").is_none());
+    }
+
+    #[test]
+    fn test_load_project_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".forgecode")).unwrap();
+        std::fs::write(
+            dir.path().join(".forgecode").join(LSP_FILE),
+            r#"{ "servers": { "zig": { "command": "/opt/zls/zls" } } }"#,
+        )
+        .unwrap();
+
+        let configs = load_lsp_configs(dir.path());
+        let zig = configs.iter().find(|c| c.language_id == "zig").unwrap();
+        assert_eq!(zig.command, "/opt/zls/zls");
+        assert!(zig.install_hint.is_some());
+
+        let file = LspConfigFile::load_from(&dir.path().join(".forgecode").join(LSP_FILE)).unwrap();
+        assert_eq!(file.servers.len(), 1);
+    }
+}
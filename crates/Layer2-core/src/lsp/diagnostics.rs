@@ -0,0 +1,395 @@
+//! Diagnostics Store - 문서별 진단 버퍼
+//!
+//! LSP 서버가 보내는 진단을 열린 문서별로 보관합니다.
+//!
+//! - **Push**: `textDocument/publishDiagnostics` 알림을 reader 태스크가 저장
+//! - **Pull**: `textDocument/diagnostic` 응답을 저장 (`resultId`로 변경 없음 응답 재사용)
+//!
+//! 편집 직후에는 `wait_for()`로 새 진단이 도착할 때까지 기다릴 수 있고,
+//! `diagnostics` 도구와 피드백 루프는 버퍼된 진단을 동기적으로 조회합니다.
+
+use super::types::{uri_to_path, Diagnostic, DiagnosticSeverity};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// 진단 수집 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSource {
+    /// 서버가 보낸 `publishDiagnostics`
+    Push,
+    /// 클라이언트가 요청한 `textDocument/diagnostic`
+    Pull,
+}
+
+/// 문서 하나의 진단
+#[derive(Debug, Clone)]
+pub struct DocumentDiagnostics {
+    /// 문서 URI
+    pub uri: String,
+    /// 진단이 계산된 문서 버전 (서버가 알려준 경우)
+    pub version: Option<i32>,
+    /// 진단 목록
+    pub diagnostics: Vec<Diagnostic>,
+    /// 수집 방식
+    pub source: DiagnosticSource,
+    /// pull 결과 ID (다음 요청의 `previousResultId`)
+    pub result_id: Option<String>,
+    /// 마지막 갱신 시각
+    pub updated_at: Instant,
+}
+
+impl DocumentDiagnostics {
+    /// 표시용 경로 (`root` 기준 상대 경로, 아니면 URI의 경로)
+    pub fn display_path(&self, root: Option<&Path>) -> String {
+        display_uri(&self.uri, root)
+    }
+
+    /// 심각도별 개수
+    pub fn counts(&self) -> DiagnosticCounts {
+        DiagnosticCounts::from_diagnostics(&self.diagnostics)
+    }
+}
+
+/// 심각도별 진단 개수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiagnosticCounts {
+    pub errors: usize,
+    pub warnings: usize,
+    pub others: usize,
+}
+
+impl DiagnosticCounts {
+    pub fn from_diagnostics(diagnostics: &[Diagnostic]) -> Self {
+        let mut counts = Self::default();
+        for diagnostic in diagnostics {
+            match diagnostic.severity() {
+                DiagnosticSeverity::Error => counts.errors += 1,
+                DiagnosticSeverity::Warning => counts.warnings += 1,
+                _ => counts.others += 1,
+            }
+        }
+        counts
+    }
+
+    pub fn total(&self) -> usize {
+        self.errors + self.warnings + self.others
+    }
+
+    /// 요약 (예: "2 errors, 1 warning")
+    pub fn summary(&self) -> String {
+        let plural = |count: usize, noun: &str| {
+            format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" })
+        };
+        let mut parts = vec![plural(self.errors, "error"), plural(self.warnings, "warning")];
+        if self.others > 0 {
+            parts.push(format!("{} info", self.others));
+        }
+        parts.join(", ")
+    }
+}
+
+/// 문서별 진단 버퍼
+#[derive(Debug, Default)]
+pub struct DiagnosticsStore {
+    documents: RwLock<HashMap<String, DocumentDiagnostics>>,
+    updated: Notify,
+}
+
+impl DiagnosticsStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// push 진단 저장
+    pub fn publish(&self, uri: &str, version: Option<i32>, diagnostics: Vec<Diagnostic>) {
+        self.insert(DocumentDiagnostics {
+            uri: uri.to_string(),
+            version,
+            diagnostics,
+            source: DiagnosticSource::Push,
+            result_id: None,
+            updated_at: Instant::now(),
+        });
+    }
+
+    /// `textDocument/publishDiagnostics` 알림 파라미터 처리
+    pub fn handle_publish(&self, params: &Value) -> bool {
+        let Some(uri) = params.get("uri").and_then(Value::as_str) else {
+            return false;
+        };
+        let version = params
+            .get("version")
+            .and_then(Value::as_i64)
+            .map(|v| v as i32);
+        let diagnostics = parse_diagnostics(params.get("diagnostics"));
+        self.publish(uri, version, diagnostics);
+        true
+    }
+
+    /// pull 진단 저장
+    pub fn store_pulled(
+        &self,
+        uri: &str,
+        version: Option<i32>,
+        result_id: Option<String>,
+        diagnostics: Vec<Diagnostic>,
+    ) {
+        self.insert(DocumentDiagnostics {
+            uri: uri.to_string(),
+            version,
+            diagnostics,
+            source: DiagnosticSource::Pull,
+            result_id,
+            updated_at: Instant::now(),
+        });
+    }
+
+    /// 변경 없음(`unchanged`) 응답: 기존 진단을 유지하고 시각만 갱신
+    pub fn touch(&self, uri: &str, version: Option<i32>) -> Option<DocumentDiagnostics> {
+        let doc = {
+            let mut documents = self.documents.write().unwrap_or_else(|e| e.into_inner());
+            let doc = documents.get_mut(uri)?;
+            doc.updated_at = Instant::now();
+            if version.is_some() {
+                doc.version = version;
+            }
+            doc.clone()
+        };
+        self.updated.notify_waiters();
+        Some(doc)
+    }
+
+    fn insert(&self, doc: DocumentDiagnostics) {
+        self.documents
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(doc.uri.clone(), doc);
+        self.updated.notify_waiters();
+    }
+
+    /// 문서 진단 조회
+    pub fn get(&self, uri: &str) -> Option<DocumentDiagnostics> {
+        self.documents
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(uri)
+            .cloned()
+    }
+
+    /// pull 결과 ID
+    pub fn result_id(&self, uri: &str) -> Option<String> {
+        self.get(uri).and_then(|doc| doc.result_id)
+    }
+
+    /// 파일 경로로 진단 조회
+    pub fn for_path(&self, path: &Path) -> Vec<Diagnostic> {
+        self.get(&super::types::path_to_uri(path))
+            .map(|doc| doc.diagnostics)
+            .unwrap_or_default()
+    }
+
+    /// 진단이 있는 모든 문서 (URI 순)
+    pub fn all(&self) -> Vec<DocumentDiagnostics> {
+        let mut docs: Vec<_> = self
+            .documents
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .filter(|doc| !doc.diagnostics.is_empty())
+            .cloned()
+            .collect();
+        docs.sort_by(|a, b| a.uri.cmp(&b.uri));
+        docs
+    }
+
+    /// 전체 개수
+    pub fn counts(&self) -> DiagnosticCounts {
+        let documents = self.documents.read().unwrap_or_else(|e| e.into_inner());
+        documents
+            .values()
+            .map(DocumentDiagnostics::counts)
+            .fold(DiagnosticCounts::default(), |acc, c| DiagnosticCounts {
+                errors: acc.errors + c.errors,
+                warnings: acc.warnings + c.warnings,
+                others: acc.others + c.others,
+            })
+    }
+
+    /// 문서 진단 제거 (문서를 닫을 때)
+    pub fn remove(&self, uri: &str) -> Option<DocumentDiagnostics> {
+        self.documents
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(uri)
+    }
+
+    /// 전체 제거
+    pub fn clear(&self) {
+        self.documents
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// `since` 이후 갱신된 진단을 기다림
+    ///
+    /// 시간 안에 도착하지 않으면 버퍼된 (이전) 진단을 반환합니다.
+    pub async fn wait_for(
+        &self,
+        uri: &str,
+        since: Instant,
+        timeout: Duration,
+    ) -> Option<DocumentDiagnostics> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let notified = self.updated.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if let Some(doc) = self.get(uri).filter(|doc| doc.updated_at >= since) {
+                return Some(doc);
+            }
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                return self.get(uri);
+            }
+        }
+    }
+}
+
+/// 표시용 경로 (`root` 기준 상대 경로, 아니면 URI의 경로)
+pub(crate) fn display_uri(uri: &str, root: Option<&Path>) -> String {
+    let Some(path) = uri_to_path(uri) else {
+        return uri.to_string();
+    };
+    match root.and_then(|root| Path::new(&path).strip_prefix(root).ok()) {
+        Some(relative) => relative.to_string_lossy().replace('\\', "/"),
+        None => path,
+    }
+}
+
+/// 진단 배열 파싱 (잘못된 항목은 건너뜀)
+pub fn parse_diagnostics(value: Option<&Value>) -> Vec<Diagnostic> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| serde_json::from_value(item.clone()).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 진단 목록 출력 (에러 먼저, 같은 심각도는 위치 순)
+pub fn format_diagnostics(path: &str, diagnostics: &[Diagnostic]) -> String {
+    let mut sorted: Vec<&Diagnostic> = diagnostics.iter().collect();
+    sorted.sort_by_key(|d| (d.severity(), d.range.start.line, d.range.start.character));
+    sorted
+        .iter()
+        .map(|d| d.format(path))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn publish_params(uri: &str, version: i32) -> Value {
+        json!({
+            "uri": uri,
+            "version": version,
+            "diagnostics": [
+                {
+                    "range": { "start": { "line": 9, "character": 0 }, "end": { "line": 9, "character": 3 } },
+                    "severity": 2,
+                    "message": "unused variable: `x`"
+                },
+                {
+                    "range": { "start": { "line": 2, "character": 4 }, "end": { "line": 2, "character": 9 } },
+                    "severity": 1,
+                    "code": "E0308",
+                    "message": "mismatched types"
+                },
+                { "message": "missing range is skipped" }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_handle_publish() {
+        let store = DiagnosticsStore::new();
+        assert!(store.handle_publish(&publish_params("file:///repo/src/main.rs", 3)));
+        assert!(!store.handle_publish(&json!({})));
+
+        let doc = store.get("file:///repo/src/main.rs").unwrap();
+        assert_eq!(doc.version, Some(3));
+        assert_eq!(doc.source, DiagnosticSource::Push);
+        assert_eq!(doc.diagnostics.len(), 2);
+        assert_eq!(doc.counts().summary(), "1 error, 1 warning");
+        assert_eq!(doc.display_path(Some(Path::new("/repo"))), "src/main.rs");
+
+        assert_eq!(
+            format_diagnostics("src/main.rs", &doc.diagnostics),
+            "src/main.rs:3:5: error[E0308]: mismatched types\nsrc/main.rs:10:1: warning: unused variable: `x`"
+        );
+
+        // 진단이 비면 목록에서 빠짐
+        store.publish("file:///repo/src/main.rs", Some(4), vec![]);
+        assert!(store.all().is_empty());
+        assert_eq!(store.counts().total(), 0);
+    }
+
+    #[test]
+    fn test_pull_result_id() {
+        let store = DiagnosticsStore::new();
+        store.store_pulled(
+            "file:///repo/a.ts",
+            Some(1),
+            Some("r1".to_string()),
+            parse_diagnostics(publish_params("", 0).get("diagnostics")),
+        );
+        assert_eq!(store.result_id("file:///repo/a.ts").as_deref(), Some("r1"));
+
+        let doc = store.touch("file:///repo/a.ts", Some(2)).unwrap();
+        assert_eq!(doc.version, Some(2));
+        assert_eq!(doc.diagnostics.len(), 2);
+        assert!(store.touch("file:///repo/missing.ts", None).is_none());
+
+        store.remove("file:///repo/a.ts");
+        assert!(store.get("file:///repo/a.ts").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_fresh_diagnostics() {
+        let store = Arc::new(DiagnosticsStore::new());
+        store.publish("file:///repo/lib.rs", Some(1), vec![]);
+        tokio::time::sleep(Duration::from_millis(2)).await;
+        let since = Instant::now();
+
+        // 오래된 진단만 있으면 타임아웃 후 그것을 반환
+        let stale = store
+            .wait_for("file:///repo/lib.rs", since, Duration::from_millis(20))
+            .await
+            .unwrap();
+        assert_eq!(stale.version, Some(1));
+
+        let publisher = Arc::clone(&store);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            publisher.handle_publish(&publish_params("file:///repo/lib.rs", 2));
+        });
+        let fresh = store
+            .wait_for("file:///repo/lib.rs", since, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(fresh.version, Some(2));
+        assert_eq!(fresh.diagnostics.len(), 2);
+    }
+}
@@ -5,9 +5,16 @@
 //! 2. 타임아웃 기반 자동 종료: 일정 시간 미사용 시 서버 종료
 //! 3. 최소 메모리: 필요할 때만 클라이언트 생성
 //! 4. 서버 가용성 캐싱: which 결과 캐시
-
-use super::{LspClient, LspClientState, LspServerConfig};
-use forge_foundation::Result;
+//!
+//! 모든 언어 서버의 진단은 매니저의 `DiagnosticsStore` 하나에 모입니다.
+
+use super::diagnostics::DiagnosticsStore;
+use super::{
+    apply_text_edits, path_to_uri, Diagnostic, DocumentSymbol, LspClient, LspClientState,
+    LspServerConfig, SymbolInformation,
+};
+use async_trait::async_trait;
+use forge_foundation::{DocumentSync, Result};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
@@ -103,6 +110,9 @@ pub struct LspManager {
 
     /// 서버 가용성 캐시
     availability_cache: RwLock<AvailabilityCache>,
+
+    /// 진단 버퍼 (모든 클라이언트 공유)
+    diagnostics: Arc<DiagnosticsStore>,
 }
 
 impl LspManager {
@@ -114,6 +124,7 @@ impl LspManager {
             enabled: RwLock::new(true),
             idle_timeout: DEFAULT_IDLE_TIMEOUT,
             availability_cache: RwLock::new(AvailabilityCache::new()),
+            diagnostics: Arc::new(DiagnosticsStore::new()),
         }
     }
 
@@ -125,9 +136,15 @@ impl LspManager {
             enabled: RwLock::new(true),
             idle_timeout: DEFAULT_IDLE_TIMEOUT,
             availability_cache: RwLock::new(AvailabilityCache::new()),
+            diagnostics: Arc::new(DiagnosticsStore::new()),
         }
     }
 
+    /// 진단 버퍼
+    pub fn diagnostics(&self) -> Arc<DiagnosticsStore> {
+        Arc::clone(&self.diagnostics)
+    }
+
     /// 유휴 타임아웃 설정
     pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
         self.idle_timeout = timeout;
@@ -142,6 +159,7 @@ impl LspManager {
             enabled: RwLock::new(false),
             idle_timeout: DEFAULT_IDLE_TIMEOUT,
             availability_cache: RwLock::new(AvailabilityCache::new()),
+            diagnostics: Arc::new(DiagnosticsStore::new()),
         }
     }
 
@@ -200,14 +218,13 @@ impl LspManager {
 
         // 서버 가용성 확인 (캐시 사용)
         if !self.is_server_available(&config.command).await {
-            return Err(forge_foundation::Error::NotFound(format!(
-                "LSP server not installed: {}",
-                config.command
-            )));
+            return Err(forge_foundation::Error::NotFound(
+                config.not_installed_message(),
+            ));
         }
 
         // 새 클라이언트 생성 및 시작
-        let client = LspClient::new(config);
+        let client = LspClient::new(config).with_diagnostics(Arc::clone(&self.diagnostics));
         client.start(root_path).await?;
 
         let managed = Arc::new(ManagedClient::new(client));
@@ -248,6 +265,119 @@ impl LspManager {
         self.get_or_start(&language, &root).await
     }
 
+    /// 파일 진단 (편집 후 확인용)
+    ///
+    /// 디스크의 현재 내용으로 문서를 동기화한 뒤, 서버가 pull 진단을 지원하면
+    /// 요청하고 아니면 `wait` 동안 새 push 진단을 기다립니다.
+    /// 기다리는 동안 도착하지 않으면 버퍼된 진단(없으면 빈 목록)을 반환합니다.
+    pub async fn file_diagnostics(&self, file_path: &Path, wait: Duration) -> Result<Vec<Diagnostic>> {
+        let client = self.get_for_file(file_path).await?;
+        let content = tokio::fs::read_to_string(file_path).await?;
+        let uri = path_to_uri(file_path);
+
+        let since = Instant::now();
+        client
+            .sync_document(&uri, client.language_id(), &content)
+            .await?;
+
+        if client.supports_pull_diagnostics().await {
+            return client.pull_diagnostics(&uri).await;
+        }
+        Ok(self
+            .diagnostics
+            .wait_for(&uri, since, wait)
+            .await
+            .map(|doc| doc.diagnostics)
+            .unwrap_or_default())
+    }
+
+    /// 파일의 문서 심볼 (RepoMap 인덱스용)
+    ///
+    /// 이미 열린 문서는 디스크 내용으로 동기화하고, 열려 있지 않으면 잠시 열었다가
+    /// 응답 후 닫습니다.
+    pub async fn document_symbols(&self, file_path: &Path) -> Result<Vec<DocumentSymbol>> {
+        let client = self.get_for_file(file_path).await?;
+        let content = tokio::fs::read_to_string(file_path).await?;
+        let uri = path_to_uri(file_path);
+
+        let was_open = client.document_version(&uri).await.is_some();
+        client
+            .sync_document(&uri, client.language_id(), &content)
+            .await?;
+        let symbols = client.document_symbols(&uri).await;
+        if !was_open {
+            client.did_close(&uri).await?;
+        }
+        symbols
+    }
+
+    /// 파일을 디스크 내용으로 동기화하고 클라이언트와 URI 반환 (위치 기반 질의용)
+    pub async fn open_document(&self, file_path: &Path) -> Result<(Arc<LspClient>, String)> {
+        let client = self.get_for_file(file_path).await?;
+        let content = tokio::fs::read_to_string(file_path).await?;
+        let uri = path_to_uri(file_path);
+        client
+            .sync_document(&uri, client.language_id(), &content)
+            .await?;
+        Ok((client, uri))
+    }
+
+    /// 실행 중인 서버로 내용 포맷 (`textDocument/formatting`)
+    ///
+    /// 서버를 새로 시작하지 않으며, 준비된 서버가 없거나 포맷을 지원하지 않으면
+    /// `None`을 반환합니다.
+    pub async fn format_document(
+        &self,
+        file_path: &Path,
+        content: &str,
+        tab_size: u32,
+        insert_spaces: bool,
+    ) -> Result<Option<String>> {
+        let Some(language) = self.detect_language(file_path) else {
+            return Ok(None);
+        };
+        let Some(client) = self.get(&language).await else {
+            return Ok(None);
+        };
+        if client.state().await != LspClientState::Ready
+            || !client.supports_formatting().await
+        {
+            return Ok(None);
+        }
+
+        let uri = path_to_uri(file_path);
+        client
+            .sync_document(&uri, client.language_id(), content)
+            .await?;
+        let edits = client.formatting(&uri, tab_size, insert_spaces).await?;
+        Ok(Some(apply_text_edits(content, &edits)))
+    }
+
+    /// 실행 중인 모든 서버에서 워크스페이스 심볼 검색
+    ///
+    /// 서버를 새로 시작하지 않으며, 실패한 서버는 건너뜁니다.
+    pub async fn workspace_symbols(&self, query: &str) -> Vec<SymbolInformation> {
+        let clients: Vec<_> = self
+            .clients
+            .read()
+            .await
+            .values()
+            .map(|managed| Arc::clone(&managed.client))
+            .collect();
+
+        let mut symbols = Vec::new();
+        for client in clients {
+            if client.state().await != LspClientState::Ready {
+                continue;
+            }
+            match client.workspace_symbols(query).await {
+                Ok(found) => symbols.extend(found),
+                Err(e) => debug!("workspace/symbol failed for {}: {}", client.language_id(), e),
+            }
+        }
+        symbols
+    }
+
     /// 모든 클라이언트 종료
     pub async fn shutdown_all(&self) -> Result<()> {
         let mut clients = self.clients.write().await;
@@ -257,6 +387,7 @@ impl LspManager {
                 warn!("Failed to shutdown LSP for {}: {}", lang, e);
             }
         }
+        self.diagnostics.clear();
 
         debug!("All LSP servers shutdown");
         Ok(())
@@ -297,10 +428,14 @@ impl LspManager {
     // 언어 감지
     // ========================================================================
 
-    /// 파일 확장자로 언어 감지
+    /// 파일 확장자로 언어 감지 (설정의 `extensions`가 내장 매핑보다 우선)
     pub fn detect_language(&self, file_path: &Path) -> Option<String> {
         let extension = file_path.extension()?.to_str()?;
 
+        if let Some(config) = self.configs.iter().find(|c| c.handles_extension(extension)) {
+            return Some(config.language_id.clone());
+        }
+
         match extension.to_lowercase().as_str() {
             // Rust
             "rs" => Some("rust".to_string()),
@@ -344,6 +479,11 @@ impl LspManager {
         }
     }
 
+    /// 서버 설정 목록
+    pub fn configs(&self) -> &[LspServerConfig] {
+        &self.configs
+    }
+
     /// 지원 언어 목록
     pub fn supported_languages(&self) -> Vec<&str> {
         self.configs.iter().map(|c| c.language_id.as_str()).collect()
@@ -389,10 +529,8 @@ impl LspManager {
         // 상위 디렉토리 탐색
         let mut current = file_path.parent();
         while let Some(dir) = current {
-            for pattern in &patterns {
-                if dir.join(pattern).exists() {
-                    return dir.to_path_buf();
-                }
+            if patterns.iter().any(|pattern| root_marker_exists(dir, pattern)) {
+                return dir.to_path_buf();
             }
             current = dir.parent();
         }
@@ -405,6 +543,65 @@ impl LspManager {
     }
 }
 
+/// 루트 표식 파일 확인 (`*.csproj`처럼 확장자 패턴 지원)
+fn root_marker_exists(dir: &Path, pattern: &str) -> bool {
+    match pattern.strip_prefix("*.") {
+        Some(extension) => std::fs::read_dir(dir).is_ok_and(|entries| {
+            entries.flatten().any(|entry| {
+                entry
+                    .path()
+                    .extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case(extension))
+            })
+        }),
+        None => dir.join(pattern).exists(),
+    }
+}
+
+/// 도구가 쓴 파일을 실행 중인 서버에 동기화 (서버를 새로 시작하지 않음)
+#[async_trait]
+impl DocumentSync for LspManager {
+    async fn file_changed(&self, path: &Path, content: &str) {
+        let Some(language) = self.detect_language(path) else {
+            return;
+        };
+        let Some(client) = self.get(&language).await else {
+            return;
+        };
+        if client.state().await != LspClientState::Ready {
+            return;
+        }
+
+        let uri = path_to_uri(path);
+        let result = async {
+            client
+                .sync_document(&uri, client.language_id(), content)
+                .await?;
+            client.did_save(&uri, content).await
+        }
+        .await;
+        if let Err(e) = result {
+            debug!("Failed to sync {} with LSP: {}", path.display(), e);
+        }
+    }
+
+    async fn file_removed(&self, path: &Path) {
+        let Some(language) = self.detect_language(path) else {
+            return;
+        };
+        let Some(client) = self.get(&language).await else {
+            return;
+        };
+        let uri = path_to_uri(path);
+        if client.document_version(&uri).await.is_some() {
+            if let Err(e) = client.did_close(&uri).await {
+                debug!("Failed to close {} in LSP: {}", path.display(), e);
+            }
+        }
+        self.diagnostics.remove(&uri);
+    }
+}
+
 impl Default for LspManager {
     fn default() -> Self {
         Self::new()
@@ -419,6 +616,21 @@ impl Default for LspManager {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_document_sync_without_server() {
+        // 실행 중인 서버가 없으면 아무 것도 시작하지 않음
+        let manager = LspManager::new();
+        let path = Path::new("/tmp/forge_sync.rs");
+        manager.file_changed(path, "fn main() {}").await;
+        assert!(manager.get("rust").await.is_none());
+
+        manager
+            .diagnostics()
+            .publish(&path_to_uri(path), Some(1), Vec::new());
+        manager.file_removed(path).await;
+        assert!(manager.diagnostics().all().is_empty());
+    }
+
     #[test]
     fn test_manager_new() {
         let manager = LspManager::new();
@@ -449,6 +661,36 @@ mod tests {
         assert_eq!(manager.detect_language(Path::new("unknown.xyz")), None);
     }
 
+    #[test]
+    fn test_config_extensions_and_roots() {
+        let mut manager = LspManager::new();
+        assert_eq!(
+            manager.detect_language(Path::new("Program.cs")),
+            Some("csharp".to_string())
+        );
+        assert!(manager.supported_languages().contains(&"zig"));
+
+        manager.add_config(LspServerConfig {
+            language_id: "elixir".to_string(),
+            command: "elixir-ls".to_string(),
+            args: vec![],
+            root_patterns: vec!["mix.exs".to_string()],
+            initialization_options: None,
+            extensions: vec!["ex".to_string(), "exs".to_string()],
+            install_hint: None,
+        });
+        assert_eq!(
+            manager.detect_language(Path::new("lib/app.EX")),
+            Some("elixir".to_string())
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("App.csproj"), "<Project />").unwrap();
+        assert!(root_marker_exists(dir.path(), "*.csproj"));
+        assert!(!root_marker_exists(dir.path(), "*.sln"));
+        assert!(!root_marker_exists(dir.path(), "mix.exs"));
+    }
+
     #[test]
     fn test_disabled_manager() {
         let manager = LspManager::disabled();
@@ -6,7 +6,7 @@
 //!
 //! 1. **경량화**: lsp-types 의존성 없이 필요한 타입만 직접 정의
 //! 2. **Lazy Loading**: Agent가 실제로 요청할 때만 LSP 서버 시작
-//! 3. **최소 기능**: 핵심 기능만 구현 (definition, references, hover)
+//! 3. **최소 기능**: 핵심 기능만 구현 (definition, references, hover, diagnostics)
 //! 4. **자동 정리**: 일정 시간 미사용 시 서버 자동 종료
 //!
 //! ## 핵심 기능 (Agent 필수)
@@ -14,6 +14,9 @@
 //! - `textDocument/definition` - 정의로 이동
 //! - `textDocument/references` - 참조 찾기
 //! - `textDocument/hover` - 심볼 정보
+//! - `textDocument/publishDiagnostics` / `textDocument/diagnostic` - 진단 (push/pull)
+//! - `textDocument/documentSymbol` / `workspace/symbol` - 심볼 (RepoMap 인덱스)
+//! - `callHierarchy/*` / `typeHierarchy/*` - 호출/타입 계층 (`who_calls` 도구)
 //!
 //! ## 지원 언어
 //!
@@ -21,6 +24,8 @@
 //! - TypeScript/JavaScript (typescript-language-server)
 //! - Python (pylsp)
 //! - Go (gopls)
+//! - C# (OmniSharp), Java (jdtls), Ruby (solargraph), PHP (intelephense), Zig (zls)
+//! - 그 외 언어는 `lsp.json`으로 추가 ([`LspConfigFile`])
 //!
 //! ## 사용 예시
 //!
@@ -58,15 +63,27 @@
 //! - **On-demand**: LSP 서버는 필요할 때만 시작
 //! - **Idle timeout**: 10분 미사용 시 자동 종료
 //! - **Availability cache**: 서버 설치 여부 5분 캐싱
-//! - **No diagnostics**: 실시간 진단 스트림 없음 (Agent에 불필요)
+//! - **Buffered diagnostics**: 진단은 문서별로 버퍼하고 요청할 때만 조회
+//!   (`diagnostics` 도구, 피드백 루프)
 
 mod client;
+mod config;
+mod diagnostics;
 mod manager;
+mod tool;
 mod types;
+mod who_calls;
 
 pub use client::{LspClient, LspClientState, LspRestartConfig};
+pub use config::{load_lsp_configs, LspConfigFile, LspServerEntry, LSP_FILE};
+pub use diagnostics::{
+    format_diagnostics, parse_diagnostics, DiagnosticCounts, DiagnosticSource, DiagnosticsStore,
+    DocumentDiagnostics,
+};
 pub use manager::LspManager;
+pub use tool::DiagnosticsTool;
 pub use types::*;
+pub use who_calls::{render_hierarchy, CallDirection, HierarchyEntry, WhoCallsTool};
 
 /// LSP 매니저 팩토리 함수
 pub fn create_lsp_manager() -> LspManager {
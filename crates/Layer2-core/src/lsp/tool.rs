@@ -0,0 +1,269 @@
+//! Diagnostics Tool - 편집 후 컴파일 에러/경고 확인
+//!
+//! `path`를 주면 파일을 언어 서버와 동기화해 최신 진단을 받고,
+//! 생략하면 지금까지 버퍼된 모든 문서의 진단을 보여줍니다.
+//!
+//! ```text
+//! src/main.rs: 1 error, 1 warning
+//! src/main.rs:3:5: error[E0308]: mismatched types (rustc)
+//! src/main.rs:10:1: warning: unused variable: `x` (rustc)
+//! ```
+
+use super::diagnostics::{format_diagnostics, DiagnosticCounts};
+use super::{Diagnostic, DiagnosticSeverity, LspManager};
+use async_trait::async_trait;
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use serde_json::{json, Value};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 기본 대기 시간 (push 진단)
+const DEFAULT_WAIT_MS: u64 = 3000;
+
+/// 최대 대기 시간
+const MAX_WAIT_MS: u64 = 30_000;
+
+/// LSP 진단 조회 도구
+pub struct DiagnosticsTool {
+    lsp: Arc<LspManager>,
+}
+
+impl DiagnosticsTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "diagnostics";
+
+    pub fn new(lsp: Arc<LspManager>) -> Self {
+        Self { lsp }
+    }
+}
+
+#[async_trait]
+impl Tool for DiagnosticsTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Diagnostics")
+            .description("Compiler errors and warnings from the language server. Pass a file path after editing it to get fresh diagnostics; omit it to list everything reported so far.")
+            .category("lsp")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "File to check (relative to the working directory). Omit to list buffered diagnostics of all open files."
+                },
+                "severity": {
+                    "type": "string",
+                    "enum": ["error", "warning", "info", "hint"],
+                    "description": "Minimum severity to show (default: hint, i.e. everything)"
+                },
+                "wait_ms": {
+                    "type": "integer",
+                    "description": "How long to wait for fresh diagnostics after syncing the file (default: 3000, max: 30000)"
+                }
+            }
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let min_severity = match input["severity"].as_str() {
+            Some(value) => match DiagnosticSeverity::parse(value) {
+                Some(severity) => severity,
+                None => {
+                    return Ok(ToolResult::error(format!(
+                        "Unknown severity '{}' (expected error, warning, info or hint)",
+                        value
+                    )))
+                }
+            },
+            None => DiagnosticSeverity::Hint,
+        };
+        let keep = |diagnostics: Vec<Diagnostic>| -> Vec<Diagnostic> {
+            diagnostics
+                .into_iter()
+                .filter(|d| d.severity() <= min_severity)
+                .collect()
+        };
+
+        let Some(path) = input["path"].as_str().filter(|p| !p.trim().is_empty()) else {
+            let docs = self.lsp.diagnostics().all();
+            let mut sections = Vec::new();
+            let mut total = DiagnosticCounts::default();
+            for doc in docs {
+                let display = doc.display_path(Some(ctx.working_dir()));
+                let diagnostics = keep(doc.diagnostics);
+                if diagnostics.is_empty() {
+                    continue;
+                }
+                let counts = DiagnosticCounts::from_diagnostics(&diagnostics);
+                total.errors += counts.errors;
+                total.warnings += counts.warnings;
+                total.others += counts.others;
+                sections.push(format!(
+                    "{}: {}\n{}",
+                    display,
+                    counts.summary(),
+                    format_diagnostics(&display, &diagnostics)
+                ));
+            }
+            if sections.is_empty() {
+                return Ok(ToolResult::success(
+                    "No diagnostics reported. Pass a file path to check a file.",
+                ));
+            }
+            return Ok(ToolResult::success(sections.join("\n\n"))
+                .with_metadata("errors", json!(total.errors))
+                .with_metadata("warnings", json!(total.warnings)));
+        };
+
+        let file = ctx.working_dir().join(path);
+        if !file.is_file() {
+            return Ok(ToolResult::error(format!("File not found: {}", path)));
+        }
+        let wait = Duration::from_millis(
+            input["wait_ms"]
+                .as_u64()
+                .unwrap_or(DEFAULT_WAIT_MS)
+                .min(MAX_WAIT_MS),
+        );
+
+        let diagnostics = match self.lsp.file_diagnostics(&file, wait).await {
+            Ok(diagnostics) => keep(diagnostics),
+            Err(e) => {
+                return Ok(ToolResult::error(format!(
+                    "No diagnostics for {}: {}",
+                    path, e
+                )))
+            }
+        };
+
+        let display = display_path(path);
+        let counts = DiagnosticCounts::from_diagnostics(&diagnostics);
+        let output = if diagnostics.is_empty() {
+            format!("{}: no diagnostics", display)
+        } else {
+            format!(
+                "{}: {}\n{}",
+                display,
+                counts.summary(),
+                format_diagnostics(&display, &diagnostics)
+            )
+        };
+        Ok(ToolResult::success(output)
+            .with_metadata("errors", json!(counts.errors))
+            .with_metadata("warnings", json!(counts.warnings)))
+    }
+}
+
+/// 입력 경로 정리 (`./` 제거, 구분자 통일)
+fn display_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    Path::new(&path)
+        .strip_prefix("./")
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+
+    fn diagnostic(line: u32, severity: u8, message: &str) -> Diagnostic {
+        serde_json::from_value(json!({
+            "range": { "start": { "line": line, "character": 0 }, "end": { "line": line, "character": 1 } },
+            "severity": severity,
+            "message": message
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_buffered_diagnostics() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let lsp = Arc::new(LspManager::new());
+        let tool = DiagnosticsTool::new(Arc::clone(&lsp));
+
+        let result = tool.execute(json!({}), &ctx).await.unwrap();
+        assert!(result.output.starts_with("No diagnostics reported"));
+
+        let uri = super::super::path_to_uri(&dir.path().join("src/lib.rs"));
+        lsp.diagnostics().publish(
+            &uri,
+            Some(1),
+            vec![
+                diagnostic(4, 2, "unused import"),
+                diagnostic(1, 1, "cannot find value `x`"),
+            ],
+        );
+
+        let result = tool.execute(json!({}), &ctx).await.unwrap();
+        assert_eq!(
+            result.output,
+            "src/lib.rs: 1 error, 1 warning\nsrc/lib.rs:2:1: error: cannot find value `x`\nsrc/lib.rs:5:1: warning: unused import"
+        );
+
+        let result = tool
+            .execute(json!({ "severity": "error" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.output.contains("unused import"));
+
+        let result = tool
+            .execute(json!({ "severity": "fatal" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn test_file_without_server() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("notes.xyz"), "text").unwrap();
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let tool = DiagnosticsTool::new(Arc::new(LspManager::new()));
+
+        let result = tool
+            .execute(json!({ "path": "./notes.xyz" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap()
+            .starts_with("No diagnostics for ./notes.xyz"));
+
+        let result = tool
+            .execute(json!({ "path": "missing.rs" }), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(result.error.as_deref(), Some("File not found: missing.rs"));
+    }
+
+    #[test]
+    fn test_display_path() {
+        assert_eq!(display_path("./src/main.rs"), "src/main.rs");
+        assert_eq!(display_path("src\\main.rs"), "src/main.rs");
+    }
+}
@@ -130,11 +130,124 @@ impl Hover {
 }
 
 // ============================================================================
-// 심볼 정보 (선택적 - Phase 2)
+// 진단 (Diagnostics)
+// ============================================================================
+
+/// 진단 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum DiagnosticSeverity {
+    Error = 1,
+    Warning = 2,
+    Information = 3,
+    Hint = 4,
+}
+
+impl DiagnosticSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Information => "info",
+            Self::Hint => "hint",
+        }
+    }
+
+    /// 이름으로 파싱 (`error`, `warning`, `info`, `hint`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "error" | "errors" => Some(Self::Error),
+            "warning" | "warnings" | "warn" => Some(Self::Warning),
+            "info" | "information" => Some(Self::Information),
+            "hint" | "hints" => Some(Self::Hint),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for DiagnosticSeverity {
+    type Error = String;
+
+    fn try_from(value: u8) -> std::result::Result<Self, String> {
+        match value {
+            1 => Ok(Self::Error),
+            2 => Ok(Self::Warning),
+            3 => Ok(Self::Information),
+            4 => Ok(Self::Hint),
+            other => Err(format!("invalid diagnostic severity: {}", other)),
+        }
+    }
+}
+
+impl From<DiagnosticSeverity> for u8 {
+    fn from(severity: DiagnosticSeverity) -> Self {
+        severity as u8
+    }
+}
+
+/// 진단 항목 (컴파일 에러, 경고 등)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// 범위
+    pub range: Range,
+
+    /// 심각도 (없으면 에러로 취급)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<DiagnosticSeverity>,
+
+    /// 진단 코드 (숫자 또는 문자열, 예: E0308)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<serde_json::Value>,
+
+    /// 진단 출처 (예: rustc, clippy, tsserver)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// 메시지
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// 실제 심각도
+    pub fn severity(&self) -> DiagnosticSeverity {
+        self.severity.unwrap_or(DiagnosticSeverity::Error)
+    }
+
+    /// 에러 여부
+    pub fn is_error(&self) -> bool {
+        self.severity() == DiagnosticSeverity::Error
+    }
+
+    /// 한 줄 형식 (`path:line:col: error[E0308]: message (rustc)`, 1-based)
+    pub fn format(&self, path: &str) -> String {
+        let code = match &self.code {
+            Some(serde_json::Value::String(code)) => format!("[{}]", code),
+            Some(serde_json::Value::Number(code)) => format!("[{}]", code),
+            _ => String::new(),
+        };
+        let mut line = format!(
+            "{}:{}:{}: {}{}: {}",
+            path,
+            self.range.start.line + 1,
+            self.range.start.character + 1,
+            self.severity().as_str(),
+            code,
+            self.message.lines().next().unwrap_or_default()
+        );
+        if let Some(source) = &self.source {
+            line.push_str(&format!(" ({})", source));
+        }
+        line
+    }
+}
+
+// ============================================================================
+// 심볼 정보 (documentSymbol, workspace/symbol)
 // ============================================================================
 
 /// 심볼 종류
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
 #[repr(u8)]
 pub enum SymbolKind {
     File = 1,
@@ -165,16 +278,200 @@ pub enum SymbolKind {
     TypeParameter = 26,
 }
 
-/// 문서 심볼 (간소화)
+impl SymbolKind {
+    const ALL: [SymbolKind; 26] = [
+        Self::File,
+        Self::Module,
+        Self::Namespace,
+        Self::Package,
+        Self::Class,
+        Self::Method,
+        Self::Property,
+        Self::Field,
+        Self::Constructor,
+        Self::Enum,
+        Self::Interface,
+        Self::Function,
+        Self::Variable,
+        Self::Constant,
+        Self::String,
+        Self::Number,
+        Self::Boolean,
+        Self::Array,
+        Self::Object,
+        Self::Key,
+        Self::Null,
+        Self::EnumMember,
+        Self::Struct,
+        Self::Event,
+        Self::Operator,
+        Self::TypeParameter,
+    ];
+}
+
+impl TryFrom<u8> for SymbolKind {
+    type Error = String;
+
+    fn try_from(value: u8) -> std::result::Result<Self, String> {
+        value
+            .checked_sub(1)
+            .and_then(|i| Self::ALL.get(i as usize).copied())
+            .ok_or_else(|| format!("invalid symbol kind: {}", value))
+    }
+}
+
+impl From<SymbolKind> for u8 {
+    fn from(kind: SymbolKind) -> Self {
+        kind as u8
+    }
+}
+
+/// 문서 심볼 (`textDocument/documentSymbol`, 계층 구조)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DocumentSymbol {
     pub name: String,
+    /// 추가 정보 (보통 시그니처 또는 타입)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
     pub kind: SymbolKind,
+    /// 심볼 전체 범위 (본문 포함)
     pub range: Range,
+    /// 심볼 이름 범위
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selection_range: Option<Range>,
     #[serde(default)]
     pub children: Vec<DocumentSymbol>,
 }
 
+/// 평면 심볼 정보 (`workspace/symbol`, 구형 `documentSymbol` 응답)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolInformation {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// 위치 (`WorkspaceSymbol`이 범위 없이 URI만 주면 파일 시작으로 채움)
+    pub location: Location,
+    /// 상위 심볼 이름 (예: 메서드의 impl/클래스)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_name: Option<String>,
+}
+
+impl SymbolInformation {
+    /// 서버 응답 항목 파싱 (`location.range`가 없어도 허용)
+    pub fn from_value(mut value: serde_json::Value) -> Option<Self> {
+        let location = value.get_mut("location")?.as_object_mut()?;
+        location.entry("range").or_insert_with(|| {
+            serde_json::json!({
+                "start": { "line": 0, "character": 0 },
+                "end": { "line": 0, "character": 0 }
+            })
+        });
+        serde_json::from_value(value).ok()
+    }
+}
+
+impl From<SymbolInformation> for DocumentSymbol {
+    fn from(symbol: SymbolInformation) -> Self {
+        Self {
+            name: symbol.name,
+            detail: symbol.container_name,
+            kind: symbol.kind,
+            range: symbol.location.range,
+            selection_range: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+// ============================================================================
+// 호출/타입 계층 (callHierarchy, typeHierarchy)
+// ============================================================================
+
+/// 계층 항목 (`CallHierarchyItem`, `TypeHierarchyItem`은 같은 구조)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallHierarchyItem {
+    pub name: String,
+    pub kind: SymbolKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub uri: String,
+    pub range: Range,
+    pub selection_range: Range,
+    /// 서버 전용 데이터 (후속 요청에 그대로 전달)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+/// 타입 계층 항목
+pub type TypeHierarchyItem = CallHierarchyItem;
+
+/// 들어오는 호출 (`from`이 대상 함수를 호출)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallHierarchyIncomingCall {
+    pub from: CallHierarchyItem,
+    /// `from` 안의 호출 위치
+    #[serde(default)]
+    pub from_ranges: Vec<Range>,
+}
+
+/// 나가는 호출 (대상 함수가 `to`를 호출)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallHierarchyOutgoingCall {
+    pub to: CallHierarchyItem,
+    /// 대상 함수 안의 호출 위치
+    #[serde(default)]
+    pub from_ranges: Vec<Range>,
+}
+
+/// 텍스트 편집 (`textDocument/formatting` 응답)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+    pub range: Range,
+    pub new_text: String,
+}
+
+/// 편집 목록 적용 (편집끼리 겹치지 않는다고 가정, 뒤에서부터 적용)
+pub fn apply_text_edits(content: &str, edits: &[TextEdit]) -> String {
+    let mut edits: Vec<&TextEdit> = edits.iter().collect();
+    edits.sort_by_key(|edit| (edit.range.start.line, edit.range.start.character));
+
+    let mut result = content.to_string();
+    for edit in edits.into_iter().rev() {
+        let start = offset_at(&result, edit.range.start);
+        let end = offset_at(&result, edit.range.end).max(start);
+        result.replace_range(start..end, &edit.new_text);
+    }
+    result
+}
+
+/// LSP 위치의 바이트 오프셋 (UTF-16 컬럼, 범위를 넘으면 줄/문서 끝)
+fn offset_at(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len(),
+        }
+    }
+    let line_end = text[line_start..]
+        .find('\n')
+        .map_or(text.len(), |i| line_start + i);
+
+    let mut units = 0;
+    for (i, c) in text[line_start..line_end].char_indices() {
+        if units >= position.character {
+            return line_start + i;
+        }
+        units += c.len_utf16() as u32;
+    }
+    line_end
+}
+
 // ============================================================================
 // 서버 설정
 // ============================================================================
@@ -199,6 +496,36 @@ pub struct LspServerConfig {
     /// 초기화 옵션 (서버별 설정)
     #[serde(default)]
     pub initialization_options: Option<serde_json::Value>,
+
+    /// 이 서버가 처리할 파일 확장자 (비어 있으면 내장 언어 감지 사용)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+
+    /// 설치 안내 (서버가 없을 때 에러 메시지에 표시)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub install_hint: Option<String>,
+}
+
+impl LspServerConfig {
+    /// 서버 실행 파일이 PATH에 있는지
+    pub fn is_installed(&self) -> bool {
+        which::which(&self.command).is_ok()
+    }
+
+    /// 확장자 처리 여부 (대소문자 무시, 앞의 `.` 허용)
+    pub fn handles_extension(&self, extension: &str) -> bool {
+        self.extensions
+            .iter()
+            .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(extension))
+    }
+
+    /// 미설치 에러 메시지
+    pub fn not_installed_message(&self) -> String {
+        match &self.install_hint {
+            Some(hint) => format!("LSP server not installed: {} ({})", self.command, hint),
+            None => format!("LSP server not installed: {}", self.command),
+        }
+    }
 }
 
 /// 기본 LSP 서버 설정 (설치 확인은 런타임에)
@@ -211,6 +538,8 @@ pub fn default_lsp_configs() -> Vec<LspServerConfig> {
             args: vec![],
             root_patterns: vec!["Cargo.toml".to_string()],
             initialization_options: None,
+            extensions: vec![],
+            install_hint: None,
         },
         // TypeScript/JavaScript
         LspServerConfig {
@@ -219,6 +548,8 @@ pub fn default_lsp_configs() -> Vec<LspServerConfig> {
             args: vec!["--stdio".to_string()],
             root_patterns: vec!["tsconfig.json".to_string(), "package.json".to_string()],
             initialization_options: None,
+            extensions: vec![],
+            install_hint: None,
         },
         // Python
         LspServerConfig {
@@ -231,6 +562,8 @@ pub fn default_lsp_configs() -> Vec<LspServerConfig> {
                 "requirements.txt".to_string(),
             ],
             initialization_options: None,
+            extensions: vec![],
+            install_hint: None,
         },
         // Go
         LspServerConfig {
@@ -239,6 +572,71 @@ pub fn default_lsp_configs() -> Vec<LspServerConfig> {
             args: vec![],
             root_patterns: vec!["go.mod".to_string()],
             initialization_options: None,
+            extensions: vec![],
+            install_hint: None,
+        },
+        // C# - OmniSharp
+        LspServerConfig {
+            language_id: "csharp".to_string(),
+            command: "omnisharp".to_string(),
+            args: vec!["--languageserver".to_string()],
+            root_patterns: vec!["*.sln".to_string(), "*.csproj".to_string()],
+            initialization_options: None,
+            extensions: vec![],
+            install_hint: Some(
+                "install omnisharp-roslyn: https://github.com/OmniSharp/omnisharp-roslyn"
+                    .to_string(),
+            ),
+        },
+        // Java - Eclipse JDT Language Server
+        LspServerConfig {
+            language_id: "java".to_string(),
+            command: "jdtls".to_string(),
+            args: vec![],
+            root_patterns: vec![
+                "pom.xml".to_string(),
+                "build.gradle".to_string(),
+                "build.gradle.kts".to_string(),
+                "settings.gradle".to_string(),
+            ],
+            initialization_options: None,
+            extensions: vec![],
+            install_hint: Some(
+                "brew install jdtls, or download from download.eclipse.org/jdtls".to_string(),
+            ),
+        },
+        // Ruby - Solargraph
+        LspServerConfig {
+            language_id: "ruby".to_string(),
+            command: "solargraph".to_string(),
+            args: vec!["stdio".to_string()],
+            root_patterns: vec!["Gemfile".to_string(), ".solargraph.yml".to_string()],
+            initialization_options: None,
+            extensions: vec![],
+            install_hint: Some("gem install solargraph".to_string()),
+        },
+        // PHP - Intelephense
+        LspServerConfig {
+            language_id: "php".to_string(),
+            command: "intelephense".to_string(),
+            args: vec!["--stdio".to_string()],
+            root_patterns: vec!["composer.json".to_string()],
+            initialization_options: None,
+            extensions: vec![],
+            install_hint: Some("npm install -g intelephense".to_string()),
+        },
+        // Zig - zls
+        LspServerConfig {
+            language_id: "zig".to_string(),
+            command: "zls".to_string(),
+            args: vec![],
+            root_patterns: vec!["build.zig".to_string(), "build.zig.zon".to_string()],
+            initialization_options: None,
+            extensions: vec![],
+            install_hint: Some(
+                "install zls matching your Zig version: https://github.com/zigtools/zls"
+                    .to_string(),
+            ),
         },
     ]
 }
@@ -294,6 +692,29 @@ pub fn uri_to_path(uri: &str) -> Option<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_apply_text_edits() {
+        let edit = |sl, sc, el, ec, text: &str| TextEdit {
+            range: Range::new(Position::new(sl, sc), Position::new(el, ec)),
+            new_text: text.to_string(),
+        };
+        let content = "fn main(){\nlet s=\"한글\";x();\n}";
+
+        let edits = vec![
+            edit(1, 0, 1, 0, "    "),
+            edit(0, 9, 0, 9, " "),
+            edit(1, 5, 1, 6, " = "),
+            // UTF-16 컬럼: `"한글";` 뒤
+            edit(1, 11, 1, 11, " "),
+            edit(2, 0, 2, 50, "}\n"),
+        ];
+        assert_eq!(
+            apply_text_edits(content, &edits),
+            "fn main() {\n    let s = \"한글\"; x();\n}\n"
+        );
+        assert_eq!(apply_text_edits(content, &[]), content);
+    }
+
     #[test]
     fn test_position() {
         let pos = Position::new(10, 5);
@@ -326,6 +747,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_diagnostic_format() {
+        let diagnostic: Diagnostic = serde_json::from_value(serde_json::json!({
+            "range": { "start": { "line": 2, "character": 4 }, "end": { "line": 2, "character": 9 } },
+            "severity": 1,
+            "code": "E0308",
+            "source": "rustc",
+            "message": "mismatched types\nexpected `u32`"
+        }))
+        .unwrap();
+        assert!(diagnostic.is_error());
+        assert_eq!(
+            diagnostic.format("src/main.rs"),
+            "src/main.rs:3:5: error[E0308]: mismatched types (rustc)"
+        );
+
+        let warning = Diagnostic {
+            severity: Some(DiagnosticSeverity::Warning),
+            code: None,
+            source: None,
+            ..diagnostic
+        };
+        assert_eq!(serde_json::to_value(&warning).unwrap()["severity"], 2);
+        assert_eq!(
+            DiagnosticSeverity::parse("warnings"),
+            Some(DiagnosticSeverity::Warning)
+        );
+    }
+
+    #[test]
+    fn test_symbol_parsing() {
+        let symbol: DocumentSymbol = serde_json::from_value(serde_json::json!({
+            "name": "Parser",
+            "detail": "struct Parser",
+            "kind": 23,
+            "range": { "start": { "line": 3, "character": 0 }, "end": { "line": 9, "character": 1 } },
+            "selectionRange": { "start": { "line": 3, "character": 11 }, "end": { "line": 3, "character": 17 } },
+            "children": [{
+                "name": "parse",
+                "kind": 6,
+                "range": { "start": { "line": 5, "character": 4 }, "end": { "line": 7, "character": 5 } }
+            }]
+        }))
+        .unwrap();
+        assert_eq!(symbol.kind, SymbolKind::Struct);
+        assert_eq!(symbol.children[0].kind, SymbolKind::Method);
+        assert!(serde_json::from_value::<SymbolKind>(serde_json::json!(27)).is_err());
+
+        // WorkspaceSymbol은 range 없이 올 수 있음
+        let info = SymbolInformation::from_value(serde_json::json!({
+            "name": "parse",
+            "kind": 12,
+            "location": { "uri": "file:///src/lib.rs" },
+            "containerName": "parser"
+        }))
+        .unwrap();
+        assert_eq!(info.location.range.start.line, 0);
+        assert_eq!(info.container_name.as_deref(), Some("parser"));
+        assert!(
+            SymbolInformation::from_value(serde_json::json!({ "name": "x", "kind": 12 })).is_none()
+        );
+    }
+
     #[test]
     fn test_default_configs() {
         let configs = default_lsp_configs();
@@ -333,5 +817,12 @@ mod tests {
         assert!(configs.iter().any(|c| c.language_id == "typescript"));
         assert!(configs.iter().any(|c| c.language_id == "python"));
         assert!(configs.iter().any(|c| c.language_id == "go"));
+
+        let solargraph = configs.iter().find(|c| c.language_id == "ruby").unwrap();
+        assert_eq!(solargraph.args, vec!["stdio"]);
+        assert_eq!(
+            solargraph.not_installed_message(),
+            "LSP server not installed: solargraph (gem install solargraph)"
+        );
     }
 }
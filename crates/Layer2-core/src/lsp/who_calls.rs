@@ -0,0 +1,608 @@
+//! Who Calls Tool - 호출/타입 계층 조회
+//!
+//! "이 함수를 바꾸면 어디가 깨지는가"를 grep 대신 언어 서버의
+//! `callHierarchy/incomingCalls`로 답합니다. `direction`으로 나가는 호출이나
+//! 상위/하위 타입도 조회할 수 있습니다.
+//!
+//! ```text
+//! Callers of `parse_config` (function, src/config.rs:42):
+//! - load (function, src/app.rs:10) — called at 14, 27
+//!   - main (function, src/main.rs:3) — called at 5
+//! ```
+
+use super::diagnostics::display_uri;
+use super::{CallHierarchyItem, DocumentSymbol, LspClient, LspManager, Position, Range};
+use async_trait::async_trait;
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+
+/// 기본 탐색 깊이
+const DEFAULT_DEPTH: usize = 1;
+
+/// 최대 탐색 깊이
+const MAX_DEPTH: usize = 3;
+
+/// 출력할 최대 항목 수
+const MAX_ENTRIES: usize = 50;
+
+/// 조회 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallDirection {
+    /// 이 함수를 호출하는 곳
+    Incoming,
+    /// 이 함수가 호출하는 곳
+    Outgoing,
+    /// 상위 타입
+    Supertypes,
+    /// 하위 타입/구현
+    Subtypes,
+}
+
+impl CallDirection {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "incoming" | "callers" => Some(Self::Incoming),
+            "outgoing" | "callees" => Some(Self::Outgoing),
+            "supertypes" => Some(Self::Supertypes),
+            "subtypes" | "implementations" => Some(Self::Subtypes),
+            _ => None,
+        }
+    }
+
+    fn is_type_hierarchy(self) -> bool {
+        matches!(self, Self::Supertypes | Self::Subtypes)
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Incoming => "Callers of",
+            Self::Outgoing => "Calls from",
+            Self::Supertypes => "Supertypes of",
+            Self::Subtypes => "Subtypes of",
+        }
+    }
+
+    fn empty_label(self) -> &'static str {
+        match self {
+            Self::Incoming => "callers",
+            Self::Outgoing => "outgoing calls",
+            Self::Supertypes => "supertypes",
+            Self::Subtypes => "subtypes",
+        }
+    }
+}
+
+/// 계층 트리의 한 항목 (전위 순회 순서)
+#[derive(Debug, Clone)]
+pub struct HierarchyEntry {
+    /// 루트 기준 깊이 (1부터)
+    pub depth: usize,
+    pub item: CallHierarchyItem,
+    /// 호출 위치 (타입 계층은 비어 있음)
+    pub call_ranges: Vec<Range>,
+    /// 호출 위치가 있는 파일 URI
+    pub call_uri: Option<String>,
+}
+
+/// 호출/타입 계층 조회 도구
+pub struct WhoCallsTool {
+    lsp: Arc<LspManager>,
+}
+
+impl WhoCallsTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "who_calls";
+
+    pub fn new(lsp: Arc<LspManager>) -> Self {
+        Self { lsp }
+    }
+
+    /// 한 단계 하위 항목 조회
+    async fn children(
+        client: &LspClient,
+        item: &CallHierarchyItem,
+        direction: CallDirection,
+    ) -> Result<Vec<(CallHierarchyItem, Vec<Range>, Option<String>)>> {
+        Ok(match direction {
+            CallDirection::Incoming => client
+                .incoming_calls(item)
+                .await?
+                .into_iter()
+                .map(|call| {
+                    let uri = call.from.uri.clone();
+                    (call.from, call.from_ranges, Some(uri))
+                })
+                .collect(),
+            CallDirection::Outgoing => client
+                .outgoing_calls(item)
+                .await?
+                .into_iter()
+                .map(|call| (call.to, call.from_ranges, Some(item.uri.clone())))
+                .collect(),
+            CallDirection::Supertypes => client
+                .supertypes(item)
+                .await?
+                .into_iter()
+                .map(|item| (item, vec![], None))
+                .collect(),
+            CallDirection::Subtypes => client
+                .subtypes(item)
+                .await?
+                .into_iter()
+                .map(|item| (item, vec![], None))
+                .collect(),
+        })
+    }
+
+    /// 깊이 우선으로 계층 탐색 (이미 방문한 항목은 다시 펼치지 않음)
+    async fn walk(
+        client: &LspClient,
+        root: &CallHierarchyItem,
+        direction: CallDirection,
+        max_depth: usize,
+    ) -> Result<(Vec<HierarchyEntry>, bool)> {
+        let mut entries = Vec::new();
+        let mut visited = HashSet::from([item_key(root)]);
+        let mut stack: Vec<HierarchyEntry> = Vec::new();
+        let mut truncated = false;
+
+        let push_children = |stack: &mut Vec<HierarchyEntry>, depth, children: Vec<_>| {
+            for (item, call_ranges, call_uri) in children.into_iter().rev() {
+                stack.push(HierarchyEntry {
+                    depth,
+                    item,
+                    call_ranges,
+                    call_uri,
+                });
+            }
+        };
+
+        push_children(
+            &mut stack,
+            1,
+            Self::children(client, root, direction).await?,
+        );
+        while let Some(entry) = stack.pop() {
+            if entries.len() >= MAX_ENTRIES {
+                truncated = true;
+                break;
+            }
+            let expand = entry.depth < max_depth && visited.insert(item_key(&entry.item));
+            let (depth, item) = (entry.depth, entry.item.clone());
+            entries.push(entry);
+            if expand {
+                // 하위 조회 실패는 해당 가지만 생략
+                if let Ok(children) = Self::children(client, &item, direction).await {
+                    push_children(&mut stack, depth + 1, children);
+                }
+            }
+        }
+
+        Ok((entries, truncated))
+    }
+}
+
+#[async_trait]
+impl Tool for WhoCallsTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Who Calls")
+            .description("Find every caller of a function (what breaks if I change this?) using the language server's call hierarchy. Can also list outgoing calls, supertypes or subtypes.")
+            .category("lsp")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "File containing the symbol (relative to the working directory)"
+                },
+                "symbol": {
+                    "type": "string",
+                    "description": "Function, method or type name defined in the file"
+                },
+                "line": {
+                    "type": "integer",
+                    "description": "1-based line of the symbol (instead of, or to disambiguate, `symbol`)"
+                },
+                "column": {
+                    "type": "integer",
+                    "description": "1-based column on `line` (default: first non-blank character)"
+                },
+                "direction": {
+                    "type": "string",
+                    "enum": ["incoming", "outgoing", "supertypes", "subtypes"],
+                    "description": "incoming = callers (default), outgoing = callees, supertypes/subtypes = type hierarchy"
+                },
+                "depth": {
+                    "type": "integer",
+                    "description": "How many levels to follow (default: 1, max: 3)"
+                }
+            },
+            "required": ["path"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let Some(path) = input["path"].as_str().filter(|p| !p.trim().is_empty()) else {
+            return Ok(ToolResult::error("Missing required parameter: path"));
+        };
+        let direction = match input["direction"].as_str() {
+            Some(value) => match CallDirection::parse(value) {
+                Some(direction) => direction,
+                None => return Ok(ToolResult::error(format!(
+                    "Unknown direction '{}' (expected incoming, outgoing, supertypes or subtypes)",
+                    value
+                ))),
+            },
+            None => CallDirection::Incoming,
+        };
+        let symbol = input["symbol"].as_str().filter(|s| !s.trim().is_empty());
+        let line = input["line"].as_u64().filter(|&l| l > 0).map(|l| l as u32);
+        if symbol.is_none() && line.is_none() {
+            return Ok(ToolResult::error("Provide `symbol` or `line`"));
+        }
+        let depth = input["depth"]
+            .as_u64()
+            .map(|d| d as usize)
+            .unwrap_or(DEFAULT_DEPTH)
+            .clamp(1, MAX_DEPTH);
+
+        let file = ctx.working_dir().join(path);
+        if !file.is_file() {
+            return Ok(ToolResult::error(format!("File not found: {}", path)));
+        }
+
+        let (client, uri) = match self.lsp.open_document(&file).await {
+            Ok(opened) => opened,
+            Err(e) => {
+                return Ok(ToolResult::error(format!(
+                    "No language server for {}: {}",
+                    path, e
+                )))
+            }
+        };
+
+        let position = match symbol {
+            Some(name) => {
+                let symbols = client.document_symbols(&uri).await?;
+                match find_symbol(&symbols, name, line) {
+                    Some(position) => position,
+                    None => {
+                        return Ok(ToolResult::error(format!(
+                            "Symbol '{}' not found in {}",
+                            name, path
+                        )))
+                    }
+                }
+            }
+            None => {
+                let content = tokio::fs::read_to_string(&file).await?;
+                let line = line.unwrap_or(1) - 1;
+                let column = match input["column"].as_u64().filter(|&c| c > 0) {
+                    Some(column) => column as u32 - 1,
+                    None => first_non_blank(&content, line),
+                };
+                Position::new(line, column)
+            }
+        };
+
+        let prepared = if direction.is_type_hierarchy() {
+            client.prepare_type_hierarchy(&uri, position).await
+        } else {
+            client.prepare_call_hierarchy(&uri, position).await
+        };
+        let root = match prepared {
+            Ok(items) => match items.into_iter().next() {
+                Some(item) => item,
+                None => {
+                    return Ok(ToolResult::error(format!(
+                        "No {} at {}:{}:{}",
+                        if direction.is_type_hierarchy() {
+                            "type"
+                        } else {
+                            "function"
+                        },
+                        path,
+                        position.line + 1,
+                        position.character + 1
+                    )))
+                }
+            },
+            Err(e) => return Ok(ToolResult::error(e.to_string())),
+        };
+
+        let (entries, truncated) = Self::walk(&client, &root, direction, depth).await?;
+        let output = render_hierarchy(
+            &root,
+            &entries,
+            direction,
+            truncated,
+            Some(ctx.working_dir()),
+        );
+        Ok(ToolResult::success(output).with_metadata("count", json!(entries.len())))
+    }
+}
+
+/// 방문 여부 판단용 키
+fn item_key(item: &CallHierarchyItem) -> (String, u32, u32) {
+    (
+        item.uri.clone(),
+        item.selection_range.start.line,
+        item.selection_range.start.character,
+    )
+}
+
+/// 문서 심볼에서 이름이 같은 심볼의 위치 (`line`이 있으면 가장 가까운 것)
+fn find_symbol(symbols: &[DocumentSymbol], name: &str, line: Option<u32>) -> Option<Position> {
+    fn collect<'a>(symbols: &'a [DocumentSymbol], name: &str, out: &mut Vec<&'a DocumentSymbol>) {
+        for symbol in symbols {
+            if symbol.name == name {
+                out.push(symbol);
+            }
+            collect(&symbol.children, name, out);
+        }
+    }
+
+    let mut matches = Vec::new();
+    collect(symbols, name, &mut matches);
+    let target = line.map(|l| l - 1).unwrap_or(0);
+    matches
+        .into_iter()
+        .min_by_key(|s| s.range.start.line.abs_diff(target))
+        .map(|s| s.selection_range.as_ref().unwrap_or(&s.range).start)
+}
+
+/// 줄의 첫 번째 공백이 아닌 문자 위치
+fn first_non_blank(content: &str, line: u32) -> u32 {
+    let Some(text) = content.lines().nth(line as usize) else {
+        return 0;
+    };
+    let indent = text.len() - text.trim_start().len();
+    text[..indent].encode_utf16().count() as u32
+}
+
+fn describe_item(item: &CallHierarchyItem, root: Option<&Path>) -> String {
+    format!(
+        "{} ({}, {}:{})",
+        item.name,
+        format!("{:?}", item.kind).to_lowercase(),
+        display_uri(&item.uri, root),
+        item.selection_range.start.line + 1
+    )
+}
+
+/// 계층 트리 출력
+pub fn render_hierarchy(
+    root_item: &CallHierarchyItem,
+    entries: &[HierarchyEntry],
+    direction: CallDirection,
+    truncated: bool,
+    root: Option<&Path>,
+) -> String {
+    let header = format!(
+        "{} `{}` ({}, {}:{})",
+        direction.title(),
+        root_item.name,
+        format!("{:?}", root_item.kind).to_lowercase(),
+        display_uri(&root_item.uri, root),
+        root_item.selection_range.start.line + 1
+    );
+    if entries.is_empty() {
+        return format!("{}: no {} found", header, direction.empty_label());
+    }
+
+    let mut lines = vec![format!("{}:", header)];
+    for entry in entries {
+        let mut line = format!(
+            "{}- {}",
+            "  ".repeat(entry.depth - 1),
+            describe_item(&entry.item, root)
+        );
+        if !entry.call_ranges.is_empty() {
+            let mut call_lines: Vec<u32> =
+                entry.call_ranges.iter().map(|r| r.start.line + 1).collect();
+            call_lines.dedup();
+            let at = call_lines
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            match entry.call_uri.as_deref() {
+                Some(uri) if uri != entry.item.uri => {
+                    line.push_str(&format!(" — called at {}:{}", display_uri(uri, root), at))
+                }
+                _ => line.push_str(&format!(" — called at {}", at)),
+            }
+        }
+        lines.push(line);
+    }
+    if truncated {
+        lines.push(format!("... truncated after {} entries", MAX_ENTRIES));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+
+    fn item(name: &str, file: &str, line: u32) -> CallHierarchyItem {
+        serde_json::from_value(json!({
+            "name": name,
+            "kind": 12,
+            "uri": format!("file:///work/{}", file),
+            "range": { "start": { "line": line, "character": 0 }, "end": { "line": line + 5, "character": 1 } },
+            "selectionRange": { "start": { "line": line, "character": 3 }, "end": { "line": line, "character": 8 } }
+        }))
+        .unwrap()
+    }
+
+    fn range(line: u32) -> Range {
+        Range::point(Position::new(line, 4))
+    }
+
+    #[test]
+    fn test_direction_parse() {
+        assert_eq!(
+            CallDirection::parse("Incoming"),
+            Some(CallDirection::Incoming)
+        );
+        assert_eq!(
+            CallDirection::parse("callees"),
+            Some(CallDirection::Outgoing)
+        );
+        assert_eq!(
+            CallDirection::parse("implementations"),
+            Some(CallDirection::Subtypes)
+        );
+        assert_eq!(CallDirection::parse("sideways"), None);
+    }
+
+    #[test]
+    fn test_render_incoming_tree() {
+        let root = Path::new("/work");
+        let target = item("parse_config", "src/config.rs", 41);
+        let entries = vec![
+            HierarchyEntry {
+                depth: 1,
+                item: item("load", "src/app.rs", 9),
+                call_ranges: vec![range(13), range(26), range(26)],
+                call_uri: Some("file:///work/src/app.rs".to_string()),
+            },
+            HierarchyEntry {
+                depth: 2,
+                item: item("main", "src/main.rs", 2),
+                call_ranges: vec![range(4)],
+                call_uri: Some("file:///work/src/main.rs".to_string()),
+            },
+        ];
+
+        assert_eq!(
+            render_hierarchy(
+                &target,
+                &entries,
+                CallDirection::Incoming,
+                false,
+                Some(root)
+            ),
+            "Callers of `parse_config` (function, src/config.rs:42):\n\
+             - load (function, src/app.rs:10) — called at 14, 27\n  \
+             - main (function, src/main.rs:3) — called at 5"
+        );
+
+        assert_eq!(
+            render_hierarchy(&target, &[], CallDirection::Incoming, false, Some(root)),
+            "Callers of `parse_config` (function, src/config.rs:42): no callers found"
+        );
+    }
+
+    #[test]
+    fn test_render_outgoing_marks_call_file() {
+        let target = item("load", "src/app.rs", 9);
+        let entries = vec![HierarchyEntry {
+            depth: 1,
+            item: item("parse_config", "src/config.rs", 41),
+            call_ranges: vec![range(13)],
+            call_uri: Some("file:///work/src/app.rs".to_string()),
+        }];
+        let output = render_hierarchy(
+            &target,
+            &entries,
+            CallDirection::Outgoing,
+            true,
+            Some(Path::new("/work")),
+        );
+        assert!(output.starts_with("Calls from `load`"));
+        assert!(output.contains("called at src/app.rs:14"));
+        assert!(output.ends_with("truncated after 50 entries"));
+    }
+
+    #[test]
+    fn test_find_symbol_prefers_nearest() {
+        let symbols: Vec<DocumentSymbol> = serde_json::from_value(json!([
+            {
+                "name": "Parser", "kind": 23,
+                "range": { "start": { "line": 0, "character": 0 }, "end": { "line": 30, "character": 1 } },
+                "children": [{
+                    "name": "new", "kind": 6,
+                    "range": { "start": { "line": 2, "character": 4 }, "end": { "line": 4, "character": 5 } },
+                    "selectionRange": { "start": { "line": 2, "character": 11 }, "end": { "line": 2, "character": 14 } }
+                }]
+            },
+            {
+                "name": "new", "kind": 12,
+                "range": { "start": { "line": 40, "character": 0 }, "end": { "line": 42, "character": 1 } }
+            }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            find_symbol(&symbols, "new", None),
+            Some(Position::new(2, 11))
+        );
+        assert_eq!(
+            find_symbol(&symbols, "new", Some(40)),
+            Some(Position::new(40, 0))
+        );
+        assert_eq!(find_symbol(&symbols, "missing", None), None);
+        assert_eq!(first_non_blank("fn a() {\n    b();\n}", 1), 4);
+    }
+
+    #[tokio::test]
+    async fn test_input_validation() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("notes.xyz"), "text").unwrap();
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let tool = WhoCallsTool::new(Arc::new(LspManager::new()));
+
+        let result = tool
+            .execute(json!({ "path": "notes.xyz" }), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(result.error.as_deref(), Some("Provide `symbol` or `line`"));
+
+        let result = tool
+            .execute(
+                json!({ "path": "notes.xyz", "line": 1, "direction": "up" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().starts_with("Unknown direction 'up'"));
+
+        let result = tool
+            .execute(json!({ "path": "missing.rs", "symbol": "main" }), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(result.error.as_deref(), Some("File not found: missing.rs"));
+
+        let result = tool
+            .execute(json!({ "path": "notes.xyz", "symbol": "main" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result
+            .error
+            .unwrap()
+            .starts_with("No language server for notes.xyz"));
+    }
+}
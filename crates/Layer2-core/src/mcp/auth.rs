@@ -0,0 +1,872 @@
+//! MCP OAuth - 원격 MCP 서버용 OAuth 2.1 인증
+//!
+//! 원격(SSE) MCP 서버가 401을 반환하면 MCP 인증 사양에 따라
+//! authorization code + PKCE 흐름을 수행합니다.
+//!
+//! ## 흐름
+//! 1. 보호 리소스 메타데이터(`WWW-Authenticate`의 `resource_metadata` 또는
+//!    `/.well-known/oauth-protected-resource`)에서 인가 서버 확인
+//! 2. 인가 서버 메타데이터(`/.well-known/oauth-authorization-server`) 조회,
+//!    없으면 `/authorize`, `/token`, `/register` 기본 경로 사용
+//! 3. `client_id`가 없으면 동적 클라이언트 등록 (RFC 7591)
+//! 4. 브라우저로 인가 페이지를 열고 `http://127.0.0.1:<port>/callback`에서 코드 수신
+//! 5. 코드 + `code_verifier`로 토큰 교환
+//!
+//! 토큰은 서버 URL별로 `~/.config/forgecode/mcp-tokens.json`(권한 0600)에 저장되며,
+//! 이후 401이 오면 refresh token으로 먼저 갱신하고 실패할 때만 다시 인가합니다.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use forge_foundation::storage::JsonStore;
+use forge_foundation::{check_egress, egress_client, Error, Result};
+use ring::digest::{digest, SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+use url::Url;
+
+/// 토큰 저장 파일명
+pub const MCP_TOKENS_FILE: &str = "mcp-tokens.json";
+
+/// 리다이렉트 콜백 경로
+const CALLBACK_PATH: &str = "/callback";
+
+/// 브라우저 인가 대기 시간
+const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// 만료 직전 토큰을 미리 갱신하는 여유 (초)
+const EXPIRY_MARGIN_SECS: i64 = 60;
+
+/// 인가 페이지를 여는 함수 (기본: 시스템 브라우저)
+///
+/// URL을 사용자에게 직접 보여주려면 (터미널 출력, TUI 알림) 이 함수에서 처리합니다.
+pub type BrowserOpener = Arc<dyn Fn(&str) -> Result<()> + Send + Sync>;
+
+/// 서버별 OAuth 설정 (모두 선택)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpOAuthConfig {
+    /// 미리 등록한 클라이언트 ID (없으면 동적 등록)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// 기밀 클라이언트 시크릿
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+
+    /// 요청할 scope
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+
+    /// 콜백 포트 (없으면 이전 등록 포트, 그것도 없으면 임의 포트)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_port: Option<u16>,
+}
+
+/// 인가 서버 메타데이터 (RFC 8414)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthServerMetadata {
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registration_endpoint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes_supported: Option<Vec<String>>,
+}
+
+/// 보호 리소스 메타데이터 (RFC 9728)
+#[derive(Debug, Deserialize)]
+struct ProtectedResourceMetadata {
+    #[serde(default)]
+    authorization_servers: Vec<String>,
+    #[serde(default)]
+    scopes_supported: Option<Vec<String>>,
+}
+
+/// 토큰 응답
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    expires_in: Option<i64>,
+    #[serde(default)]
+    scope: Option<String>,
+}
+
+/// 동적 클라이언트 등록 응답
+#[derive(Debug, Deserialize)]
+struct RegistrationResponse {
+    client_id: String,
+    #[serde(default)]
+    client_secret: Option<String>,
+}
+
+/// 서버 하나의 저장된 인증 정보
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpCredential {
+    pub access_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
+    /// 토큰을 발급한 클라이언트 (갱신 시 재사용)
+    pub client_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    pub redirect_uri: String,
+    pub token_endpoint: String,
+}
+
+impl McpCredential {
+    /// 만료되었거나 곧 만료되는지
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|at| at <= Utc::now() + ChronoDuration::seconds(EXPIRY_MARGIN_SECS))
+    }
+}
+
+/// 서버 URL별 토큰 저장소 (파일 권한 0600)
+#[derive(Debug, Clone)]
+pub struct McpTokenStore {
+    path: PathBuf,
+}
+
+impl McpTokenStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// 기본 저장소 (`~/.config/forgecode/mcp-tokens.json`)
+    pub fn global() -> Result<Self> {
+        Ok(Self::new(JsonStore::global()?.file_path(MCP_TOKENS_FILE)))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn load_all(&self) -> Result<HashMap<String, McpCredential>> {
+        if !self.path.exists() {
+            return Ok(HashMap::new());
+        }
+        let content = std::fs::read_to_string(&self.path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    fn save_all(&self, credentials: &HashMap<String, McpCredential>) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(credentials)?)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(())
+    }
+
+    /// 서버의 인증 정보 조회
+    pub fn get(&self, server_url: &str) -> Result<Option<McpCredential>> {
+        Ok(self.load_all()?.remove(server_url))
+    }
+
+    /// 서버의 인증 정보 저장
+    pub fn save(&self, server_url: &str, credential: &McpCredential) -> Result<()> {
+        let mut all = self.load_all()?;
+        all.insert(server_url.to_string(), credential.clone());
+        self.save_all(&all)
+    }
+
+    /// 서버의 인증 정보 삭제 (있었으면 true)
+    pub fn remove(&self, server_url: &str) -> Result<bool> {
+        let mut all = self.load_all()?;
+        let removed = all.remove(server_url).is_some();
+        if removed {
+            self.save_all(&all)?;
+        }
+        Ok(removed)
+    }
+}
+
+/// 원격 MCP 서버 하나의 OAuth 인증 관리
+pub struct McpAuth {
+    /// MCP 서버 URL (토큰의 `resource`이자 저장 키)
+    server_url: String,
+    config: McpOAuthConfig,
+    store: McpTokenStore,
+    client: reqwest::Client,
+    browser: BrowserOpener,
+
+    /// 현재 인증 정보 (갱신/인가 흐름을 직렬화하는 잠금 겸용)
+    credential: Mutex<Option<McpCredential>>,
+}
+
+impl McpAuth {
+    /// 기본 토큰 저장소를 쓰는 인증 관리자
+    pub fn new(server_url: impl Into<String>, config: McpOAuthConfig) -> Result<Self> {
+        Ok(Self::with_store(
+            server_url,
+            config,
+            McpTokenStore::global()?,
+        ))
+    }
+
+    /// 토큰 저장소 지정
+    pub fn with_store(
+        server_url: impl Into<String>,
+        config: McpOAuthConfig,
+        store: McpTokenStore,
+    ) -> Self {
+        let server_url = server_url.into();
+        let credential = store.get(&server_url).unwrap_or_else(|e| {
+            warn!(
+                "Failed to read MCP tokens from {}: {}",
+                store.path().display(),
+                e
+            );
+            None
+        });
+        let client = egress_client()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            server_url,
+            config,
+            store,
+            client,
+            browser: Arc::new(open_browser),
+            credential: Mutex::new(credential),
+        }
+    }
+
+    /// 인가 페이지를 여는 방법 설정
+    pub fn with_browser_opener(mut self, opener: BrowserOpener) -> Self {
+        self.browser = opener;
+        self
+    }
+
+    /// MCP 서버 URL
+    pub fn server_url(&self) -> &str {
+        &self.server_url
+    }
+
+    /// 요청에 붙일 access token (만료되었으면 refresh token으로 갱신)
+    pub async fn access_token(&self) -> Option<String> {
+        let mut credential = self.credential.lock().await;
+        let current = credential.as_ref()?;
+        if current.is_expired() && current.refresh_token.is_some() {
+            match self.refresh(current).await {
+                Ok(refreshed) => *credential = Some(refreshed),
+                Err(e) => debug!("MCP token refresh failed for {}: {}", self.server_url, e),
+            }
+        }
+        credential.as_ref().map(|c| c.access_token.clone())
+    }
+
+    /// 401 응답 처리: 갱신을 시도하고, 안 되면 브라우저 인가 후 새 access token 반환
+    ///
+    /// `failed_token`은 거부된 토큰입니다. 그사이 다른 요청이 이미 새 토큰을
+    /// 받았다면 다시 인가하지 않고 그 토큰을 돌려줍니다.
+    pub async fn handle_unauthorized(
+        &self,
+        www_authenticate: Option<&str>,
+        failed_token: Option<&str>,
+    ) -> Result<String> {
+        let mut credential = self.credential.lock().await;
+
+        if let Some(current) = credential.as_ref() {
+            if failed_token != Some(current.access_token.as_str()) {
+                return Ok(current.access_token.clone());
+            }
+            if current.refresh_token.is_some() {
+                match self.refresh(current).await {
+                    Ok(refreshed) => {
+                        let token = refreshed.access_token.clone();
+                        *credential = Some(refreshed);
+                        return Ok(token);
+                    }
+                    Err(e) => info!("MCP token refresh failed, re-authorizing: {}", e),
+                }
+            }
+        }
+
+        let authorized = self
+            .authorize(www_authenticate, credential.as_ref())
+            .await?;
+        let token = authorized.access_token.clone();
+        *credential = Some(authorized);
+        Ok(token)
+    }
+
+    /// 저장된 토큰 삭제
+    pub async fn logout(&self) -> Result<bool> {
+        *self.credential.lock().await = None;
+        self.store.remove(&self.server_url)
+    }
+
+    // ========================================================================
+    // Flows
+    // ========================================================================
+
+    async fn refresh(&self, current: &McpCredential) -> Result<McpCredential> {
+        let refresh_token = current
+            .refresh_token
+            .as_deref()
+            .ok_or_else(|| Error::Mcp("No refresh token".to_string()))?;
+
+        let mut form = vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", current.client_id.as_str()),
+            ("resource", self.server_url.as_str()),
+        ];
+        if let Some(secret) = current.client_secret.as_deref() {
+            form.push(("client_secret", secret));
+        }
+        let response = self.token_request(&current.token_endpoint, &form).await?;
+
+        let refreshed = McpCredential {
+            access_token: response.access_token,
+            refresh_token: response
+                .refresh_token
+                .or_else(|| current.refresh_token.clone()),
+            expires_at: expires_at(response.expires_in),
+            scope: response.scope.or_else(|| current.scope.clone()),
+            ..current.clone()
+        };
+        self.store.save(&self.server_url, &refreshed)?;
+        info!("Refreshed MCP access token for {}", self.server_url);
+        Ok(refreshed)
+    }
+
+    async fn authorize(
+        &self,
+        www_authenticate: Option<&str>,
+        previous: Option<&McpCredential>,
+    ) -> Result<McpCredential> {
+        let (metadata, resource_scopes) = self.discover(www_authenticate).await?;
+
+        // 이전에 등록한 redirect_uri가 있으면 같은 포트 사용
+        let port = self.config.redirect_port.or_else(|| {
+            previous
+                .and_then(|c| Url::parse(&c.redirect_uri).ok())
+                .and_then(|u| u.port())
+        });
+        let listener = match TcpListener::bind(("127.0.0.1", port.unwrap_or(0))).await {
+            Ok(listener) => listener,
+            Err(_) if self.config.redirect_port.is_none() => {
+                TcpListener::bind(("127.0.0.1", 0)).await?
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let redirect_uri = format!(
+            "http://127.0.0.1:{}{}",
+            listener.local_addr()?.port(),
+            CALLBACK_PATH
+        );
+
+        let (client_id, client_secret) = match (&self.config.client_id, previous) {
+            (Some(id), _) => (id.clone(), self.config.client_secret.clone()),
+            (None, Some(prev)) if prev.redirect_uri == redirect_uri => {
+                (prev.client_id.clone(), prev.client_secret.clone())
+            }
+            (None, _) => self.register(&metadata, &redirect_uri).await?,
+        };
+
+        let verifier = random_token(32)?;
+        let state = random_token(16)?;
+        let scope = if self.config.scopes.is_empty() {
+            resource_scopes.or_else(|| metadata.scopes_supported.clone())
+        } else {
+            Some(self.config.scopes.clone())
+        }
+        .map(|scopes| scopes.join(" "))
+        .filter(|s| !s.is_empty());
+
+        let mut url = Url::parse(&metadata.authorization_endpoint)
+            .map_err(|e| Error::Mcp(format!("Invalid authorization endpoint: {}", e)))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &client_id)
+                .append_pair("redirect_uri", &redirect_uri)
+                .append_pair("code_challenge", &pkce_challenge(&verifier))
+                .append_pair("code_challenge_method", "S256")
+                .append_pair("state", &state)
+                .append_pair("resource", &self.server_url);
+            if let Some(scope) = &scope {
+                query.append_pair("scope", scope);
+            }
+        }
+
+        info!(
+            "MCP server {} requires authorization, opening {}",
+            self.server_url, url
+        );
+        if let Err(e) = (self.browser)(url.as_str()) {
+            warn!(
+                "Failed to open a browser ({}); open this URL manually: {}",
+                e, url
+            );
+        }
+
+        let code = tokio::time::timeout(AUTHORIZATION_TIMEOUT, wait_for_code(&listener, &state))
+            .await
+            .map_err(|_| Error::Timeout("MCP authorization timed out".to_string()))??;
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code.as_str()),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("client_id", client_id.as_str()),
+            ("code_verifier", verifier.as_str()),
+            ("resource", self.server_url.as_str()),
+        ];
+        if let Some(secret) = client_secret.as_deref() {
+            form.push(("client_secret", secret));
+        }
+        let response = self.token_request(&metadata.token_endpoint, &form).await?;
+
+        let credential = McpCredential {
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+            expires_at: expires_at(response.expires_in),
+            scope: response.scope.or(scope),
+            client_id,
+            client_secret,
+            redirect_uri,
+            token_endpoint: metadata.token_endpoint,
+        };
+        self.store.save(&self.server_url, &credential)?;
+        info!("Authorized MCP server {}", self.server_url);
+        Ok(credential)
+    }
+
+    /// 인가 서버 메타데이터와 리소스가 알려준 scope 조회
+    async fn discover(
+        &self,
+        www_authenticate: Option<&str>,
+    ) -> Result<(AuthServerMetadata, Option<Vec<String>>)> {
+        let server = Url::parse(&self.server_url)
+            .map_err(|e| Error::Mcp(format!("Invalid MCP server URL: {}", e)))?;
+        let origin = server.origin().ascii_serialization();
+
+        let resource_url = www_authenticate
+            .and_then(|h| auth_param(h, "resource_metadata"))
+            .unwrap_or_else(|| format!("{}/.well-known/oauth-protected-resource", origin));
+        let resource: Option<ProtectedResourceMetadata> = self.get_json(&resource_url).await;
+
+        let issuer = resource
+            .as_ref()
+            .and_then(|r| r.authorization_servers.first().cloned())
+            .unwrap_or_else(|| origin.clone());
+        let scopes = resource.and_then(|r| r.scopes_supported);
+
+        for url in metadata_urls(&issuer) {
+            if let Some(metadata) = self.get_json::<AuthServerMetadata>(&url).await {
+                return Ok((metadata, scopes));
+            }
+        }
+
+        // 메타데이터가 없는 서버: 사양의 기본 경로
+        let base = Url::parse(&issuer)
+            .map(|u| u.origin().ascii_serialization())
+            .unwrap_or(origin);
+        Ok((
+            AuthServerMetadata {
+                authorization_endpoint: format!("{}/authorize", base),
+                token_endpoint: format!("{}/token", base),
+                registration_endpoint: Some(format!("{}/register", base)),
+                scopes_supported: None,
+            },
+            scopes,
+        ))
+    }
+
+    /// 동적 클라이언트 등록 (공개 클라이언트)
+    async fn register(
+        &self,
+        metadata: &AuthServerMetadata,
+        redirect_uri: &str,
+    ) -> Result<(String, Option<String>)> {
+        let endpoint = metadata.registration_endpoint.as_deref().ok_or_else(|| {
+            Error::Mcp(format!(
+                "MCP server {} does not support dynamic client registration; set oauth.clientId",
+                self.server_url
+            ))
+        })?;
+        check_egress(endpoint)?;
+
+        let response = self
+            .client
+            .post(endpoint)
+            .json(&serde_json::json!({
+                "client_name": "ForgeCode",
+                "redirect_uris": [redirect_uri],
+                "grant_types": ["authorization_code", "refresh_token"],
+                "response_types": ["code"],
+                "token_endpoint_auth_method": "none",
+            }))
+            .send()
+            .await
+            .map_err(|e| Error::Mcp(format!("Client registration failed: {}", e)))?;
+        if !response.status().is_success() {
+            return Err(Error::Mcp(format!(
+                "Client registration failed: HTTP {}",
+                response.status()
+            )));
+        }
+        let registration: RegistrationResponse = response
+            .json()
+            .await
+            .map_err(|e| Error::Mcp(format!("Invalid registration response: {}", e)))?;
+        Ok((registration.client_id, registration.client_secret))
+    }
+
+    async fn token_request(&self, endpoint: &str, form: &[(&str, &str)]) -> Result<TokenResponse> {
+        check_egress(endpoint)?;
+        let response = self
+            .client
+            .post(endpoint)
+            .form(form)
+            .send()
+            .await
+            .map_err(|e| Error::Mcp(format!("Token request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::Mcp(format!(
+                "Token request failed: HTTP {} {}",
+                status,
+                body.trim()
+            )));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| Error::Mcp(format!("Invalid token response: {}", e)))
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Option<T> {
+        check_egress(url).ok()?;
+        let response = self.client.get(url).send().await.ok()?;
+        if !response.status().is_success() {
+            return None;
+        }
+        response.json().await.ok()
+    }
+}
+
+/// 인가 서버 메타데이터 후보 URL (RFC 8414 경로 삽입, OpenID 순)
+fn metadata_urls(issuer: &str) -> Vec<String> {
+    let Ok(url) = Url::parse(issuer) else {
+        return Vec::new();
+    };
+    let origin = url.origin().ascii_serialization();
+    let path = url.path().trim_end_matches('/');
+    vec![
+        format!("{}/.well-known/oauth-authorization-server{}", origin, path),
+        format!("{}/.well-known/openid-configuration{}", origin, path),
+        format!("{}{}/.well-known/openid-configuration", origin, path),
+    ]
+}
+
+/// `WWW-Authenticate: Bearer key="value", ...`에서 파라미터 추출
+fn auth_param(header: &str, key: &str) -> Option<String> {
+    let params = header.trim().strip_prefix("Bearer").unwrap_or(header);
+    params.split(',').find_map(|pair| {
+        let (k, v) = pair.split_once('=')?;
+        (k.trim().eq_ignore_ascii_case(key)).then(|| v.trim().trim_matches('"').to_string())
+    })
+}
+
+/// PKCE S256 challenge
+fn pkce_challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(digest(&SHA256, verifier.as_bytes()))
+}
+
+/// URL-safe 임의 문자열 (`bytes` 바이트)
+fn random_token(bytes: usize) -> Result<String> {
+    let mut buf = vec![0u8; bytes];
+    SystemRandom::new()
+        .fill(&mut buf)
+        .map_err(|_| Error::Internal("Failed to generate random bytes".to_string()))?;
+    Ok(URL_SAFE_NO_PAD.encode(buf))
+}
+
+fn expires_at(expires_in: Option<i64>) -> Option<DateTime<Utc>> {
+    expires_in.map(|secs| Utc::now() + ChronoDuration::seconds(secs))
+}
+
+/// 콜백 요청을 받아 인가 코드 반환 (`state`가 맞지 않는 요청은 무시)
+async fn wait_for_code(listener: &TcpListener, state: &str) -> Result<String> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let mut reader = BufReader::new(stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).await?;
+        let mut stream = reader.into_inner();
+
+        let target = request_line.split_whitespace().nth(1).unwrap_or("");
+        let params: HashMap<String, String> = Url::parse(&format!("http://localhost{}", target))
+            .map(|u| u.query_pairs().into_owned().collect())
+            .unwrap_or_default();
+
+        let (result, message) = if !target.starts_with(CALLBACK_PATH) {
+            (None, "Not found")
+        } else if params.get("state").map(String::as_str) != Some(state) {
+            (None, "Authorization state mismatch; please try again.")
+        } else if let Some(error) = params.get("error") {
+            let description = params.get("error_description").unwrap_or(error);
+            (
+                Some(Err(Error::Mcp(format!(
+                    "Authorization denied: {}",
+                    description
+                )))),
+                "Authorization failed. You can close this window.",
+            )
+        } else if let Some(code) = params.get("code") {
+            (
+                Some(Ok(code.clone())),
+                "ForgeCode is authorized. You can close this window.",
+            )
+        } else {
+            (None, "Missing authorization code.")
+        };
+
+        let body = format!("<html><body><p>{}</p></body></html>", message);
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: text/html; charset=utf-8\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+        let _ = stream.shutdown().await;
+
+        if let Some(result) = result {
+            return result;
+        }
+    }
+}
+
+/// 시스템 브라우저로 URL 열기
+pub fn open_browser(url: &str) -> Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut command = std::process::Command::new("open");
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = std::process::Command::new("xdg-open");
+
+    command
+        .arg(url)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpStream;
+
+    #[test]
+    fn test_pkce_and_params() {
+        // RFC 7636 Appendix B
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+        assert_eq!(random_token(32).unwrap().len(), 43);
+
+        let header = r#"Bearer realm="mcp", resource_metadata="https://mcp.example.com/.well-known/oauth-protected-resource""#;
+        assert_eq!(
+            auth_param(header, "resource_metadata").as_deref(),
+            Some("https://mcp.example.com/.well-known/oauth-protected-resource")
+        );
+        assert_eq!(auth_param(header, "scope"), None);
+
+        assert_eq!(
+            metadata_urls("https://auth.example.com/tenant")[0],
+            "https://auth.example.com/.well-known/oauth-authorization-server/tenant"
+        );
+    }
+
+    /// 메타데이터/등록/토큰 엔드포인트만 있는 최소 인가 서버
+    async fn fake_auth_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let issuer = base.clone();
+        tokio::spawn(async move {
+            let mut issued = 0;
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut head = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).await.unwrap();
+                    if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).await.unwrap();
+                let body = String::from_utf8(body).unwrap();
+
+                let (status, json) = if head.contains("/.well-known/oauth-authorization-server") {
+                    (
+                        "200 OK",
+                        serde_json::json!({
+                            "authorization_endpoint": format!("{}/authorize", issuer),
+                            "token_endpoint": format!("{}/token", issuer),
+                            "registration_endpoint": format!("{}/register", issuer),
+                        }),
+                    )
+                } else if head.starts_with("POST /register") {
+                    (
+                        "201 Created",
+                        serde_json::json!({ "client_id": "dyn-client" }),
+                    )
+                } else if head.starts_with("POST /token") {
+                    let grant_ok = (body.contains("grant_type=authorization_code")
+                        && body.contains("code=the-code")
+                        && body.contains("code_verifier="))
+                        || body.contains("refresh_token=refresh-1");
+                    if grant_ok {
+                        issued += 1;
+                        (
+                            "200 OK",
+                            serde_json::json!({
+                                "access_token": format!("access-{}", issued),
+                                "token_type": "Bearer",
+                                "expires_in": 3600,
+                                "refresh_token": "refresh-1",
+                            }),
+                        )
+                    } else {
+                        (
+                            "400 Bad Request",
+                            serde_json::json!({ "error": "invalid_grant" }),
+                        )
+                    }
+                } else {
+                    ("404 Not Found", serde_json::json!({}))
+                };
+
+                let body = json.to_string();
+                let response = format!(
+                    "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let mut stream = reader.into_inner();
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        base
+    }
+
+    /// 브라우저 대신 콜백으로 바로 코드를 보내는 opener
+    fn auto_approve() -> BrowserOpener {
+        Arc::new(|url: &str| {
+            let url = Url::parse(url).unwrap();
+            let params: HashMap<_, _> = url.query_pairs().into_owned().collect();
+            assert_eq!(params["code_challenge_method"], "S256");
+            let redirect = Url::parse(&params["redirect_uri"]).unwrap();
+            let request = format!(
+                "GET {}?code=the-code&state={} HTTP/1.1\r\n\r\n",
+                redirect.path(),
+                params["state"]
+            );
+            let addr = format!("127.0.0.1:{}", redirect.port().unwrap());
+            tokio::spawn(async move {
+                let mut stream = TcpStream::connect(addr).await.unwrap();
+                stream.write_all(request.as_bytes()).await.unwrap();
+            });
+            Ok(())
+        })
+    }
+
+    #[tokio::test]
+    async fn test_authorize_and_refresh() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = McpTokenStore::new(dir.path().join(MCP_TOKENS_FILE));
+        let base = fake_auth_server().await;
+        let server_url = format!("{}/sse", base);
+
+        let auth = McpAuth::with_store(&server_url, McpOAuthConfig::default(), store.clone())
+            .with_browser_opener(auto_approve());
+        assert_eq!(auth.access_token().await, None);
+
+        // 첫 401: 동적 등록 + PKCE 인가
+        let token = auth.handle_unauthorized(None, None).await.unwrap();
+        assert_eq!(token, "access-1");
+        let saved = store.get(&server_url).unwrap().unwrap();
+        assert_eq!(saved.client_id, "dyn-client");
+        assert_eq!(saved.refresh_token.as_deref(), Some("refresh-1"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(store.path())
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // 다른 요청이 이미 갱신한 토큰은 그대로 사용
+        assert_eq!(
+            auth.handle_unauthorized(None, Some("stale")).await.unwrap(),
+            "access-1"
+        );
+
+        // 토큰 거부: refresh token으로 갱신 (브라우저 없이)
+        let auth = McpAuth::with_store(&server_url, McpOAuthConfig::default(), store.clone())
+            .with_browser_opener(Arc::new(|_: &str| panic!("should refresh, not authorize")));
+        assert_eq!(auth.access_token().await.as_deref(), Some("access-1"));
+        let token = auth
+            .handle_unauthorized(None, Some("access-1"))
+            .await
+            .unwrap();
+        assert_eq!(token, "access-2");
+        assert_eq!(
+            store.get(&server_url).unwrap().unwrap().access_token,
+            "access-2"
+        );
+
+        assert!(auth.logout().await.unwrap());
+        assert_eq!(auth.access_token().await, None);
+    }
+}
@@ -4,20 +4,35 @@
 //!
 //! ## 기능
 //! - **연결 풀링**: 서버별 연결 재사용
-//! - **헬스 체크**: 주기적 서버 상태 확인
-//! - **자동 재연결**: 연결 끊김 시 자동 복구
+//! - **헬스 체크**: 주기적 서버 상태 확인 (핸드셰이크 지연, 연속 실패, 마지막 에러)
+//! - **자동 재시작**: 죽은 stdio 서버를 지수 백오프로 재시작 (`McpRestartPolicy`)
+//! - **상태 스트림**: `subscribe_status`로 서버 상태 변화를 구독 (TUI 상태 패널)
 //! - **TTL 관리**: 유휴 연결 자동 정리
-
-use super::{McpClient, McpTool, McpToolCall, McpTransportConfig};
+//! - **서버별 권한**: 연결 시 `mcp.<server>.*` / `mcp.<server>.<tool>` 권한 등록
+//! - **리소스**: 모든 서버의 리소스 목록/읽기 (`resource_read` 도구에서 사용)
+//! - **프롬프트**: 모든 서버의 프롬프트 목록/가져오기 (슬래시 명령어 스킬로 노출)
+//! - **도구 정책**: 서버별 include/exclude 필터와 지연 로딩 (`McpToolPolicy`)
+//! - **루트**: 모든 서버에 프로젝트 디렉토리 변경 알림 (`set_roots`)
+//! - **도구 변경 알림**: `notifications/tools/list_changed`를 `subscribe_tools_changed`로
+//!   전달하고 `reload_server_tools`로 다시 가져옴
+//! - **진행 상황**: 도구 실행 중 `notifications/progress`를 `ToolOutputSink::on_progress`로 전달
+
+use super::{
+    McpClient, McpPrompt, McpResource, McpResourceContent, McpRoot, McpTool, McpToolCall,
+    McpToolPolicy, McpTransportConfig,
+};
 use async_trait::async_trait;
+use forge_foundation::permission::{
+    mcp_action, mcp_permission_name, mcp_tool_risk, register_mcp_server, unregister_mcp_server,
+};
 use forge_foundation::{
-    PermissionAction, PermissionDef, Result, Tool, ToolContext, ToolMeta, ToolResult,
+    OutputControl, PermissionAction, PermissionDef, Result, Tool, ToolContext, ToolMeta, ToolResult,
 };
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, mpsc, watch, RwLock};
 use tracing::{debug, error, info, warn};
 
 /// 기본 연결 TTL (10분)
@@ -26,11 +41,89 @@ const DEFAULT_CONNECTION_TTL: Duration = Duration::from_secs(600);
 /// 헬스 체크 간격 (1분)
 const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
 
-/// 재연결 시도 간격 (5초)
-const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+/// MCP 서버 자동 재시작 정책 (지수 백오프)
+///
+/// 헬스 체크에서 stdio 서버 프로세스가 죽은 것을 발견하면 바로 한 번 재시작하고,
+/// 실패할 때마다 `initial_delay * multiplier^(n-1)` (최대 `max_delay`)만큼 기다립니다.
+/// `max_attempts`번 연속 실패하면 서버를 다시 추가할 때까지 재시작하지 않습니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct McpRestartPolicy {
+    /// 자동 재시작 활성화 (stdio 서버만)
+    pub enabled: bool,
+    /// 첫 재시작 실패 후 대기 시간
+    pub initial_delay: Duration,
+    /// 백오프 배수
+    pub multiplier: f64,
+    /// 최대 대기 시간
+    pub max_delay: Duration,
+    /// 최대 연속 재시작 시도 횟수
+    pub max_attempts: u32,
+}
+
+impl Default for McpRestartPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            initial_delay: Duration::from_secs(1),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(60),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl McpRestartPolicy {
+    /// 자동 재시작 비활성화
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Default::default()
+        }
+    }
+
+    /// `failed_attempts`번 재시작에 실패한 뒤 다음 시도까지 대기 시간
+    pub fn backoff(&self, failed_attempts: u32) -> Duration {
+        if failed_attempts == 0 {
+            return Duration::ZERO;
+        }
+        let factor = self.multiplier.powi(failed_attempts.min(32) as i32 - 1);
+        Duration::try_from_secs_f64(self.initial_delay.as_secs_f64() * factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
+/// 서버 헬스 상태
+#[derive(Debug)]
+struct HealthState {
+    /// 연결 상태
+    healthy: bool,
+    /// 연속 실패 횟수 (연결 끊김 + 실패한 재시작)
+    consecutive_failures: u32,
+    /// 마지막 에러
+    last_error: Option<String>,
+    /// 다음 재시작 가능 시각
+    next_restart: Option<Instant>,
+    /// 성공한 재시작 횟수
+    restarts: u32,
+}
+
+impl HealthState {
+    fn new() -> Self {
+        Self {
+            healthy: true,
+            consecutive_failures: 0,
+            last_error: None,
+            next_restart: None,
+            restarts: 0,
+        }
+    }
 
-/// 최대 재연결 시도 횟수
-const MAX_RECONNECT_ATTEMPTS: u32 = 3;
+    /// 실패한 재시작 시도 횟수 (첫 실패는 연결 끊김)
+    fn failed_attempts(&self) -> u32 {
+        self.consecutive_failures.saturating_sub(1)
+    }
+}
 
 /// 연결된 클라이언트 정보
 struct ManagedConnection {
@@ -38,26 +131,34 @@ struct ManagedConnection {
     client: Arc<RwLock<McpClient>>,
     /// 연결 설정 (재연결용)
     config: McpTransportConfig,
+    /// 도구 노출 정책
+    policy: McpToolPolicy,
     /// 마지막 사용 시간
     last_used: RwLock<Instant>,
+    /// 자동 재시작 정책
+    restart_policy: McpRestartPolicy,
     /// 마지막 헬스 체크 시간
     last_health_check: RwLock<Instant>,
-    /// 연결 상태
-    healthy: RwLock<bool>,
-    /// 재연결 시도 횟수
-    reconnect_attempts: RwLock<u32>,
+    /// 헬스 상태
+    health: RwLock<HealthState>,
 }
 
 impl ManagedConnection {
-    fn new(client: McpClient, config: McpTransportConfig) -> Self {
+    fn new(
+        client: McpClient,
+        config: McpTransportConfig,
+        policy: McpToolPolicy,
+        restart_policy: McpRestartPolicy,
+    ) -> Self {
         let now = Instant::now();
         Self {
             client: Arc::new(RwLock::new(client)),
             config,
+            policy,
+            restart_policy,
             last_used: RwLock::new(now),
             last_health_check: RwLock::new(now),
-            healthy: RwLock::new(true),
-            reconnect_attempts: RwLock::new(0),
+            health: RwLock::new(HealthState::new()),
         }
     }
 
@@ -71,47 +172,133 @@ impl ManagedConnection {
         self.last_used.read().await.elapsed() > ttl
     }
 
+    /// 정책이 허용하는 도구 (지연 로딩 서버는 여기서 `tools/list`)
+    async fn allowed_tools(&self) -> Vec<McpTool> {
+        let client = self.client.read().await;
+        match client.ensure_tools().await {
+            // 처음 가져왔으면 도구별 권한도 등록
+            Ok(true) => register_permissions(&client, &self.policy).await,
+            Ok(false) => {}
+            Err(e) => warn!("Failed to list tools of MCP server '{}': {}", client.name(), e),
+        }
+        client
+            .tools()
+            .await
+            .into_iter()
+            .filter(|tool| self.policy.allows(&tool.name))
+            .collect()
+    }
+
     /// 헬스 체크 필요 여부
     async fn needs_health_check(&self) -> bool {
         self.last_health_check.read().await.elapsed() > HEALTH_CHECK_INTERVAL
     }
 
-    /// 헬스 체크 수행
+    /// 헬스 체크 수행 (연결이 끊긴 순간을 실패로 기록)
     async fn perform_health_check(&self) -> bool {
         *self.last_health_check.write().await = Instant::now();
 
         let client = self.client.read().await;
         let is_healthy = client.is_connected();
-
-        *self.healthy.write().await = is_healthy;
+        let mut health = self.health.write().await;
+        if is_healthy {
+            health.healthy = true;
+        } else if health.healthy {
+            let error = client
+                .last_error()
+                .await
+                .map(|e| e.to_string())
+                .unwrap_or_else(|| "Server disconnected".to_string());
+            warn!("MCP server '{}' health check failed: {}", client.name(), error);
+            health.healthy = false;
+            health.consecutive_failures += 1;
+            health.last_error = Some(error);
+            health.next_restart = None;
+        }
         is_healthy
     }
 
-    /// 재연결 시도
-    async fn try_reconnect(&self) -> Result<()> {
-        let mut attempts = self.reconnect_attempts.write().await;
-        if *attempts >= MAX_RECONNECT_ATTEMPTS {
-            return Err(forge_foundation::Error::Internal(
-                "Max reconnection attempts reached".to_string(),
-            ));
-        }
+    /// 헬스 체크 실패 시 자동 재시작 대상인지 (설정이 있는 stdio 서버)
+    fn can_auto_restart(&self) -> bool {
+        self.restart_policy.enabled
+            && matches!(&self.config, McpTransportConfig::Stdio { command, .. } if !command.is_empty())
+    }
 
-        *attempts += 1;
-        drop(attempts);
+    /// 백오프가 끝나 재시작할 수 있는지
+    async fn restart_due(&self) -> bool {
+        let health = self.health.read().await;
+        health.failed_attempts() < self.restart_policy.max_attempts
+            && health.next_restart.is_none_or(|at| Instant::now() >= at)
+    }
 
-        // 잠시 대기
-        tokio::time::sleep(RECONNECT_DELAY).await;
+    /// 재연결 시도 (백오프 중이면 대기하지 않고 실패)
+    async fn try_reconnect(&self) -> Result<()> {
+        {
+            let health = self.health.read().await;
+            if health.failed_attempts() >= self.restart_policy.max_attempts {
+                return Err(forge_foundation::Error::Internal(
+                    "Max reconnection attempts reached".to_string(),
+                ));
+            }
+            if let Some(wait) = health
+                .next_restart
+                .and_then(|at| at.checked_duration_since(Instant::now()))
+            {
+                return Err(forge_foundation::Error::Internal(format!(
+                    "Reconnection backing off for {}ms",
+                    wait.as_millis()
+                )));
+            }
+        }
 
         // 재연결
         let mut client = self.client.write().await;
-        client.disconnect().await?;
-        client.connect(&self.config).await?;
-
-        // 성공하면 카운터 리셋
-        *self.reconnect_attempts.write().await = 0;
-        *self.healthy.write().await = true;
+        if let Err(e) = client.disconnect().await {
+            debug!("Error closing MCP server '{}' before reconnect: {}", client.name(), e);
+        }
+        let result = client.connect(&self.config).await;
+
+        let mut health = self.health.write().await;
+        match &result {
+            Ok(()) => {
+                register_permissions(&client, &self.policy).await;
+                health.healthy = true;
+                health.consecutive_failures = 0;
+                health.next_restart = None;
+                health.restarts += 1;
+            }
+            Err(e) => {
+                health.healthy = false;
+                health.consecutive_failures = health.consecutive_failures.max(1) + 1;
+                health.last_error = Some(e.to_string());
+                health.next_restart =
+                    Some(Instant::now() + self.restart_policy.backoff(health.failed_attempts()));
+            }
+        }
+        result
+    }
 
-        Ok(())
+    /// 현재 상태
+    async fn status(&self, name: &str) -> ServerStatus {
+        let client = self.client.read().await;
+        let health = self.health.read().await;
+        ServerStatus {
+            name: name.to_string(),
+            connected: client.is_connected(),
+            healthy: health.healthy,
+            last_used: self.last_used.read().await.elapsed(),
+            reconnect_attempts: health.failed_attempts(),
+            handshake_latency: client.handshake_latency().await,
+            consecutive_failures: health.consecutive_failures,
+            last_error: health.last_error.clone(),
+            restarts: health.restarts,
+            next_restart_in: health
+                .next_restart
+                .filter(|_| !health.healthy && self.can_auto_restart())
+                .map(|at| at.saturating_duration_since(Instant::now())),
+            auto_restart: self.can_auto_restart()
+                && health.failed_attempts() < self.restart_policy.max_attempts,
+        }
     }
 }
 
@@ -119,21 +306,25 @@ impl ManagedConnection {
 ///
 /// ## 기능
 /// - 연결 풀링 및 재사용
-/// - 헬스 체크 및 자동 재연결
+/// - 헬스 체크 및 자동 재연결/재시작
 /// - TTL 기반 유휴 연결 정리
+/// - 서버 상태 스트림
 pub struct McpBridge {
     /// 연결된 MCP 클라이언트들
     connections: RwLock<HashMap<String, Arc<ManagedConnection>>>,
     /// 연결 TTL
     connection_ttl: Duration,
+    /// 새로 추가하는 서버의 자동 재시작 정책
+    restart_policy: McpRestartPolicy,
+    /// 서버 상태 스냅샷 스트림
+    status_tx: watch::Sender<Vec<ServerStatus>>,
+    /// 도구 목록이 바뀐 서버 이름 스트림
+    tools_changed_tx: broadcast::Sender<String>,
 }
 
 impl McpBridge {
     pub fn new() -> Self {
-        Self {
-            connections: RwLock::new(HashMap::new()),
-            connection_ttl: DEFAULT_CONNECTION_TTL,
-        }
+        Self::with_ttl(DEFAULT_CONNECTION_TTL)
     }
 
     /// TTL 설정과 함께 생성
@@ -141,49 +332,128 @@ impl McpBridge {
         Self {
             connections: RwLock::new(HashMap::new()),
             connection_ttl: ttl,
+            restart_policy: McpRestartPolicy::default(),
+            status_tx: watch::Sender::new(Vec::new()),
+            tools_changed_tx: broadcast::channel(32).0,
         }
     }
 
+    /// 자동 재시작 정책 설정
+    pub fn with_restart_policy(mut self, policy: McpRestartPolicy) -> Self {
+        self.restart_policy = policy;
+        self
+    }
+
+    /// 자동 재시작 정책
+    pub fn restart_policy(&self) -> &McpRestartPolicy {
+        &self.restart_policy
+    }
+
+    /// 서버 상태 구독 (추가/제거, 헬스 체크, 재시작마다 전체 스냅샷)
+    pub fn subscribe_status(&self) -> watch::Receiver<Vec<ServerStatus>> {
+        self.status_tx.subscribe()
+    }
+
+    /// 현재 상태를 구독자에게 알림
+    async fn publish_status(&self) {
+        let statuses = self.all_server_status().await;
+        self.status_tx.send_replace(statuses);
+    }
+
+    /// 도구 목록 변경 구독 (`notifications/tools/list_changed`를 보낸 서버 이름)
+    pub fn subscribe_tools_changed(&self) -> broadcast::Receiver<String> {
+        self.tools_changed_tx.subscribe()
+    }
+
+    /// 클라이언트의 도구 목록 변경 알림을 브리지 구독자에게 전달
+    ///
+    /// 클라이언트와 전송 계층이 모두 사라지면 전달 태스크도 끝납니다.
+    fn forward_tools_changed(&self, client: &McpClient) {
+        let mut changed = client.subscribe_tools_changed();
+        let tx = self.tools_changed_tx.clone();
+        tokio::spawn(async move {
+            loop {
+                match changed.recv().await {
+                    Ok(server) => {
+                        let _ = tx.send(server);
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
     /// MCP 서버 추가 (연결 설정 포함)
     pub async fn add_server_with_config(
         &self,
         client: McpClient,
         config: McpTransportConfig,
+    ) {
+        self.add_server_with_policy(client, config, McpToolPolicy::default())
+            .await;
+    }
+
+    /// MCP 서버 추가 (연결 설정 + 도구 노출 정책)
+    pub async fn add_server_with_policy(
+        &self,
+        client: McpClient,
+        config: McpTransportConfig,
+        policy: McpToolPolicy,
     ) {
         let name = client.name().to_string();
-        let managed = Arc::new(ManagedConnection::new(client, config));
+        register_permissions(&client, &policy).await;
+        self.forward_tools_changed(&client);
+        let managed = Arc::new(ManagedConnection::new(
+            client,
+            config,
+            policy,
+            self.restart_policy.clone(),
+        ));
 
         self.connections.write().await.insert(name.clone(), managed);
         info!("MCP server '{}' added to bridge", name);
+        self.publish_status().await;
     }
 
     /// MCP 서버 추가 (기존 호환성)
     pub async fn add_server(&self, client: McpClient) {
         let name = client.name().to_string();
+        register_permissions(&client, &McpToolPolicy::default()).await;
+        self.forward_tools_changed(&client);
         // 기본 설정 사용 (재연결 불가)
         let config = McpTransportConfig::Stdio {
             command: String::new(),
             args: vec![],
             env: HashMap::new(),
         };
-        let managed = Arc::new(ManagedConnection::new(client, config));
+        let managed = Arc::new(ManagedConnection::new(
+            client,
+            config,
+            McpToolPolicy::default(),
+            self.restart_policy.clone(),
+        ));
 
         self.connections.write().await.insert(name.clone(), managed);
         info!("MCP server '{}' added to bridge (without reconnect config)", name);
+        self.publish_status().await;
     }
 
     /// MCP 서버 제거
     pub async fn remove_server(&self, name: &str) -> Option<Arc<RwLock<McpClient>>> {
-        if let Some(managed) = self.connections.write().await.remove(name) {
-            // 연결 종료
+        let managed = self.connections.write().await.remove(name)?;
+        unregister_mcp_server(name);
+
+        // 연결 종료
+        {
             let mut client = managed.client.write().await;
             if let Err(e) = client.disconnect().await {
                 warn!("Error disconnecting MCP server '{}': {}", name, e);
             }
-            info!("MCP server '{}' removed from bridge", name);
-            return Some(Arc::clone(&managed.client));
         }
-        None
+        info!("MCP server '{}' removed from bridge", name);
+        self.publish_status().await;
+        Some(Arc::clone(&managed.client))
     }
 
     /// 서버 이름으로 클라이언트 조회 (헬스 체크 포함)
@@ -213,15 +483,16 @@ impl McpBridge {
 
     /// 재연결 시도
     async fn try_reconnect(&self, name: &str) -> Result<()> {
-        let connections = self.connections.read().await;
-        if let Some(managed) = connections.get(name) {
-            managed.try_reconnect().await
-        } else {
-            Err(forge_foundation::Error::NotFound(format!(
+        let managed = self.connections.read().await.get(name).cloned();
+        let Some(managed) = managed else {
+            return Err(forge_foundation::Error::NotFound(format!(
                 "MCP server '{}' not found",
                 name
-            )))
-        }
+            )));
+        };
+        let result = managed.try_reconnect().await;
+        self.publish_status().await;
+        result
     }
 
     /// 모든 MCP 도구를 Layer2 Tool로 변환
@@ -230,14 +501,12 @@ impl McpBridge {
 
         for (server_name, managed) in self.connections.read().await.iter() {
             // 건강하지 않은 서버는 건너뜀
-            if !*managed.healthy.read().await {
+            if !managed.health.read().await.healthy {
                 debug!("Skipping unhealthy MCP server '{}'", server_name);
                 continue;
             }
 
-            let client_guard = managed.client.read().await;
-
-            for mcp_tool in client_guard.tools().await {
+            for mcp_tool in managed.allowed_tools().await {
                 let tool = McpToolAdapter::new(
                     server_name.clone(),
                     mcp_tool.clone(),
@@ -258,9 +527,7 @@ impl McpBridge {
             // 사용 시간 갱신
             managed.touch().await;
 
-            let client_guard = managed.client.read().await;
-
-            for mcp_tool in client_guard.tools().await {
+            for mcp_tool in managed.allowed_tools().await {
                 let tool = McpToolAdapter::new(
                     server_name.to_string(),
                     mcp_tool.clone(),
@@ -273,31 +540,191 @@ impl McpBridge {
         tools
     }
 
+    /// 서버의 도구 목록 다시 가져오기 (정책을 적용한 도구 수 반환)
+    ///
+    /// `tools/list_changed` 알림을 받은 뒤 호출합니다. 새 도구의 권한도 등록합니다.
+    pub async fn reload_server_tools(&self, server_name: &str) -> Result<usize> {
+        let managed = self
+            .connections
+            .read()
+            .await
+            .get(server_name)
+            .cloned()
+            .ok_or_else(|| forge_foundation::Error::McpServerNotFound(server_name.to_string()))?;
+
+        {
+            let client = managed.client.read().await;
+            client.refresh_tools().await?;
+            register_permissions(&client, &managed.policy).await;
+        }
+        Ok(managed.allowed_tools().await.len())
+    }
+
     /// 연결된 서버 목록
     pub async fn list_servers(&self) -> Vec<String> {
         self.connections.read().await.keys().cloned().collect()
     }
 
+    /// 서버의 도구 노출 정책
+    pub async fn tool_policy(&self, server_name: &str) -> Option<McpToolPolicy> {
+        self.connections
+            .read()
+            .await
+            .get(server_name)
+            .map(|managed| managed.policy.clone())
+    }
+
+    /// 아직 `tools/list`를 가져오지 않은 지연 로딩 서버
+    pub async fn pending_tool_servers(&self) -> Vec<String> {
+        let mut pending = Vec::new();
+        for (server_name, managed) in self.connections.read().await.iter() {
+            if !managed.client.read().await.tools_loaded() {
+                pending.push(server_name.clone());
+            }
+        }
+        pending.sort();
+        pending
+    }
+
+    /// 연결된 모든 서버의 리소스 목록 (서버 이름, 리소스)
+    ///
+    /// 리소스를 지원하지 않거나 조회에 실패한 서버는 건너뜁니다.
+    pub async fn list_resources(&self) -> Vec<(String, McpResource)> {
+        let mut resources = Vec::new();
+
+        for (server_name, managed) in self.connections.read().await.iter() {
+            if !managed.health.read().await.healthy {
+                continue;
+            }
+
+            let client = managed.client.read().await;
+            if !client.is_connected() {
+                continue;
+            }
+            match client.list_resources().await {
+                Ok(value) => {
+                    let listed: Vec<McpResource> = value
+                        .get("resources")
+                        .cloned()
+                        .and_then(|v| serde_json::from_value(v).ok())
+                        .unwrap_or_default();
+                    resources.extend(listed.into_iter().map(|r| (server_name.clone(), r)));
+                }
+                Err(e) => debug!("MCP server '{}' lists no resources: {}", server_name, e),
+            }
+        }
+
+        resources.sort_by(|a, b| (&a.0, &a.1.uri).cmp(&(&b.0, &b.1.uri)));
+        resources
+    }
+
+    /// 서버의 리소스 읽기
+    pub async fn read_resource(
+        &self,
+        server_name: &str,
+        uri: &str,
+    ) -> Result<Vec<McpResourceContent>> {
+        let managed = self
+            .connections
+            .read()
+            .await
+            .get(server_name)
+            .cloned()
+            .ok_or_else(|| forge_foundation::Error::McpServerNotFound(server_name.to_string()))?;
+        managed.touch().await;
+
+        let value = managed.client.read().await.read_resource(uri).await?;
+        let contents = value
+            .get("contents")
+            .cloned()
+            .unwrap_or(Value::Array(Vec::new()));
+        serde_json::from_value(contents).map_err(|e| {
+            forge_foundation::Error::Mcp(format!("Invalid resources/read response: {}", e))
+        })
+    }
+
+    /// 연결된 모든 서버의 프롬프트 목록 (서버 이름, 프롬프트)
+    ///
+    /// 프롬프트를 지원하지 않거나 조회에 실패한 서버는 건너뜁니다.
+    pub async fn list_prompts(&self) -> Vec<(String, McpPrompt)> {
+        let mut prompts = Vec::new();
+
+        for (server_name, managed) in self.connections.read().await.iter() {
+            if !managed.health.read().await.healthy {
+                continue;
+            }
+
+            let client = managed.client.read().await;
+            if !client.is_connected() {
+                continue;
+            }
+            match client.list_prompts().await {
+                Ok(value) => {
+                    let listed: Vec<McpPrompt> = value
+                        .get("prompts")
+                        .cloned()
+                        .and_then(|v| serde_json::from_value(v).ok())
+                        .unwrap_or_default();
+                    prompts.extend(listed.into_iter().map(|p| (server_name.clone(), p)));
+                }
+                Err(e) => debug!("MCP server '{}' lists no prompts: {}", server_name, e),
+            }
+        }
+
+        prompts.sort_by(|a, b| (&a.0, &a.1.name).cmp(&(&b.0, &b.1.name)));
+        prompts
+    }
+
+    /// 서버의 프롬프트 가져오기 (`prompts/get` 결과)
+    pub async fn get_prompt(
+        &self,
+        server_name: &str,
+        name: &str,
+        arguments: &HashMap<String, String>,
+    ) -> Result<Value> {
+        let managed = self
+            .connections
+            .read()
+            .await
+            .get(server_name)
+            .cloned()
+            .ok_or_else(|| forge_foundation::Error::McpServerNotFound(server_name.to_string()))?;
+        managed.touch().await;
+
+        let arguments = serde_json::to_value(arguments)?;
+        let client = managed.client.read().await;
+        client.get_prompt(name, Some(arguments)).await
+    }
+
+    /// 모든 서버의 루트 변경 (연결된 서버에는 `roots/list_changed` 알림)
+    pub async fn set_roots(&self, roots: Vec<McpRoot>) {
+        let clients: Vec<(String, Arc<RwLock<McpClient>>)> = self
+            .connections
+            .read()
+            .await
+            .iter()
+            .map(|(name, managed)| (name.clone(), Arc::clone(&managed.client)))
+            .collect();
+
+        for (name, client) in clients {
+            if let Err(e) = client.read().await.set_roots(roots.clone()).await {
+                warn!("Failed to notify MCP server '{}' of new roots: {}", name, e);
+            }
+        }
+    }
+
     /// 서버 상태 확인
     pub async fn server_status(&self, name: &str) -> Option<ServerStatus> {
-        let connections = self.connections.read().await;
-        if let Some(managed) = connections.get(name) {
-            Some(ServerStatus {
-                name: name.to_string(),
-                connected: managed.client.read().await.is_connected(),
-                healthy: *managed.healthy.read().await,
-                last_used: managed.last_used.read().await.elapsed(),
-                reconnect_attempts: *managed.reconnect_attempts.read().await,
-            })
-        } else {
-            None
-        }
+        let managed = self.connections.read().await.get(name).cloned()?;
+        Some(managed.status(name).await)
     }
 
-    /// 모든 서버 상태
+    /// 모든 서버 상태 (이름순)
     pub async fn all_server_status(&self) -> Vec<ServerStatus> {
+        let mut names = self.list_servers().await;
+        names.sort();
         let mut statuses = Vec::new();
-        for name in self.list_servers().await {
+        for name in names {
             if let Some(status) = self.server_status(&name).await {
                 statuses.push(status);
             }
@@ -324,29 +751,44 @@ impl McpBridge {
         }
     }
 
-    /// 모든 연결 헬스 체크
+    /// 모든 연결 헬스 체크 (죽은 stdio 서버는 재시작 정책에 따라 재시작)
     pub async fn health_check_all(&self) {
-        let connections = self.connections.read().await;
-        for (name, managed) in connections.iter() {
-            if managed.needs_health_check().await {
-                let healthy = managed.perform_health_check().await;
-                if !healthy {
-                    warn!("MCP server '{}' health check failed", name);
-                }
+        let connections: Vec<(String, Arc<ManagedConnection>)> = self
+            .connections
+            .read()
+            .await
+            .iter()
+            .map(|(name, managed)| (name.clone(), Arc::clone(managed)))
+            .collect();
+
+        for (name, managed) in connections {
+            if managed.perform_health_check().await
+                || !managed.can_auto_restart()
+                || !managed.restart_due().await
+            {
+                continue;
+            }
+            match managed.try_reconnect().await {
+                Ok(()) => info!("Restarted MCP server '{}'", name),
+                Err(e) => warn!("Failed to restart MCP server '{}': {}", name, e),
             }
         }
+        self.publish_status().await;
     }
 
     /// 모든 서버 연결 종료
     pub async fn shutdown(&self) {
         let mut connections = self.connections.write().await;
         for (name, managed) in connections.drain() {
+            unregister_mcp_server(&name);
             let mut client = managed.client.write().await;
             if let Err(e) = client.disconnect().await {
                 warn!("Error disconnecting MCP server '{}': {}", name, e);
             }
         }
+        drop(connections);
         info!("All MCP connections closed");
+        self.publish_status().await;
     }
 }
 
@@ -356,6 +798,23 @@ impl Default for McpBridge {
     }
 }
 
+/// 서버 도구들의 권한 정의 등록 (`mcp.<server>.*`, `mcp.<server>.<tool>`)
+async fn register_permissions(client: &McpClient, policy: &McpToolPolicy) {
+    let tools = client.tools().await;
+    let count = register_mcp_server(
+        client.name(),
+        tools
+            .iter()
+            .filter(|tool| policy.allows(&tool.name))
+            .map(|tool| (tool.name.as_str(), tool.description.as_deref())),
+    );
+    debug!(
+        "Registered {} permissions for MCP server '{}'",
+        count,
+        client.name()
+    );
+}
+
 /// 서버 상태 정보
 #[derive(Debug, Clone)]
 pub struct ServerStatus {
@@ -363,7 +822,20 @@ pub struct ServerStatus {
     pub connected: bool,
     pub healthy: bool,
     pub last_used: Duration,
+    /// 연속으로 실패한 재연결 시도 횟수
     pub reconnect_attempts: u32,
+    /// 마지막 initialize 핸드셰이크 소요 시간
+    pub handshake_latency: Option<Duration>,
+    /// 연속 실패 횟수 (연결 끊김 + 실패한 재시작)
+    pub consecutive_failures: u32,
+    /// 마지막 에러
+    pub last_error: Option<String>,
+    /// 성공한 재시작 횟수
+    pub restarts: u32,
+    /// 다음 자동 재시작까지 남은 시간 (재시작 대기 중일 때만)
+    pub next_restart_in: Option<Duration>,
+    /// 끊기면 자동 재시작되는지
+    pub auto_restart: bool,
 }
 
 /// MCP Tool을 Layer2 Tool trait으로 변환하는 어댑터
@@ -411,10 +883,15 @@ impl Tool for McpToolAdapter {
             )
             .category("mcp")
             .permission(
-                PermissionDef::new("mcp.execute", "mcp")
-                    .risk_level(5)
-                    .description(format!("Execute MCP tool {} from {}", self.mcp_tool.name, self.server_name))
-                    .requires_confirmation(true),
+                PermissionDef::new(
+                    mcp_permission_name(&self.server_name, &self.mcp_tool.name),
+                    "mcp",
+                )
+                .risk_level(mcp_tool_risk(&self.mcp_tool.name))
+                .description(format!(
+                    "Execute MCP tool {} from {}",
+                    self.mcp_tool.name, self.server_name
+                )),
             )
     }
 
@@ -430,14 +907,11 @@ impl Tool for McpToolAdapter {
     }
 
     fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
-        // MCP 도구는 Custom 권한 사용 (server:tool 형식)
-        Some(PermissionAction::Custom {
-            name: format!("mcp:{}", self.server_name),
-            details: self.mcp_tool.name.clone(),
-        })
+        // 서버 단위 범위(mcp.<server>.*)로 허용할 수 있도록 서버별 Custom 권한 사용
+        Some(mcp_action(&self.server_name, &self.mcp_tool.name))
     }
 
-    async fn execute(&self, input: Value, _context: &dyn ToolContext) -> Result<ToolResult> {
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
         let call = McpToolCall {
             name: self.mcp_tool.name.clone(),
             arguments: input,
@@ -453,7 +927,28 @@ impl Tool for McpToolAdapter {
             )));
         }
 
-        let result = client.call_tool(&call).await?;
+        // 출력 수신자가 있으면 진행 상황을 전달 (오래 걸리는 호출이 멈춘 것처럼 보이지 않도록)
+        let result = match context.output_sink() {
+            Some(sink) => {
+                let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+                let call_future = client.call_tool_with_progress(&call, progress_tx);
+                tokio::pin!(call_future);
+                loop {
+                    tokio::select! {
+                        result = &mut call_future => break result?,
+                        Some(progress) = progress_rx.recv() => {
+                            if let OutputControl::Abort(reason) = sink.on_progress(&progress) {
+                                return Ok(ToolResult::error(format!(
+                                    "MCP tool '{}' aborted: {}",
+                                    self.mcp_tool.name, reason
+                                )));
+                            }
+                        }
+                    }
+                }
+            }
+            None => client.call_tool(&call).await?,
+        };
 
         if result.is_error {
             Ok(ToolResult::error(
@@ -488,4 +983,67 @@ mod tests {
         let bridge = McpBridge::new();
         assert!(bridge.server_status("nonexistent").await.is_none());
     }
+
+    #[test]
+    fn test_restart_backoff() {
+        let policy = McpRestartPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::ZERO);
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(30), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_crashed_stdio_server_restarts_with_backoff() {
+        let bridge = McpBridge::new().with_restart_policy(McpRestartPolicy {
+            max_attempts: 2,
+            ..Default::default()
+        });
+        let mut status_rx = bridge.subscribe_status();
+
+        let config = McpTransportConfig::Stdio {
+            command: "forge-nonexistent-mcp-server".to_string(),
+            args: vec![],
+            env: HashMap::new(),
+        };
+        bridge
+            .add_server_with_config(McpClient::new("crashy"), config)
+            .await;
+        assert!(status_rx.has_changed().unwrap());
+        assert!(status_rx.borrow_and_update()[0].healthy);
+
+        // 끊김 감지 → 즉시 재시작 시도 → 실패 후 백오프
+        bridge.health_check_all().await;
+        let status = bridge.server_status("crashy").await.unwrap();
+        assert!(!status.healthy);
+        assert_eq!(status.consecutive_failures, 2);
+        assert_eq!(status.reconnect_attempts, 1);
+        assert!(status.last_error.unwrap().contains("spawn"));
+        assert!(status.next_restart_in.is_some());
+        assert!(status.auto_restart);
+
+        // 백오프 중에는 다시 시도하지 않음
+        bridge.health_check_all().await;
+        let status = bridge.server_status("crashy").await.unwrap();
+        assert_eq!(status.consecutive_failures, 2);
+        assert!(status_rx.has_changed().unwrap());
+
+        bridge.remove_server("crashy").await;
+        assert!(status_rx.borrow_and_update().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_server_without_config_is_not_restarted() {
+        let bridge = McpBridge::new();
+        bridge.add_server(McpClient::new("manual")).await;
+
+        bridge.health_check_all().await;
+        bridge.health_check_all().await;
+        let status = bridge.server_status("manual").await.unwrap();
+        assert!(!status.healthy);
+        assert!(!status.auto_restart);
+        assert_eq!(status.consecutive_failures, 1);
+        assert_eq!(status.last_error.as_deref(), Some("Server disconnected"));
+        bridge.remove_server("manual").await;
+    }
 }
@@ -1,15 +1,30 @@
 //! MCP Client - MCP 서버 클라이언트
 //!
 //! MCP 서버와의 통신을 담당하며 도구 목록 관리 및 도구 호출을 처리
-
-use super::transport::{McpTransport, SseTransport, StdioTransport};
-use super::types::{McpServerConfig, McpTool, McpToolCall, McpToolResult, McpTransportConfig};
-use forge_foundation::{Error, Result};
+//!
+//! `roots` capability: 서버의 `roots/list` 요청에 프로젝트 디렉토리를 알려주고,
+//! `set_roots`로 바뀌면 `notifications/roots/list_changed`를 보냅니다.
+//!
+//! 서버 알림: `notifications/tools/list_changed`를 받으면 도구 목록을 다시 가져오도록
+//! 표시하고 `subscribe_tools_changed` 구독자에게 알립니다. `notifications/progress`는
+//! `call_tool_with_progress`로 호출한 도구의 진행 채널로 전달합니다.
+
+use super::auth::McpAuth;
+use super::transport::{
+    JsonRpcError, McpNotificationHandler, McpRequestHandler, McpTransport, SseTransport,
+    StdioTransport,
+};
+use super::types::{
+    McpRoot, McpServerConfig, McpTool, McpToolCall, McpToolResult, McpTransportConfig,
+};
+use forge_foundation::{Error, Result, ToolProgress};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, RwLock};
 use tracing::{debug, error, info, warn};
 
 /// MCP 클라이언트 상태
@@ -162,6 +177,21 @@ pub struct McpClient {
     /// 사용 가능한 도구들
     tools: RwLock<Vec<McpTool>>,
 
+    /// 연결 시 `tools/list`를 미룸 (`ensure_tools`에서 가져옴)
+    lazy_tools: bool,
+
+    /// `tools/list`를 가져왔는지 (`tools/list_changed` 알림 처리기와 공유)
+    tools_loaded: Arc<AtomicBool>,
+
+    /// `tools/list_changed` 알림 (서버 이름)
+    tools_changed: broadcast::Sender<String>,
+
+    /// 진행 중인 도구 호출의 진행 채널 (progressToken → 채널)
+    progress: Arc<std::sync::Mutex<HashMap<String, mpsc::UnboundedSender<ToolProgress>>>>,
+
+    /// progressToken 시퀀스
+    progress_seq: AtomicU64,
+
     /// 서버 capabilities
     capabilities: RwLock<ServerCapabilities>,
 
@@ -179,22 +209,21 @@ pub struct McpClient {
 
     /// 마지막 에러
     last_error: RwLock<Option<McpErrorKind>>,
+
+    /// 마지막 initialize 핸드셰이크 소요 시간
+    handshake_latency: RwLock<Option<Duration>>,
+
+    /// 서버에 알려줄 루트 (`roots/list` 처리기와 공유)
+    roots: Arc<std::sync::RwLock<Vec<McpRoot>>>,
+
+    /// 원격 서버 OAuth 인증 (SSE 연결 시 생성)
+    auth: Option<Arc<McpAuth>>,
 }
 
 impl McpClient {
     /// 새 클라이언트 생성
     pub fn new(name: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            transport: None,
-            tools: RwLock::new(Vec::new()),
-            capabilities: RwLock::new(ServerCapabilities::default()),
-            state: RwLock::new(McpClientState::Disconnected),
-            reconnect_config: McpReconnectConfig::default(),
-            reconnect_count: AtomicU32::new(0),
-            last_transport_config: RwLock::new(None),
-            last_error: RwLock::new(None),
-        }
+        Self::with_reconnect_config(name, McpReconnectConfig::default())
     }
 
     /// 재연결 설정과 함께 클라이언트 생성
@@ -206,18 +235,115 @@ impl McpClient {
             name: name.into(),
             transport: None,
             tools: RwLock::new(Vec::new()),
+            lazy_tools: false,
+            tools_loaded: Arc::default(),
+            tools_changed: broadcast::channel(16).0,
+            progress: Arc::default(),
+            progress_seq: AtomicU64::new(1),
             capabilities: RwLock::new(ServerCapabilities::default()),
             state: RwLock::new(McpClientState::Disconnected),
             reconnect_config,
             reconnect_count: AtomicU32::new(0),
             last_transport_config: RwLock::new(None),
             last_error: RwLock::new(None),
+            handshake_latency: RwLock::new(None),
+            roots: Arc::default(),
+            auth: None,
         }
     }
 
     /// 설정에서 클라이언트 생성
     pub fn from_config(config: &McpServerConfig) -> Self {
-        Self::new(&config.name)
+        Self::new(&config.name).with_lazy_tools(config.tools.lazy)
+    }
+
+    /// 도구 목록 지연 로딩 설정
+    pub fn with_lazy_tools(mut self, lazy: bool) -> Self {
+        self.lazy_tools = lazy;
+        self
+    }
+
+    /// 서버에 알려줄 루트 설정
+    pub fn with_roots(self, roots: Vec<McpRoot>) -> Self {
+        *self.roots.write().unwrap_or_else(|e| e.into_inner()) = roots;
+        self
+    }
+
+    /// 현재 루트
+    pub fn roots(&self) -> Vec<McpRoot> {
+        self.roots.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// 루트 변경 (연결돼 있으면 `notifications/roots/list_changed` 전송)
+    pub async fn set_roots(&self, roots: Vec<McpRoot>) -> Result<()> {
+        {
+            let mut current = self.roots.write().unwrap_or_else(|e| e.into_inner());
+            if *current == roots {
+                return Ok(());
+            }
+            *current = roots;
+        }
+
+        match &self.transport {
+            Some(transport) if transport.is_connected() => {
+                debug!("Notifying MCP server '{}' of changed roots", self.name);
+                transport
+                    .notify("notifications/roots/list_changed", None)
+                    .await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 서버 → 클라이언트 요청 처리기 (`roots/list`, `ping`)
+    fn request_handler(&self) -> McpRequestHandler {
+        let roots = Arc::clone(&self.roots);
+        Arc::new(move |method, _params| match method {
+            "roots/list" => {
+                let roots = roots.read().unwrap_or_else(|e| e.into_inner()).clone();
+                Ok(json!({ "roots": roots }))
+            }
+            "ping" => Ok(json!({})),
+            _ => Err(JsonRpcError::method_not_found()),
+        })
+    }
+
+    /// 서버 알림 처리기 (`notifications/tools/list_changed`, `notifications/progress`)
+    fn notification_handler(&self) -> McpNotificationHandler {
+        let name = self.name.clone();
+        let tools_loaded = Arc::clone(&self.tools_loaded);
+        let tools_changed = self.tools_changed.clone();
+        let progress = Arc::clone(&self.progress);
+        Arc::new(move |method, params| match method {
+            "notifications/tools/list_changed" => {
+                info!("MCP server '{}' changed its tool list", name);
+                tools_loaded.store(false, Ordering::SeqCst);
+                let _ = tools_changed.send(name.clone());
+            }
+            "notifications/progress" => {
+                let Some(params) = params else {
+                    return;
+                };
+                let token = match params.get("progressToken") {
+                    Some(Value::String(token)) => token.clone(),
+                    Some(token) => token.to_string(),
+                    None => return,
+                };
+                let Ok(update) = serde_json::from_value::<ToolProgress>(params) else {
+                    return;
+                };
+                let progress = progress.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(tx) = progress.get(&token) {
+                    let _ = tx.send(update);
+                }
+            }
+            _ => {}
+        })
+    }
+
+    /// `tools/list_changed` 알림 구독 (서버 이름을 받음)
+    pub fn subscribe_tools_changed(&self) -> broadcast::Receiver<String> {
+        self.tools_changed.subscribe()
     }
 
     /// 서버 이름
@@ -235,11 +361,21 @@ impl McpClient {
         self.last_error.read().await.clone()
     }
 
+    /// 마지막 initialize 핸드셰이크 소요 시간 (연결 전에는 None)
+    pub async fn handshake_latency(&self) -> Option<Duration> {
+        *self.handshake_latency.read().await
+    }
+
     /// 재연결 횟수
     pub fn reconnect_count(&self) -> u32 {
         self.reconnect_count.load(Ordering::SeqCst)
     }
 
+    /// OAuth 인증 (SSE 서버에 연결한 뒤에만 있음)
+    pub fn auth(&self) -> Option<&Arc<McpAuth>> {
+        self.auth.as_ref()
+    }
+
     /// 연결 상태
     pub fn is_connected(&self) -> bool {
         self.transport
@@ -272,25 +408,40 @@ impl McpClient {
                     }
                 }
             }
-            McpTransportConfig::Sse { url } => match SseTransport::connect(url).await {
-                Ok(t) => Arc::new(t),
-                Err(e) => {
-                    let error = McpErrorKind::ConnectionFailed(format!(
-                        "Failed to connect to '{}': {}",
-                        url, e
-                    ));
-                    error!("{}", error);
-                    *self.last_error.write().await = Some(error.clone());
-                    *self.state.write().await = McpClientState::Error;
-                    return Err(Error::Internal(error.to_string()));
+            McpTransportConfig::Sse { url, oauth } => {
+                // 같은 서버로 재연결하면 진행 중인 인증 상태를 유지
+                if self.auth.as_ref().map(|a| a.server_url()) != Some(url.as_str()) {
+                    self.auth = match McpAuth::new(url, oauth.clone().unwrap_or_default()) {
+                        Ok(auth) => Some(Arc::new(auth)),
+                        Err(e) => {
+                            warn!("MCP OAuth unavailable for '{}': {}", self.name, e);
+                            None
+                        }
+                    };
                 }
-            },
+                match SseTransport::connect_with_auth(url, self.auth.clone()).await {
+                    Ok(t) => Arc::new(t),
+                    Err(e) => {
+                        let error = McpErrorKind::ConnectionFailed(format!(
+                            "Failed to connect to '{}': {}",
+                            url, e
+                        ));
+                        error!("{}", error);
+                        *self.last_error.write().await = Some(error.clone());
+                        *self.state.write().await = McpClientState::Error;
+                        return Err(Error::Internal(error.to_string()));
+                    }
+                }
+            }
         };
 
+        transport.set_request_handler(self.request_handler());
+        transport.set_notification_handler(self.notification_handler());
         self.transport = Some(transport);
         *self.state.write().await = McpClientState::Initializing;
 
         // Initialize 요청
+        let started = Instant::now();
         if let Err(e) = self.initialize().await {
             let error = McpErrorKind::InitializeFailed(e.to_string());
             error!("{}", error);
@@ -299,9 +450,13 @@ impl McpClient {
             self.transport = None;
             return Err(Error::Internal(error.to_string()));
         }
+        *self.handshake_latency.write().await = Some(started.elapsed());
 
-        // 도구 목록 가져오기
-        if let Err(e) = self.refresh_tools().await {
+        // 도구 목록 가져오기 (지연 로딩이면 처음 필요할 때)
+        if self.lazy_tools {
+            self.tools.write().await.clear();
+            self.tools_loaded.store(false, Ordering::SeqCst);
+        } else if let Err(e) = self.refresh_tools().await {
             warn!("Failed to refresh tools: {}", e);
             // 도구 목록 실패는 치명적이지 않음 - 계속 진행
         }
@@ -476,6 +631,7 @@ impl McpClient {
         }
 
         self.tools.write().await.clear();
+        self.tools_loaded.store(false, Ordering::SeqCst);
 
         info!("Disconnected from MCP server: {}", self.name);
         Ok(())
@@ -502,6 +658,7 @@ impl McpClient {
 
         let tool_count = tools_result.tools.len();
         *self.tools.write().await = tools_result.tools;
+        self.tools_loaded.store(true, Ordering::SeqCst);
 
         debug!(
             "Refreshed {} tools from MCP server '{}'",
@@ -511,6 +668,20 @@ impl McpClient {
         Ok(())
     }
 
+    /// 도구 목록을 가져왔는지 (지연 로딩 서버는 처음 필요할 때까지 false)
+    pub fn tools_loaded(&self) -> bool {
+        self.tools_loaded.load(Ordering::SeqCst)
+    }
+
+    /// 도구 목록이 없으면 가져오기 (새로 가져왔으면 true)
+    pub async fn ensure_tools(&self) -> Result<bool> {
+        if self.tools_loaded() {
+            return Ok(false);
+        }
+        self.refresh_tools().await?;
+        Ok(true)
+    }
+
     /// 사용 가능한 도구 목록
     pub async fn tools(&self) -> Vec<McpTool> {
         self.tools.read().await.clone()
@@ -528,6 +699,40 @@ impl McpClient {
 
     /// 도구 호출
     pub async fn call_tool(&self, call: &McpToolCall) -> Result<McpToolResult> {
+        self.send_tool_call(call, None).await
+    }
+
+    /// 진행 상황을 받으며 도구 호출 (`notifications/progress`를 `progress`로 전달)
+    pub async fn call_tool_with_progress(
+        &self,
+        call: &McpToolCall,
+        progress: mpsc::UnboundedSender<ToolProgress>,
+    ) -> Result<McpToolResult> {
+        let token = format!(
+            "{}-{}",
+            self.name,
+            self.progress_seq.fetch_add(1, Ordering::SeqCst)
+        );
+        self.progress
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(token.clone(), progress);
+
+        let result = self.send_tool_call(call, Some(&token)).await;
+
+        self.progress
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&token);
+        result
+    }
+
+    /// `tools/call` 요청 (progressToken이 있으면 `_meta`에 포함)
+    async fn send_tool_call(
+        &self,
+        call: &McpToolCall,
+        progress_token: Option<&str>,
+    ) -> Result<McpToolResult> {
         let transport = self
             .transport
             .as_ref()
@@ -539,10 +744,13 @@ impl McpClient {
         );
 
         // tools/call 요청
-        let params = json!({
+        let mut params = json!({
             "name": call.name,
             "arguments": call.arguments
         });
+        if let Some(token) = progress_token {
+            params["_meta"] = json!({ "progressToken": token });
+        }
 
         let result = transport.request("tools/call", Some(params)).await?;
 
@@ -632,4 +840,59 @@ mod tests {
         let result = client.call_tool(&call).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_roots_request_handler() {
+        let root = McpRoot {
+            uri: "file:///work/app".to_string(),
+            name: Some("app".to_string()),
+        };
+        let client = McpClient::new("fs").with_roots(vec![root.clone()]);
+        let handler = client.request_handler();
+
+        let listed = handler("roots/list", None).unwrap();
+        assert_eq!(listed["roots"][0]["uri"], "file:///work/app");
+
+        // 연결 전 변경은 알림 없이 반영
+        let other = McpRoot {
+            uri: "file:///work/lib".to_string(),
+            name: None,
+        };
+        client.set_roots(vec![root, other]).await.unwrap();
+        let listed = handler("roots/list", None).unwrap();
+        assert_eq!(listed["roots"].as_array().unwrap().len(), 2);
+
+        assert_eq!(handler("ping", None).unwrap(), json!({}));
+        assert_eq!(handler("sampling/createMessage", None).unwrap_err().code, -32601);
+    }
+
+    #[tokio::test]
+    async fn test_notification_handler() {
+        let client = McpClient::new("github");
+        client.tools_loaded.store(true, Ordering::SeqCst);
+        let mut changed = client.subscribe_tools_changed();
+        let handler = client.notification_handler();
+
+        handler("notifications/tools/list_changed", None);
+        assert_eq!(changed.recv().await.unwrap(), "github");
+        assert!(!client.tools_loaded());
+
+        // 등록된 progressToken의 진행 상황만 전달
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        client
+            .progress
+            .lock()
+            .unwrap()
+            .insert("github-1".to_string(), tx);
+        handler(
+            "notifications/progress",
+            Some(json!({ "progressToken": "other", "progress": 1 })),
+        );
+        handler(
+            "notifications/progress",
+            Some(json!({ "progressToken": "github-1", "progress": 2, "total": 4 })),
+        );
+        assert_eq!(rx.recv().await.unwrap().percent(), Some(50));
+        assert!(rx.try_recv().is_err());
+    }
 }
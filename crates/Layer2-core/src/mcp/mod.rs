@@ -1,16 +1,22 @@
 //! MCP Bridge - Model Context Protocol 연동
 //!
-//! 외부 MCP 서버를 통해 도구를 확장합니다.
+//! 외부 MCP 서버를 통해 도구를 확장하고, 반대로 ForgeCode 자신을
+//! MCP 서버로 노출합니다 (`McpServer`, `forge mcp serve`).
 //!
 //! ## 기능
 //! - MCP 서버 연결 관리 (stdio, SSE)
-//! - 도구 목록 동기화
+//! - 도구 목록 동기화 (`notifications/tools/list_changed` 시 다시 가져옴)
+//! - 오래 걸리는 도구 호출의 진행 상황 전달 (`notifications/progress`)
 //! - 도구 호출 프록시
-//! - 리소스 및 프롬프트 접근
+//! - `roots` capability로 프로젝트 디렉토리 알림 (`McpRoot`)
+//! - 서버 헬스 상태 스트림과 죽은 stdio 서버 자동 재시작 (`McpRestartPolicy`)
+//! - 리소스 접근 (`resource_read` 도구로 에이전트 컨텍스트에 제공)
+//! - 프롬프트를 `/<server>:<prompt>` 슬래시 명령어 스킬로 노출 (`McpPromptSkill`)
+//! - 서버별 도구 필터·지연 로딩, 접힌 서버는 `search_mcp_tools`로 검색 (`McpToolPolicy`)
 //!
 //! ## 지원 전송
 //! - stdio: 로컬 프로세스와 stdin/stdout 통신
-//! - SSE: HTTP Server-Sent Events 기반 원격 통신
+//! - SSE: HTTP Server-Sent Events 기반 원격 통신 (OAuth 2.1 + PKCE 인증)
 //!
 //! ## 프로토콜
 //! - JSON-RPC 2.0 over stdio/SSE
@@ -19,15 +25,32 @@
 //! ## 참고
 //! - https://modelcontextprotocol.io/
 
+mod auth;
 mod bridge;
 mod client;
+mod prompt;
+mod resource;
+mod search;
+mod server;
 mod transport;
 mod types;
 
-pub use bridge::{McpBridge, McpToolAdapter, ServerStatus};
+pub use auth::{
+    open_browser, AuthServerMetadata, BrowserOpener, McpAuth, McpCredential, McpOAuthConfig,
+    McpTokenStore, MCP_TOKENS_FILE,
+};
+pub use bridge::{McpBridge, McpRestartPolicy, McpToolAdapter, ServerStatus};
 pub use client::{McpClient, McpClientState, McpErrorKind, McpReconnectConfig};
-pub use transport::{McpTransport, SseTransport, StdioTransport};
+pub use prompt::{render_prompt_messages, McpPromptSkill, MCP_SKILL_CATEGORY};
+pub use resource::McpResourceTool;
+pub use search::McpToolSearchTool;
+pub use server::{
+    generate_sse_token, McpServer, DEFAULT_SERVED_TOOLS, REPOMAP_FULL_URI, REPOMAP_SUMMARY_URI,
+};
+pub use transport::{
+    McpNotificationHandler, McpRequestHandler, McpTransport, SseTransport, StdioTransport,
+};
 pub use types::{
-    McpContent, McpPrompt, McpPromptArgument, McpResource, McpServerConfig, McpTool, McpToolCall,
-    McpToolResult, McpTransportConfig,
+    McpContent, McpPrompt, McpPromptArgument, McpResource, McpResourceContent, McpRoot,
+    McpServerConfig, McpTool, McpToolCall, McpToolPolicy, McpToolResult, McpTransportConfig,
 };
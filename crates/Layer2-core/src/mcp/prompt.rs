@@ -0,0 +1,335 @@
+//! MCP Prompt Skill - 서버 프롬프트를 슬래시 명령어로
+//!
+//! 연결된 MCP 서버의 프롬프트(`prompts/list`)를 `/<server>:<prompt>` 스킬로
+//! 노출합니다 (예: `/notion:summarize-page`). 호출하면 `prompts/get`으로 받은
+//! 메시지를 에이전트에 보낼 프롬프트로 사용합니다.
+//!
+//! 인자는 `--<name> <value>` 또는 선언 순서대로 위치 인자로 넘깁니다.
+//! 마지막 인자는 남은 위치 인자를 모두 받으므로 따옴표 없이 문장을 넘길 수 있습니다.
+
+use super::bridge::McpBridge;
+use super::types::McpPrompt;
+use crate::skill::{
+    Skill, SkillArgument, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput,
+};
+use async_trait::async_trait;
+use forge_foundation::Result;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// MCP 프롬프트 스킬의 카테고리
+pub const MCP_SKILL_CATEGORY: &str = "mcp";
+
+/// MCP 프롬프트 스킬
+pub struct McpPromptSkill {
+    server: String,
+    prompt: McpPrompt,
+    bridge: Arc<RwLock<McpBridge>>,
+}
+
+impl McpPromptSkill {
+    pub fn new(
+        server: impl Into<String>,
+        prompt: McpPrompt,
+        bridge: Arc<RwLock<McpBridge>>,
+    ) -> Self {
+        Self {
+            server: server.into(),
+            prompt,
+            bridge,
+        }
+    }
+
+    /// 서버 이름
+    pub fn server(&self) -> &str {
+        &self.server
+    }
+
+    /// 원본 프롬프트 정의
+    pub fn prompt(&self) -> &McpPrompt {
+        &self.prompt
+    }
+
+    /// 스킬 이름 (`<server>:<prompt>`)
+    pub fn skill_name(&self) -> String {
+        format!("{}:{}", self.server, self.prompt.name)
+    }
+
+    /// 입력을 프롬프트 인자로 변환
+    ///
+    /// 플래그로 주지 않은 인자는 선언 순서대로 위치 인자로 채우고,
+    /// 마지막 빈 인자는 남은 위치 인자를 공백으로 이어 받습니다.
+    pub fn prompt_arguments(&self, input: &SkillInput) -> HashMap<String, String> {
+        let mut arguments = input.arguments.clone();
+        let unfilled: Vec<&str> = self
+            .prompt
+            .arguments
+            .iter()
+            .map(|arg| arg.name.as_str())
+            .filter(|name| !arguments.contains_key(*name))
+            .collect();
+
+        let mut positional = input.positional_args.iter();
+        for (i, name) in unfilled.iter().enumerate() {
+            let value = if i + 1 == unfilled.len() {
+                positional.by_ref().cloned().collect::<Vec<_>>().join(" ")
+            } else {
+                match positional.next() {
+                    Some(value) => value.clone(),
+                    None => break,
+                }
+            };
+            if value.is_empty() {
+                break;
+            }
+            arguments.insert(name.to_string(), value);
+        }
+        arguments
+    }
+}
+
+#[async_trait]
+impl Skill for McpPromptSkill {
+    fn definition(&self) -> SkillDefinition {
+        let name = self.skill_name();
+        let usage = self
+            .prompt
+            .arguments
+            .iter()
+            .map(|arg| {
+                if arg.required {
+                    format!(" --{} <value>", arg.name)
+                } else {
+                    format!(" [--{} <value>]", arg.name)
+                }
+            })
+            .collect::<String>();
+
+        SkillDefinition {
+            command: format!("/{}", name),
+            description: self
+                .prompt
+                .description
+                .clone()
+                .filter(|d| !d.trim().is_empty())
+                .unwrap_or_else(|| format!("MCP prompt {} from {}", self.prompt.name, self.server)),
+            usage: format!("/{}{}", name, usage),
+            arguments: self
+                .prompt
+                .arguments
+                .iter()
+                .map(|arg| SkillArgument {
+                    name: arg.name.clone(),
+                    description: arg.description.clone().unwrap_or_else(|| arg.name.clone()),
+                    required: arg.required,
+                    default: None,
+                    short_flag: None,
+                    long_flag: Some(format!("--{}", arg.name)),
+                    ..Default::default()
+                })
+                .collect(),
+            name,
+            category: MCP_SKILL_CATEGORY.into(),
+            user_invocable: true,
+        }
+    }
+
+    fn metadata(&self) -> SkillMetadata {
+        SkillMetadata {
+            name: self.skill_name(),
+            source: Some(format!("mcp:{}", self.server)),
+            tags: vec![MCP_SKILL_CATEGORY.into(), self.server.clone()],
+            ..Default::default()
+        }
+    }
+
+    /// 따옴표로 묶인 값을 하나의 인자로 파싱 (`--title "Weekly notes"`, `--title=...`)
+    fn parse_input(&self, raw: &str) -> SkillInput {
+        let mut input = SkillInput::new(raw);
+        let parts =
+            shlex::split(raw).unwrap_or_else(|| raw.split_whitespace().map(String::from).collect());
+
+        let mut parts = parts.into_iter().skip(1).peekable();
+        while let Some(part) = parts.next() {
+            match part.strip_prefix("--") {
+                Some(flag) => {
+                    if let Some((key, value)) = flag.split_once('=') {
+                        input.arguments.insert(key.to_string(), value.to_string());
+                    } else {
+                        let value = parts
+                            .next_if(|next| !next.starts_with("--"))
+                            .unwrap_or_else(|| "true".to_string());
+                        input.arguments.insert(flag.to_string(), value);
+                    }
+                }
+                None => input.positional_args.push(part),
+            }
+        }
+        input
+    }
+
+    async fn execute(&self, _ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
+        let missing = self.missing_arguments(&input);
+        if !missing.is_empty() {
+            let names: Vec<_> = missing.iter().map(|arg| arg.name.as_str()).collect();
+            return Ok(SkillOutput::failure(format!(
+                "Missing required argument{}: {}",
+                if names.len() == 1 { "" } else { "s" },
+                names.join(", ")
+            )));
+        }
+
+        let arguments = self.prompt_arguments(&input);
+        let result = {
+            let bridge = self.bridge.read().await;
+            bridge
+                .get_prompt(&self.server, &self.prompt.name, &arguments)
+                .await
+        };
+        match result {
+            Ok(value) => {
+                let text = render_prompt_messages(&value);
+                if text.trim().is_empty() {
+                    Ok(SkillOutput::failure(format!(
+                        "MCP prompt {} returned no text",
+                        self.skill_name()
+                    )))
+                } else {
+                    Ok(SkillOutput::success(text).with_data(value))
+                }
+            }
+            Err(e) => Ok(SkillOutput::failure(format!(
+                "Failed to get MCP prompt {}: {}",
+                self.skill_name(),
+                e
+            ))),
+        }
+    }
+}
+
+/// `prompts/get` 결과의 메시지를 하나의 프롬프트 텍스트로
+///
+/// 사용자 메시지는 그대로, 그 외 역할은 `[role]`을 붙입니다.
+/// 임베드된 리소스는 텍스트만, 이미지 등은 자리표시만 남깁니다.
+pub fn render_prompt_messages(value: &Value) -> String {
+    let Some(messages) = value.get("messages").and_then(Value::as_array) else {
+        return String::new();
+    };
+
+    messages
+        .iter()
+        .filter_map(|message| {
+            let content = message.get("content")?;
+            let text = match content.get("type").and_then(Value::as_str) {
+                Some("text") => content.get("text")?.as_str()?.to_string(),
+                Some("resource") => {
+                    let resource = content.get("resource")?;
+                    match resource.get("text").and_then(Value::as_str) {
+                        Some(text) => text.to_string(),
+                        None => format!(
+                            "[resource {}]",
+                            resource.get("uri").and_then(Value::as_str).unwrap_or("")
+                        ),
+                    }
+                }
+                Some(other) => format!("[{} content]", other),
+                None => return None,
+            };
+            match message.get("role").and_then(Value::as_str) {
+                Some("user") | None => Some(text),
+                Some(role) => Some(format!("[{}] {}", role, text)),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::McpPromptArgument;
+    use serde_json::json;
+
+    fn skill() -> McpPromptSkill {
+        let argument = |name: &str, required| McpPromptArgument {
+            name: name.to_string(),
+            description: None,
+            required,
+        };
+        McpPromptSkill::new(
+            "notion",
+            McpPrompt {
+                name: "summarize-page".to_string(),
+                description: Some("Summarize a Notion page".to_string()),
+                arguments: vec![argument("page", true), argument("style", false)],
+            },
+            Arc::new(RwLock::new(McpBridge::new())),
+        )
+    }
+
+    #[test]
+    fn test_definition() {
+        let def = skill().definition();
+        assert_eq!(def.name, "notion:summarize-page");
+        assert_eq!(def.command, "/notion:summarize-page");
+        assert_eq!(def.category, MCP_SKILL_CATEGORY);
+        assert_eq!(
+            def.usage,
+            "/notion:summarize-page --page <value> [--style <value>]"
+        );
+        assert!(def.arguments[0].required);
+    }
+
+    #[test]
+    fn test_prompt_arguments() {
+        let skill = skill();
+
+        let input = skill.parse_input("/notion:summarize-page --style=brief \"Weekly notes\"");
+        let args = skill.prompt_arguments(&input);
+        assert_eq!(args["page"], "Weekly notes");
+        assert_eq!(args["style"], "brief");
+
+        // 마지막 인자는 남은 위치 인자를 모두 받음
+        let input = skill.parse_input("/notion:summarize-page roadmap in detail");
+        let args = skill.prompt_arguments(&input);
+        assert_eq!(args["page"], "roadmap");
+        assert_eq!(args["style"], "in detail");
+
+        let input = skill.parse_input("/notion:summarize-page --style brief");
+        assert_eq!(skill.missing_arguments(&input)[0].name, "page");
+    }
+
+    #[tokio::test]
+    async fn test_missing_argument_fails() {
+        let skill = skill();
+        let tool_ctx = crate::tool::RuntimeContext::new(
+            "test-session",
+            std::env::temp_dir(),
+            Arc::new(forge_foundation::PermissionService::new()),
+        );
+        let ctx = SkillContext::new(&tool_ctx, "test-session");
+        let output = skill
+            .execute(&ctx, skill.parse_input("/notion:summarize-page"))
+            .await
+            .unwrap();
+        assert!(!output.success);
+        assert_eq!(output.message, "Missing required argument: page");
+    }
+
+    #[test]
+    fn test_render_prompt_messages() {
+        let value = json!({
+            "messages": [
+                { "role": "user", "content": { "type": "text", "text": "Summarize this page." } },
+                { "role": "user", "content": { "type": "resource", "resource": { "uri": "notion://p/1", "text": "# Roadmap" } } },
+                { "role": "assistant", "content": { "type": "image", "data": "..." } }
+            ]
+        });
+        assert_eq!(
+            render_prompt_messages(&value),
+            "Summarize this page.\n\n# Roadmap\n\n[assistant] [image content]"
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Resource Read Tool - MCP 리소스를 에이전트 컨텍스트로 가져오기
+//!
+//! 연결된 MCP 서버의 리소스를 나열하고(`uri` 없이 호출), 선택한 리소스를
+//! 토큰 예산(`max_tokens`, 기본 4000) 안으로 잘라 대화에 넣습니다.
+//! MCP 서버가 하나 이상 연결되면 `AgentContext`가 자동 등록합니다.
+
+use super::bridge::McpBridge;
+use super::types::{McpResource, McpResourceContent};
+use async_trait::async_trait;
+use forge_foundation::permission::mcp_action;
+use forge_foundation::{
+    PermissionAction, PermissionStatus, Result, Tool, ToolContext, ToolMeta, ToolResult,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// 리소스 읽기에 필요한 권한의 도구 이름 (`mcp.<server>.read_resource`)
+const READ_RESOURCE_PERMISSION: &str = "read_resource";
+
+/// 토큰 추정 (약 4자 = 1토큰)
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Resource Read 도구 입력
+#[derive(Debug, Default, Deserialize)]
+pub struct ResourceReadInput {
+    /// 읽을 리소스 URI (없으면 목록)
+    #[serde(default, alias = "resource", alias = "url")]
+    pub uri: Option<String>,
+
+    /// MCP 서버 이름 (URI가 여러 서버에 있을 때 필요)
+    #[serde(default, alias = "server_name")]
+    pub server: Option<String>,
+
+    /// 최대 토큰 수 (기본: 4000)
+    #[serde(default, alias = "token_budget", alias = "limit")]
+    pub max_tokens: Option<usize>,
+}
+
+/// MCP 리소스 읽기 도구
+pub struct McpResourceTool {
+    bridge: Arc<RwLock<McpBridge>>,
+}
+
+impl McpResourceTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "resource_read";
+
+    /// 기본 토큰 예산
+    pub const DEFAULT_MAX_TOKENS: usize = 4000;
+
+    pub fn new(bridge: Arc<RwLock<McpBridge>>) -> Self {
+        Self { bridge }
+    }
+
+    fn parse_input(input: &Value) -> ResourceReadInput {
+        match input {
+            Value::String(uri) => ResourceReadInput {
+                uri: Some(uri.clone()),
+                ..Default::default()
+            },
+            other => serde_json::from_value(other.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// 요청한 URI를 가진 서버 찾기
+    fn resolve_server(
+        resources: &[(String, McpResource)],
+        servers: &[String],
+        uri: &str,
+        server: Option<&str>,
+    ) -> std::result::Result<String, String> {
+        if let Some(server) = server {
+            return if servers.iter().any(|s| s == server) {
+                Ok(server.to_string())
+            } else {
+                Err(format!("MCP server '{}' is not connected", server))
+            };
+        }
+
+        let mut owners: Vec<&str> = resources
+            .iter()
+            .filter(|(_, r)| r.uri == uri)
+            .map(|(s, _)| s.as_str())
+            .collect();
+        owners.dedup();
+        match (owners.as_slice(), servers) {
+            ([owner], _) => Ok(owner.to_string()),
+            ([], [only]) => Ok(only.clone()),
+            ([], _) => Err(format!(
+                "No connected MCP server lists '{}'; pass 'server' to read it anyway",
+                uri
+            )),
+            (_, _) => Err(format!(
+                "'{}' is offered by several servers ({}); pass 'server'",
+                uri,
+                owners.join(", ")
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for McpResourceTool {
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Resource Read")
+            .description(
+                "List resources offered by connected MCP servers, or read one into context by uri (truncated to max_tokens)",
+            )
+            .category("mcp")
+    }
+
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "uri": {
+                    "type": "string",
+                    "description": "Resource URI to read. Omit to list available resources."
+                },
+                "server": {
+                    "type": "string",
+                    "description": "MCP server name (only needed when several servers offer the same URI)"
+                },
+                "max_tokens": {
+                    "type": "integer",
+                    "description": "Maximum tokens of content to return (default: 4000)"
+                }
+            }
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        // 목록은 권한 불필요, 읽기는 서버 단위 MCP 권한 (`mcp.<server>.*`로 허용/거부)
+        // 서버를 생략하면 실행 중 서버를 찾은 뒤 검사
+        let input = Self::parse_input(input);
+        input.uri.as_ref()?;
+        Some(mcp_action(
+            input.server.as_deref()?,
+            READ_RESOURCE_PERMISSION,
+        ))
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let input = Self::parse_input(&input);
+        let bridge = self.bridge.read().await;
+        let resources = bridge.list_resources().await;
+
+        let Some(uri) = input.uri else {
+            return Ok(ToolResult::success(format_resource_list(
+                &resources,
+                input.server.as_deref(),
+            )));
+        };
+
+        let servers = bridge.list_servers().await;
+        let server = match Self::resolve_server(&resources, &servers, &uri, input.server.as_deref())
+        {
+            Ok(server) => server,
+            Err(message) => return Ok(ToolResult::error(message)),
+        };
+
+        let action = mcp_action(&server, READ_RESOURCE_PERMISSION);
+        if matches!(
+            context.check_permission(Self::NAME, &action).await,
+            PermissionStatus::Denied
+        ) {
+            return Ok(ToolResult::error(format!(
+                "Permission denied for MCP server '{}'",
+                server
+            )));
+        }
+
+        let contents = match bridge.read_resource(&server, &uri).await {
+            Ok(contents) => contents,
+            Err(e) => {
+                return Ok(ToolResult::error(format!(
+                    "Failed to read '{}' from {}: {}",
+                    uri, server, e
+                )))
+            }
+        };
+
+        let max_tokens = input.max_tokens.unwrap_or(Self::DEFAULT_MAX_TOKENS).max(1);
+        let (text, truncated) = truncate_to_tokens(&format_contents(&contents), max_tokens);
+        let mut output = format!("# {} ({})\n\n{}", uri, server, text);
+        if let Some(total) = truncated {
+            output.push_str(&format!(
+                "\n\n[Truncated to ~{} of ~{} tokens; call again with a larger max_tokens to read more]",
+                max_tokens, total
+            ));
+        }
+        Ok(ToolResult::success(output))
+    }
+}
+
+/// 리소스 목록 출력
+fn format_resource_list(resources: &[(String, McpResource)], server: Option<&str>) -> String {
+    let listed: Vec<_> = resources
+        .iter()
+        .filter(|(s, _)| server.is_none_or(|server| s == server))
+        .collect();
+    if listed.is_empty() {
+        return "No MCP resources available.".to_string();
+    }
+
+    let mut output = format!("{} MCP resources:\n", listed.len());
+    for (server, resource) in listed {
+        output.push_str(&format!(
+            "- [{}] {} - {}",
+            server, resource.uri, resource.name
+        ));
+        if let Some(mime) = &resource.mime_type {
+            output.push_str(&format!(" ({})", mime));
+        }
+        if let Some(description) = resource.description.as_deref().filter(|d| !d.is_empty()) {
+            output.push_str(&format!(": {}", description));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// 리소스 내용을 텍스트로 (바이너리는 요약만)
+fn format_contents(contents: &[McpResourceContent]) -> String {
+    contents
+        .iter()
+        .map(|content| match (&content.text, &content.blob) {
+            (Some(text), _) => text.clone(),
+            (None, Some(blob)) => format!(
+                "[binary content {} ({}), ~{} bytes]",
+                content.uri,
+                content
+                    .mime_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream"),
+                blob.len() * 3 / 4
+            ),
+            (None, None) => String::new(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// 토큰 예산으로 자르기 (잘렸으면 원래 추정 토큰 수 반환)
+pub fn truncate_to_tokens(text: &str, max_tokens: usize) -> (String, Option<usize>) {
+    let max_chars = max_tokens.saturating_mul(CHARS_PER_TOKEN);
+    if text.len() <= max_chars {
+        return (text.to_string(), None);
+    }
+
+    let mut end = max_chars;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    // 줄 중간에서 자르지 않도록 마지막 줄바꿈까지
+    if let Some(newline) = text[..end].rfind('\n').filter(|&n| n > end / 2) {
+        end = newline;
+    }
+    (
+        text[..end].to_string(),
+        Some(text.len().div_ceil(CHARS_PER_TOKEN)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(uri: &str) -> McpResource {
+        McpResource {
+            uri: uri.to_string(),
+            name: uri.rsplit('/').next().unwrap().to_string(),
+            description: Some("A doc".to_string()),
+            mime_type: Some("text/markdown".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_truncate_to_tokens() {
+        let text = "line one\nline two\nline three\n";
+        assert_eq!(truncate_to_tokens(text, 100), (text.to_string(), None));
+
+        let (cut, total) = truncate_to_tokens(text, 5);
+        assert_eq!(cut, "line one\nline two");
+        assert_eq!(total, Some(8));
+
+        // 멀티바이트 문자 경계
+        let (cut, _) = truncate_to_tokens("가나다라마바사", 1);
+        assert_eq!(cut, "가");
+    }
+
+    #[test]
+    fn test_resolve_server_and_list() {
+        let resources = vec![
+            ("docs".to_string(), resource("file:///guide.md")),
+            ("wiki".to_string(), resource("file:///guide.md")),
+            ("wiki".to_string(), resource("wiki://home")),
+        ];
+        let servers = vec!["docs".to_string(), "wiki".to_string()];
+
+        assert_eq!(
+            McpResourceTool::resolve_server(&resources, &servers, "wiki://home", None),
+            Ok("wiki".to_string())
+        );
+        assert!(
+            McpResourceTool::resolve_server(&resources, &servers, "file:///guide.md", None)
+                .unwrap_err()
+                .contains("several servers")
+        );
+        assert!(
+            McpResourceTool::resolve_server(&resources, &servers, "x://y", Some("gone")).is_err()
+        );
+
+        let list = format_resource_list(&resources, Some("wiki"));
+        assert!(list.starts_with("2 MCP resources"));
+        assert!(list.contains("- [wiki] wiki://home - home (text/markdown): A doc"));
+    }
+
+    #[tokio::test]
+    async fn test_no_servers() {
+        let tool = McpResourceTool::new(Arc::new(RwLock::new(McpBridge::new())));
+        assert!(tool.required_permission(&json!({})).is_none());
+        assert!(tool
+            .required_permission(&json!({ "uri": "wiki://home" }))
+            .is_none());
+        assert!(tool
+            .required_permission(&json!({ "uri": "wiki://home", "server": "wiki" }))
+            .is_some());
+
+        let ctx = crate::tool::RuntimeContext::new(
+            "test-session",
+            std::env::temp_dir(),
+            Arc::new(forge_foundation::PermissionService::new()),
+        );
+        let listed = tool.execute(json!({}), &ctx).await.unwrap();
+        assert_eq!(listed.output, "No MCP resources available.");
+        let read = tool
+            .execute(json!({ "uri": "wiki://home" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!read.success);
+    }
+}
@@ -0,0 +1,299 @@
+//! Search MCP Tools - 접힌 MCP 서버 도구 찾기
+//!
+//! MCP 서버를 여러 개 연결하면 도구 목록과 LLM 프롬프트가 지나치게 커집니다.
+//! `collapsed` 정책의 서버(또는 도구 수 한도를 넘긴 서버)는 도구를 직접 등록하지
+//! 않고, 이 메타 도구로 찾은 도구만 레지스트리에 로드합니다. 로드된 도구는 다음
+//! 턴부터 이름으로 바로 호출할 수 있습니다.
+
+use super::bridge::McpBridge;
+use crate::tool::ToolRegistry;
+use async_trait::async_trait;
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, Weak};
+use tokio::sync::RwLock;
+
+/// Search MCP Tools 입력
+#[derive(Debug, Default, Deserialize)]
+pub struct SearchMcpToolsInput {
+    /// 검색어 (비어 있으면 목록만)
+    #[serde(default)]
+    pub query: String,
+
+    /// 특정 서버로 제한
+    #[serde(default)]
+    pub server: Option<String>,
+
+    /// 로드할 최대 도구 수 (기본: 5)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// 접힌 MCP 도구 검색 메타 도구
+pub struct McpToolSearchTool {
+    bridge: Arc<RwLock<McpBridge>>,
+    /// 도구를 로드할 레지스트리 (이 도구를 담고 있으므로 순환 참조 방지)
+    registry: Weak<RwLock<ToolRegistry>>,
+    /// 접힌 서버
+    servers: Vec<String>,
+    /// 검색으로 로드한 도구 (새로고침 후에도 유지)
+    loaded: Arc<Mutex<HashSet<String>>>,
+}
+
+impl McpToolSearchTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "search_mcp_tools";
+
+    /// 기본 로드 수
+    pub const DEFAULT_LIMIT: usize = 5;
+
+    pub fn new(
+        bridge: Arc<RwLock<McpBridge>>,
+        registry: Weak<RwLock<ToolRegistry>>,
+        servers: Vec<String>,
+        loaded: Arc<Mutex<HashSet<String>>>,
+    ) -> Self {
+        Self {
+            bridge,
+            registry,
+            servers,
+            loaded,
+        }
+    }
+
+    /// 접힌 서버 목록
+    pub fn servers(&self) -> &[String] {
+        &self.servers
+    }
+}
+
+#[async_trait]
+impl Tool for McpToolSearchTool {
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Search MCP Tools")
+            .description(format!(
+                "Find tools of MCP servers that are not listed directly ({}) and load the best matches so they can be called by name on the next turn",
+                self.servers.join(", ")
+            ))
+            .category("mcp")
+    }
+
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "Keywords describing the needed tool (e.g. 'create issue'). Empty lists every tool name."
+                },
+                "server": {
+                    "type": "string",
+                    "enum": self.servers,
+                    "description": "Only search this MCP server"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of tools to load (default: 5)"
+                }
+            }
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        // 검색과 로드만 하며, 로드된 도구 호출은 각자 권한 확인
+        None
+    }
+
+    async fn execute(&self, input: Value, _context: &dyn ToolContext) -> Result<ToolResult> {
+        let input: SearchMcpToolsInput = serde_json::from_value(input).unwrap_or_default();
+        let servers: Vec<&String> = self
+            .servers
+            .iter()
+            .filter(|s| input.server.as_ref().is_none_or(|server| *s == server))
+            .collect();
+        if servers.is_empty() {
+            return Ok(ToolResult::error(format!(
+                "No collapsed MCP server named '{}' (available: {})",
+                input.server.unwrap_or_default(),
+                self.servers.join(", ")
+            )));
+        }
+
+        // 후보 수집 (지연 로딩 서버는 여기서 tools/list)
+        let mut candidates = Vec::new();
+        {
+            let bridge = self.bridge.read().await;
+            for server in servers {
+                for tool in bridge.get_server_tools(server).await {
+                    candidates.push((server.clone(), tool));
+                }
+            }
+        }
+
+        let terms: Vec<String> = input
+            .query
+            .split_whitespace()
+            .map(|t| t.to_lowercase())
+            .collect();
+        if terms.is_empty() {
+            let mut names: Vec<String> = candidates.iter().map(|(_, t)| t.meta().name).collect();
+            names.sort();
+            return Ok(ToolResult::success(format!(
+                "{} MCP tools available (search with a query to load them):\n{}",
+                names.len(),
+                names.join("\n")
+            )));
+        }
+
+        let mut matches: Vec<_> = candidates
+            .into_iter()
+            .map(|(server, tool)| {
+                let meta = tool.meta();
+                (score(&terms, &meta.name, &meta.description), server, tool)
+            })
+            .filter(|(score, _, _)| *score > 0)
+            .collect();
+        matches.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| a.2.meta().name.cmp(&b.2.meta().name))
+        });
+        matches.truncate(input.limit.unwrap_or(Self::DEFAULT_LIMIT).max(1));
+
+        if matches.is_empty() {
+            return Ok(ToolResult::success(format!(
+                "No MCP tools match '{}'. Search with an empty query to list them.",
+                input.query
+            )));
+        }
+
+        let mut output = format!(
+            "Loaded {} MCP tool{}; call {} by name:\n",
+            matches.len(),
+            if matches.len() == 1 { "" } else { "s" },
+            if matches.len() == 1 { "it" } else { "them" }
+        );
+        let Some(registry) = self.registry.upgrade() else {
+            return Ok(ToolResult::error("Tool registry is no longer available"));
+        };
+        {
+            let mut registry = registry.write().await;
+            let mut loaded = self.loaded.lock().unwrap();
+            for (_, server, tool) in matches {
+                let meta = tool.meta();
+                output.push_str(&format!("- {}: {}", meta.name, meta.description));
+                let params = parameter_names(&tool.schema());
+                if !params.is_empty() {
+                    output.push_str(&format!(" (params: {})", params.join(", ")));
+                }
+                output.push('\n');
+                loaded.insert(meta.name.clone());
+                registry.add_mcp_tools(&server, vec![tool]);
+            }
+        }
+        Ok(ToolResult::success(output))
+    }
+}
+
+/// 검색 점수 (이름 일치 2점, 설명 일치 1점)
+fn score(terms: &[String], name: &str, description: &str) -> usize {
+    let name = name.to_lowercase();
+    let description = description.to_lowercase();
+    terms
+        .iter()
+        .map(|term| {
+            if name.contains(term.as_str()) {
+                2
+            } else if description.contains(term.as_str()) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// 입력 스키마의 인자 이름 (필수는 `*`)
+fn parameter_names(schema: &Value) -> Vec<String> {
+    let required: Vec<&str> = schema["required"]
+        .as_array()
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let mut names: Vec<String> = schema["properties"]
+        .as_object()
+        .map(|props| {
+            props
+                .keys()
+                .map(|name| {
+                    if required.contains(&name.as_str()) {
+                        format!("{}*", name)
+                    } else {
+                        name.clone()
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        let terms = vec!["issue".to_string(), "create".to_string()];
+        assert_eq!(
+            score(&terms, "mcp_github_create_issue", "Create an issue"),
+            4
+        );
+        assert_eq!(score(&terms, "mcp_github_search", "Search issues"), 1);
+        assert_eq!(score(&terms, "mcp_github_list_repos", "List repos"), 0);
+    }
+
+    #[test]
+    fn test_parameter_names() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "title": {}, "body": {} },
+            "required": ["title"]
+        });
+        assert_eq!(parameter_names(&schema), vec!["body", "title*"]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_server() {
+        let tool = McpToolSearchTool::new(
+            Arc::new(RwLock::new(McpBridge::new())),
+            Weak::new(),
+            vec!["github".to_string()],
+            Arc::default(),
+        );
+        let ctx = crate::tool::RuntimeContext::new(
+            "test-session",
+            std::env::temp_dir(),
+            Arc::new(forge_foundation::PermissionService::new()),
+        );
+
+        let result = tool
+            .execute(json!({ "query": "issue", "server": "notion" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+
+        // 연결되지 않은 서버는 후보 없음
+        let result = tool
+            .execute(json!({ "query": "issue" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result.output.starts_with("No MCP tools match"));
+    }
+}
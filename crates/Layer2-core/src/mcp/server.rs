@@ -0,0 +1,767 @@
+//! MCP Server - ForgeCode 도구를 MCP 서버로 노출
+//!
+//! `forge mcp serve`로 실행되며, 다른 MCP 클라이언트(Claude Desktop, IDE)가
+//! ForgeCode의 builtin 도구와 Repository Map을 백엔드로 사용할 수 있게 합니다.
+//!
+//! ## 제공 기능
+//! - **tools**: 선택한 builtin 도구 (기본: `DEFAULT_SERVED_TOOLS`)
+//! - **resources**: `repomap://summary` (토큰 예산 내 요약), `repomap://full`
+//!
+//! 도구 호출은 `AgentContext::execute_tool`을 거치므로 `PermissionService` 정책,
+//! 읽기 전용 모드, 권한 델리게이트(원격 승인)가 대화형 세션과 똑같이 적용됩니다.
+//! 델리게이트가 없으면 미리 허용되지 않은 쓰기/실행은 거부됩니다.
+//!
+//! ## 전송
+//! - stdio: 줄 단위 JSON-RPC (`serve_stdio`)
+//! - SSE: `GET /sse`로 이벤트 스트림을 열고 `POST /messages?sessionId=...`로 요청 (`serve_sse`)
+//!
+//! SSE 요청은 모두 `Authorization: Bearer <token>`이 필요하고 (`generate_sse_token`),
+//! 브라우저의 DNS rebinding 공격을 막기 위해 loopback이 아닌 `Origin`과
+//! 허용되지 않은 `Host`는 거부합니다.
+
+use super::transport::JsonRpcError;
+use crate::context::AgentContext;
+use crate::repomap::{RepoAnalyzer, RepoMapConfig};
+use forge_foundation::{Error, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, Mutex, RwLock};
+use tokio::task::JoinSet;
+use tracing::{debug, info, warn};
+
+/// MCP 프로토콜 버전
+const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// 기본으로 노출하는 builtin 도구
+pub const DEFAULT_SERVED_TOOLS: &[&str] = &["read", "grep", "glob", "edit", "write", "bash"];
+
+/// Repository Map 요약 리소스
+pub const REPOMAP_SUMMARY_URI: &str = "repomap://summary";
+
+/// Repository Map 전체 리소스
+pub const REPOMAP_FULL_URI: &str = "repomap://full";
+
+/// SSE 요청 본문 최대 크기 (10MB)
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// SSE keep-alive 간격
+const SSE_KEEPALIVE: Duration = Duration::from_secs(30);
+
+/// 잘못된 파라미터 (JSON-RPC -32602)
+fn invalid_params(message: impl Into<String>) -> JsonRpcError {
+    JsonRpcError {
+        code: -32602,
+        message: message.into(),
+        data: None,
+    }
+}
+
+/// ForgeCode MCP 서버
+pub struct McpServer {
+    /// 도구 실행 컨텍스트 (권한 검사 포함)
+    context: Arc<AgentContext>,
+
+    /// 노출할 도구 이름
+    tools: Vec<String>,
+
+    /// Repository Map 루트
+    repo_root: PathBuf,
+
+    /// `repomap://summary` 토큰 예산
+    repomap_tokens: usize,
+}
+
+impl McpServer {
+    /// 기본 도구를 노출하는 서버 생성 (RepoMap 루트는 컨텍스트 작업 디렉토리)
+    pub fn new(context: Arc<AgentContext>) -> Self {
+        let repo_root = context.working_directory().clone();
+        Self {
+            context,
+            tools: DEFAULT_SERVED_TOOLS.iter().map(|s| s.to_string()).collect(),
+            repo_root,
+            repomap_tokens: RepoMapConfig::default().max_tokens,
+        }
+    }
+
+    /// 노출할 도구 설정
+    pub fn with_tools(mut self, tools: Vec<String>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Repository Map 루트 설정
+    pub fn with_repo_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.repo_root = root.into();
+        self
+    }
+
+    /// `repomap://summary` 토큰 예산 설정
+    pub fn with_repomap_tokens(mut self, tokens: usize) -> Self {
+        self.repomap_tokens = tokens;
+        self
+    }
+
+    /// 노출 중인 도구 이름
+    pub fn tool_names(&self) -> &[String] {
+        &self.tools
+    }
+
+    /// 도구 실행 컨텍스트
+    pub fn context(&self) -> &Arc<AgentContext> {
+        &self.context
+    }
+
+    // ========================================================================
+    // JSON-RPC
+    // ========================================================================
+
+    /// JSON 텍스트 메시지 처리 (알림이면 None)
+    pub async fn handle_message(&self, message: &str) -> Option<String> {
+        let response = match serde_json::from_str::<Value>(message) {
+            Ok(request) => self.handle(request).await?,
+            Err(_) => error_response(Value::Null, JsonRpcError::parse_error()),
+        };
+        Some(response.to_string())
+    }
+
+    /// JSON-RPC 요청 처리 (알림이면 None)
+    pub async fn handle(&self, request: Value) -> Option<Value> {
+        let id = request.get("id").cloned();
+        let Some(method) = request.get("method").and_then(Value::as_str) else {
+            return Some(error_response(
+                id.unwrap_or(Value::Null),
+                JsonRpcError::invalid_request(),
+            ));
+        };
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+
+        // id가 없으면 알림 (응답하지 않음)
+        let Some(id) = id else {
+            debug!("MCP notification: {}", method);
+            return None;
+        };
+
+        Some(match self.dispatch(method, params).await {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => error_response(id, error),
+        })
+    }
+
+    async fn dispatch(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> std::result::Result<Value, JsonRpcError> {
+        match method {
+            "initialize" => Ok(json!({
+                "protocolVersion": MCP_PROTOCOL_VERSION,
+                "capabilities": { "tools": {}, "resources": {} },
+                "serverInfo": { "name": "forgecode", "version": env!("CARGO_PKG_VERSION") },
+            })),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": self.list_tools().await })),
+            "tools/call" => self.call_tool(params).await,
+            "resources/list" => Ok(json!({ "resources": self.list_resources() })),
+            "resources/read" => self.read_resource(params).await,
+            _ => Err(JsonRpcError::method_not_found()),
+        }
+    }
+
+    async fn list_tools(&self) -> Vec<Value> {
+        let mut tools = Vec::new();
+        for name in &self.tools {
+            let Some(tool) = self.context.get_tool(name).await else {
+                warn!("MCP server: tool '{}' is not registered", name);
+                continue;
+            };
+            tools.push(json!({
+                "name": name,
+                "description": tool.meta().description,
+                "inputSchema": tool.schema(),
+            }));
+        }
+        tools
+    }
+
+    async fn call_tool(&self, params: Value) -> std::result::Result<Value, JsonRpcError> {
+        let name = params
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid_params("Missing tool name"))?;
+        if !self.tools.iter().any(|t| t == name) {
+            return Err(invalid_params(format!("Unknown tool: {}", name)));
+        }
+        let arguments = params
+            .get("arguments")
+            .cloned()
+            .unwrap_or_else(|| json!({}));
+
+        let result = match self.context.execute_tool(name, arguments).await {
+            Ok(result) => result,
+            Err(e) => return Ok(tool_result(vec![text_content(e.to_string())], true)),
+        };
+
+        let text = match (&result.error, result.success) {
+            (Some(error), false) if result.output.is_empty() => error.clone(),
+            (Some(error), false) => format!("{}\n\n{}", result.output, error),
+            _ => result.output.clone(),
+        };
+        let mut content = vec![text_content(text)];
+        content.extend(result.images.iter().map(
+            |image| json!({ "type": "image", "data": image.data, "mimeType": image.media_type }),
+        ));
+        Ok(tool_result(content, !result.success))
+    }
+
+    fn list_resources(&self) -> Vec<Value> {
+        vec![
+            json!({
+                "uri": REPOMAP_SUMMARY_URI,
+                "name": "Repository map",
+                "description": "Most important files and their symbols, within a token budget",
+                "mimeType": "text/plain",
+            }),
+            json!({
+                "uri": REPOMAP_FULL_URI,
+                "name": "Full repository map",
+                "description": "Every analyzed file with its symbols",
+                "mimeType": "text/plain",
+            }),
+        ]
+    }
+
+    async fn read_resource(&self, params: Value) -> std::result::Result<Value, JsonRpcError> {
+        let uri = params
+            .get("uri")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid_params("Missing resource uri"))?;
+        if uri != REPOMAP_SUMMARY_URI && uri != REPOMAP_FULL_URI {
+            return Err(invalid_params(format!("Unknown resource: {}", uri)));
+        }
+
+        let config = RepoMapConfig {
+            max_tokens: self.repomap_tokens,
+            ..Default::default()
+        };
+        let analyzer = RepoAnalyzer::new(&self.repo_root, config);
+        let map = analyzer
+            .analyze()
+            .await
+            .map_err(|e| JsonRpcError::internal_error(e.to_string()))?;
+        let text = if uri == REPOMAP_FULL_URI {
+            map.to_full_string()
+        } else {
+            analyzer.summarize(&map)
+        };
+
+        Ok(json!({
+            "contents": [{ "uri": uri, "mimeType": "text/plain", "text": text }]
+        }))
+    }
+
+    // ========================================================================
+    // Transports
+    // ========================================================================
+
+    /// stdio 전송: stdin에서 줄 단위 요청을 읽고 stdout에 응답
+    ///
+    /// 요청은 동시에 처리되므로 오래 걸리는 도구 호출이 다른 요청을 막지 않습니다.
+    /// stdin이 닫히면 진행 중인 요청에 응답한 뒤 종료합니다.
+    pub async fn serve_stdio(self: Arc<Self>) -> Result<()> {
+        let mut lines = BufReader::new(tokio::io::stdin()).lines();
+        let stdout = Arc::new(Mutex::new(tokio::io::stdout()));
+        let mut pending = JoinSet::new();
+        info!("MCP server listening on stdio");
+
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let server = Arc::clone(&self);
+            let stdout = Arc::clone(&stdout);
+            pending.spawn(async move {
+                if let Some(response) = server.handle_message(&line).await {
+                    let mut stdout = stdout.lock().await;
+                    let written = async {
+                        stdout.write_all(response.as_bytes()).await?;
+                        stdout.write_all(b"\n").await?;
+                        stdout.flush().await
+                    };
+                    if let Err(e) = written.await {
+                        warn!("MCP server: failed to write response: {}", e);
+                    }
+                }
+            });
+        }
+        while pending.join_next().await.is_some() {}
+        Ok(())
+    }
+
+    /// SSE 전송: `GET /sse` 이벤트 스트림 + `POST /messages` 요청
+    ///
+    /// 모든 요청은 `token`을 bearer 토큰으로 보내야 합니다.
+    /// `POST /messages`는 `sessionId`가 없거나 모르는 세션이면 404를 반환합니다.
+    pub async fn serve_sse(self: Arc<Self>, listener: TcpListener, token: String) -> Result<()> {
+        let local_addr = listener.local_addr()?;
+        let guard = Arc::new(SseGuard { token, local_addr });
+        let sessions: SseSessions = Arc::new(RwLock::new(HashMap::new()));
+        info!("MCP server listening on http://{}/sse", local_addr);
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let server = Arc::clone(&self);
+            let guard = Arc::clone(&guard);
+            let sessions = Arc::clone(&sessions);
+            tokio::spawn(async move {
+                if let Err(e) = server.handle_http(stream, &guard, sessions).await {
+                    debug!("MCP server: connection from {} closed: {}", peer, e);
+                }
+            });
+        }
+    }
+
+    async fn handle_http(
+        &self,
+        stream: TcpStream,
+        guard: &SseGuard,
+        sessions: SseSessions,
+    ) -> Result<()> {
+        let mut reader = BufReader::new(stream);
+        let request = HttpRequest::read(&mut reader).await?;
+        let mut stream = reader.into_inner();
+
+        if let Err(status) = guard.check(&request) {
+            warn!(
+                "MCP server: rejected {} {} ({})",
+                request.method,
+                request.path(),
+                status
+            );
+            return write_status(&mut stream, status).await;
+        }
+
+        match (request.method.as_str(), request.path()) {
+            ("GET", "/sse") => {
+                let session_id = uuid::Uuid::new_v4().to_string();
+                let (tx, rx) = mpsc::unbounded_channel();
+                sessions.write().await.insert(session_id.clone(), tx);
+                let result = stream_events(&mut stream, &session_id, rx).await;
+                sessions.write().await.remove(&session_id);
+                result
+            }
+            ("POST", "/messages") => {
+                let target = match request.query("sessionId") {
+                    Some(id) => sessions.read().await.get(&id).cloned(),
+                    None => None,
+                };
+                let Some(target) = target else {
+                    return write_status(&mut stream, "404 Not Found").await;
+                };
+                write_status(&mut stream, "202 Accepted").await?;
+
+                let body = String::from_utf8_lossy(&request.body);
+                if let Some(response) = self.handle_message(&body).await {
+                    let _ = target.send(response);
+                }
+                Ok(())
+            }
+            _ => write_status(&mut stream, "404 Not Found").await,
+        }
+    }
+}
+
+/// SSE 세션 (세션 ID → 이벤트 전송 채널)
+type SseSessions = Arc<RwLock<HashMap<String, mpsc::UnboundedSender<String>>>>;
+
+/// SSE 전송용 무작위 bearer 토큰 생성
+pub fn generate_sse_token() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// SSE 요청 검사 (bearer 토큰, `Origin`, `Host`)
+struct SseGuard {
+    token: String,
+    local_addr: SocketAddr,
+}
+
+impl SseGuard {
+    /// 거부할 요청이면 HTTP 상태
+    fn check(&self, request: &HttpRequest) -> std::result::Result<(), &'static str> {
+        if let Some(origin) = request.header("origin") {
+            let authority = origin
+                .split_once("://")
+                .map(|(_, rest)| rest)
+                .unwrap_or(origin);
+            if !is_loopback_authority(authority) {
+                return Err("403 Forbidden");
+            }
+        }
+        let host_allowed = request.header("host").is_some_and(|host| {
+            is_loopback_authority(host)
+                || authority_host(host).parse::<IpAddr>().ok() == Some(self.local_addr.ip())
+        });
+        if !host_allowed {
+            return Err("403 Forbidden");
+        }
+
+        let token = request
+            .header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .unwrap_or("");
+        if !constant_time_eq(token.as_bytes(), self.token.as_bytes()) {
+            return Err("401 Unauthorized");
+        }
+        Ok(())
+    }
+}
+
+/// `host[:port]`에서 호스트 부분 (`[::1]:80` → `::1`)
+fn authority_host(authority: &str) -> &str {
+    let authority = authority.trim().trim_end_matches('/');
+    if let Some(rest) = authority.strip_prefix('[') {
+        return rest.split(']').next().unwrap_or("");
+    }
+    authority.split(':').next().unwrap_or("")
+}
+
+/// loopback 호스트 (`localhost`, `127.0.0.1`, `[::1]`)
+fn is_loopback_authority(authority: &str) -> bool {
+    let host = authority_host(authority);
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// 길이 외의 정보를 노출하지 않는 비교
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn error_response(id: Value, error: JsonRpcError) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": error })
+}
+
+fn text_content(text: String) -> Value {
+    json!({ "type": "text", "text": text })
+}
+
+fn tool_result(content: Vec<Value>, is_error: bool) -> Value {
+    json!({ "content": content, "isError": is_error })
+}
+
+/// SSE 스트림: endpoint 이벤트 후 응답을 message 이벤트로 전달
+async fn stream_events(
+    stream: &mut TcpStream,
+    session_id: &str,
+    mut rx: mpsc::UnboundedReceiver<String>,
+) -> Result<()> {
+    stream
+        .write_all(
+            b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\ncache-control: no-cache\r\nconnection: keep-alive\r\n\r\n",
+        )
+        .await?;
+    let endpoint = format!(
+        "event: endpoint\ndata: /messages?sessionId={}\n\n",
+        session_id
+    );
+    stream.write_all(endpoint.as_bytes()).await?;
+    stream.flush().await?;
+
+    let mut keepalive = tokio::time::interval(SSE_KEEPALIVE);
+    keepalive.tick().await;
+    loop {
+        let event = tokio::select! {
+            message = rx.recv() => match message {
+                Some(message) => format!("event: message\ndata: {}\n\n", message),
+                None => return Ok(()),
+            },
+            _ = keepalive.tick() => ": keep-alive\n\n".to_string(),
+        };
+        stream.write_all(event.as_bytes()).await?;
+        stream.flush().await?;
+    }
+}
+
+async fn write_status(stream: &mut TcpStream, status: &str) -> Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+        status
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+/// 최소한의 HTTP/1.1 요청 (SSE 전송용)
+struct HttpRequest {
+    method: String,
+    target: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpRequest {
+    async fn read(reader: &mut BufReader<TcpStream>) -> Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line).await?;
+        let mut parts = line.split_whitespace();
+        let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+            return Err(Error::Mcp("Malformed HTTP request line".to_string()));
+        };
+        let (method, target) = (method.to_ascii_uppercase(), target.to_string());
+
+        let mut content_length = 0usize;
+        let mut headers = Vec::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).await? == 0 || line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                let (name, value) = (name.trim().to_ascii_lowercase(), value.trim());
+                if name == "content-length" {
+                    content_length = value.parse().unwrap_or(0);
+                }
+                headers.push((name, value.to_string()));
+            }
+        }
+        if content_length > MAX_BODY_BYTES {
+            return Err(Error::Mcp(format!(
+                "Request body too large ({} bytes)",
+                content_length
+            )));
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).await?;
+        Ok(Self {
+            method,
+            target,
+            headers,
+            body,
+        })
+    }
+
+    /// 헤더 값 (`name`은 소문자)
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn path(&self) -> &str {
+        self.target.split('?').next().unwrap_or("")
+    }
+
+    fn query(&self, key: &str) -> Option<String> {
+        let (_, query) = self.target.split_once('?')?;
+        query.split('&').find_map(|pair| {
+            let (k, v) = pair.split_once('=')?;
+            (k == key).then(|| {
+                urlencoding::decode(v)
+                    .map(|v| v.into_owned())
+                    .unwrap_or_default()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge_foundation::PermissionService;
+
+    fn server(dir: &std::path::Path, permissions: PermissionService) -> McpServer {
+        let context = AgentContext::builder()
+            .working_directory(dir.to_path_buf())
+            .with_permission_service(Arc::new(permissions))
+            .build();
+        McpServer::new(Arc::new(context))
+    }
+
+    async fn call(server: &McpServer, method: &str, params: Value) -> Value {
+        server
+            .handle(json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_initialize_and_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = server(dir.path(), PermissionService::new());
+
+        let init = call(&server, "initialize", json!({})).await;
+        assert_eq!(init["result"]["protocolVersion"], MCP_PROTOCOL_VERSION);
+        assert!(server
+            .handle(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+            .await
+            .is_none());
+
+        let tools = call(&server, "tools/list", json!({})).await;
+        let names: Vec<_> = tools["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["name"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(names, DEFAULT_SERVED_TOOLS);
+        assert!(tools["result"]["tools"][0]["inputSchema"].is_object());
+
+        let unknown = call(&server, "prompts/list", json!({})).await;
+        assert_eq!(unknown["error"]["code"], -32601);
+        let parse = server.handle_message("{not json").await.unwrap();
+        assert!(parse.contains("-32700"));
+    }
+
+    #[tokio::test]
+    async fn test_tool_calls_are_permission_gated() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("hello.txt"), "hello mcp").unwrap();
+        let server = server(dir.path(), PermissionService::new());
+
+        let read = call(
+            &server,
+            "tools/call",
+            json!({ "name": "read", "arguments": { "file_path": dir.path().join("hello.txt") } }),
+        )
+        .await;
+        assert_eq!(read["result"]["isError"], false);
+        assert!(read["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("hello mcp"));
+
+        // 허용되지 않은 쓰기는 거부
+        let target = dir.path().join("out.txt");
+        let write = call(
+            &server,
+            "tools/call",
+            json!({ "name": "write", "arguments": { "file_path": target, "content": "x" } }),
+        )
+        .await;
+        assert_eq!(write["result"]["isError"], true);
+        assert!(!target.exists());
+
+        // 노출하지 않은 도구
+        let hidden = call(&server, "tools/call", json!({ "name": "http_request" })).await;
+        assert_eq!(hidden["error"]["code"], -32602);
+    }
+
+    #[tokio::test]
+    async fn test_repomap_resource() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lib.rs"), "pub fn answer() -> u32 { 42 }\n").unwrap();
+        let server = server(dir.path(), PermissionService::new());
+
+        let list = call(&server, "resources/list", json!({})).await;
+        assert_eq!(list["result"]["resources"][0]["uri"], REPOMAP_SUMMARY_URI);
+
+        let read = call(
+            &server,
+            "resources/read",
+            json!({ "uri": REPOMAP_FULL_URI }),
+        )
+        .await;
+        let text = read["result"]["contents"][0]["text"].as_str().unwrap();
+        assert!(text.contains("lib.rs"));
+    }
+
+    #[tokio::test]
+    async fn test_sse_transport() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = Arc::new(server(dir.path(), PermissionService::new()));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(server.serve_sse(listener, "secret".to_string()));
+
+        // 토큰 없음, 외부 Origin, 외부 Host는 거부
+        for (headers, expected) in [
+            ("host: localhost\r\n", "401"),
+            (
+                "host: localhost\r\nauthorization: Bearer secret\r\norigin: https://evil.example\r\n",
+                "403",
+            ),
+            (
+                "host: evil.example:8765\r\nauthorization: Bearer secret\r\n",
+                "403",
+            ),
+        ] {
+            let mut rejected = TcpStream::connect(addr).await.unwrap();
+            rejected
+                .write_all(format!("GET /sse HTTP/1.1\r\n{}\r\n", headers).as_bytes())
+                .await
+                .unwrap();
+            let mut status = String::new();
+            BufReader::new(rejected)
+                .read_line(&mut status)
+                .await
+                .unwrap();
+            assert!(status.contains(expected), "{}: {}", headers, status);
+        }
+
+        let auth = "host: 127.0.0.1\r\nauthorization: Bearer secret\r\n";
+        let mut events = TcpStream::connect(addr).await.unwrap();
+        events
+            .write_all(
+                format!(
+                    "GET /sse HTTP/1.1\r\n{}origin: http://localhost:3000\r\n\r\n",
+                    auth
+                )
+                .as_bytes(),
+            )
+            .await
+            .unwrap();
+        let mut events = BufReader::new(events);
+        let mut endpoint = String::new();
+        while !endpoint.starts_with("data: ") {
+            endpoint.clear();
+            events.read_line(&mut endpoint).await.unwrap();
+        }
+        let path = endpoint.trim().trim_start_matches("data: ").to_string();
+        assert!(path.starts_with("/messages?sessionId="));
+
+        let body = r#"{"jsonrpc":"2.0","id":7,"method":"ping"}"#;
+        let post = |path: String| async move {
+            let mut post = TcpStream::connect(addr).await.unwrap();
+            post.write_all(
+                format!(
+                    "POST {} HTTP/1.1\r\n{}content-length: {}\r\n\r\n{}",
+                    path,
+                    auth,
+                    body.len(),
+                    body
+                )
+                .as_bytes(),
+            )
+            .await
+            .unwrap();
+            let mut status = String::new();
+            BufReader::new(post).read_line(&mut status).await.unwrap();
+            status
+        };
+        // sessionId가 없으면 다른 세션으로 보내지 않음
+        assert!(post("/messages".to_string()).await.contains("404"));
+        assert!(post(path).await.contains("202"));
+
+        let mut message = String::new();
+        while !message.contains("\"id\":7") {
+            message.clear();
+            events.read_line(&mut message).await.unwrap();
+        }
+        assert!(message.contains("\"result\":{}"));
+    }
+}
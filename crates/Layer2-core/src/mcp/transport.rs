@@ -2,12 +2,18 @@
 //!
 //! MCP 서버와의 통신을 위한 전송 계층
 //! - Stdio: 로컬 프로세스와 stdin/stdout 통신
-//! - SSE: HTTP Server-Sent Events
+//! - SSE: HTTP Server-Sent Events (OAuth 인증 지원, `super::auth`)
+//!
+//! 서버가 보낸 요청(`roots/list`, `ping` 등)은 `set_request_handler`로 등록한
+//! 처리기가 응답합니다. 처리기가 없으면 `Method not found`로 응답합니다.
+//! 서버 알림(`notifications/progress` 등)은 `set_notification_handler`로 등록한
+//! 처리기에 전달됩니다.
 
+use super::auth::McpAuth;
 use async_trait::async_trait;
-use forge_foundation::{Error, Result};
+use forge_foundation::{check_egress, egress_client, Error, Result};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::process::Stdio;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -93,6 +99,80 @@ impl JsonRpcError {
     }
 }
 
+/// 서버가 보낸 요청 처리기 (메서드, 파라미터 → 결과)
+pub type McpRequestHandler =
+    Arc<dyn Fn(&str, Option<Value>) -> std::result::Result<Value, JsonRpcError> + Send + Sync>;
+
+/// 서버가 보낸 알림 처리기 (메서드, 파라미터)
+pub type McpNotificationHandler = Arc<dyn Fn(&str, Option<Value>) + Send + Sync>;
+
+/// 전송 계층과 수신 태스크가 공유하는 처리기 슬롯
+type RequestHandlerSlot = Arc<std::sync::RwLock<Option<McpRequestHandler>>>;
+
+/// 전송 계층과 수신 태스크가 공유하는 알림 처리기 슬롯
+type NotificationHandlerSlot = Arc<std::sync::RwLock<Option<McpNotificationHandler>>>;
+
+/// 서버에서 받은 메시지
+enum IncomingMessage {
+    /// 우리 요청에 대한 응답
+    Response(JsonRpcResponse),
+    /// 서버 → 클라이언트 요청 (id는 문자열일 수도 있음)
+    Request {
+        id: Value,
+        method: String,
+        params: Option<Value>,
+    },
+    /// 서버 알림
+    Notification {
+        method: String,
+        params: Option<Value>,
+    },
+}
+
+impl IncomingMessage {
+    fn parse(text: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(text).ok()?;
+        match value.get("method").and_then(Value::as_str) {
+            Some(method) => {
+                let method = method.to_string();
+                let params = value.get("params").cloned();
+                match value.get("id").cloned() {
+                    Some(id) => Some(Self::Request { id, method, params }),
+                    None => Some(Self::Notification { method, params }),
+                }
+            }
+            None => serde_json::from_value(value).ok().map(Self::Response),
+        }
+    }
+}
+
+/// 서버 요청을 처리기로 처리한 JSON-RPC 응답
+fn respond_to_server(
+    handler: &RequestHandlerSlot,
+    id: Value,
+    method: &str,
+    params: Option<Value>,
+) -> Value {
+    let handler = handler.read().unwrap_or_else(|e| e.into_inner()).clone();
+    let result = match handler {
+        Some(handler) => handler(method, params),
+        None => Err(JsonRpcError::method_not_found()),
+    };
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+    }
+}
+
+/// 서버 알림을 처리기로 전달
+fn dispatch_notification(handler: &NotificationHandlerSlot, method: &str, params: Option<Value>) {
+    debug!("MCP notification: {}", method);
+    let handler = handler.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(handler) = handler {
+        handler(method, params);
+    }
+}
+
 /// JSON-RPC 알림 (응답 없음)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JsonRpcNotification {
@@ -116,6 +196,12 @@ pub trait McpTransport: Send + Sync {
 
     /// 연결 상태 확인
     fn is_connected(&self) -> bool;
+
+    /// 서버가 보낸 요청의 처리기 등록
+    fn set_request_handler(&self, _handler: McpRequestHandler) {}
+
+    /// 서버가 보낸 알림의 처리기 등록
+    fn set_notification_handler(&self, _handler: McpNotificationHandler) {}
 }
 
 /// Stdio Transport - 프로세스 기반 통신
@@ -134,6 +220,12 @@ pub struct StdioTransport {
 
     /// 연결 상태
     connected: Arc<std::sync::atomic::AtomicBool>,
+
+    /// 서버 요청 처리기
+    request_handler: RequestHandlerSlot,
+
+    /// 서버 알림 처리기
+    notification_handler: NotificationHandlerSlot,
 }
 
 impl StdioTransport {
@@ -178,6 +270,12 @@ impl StdioTransport {
         let connected_for_writer = Arc::clone(&connected);
         let connected_for_reader = Arc::clone(&connected);
 
+        let request_handler: RequestHandlerSlot = Arc::default();
+        let handler_for_reader = Arc::clone(&request_handler);
+        let notification_handler: NotificationHandlerSlot = Arc::default();
+        let notification_handler_for_reader = Arc::clone(&notification_handler);
+        let reply_tx = stdin_tx.clone();
+
         // stdin writer task
         let mut stdin_writer = stdin;
         tokio::spawn(async move {
@@ -201,9 +299,9 @@ impl StdioTransport {
             while let Ok(Some(line)) = reader.next_line().await {
                 debug!("MCP stdout: {}", line);
 
-                // JSON-RPC 응답 파싱
-                match serde_json::from_str::<JsonRpcResponse>(&line) {
-                    Ok(response) => {
+                // JSON-RPC 응답/요청 파싱
+                match IncomingMessage::parse(&line) {
+                    Some(IncomingMessage::Response(response)) => {
                         if let Some(id) = response.id {
                             let mut pending = pending_for_reader.write().await;
                             if let Some(sender) = pending.remove(&id) {
@@ -211,8 +309,17 @@ impl StdioTransport {
                             }
                         }
                     }
-                    Err(e) => {
-                        debug!("Non-JSON-RPC line or parse error: {}", e);
+                    Some(IncomingMessage::Request { id, method, params }) => {
+                        let reply = respond_to_server(&handler_for_reader, id, &method, params);
+                        if reply_tx.send(format!("{}\n", reply)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(IncomingMessage::Notification { method, params }) => {
+                        dispatch_notification(&notification_handler_for_reader, &method, params);
+                    }
+                    None => {
+                        debug!("Non-JSON-RPC line: {}", line);
                     }
                 }
             }
@@ -226,6 +333,8 @@ impl StdioTransport {
             stdin_tx,
             pending_requests,
             connected,
+            request_handler,
+            notification_handler,
         })
     }
 
@@ -318,6 +427,20 @@ impl McpTransport for StdioTransport {
     fn is_connected(&self) -> bool {
         self.connected.load(Ordering::SeqCst)
     }
+
+    fn set_request_handler(&self, handler: McpRequestHandler) {
+        *self
+            .request_handler
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = Some(handler);
+    }
+
+    fn set_notification_handler(&self, handler: McpNotificationHandler) {
+        *self
+            .notification_handler
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = Some(handler);
+    }
 }
 
 /// SSE Transport - HTTP Server-Sent Events 기반 통신
@@ -340,14 +463,32 @@ pub struct SseTransport {
 
     /// 메시지 엔드포인트 URL
     message_url: String,
+
+    /// OAuth 인증 (없으면 인증 헤더 없이 요청)
+    auth: Option<Arc<McpAuth>>,
+
+    /// 서버 요청 처리기
+    request_handler: RequestHandlerSlot,
+
+    /// 서버 알림 처리기
+    notification_handler: NotificationHandlerSlot,
 }
 
 impl SseTransport {
     /// SSE 연결 생성
     pub async fn connect(url: &str) -> Result<Self> {
+        Self::connect_with_auth(url, None).await
+    }
+
+    /// OAuth 인증과 함께 SSE 연결 생성
+    ///
+    /// 저장된 access token을 요청에 붙이고, 401을 받으면 `McpAuth`로 갱신/인가한 뒤
+    /// 한 번 재시도합니다.
+    pub async fn connect_with_auth(url: &str, auth: Option<Arc<McpAuth>>) -> Result<Self> {
         info!("Connecting to MCP SSE server: {}", url);
+        check_egress(url)?;
 
-        let client = reqwest::Client::builder()
+        let client = egress_client()
             .timeout(std::time::Duration::from_secs(60))
             .build()
             .map_err(|e| Error::Internal(format!("Failed to create HTTP client: {}", e)))?;
@@ -369,9 +510,25 @@ impl SseTransport {
         let connected_for_sse = Arc::clone(&connected);
         let sse_url = url.to_string();
         let client_clone = client.clone();
+        let auth_for_sse = auth.clone();
+        let request_handler: RequestHandlerSlot = Arc::default();
+        let handler_for_sse = Arc::clone(&request_handler);
+        let notification_handler: NotificationHandlerSlot = Arc::default();
+        let notification_handler_for_sse = Arc::clone(&notification_handler);
+        let message_url_for_sse = message_url.clone();
 
         tokio::spawn(async move {
-            Self::sse_listener(sse_url, client_clone, pending_for_sse, connected_for_sse).await;
+            Self::sse_listener(
+                sse_url,
+                client_clone,
+                pending_for_sse,
+                connected_for_sse,
+                auth_for_sse,
+                handler_for_sse,
+                notification_handler_for_sse,
+                message_url_for_sse,
+            )
+            .await;
         });
 
         Ok(Self {
@@ -381,49 +538,108 @@ impl SseTransport {
             pending_requests,
             connected,
             message_url,
+            auth,
+            request_handler,
+            notification_handler,
         })
     }
 
     /// SSE 이벤트 수신 루프
+    #[allow(clippy::too_many_arguments)]
     async fn sse_listener(
         url: String,
         client: reqwest::Client,
         pending: Arc<RwLock<HashMap<u64, oneshot::Sender<JsonRpcResponse>>>>,
         connected: Arc<std::sync::atomic::AtomicBool>,
+        auth: Option<Arc<McpAuth>>,
+        request_handler: RequestHandlerSlot,
+        notification_handler: NotificationHandlerSlot,
+        message_url: String,
     ) {
         use reqwest_eventsource::{Event, EventSource};
 
-        let mut es = EventSource::new(client.get(&url)).expect("Failed to create EventSource");
+        let mut token = match &auth {
+            Some(auth) => auth.access_token().await,
+            None => None,
+        };
+        let mut reauthorized = false;
 
-        while let Some(event) = es.next().await {
-            match event {
-                Ok(Event::Open) => {
-                    info!("SSE connection opened");
-                }
-                Ok(Event::Message(message)) => {
-                    debug!("SSE message: {}", message.data);
-
-                    // JSON-RPC 응답 파싱
-                    match serde_json::from_str::<JsonRpcResponse>(&message.data) {
-                        Ok(response) => {
-                            if let Some(id) = response.id {
-                                let mut pending_guard = pending.write().await;
-                                if let Some(sender) = pending_guard.remove(&id) {
-                                    let _ = sender.send(response);
+        'connect: loop {
+            let mut request = client.get(&url);
+            if let Some(token) = &token {
+                request = request.bearer_auth(token);
+            }
+            let mut es = EventSource::new(request).expect("Failed to create EventSource");
+
+            while let Some(event) = es.next().await {
+                match event {
+                    Ok(Event::Open) => {
+                        info!("SSE connection opened");
+                    }
+                    Ok(Event::Message(message)) => {
+                        debug!("SSE message: {}", message.data);
+
+                        // JSON-RPC 응답/요청 파싱
+                        match IncomingMessage::parse(&message.data) {
+                            Some(IncomingMessage::Response(response)) => {
+                                if let Some(id) = response.id {
+                                    let mut pending_guard = pending.write().await;
+                                    if let Some(sender) = pending_guard.remove(&id) {
+                                        let _ = sender.send(response);
+                                    }
+                                }
+                            }
+                            Some(IncomingMessage::Request { id, method, params }) => {
+                                // 서버 요청의 응답은 메시지 엔드포인트로 POST
+                                let reply = respond_to_server(&request_handler, id, &method, params);
+                                let mut request = client.post(&message_url).json(&reply);
+                                if let Some(token) = &token {
+                                    request = request.bearer_auth(token);
                                 }
+                                if let Err(e) = request.send().await {
+                                    error!("Failed to answer SSE request '{}': {}", method, e);
+                                }
+                            }
+                            Some(IncomingMessage::Notification { method, params }) => {
+                                dispatch_notification(&notification_handler, &method, params);
+                            }
+                            None => {
+                                debug!("Failed to parse SSE message: {}", message.data);
                             }
                         }
-                        Err(e) => {
-                            debug!("Failed to parse SSE message: {}", e);
+                    }
+                    Err(reqwest_eventsource::Error::InvalidStatusCode(status, response))
+                        if status == reqwest::StatusCode::UNAUTHORIZED && !reauthorized =>
+                    {
+                        let Some(auth) = &auth else {
+                            error!("SSE error: HTTP {} (no OAuth configured)", status);
+                            break;
+                        };
+                        es.close();
+                        reauthorized = true;
+                        let header = www_authenticate(response.headers());
+                        match auth
+                            .handle_unauthorized(header.as_deref(), token.as_deref())
+                            .await
+                        {
+                            Ok(new_token) => {
+                                token = Some(new_token);
+                                continue 'connect;
+                            }
+                            Err(e) => {
+                                error!("SSE authorization failed: {}", e);
+                                break;
+                            }
                         }
                     }
-                }
-                Err(e) => {
-                    error!("SSE error: {}", e);
-                    connected.store(false, Ordering::SeqCst);
-                    break;
+                    Err(e) => {
+                        error!("SSE error: {}", e);
+                        connected.store(false, Ordering::SeqCst);
+                        break;
+                    }
                 }
             }
+            break;
         }
 
         connected.store(false, Ordering::SeqCst);
@@ -434,6 +650,48 @@ impl SseTransport {
     fn next_id(&self) -> u64 {
         self.request_id.fetch_add(1, Ordering::SeqCst)
     }
+
+    /// 메시지 엔드포인트로 POST (401이면 인증 후 한 번 재시도)
+    async fn post<T: Serialize + ?Sized>(&self, body: &T) -> Result<reqwest::Response> {
+        let mut token = match &self.auth {
+            Some(auth) => auth.access_token().await,
+            None => None,
+        };
+        let mut retried = false;
+
+        loop {
+            let mut request = self.client.post(&self.message_url).json(body);
+            if let Some(token) = &token {
+                request = request.bearer_auth(token);
+            }
+            let response = request
+                .send()
+                .await
+                .map_err(|e| Error::Internal(format!("Failed to send request: {}", e)))?;
+
+            match &self.auth {
+                Some(auth)
+                    if !retried && response.status() == reqwest::StatusCode::UNAUTHORIZED =>
+                {
+                    retried = true;
+                    let header = www_authenticate(response.headers());
+                    token = Some(
+                        auth.handle_unauthorized(header.as_deref(), token.as_deref())
+                            .await?,
+                    );
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+}
+
+/// `WWW-Authenticate` 헤더 값
+fn www_authenticate(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(reqwest::header::WWW_AUTHENTICATE)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
 }
 
 use futures::StreamExt;
@@ -458,13 +716,7 @@ impl McpTransport for SseTransport {
         }
 
         // POST 요청으로 메시지 전송
-        let response = self
-            .client
-            .post(&self.message_url)
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| Error::Internal(format!("Failed to send request: {}", e)))?;
+        let response = self.post(&request).await?;
 
         if !response.status().is_success() {
             return Err(Error::Internal(format!(
@@ -503,12 +755,7 @@ impl McpTransport for SseTransport {
             params,
         };
 
-        self.client
-            .post(&self.message_url)
-            .json(&notification)
-            .send()
-            .await
-            .map_err(|e| Error::Internal(format!("Failed to send notification: {}", e)))?;
+        self.post(&notification).await?;
 
         Ok(())
     }
@@ -521,6 +768,20 @@ impl McpTransport for SseTransport {
     fn is_connected(&self) -> bool {
         self.connected.load(Ordering::SeqCst)
     }
+
+    fn set_request_handler(&self, handler: McpRequestHandler) {
+        *self
+            .request_handler
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = Some(handler);
+    }
+
+    fn set_notification_handler(&self, handler: McpNotificationHandler) {
+        *self
+            .notification_handler
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = Some(handler);
+    }
 }
 
 #[cfg(test)]
@@ -544,4 +805,29 @@ mod tests {
         let error = JsonRpcError::method_not_found();
         assert_eq!(error.code, -32601);
     }
+
+    #[test]
+    fn test_incoming_message_and_server_request() {
+        let response = IncomingMessage::parse(r#"{"jsonrpc":"2.0","id":3,"result":{}}"#);
+        assert!(matches!(response, Some(IncomingMessage::Response(r)) if r.id == Some(3)));
+        let notification =
+            IncomingMessage::parse(r#"{"jsonrpc":"2.0","method":"notifications/progress"}"#);
+        assert!(matches!(notification, Some(IncomingMessage::Notification { method, .. }) if method == "notifications/progress"));
+
+        let Some(IncomingMessage::Request { id, method, params }) =
+            IncomingMessage::parse(r#"{"jsonrpc":"2.0","id":"r-1","method":"roots/list"}"#)
+        else {
+            panic!("expected a server request");
+        };
+
+        // 처리기가 없으면 Method not found
+        let slot: RequestHandlerSlot = Arc::default();
+        let reply = respond_to_server(&slot, id.clone(), &method, params.clone());
+        assert_eq!(reply["id"], "r-1");
+        assert_eq!(reply["error"]["code"], -32601);
+
+        *slot.write().unwrap() = Some(Arc::new(|method, _| Ok(json!({ "method": method }))));
+        let reply = respond_to_server(&slot, id, &method, params);
+        assert_eq!(reply["result"]["method"], "roots/list");
+    }
 }
@@ -1,5 +1,6 @@
 //! MCP Types - MCP 관련 타입 정의
 
+use super::auth::McpOAuthConfig;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -102,6 +103,70 @@ pub struct McpServerConfig {
     /// 자동 연결 여부
     #[serde(default)]
     pub auto_connect: bool,
+
+    /// 도구 노출 정책 (필터, 지연 로딩, 접기)
+    #[serde(default)]
+    pub tools: McpToolPolicy,
+}
+
+/// 서버별 MCP 도구 노출 정책
+///
+/// ```json
+/// { "include": ["search*", "get_page"], "exclude": ["delete*"], "lazy": true, "collapsed": true }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpToolPolicy {
+    /// 노출할 도구 (비어 있으면 전부, 끝의 `*`는 접두사 매칭)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+
+    /// 숨길 도구 (`include`보다 우선)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
+    /// 연결 시 `tools/list`를 미루고 도구가 처음 필요할 때 가져옴
+    #[serde(default)]
+    pub lazy: bool,
+
+    /// 도구를 직접 등록하지 않고 `search_mcp_tools`로 찾은 것만 로드
+    #[serde(default)]
+    pub collapsed: bool,
+}
+
+impl McpToolPolicy {
+    /// 노출할 도구 지정
+    pub fn with_include(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.include = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 숨길 도구 지정
+    pub fn with_exclude(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.exclude = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 지연 로딩
+    pub fn with_lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// `search_mcp_tools` 뒤로 접기
+    pub fn with_collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// 도구 노출 여부
+    pub fn allows(&self, tool: &str) -> bool {
+        let matches = |pattern: &String| match pattern.strip_suffix('*') {
+            Some(prefix) => tool.starts_with(prefix),
+            None => pattern == tool,
+        };
+        (self.include.is_empty() || self.include.iter().any(matches))
+            && !self.exclude.iter().any(matches)
+    }
 }
 
 /// MCP 전송 설정
@@ -124,6 +189,9 @@ pub enum McpTransportConfig {
     Sse {
         /// 서버 URL
         url: String,
+        /// OAuth 설정 (서버가 401을 반환하면 인가 흐름 수행)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        oauth: Option<McpOAuthConfig>,
     },
 }
 
@@ -145,6 +213,25 @@ pub struct McpResource {
     pub mime_type: Option<String>,
 }
 
+/// MCP 리소스 내용 (`resources/read` 결과의 `contents` 항목)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourceContent {
+    /// 리소스 URI
+    pub uri: String,
+
+    /// MIME 타입
+    #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// 텍스트 내용
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// 바이너리 내용 (base64)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
+}
+
 /// MCP 프롬프트
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpPrompt {
@@ -175,6 +262,37 @@ pub struct McpPromptArgument {
     pub required: bool,
 }
 
+/// MCP 루트 (서버에 알려주는 프로젝트 디렉토리, `roots/list`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpRoot {
+    /// `file://` URI
+    pub uri: String,
+
+    /// 표시 이름
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl McpRoot {
+    /// 디렉토리 경로에서 생성 (상대 경로는 현재 디렉토리 기준, 이름은 디렉토리 이름)
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Self {
+        let path = path.as_ref();
+        let path = match std::env::current_dir() {
+            Ok(dir) if path.is_relative() => dir.join(path),
+            _ => path.to_path_buf(),
+        };
+        let uri = url::Url::from_file_path(&path)
+            .map(String::from)
+            .unwrap_or_else(|_| format!("file://{}", path.display()));
+        Self {
+            uri,
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,4 +331,15 @@ mod tests {
         };
         assert_eq!(prompt.arguments.len(), 1);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_mcp_root_from_path() {
+        let root = McpRoot::from_path("/home/dev/my project");
+        assert_eq!(root.uri, "file:///home/dev/my%20project");
+        assert_eq!(root.name.as_deref(), Some("my project"));
+
+        let json = serde_json::to_value(McpRoot::from_path("/")).unwrap();
+        assert_eq!(json, serde_json::json!({ "uri": "file:///" }));
+    }
 }
@@ -4,6 +4,7 @@
 //! Claude Code 호환 디렉토리 구조를 지원합니다.
 
 use super::manifest::PluginManifest;
+use super::script::is_script_plugin;
 use crate::skill::{FileBasedSkill, SkillLoader};
 use crate::config::strip_json_comments;
 use serde::{Deserialize, Serialize};
@@ -167,6 +168,47 @@ impl PluginDiscovery {
         Ok(plugins)
     }
 
+    /// 단일 파일 스크립트 플러그인 발견 (`plugins/*.js` 등, 경로 순)
+    ///
+    /// 같은 파일 이름은 우선순위가 높은 범위의 것만 반환합니다.
+    pub async fn discover_scripts(&self) -> Vec<(PathBuf, PluginScope)> {
+        let mut scripts: Vec<(PathBuf, PluginScope)> = Vec::new();
+
+        for (path, scope) in &self.search_paths {
+            if !path.exists() || path.to_string_lossy().contains("skills") {
+                continue;
+            }
+
+            let mut entries = match fs::read_dir(path).await {
+                Ok(entries) => entries,
+                Err(e) => {
+                    warn!("Failed to scan plugin directory {:?}: {}", path, e);
+                    continue;
+                }
+            };
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                let file = entry.path();
+                if !is_script_plugin(&file) {
+                    continue;
+                }
+                match scripts
+                    .iter_mut()
+                    .find(|(existing, _)| existing.file_name() == file.file_name())
+                {
+                    Some(existing) if existing.1.priority() < scope.priority() => {
+                        *existing = (file, *scope);
+                    }
+                    Some(_) => {}
+                    None => scripts.push((file, *scope)),
+                }
+            }
+        }
+
+        scripts.sort_by(|a, b| a.0.cmp(&b.0));
+        info!("Discovered {} script plugins", scripts.len());
+        scripts
+    }
+
     /// plugin.json 파싱
     async fn parse_plugin_manifest(&self, path: &Path) -> Result<PluginManifest> {
         let content = fs::read_to_string(path).await?;
@@ -329,12 +371,20 @@ struct PluginProvides {
 
     #[serde(default)]
     pub skills: Vec<String>,
+
+    #[serde(default)]
+    pub providers: Vec<String>,
+
+    #[serde(default)]
+    pub executors: Vec<String>,
 }
 
 impl PluginJsonFile {
     /// PluginManifest로 변환
     fn into_manifest(self) -> PluginManifest {
-        use super::manifest::{PluginProvides as ManifestProvides, PluginDependency, PluginVersion};
+        use super::manifest::{
+            PluginDependency, PluginProvides as ManifestProvides, PluginType, PluginVersion,
+        };
 
         // 버전 파싱
         let version = PluginVersion::parse(&self.version).unwrap_or_default();
@@ -347,6 +397,17 @@ impl PluginJsonFile {
             manifest = manifest.with_author(&author);
         }
 
+        // 플러그인 타입 / 메인 파일 (WASM 모듈 등)
+        manifest = manifest.with_type(match self.r#type.as_str() {
+            "native" => PluginType::Native,
+            "wasm" => PluginType::Wasm,
+            "remote" => PluginType::Remote,
+            _ => PluginType::Script,
+        });
+        if let Some(main) = self.main {
+            manifest = manifest.with_metadata("main", main);
+        }
+
         // provides 변환
         let mut provides = ManifestProvides::new();
         for tool in self.provides.tools {
@@ -355,6 +416,12 @@ impl PluginJsonFile {
         for skill in self.provides.skills {
             provides = provides.with_skill(&skill);
         }
+        for provider in self.provides.providers {
+            provides = provides.with_provider(&provider);
+        }
+        for executor in self.provides.executors {
+            provides = provides.with_executor(&executor);
+        }
         manifest = manifest.with_provides(provides);
 
         // dependencies 변환
@@ -448,6 +515,37 @@ This is a test skill.
         assert!(skills.iter().any(|s| s.config().name == "test-skill"));
     }
 
+    #[tokio::test]
+    async fn test_discover_scripts() {
+        let temp = TempDir::new().unwrap();
+        let working_dir = temp.path();
+        let project = working_dir.join(".forgecode/plugins");
+        let local = working_dir.join(".forgecode.local/plugins");
+        fs::create_dir_all(&project).await.unwrap();
+        fs::create_dir_all(&local).await.unwrap();
+
+        fs::write(project.join("shout.js"), "").await.unwrap();
+        fs::write(project.join("notes.txt"), "").await.unwrap();
+        fs::write(local.join("shout.js"), "").await.unwrap();
+        fs::write(local.join("greet.mjs"), "").await.unwrap();
+
+        let discovery = PluginDiscovery::new(working_dir);
+        let scripts: Vec<(PathBuf, PluginScope)> = discovery
+            .discover_scripts()
+            .await
+            .into_iter()
+            .filter(|(p, _)| p.starts_with(working_dir))
+            .collect();
+
+        assert_eq!(
+            scripts,
+            [
+                (local.join("greet.mjs"), PluginScope::Local),
+                (local.join("shout.js"), PluginScope::Local),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_scope_priority() {
         let temp = TempDir::new().unwrap();
@@ -2,6 +2,8 @@
 //!
 //! GitHub 등 소스에서 플러그인을 다운로드하고 설치합니다.
 
+use super::manifest::{PluginVersion, VersionReq};
+use super::marketplace::{PackageKind, RemoteMarketplace, VerifiedPackage};
 use super::store::{InstalledPlugin, PluginStore};
 use reqwest::Client;
 use std::path::{Path, PathBuf};
@@ -382,6 +384,118 @@ impl PluginInstaller {
         Ok(None)
     }
 
+    // ========================================================================
+    // 원격 마켓플레이스
+    // ========================================================================
+
+    /// 원격 인덱스에서 서명 검증 후 설치
+    ///
+    /// `version_req`(예: `"^1.2"`, `"=1.2.0"`)를 만족하는 최신 버전을 설치하고,
+    /// 요구사항과 sha256을 기록해 `update_remote`가 같은 범위 안에서 업데이트합니다.
+    pub async fn install_remote(
+        &self,
+        marketplace: &RemoteMarketplace,
+        id: &str,
+        version_req: &str,
+    ) -> Result<InstalledPlugin> {
+        let req = VersionReq::parse(version_req).ok_or_else(|| {
+            forge_foundation::Error::InvalidInput(format!(
+                "Invalid version requirement: {}",
+                version_req
+            ))
+        })?;
+        let verified = marketplace.fetch(PackageKind::Plugin, id, &req).await?;
+
+        let temp_dir = std::env::temp_dir().join(format!("forge_plugin_{}", uuid::Uuid::new_v4()));
+        let result = self
+            .install_verified(&verified, version_req, &temp_dir)
+            .await;
+        let _ = fs::remove_dir_all(&temp_dir).await;
+
+        let installed = result?;
+        marketplace
+            .record_provenance(&verified, &installed.path)
+            .await;
+        Ok(installed)
+    }
+
+    /// 기록된 버전 요구사항 범위 안에서 업데이트 (고정 버전이거나 최신이면 None)
+    pub async fn update_remote(
+        &self,
+        marketplace: &RemoteMarketplace,
+        id: &str,
+    ) -> Result<Option<InstalledPlugin>> {
+        let plugin = self.store.get(id).await.ok_or_else(|| {
+            forge_foundation::Error::NotFound(format!("Plugin {} not installed", id))
+        })?;
+
+        let version_req = plugin.version_req.clone().unwrap_or_else(|| "*".into());
+        let req = VersionReq::parse(&version_req).unwrap_or_default();
+        if req.is_pinned() {
+            debug!("Plugin {} is pinned to {}", id, version_req);
+            return Ok(None);
+        }
+
+        let Some((_, package)) = marketplace.resolve(PackageKind::Plugin, id, &req).await else {
+            return Ok(None);
+        };
+        let current = PluginVersion::parse(plugin.version.trim_start_matches('v'));
+        match (current, package.parsed_version()) {
+            (Some(current), Some(latest)) if latest > current => {}
+            _ => return Ok(None),
+        }
+
+        info!(
+            "Updating plugin {} {} -> {}",
+            id, plugin.version, package.version
+        );
+        let installed = self.install_remote(marketplace, id, &version_req).await?;
+        if !plugin.enabled {
+            self.store.set_enabled(id, false).await?;
+        }
+        Ok(Some(installed))
+    }
+
+    /// 검증된 아카이브를 풀어 설치하고 출처 기록
+    async fn install_verified(
+        &self,
+        verified: &VerifiedPackage,
+        version_req: &str,
+        temp_dir: &Path,
+    ) -> Result<InstalledPlugin> {
+        let package = &verified.package;
+        let root = verified.unpack(temp_dir).await?;
+
+        // 인덱스 항목과 실제 매니페스트가 일치해야 함 (다른 플러그인으로 바꿔치기 방지)
+        let manifest_content = fs::read_to_string(root.join("plugin.json")).await?;
+        let manifest: serde_json::Value = serde_json::from_str(&manifest_content)?;
+        let manifest_version = manifest["version"]
+            .as_str()
+            .and_then(|v| PluginVersion::parse(v.trim_start_matches('v')));
+        if manifest["id"].as_str() != Some(package.id.as_str())
+            || manifest_version != package.parsed_version()
+        {
+            return Err(forge_foundation::Error::PermissionDenied(format!(
+                "Package {}@{} does not match its plugin.json",
+                package.id, package.version
+            )));
+        }
+
+        // 이전 버전 파일 제거 후 설치
+        self.store.remove_plugin_dir(&package.id).await?;
+        let installed = self.install_from_path(&root).await?;
+
+        let installed = InstalledPlugin {
+            source: package.url.clone(),
+            ..installed
+        }
+        .with_version_req(version_req)
+        .with_integrity(&package.sha256);
+        self.store.record_install(installed.clone()).await?;
+
+        Ok(installed)
+    }
+
     // ========================================================================
     // 유틸리티
     // ========================================================================
@@ -615,4 +729,93 @@ mod tests {
         assert!(result.is_ok());
         assert!(!store.contains("test.plugin").await);
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_install_and_update_remote() {
+        use crate::plugin::marketplace::tests::{pack_dir, write_index, TestSigner};
+        use forge_foundation::audit::AuditLogger;
+
+        let temp = TempDir::new().unwrap();
+        let store = Arc::new(PluginStore::new(temp.path().join("plugins")));
+        let signer = TestSigner::new();
+
+        // 서명된 1.0.0 / 1.1.0 / 2.0.0 패키지
+        let mut packages = Vec::new();
+        for version in ["1.0.0", "1.1.0", "2.0.0"] {
+            let plugin_dir = temp
+                .path()
+                .join(format!("src-{}", version))
+                .join("remote-plugin");
+            fs::create_dir_all(&plugin_dir).await.unwrap();
+            fs::write(
+                plugin_dir.join("plugin.json"),
+                format!(r#"{{"id": "remote.plugin", "version": "{}"}}"#, version),
+            )
+            .await
+            .unwrap();
+            let archive = temp.path().join(format!("remote-{}.tar.gz", version));
+            let bytes = pack_dir(&plugin_dir, &archive);
+            let url = url::Url::from_file_path(&archive).unwrap().to_string();
+            packages.push(signer.package(
+                PackageKind::Plugin,
+                "remote.plugin",
+                version,
+                &url,
+                &bytes,
+            ));
+        }
+        let (old, newer) = (packages[..1].to_vec(), packages.clone());
+        let index_url = write_index(temp.path(), old);
+
+        let logger = Arc::new(AuditLogger::in_memory().unwrap());
+        let marketplace = RemoteMarketplace::new(signer.verifier())
+            .with_index(&index_url)
+            .with_audit_logger(Arc::clone(&logger));
+        marketplace.refresh().await.unwrap();
+
+        let installer = PluginInstaller::new(store.clone());
+        let installed = installer
+            .install_remote(&marketplace, "remote.plugin", "^1.0")
+            .await
+            .unwrap();
+        assert_eq!(installed.version, "1.0.0");
+        assert_eq!(installed.version_req.as_deref(), Some("^1.0"));
+        assert_eq!(
+            installed.integrity.as_deref(),
+            Some(packages[0].sha256.as_str())
+        );
+        assert!(installed.path.join("plugin.json").exists());
+
+        // 출처 기록
+        let entries = logger.recent(10).await.unwrap();
+        assert!(entries
+            .iter()
+            .any(|e| e.tags.iter().any(|t| t == "provenance")));
+
+        // 새 버전 게시: ^1.0 범위 안의 1.1.0으로 업데이트 (2.0.0 제외)
+        write_index(temp.path(), newer);
+        marketplace.refresh().await.unwrap();
+        let updated = installer
+            .update_remote(&marketplace, "remote.plugin")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.version, "1.1.0");
+        assert!(installer
+            .update_remote(&marketplace, "remote.plugin")
+            .await
+            .unwrap()
+            .is_none());
+
+        // 고정 버전은 업데이트하지 않음
+        installer
+            .install_remote(&marketplace, "remote.plugin", "=1.0.0")
+            .await
+            .unwrap();
+        assert!(installer
+            .update_remote(&marketplace, "remote.plugin")
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
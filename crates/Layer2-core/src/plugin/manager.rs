@@ -6,13 +6,22 @@
 //! - PluginStore를 통한 플러그인 영속화
 //! - 플러그인 발견 및 자동 로드
 
+use super::discovery::{PluginDiscovery, PluginScope};
 use super::events::EventBus;
 use super::registry::PluginRegistry;
-use super::traits::{Plugin, PluginContext, PluginStatus};
-use crate::registry::{DynamicToolRegistry, DynamicSkillRegistry};
-use forge_foundation::{Error, Result};
-use std::path::PathBuf;
+use super::script::ScriptPlugin;
+use super::traits::{audit_denied, Plugin, PluginCapability, PluginContext, PluginStatus};
+use super::wasm::WasmPlugin;
+use crate::registry::{DynamicSkillRegistry, DynamicToolRegistry, SnapshotJournal};
+use crate::repomap::LanguageAnalyzer;
+use forge_foundation::audit::AuditLogger;
+use forge_foundation::{check_egress, Error, Result};
+use forge_provider::Gateway;
+use forge_task::{Executor, TaskManager};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
 /// 플러그인 매니저 설정
@@ -26,6 +35,21 @@ pub struct PluginManagerConfig {
 
     /// 오류 시 계속 진행
     pub continue_on_error: bool,
+
+    /// 레지스트리 변경 이력 저널 디렉토리 (None이면 기록하지 않음)
+    ///
+    /// `forge registry history`가 조회하는 위치에 기록하려면 `with_standard_history`.
+    pub history_dir: Option<PathBuf>,
+}
+
+impl PluginManagerConfig {
+    /// 표준 위치(`SnapshotJournal::default_dir()`)에 변경 이력 기록
+    ///
+    /// 기본 설정은 파일을 쓰지 않으므로, CLI처럼 이력을 남길 애플리케이션에서만 사용합니다.
+    pub fn with_standard_history(mut self) -> Self {
+        self.history_dir = SnapshotJournal::default_dir();
+        self
+    }
 }
 
 impl Default for PluginManagerConfig {
@@ -34,10 +58,14 @@ impl Default for PluginManagerConfig {
             plugin_paths: vec![],
             auto_load: true,
             continue_on_error: true,
+            history_dir: None,
         }
     }
 }
 
+/// 플러그인이 등록한 LLM 프로바이더 (플러그인 ID, Gateway 이름, 프로바이더)
+type RegisteredProvider = (String, String, Arc<dyn forge_provider::Provider>);
+
 /// 플러그인 매니저 - 전체 플러그인 시스템 관리
 pub struct PluginManager {
     /// 플러그인 레지스트리
@@ -49,6 +77,15 @@ pub struct PluginManager {
     /// Skill 레지스트리 (동적 등록 지원)
     skill_registry: Arc<DynamicSkillRegistry>,
 
+    /// 플러그인이 등록한 RepoMap 언어 분석기 (플러그인 ID, 분석기)
+    languages: RwLock<Vec<(String, Arc<dyn LanguageAnalyzer>)>>,
+
+    /// 플러그인이 등록한 LLM 프로바이더
+    providers: RwLock<Vec<RegisteredProvider>>,
+
+    /// 플러그인이 등록한 Task executor (플러그인 ID, executor)
+    executors: RwLock<Vec<(String, Arc<dyn Executor>)>>,
+
     /// 이벤트 버스
     event_bus: Arc<EventBus>,
 
@@ -57,30 +94,49 @@ pub struct PluginManager {
 
     /// 설정
     config: PluginManagerConfig,
+
+    /// capability 거부를 기록할 감사 로거
+    audit_logger: Option<Arc<AuditLogger>>,
 }
 
 impl PluginManager {
-    /// 새 매니저 생성
+    /// 새 매니저 생성 (변경 이력 저널 없음, 기록하려면 `with_config`)
     pub fn new(working_dir: PathBuf) -> Self {
         Self {
             registry: Arc::new(PluginRegistry::new()),
             tool_registry: Arc::new(DynamicToolRegistry::new()),
             skill_registry: Arc::new(DynamicSkillRegistry::new()),
+            languages: RwLock::new(Vec::new()),
+            providers: RwLock::new(Vec::new()),
+            executors: RwLock::new(Vec::new()),
             event_bus: Arc::new(EventBus::new()),
             working_dir,
             config: PluginManagerConfig::default(),
+            audit_logger: None,
         }
     }
 
     /// 설정으로 생성
     pub fn with_config(working_dir: PathBuf, config: PluginManagerConfig) -> Self {
+        let (tool_registry, skill_registry) = match &config.history_dir {
+            Some(dir) => (
+                DynamicToolRegistry::new().with_journal(SnapshotJournal::new(dir.join("tools.history.jsonl"))),
+                DynamicSkillRegistry::new().with_journal(SnapshotJournal::new(dir.join("skills.history.jsonl"))),
+            ),
+            None => (DynamicToolRegistry::new(), DynamicSkillRegistry::new()),
+        };
+
         Self {
             registry: Arc::new(PluginRegistry::new()),
-            tool_registry: Arc::new(DynamicToolRegistry::new()),
-            skill_registry: Arc::new(DynamicSkillRegistry::new()),
+            tool_registry: Arc::new(tool_registry),
+            skill_registry: Arc::new(skill_registry),
+            languages: RwLock::new(Vec::new()),
+            providers: RwLock::new(Vec::new()),
+            executors: RwLock::new(Vec::new()),
             event_bus: Arc::new(EventBus::new()),
             working_dir,
             config,
+            audit_logger: None,
         }
     }
 
@@ -94,12 +150,29 @@ impl PluginManager {
             registry: Arc::new(PluginRegistry::new()),
             tool_registry,
             skill_registry,
+            languages: RwLock::new(Vec::new()),
+            providers: RwLock::new(Vec::new()),
+            executors: RwLock::new(Vec::new()),
             event_bus: Arc::new(EventBus::new()),
             working_dir,
             config: PluginManagerConfig::default(),
+            audit_logger: None,
         }
     }
 
+    /// capability 거부를 감사 로그에 기록
+    pub fn with_audit_logger(mut self, logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(logger);
+        self
+    }
+
+    /// 플러그인이 선언한 capability로 제한된 컨텍스트 생성
+    fn plugin_context(&self, plugin: &dyn Plugin) -> PluginContext {
+        PluginContext::new(Arc::clone(&self.event_bus), self.working_dir.clone())
+            .for_plugin(plugin.manifest().id, plugin.capabilities())
+            .with_audit_logger(self.audit_logger.clone())
+    }
+
     // ========================================================================
     // 플러그인 로드/언로드
     // ========================================================================
@@ -137,7 +210,7 @@ impl PluginManager {
         }
 
         // 플러그인 컨텍스트 생성
-        let ctx = PluginContext::new(Arc::clone(&self.event_bus), self.working_dir.clone());
+        let ctx = self.plugin_context(plugin.as_ref());
 
         // on_load 호출
         if let Err(e) = plugin.on_load(&ctx).await {
@@ -149,11 +222,14 @@ impl PluginManager {
         // 플러그인이 등록한 Tool/Skill 수집
         let tools = ctx.take_tools().await;
         let skills = ctx.take_skills().await;
+        let languages = ctx.take_languages().await;
+        let providers = ctx.take_providers().await;
+        let executors = ctx.take_executors().await;
 
         // Tool 등록 - DynamicToolRegistry로 동적 등록 가능
         for tool in tools {
             let tool_name = tool.name().to_string();
-            if let Err(e) = self.tool_registry.register(tool).await {
+            if let Err(e) = self.tool_registry.register_with_provider(tool, &id).await {
                 warn!("Failed to register tool {} from plugin {}: {}", tool_name, id, e);
             } else {
                 debug!("Registered tool from plugin {}: {}", id, tool_name);
@@ -163,13 +239,46 @@ impl PluginManager {
         // Skill 등록 - DynamicSkillRegistry로 동적 등록 가능
         for skill in skills {
             let skill_name = skill.definition().name.clone();
-            if let Err(e) = self.skill_registry.register(skill).await {
+            if let Err(e) = self.skill_registry.register_with_provider(skill, &id).await {
                 warn!("Failed to register skill {} from plugin {}: {}", skill_name, id, e);
             } else {
                 debug!("Registered skill from plugin {}: {}", id, skill_name);
             }
         }
 
+        // 언어 분석기 등록 - RepoAnalyzer::with_languages로 적용
+        if !languages.is_empty() {
+            let mut registered = self.languages.write().await;
+            for analyzer in languages {
+                debug!(
+                    "Registered language from plugin {}: {}",
+                    id,
+                    analyzer.language()
+                );
+                registered.push((id.clone(), analyzer));
+            }
+        }
+
+        // 프로바이더 / executor 등록 - register_providers / register_executors로 적용
+        if !providers.is_empty() {
+            let mut registered = self.providers.write().await;
+            for (name, provider) in providers {
+                debug!("Registered provider from plugin {}: {}", id, name);
+                registered.push((id.clone(), name, provider));
+            }
+        }
+        if !executors.is_empty() {
+            let mut registered = self.executors.write().await;
+            for executor in executors {
+                debug!(
+                    "Registered executor from plugin {}: {}",
+                    id,
+                    executor.name()
+                );
+                registered.push((id.clone(), executor));
+            }
+        }
+
         // 상태 업데이트
         self.registry.set_status(&id, PluginStatus::Active).await;
 
@@ -197,7 +306,7 @@ impl PluginManager {
         let manifest = plugin.manifest();
 
         // 플러그인 컨텍스트 생성
-        let ctx = PluginContext::new(Arc::clone(&self.event_bus), self.working_dir.clone());
+        let ctx = self.plugin_context(plugin.as_ref());
 
         // on_unload 호출
         if let Err(e) = plugin.on_unload(&ctx).await {
@@ -219,6 +328,22 @@ impl PluginManager {
             }
         }
 
+        // 플러그인이 등록한 언어 분석기 제거
+        self.languages
+            .write()
+            .await
+            .retain(|(provider, _)| provider != id);
+
+        // 플러그인이 등록한 프로바이더 / executor 제거 (이미 적용된 Gateway/TaskManager는 호출자가 정리)
+        self.providers
+            .write()
+            .await
+            .retain(|(plugin_id, _, _)| plugin_id != id);
+        self.executors
+            .write()
+            .await
+            .retain(|(plugin_id, _)| plugin_id != id);
+
         // 레지스트리에서 제거
         self.registry.unregister(id).await;
 
@@ -247,6 +372,102 @@ impl PluginManager {
         Ok(())
     }
 
+    /// 모듈 파일이 바뀐 WASM 플러그인의 도구/스킬 교체 (교체한 플러그인 ID 반환)
+    ///
+    /// 새 모듈의 `describe()`가 실패하면 기존 도구/스킬을 유지합니다.
+    pub async fn reload_wasm_plugins(&self) -> Vec<String> {
+        let mut reloaded = Vec::new();
+
+        for manifest in self.registry.list().await {
+            let Some(plugin) = self.registry.get(&manifest.id).await else {
+                continue;
+            };
+            let Some(wasm) = plugin.as_any().downcast_ref::<WasmPlugin>() else {
+                continue;
+            };
+            if !wasm.is_modified() {
+                continue;
+            }
+
+            let (tools, skills) = match wasm.instantiate().await {
+                Ok(instance) => instance,
+                Err(e) => {
+                    warn!("Failed to reload WASM plugin {}: {}", manifest.id, e);
+                    continue;
+                }
+            };
+            if let Err(e) = self.tool_registry.sync_provider(&manifest.id, tools).await {
+                warn!("Failed to sync tools of WASM plugin {}: {}", manifest.id, e);
+            }
+            if let Err(e) = self
+                .skill_registry
+                .sync_provider(&manifest.id, skills)
+                .await
+            {
+                warn!(
+                    "Failed to sync skills of WASM plugin {}: {}",
+                    manifest.id, e
+                );
+            }
+
+            info!("Reloaded WASM plugin {}", manifest.id);
+            reloaded.push(manifest.id);
+        }
+
+        reloaded
+    }
+
+    /// 발견한 스크립트 플러그인 로드 (로드한 플러그인 ID 반환)
+    ///
+    /// 로드 시 스크립트의 최상위 코드가 실행되므로 프로젝트 범위(`.forgecode/`,
+    /// `.forgecode.local/`)의 스크립트는 `trust`가 승인한 것만 로드합니다.
+    /// 사용자 범위(`~/.forgecode/plugins`)의 스크립트는 확인 없이 로드합니다.
+    pub async fn load_scripts<F>(&self, trust: F) -> Result<Vec<String>>
+    where
+        F: Fn(&Path) -> bool,
+    {
+        let discovery = PluginDiscovery::new(&self.working_dir);
+        let mut loaded = Vec::new();
+
+        for (script, scope) in discovery.discover_scripts().await {
+            if scope != PluginScope::User && !trust(&script) {
+                info!("Skipping untrusted script plugin {}", script.display());
+                continue;
+            }
+
+            let plugin = ScriptPlugin::new(&script, &self.working_dir);
+            let id = plugin.manifest().id;
+            match self.load(Arc::new(plugin)).await {
+                Ok(()) => loaded.push(id),
+                Err(e) if self.config.continue_on_error => {
+                    warn!("Failed to load script plugin {}: {}", script.display(), e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(loaded)
+    }
+
+    /// `interval`마다 WASM 모듈 변경을 확인해 hot reload (매니저가 해제되면 종료)
+    pub fn start_wasm_hot_reload(
+        self: &Arc<Self>,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let manager = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let Some(manager) = manager.upgrade() else {
+                    break;
+                };
+                manager.reload_wasm_plugins().await;
+            }
+        })
+    }
+
     // ========================================================================
     // 플러그인 활성화/비활성화
     // ========================================================================
@@ -257,7 +478,7 @@ impl PluginManager {
             Error::NotFound(format!("Plugin {} not found", id))
         })?;
 
-        let ctx = PluginContext::new(Arc::clone(&self.event_bus), self.working_dir.clone());
+        let ctx = self.plugin_context(plugin.as_ref());
         plugin.on_activate(&ctx).await?;
 
         self.registry.set_enabled(id, true).await;
@@ -272,7 +493,7 @@ impl PluginManager {
             Error::NotFound(format!("Plugin {} not found", id))
         })?;
 
-        let ctx = PluginContext::new(Arc::clone(&self.event_bus), self.working_dir.clone());
+        let ctx = self.plugin_context(plugin.as_ref());
         plugin.on_deactivate(&ctx).await?;
 
         self.registry.set_enabled(id, false).await;
@@ -305,6 +526,81 @@ impl PluginManager {
         &self.event_bus
     }
 
+    /// 플러그인이 등록한 언어 분석기 (`RepoAnalyzer::with_languages`에 전달)
+    pub async fn language_analyzers(&self) -> Vec<Arc<dyn LanguageAnalyzer>> {
+        self.languages
+            .read()
+            .await
+            .iter()
+            .map(|(_, analyzer)| Arc::clone(analyzer))
+            .collect()
+    }
+
+    /// 플러그인이 등록한 LLM 프로바이더 (Gateway 이름, 프로바이더)
+    pub async fn providers(&self) -> Vec<(String, Arc<dyn forge_provider::Provider>)> {
+        self.providers
+            .read()
+            .await
+            .iter()
+            .map(|(_, name, provider)| (name.clone(), Arc::clone(provider)))
+            .collect()
+    }
+
+    /// 플러그인이 등록한 Task executor
+    pub async fn executors(&self) -> Vec<Arc<dyn Executor>> {
+        self.executors
+            .read()
+            .await
+            .iter()
+            .map(|(_, executor)| Arc::clone(executor))
+            .collect()
+    }
+
+    /// 플러그인 프로바이더를 Gateway에 추가 (추가된 수 반환)
+    ///
+    /// 설정 파일의 프로바이더와 이름이 겹치거나 egress 정책이 막는 프로바이더는 건너뜁니다.
+    pub async fn register_providers(&self, gateway: &mut Gateway) -> usize {
+        let mut added = 0;
+        for (plugin_id, name, provider) in self.providers.read().await.iter() {
+            if gateway.list_providers().contains(&name.as_str()) {
+                warn!(
+                    "Plugin {} provider '{}' conflicts with an existing provider",
+                    plugin_id, name
+                );
+                continue;
+            }
+            if let Some(base_url) = &provider.metadata().base_url {
+                if let Err(e) = check_egress(base_url) {
+                    warn!("Skipping plugin {} provider '{}': {}", plugin_id, name, e);
+                    continue;
+                }
+            }
+            gateway.add_provider(name.clone(), Arc::clone(provider));
+            info!("Added provider '{}' from plugin {}", name, plugin_id);
+            added += 1;
+        }
+        added
+    }
+
+    /// 플러그인 executor를 TaskManager에 등록 (등록된 수 반환)
+    pub async fn register_executors(&self, task_manager: &TaskManager) -> usize {
+        let executors = self.executors.read().await;
+        for (plugin_id, executor) in executors.iter() {
+            if task_manager
+                .register_executor(Arc::clone(executor))
+                .await
+                .is_some()
+            {
+                warn!(
+                    "Plugin {} executor '{}' replaced an existing executor",
+                    plugin_id,
+                    executor.name()
+                );
+            }
+        }
+        executors.len()
+    }
+
     // ========================================================================
     // 시스템 프롬프트 수정
     // ========================================================================
@@ -314,8 +610,22 @@ impl PluginManager {
         let mut prompt = base_prompt.to_string();
 
         for plugin in self.registry.list_enabled().await {
-            if let Some(modified) = plugin.modify_system_prompt(&prompt) {
+            let Some(modified) = plugin.modify_system_prompt(&prompt) else {
+                continue;
+            };
+            if plugin
+                .capabilities()
+                .contains(&PluginCapability::ModifySystemPrompt)
+            {
                 prompt = modified;
+            } else {
+                audit_denied(
+                    self.audit_logger.as_deref(),
+                    &plugin.manifest().id,
+                    PluginCapability::ModifySystemPrompt,
+                    "modify the system prompt",
+                )
+                .await;
             }
         }
 
@@ -381,6 +691,124 @@ mod tests {
         }
     }
 
+    struct DhallAnalyzer;
+
+    impl LanguageAnalyzer for DhallAnalyzer {
+        fn language(&self) -> &str {
+            "dhall"
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["dhall"]
+        }
+
+        fn parse(&self, _content: &str, _file_info: &mut crate::repomap::FileInfo) {}
+    }
+
+    struct LanguagePlugin;
+
+    #[async_trait]
+    impl Plugin for LanguagePlugin {
+        fn manifest(&self) -> PluginManifest {
+            PluginManifest::new("lang.dhall", "Dhall")
+        }
+
+        fn capabilities(&self) -> Vec<PluginCapability> {
+            vec![PluginCapability::RegisterLanguages]
+        }
+
+        async fn on_load(&self, ctx: &PluginContext) -> Result<()> {
+            ctx.register_language(Arc::new(DhallAnalyzer)).await
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// capability 없이 도구 등록과 프롬프트 수정을 시도하는 플러그인
+    struct RoguePlugin {
+        capabilities: Vec<PluginCapability>,
+    }
+
+    #[async_trait]
+    impl Plugin for RoguePlugin {
+        fn manifest(&self) -> PluginManifest {
+            PluginManifest::new("rogue.plugin", "Rogue")
+        }
+
+        fn capabilities(&self) -> Vec<PluginCapability> {
+            self.capabilities.clone()
+        }
+
+        async fn on_load(&self, ctx: &PluginContext) -> Result<()> {
+            if !ctx.has_capability(PluginCapability::RegisterTools) {
+                // 거부되어도 로드는 계속
+                assert!(ctx
+                    .register_tool(Arc::new(crate::tool::builtin::ReadTool::new()))
+                    .await
+                    .is_err());
+            }
+            Ok(())
+        }
+
+        fn modify_system_prompt(&self, prompt: &str) -> Option<String> {
+            Some(format!("{}\nrogue", prompt))
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_capability_enforcement() {
+        let logger = Arc::new(AuditLogger::in_memory().unwrap());
+        let manager =
+            PluginManager::new(PathBuf::from("/tmp")).with_audit_logger(Arc::clone(&logger));
+        manager
+            .load(Arc::new(RoguePlugin {
+                capabilities: vec![],
+            }))
+            .await
+            .unwrap();
+
+        assert!(manager.tool_registry().is_empty().await);
+        assert_eq!(manager.apply_system_prompt_modifiers("base").await, "base");
+
+        let denied: Vec<String> = logger
+            .recent(10)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|e| e.target)
+            .collect();
+        assert!(denied.contains(&"register_tools".to_string()));
+        assert!(denied.contains(&"modify_system_prompt".to_string()));
+
+        // 선언하면 허용
+        manager.unload("rogue.plugin").await.unwrap();
+        manager
+            .load(Arc::new(RoguePlugin {
+                capabilities: vec![PluginCapability::ModifySystemPrompt],
+            }))
+            .await
+            .unwrap();
+        assert_eq!(
+            manager.apply_system_prompt_modifiers("base").await,
+            "base\nrogue"
+        );
+    }
+
+    #[test]
+    fn test_default_config_writes_no_history() {
+        assert!(PluginManagerConfig::default().history_dir.is_none());
+        assert_eq!(
+            PluginManagerConfig::default().with_standard_history().history_dir,
+            SnapshotJournal::default_dir()
+        );
+    }
+
     #[tokio::test]
     async fn test_load_plugin() {
         let manager = PluginManager::new(PathBuf::from("/tmp"));
@@ -401,4 +829,115 @@ mod tests {
 
         assert_eq!(manager.plugin_count().await, 0);
     }
+
+    #[tokio::test]
+    async fn test_plugin_languages() {
+        let manager = PluginManager::new(PathBuf::from("/tmp"));
+        manager.load(Arc::new(LanguagePlugin)).await.unwrap();
+
+        let analyzer = crate::repomap::RepoAnalyzer::with_defaults("/tmp")
+            .with_languages(manager.language_analyzers().await);
+        let registry = analyzer.languages();
+        let found = registry.for_path(std::path::Path::new("config.dhall"));
+        assert_eq!(found.map(|a| a.language()), Some("dhall"));
+
+        manager.unload("lang.dhall").await.unwrap();
+        assert!(manager.language_analyzers().await.is_empty());
+    }
+
+    struct RemoteExecutor;
+
+    #[async_trait]
+    impl Executor for RemoteExecutor {
+        async fn execute(&self, task: &forge_task::Task) -> Result<forge_task::TaskResult> {
+            Ok(forge_task::TaskResult::success(task.command.clone()))
+        }
+
+        async fn cancel(&self, _task: &forge_task::Task) -> Result<()> {
+            Ok(())
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn name(&self) -> &'static str {
+            "remote"
+        }
+    }
+
+    struct BackendPlugin;
+
+    #[async_trait]
+    impl Plugin for BackendPlugin {
+        fn manifest(&self) -> PluginManifest {
+            PluginManifest::new("backend.remote", "Remote Backend").with_provides(
+                PluginProvides::new()
+                    .with_provider("plugin-llm")
+                    .with_executor("remote"),
+            )
+        }
+
+        fn capabilities(&self) -> Vec<PluginCapability> {
+            vec![
+                PluginCapability::RegisterProviders,
+                PluginCapability::RegisterExecutors,
+            ]
+        }
+
+        async fn on_load(&self, ctx: &PluginContext) -> Result<()> {
+            ctx.register_provider("plugin-llm", Arc::new(forge_provider::MockProvider::new()))
+                .await?;
+            ctx.register_executor(Arc::new(RemoteExecutor)).await
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[tokio::test]
+    async fn test_plugin_providers_and_executors() {
+        let manager = PluginManager::new(PathBuf::from("/tmp"));
+        manager.load(Arc::new(BackendPlugin)).await.unwrap();
+
+        let mut gateway = Gateway::new();
+        assert_eq!(manager.register_providers(&mut gateway).await, 1);
+        assert!(gateway.get_provider("plugin-llm").is_ok());
+        // 이미 있는 이름은 덮어쓰지 않음
+        assert_eq!(manager.register_providers(&mut gateway).await, 0);
+
+        let task_manager = TaskManager::default().await;
+        assert_eq!(manager.register_executors(&task_manager).await, 1);
+        assert_eq!(task_manager.custom_executor_names().await, ["remote"]);
+
+        manager.unload("backend.remote").await.unwrap();
+        assert!(manager.providers().await.is_empty());
+        assert!(manager.executors().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_load_scripts_requires_trust() {
+        let temp = tempfile::tempdir().unwrap();
+        let plugins = temp.path().join(".forgecode/plugins");
+        std::fs::create_dir_all(&plugins).unwrap();
+        std::fs::write(
+            plugins.join("hello.js"),
+            r#"module.exports = { run() { return "hi"; } };"#,
+        )
+        .unwrap();
+
+        // 신뢰하지 않은 프로젝트 스크립트는 describe도 실행하지 않음
+        let manager = PluginManager::new(temp.path().to_path_buf());
+        let loaded = manager.load_scripts(|_| false).await.unwrap();
+        assert!(!loaded.contains(&"script.hello".to_string()));
+        assert!(manager.tool_registry().get("hello").await.is_none());
+
+        let loaded = manager
+            .load_scripts(|path| path.starts_with(&plugins))
+            .await
+            .unwrap();
+        assert!(loaded.contains(&"script.hello".to_string()));
+        assert!(manager.tool_registry().get("hello").await.is_some());
+    }
 }
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// 플러그인 버전
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PluginVersion {
     pub major: u32,
     pub minor: u32,
@@ -49,6 +49,177 @@ impl Default for PluginVersion {
     }
 }
 
+/// 버전 요구사항 (Cargo 스타일 semver 범위)
+///
+/// - `*` 또는 빈 문자열: 모든 버전
+/// - `=1.2.3`: 고정 (pin), `=1.2`는 `1.2.x`
+/// - `^1.2.3` / `1.2.3`: 호환 범위 (`>=1.2.3, <2.0.0`, 0.x는 minor까지 고정)
+/// - `~1.2.3`: patch 업데이트만 (`>=1.2.3, <1.3.0`)
+/// - `>=`, `>`, `<=`, `<`: 비교 (쉼표로 조합, 예: `>=1.2, <1.5`)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VersionReq {
+    comparators: Vec<VersionComparator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VersionComparator {
+    op: VersionOp,
+    version: PluginVersion,
+    /// 명시된 자리수 (1 = major, 2 = major.minor, 3 = 전체)
+    precision: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VersionOp {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+}
+
+impl VersionReq {
+    /// 모든 버전 허용
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// 정확히 한 버전으로 고정
+    pub fn exact(version: &PluginVersion) -> Self {
+        Self {
+            comparators: vec![VersionComparator {
+                op: VersionOp::Exact,
+                version: version.clone(),
+                precision: 3,
+            }],
+        }
+    }
+
+    /// 요구사항 문자열 파싱
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut comparators = Vec::new();
+
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            if part == "*" {
+                continue;
+            }
+            let (op, rest) = [
+                (">=", VersionOp::GreaterEq),
+                ("<=", VersionOp::LessEq),
+                (">", VersionOp::Greater),
+                ("<", VersionOp::Less),
+                ("=", VersionOp::Exact),
+                ("~", VersionOp::Tilde),
+                ("^", VersionOp::Caret),
+            ]
+            .into_iter()
+            .find_map(|(prefix, op)| part.strip_prefix(prefix).map(|rest| (op, rest)))
+            .unwrap_or((VersionOp::Caret, part));
+
+            let rest = rest.trim().trim_start_matches('v');
+            let numbers: Vec<u32> = rest
+                .split('.')
+                .map(|n| n.parse().ok())
+                .collect::<Option<_>>()?;
+            if numbers.is_empty() || numbers.len() > 3 {
+                return None;
+            }
+
+            comparators.push(VersionComparator {
+                op,
+                version: PluginVersion::new(
+                    numbers[0],
+                    numbers.get(1).copied().unwrap_or(0),
+                    numbers.get(2).copied().unwrap_or(0),
+                ),
+                precision: numbers.len() as u8,
+            });
+        }
+
+        Some(Self { comparators })
+    }
+
+    /// 버전이 요구사항을 만족하는지
+    pub fn matches(&self, version: &PluginVersion) -> bool {
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+
+    /// 정확히 한 버전으로 고정된 요구사항인지
+    pub fn is_pinned(&self) -> bool {
+        matches!(
+            self.comparators.as_slice(),
+            [VersionComparator {
+                op: VersionOp::Exact,
+                precision: 3,
+                ..
+            }]
+        )
+    }
+}
+
+impl VersionComparator {
+    fn matches(&self, v: &PluginVersion) -> bool {
+        let base = &self.version;
+        match self.op {
+            VersionOp::Greater => v > base,
+            VersionOp::GreaterEq => v >= base,
+            VersionOp::Less => v < base,
+            VersionOp::LessEq => v <= base,
+            VersionOp::Exact => match self.precision {
+                1 => v.major == base.major,
+                2 => v.major == base.major && v.minor == base.minor,
+                _ => v == base,
+            },
+            VersionOp::Tilde => {
+                v >= base && v.major == base.major && (self.precision == 1 || v.minor == base.minor)
+            }
+            VersionOp::Caret => {
+                if v < base || v.major != base.major {
+                    return false;
+                }
+                match (base.major, base.minor, self.precision) {
+                    (0, _, 1) => true,
+                    (0, 0, 2) => v.minor == 0,
+                    (0, 0, _) => v.minor == 0 && v.patch == base.patch,
+                    (0, _, _) => v.minor == base.minor,
+                    _ => true,
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.comparators.is_empty() {
+            return write!(f, "*");
+        }
+        for (i, c) in self.comparators.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            let op = match c.op {
+                VersionOp::Exact => "=",
+                VersionOp::Greater => ">",
+                VersionOp::GreaterEq => ">=",
+                VersionOp::Less => "<",
+                VersionOp::LessEq => "<=",
+                VersionOp::Tilde => "~",
+                VersionOp::Caret => "^",
+            };
+            let v = &c.version;
+            match c.precision {
+                1 => write!(f, "{}{}", op, v.major)?,
+                2 => write!(f, "{}{}.{}", op, v.major, v.minor)?,
+                _ => write!(f, "{}{}", op, v)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 /// 플러그인 의존성
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginDependency {
@@ -177,6 +348,13 @@ impl PluginManifest {
         self.metadata.insert(key.into(), value.into());
         self
     }
+
+    /// `plugin.json`의 `permissions` (메타데이터 `permission_<N>`, 선언 순서)
+    pub fn permissions(&self) -> Vec<String> {
+        (0..)
+            .map_while(|i| self.metadata.get(&format!("permission_{}", i)).cloned())
+            .collect()
+    }
 }
 
 /// 플러그인이 제공하는 기능 목록
@@ -191,6 +369,14 @@ pub struct PluginProvides {
     /// 제공하는 이벤트 핸들러
     pub event_handlers: Vec<String>,
 
+    /// 제공하는 LLM 프로바이더 이름들 (Gateway 등록 이름)
+    #[serde(default)]
+    pub providers: Vec<String>,
+
+    /// 제공하는 Task executor 이름들 (`ExecutionMode::Custom`)
+    #[serde(default)]
+    pub executors: Vec<String>,
+
     /// 시스템 프롬프트 수정 여부
     pub modifies_system_prompt: bool,
 }
@@ -215,6 +401,16 @@ impl PluginProvides {
         self
     }
 
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.providers.push(provider.into());
+        self
+    }
+
+    pub fn with_executor(mut self, executor: impl Into<String>) -> Self {
+        self.executors.push(executor.into());
+        self
+    }
+
     pub fn modifies_prompt(mut self) -> Self {
         self.modifies_system_prompt = true;
         self
@@ -266,6 +462,30 @@ mod tests {
         assert!(!v1.is_compatible_with(&v3));
     }
 
+    #[test]
+    fn test_version_req() {
+        let v = |s: &str| PluginVersion::parse(s).unwrap();
+        let req = |s: &str| VersionReq::parse(s).unwrap();
+
+        assert!(req("*").matches(&v("9.9.9")));
+        assert!(req("1.2.3").matches(&v("1.9.0")));
+        assert!(!req("^1.2.3").matches(&v("2.0.0")));
+        assert!(!req("^1.2.3").matches(&v("1.2.2")));
+        assert!(req("^0.2.3").matches(&v("0.2.9")));
+        assert!(!req("^0.2.3").matches(&v("0.3.0")));
+        assert!(req("~1.2.3").matches(&v("1.2.9")));
+        assert!(!req("~1.2.3").matches(&v("1.3.0")));
+        assert!(req("=1.2").matches(&v("1.2.7")));
+        assert!(!req("=1.2.3").matches(&v("1.2.4")));
+        assert!(req(">=1.2, <1.5").matches(&v("1.4.9")));
+        assert!(!req(">=1.2, <1.5").matches(&v("1.5.0")));
+
+        assert!(req("=v1.2.3").is_pinned());
+        assert!(!req("=1.2").is_pinned());
+        assert_eq!(req(">=1.2, <2").to_string(), ">=1.2, <2");
+        assert!(VersionReq::parse("^x.1").is_none());
+    }
+
     #[test]
     fn test_manifest_builder() {
         let manifest = PluginManifest::new("test.plugin", "Test Plugin")
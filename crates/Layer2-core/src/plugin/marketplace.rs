@@ -0,0 +1,663 @@
+//! Remote Marketplace - 서명된 원격 플러그인/스킬 인덱스
+//!
+//! HTTPS 인덱스에서 패키지를 찾아 내려받고, ed25519 서명과 sha256을 검증한 뒤
+//! `PluginInstaller::install_remote` / `SkillManager::install_remote`가 설치합니다.
+//! 설치 출처(인덱스, URL, 버전, 서명 키)는 감사 로그에 기록됩니다.
+//!
+//! ## 인덱스 형식
+//! ```json
+//! {
+//!   "name": "ForgeCode Marketplace",
+//!   "packages": [
+//!     {
+//!       "id": "forge.git-enhanced",
+//!       "kind": "plugin",
+//!       "version": "1.2.0",
+//!       "url": "https://example.com/forge.git-enhanced-1.2.0.tar.gz",
+//!       "sha256": "9f86d0...",
+//!       "signature": "base64 ed25519 signature",
+//!       "keyId": "forge-2024"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! - 서명 대상은 패키지 아카이브(`.tar.gz`) 바이트 전체이며, `keyId`의 신뢰 키로 검증합니다.
+//! - 인덱스와 패키지 URL은 `https://`만 허용합니다 (로컬 미러용 `file://` 제외).
+//! - 버전은 `VersionReq`로 고르며 (`=1.2.0` 고정, `^1.2` 범위 등), 범위 안의 최신 버전을 선택합니다.
+
+use super::manifest::{PluginVersion, VersionReq};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use forge_foundation::audit::{AuditAction, AuditEntry, AuditLogger, AuditResult};
+use forge_foundation::{Error, Result};
+use reqwest::Client;
+use ring::digest::{digest, SHA256};
+use ring::signature::{UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+// ============================================================================
+// RemoteIndex - 인덱스 파일
+// ============================================================================
+
+/// 패키지 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageKind {
+    /// 플러그인 (`plugin.json` 포함)
+    Plugin,
+    /// 스킬 (`SKILL.md` 포함)
+    Skill,
+}
+
+/// 인덱스에 등록된 패키지 버전 하나
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemotePackage {
+    /// 플러그인 ID 또는 스킬 이름
+    pub id: String,
+
+    /// 종류
+    pub kind: PackageKind,
+
+    /// 버전 (`1.2.0`, `v` 접두사 허용)
+    pub version: String,
+
+    /// 아카이브 URL (`.tar.gz`)
+    pub url: String,
+
+    /// 아카이브 sha256 (hex)
+    pub sha256: String,
+
+    /// 아카이브에 대한 ed25519 서명 (base64)
+    pub signature: String,
+
+    /// 서명 키 ID
+    pub key_id: String,
+
+    /// 설명
+    #[serde(default)]
+    pub description: String,
+}
+
+impl RemotePackage {
+    /// 파싱된 버전
+    pub fn parsed_version(&self) -> Option<PluginVersion> {
+        PluginVersion::parse(self.version.trim_start_matches('v'))
+    }
+}
+
+/// 원격 인덱스
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RemoteIndex {
+    /// 인덱스 이름
+    #[serde(default)]
+    pub name: String,
+
+    /// 패키지 목록 (같은 ID의 여러 버전 포함)
+    #[serde(default)]
+    pub packages: Vec<RemotePackage>,
+}
+
+impl RemoteIndex {
+    /// 요구사항을 만족하는 최신 버전
+    pub fn resolve(&self, kind: PackageKind, id: &str, req: &VersionReq) -> Option<&RemotePackage> {
+        self.packages
+            .iter()
+            .filter(|p| p.kind == kind && p.id == id)
+            .filter_map(|p| p.parsed_version().map(|v| (v, p)))
+            .filter(|(v, _)| req.matches(v))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, p)| p)
+    }
+}
+
+// ============================================================================
+// PackageVerifier - 서명 검증
+// ============================================================================
+
+/// 신뢰하는 ed25519 공개 키로 패키지 검증
+#[derive(Debug, Clone, Default)]
+pub struct PackageVerifier {
+    /// 키 ID → 공개 키 (32 bytes)
+    keys: HashMap<String, Vec<u8>>,
+}
+
+impl PackageVerifier {
+    /// 신뢰 키 없이 생성 (모든 패키지 거부)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 신뢰 키 추가 (base64 인코딩된 32바이트 ed25519 공개 키)
+    pub fn with_trusted_key(
+        mut self,
+        key_id: impl Into<String>,
+        public_key_base64: &str,
+    ) -> Result<Self> {
+        let key_id = key_id.into();
+        let key = BASE64.decode(public_key_base64.trim()).map_err(|e| {
+            Error::InvalidInput(format!("Invalid public key for {}: {}", key_id, e))
+        })?;
+        if key.len() != 32 {
+            return Err(Error::InvalidInput(format!(
+                "Public key for {} must be 32 bytes, got {}",
+                key_id,
+                key.len()
+            )));
+        }
+        self.keys.insert(key_id, key);
+        Ok(self)
+    }
+
+    /// 신뢰 키 등록 여부
+    pub fn has_key(&self, key_id: &str) -> bool {
+        self.keys.contains_key(key_id)
+    }
+
+    /// 아카이브 sha256과 서명 검증
+    pub fn verify(&self, package: &RemotePackage, bytes: &[u8]) -> Result<()> {
+        let actual = sha256_hex(bytes);
+        if !actual.eq_ignore_ascii_case(package.sha256.trim()) {
+            return Err(Error::PermissionDenied(format!(
+                "Checksum mismatch for {}@{}: expected {}, got {}",
+                package.id, package.version, package.sha256, actual
+            )));
+        }
+
+        let key = self.keys.get(&package.key_id).ok_or_else(|| {
+            Error::PermissionDenied(format!(
+                "Package {}@{} is signed with untrusted key '{}'",
+                package.id, package.version, package.key_id
+            ))
+        })?;
+        let signature = BASE64.decode(package.signature.trim()).map_err(|e| {
+            Error::PermissionDenied(format!(
+                "Invalid signature encoding for {}@{}: {}",
+                package.id, package.version, e
+            ))
+        })?;
+
+        UnparsedPublicKey::new(&ED25519, key)
+            .verify(bytes, &signature)
+            .map_err(|_| {
+                Error::PermissionDenied(format!(
+                    "Signature verification failed for {}@{}",
+                    package.id, package.version
+                ))
+            })
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    digest(&SHA256, bytes)
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// ============================================================================
+// VerifiedPackage - 검증된 아카이브
+// ============================================================================
+
+/// 서명 검증을 통과한 패키지
+#[derive(Debug, Clone)]
+pub struct VerifiedPackage {
+    /// 패키지 정보
+    pub package: RemotePackage,
+
+    /// 패키지를 찾은 인덱스 URL
+    pub index_url: String,
+
+    /// 아카이브 바이트
+    bytes: Vec<u8>,
+}
+
+impl VerifiedPackage {
+    /// 아카이브 바이트
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// `dest`에 압축 해제 후 패키지 루트 반환
+    ///
+    /// 아카이브 최상위에 디렉토리 하나만 있으면 그 디렉토리가 루트입니다.
+    pub async fn unpack(&self, dest: &Path) -> Result<PathBuf> {
+        fs::create_dir_all(dest).await?;
+        let archive = dest.join("package.tar.gz");
+        fs::write(&archive, &self.bytes).await?;
+
+        let output = tokio::process::Command::new("tar")
+            .arg("-xzf")
+            .arg(&archive)
+            .arg("-C")
+            .arg(dest)
+            .output()
+            .await?;
+        let _ = fs::remove_file(&archive).await;
+        if !output.status.success() {
+            return Err(Error::Internal(format!(
+                "Failed to extract {}: {}",
+                self.package.id,
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        let mut entries = fs::read_dir(dest).await?;
+        let mut children = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            children.push(entry.path());
+        }
+        match children.as_slice() {
+            [only] if only.is_dir() => Ok(only.clone()),
+            _ => Ok(dest.to_path_buf()),
+        }
+    }
+}
+
+// ============================================================================
+// RemoteMarketplace - 원격 인덱스 클라이언트
+// ============================================================================
+
+/// 원격 플러그인/스킬 인덱스 클라이언트
+pub struct RemoteMarketplace {
+    /// HTTP 클라이언트
+    client: Client,
+
+    /// 인덱스 URL 목록 (앞쪽 우선)
+    index_urls: Vec<String>,
+
+    /// 서명 검증기
+    verifier: PackageVerifier,
+
+    /// 가져온 인덱스 (URL → 인덱스)
+    cache: RwLock<HashMap<String, RemoteIndex>>,
+
+    /// 출처 기록용 감사 로거
+    audit_logger: Option<Arc<AuditLogger>>,
+}
+
+impl RemoteMarketplace {
+    /// 검증기로 생성
+    pub fn new(verifier: PackageVerifier) -> Self {
+        Self {
+            client: Client::new(),
+            index_urls: Vec::new(),
+            verifier,
+            cache: RwLock::new(HashMap::new()),
+            audit_logger: None,
+        }
+    }
+
+    /// 인덱스 URL 추가
+    pub fn with_index(mut self, url: impl Into<String>) -> Self {
+        self.index_urls.push(url.into());
+        self
+    }
+
+    /// 감사 로거 설정
+    pub fn with_audit_logger(mut self, logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(logger);
+        self
+    }
+
+    /// 인덱스 URL 목록
+    pub fn index_urls(&self) -> &[String] {
+        &self.index_urls
+    }
+
+    /// 모든 인덱스 새로고침 (가져온 인덱스 수 반환)
+    pub async fn refresh(&self) -> Result<usize> {
+        let mut loaded = 0;
+        for url in &self.index_urls {
+            match self.fetch_index(url).await {
+                Ok(index) => {
+                    info!("Loaded {} packages from {}", index.packages.len(), url);
+                    self.cache.write().await.insert(url.clone(), index);
+                    loaded += 1;
+                }
+                Err(e) => warn!("Failed to fetch index {}: {}", url, e),
+            }
+        }
+        Ok(loaded)
+    }
+
+    /// 인덱스 가져오기
+    async fn fetch_index(&self, url: &str) -> Result<RemoteIndex> {
+        let bytes = self.get_bytes(url).await?;
+        serde_json::from_slice(&bytes)
+            .map_err(|e| Error::InvalidInput(format!("Invalid marketplace index {}: {}", url, e)))
+    }
+
+    /// 요구사항을 만족하는 최신 버전 (인덱스 URL, 패키지)
+    pub async fn resolve(
+        &self,
+        kind: PackageKind,
+        id: &str,
+        req: &VersionReq,
+    ) -> Option<(String, RemotePackage)> {
+        let cache = self.cache.read().await;
+        self.index_urls
+            .iter()
+            .filter_map(|url| {
+                let package = cache.get(url)?.resolve(kind, id, req)?;
+                Some((package.parsed_version()?, url, package))
+            })
+            // 같은 버전이면 앞쪽 인덱스 우선
+            .fold(
+                None,
+                |best: Option<(PluginVersion, &String, &RemotePackage)>, item| match &best {
+                    Some((version, _, _)) if *version >= item.0 => best,
+                    _ => Some(item),
+                },
+            )
+            .map(|(_, url, package)| (url.clone(), package.clone()))
+    }
+
+    /// 패키지를 찾아 내려받고 검증 (실패는 감사 로그에 기록)
+    pub async fn fetch(
+        &self,
+        kind: PackageKind,
+        id: &str,
+        req: &VersionReq,
+    ) -> Result<VerifiedPackage> {
+        let (index_url, package) = self
+            .resolve(kind, id, req)
+            .await
+            .ok_or_else(|| Error::NotFound(format!("No version of {} matches {}", id, req)))?;
+
+        debug!(
+            "Downloading {}@{} from {}",
+            package.id, package.version, package.url
+        );
+        let bytes = self.get_bytes(&package.url).await?;
+        if let Err(e) = self.verifier.verify(&package, &bytes) {
+            self.audit(
+                AuditEntry::new(AuditAction::PermissionDenied, "marketplace")
+                    .with_error(e.to_string())
+                    .with_result(AuditResult::Denied),
+                &index_url,
+                &package,
+            )
+            .await;
+            return Err(e);
+        }
+
+        Ok(VerifiedPackage {
+            package,
+            index_url,
+            bytes,
+        })
+    }
+
+    /// 설치 출처 기록
+    pub async fn record_provenance(&self, verified: &VerifiedPackage, installed_path: &Path) {
+        info!(
+            "Installed {}@{} from {} (sha256 {}, key {})",
+            verified.package.id,
+            verified.package.version,
+            verified.package.url,
+            verified.package.sha256,
+            verified.package.key_id
+        );
+        self.audit(
+            AuditEntry::new(AuditAction::Custom, "marketplace")
+                .with_result(AuditResult::Success)
+                .with_tag("provenance")
+                .with_data(json!({ "path": installed_path.display().to_string() })),
+            &verified.index_url,
+            &verified.package,
+        )
+        .await;
+    }
+
+    async fn audit(&self, entry: AuditEntry, index_url: &str, package: &RemotePackage) {
+        let Some(logger) = &self.audit_logger else {
+            return;
+        };
+
+        let mut data = json!({
+            "kind": package.kind,
+            "version": package.version,
+            "index": index_url,
+            "url": package.url,
+            "sha256": package.sha256,
+            "keyId": package.key_id,
+        });
+        if let (Some(extra), Some(map)) = (entry.data.as_object(), data.as_object_mut()) {
+            map.extend(extra.clone());
+        }
+
+        let entry = entry
+            .with_target(&package.id)
+            .with_description(format!(
+                "Marketplace package {}@{}",
+                package.id, package.version
+            ))
+            .with_tag("marketplace")
+            .with_data(data);
+        if let Err(e) = logger.log(entry).await {
+            warn!("Failed to record audit entry: {}", e);
+        }
+    }
+
+    /// URL 내용 가져오기 (`https://`, 로컬 미러 `file://`)
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        let parsed = url::Url::parse(url)
+            .map_err(|e| Error::InvalidInput(format!("Invalid URL {}: {}", url, e)))?;
+
+        match parsed.scheme() {
+            "https" => {
+                let response = self
+                    .client
+                    .get(url)
+                    .header("User-Agent", "ForgeCode")
+                    .send()
+                    .await
+                    .map_err(|e| Error::Http(e.to_string()))?;
+                if !response.status().is_success() {
+                    return Err(Error::Http(format!(
+                        "Failed to fetch {}: HTTP {}",
+                        url,
+                        response.status()
+                    )));
+                }
+                let bytes = response
+                    .bytes()
+                    .await
+                    .map_err(|e| Error::Http(e.to_string()))?;
+                Ok(bytes.to_vec())
+            }
+            "file" => {
+                let path = parsed
+                    .to_file_path()
+                    .map_err(|_| Error::InvalidInput(format!("Invalid file URL: {}", url)))?;
+                Ok(fs::read(path).await?)
+            }
+            scheme => Err(Error::InvalidInput(format!(
+                "Refusing to fetch {} over {}: only https is allowed",
+                url, scheme
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    /// 테스트용 서명 키
+    pub(crate) struct TestSigner {
+        key_pair: Ed25519KeyPair,
+    }
+
+    impl TestSigner {
+        pub(crate) fn new() -> Self {
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+            Self {
+                key_pair: Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap(),
+            }
+        }
+
+        pub(crate) fn public_key(&self) -> String {
+            BASE64.encode(self.key_pair.public_key().as_ref())
+        }
+
+        pub(crate) fn verifier(&self) -> PackageVerifier {
+            PackageVerifier::new()
+                .with_trusted_key("test", &self.public_key())
+                .unwrap()
+        }
+
+        /// `bytes`를 `url`에 둔 서명된 패키지
+        pub(crate) fn package(
+            &self,
+            kind: PackageKind,
+            id: &str,
+            version: &str,
+            url: &str,
+            bytes: &[u8],
+        ) -> RemotePackage {
+            RemotePackage {
+                id: id.into(),
+                kind,
+                version: version.into(),
+                url: url.into(),
+                sha256: sha256_hex(bytes),
+                signature: BASE64.encode(self.key_pair.sign(bytes).as_ref()),
+                key_id: "test".into(),
+                description: String::new(),
+            }
+        }
+    }
+
+    /// `source` 디렉토리를 `dest`에 tar.gz로 묶고 바이트 반환
+    pub(crate) fn pack_dir(source: &Path, dest: &Path) -> Vec<u8> {
+        let status = std::process::Command::new("tar")
+            .arg("-czf")
+            .arg(dest)
+            .arg("-C")
+            .arg(source.parent().unwrap())
+            .arg(source.file_name().unwrap())
+            .status()
+            .unwrap();
+        assert!(status.success());
+        std::fs::read(dest).unwrap()
+    }
+
+    /// 패키지 목록을 `dir/index.json`에 쓰고 `file://` URL 반환
+    pub(crate) fn write_index(dir: &Path, packages: Vec<RemotePackage>) -> String {
+        let index = RemoteIndex {
+            name: "test".into(),
+            packages,
+        };
+        let path = dir.join("index.json");
+        std::fs::write(&path, serde_json::to_vec(&index).unwrap()).unwrap();
+        url::Url::from_file_path(&path).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let signer = TestSigner::new();
+        let bytes = b"archive bytes";
+        let package = signer.package(PackageKind::Plugin, "p", "1.0.0", "https://x", bytes);
+
+        assert!(signer.verifier().verify(&package, bytes).is_ok());
+
+        // 변조된 내용 / 서명 / 신뢰하지 않는 키
+        assert!(signer.verifier().verify(&package, b"tampered").is_err());
+        let mut forged = package.clone();
+        forged.signature = BASE64.encode([0u8; 64]);
+        assert!(signer.verifier().verify(&forged, bytes).is_err());
+        assert!(PackageVerifier::new().verify(&package, bytes).is_err());
+        assert!(PackageVerifier::new()
+            .with_trusted_key("short", "AAAA")
+            .is_err());
+    }
+
+    #[test]
+    fn test_index_resolve() {
+        let signer = TestSigner::new();
+        let package =
+            |version: &str| signer.package(PackageKind::Plugin, "p", version, "https://x", b"");
+        let index = RemoteIndex {
+            name: "test".into(),
+            packages: vec![package("1.0.0"), package("1.4.2"), package("v2.0.0")],
+        };
+
+        let resolve = |req: &str| {
+            index
+                .resolve(PackageKind::Plugin, "p", &VersionReq::parse(req).unwrap())
+                .map(|p| p.version.clone())
+        };
+        assert_eq!(resolve("*").as_deref(), Some("v2.0.0"));
+        assert_eq!(resolve("^1").as_deref(), Some("1.4.2"));
+        assert_eq!(resolve("=1.0.0").as_deref(), Some("1.0.0"));
+        assert_eq!(resolve("^3"), None);
+        assert!(index
+            .resolve(PackageKind::Skill, "p", &VersionReq::any())
+            .is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_fetch_rejects_tampered_package() {
+        let temp = tempfile::tempdir().unwrap();
+        let signer = TestSigner::new();
+        let archive = temp.path().join("p.tar.gz");
+        std::fs::write(&archive, b"original").unwrap();
+        let url = url::Url::from_file_path(&archive).unwrap().to_string();
+        let index_url = write_index(
+            temp.path(),
+            vec![signer.package(PackageKind::Plugin, "p", "1.0.0", &url, b"original")],
+        );
+
+        let logger = Arc::new(AuditLogger::in_memory().unwrap());
+        let marketplace = RemoteMarketplace::new(signer.verifier())
+            .with_index(&index_url)
+            .with_audit_logger(Arc::clone(&logger));
+        assert_eq!(marketplace.refresh().await.unwrap(), 1);
+
+        let verified = marketplace
+            .fetch(PackageKind::Plugin, "p", &VersionReq::any())
+            .await
+            .unwrap();
+        assert_eq!(verified.bytes(), b"original");
+
+        // 서명 이후 아카이브가 바뀌면 거부하고 감사 로그에 기록
+        std::fs::write(&archive, b"tampered").unwrap();
+        let err = marketplace
+            .fetch(PackageKind::Plugin, "p", &VersionReq::any())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::PermissionDenied(_)));
+
+        let entries = logger.recent(10).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].result, AuditResult::Denied);
+        assert_eq!(entries[0].target.as_deref(), Some("p"));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_plain_http() {
+        let marketplace = RemoteMarketplace::new(PackageVerifier::new());
+        let err = marketplace
+            .get_bytes("http://example.com/index.json")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidInput(_)));
+    }
+}
@@ -36,8 +36,8 @@
 //! ## 플러그인 타입
 //!
 //! 1. **Native Plugin**: Rust로 작성된 컴파일타임 플러그인
-//! 2. **WASM Plugin**: WebAssembly 기반 런타임 플러그인 (향후)
-//! 3. **Script Plugin**: JavaScript/Lua 스크립트 플러그인 (향후)
+//! 2. **WASM Plugin**: WebAssembly 기반 런타임 플러그인 (`wasm` 모듈, wasmtime WASI 샌드박스)
+//! 3. **Script Plugin**: 단일 파일 JavaScript 플러그인 (`script` 모듈, 내장 QuickJS 샌드박스)
 //!
 //! ## 예시
 //!
@@ -67,12 +67,18 @@ mod events;
 mod store;
 mod discovery;
 mod installer;
+mod wasm;
+mod script;
+mod marketplace;
 
-pub use traits::{Plugin, PluginContext, PluginCapability};
+pub use traits::{Plugin, PluginContext, PluginCapability, PluginEvents};
 pub use registry::PluginRegistry;
 pub use manager::{PluginManager, PluginManagerConfig, PluginSummary};
-pub use manifest::{PluginManifest, PluginVersion, PluginDependency, PluginProvides};
+pub use manifest::{PluginManifest, PluginVersion, PluginDependency, PluginProvides, PluginType, VersionReq};
 pub use events::{PluginEvent, PluginEventHandler, EventBus};
 pub use store::{PluginStore, InstalledPlugin};
 pub use discovery::{PluginDiscovery, DiscoveredPlugin, PluginScope};
 pub use installer::{PluginInstaller, PluginSource};
+pub use wasm::{WasmCapabilities, WasmGrant, WasmPlugin, WasmRuntime, WIT_INTERFACE};
+pub use script::{is_script_plugin, ScriptPlugin, ScriptRuntime, SCRIPT_EXTENSIONS};
+pub use marketplace::{PackageKind, PackageVerifier, RemoteIndex, RemoteMarketplace, RemotePackage, VerifiedPackage};
@@ -0,0 +1,894 @@
+//! Script Plugin - 단일 파일 JavaScript 플러그인
+//!
+//! Rust 컴파일 없이 파일 하나로 도구나 스킬을 추가합니다.
+//! `plugins/` 디렉토리의 `*.js`, `*.mjs`, `*.cjs` 파일이 각각 플러그인 하나가 되며,
+//! 호출마다 내장 QuickJS(rquickjs) 런타임의 새 인스턴스에서 실행됩니다.
+//!
+//! ## 파일 형식
+//! ```js
+//! // .forgecode/plugins/word-count.js
+//! module.exports = {
+//!   name: "word_count",                 // 생략 시 파일 이름
+//!   kind: "tool",                       // "tool" (기본) | "skill"
+//!   description: "Count words in a file",
+//!   permissions: ["read:src"],          // forge.* 호스트 함수가 접근할 수 있는 범위
+//!   schema: {                           // 도구 입력 JSON Schema
+//!     type: "object",
+//!     properties: { path: { type: "string" } },
+//!     required: ["path"],
+//!   },
+//!   async run(input, ctx) {             // ctx = { workingDir }
+//!     const text = forge.readFile(input.path);
+//!     return `${text.split(/\s+/).filter(Boolean).length} words`;
+//!   },
+//! };
+//! ```
+//!
+//! ESM(`export default { ... }`)도 지원합니다. `run`이 문자열이 아닌 값을 반환하면 JSON으로
+//! 직렬화하고, 예외는 도구 오류가 됩니다. 스킬의 `run(args)`은 에이전트에 전달할 프롬프트를
+//! 반환합니다 (`args` = `{ raw, arguments, positional }`).
+//!
+//! ## Capability 샌드박스
+//!
+//! 스크립트에는 `require`, `import`, 프로세스, 네트워크가 없고, 파일 접근은 `forge` 전역의
+//! 호스트 함수로만 가능합니다. 호스트 함수는 `permissions`에 선언된 범위만 허용하며,
+//! 권한 문법은 WASM 플러그인과 같습니다 ([`WasmCapabilities`], `network:`는 지원하지 않음).
+//!
+//! | 함수 | 필요한 권한 |
+//! |------|-------------|
+//! | `forge.readFile(path)` | `read:<dir>` 또는 `write:<dir>` |
+//! | `forge.listDir(path)` | `read:<dir>` 또는 `write:<dir>` |
+//! | `forge.writeFile(path, text)` | `write:<dir>` |
+//!
+//! 도구/스킬 호출마다 선언된 권한 전부가 PermissionService 검사를 받습니다.
+//! 로드 시 `describe`(스크립트 최상위 코드)는 권한 없이 실행됩니다.
+//! 프로젝트 범위의 스크립트는 저장소를 받는 것만으로 실행되지 않도록
+//! [`PluginManager::load_scripts`](super::PluginManager::load_scripts)가 신뢰 확인 후 로드합니다.
+//!
+//! `console.log`는 `tracing` debug 로그로 보내지므로 결과와 섞이지 않습니다.
+
+use super::manifest::{PluginManifest, PluginProvides, PluginType};
+use super::traits::{Plugin, PluginCapability, PluginContext};
+use super::wasm::{grant_dir, WasmCapabilities};
+use crate::skill::{Skill, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput};
+use async_trait::async_trait;
+use forge_foundation::{
+    Error, PermissionAction, PermissionStatus, Result, Tool, ToolContext, ToolMeta, ToolResult,
+};
+use rquickjs::{Context, Ctx, Function, Module, Runtime};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::any::Any;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// 스크립트 플러그인 파일 확장자
+pub const SCRIPT_EXTENSIONS: &[&str] = &["js", "mjs", "cjs"];
+
+/// 스크립트 평가 전에 실행하는 prelude
+///
+/// `forge` 호스트 함수와 `console`을 정의하고, `__forgeStart(mode, payload)`가
+/// `describe` / `run` 결과를 `__forgeReply`에 `{ ok }` 또는 `{ err }`로 남깁니다.
+const PRELUDE: &str = r#"
+globalThis.__forgeCall = (op, args) => {
+  const reply = JSON.parse(__forgeHost(op, JSON.stringify(args)));
+  if ("err" in reply) throw new Error(reply.err);
+  return reply.ok;
+};
+globalThis.forge = Object.freeze({
+  readFile: (path) => __forgeCall("readFile", { path: String(path) }),
+  writeFile: (path, text) => __forgeCall("writeFile", { path: String(path), text: String(text) }),
+  listDir: (path) => __forgeCall("listDir", { path: String(path ?? ".") }),
+});
+const log = (...args) => __forgeLog(args.map(String).join(" "));
+globalThis.console = { log, info: log, warn: log, error: log, debug: log };
+globalThis.__forgeReply = undefined;
+globalThis.__forgeStart = (mode, payload) => {
+  const def = __forgeDef;
+  const settle = (promise) => promise.then(
+    (ok) => { __forgeReply = { ok }; },
+    (e) => { __forgeReply = { err: e && e.message ? e.message : String(e) }; },
+  );
+  if (mode === "describe") {
+    return settle(Promise.resolve({
+      name: def.name, kind: def.kind, description: def.description,
+      schema: def.schema, usage: def.usage, permissions: def.permissions,
+    }));
+  }
+  settle(Promise.resolve().then(() => {
+    if (typeof def.run !== "function") throw new Error("script does not export run()");
+    return def.run(payload.input, payload.context);
+  }).then((out) => typeof out === "string" ? out : JSON.stringify(out ?? null, null, 2)));
+};
+"#;
+
+// ============================================================================
+// ScriptRuntime - 실행 제한
+// ============================================================================
+
+/// JavaScript 실행 제한
+#[derive(Debug, Clone)]
+pub struct ScriptRuntime {
+    /// 호출당 제한 시간
+    pub timeout: Duration,
+
+    /// 힙 최대 크기 (bytes, None이면 제한 없음)
+    pub max_memory_bytes: Option<usize>,
+}
+
+impl Default for ScriptRuntime {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            max_memory_bytes: Some(64 * 1024 * 1024),
+        }
+    }
+}
+
+impl ScriptRuntime {
+    /// 기본 설정으로 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 호출당 제한 시간 설정
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 메모리 상한 설정
+    pub fn with_max_memory(mut self, bytes: Option<usize>) -> Self {
+        self.max_memory_bytes = bytes;
+        self
+    }
+}
+
+// ============================================================================
+// ScriptSandbox - capability 검사 호스트 함수
+// ============================================================================
+
+/// 스크립트 권한 파싱 (`read:` / `write:`만 허용)
+fn script_capabilities(permissions: &[String], base: &Path) -> WasmCapabilities {
+    let (files, other): (Vec<String>, Vec<String>) = permissions
+        .iter()
+        .cloned()
+        .partition(|p| p.starts_with("read:") || p.starts_with("write:"));
+    for permission in other {
+        warn!("Script plugins cannot use '{}' permissions", permission);
+    }
+    WasmCapabilities::from_permissions(&files, base)
+}
+
+/// `forge.*` 호스트 함수 구현
+#[derive(Debug, Clone)]
+struct ScriptSandbox {
+    capabilities: WasmCapabilities,
+    working_dir: PathBuf,
+}
+
+impl ScriptSandbox {
+    /// `__forgeHost(op, args)` 처리 (응답은 `{"ok": ...}` 또는 `{"err": "..."}` JSON)
+    fn dispatch(&self, op: &str, args: &str) -> String {
+        let result = serde_json::from_str::<Value>(args)
+            .map_err(|e| e.to_string())
+            .and_then(|args| self.call(op, &args));
+        match result {
+            Ok(value) => json!({ "ok": value }),
+            Err(message) => json!({ "err": message }),
+        }
+        .to_string()
+    }
+
+    fn call(&self, op: &str, args: &Value) -> std::result::Result<Value, String> {
+        let path = args["path"]
+            .as_str()
+            .ok_or_else(|| format!("{}: path is required", op))?;
+        match op {
+            "readFile" => {
+                let resolved = self.authorize(path, false)?;
+                std::fs::read_to_string(&resolved)
+                    .map(Value::String)
+                    .map_err(|e| format!("{}: {}", path, e))
+            }
+            "writeFile" => {
+                let resolved = self.authorize(path, true)?;
+                let text = args["text"].as_str().unwrap_or_default();
+                std::fs::write(&resolved, text)
+                    .map(|_| Value::Null)
+                    .map_err(|e| format!("{}: {}", path, e))
+            }
+            "listDir" => {
+                let resolved = self.authorize(path, false)?;
+                let entries = std::fs::read_dir(&resolved).map_err(|e| format!("{}: {}", path, e))?;
+                let mut names: Vec<String> = entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.file_name().to_string_lossy().to_string())
+                    .collect();
+                names.sort();
+                Ok(json!(names))
+            }
+            other => Err(format!("Unknown host function: {}", other)),
+        }
+    }
+
+    /// 경로가 선언된 권한 범위 안인지 확인 (작업 디렉토리 기준 절대 경로)
+    fn authorize(&self, path: &str, write: bool) -> std::result::Result<PathBuf, String> {
+        let resolved = grant_dir(&self.working_dir, path);
+        if self.capabilities.allows_path(&resolved, write) {
+            Ok(resolved)
+        } else {
+            Err(format!(
+                "Plugin has no {} permission for {}",
+                if write { "write" } else { "read" },
+                path
+            ))
+        }
+    }
+}
+
+// ============================================================================
+// ScriptHost - 스크립트 호출
+// ============================================================================
+
+/// 스크립트 파일 하나에 대한 호출 핸들
+#[derive(Debug, Clone)]
+struct ScriptHost {
+    plugin_id: String,
+    script: PathBuf,
+    runtime: ScriptRuntime,
+    working_dir: PathBuf,
+    /// 스크립트가 선언한 권한 (`describe` 후 설정)
+    capabilities: WasmCapabilities,
+}
+
+/// prelude 응답
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ScriptReply {
+    Ok(Value),
+    Err(String),
+}
+
+impl ScriptHost {
+    /// ESM 여부 (`.mjs`, 또는 `export` 문이 있는 `.js`)
+    fn is_module(&self, source: &str) -> bool {
+        match self.script.extension().and_then(|e| e.to_str()) {
+            Some("mjs") => true,
+            Some("cjs") => false,
+            _ => source
+                .lines()
+                .any(|line| line.trim_start().starts_with("export ")),
+        }
+    }
+
+    /// 새 런타임에서 스크립트 실행 (`sandboxed`면 권한 없이, Err(message) = 스크립트 예외)
+    async fn call(
+        &self,
+        mode: &str,
+        payload: Value,
+        sandboxed: bool,
+    ) -> Result<std::result::Result<Value, String>> {
+        let source = tokio::fs::read_to_string(&self.script).await.map_err(|e| {
+            Error::Plugin(format!(
+                "Failed to read script {}: {}",
+                self.script.display(),
+                e
+            ))
+        })?;
+        let sandbox = ScriptSandbox {
+            capabilities: if sandboxed {
+                WasmCapabilities::default()
+            } else {
+                self.capabilities.clone()
+            },
+            working_dir: self.working_dir.clone(),
+        };
+
+        debug!("Running script plugin {}: {}", self.plugin_id, mode);
+        let host = self.clone();
+        let mode = mode.to_string();
+        tokio::task::spawn_blocking(move || host.evaluate(&source, &mode, &payload, sandbox))
+            .await
+            .map_err(|e| Error::Plugin(format!("Script plugin task failed: {}", e)))?
+    }
+
+    /// 스크립트 평가 후 `mode` 수행 (blocking)
+    fn evaluate(
+        &self,
+        source: &str,
+        mode: &str,
+        payload: &Value,
+        sandbox: ScriptSandbox,
+    ) -> Result<std::result::Result<Value, String>> {
+        let setup_error =
+            |e: rquickjs::Error| Error::Plugin(format!("Failed to start JavaScript runtime: {}", e));
+        let runtime = Runtime::new().map_err(setup_error)?;
+        if let Some(limit) = self.runtime.max_memory_bytes {
+            runtime.set_memory_limit(limit);
+        }
+        let timed_out = Arc::new(AtomicBool::new(false));
+        let deadline = Instant::now() + self.runtime.timeout;
+        let flag = Arc::clone(&timed_out);
+        runtime.set_interrupt_handler(Some(Box::new(move || {
+            let expired = Instant::now() >= deadline;
+            if expired {
+                flag.store(true, Ordering::Relaxed);
+            }
+            expired
+        })));
+        let context = Context::full(&runtime).map_err(setup_error)?;
+
+        let plugin_id = self.plugin_id.clone();
+        let module = self.is_module(source);
+        let started = context.with(|ctx| {
+            let start = || -> rquickjs::Result<()> {
+                let globals = ctx.globals();
+                globals.set(
+                    "__forgeHost",
+                    Function::new(ctx.clone(), move |op: String, args: String| {
+                        sandbox.dispatch(&op, &args)
+                    })?,
+                )?;
+                globals.set(
+                    "__forgeLog",
+                    Function::new(ctx.clone(), move |message: String| {
+                        debug!("[{}] {}", plugin_id, message);
+                    })?,
+                )?;
+                ctx.eval::<(), _>(PRELUDE)?;
+
+                let def: rquickjs::Value = if module {
+                    let (module, promise) =
+                        Module::declare(ctx.clone(), self.plugin_id.as_str(), source)?.eval()?;
+                    promise.finish::<()>()?;
+                    module.get("default")?
+                } else {
+                    ctx.eval(format!(
+                        "(function () {{\nconst module = {{ exports: {{}} }};\n(function (module, exports) {{\n{}\n}})(module, module.exports);\nreturn module.exports;\n}})()",
+                        source
+                    ))?
+                };
+                globals.set("__forgeDef", def)?;
+                ctx.eval::<(), _>(format!("__forgeStart({}, {})", json!(mode), payload))
+            };
+            start().map_err(|e| exception_message(&ctx, e))
+        });
+
+        // async run()의 Promise 처리
+        while runtime.is_job_pending() {
+            if runtime.execute_pending_job().is_err() {
+                break;
+            }
+        }
+
+        if timed_out.load(Ordering::Relaxed) {
+            return Err(Error::Timeout(format!(
+                "Script plugin {} timed out after {}s",
+                self.plugin_id,
+                self.runtime.timeout.as_secs()
+            )));
+        }
+        if let Err(message) = started {
+            return Ok(Err(message));
+        }
+
+        let reply = context.with(|ctx| {
+            ctx.eval::<String, _>("JSON.stringify(__forgeReply === undefined ? null : __forgeReply)")
+                .map_err(|e| exception_message(&ctx, e))
+        });
+        match reply.map(|reply| serde_json::from_str::<Option<ScriptReply>>(&reply)) {
+            Ok(Ok(Some(ScriptReply::Ok(value)))) => Ok(Ok(value)),
+            Ok(Ok(Some(ScriptReply::Err(message)))) => Ok(Err(message)),
+            Ok(Ok(None)) => Ok(Err("script did not settle its result".to_string())),
+            Ok(Err(e)) => Err(Error::Plugin(format!(
+                "Script plugin {} returned an invalid reply: {}",
+                self.plugin_id, e
+            ))),
+            Err(message) => Ok(Err(message)),
+        }
+    }
+
+    /// `run(input, ctx)` 호출 결과 문자열
+    async fn run(&self, input: Value) -> Result<std::result::Result<String, String>> {
+        let payload = json!({
+            "input": input,
+            "context": { "workingDir": self.working_dir },
+        });
+        Ok(self.call("run", payload, false).await?.map(|value| match value {
+            Value::String(s) => s,
+            other => other.to_string(),
+        }))
+    }
+
+    /// 스크립트가 정의한 도구/스킬 정보 (권한 없이 실행)
+    async fn describe(&self) -> Result<ScriptSpec> {
+        let value = self
+            .call("describe", Value::Null, true)
+            .await?
+            .map_err(|e| Error::Plugin(format!("Failed to load {}: {}", self.plugin_id, e)))?;
+        serde_json::from_value(value).map_err(|e| {
+            Error::Plugin(format!(
+                "Invalid definition exported by {}: {}",
+                self.plugin_id, e
+            ))
+        })
+    }
+
+    /// 선언된 권한 전부에 대한 확인 (거부 시 사유)
+    async fn authorize(&self, context: &dyn ToolContext, name: &str) -> Result<Option<String>> {
+        for action in self.capabilities.permission_actions() {
+            let allowed = match context.check_permission(name, &action).await {
+                PermissionStatus::Denied => false,
+                PermissionStatus::Unknown => {
+                    let description =
+                        format!("Script plugin {}: {}", self.plugin_id, action.description());
+                    context
+                        .request_permission(name, &description, action.clone())
+                        .await?
+                }
+                _ => true,
+            };
+            if !allowed {
+                return Ok(Some(format!(
+                    "Permission denied for plugin {} ({})",
+                    self.plugin_id,
+                    action.description()
+                )));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// rquickjs 오류를 스크립트 오류 메시지로 변환 (예외는 `message`)
+fn exception_message(ctx: &Ctx<'_>, err: rquickjs::Error) -> String {
+    if !matches!(err, rquickjs::Error::Exception) {
+        return err.to_string();
+    }
+    let caught = ctx.catch();
+    if let Some(exception) = caught.as_exception() {
+        return exception
+            .message()
+            .unwrap_or_else(|| "uncaught exception".to_string());
+    }
+    caught
+        .as_string()
+        .and_then(|s| s.to_string().ok())
+        .unwrap_or_else(|| "uncaught exception".to_string())
+}
+
+/// 스크립트가 export한 정의
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ScriptSpec {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    kind: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    schema: Option<Value>,
+    #[serde(default)]
+    usage: Option<String>,
+    #[serde(default)]
+    permissions: Vec<String>,
+}
+
+// ============================================================================
+// ScriptTool / ScriptSkill
+// ============================================================================
+
+/// 스크립트가 정의한 도구
+struct ScriptTool {
+    host: Arc<ScriptHost>,
+    name: String,
+    description: String,
+    schema: Value,
+}
+
+#[async_trait]
+impl Tool for ScriptTool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(&self.name)
+            .display_name(&self.name)
+            .description(&self.description)
+            .category("plugin")
+    }
+
+    fn schema(&self) -> Value {
+        self.schema.clone()
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        self.required_permissions(input).into_iter().next()
+    }
+
+    fn required_permissions(&self, _input: &Value) -> Vec<PermissionAction> {
+        self.host.capabilities.permission_actions()
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        if let Some(denied) = self.host.authorize(context, &self.name).await? {
+            return Ok(ToolResult::error(denied));
+        }
+        match self.host.run(input).await? {
+            Ok(output) => Ok(ToolResult::success(output)),
+            Err(message) => Ok(ToolResult::error(message)),
+        }
+    }
+}
+
+/// 스크립트가 정의한 스킬
+struct ScriptSkill {
+    host: Arc<ScriptHost>,
+    name: String,
+    description: String,
+    usage: String,
+}
+
+#[async_trait]
+impl Skill for ScriptSkill {
+    fn definition(&self) -> SkillDefinition {
+        SkillDefinition {
+            name: self.name.clone(),
+            command: format!("/{}", self.name),
+            description: self.description.clone(),
+            usage: self.usage.clone(),
+            arguments: vec![],
+            category: "plugin".to_string(),
+            user_invocable: true,
+        }
+    }
+
+    fn metadata(&self) -> SkillMetadata {
+        SkillMetadata {
+            name: self.name.clone(),
+            source: Some(self.host.plugin_id.clone()),
+            ..Default::default()
+        }
+    }
+
+    async fn execute(&self, ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
+        if let Some(denied) = self.host.authorize(ctx.tool_ctx, &self.name).await? {
+            return Ok(SkillOutput::failure(denied));
+        }
+        let args = json!({
+            "raw": input.raw_command,
+            "arguments": input.arguments,
+            "positional": input.positional_args,
+        });
+        match self.host.run(args).await? {
+            Ok(prompt) => Ok(SkillOutput::success(prompt)),
+            Err(message) => Ok(SkillOutput::failure(message)),
+        }
+    }
+}
+
+// ============================================================================
+// ScriptPlugin
+// ============================================================================
+
+/// 단일 파일 JavaScript 플러그인 (ID = `script.<파일 이름>`)
+pub struct ScriptPlugin {
+    manifest: PluginManifest,
+    host: Arc<ScriptHost>,
+    /// 로드 시 스크립트가 정의한 도구/스킬 이름
+    provides: RwLock<PluginProvides>,
+}
+
+impl ScriptPlugin {
+    /// 스크립트 파일로 생성 (파일 경로는 `working_dir` 기준으로 해석)
+    pub fn new(script: impl Into<PathBuf>, working_dir: &Path) -> Self {
+        let script = script.into();
+        let stem = script
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let manifest = PluginManifest::new(format!("script.{}", stem), &stem)
+            .with_type(PluginType::Script)
+            .with_metadata("main", script.display().to_string());
+
+        let host = ScriptHost {
+            plugin_id: manifest.id.clone(),
+            script,
+            runtime: ScriptRuntime::default(),
+            working_dir: working_dir.to_path_buf(),
+            capabilities: WasmCapabilities::default(),
+        };
+
+        Self {
+            manifest,
+            host: Arc::new(host),
+            provides: RwLock::new(PluginProvides::new()),
+        }
+    }
+
+    /// 실행 제한 변경
+    pub fn with_runtime(mut self, runtime: ScriptRuntime) -> Self {
+        Arc::make_mut(&mut self.host).runtime = runtime;
+        self
+    }
+
+    /// 스크립트 경로
+    pub fn script(&self) -> &Path {
+        &self.host.script
+    }
+
+    /// 스크립트를 로드해 도구 또는 스킬 생성
+    pub async fn instantiate(&self) -> Result<(Vec<Arc<dyn Tool>>, Vec<Arc<dyn Skill>>)> {
+        let spec = self.host.describe().await?;
+        let name = spec
+            .name
+            .filter(|n| !n.trim().is_empty())
+            .unwrap_or_else(|| self.manifest.name.clone());
+        let description = spec.description.unwrap_or_default();
+        let host = Arc::new(ScriptHost {
+            capabilities: script_capabilities(&spec.permissions, &self.host.working_dir),
+            ..(*self.host).clone()
+        });
+
+        let mut provides = PluginProvides::new();
+        let mut tools: Vec<Arc<dyn Tool>> = Vec::new();
+        let mut skills: Vec<Arc<dyn Skill>> = Vec::new();
+        match spec.kind.as_deref().unwrap_or("tool") {
+            "tool" => {
+                provides.tools.push(name.clone());
+                tools.push(Arc::new(ScriptTool {
+                    host,
+                    name,
+                    description,
+                    schema: spec
+                        .schema
+                        .unwrap_or_else(|| json!({ "type": "object", "properties": {} })),
+                }));
+            }
+            "skill" => {
+                provides.skills.push(name.clone());
+                skills.push(Arc::new(ScriptSkill {
+                    host,
+                    name,
+                    description,
+                    usage: spec.usage.unwrap_or_default(),
+                }));
+            }
+            other => {
+                return Err(Error::Plugin(format!(
+                    "Unknown kind '{}' in {} (expected \"tool\" or \"skill\")",
+                    other, self.manifest.id
+                )));
+            }
+        }
+
+        *self.provides.write().unwrap_or_else(|e| e.into_inner()) = provides;
+        Ok((tools, skills))
+    }
+}
+
+/// 스크립트 플러그인 파일인지 (확장자 기준)
+pub fn is_script_plugin(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| SCRIPT_EXTENSIONS.contains(&e))
+}
+
+#[async_trait]
+impl Plugin for ScriptPlugin {
+    fn manifest(&self) -> PluginManifest {
+        let mut manifest = self.manifest.clone();
+        manifest.provides = self
+            .provides
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        manifest
+    }
+
+    fn capabilities(&self) -> Vec<PluginCapability> {
+        vec![
+            PluginCapability::RegisterTools,
+            PluginCapability::RegisterSkills,
+        ]
+    }
+
+    async fn on_load(&self, ctx: &PluginContext) -> Result<()> {
+        let (tools, skills) = self.instantiate().await?;
+        for tool in tools {
+            ctx.register_tool(tool).await?;
+        }
+        for skill in skills {
+            ctx.register_skill(skill).await?;
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::PluginManager;
+    use forge_foundation::permission::PermissionService;
+
+    fn context(dir: &Path) -> crate::tool::RuntimeContext {
+        crate::tool::RuntimeContext::new(
+            "test",
+            dir.to_path_buf(),
+            Arc::new(PermissionService::with_auto_approve()),
+        )
+    }
+
+    #[test]
+    fn test_is_script_plugin() {
+        let temp = tempfile::tempdir().unwrap();
+        for file in ["a.js", "b.mjs", "c.lua", "d.json"] {
+            std::fs::write(temp.path().join(file), "").unwrap();
+        }
+
+        assert!(is_script_plugin(&temp.path().join("a.js")));
+        assert!(is_script_plugin(&temp.path().join("b.mjs")));
+        assert!(!is_script_plugin(&temp.path().join("c.lua")));
+        assert!(!is_script_plugin(&temp.path().join("d.json")));
+
+        let plugin = ScriptPlugin::new(temp.path().join("a.js"), temp.path());
+        assert_eq!(plugin.manifest().id, "script.a");
+        assert_eq!(plugin.manifest().plugin_type, PluginType::Script);
+    }
+
+    #[tokio::test]
+    async fn test_script_tool() {
+        let temp = tempfile::tempdir().unwrap();
+        let script = temp.path().join("shout.js");
+        std::fs::write(
+            &script,
+            r#"module.exports = {
+  description: "Upper-case text",
+  schema: { type: "object", properties: { text: { type: "string" } } },
+  run(input, ctx) {
+    console.log("noise");
+    if (!input.text) throw new Error("text is required");
+    return input.text.toUpperCase() + (ctx.workingDir ? "!" : "");
+  },
+};"#,
+        )
+        .unwrap();
+
+        let manager = PluginManager::new(temp.path().to_path_buf());
+        manager
+            .load(Arc::new(ScriptPlugin::new(&script, temp.path())))
+            .await
+            .unwrap();
+
+        let tool = manager.tool_registry().get("shout").await.unwrap();
+        assert_eq!(tool.meta().description, "Upper-case text");
+        assert!(tool.required_permission(&json!({})).is_none());
+
+        let ctx = context(temp.path());
+        let result = tool.execute(json!({"text": "hi"}), &ctx).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.output, "HI!");
+
+        let result = tool.execute(json!({}), &ctx).await.unwrap();
+        assert!(!result.success);
+        assert_eq!(result.error.as_deref(), Some("text is required"));
+    }
+
+    #[tokio::test]
+    async fn test_script_skill_esm() {
+        let temp = tempfile::tempdir().unwrap();
+        let script = temp.path().join("greet.mjs");
+        std::fs::write(
+            &script,
+            r#"export default {
+  name: "greet",
+  kind: "skill",
+  async run(args) { return { prompt: `Greet ${args.positional[0]}` }; },
+};"#,
+        )
+        .unwrap();
+
+        let plugin = ScriptPlugin::new(&script, temp.path());
+        let (tools, skills) = plugin.instantiate().await.unwrap();
+        assert!(tools.is_empty());
+        assert_eq!(plugin.manifest().provides.skills, ["greet"]);
+
+        let ctx = context(temp.path());
+        let tool_ctx: &dyn ToolContext = &ctx;
+        let output = skills[0]
+            .execute(
+                &SkillContext::new(tool_ctx, "test"),
+                SkillInput::new("/greet Ada").with_positional("Ada"),
+            )
+            .await
+            .unwrap();
+        assert!(output.success);
+        assert!(output.message.contains("Greet Ada"));
+    }
+
+    #[tokio::test]
+    async fn test_script_sandbox() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join("docs")).unwrap();
+        std::fs::write(temp.path().join("docs/a.txt"), "one two three").unwrap();
+        std::fs::write(temp.path().join("secret.txt"), "token").unwrap();
+        let script = temp.path().join("count.js");
+        std::fs::write(
+            &script,
+            r#"module.exports = {
+  permissions: ["read:docs", "network:example.com"],
+  run(input) {
+    if (input.require) return typeof require;
+    return forge.readFile(input.path).split(/\s+/).length;
+  },
+};"#,
+        )
+        .unwrap();
+
+        let plugin = ScriptPlugin::new(&script, temp.path());
+        let (tools, _) = plugin.instantiate().await.unwrap();
+        let tool = &tools[0];
+
+        // read: 권한만 PermissionService 검사 대상 (network:는 무시)
+        let actions = tool.required_permissions(&json!({}));
+        assert_eq!(actions.len(), 1);
+        assert!(matches!(actions[0], PermissionAction::FileReadSensitive { .. }));
+
+        let ctx = context(temp.path());
+        let result = tool.execute(json!({"path": "docs/a.txt"}), &ctx).await.unwrap();
+        assert_eq!(result.output, "3");
+
+        for path in ["secret.txt", "docs/../secret.txt", "/etc/passwd"] {
+            let result = tool.execute(json!({ "path": path }), &ctx).await.unwrap();
+            assert!(!result.success);
+            assert!(result.error.unwrap().contains("no read permission"));
+        }
+
+        let result = tool.execute(json!({"require": true}), &ctx).await.unwrap();
+        assert_eq!(result.output, "undefined");
+
+        // 권한이 거부되면 실행하지 않음
+        let denied = crate::tool::RuntimeContext::new(
+            "test",
+            temp.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let result = tool
+            .execute(json!({"path": "docs/a.txt"}), &denied)
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Permission denied"));
+    }
+
+    #[tokio::test]
+    async fn test_script_timeout() {
+        let temp = tempfile::tempdir().unwrap();
+        let script = temp.path().join("spin.js");
+        std::fs::write(&script, "module.exports = { run() { for (;;) {} } };").unwrap();
+
+        let plugin = ScriptPlugin::new(&script, temp.path())
+            .with_runtime(ScriptRuntime::new().with_timeout(Duration::from_millis(200)));
+        let (tools, _) = plugin.instantiate().await.unwrap();
+        let err = tools[0]
+            .execute(json!({}), &context(temp.path()))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("timed out"));
+    }
+}
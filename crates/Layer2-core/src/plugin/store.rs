@@ -48,6 +48,14 @@ pub struct InstalledPlugin {
     /// 작성자
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
+
+    /// 원격 인덱스 설치 시 버전 요구사항 (업데이트 범위, 예: "^1.2")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_req: Option<String>,
+
+    /// 원격 인덱스 설치 시 검증된 패키지 sha256
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrity: Option<String>,
 }
 
 fn default_true() -> bool {
@@ -68,6 +76,8 @@ impl InstalledPlugin {
             path: path.into(),
             description: None,
             author: None,
+            version_req: None,
+            integrity: None,
         }
     }
 
@@ -88,6 +98,18 @@ impl InstalledPlugin {
         self.author = Some(author.into());
         self
     }
+
+    /// 버전 요구사항 설정
+    pub fn with_version_req(mut self, version_req: impl Into<String>) -> Self {
+        self.version_req = Some(version_req.into());
+        self
+    }
+
+    /// 패키지 sha256 설정
+    pub fn with_integrity(mut self, sha256: impl Into<String>) -> Self {
+        self.integrity = Some(sha256.into());
+        self
+    }
 }
 
 // ============================================================================
@@ -1,23 +1,48 @@
 //! Plugin traits - 핵심 플러그인 인터페이스
-
-use super::events::EventBus;
+//!
+//! ## Capability 샌드박스
+//!
+//! 플러그인은 `Plugin::capabilities()`에 선언한 기능만 사용할 수 있습니다.
+//! `PluginManager`는 플러그인마다 선언된 capability로 범위가 제한된 `PluginContext`를 만들고,
+//! 선언되지 않은 작업은 `Error::PermissionDenied`로 거부하며 감사 로그에 남깁니다.
+//!
+//! | 작업 | 필요한 capability |
+//! |------|-------------------|
+//! | `register_tool` | `RegisterTools` |
+//! | `register_skill` | `RegisterSkills` |
+//! | `register_language` | `RegisterLanguages` |
+//! | `register_provider` | `RegisterProviders` |
+//! | `register_executor` | `RegisterExecutors` |
+//! | `events().publish` | `PublishEvents` |
+//! | `events().subscribe` / `register_handler` | `HandleEvents` |
+//! | `set_config` | `ManageConfig` (읽기는 항상 허용) |
+//! | `save_state` | `PersistState` |
+//! | `modify_system_prompt` 적용 | `ModifySystemPrompt` |
+
+use super::events::{EventBus, EventType, PluginEvent, PluginEventHandler};
 use super::manifest::PluginManifest;
+use crate::repomap::LanguageAnalyzer;
 use crate::skill::Skill;
 use crate::tool::Tool;
 use async_trait::async_trait;
-use forge_foundation::Result;
+use forge_foundation::audit::{AuditAction, AuditEntry, AuditLogger, AuditResult};
+use forge_foundation::{Error, Result};
+use forge_task::Executor;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
+use tracing::warn;
 
 // ============================================================================
 // PluginCapability - 플러그인 기능 열거
 // ============================================================================
 
 /// 플러그인이 제공할 수 있는 기능
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PluginCapability {
     /// 새로운 Tool 등록
     RegisterTools,
@@ -25,12 +50,24 @@ pub enum PluginCapability {
     /// 새로운 Skill 등록
     RegisterSkills,
 
+    /// RepoMap 언어 분석기 등록
+    RegisterLanguages,
+
+    /// LLM 프로바이더 등록 (Gateway)
+    RegisterProviders,
+
+    /// Task executor 등록 (TaskManager)
+    RegisterExecutors,
+
     /// 시스템 프롬프트 수정
     ModifySystemPrompt,
 
-    /// 이벤트 핸들링
+    /// 이벤트 핸들링 (구독, 핸들러 등록)
     HandleEvents,
 
+    /// 이벤트 발행
+    PublishEvents,
+
     /// 설정 관리
     ManageConfig,
 
@@ -38,18 +75,104 @@ pub enum PluginCapability {
     PersistState,
 }
 
+impl PluginCapability {
+    /// 모든 capability (호스트 컨텍스트용)
+    pub const ALL: [PluginCapability; 10] = [
+        Self::RegisterTools,
+        Self::RegisterSkills,
+        Self::RegisterLanguages,
+        Self::RegisterProviders,
+        Self::RegisterExecutors,
+        Self::ModifySystemPrompt,
+        Self::HandleEvents,
+        Self::PublishEvents,
+        Self::ManageConfig,
+        Self::PersistState,
+    ];
+
+    /// 문자열 표현 (`plugin.json`, 감사 로그)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::RegisterTools => "register_tools",
+            Self::RegisterSkills => "register_skills",
+            Self::RegisterLanguages => "register_languages",
+            Self::RegisterProviders => "register_providers",
+            Self::RegisterExecutors => "register_executors",
+            Self::ModifySystemPrompt => "modify_system_prompt",
+            Self::HandleEvents => "handle_events",
+            Self::PublishEvents => "publish_events",
+            Self::ManageConfig => "manage_config",
+            Self::PersistState => "persist_state",
+        }
+    }
+}
+
+impl std::fmt::Display for PluginCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// capability 거부를 감사 로그에 기록
+pub(crate) async fn audit_denied(
+    logger: Option<&AuditLogger>,
+    plugin_id: &str,
+    capability: PluginCapability,
+    operation: &str,
+) {
+    warn!(
+        "Denied plugin {} '{}': capability {} not declared",
+        plugin_id, operation, capability
+    );
+
+    if let Some(logger) = logger {
+        let entry = AuditEntry::new(AuditAction::PermissionDenied, plugin_id)
+            .with_result(AuditResult::Denied)
+            .with_target(capability.as_str())
+            .with_description(format!(
+                "Plugin {} attempted '{}' without the {} capability",
+                plugin_id, operation, capability
+            ))
+            .with_tag("plugin");
+        if let Err(e) = logger.log(entry).await {
+            warn!("Failed to record audit entry: {}", e);
+        }
+    }
+}
+
 // ============================================================================
 // PluginContext - 플러그인에 제공되는 컨텍스트
 // ============================================================================
 
 /// 플러그인 컨텍스트 - 플러그인이 ForgeCode와 상호작용하는 인터페이스
+///
+/// `new`는 모든 capability를 가진 호스트 컨텍스트이고,
+/// `for_plugin`으로 플러그인이 선언한 capability만 허용하도록 제한합니다.
 pub struct PluginContext {
+    /// 플러그인 ID (감사 로그 actor)
+    plugin_id: String,
+
+    /// 허용된 capability
+    capabilities: HashSet<PluginCapability>,
+
+    /// 거부 기록용 감사 로거
+    audit_logger: Option<Arc<AuditLogger>>,
+
     /// 등록할 Tool 목록
     registered_tools: RwLock<Vec<Arc<dyn Tool>>>,
 
     /// 등록할 Skill 목록
     registered_skills: RwLock<Vec<Arc<dyn Skill>>>,
 
+    /// 등록할 언어 분석기 목록
+    registered_languages: RwLock<Vec<Arc<dyn LanguageAnalyzer>>>,
+
+    /// 등록할 LLM 프로바이더 목록 (Gateway 이름, 프로바이더)
+    registered_providers: RwLock<Vec<(String, Arc<dyn forge_provider::Provider>)>>,
+
+    /// 등록할 Task executor 목록
+    registered_executors: RwLock<Vec<Arc<dyn Executor>>>,
+
     /// 이벤트 버스 (이벤트 발행/구독)
     event_bus: Arc<EventBus>,
 
@@ -67,8 +190,14 @@ impl PluginContext {
     /// 새 컨텍스트 생성
     pub fn new(event_bus: Arc<EventBus>, working_dir: std::path::PathBuf) -> Self {
         Self {
+            plugin_id: "host".to_string(),
+            capabilities: PluginCapability::ALL.into_iter().collect(),
+            audit_logger: None,
             registered_tools: RwLock::new(Vec::new()),
             registered_skills: RwLock::new(Vec::new()),
+            registered_languages: RwLock::new(Vec::new()),
+            registered_providers: RwLock::new(Vec::new()),
+            registered_executors: RwLock::new(Vec::new()),
             event_bus,
             config: RwLock::new(HashMap::new()),
             state: RwLock::new(HashMap::new()),
@@ -76,14 +205,63 @@ impl PluginContext {
         }
     }
 
+    /// 플러그인 범위로 제한 (선언된 capability만 허용)
+    pub fn for_plugin(
+        mut self,
+        plugin_id: impl Into<String>,
+        capabilities: impl IntoIterator<Item = PluginCapability>,
+    ) -> Self {
+        self.plugin_id = plugin_id.into();
+        self.capabilities = capabilities.into_iter().collect();
+        self
+    }
+
+    /// 거부된 작업을 기록할 감사 로거 설정
+    pub fn with_audit_logger(mut self, logger: Option<Arc<AuditLogger>>) -> Self {
+        self.audit_logger = logger;
+        self
+    }
+
+    /// 플러그인 ID
+    pub fn plugin_id(&self) -> &str {
+        &self.plugin_id
+    }
+
+    /// capability 허용 여부
+    pub fn has_capability(&self, capability: PluginCapability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    /// capability 확인 (없으면 감사 로그 기록 후 거부)
+    async fn require(&self, capability: PluginCapability, operation: &str) -> Result<()> {
+        if self.has_capability(capability) {
+            return Ok(());
+        }
+
+        audit_denied(
+            self.audit_logger.as_deref(),
+            &self.plugin_id,
+            capability,
+            operation,
+        )
+        .await;
+        Err(Error::PermissionDenied(format!(
+            "Plugin {} is not allowed to {} (requires capability '{}')",
+            self.plugin_id, operation, capability
+        )))
+    }
+
     // ========================================================================
     // Tool 등록
     // ========================================================================
 
-    /// Tool 등록
-    pub async fn register_tool(&self, tool: Arc<dyn Tool>) {
+    /// Tool 등록 (`RegisterTools`)
+    pub async fn register_tool(&self, tool: Arc<dyn Tool>) -> Result<()> {
+        self.require(PluginCapability::RegisterTools, "register tools")
+            .await?;
         let mut tools = self.registered_tools.write().await;
         tools.push(tool);
+        Ok(())
     }
 
     /// 등록된 Tool 목록 반환
@@ -96,10 +274,13 @@ impl PluginContext {
     // Skill 등록
     // ========================================================================
 
-    /// Skill 등록
-    pub async fn register_skill(&self, skill: Arc<dyn Skill>) {
+    /// Skill 등록 (`RegisterSkills`)
+    pub async fn register_skill(&self, skill: Arc<dyn Skill>) -> Result<()> {
+        self.require(PluginCapability::RegisterSkills, "register skills")
+            .await?;
         let mut skills = self.registered_skills.write().await;
         skills.push(skill);
+        Ok(())
     }
 
     /// 등록된 Skill 목록 반환
@@ -108,13 +289,70 @@ impl PluginContext {
         std::mem::take(&mut *skills)
     }
 
+    // ========================================================================
+    // 언어 분석기 등록
+    // ========================================================================
+
+    /// RepoMap 언어 분석기 등록 (같은 확장자의 내장 분석기보다 우선, `RegisterLanguages`)
+    pub async fn register_language(&self, analyzer: Arc<dyn LanguageAnalyzer>) -> Result<()> {
+        self.require(PluginCapability::RegisterLanguages, "register languages")
+            .await?;
+        let mut languages = self.registered_languages.write().await;
+        languages.push(analyzer);
+        Ok(())
+    }
+
+    /// 등록된 언어 분석기 목록 반환
+    pub async fn take_languages(&self) -> Vec<Arc<dyn LanguageAnalyzer>> {
+        let mut languages = self.registered_languages.write().await;
+        std::mem::take(&mut *languages)
+    }
+
+    // ========================================================================
+    // 프로바이더 / Executor 등록
+    // ========================================================================
+
+    /// LLM 프로바이더 등록 (`Gateway`에 `name`으로 추가, `RegisterProviders`)
+    pub async fn register_provider(
+        &self,
+        name: impl Into<String>,
+        provider: Arc<dyn forge_provider::Provider>,
+    ) -> Result<()> {
+        self.require(PluginCapability::RegisterProviders, "register providers")
+            .await?;
+        let mut providers = self.registered_providers.write().await;
+        providers.push((name.into(), provider));
+        Ok(())
+    }
+
+    /// 등록된 프로바이더 목록 반환
+    pub async fn take_providers(&self) -> Vec<(String, Arc<dyn forge_provider::Provider>)> {
+        let mut providers = self.registered_providers.write().await;
+        std::mem::take(&mut *providers)
+    }
+
+    /// Task executor 등록 (`ExecutionMode::Custom { executor: name() }`, `RegisterExecutors`)
+    pub async fn register_executor(&self, executor: Arc<dyn Executor>) -> Result<()> {
+        self.require(PluginCapability::RegisterExecutors, "register executors")
+            .await?;
+        let mut executors = self.registered_executors.write().await;
+        executors.push(executor);
+        Ok(())
+    }
+
+    /// 등록된 executor 목록 반환
+    pub async fn take_executors(&self) -> Vec<Arc<dyn Executor>> {
+        let mut executors = self.registered_executors.write().await;
+        std::mem::take(&mut *executors)
+    }
+
     // ========================================================================
     // 이벤트
     // ========================================================================
 
-    /// 이벤트 버스 접근
-    pub fn event_bus(&self) -> &Arc<EventBus> {
-        &self.event_bus
+    /// 이벤트 버스 핸들 (발행은 `PublishEvents`, 구독은 `HandleEvents`)
+    pub fn events(&self) -> PluginEvents<'_> {
+        PluginEvents { ctx: self }
     }
 
     // ========================================================================
@@ -127,10 +365,13 @@ impl PluginContext {
         config.get(key).cloned()
     }
 
-    /// 설정 값 설정
-    pub async fn set_config(&self, key: impl Into<String>, value: Value) {
+    /// 설정 값 설정 (`ManageConfig`)
+    pub async fn set_config(&self, key: impl Into<String>, value: Value) -> Result<()> {
+        self.require(PluginCapability::ManageConfig, "change config")
+            .await?;
         let mut config = self.config.write().await;
         config.insert(key.into(), value);
+        Ok(())
     }
 
     /// 모든 설정 반환
@@ -139,20 +380,26 @@ impl PluginContext {
         config.clone()
     }
 
-    /// 설정 로드 (외부에서 주입)
-    pub async fn load_config(&self, config: HashMap<String, Value>) {
+    /// 설정 로드 (외부에서 주입, `ManageConfig`)
+    pub async fn load_config(&self, config: HashMap<String, Value>) -> Result<()> {
+        self.require(PluginCapability::ManageConfig, "replace config")
+            .await?;
         let mut current = self.config.write().await;
         *current = config;
+        Ok(())
     }
 
     // ========================================================================
     // 상태 저장
     // ========================================================================
 
-    /// 상태 저장
-    pub async fn save_state(&self, key: impl Into<String>, value: Value) {
+    /// 상태 저장 (`PersistState`)
+    pub async fn save_state(&self, key: impl Into<String>, value: Value) -> Result<()> {
+        self.require(PluginCapability::PersistState, "persist state")
+            .await?;
         let mut state = self.state.write().await;
         state.insert(key.into(), value);
+        Ok(())
     }
 
     /// 상태 로드
@@ -177,6 +424,46 @@ impl PluginContext {
     }
 }
 
+// ============================================================================
+// PluginEvents - capability 범위의 이벤트 버스 핸들
+// ============================================================================
+
+/// 플러그인용 이벤트 버스 핸들
+pub struct PluginEvents<'a> {
+    ctx: &'a PluginContext,
+}
+
+impl PluginEvents<'_> {
+    /// 이벤트 발행 (source = 플러그인 ID, `PublishEvents`)
+    pub async fn publish(&self, event_type: EventType, data: Value) -> Result<()> {
+        self.ctx
+            .require(PluginCapability::PublishEvents, "publish events")
+            .await?;
+        self.ctx
+            .event_bus
+            .publish(PluginEvent::new(event_type, data, &self.ctx.plugin_id))
+            .await;
+        Ok(())
+    }
+
+    /// 이벤트 구독 (`HandleEvents`)
+    pub async fn subscribe(&self) -> Result<broadcast::Receiver<PluginEvent>> {
+        self.ctx
+            .require(PluginCapability::HandleEvents, "subscribe to events")
+            .await?;
+        Ok(self.ctx.event_bus.subscribe())
+    }
+
+    /// 이벤트 핸들러 등록 (`HandleEvents`)
+    pub async fn register_handler(&self, handler: Arc<dyn PluginEventHandler>) -> Result<()> {
+        self.ctx
+            .require(PluginCapability::HandleEvents, "register event handlers")
+            .await?;
+        self.ctx.event_bus.register_handler(handler).await;
+        Ok(())
+    }
+}
+
 // ============================================================================
 // Plugin Trait - 모든 플러그인이 구현해야 하는 인터페이스
 // ============================================================================
@@ -190,6 +477,8 @@ pub trait Plugin: Send + Sync {
     fn manifest(&self) -> PluginManifest;
 
     /// 플러그인이 제공하는 기능 목록
+    ///
+    /// 선언하지 않은 기능은 `PluginContext`에서 거부됩니다.
     fn capabilities(&self) -> Vec<PluginCapability> {
         vec![]
     }
@@ -296,9 +585,45 @@ mod tests {
         let event_bus = Arc::new(EventBus::new());
         let ctx = PluginContext::new(event_bus, std::path::PathBuf::from("/tmp"));
 
-        ctx.set_config("key", serde_json::json!("value")).await;
+        ctx.set_config("key", serde_json::json!("value"))
+            .await
+            .unwrap();
         let value = ctx.get_config("key").await;
 
         assert_eq!(value, Some(serde_json::json!("value")));
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_capability_enforcement() {
+        let logger = Arc::new(AuditLogger::in_memory().unwrap());
+        let event_bus = Arc::new(EventBus::new());
+        let ctx = PluginContext::new(Arc::clone(&event_bus), std::path::PathBuf::from("/tmp"))
+            .for_plugin("test.plugin", [PluginCapability::HandleEvents])
+            .with_audit_logger(Some(Arc::clone(&logger)));
+
+        // 읽기 전용 설정, 구독은 허용
+        assert!(ctx.get_config("key").await.is_none());
+        assert!(ctx.events().subscribe().await.is_ok());
+
+        // 선언하지 않은 작업은 거부
+        let err = ctx
+            .set_config("key", serde_json::json!(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::PermissionDenied(_)));
+        assert!(ctx
+            .events()
+            .publish(EventType::Custom, Value::Null)
+            .await
+            .is_err());
+        assert!(ctx.save_state("key", Value::Null).await.is_err());
+        assert!(ctx.get_config("key").await.is_none());
+        assert!(event_bus.history().await.is_empty());
+
+        let denied = logger.recent(10).await.unwrap();
+        assert_eq!(denied.len(), 3);
+        assert!(denied.iter().all(|e| e.actor == "test.plugin"
+            && e.action == AuditAction::PermissionDenied
+            && e.result == AuditResult::Denied));
+    }
 }
@@ -0,0 +1,1077 @@
+//! WASM Plugin - WebAssembly 플러그인 호스트
+//!
+//! `plugin.json`의 `"type": "wasm"` 플러그인을 내장 wasmtime WASI 샌드박스에서 실행합니다.
+//! 모듈은 [`WIT_INTERFACE`] (`forge:plugin/plugin` world)를 구현한 컴포넌트이며,
+//! 호스트는 컴파일된 컴포넌트를 캐시하고 호출마다 새 인스턴스에서 export 함수를 호출합니다.
+//!
+//! ## 인터페이스
+//! - `describe() -> string`: 제공하는 도구/스킬 (JSON)
+//! - `call-tool(name, input) -> result<string, string>`: 도구 실행
+//! - `run-skill(name, args) -> result<string, string>`: 스킬 실행 (에이전트에 전달할 프롬프트)
+//!
+//! ## Capability 샌드박스
+//!
+//! 모듈은 `permissions`로 허용된 디렉토리와 호스트만 사용할 수 있고,
+//! 도구/스킬 호출마다 같은 권한 전부가 PermissionService 검사를 받습니다.
+//! `describe()`는 권한 검사 전(로드 시)에 실행되므로 capability 없이 실행합니다.
+//!
+//! | `permissions` | WASI | PermissionAction |
+//! |---------------|------|------------------|
+//! | `read:<path>` | 읽기 전용 preopen | `FileReadSensitive` |
+//! | `write:<path>` | 읽기/쓰기 preopen | `FileWrite` |
+//! | `network:<host>` | 호스트 주소로의 TCP 연결만 허용 | `Network` |
+//!
+//! 경로는 작업 디렉토리 기준이며 게스트에서도 같은 절대 경로로 보입니다.
+//! 네트워크 호스트는 전역 `EgressPolicy`가 허용할 때만 호출 시점에 주소로 해석되며,
+//! 와일드카드 호스트는 주소로 제한할 수 없으므로 허용하지 않습니다.
+//! WASI에는 프로세스 실행이 없으므로 `execute:` 권한은 무시됩니다.
+//!
+//! ## Hot reload
+//!
+//! `PluginManager::reload_wasm_plugins`가 모듈 파일의 수정 시각을 확인하고,
+//! 바뀐 플러그인의 도구/스킬을 `DynamicToolRegistry::sync_provider`로 교체합니다.
+//!
+//! ```ignore
+//! let plugin = WasmPlugin::from_discovered(&discovered, &working_dir)?;
+//! manager.load(Arc::new(plugin)).await?;
+//! manager.start_wasm_hot_reload(Duration::from_secs(2));
+//! ```
+
+use super::discovery::DiscoveredPlugin;
+use super::manifest::{PluginManifest, PluginProvides, PluginType};
+use super::traits::{Plugin, PluginCapability, PluginContext};
+use crate::skill::{Skill, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput};
+use async_trait::async_trait;
+use forge_foundation::{
+    egress_policy, Error, PermissionAction, PermissionStatus, Result, Tool, ToolContext, ToolMeta,
+    ToolResult,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::any::Any;
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{Duration, SystemTime};
+use tracing::{debug, warn};
+use wasmtime::component::{Component as WasmComponent, Instance, Linker, ResourceTable};
+use wasmtime::{Config, Engine, Store, StoreLimits, StoreLimitsBuilder, Trap};
+use wasmtime_wasi::{DirPerms, FilePerms, SocketAddrUse, WasiCtx, WasiCtxBuilder, WasiView};
+
+/// 플러그인 WIT 인터페이스 (`forge:plugin@0.1.0`)
+pub const WIT_INTERFACE: &str = include_str!("wit/forge-plugin.wit");
+
+/// `main`이 없을 때 사용하는 모듈 파일 이름
+const DEFAULT_MODULE: &str = "plugin.wasm";
+
+/// epoch 증가 간격 (호출 제한 시간의 해상도)
+const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+// ============================================================================
+// WasmRuntime - 실행 제한
+// ============================================================================
+
+/// WASM 실행 제한
+#[derive(Debug, Clone)]
+pub struct WasmRuntime {
+    /// 호출당 제한 시간
+    pub timeout: Duration,
+
+    /// 선형 메모리 최대 크기 (bytes, None이면 제한 없음)
+    pub max_memory_bytes: Option<u64>,
+}
+
+impl Default for WasmRuntime {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            max_memory_bytes: Some(256 * 1024 * 1024),
+        }
+    }
+}
+
+impl WasmRuntime {
+    /// 기본 설정으로 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 호출당 제한 시간 설정
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 메모리 상한 설정
+    pub fn with_max_memory(mut self, bytes: Option<u64>) -> Self {
+        self.max_memory_bytes = bytes;
+        self
+    }
+}
+
+// ============================================================================
+// WasmCapabilities - 권한 → 샌드박스 매핑
+// ============================================================================
+
+/// 플러그인에 허용된 capability
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WasmGrant {
+    /// 디렉토리 읽기
+    Read(PathBuf),
+    /// 디렉토리 쓰기
+    Write(PathBuf),
+    /// 네트워크 (호스트)
+    Network(String),
+}
+
+/// 플러그인 capability 목록 (`plugin.json`의 `permissions`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WasmCapabilities {
+    grants: Vec<WasmGrant>,
+}
+
+impl WasmCapabilities {
+    /// `read:<path>`, `write:<path>`, `network:<host>` 권한 파싱 (경로는 `base` 기준)
+    ///
+    /// glob 경로(`src/**/*.rs`)는 와일드카드 앞 디렉토리까지 허용합니다.
+    pub fn from_permissions(permissions: &[String], base: &Path) -> Self {
+        let mut grants = Vec::new();
+
+        for permission in permissions {
+            let Some((kind, target)) = permission.split_once(':') else {
+                warn!(
+                    "Ignoring WASM plugin permission without a target: {}",
+                    permission
+                );
+                continue;
+            };
+            let target = target.trim();
+            match kind.trim() {
+                "read" => grants.push(WasmGrant::Read(grant_dir(base, target))),
+                "write" => grants.push(WasmGrant::Write(grant_dir(base, target))),
+                "network" => grants.push(WasmGrant::Network(target.to_string())),
+                other => warn!(
+                    "WASM plugins cannot use '{}' permissions: {}",
+                    other, permission
+                ),
+            }
+        }
+
+        Self { grants }
+    }
+
+    /// 허용 목록
+    pub fn grants(&self) -> &[WasmGrant] {
+        &self.grants
+    }
+
+    /// 네트워크 허용 여부
+    pub fn allows_network(&self) -> bool {
+        self.grants
+            .iter()
+            .any(|g| matches!(g, WasmGrant::Network(_)))
+    }
+
+    /// 경로가 `read:`/`write:` 범위 안인지 (`write`면 `write:`만 인정)
+    ///
+    /// 심볼릭 링크로 범위를 벗어나지 않도록 존재하는 부분은 실제 경로로 비교합니다.
+    pub fn allows_path(&self, path: &Path, write: bool) -> bool {
+        let path = real_path(path);
+        self.grants.iter().any(|grant| match grant {
+            WasmGrant::Write(dir) => path.starts_with(real_path(dir)),
+            WasmGrant::Read(dir) => !write && path.starts_with(real_path(dir)),
+            WasmGrant::Network(_) => false,
+        })
+    }
+
+    /// PermissionService에서 검사할 권한 액션 (도구/스킬 호출마다)
+    pub fn permission_actions(&self) -> Vec<PermissionAction> {
+        self.grants
+            .iter()
+            .map(|grant| match grant {
+                WasmGrant::Read(path) => PermissionAction::FileReadSensitive {
+                    path: path.display().to_string(),
+                },
+                WasmGrant::Write(path) => PermissionAction::FileWrite {
+                    path: path.display().to_string(),
+                },
+                WasmGrant::Network(host) => PermissionAction::Network { url: host.clone() },
+            })
+            .collect()
+    }
+
+    /// preopen 디렉토리와 권한 (`read:`는 읽기 전용, 같은 디렉토리에 `write:`가 있으면 읽기/쓰기)
+    fn preopens(&self) -> Vec<(&Path, DirPerms, FilePerms)> {
+        let mut preopens: Vec<(&Path, DirPerms, FilePerms)> = Vec::new();
+
+        for grant in &self.grants {
+            let (path, dir_perms, file_perms) = match grant {
+                WasmGrant::Read(path) => (path, DirPerms::READ, FilePerms::READ),
+                WasmGrant::Write(path) => (path, DirPerms::all(), FilePerms::all()),
+                WasmGrant::Network(_) => continue,
+            };
+            match preopens.iter_mut().find(|(p, _, _)| *p == path.as_path()) {
+                Some(existing) => {
+                    existing.1 |= dir_perms;
+                    existing.2 |= file_perms;
+                }
+                None => preopens.push((path, dir_perms, file_perms)),
+            }
+        }
+        preopens
+    }
+
+    /// 연결을 허용할 네트워크 호스트 (EgressPolicy가 차단하거나 와일드카드인 호스트 제외)
+    fn network_hosts(&self) -> Vec<&str> {
+        let policy = egress_policy();
+        self.grants
+            .iter()
+            .filter_map(|grant| match grant {
+                WasmGrant::Network(host) => Some(host.as_str()),
+                _ => None,
+            })
+            .filter(|host| {
+                if host.contains('*') {
+                    warn!("WASM plugins cannot use wildcard network hosts: {}", host);
+                    false
+                } else if !policy.allows(host) {
+                    warn!("Network access to {} is blocked by the egress policy", host);
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect()
+    }
+
+    /// 허용된 호스트의 주소 (호출 시점에 해석)
+    async fn allowed_addrs(&self) -> HashSet<IpAddr> {
+        let mut addrs = HashSet::new();
+        for host in self.network_hosts() {
+            if let Ok(ip) = host.trim_matches(['[', ']']).parse::<IpAddr>() {
+                addrs.insert(ip);
+                continue;
+            }
+            match tokio::net::lookup_host((host, 0)).await {
+                Ok(resolved) => addrs.extend(resolved.map(|addr| addr.ip())),
+                Err(e) => warn!("Failed to resolve WASM plugin host {}: {}", host, e),
+            }
+        }
+        addrs
+    }
+
+    /// WASI 컨텍스트 생성 (preopen + 주소 단위 네트워크 허용)
+    async fn wasi_ctx(&self, working_dir: &Path) -> Result<WasiCtx> {
+        let mut builder = WasiCtxBuilder::new();
+        builder.env("FORGE_WORKING_DIR", working_dir.display().to_string());
+
+        for (path, dir_perms, file_perms) in self.preopens() {
+            if !path.is_dir() {
+                warn!("Skipping missing WASM plugin directory: {}", path.display());
+                continue;
+            }
+            let guest = path.display().to_string();
+            builder
+                .preopened_dir(path, guest, dir_perms, file_perms)
+                .map_err(|e| Error::Plugin(format!("Failed to open {}: {}", path.display(), e)))?;
+        }
+
+        let addrs = self.allowed_addrs().await;
+        builder.allow_udp(false);
+        if !addrs.is_empty() {
+            builder
+                .allow_ip_name_lookup(true)
+                .socket_addr_check(move |addr, usage| {
+                    matches!(usage, SocketAddrUse::TcpConnect) && addrs.contains(&addr.ip())
+                });
+        }
+        Ok(builder.build())
+    }
+}
+
+/// 권한 경로를 절대 디렉토리로 변환 (glob은 와일드카드 앞까지)
+pub(super) fn grant_dir(base: &Path, target: &str) -> PathBuf {
+    let mut dir = PathBuf::new();
+    for component in Path::new(target).components() {
+        let part = component.as_os_str().to_string_lossy();
+        if part.contains(['*', '?', '[']) {
+            break;
+        }
+        dir.push(component);
+    }
+
+    let dir = if dir.is_absolute() {
+        dir
+    } else {
+        base.join(dir)
+    };
+
+    // `..`/`.` 정리 (존재하지 않는 경로도 허용해야 하므로 canonicalize 대신)
+    let mut normalized = PathBuf::new();
+    for component in dir.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// 존재하는 가장 가까운 상위 경로까지 canonicalize (나머지는 그대로 붙임)
+fn real_path(path: &Path) -> PathBuf {
+    let mut existing = path;
+    let mut rest = Vec::new();
+    loop {
+        if let Ok(real) = existing.canonicalize() {
+            return rest.iter().rev().fold(real, |acc, part| acc.join(part));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                rest.push(name.to_os_string());
+                existing = parent;
+            }
+            _ => return path.to_path_buf(),
+        }
+    }
+}
+
+// ============================================================================
+// WasmEngine - 공유 wasmtime 엔진
+// ============================================================================
+
+/// 인스턴스별 WASI 상태
+struct WasiState {
+    ctx: WasiCtx,
+    table: ResourceTable,
+    limits: StoreLimits,
+}
+
+impl WasiView for WasiState {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.ctx
+    }
+}
+
+/// 프로세스 전역 엔진 + WASI 링커
+struct WasmEngine {
+    engine: Engine,
+    linker: Linker<WasiState>,
+}
+
+/// 엔진 초기화 (최초 호출 시 epoch 증가 스레드 시작)
+fn wasm_engine() -> Result<&'static WasmEngine> {
+    static ENGINE: OnceLock<std::result::Result<WasmEngine, String>> = OnceLock::new();
+
+    ENGINE
+        .get_or_init(|| {
+            let mut config = Config::new();
+            config.async_support(true).epoch_interruption(true);
+            let engine = Engine::new(&config).map_err(|e| e.to_string())?;
+            let mut linker = Linker::new(&engine);
+            wasmtime_wasi::add_to_linker_async(&mut linker).map_err(|e| e.to_string())?;
+
+            let ticker = engine.clone();
+            std::thread::Builder::new()
+                .name("wasm-epoch".to_string())
+                .spawn(move || loop {
+                    std::thread::sleep(EPOCH_TICK);
+                    ticker.increment_epoch();
+                })
+                .map_err(|e| e.to_string())?;
+
+            Ok(WasmEngine { engine, linker })
+        })
+        .as_ref()
+        .map_err(|e| Error::Plugin(format!("WASM runtime unavailable: {}", e)))
+}
+
+// ============================================================================
+// WasmHost - 모듈 호출
+// ============================================================================
+
+/// 컴파일된 컴포넌트와 컴파일 시점의 모듈 수정 시각
+type CompiledComponent = (Option<SystemTime>, WasmComponent);
+
+/// 모듈 하나에 대한 호출 핸들 (도구/스킬이 공유)
+#[derive(Clone)]
+struct WasmHost {
+    plugin_id: String,
+    module: PathBuf,
+    runtime: WasmRuntime,
+    capabilities: WasmCapabilities,
+    working_dir: PathBuf,
+    /// 컴파일된 컴포넌트 (모듈이 바뀌면 다시 컴파일)
+    compiled: Arc<Mutex<Option<CompiledComponent>>>,
+}
+
+impl WasmHost {
+    /// 컴포넌트 컴파일 (모듈이 바뀌지 않았으면 캐시 사용)
+    fn component(&self, engine: &Engine) -> Result<WasmComponent> {
+        let mtime = module_mtime(&self.module);
+        let mut compiled = self.compiled.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((loaded, component)) = compiled.as_ref() {
+            if *loaded == mtime {
+                return Ok(component.clone());
+            }
+        }
+
+        debug!("Compiling WASM plugin {}", self.plugin_id);
+        let component = WasmComponent::from_file(engine, &self.module).map_err(|e| {
+            Error::Plugin(format!(
+                "Failed to compile WASM plugin {}: {:#}",
+                self.plugin_id, e
+            ))
+        })?;
+        *compiled = Some((mtime, component.clone()));
+        Ok(component)
+    }
+
+    /// 새 인스턴스 생성 (`sandboxed`면 capability 없이)
+    async fn instantiate(&self, sandboxed: bool) -> Result<(Store<WasiState>, Instance)> {
+        let wasm = wasm_engine()?;
+        let component = self.component(&wasm.engine)?;
+
+        let ctx = if sandboxed {
+            WasmCapabilities::default()
+                .wasi_ctx(&self.working_dir)
+                .await?
+        } else {
+            self.capabilities.wasi_ctx(&self.working_dir).await?
+        };
+        let mut limits = StoreLimitsBuilder::new();
+        if let Some(bytes) = self.runtime.max_memory_bytes {
+            limits = limits.memory_size(usize::try_from(bytes).unwrap_or(usize::MAX));
+        }
+        let mut store = Store::new(
+            &wasm.engine,
+            WasiState {
+                ctx,
+                table: ResourceTable::new(),
+                limits: limits.build(),
+            },
+        );
+        store.limiter(|state| &mut state.limits);
+        let ticks = self.runtime.timeout.as_millis() / EPOCH_TICK.as_millis();
+        store.set_epoch_deadline(u64::try_from(ticks).unwrap_or(u64::MAX).max(1));
+        store.epoch_deadline_trap();
+
+        let instance = wasm
+            .linker
+            .instantiate_async(&mut store, &component)
+            .await
+            .map_err(|e| self.call_error(e))?;
+        Ok((store, instance))
+    }
+
+    /// wasmtime 오류 변환 (epoch 인터럽트 = 제한 시간 초과)
+    fn call_error(&self, err: wasmtime::Error) -> Error {
+        if matches!(err.downcast_ref::<Trap>(), Some(Trap::Interrupt)) {
+            return Error::Timeout(format!(
+                "WASM plugin {} timed out after {}s",
+                self.plugin_id,
+                self.runtime.timeout.as_secs()
+            ));
+        }
+        Error::Plugin(format!("WASM plugin {} trapped: {:#}", self.plugin_id, err))
+    }
+
+    /// `result<string, string>` 함수 호출 (Err = 게스트가 반환한 오류)
+    async fn invoke_result(
+        &self,
+        func: &str,
+        name: &str,
+        payload: &Value,
+    ) -> Result<std::result::Result<String, String>> {
+        debug!(
+            "Invoking WASM plugin {}: {}({})",
+            self.plugin_id, func, name
+        );
+        let (mut store, instance) = self.instantiate(false).await?;
+        let payload = payload.to_string();
+        let (result,) = instance
+            .get_typed_func::<(&str, &str), (std::result::Result<String, String>,)>(
+                &mut store, func,
+            )
+            .map_err(|e| self.call_error(e))?
+            .call_async(&mut store, (name, payload.as_str()))
+            .await
+            .map_err(|e| self.call_error(e))?;
+        Ok(result)
+    }
+
+    /// 제공하는 도구/스킬 조회 (capability 없이 실행)
+    async fn describe(&self) -> Result<WasmDescription> {
+        let (mut store, instance) = self.instantiate(true).await?;
+        let (json,) = instance
+            .get_typed_func::<(), (String,)>(&mut store, "describe")
+            .map_err(|e| self.call_error(e))?
+            .call_async(&mut store, ())
+            .await
+            .map_err(|e| self.call_error(e))?;
+        serde_json::from_str(&json).map_err(|e| {
+            Error::Plugin(format!(
+                "Invalid describe() output from {}: {}",
+                self.plugin_id, e
+            ))
+        })
+    }
+
+    /// 플러그인 capability 전부에 대한 권한 확인 (거부 시 사유)
+    async fn authorize(&self, context: &dyn ToolContext, name: &str) -> Result<Option<String>> {
+        for action in self.capabilities.permission_actions() {
+            let allowed = match context.check_permission(name, &action).await {
+                PermissionStatus::Denied => false,
+                PermissionStatus::Unknown => {
+                    let description =
+                        format!("WASM plugin {}: {}", self.plugin_id, action.description());
+                    context
+                        .request_permission(name, &description, action.clone())
+                        .await?
+                }
+                _ => true,
+            };
+            if !allowed {
+                return Ok(Some(format!(
+                    "Permission denied for plugin {} ({})",
+                    self.plugin_id,
+                    action.description()
+                )));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// `describe()` 결과
+#[derive(Debug, Clone, Default, Deserialize)]
+struct WasmDescription {
+    #[serde(default)]
+    tools: Vec<WasmToolSpec>,
+    #[serde(default)]
+    skills: Vec<WasmSkillSpec>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct WasmToolSpec {
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default = "default_schema")]
+    schema: Value,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct WasmSkillSpec {
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    usage: String,
+}
+
+fn default_schema() -> Value {
+    json!({ "type": "object", "properties": {} })
+}
+
+// ============================================================================
+// WasmTool / WasmSkill
+// ============================================================================
+
+/// WASM 플러그인이 제공하는 도구
+struct WasmTool {
+    host: Arc<WasmHost>,
+    spec: WasmToolSpec,
+}
+
+#[async_trait]
+impl Tool for WasmTool {
+    fn name(&self) -> &str {
+        &self.spec.name
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(&self.spec.name)
+            .display_name(&self.spec.name)
+            .description(&self.spec.description)
+            .category("plugin")
+    }
+
+    fn schema(&self) -> Value {
+        self.spec.schema.clone()
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        self.required_permissions(input).into_iter().next()
+    }
+
+    fn required_permissions(&self, _input: &Value) -> Vec<PermissionAction> {
+        self.host.capabilities.permission_actions()
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        if let Some(denied) = self.host.authorize(context, &self.spec.name).await? {
+            return Ok(ToolResult::error(denied));
+        }
+        match self
+            .host
+            .invoke_result("call-tool", &self.spec.name, &input)
+            .await?
+        {
+            Ok(output) => Ok(ToolResult::success(output)),
+            Err(message) => Ok(ToolResult::error(message)),
+        }
+    }
+}
+
+/// WASM 플러그인이 제공하는 스킬
+struct WasmSkill {
+    host: Arc<WasmHost>,
+    spec: WasmSkillSpec,
+}
+
+#[async_trait]
+impl Skill for WasmSkill {
+    fn definition(&self) -> SkillDefinition {
+        SkillDefinition {
+            name: self.spec.name.clone(),
+            command: format!("/{}", self.spec.name),
+            description: self.spec.description.clone(),
+            usage: self.spec.usage.clone(),
+            arguments: vec![],
+            category: "plugin".to_string(),
+            user_invocable: true,
+        }
+    }
+
+    fn metadata(&self) -> SkillMetadata {
+        SkillMetadata {
+            name: self.spec.name.clone(),
+            source: Some(self.host.plugin_id.clone()),
+            ..Default::default()
+        }
+    }
+
+    async fn execute(&self, ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
+        if let Some(denied) = self.host.authorize(ctx.tool_ctx, &self.spec.name).await? {
+            return Ok(SkillOutput::failure(denied));
+        }
+        let args = json!({
+            "raw": input.raw_command,
+            "arguments": input.arguments,
+            "positional": input.positional_args,
+        });
+        match self
+            .host
+            .invoke_result("run-skill", &self.spec.name, &args)
+            .await?
+        {
+            Ok(prompt) => Ok(SkillOutput::success(prompt)),
+            Err(message) => Ok(SkillOutput::failure(message)),
+        }
+    }
+}
+
+// ============================================================================
+// WasmPlugin
+// ============================================================================
+
+/// WebAssembly 플러그인
+pub struct WasmPlugin {
+    manifest: PluginManifest,
+    host: Arc<WasmHost>,
+    /// 마지막 `describe()` 결과 (언로드 시 등록 해제할 이름)
+    provides: RwLock<PluginProvides>,
+    /// 마지막으로 로드한 모듈의 수정 시각
+    loaded_mtime: RwLock<Option<SystemTime>>,
+}
+
+impl WasmPlugin {
+    /// 매니페스트와 모듈 경로로 생성 (권한은 `working_dir` 기준)
+    pub fn new(manifest: PluginManifest, module: impl Into<PathBuf>, working_dir: &Path) -> Self {
+        let capabilities = WasmCapabilities::from_permissions(&manifest.permissions(), working_dir);
+        let host = WasmHost {
+            plugin_id: manifest.id.clone(),
+            module: module.into(),
+            runtime: WasmRuntime::default(),
+            capabilities,
+            working_dir: working_dir.to_path_buf(),
+            compiled: Arc::new(Mutex::new(None)),
+        };
+
+        Self {
+            provides: RwLock::new(manifest.provides.clone()),
+            manifest,
+            host: Arc::new(host),
+            loaded_mtime: RwLock::new(None),
+        }
+    }
+
+    /// 발견된 `"type": "wasm"` 플러그인으로 생성 (모듈 = `main`, 기본 `plugin.wasm`)
+    pub fn from_discovered(plugin: &DiscoveredPlugin, working_dir: &Path) -> Result<Self> {
+        if plugin.manifest.plugin_type != PluginType::Wasm {
+            return Err(Error::Plugin(format!(
+                "Plugin {} is not a WASM plugin",
+                plugin.manifest.id
+            )));
+        }
+        let main = plugin
+            .manifest
+            .metadata
+            .get("main")
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_MODULE);
+        let module = plugin.path.join(main);
+        if !module.is_file() {
+            return Err(Error::NotFound(format!("WASM module {}", module.display())));
+        }
+
+        Ok(Self::new(plugin.manifest.clone(), module, working_dir))
+    }
+
+    /// 실행 제한 변경
+    pub fn with_runtime(mut self, runtime: WasmRuntime) -> Self {
+        Arc::make_mut(&mut self.host).runtime = runtime;
+        self
+    }
+
+    /// 모듈 경로
+    pub fn module(&self) -> &Path {
+        &self.host.module
+    }
+
+    /// 허용된 capability
+    pub fn capabilities(&self) -> &WasmCapabilities {
+        &self.host.capabilities
+    }
+
+    /// 마지막 로드 이후 모듈 파일이 바뀌었는지
+    pub fn is_modified(&self) -> bool {
+        let current = module_mtime(&self.host.module);
+        let loaded = *self.loaded_mtime.read().unwrap_or_else(|e| e.into_inner());
+        current.is_some() && current != loaded
+    }
+
+    /// 모듈을 다시 읽어 도구/스킬 목록 생성
+    pub async fn instantiate(&self) -> Result<(Vec<Arc<dyn Tool>>, Vec<Arc<dyn Skill>>)> {
+        let mtime = module_mtime(&self.host.module);
+        let description = self.host.describe().await?;
+
+        let mut provides = PluginProvides::new();
+        let tools: Vec<Arc<dyn Tool>> = description
+            .tools
+            .into_iter()
+            .map(|spec| {
+                provides.tools.push(spec.name.clone());
+                Arc::new(WasmTool {
+                    host: Arc::clone(&self.host),
+                    spec,
+                }) as Arc<dyn Tool>
+            })
+            .collect();
+        let skills: Vec<Arc<dyn Skill>> = description
+            .skills
+            .into_iter()
+            .map(|spec| {
+                provides.skills.push(spec.name.clone());
+                Arc::new(WasmSkill {
+                    host: Arc::clone(&self.host),
+                    spec,
+                }) as Arc<dyn Skill>
+            })
+            .collect();
+
+        *self.provides.write().unwrap_or_else(|e| e.into_inner()) = provides;
+        *self.loaded_mtime.write().unwrap_or_else(|e| e.into_inner()) = mtime;
+        Ok((tools, skills))
+    }
+}
+
+fn module_mtime(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[async_trait]
+impl Plugin for WasmPlugin {
+    fn manifest(&self) -> PluginManifest {
+        let mut manifest = self.manifest.clone();
+        manifest.provides = self
+            .provides
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        manifest
+    }
+
+    fn capabilities(&self) -> Vec<PluginCapability> {
+        vec![
+            PluginCapability::RegisterTools,
+            PluginCapability::RegisterSkills,
+        ]
+    }
+
+    async fn on_load(&self, ctx: &PluginContext) -> Result<()> {
+        let (tools, skills) = self.instantiate().await?;
+        for tool in tools {
+            ctx.register_tool(tool).await?;
+        }
+        for skill in skills {
+            ctx.register_skill(skill).await?;
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_from_permissions() {
+        let permissions = vec![
+            "read:src/**/*.rs".to_string(),
+            "write:./target/../out".to_string(),
+            "network:api.example.com".to_string(),
+            "execute:git".to_string(),
+        ];
+        let caps = WasmCapabilities::from_permissions(&permissions, Path::new("/project"));
+
+        assert_eq!(
+            caps.grants(),
+            [
+                WasmGrant::Read(PathBuf::from("/project/src")),
+                WasmGrant::Write(PathBuf::from("/project/out")),
+                WasmGrant::Network("api.example.com".into()),
+            ]
+        );
+        assert_eq!(
+            caps.preopens(),
+            [
+                (Path::new("/project/src"), DirPerms::READ, FilePerms::READ),
+                (Path::new("/project/out"), DirPerms::all(), FilePerms::all()),
+            ]
+        );
+        assert_eq!(
+            caps.permission_actions()[1],
+            PermissionAction::FileWrite {
+                path: "/project/out".into()
+            }
+        );
+
+        // 같은 디렉토리의 read + write는 하나의 읽기/쓰기 preopen, 와일드카드 호스트는 제외
+        let permissions = vec![
+            "read:src".to_string(),
+            "write:src".to_string(),
+            "network:*.example.com".to_string(),
+            "network:api.example.com".to_string(),
+        ];
+        let caps = WasmCapabilities::from_permissions(&permissions, Path::new("/project"));
+        assert_eq!(
+            caps.preopens(),
+            [(Path::new("/project/src"), DirPerms::all(), FilePerms::all())]
+        );
+        assert_eq!(caps.network_hosts(), ["api.example.com"]);
+    }
+
+    /// `describe()`가 `describe`를 반환하고 `call-tool`은 입력을 그대로,
+    /// `run-skill`은 스킬 이름을 오류로 반환하는 테스트 컴포넌트 (WAT)
+    fn test_component(describe: &str) -> String {
+        let data = describe.replace('\\', "\\\\").replace('"', "\\\"");
+        format!(
+            r#"(component
+  (core module $m
+    (memory (export "memory") 1)
+    (global $heap (mut i32) (i32.const 4096))
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $p i32)
+      (local.set $p (global.get $heap))
+      (global.set $heap (i32.add (global.get $heap) (local.get 3)))
+      (local.get $p))
+    (data (i32.const 64) "{data}")
+    (func (export "describe") (result i32)
+      (i32.store (i32.const 0) (i32.const 64))
+      (i32.store (i32.const 4) (i32.const {len}))
+      (i32.const 0))
+    (func (export "call-tool") (param i32 i32 i32 i32) (result i32)
+      (i32.store8 (i32.const 16) (i32.const 0))
+      (i32.store (i32.const 20) (local.get 2))
+      (i32.store (i32.const 24) (local.get 3))
+      (i32.const 16))
+    (func (export "run-skill") (param i32 i32 i32 i32) (result i32)
+      (i32.store8 (i32.const 16) (i32.const 1))
+      (i32.store (i32.const 20) (local.get 0))
+      (i32.store (i32.const 24) (local.get 1))
+      (i32.const 16)))
+  (core instance $i (instantiate $m))
+  (func (export "describe") (result string)
+    (canon lift (core func $i "describe")
+      (memory (core memory $i "memory")) (realloc (core func $i "realloc"))))
+  (func (export "call-tool") (param "name" string) (param "input" string)
+    (result (result string (error string)))
+    (canon lift (core func $i "call-tool")
+      (memory (core memory $i "memory")) (realloc (core func $i "realloc"))))
+  (func (export "run-skill") (param "name" string) (param "args" string)
+    (result (result string (error string)))
+    (canon lift (core func $i "run-skill")
+      (memory (core memory $i "memory")) (realloc (core func $i "realloc")))))"#,
+            len = describe.len()
+        )
+    }
+
+    /// 테스트 컴포넌트를 모듈 파일로 저장 (wasmtime `wat` 기능으로 텍스트 형식도 로드됨)
+    fn write_module(path: &Path, describe: &str) {
+        std::fs::write(path, test_component(describe)).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_wasm_plugin_load_and_call() {
+        use crate::plugin::PluginManager;
+        use forge_foundation::permission::PermissionService;
+
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir(temp.path().join("out")).unwrap();
+        let module = temp.path().join("plugin.wasm");
+        write_module(
+            &module,
+            r#"{"tools":[{"name":"wasm_echo","description":"Echo"}],"skills":[{"name":"wasm-skill"}]}"#,
+        );
+
+        let manifest = PluginManifest::new("test.wasm", "Test WASM")
+            .with_type(PluginType::Wasm)
+            .with_metadata("permission_0", "write:out");
+        let plugin = WasmPlugin::new(manifest, &module, temp.path());
+        assert!(plugin.is_modified());
+
+        let manager = PluginManager::new(temp.path().to_path_buf());
+        manager.load(Arc::new(plugin)).await.unwrap();
+        assert_eq!(
+            manager
+                .registry()
+                .get_manifest("test.wasm")
+                .await
+                .unwrap()
+                .provides
+                .tools,
+            ["wasm_echo"]
+        );
+
+        let tool = manager.tool_registry().get("wasm_echo").await.unwrap();
+        let write = PermissionAction::FileWrite {
+            path: temp.path().join("out").display().to_string(),
+        };
+        assert_eq!(
+            tool.required_permissions(&json!({})),
+            std::slice::from_ref(&write)
+        );
+
+        // 권한이 없으면 실행 전 거부
+        let permissions = Arc::new(PermissionService::new());
+        let ctx = crate::tool::RuntimeContext::new(
+            "test",
+            temp.path().to_path_buf(),
+            Arc::clone(&permissions),
+        );
+        let result = tool.execute(json!({"text": "hi"}), &ctx).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Permission denied"));
+
+        let skill = manager
+            .skill_registry()
+            .get_by_name("wasm-skill")
+            .await
+            .unwrap();
+        let tool_ctx: &dyn ToolContext = &ctx;
+        let output = skill
+            .execute(
+                &SkillContext::new(tool_ctx, "test"),
+                SkillInput::new("/wasm-skill"),
+            )
+            .await
+            .unwrap();
+        assert!(!output.success);
+
+        permissions.grant_session("wasm_echo", write);
+        let result = tool.execute(json!({"text": "hi"}), &ctx).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.output, r#"{"text":"hi"}"#);
+
+        manager.unload("test.wasm").await.unwrap();
+        assert!(manager.tool_registry().get("wasm_echo").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_wasm_hot_reload() {
+        use crate::plugin::PluginManager;
+
+        let temp = tempfile::tempdir().unwrap();
+        let module = temp.path().join("plugin.wasm");
+        write_module(&module, r#"{"tools":[{"name":"wasm_v1"}]}"#);
+
+        let plugin = WasmPlugin::new(PluginManifest::new("hot.wasm", "Hot"), &module, temp.path());
+        let manager = PluginManager::new(temp.path().to_path_buf());
+        manager.load(Arc::new(plugin)).await.unwrap();
+        assert!(manager.reload_wasm_plugins().await.is_empty());
+
+        // 새 모듈: v1 제거, v2 등록
+        write_module(&module, r#"{"tools":[{"name":"wasm_v2"}]}"#);
+        let file = std::fs::File::options().write(true).open(&module).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+
+        assert_eq!(manager.reload_wasm_plugins().await, ["hot.wasm"]);
+        assert!(manager.tool_registry().get("wasm_v1").await.is_none());
+        assert!(manager.tool_registry().get("wasm_v2").await.is_some());
+        assert_eq!(
+            manager
+                .registry()
+                .get_manifest("hot.wasm")
+                .await
+                .unwrap()
+                .provides
+                .tools,
+            ["wasm_v2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wasm_timeout() {
+        let temp = tempfile::tempdir().unwrap();
+        let module = temp.path().join("plugin.wasm");
+        std::fs::write(
+            &module,
+            r#"(component
+  (core module $m
+    (memory (export "memory") 1)
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32) (i32.const 0))
+    (func (export "describe") (result i32) (loop $l (br $l)) (i32.const 0)))
+  (core instance $i (instantiate $m))
+  (func (export "describe") (result string)
+    (canon lift (core func $i "describe")
+      (memory (core memory $i "memory")) (realloc (core func $i "realloc")))))"#,
+        )
+        .unwrap();
+
+        let plugin = WasmPlugin::new(
+            PluginManifest::new("spin.wasm", "Spin"),
+            &module,
+            temp.path(),
+        )
+        .with_runtime(WasmRuntime::new().with_timeout(Duration::from_millis(100)));
+        assert!(matches!(plugin.instantiate().await, Err(Error::Timeout(_))));
+    }
+}
@@ -1,12 +1,17 @@
 //! Dynamic Registry - 동적으로 변경 가능한 레지스트리
 
 use super::entry::{EntryMetadata, EntryState, RegistryEntry};
-use super::snapshot::{HotReloadConfig, HotReloadResult, HotReloadState, RegistrySnapshot, SnapshotManager, SnapshotInfo};
+use super::journal::SnapshotJournal;
+use super::snapshot::{
+    HotReloadConfig, HotReloadResult, HotReloadState, RegistrySnapshot, SnapshotHistoryEntry,
+    SnapshotInfo, SnapshotManager,
+};
 use super::traits::{RegistryEvent, RegistryEventHandler};
 use crate::skill::Skill;
 use crate::tool::Tool;
 use forge_foundation::Result;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::{broadcast, RwLock};
@@ -39,6 +44,15 @@ pub struct DynamicRegistry<T: ?Sized + Send + Sync> {
     /// 현재 Hot-reload 상태
     hot_reload_state: RwLock<HotReloadState>,
 
+    /// 스냅샷 저널 (옵션, 세션 외부 이력 조회용)
+    journal: RwLock<Option<SnapshotJournal>>,
+
+    /// 상태 ID 시퀀스
+    state_seq: AtomicU64,
+
+    /// 현재 상태 ID - 변경이 일어날 때마다 새로 발급
+    state_id: RwLock<String>,
+
     /// 레지스트리 이름 (디버깅용)
     name: String,
 }
@@ -55,10 +69,68 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
             snapshot_manager: RwLock::new(SnapshotManager::new()),
             hot_reload_config: RwLock::new(HotReloadConfig::default()),
             hot_reload_state: RwLock::new(HotReloadState::Idle),
+            journal: RwLock::new(None),
+            state_seq: AtomicU64::new(0),
+            state_id: RwLock::new(Self::format_state_id(0)),
             name: name.into(),
         }
     }
 
+    /// 저널과 함께 생성
+    pub fn with_journal(self, journal: SnapshotJournal) -> Self {
+        Self {
+            journal: RwLock::new(Some(journal)),
+            ..self
+        }
+    }
+
+    /// 저널 설정 (None이면 해제)
+    pub async fn set_journal(&self, journal: Option<SnapshotJournal>) {
+        *self.journal.write().await = journal;
+    }
+
+    /// 현재 상태 ID
+    pub async fn current_state_id(&self) -> String {
+        self.state_id.read().await.clone()
+    }
+
+    fn format_state_id(seq: u64) -> String {
+        format!("{}-{}", chrono::Utc::now().format("%Y%m%dT%H%M%S%.3f"), seq)
+    }
+
+    // ========================================================================
+    // 자동 스냅샷
+    // ========================================================================
+
+    /// 변경 직전: 현재 상태를 (현재 상태 ID로) 링 버퍼에 저장
+    async fn before_mutation(&self, action: &str) {
+        if !self.snapshot_manager.read().await.is_auto_snapshot_enabled() {
+            return;
+        }
+
+        let id = self.current_state_id().await;
+        let snapshot = self
+            .create_snapshot(&id)
+            .await
+            .with_description(format!("before {}", action));
+        self.snapshot_manager.write().await.save(snapshot);
+    }
+
+    /// 변경 직후: 새 상태 ID 발급 후 저널에 기록
+    async fn after_mutation(&self, action: &str) {
+        let seq = self.state_seq.fetch_add(1, Ordering::Relaxed) + 1;
+        let id = Self::format_state_id(seq);
+        *self.state_id.write().await = id.clone();
+
+        let journal = self.journal.read().await.clone();
+        if let Some(journal) = journal {
+            let record = self.create_snapshot(&id).await.with_description(action).record();
+            if let Err(e) = journal.append(&record) {
+                warn!("[{}] Failed to write snapshot journal: {}", self.name, e);
+            }
+        }
+    }
+
     // ========================================================================
     // 등록 / 해제
     // ========================================================================
@@ -66,6 +138,16 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
     /// 항목 등록
     pub async fn register(&self, key: impl Into<String>, value: Arc<T>, metadata: EntryMetadata) -> Result<()> {
         let key = key.into();
+        let action = format!("register '{}'", key);
+
+        self.before_mutation(&action).await;
+        self.insert_entry(key, value, metadata).await?;
+        self.after_mutation(&action).await;
+        Ok(())
+    }
+
+    /// 항목 저장 (스냅샷 없이)
+    async fn insert_entry(&self, key: String, value: Arc<T>, metadata: EntryMetadata) -> Result<()> {
         let category = metadata.category.clone();
         let version = metadata.version.clone();
         let provider = metadata.provider.clone();
@@ -109,6 +191,19 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
 
     /// 항목 등록 해제
     pub async fn unregister(&self, key: &str) -> Option<Arc<T>> {
+        if !self.contains(key).await {
+            return None;
+        }
+
+        let action = format!("unregister '{}'", key);
+        self.before_mutation(&action).await;
+        let removed = self.remove_entry(key).await;
+        self.after_mutation(&action).await;
+        removed
+    }
+
+    /// 항목 제거 (스냅샷 없이)
+    async fn remove_entry(&self, key: &str) -> Option<Arc<T>> {
         let entry = {
             let mut entries = self.entries.write().await;
             entries.remove(key)
@@ -134,7 +229,21 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
 
     /// 항목 교체
     pub async fn replace(&self, key: &str, new_value: Arc<T>, new_version: impl Into<String>) -> Option<Arc<T>> {
+        if !self.contains(key).await {
+            return None;
+        }
+
         let new_version = new_version.into();
+        let action = format!("replace '{}' -> v{}", key, new_version);
+
+        self.before_mutation(&action).await;
+        let old_value = self.replace_entry(key, new_value, new_version).await;
+        self.after_mutation(&action).await;
+        old_value
+    }
+
+    /// 항목 교체 (스냅샷 없이)
+    async fn replace_entry(&self, key: &str, new_value: Arc<T>, new_version: String) -> Option<Arc<T>> {
         let old_version;
 
         let old_value = {
@@ -259,37 +368,61 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
 
     /// 항목 활성화
     pub async fn enable(&self, key: &str) -> bool {
-        let mut entries = self.entries.write().await;
-        if let Some(entry) = entries.get_mut(key) {
-            entry.enable();
-            self.emit_event(RegistryEvent::Enabled { key: key.into() }).await;
-            true
-        } else {
-            false
+        if !self.contains(key).await {
+            return false;
         }
+
+        let action = format!("enable '{}'", key);
+        self.before_mutation(&action).await;
+        {
+            let mut entries = self.entries.write().await;
+            match entries.get_mut(key) {
+                Some(entry) => entry.enable(),
+                None => return false,
+            }
+        }
+        self.emit_event(RegistryEvent::Enabled { key: key.into() }).await;
+        self.after_mutation(&action).await;
+        true
     }
 
     /// 항목 비활성화
     pub async fn disable(&self, key: &str) -> bool {
-        let mut entries = self.entries.write().await;
-        if let Some(entry) = entries.get_mut(key) {
-            entry.disable();
-            self.emit_event(RegistryEvent::Disabled { key: key.into() }).await;
-            true
-        } else {
-            false
+        if !self.contains(key).await {
+            return false;
         }
+
+        let action = format!("disable '{}'", key);
+        self.before_mutation(&action).await;
+        {
+            let mut entries = self.entries.write().await;
+            match entries.get_mut(key) {
+                Some(entry) => entry.disable(),
+                None => return false,
+            }
+        }
+        self.emit_event(RegistryEvent::Disabled { key: key.into() }).await;
+        self.after_mutation(&action).await;
+        true
     }
 
     /// 항목 상태 변경
     pub async fn set_state(&self, key: &str, state: EntryState) -> bool {
-        let mut entries = self.entries.write().await;
-        if let Some(entry) = entries.get_mut(key) {
-            entry.metadata.set_state(state);
-            true
-        } else {
-            false
+        if !self.contains(key).await {
+            return false;
+        }
+
+        let action = format!("set '{}' {}", key, state);
+        self.before_mutation(&action).await;
+        {
+            let mut entries = self.entries.write().await;
+            match entries.get_mut(key) {
+                Some(entry) => entry.metadata.set_state(state),
+                None => return false,
+            }
         }
+        self.after_mutation(&action).await;
+        true
     }
 
     // ========================================================================
@@ -298,6 +431,13 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
 
     /// 전체 클리어
     pub async fn clear(&self) {
+        self.before_mutation("clear").await;
+        self.clear_entries().await;
+        self.after_mutation("clear").await;
+    }
+
+    /// 전체 클리어 (스냅샷 없이)
+    async fn clear_entries(&self) {
         {
             let mut entries = self.entries.write().await;
             entries.clear();
@@ -313,6 +453,9 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
 
     /// 여러 항목 한번에 등록
     pub async fn register_bulk(&self, items: Vec<(String, Arc<T>, EntryMetadata)>) -> Result<()> {
+        let action = format!("register {} entries", items.len());
+        self.before_mutation(&action).await;
+
         let mut added = Vec::new();
 
         for (key, value, metadata) in items {
@@ -336,6 +479,7 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
             replaced: vec![],
         }).await;
 
+        self.after_mutation(&action).await;
         Ok(())
     }
 
@@ -461,6 +605,8 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
             replaced: vec![format!("restored_from_{}", snapshot_id)],
         }).await;
 
+        self.after_mutation(&format!("restore '{}'", snapshot_id)).await;
+
         info!("[{}] Restored from snapshot '{}'", self.name, snapshot_id);
         Ok(())
     }
@@ -502,6 +648,37 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
         manager.list()
     }
 
+    /// 변경 이력 - 각 스냅샷에서 다음 상태까지의 diff (마지막은 현재 상태 기준)
+    pub async fn history(&self) -> Vec<SnapshotHistoryEntry> {
+        let id = self.current_state_id().await;
+        let current = self.create_snapshot(id).await.record();
+
+        let manager = self.snapshot_manager.read().await;
+        manager.history(&current)
+    }
+
+    /// 지정한 스냅샷 시점으로 롤백 (그 이후 스냅샷은 폐기)
+    pub async fn rollback_to(&self, snapshot_id: &str) -> Result<()> {
+        let snapshot = self.snapshot_manager.write().await.take_from(snapshot_id);
+
+        match snapshot {
+            Some(snapshot) => {
+                info!("[{}] Rolling back to snapshot '{}'", self.name, snapshot_id);
+                self.restore_snapshot(snapshot).await
+            }
+            None => Err(forge_foundation::Error::NotFound(format!(
+                "Snapshot '{}' not found", snapshot_id
+            ))),
+        }
+    }
+
+    /// 자동 스냅샷 설정 (링 버퍼 크기, 활성화 여부)
+    pub async fn configure_snapshots(&self, max_snapshots: usize, auto_snapshot: bool) {
+        let mut manager = self.snapshot_manager.write().await;
+        manager.set_max_snapshots(max_snapshots);
+        manager.set_auto_snapshot(auto_snapshot);
+    }
+
     // ========================================================================
     // Hot-reload
     // ========================================================================
@@ -549,15 +726,15 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
         let old_keys: Vec<String> = self.keys().await;
         let new_keys: Vec<String> = new_items.iter().map(|(k, _, _)| k.clone()).collect();
 
-        // 3. 클리어 후 새 항목 등록
-        self.clear().await;
+        // 3. 클리어 후 새 항목 등록 (항목별 자동 스냅샷 없이)
+        self.clear_entries().await;
 
         let mut added = 0;
         let mut replaced = 0;
 
         for (key, value, metadata) in new_items {
             let was_existing = old_keys.contains(&key);
-            if let Err(e) = self.register(&key, value, metadata).await {
+            if let Err(e) = self.insert_entry(key.clone(), value, metadata).await {
                 error!("[{}] Failed to register '{}': {}", self.name, key, e);
                 continue;
             }
@@ -570,6 +747,7 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
         }
 
         let removed = old_keys.iter().filter(|k| !new_keys.contains(k)).count();
+        self.after_mutation("hot-reload").await;
 
         // 상태 변경: Validating
         *self.hot_reload_state.write().await = HotReloadState::Validating;
@@ -628,8 +806,13 @@ impl<T: ?Sized + Send + Sync + 'static> DynamicRegistry<T> {
             self.save_snapshot(snapshot_id).await;
         }
 
-        // 교체
-        match self.replace(key, new_value, &new_version).await {
+        // 교체 (위에서 스냅샷을 저장했으므로 자동 스냅샷 생략)
+        let replaced = self.replace_entry(key, new_value, new_version.clone()).await;
+        if replaced.is_some() {
+            self.after_mutation(&format!("replace '{}' -> v{}", key, new_version)).await;
+        }
+
+        match replaced {
             Some(old_value) => {
                 info!("[{}] Safely replaced '{}' to v{}", self.name, key, new_version);
                 Ok(old_value)
@@ -668,6 +851,13 @@ impl DynamicToolRegistry {
         }
     }
 
+    /// 저널과 함께 생성
+    pub fn with_journal(self, journal: SnapshotJournal) -> Self {
+        Self {
+            inner: self.inner.with_journal(journal),
+        }
+    }
+
     /// 빌트인 도구 포함하여 생성
     pub fn with_builtins() -> Self {
         let registry = Self::new();
@@ -685,11 +875,66 @@ impl DynamicToolRegistry {
         self.inner.register(key, tool, metadata).await
     }
 
+    /// 제공자(plugin ID)를 기록하며 Tool 등록
+    pub async fn register_with_provider(&self, tool: Arc<dyn Tool>, provider: &str) -> Result<()> {
+        let meta = tool.meta();
+        let key = meta.name.clone();
+
+        let metadata = EntryMetadata::new(&key, &meta.category, "1.0.0").with_provider(provider);
+        self.inner.register(key, tool, metadata).await
+    }
+
     /// Tool 등록 해제
     pub async fn unregister(&self, name: &str) -> Option<Arc<dyn Tool>> {
         self.inner.unregister(name).await
     }
 
+    /// 제공자의 Tool 목록을 새 목록으로 동기화 (바뀐 Tool 수 반환)
+    ///
+    /// 없어진 Tool은 해제, 설명/스키마가 바뀐 Tool은 교체, 새 Tool은 등록합니다.
+    /// MCP 서버가 `tools/list_changed`를 보냈을 때처럼 제공자가 목록 전체를 다시 줄 때 사용합니다.
+    pub async fn sync_provider(&self, provider: &str, tools: Vec<Arc<dyn Tool>>) -> Result<usize> {
+        let mut changed = 0;
+
+        let names: Vec<String> = tools.iter().map(|t| t.meta().name).collect();
+        for key in self.inner.keys().await {
+            let owned = self
+                .inner
+                .get_metadata(&key)
+                .await
+                .is_some_and(|m| m.provider.as_deref() == Some(provider));
+            if owned && !names.contains(&key) {
+                self.unregister(&key).await;
+                changed += 1;
+            }
+        }
+
+        for tool in tools {
+            let meta = tool.meta();
+            match self.inner.get_any(&meta.name).await {
+                Some(existing) => {
+                    let old = existing.meta();
+                    if old.description != meta.description || existing.schema() != tool.schema() {
+                        let replace_count = self
+                            .inner
+                            .get_metadata(&meta.name)
+                            .await
+                            .map(|m| m.replace_count + 1)
+                            .unwrap_or(1);
+                        let version = format!("1.0.{}", replace_count);
+                        self.replace(&meta.name, tool, version).await;
+                        changed += 1;
+                    }
+                }
+                None => {
+                    self.register_with_provider(tool, provider).await?;
+                    changed += 1;
+                }
+            }
+        }
+        Ok(changed)
+    }
+
     /// Tool 교체
     pub async fn replace(&self, name: &str, new_tool: Arc<dyn Tool>, version: impl Into<String>) -> Option<Arc<dyn Tool>> {
         self.inner.replace(name, new_tool, version).await
@@ -729,6 +974,26 @@ impl DynamicToolRegistry {
     pub async fn stats(&self) -> RegistryStats {
         self.inner.stats().await
     }
+
+    /// 내부 제네릭 레지스트리 (스냅샷/저널 설정용)
+    pub fn inner(&self) -> &DynamicRegistry<dyn Tool> {
+        &self.inner
+    }
+
+    /// 변경 이력
+    pub async fn history(&self) -> Vec<SnapshotHistoryEntry> {
+        self.inner.history().await
+    }
+
+    /// 지정한 스냅샷 시점으로 롤백
+    pub async fn rollback_to(&self, snapshot_id: &str) -> Result<()> {
+        self.inner.rollback_to(snapshot_id).await
+    }
+
+    /// 마지막 변경 취소
+    pub async fn rollback(&self) -> Result<()> {
+        self.inner.rollback().await
+    }
 }
 
 impl Default for DynamicToolRegistry {
@@ -757,6 +1022,14 @@ impl DynamicSkillRegistry {
         }
     }
 
+    /// 저널과 함께 생성
+    pub fn with_journal(self, journal: SnapshotJournal) -> Self {
+        Self {
+            inner: self.inner.with_journal(journal),
+            command_map: self.command_map,
+        }
+    }
+
     /// 빌트인 스킬 포함하여 생성
     pub fn with_builtins() -> Self {
         let registry = Self::new();
@@ -766,12 +1039,23 @@ impl DynamicSkillRegistry {
 
     /// Skill 등록
     pub async fn register(&self, skill: Arc<dyn Skill>) -> Result<()> {
+        let def = skill.definition();
+        let metadata = EntryMetadata::new(&def.name, &def.category, "1.0.0");
+        self.register_with_metadata(skill, metadata).await
+    }
+
+    /// 제공자(plugin ID)를 기록하며 Skill 등록
+    pub async fn register_with_provider(&self, skill: Arc<dyn Skill>, provider: &str) -> Result<()> {
+        let def = skill.definition();
+        let metadata = EntryMetadata::new(&def.name, &def.category, "1.0.0").with_provider(provider);
+        self.register_with_metadata(skill, metadata).await
+    }
+
+    async fn register_with_metadata(&self, skill: Arc<dyn Skill>, metadata: EntryMetadata) -> Result<()> {
         let def = skill.definition();
         let key = def.name.clone();
         let command = def.command.clone();
-        let category = def.category.clone();
 
-        let metadata = EntryMetadata::new(&key, &category, "1.0.0");
         self.inner.register(&key, skill, metadata).await?;
 
         // 명령어 매핑 추가
@@ -802,6 +1086,44 @@ impl DynamicSkillRegistry {
         self.inner.replace(name, new_skill, version).await
     }
 
+    /// 제공자의 Skill 목록을 새 목록으로 동기화 (바뀐 Skill 수 반환)
+    ///
+    /// `DynamicToolRegistry::sync_provider`와 같지만, 같은 이름의 Skill은 항상 새 인스턴스로 교체합니다
+    /// (플러그인 hot reload 시 새 모듈을 사용하도록).
+    pub async fn sync_provider(
+        &self,
+        provider: &str,
+        skills: Vec<Arc<dyn Skill>>,
+    ) -> Result<usize> {
+        let mut changed = 0;
+
+        let names: Vec<String> = skills.iter().map(|s| s.definition().name).collect();
+        for key in self.inner.keys().await {
+            let owned = self
+                .inner
+                .get_metadata(&key)
+                .await
+                .is_some_and(|m| m.provider.as_deref() == Some(provider));
+            if owned && !names.contains(&key) {
+                self.unregister(&key).await;
+                changed += 1;
+            }
+        }
+
+        for skill in skills {
+            let name = skill.definition().name;
+            match self.inner.get_metadata(&name).await {
+                Some(metadata) => {
+                    let version = format!("1.0.{}", metadata.replace_count + 1);
+                    self.replace(&name, skill, version).await;
+                }
+                None => self.register_with_provider(skill, provider).await?,
+            }
+            changed += 1;
+        }
+        Ok(changed)
+    }
+
     /// 이름으로 Skill 조회
     pub async fn get_by_name(&self, name: &str) -> Option<Arc<dyn Skill>> {
         self.inner.get(name).await
@@ -863,6 +1185,40 @@ impl DynamicSkillRegistry {
     pub async fn stats(&self) -> RegistryStats {
         self.inner.stats().await
     }
+
+    /// 내부 제네릭 레지스트리 (스냅샷/저널 설정용)
+    pub fn inner(&self) -> &DynamicRegistry<dyn Skill> {
+        &self.inner
+    }
+
+    /// 변경 이력
+    pub async fn history(&self) -> Vec<SnapshotHistoryEntry> {
+        self.inner.history().await
+    }
+
+    /// 지정한 스냅샷 시점으로 롤백 (명령어 매핑도 함께 복원)
+    pub async fn rollback_to(&self, snapshot_id: &str) -> Result<()> {
+        self.inner.rollback_to(snapshot_id).await?;
+        self.rebuild_command_map().await;
+        Ok(())
+    }
+
+    /// 마지막 변경 취소 (명령어 매핑도 함께 복원)
+    pub async fn rollback(&self) -> Result<()> {
+        self.inner.rollback().await?;
+        self.rebuild_command_map().await;
+        Ok(())
+    }
+
+    /// 현재 항목 기준으로 명령어 매핑 재구성
+    async fn rebuild_command_map(&self) {
+        let mut cmd_map = HashMap::new();
+        for skill in self.inner.all_including_inactive().await {
+            let def = skill.definition();
+            cmd_map.insert(def.command.clone(), def.name.clone());
+        }
+        *self.command_map.write().await = cmd_map;
+    }
 }
 
 impl Default for DynamicSkillRegistry {
@@ -890,6 +1246,29 @@ mod tests {
         assert!(!registry.is_empty().await);
     }
 
+    #[tokio::test]
+    async fn test_sync_provider() {
+        let registry = DynamicToolRegistry::new();
+        registry.register(Arc::new(WriteTool::new())).await.unwrap();
+
+        let read: Arc<dyn Tool> = Arc::new(ReadTool::new());
+        let tools = vec![Arc::clone(&read)];
+        let changed = registry.sync_provider("mcp:fs", tools).await;
+        assert_eq!(changed.unwrap(), 1);
+        let metadata = registry.inner().get_metadata("read").await.unwrap();
+        assert_eq!(metadata.provider.as_deref(), Some("mcp:fs"));
+
+        // 변경이 없으면 그대로
+        let changed = registry.sync_provider("mcp:fs", vec![read]).await;
+        assert_eq!(changed.unwrap(), 0);
+
+        // 없어진 Tool만 해제 (다른 제공자의 Tool은 유지)
+        let changed = registry.sync_provider("mcp:fs", Vec::new()).await;
+        assert_eq!(changed.unwrap(), 1);
+        assert!(!registry.contains("read").await);
+        assert!(registry.contains("write").await);
+    }
+
     #[tokio::test]
     async fn test_dynamic_skill_registry() {
         let registry = DynamicSkillRegistry::new();
@@ -1099,6 +1478,86 @@ mod tests {
         assert!(categories.contains(&"filesystem".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_auto_snapshot_history_and_rollback_to() {
+        let registry: DynamicRegistry<dyn Tool> = DynamicRegistry::new("test");
+        let initial_state = registry.current_state_id().await;
+
+        registry.register_simple("read", Arc::new(ReadTool::new()) as Arc<dyn Tool>).await.unwrap();
+        registry.register_simple("write", Arc::new(WriteTool::new()) as Arc<dyn Tool>).await.unwrap();
+        registry.replace("read", Arc::new(ReadTool::new()), "2.0.0").await;
+
+        // 변경마다 직전 상태가 자동 저장됨
+        let history = registry.history().await;
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].info.id, initial_state);
+        assert_eq!(history[0].diff.added, vec!["read".to_string()]);
+        assert_eq!(history[1].diff.added, vec!["write".to_string()]);
+        assert_eq!(history[2].diff.replaced[0].new_version, "2.0.0");
+
+        // 두 번째 변경 직전 상태로 롤백 → read만 남음
+        let target = history[1].info.id.clone();
+        registry.rollback_to(&target).await.unwrap();
+        assert!(registry.contains("read").await);
+        assert!(!registry.contains("write").await);
+
+        // 롤백 지점 이후 이력은 폐기됨
+        assert_eq!(registry.list_snapshots().await.len(), 1);
+        assert!(registry.rollback_to(&target).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_auto_snapshot_disabled_and_ring_bound() {
+        let registry: DynamicRegistry<dyn Tool> = DynamicRegistry::new("test");
+        registry.configure_snapshots(2, true).await;
+
+        for i in 0..5 {
+            registry
+                .register_simple(format!("tool{}", i), Arc::new(ReadTool::new()) as Arc<dyn Tool>)
+                .await
+                .unwrap();
+        }
+        assert_eq!(registry.list_snapshots().await.len(), 2);
+
+        registry.configure_snapshots(2, false).await;
+        registry.unregister("tool0").await;
+        assert_eq!(registry.list_snapshots().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_journal_records_post_mutation_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = SnapshotJournal::new(dir.path().join("tools.history.jsonl"));
+        let registry: DynamicRegistry<dyn Tool> =
+            DynamicRegistry::new("tools").with_journal(journal.clone());
+
+        registry.register_simple("read", Arc::new(ReadTool::new()) as Arc<dyn Tool>).await.unwrap();
+        registry.register_simple("write", Arc::new(WriteTool::new()) as Arc<dyn Tool>).await.unwrap();
+
+        let records = journal.load().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].info.id, registry.current_state_id().await);
+
+        let history = journal.history().unwrap();
+        assert_eq!(history[0].diff.added, vec!["write".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_skill_rollback_restores_commands() {
+        let registry = DynamicSkillRegistry::new();
+        let skill: Arc<dyn Skill> = Arc::new(CommitSkill::new());
+        registry.register_with_provider(skill, "git-plugin").await.unwrap();
+
+        let meta = registry.inner().get_metadata("commit").await.unwrap();
+        assert_eq!(meta.provider.as_deref(), Some("git-plugin"));
+
+        registry.unregister("commit").await;
+        assert!(registry.get_by_command("/commit").await.is_none());
+
+        registry.rollback().await.unwrap();
+        assert!(registry.get_by_command("/commit").await.is_some());
+    }
+
     #[tokio::test]
     async fn test_snapshot_list() {
         let registry: DynamicRegistry<dyn Tool> = DynamicRegistry::new("test");
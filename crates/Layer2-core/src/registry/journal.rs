@@ -0,0 +1,173 @@
+//! Snapshot Journal - 스냅샷 이력 영속화
+//!
+//! 스냅샷 레코드(메타데이터만)를 JSON Lines 파일로 기록하여
+//! 세션 밖에서도 (`forge registry history`) 레지스트리 변경 이력을 조회할 수 있게 합니다.
+
+use super::snapshot::{SnapshotHistoryEntry, SnapshotRecord};
+use forge_foundation::Result;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// 스냅샷 저널 - 레지스트리별 `<name>.history.jsonl`
+#[derive(Debug, Clone)]
+pub struct SnapshotJournal {
+    /// 저널 파일 경로
+    path: PathBuf,
+
+    /// 유지할 최대 레코드 수
+    max_records: usize,
+}
+
+impl SnapshotJournal {
+    /// 새 저널 생성
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            max_records: 32,
+        }
+    }
+
+    /// 최대 레코드 수 설정
+    pub fn with_max_records(mut self, max: usize) -> Self {
+        self.max_records = max.max(1);
+        self
+    }
+
+    /// 기본 저널 디렉토리 (~/.forgecode/registry)
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".forgecode").join("registry"))
+    }
+
+    /// 기본 위치의 레지스트리 저널
+    pub fn for_registry(name: &str) -> Option<Self> {
+        Self::default_dir().map(|dir| Self::new(dir.join(format!("{}.history.jsonl", name))))
+    }
+
+    /// 저널 파일 경로
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 레코드 추가 (최대 수를 넘으면 오래된 레코드부터 잘라냄)
+    pub fn append(&self, record: &SnapshotRecord) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut records = self.load()?;
+        records.push(record.clone());
+
+        if records.len() > self.max_records {
+            let excess = records.len() - self.max_records;
+            records.drain(..excess);
+            return self.write_all(&records);
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+        Ok(())
+    }
+
+    /// 모든 레코드 로드 (오래된 순). 손상된 줄은 건너뜁니다.
+    pub fn load(&self) -> Result<Vec<SnapshotRecord>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = std::fs::read_to_string(&self.path)?;
+        let records = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str::<SnapshotRecord>(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    warn!("Skipping corrupt journal line in {:?}: {}", self.path, e);
+                    None
+                }
+            })
+            .collect();
+
+        Ok(records)
+    }
+
+    /// 이력 조회 - 연속된 레코드 사이의 변경 (마지막 레코드는 비교 대상 없음)
+    pub fn history(&self) -> Result<Vec<SnapshotHistoryEntry>> {
+        let records = self.load()?;
+
+        Ok(records
+            .windows(2)
+            .map(|pair| SnapshotHistoryEntry {
+                info: pair[0].info.clone(),
+                diff: pair[0].diff(&pair[1]),
+            })
+            .collect())
+    }
+
+    /// ID로 레코드 찾기
+    pub fn find(&self, id: &str) -> Result<Option<SnapshotRecord>> {
+        Ok(self.load()?.into_iter().find(|r| r.info.id == id))
+    }
+
+    /// 저널 비우기
+    pub fn clear(&self) -> Result<()> {
+        if self.path.exists() {
+            std::fs::remove_file(&self.path)?;
+        }
+        Ok(())
+    }
+
+    fn write_all(&self, records: &[SnapshotRecord]) -> Result<()> {
+        let mut content = String::new();
+        for record in records {
+            content.push_str(&serde_json::to_string(record)?);
+            content.push('\n');
+        }
+        std::fs::write(&self.path, content)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::entry::EntryMetadata;
+    use crate::registry::snapshot::RegistrySnapshot;
+    use std::sync::Arc;
+
+    fn record(id: &str, keys: &[&str]) -> SnapshotRecord {
+        let mut snapshot: RegistrySnapshot<String> = RegistrySnapshot::new(id);
+        for key in keys {
+            snapshot.add_entry(
+                key.to_string(),
+                Arc::new(key.to_string()),
+                EntryMetadata::new(*key, "default", "1.0.0"),
+            );
+        }
+        snapshot.record()
+    }
+
+    #[test]
+    fn test_journal_append_and_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = SnapshotJournal::new(dir.path().join("tools.history.jsonl")).with_max_records(2);
+
+        journal.append(&record("a", &[])).unwrap();
+        journal.append(&record("b", &["read"])).unwrap();
+        journal.append(&record("c", &["read", "write"])).unwrap();
+
+        let records = journal.load().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].info.id, "b");
+
+        let history = journal.history().unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].diff.added, vec!["write".to_string()]);
+
+        assert!(journal.find("c").unwrap().is_some());
+        assert!(journal.find("a").unwrap().is_none());
+    }
+}
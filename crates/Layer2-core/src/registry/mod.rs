@@ -13,6 +13,8 @@
 //! 2. **Hot-reload**: 런타임에 Plugin/Skill 교체 지원
 //! 3. **Event-driven**: 변경 시 이벤트 발행으로 리스너에게 통보
 //! 4. **Version Control**: 변경 이력 추적 및 롤백 지원
+//!    - 모든 변경 직전에 자동 스냅샷 (고정 크기 링 버퍼)
+//!    - `SnapshotJournal`로 이력을 파일에 남겨 `forge registry history`에서 조회
 //!
 //! ## 아키텍처
 //!
@@ -64,12 +66,15 @@
 mod traits;
 mod dynamic;
 mod entry;
+mod journal;
 mod snapshot;
 
 pub use traits::{Registerable, RegistryEvent, RegistryEventHandler};
 pub use dynamic::{DynamicRegistry, DynamicToolRegistry, DynamicSkillRegistry, RegistryStats};
 pub use entry::{RegistryEntry, EntryMetadata, EntryState};
+pub use journal::SnapshotJournal;
 pub use snapshot::{
     RegistrySnapshot, SnapshotInfo, SnapshotManager,
     HotReloadState, HotReloadResult, HotReloadConfig,
+    SnapshotDiff, SnapshotHistoryEntry, SnapshotRecord, VersionChange,
 };
@@ -2,9 +2,10 @@
 //!
 //! Hot-reload 시 안전하게 상태를 저장하고 복원하는 기능 제공
 
-use super::entry::EntryMetadata;
+use super::entry::{EntryMetadata, EntryState};
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
 // ============================================================================
@@ -96,6 +97,23 @@ impl<T: ?Sized + Send + Sync + 'static> RegistrySnapshot<T> {
             category_count: self.categories.len(),
         }
     }
+
+    /// 메타데이터만 담은 직렬화 가능한 레코드 (값 제외)
+    pub fn record(&self) -> SnapshotRecord {
+        SnapshotRecord {
+            info: self.info(),
+            entries: self
+                .entries
+                .iter()
+                .map(|(k, e)| (k.clone(), e.metadata.clone()))
+                .collect(),
+        }
+    }
+
+    /// 이 스냅샷에서 `newer`까지의 변경 사항
+    pub fn diff(&self, newer: &RegistrySnapshot<T>) -> SnapshotDiff {
+        self.record().diff(&newer.record())
+    }
 }
 
 impl<T: ?Sized + Send + Sync> Clone for RegistrySnapshot<T> {
@@ -119,7 +137,7 @@ impl<T: ?Sized + Send + Sync> Clone for RegistrySnapshot<T> {
 }
 
 /// 스냅샷 정보 (메타데이터만)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnapshotInfo {
     pub id: String,
     pub created_at: DateTime<Utc>,
@@ -128,14 +146,151 @@ pub struct SnapshotInfo {
     pub category_count: usize,
 }
 
+// ============================================================================
+// SnapshotRecord / SnapshotDiff - 이력 조회용
+// ============================================================================
+
+/// 스냅샷 레코드 - 항목 값 없이 메타데이터만 저장 (저널 영속화용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotRecord {
+    /// 스냅샷 정보
+    pub info: SnapshotInfo,
+
+    /// 키 -> 메타데이터
+    pub entries: HashMap<String, EntryMetadata>,
+}
+
+impl SnapshotRecord {
+    /// 이 레코드에서 `newer`까지의 변경 사항
+    pub fn diff(&self, newer: &SnapshotRecord) -> SnapshotDiff {
+        let mut diff = SnapshotDiff {
+            from: self.info.id.clone(),
+            to: newer.info.id.clone(),
+            ..Default::default()
+        };
+
+        for (key, new_meta) in &newer.entries {
+            match self.entries.get(key) {
+                None => diff.added.push(key.clone()),
+                Some(old_meta) => {
+                    if old_meta.version != new_meta.version
+                        || old_meta.replace_count != new_meta.replace_count
+                    {
+                        diff.replaced.push(VersionChange {
+                            key: key.clone(),
+                            old_version: old_meta.version.clone(),
+                            new_version: new_meta.version.clone(),
+                        });
+                    }
+                    if old_meta.state != new_meta.state {
+                        diff.state_changed.push((key.clone(), old_meta.state, new_meta.state));
+                    }
+                }
+            }
+        }
+
+        for key in self.entries.keys() {
+            if !newer.entries.contains_key(key) {
+                diff.removed.push(key.clone());
+            }
+        }
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.replaced.sort_by(|a, b| a.key.cmp(&b.key));
+        diff.state_changed.sort_by(|a, b| a.0.cmp(&b.0));
+        diff
+    }
+
+    /// `newer`에서 추가되거나 교체된 항목의 제공자 목록 (롤백 대상 추적용)
+    pub fn providers_changed_since(&self, newer: &SnapshotRecord) -> Vec<String> {
+        let diff = self.diff(newer);
+        let mut providers: Vec<String> = diff
+            .added
+            .iter()
+            .chain(diff.replaced.iter().map(|c| &c.key))
+            .filter_map(|k| newer.entries.get(k).and_then(|m| m.provider.clone()))
+            .collect();
+        providers.sort();
+        providers.dedup();
+        providers
+    }
+}
+
+/// 항목 버전 변경
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionChange {
+    pub key: String,
+    pub old_version: String,
+    pub new_version: String,
+}
+
+/// 두 스냅샷 사이의 차이
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    /// 이전 스냅샷 ID
+    pub from: String,
+    /// 이후 스냅샷 ID
+    pub to: String,
+    /// 추가된 키
+    pub added: Vec<String>,
+    /// 제거된 키
+    pub removed: Vec<String>,
+    /// 교체된 항목
+    pub replaced: Vec<VersionChange>,
+    /// 상태가 바뀐 항목 (키, 이전, 이후)
+    pub state_changed: Vec<(String, EntryState, EntryState)>,
+}
+
+impl SnapshotDiff {
+    /// 변경 사항 없음
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.replaced.is_empty()
+            && self.state_changed.is_empty()
+    }
+
+    /// 한 줄 요약 (`+read -write ~edit(1.0.0→2.0.0)`)
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "(no changes)".to_string();
+        }
+
+        let mut parts = Vec::new();
+        parts.extend(self.added.iter().map(|k| format!("+{}", k)));
+        parts.extend(self.removed.iter().map(|k| format!("-{}", k)));
+        parts.extend(
+            self.replaced
+                .iter()
+                .map(|c| format!("~{}({}→{})", c.key, c.old_version, c.new_version)),
+        );
+        parts.extend(
+            self.state_changed
+                .iter()
+                .map(|(k, old, new)| format!("{}:{}→{}", k, old, new)),
+        );
+        parts.join(" ")
+    }
+}
+
+/// 이력 항목 - 스냅샷과 바로 다음 상태까지의 변경
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotHistoryEntry {
+    pub info: SnapshotInfo,
+    pub diff: SnapshotDiff,
+}
+
 // ============================================================================
 // SnapshotManager - 스냅샷 관리자
 // ============================================================================
 
 /// 스냅샷 관리자 - 여러 스냅샷을 관리하고 롤백 지원
+///
+/// 스냅샷은 고정 크기 링 버퍼에 저장되며, 가득 차면 가장 오래된 것부터 버려집니다.
 pub struct SnapshotManager<T: ?Sized + Send + Sync> {
-    /// 저장된 스냅샷들
-    snapshots: Vec<RegistrySnapshot<T>>,
+    /// 저장된 스냅샷들 (오래된 순)
+    snapshots: VecDeque<RegistrySnapshot<T>>,
 
     /// 최대 스냅샷 수
     max_snapshots: usize,
@@ -148,18 +303,31 @@ impl<T: ?Sized + Send + Sync + 'static> SnapshotManager<T> {
     /// 새 매니저 생성
     pub fn new() -> Self {
         Self {
-            snapshots: Vec::new(),
-            max_snapshots: 10,
+            snapshots: VecDeque::new(),
+            max_snapshots: 32,
             auto_snapshot: true,
         }
     }
 
     /// 최대 스냅샷 수 설정
     pub fn with_max_snapshots(mut self, max: usize) -> Self {
-        self.max_snapshots = max;
+        self.set_max_snapshots(max);
         self
     }
 
+    /// 최대 스냅샷 수 변경 (초과분은 오래된 것부터 제거)
+    pub fn set_max_snapshots(&mut self, max: usize) {
+        self.max_snapshots = max.max(1);
+        while self.snapshots.len() > self.max_snapshots {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// 최대 스냅샷 수
+    pub fn max_snapshots(&self) -> usize {
+        self.max_snapshots
+    }
+
     /// 자동 스냅샷 설정
     pub fn with_auto_snapshot(mut self, enabled: bool) -> Self {
         self.auto_snapshot = enabled;
@@ -171,19 +339,24 @@ impl<T: ?Sized + Send + Sync + 'static> SnapshotManager<T> {
         self.auto_snapshot
     }
 
+    /// 자동 스냅샷 켜기/끄기
+    pub fn set_auto_snapshot(&mut self, enabled: bool) {
+        self.auto_snapshot = enabled;
+    }
+
     /// 스냅샷 저장
     pub fn save(&mut self, snapshot: RegistrySnapshot<T>) {
         // 최대 수 초과 시 가장 오래된 것 제거
-        if self.snapshots.len() >= self.max_snapshots {
-            self.snapshots.remove(0);
+        while self.snapshots.len() >= self.max_snapshots {
+            self.snapshots.pop_front();
         }
 
-        self.snapshots.push(snapshot);
+        self.snapshots.push_back(snapshot);
     }
 
     /// 가장 최근 스냅샷 가져오기
     pub fn latest(&self) -> Option<&RegistrySnapshot<T>> {
-        self.snapshots.last()
+        self.snapshots.back()
     }
 
     /// ID로 스냅샷 가져오기
@@ -208,11 +381,33 @@ impl<T: ?Sized + Send + Sync + 'static> SnapshotManager<T> {
 
     /// 특정 스냅샷 삭제
     pub fn remove(&mut self, id: &str) -> Option<RegistrySnapshot<T>> {
-        if let Some(pos) = self.snapshots.iter().position(|s| s.id == id) {
-            Some(self.snapshots.remove(pos))
-        } else {
-            None
-        }
+        let pos = self.snapshots.iter().position(|s| s.id == id)?;
+        self.snapshots.remove(pos)
+    }
+
+    /// 지정한 스냅샷과 그 이후 스냅샷을 모두 꺼내고 지정한 스냅샷 반환
+    ///
+    /// 해당 시점으로 되돌린 뒤에는 그 이후 이력이 더 이상 유효하지 않으므로 함께 버립니다.
+    pub fn take_from(&mut self, id: &str) -> Option<RegistrySnapshot<T>> {
+        let pos = self.snapshots.iter().position(|s| s.id == id)?;
+        self.snapshots.drain(pos..).next()
+    }
+
+    /// 이력 조회 - 각 스냅샷에서 다음 스냅샷(마지막은 `current`)까지의 변경
+    pub fn history(&self, current: &SnapshotRecord) -> Vec<SnapshotHistoryEntry> {
+        let records: Vec<SnapshotRecord> = self.snapshots.iter().map(|s| s.record()).collect();
+
+        records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| {
+                let next = records.get(i + 1).unwrap_or(current);
+                SnapshotHistoryEntry {
+                    info: record.info.clone(),
+                    diff: record.diff(next),
+                }
+            })
+            .collect()
     }
 
     /// 모든 스냅샷 삭제
@@ -222,7 +417,7 @@ impl<T: ?Sized + Send + Sync + 'static> SnapshotManager<T> {
 
     /// 가장 최근 스냅샷으로 롤백 (pop하여 반환)
     pub fn pop_latest(&mut self) -> Option<RegistrySnapshot<T>> {
-        self.snapshots.pop()
+        self.snapshots.pop_back()
     }
 }
 
@@ -384,6 +579,56 @@ mod tests {
         assert!(manager.get("snapshot-2").is_some());
     }
 
+    #[test]
+    fn test_snapshot_diff() {
+        let mut old: RegistrySnapshot<String> = RegistrySnapshot::new("old");
+        old.add_entry("read".into(), Arc::new("r".into()), EntryMetadata::new("read", "fs", "1.0.0"));
+        old.add_entry("write".into(), Arc::new("w".into()), EntryMetadata::new("write", "fs", "1.0.0"));
+
+        let mut new: RegistrySnapshot<String> = RegistrySnapshot::new("new");
+        new.add_entry("read".into(), Arc::new("r2".into()), EntryMetadata::new("read", "fs", "2.0.0"));
+        new.add_entry(
+            "grep".into(),
+            Arc::new("g".into()),
+            EntryMetadata::new("grep", "fs", "1.0.0").with_provider("my-plugin"),
+        );
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.added, vec!["grep".to_string()]);
+        assert_eq!(diff.removed, vec!["write".to_string()]);
+        assert_eq!(diff.replaced.len(), 1);
+        assert_eq!(diff.replaced[0].new_version, "2.0.0");
+        assert_eq!(diff.summary(), "+grep -write ~read(1.0.0→2.0.0)");
+
+        let providers = old.record().providers_changed_since(&new.record());
+        assert_eq!(providers, vec!["my-plugin".to_string()]);
+    }
+
+    #[test]
+    fn test_snapshot_manager_take_from_and_history() {
+        let mut manager: SnapshotManager<String> = SnapshotManager::new();
+
+        for i in 0..3 {
+            let mut snapshot = RegistrySnapshot::new(format!("s{}", i));
+            for j in 0..i {
+                let key = format!("k{}", j);
+                snapshot.add_entry(key.clone(), Arc::new(key.clone()), EntryMetadata::new(&key, "c", "1.0.0"));
+            }
+            manager.save(snapshot);
+        }
+
+        let current = RegistrySnapshot::<String>::new("current").record();
+        let history = manager.history(&current);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].diff.added, vec!["k0".to_string()]);
+        assert_eq!(history[2].diff.removed.len(), 2);
+
+        let taken = manager.take_from("s1").unwrap();
+        assert_eq!(taken.id, "s1");
+        assert_eq!(manager.len(), 1);
+        assert!(manager.get("s2").is_none());
+    }
+
     #[test]
     fn test_hot_reload_result() {
         let result = HotReloadResult::success(5, 2, 1, 100);
@@ -1,10 +1,24 @@
 //! Repository Analyzer - 코드베이스 분석기
 //!
 //! 파일을 파싱하여 심볼을 추출합니다.
-
+//! LSP 매니저가 연결되어 있으면 언어 서버의 심볼(documentSymbol, workspace/symbol)을
+//! 우선 사용하고, 서버가 없거나 실패하면 정규식 파싱으로 대체합니다.
+//!
+//! 언어별 파싱은 `LanguageRegistry`의 분석기가 맡습니다 (`with_language`로 추가).
+//! 캐시가 설정되어 있으면(`with_cache`) 바뀐 파일만 다시 파싱합니다.
+
+use super::cache::{content_hash, CachedFile, FileStamp, RepoMapCache};
+use super::embeddings::Embedder;
+use super::graph::DependencyGraph;
+use super::language::{LanguageAnalyzer, LanguageRegistry};
+use super::ranker::FileRanker;
+use super::todos::{extract_todos, TodoBacklog};
 use super::types::{FileInfo, RepoMap, RepoMapConfig, SymbolDef, SymbolKind};
+use crate::lsp::{uri_to_path, DocumentSymbol, LspManager, SymbolKind as LspSymbolKind};
 use forge_foundation::{Error, Result};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio::fs;
 use tracing::{debug, warn};
 
@@ -14,6 +28,12 @@ pub struct RepoAnalyzer {
     config: RepoMapConfig,
     /// 루트 경로
     root: PathBuf,
+    /// 언어 서버 (심볼 인덱스)
+    lsp: Option<Arc<LspManager>>,
+    /// 증분 인덱스 경로
+    cache_path: Option<PathBuf>,
+    /// 언어별 분석기
+    languages: LanguageRegistry,
 }
 
 impl RepoAnalyzer {
@@ -22,6 +42,9 @@ impl RepoAnalyzer {
         Self {
             config,
             root: root.into(),
+            lsp: None,
+            cache_path: None,
+            languages: LanguageRegistry::builtin(),
         }
     }
 
@@ -30,6 +53,116 @@ impl RepoAnalyzer {
         Self::new(root, RepoMapConfig::default())
     }
 
+    /// 언어 서버 심볼 사용
+    pub fn with_lsp(mut self, lsp: Arc<LspManager>) -> Self {
+        self.lsp = Some(lsp);
+        self
+    }
+
+    /// 언어 분석기 추가 (같은 확장자의 기존 분석기보다 우선)
+    pub fn with_language(mut self, analyzer: Arc<dyn LanguageAnalyzer>) -> Self {
+        self.languages.register(analyzer);
+        self
+    }
+
+    /// 언어 분석기 여러 개 추가 (플러그인 제공 분석기 등)
+    pub fn with_languages(
+        mut self,
+        analyzers: impl IntoIterator<Item = Arc<dyn LanguageAnalyzer>>,
+    ) -> Self {
+        for analyzer in analyzers {
+            self.languages.register(analyzer);
+        }
+        self
+    }
+
+    /// 언어 분석기 레지스트리
+    pub fn languages(&self) -> &LanguageRegistry {
+        &self.languages
+    }
+
+    /// 증분 인덱스 사용 (파일별 분석 결과를 `path`에 저장하고 바뀐 파일만 재분석)
+    pub fn with_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache_path = Some(path.into());
+        self
+    }
+
+    /// 프로젝트 기본 위치(`.forgecode/cache/repomap.db`)의 증분 인덱스 사용
+    pub fn with_project_cache(self) -> Self {
+        let path = RepoMapCache::project_path(&self.root);
+        self.with_cache(path)
+    }
+
+    /// 파일들의 캐시 항목 무효화 (파일 감시자/편집 도구용)
+    ///
+    /// 캐시가 없으면 아무것도 하지 않습니다. 삭제된 항목 수를 반환합니다.
+    pub fn invalidate(&self, paths: &[PathBuf]) -> Result<usize> {
+        let Some(cache_path) = &self.cache_path else {
+            return Ok(0);
+        };
+        let relative: Vec<String> = paths.iter().map(|p| self.relative_path(p)).collect();
+        RepoMapCache::open(cache_path)?.invalidate(&relative)
+    }
+
+    /// 심볼이 정의된 파일 찾기
+    ///
+    /// 언어 서버가 있으면 `workspace/symbol` 인덱스를, 없거나 결과가 없으면
+    /// 맵의 심볼 인덱스를 사용합니다.
+    pub async fn find_symbol_files(&self, map: &RepoMap, symbol: &str) -> Vec<PathBuf> {
+        if let Some(lsp) = &self.lsp {
+            let mut files: Vec<PathBuf> = lsp
+                .workspace_symbols(symbol)
+                .await
+                .into_iter()
+                .filter(|s| s.name == symbol)
+                .filter_map(|s| uri_to_path(&s.location.uri).map(PathBuf::from))
+                .filter(|path| path.starts_with(&self.root))
+                .collect();
+            files.sort();
+            files.dedup();
+            if !files.is_empty() {
+                return files;
+            }
+        }
+        map.find_files_by_symbol(symbol)
+            .into_iter()
+            .map(|f| f.path.clone())
+            .collect()
+    }
+
+    /// 언급된 심볼을 정의한 파일을 포커스로 중요도 재계산
+    pub async fn rank_for_symbols(&self, map: &mut RepoMap, symbols: &[&str]) {
+        let mut focus = Vec::new();
+        for symbol in symbols {
+            for path in self.find_symbol_files(map, symbol).await {
+                if !focus.contains(&path) {
+                    focus.push(path);
+                }
+            }
+        }
+        let graph = DependencyGraph::from_repo_map(map);
+        FileRanker::new().rank_for_context(map, &graph, &focus);
+    }
+
+    /// 질의 기반 중요도 재계산 (그래프 점수 + 질의-심볼 임베딩 유사도)
+    ///
+    /// 이후 `summarize`는 질의와 관련된 심볼을 먼저 담습니다.
+    pub fn rank_for_query(&self, map: &mut RepoMap, query: &str, embedder: &dyn Embedder) {
+        let graph = DependencyGraph::from_repo_map(map);
+        FileRanker::new().rank_for_query(map, &graph, &[], query, embedder);
+    }
+
+    /// 설정의 토큰 예산(`max_tokens`)에 맞춘 요약
+    ///
+    /// `symbol_budget`이면 심볼 단위로, 아니면 파일 단위로 담습니다.
+    pub fn summarize(&self, map: &RepoMap) -> String {
+        if self.config.symbol_budget {
+            map.to_symbols_within_budget(self.config.max_tokens)
+        } else {
+            map.to_string_within_budget(self.config.max_tokens)
+        }
+    }
+
     /// Repository Map 생성
     pub async fn analyze(&self) -> Result<RepoMap> {
         let mut map = RepoMap::new(self.root.clone());
@@ -38,6 +171,11 @@ impl RepoAnalyzer {
         let files = self.collect_files().await?;
         debug!("Found {} files to analyze", files.len());
 
+        if let Some(cache_path) = &self.cache_path {
+            self.analyze_incremental(&mut map, files, cache_path).await;
+            return Ok(map);
+        }
+
         // 각 파일 분석
         for path in files.into_iter().take(self.config.max_files) {
             match self.analyze_file(&path).await {
@@ -53,6 +191,112 @@ impl RepoAnalyzer {
         Ok(map)
     }
 
+    /// 증분 분석 - 캐시 항목이 최신이면 재사용하고 바뀐 파일만 파싱
+    ///
+    /// 캐시를 열거나 저장하지 못하면 경고만 남기고 전체 분석 결과를 사용합니다.
+    async fn analyze_incremental(&self, map: &mut RepoMap, files: Vec<PathBuf>, cache_path: &Path) {
+        let lsp = self.lsp.is_some();
+        let path = cache_path.to_path_buf();
+        let loaded = tokio::task::spawn_blocking(move || RepoMapCache::open(&path)?.load()).await;
+        let mut cached = match loaded {
+            Ok(Ok(cached)) => cached,
+            Ok(Err(e)) => {
+                warn!("Repo map cache unavailable, re-parsing all files: {}", e);
+                HashMap::new()
+            }
+            Err(e) => {
+                warn!("Repo map cache task failed: {}", e);
+                HashMap::new()
+            }
+        };
+
+        let mut updated = Vec::new();
+        let mut keep = HashSet::new();
+        let (mut reused, mut parsed) = (0, 0);
+        for path in files.into_iter().take(self.config.max_files) {
+            let relative = self.relative_path(&path);
+            let stamp = match fs::metadata(&path).await {
+                Ok(meta) => FileStamp::from_metadata(&meta),
+                Err(e) => {
+                    warn!("Failed to analyze {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+            // 분석기가 바뀐 항목(언어 서버 사용 여부, 언어)은 재사용하지 않음
+            let language = self.detect_language(&path);
+            let entry = cached
+                .remove(&relative)
+                .filter(|entry| entry.lsp == lsp && entry.info.language == language);
+
+            // mtime + 크기가 같으면 내용을 읽지 않음
+            if let Some(mut entry) = entry.clone().filter(|entry| entry.stamp == stamp) {
+                entry.info.path = path;
+                keep.insert(relative);
+                map.add_file(entry.info);
+                reused += 1;
+                continue;
+            }
+
+            let content = match fs::read_to_string(&path).await {
+                Ok(content) => content,
+                Err(e) => {
+                    warn!("Failed to analyze {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+            let hash = content_hash(&content);
+            let info = match entry {
+                // 내용이 같으면 (touch, checkout 등) 스탬프만 갱신
+                Some(mut entry) if entry.hash == hash => {
+                    reused += 1;
+                    entry.info.path = path;
+                    entry.info
+                }
+                _ => {
+                    parsed += 1;
+                    self.analyze_content(&path, &content).await
+                }
+            };
+            keep.insert(relative);
+            updated.push(CachedFile {
+                stamp,
+                hash,
+                lsp,
+                info: info.clone(),
+            });
+            map.add_file(info);
+        }
+
+        let path = cache_path.to_path_buf();
+        let stored =
+            tokio::task::spawn_blocking(move || RepoMapCache::open(&path)?.store(&updated, &keep))
+                .await;
+        match stored {
+            Ok(Ok(removed)) => debug!(
+                "Repo map: {} files reused, {} parsed, {} removed from cache",
+                reused, parsed, removed
+            ),
+            Ok(Err(e)) => warn!("Failed to update repo map cache: {}", e),
+            Err(e) => warn!("Repo map cache task failed: {}", e),
+        }
+    }
+
+    /// TODO/FIXME/HACK 백로그 생성 (git blame으로 담당자/경과 일수 포함)
+    pub async fn todo_backlog(&self) -> Result<TodoBacklog> {
+        let map = self.analyze().await?;
+        let mut backlog = TodoBacklog::from_map(&map);
+        if !backlog.is_empty() {
+            let root = self.root.clone();
+            backlog = tokio::task::spawn_blocking(move || {
+                backlog.annotate_with_git(&root);
+                backlog
+            })
+            .await
+            .map_err(|e| Error::Internal(format!("git blame task failed: {}", e)))?;
+        }
+        Ok(backlog)
+    }
+
     /// 파일 목록 수집
     async fn collect_files(&self) -> Result<Vec<PathBuf>> {
         let mut files = Vec::new();
@@ -95,11 +339,7 @@ impl RepoAnalyzer {
 
     /// 파일 포함 여부 확인
     fn should_include(&self, path: &Path) -> bool {
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        matches!(
-            ext,
-            "rs" | "py" | "js" | "ts" | "tsx" | "jsx" | "go" | "java" | "c" | "cpp" | "h" | "hpp"
-        )
+        self.languages.for_path(path).is_some()
     }
 
     /// 파일 제외 여부 확인
@@ -129,673 +369,118 @@ impl RepoAnalyzer {
         )
     }
 
+    /// 루트 기준 상대 경로
+    fn relative_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string()
+    }
+
     /// 단일 파일 분석
     async fn analyze_file(&self, path: &Path) -> Result<FileInfo> {
         let content = fs::read_to_string(path)
             .await
             .map_err(|e| Error::Internal(format!("Failed to read file: {}", e)))?;
+        Ok(self.analyze_content(path, &content).await)
+    }
 
-        let relative_path = path
-            .strip_prefix(&self.root)
-            .unwrap_or(path)
-            .to_string_lossy()
-            .to_string();
+    /// 읽은 파일 내용 분석
+    async fn analyze_content(&self, path: &Path, content: &str) -> FileInfo {
+        let relative_path = self.relative_path(path);
 
         let language = self.detect_language(path);
         let line_count = content.lines().count();
 
-        let mut file_info = FileInfo::new(path.to_path_buf(), relative_path, language.clone());
+        let mut file_info = FileInfo::new(path.to_path_buf(), relative_path, language);
         file_info.line_count = line_count;
+        file_info.todos = extract_todos(content, &file_info.relative_path);
 
         // 언어별 파싱
-        match language.as_str() {
-            "rust" => self.parse_rust(&content, &mut file_info),
-            "python" => self.parse_python(&content, &mut file_info),
-            "javascript" | "typescript" => self.parse_javascript(&content, &mut file_info),
-            "go" => self.parse_go(&content, &mut file_info),
-            "java" => self.parse_java(&content, &mut file_info),
-            "c" | "cpp" => self.parse_c(&content, &mut file_info),
-            _ => {}
-        }
-
-        Ok(file_info)
-    }
-
-    /// 언어 감지
-    fn detect_language(&self, path: &Path) -> String {
-        match path.extension().and_then(|e| e.to_str()) {
-            Some("rs") => "rust".to_string(),
-            Some("py") => "python".to_string(),
-            Some("js" | "jsx") => "javascript".to_string(),
-            Some("ts" | "tsx") => "typescript".to_string(),
-            Some("go") => "go".to_string(),
-            Some("java") => "java".to_string(),
-            Some("c" | "h") => "c".to_string(),
-            Some("cpp" | "hpp" | "cc" | "cxx") => "cpp".to_string(),
-            _ => "unknown".to_string(),
+        if let Some(analyzer) = self.languages.for_path(path) {
+            analyzer.parse(content, &mut file_info);
         }
-    }
 
-    /// Rust 파일 파싱
-    fn parse_rust(&self, content: &str, file_info: &mut FileInfo) {
-        for (line_num, line) in content.lines().enumerate() {
-            let line_num = line_num + 1;
-            let trimmed = line.trim();
-
-            // use statements
-            if trimmed.starts_with("use ") {
-                if let Some(import) = trimmed
-                    .strip_prefix("use ")
-                    .and_then(|s| s.strip_suffix(';'))
-                {
-                    file_info.add_import(import.to_string());
-                }
-            }
-
-            // pub/private 감지
-            let (vis, rest) = if trimmed.starts_with("pub ") {
-                (Some("pub"), trimmed.strip_prefix("pub ").unwrap_or(trimmed))
-            } else if trimmed.starts_with("pub(crate) ") {
-                (
-                    Some("pub(crate)"),
-                    trimmed.strip_prefix("pub(crate) ").unwrap_or(trimmed),
-                )
-            } else {
-                (None, trimmed)
-            };
-
-            // struct
-            if rest.starts_with("struct ") {
-                if let Some(name) = self.extract_identifier(rest, "struct ") {
-                    let mut sym = SymbolDef::new(name, SymbolKind::Struct, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // enum
-            else if rest.starts_with("enum ") {
-                if let Some(name) = self.extract_identifier(rest, "enum ") {
-                    let mut sym = SymbolDef::new(name, SymbolKind::Enum, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // trait
-            else if rest.starts_with("trait ") {
-                if let Some(name) = self.extract_identifier(rest, "trait ") {
-                    let mut sym = SymbolDef::new(name, SymbolKind::Interface, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // fn
-            else if rest.starts_with("fn ") || rest.starts_with("async fn ") {
-                let fn_start = if rest.starts_with("async fn ") {
-                    "async fn "
-                } else {
-                    "fn "
-                };
-                if let Some(sig) = self.extract_fn_signature(rest, fn_start) {
-                    let mut sym = SymbolDef::new(&sig.0, SymbolKind::Function, line_num)
-                        .with_signature(&sig.1);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // impl
-            else if rest.starts_with("impl ") || rest.starts_with("impl<") {
-                // impl 블록은 특별히 처리하지 않음 (메서드는 내부에서 처리)
-            }
-            // mod
-            else if rest.starts_with("mod ") {
-                if let Some(name) = self.extract_identifier(rest, "mod ") {
-                    let mut sym = SymbolDef::new(name, SymbolKind::Module, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // const
-            else if rest.starts_with("const ") {
-                if let Some(name) = self.extract_const_name(rest) {
-                    let mut sym = SymbolDef::new(name, SymbolKind::Constant, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // type alias
-            else if rest.starts_with("type ") {
-                if let Some(name) = self.extract_identifier(rest, "type ") {
-                    let mut sym = SymbolDef::new(name, SymbolKind::TypeAlias, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // macro_rules!
-            else if rest.starts_with("macro_rules!") {
-                if let Some(name) = rest
-                    .strip_prefix("macro_rules!")
-                    .and_then(|s| s.trim().split_whitespace().next())
-                {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Macro, line_num));
+        // 언어 서버 심볼 우선 (임포트는 정규식 결과 유지)
+        if let Some(lsp) = &self.lsp {
+            match lsp.document_symbols(path).await {
+                Ok(symbols) if !symbols.is_empty() => {
+                    let mut lsp_symbols = symbols_from_lsp(&symbols, None);
+                    merge_symbol_details(&mut lsp_symbols, &file_info.symbols);
+                    file_info.symbols = lsp_symbols;
                 }
+                Ok(_) => {}
+                Err(e) => debug!("LSP symbols unavailable for {}: {}", path.display(), e),
             }
         }
-    }
 
-    /// Python 파일 파싱
-    fn parse_python(&self, content: &str, file_info: &mut FileInfo) {
-        for (line_num, line) in content.lines().enumerate() {
-            let line_num = line_num + 1;
-            let trimmed = line.trim();
-
-            // import statements
-            if trimmed.starts_with("import ") || trimmed.starts_with("from ") {
-                file_info.add_import(trimmed.to_string());
-            }
-            // class
-            else if trimmed.starts_with("class ") {
-                if let Some(name) = self.extract_python_class(trimmed) {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Class, line_num));
-                }
-            }
-            // def (함수)
-            else if trimmed.starts_with("def ") {
-                if let Some((name, sig)) = self.extract_python_function(trimmed) {
-                    file_info.add_symbol(
-                        SymbolDef::new(name, SymbolKind::Function, line_num).with_signature(sig),
-                    );
-                }
-            }
-            // async def
-            else if trimmed.starts_with("async def ") {
-                if let Some((name, sig)) =
-                    self.extract_python_function(&trimmed.replace("async def ", "def "))
-                {
-                    file_info.add_symbol(
-                        SymbolDef::new(name, SymbolKind::Function, line_num)
-                            .with_signature(format!("async {}", sig)),
-                    );
-                }
-            }
-        }
+        file_info
     }
 
-    /// JavaScript/TypeScript 파일 파싱
-    fn parse_javascript(&self, content: &str, file_info: &mut FileInfo) {
-        for (line_num, line) in content.lines().enumerate() {
-            let line_num = line_num + 1;
-            let trimmed = line.trim();
-
-            // import statements
-            if trimmed.starts_with("import ") {
-                file_info.add_import(trimmed.to_string());
-            }
-            // export
-            else if trimmed.starts_with("export ") {
-                let rest = trimmed.strip_prefix("export ").unwrap_or(trimmed);
-
-                if rest.starts_with("default ") {
-                    // export default
-                    continue;
-                } else if rest.starts_with("class ") {
-                    if let Some(name) = self.extract_js_class(rest) {
-                        file_info.add_symbol(
-                            SymbolDef::new(name, SymbolKind::Class, line_num)
-                                .with_visibility("export"),
-                        );
-                    }
-                } else if rest.starts_with("function ")
-                    || rest.starts_with("async function ")
-                    || rest.starts_with("const ")
-                {
-                    if let Some((name, kind)) = self.extract_js_declaration(rest) {
-                        file_info.add_symbol(
-                            SymbolDef::new(name, kind, line_num).with_visibility("export"),
-                        );
-                    }
-                } else if rest.starts_with("interface ") {
-                    if let Some(name) = self.extract_identifier(rest, "interface ") {
-                        file_info.add_symbol(
-                            SymbolDef::new(name, SymbolKind::Interface, line_num)
-                                .with_visibility("export"),
-                        );
-                    }
-                } else if rest.starts_with("type ") {
-                    if let Some(name) = self.extract_identifier(rest, "type ") {
-                        file_info.add_symbol(
-                            SymbolDef::new(name, SymbolKind::TypeAlias, line_num)
-                                .with_visibility("export"),
-                        );
-                    }
-                }
-            }
-            // class
-            else if trimmed.starts_with("class ") {
-                if let Some(name) = self.extract_js_class(trimmed) {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Class, line_num));
-                }
-            }
-            // function
-            else if trimmed.starts_with("function ") || trimmed.starts_with("async function ") {
-                if let Some((name, kind)) = self.extract_js_declaration(trimmed) {
-                    file_info.add_symbol(SymbolDef::new(name, kind, line_num));
-                }
-            }
-            // interface (TypeScript)
-            else if trimmed.starts_with("interface ") {
-                if let Some(name) = self.extract_identifier(trimmed, "interface ") {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Interface, line_num));
-                }
-            }
-            // type (TypeScript)
-            else if trimmed.starts_with("type ") && trimmed.contains('=') {
-                if let Some(name) = self.extract_identifier(trimmed, "type ") {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::TypeAlias, line_num));
-                }
-            }
-        }
-    }
-
-    /// Go 파일 파싱
-    fn parse_go(&self, content: &str, file_info: &mut FileInfo) {
-        for (line_num, line) in content.lines().enumerate() {
-            let line_num = line_num + 1;
-            let trimmed = line.trim();
-
-            // import
-            if trimmed.starts_with("import ") {
-                file_info.add_import(trimmed.to_string());
-            }
-            // func
-            else if trimmed.starts_with("func ") {
-                if let Some((name, sig)) = self.extract_go_function(trimmed) {
-                    let vis = if name
-                        .chars()
-                        .next()
-                        .map(|c| c.is_uppercase())
-                        .unwrap_or(false)
-                    {
-                        Some("pub")
-                    } else {
-                        None
-                    };
-                    let mut sym =
-                        SymbolDef::new(name, SymbolKind::Function, line_num).with_signature(sig);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // type struct
-            else if trimmed.starts_with("type ") && trimmed.contains("struct") {
-                if let Some(name) = self.extract_identifier(trimmed, "type ") {
-                    let vis = if name
-                        .chars()
-                        .next()
-                        .map(|c| c.is_uppercase())
-                        .unwrap_or(false)
-                    {
-                        Some("pub")
-                    } else {
-                        None
-                    };
-                    let mut sym = SymbolDef::new(name, SymbolKind::Struct, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // type interface
-            else if trimmed.starts_with("type ") && trimmed.contains("interface") {
-                if let Some(name) = self.extract_identifier(trimmed, "type ") {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Interface, line_num));
-                }
-            }
-        }
-    }
-
-    /// Java 파일 파싱
-    fn parse_java(&self, content: &str, file_info: &mut FileInfo) {
-        for (line_num, line) in content.lines().enumerate() {
-            let line_num = line_num + 1;
-            let trimmed = line.trim();
-
-            // import
-            if trimmed.starts_with("import ") {
-                file_info.add_import(trimmed.to_string());
-            }
-            // class
-            else if trimmed.contains("class ") && !trimmed.starts_with("//") {
-                if let Some((vis, name)) = self.extract_java_class(trimmed) {
-                    let mut sym = SymbolDef::new(name, SymbolKind::Class, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // interface
-            else if trimmed.contains("interface ") && !trimmed.starts_with("//") {
-                if let Some((vis, name)) = self.extract_java_interface(trimmed) {
-                    let mut sym = SymbolDef::new(name, SymbolKind::Interface, line_num);
-                    if let Some(v) = vis {
-                        sym = sym.with_visibility(v);
-                    }
-                    file_info.add_symbol(sym);
-                }
-            }
-            // enum
-            else if trimmed.contains("enum ") && !trimmed.starts_with("//") {
-                if let Some(name) = self.extract_java_enum(trimmed) {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Enum, line_num));
-                }
-            }
-        }
+    /// 언어 감지
+    fn detect_language(&self, path: &Path) -> String {
+        self.languages
+            .for_path(path)
+            .map_or_else(|| "unknown".to_string(), |a| a.language().to_string())
     }
+}
 
-    /// C/C++ 파일 파싱
-    fn parse_c(&self, content: &str, file_info: &mut FileInfo) {
-        for (line_num, line) in content.lines().enumerate() {
-            let line_num = line_num + 1;
-            let trimmed = line.trim();
-
-            // #include
-            if trimmed.starts_with("#include ") {
-                file_info.add_import(trimmed.to_string());
-            }
-            // struct
-            else if trimmed.starts_with("struct ") || trimmed.starts_with("typedef struct") {
-                if let Some(name) = self.extract_c_struct(trimmed) {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Struct, line_num));
-                }
-            }
-            // class (C++)
-            else if trimmed.starts_with("class ") {
-                if let Some(name) = self.extract_identifier(trimmed, "class ") {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Class, line_num));
-                }
-            }
-            // enum
-            else if trimmed.starts_with("enum ") {
-                if let Some(name) = self.extract_identifier(trimmed, "enum ") {
-                    file_info.add_symbol(SymbolDef::new(name, SymbolKind::Enum, line_num));
-                }
-            }
-            // function declaration (간단한 휴리스틱)
-            else if trimmed.contains('(')
-                && !trimmed.starts_with("//")
-                && !trimmed.starts_with("/*")
-                && !trimmed.starts_with("if ")
-                && !trimmed.starts_with("while ")
-                && !trimmed.starts_with("for ")
-                && !trimmed.starts_with("switch ")
-            {
-                if let Some((name, sig)) = self.extract_c_function(trimmed) {
-                    file_info.add_symbol(
-                        SymbolDef::new(name, SymbolKind::Function, line_num).with_signature(sig),
-                    );
-                }
+/// LSP 심볼 → RepoMap 심볼 (필드/매개변수 등은 제외, impl 블록 등은 자식을 끌어올림)
+fn symbols_from_lsp(symbols: &[DocumentSymbol], parent: Option<&str>) -> Vec<SymbolDef> {
+    let mut result = Vec::new();
+    for symbol in symbols {
+        let kind = match symbol.kind {
+            LspSymbolKind::File
+            | LspSymbolKind::Module
+            | LspSymbolKind::Namespace
+            | LspSymbolKind::Package => SymbolKind::Module,
+            LspSymbolKind::Class => SymbolKind::Class,
+            LspSymbolKind::Struct => SymbolKind::Struct,
+            LspSymbolKind::Enum => SymbolKind::Enum,
+            LspSymbolKind::Interface => SymbolKind::Interface,
+            LspSymbolKind::Function if parent.is_none() => SymbolKind::Function,
+            LspSymbolKind::Function | LspSymbolKind::Method | LspSymbolKind::Constructor => {
+                SymbolKind::Method
+            }
+            LspSymbolKind::Constant => SymbolKind::Constant,
+            LspSymbolKind::Variable if parent.is_none() => SymbolKind::Variable,
+            LspSymbolKind::Object => {
+                // rust-analyzer의 impl 블록 등: 자식만 유지
+                result.extend(symbols_from_lsp(&symbol.children, Some(&symbol.name)));
+                continue;
             }
-        }
-    }
-
-    // --- Helper methods ---
-
-    /// 식별자 추출
-    fn extract_identifier(&self, line: &str, prefix: &str) -> Option<String> {
-        let rest = line.strip_prefix(prefix)?;
-        let name: String = rest
-            .chars()
-            .take_while(|c| c.is_alphanumeric() || *c == '_')
-            .collect();
-        if name.is_empty() {
-            None
-        } else {
-            Some(name)
-        }
-    }
-
-    /// 함수 시그니처 추출 (Rust)
-    fn extract_fn_signature(&self, line: &str, prefix: &str) -> Option<(String, String)> {
-        let rest = line.strip_prefix(prefix)?;
-        let name_end = rest.find('(')?;
-        let name: String = rest[..name_end]
-            .chars()
-            .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '<')
-            .collect();
-
-        // 시그니처 추출 (파라미터 부분만)
-        let sig_start = rest.find('(')?;
-        let sig_end = rest.rfind(')').map(|i| i + 1).unwrap_or(rest.len());
-        let signature = rest[sig_start..sig_end].to_string();
-
-        Some((name, signature))
-    }
-
-    /// const 이름 추출
-    fn extract_const_name(&self, line: &str) -> Option<String> {
-        let rest = line.strip_prefix("const ")?;
-        let name: String = rest
-            .chars()
-            .take_while(|c| c.is_alphanumeric() || *c == '_')
-            .collect();
-        if name.is_empty() {
-            None
-        } else {
-            Some(name)
-        }
-    }
-
-    /// Python 클래스 추출
-    fn extract_python_class(&self, line: &str) -> Option<String> {
-        let rest = line.strip_prefix("class ")?;
-        let name: String = rest
-            .chars()
-            .take_while(|c| c.is_alphanumeric() || *c == '_')
-            .collect();
-        if name.is_empty() {
-            None
-        } else {
-            Some(name)
-        }
-    }
-
-    /// Python 함수 추출
-    fn extract_python_function(&self, line: &str) -> Option<(String, String)> {
-        let rest = line.strip_prefix("def ")?;
-        let paren_pos = rest.find('(')?;
-        let name = rest[..paren_pos].to_string();
-
-        let sig_end = rest.find(')').map(|i| i + 1).unwrap_or(rest.len());
-        let signature = rest[paren_pos..sig_end].to_string();
-
-        Some((name, signature))
-    }
+            _ => continue,
+        };
 
-    /// JS 클래스 추출
-    fn extract_js_class(&self, line: &str) -> Option<String> {
-        let rest = line.strip_prefix("class ")?;
-        let name: String = rest
-            .chars()
-            .take_while(|c| c.is_alphanumeric() || *c == '_')
-            .collect();
-        if name.is_empty() {
-            None
-        } else {
-            Some(name)
-        }
+        let start = symbol.selection_range.as_ref().unwrap_or(&symbol.range).start;
+        let mut def = SymbolDef::new(&symbol.name, kind, start.line as usize + 1);
+        def.end_line = symbol.range.end.line as usize + 1;
+        def.signature = symbol.detail.clone().filter(|d| !d.is_empty());
+        def.parent = parent.map(String::from);
+        def.children = symbols_from_lsp(&symbol.children, Some(&symbol.name));
+        result.push(def);
     }
+    result
+}
 
-    /// JS 선언 추출
-    fn extract_js_declaration(&self, line: &str) -> Option<(String, SymbolKind)> {
-        if line.starts_with("function ") || line.starts_with("async function ") {
-            let prefix = if line.starts_with("async ") {
-                "async function "
-            } else {
-                "function "
-            };
-            let rest = line.strip_prefix(prefix)?;
-            let name: String = rest
-                .chars()
-                .take_while(|c| c.is_alphanumeric() || *c == '_')
-                .collect();
-            if !name.is_empty() {
-                return Some((name, SymbolKind::Function));
+/// 정규식 파싱 결과의 가시성/문서 주석을 같은 이름·라인의 LSP 심볼에 복사
+fn merge_symbol_details(symbols: &mut [SymbolDef], parsed: &[SymbolDef]) {
+    for symbol in symbols {
+        if let Some(found) = parsed
+            .iter()
+            .find(|p| p.name == symbol.name && p.line == symbol.line)
+        {
+            if symbol.visibility.is_none() {
+                symbol.visibility = found.visibility.clone();
             }
-        } else if line.starts_with("const ") {
-            let rest = line.strip_prefix("const ")?;
-            let name: String = rest
-                .chars()
-                .take_while(|c| c.is_alphanumeric() || *c == '_')
-                .collect();
-            if !name.is_empty() {
-                return Some((name, SymbolKind::Constant));
+            if symbol.doc.is_none() {
+                symbol.doc = found.doc.clone();
             }
-        }
-        None
-    }
-
-    /// Go 함수 추출
-    fn extract_go_function(&self, line: &str) -> Option<(String, String)> {
-        let rest = line.strip_prefix("func ")?;
-
-        // 메서드인 경우 (receiver 있음)
-        if rest.starts_with('(') {
-            let receiver_end = rest.find(')')?;
-            let after_receiver = &rest[receiver_end + 1..].trim();
-            let name_end = after_receiver.find('(')?;
-            let name = after_receiver[..name_end].trim().to_string();
-            let sig_end = after_receiver
-                .rfind(')')
-                .map(|i| i + 1)
-                .unwrap_or(after_receiver.len());
-            let sig = after_receiver[name_end..sig_end].to_string();
-            Some((name, sig))
-        } else {
-            let name_end = rest.find('(')?;
-            let name = rest[..name_end].trim().to_string();
-            let sig_end = rest.rfind(')').map(|i| i + 1).unwrap_or(rest.len());
-            let sig = rest[name_end..sig_end].to_string();
-            Some((name, sig))
-        }
-    }
-
-    /// Java 클래스 추출
-    fn extract_java_class(&self, line: &str) -> Option<(Option<&str>, String)> {
-        let vis = if line.contains("public ") {
-            Some("public")
-        } else if line.contains("private ") {
-            Some("private")
-        } else if line.contains("protected ") {
-            Some("protected")
-        } else {
-            None
-        };
-
-        let class_pos = line.find("class ")?;
-        let rest = &line[class_pos + 6..];
-        let name: String = rest
-            .chars()
-            .take_while(|c| c.is_alphanumeric() || *c == '_')
-            .collect();
-
-        if name.is_empty() {
-            None
-        } else {
-            Some((vis, name))
-        }
-    }
-
-    /// Java 인터페이스 추출
-    fn extract_java_interface(&self, line: &str) -> Option<(Option<&str>, String)> {
-        let vis = if line.contains("public ") {
-            Some("public")
-        } else {
-            None
-        };
-
-        let iface_pos = line.find("interface ")?;
-        let rest = &line[iface_pos + 10..];
-        let name: String = rest
-            .chars()
-            .take_while(|c| c.is_alphanumeric() || *c == '_')
-            .collect();
-
-        if name.is_empty() {
-            None
-        } else {
-            Some((vis, name))
-        }
-    }
-
-    /// Java enum 추출
-    fn extract_java_enum(&self, line: &str) -> Option<String> {
-        let enum_pos = line.find("enum ")?;
-        let rest = &line[enum_pos + 5..];
-        let name: String = rest
-            .chars()
-            .take_while(|c| c.is_alphanumeric() || *c == '_')
-            .collect();
-
-        if name.is_empty() {
-            None
-        } else {
-            Some(name)
-        }
-    }
-
-    /// C struct 추출
-    fn extract_c_struct(&self, line: &str) -> Option<String> {
-        if line.starts_with("typedef struct") {
-            // typedef struct { ... } Name;
-            None // 복잡한 케이스, 스킵
-        } else {
-            let rest = line.strip_prefix("struct ")?;
-            let name: String = rest
-                .chars()
-                .take_while(|c| c.is_alphanumeric() || *c == '_')
-                .collect();
-            if name.is_empty() {
-                None
-            } else {
-                Some(name)
+            if symbol.signature.is_none() {
+                symbol.signature = found.signature.clone();
             }
         }
-    }
-
-    /// C 함수 추출
-    fn extract_c_function(&self, line: &str) -> Option<(String, String)> {
-        let paren_pos = line.find('(')?;
-        let before_paren = &line[..paren_pos];
-
-        // 마지막 단어가 함수명
-        let words: Vec<&str> = before_paren.split_whitespace().collect();
-        let name = words.last()?.trim_start_matches('*').to_string();
-
-        if name.is_empty() || name.starts_with('{') || name.starts_with('=') {
-            return None;
-        }
-
-        let sig_end = line.find(')').map(|i| i + 1).unwrap_or(line.len());
-        let sig = line[paren_pos..sig_end].to_string();
-
-        Some((name, sig))
+        merge_symbol_details(&mut symbol.children, parsed);
     }
 }
 
@@ -804,22 +489,98 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_extract_rust_function() {
-        let analyzer = RepoAnalyzer::with_defaults("/tmp");
-
-        let sig = analyzer.extract_fn_signature("fn process(data: &str) -> Result<()>", "fn ");
-        assert!(sig.is_some());
-        let (name, params) = sig.unwrap();
-        assert_eq!(name, "process");
-        assert!(params.contains("data"));
-    }
+    fn test_symbols_from_lsp() {
+        let symbols: Vec<DocumentSymbol> = serde_json::from_value(serde_json::json!([
+            {
+                "name": "Parser",
+                "kind": 23,
+                "range": { "start": { "line": 2, "character": 0 }, "end": { "line": 5, "character": 1 } },
+                "selectionRange": { "start": { "line": 3, "character": 11 }, "end": { "line": 3, "character": 17 } },
+                "children": [
+                    { "name": "input", "kind": 8, "range": { "start": { "line": 4, "character": 4 }, "end": { "line": 4, "character": 9 } } }
+                ]
+            },
+            {
+                "name": "impl Parser",
+                "kind": 19,
+                "range": { "start": { "line": 7, "character": 0 }, "end": { "line": 11, "character": 1 } },
+                "children": [
+                    { "name": "parse", "kind": 12, "detail": "fn(&self) -> Ast", "range": { "start": { "line": 8, "character": 4 }, "end": { "line": 10, "character": 5 } } }
+                ]
+            }
+        ]))
+        .unwrap();
+
+        let mut defs = symbols_from_lsp(&symbols, None);
+        assert_eq!(defs.len(), 2);
+        assert_eq!(defs[0].kind, SymbolKind::Struct);
+        assert_eq!((defs[0].line, defs[0].end_line), (4, 6));
+        assert!(defs[0].children.is_empty());
+        assert_eq!(defs[1].kind, SymbolKind::Method);
+        assert_eq!(defs[1].parent.as_deref(), Some("impl Parser"));
+        assert_eq!(defs[1].signature.as_deref(), Some("fn(&self) -> Ast"));
+
+        let parsed = vec![SymbolDef::new("Parser", SymbolKind::Struct, 4).with_visibility("pub")];
+        merge_symbol_details(&mut defs, &parsed);
+        assert_eq!(defs[0].visibility.as_deref(), Some("pub"));
+        assert!(defs[1].visibility.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_symbol_files_without_server() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lib.rs"), "pub struct Parser;\n").unwrap();
+
+        // 언어 서버를 쓸 수 없으면 정규식 인덱스로 대체
+        let analyzer =
+            RepoAnalyzer::with_defaults(dir.path()).with_lsp(Arc::new(LspManager::disabled()));
+        let mut map = analyzer.analyze().await.unwrap();
+        let files = analyzer.find_symbol_files(&map, "Parser").await;
+        assert_eq!(files, vec![dir.path().join("lib.rs")]);
+
+        analyzer.rank_for_symbols(&mut map, &["Parser"]).await;
+        assert!(map.files[0].importance_score > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_analysis() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.rs");
+        std::fs::write(&a, "pub struct A;\n").unwrap();
+        std::fs::write(dir.path().join("b.rs"), "pub fn b() {}\n").unwrap();
+
+        let analyzer = RepoAnalyzer::with_defaults(dir.path()).with_project_cache();
+        assert_eq!(analyzer.analyze().await.unwrap().files.len(), 2);
+        let mut cache = RepoMapCache::open(&RepoMapCache::project_path(dir.path())).unwrap();
+        assert_eq!(cache.len().unwrap(), 2);
+
+        // 스탬프가 같으면 파싱하지 않고 캐시 항목 사용
+        let mut info = FileInfo::new(a.clone(), "a.rs".into(), "rust".into());
+        info.add_symbol(SymbolDef::new("FromCache", SymbolKind::Struct, 1));
+        let stamp = FileStamp::from_metadata(&std::fs::metadata(&a).unwrap());
+        let entry = CachedFile {
+            stamp,
+            hash: String::new(),
+            lsp: false,
+            info,
+        };
+        let keep: HashSet<String> = ["a.rs", "b.rs"].map(String::from).into();
+        cache.store(&[entry], &keep).unwrap();
+        let map = analyzer.analyze().await.unwrap();
+        assert_eq!(map.find_files_by_symbol("FromCache").len(), 1);
 
-    #[test]
-    fn test_extract_python_class() {
-        let analyzer = RepoAnalyzer::with_defaults("/tmp");
+        // 바뀐 파일은 다시 파싱, 사라진 파일은 캐시에서 제거
+        std::fs::write(&a, "pub struct A;\npub struct Renamed;\n").unwrap();
+        std::fs::remove_file(dir.path().join("b.rs")).unwrap();
+        let map = analyzer.analyze().await.unwrap();
+        assert_eq!(map.files.len(), 1);
+        assert_eq!(map.find_files_by_symbol("Renamed").len(), 1);
+        assert!(map.find_files_by_symbol("FromCache").is_empty());
+        assert_eq!(cache.len().unwrap(), 1);
+        assert_ne!(cache.stamp("a.rs"), Some(stamp));
 
-        let name = analyzer.extract_python_class("class MyClass(BaseClass):");
-        assert_eq!(name, Some("MyClass".to_string()));
+        assert_eq!(analyzer.invalidate(&[a]).unwrap(), 1);
+        assert!(cache.is_empty().unwrap());
     }
 
     #[test]
@@ -838,5 +599,13 @@ mod tests {
             analyzer.detect_language(Path::new("test.tsx")),
             "typescript".to_string()
         );
+        assert_eq!(
+            analyzer.detect_language(Path::new("Program.cs")),
+            "csharp".to_string()
+        );
+        assert_eq!(
+            analyzer.detect_language(Path::new("notes.txt")),
+            "unknown".to_string()
+        );
     }
 }
@@ -0,0 +1,336 @@
+//! Symbol Budget - 심볼 단위 토큰 예산
+//!
+//! 파일 단위 예산(`to_string_within_budget`)은 큰 파일 하나가 관련 없는 심볼로
+//! 예산을 다 써버릴 수 있습니다. 여기서는 심볼마다 점수를 매겨 높은 순으로
+//! 시그니처만(본문 생략) 담아, 여러 파일에 걸쳐 가장 관련 있는 정의에 예산을 씁니다.
+//!
+//! ## 심볼 점수
+//! - 파일 중요도 (`FileRanker`/`rank_for_query` 결과)
+//! - 심볼 종류 (타입 > 함수 > 메서드 > 상수 > 변수)
+//! - 공개 여부, 문서 주석 유무
+//! - 다른 파일의 임포트에서 언급된 횟수
+//! - 같은 파일 안에서는 순위가 내려갈수록 감쇠 (한 파일이 예산을 독점하지 않도록)
+//!
+//! 선택된 심볼은 파일별로 원래 순서대로 출력하고, 생략된 심볼 수는 `⋮`로 표시합니다.
+//! 메서드만 선택되면 부모 타입 라인을 문맥으로 함께 넣습니다.
+
+use super::types::{FileInfo, RepoMap, SymbolDef, SymbolKind};
+use std::collections::{HashMap, HashSet};
+
+/// 생략 표시 라인 예상 토큰
+const ELISION_TOKENS: usize = 3;
+
+/// 같은 파일에서 k번째 심볼 점수에 곱하는 감쇠 (`DECAY^k`)
+const SAME_FILE_DECAY: f64 = 0.95;
+
+/// 심볼 위치 (파일, 최상위 심볼, 자식)
+type SymbolKey = (usize, usize, Option<usize>);
+
+/// 점수가 매겨진 심볼
+struct RankedSymbol {
+    key: SymbolKey,
+    score: f64,
+}
+
+impl RepoMap {
+    /// 토큰 예산 내에서 심볼 단위로 압축된 맵 생성 (시그니처만)
+    pub fn to_symbols_within_budget(&self, max_tokens: usize) -> String {
+        let mut header = vec![
+            format!("# Repository: {}", self.root.display()),
+            format!("# Files: {}", self.files.len()),
+            String::new(),
+        ];
+        let mut used = estimate_tokens(&header.join("\n"));
+
+        // 1. 점수 높은 순으로 예산이 허락하는 만큼 선택
+        let mut selected: HashSet<SymbolKey> = HashSet::new();
+        let mut open_files: HashSet<usize> = HashSet::new();
+        let mut skipped = 0;
+        for ranked in self.rank_symbols() {
+            let (file_idx, top_idx, child_idx) = ranked.key;
+            let file = &self.files[file_idx];
+
+            let mut cost = estimate_tokens(&symbol_line(ranked.key, file));
+            if !open_files.contains(&file_idx) {
+                cost += estimate_tokens(&format!("# {}", file.relative_path)) + ELISION_TOKENS;
+            }
+            let needs_parent =
+                child_idx.is_some() && !selected.contains(&(file_idx, top_idx, None));
+            if needs_parent {
+                cost += estimate_tokens(&symbol_line((file_idx, top_idx, None), file));
+            }
+
+            if used + cost > max_tokens {
+                skipped += 1;
+                continue;
+            }
+            used += cost;
+            open_files.insert(file_idx);
+            if needs_parent {
+                selected.insert((file_idx, top_idx, None));
+            }
+            selected.insert(ranked.key);
+        }
+
+        // 2. 파일 중요도 순, 파일 안에서는 원래 순서로 출력
+        let mut files: Vec<usize> = open_files.into_iter().collect();
+        files.sort_by(|&a, &b| {
+            self.files[b]
+                .importance_score
+                .total_cmp(&self.files[a].importance_score)
+                .then(a.cmp(&b))
+        });
+
+        let mut lines = std::mem::take(&mut header);
+        for &file_idx in &files {
+            let file = &self.files[file_idx];
+            lines.push(format!("# {}", file.relative_path));
+            let mut omitted = 0;
+            for (top_idx, symbol) in file.symbols.iter().enumerate() {
+                if selected.contains(&(file_idx, top_idx, None)) {
+                    lines.push(symbol_line((file_idx, top_idx, None), file));
+                } else if symbol.kind != SymbolKind::Import {
+                    omitted += 1;
+                }
+                for child_idx in 0..symbol.children.len() {
+                    let key = (file_idx, top_idx, Some(child_idx));
+                    if selected.contains(&key) {
+                        lines.push(symbol_line(key, file));
+                    } else {
+                        omitted += 1;
+                    }
+                }
+            }
+            if omitted > 0 {
+                lines.push(format!("⋮ {} more", omitted));
+            }
+            lines.push(String::new());
+        }
+
+        let omitted_files = self.files.len() - files.len();
+        if skipped > 0 || omitted_files > 0 {
+            lines.push(format!(
+                "# ... {} more symbols in {} more files",
+                skipped, omitted_files
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    /// 모든 심볼을 점수 높은 순으로 정렬
+    fn rank_symbols(&self) -> Vec<RankedSymbol> {
+        // 랭킹 전이면 모든 파일을 같은 중요도로 취급
+        let ranked = self.files.iter().any(|f| f.importance_score > 0.0);
+        let references = self.import_mentions();
+
+        let mut symbols = Vec::new();
+        for (file_idx, file) in self.files.iter().enumerate() {
+            let file_score = if ranked { file.importance_score } else { 1.0 };
+            let mut file_symbols = Vec::new();
+            for (top_idx, symbol) in file.symbols.iter().enumerate() {
+                let keys = std::iter::once((symbol, None)).chain(
+                    symbol
+                        .children
+                        .iter()
+                        .enumerate()
+                        .map(|(i, child)| (child, Some(i))),
+                );
+                for (def, child_idx) in keys {
+                    if def.kind == SymbolKind::Import {
+                        continue;
+                    }
+                    let refs = references.get(def.name.as_str()).copied().unwrap_or(0);
+                    file_symbols.push(RankedSymbol {
+                        key: (file_idx, top_idx, child_idx),
+                        score: (0.2 + 0.8 * file_score) * symbol_weight(def, refs),
+                    });
+                }
+            }
+
+            file_symbols.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.key.cmp(&b.key)));
+            let mut decay = 1.0;
+            for mut symbol in file_symbols {
+                symbol.score *= decay;
+                decay *= SAME_FILE_DECAY;
+                symbols.push(symbol);
+            }
+        }
+
+        symbols.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.key.cmp(&b.key)));
+        symbols
+    }
+
+    /// 심볼 이름 → 다른 파일 임포트에서 언급된 횟수
+    fn import_mentions(&self) -> HashMap<&str, usize> {
+        let mut mentions: HashMap<&str, usize> = HashMap::new();
+        for file in &self.files {
+            for symbol in &file.symbols {
+                let names = std::iter::once(symbol).chain(symbol.children.iter());
+                for def in names {
+                    let count = self
+                        .files
+                        .iter()
+                        .filter(|other| other.path != file.path)
+                        .filter(|other| other.imports.iter().any(|i| mentions_name(i, &def.name)))
+                        .count();
+                    if count > 0 {
+                        *mentions.entry(def.name.as_str()).or_insert(0) += count;
+                    }
+                }
+            }
+        }
+        mentions
+    }
+}
+
+/// 심볼 자체의 가중치 (종류, 공개 여부, 문서, 참조)
+fn symbol_weight(symbol: &SymbolDef, references: usize) -> f64 {
+    let kind = match symbol.kind {
+        SymbolKind::Struct | SymbolKind::Class | SymbolKind::Interface | SymbolKind::Enum => 1.0,
+        SymbolKind::Function | SymbolKind::TypeAlias | SymbolKind::Macro => 0.8,
+        SymbolKind::Method => 0.6,
+        SymbolKind::Constant => 0.5,
+        SymbolKind::Module => 0.4,
+        SymbolKind::Variable => 0.3,
+        SymbolKind::Import => 0.0,
+    };
+    let visibility = match symbol.visibility.as_deref() {
+        Some("pub" | "public" | "export") => 1.0,
+        Some(_) => 0.6,
+        None => 0.8,
+    };
+    let doc = if symbol.doc.is_some() { 1.1 } else { 1.0 };
+    let references = 1.0 + 0.25 * references.min(4) as f64;
+    kind * visibility * doc * references
+}
+
+/// 임포트 문자열이 식별자 `name`을 포함하는지 (단어 경계 기준)
+fn mentions_name(import: &str, name: &str) -> bool {
+    import
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|part| part == name)
+}
+
+/// 심볼 한 줄 (시그니처만, 자식은 들여쓰기)
+fn symbol_line((_, top_idx, child_idx): SymbolKey, file: &FileInfo) -> String {
+    let symbol = &file.symbols[top_idx];
+    match child_idx {
+        Some(i) => format!("  {}", symbol.children[i].to_signature_string()),
+        None => symbol.to_signature_string(),
+    }
+}
+
+/// 대략적인 토큰 수 (4 문자 = 1 토큰)
+fn estimate_tokens(text: &str) -> usize {
+    text.len() / 4 + 1
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn file(name: &str, symbols: Vec<SymbolDef>) -> FileInfo {
+        let mut info = FileInfo::new(
+            PathBuf::from(format!("/project/src/{}", name)),
+            format!("src/{}", name),
+            "rust".into(),
+        );
+        for symbol in symbols {
+            info.add_symbol(symbol);
+        }
+        info
+    }
+
+    /// 큰 파일 하나 + 작은 핵심 파일
+    fn sample_map() -> RepoMap {
+        let mut map = RepoMap::new(PathBuf::from("/project"));
+
+        let helpers = (0..200)
+            .map(|i| {
+                SymbolDef::new(format!("helper_{}", i), SymbolKind::Function, i + 1)
+                    .with_signature("(value: &str) -> String")
+            })
+            .collect();
+        map.add_file(file("big.rs", helpers));
+
+        let mut engine = SymbolDef::new("Engine", SymbolKind::Struct, 1).with_visibility("pub");
+        engine.add_child(
+            SymbolDef::new("run", SymbolKind::Method, 3)
+                .with_visibility("pub")
+                .with_signature("(&self) -> Result<()>"),
+        );
+        map.add_file(file("engine.rs", vec![engine]));
+
+        let mut main = file(
+            "main.rs",
+            vec![SymbolDef::new("main", SymbolKind::Function, 1)],
+        );
+        main.add_import("crate::engine::Engine".into());
+        map.add_file(main);
+        map
+    }
+
+    #[test]
+    fn test_symbol_budget_spans_files() {
+        let map = sample_map();
+        let output = map.to_symbols_within_budget(200);
+
+        // 큰 파일이 예산을 다 쓰지 않고 다른 파일의 핵심 정의가 들어감
+        assert!(output.contains("# src/engine.rs"));
+        assert!(output.contains("struct pub Engine:1"));
+        assert!(output.contains("# src/main.rs"));
+        assert!(output.contains("# src/big.rs"));
+        assert!(output.contains("⋮"));
+        assert!(output.contains("more symbols"));
+        assert!(output.len() / 4 <= 200);
+
+        // 파일 단위 예산은 큰 파일만으로 예산 초과
+        assert!(!map.to_string_within_budget(200).contains("Engine"));
+    }
+
+    #[test]
+    fn test_symbol_budget_follows_importance() {
+        let mut map = sample_map();
+        map.files[0].importance_score = 1.0;
+        map.files[1].importance_score = 0.1;
+        map.files[2].importance_score = 0.1;
+
+        let output = map.to_symbols_within_budget(120);
+        assert!(output.contains("helper_0"));
+        assert!(!output.contains("Engine"));
+    }
+
+    #[test]
+    fn test_child_includes_parent_context() {
+        let mut map = RepoMap::new(PathBuf::from("/project"));
+        let mut service = SymbolDef::new("Service", SymbolKind::Class, 1);
+        service.add_child(
+            SymbolDef::new("handle", SymbolKind::Method, 2)
+                .with_visibility("pub")
+                .with_doc("Handles a request"),
+        );
+        map.add_file(file("service.rs", vec![service]));
+
+        let output = map.to_symbols_within_budget(1000);
+        let class = output.find("class  Service:1").unwrap();
+        let method = output.find("  method pub handle:2").unwrap();
+        assert!(class < method);
+        assert!(!output.contains("more symbols"));
+    }
+
+    #[test]
+    fn test_symbol_weight() {
+        let public = SymbolDef::new("A", SymbolKind::Struct, 1).with_visibility("pub");
+        let private = SymbolDef::new("b", SymbolKind::Variable, 1).with_visibility("priv");
+        assert!(symbol_weight(&public, 0) > symbol_weight(&private, 0));
+        assert!(symbol_weight(&public, 2) > symbol_weight(&public, 0));
+        assert!(mentions_name("crate::engine::Engine", "Engine"));
+        assert!(!mentions_name("crate::engine::EngineConfig", "Engine"));
+    }
+}
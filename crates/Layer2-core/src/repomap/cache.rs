@@ -0,0 +1,314 @@
+//! Repo Map Cache - 파일별 분석 결과 증분 인덱스
+//!
+//! 분석 결과(`FileInfo`)를 `.forgecode/cache/repomap.db`(SQLite)에 파일 단위로
+//! 저장해, 다음 분석에서는 바뀐 파일만 다시 파싱합니다.
+//!
+//! ## 무효화
+//! 1. mtime + 크기가 같으면 그대로 재사용 (파일 내용을 읽지 않음)
+//! 2. 다르면 내용 해시(SHA-256)를 비교해 같으면 재사용 (스탬프만 갱신)
+//! 3. 해시도 다르면 다시 파싱
+//!
+//! 사라진 파일은 분석이 끝날 때 삭제되고, 파일 감시자나 편집 도구가
+//! [`RepoMapCache::invalidate`]로 특정 파일을 즉시 무효화할 수도 있습니다.
+
+use super::types::FileInfo;
+use crate::config::CONFIG_DIR_NAME;
+use forge_foundation::Result;
+use ring::digest::{digest, SHA256};
+use rusqlite::{params, Connection};
+use std::collections::{HashMap, HashSet};
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// 프로젝트 설정 디렉토리 기준 캐시 파일 경로
+pub const REPOMAP_CACHE_FILE: &str = "cache/repomap.db";
+
+/// 스키마 버전 (`FileInfo` 형식이 바뀌면 올림 - 기존 캐시는 버려짐)
+const SCHEMA_VERSION: i32 = 1;
+
+/// 파일 변경 감지용 스탬프 (mtime + 크기)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FileStamp {
+    /// 수정 시각 (UNIX epoch 기준 나노초)
+    pub mtime: i64,
+    /// 파일 크기 (바이트)
+    pub size: u64,
+}
+
+impl FileStamp {
+    pub(crate) fn from_metadata(meta: &Metadata) -> Self {
+        let mtime = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_nanos() as i64);
+        Self {
+            mtime,
+            size: meta.len(),
+        }
+    }
+}
+
+/// 캐시된 파일 분석 결과
+#[derive(Debug, Clone)]
+pub(crate) struct CachedFile {
+    pub stamp: FileStamp,
+    /// 내용 해시 (hex)
+    pub hash: String,
+    /// 언어 서버 심볼로 분석했는지
+    pub lsp: bool,
+    pub info: FileInfo,
+}
+
+/// 파일 내용 해시 (SHA-256 hex)
+pub(crate) fn content_hash(content: &str) -> String {
+    digest(&SHA256, content.as_bytes())
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// RepoMap 증분 인덱스 (SQLite)
+pub struct RepoMapCache {
+    conn: Connection,
+}
+
+impl RepoMapCache {
+    /// 프로젝트의 기본 캐시 경로 (`<root>/.forgecode/cache/repomap.db`)
+    pub fn project_path(root: &Path) -> PathBuf {
+        root.join(CONFIG_DIR_NAME).join(REPOMAP_CACHE_FILE)
+    }
+
+    /// 캐시 열기 (없으면 생성)
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Self::init(Connection::open(path)?)
+    }
+
+    /// 메모리 캐시 (테스트용)
+    pub fn in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        let version: i32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        if version != SCHEMA_VERSION {
+            conn.execute_batch("DROP TABLE IF EXISTS files;")?;
+        }
+        conn.execute_batch(&format!(
+            "PRAGMA journal_mode = WAL;
+             PRAGMA user_version = {};
+             CREATE TABLE IF NOT EXISTS files (
+                 path  TEXT PRIMARY KEY,
+                 mtime INTEGER NOT NULL,
+                 size  INTEGER NOT NULL,
+                 hash  TEXT NOT NULL,
+                 lsp   INTEGER NOT NULL,
+                 info  TEXT NOT NULL
+             );",
+            SCHEMA_VERSION
+        ))?;
+        Ok(Self { conn })
+    }
+
+    /// 캐시된 파일 수
+    pub fn len(&self) -> Result<usize> {
+        let count: i64 = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM files", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+
+    /// 캐시가 비어 있는지
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// 파일 무효화 (상대 경로) - 다음 분석에서 다시 파싱됨
+    ///
+    /// 삭제된 항목 수를 반환합니다.
+    pub fn invalidate<S: AsRef<str>>(&self, relative_paths: &[S]) -> Result<usize> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("DELETE FROM files WHERE path = ?1")?;
+        let mut removed = 0;
+        for path in relative_paths {
+            removed += stmt.execute([path.as_ref()])?;
+        }
+        Ok(removed)
+    }
+
+    /// 전체 캐시 삭제
+    pub fn clear(&self) -> Result<()> {
+        self.conn.execute("DELETE FROM files", [])?;
+        Ok(())
+    }
+
+    /// 캐시된 파일 한 개의 스탬프
+    #[cfg(test)]
+    pub(crate) fn stamp(&self, relative_path: &str) -> Option<FileStamp> {
+        use rusqlite::OptionalExtension;
+
+        self.conn
+            .query_row(
+                "SELECT mtime, size FROM files WHERE path = ?1",
+                [relative_path],
+                |row| {
+                    Ok(FileStamp {
+                        mtime: row.get(0)?,
+                        size: row.get::<_, i64>(1)? as u64,
+                    })
+                },
+            )
+            .optional()
+            .ok()
+            .flatten()
+    }
+
+    /// 전체 캐시 로드 (상대 경로 → 항목, 손상된 항목은 건너뜀)
+    pub(crate) fn load(&self) -> Result<HashMap<String, CachedFile>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT path, mtime, size, hash, lsp, info FROM files")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                FileStamp {
+                    mtime: row.get(1)?,
+                    size: row.get::<_, i64>(2)? as u64,
+                },
+                row.get::<_, String>(3)?,
+                row.get::<_, bool>(4)?,
+                row.get::<_, String>(5)?,
+            ))
+        })?;
+
+        let mut entries = HashMap::new();
+        for row in rows {
+            let (path, stamp, hash, lsp, info) = row?;
+            if let Ok(info) = serde_json::from_str(&info) {
+                entries.insert(
+                    path,
+                    CachedFile {
+                        stamp,
+                        hash,
+                        lsp,
+                        info,
+                    },
+                );
+            }
+        }
+        Ok(entries)
+    }
+
+    /// 분석 결과 저장 (한 트랜잭션)
+    ///
+    /// `updated`를 저장하고, `keep`에 없는 항목(사라진 파일)은 삭제합니다.
+    /// 삭제된 항목 수를 반환합니다.
+    pub(crate) fn store(
+        &mut self,
+        updated: &[CachedFile],
+        keep: &HashSet<String>,
+    ) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        {
+            let mut upsert = tx.prepare_cached(
+                "INSERT OR REPLACE INTO files (path, mtime, size, hash, lsp, info)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            for entry in updated {
+                let info = serde_json::to_string(&entry.info)?;
+                upsert.execute(params![
+                    entry.info.relative_path,
+                    entry.stamp.mtime,
+                    entry.stamp.size as i64,
+                    entry.hash,
+                    entry.lsp,
+                    info
+                ])?;
+            }
+        }
+
+        let stale: Vec<String> = {
+            let mut stmt = tx.prepare("SELECT path FROM files")?;
+            let paths = stmt
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            paths.into_iter().filter(|p| !keep.contains(p)).collect()
+        };
+        for path in &stale {
+            tx.execute("DELETE FROM files WHERE path = ?1", [path])?;
+        }
+
+        tx.commit()?;
+        Ok(stale.len())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached(path: &str, mtime: i64) -> CachedFile {
+        CachedFile {
+            stamp: FileStamp { mtime, size: 10 },
+            hash: content_hash(path),
+            lsp: false,
+            info: FileInfo::new(PathBuf::from(path), path.to_string(), "rust".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_store_load_and_prune() {
+        let mut cache = RepoMapCache::in_memory().unwrap();
+        assert!(cache.is_empty().unwrap());
+
+        let keep: HashSet<String> = ["a.rs", "b.rs"].map(String::from).into();
+        let removed = cache
+            .store(&[cached("a.rs", 1), cached("b.rs", 2)], &keep)
+            .unwrap();
+        assert_eq!(removed, 0);
+        let entries = cache.load().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries["b.rs"].stamp, FileStamp { mtime: 2, size: 10 });
+        assert_eq!(entries["a.rs"].info.language, "rust");
+
+        // b.rs 삭제됨
+        let keep: HashSet<String> = ["a.rs"].map(String::from).into();
+        assert_eq!(cache.store(&[cached("a.rs", 3)], &keep).unwrap(), 1);
+        assert_eq!(cache.len().unwrap(), 1);
+        assert_eq!(cache.stamp("a.rs").unwrap().mtime, 3);
+
+        assert_eq!(cache.invalidate(&["a.rs", "missing.rs"]).unwrap(), 1);
+        assert!(cache.is_empty().unwrap());
+    }
+
+    #[test]
+    fn test_open_persists() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = RepoMapCache::project_path(dir.path());
+        assert!(path.ends_with(".forgecode/cache/repomap.db"));
+
+        let keep: HashSet<String> = ["a.rs"].map(String::from).into();
+        RepoMapCache::open(&path)
+            .unwrap()
+            .store(&[cached("a.rs", 1)], &keep)
+            .unwrap();
+        assert_eq!(RepoMapCache::open(&path).unwrap().len().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_content_hash() {
+        assert_eq!(content_hash("a"), content_hash("a"));
+        assert_ne!(content_hash("a"), content_hash("b"));
+        assert_eq!(content_hash("").len(), 64);
+    }
+}
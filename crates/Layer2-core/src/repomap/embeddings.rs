@@ -0,0 +1,386 @@
+//! Code Embeddings - repomap 심볼/청크 임베딩 인덱스
+//!
+//! 분석기가 추출한 심볼마다 코드 청크를 만들고 벡터로 임베딩해
+//! "인증은 어디서 처리하나" 같은 질의를 코사인 유사도로 검색합니다.
+//!
+//! 기본 임베더(`HashingEmbedder`)는 외부 모델 없이 동작합니다.
+//! - 식별자 분리 (`validateToken`, `validate_token` → validate, token)
+//! - 간단한 어간 처리 (`handled`, `handler` → handl)
+//! - 긴 단어의 접두사 (`authentication` → auth)
+//! - feature hashing 후 L2 정규화
+//!
+//! 모델 기반 임베딩이 필요하면 `Embedder`를 구현해 `EmbeddingIndex::build`에 넘기면 됩니다.
+
+use super::types::{FileInfo, RepoMap, SymbolDef};
+use serde::{Deserialize, Serialize};
+
+/// 청크 최대 라인 수
+const MAX_CHUNK_LINES: usize = 40;
+
+/// 결과에 넣을 스니펫 라인 수
+const SNIPPET_LINES: usize = 8;
+
+/// 기본 벡터 차원
+const DEFAULT_DIMENSION: usize = 512;
+
+/// 검색에서 무시할 단어 (키워드, 흔한 영어 단어)
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "with", "from", "this", "that", "into", "where", "what", "how", "which",
+    "are", "is", "in", "of", "to", "a", "an", "or", "be", "it", "on", "by", "as", "at", "fn",
+    "pub", "let", "mut", "self", "impl", "use", "return", "def", "function", "const", "var", "new",
+    "none", "some", "true", "false", "crate", "super", "async", "await", "struct", "enum",
+];
+
+// ============================================================================
+// Embedder
+// ============================================================================
+
+/// 텍스트 임베딩
+pub trait Embedder: Send + Sync {
+    /// 벡터 차원
+    fn dimension(&self) -> usize;
+
+    /// 텍스트를 L2 정규화된 벡터로 변환
+    fn embed(&self, text: &str) -> Vec<f32>;
+}
+
+/// 외부 모델이 필요 없는 feature hashing 임베더
+#[derive(Debug, Clone, Copy)]
+pub struct HashingEmbedder {
+    dimension: usize,
+}
+
+impl HashingEmbedder {
+    /// 차원 지정 생성
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            dimension: dimension.max(16),
+        }
+    }
+
+    fn add_feature(&self, vector: &mut [f32], feature: &str, weight: f32) {
+        let hash = fnv1a(feature);
+        let index = (hash % self.dimension as u64) as usize;
+        let sign = if hash & (1 << 63) == 0 { 1.0 } else { -1.0 };
+        vector[index] += sign * weight;
+    }
+}
+
+impl Default for HashingEmbedder {
+    fn default() -> Self {
+        Self::new(DEFAULT_DIMENSION)
+    }
+}
+
+impl Embedder for HashingEmbedder {
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn embed(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0; self.dimension];
+        for token in tokenize(text) {
+            let stem = stem(&token);
+            self.add_feature(&mut vector, stem, 1.0);
+            // "auth" ↔ "authentication"/"authorize"
+            if stem.len() > 4 {
+                self.add_feature(&mut vector, &stem[..4], 0.5);
+            }
+        }
+        normalize(&mut vector);
+        vector
+    }
+}
+
+/// FNV-1a 64bit
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// 식별자 분리 + 소문자화 (camelCase, snake_case, 경로)
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for word in text.split(|c: char| !c.is_alphanumeric()) {
+        let mut current = String::new();
+        let mut prev_lower = false;
+        for c in word.chars() {
+            if c.is_uppercase() && prev_lower && !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            prev_lower = c.is_lowercase() || c.is_ascii_digit();
+            current.extend(c.to_lowercase());
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+    }
+    tokens.retain(|t| {
+        t.len() > 1 && !t.chars().all(|c| c.is_ascii_digit()) && !STOP_WORDS.contains(&t.as_str())
+    });
+    tokens
+}
+
+/// 간단한 어간 처리
+fn stem(token: &str) -> &str {
+    for suffix in ["ing", "ed", "er", "es", "s"] {
+        if let Some(stripped) = token.strip_suffix(suffix) {
+            if stripped.len() >= 3 {
+                return stripped;
+            }
+        }
+    }
+    token
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+}
+
+/// 정규화된 벡터의 코사인 유사도
+pub(crate) fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// 심볼 요약 텍스트 (이름, 부모, 시그니처, 문서, 경로 - 파일을 읽지 않음)
+pub(crate) fn symbol_text(file: &FileInfo, symbol: &SymbolDef) -> String {
+    format!(
+        "{} {} {} {} {}",
+        symbol.name,
+        symbol.parent.as_deref().unwrap_or(""),
+        symbol.signature.as_deref().unwrap_or(""),
+        symbol.doc.as_deref().unwrap_or(""),
+        file.relative_path
+    )
+}
+
+// ============================================================================
+// Index
+// ============================================================================
+
+/// 검색 단위 코드 청크
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeChunk {
+    /// 상대 경로
+    pub file: String,
+    /// 시작 라인 (1부터)
+    pub line: usize,
+    /// 끝 라인
+    pub end_line: usize,
+    /// 심볼 이름 (심볼 없는 파일은 None)
+    pub symbol: Option<String>,
+    /// 심볼 종류
+    pub kind: Option<String>,
+    /// 앞부분 코드
+    pub snippet: String,
+}
+
+/// 검색 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    #[serde(flatten)]
+    pub chunk: CodeChunk,
+    /// 코사인 유사도
+    pub score: f32,
+}
+
+/// 임베딩 인덱스
+pub struct EmbeddingIndex {
+    chunks: Vec<CodeChunk>,
+    vectors: Vec<Vec<f32>>,
+}
+
+impl EmbeddingIndex {
+    /// RepoMap에서 인덱스 생성 (파일을 다시 읽으므로 blocking 컨텍스트에서 호출)
+    pub fn build(map: &RepoMap, embedder: &dyn Embedder) -> Self {
+        let mut chunks = Vec::new();
+        let mut texts = Vec::new();
+
+        for file in &map.files {
+            let Ok(content) = std::fs::read_to_string(&file.path) else {
+                continue;
+            };
+            let lines: Vec<&str> = content.lines().collect();
+            if lines.is_empty() {
+                continue;
+            }
+
+            let mut symbols: Vec<&SymbolDef> = file
+                .symbols
+                .iter()
+                .flat_map(|s| std::iter::once(s).chain(s.children.iter()))
+                .collect();
+            symbols.sort_by_key(|s| s.line);
+
+            if symbols.is_empty() {
+                // 심볼이 없으면 고정 크기 창으로 나눔
+                for start in (0..lines.len()).step_by(MAX_CHUNK_LINES) {
+                    let end = (start + MAX_CHUNK_LINES).min(lines.len());
+                    let body = lines[start..end].join("\n");
+                    texts.push(format!("{}\n{}", file.relative_path, body));
+                    chunks.push(CodeChunk {
+                        file: file.relative_path.clone(),
+                        line: start + 1,
+                        end_line: end,
+                        symbol: None,
+                        kind: None,
+                        snippet: snippet(&lines[start..end]),
+                    });
+                }
+                continue;
+            }
+
+            for (i, symbol) in symbols.iter().enumerate() {
+                let start = symbol.line.clamp(1, lines.len());
+                // 다음 심볼 전까지 (최대 MAX_CHUNK_LINES)
+                let next = symbols[i + 1..]
+                    .iter()
+                    .map(|s| s.line)
+                    .find(|&line| line > start)
+                    .unwrap_or(lines.len() + 1);
+                let end = (next - 1)
+                    .clamp(start, start + MAX_CHUNK_LINES - 1)
+                    .min(lines.len());
+                let body = &lines[start - 1..end];
+
+                // 이름/경로/문서는 본문보다 가중치를 높임 (반복)
+                let header = format!(
+                    "{} {} {} {}",
+                    symbol.name,
+                    symbol.parent.as_deref().unwrap_or(""),
+                    symbol.doc.as_deref().unwrap_or(""),
+                    file.relative_path
+                );
+                texts.push(format!("{}\n{}\n{}", header, header, body.join("\n")));
+                chunks.push(CodeChunk {
+                    file: file.relative_path.clone(),
+                    line: start,
+                    end_line: end,
+                    symbol: Some(symbol.name.clone()),
+                    kind: Some(symbol.kind.as_str().to_string()),
+                    snippet: snippet(body),
+                });
+            }
+        }
+
+        let vectors = texts.iter().map(|text| embedder.embed(text)).collect();
+        Self { chunks, vectors }
+    }
+
+    /// 청크 수
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// 비어있는지
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// 질의와 가까운 청크 (경로 접두사 필터 선택)
+    pub fn search(
+        &self,
+        query: &str,
+        embedder: &dyn Embedder,
+        path: Option<&str>,
+        limit: usize,
+    ) -> Vec<SearchHit> {
+        let query = embedder.embed(query);
+        let mut hits: Vec<SearchHit> = self
+            .chunks
+            .iter()
+            .zip(&self.vectors)
+            .filter(|(chunk, _)| match path {
+                Some(prefix) => chunk.file.starts_with(prefix),
+                None => true,
+            })
+            .map(|(chunk, vector)| SearchHit {
+                chunk: chunk.clone(),
+                score: cosine(&query, vector),
+            })
+            .filter(|hit| hit.score > 0.0)
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(limit);
+        hits
+    }
+}
+
+fn snippet(lines: &[&str]) -> String {
+    lines[..lines.len().min(SNIPPET_LINES)].join("\n")
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repomap::{FileInfo, SymbolKind};
+
+    #[test]
+    fn test_tokenize_and_stem() {
+        assert_eq!(
+            tokenize("fn validateToken(auth_header: &str) -> HTTPResult"),
+            vec!["validate", "token", "auth", "header", "str", "httpresult"]
+        );
+        assert_eq!(stem("handled"), "handl");
+        assert_eq!(stem("handler"), "handl");
+        assert_eq!(stem("tokens"), "token");
+        assert_eq!(stem("is"), "is");
+    }
+
+    #[test]
+    fn test_embedding_similarity() {
+        let embedder = HashingEmbedder::default();
+        let query = embedder.embed("where is authentication handled");
+        let auth = embedder.embed("fn authenticate_user(token) // handles login auth");
+        let render = embedder.embed("fn render_table(rows) // draws the status bar");
+
+        let norm: f32 = query.iter().map(|v| v * v).sum();
+        assert!((norm - 1.0).abs() < 1e-4);
+        assert!(cosine(&query, &auth) > cosine(&query, &render));
+    }
+
+    #[test]
+    fn test_index_search() {
+        let dir = tempfile::tempdir().unwrap();
+        let auth_path = dir.path().join("auth.rs");
+        let ui_path = dir.path().join("ui.rs");
+        std::fs::write(
+            &auth_path,
+            "/// Checks the session token\npub fn verify_session(token: &str) -> bool {\n    !token.is_empty()\n}\n",
+        )
+        .unwrap();
+        std::fs::write(&ui_path, "pub fn draw_header() {}\n").unwrap();
+
+        let mut map = RepoMap::new(dir.path().to_path_buf());
+        let mut auth = FileInfo::new(auth_path, "src/auth.rs".into(), "rust".into());
+        auth.add_symbol(
+            SymbolDef::new("verify_session", SymbolKind::Function, 2)
+                .with_doc("Checks the session token"),
+        );
+        map.add_file(auth);
+        let mut ui = FileInfo::new(ui_path, "src/ui.rs".into(), "rust".into());
+        ui.add_symbol(SymbolDef::new("draw_header", SymbolKind::Function, 1));
+        map.add_file(ui);
+
+        let embedder = HashingEmbedder::default();
+        let index = EmbeddingIndex::build(&map, &embedder);
+        assert_eq!(index.len(), 2);
+
+        let hits = index.search("where are session tokens verified", &embedder, None, 5);
+        assert_eq!(hits[0].chunk.file, "src/auth.rs");
+        assert_eq!(hits[0].chunk.line, 2);
+        assert_eq!(hits[0].chunk.end_line, 4);
+        assert!(hits[0].chunk.snippet.starts_with("pub fn verify_session"));
+
+        let hits = index.search("session token", &embedder, Some("src/ui"), 5);
+        assert!(hits.iter().all(|hit| hit.chunk.file == "src/ui.rs"));
+    }
+}
@@ -0,0 +1,249 @@
+//! Repo Map Export - 외부 도구용 내보내기 형식
+//!
+//! `RepoMap::export`로 구조와 파일 의존성을 다른 도구/문서에 넣을 수 있는
+//! 형식으로 변환합니다.
+//! - JSON: 파일, 심볼, 임포트와 의존성 엣지 (기계 판독용)
+//! - DOT: Graphviz 의존성 그래프 (`dot -Tsvg`)
+//! - Mermaid: Markdown 문서에 넣을 수 있는 의존성 다이어그램
+//!
+//! 의존성은 `DependencyGraph::from_repo_map`으로 계산하며, 노드는 상대 경로로 정렬합니다.
+
+use super::graph::DependencyGraph;
+use super::types::{FileInfo, RepoMap};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// JSON (파일, 심볼, 의존성)
+    Json,
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+impl ExportFormat {
+    /// 모든 형식
+    pub const ALL: [ExportFormat; 3] =
+        [ExportFormat::Json, ExportFormat::Dot, ExportFormat::Mermaid];
+
+    /// 이름 또는 확장자로 찾기 (`json`, `dot`/`gv`, `mermaid`/`mmd`)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "dot" | "gv" | "graphviz" => Some(Self::Dot),
+            "mermaid" | "mmd" => Some(Self::Mermaid),
+            _ => None,
+        }
+    }
+
+    /// 형식 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Dot => "dot",
+            ExportFormat::Mermaid => "mermaid",
+        }
+    }
+
+    /// 기본 파일 확장자
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Dot => "dot",
+            ExportFormat::Mermaid => "mmd",
+        }
+    }
+}
+
+impl RepoMap {
+    /// 지정한 형식으로 내보내기
+    pub fn export(&self, format: ExportFormat) -> String {
+        let files = self.sorted_files();
+        let edges = self.dependency_edges();
+        match format {
+            ExportFormat::Json => export_json(self, &files, &edges),
+            ExportFormat::Dot => export_dot(&files, &edges),
+            ExportFormat::Mermaid => export_mermaid(&files, &edges),
+        }
+    }
+
+    /// 상대 경로 순으로 정렬된 파일
+    fn sorted_files(&self) -> Vec<&FileInfo> {
+        let mut files: Vec<&FileInfo> = self.files.iter().collect();
+        files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+        files
+    }
+
+    /// 의존성 엣지 (상대 경로, 정렬됨)
+    fn dependency_edges(&self) -> Vec<(String, String)> {
+        let graph = DependencyGraph::from_repo_map(self);
+        let relative: HashMap<&PathBuf, &str> = self
+            .files
+            .iter()
+            .map(|f| (&f.path, f.relative_path.as_str()))
+            .collect();
+
+        let mut edges: Vec<(String, String)> = self
+            .files
+            .iter()
+            .flat_map(|file| {
+                graph
+                    .dependencies(&file.path)
+                    .into_iter()
+                    .filter(|dep| **dep != file.path)
+                    .filter_map(|dep| relative.get(dep))
+                    .map(|dep| (file.relative_path.clone(), dep.to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        edges.sort();
+        edges.dedup();
+        edges
+    }
+}
+
+fn export_json(map: &RepoMap, files: &[&FileInfo], edges: &[(String, String)]) -> String {
+    let value = json!({
+        "root": map.root,
+        "generated_at": map.generated_at,
+        "total_tokens": map.total_tokens,
+        "files": files,
+        "dependencies": edges
+            .iter()
+            .map(|(from, to)| json!({ "from": from, "to": to }))
+            .collect::<Vec<_>>(),
+    });
+    let mut out = serde_json::to_string_pretty(&value).unwrap_or_default();
+    out.push('\n');
+    out
+}
+
+fn export_dot(files: &[&FileInfo], edges: &[(String, String)]) -> String {
+    let mut out = String::from("digraph repomap {\n");
+    out.push_str("    rankdir=LR;\n");
+    out.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+    for file in files {
+        out.push_str(&format!(
+            "    \"{}\" [label=\"{}\\n{} · {} symbols\"];\n",
+            dot_escape(&file.relative_path),
+            dot_escape(&file.relative_path),
+            dot_escape(&file.language),
+            file.symbols.len()
+        ));
+    }
+    for (from, to) in edges {
+        out.push_str(&format!(
+            "    \"{}\" -> \"{}\";\n",
+            dot_escape(from),
+            dot_escape(to)
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn export_mermaid(files: &[&FileInfo], edges: &[(String, String)]) -> String {
+    // Mermaid 노드 ID는 경로를 쓸 수 없으므로 인덱스 사용
+    let ids: HashMap<&str, usize> = files
+        .iter()
+        .enumerate()
+        .map(|(i, f)| (f.relative_path.as_str(), i))
+        .collect();
+
+    let mut out = String::from("graph LR\n");
+    for (i, file) in files.iter().enumerate() {
+        out.push_str(&format!(
+            "    n{}[\"{}\"]\n",
+            i,
+            file.relative_path.replace('"', "#quot;")
+        ));
+    }
+    for (from, to) in edges {
+        if let (Some(from), Some(to)) = (ids.get(from.as_str()), ids.get(to.as_str())) {
+            out.push_str(&format!("    n{} --> n{}\n", from, to));
+        }
+    }
+    out
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repomap::{SymbolDef, SymbolKind};
+
+    fn sample_map() -> RepoMap {
+        let mut map = RepoMap::new(PathBuf::from("/project"));
+
+        let mut lib = FileInfo::new(
+            PathBuf::from("/project/src/lib.rs"),
+            "src/lib.rs".into(),
+            "rust".into(),
+        );
+        lib.add_import("crate::parser".into());
+        lib.add_symbol(SymbolDef::new("run", SymbolKind::Function, 3));
+        map.add_file(lib);
+
+        let mut parser = FileInfo::new(
+            PathBuf::from("/project/src/parser.rs"),
+            "src/parser.rs".into(),
+            "rust".into(),
+        );
+        parser.add_symbol(SymbolDef::new("Parser", SymbolKind::Struct, 1));
+        map.add_file(parser);
+        map
+    }
+
+    #[test]
+    fn test_export_format_parse() {
+        assert_eq!(ExportFormat::parse("JSON"), Some(ExportFormat::Json));
+        assert_eq!(ExportFormat::parse("gv"), Some(ExportFormat::Dot));
+        assert_eq!(ExportFormat::parse("mmd"), Some(ExportFormat::Mermaid));
+        assert_eq!(ExportFormat::parse("svg"), None);
+        for format in ExportFormat::ALL {
+            assert_eq!(ExportFormat::parse(format.name()), Some(format));
+        }
+    }
+
+    #[test]
+    fn test_export_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&sample_map().export(ExportFormat::Json)).unwrap();
+        assert_eq!(json["files"].as_array().unwrap().len(), 2);
+        assert_eq!(json["files"][1]["symbols"][0]["name"], "Parser");
+        assert_eq!(json["dependencies"][0]["from"], "src/lib.rs");
+        assert_eq!(json["dependencies"][0]["to"], "src/parser.rs");
+    }
+
+    #[test]
+    fn test_export_dot_and_mermaid() {
+        let map = sample_map();
+
+        let dot = map.export(ExportFormat::Dot);
+        assert!(dot.starts_with("digraph repomap {"));
+        assert!(dot.contains("\"src/lib.rs\" -> \"src/parser.rs\";"));
+        assert!(dot.contains("rust · 1 symbols"));
+        assert!(dot.trim_end().ends_with('}'));
+
+        let mermaid = map.export(ExportFormat::Mermaid);
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("n0[\"src/lib.rs\"]"));
+        assert!(mermaid.contains("n0 --> n1"));
+    }
+
+    #[test]
+    fn test_dot_escape() {
+        assert_eq!(dot_escape(r#"a"b\c"#), r#"a\"b\\c"#);
+    }
+}
@@ -0,0 +1,662 @@
+//! Built-in Analyzers - Rust, Python, JavaScript/TypeScript, Go, Java, C/C++
+//!
+//! 줄 단위 휴리스틱 파서입니다. 언어 서버가 있으면 `RepoAnalyzer`가
+//! 심볼을 언어 서버 결과로 대체합니다.
+
+use super::{extract_identifier, FnAnalyzer, LanguageAnalyzer};
+use crate::repomap::types::{FileInfo, SymbolDef, SymbolKind};
+use std::sync::Arc;
+
+/// 내장 분석기 목록
+pub(super) fn analyzers() -> Vec<Arc<dyn LanguageAnalyzer>> {
+    vec![
+        Arc::new(FnAnalyzer::new("rust", &["rs"], parse_rust)),
+        Arc::new(FnAnalyzer::new("python", &["py"], parse_python)),
+        Arc::new(FnAnalyzer::new(
+            "javascript",
+            &["js", "jsx"],
+            parse_javascript,
+        )),
+        Arc::new(FnAnalyzer::new(
+            "typescript",
+            &["ts", "tsx"],
+            parse_javascript,
+        )),
+        Arc::new(FnAnalyzer::new("go", &["go"], parse_go)),
+        Arc::new(FnAnalyzer::new("java", &["java"], parse_java)),
+        Arc::new(FnAnalyzer::new("c", &["c", "h"], parse_c)),
+        Arc::new(FnAnalyzer::new(
+            "cpp",
+            &["cpp", "hpp", "cc", "cxx"],
+            parse_c,
+        )),
+    ]
+}
+
+/// Rust 파일 파싱
+fn parse_rust(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        // use statements
+        if trimmed.starts_with("use ") {
+            if let Some(import) = trimmed
+                .strip_prefix("use ")
+                .and_then(|s| s.strip_suffix(';'))
+            {
+                file_info.add_import(import.to_string());
+            }
+        }
+
+        // pub/private 감지
+        let (vis, rest) = if trimmed.starts_with("pub ") {
+            (Some("pub"), trimmed.strip_prefix("pub ").unwrap_or(trimmed))
+        } else if trimmed.starts_with("pub(crate) ") {
+            (
+                Some("pub(crate)"),
+                trimmed.strip_prefix("pub(crate) ").unwrap_or(trimmed),
+            )
+        } else {
+            (None, trimmed)
+        };
+
+        // struct
+        if rest.starts_with("struct ") {
+            if let Some(name) = extract_identifier(rest, "struct ") {
+                let mut sym = SymbolDef::new(name, SymbolKind::Struct, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // enum
+        else if rest.starts_with("enum ") {
+            if let Some(name) = extract_identifier(rest, "enum ") {
+                let mut sym = SymbolDef::new(name, SymbolKind::Enum, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // trait
+        else if rest.starts_with("trait ") {
+            if let Some(name) = extract_identifier(rest, "trait ") {
+                let mut sym = SymbolDef::new(name, SymbolKind::Interface, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // fn
+        else if rest.starts_with("fn ") || rest.starts_with("async fn ") {
+            let fn_start = if rest.starts_with("async fn ") {
+                "async fn "
+            } else {
+                "fn "
+            };
+            if let Some(sig) = extract_fn_signature(rest, fn_start) {
+                let mut sym =
+                    SymbolDef::new(&sig.0, SymbolKind::Function, line_num).with_signature(&sig.1);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // impl
+        else if rest.starts_with("impl ") || rest.starts_with("impl<") {
+            // impl 블록은 특별히 처리하지 않음 (메서드는 내부에서 처리)
+        }
+        // mod
+        else if rest.starts_with("mod ") {
+            if let Some(name) = extract_identifier(rest, "mod ") {
+                let mut sym = SymbolDef::new(name, SymbolKind::Module, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // const
+        else if rest.starts_with("const ") {
+            if let Some(name) = extract_const_name(rest) {
+                let mut sym = SymbolDef::new(name, SymbolKind::Constant, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // type alias
+        else if rest.starts_with("type ") {
+            if let Some(name) = extract_identifier(rest, "type ") {
+                let mut sym = SymbolDef::new(name, SymbolKind::TypeAlias, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // macro_rules!
+        else if rest.starts_with("macro_rules!") {
+            if let Some(name) = rest
+                .strip_prefix("macro_rules!")
+                .and_then(|s| s.split_whitespace().next())
+            {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Macro, line_num));
+            }
+        }
+    }
+}
+
+/// Python 파일 파싱
+fn parse_python(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        // import statements
+        if trimmed.starts_with("import ") || trimmed.starts_with("from ") {
+            file_info.add_import(trimmed.to_string());
+        }
+        // class
+        else if trimmed.starts_with("class ") {
+            if let Some(name) = extract_python_class(trimmed) {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Class, line_num));
+            }
+        }
+        // def (함수)
+        else if trimmed.starts_with("def ") {
+            if let Some((name, sig)) = extract_python_function(trimmed) {
+                file_info.add_symbol(
+                    SymbolDef::new(name, SymbolKind::Function, line_num).with_signature(sig),
+                );
+            }
+        }
+        // async def
+        else if trimmed.starts_with("async def ") {
+            if let Some((name, sig)) =
+                extract_python_function(&trimmed.replace("async def ", "def "))
+            {
+                file_info.add_symbol(
+                    SymbolDef::new(name, SymbolKind::Function, line_num)
+                        .with_signature(format!("async {}", sig)),
+                );
+            }
+        }
+    }
+}
+
+/// JavaScript/TypeScript 파일 파싱
+fn parse_javascript(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        // import statements
+        if trimmed.starts_with("import ") {
+            file_info.add_import(trimmed.to_string());
+        }
+        // export
+        else if trimmed.starts_with("export ") {
+            let rest = trimmed.strip_prefix("export ").unwrap_or(trimmed);
+
+            if rest.starts_with("default ") {
+                // export default
+                continue;
+            } else if rest.starts_with("class ") {
+                if let Some(name) = extract_js_class(rest) {
+                    file_info.add_symbol(
+                        SymbolDef::new(name, SymbolKind::Class, line_num).with_visibility("export"),
+                    );
+                }
+            } else if rest.starts_with("function ")
+                || rest.starts_with("async function ")
+                || rest.starts_with("const ")
+            {
+                if let Some((name, kind)) = extract_js_declaration(rest) {
+                    file_info
+                        .add_symbol(SymbolDef::new(name, kind, line_num).with_visibility("export"));
+                }
+            } else if rest.starts_with("interface ") {
+                if let Some(name) = extract_identifier(rest, "interface ") {
+                    file_info.add_symbol(
+                        SymbolDef::new(name, SymbolKind::Interface, line_num)
+                            .with_visibility("export"),
+                    );
+                }
+            } else if rest.starts_with("type ") {
+                if let Some(name) = extract_identifier(rest, "type ") {
+                    file_info.add_symbol(
+                        SymbolDef::new(name, SymbolKind::TypeAlias, line_num)
+                            .with_visibility("export"),
+                    );
+                }
+            }
+        }
+        // class
+        else if trimmed.starts_with("class ") {
+            if let Some(name) = extract_js_class(trimmed) {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Class, line_num));
+            }
+        }
+        // function
+        else if trimmed.starts_with("function ") || trimmed.starts_with("async function ") {
+            if let Some((name, kind)) = extract_js_declaration(trimmed) {
+                file_info.add_symbol(SymbolDef::new(name, kind, line_num));
+            }
+        }
+        // interface (TypeScript)
+        else if trimmed.starts_with("interface ") {
+            if let Some(name) = extract_identifier(trimmed, "interface ") {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Interface, line_num));
+            }
+        }
+        // type (TypeScript)
+        else if trimmed.starts_with("type ") && trimmed.contains('=') {
+            if let Some(name) = extract_identifier(trimmed, "type ") {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::TypeAlias, line_num));
+            }
+        }
+    }
+}
+
+/// Go 파일 파싱
+fn parse_go(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        // import
+        if trimmed.starts_with("import ") {
+            file_info.add_import(trimmed.to_string());
+        }
+        // func
+        else if trimmed.starts_with("func ") {
+            if let Some((name, sig)) = extract_go_function(trimmed) {
+                let vis = if name
+                    .chars()
+                    .next()
+                    .map(|c| c.is_uppercase())
+                    .unwrap_or(false)
+                {
+                    Some("pub")
+                } else {
+                    None
+                };
+                let mut sym =
+                    SymbolDef::new(name, SymbolKind::Function, line_num).with_signature(sig);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // type struct
+        else if trimmed.starts_with("type ") && trimmed.contains("struct") {
+            if let Some(name) = extract_identifier(trimmed, "type ") {
+                let vis = if name
+                    .chars()
+                    .next()
+                    .map(|c| c.is_uppercase())
+                    .unwrap_or(false)
+                {
+                    Some("pub")
+                } else {
+                    None
+                };
+                let mut sym = SymbolDef::new(name, SymbolKind::Struct, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // type interface
+        else if trimmed.starts_with("type ") && trimmed.contains("interface") {
+            if let Some(name) = extract_identifier(trimmed, "type ") {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Interface, line_num));
+            }
+        }
+    }
+}
+
+/// Java 파일 파싱
+fn parse_java(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        // import
+        if trimmed.starts_with("import ") {
+            file_info.add_import(trimmed.to_string());
+        }
+        // class
+        else if trimmed.contains("class ") && !trimmed.starts_with("//") {
+            if let Some((vis, name)) = extract_java_class(trimmed) {
+                let mut sym = SymbolDef::new(name, SymbolKind::Class, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // interface
+        else if trimmed.contains("interface ") && !trimmed.starts_with("//") {
+            if let Some((vis, name)) = extract_java_interface(trimmed) {
+                let mut sym = SymbolDef::new(name, SymbolKind::Interface, line_num);
+                if let Some(v) = vis {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+        // enum
+        else if trimmed.contains("enum ") && !trimmed.starts_with("//") {
+            if let Some(name) = extract_java_enum(trimmed) {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Enum, line_num));
+            }
+        }
+    }
+}
+
+/// C/C++ 파일 파싱
+fn parse_c(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        // #include
+        if trimmed.starts_with("#include ") {
+            file_info.add_import(trimmed.to_string());
+        }
+        // struct
+        else if trimmed.starts_with("struct ") || trimmed.starts_with("typedef struct") {
+            if let Some(name) = extract_c_struct(trimmed) {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Struct, line_num));
+            }
+        }
+        // class (C++)
+        else if trimmed.starts_with("class ") {
+            if let Some(name) = extract_identifier(trimmed, "class ") {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Class, line_num));
+            }
+        }
+        // enum
+        else if trimmed.starts_with("enum ") {
+            if let Some(name) = extract_identifier(trimmed, "enum ") {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Enum, line_num));
+            }
+        }
+        // function declaration (간단한 휴리스틱)
+        else if trimmed.contains('(')
+            && !trimmed.starts_with("//")
+            && !trimmed.starts_with("/*")
+            && !trimmed.starts_with("if ")
+            && !trimmed.starts_with("while ")
+            && !trimmed.starts_with("for ")
+            && !trimmed.starts_with("switch ")
+        {
+            if let Some((name, sig)) = extract_c_function(trimmed) {
+                file_info.add_symbol(
+                    SymbolDef::new(name, SymbolKind::Function, line_num).with_signature(sig),
+                );
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// 함수 시그니처 추출 (Rust)
+fn extract_fn_signature(line: &str, prefix: &str) -> Option<(String, String)> {
+    let rest = line.strip_prefix(prefix)?;
+    let name_end = rest.find('(')?;
+    let name: String = rest[..name_end]
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '<')
+        .collect();
+
+    // 시그니처 추출 (파라미터 부분만)
+    let sig_start = rest.find('(')?;
+    let sig_end = rest.rfind(')').map(|i| i + 1).unwrap_or(rest.len());
+    let signature = rest[sig_start..sig_end].to_string();
+
+    Some((name, signature))
+}
+
+/// const 이름 추출
+fn extract_const_name(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("const ")?;
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Python 클래스 추출
+fn extract_python_class(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("class ")?;
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Python 함수 추출
+fn extract_python_function(line: &str) -> Option<(String, String)> {
+    let rest = line.strip_prefix("def ")?;
+    let paren_pos = rest.find('(')?;
+    let name = rest[..paren_pos].to_string();
+
+    let sig_end = rest.find(')').map(|i| i + 1).unwrap_or(rest.len());
+    let signature = rest[paren_pos..sig_end].to_string();
+
+    Some((name, signature))
+}
+
+/// JS 클래스 추출
+fn extract_js_class(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("class ")?;
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// JS 선언 추출
+fn extract_js_declaration(line: &str) -> Option<(String, SymbolKind)> {
+    if line.starts_with("function ") || line.starts_with("async function ") {
+        let prefix = if line.starts_with("async ") {
+            "async function "
+        } else {
+            "function "
+        };
+        let rest = line.strip_prefix(prefix)?;
+        let name: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        if !name.is_empty() {
+            return Some((name, SymbolKind::Function));
+        }
+    } else if line.starts_with("const ") {
+        let rest = line.strip_prefix("const ")?;
+        let name: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        if !name.is_empty() {
+            return Some((name, SymbolKind::Constant));
+        }
+    }
+    None
+}
+
+/// Go 함수 추출
+fn extract_go_function(line: &str) -> Option<(String, String)> {
+    let rest = line.strip_prefix("func ")?;
+
+    // 메서드인 경우 (receiver 있음)
+    if rest.starts_with('(') {
+        let receiver_end = rest.find(')')?;
+        let after_receiver = &rest[receiver_end + 1..].trim();
+        let name_end = after_receiver.find('(')?;
+        let name = after_receiver[..name_end].trim().to_string();
+        let sig_end = after_receiver
+            .rfind(')')
+            .map(|i| i + 1)
+            .unwrap_or(after_receiver.len());
+        let sig = after_receiver[name_end..sig_end].to_string();
+        Some((name, sig))
+    } else {
+        let name_end = rest.find('(')?;
+        let name = rest[..name_end].trim().to_string();
+        let sig_end = rest.rfind(')').map(|i| i + 1).unwrap_or(rest.len());
+        let sig = rest[name_end..sig_end].to_string();
+        Some((name, sig))
+    }
+}
+
+/// Java 클래스 추출
+fn extract_java_class(line: &str) -> Option<(Option<&str>, String)> {
+    let vis = if line.contains("public ") {
+        Some("public")
+    } else if line.contains("private ") {
+        Some("private")
+    } else if line.contains("protected ") {
+        Some("protected")
+    } else {
+        None
+    };
+
+    let class_pos = line.find("class ")?;
+    let rest = &line[class_pos + 6..];
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some((vis, name))
+    }
+}
+
+/// Java 인터페이스 추출
+fn extract_java_interface(line: &str) -> Option<(Option<&str>, String)> {
+    let vis = if line.contains("public ") {
+        Some("public")
+    } else {
+        None
+    };
+
+    let iface_pos = line.find("interface ")?;
+    let rest = &line[iface_pos + 10..];
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some((vis, name))
+    }
+}
+
+/// Java enum 추출
+fn extract_java_enum(line: &str) -> Option<String> {
+    let enum_pos = line.find("enum ")?;
+    let rest = &line[enum_pos + 5..];
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// C struct 추출
+fn extract_c_struct(line: &str) -> Option<String> {
+    if line.starts_with("typedef struct") {
+        // typedef struct { ... } Name;
+        None // 복잡한 케이스, 스킵
+    } else {
+        let rest = line.strip_prefix("struct ")?;
+        let name: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    }
+}
+
+/// C 함수 추출
+fn extract_c_function(line: &str) -> Option<(String, String)> {
+    let paren_pos = line.find('(')?;
+    let before_paren = &line[..paren_pos];
+
+    // 마지막 단어가 함수명
+    let words: Vec<&str> = before_paren.split_whitespace().collect();
+    let name = words.last()?.trim_start_matches('*').to_string();
+
+    if name.is_empty() || name.starts_with('{') || name.starts_with('=') {
+        return None;
+    }
+
+    let sig_end = line.find(')').map(|i| i + 1).unwrap_or(line.len());
+    let sig = line[paren_pos..sig_end].to_string();
+
+    Some((name, sig))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_rust_function() {
+        let sig = extract_fn_signature("fn process(data: &str) -> Result<()>", "fn ");
+        assert!(sig.is_some());
+        let (name, params) = sig.unwrap();
+        assert_eq!(name, "process");
+        assert!(params.contains("data"));
+    }
+
+    #[test]
+    fn test_extract_python_class() {
+        let name = extract_python_class("class MyClass(BaseClass):");
+        assert_eq!(name, Some("MyClass".to_string()));
+    }
+}
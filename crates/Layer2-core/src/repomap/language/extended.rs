@@ -0,0 +1,778 @@
+//! Extended Analyzers - C#, Kotlin, Swift, Ruby, PHP, SQL, HCL
+//!
+//! 내장 분석기와 같은 줄 단위 휴리스틱 파서입니다. 선언 앞의 수식어
+//! (`public`, `static`, `@Attribute` 등)를 벗겨낸 뒤 키워드로 심볼 종류를 정합니다.
+
+use super::{extract_identifier, FnAnalyzer, LanguageAnalyzer};
+use crate::repomap::types::{FileInfo, SymbolDef, SymbolKind};
+use std::sync::Arc;
+
+/// 확장 분석기 목록
+pub(super) fn analyzers() -> Vec<Arc<dyn LanguageAnalyzer>> {
+    vec![
+        Arc::new(FnAnalyzer::new("csharp", &["cs"], parse_csharp)),
+        Arc::new(FnAnalyzer::new("kotlin", &["kt", "kts"], parse_kotlin)),
+        Arc::new(FnAnalyzer::new("swift", &["swift"], parse_swift)),
+        Arc::new(FnAnalyzer::new("ruby", &["rb"], parse_ruby)),
+        Arc::new(FnAnalyzer::new("php", &["php"], parse_php)),
+        Arc::new(FnAnalyzer::new("sql", &["sql"], parse_sql)),
+        Arc::new(FnAnalyzer::new("hcl", &["tf", "hcl"], parse_hcl)),
+    ]
+}
+
+/// 가시성 키워드 (수식어 중 `SymbolDef::visibility`로 기록)
+const VISIBILITY: &[&str] = &[
+    "public",
+    "private",
+    "protected",
+    "internal",
+    "fileprivate",
+    "open",
+];
+
+/// 수식어를 벗겨낸 선언
+struct Decl<'a> {
+    visibility: Option<&'a str>,
+    rest: &'a str,
+}
+
+/// 선언 앞의 수식어/어노테이션 제거
+fn strip_modifiers<'a>(line: &'a str, modifiers: &[&str]) -> Decl<'a> {
+    let mut visibility = None;
+    let mut rest = line;
+    loop {
+        let word = rest.split_whitespace().next().unwrap_or("");
+        let is_attribute = word.starts_with('@') || word.starts_with('[');
+        if word.is_empty() || !(is_attribute || modifiers.contains(&word)) {
+            break;
+        }
+        if visibility.is_none() && VISIBILITY.contains(&word) {
+            visibility = Some(word);
+        }
+        rest = rest[word.len()..].trim_start();
+    }
+    Decl { visibility, rest }
+}
+
+/// 첫 `(`부터 짝이 맞는 `)`까지 (매개변수 목록)
+fn paren_signature(s: &str) -> Option<String> {
+    let start = s.find('(')?;
+    let mut depth = 0;
+    for (i, c) in s[start..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(s[start..=start + i].to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 심볼 추가 (가시성 포함)
+fn push(file_info: &mut FileInfo, name: String, kind: SymbolKind, line: usize, vis: Option<&str>) {
+    let mut sym = SymbolDef::new(name, kind, line);
+    if let Some(v) = vis {
+        sym = sym.with_visibility(v);
+    }
+    file_info.add_symbol(sym);
+}
+
+/// 들여쓰기 여부 (클래스 안의 선언)
+fn is_indented(line: &str) -> bool {
+    line.starts_with(' ') || line.starts_with('\t')
+}
+
+/// `class`/`struct`/... 같은 타입 키워드 선언
+fn type_declaration(rest: &str, keywords: &[(&str, SymbolKind)]) -> Option<(String, SymbolKind)> {
+    keywords.iter().find_map(|(keyword, kind)| {
+        extract_identifier(rest, &format!("{} ", keyword)).map(|name| (name, *kind))
+    })
+}
+
+// ============================================================================
+// C#
+// ============================================================================
+
+const CSHARP_MODIFIERS: &[&str] = &[
+    "public",
+    "private",
+    "protected",
+    "internal",
+    "static",
+    "abstract",
+    "sealed",
+    "partial",
+    "readonly",
+    "async",
+    "virtual",
+    "override",
+    "unsafe",
+    "new",
+    "extern",
+    "const",
+];
+
+const CSHARP_NOT_METHODS: &[&str] = &[
+    "if", "for", "foreach", "while", "switch", "return", "using", "lock", "catch", "throw",
+    "await", "new", "var",
+];
+
+fn parse_csharp(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        if let Some(import) = trimmed
+            .strip_prefix("using ")
+            .and_then(|s| s.strip_suffix(';'))
+            .filter(|s| !s.contains(['(', '=']))
+        {
+            file_info.add_import(import.to_string());
+            continue;
+        }
+        if let Some(name) = trimmed.strip_prefix("namespace ") {
+            let name = name.trim_end_matches(['{', ';']).trim();
+            file_info.add_symbol(SymbolDef::new(name, SymbolKind::Module, line_num));
+            continue;
+        }
+
+        let decl = strip_modifiers(trimmed, CSHARP_MODIFIERS);
+        let rest = decl.rest.strip_prefix("record ").map_or(decl.rest, |r| {
+            if r.starts_with("struct ") || r.starts_with("class ") {
+                r
+            } else {
+                decl.rest
+            }
+        });
+        if let Some((name, kind)) = type_declaration(
+            rest,
+            &[
+                ("class", SymbolKind::Class),
+                ("record", SymbolKind::Class),
+                ("struct", SymbolKind::Struct),
+                ("interface", SymbolKind::Interface),
+                ("enum", SymbolKind::Enum),
+            ],
+        ) {
+            push(file_info, name, kind, line_num, decl.visibility);
+        }
+        // 메서드: `<가시성> <반환 타입> Name(...)`
+        else if let Some(before) = rest
+            .find('(')
+            .map(|i| &rest[..i])
+            .filter(|before| decl.visibility.is_some() && !before.contains('='))
+        {
+            let mut words = before.split_whitespace().rev();
+            let name = words.next().unwrap_or("");
+            let name = name.split('<').next().unwrap_or(name);
+            if !name.is_empty() && !CSHARP_NOT_METHODS.contains(&name) {
+                let mut sym = SymbolDef::new(name, SymbolKind::Method, line_num);
+                if let Some(sig) = paren_signature(rest) {
+                    sym = sym.with_signature(sig);
+                }
+                if let Some(v) = decl.visibility {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Kotlin
+// ============================================================================
+
+const KOTLIN_MODIFIERS: &[&str] = &[
+    "public",
+    "private",
+    "protected",
+    "internal",
+    "open",
+    "abstract",
+    "sealed",
+    "data",
+    "inline",
+    "value",
+    "override",
+    "suspend",
+    "operator",
+    "infix",
+    "tailrec",
+    "external",
+    "final",
+    "inner",
+    "annotation",
+    "expect",
+    "actual",
+    "const",
+    "lateinit",
+];
+
+fn parse_kotlin(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        if let Some(import) = trimmed.strip_prefix("import ") {
+            file_info.add_import(import.trim().to_string());
+            continue;
+        }
+
+        let decl = strip_modifiers(trimmed, KOTLIN_MODIFIERS);
+        let rest = decl.rest;
+        if let Some(name) = rest
+            .strip_prefix("enum class ")
+            .and_then(|r| extract_identifier(r, ""))
+        {
+            push(file_info, name, SymbolKind::Enum, line_num, decl.visibility);
+        } else if let Some(name) = rest
+            .strip_prefix("companion object")
+            .map(|r| extract_identifier(r.trim(), "").unwrap_or_else(|| "Companion".into()))
+        {
+            push(
+                file_info,
+                name,
+                SymbolKind::Class,
+                line_num,
+                decl.visibility,
+            );
+        } else if let Some((name, kind)) = type_declaration(
+            rest,
+            &[
+                ("class", SymbolKind::Class),
+                ("object", SymbolKind::Class),
+                ("interface", SymbolKind::Interface),
+                ("fun interface", SymbolKind::Interface),
+                ("typealias", SymbolKind::TypeAlias),
+            ],
+        ) {
+            push(file_info, name, kind, line_num, decl.visibility);
+        } else if let Some(after) = rest.strip_prefix("fun ") {
+            // `fun <T> Receiver.name(...)`
+            let after = match after.strip_prefix('<') {
+                Some(generic) => generic.split_once('>').map_or("", |(_, r)| r.trim_start()),
+                None => after,
+            };
+            let head = &after[..after.find('(').unwrap_or(after.len())];
+            let name = head.rsplit('.').next().unwrap_or(head).trim();
+            if !name.is_empty() {
+                let kind = if is_indented(line) {
+                    SymbolKind::Method
+                } else {
+                    SymbolKind::Function
+                };
+                let mut sym = SymbolDef::new(name, kind, line_num);
+                if let Some(sig) = paren_signature(after) {
+                    sym = sym.with_signature(sig);
+                }
+                if let Some(v) = decl.visibility {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        } else if trimmed.split_whitespace().any(|w| w == "const") {
+            if let Some(name) = extract_identifier(rest, "val ") {
+                push(
+                    file_info,
+                    name,
+                    SymbolKind::Constant,
+                    line_num,
+                    decl.visibility,
+                );
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Swift
+// ============================================================================
+
+const SWIFT_MODIFIERS: &[&str] = &[
+    "public",
+    "private",
+    "fileprivate",
+    "internal",
+    "open",
+    "final",
+    "static",
+    "override",
+    "mutating",
+    "nonmutating",
+    "convenience",
+    "required",
+    "dynamic",
+    "lazy",
+    "indirect",
+    "nonisolated",
+];
+
+fn parse_swift(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        if let Some(import) = trimmed.strip_prefix("import ") {
+            file_info.add_import(import.trim().to_string());
+            continue;
+        }
+
+        let decl = strip_modifiers(trimmed, SWIFT_MODIFIERS);
+        // `class func`/`class var`의 class는 수식어
+        let rest = match decl.rest.strip_prefix("class ") {
+            Some(r) if r.starts_with("func ") || r.starts_with("var ") => r,
+            _ => decl.rest,
+        };
+        if let Some((name, kind)) = type_declaration(
+            rest,
+            &[
+                ("class", SymbolKind::Class),
+                ("actor", SymbolKind::Class),
+                ("struct", SymbolKind::Struct),
+                ("enum", SymbolKind::Enum),
+                ("protocol", SymbolKind::Interface),
+                ("extension", SymbolKind::Module),
+                ("typealias", SymbolKind::TypeAlias),
+            ],
+        ) {
+            push(file_info, name, kind, line_num, decl.visibility);
+        } else if let Some(after) = rest.strip_prefix("func ") {
+            let name: String = after
+                .chars()
+                .take_while(|c| !matches!(c, '(' | '<' | ' '))
+                .collect();
+            if !name.is_empty() {
+                let kind = if is_indented(line) {
+                    SymbolKind::Method
+                } else {
+                    SymbolKind::Function
+                };
+                let mut sym = SymbolDef::new(name, kind, line_num);
+                if let Some(sig) = paren_signature(after) {
+                    sym = sym.with_signature(sig);
+                }
+                if let Some(v) = decl.visibility {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Ruby
+// ============================================================================
+
+fn parse_ruby(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("require ") || trimmed.starts_with("require_relative ") {
+            file_info.add_import(trimmed.to_string());
+        } else if let Some(rest) = trimmed.strip_prefix("class ") {
+            // `class Foo::Bar < Base`, `class << self`는 제외
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | ':'))
+                .collect();
+            if !name.is_empty() {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Class, line_num));
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("module ") {
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | ':'))
+                .collect();
+            if !name.is_empty() {
+                file_info.add_symbol(SymbolDef::new(name, SymbolKind::Module, line_num));
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("def ") {
+            let rest = rest.strip_prefix("self.").unwrap_or(rest);
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '?' | '!' | '='))
+                .collect();
+            if !name.is_empty() {
+                let kind = if is_indented(line) {
+                    SymbolKind::Method
+                } else {
+                    SymbolKind::Function
+                };
+                let mut sym = SymbolDef::new(name, kind, line_num);
+                if let Some(sig) = paren_signature(rest) {
+                    sym = sym.with_signature(sig);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// PHP
+// ============================================================================
+
+const PHP_MODIFIERS: &[&str] = &[
+    "public",
+    "private",
+    "protected",
+    "static",
+    "abstract",
+    "final",
+    "readonly",
+];
+
+fn parse_php(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        if let Some(import) = trimmed
+            .strip_prefix("use ")
+            .and_then(|s| s.strip_suffix(';'))
+            .filter(|_| !is_indented(line))
+        {
+            file_info.add_import(import.trim().to_string());
+            continue;
+        }
+        if ["require", "require_once", "include", "include_once"]
+            .iter()
+            .any(|kw| {
+                trimmed.starts_with(&format!("{} ", kw)) || trimmed.starts_with(&format!("{}(", kw))
+            })
+        {
+            file_info.add_import(trimmed.trim_end_matches(';').to_string());
+            continue;
+        }
+        if let Some(name) = trimmed.strip_prefix("namespace ") {
+            let name = name.trim_end_matches([';', '{']).trim();
+            file_info.add_symbol(SymbolDef::new(name, SymbolKind::Module, line_num));
+            continue;
+        }
+
+        let decl = strip_modifiers(trimmed, PHP_MODIFIERS);
+        let rest = decl.rest;
+        if let Some((name, kind)) = type_declaration(
+            rest,
+            &[
+                ("class", SymbolKind::Class),
+                ("interface", SymbolKind::Interface),
+                ("trait", SymbolKind::Interface),
+                ("enum", SymbolKind::Enum),
+                ("const", SymbolKind::Constant),
+            ],
+        ) {
+            push(file_info, name, kind, line_num, decl.visibility);
+        } else if let Some(after) = rest.strip_prefix("function ") {
+            let after = after.trim_start_matches('&');
+            if let Some(name) = extract_identifier(after, "") {
+                let kind = if is_indented(line) {
+                    SymbolKind::Method
+                } else {
+                    SymbolKind::Function
+                };
+                let mut sym = SymbolDef::new(name, kind, line_num);
+                if let Some(sig) = paren_signature(after) {
+                    sym = sym.with_signature(sig);
+                }
+                if let Some(v) = decl.visibility {
+                    sym = sym.with_visibility(v);
+                }
+                file_info.add_symbol(sym);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// SQL
+// ============================================================================
+
+/// `CREATE` 뒤, 객체 종류 앞에 올 수 있는 단어
+const SQL_CREATE_OPTIONS: &[&str] = &[
+    "OR",
+    "REPLACE",
+    "TEMP",
+    "TEMPORARY",
+    "UNIQUE",
+    "MATERIALIZED",
+    "UNLOGGED",
+    "GLOBAL",
+    "LOCAL",
+];
+
+fn parse_sql(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let mut words = line.split_whitespace().peekable();
+        if !words
+            .next()
+            .is_some_and(|w| w.eq_ignore_ascii_case("create"))
+        {
+            continue;
+        }
+        while words
+            .peek()
+            .is_some_and(|w| SQL_CREATE_OPTIONS.iter().any(|o| w.eq_ignore_ascii_case(o)))
+        {
+            words.next();
+        }
+        let kind = match words.next().map(|w| w.to_ascii_uppercase()).as_deref() {
+            Some("TABLE") => SymbolKind::Struct,
+            Some("VIEW") => SymbolKind::TypeAlias,
+            Some("TYPE" | "DOMAIN") => SymbolKind::TypeAlias,
+            Some("FUNCTION" | "PROCEDURE" | "TRIGGER") => SymbolKind::Function,
+            Some("SCHEMA") => SymbolKind::Module,
+            Some("INDEX") => SymbolKind::Variable,
+            _ => continue,
+        };
+        // IF NOT EXISTS
+        let mut name = words.next();
+        if name.is_some_and(|w| w.eq_ignore_ascii_case("if")) {
+            name = words.nth(2);
+        }
+        let Some(name) = name else {
+            continue;
+        };
+        let name: String = name
+            .split('(')
+            .next()
+            .unwrap_or(name)
+            .trim_end_matches(';')
+            .chars()
+            .filter(|c| !matches!(c, '"' | '`' | '[' | ']'))
+            .collect();
+        if !name.is_empty() {
+            let mut sym = SymbolDef::new(name, kind, line_num);
+            if kind == SymbolKind::Function {
+                if let Some(sig) = paren_signature(line) {
+                    sym = sym.with_signature(sig);
+                }
+            }
+            file_info.add_symbol(sym);
+        }
+    }
+}
+
+// ============================================================================
+// HCL / Terraform
+// ============================================================================
+
+fn parse_hcl(content: &str, file_info: &mut FileInfo) {
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        // 모듈 소스는 의존성
+        if let Some(source) = trimmed
+            .strip_prefix("source ")
+            .or_else(|| trimmed.strip_prefix("source="))
+            .map(|s| s.trim_start().trim_start_matches('='))
+        {
+            file_info.add_import(source.trim().trim_matches('"').to_string());
+            continue;
+        }
+        if is_indented(line) {
+            continue;
+        }
+
+        // 최상위 블록: `<type> "label" "label" {`
+        let Some(head) = trimmed
+            .strip_suffix("{}")
+            .or_else(|| trimmed.strip_suffix('{'))
+        else {
+            continue;
+        };
+        let mut parts = head.split_whitespace();
+        let block = parts.next().unwrap_or("");
+        let labels: Vec<&str> = parts.map(|l| l.trim_matches('"')).collect();
+        let (name, kind) = match (block, labels.as_slice()) {
+            ("resource", [ty, name]) => (format!("{}.{}", ty, name), SymbolKind::Struct),
+            ("data", [ty, name]) => (format!("data.{}.{}", ty, name), SymbolKind::Struct),
+            ("module", [name]) => (format!("module.{}", name), SymbolKind::Module),
+            ("variable", [name]) => (format!("var.{}", name), SymbolKind::Variable),
+            ("output", [name]) => (name.to_string(), SymbolKind::Constant),
+            ("provider", [name]) => (name.to_string(), SymbolKind::Module),
+            _ => continue,
+        };
+        file_info.add_symbol(SymbolDef::new(name, kind, line_num));
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn parse(parser: fn(&str, &mut FileInfo), content: &str) -> FileInfo {
+        let mut info = FileInfo::new(PathBuf::from("f"), "f".into(), "test".into());
+        parser(content, &mut info);
+        info
+    }
+
+    fn names(info: &FileInfo) -> Vec<(&str, SymbolKind)> {
+        info.symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_csharp() {
+        let info = parse(
+            parse_csharp,
+            "using System.Linq;\nnamespace App.Core;\n\n[Serializable]\npublic sealed partial class Order\n{\n    public async Task<int> Submit(int id) {\n        if (id > 0) return 1;\n    }\n    private record struct Line(int Qty);\n}\ninternal enum State { A }\n",
+        );
+        assert_eq!(info.imports, vec!["System.Linq"]);
+        assert_eq!(
+            names(&info),
+            vec![
+                ("App.Core", SymbolKind::Module),
+                ("Order", SymbolKind::Class),
+                ("Submit", SymbolKind::Method),
+                ("Line", SymbolKind::Struct),
+                ("State", SymbolKind::Enum),
+            ]
+        );
+        assert_eq!(info.symbols[1].visibility.as_deref(), Some("public"));
+        assert_eq!(info.symbols[2].signature.as_deref(), Some("(int id)"));
+    }
+
+    #[test]
+    fn test_parse_kotlin() {
+        let info = parse(
+            parse_kotlin,
+            "import kotlinx.coroutines.flow.Flow\n\n@JvmInline\nvalue class Id(val raw: String)\ninternal data class User(val id: Id)\nenum class Role { ADMIN }\nsealed interface Event\nobject Registry {\n    private const val LIMIT = 10\n    suspend fun <T> load(id: Id): T = TODO()\n}\nfun String.slug(): String = lowercase()\ntypealias Users = List<User>\n",
+        );
+        assert_eq!(info.imports, vec!["kotlinx.coroutines.flow.Flow"]);
+        assert_eq!(
+            names(&info),
+            vec![
+                ("Id", SymbolKind::Class),
+                ("User", SymbolKind::Class),
+                ("Role", SymbolKind::Enum),
+                ("Event", SymbolKind::Interface),
+                ("Registry", SymbolKind::Class),
+                ("LIMIT", SymbolKind::Constant),
+                ("load", SymbolKind::Method),
+                ("slug", SymbolKind::Function),
+                ("Users", SymbolKind::TypeAlias),
+            ]
+        );
+        assert_eq!(info.symbols[1].visibility.as_deref(), Some("internal"));
+        assert_eq!(info.symbols[6].signature.as_deref(), Some("(id: Id)"));
+    }
+
+    #[test]
+    fn test_parse_swift() {
+        let info = parse(
+            parse_swift,
+            "import SwiftUI\n\n@MainActor\npublic final class Store: ObservableObject {\n    @Published private(set) var items: [Item] = []\n    public class func shared() -> Store { Store() }\n    func add(_ item: Item) {}\n}\nprotocol Loader {}\nextension Store: Loader {}\nstruct Item {}\n",
+        );
+        assert_eq!(info.imports, vec!["SwiftUI"]);
+        assert_eq!(
+            names(&info),
+            vec![
+                ("Store", SymbolKind::Class),
+                ("shared", SymbolKind::Method),
+                ("add", SymbolKind::Method),
+                ("Loader", SymbolKind::Interface),
+                ("Store", SymbolKind::Module),
+                ("Item", SymbolKind::Struct),
+            ]
+        );
+        assert_eq!(info.symbols[2].signature.as_deref(), Some("(_ item: Item)"));
+    }
+
+    #[test]
+    fn test_parse_ruby() {
+        let info = parse(
+            parse_ruby,
+            "require 'json'\n\nmodule Billing\n  class Invoice < Base\n    def self.build(attrs)\n    end\n\n    def paid?\n    end\n  end\nend\n\ndef helper; end\n",
+        );
+        assert_eq!(info.imports, vec!["require 'json'"]);
+        assert_eq!(
+            names(&info),
+            vec![
+                ("Billing", SymbolKind::Module),
+                ("Invoice", SymbolKind::Class),
+                ("build", SymbolKind::Method),
+                ("paid?", SymbolKind::Method),
+                ("helper", SymbolKind::Function),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_php() {
+        let info = parse(
+            parse_php,
+            "<?php\nnamespace App\\Http;\n\nuse App\\Models\\User;\nrequire_once 'helpers.php';\n\nfinal class UserController extends Controller\n{\n    use HasEvents;\n    const VERSION = 2;\n    public static function show(int $id): User {}\n}\n\ntrait HasEvents {}\nfunction helper() {}\n",
+        );
+        assert_eq!(
+            info.imports,
+            vec!["App\\Models\\User", "require_once 'helpers.php'"]
+        );
+        assert_eq!(
+            names(&info),
+            vec![
+                ("App\\Http", SymbolKind::Module),
+                ("UserController", SymbolKind::Class),
+                ("VERSION", SymbolKind::Constant),
+                ("show", SymbolKind::Method),
+                ("HasEvents", SymbolKind::Interface),
+                ("helper", SymbolKind::Function),
+            ]
+        );
+        assert_eq!(info.symbols[3].visibility.as_deref(), Some("public"));
+    }
+
+    #[test]
+    fn test_parse_sql() {
+        let info = parse(
+            parse_sql,
+            "CREATE TABLE IF NOT EXISTS \"users\" (\n  id INT\n);\ncreate or replace view active_users as select 1;\nCREATE UNIQUE INDEX users_email ON users(email);\nCREATE FUNCTION app.total(a int, b int) RETURNS int AS $$ $$;\n-- CREATE TABLE ignored\n",
+        );
+        assert_eq!(
+            names(&info),
+            vec![
+                ("users", SymbolKind::Struct),
+                ("active_users", SymbolKind::TypeAlias),
+                ("users_email", SymbolKind::Variable),
+                ("app.total", SymbolKind::Function),
+            ]
+        );
+        assert_eq!(info.symbols[3].signature.as_deref(), Some("(a int, b int)"));
+    }
+
+    #[test]
+    fn test_parse_hcl() {
+        let info = parse(
+            parse_hcl,
+            "provider \"aws\" {\n  region = \"us-east-1\"\n}\n\nresource \"aws_instance\" \"web\" {\n  ami = var.ami\n}\n\nmodule \"vpc\" {\n  source = \"./modules/vpc\"\n}\n\nvariable \"ami\" {}\noutput \"ip\" {\n  value = aws_instance.web.public_ip\n}\nlocals {\n}\n",
+        );
+        assert_eq!(info.imports, vec!["./modules/vpc"]);
+        assert_eq!(
+            names(&info),
+            vec![
+                ("aws", SymbolKind::Module),
+                ("aws_instance.web", SymbolKind::Struct),
+                ("module.vpc", SymbolKind::Module),
+                ("var.ami", SymbolKind::Variable),
+                ("ip", SymbolKind::Constant),
+            ]
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Language Analyzers - 언어별 심볼 추출 백엔드
+//!
+//! 언어마다 `LanguageAnalyzer`를 구현해 `LanguageRegistry`에 등록합니다.
+//! `RepoAnalyzer`는 확장자로 분석기를 찾아 파싱만 맡기므로, 새 언어는
+//! 랭킹/그래프 코드를 건드리지 않고 추가할 수 있습니다.
+//!
+//! ## 내장 언어
+//! - Rust, Python, JavaScript/TypeScript, Go, Java, C/C++ (`builtin`)
+//! - C#, Kotlin, Swift, Ruby, PHP, SQL, HCL/Terraform (`extended`)
+//!
+//! 플러그인은 `PluginContext::register_language`로 분석기를 추가하거나
+//! 같은 확장자의 내장 분석기를 대체할 수 있습니다.
+
+mod builtin;
+mod extended;
+
+use super::types::FileInfo;
+use std::path::Path;
+use std::sync::Arc;
+
+/// 언어별 파서
+pub trait LanguageAnalyzer: Send + Sync {
+    /// 언어 이름 (`FileInfo::language`, 예: "rust")
+    fn language(&self) -> &str;
+
+    /// 처리하는 파일 확장자 (점 없이, 예: `["rs"]`)
+    fn extensions(&self) -> &[&str];
+
+    /// 파일 내용에서 심볼/임포트 추출
+    fn parse(&self, content: &str, file_info: &mut FileInfo);
+}
+
+/// 파싱 함수 기반 분석기 (내장 언어용)
+pub(crate) struct FnAnalyzer {
+    language: &'static str,
+    extensions: &'static [&'static str],
+    parse: fn(&str, &mut FileInfo),
+}
+
+impl FnAnalyzer {
+    pub(crate) const fn new(
+        language: &'static str,
+        extensions: &'static [&'static str],
+        parse: fn(&str, &mut FileInfo),
+    ) -> Self {
+        Self {
+            language,
+            extensions,
+            parse,
+        }
+    }
+}
+
+impl LanguageAnalyzer for FnAnalyzer {
+    fn language(&self) -> &str {
+        self.language
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.extensions
+    }
+
+    fn parse(&self, content: &str, file_info: &mut FileInfo) {
+        (self.parse)(content, file_info)
+    }
+}
+
+/// 확장자 → 분석기 레지스트리
+///
+/// 같은 확장자를 처리하는 분석기가 여럿이면 나중에 등록된 것이 우선합니다.
+#[derive(Clone)]
+pub struct LanguageRegistry {
+    analyzers: Vec<Arc<dyn LanguageAnalyzer>>,
+}
+
+impl LanguageRegistry {
+    /// 빈 레지스트리
+    pub fn empty() -> Self {
+        Self {
+            analyzers: Vec::new(),
+        }
+    }
+
+    /// 내장 언어가 모두 등록된 레지스트리
+    pub fn builtin() -> Self {
+        let mut registry = Self::empty();
+        for analyzer in builtin::analyzers()
+            .into_iter()
+            .chain(extended::analyzers())
+        {
+            registry.register(analyzer);
+        }
+        registry
+    }
+
+    /// 분석기 등록
+    pub fn register(&mut self, analyzer: Arc<dyn LanguageAnalyzer>) {
+        self.analyzers.push(analyzer);
+    }
+
+    /// 파일 확장자에 맞는 분석기
+    pub fn for_path(&self, path: &Path) -> Option<&Arc<dyn LanguageAnalyzer>> {
+        let ext = path.extension()?.to_str()?;
+        self.analyzers.iter().rev().find(|analyzer| {
+            analyzer
+                .extensions()
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(ext))
+        })
+    }
+
+    /// 등록된 언어 이름 (중복 제거, 등록 순)
+    pub fn languages(&self) -> Vec<&str> {
+        let mut languages: Vec<&str> = Vec::new();
+        for analyzer in &self.analyzers {
+            if !languages.contains(&analyzer.language()) {
+                languages.push(analyzer.language());
+            }
+        }
+        languages
+    }
+}
+
+impl Default for LanguageRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl std::fmt::Debug for LanguageRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LanguageRegistry")
+            .field("languages", &self.languages())
+            .finish()
+    }
+}
+
+/// `prefix` 뒤의 식별자 추출
+pub(crate) fn extract_identifier(line: &str, prefix: &str) -> Option<String> {
+    let rest = line.strip_prefix(prefix)?;
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repomap::types::{SymbolDef, SymbolKind};
+    use std::path::PathBuf;
+
+    fn parse_marker(_content: &str, file_info: &mut FileInfo) {
+        file_info.add_symbol(SymbolDef::new("Custom", SymbolKind::Module, 1));
+    }
+
+    #[test]
+    fn test_registry_lookup() {
+        let mut registry = LanguageRegistry::builtin();
+        let language = |registry: &LanguageRegistry, path: &str| {
+            registry
+                .for_path(Path::new(path))
+                .map(|a| a.language().to_string())
+        };
+        assert_eq!(language(&registry, "src/main.rs").as_deref(), Some("rust"));
+        assert_eq!(language(&registry, "App.KT").as_deref(), Some("kotlin"));
+        assert_eq!(language(&registry, "main.tf").as_deref(), Some("hcl"));
+        assert_eq!(language(&registry, "README.md"), None);
+        assert_eq!(language(&registry, "Makefile"), None);
+        for name in ["csharp", "kotlin", "swift", "ruby", "php", "sql", "hcl"] {
+            assert!(registry.languages().contains(&name), "{}", name);
+        }
+
+        // 나중에 등록된 분석기가 같은 확장자를 대체
+        registry.register(Arc::new(FnAnalyzer::new(
+            "rust-custom",
+            &["rs"],
+            parse_marker,
+        )));
+        assert_eq!(
+            language(&registry, "lib.rs").as_deref(),
+            Some("rust-custom")
+        );
+        let mut info = FileInfo::new(PathBuf::from("lib.rs"), "lib.rs".into(), "rust".into());
+        registry
+            .for_path(Path::new("lib.rs"))
+            .unwrap()
+            .parse("", &mut info);
+        assert_eq!(info.symbols[0].name, "Custom");
+    }
+
+    #[test]
+    fn test_extract_identifier() {
+        assert_eq!(
+            extract_identifier("struct Foo<T>", "struct "),
+            Some("Foo".into())
+        );
+        assert_eq!(extract_identifier("struct {", "struct "), None);
+    }
+}
@@ -6,18 +6,36 @@
 //! ## 기능
 //! - 파일 구조 분석 (클래스, 함수, 모듈 등)
 //! - 심볼 추출 및 의존성 그래프
-//! - 관련 파일 추천 (PageRank 기반)
-//! - 토큰 예산 내에서 최적화된 맵 생성
+//! - 관련 파일 추천 (PageRank 기반, 질의 임베딩 유사도 혼합)
+//! - 토큰 예산 내에서 최적화된 맵 생성 (심볼 단위, 시그니처만)
+//! - JSON / Graphviz DOT / Mermaid 내보내기 (`RepoMap::export`)
+//! - TODO/FIXME/HACK 주석 백로그 (담당자, 경과 일수)
+//! - 심볼/청크 임베딩 인덱스 기반 의미 검색
+//! - 언어 서버 심볼 인덱스 사용 (`RepoAnalyzer::with_lsp`, 없으면 정규식 파싱)
+//! - 증분 인덱스 (`.forgecode/cache/repomap.db`, 바뀐 파일만 재분석)
 //!
 //! ## 지원 언어
 //! - Rust, Python, JavaScript/TypeScript, Go, Java, C/C++
+//! - C#, Kotlin, Swift, Ruby, PHP, SQL, HCL/Terraform
+//! - `LanguageAnalyzer` 구현으로 추가 (`RepoAnalyzer::with_language`, 플러그인)
 
 mod analyzer;
+mod budget;
+mod cache;
+mod embeddings;
+mod export;
 mod graph;
+mod language;
 mod ranker;
+mod todos;
 mod types;
 
 pub use analyzer::RepoAnalyzer;
+pub use cache::{RepoMapCache, REPOMAP_CACHE_FILE};
+pub use embeddings::{CodeChunk, Embedder, EmbeddingIndex, HashingEmbedder, SearchHit};
+pub use export::ExportFormat;
 pub use graph::DependencyGraph;
+pub use language::{LanguageAnalyzer, LanguageRegistry};
 pub use ranker::FileRanker;
+pub use todos::{extract_todos, TodoBacklog, TodoFilter, TodoItem, TodoKind};
 pub use types::{FileInfo, RepoMap, RepoMapConfig, SymbolDef, SymbolKind, SymbolRef, SymbolUsage};
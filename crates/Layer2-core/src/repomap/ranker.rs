@@ -2,7 +2,11 @@
 //!
 //! PageRank 알고리즘과 휴리스틱을 사용하여 파일의 중요도를 계산합니다.
 //! 이를 통해 토큰 예산 내에서 가장 관련 있는 파일을 선택합니다.
+//!
+//! 질의가 있으면(`rank_for_query`) 질의와 심볼 시그니처/문서의 임베딩 유사도를
+//! 그래프 점수에 섞어, 중심성만이 아니라 질의와 관련된 파일이 예산에 들어가게 합니다.
 
+use super::embeddings::{cosine, symbol_text, Embedder};
 use super::graph::DependencyGraph;
 use super::types::{RepoMap, SymbolKind};
 use std::collections::HashMap;
@@ -16,6 +20,8 @@ pub struct FileRanker {
     max_iterations: usize,
     /// 수렴 임계값
     convergence_threshold: f64,
+    /// 질의 유사도 가중치 (0-1, 나머지는 그래프 점수)
+    semantic_weight: f64,
 }
 
 impl FileRanker {
@@ -25,6 +31,7 @@ impl FileRanker {
             damping_factor: 0.85,
             max_iterations: 100,
             convergence_threshold: 1e-6,
+            semantic_weight: 0.5,
         }
     }
 
@@ -34,9 +41,16 @@ impl FileRanker {
             damping_factor,
             max_iterations,
             convergence_threshold: 1e-6,
+            semantic_weight: 0.5,
         }
     }
 
+    /// 질의 유사도 가중치 설정 (0이면 그래프 점수만 사용)
+    pub fn with_semantic_weight(mut self, weight: f64) -> Self {
+        self.semantic_weight = weight.clamp(0.0, 1.0);
+        self
+    }
+
     /// 파일 중요도 계산 및 업데이트
     pub fn rank(&self, map: &mut RepoMap, graph: &DependencyGraph, focus_files: &[PathBuf]) {
         // 1. PageRank 기반 점수
@@ -75,6 +89,37 @@ impl FileRanker {
         self.rank(map, graph, context_files);
     }
 
+    /// 질의 기반 랭킹 (그래프 점수 + 질의-심볼 임베딩 유사도)
+    ///
+    /// 질의와 겹치는 심볼이 하나도 없으면 그래프 점수를 그대로 둡니다.
+    pub fn rank_for_query(
+        &self,
+        map: &mut RepoMap,
+        graph: &DependencyGraph,
+        focus_files: &[PathBuf],
+        query: &str,
+        embedder: &dyn Embedder,
+    ) {
+        self.rank(map, graph, focus_files);
+        if query.trim().is_empty() || self.semantic_weight <= 0.0 {
+            return;
+        }
+
+        let similarity = self.compute_similarity(map, query, embedder);
+        let max_similarity = similarity.values().fold(0.0_f64, |a, &b| a.max(b));
+        if max_similarity <= 0.0 {
+            return;
+        }
+
+        for file in &mut map.files {
+            let sim = similarity.get(&file.path).copied().unwrap_or(0.0) / max_similarity;
+            file.importance_score =
+                file.importance_score * (1.0 - self.semantic_weight) + sim * self.semantic_weight;
+        }
+
+        self.normalize_scores(map);
+    }
+
     /// PageRank 계산
     fn compute_pagerank(&self, map: &RepoMap, graph: &DependencyGraph) -> HashMap<PathBuf, f64> {
         let n = map.files.len() as f64;
@@ -205,6 +250,30 @@ impl FileRanker {
         scores
     }
 
+    /// 질의 유사도 계산 (파일 내 심볼 중 최댓값)
+    fn compute_similarity(
+        &self,
+        map: &RepoMap,
+        query: &str,
+        embedder: &dyn Embedder,
+    ) -> HashMap<PathBuf, f64> {
+        let query = embedder.embed(query);
+
+        map.files
+            .iter()
+            .map(|file| {
+                let path_score = cosine(&query, &embedder.embed(&file.relative_path));
+                let score = file
+                    .symbols
+                    .iter()
+                    .flat_map(|s| std::iter::once(s).chain(s.children.iter()))
+                    .map(|symbol| cosine(&query, &embedder.embed(&symbol_text(file, symbol))))
+                    .fold(path_score, f32::max);
+                (file.path.clone(), f64::from(score.max(0.0)))
+            })
+            .collect()
+    }
+
     /// 포커스 파일 근접도 계산
     fn compute_proximity(
         &self,
@@ -323,4 +392,66 @@ mod tests {
 
         assert!(lib_score > test_score);
     }
+
+    #[test]
+    fn test_rank_for_query() {
+        let mut map = RepoMap::new(PathBuf::from("/project"));
+
+        // 그래프 중심 파일 (lib.rs, 타입 다수)
+        let mut lib_file = FileInfo::new(
+            PathBuf::from("/project/src/lib.rs"),
+            "src/lib.rs".to_string(),
+            "rust".to_string(),
+        );
+        for name in ["Config", "Engine", "Registry"] {
+            lib_file.add_symbol(SymbolDef::new(name, SymbolKind::Struct, 1).with_visibility("pub"));
+        }
+        map.add_file(lib_file);
+
+        let mut auth_file = FileInfo::new(
+            PathBuf::from("/project/src/session.rs"),
+            "src/session.rs".to_string(),
+            "rust".to_string(),
+        );
+        auth_file.add_symbol(
+            SymbolDef::new("verify_token", SymbolKind::Function, 1)
+                .with_signature("fn verify_token(token: &str) -> bool")
+                .with_doc("Validates the login session token"),
+        );
+        map.add_file(auth_file);
+
+        let score = |map: &RepoMap, path: &str| {
+            map.files
+                .iter()
+                .find(|f| f.relative_path == path)
+                .map(|f| f.importance_score)
+                .unwrap()
+        };
+
+        let ranker = FileRanker::new();
+        let graph = DependencyGraph::from_repo_map(&map);
+        let embedder = crate::repomap::HashingEmbedder::default();
+
+        ranker.rank(&mut map, &graph, &[]);
+        assert!(score(&map, "src/lib.rs") > score(&map, "src/session.rs"));
+
+        ranker.rank_for_query(
+            &mut map,
+            &graph,
+            &[],
+            "where is the session token validated",
+            &embedder,
+        );
+        assert!(score(&map, "src/session.rs") > score(&map, "src/lib.rs"));
+
+        // 가중치 0이면 그래프 점수만
+        FileRanker::new().with_semantic_weight(0.0).rank_for_query(
+            &mut map,
+            &graph,
+            &[],
+            "where is the session token validated",
+            &embedder,
+        );
+        assert!(score(&map, "src/lib.rs") > score(&map, "src/session.rs"));
+    }
 }
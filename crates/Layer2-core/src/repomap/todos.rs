@@ -0,0 +1,445 @@
+//! TODO Backlog - TODO/FIXME/HACK 주석 인덱싱
+//!
+//! 분석기가 이미 읽는 파일에서 작업 표시 주석을 추출해 구조화된 백로그로 만듭니다.
+//!
+//! ```text
+//! // TODO(alice): 재시도 로직 추가       → owner = alice
+//! # FIXME @bob handle unicode paths     → owner = bob
+//! /* HACK: 임시 우회 */                 → owner = git blame 작성자
+//! ```
+//!
+//! 담당자가 주석에 없으면 `git blame`의 작성자를, 나이는 해당 줄의
+//! 커밋 시각을 사용합니다 (git 저장소가 아니면 비워 둠).
+
+use super::types::RepoMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+use tracing::debug;
+
+/// 주석 본문 최대 길이
+const MAX_TEXT_CHARS: usize = 160;
+
+lazy_static::lazy_static! {
+    /// 주석 시작 기호 뒤의 TODO/FIXME/HACK (문자열/식별자 안의 단어는 제외)
+    static ref TODO_COMMENT: Regex = Regex::new(
+        r"(?://+|#+|/\*+|\*|--|<!--)\s*(TODO|FIXME|HACK)\b(?:\(([^)]*)\))?[:\s-]*(.*)$"
+    )
+    .unwrap();
+    static ref MENTION: Regex = Regex::new(r"(?:^|\s)@([A-Za-z0-9][\w.-]*)").unwrap();
+}
+
+// ============================================================================
+// TodoKind
+// ============================================================================
+
+/// 주석 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TodoKind {
+    /// 버그 - 먼저 처리
+    Fixme,
+    /// 임시 우회 코드
+    Hack,
+    /// 할 일
+    Todo,
+}
+
+impl TodoKind {
+    /// 주석 표기
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TodoKind::Fixme => "FIXME",
+            TodoKind::Hack => "HACK",
+            TodoKind::Todo => "TODO",
+        }
+    }
+
+    /// 문자열 파싱 (대소문자 무시)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "FIXME" => Some(TodoKind::Fixme),
+            "HACK" => Some(TodoKind::Hack),
+            "TODO" => Some(TodoKind::Todo),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TodoKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// ============================================================================
+// TodoItem
+// ============================================================================
+
+/// 백로그 항목
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TodoItem {
+    /// 종류
+    pub kind: TodoKind,
+    /// 상대 경로
+    pub file: String,
+    /// 라인 번호 (1부터)
+    pub line: usize,
+    /// 주석 내용
+    pub text: String,
+    /// 담당자 (주석 표기 → git blame 작성자 순)
+    pub owner: Option<String>,
+    /// 작성 후 경과 일수 (git blame)
+    pub age_days: Option<u64>,
+}
+
+impl TodoItem {
+    /// 한 줄 표현
+    pub fn to_compact_string(&self) -> String {
+        let mut line = format!("{} {}:{} {}", self.kind, self.file, self.line, self.text);
+        match (&self.owner, self.age_days) {
+            (Some(owner), Some(days)) => line.push_str(&format!(" (@{}, {}d)", owner, days)),
+            (Some(owner), None) => line.push_str(&format!(" (@{})", owner)),
+            (None, Some(days)) => line.push_str(&format!(" ({}d)", days)),
+            (None, None) => {}
+        }
+        line
+    }
+}
+
+/// 파일 내용에서 TODO/FIXME/HACK 주석 추출
+pub fn extract_todos(content: &str, relative_path: &str) -> Vec<TodoItem> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let caps = TODO_COMMENT.captures(line)?;
+            let kind = TodoKind::parse(&caps[1])?;
+
+            let mut text = caps[3].trim();
+            for suffix in ["*/", "-->"] {
+                text = text.strip_suffix(suffix).unwrap_or(text).trim_end();
+            }
+
+            let owner = caps
+                .get(2)
+                .map(|m| m.as_str().trim().trim_start_matches('@').to_string())
+                .filter(|owner| !owner.is_empty())
+                .or_else(|| MENTION.captures(text).map(|m| m[1].to_string()));
+
+            Some(TodoItem {
+                kind,
+                file: relative_path.to_string(),
+                line: index + 1,
+                text: truncate(text),
+                owner,
+                age_days: None,
+            })
+        })
+        .collect()
+}
+
+fn truncate(text: &str) -> String {
+    if text.chars().count() <= MAX_TEXT_CHARS {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(MAX_TEXT_CHARS).collect();
+    format!("{}...", truncated)
+}
+
+// ============================================================================
+// TodoFilter
+// ============================================================================
+
+/// 백로그 필터
+#[derive(Debug, Clone, Default)]
+pub struct TodoFilter {
+    /// 종류
+    pub kind: Option<TodoKind>,
+    /// 담당자 (대소문자 무시)
+    pub owner: Option<String>,
+    /// 경로 접두사
+    pub path: Option<String>,
+    /// 최소 경과 일수
+    pub min_age_days: Option<u64>,
+}
+
+impl TodoFilter {
+    /// 항목이 필터와 일치하는지
+    pub fn matches(&self, item: &TodoItem) -> bool {
+        if self.kind.is_some_and(|kind| kind != item.kind) {
+            return false;
+        }
+        if let Some(owner) = &self.owner {
+            let owner = owner.trim_start_matches('@');
+            if !item
+                .owner
+                .as_ref()
+                .is_some_and(|o| o.eq_ignore_ascii_case(owner))
+            {
+                return false;
+            }
+        }
+        if let Some(path) = &self.path {
+            let path = path.trim_start_matches("./");
+            if !item.file.replace('\\', "/").starts_with(path) {
+                return false;
+            }
+        }
+        if let Some(min) = self.min_age_days {
+            if item.age_days.unwrap_or(0) < min {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// ============================================================================
+// TodoBacklog
+// ============================================================================
+
+/// TODO 백로그 (종류 → 오래된 순 정렬)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TodoBacklog {
+    /// 항목들
+    pub items: Vec<TodoItem>,
+}
+
+impl TodoBacklog {
+    /// 항목들로 생성
+    pub fn new(items: Vec<TodoItem>) -> Self {
+        let mut backlog = Self { items };
+        backlog.sort();
+        backlog
+    }
+
+    /// Repository Map의 파일들에서 수집
+    pub fn from_map(map: &RepoMap) -> Self {
+        Self::new(
+            map.files
+                .iter()
+                .flat_map(|file| file.todos.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// 항목 수
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// 비어있는지
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 필터 적용
+    pub fn filter(&self, filter: &TodoFilter) -> Vec<&TodoItem> {
+        self.items
+            .iter()
+            .filter(|item| filter.matches(item))
+            .collect()
+    }
+
+    /// 번호 붙인 목록 (`/todos --select`의 번호와 일치)
+    pub fn render(items: &[&TodoItem]) -> String {
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| format!("{}. {}", index + 1, item.to_compact_string()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// 종류별 개수
+    pub fn counts(&self) -> HashMap<TodoKind, usize> {
+        let mut counts = HashMap::new();
+        for item in &self.items {
+            *counts.entry(item.kind).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// git blame으로 담당자/경과 일수 채우기 (git 저장소가 아니면 무시)
+    pub fn annotate_with_git(&mut self, root: &Path) {
+        let files: BTreeSet<String> = self.items.iter().map(|item| item.file.clone()).collect();
+        let now = chrono::Utc::now().timestamp();
+
+        for file in files {
+            let Some(blame) = blame_file(root, &file) else {
+                continue;
+            };
+            for item in self.items.iter_mut().filter(|item| item.file == file) {
+                let Some(line) = blame.get(&item.line) else {
+                    continue;
+                };
+                if item.owner.is_none() {
+                    item.owner = line.author.clone();
+                }
+                item.age_days = Some((now - line.time).max(0) as u64 / 86_400);
+            }
+        }
+
+        self.sort();
+    }
+
+    /// FIXME → HACK → TODO, 같은 종류는 오래된 순
+    fn sort(&mut self) {
+        self.items.sort_by(|a, b| {
+            a.kind
+                .cmp(&b.kind)
+                .then(b.age_days.unwrap_or(0).cmp(&a.age_days.unwrap_or(0)))
+                .then(a.file.cmp(&b.file))
+                .then(a.line.cmp(&b.line))
+        });
+    }
+}
+
+/// blame 결과 (라인별)
+struct BlameLine {
+    author: Option<String>,
+    time: i64,
+}
+
+/// `git blame --line-porcelain` 실행 및 파싱
+fn blame_file(root: &Path, file: &str) -> Option<HashMap<usize, BlameLine>> {
+    let output = Command::new("git")
+        .args(["blame", "--line-porcelain", "--", file])
+        .current_dir(root)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        debug!("git blame skipped for {}", file);
+        return None;
+    }
+    Some(parse_line_porcelain(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// `--line-porcelain` 출력 파싱
+fn parse_line_porcelain(output: &str) -> HashMap<usize, BlameLine> {
+    let mut lines = HashMap::new();
+    let mut current_line = 0;
+    let mut author = None;
+    let mut time = 0;
+
+    for line in output.lines() {
+        if line.starts_with('\t') {
+            // 내용 줄 - 이 줄에 대한 헤더 끝
+            lines.insert(
+                current_line,
+                BlameLine {
+                    author: author.take(),
+                    time,
+                },
+            );
+        } else if let Some(name) = line.strip_prefix("author ") {
+            // 커밋되지 않은 줄은 작성자 없음
+            author = (name != "Not Committed Yet").then(|| name.to_string());
+        } else if let Some(t) = line.strip_prefix("author-time ") {
+            time = t.trim().parse().unwrap_or(0);
+        } else {
+            // 헤더: <sha> <orig_line> <final_line> [<count>]
+            let mut parts = line.split(' ');
+            if let (Some(sha), Some(_), Some(final_line)) =
+                (parts.next(), parts.next(), parts.next())
+            {
+                if sha.len() >= 40 && sha.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    current_line = final_line.parse().unwrap_or(0);
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_todos() {
+        let content = r#"
+fn main() {
+    // TODO(alice): add retry logic
+    let todo_list = "TODO: not a comment";
+    # FIXME @bob handle unicode paths
+    /* HACK: temporary workaround */
+    // todo lowercase is ignored
+}
+"#;
+        let todos = extract_todos(content, "src/main.rs");
+        assert_eq!(todos.len(), 3);
+
+        assert_eq!(todos[0].kind, TodoKind::Todo);
+        assert_eq!(todos[0].line, 3);
+        assert_eq!(todos[0].text, "add retry logic");
+        assert_eq!(todos[0].owner.as_deref(), Some("alice"));
+
+        assert_eq!(todos[1].kind, TodoKind::Fixme);
+        assert_eq!(todos[1].owner.as_deref(), Some("bob"));
+
+        assert_eq!(todos[2].kind, TodoKind::Hack);
+        assert_eq!(todos[2].text, "temporary workaround");
+        assert!(todos[2].owner.is_none());
+    }
+
+    #[test]
+    fn test_backlog_filter_and_order() {
+        let mut items = extract_todos("// TODO: a\n// FIXME(carol): b\n", "src/lib.rs");
+        items.extend(extract_todos("// TODO(dave): c\n", "tests/it.rs"));
+        items[2].age_days = Some(30);
+        let backlog = TodoBacklog::new(items);
+
+        // FIXME 먼저, 같은 종류는 오래된 순
+        assert_eq!(backlog.items[0].kind, TodoKind::Fixme);
+        assert_eq!(backlog.items[1].text, "c");
+        assert_eq!(backlog.counts()[&TodoKind::Todo], 2);
+
+        let filter = TodoFilter {
+            path: Some("./src".into()),
+            ..Default::default()
+        };
+        assert_eq!(backlog.filter(&filter).len(), 2);
+
+        let filter = TodoFilter {
+            owner: Some("@Dave".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            backlog.filter(&filter)[0].to_compact_string(),
+            "TODO tests/it.rs:1 c (@dave, 30d)"
+        );
+
+        let filter = TodoFilter {
+            kind: Some(TodoKind::Hack),
+            ..Default::default()
+        };
+        assert!(backlog.filter(&filter).is_empty());
+
+        let all = backlog.filter(&TodoFilter::default());
+        assert!(TodoBacklog::render(&all).starts_with("1. FIXME src/lib.rs:2 b (@carol)\n2. TODO"));
+    }
+
+    #[test]
+    fn test_parse_line_porcelain() {
+        let sha = "a".repeat(40);
+        let output = format!(
+            "{sha} 1 1 2\nauthor Alice\nauthor-time 1700000000\nsummary init\n\t// TODO: x\n\
+             {sha} 2 2\nauthor Not Committed Yet\nauthor-time 1700000100\n\tlet y = 1;\n"
+        );
+        let blame = parse_line_porcelain(&output);
+        assert_eq!(blame[&1].author.as_deref(), Some("Alice"));
+        assert_eq!(blame[&1].time, 1_700_000_000);
+        assert!(blame[&2].author.is_none());
+    }
+}
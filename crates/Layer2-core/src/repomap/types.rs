@@ -1,5 +1,6 @@
 //! Repository Map 타입 정의
 
+use super::todos::TodoItem;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
@@ -21,6 +22,8 @@ pub struct RepoMapConfig {
     pub analyze_dependencies: bool,
     /// 관련 파일 추천 활성화
     pub enable_ranking: bool,
+    /// 심볼 단위 토큰 예산 (false면 파일 단위로 담음)
+    pub symbol_budget: bool,
 }
 
 impl Default for RepoMapConfig {
@@ -52,6 +55,7 @@ impl Default for RepoMapConfig {
             symbol_depth: 2,
             analyze_dependencies: true,
             enable_ranking: true,
+            symbol_budget: true,
         }
     }
 }
@@ -189,29 +193,7 @@ impl SymbolDef {
     /// 압축된 표현 생성 (토큰 절약)
     pub fn to_compact_string(&self, depth: usize) -> String {
         let indent = "  ".repeat(depth);
-        let vis = self.visibility.as_deref().unwrap_or("");
-        let sig = self.signature.as_deref().unwrap_or("");
-
-        let line = if sig.is_empty() {
-            format!(
-                "{}{} {} {}:{}",
-                indent,
-                self.kind.as_str(),
-                vis,
-                self.name,
-                self.line
-            )
-        } else {
-            format!(
-                "{}{} {} {}{}:{}",
-                indent,
-                self.kind.as_str(),
-                vis,
-                self.name,
-                sig,
-                self.line
-            )
-        };
+        let line = format!("{}{}", indent, self.to_signature_string());
 
         if self.children.is_empty() {
             line.trim().to_string()
@@ -224,6 +206,20 @@ impl SymbolDef {
             format!("{}\n{}", line.trim(), children.join("\n"))
         }
     }
+
+    /// 시그니처 한 줄 (자식 제외)
+    pub fn to_signature_string(&self) -> String {
+        let vis = self.visibility.as_deref().unwrap_or("");
+        let sig = self.signature.as_deref().unwrap_or("");
+        format!(
+            "{} {} {}{}:{}",
+            self.kind.as_str(),
+            vis,
+            self.name,
+            sig,
+            self.line
+        )
+    }
 }
 
 /// 심볼 참조 (사용 위치)
@@ -267,6 +263,9 @@ pub struct FileInfo {
     pub estimated_tokens: usize,
     /// 중요도 점수 (랭킹용)
     pub importance_score: f64,
+    /// TODO/FIXME/HACK 주석
+    #[serde(default)]
+    pub todos: Vec<TodoItem>,
 }
 
 impl FileInfo {
@@ -282,6 +281,7 @@ impl FileInfo {
             exports: Vec::new(),
             estimated_tokens: 0,
             importance_score: 0.0,
+            todos: Vec::new(),
         }
     }
 
@@ -5,9 +5,11 @@
 //! 2. git diff로 변경 내용 분석
 //! 3. 의미있는 커밋 메시지 생성
 //! 4. 커밋 실행
+//! 5. `--pr`이면 브랜치를 올리고 `pull_request` 도구로 PR 생성
 
 use crate::skill::{
-    Skill, SkillArgument, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput,
+    Skill, SkillArgument, SkillArgumentType, SkillContext, SkillDefinition, SkillInput,
+    SkillMetadata, SkillOutput,
 };
 use async_trait::async_trait;
 use forge_foundation::Result;
@@ -34,7 +36,7 @@ impl Skill for CommitSkill {
             name: "commit".into(),
             command: "/commit".into(),
             description: "Analyze changes and create a meaningful git commit".into(),
-            usage: "/commit [-m <message>] [--all] [--amend]".into(),
+            usage: "/commit [-m <message>] [--all] [--amend] [--pr]".into(),
             arguments: vec![
                 SkillArgument {
                     name: "message".into(),
@@ -43,6 +45,7 @@ impl Skill for CommitSkill {
                     default: None,
                     short_flag: Some("-m".into()),
                     long_flag: Some("--message".into()),
+                    ..Default::default()
                 },
                 SkillArgument {
                     name: "all".into(),
@@ -51,6 +54,8 @@ impl Skill for CommitSkill {
                     default: Some("false".into()),
                     short_flag: Some("-a".into()),
                     long_flag: Some("--all".into()),
+                    kind: SkillArgumentType::Boolean,
+                    ..Default::default()
                 },
                 SkillArgument {
                     name: "amend".into(),
@@ -59,6 +64,18 @@ impl Skill for CommitSkill {
                     default: Some("false".into()),
                     short_flag: None,
                     long_flag: Some("--amend".into()),
+                    kind: SkillArgumentType::Boolean,
+                    ..Default::default()
+                },
+                SkillArgument {
+                    name: "pr".into(),
+                    description: "Push the branch and open a pull request after committing".into(),
+                    required: false,
+                    default: Some("false".into()),
+                    short_flag: None,
+                    long_flag: Some("--pr".into()),
+                    kind: SkillArgumentType::Boolean,
+                    ..Default::default()
                 },
             ],
             category: "git".into(),
@@ -72,7 +89,7 @@ impl Skill for CommitSkill {
             version: "1.0.0".into(),
             author: Some("ForgeCode".into()),
             source: None,
-            required_tools: vec!["bash".into()],
+            required_tools: vec!["bash".into(), "pull_request".into()],
             required_permissions: vec!["execute".into()],
             tags: vec!["git".into(), "vcs".into()],
             hidden: false,
@@ -94,7 +111,13 @@ Guidelines for commit messages:
 - Add a body with more details if needed
 - Focus on the "why" rather than the "what"
 
-Do NOT push to remote unless explicitly asked."#
+Do NOT push to remote unless explicitly asked.
+
+When asked to open a pull request (--pr):
+- If the current branch is the default branch, create a feature branch first
+- Use the pull_request tool with action "create" (it pushes the branch); use the
+  commit subject as the title and summarize the changes in the body
+- Report the PR URL"#
                 .into(),
         )
     }
@@ -111,9 +134,10 @@ Do NOT push to remote unless explicitly asked."#
         let message = input.get("m").or(input.get("message")).cloned();
         let stage_all = input.has_flag("a") || input.has_flag("all");
         let amend = input.has_flag("amend");
+        let open_pr = input.has_flag("pr");
 
         // Git 정보 수집을 위한 프롬프트 생성
-        let prompt = if let Some(ref msg) = message {
+        let mut prompt = if let Some(ref msg) = message {
             format!(
                 "Create a git commit with the message: '{}'. Stage all: {}. Amend: {}",
                 msg, stage_all, amend
@@ -125,12 +149,17 @@ Do NOT push to remote unless explicitly asked."#
             )
         };
 
+        if open_pr {
+            prompt.push_str(". Then open a pull request for the branch with the pull_request tool");
+        }
+
         // 에이전트 루프에서 사용할 데이터 반환
         Ok(SkillOutput::success(prompt)
             .with_data(serde_json::json!({
                 "requires_agent_loop": true,
                 "stage_all": stage_all,
                 "amend": amend,
+                "open_pr": open_pr,
                 "message": message,
                 "git_info": ctx.git_info.as_ref().map(|g| serde_json::json!({
                     "branch": g.branch,
@@ -170,4 +199,23 @@ mod tests {
         assert_eq!(input.get("m"), Some(&"'fix".to_string())); // Simple split
         assert!(input.has_flag("all"));
     }
+
+    #[tokio::test]
+    async fn test_commit_with_pr() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool_ctx = crate::tool::RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            std::sync::Arc::new(forge_foundation::PermissionService::new()),
+        );
+        let ctx = SkillContext::new(&tool_ctx, "session");
+        let skill = CommitSkill::new();
+
+        let output = skill
+            .execute(&ctx, skill.parse_input("/commit --all --pr"))
+            .await
+            .unwrap();
+        assert!(output.message.contains("open a pull request"));
+        assert_eq!(output.data["open_pr"], true);
+    }
 }
@@ -0,0 +1,242 @@
+//! Docs Skill - 변경된 코드의 문서 주석 작성
+//!
+//! `/docs`는 작업 트리의 변경 파일(`--base`가 있으면 그 브랜치 이후 커밋된 변경 포함)을
+//! 모아 에이전트가 공개 항목의 문서 주석을 추가/갱신하도록 합니다.
+
+use crate::git::{FileStatus, GitError, GitOps};
+use crate::skill::{
+    Skill, SkillArgument, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput,
+};
+use async_trait::async_trait;
+use forge_foundation::Result;
+use ignore::WalkBuilder;
+use serde_json::json;
+
+/// 문서 주석 작성 스킬
+pub struct DocsSkill;
+
+impl DocsSkill {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 문서화 대상 파일 (저장소 루트 기준 경로)
+    ///
+    /// 삭제된 파일과 바이너리 파일은 제외하고, 추적되지 않는 디렉토리는
+    /// 안의 파일들로 펼칩니다 (`.gitignore` 적용).
+    fn changed_files(
+        git: &GitOps,
+        base: Option<&str>,
+    ) -> std::result::Result<Vec<String>, GitError> {
+        let root = git.root();
+        let mut files = Vec::new();
+        for (path, status) in git.status()?.files {
+            if !matches!(
+                status,
+                FileStatus::New | FileStatus::Modified | FileStatus::Untracked
+            ) {
+                continue;
+            }
+            let full = root.join(&path);
+            if !full.is_dir() {
+                files.push(path.to_string_lossy().replace('\\', "/"));
+                continue;
+            }
+            for entry in WalkBuilder::new(&full).build().filter_map(|e| e.ok()) {
+                if entry.path().is_file() {
+                    if let Ok(relative) = entry.path().strip_prefix(root) {
+                        files.push(relative.to_string_lossy().replace('\\', "/"));
+                    }
+                }
+            }
+        }
+
+        if let Some(base) = base {
+            let merge_base = git.merge_base(base, "HEAD")?;
+            files.extend(
+                git.diff_stat(&merge_base, "HEAD")?
+                    .files
+                    .into_iter()
+                    .filter(|file| !file.binary && file.additions > 0)
+                    .map(|file| file.path),
+            );
+        }
+
+        files.sort();
+        files.dedup();
+        Ok(files)
+    }
+}
+
+impl Default for DocsSkill {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Skill for DocsSkill {
+    fn definition(&self) -> SkillDefinition {
+        SkillDefinition {
+            name: "docs".into(),
+            command: "/docs".into(),
+            description: "Add or update doc comments for changed code".into(),
+            usage: "/docs [PATH] [--base <ref>]".into(),
+            arguments: vec![
+                SkillArgument {
+                    name: "path".into(),
+                    description: "Only document changed files under this path".into(),
+                    required: false,
+                    default: None,
+                    short_flag: None,
+                    long_flag: None,
+                    ..Default::default()
+                },
+                SkillArgument {
+                    name: "base".into(),
+                    description: "Also include files committed since this branch or ref".into(),
+                    required: false,
+                    default: None,
+                    short_flag: Some("-b".into()),
+                    long_flag: Some("--base".into()),
+                    ..Default::default()
+                },
+            ],
+            category: "code".into(),
+            user_invocable: true,
+        }
+    }
+
+    fn metadata(&self) -> SkillMetadata {
+        SkillMetadata {
+            name: "docs".into(),
+            version: "1.0.0".into(),
+            author: Some("ForgeCode".into()),
+            source: None,
+            required_tools: vec!["read".into(), "edit".into()],
+            required_permissions: vec!["write".into()],
+            tags: vec!["documentation".into()],
+            hidden: false,
+        }
+    }
+
+    fn system_prompt(&self) -> Option<String> {
+        Some(
+            r#"You are documenting recently changed code.
+
+For each listed file:
+1. Read the file and, where useful, its diff (`git diff -- <file>`) to see what changed
+2. Add doc comments to new public items (types, functions, methods, modules) that lack them
+3. Update existing doc comments that no longer match the changed code
+
+Rules:
+- Match the file's existing doc comment style, language and length
+- Describe what the item does and anything non-obvious (errors, panics, invariants);
+  do not restate the signature
+- Only change comments; never change behavior, formatting or unrelated code
+- Skip generated files, lockfiles and files with nothing public to document
+
+Finish with a short list of the items you documented, grouped by file."#
+                .into(),
+        )
+    }
+
+    fn requires_agent_loop(&self) -> bool {
+        true
+    }
+
+    async fn execute(&self, ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
+        let git = match GitOps::new(ctx.tool_ctx.working_dir()) {
+            Ok(git) => git,
+            Err(e) => return Ok(SkillOutput::failure(e.to_string())),
+        };
+        let base = input.get("base").or(input.get("b")).map(String::as_str);
+        let mut files = match Self::changed_files(&git, base) {
+            Ok(files) => files,
+            Err(e) => return Ok(SkillOutput::failure(e.to_string())),
+        };
+        if let Some(path) = input.positional_args.first() {
+            let prefix = path.trim_start_matches("./");
+            files.retain(|file| file.starts_with(prefix));
+        }
+
+        if files.is_empty() {
+            return Ok(SkillOutput::success("No changed files to document")
+                .with_summary("Nothing to document"));
+        }
+
+        let since = base.map(|b| format!(" (since {})", b)).unwrap_or_default();
+        let list: Vec<String> = files.iter().map(|file| format!("- {}", file)).collect();
+        let prompt = format!(
+            "Add or update doc comments for the changed code in these {} files{}:\n\n{}",
+            files.len(),
+            since,
+            list.join("\n")
+        );
+
+        Ok(SkillOutput::success(prompt)
+            .with_data(json!({
+                "requires_agent_loop": true,
+                "files": files,
+            }))
+            .with_summary(format!("Documenting {} files", files.len())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_docs_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool_ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let ctx = SkillContext::new(&tool_ctx, "session");
+        let skill = DocsSkill::new();
+
+        // git 저장소가 아님
+        let output = skill
+            .execute(&ctx, skill.parse_input("/docs"))
+            .await
+            .unwrap();
+        assert!(!output.success);
+
+        let initialized = std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(dir.path())
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if !initialized {
+            return;
+        }
+        let output = skill
+            .execute(&ctx, skill.parse_input("/docs"))
+            .await
+            .unwrap();
+        assert_eq!(output.message, "No changed files to document");
+
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/lib.rs"), "pub fn a() {}").unwrap();
+        std::fs::write(dir.path().join("README.md"), "# x").unwrap();
+        let output = skill
+            .execute(&ctx, skill.parse_input("/docs"))
+            .await
+            .unwrap();
+        assert_eq!(output.data["files"], json!(["README.md", "src/lib.rs"]));
+
+        let output = skill
+            .execute(&ctx, skill.parse_input("/docs ./src"))
+            .await
+            .unwrap();
+        assert!(output
+            .message
+            .ends_with("in these 1 files:\n\n- src/lib.rs"));
+    }
+}
@@ -39,6 +39,7 @@ impl Skill for ExplainSkill {
                     default: None,
                     short_flag: None,
                     long_flag: None,
+                    ..Default::default()
                 },
                 SkillArgument {
                     name: "depth".into(),
@@ -47,6 +48,8 @@ impl Skill for ExplainSkill {
                     default: Some("normal".into()),
                     short_flag: Some("-d".into()),
                     long_flag: Some("--depth".into()),
+                    choices: vec!["brief".into(), "normal".into(), "detailed".into()],
+                    ..Default::default()
                 },
                 SkillArgument {
                     name: "audience".into(),
@@ -55,6 +58,8 @@ impl Skill for ExplainSkill {
                     default: Some("intermediate".into()),
                     short_flag: Some("-a".into()),
                     long_flag: Some("--audience".into()),
+                    choices: vec!["beginner".into(), "intermediate".into(), "expert".into()],
+                    ..Default::default()
                 },
             ],
             category: "code".into(),
@@ -0,0 +1,181 @@
+//! Fix Skill - 테스트가 통과할 때까지 수정
+//!
+//! `/fix`는 테스트를 실행하고 실패를 고친 뒤 다시 실행하는 과정을
+//! 테스트가 통과하거나 예산(`--attempts`, 테스트 실행 횟수)을 다 쓸 때까지 반복합니다.
+//! 예산은 프롬프트뿐 아니라 에이전트 반복 횟수 상한(`max_iterations`)으로도 적용됩니다.
+
+use super::test::{test_arguments, test_command};
+use crate::skill::{
+    Skill, SkillArgument, SkillArgumentType, SkillContext, SkillDefinition, SkillInput,
+    SkillMetadata, SkillOutput,
+};
+use async_trait::async_trait;
+use forge_foundation::Result;
+use serde_json::json;
+
+/// 기본 테스트 실행 횟수
+const DEFAULT_ATTEMPTS: usize = 5;
+
+/// 테스트 실행 한 번당 허용하는 에이전트 반복 (읽기, 수정, 실행)
+const ITERATIONS_PER_ATTEMPT: usize = 8;
+
+/// 테스트 수정 스킬
+pub struct FixSkill;
+
+impl FixSkill {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 테스트 실행 횟수 예산
+    fn attempts(input: &SkillInput) -> usize {
+        input
+            .get("attempts")
+            .or(input.get("n"))
+            .and_then(|n| n.parse::<usize>().ok())
+            .filter(|&n| n > 0)
+            .unwrap_or(DEFAULT_ATTEMPTS)
+    }
+}
+
+impl Default for FixSkill {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Skill for FixSkill {
+    fn definition(&self) -> SkillDefinition {
+        let mut arguments = test_arguments();
+        arguments.push(SkillArgument {
+            name: "attempts".into(),
+            description: "Maximum number of test runs before giving up".into(),
+            required: false,
+            default: Some(DEFAULT_ATTEMPTS.to_string()),
+            short_flag: Some("-n".into()),
+            long_flag: Some("--attempts".into()),
+            kind: SkillArgumentType::Integer,
+            ..Default::default()
+        });
+
+        SkillDefinition {
+            name: "fix".into(),
+            command: "/fix".into(),
+            description: "Fix failing tests, re-running them until green or out of attempts".into(),
+            usage: "/fix [FILTER] [--attempts <n>] [--command <cmd...>]".into(),
+            arguments,
+            category: "testing".into(),
+            user_invocable: true,
+        }
+    }
+
+    fn metadata(&self) -> SkillMetadata {
+        SkillMetadata {
+            name: "fix".into(),
+            version: "1.0.0".into(),
+            author: Some("ForgeCode".into()),
+            source: None,
+            required_tools: vec!["bash".into(), "read".into(), "edit".into()],
+            required_permissions: vec!["execute".into(), "write".into()],
+            tags: vec!["testing".into(), "debugging".into()],
+            hidden: false,
+        }
+    }
+
+    fn system_prompt(&self) -> Option<String> {
+        Some(
+            r#"You are fixing failing tests. Work in a loop:
+
+1. Run the test command with the bash tool
+2. If everything passes, stop
+3. Pick the first failure, read the test and the code under test, and find the root cause
+4. Fix the code with the smallest change that addresses the cause, then run the tests again
+
+Rules:
+- Fix the code under test, not the test, unless the test itself is clearly wrong;
+  say so explicitly when you change a test
+- Never delete, skip or weaken tests to make them pass
+- Count every test run against the budget; when it is used up, stop even if tests still fail
+
+Finish with a short report: final test status, what you changed and why,
+and any failures left with your best guess at the cause."#
+                .into(),
+        )
+    }
+
+    fn requires_agent_loop(&self) -> bool {
+        true
+    }
+
+    fn max_iterations(&self, input: &SkillInput) -> Option<usize> {
+        Some(Self::attempts(input) * ITERATIONS_PER_ATTEMPT)
+    }
+
+    async fn execute(&self, ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
+        let Some((framework, command)) = test_command(ctx.tool_ctx.working_dir(), &input) else {
+            return Ok(SkillOutput::failure(
+                "No test framework detected; pass the test command with --command",
+            ));
+        };
+        let attempts = Self::attempts(&input);
+
+        let prompt = format!(
+            "Make `{}` pass. Budget: at most {} test run{}.",
+            command,
+            attempts,
+            if attempts == 1 { "" } else { "s" }
+        );
+        Ok(SkillOutput::success(prompt)
+            .with_data(json!({
+                "requires_agent_loop": true,
+                "framework": framework,
+                "command": command,
+                "attempts": attempts,
+            }))
+            .with_summary(format!("Fixing {} tests", framework)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_fix_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+        let tool_ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let ctx = SkillContext::new(&tool_ctx, "session");
+        let skill = FixSkill::new();
+
+        let input = skill
+            .validate_input(skill.parse_input("/fix parser -n 2"))
+            .unwrap();
+        assert_eq!(
+            skill.max_iterations(&input),
+            Some(2 * ITERATIONS_PER_ATTEMPT)
+        );
+        let output = skill.execute(&ctx, input).await.unwrap();
+        assert_eq!(
+            output.message,
+            "Make `cargo test parser` pass. Budget: at most 2 test runs."
+        );
+
+        let input = skill.validate_input(skill.parse_input("/fix")).unwrap();
+        assert_eq!(
+            skill.max_iterations(&input),
+            Some(DEFAULT_ATTEMPTS * ITERATIONS_PER_ATTEMPT)
+        );
+        assert!(skill
+            .validate_input(skill.parse_input("/fix --attempts many"))
+            .is_err());
+    }
+}
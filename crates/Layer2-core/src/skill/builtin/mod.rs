@@ -5,7 +5,17 @@
 mod commit;
 mod review_pr;
 mod explain;
+mod resolve_conflicts;
+mod todos;
+mod test;
+mod fix;
+mod docs;
 
 pub use commit::CommitSkill;
 pub use review_pr::ReviewPrSkill;
 pub use explain::ExplainSkill;
+pub use resolve_conflicts::ResolveConflictsSkill;
+pub use todos::TodosSkill;
+pub use test::TestSkill;
+pub use fix::FixSkill;
+pub use docs::DocsSkill;
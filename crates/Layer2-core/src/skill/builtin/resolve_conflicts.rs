@@ -0,0 +1,186 @@
+//! Resolve Conflicts Skill - 머지 충돌 해결 안내
+//!
+//! merge/rebase/checkpoint 복원 후 남은 충돌을 hunk 단위로 보여주고,
+//! 에이전트가 hunk마다 해결안을 제안하면 사용자가 `resolve_conflict`
+//! 도구의 권한 요청으로 하나씩 승인합니다.
+
+use crate::git::GitOps;
+use crate::skill::{
+    Skill, SkillArgument, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput,
+};
+use async_trait::async_trait;
+use forge_foundation::Result;
+use serde_json::json;
+use std::path::Path;
+
+/// 머지 충돌 해결 스킬
+pub struct ResolveConflictsSkill;
+
+impl ResolveConflictsSkill {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ResolveConflictsSkill {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Skill for ResolveConflictsSkill {
+    fn definition(&self) -> SkillDefinition {
+        SkillDefinition {
+            name: "resolve-conflicts".into(),
+            command: "/resolve-conflicts".into(),
+            description: "Walk through merge conflicts hunk by hunk and resolve them with approval"
+                .into(),
+            usage: "/resolve-conflicts [PATH]".into(),
+            arguments: vec![SkillArgument {
+                name: "path".into(),
+                description: "Only resolve conflicts in this file".into(),
+                required: false,
+                default: None,
+                short_flag: None,
+                long_flag: None,
+                ..Default::default()
+            }],
+            category: "git".into(),
+            user_invocable: true,
+        }
+    }
+
+    fn metadata(&self) -> SkillMetadata {
+        SkillMetadata {
+            name: "resolve-conflicts".into(),
+            version: "1.0.0".into(),
+            author: Some("ForgeCode".into()),
+            source: None,
+            required_tools: vec!["resolve_conflict".into(), "read".into(), "bash".into()],
+            required_permissions: vec!["git.resolve".into()],
+            tags: vec!["git".into(), "merge".into(), "conflict".into()],
+            hidden: false,
+        }
+    }
+
+    fn system_prompt(&self) -> Option<String> {
+        Some(
+            r#"You are a merge-conflict assistant. Resolve the conflicts one hunk at a time:
+
+1. For each hunk, read enough of the surrounding code (and `git log` of both sides
+   if needed) to understand what each side intended
+2. Explain the conflict in one or two sentences and propose a resolution:
+   ours, theirs, both, base, or a hand-merged custom version that keeps both intents
+3. Apply it with the resolve_conflict tool (action "resolve"); the user approves or
+   rejects each hunk. If rejected, ask what they want instead
+4. Hunk numbers restart from 1 after each resolution, so always resolve from the list
+   the tool returns
+5. When all files are resolved, run the relevant build/tests and tell the user how to
+   finish (e.g. `git merge --continue`); do not continue or abort the operation yourself
+
+Never resolve a hunk by discarding a side you have not understood."#
+                .into(),
+        )
+    }
+
+    fn requires_agent_loop(&self) -> bool {
+        true
+    }
+
+    async fn execute(&self, ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
+        let git = match GitOps::new(ctx.tool_ctx.working_dir()) {
+            Ok(git) => git,
+            Err(e) => return Ok(SkillOutput::failure(e.to_string())),
+        };
+        let conflicts = match git.conflicts() {
+            Ok(conflicts) => conflicts,
+            Err(e) => return Ok(SkillOutput::failure(e.to_string())),
+        };
+        let path = input.positional_args.first().cloned();
+        let selected: Vec<_> = conflicts
+            .iter()
+            .filter(|f| path.as_deref().is_none_or(|p| f.path == Path::new(p)))
+            .collect();
+        if selected.is_empty() {
+            return Ok(
+                SkillOutput::success("No merge conflicts to resolve").with_summary("No conflicts")
+            );
+        }
+
+        let operation = git.conflict_operation().ok().flatten();
+        let hunks: usize = selected.iter().map(|f| f.hunks.len()).sum();
+        let mut prompt = format!(
+            "Resolve these merge conflicts{} hunk by hunk ({} file{}, {} hunk{}):\n\n",
+            operation
+                .map(|op| format!(" from `git {}`", op.command()))
+                .unwrap_or_default(),
+            selected.len(),
+            if selected.len() == 1 { "" } else { "s" },
+            hunks,
+            if hunks == 1 { "" } else { "s" }
+        );
+        for file in &selected {
+            prompt.push_str(&file.render());
+            prompt.push('\n');
+        }
+
+        Ok(SkillOutput::success(prompt.trim_end())
+            .with_data(json!({
+                "requires_agent_loop": true,
+                "operation": operation,
+                "files": selected.iter().map(|f| &f.path).collect::<Vec<_>>(),
+                "hunks": hunks,
+            }))
+            .with_summary(format!(
+                "Resolving {} conflict hunk{} in {} file{}",
+                hunks,
+                if hunks == 1 { "" } else { "s" },
+                selected.len(),
+                if selected.len() == 1 { "" } else { "s" }
+            )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_no_conflicts_outside_merge() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool_ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let ctx = SkillContext::new(&tool_ctx, "session");
+        let skill = ResolveConflictsSkill::new();
+        assert_eq!(skill.definition().command, "/resolve-conflicts");
+
+        // git 저장소가 아님
+        let output = skill
+            .execute(&ctx, skill.parse_input("/resolve-conflicts"))
+            .await
+            .unwrap();
+        assert!(!output.success);
+
+        let initialized = std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(dir.path())
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if !initialized {
+            return;
+        }
+        let output = skill
+            .execute(&ctx, skill.parse_input("/resolve-conflicts"))
+            .await
+            .unwrap();
+        assert!(output.success);
+        assert_eq!(output.message, "No merge conflicts to resolve");
+    }
+}
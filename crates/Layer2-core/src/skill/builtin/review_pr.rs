@@ -1,15 +1,21 @@
 //! Review PR Skill - Pull Request 리뷰 자동화
 //!
 //! Claude Code의 /review-pr 스킬과 유사하게 동작:
-//! 1. PR 정보 및 diff 가져오기
+//! 1. PR 정보 및 diff 가져오기 (gh/glab으로 미리 조회해 프롬프트에 포함)
 //! 2. 코드 변경사항 분석
-//! 3. 리뷰 코멘트 생성
+//! 3. 리뷰 코멘트 생성 (`--post`면 `pull_request` 도구로 PR에 게시)
 
+use crate::git::{parse_pr_ref, CodeHost, GitError, GitOps, PullRequest};
 use crate::skill::{
-    Skill, SkillArgument, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput,
+    Skill, SkillArgument, SkillArgumentType, SkillContext, SkillDefinition, SkillInput,
+    SkillMetadata, SkillOutput,
 };
 use async_trait::async_trait;
 use forge_foundation::Result;
+use std::path::Path;
+
+/// 프롬프트에 포함할 diff 최대 문자 수
+const MAX_DIFF_CHARS: usize = 60_000;
 
 /// Pull Request 리뷰 스킬
 pub struct ReviewPrSkill;
@@ -26,6 +32,44 @@ impl Default for ReviewPrSkill {
     }
 }
 
+/// PR 정보와 diff 조회 (PR 지정이 없으면 현재 브랜치의 PR)
+async fn fetch_pr(
+    working_dir: &Path,
+    pr_ref: Option<&str>,
+) -> std::result::Result<(PullRequest, String), GitError> {
+    let dir = working_dir.to_path_buf();
+    let pr_ref = pr_ref.map(String::from);
+    tokio::task::spawn_blocking(move || {
+        let host = CodeHost::detect(&dir)?;
+        let number = match pr_ref {
+            Some(pr_ref) => parse_pr_ref(&pr_ref).ok_or_else(|| {
+                GitError::HostCommandFailed(format!("'{}' is not a PR number or URL", pr_ref))
+            })?,
+            None => {
+                let branch = GitOps::new(&dir)?.current_branch()?;
+                host.pr_for_branch(&branch)?
+                    .ok_or_else(|| {
+                        GitError::HostCommandFailed(format!("no open PR for branch '{}'", branch))
+                    })?
+                    .number
+            }
+        };
+        Ok((host.get_pr(number)?, host.get_pr_diff(number)?))
+    })
+    .await
+    .map_err(|e| GitError::HostCommandFailed(e.to_string()))?
+}
+
+/// 긴 diff 자르기
+fn truncate_diff(diff: &str) -> String {
+    if diff.chars().count() <= MAX_DIFF_CHARS {
+        return diff.trim_end().to_string();
+    }
+    let mut truncated: String = diff.chars().take(MAX_DIFF_CHARS).collect();
+    truncated.push_str("\n... (diff truncated; read the remaining files directly)");
+    truncated
+}
+
 #[async_trait]
 impl Skill for ReviewPrSkill {
     fn definition(&self) -> SkillDefinition {
@@ -33,7 +77,7 @@ impl Skill for ReviewPrSkill {
             name: "review-pr".into(),
             command: "/review-pr".into(),
             description: "Review a pull request and provide feedback".into(),
-            usage: "/review-pr [PR_NUMBER | PR_URL] [--focus <area>]".into(),
+            usage: "/review-pr [PR_NUMBER | PR_URL] [--focus <area>] [--post]".into(),
             arguments: vec![
                 SkillArgument {
                     name: "pr".into(),
@@ -42,6 +86,7 @@ impl Skill for ReviewPrSkill {
                     default: None,
                     short_flag: None,
                     long_flag: None,
+                    ..Default::default()
                 },
                 SkillArgument {
                     name: "focus".into(),
@@ -50,6 +95,13 @@ impl Skill for ReviewPrSkill {
                     default: None,
                     short_flag: Some("-f".into()),
                     long_flag: Some("--focus".into()),
+                    choices: vec![
+                        "security".into(),
+                        "performance".into(),
+                        "style".into(),
+                        "logic".into(),
+                    ],
+                    ..Default::default()
                 },
                 SkillArgument {
                     name: "output".into(),
@@ -58,6 +110,18 @@ impl Skill for ReviewPrSkill {
                     default: Some("markdown".into()),
                     short_flag: Some("-o".into()),
                     long_flag: Some("--output".into()),
+                    choices: vec!["markdown".into(), "json".into(), "github".into()],
+                    ..Default::default()
+                },
+                SkillArgument {
+                    name: "post".into(),
+                    description: "Post the review as comments on the PR".into(),
+                    required: false,
+                    default: Some("false".into()),
+                    short_flag: None,
+                    long_flag: Some("--post".into()),
+                    kind: SkillArgumentType::Boolean,
+                    ..Default::default()
                 },
             ],
             category: "git".into(),
@@ -71,7 +135,7 @@ impl Skill for ReviewPrSkill {
             version: "1.0.0".into(),
             author: Some("ForgeCode".into()),
             source: None,
-            required_tools: vec!["bash".into(), "read".into()],
+            required_tools: vec!["bash".into(), "read".into(), "pull_request".into()],
             required_permissions: vec!["execute".into()],
             tags: vec!["git".into(), "review".into(), "pr".into()],
             hidden: false,
@@ -82,9 +146,12 @@ impl Skill for ReviewPrSkill {
         Some(
             r#"You are a code reviewer assistant. Your task is to:
 
-1. Fetch the PR diff and related information
+1. Fetch the PR diff and related information (it is included below when it could be
+   fetched; otherwise use the pull_request tool with action "view" / "diff")
 2. Analyze the changes thoroughly
 3. Provide constructive feedback
+4. If asked to post the review, use the pull_request tool with action "comment":
+   one inline comment (path + line) per issue, then one summary comment
 
 Review guidelines:
 - Focus on logic errors, security issues, and performance problems
@@ -124,9 +191,15 @@ Brief overview of the PR changes
         let output_format = input.get("output").or(input.get("o"))
             .cloned()
             .unwrap_or_else(|| "markdown".to_string());
+        let post = input.has_flag("post");
+
+        // PR 정보와 diff를 미리 가져와 에이전트의 조회 턴을 줄임
+        let fetched = fetch_pr(ctx.tool_ctx.working_dir(), pr_ref.as_deref()).await;
 
         // PR 정보 결정
-        let pr_target = if let Some(ref pr) = pr_ref {
+        let pr_target = if let Ok((pr, _)) = &fetched {
+            format!("#{} {}", pr.number, pr.title)
+        } else if let Some(ref pr) = pr_ref {
             pr.clone()
         } else if let Some(ref git_info) = ctx.git_info {
             format!("current branch: {}", git_info.branch)
@@ -134,19 +207,38 @@ Brief overview of the PR changes
             "current branch".to_string()
         };
 
-        let prompt = format!(
+        let mut prompt = format!(
             "Review the pull request: {}. Focus: {:?}. Output format: {}",
             pr_target,
             focus,
             output_format
         );
+        match &fetched {
+            Ok((pr, diff)) => prompt.push_str(&format!(
+                "\n\n{}\n{}\n\nDiff:\n```diff\n{}\n```",
+                pr.summary(),
+                pr.url,
+                truncate_diff(diff)
+            )),
+            Err(e) => prompt.push_str(&format!(
+                "\n\nThe PR could not be fetched automatically ({}); fetch it yourself.",
+                e
+            )),
+        }
+        if post {
+            prompt.push_str(
+                "\n\nWhen the review is done, post it on the PR with the pull_request tool.",
+            );
+        }
 
         Ok(SkillOutput::success(prompt)
             .with_data(serde_json::json!({
                 "requires_agent_loop": true,
                 "pr_ref": pr_ref,
+                "pr": fetched.as_ref().ok().map(|(pr, _)| pr),
                 "focus": focus,
                 "output_format": output_format,
+                "post": post,
             }))
             .with_summary(format!("Ready to review PR: {}", pr_target)))
     }
@@ -164,4 +256,36 @@ mod tests {
         assert_eq!(def.command, "/review-pr");
         assert!(skill.requires_agent_loop());
     }
+
+    #[tokio::test]
+    async fn test_review_pr_without_code_host() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool_ctx = crate::tool::RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            std::sync::Arc::new(forge_foundation::PermissionService::new()),
+        );
+        let ctx = SkillContext::new(&tool_ctx, "session");
+        let skill = ReviewPrSkill::new();
+
+        // 저장소가 아니면 diff 없이 에이전트가 직접 조회하도록 안내
+        let output = skill
+            .execute(&ctx, skill.parse_input("/review-pr 12 --post"))
+            .await
+            .unwrap();
+        assert!(output.message.contains("Review the pull request: 12"));
+        assert!(output
+            .message
+            .contains("could not be fetched automatically"));
+        assert!(output.message.contains("pull_request tool"));
+    }
+
+    #[test]
+    fn test_truncate_diff() {
+        assert_eq!(truncate_diff("diff --git a b\n"), "diff --git a b");
+        let long = "x".repeat(MAX_DIFF_CHARS + 10);
+        assert!(
+            truncate_diff(&long).ends_with("(diff truncated; read the remaining files directly)")
+        );
+    }
 }
@@ -0,0 +1,335 @@
+//! Test Skill - 테스트 실행 및 실패 요약
+//!
+//! `/test`는 프로젝트 파일로 테스트 프레임워크를 감지해 테스트를 실행하고
+//! 실패한 테스트를 요약합니다. 감지할 수 없으면 `--command`로 직접 지정합니다.
+//! 프레임워크 감지는 `/fix`도 함께 사용합니다.
+
+use crate::skill::{
+    Skill, SkillArgument, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput,
+};
+use async_trait::async_trait;
+use forge_foundation::Result;
+use serde_json::json;
+use std::path::Path;
+
+/// 감지된 테스트 프레임워크
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TestFramework {
+    /// 프레임워크 이름 (cargo, pytest 등)
+    pub name: &'static str,
+
+    /// 전체 테스트 명령어
+    pub command: String,
+}
+
+impl TestFramework {
+    fn new(name: &'static str, command: impl Into<String>) -> Self {
+        Self {
+            name,
+            command: command.into(),
+        }
+    }
+
+    /// 작업 디렉토리의 프로젝트 파일로 감지
+    pub fn detect(dir: &Path) -> Option<Self> {
+        let has = |file: &str| dir.join(file).exists();
+
+        if has("Cargo.toml") {
+            return Some(Self::new("cargo", "cargo test"));
+        }
+        if has("go.mod") {
+            return Some(Self::new("go", "go test ./..."));
+        }
+        if has("package.json") {
+            let runner = if has("pnpm-lock.yaml") {
+                "pnpm"
+            } else if has("yarn.lock") {
+                "yarn"
+            } else {
+                "npm"
+            };
+            return Some(Self::new(runner, format!("{} test", runner)));
+        }
+        if [
+            "pytest.ini",
+            "pyproject.toml",
+            "setup.cfg",
+            "tox.ini",
+            "setup.py",
+            "conftest.py",
+        ]
+        .iter()
+        .any(|file| has(file))
+        {
+            return Some(Self::new("pytest", "pytest"));
+        }
+        if has("pom.xml") {
+            return Some(Self::new("maven", "mvn test"));
+        }
+        if has("build.gradle") || has("build.gradle.kts") {
+            let gradle = if has("gradlew") {
+                "./gradlew"
+            } else {
+                "gradle"
+            };
+            return Some(Self::new("gradle", format!("{} test", gradle)));
+        }
+        let makefile = std::fs::read_to_string(dir.join("Makefile")).unwrap_or_default();
+        if makefile.lines().any(|line| line.starts_with("test:")) {
+            return Some(Self::new("make", "make test"));
+        }
+        None
+    }
+
+    /// `filter`(테스트 이름/경로)로 범위를 좁힌 명령어
+    pub fn command_with_filter(&self, filter: Option<&str>) -> String {
+        let Some(filter) = filter.filter(|f| !f.trim().is_empty()) else {
+            return self.command.clone();
+        };
+        let quoted = shlex::try_quote(filter)
+            .map(|q| q.into_owned())
+            .unwrap_or_else(|_| filter.to_string());
+        match self.name {
+            "cargo" => format!("cargo test {}", quoted),
+            "go" => format!("go test ./... -run {}", quoted),
+            "npm" | "pnpm" | "yarn" => format!("{} -- {}", self.command, quoted),
+            "pytest" => format!("pytest {}", quoted),
+            "maven" => format!("mvn test -Dtest={}", quoted),
+            "gradle" => format!("{} --tests {}", self.command, quoted),
+            _ => self.command.clone(),
+        }
+    }
+}
+
+/// 명령어 오버라이드(`--command`) 또는 감지된 프레임워크의 명령어
+///
+/// 반환: (프레임워크 이름, 명령어). 둘 다 없으면 None.
+pub(crate) fn test_command(dir: &Path, input: &SkillInput) -> Option<(String, String)> {
+    if let Some(command) = command_override(&input.raw_command) {
+        return Some(("custom".to_string(), command));
+    }
+    let filter = input
+        .positional_args
+        .first()
+        .or(input.get("filter"))
+        .map(String::as_str);
+    TestFramework::detect(dir).map(|framework| {
+        (
+            framework.name.to_string(),
+            framework.command_with_filter(filter),
+        )
+    })
+}
+
+/// `--command`/`-c` 뒤의 원문 전체
+///
+/// 기본 파서는 공백으로 나누고 `-x` 같은 토큰을 플래그로 잡으므로 원문에서 가져옵니다.
+/// 통째로 따옴표로 묶인 명령어(`--command 'make check'`)는 따옴표를 벗깁니다.
+fn command_override(raw: &str) -> Option<String> {
+    let mut offset = 0;
+    for word in raw.split_whitespace() {
+        let start = offset + raw[offset..].find(word)?;
+        offset = start + word.len();
+        if word == "--command" || word == "-c" {
+            let rest = raw[offset..].trim();
+            if rest.is_empty() {
+                return None;
+            }
+            return Some(match shlex::split(rest) {
+                Some(mut words) if words.len() == 1 => words.remove(0),
+                _ => rest.to_string(),
+            });
+        }
+    }
+    None
+}
+
+/// 테스트 명령어 인자 (`/test`, `/fix` 공통)
+pub(crate) fn test_arguments() -> Vec<SkillArgument> {
+    vec![
+        SkillArgument {
+            name: "filter".into(),
+            description: "Only run tests matching this name or path".into(),
+            required: false,
+            default: None,
+            short_flag: None,
+            long_flag: None,
+            ..Default::default()
+        },
+        SkillArgument {
+            name: "command".into(),
+            description: "Test command to run instead of the detected one (rest of the line)".into(),
+            required: false,
+            default: None,
+            short_flag: Some("-c".into()),
+            long_flag: Some("--command".into()),
+            ..Default::default()
+        },
+    ]
+}
+
+/// 테스트 실행 스킬
+pub struct TestSkill;
+
+impl TestSkill {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TestSkill {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Skill for TestSkill {
+    fn definition(&self) -> SkillDefinition {
+        SkillDefinition {
+            name: "test".into(),
+            command: "/test".into(),
+            description: "Detect the test framework, run the tests and summarize failures".into(),
+            usage: "/test [FILTER] [--command <cmd...>]".into(),
+            arguments: test_arguments(),
+            category: "testing".into(),
+            user_invocable: true,
+        }
+    }
+
+    fn metadata(&self) -> SkillMetadata {
+        SkillMetadata {
+            name: "test".into(),
+            version: "1.0.0".into(),
+            author: Some("ForgeCode".into()),
+            source: None,
+            required_tools: vec!["bash".into(), "read".into()],
+            required_permissions: vec!["execute".into()],
+            tags: vec!["testing".into()],
+            hidden: false,
+        }
+    }
+
+    fn system_prompt(&self) -> Option<String> {
+        Some(
+            r#"You are a test runner. Run the given test command once with the bash tool and report the result.
+
+Summary format:
+- First line: passed/failed/skipped counts, or that the suite did not build
+- For each failing test: its name, file:line if shown, the assertion or error message
+  (a few lines at most), and a one-sentence guess at the likely cause
+- Group failures that share a cause instead of repeating it
+
+Do not modify any files. If the command cannot run (missing tool, build error),
+show the relevant error and suggest the fix. Suggest `/fix` when tests fail."#
+                .into(),
+        )
+    }
+
+    fn requires_agent_loop(&self) -> bool {
+        true
+    }
+
+    async fn execute(&self, ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
+        let Some((framework, command)) = test_command(ctx.tool_ctx.working_dir(), &input) else {
+            return Ok(SkillOutput::failure(
+                "No test framework detected; pass the test command with --command",
+            ));
+        };
+
+        let prompt = format!(
+            "Run the tests with `{}` and summarize any failures.",
+            command
+        );
+        Ok(SkillOutput::success(prompt)
+            .with_data(json!({
+                "requires_agent_loop": true,
+                "framework": framework,
+                "command": command,
+            }))
+            .with_summary(format!("Running {} tests", framework)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_command_override() {
+        assert_eq!(
+            command_override("/test --command pytest -k 'a or b'").as_deref(),
+            Some("pytest -k 'a or b'")
+        );
+        assert_eq!(
+            command_override("/fix -c 'make check'").as_deref(),
+            Some("make check")
+        );
+        assert_eq!(command_override("/test --command"), None);
+        assert_eq!(command_override("/test parser"), None);
+    }
+
+    #[test]
+    fn test_detect_framework() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(TestFramework::detect(dir.path()).is_none());
+
+        std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("yarn.lock"), "").unwrap();
+        let node = TestFramework::detect(dir.path()).unwrap();
+        assert_eq!(node.command, "yarn test");
+        assert_eq!(
+            node.command_with_filter(Some("login flow")),
+            "yarn test -- 'login flow'"
+        );
+
+        std::fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+        let cargo = TestFramework::detect(dir.path()).unwrap();
+        assert_eq!(cargo.name, "cargo");
+        assert_eq!(
+            cargo.command_with_filter(Some("parser::")),
+            "cargo test parser::"
+        );
+        assert_eq!(cargo.command_with_filter(None), "cargo test");
+    }
+
+    #[tokio::test]
+    async fn test_test_skill_prompt() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool_ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let ctx = SkillContext::new(&tool_ctx, "session");
+        let skill = TestSkill::new();
+
+        let output = skill
+            .execute(&ctx, skill.parse_input("/test"))
+            .await
+            .unwrap();
+        assert!(!output.success);
+
+        let output = skill
+            .execute(&ctx, skill.parse_input("/test --command make check"))
+            .await
+            .unwrap();
+        assert!(output.message.contains("`make check`"));
+        let output = skill
+            .execute(&ctx, skill.parse_input("/test -c 'pytest -x'"))
+            .await
+            .unwrap();
+        assert!(output.message.contains("`pytest -x`"));
+
+        std::fs::write(dir.path().join("go.mod"), "module x").unwrap();
+        let output = skill
+            .execute(&ctx, skill.parse_input("/test TestParse"))
+            .await
+            .unwrap();
+        assert!(output.message.contains("`go test ./... -run TestParse`"));
+        assert_eq!(output.data["framework"], "go");
+    }
+}
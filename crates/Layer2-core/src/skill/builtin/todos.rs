@@ -0,0 +1,235 @@
+//! Todos Skill - TODO 백로그 검토 및 작업화
+//!
+//! `/todos`로 백로그를 훑어보고, `/todos --select 1,3`으로 고른 항목을
+//! 에이전트 작업으로 바꿉니다. 번호는 `list_todos` 도구와 같습니다.
+
+use crate::repomap::{RepoAnalyzer, TodoBacklog};
+use crate::skill::{
+    Skill, SkillArgument, SkillContext, SkillDefinition, SkillInput, SkillMetadata, SkillOutput,
+};
+use crate::tool::builtin::list_todos::todo_filter;
+use async_trait::async_trait;
+use forge_foundation::Result;
+use serde_json::json;
+
+/// 프롬프트에 넣을 최대 항목 수 (선택 없이 볼 때)
+const MAX_LISTED: usize = 50;
+
+/// TODO 백로그 스킬
+pub struct TodosSkill;
+
+impl TodosSkill {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TodosSkill {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// "1,3,5-7" 형식의 번호 목록 파싱
+fn parse_selection(selection: &str) -> Vec<usize> {
+    let mut numbers = Vec::new();
+    for part in selection
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+    {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                if let (Ok(start), Ok(end)) = (start.trim().parse::<usize>(), end.trim().parse()) {
+                    numbers.extend(start..=end);
+                }
+            }
+            None => numbers.extend(part.parse::<usize>().ok()),
+        }
+    }
+    numbers.retain(|&n| n > 0);
+    numbers.dedup();
+    numbers
+}
+
+#[async_trait]
+impl Skill for TodosSkill {
+    fn definition(&self) -> SkillDefinition {
+        SkillDefinition {
+            name: "todos".into(),
+            command: "/todos".into(),
+            description: "Review the TODO/FIXME/HACK backlog and turn items into tasks".into(),
+            usage: "/todos [PATH] [--kind <todo|fixme|hack>] [--owner <name>] [--select <1,3,5-7>]"
+                .into(),
+            arguments: vec![
+                SkillArgument {
+                    name: "path".into(),
+                    description: "Only include items under this path".into(),
+                    required: false,
+                    default: None,
+                    short_flag: None,
+                    long_flag: None,
+                    ..Default::default()
+                },
+                SkillArgument {
+                    name: "kind".into(),
+                    description: "Annotation kind: todo, fixme, hack".into(),
+                    required: false,
+                    default: None,
+                    short_flag: Some("-k".into()),
+                    long_flag: Some("--kind".into()),
+                    choices: vec!["todo".into(), "fixme".into(), "hack".into()],
+                    ..Default::default()
+                },
+                SkillArgument {
+                    name: "owner".into(),
+                    description: "Only include items owned by this person".into(),
+                    required: false,
+                    default: None,
+                    short_flag: Some("-o".into()),
+                    long_flag: Some("--owner".into()),
+                    ..Default::default()
+                },
+                SkillArgument {
+                    name: "select".into(),
+                    description: "Backlog numbers to turn into tasks (e.g. 1,3,5-7)".into(),
+                    required: false,
+                    default: None,
+                    short_flag: Some("-s".into()),
+                    long_flag: Some("--select".into()),
+                    ..Default::default()
+                },
+            ],
+            category: "code".into(),
+            user_invocable: true,
+        }
+    }
+
+    fn metadata(&self) -> SkillMetadata {
+        SkillMetadata {
+            name: "todos".into(),
+            version: "1.0.0".into(),
+            author: Some("ForgeCode".into()),
+            source: None,
+            required_tools: vec!["list_todos".into(), "read".into(), "edit".into()],
+            required_permissions: vec![],
+            tags: vec!["todo".into(), "planning".into(), "backlog".into()],
+            hidden: false,
+        }
+    }
+
+    fn system_prompt(&self) -> Option<String> {
+        Some(
+            r#"You are a backlog assistant working from TODO/FIXME/HACK comments in the codebase.
+
+When reviewing the backlog:
+- Group related items and point out duplicates
+- Flag FIXMEs and old items first
+- Recommend a few items that are small and well-defined enough to do now
+
+When turning items into tasks:
+- Treat each selected item as a separate task and work through them one at a time
+- Read the surrounding code before changing anything
+- Resolve the item, then remove or update its comment
+- Run the relevant tests after each task
+- If an item is unclear or too large, explain why and skip it instead of guessing"#
+                .into(),
+        )
+    }
+
+    fn requires_agent_loop(&self) -> bool {
+        true
+    }
+
+    async fn execute(&self, ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
+        let args = json!({
+            "kind": input.get("kind").or(input.get("k")),
+            "owner": input.get("owner").or(input.get("o")),
+            "path": input.positional_args.first().or(input.get("path")),
+        });
+        let filter = match todo_filter(&args) {
+            Ok(filter) => filter,
+            Err(e) => return Ok(SkillOutput::failure(e)),
+        };
+
+        let backlog = RepoAnalyzer::with_defaults(ctx.tool_ctx.working_dir())
+            .todo_backlog()
+            .await?;
+        let items = backlog.filter(&filter);
+        if items.is_empty() {
+            return Ok(SkillOutput::success("No TODO/FIXME/HACK comments found")
+                .with_summary("Backlog is empty"));
+        }
+
+        let selection = input
+            .get("select")
+            .or(input.get("s"))
+            .map(|s| parse_selection(s))
+            .unwrap_or_default();
+
+        if selection.is_empty() {
+            let shown = &items[..items.len().min(MAX_LISTED)];
+            let prompt = format!(
+                "Review this TODO backlog ({} items{}) and recommend what to tackle first. \
+                 The user can turn items into tasks with `/todos --select <numbers>`.\n\n{}",
+                items.len(),
+                if items.len() > shown.len() {
+                    format!(", first {} shown", shown.len())
+                } else {
+                    String::new()
+                },
+                TodoBacklog::render(shown)
+            );
+            return Ok(SkillOutput::success(prompt)
+                .with_data(json!({
+                    "requires_agent_loop": true,
+                    "total": items.len(),
+                }))
+                .with_summary(format!("Reviewing {} backlog items", items.len())));
+        }
+
+        let selected: Vec<_> = selection
+            .iter()
+            .filter_map(|&n| items.get(n - 1).copied())
+            .collect();
+        if selected.is_empty() {
+            return Ok(SkillOutput::failure(format!(
+                "No backlog items match selection (backlog has {} items)",
+                items.len()
+            )));
+        }
+
+        let prompt = format!(
+            "Turn these {} backlog items into tasks and complete them one at a time:\n\n{}",
+            selected.len(),
+            TodoBacklog::render(&selected)
+        );
+        Ok(SkillOutput::success(prompt)
+            .with_data(json!({
+                "requires_agent_loop": true,
+                "tasks": selected,
+            }))
+            .with_summary(format!("Working on {} backlog items", selected.len())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_todos_definition() {
+        let skill = TodosSkill::new();
+        let def = skill.definition();
+
+        assert_eq!(def.command, "/todos");
+        assert!(skill.requires_agent_loop());
+    }
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection("1,3, 5-7"), vec![1, 3, 5, 6, 7]);
+        assert_eq!(parse_selection("0,x,2"), vec![2]);
+        assert!(parse_selection("").is_empty());
+    }
+}
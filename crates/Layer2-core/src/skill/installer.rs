@@ -5,11 +5,12 @@
 
 use super::loader::FileBasedSkill;
 use super::store::{InstalledSkill, SkillStore};
+use crate::plugin::{PackageKind, PluginVersion, RemoteMarketplace, VerifiedPackage, VersionReq};
 use reqwest::Client;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 use forge_foundation::Result;
 
@@ -381,7 +382,7 @@ impl SkillInstaller {
                 if file_name.starts_with(&backup_pattern) {
                     if latest_backup
                         .as_ref()
-                        .map_or(true, |(_, old)| &file_name > old)
+                        .is_none_or(|(_, old)| &file_name > old)
                     {
                         latest_backup = Some((entry.path(), file_name));
                     }
@@ -443,6 +444,112 @@ impl SkillInstaller {
         Ok(())
     }
 
+    // ========================================================================
+    // 원격 마켓플레이스
+    // ========================================================================
+
+    /// 원격 인덱스에서 서명 검증 후 설치 (`version_req` 범위의 최신 버전)
+    pub async fn install_remote(
+        &self,
+        marketplace: &RemoteMarketplace,
+        name: &str,
+        version_req: &str,
+    ) -> Result<InstalledSkill> {
+        let req = VersionReq::parse(version_req).ok_or_else(|| {
+            forge_foundation::Error::InvalidInput(format!(
+                "Invalid version requirement: {}",
+                version_req
+            ))
+        })?;
+        let verified = marketplace.fetch(PackageKind::Skill, name, &req).await?;
+
+        let temp_dir = std::env::temp_dir().join(format!("forge_skill_{}", uuid::Uuid::new_v4()));
+        let result = self
+            .install_verified(&verified, version_req, &temp_dir)
+            .await;
+        let _ = fs::remove_dir_all(&temp_dir).await;
+
+        let installed = result?;
+        marketplace
+            .record_provenance(&verified, &installed.path)
+            .await;
+        Ok(installed)
+    }
+
+    /// 기록된 버전 요구사항 범위 안에서 업데이트 (고정 버전이거나 최신이면 None)
+    pub async fn update_remote(
+        &self,
+        marketplace: &RemoteMarketplace,
+        name: &str,
+    ) -> Result<Option<InstalledSkill>> {
+        let skill = self.store.get(name).await.ok_or_else(|| {
+            forge_foundation::Error::NotFound(format!("Skill '{}' not installed", name))
+        })?;
+
+        let version_req = skill.version_req.clone().unwrap_or_else(|| "*".into());
+        let req = VersionReq::parse(&version_req).unwrap_or_default();
+        if req.is_pinned() {
+            debug!("Skill {} is pinned to {}", name, version_req);
+            return Ok(None);
+        }
+
+        let Some((_, package)) = marketplace.resolve(PackageKind::Skill, name, &req).await else {
+            return Ok(None);
+        };
+        let current = PluginVersion::parse(skill.version.trim_start_matches('v'));
+        match (current, package.parsed_version()) {
+            (Some(current), Some(latest)) if latest > current => {}
+            _ => return Ok(None),
+        }
+
+        info!(
+            "Updating skill {} {} -> {}",
+            name, skill.version, package.version
+        );
+        let _ = self.backup(name).await;
+        let installed = self.install_remote(marketplace, name, &version_req).await?;
+        if !skill.enabled {
+            self.store.set_enabled(name, false).await?;
+        }
+        Ok(Some(installed))
+    }
+
+    /// 검증된 아카이브를 풀어 설치하고 출처 기록
+    async fn install_verified(
+        &self,
+        verified: &VerifiedPackage,
+        version_req: &str,
+        temp_dir: &Path,
+    ) -> Result<InstalledSkill> {
+        let package = &verified.package;
+        let root = verified.unpack(temp_dir).await?;
+
+        // 인덱스 항목과 실제 SKILL.md 이름이 일치해야 함
+        let skill = FileBasedSkill::from_file(&root.join("SKILL.md"))?;
+        if skill.config().name != package.id {
+            return Err(forge_foundation::Error::PermissionDenied(format!(
+                "Package {}@{} contains skill '{}'",
+                package.id,
+                package.version,
+                skill.config().name
+            )));
+        }
+
+        self.store.remove_skill_dir(&package.id).await?;
+        let installed = self.install_from_path(&root).await?;
+
+        let installed = InstalledSkill {
+            version: package.version.clone(),
+            source: package.url.clone(),
+            ..installed
+        }
+        .with_version_req(version_req)
+        .with_integrity(&package.sha256);
+        self.store.record_install(installed.clone()).await?;
+
+        Ok(installed)
+    }
+
     // ========================================================================
     // 유틸리티
     // ========================================================================
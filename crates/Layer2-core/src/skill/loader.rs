@@ -17,22 +17,34 @@
 //! ```yaml
 //! name: skill-name              # 필수 (64자 이하)
 //! description: 설명             # 필수 (1024자 이하)
-//! allowed-tools: [Read, Bash]   # 허용 도구
+//! allowed-tools: [Read, Bash]   # 허용 도구 (이 도구만 보는 서브에이전트에서 실행)
 //! user-invocable: true          # 사용자 호출 가능
-//! context: fork                 # subagent 실행
+//! context: fork                 # 대화와 분리된 서브에이전트에서 실행
 //! agent: Explore                # subagent 타입
-//! model: claude-sonnet-4        # 모델 오버라이드
+//! model: sonnet                 # 모델 오버라이드 (haiku/sonnet/opus/inherit 또는 모델 ID)
 //! argument-hint: [-m message]   # 인자 힌트
 //! # 확장 필드 (ForgeCode)
+//! max-tokens: 2048              # 응답 최대 토큰
+//! stop-sequences: ["</plan>"]   # 커스텀 stop sequence
+//! arguments:                    # 타입 있는 인자 (argument-hint보다 우선)
+//!   - {name: env, required: true, choices: [dev, prod]}
+//!   - {name: replicas, type: integer, default: "1"}
+//! steps:                        # 스킬/도구를 순서대로 호출 (파이프라인)
+//!   - run: cargo test
+//!   - {skill: /commit --all, on-failure: continue}
 //! category: git                 # 카테고리
 //! difficulty: beginner          # 난이도
 //! prerequisites: [skill-1]      # 선행 스킬
 //! estimated-time: "5 minutes"   # 예상 시간
 //! ```
 
+use super::pipeline::{PipelineStep, SkillPipeline};
 use super::traits::{Skill, SkillContext, SkillDefinition, SkillInput, SkillOutput, SkillMetadata, SkillArgument};
+use crate::forgecmd::RulePattern;
+use crate::subagent::{dedup, normalize_tool_name};
 use async_trait::async_trait;
 use forge_foundation::Result;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
@@ -60,8 +72,12 @@ pub struct SkillConfig {
     // Claude Code 표준 필드
     // ========================================
 
-    /// 허용된 Tool 목록
-    #[serde(rename = "allowed-tools")]
+    /// 허용된 Tool 목록 (`Read, Grep` 또는 `[Read, Grep]`, 인자 제한 포함)
+    #[serde(
+        rename = "allowed-tools",
+        default,
+        deserialize_with = "crate::subagent::deserialize_tool_patterns"
+    )]
     pub allowed_tools: Option<Vec<String>>,
 
     /// 모델만 호출 가능 (사용자 직접 호출 불가)
@@ -119,6 +135,20 @@ pub struct SkillConfig {
     /// 태그 목록
     pub tags: Option<Vec<String>>,
 
+    /// 응답 최대 토큰 (모델 한도로 제한됨)
+    #[serde(rename = "max-tokens")]
+    pub max_tokens: Option<u32>,
+
+    /// 커스텀 stop sequence
+    #[serde(rename = "stop-sequences")]
+    pub stop_sequences: Option<Vec<String>>,
+
+    /// 타입 있는 인자 스키마 (있으면 argument-hint 대신 사용)
+    pub arguments: Option<Vec<SkillArgument>>,
+
+    /// 실행 단계 (있으면 프롬프트 대신 다른 스킬/도구를 순서대로 호출)
+    pub steps: Option<Vec<PipelineStep>>,
+
     /// 스킬별 Hook 정의
     pub hooks: Option<SkillHooks>,
 }
@@ -332,31 +362,35 @@ impl Skill for FileBasedSkill {
             format!("/{}", self.config.name)
         };
 
-        // argument-hint에서 인자 생성
-        let arguments = self.config.argument_hint
-            .as_ref()
-            .map(|hints| {
-                hints.iter().map(|hint| {
-                    let name = hint.trim_start_matches('-').to_string();
-                    let (short_flag, long_flag) = if hint.starts_with("--") {
-                        (None, Some(hint.clone()))
-                    } else if hint.starts_with('-') {
-                        (Some(hint.clone()), None)
-                    } else {
-                        (None, None)
-                    };
-
-                    SkillArgument {
-                        name,
-                        description: format!("Argument: {}", hint),
-                        required: false,
-                        default: None,
-                        short_flag,
-                        long_flag,
-                    }
-                }).collect()
-            })
-            .unwrap_or_default();
+        // arguments 스키마 우선, 없으면 argument-hint에서 인자 생성
+        let arguments = match self.config.arguments.clone() {
+            Some(arguments) => arguments,
+            None => self.config.argument_hint
+                .as_ref()
+                .map(|hints| {
+                    hints.iter().map(|hint| {
+                        let name = hint.trim_start_matches('-').to_string();
+                        let (short_flag, long_flag) = if hint.starts_with("--") {
+                            (None, Some(hint.clone()))
+                        } else if hint.starts_with('-') {
+                            (Some(hint.clone()), None)
+                        } else {
+                            (None, None)
+                        };
+
+                        SkillArgument {
+                            name,
+                            description: format!("Argument: {}", hint),
+                            required: false,
+                            default: None,
+                            short_flag,
+                            long_flag,
+                            ..Default::default()
+                        }
+                    }).collect()
+                })
+                .unwrap_or_default(),
+        };
 
         // 카테고리: 설정에서 가져오거나 기본값 사용
         let category = self.config.category.clone()
@@ -391,7 +425,7 @@ impl Skill for FileBasedSkill {
             source: Some(self.source_path.display().to_string()),
             version: self.config.version.clone().unwrap_or_else(|| "1.0.0".to_string()),
             author: self.config.author.clone(),
-            required_tools: self.config.allowed_tools.clone().unwrap_or_default(),
+            required_tools: self.allowed_tools().unwrap_or_default(),
             required_permissions: vec![],
             tags,
             hidden: self.config.user_invocable == Some(false),
@@ -410,6 +444,60 @@ impl Skill for FileBasedSkill {
         self.needs_agent_loop()
     }
 
+    fn max_tokens(&self) -> Option<u32> {
+        self.config.max_tokens
+    }
+
+    fn stop_sequences(&self) -> Vec<String> {
+        self.config.stop_sequences.clone().unwrap_or_default()
+    }
+
+    fn pipeline(&self) -> Option<SkillPipeline> {
+        self.config.steps.clone().map(SkillPipeline::new)
+    }
+
+    fn allowed_tools(&self) -> Option<Vec<String>> {
+        let tools = self.config.allowed_tools.as_ref()?;
+        Some(dedup(tools.iter().map(|tool| normalize_tool_name(tool))))
+    }
+
+    fn allowed_tool_rules(&self) -> Vec<RulePattern> {
+        let rules: Vec<RulePattern> = self
+            .config
+            .allowed_tools
+            .iter()
+            .flatten()
+            .map(|tool| RulePattern::parse(tool))
+            .collect();
+        let unrestricted = |name: &str| {
+            rules.iter().any(|rule| {
+                matches!(rule, RulePattern::Tool { tool, specifier: None } if tool == name)
+            })
+        };
+        rules
+            .iter()
+            .filter(|rule| match rule {
+                RulePattern::Tool {
+                    tool,
+                    specifier: Some(_),
+                } => !unrestricted(tool),
+                _ => false,
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn model(&self) -> Option<String> {
+        self.config
+            .model
+            .clone()
+            .filter(|model| !model.trim().is_empty())
+    }
+
+    fn forks_context(&self) -> bool {
+        self.needs_agent_loop()
+    }
+
     async fn execute(&self, _ctx: &SkillContext<'_>, input: SkillInput) -> Result<SkillOutput> {
         // $ARGUMENTS 치환
         let mut prompt = self.system_prompt.clone();
@@ -569,12 +657,30 @@ impl SkillLoader {
 
 /// YAML frontmatter와 body를 분리
 fn parse_frontmatter(content: &str) -> Result<(SkillConfig, String)> {
+    let (config, body): (SkillConfig, String) = split_frontmatter(content)?;
+
+    // 잘못된 단계는 실행 시점이 아니라 로드 시점에 알림
+    if let Some(ref steps) = config.steps {
+        SkillPipeline::new(steps.clone()).validate().map_err(|e| {
+            forge_foundation::Error::InvalidInput(format!("Skill '{}': {}", config.name, e))
+        })?;
+    }
+
+    Ok((config, body))
+}
+
+/// YAML frontmatter를 `T`로 파싱하고 body와 분리 (frontmatter가 없으면 `T::default()`)
+///
+/// 서브에이전트 정의(`agents/*.md`)도 같은 형식을 사용합니다.
+pub(crate) fn split_frontmatter<T: DeserializeOwned + Default>(
+    content: &str,
+) -> Result<(T, String)> {
     let lines: Vec<&str> = content.lines().collect();
 
     // frontmatter 시작 확인
     if lines.is_empty() || lines[0].trim() != "---" {
         // frontmatter 없음 - 기본 설정 사용
-        return Ok((SkillConfig::default(), content.to_string()));
+        return Ok((T::default(), content.to_string()));
     }
 
     // frontmatter 끝 찾기
@@ -587,12 +693,12 @@ fn parse_frontmatter(content: &str) -> Result<(SkillConfig, String)> {
     }
 
     let end_idx = end_idx.ok_or_else(|| {
-        forge_foundation::Error::InvalidInput("Invalid SKILL.md: unclosed frontmatter".into())
+        forge_foundation::Error::InvalidInput("Invalid frontmatter: missing closing '---'".into())
     })?;
 
     // YAML 파싱
     let yaml_content = lines[1..end_idx].join("\n");
-    let config: SkillConfig = serde_yaml::from_str(&yaml_content)
+    let config: T = serde_yaml::from_str(&yaml_content)
         .map_err(|e| forge_foundation::Error::InvalidInput(format!("Invalid YAML frontmatter: {}", e)))?;
 
     // Body 추출
@@ -651,6 +757,34 @@ $ARGUMENTS 파라미터가 있으면 사용합니다.
         assert_eq!(skill.definition().command, "/commit");
         assert!(skill.requires_agent_loop());
         assert!(skill.system_prompt().is_some());
+        assert_eq!(skill.allowed_tools(), Some(vec!["read".into(), "bash".into(), "grep".into()]));
+    }
+
+    #[test]
+    fn test_subagent_constraints() {
+        let content = r#"---
+name: audit
+allowed-tools: Read, Grep, Bash(git log:*)
+model: haiku
+context: fork
+---
+Audit the repository.
+"#;
+        let skill = FileBasedSkill::parse(content, PathBuf::from("audit/SKILL.md")).unwrap();
+        assert_eq!(
+            skill.allowed_tools(),
+            Some(vec!["read".into(), "grep".into(), "bash".into()])
+        );
+        assert_eq!(skill.allowed_tool_rules(), [RulePattern::parse("Bash(git log:*)")]);
+        assert_eq!(skill.model(), Some("haiku".into()));
+        assert!(skill.forks_context());
+
+        let plain =
+            FileBasedSkill::parse("---\nname: plain\n---\nHi", PathBuf::from("plain/SKILL.md"))
+                .unwrap();
+        assert_eq!(plain.allowed_tools(), None);
+        assert_eq!(plain.model(), None);
+        assert!(!plain.forks_context());
     }
 
     #[test]
@@ -713,6 +847,9 @@ related-skills:
   - monitor
 estimated-time: "15 minutes"
 last-updated: "2025-02-05"
+max-tokens: 2048
+stop-sequences:
+  - "</summary>"
 ---
 
 배포 스크립트 실행
@@ -734,6 +871,79 @@ last-updated: "2025-02-05"
         assert_eq!(config.related_skills, Some(vec!["rollback".into(), "monitor".into()]));
         assert_eq!(config.estimated_time, Some("15 minutes".into()));
         assert_eq!(config.last_updated, Some("2025-02-05".into()));
+        assert_eq!(config.max_tokens, Some(2048));
+        assert_eq!(config.stop_sequences, Some(vec!["</summary>".into()]));
+    }
+
+    #[test]
+    fn test_typed_arguments() {
+        let content = r#"---
+name: deploy
+arguments:
+  - name: env
+    description: Target environment
+    required: true
+    choices: [dev, prod]
+  - name: replicas
+    type: integer
+    default: "1"
+argument-hint:
+  - --ignored
+---
+
+Deploy to $ARGUMENTS
+"#;
+
+        let skill = FileBasedSkill::parse(content, PathBuf::from("deploy/SKILL.md")).unwrap();
+        let arguments = skill.definition().arguments;
+        assert_eq!(arguments.len(), 2);
+        assert_eq!(arguments[1].kind, crate::skill::SkillArgumentType::Integer);
+
+        let input = skill.parse_input("/deploy --env dev --replicas 2");
+        assert!(skill.validate_input(input).is_ok());
+        let input = skill.parse_input("/deploy --env qa");
+        assert!(skill.validate_input(input).is_err());
+    }
+
+    #[test]
+    fn test_pipeline_steps() {
+        let content = r#"---
+name: ship
+description: Test, commit and push
+steps:
+  - name: test
+    run: cargo test
+  - skill: /commit --all
+    on-failure: continue
+  - run: git push
+    on-failure: retry
+    retries: 1
+---
+"#;
+
+        let skill = FileBasedSkill::parse(content, PathBuf::from("ship/SKILL.md")).unwrap();
+        let pipeline = skill.pipeline().unwrap();
+        assert_eq!(pipeline.steps.len(), 3);
+        assert_eq!(
+            pipeline.steps[1].on_failure,
+            crate::skill::FailurePolicy::Continue
+        );
+        assert_eq!(pipeline.steps[2].max_attempts(), 2);
+
+        let invalid = "---\nname: broken\nsteps:\n  - name: nothing\n---\n";
+        let error = FileBasedSkill::parse(invalid, PathBuf::from("broken/SKILL.md"))
+            .err()
+            .unwrap();
+        assert!(error
+            .to_string()
+            .contains("step 1 (nothing) needs one of skill, tool or run"));
+
+        let plain = FileBasedSkill::parse(
+            "---\nname: plain\n---\nDo it",
+            PathBuf::from("plain/SKILL.md"),
+        )
+        .unwrap();
+        assert!(plain.pipeline().is_none());
     }
 
     /// Claude Code 최소 형식 호환성 테스트
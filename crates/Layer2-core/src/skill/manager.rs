@@ -16,6 +16,10 @@
 //! // GitHub에서 직접 설치
 //! manager.install("github:user/skills/my-skill").await?;
 //!
+//! // 서명된 원격 인덱스에서 버전 범위로 설치 / 업데이트
+//! manager.install_remote(&remote, "commit", "^1.2").await?;
+//! manager.update_remote(&remote, "commit").await?;
+//!
 //! // 스킬 교체
 //! manager.replace("commit", new_content).await?;
 //!
@@ -27,6 +31,7 @@ use super::installer::SkillInstaller;
 use super::loader::FileBasedSkill;
 use super::marketplace::{MarketplaceSkill, SkillMarketplace};
 use super::store::{InstalledSkill, SkillStore};
+use crate::plugin::RemoteMarketplace;
 use crate::registry::DynamicSkillRegistry;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -166,6 +171,37 @@ impl SkillManager {
         self.installer.install_from_raw(name, content).await
     }
 
+    /// 원격 인덱스에서 서명 검증 후 설치 (`version_req` 범위의 최신 버전)
+    pub async fn install_remote(
+        &self,
+        marketplace: &RemoteMarketplace,
+        name: &str,
+        version_req: &str,
+    ) -> Result<InstalledSkill> {
+        self.installer
+            .install_remote(marketplace, name, version_req)
+            .await
+    }
+
+    /// 기록된 버전 요구사항 범위 안에서 원격 스킬 업데이트 (레지스트리에 반영)
+    pub async fn update_remote(
+        &self,
+        marketplace: &RemoteMarketplace,
+        name: &str,
+    ) -> Result<Option<InstalledSkill>> {
+        let updated = self.installer.update_remote(marketplace, name).await?;
+
+        if let (Some(installed), Some(registry)) = (&updated, &self.registry) {
+            if let Ok(skill) = FileBasedSkill::from_file(&installed.path.join("SKILL.md")) {
+                let _ = registry
+                    .replace(name, Arc::new(skill), installed.version.clone())
+                    .await;
+            }
+        }
+
+        Ok(updated)
+    }
+
     // ========================================================================
     // 변경 (핵심!)
     // ========================================================================
@@ -250,6 +250,13 @@ impl SkillMarketplace {
     async fn fetch_registry(&self, url: &str) -> Result<MarketplaceRegistry> {
         debug!("Fetching registry from {}", url);
 
+        if !url.starts_with("https://") {
+            return Err(forge_foundation::Error::InvalidInput(format!(
+                "Refusing to fetch registry over insecure transport: {}",
+                url
+            )));
+        }
+
         let response = self.client
             .get(url)
             .header("User-Agent", "ForgeCode")
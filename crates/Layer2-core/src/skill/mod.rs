@@ -41,6 +41,8 @@
 
 mod registry;
 mod traits;
+mod plan;
+mod pipeline;
 mod loader;
 mod store;
 mod installer;
@@ -49,12 +51,25 @@ mod manager;
 
 pub use registry::SkillRegistry;
 pub use traits::{
-    Skill, SkillArgument, SkillContext, SkillDefinition, SkillInput, SkillOutput, SkillMetadata,
+    Skill, SkillArgument, SkillArgumentType, SkillContext, SkillDefinition, SkillInput, SkillOutput, SkillMetadata,
     GitInfo, SkillAction,
 };
 
+// Dry-run plan (--dry-run)
+pub use plan::{
+    render_plan_approvals, split_dry_run, touches_git_remote, DryRunRecorder, PlanStep,
+    PlanStepKind, SkillPlan, DRY_RUN_FLAG,
+};
+
+// Skill composition (steps: in SKILL.md)
+pub use pipeline::{
+    FailurePolicy, PipelineAction, PipelineReport, PipelineStep, SkillPipeline, StepOutcome,
+    DEFAULT_STEP_RETRIES, RUN_TOOL,
+};
+
 // File-based skill loader (Claude Code compatible)
 pub use loader::{SkillLoader, FileBasedSkill, SkillConfig};
+pub(crate) use loader::split_frontmatter;
 
 // Skill store and installer (easy replacement!)
 pub use store::{SkillStore, InstalledSkill};
@@ -68,4 +83,7 @@ pub use manager::SkillManager;
 
 // Built-in skills
 pub mod builtin;
-pub use builtin::{CommitSkill, ReviewPrSkill, ExplainSkill};
+pub use builtin::{
+    CommitSkill, DocsSkill, ExplainSkill, FixSkill, ResolveConflictsSkill, ReviewPrSkill, TestSkill,
+    TodosSkill,
+};
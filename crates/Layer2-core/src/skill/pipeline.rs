@@ -0,0 +1,561 @@
+//! Skill Pipeline - 스킬 조합
+//!
+//! 스킬 정의(SKILL.md frontmatter의 `steps`)에 다른 스킬과 도구를 순서대로 호출하는
+//! 단계를 선언합니다. Rust 스킬을 작성하지 않고도 `/ship` 같은 워크플로우를 만들 수 있습니다.
+//!
+//! ```yaml
+//! name: ship
+//! description: Test, commit, push and open a PR
+//! arguments:
+//!   - {name: base, default: main}
+//! steps:
+//!   - name: test
+//!     run: cargo test                  # bash 도구 단축형
+//!   - skill: /commit --all             # 다른 스킬 호출
+//!   - run: git push -u origin HEAD
+//!     on-failure: retry                # abort(기본) | continue | retry
+//!     retries: 2
+//!   - tool: bash                       # 임의의 도구 호출
+//!     input: {command: "gh pr create --fill --base ${base}"}
+//!     on-failure: continue
+//! ```
+//!
+//! 단계의 문자열에서 `$ARGUMENTS`는 스킬에 전달된 전체 인자로,
+//! `${name}`은 인자 값(없으면 빈 문자열)으로 치환됩니다.
+//! 실행은 Layer3의 `execute_pipeline`이 담당합니다.
+
+use super::traits::{SkillDefinition, SkillInput};
+use forge_foundation::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// `run` 단계가 사용하는 도구
+pub const RUN_TOOL: &str = "bash";
+
+/// `on-failure: retry`의 기본 재시도 횟수
+pub const DEFAULT_STEP_RETRIES: u32 = 2;
+
+fn default_retries() -> u32 {
+    DEFAULT_STEP_RETRIES
+}
+
+// ============================================================================
+// PipelineStep
+// ============================================================================
+
+/// 단계 실패 시 동작
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FailurePolicy {
+    /// 파이프라인 중단 (기본)
+    #[default]
+    Abort,
+    /// 실패를 기록하고 다음 단계 진행
+    Continue,
+    /// `retries`번 다시 시도하고, 그래도 실패하면 중단
+    Retry,
+}
+
+/// 단계가 실행할 동작 (인자 치환 후)
+#[derive(Debug, Clone, PartialEq)]
+pub enum PipelineAction {
+    /// 스킬 호출 (`/commit --all`)
+    Skill(String),
+    /// 도구 호출
+    Tool { name: String, input: Value },
+}
+
+/// 파이프라인의 한 단계
+///
+/// `skill`, `tool`, `run` 중 정확히 하나를 지정합니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PipelineStep {
+    /// 표시 이름 (없으면 호출 대상에서 생성)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// 호출할 스킬 명령어
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
+
+    /// 호출할 도구 이름
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+
+    /// 도구 입력
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub input: Value,
+
+    /// 셸 명령어 (`bash` 도구 호출)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<String>,
+
+    /// 실패 시 동작
+    #[serde(default)]
+    pub on_failure: FailurePolicy,
+
+    /// 재시도 횟수 (`on-failure: retry`일 때만 사용)
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+}
+
+impl PipelineStep {
+    fn new() -> Self {
+        Self {
+            name: None,
+            skill: None,
+            tool: None,
+            input: Value::Null,
+            run: None,
+            on_failure: FailurePolicy::default(),
+            retries: DEFAULT_STEP_RETRIES,
+        }
+    }
+
+    /// 스킬 호출 단계
+    pub fn for_skill(command: impl Into<String>) -> Self {
+        Self {
+            skill: Some(command.into()),
+            ..Self::new()
+        }
+    }
+
+    /// 도구 호출 단계
+    pub fn for_tool(name: impl Into<String>, input: Value) -> Self {
+        Self {
+            tool: Some(name.into()),
+            input,
+            ..Self::new()
+        }
+    }
+
+    /// 셸 명령어 단계
+    pub fn for_command(command: impl Into<String>) -> Self {
+        Self {
+            run: Some(command.into()),
+            ..Self::new()
+        }
+    }
+
+    /// 표시 이름 지정
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// 실패 시 동작 지정
+    pub fn with_on_failure(mut self, policy: FailurePolicy) -> Self {
+        self.on_failure = policy;
+        self
+    }
+
+    /// 재시도 횟수 지정
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// 호출 대상 문제 (정확히 하나가 아니면 설명)
+    fn target_problem(&self) -> Option<&'static str> {
+        let targets = [
+            self.skill.is_some(),
+            self.tool.is_some(),
+            self.run.is_some(),
+        ]
+        .iter()
+        .filter(|set| **set)
+        .count();
+        match targets {
+            1 => None,
+            0 => Some("needs one of skill, tool or run"),
+            _ => Some("sets more than one of skill, tool and run"),
+        }
+    }
+
+    /// 호출 대상이 정확히 하나인지 확인
+    pub fn validate(&self) -> Result<()> {
+        match self.target_problem() {
+            None => Ok(()),
+            Some(problem) => Err(Error::InvalidInput(format!(
+                "step '{}' {}",
+                self.label(),
+                problem
+            ))),
+        }
+    }
+
+    /// 표시 이름
+    pub fn label(&self) -> String {
+        if let Some(ref name) = self.name {
+            return name.clone();
+        }
+        if let Some(ref skill) = self.skill {
+            return skill
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+        }
+        if let Some(ref tool) = self.tool {
+            return tool.clone();
+        }
+        match self.run {
+            Some(ref command) => command
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            None => "step".to_string(),
+        }
+    }
+
+    /// 최대 시도 횟수
+    pub fn max_attempts(&self) -> u32 {
+        match self.on_failure {
+            FailurePolicy::Retry => self.retries + 1,
+            FailurePolicy::Abort | FailurePolicy::Continue => 1,
+        }
+    }
+
+    /// 변수를 치환한 실행 동작
+    pub fn action(&self, vars: &HashMap<String, String>) -> Result<PipelineAction> {
+        self.validate()?;
+        if let Some(ref skill) = self.skill {
+            return Ok(PipelineAction::Skill(substitute(skill, vars)));
+        }
+        if let Some(ref tool) = self.tool {
+            return Ok(PipelineAction::Tool {
+                name: tool.clone(),
+                input: substitute_value(&self.input, vars),
+            });
+        }
+        let command = self.run.as_deref().unwrap_or_default();
+        Ok(PipelineAction::Tool {
+            name: RUN_TOOL.to_string(),
+            input: json!({ "command": substitute(command, vars) }),
+        })
+    }
+}
+
+/// `$ARGUMENTS`와 `${name}` 치환
+fn substitute(text: &str, vars: &HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        if let Some(tail) = after.strip_prefix("ARGUMENTS") {
+            out.push_str(
+                vars.get("ARGUMENTS")
+                    .map(String::as_str)
+                    .unwrap_or_default(),
+            );
+            rest = tail;
+        } else if let Some((name, tail)) = after.strip_prefix('{').and_then(|s| s.split_once('}')) {
+            out.push_str(
+                vars.get(name.trim())
+                    .map(String::as_str)
+                    .unwrap_or_default(),
+            );
+            rest = tail;
+        } else {
+            out.push('$');
+            rest = after;
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// JSON 값 안의 문자열을 모두 치환
+fn substitute_value(value: &Value, vars: &HashMap<String, String>) -> Value {
+    match value {
+        Value::String(text) => Value::String(substitute(text, vars)),
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|v| substitute_value(v, vars)).collect())
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), substitute_value(v, vars)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+// ============================================================================
+// SkillPipeline
+// ============================================================================
+
+/// 스킬이 선언한 단계 목록
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SkillPipeline {
+    /// 단계들 (실행 순서)
+    pub steps: Vec<PipelineStep>,
+}
+
+impl SkillPipeline {
+    /// 단계 목록으로 생성
+    pub fn new(steps: Vec<PipelineStep>) -> Self {
+        Self { steps }
+    }
+
+    /// 단계 추가
+    pub fn with_step(mut self, step: PipelineStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// 모든 단계 검증 (빈 파이프라인은 에러)
+    pub fn validate(&self) -> Result<()> {
+        if self.steps.is_empty() {
+            return Err(Error::InvalidInput("pipeline has no steps".into()));
+        }
+        for (index, step) in self.steps.iter().enumerate() {
+            if let Some(problem) = step.target_problem() {
+                return Err(Error::InvalidInput(format!(
+                    "step {} ({}) {}",
+                    index + 1,
+                    step.label(),
+                    problem
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// 단계에서 치환할 변수
+    ///
+    /// `input`은 검증 전의 파싱된 입력입니다. `ARGUMENTS`는 명령어 뒤의 전체 인자이고,
+    /// 선언된 인자는 플래그 값, 플래그로 주지 않았으면 선언 순서대로 위치 인자 값,
+    /// 그것도 없으면 기본값을 가집니다.
+    pub fn variables(definition: &SkillDefinition, input: &SkillInput) -> HashMap<String, String> {
+        let mut vars: HashMap<String, String> = input.arguments.clone();
+        let raw_args = input
+            .raw_command
+            .split_whitespace()
+            .skip(1)
+            .collect::<Vec<_>>()
+            .join(" ");
+        vars.insert("ARGUMENTS".to_string(), raw_args);
+
+        let mut positional = input.positional_args.iter();
+        for arg in &definition.arguments {
+            let value = match arg.value_in(input) {
+                Some(value) => Some(arg.validate(value).unwrap_or_else(|_| value.clone())),
+                None => positional.next().cloned().or_else(|| arg.default.clone()),
+            };
+            if let Some(value) = value {
+                vars.insert(arg.name.clone(), value);
+            }
+        }
+        vars
+    }
+}
+
+// ============================================================================
+// PipelineReport
+// ============================================================================
+
+/// 단계 실행 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepOutcome {
+    /// 단계 표시 이름
+    pub step: String,
+
+    /// 성공 여부
+    pub success: bool,
+
+    /// 출력 (실패 시 에러 메시지)
+    pub output: String,
+
+    /// 시도 횟수
+    pub attempts: u32,
+}
+
+/// 파이프라인 실행 결과
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PipelineReport {
+    /// 스킬 이름
+    pub skill: String,
+
+    /// 실행된 단계 결과 (순서대로)
+    pub steps: Vec<StepOutcome>,
+}
+
+impl PipelineReport {
+    /// 빈 결과 생성
+    pub fn new(skill: impl Into<String>) -> Self {
+        Self {
+            skill: skill.into(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// 모든 단계가 성공했는지
+    pub fn succeeded(&self) -> bool {
+        self.steps.iter().all(|step| step.success)
+    }
+
+    /// 실패한 단계 (`on-failure: continue`로 넘어간 단계)
+    pub fn failures(&self) -> impl Iterator<Item = &StepOutcome> {
+        self.steps.iter().filter(|step| !step.success)
+    }
+
+    /// 단계별 결과 요약 (마크다운)
+    pub fn summary(&self) -> String {
+        let mut out = format!(
+            "Ran {} step{} for /{}",
+            self.steps.len(),
+            if self.steps.len() == 1 { "" } else { "s" },
+            self.skill
+        );
+        let failed = self.failures().count();
+        if failed > 0 {
+            out.push_str(&format!(" ({} failed)", failed));
+        }
+        out.push_str(".\n");
+
+        for step in &self.steps {
+            let mark = if step.success { "✓" } else { "✗" };
+            let attempts = if step.attempts > 1 {
+                format!(" ({} attempts)", step.attempts)
+            } else {
+                String::new()
+            };
+            out.push_str(&format!("- {} {}{}", mark, step.step, attempts));
+            if !step.success {
+                if let Some(line) = step.output.lines().find(|line| !line.trim().is_empty()) {
+                    out.push_str(&format!(": {}", line.trim()));
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::SkillArgument;
+
+    #[test]
+    fn test_parse_steps() {
+        let yaml = r#"
+- name: test
+  run: cargo test
+- skill: /commit --all
+- run: git push
+  on-failure: retry
+  retries: 1
+- tool: bash
+  input: {command: "gh pr create --base ${base}"}
+  on-failure: continue
+"#;
+        let pipeline: SkillPipeline = serde_yaml::from_str(yaml).unwrap();
+        assert!(pipeline.validate().is_ok());
+        assert_eq!(pipeline.steps.len(), 4);
+        assert_eq!(pipeline.steps[0].label(), "test");
+        assert_eq!(pipeline.steps[1].label(), "/commit");
+        assert_eq!(pipeline.steps[1].max_attempts(), 1);
+        assert_eq!(pipeline.steps[2].max_attempts(), 2);
+        assert_eq!(pipeline.steps[3].on_failure, FailurePolicy::Continue);
+
+        let invalid =
+            SkillPipeline::new(vec![PipelineStep::for_command("ls"), PipelineStep::new()]);
+        let error = invalid.validate().unwrap_err().to_string();
+        assert!(error.contains("step 2 (step) needs one of skill, tool or run"));
+        assert!(SkillPipeline::default().validate().is_err());
+
+        let mut both = PipelineStep::for_skill("/commit");
+        both.run = Some("ls".into());
+        assert!(both.validate().is_err());
+    }
+
+    #[test]
+    fn test_step_action_substitution() {
+        let definition = SkillDefinition {
+            name: "ship".into(),
+            command: "/ship".into(),
+            description: String::new(),
+            usage: String::new(),
+            arguments: vec![
+                SkillArgument {
+                    name: "base".into(),
+                    default: Some("main".into()),
+                    ..Default::default()
+                },
+                SkillArgument {
+                    name: "title".into(),
+                    long_flag: Some("--title".into()),
+                    ..Default::default()
+                },
+            ],
+            category: "git".into(),
+            user_invocable: true,
+        };
+
+        let input = SkillInput::new("/ship develop --title Release")
+            .with_positional("develop")
+            .with_arg("title", "Release");
+        let vars = SkillPipeline::variables(&definition, &input);
+        assert_eq!(vars["base"], "develop");
+        assert_eq!(vars["ARGUMENTS"], "develop --title Release");
+
+        let step = PipelineStep::for_tool(
+            "bash",
+            json!({ "command": "gh pr create --base ${base} --title '${ title }'${missing}", "timeout": 1000 }),
+        );
+        assert_eq!(
+            step.action(&vars).unwrap(),
+            PipelineAction::Tool {
+                name: "bash".into(),
+                input: json!({ "command": "gh pr create --base develop --title 'Release'", "timeout": 1000 }),
+            }
+        );
+
+        let run = PipelineStep::for_command("echo $ARGUMENTS costs $5");
+        assert_eq!(
+            run.action(&vars).unwrap(),
+            PipelineAction::Tool {
+                name: RUN_TOOL.into(),
+                input: json!({ "command": "echo develop --title Release costs $5" }),
+            }
+        );
+
+        let defaults = SkillPipeline::variables(&definition, &SkillInput::new("/ship"));
+        let skill = PipelineStep::for_skill("/review-pr --base ${base}");
+        assert_eq!(
+            skill.action(&defaults).unwrap(),
+            PipelineAction::Skill("/review-pr --base main".into())
+        );
+    }
+
+    #[test]
+    fn test_report_summary() {
+        let mut report = PipelineReport::new("ship");
+        report.steps.push(StepOutcome {
+            step: "test".into(),
+            success: true,
+            output: "ok".into(),
+            attempts: 1,
+        });
+        report.steps.push(StepOutcome {
+            step: "push".into(),
+            success: false,
+            output: "\nrejected: non-fast-forward\nhint: pull first".into(),
+            attempts: 3,
+        });
+        assert!(!report.succeeded());
+
+        let summary = report.summary();
+        assert!(summary.starts_with("Ran 2 steps for /ship (1 failed)."));
+        assert!(summary.contains("- ✓ test\n"));
+        assert!(summary.contains("- ✗ push (3 attempts): rejected: non-fast-forward\n"));
+    }
+}
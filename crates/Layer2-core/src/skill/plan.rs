@@ -0,0 +1,455 @@
+//! Skill Dry-run Plan - 스킬 실행 계획
+//!
+//! `--dry-run`으로 호출된 스킬은 실제로 실행하지 않고, 수행하려는 작업
+//! (명령어, 파일 변경, 프롬프트)을 `SkillPlan`으로 기록합니다.
+//!
+//! - 읽기 전용 도구 호출(권한이 필요 없는 호출)은 그대로 실행되어 계획 수립에 사용됨
+//! - 권한이 필요한 호출은 `DryRunRecorder`에 기록만 되고 실행되지 않음
+//! - 기록된 계획은 체크리스트로 표시되며, 사용자가 승인하면 그대로 재생됨
+//!
+//! ```ignore
+//! let (command, dry_run) = split_dry_run("/commit --dry-run --all");
+//! let recorder = Arc::new(DryRunRecorder::new());
+//! core_ctx.set_dry_run(Some(recorder.clone()));
+//! // ... 에이전트 실행 ...
+//! let plan = SkillPlan::new("commit").with_steps(recorder.take());
+//! println!("{}", plan.render_checklist());
+//! ```
+
+use forge_foundation::{PermissionAction, PermissionSimulation, PermissionStatus};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Mutex;
+
+/// dry-run 플래그
+pub const DRY_RUN_FLAG: &str = "--dry-run";
+
+/// 원격 저장소를 건드리는 git 하위 명령어
+const GIT_REMOTE_SUBCOMMANDS: &[&str] = &["push", "pull", "fetch", "clone", "remote", "ls-remote"];
+
+/// 원격을 건드리는 gh 하위 명령어 (읽기 전용 `view`/`list`/`diff`/`status` 제외)
+const GH_READ_ONLY_ACTIONS: &[&str] = &["view", "list", "diff", "status", "checks"];
+
+/// 명령어 문자열에서 `--dry-run` 플래그 분리
+///
+/// 반환: (플래그를 제거한 명령어, dry-run 여부)
+pub fn split_dry_run(raw: &str) -> (String, bool) {
+    let mut dry_run = false;
+    let parts: Vec<&str> = raw
+        .split_whitespace()
+        .filter(|part| {
+            let matched = *part == DRY_RUN_FLAG;
+            dry_run |= matched;
+            !matched
+        })
+        .collect();
+    (parts.join(" "), dry_run)
+}
+
+/// 명령어가 git 원격(또는 GitHub)에 변경을 가하는지 확인
+pub fn touches_git_remote(command: &str) -> bool {
+    command
+        .split(['&', '|', ';', '\n'])
+        .map(str::split_whitespace)
+        .any(|mut words| match words.next() {
+            Some("git") => {
+                // 전역 옵션 건너뛰기 (`-C <path>`, `-c <key=value>`는 값을 가짐)
+                while let Some(word) = words.next() {
+                    if word == "-C" || word == "-c" {
+                        words.next();
+                    } else if !word.starts_with('-') {
+                        return GIT_REMOTE_SUBCOMMANDS.contains(&word);
+                    }
+                }
+                false
+            }
+            Some("gh") => {
+                let _group = words.next();
+                words
+                    .next()
+                    .is_some_and(|action| !GH_READ_ONLY_ACTIONS.contains(&action))
+            }
+            _ => false,
+        })
+}
+
+// ============================================================================
+// PlanStep
+// ============================================================================
+
+/// 계획 단계 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStepKind {
+    /// 에이전트에 전달할 프롬프트
+    Prompt,
+    /// 셸 명령어 실행
+    Command,
+    /// 파일 생성/수정
+    FileWrite,
+    /// 파일 삭제
+    FileDelete,
+    /// 네트워크 요청
+    Network,
+    /// 기타 도구 호출
+    Tool,
+}
+
+impl PlanStepKind {
+    /// 체크리스트 라벨
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlanStepKind::Prompt => "prompt",
+            PlanStepKind::Command => "run",
+            PlanStepKind::FileWrite => "write",
+            PlanStepKind::FileDelete => "delete",
+            PlanStepKind::Network => "network",
+            PlanStepKind::Tool => "tool",
+        }
+    }
+}
+
+/// 계획의 한 단계
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanStep {
+    /// 종류
+    pub kind: PlanStepKind,
+
+    /// 한 줄 요약 (명령어, 파일 경로 등)
+    pub summary: String,
+
+    /// 재생할 도구 이름 (프롬프트 단계는 None)
+    pub tool: Option<String>,
+
+    /// 재생할 도구 입력
+    pub input: Value,
+
+    /// git 원격 등 외부에 영향을 주는지 여부
+    pub remote: bool,
+}
+
+impl PlanStep {
+    /// 프롬프트 단계
+    pub fn prompt(text: impl Into<String>) -> Self {
+        Self {
+            kind: PlanStepKind::Prompt,
+            summary: text.into(),
+            tool: None,
+            input: Value::Null,
+            remote: false,
+        }
+    }
+
+    /// 도구 호출에서 단계 생성 (권한 정보로 종류 결정)
+    pub fn from_tool_call(tool: &str, input: &Value, action: &PermissionAction) -> Self {
+        let (kind, summary) = match action {
+            PermissionAction::Execute { command } => (PlanStepKind::Command, command.clone()),
+            PermissionAction::FileWrite { path } => (PlanStepKind::FileWrite, path.clone()),
+            PermissionAction::FileDelete { path } => (PlanStepKind::FileDelete, path.clone()),
+            PermissionAction::FileReadSensitive { path } => (PlanStepKind::Tool, path.clone()),
+            PermissionAction::Network { url } => (PlanStepKind::Network, url.clone()),
+            PermissionAction::Custom { name, details } => {
+                (PlanStepKind::Tool, format!("{}: {}", name, details))
+            }
+        };
+        let remote = kind == PlanStepKind::Command && touches_git_remote(&summary);
+
+        Self {
+            kind,
+            summary,
+            tool: Some(tool.to_string()),
+            input: input.clone(),
+            remote,
+        }
+    }
+
+    /// 재생 가능한 단계인지 (도구 호출)
+    pub fn is_executable(&self) -> bool {
+        self.tool.is_some()
+    }
+
+    /// 체크리스트 한 줄
+    pub fn checklist_line(&self) -> String {
+        let summary = match self.kind {
+            PlanStepKind::Prompt => first_line(&self.summary),
+            _ => format!("`{}`", first_line(&self.summary)),
+        };
+        let tool = match (&self.tool, self.kind) {
+            (Some(tool), PlanStepKind::Tool) => format!(" ({})", tool),
+            _ => String::new(),
+        };
+        let remote = if self.remote { " ⚠ remote" } else { "" };
+        format!(
+            "- [ ] **{}** {}{}{}",
+            self.kind.label(),
+            summary,
+            tool,
+            remote
+        )
+    }
+}
+
+/// 첫 줄만 (여러 줄이면 … 표시)
+fn first_line(text: &str) -> String {
+    let mut lines = text.trim().lines();
+    let first = lines.next().unwrap_or("").to_string();
+    if lines.next().is_some() {
+        format!("{} …", first)
+    } else {
+        first
+    }
+}
+
+// ============================================================================
+// SkillPlan
+// ============================================================================
+
+/// dry-run으로 수집한 스킬 실행 계획
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SkillPlan {
+    /// 스킬 이름
+    pub skill: String,
+
+    /// 단계들 (실행 순서)
+    pub steps: Vec<PlanStep>,
+}
+
+impl SkillPlan {
+    /// 빈 계획 생성
+    pub fn new(skill: impl Into<String>) -> Self {
+        Self {
+            skill: skill.into(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// 단계 추가
+    pub fn with_step(mut self, step: PlanStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// 여러 단계 추가
+    pub fn with_steps(mut self, steps: impl IntoIterator<Item = PlanStep>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    /// 재생할 도구 호출 단계들
+    pub fn executable_steps(&self) -> impl Iterator<Item = &PlanStep> {
+        self.steps.iter().filter(|step| step.is_executable())
+    }
+
+    /// 실행할 작업이 있는지
+    pub fn has_actions(&self) -> bool {
+        self.executable_steps().next().is_some()
+    }
+
+    /// git 원격 등 외부에 영향을 주는 단계가 있는지
+    pub fn touches_remote(&self) -> bool {
+        self.steps.iter().any(|step| step.remote)
+    }
+
+    /// 마크다운 체크리스트로 렌더링
+    pub fn render_checklist(&self) -> String {
+        let mut out = format!("📝 **Dry run: /{}**\n\n", self.skill);
+        for step in &self.steps {
+            out.push_str(&step.checklist_line());
+            out.push('\n');
+        }
+
+        let actions = self.executable_steps().count();
+        if actions == 0 {
+            out.push_str("\nNo changes would be made.\n");
+        } else {
+            out.push_str(&format!(
+                "\n{} action{} not executed.",
+                actions,
+                if actions == 1 { "" } else { "s" }
+            ));
+            if self.touches_remote() {
+                out.push_str(" ⚠ Includes steps that modify a git remote.");
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// 계획 실행에 필요한 승인 목록 (마크다운)
+///
+/// `AgentContext::simulate_plan` 결과에서 물어볼 권한과 거부된 권한만 보여줍니다.
+/// 둘 다 없으면 None.
+pub fn render_plan_approvals(simulations: &[PermissionSimulation]) -> Option<String> {
+    let mut lines = Vec::new();
+    for simulation in simulations {
+        for required in &simulation.required {
+            let mark = match required.status {
+                PermissionStatus::Unknown => "🔐",
+                PermissionStatus::Denied => "⛔ denied:",
+                PermissionStatus::Granted | PermissionStatus::AutoApproved => continue,
+            };
+            let line = format!(
+                "- {} {} ({})",
+                mark,
+                required.action.description(),
+                simulation.tool
+            );
+            if !lines.contains(&line) {
+                lines.push(line);
+            }
+        }
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    Some(format!("**Approvals needed**\n\n{}\n", lines.join("\n")))
+}
+
+// ============================================================================
+// DryRunRecorder
+// ============================================================================
+
+/// dry-run 중 실행되지 않은 도구 호출 기록
+#[derive(Debug, Default)]
+pub struct DryRunRecorder {
+    steps: Mutex<Vec<PlanStep>>,
+}
+
+impl DryRunRecorder {
+    /// 새 레코더 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 도구 호출 기록 후 기록된 단계 반환
+    pub fn record(&self, tool: &str, input: &Value, action: &PermissionAction) -> PlanStep {
+        let step = PlanStep::from_tool_call(tool, input, action);
+        self.steps
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(step.clone());
+        step
+    }
+
+    /// 기록된 단계 복사본
+    pub fn steps(&self) -> Vec<PlanStep> {
+        self.steps.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// 기록된 단계를 꺼내고 비움
+    pub fn take(&self) -> Vec<PlanStep> {
+        std::mem::take(&mut *self.steps.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_split_dry_run() {
+        assert_eq!(
+            split_dry_run("/commit --dry-run --all"),
+            ("/commit --all".to_string(), true)
+        );
+        assert_eq!(
+            split_dry_run("/review-pr 42"),
+            ("/review-pr 42".to_string(), false)
+        );
+    }
+
+    #[test]
+    fn test_touches_git_remote() {
+        assert!(touches_git_remote("git push origin main"));
+        assert!(touches_git_remote("git add -A && git commit -m x && git push"));
+        assert!(touches_git_remote("git -C repo fetch --all"));
+        assert!(touches_git_remote("gh pr create --fill"));
+        assert!(!touches_git_remote("gh pr view 42"));
+        assert!(!touches_git_remote("git commit -m 'push the button'"));
+        assert!(!touches_git_remote("cargo test"));
+    }
+
+    #[test]
+    fn test_recorder_and_checklist() {
+        let recorder = DryRunRecorder::new();
+        recorder.record(
+            "bash",
+            &json!({ "command": "git commit -m 'fix'" }),
+            &PermissionAction::Execute {
+                command: "git commit -m 'fix'".into(),
+            },
+        );
+        let push = recorder.record(
+            "bash",
+            &json!({ "command": "git push" }),
+            &PermissionAction::Execute {
+                command: "git push".into(),
+            },
+        );
+        assert!(push.remote);
+        recorder.record(
+            "write",
+            &json!({ "path": "CHANGELOG.md", "content": "..." }),
+            &PermissionAction::FileWrite {
+                path: "CHANGELOG.md".into(),
+            },
+        );
+
+        let plan = SkillPlan::new("commit")
+            .with_step(PlanStep::prompt("Create a commit\nwith details"))
+            .with_steps(recorder.take());
+        assert!(recorder.steps().is_empty());
+        assert_eq!(plan.executable_steps().count(), 3);
+        assert!(plan.touches_remote());
+
+        let checklist = plan.render_checklist();
+        assert!(checklist.contains("- [ ] **prompt** Create a commit …"));
+        assert!(checklist.contains("- [ ] **run** `git push` ⚠ remote"));
+        assert!(checklist.contains("- [ ] **write** `CHANGELOG.md`"));
+        assert!(checklist.contains("3 actions not executed."));
+    }
+
+    #[test]
+    fn test_render_plan_approvals() {
+        use forge_foundation::RequiredPermission;
+
+        let required = |action, status| RequiredPermission { action, status };
+        let simulations = vec![
+            PermissionSimulation::new(
+                "bash",
+                vec![required(
+                    PermissionAction::Execute {
+                        command: "git push".into(),
+                    },
+                    PermissionStatus::Unknown,
+                )],
+            ),
+            PermissionSimulation::new(
+                "write",
+                vec![
+                    required(
+                        PermissionAction::FileWrite {
+                            path: "CHANGELOG.md".into(),
+                        },
+                        PermissionStatus::Granted,
+                    ),
+                    required(
+                        PermissionAction::FileDelete {
+                            path: "old.md".into(),
+                        },
+                        PermissionStatus::Denied,
+                    ),
+                ],
+            ),
+        ];
+
+        let approvals = render_plan_approvals(&simulations).unwrap();
+        assert!(approvals.contains("- 🔐 Execute: git push (bash)"));
+        assert!(approvals.contains("- ⛔ denied: Delete file: old.md (write)"));
+        assert!(!approvals.contains("CHANGELOG.md"));
+
+        assert!(render_plan_approvals(&simulations[1..1]).is_none());
+    }
+}
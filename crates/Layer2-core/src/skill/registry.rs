@@ -6,6 +6,7 @@ use std::sync::Arc;
 use tracing::{debug, info};
 
 /// 스킬 레지스트리 - 모든 스킬을 관리
+#[derive(Clone)]
 pub struct SkillRegistry {
     /// 명령어로 인덱싱된 스킬 (예: "/commit" -> CommitSkill)
     skills_by_command: HashMap<String, Arc<dyn Skill>>,
@@ -41,6 +42,11 @@ impl SkillRegistry {
         self.register(Arc::new(CommitSkill::new()));
         self.register(Arc::new(ReviewPrSkill::new()));
         self.register(Arc::new(ExplainSkill::new()));
+        self.register(Arc::new(TodosSkill::new()));
+        self.register(Arc::new(ResolveConflictsSkill::new()));
+        self.register(Arc::new(TestSkill::new()));
+        self.register(Arc::new(FixSkill::new()));
+        self.register(Arc::new(DocsSkill::new()));
 
         info!("Registered {} built-in skills", self.skills_by_name.len());
     }
@@ -82,6 +88,15 @@ impl SkillRegistry {
         }
     }
 
+    /// 카테고리의 스킬을 모두 등록 해제 (해제된 수 반환)
+    pub fn unregister_category(&mut self, category: &str) -> usize {
+        let names = self.skills_by_category.remove(category).unwrap_or_default();
+        names
+            .iter()
+            .filter(|name| self.unregister(name).is_some())
+            .count()
+    }
+
     /// 명령어로 스킬 조회 (예: "/commit")
     pub fn get_by_command(&self, command: &str) -> Option<Arc<dyn Skill>> {
         // 명령어 정규화 (앞에 /가 없으면 추가)
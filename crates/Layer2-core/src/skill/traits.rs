@@ -1,7 +1,9 @@
 //! Skill traits and core types
 
+use super::pipeline::SkillPipeline;
+use crate::forgecmd::RulePattern;
 use async_trait::async_trait;
-use forge_foundation::{Result, ToolContext};
+use forge_foundation::{Error, Result, ToolContext};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -36,17 +38,27 @@ pub struct SkillDefinition {
 }
 
 /// 스킬 인자 정의
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SkillArgument {
     /// 인자 이름
     pub name: String,
 
     /// 설명
+    #[serde(default)]
     pub description: String,
 
     /// 필수 여부
+    #[serde(default)]
     pub required: bool,
 
+    /// 값 타입
+    #[serde(default, rename = "type")]
+    pub kind: SkillArgumentType,
+
+    /// 허용 값 목록 (비어 있으면 제한 없음)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<String>,
+
     /// 기본값
     pub default: Option<String>,
 
@@ -57,6 +69,96 @@ pub struct SkillArgument {
     pub long_flag: Option<String>,
 }
 
+/// 스킬 인자 값 타입
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillArgumentType {
+    /// 문자열 (기본)
+    #[default]
+    String,
+    /// 정수
+    Integer,
+    /// 실수
+    Number,
+    /// 불리언 플래그 (`--all`, `--all false`)
+    Boolean,
+}
+
+impl SkillArgument {
+    /// 입력에서 이 인자를 찾을 때 쓰는 키 (이름, 긴 플래그, 짧은 플래그)
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(
+            [&self.long_flag, &self.short_flag]
+                .into_iter()
+                .flatten()
+                .map(|flag| flag.trim_start_matches('-')),
+        )
+    }
+
+    /// 입력에 주어진 값 (플래그 별칭 포함)
+    pub fn value_in<'i>(&self, input: &'i SkillInput) -> Option<&'i String> {
+        self.keys().find_map(|key| input.arguments.get(key))
+    }
+
+    /// 값 검증 후 정규화된 값 반환
+    ///
+    /// 선택지는 대소문자를 구분하지 않고 선언된 표기로, 불리언은 `true`/`false`로 맞춥니다.
+    pub fn validate(&self, value: &str) -> std::result::Result<String, String> {
+        let value = value.trim();
+        let value = match self.kind {
+            SkillArgumentType::String => value.to_string(),
+            SkillArgumentType::Integer => value
+                .parse::<i64>()
+                .map(|n| n.to_string())
+                .map_err(|_| format!("expected an integer, got '{}'", value))?,
+            SkillArgumentType::Number => value
+                .parse::<f64>()
+                .map(|_| value.to_string())
+                .map_err(|_| format!("expected a number, got '{}'", value))?,
+            SkillArgumentType::Boolean => match value.to_ascii_lowercase().as_str() {
+                "true" | "yes" | "y" | "on" | "1" => "true".to_string(),
+                "false" | "no" | "n" | "off" | "0" => "false".to_string(),
+                _ => return Err(format!("expected true or false, got '{}'", value)),
+            },
+        };
+
+        if self.choices.is_empty() {
+            return Ok(value);
+        }
+        self.choices
+            .iter()
+            .find(|choice| choice.eq_ignore_ascii_case(&value))
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "expected one of {}, got '{}'",
+                    self.choices.join(", "),
+                    value
+                )
+            })
+    }
+
+    /// 입력 안내 문구 (TUI 프롬프트용, 예: "one of brief, normal, detailed; default normal")
+    pub fn hint(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if !self.choices.is_empty() {
+            parts.push(format!("one of {}", self.choices.join(", ")));
+        } else {
+            match self.kind {
+                SkillArgumentType::String => {}
+                SkillArgumentType::Integer => parts.push("integer".to_string()),
+                SkillArgumentType::Number => parts.push("number".to_string()),
+                SkillArgumentType::Boolean => parts.push("true or false".to_string()),
+            }
+        }
+        if let Some(ref default) = self.default {
+            parts.push(format!("default {}", default));
+        }
+
+        (!parts.is_empty()).then(|| parts.join("; "))
+    }
+}
+
 // ============================================================================
 // SkillMetadata - 런타임 메타데이터
 // ============================================================================
@@ -163,9 +265,11 @@ impl SkillInput {
         self.arguments.get(key).cloned().unwrap_or_else(|| default.to_string())
     }
 
-    /// 플래그 존재 여부
+    /// 플래그 존재 여부 (`--flag false`로 명시하면 꺼짐)
     pub fn has_flag(&self, flag: &str) -> bool {
-        self.arguments.contains_key(flag)
+        self.arguments
+            .get(flag)
+            .is_some_and(|value| !value.eq_ignore_ascii_case("false"))
     }
 }
 
@@ -403,10 +507,127 @@ pub trait Skill: Send + Sync {
         input
     }
 
+    /// 입력에 빠진 필수 인자
+    ///
+    /// 플래그로 주지 않은 인자는 선언 순서대로 위치 인자가 채운 것으로 봅니다.
+    /// TUI는 빠진 인자를 사용자에게 하나씩 물어봅니다.
+    fn missing_arguments(&self, input: &SkillInput) -> Vec<SkillArgument> {
+        let mut positional = input.positional_args.len();
+        self.definition()
+            .arguments
+            .into_iter()
+            .filter(|arg| {
+                if arg.value_in(input).is_some() {
+                    return false;
+                }
+                if positional > 0 {
+                    positional -= 1;
+                    return false;
+                }
+                arg.required && arg.default.is_none()
+            })
+            .collect()
+    }
+
+    /// 인자 스키마로 입력 검증 (스킬 실행 전에 호출)
+    ///
+    /// 플래그로 준 값은 타입/선택지를 검증한 뒤 인자 이름으로도 채우고,
+    /// 주지 않은 인자는 기본값으로 채웁니다. 위치 인자는 필수 인자 확인에만 쓰입니다.
+    fn validate_input(&self, mut input: SkillInput) -> Result<SkillInput> {
+        let definition = self.definition();
+        let mut errors: Vec<String> = self
+            .missing_arguments(&input)
+            .iter()
+            .map(|arg| format!("missing required argument '{}'", arg.name))
+            .collect();
+
+        for arg in &definition.arguments {
+            let Some(value) = arg.value_in(&input).cloned() else {
+                if let Some(ref default) = arg.default {
+                    input.arguments.insert(arg.name.clone(), default.clone());
+                }
+                continue;
+            };
+
+            match arg.validate(&value) {
+                Ok(value) => {
+                    input.arguments.insert(arg.name.clone(), value);
+                }
+                Err(_) if arg.kind == SkillArgumentType::Boolean => {
+                    // `--all file.rs`: 기본 파서가 다음 토큰을 플래그 값으로 잡은 경우
+                    for key in arg.keys() {
+                        if let Some(value) = input.arguments.get_mut(key) {
+                            *value = "true".to_string();
+                        }
+                    }
+                    input.arguments.insert(arg.name.clone(), "true".to_string());
+                    input.positional_args.push(value);
+                }
+                Err(e) => errors.push(format!("'{}': {}", arg.name, e)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(input)
+        } else {
+            Err(Error::InvalidInput(format!(
+                "/{}: {}",
+                definition.name,
+                errors.join("; ")
+            )))
+        }
+    }
+
+    /// 선언된 실행 단계 (있으면 프롬프트 대신 단계를 순서대로 실행)
+    fn pipeline(&self) -> Option<SkillPipeline> {
+        None
+    }
+
     /// 에이전트 루프가 필요한지 여부
     fn requires_agent_loop(&self) -> bool {
         false
     }
+
+    /// LLM 응답 최대 토큰 (None이면 에이전트 기본값)
+    fn max_tokens(&self) -> Option<u32> {
+        None
+    }
+
+    /// 커스텀 stop sequence (구조화된 출력을 구분자에서 정확히 끊을 때)
+    fn stop_sequences(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// 에이전트 반복 횟수 상한 (None이면 에이전트 기본값)
+    ///
+    /// 입력에 따라 예산이 달라지는 스킬(`/fix --attempts 3`)이 사용합니다.
+    fn max_iterations(&self, _input: &SkillInput) -> Option<usize> {
+        None
+    }
+
+    /// 허용 도구 (None이면 제한 없음)
+    ///
+    /// 지정되면 이 도구만 보는 서브에이전트 컨텍스트에서 실행됩니다.
+    fn allowed_tools(&self) -> Option<Vec<String>> {
+        None
+    }
+
+    /// 허용 도구의 인자 제한 (`Bash(git log:*)`, `Read(src/**)`)
+    ///
+    /// 같은 도구가 제한 없이도 나열되면 (`Bash`, `Bash(*)`) 그 도구의 제한은 빠집니다.
+    fn allowed_tool_rules(&self) -> Vec<RulePattern> {
+        Vec::new()
+    }
+
+    /// 모델 오버라이드 (`haiku`/`sonnet`/`opus`/`inherit` 또는 모델 ID)
+    fn model(&self) -> Option<String> {
+        None
+    }
+
+    /// 대화 기록 없이 분리된 서브에이전트에서 실행할지 (`context: fork`)
+    fn forks_context(&self) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
@@ -445,6 +666,88 @@ mod tests {
         assert!(input.arguments.contains_key("verbose"));
     }
 
+    #[test]
+    fn test_argument_schema_validation() {
+        struct TypedSkill;
+
+        #[async_trait]
+        impl Skill for TypedSkill {
+            fn definition(&self) -> SkillDefinition {
+                SkillDefinition {
+                    name: "deploy".into(),
+                    command: "/deploy".into(),
+                    description: "Deploy".into(),
+                    usage: "/deploy <env>".into(),
+                    arguments: vec![
+                        SkillArgument {
+                            name: "env".into(),
+                            required: true,
+                            choices: vec!["dev".into(), "prod".into()],
+                            long_flag: Some("--env".into()),
+                            ..Default::default()
+                        },
+                        SkillArgument {
+                            name: "replicas".into(),
+                            kind: SkillArgumentType::Integer,
+                            default: Some("1".into()),
+                            short_flag: Some("-r".into()),
+                            ..Default::default()
+                        },
+                        SkillArgument {
+                            name: "force".into(),
+                            kind: SkillArgumentType::Boolean,
+                            default: Some("false".into()),
+                            ..Default::default()
+                        },
+                    ],
+                    category: "test".into(),
+                    user_invocable: true,
+                }
+            }
+
+            async fn execute(&self, _ctx: &SkillContext<'_>, _input: SkillInput) -> Result<SkillOutput> {
+                Ok(SkillOutput::success("done"))
+            }
+        }
+
+        let skill = TypedSkill;
+
+        let missing = skill
+            .validate_input(skill.parse_input("/deploy"))
+            .unwrap_err()
+            .to_string();
+        assert!(missing.contains("missing required argument 'env'"));
+
+        // 선택지 정규화, 짧은 플래그 별칭, 기본값
+        let input = skill
+            .validate_input(skill.parse_input("/deploy --env PROD -r 3"))
+            .unwrap();
+        assert_eq!(input.get("env").unwrap(), "prod");
+        assert_eq!(input.get("replicas").unwrap(), "3");
+        assert!(!input.has_flag("force"));
+
+        let invalid = skill
+            .validate_input(skill.parse_input("/deploy --env staging -r many"))
+            .unwrap_err()
+            .to_string();
+        assert!(invalid.contains("expected one of dev, prod"));
+        assert!(invalid.contains("expected an integer"));
+
+        // 불리언 플래그가 잡은 다음 토큰은 위치 인자로 복원
+        let input = skill
+            .validate_input(skill.parse_input("/deploy --env dev --force notes.md"))
+            .unwrap();
+        assert!(input.has_flag("force"));
+        assert_eq!(input.positional_args, vec!["notes.md".to_string()]);
+
+        let env = &skill.definition().arguments[0];
+        assert_eq!(env.hint().as_deref(), Some("one of dev, prod"));
+        assert_eq!(
+            skill.definition().arguments[1].hint().as_deref(),
+            Some("integer; default 1")
+        );
+    }
+
     #[test]
     fn test_skill_output() {
         let output = SkillOutput::success("Commit created")
@@ -0,0 +1,391 @@
+//! Subagent Loader - 파일 기반 서브에이전트 정의 로딩
+//!
+//! Claude Code 스타일의 서브에이전트 정의 파일(`agents/*.md`)을 파싱합니다.
+//! 로드된 정의는 Layer3에서 `SubagentDefinition`으로 변환되어
+//! native agent(`subagent` 도구)와 agent provider(`AgentQueryOptions::subagents`) 양쪽에 등록됩니다.
+//!
+//! ## 호환성
+//! - **Claude Code**: `.claude/agents/`
+//! - **ForgeCode**: `.forgecode/agents/`
+//!
+//! ## 파일 형식
+//! ```markdown
+//! ---
+//! name: code-reviewer            # 생략 시 파일 이름
+//! description: 코드 변경 리뷰     # 디스패치할 때 모델이 보는 설명
+//! tools: Read, Grep, Glob        # 쉼표 구분 또는 목록 (생략 시 기본 도구)
+//! model: sonnet                  # sonnet | opus | haiku | inherit
+//! ---
+//!
+//! You are a senior code reviewer...  (본문 = 시스템 프롬프트)
+//! ```
+//!
+//! 도구 이름은 ForgeCode 이름으로 정규화됩니다 (`WebFetch` → `web_fetch`,
+//! `mcp__github__create_issue` → `mcp_github_create_issue`). `Bash(git add:*)`처럼
+//! 인자 패턴이 붙은 항목은 도구 단위(`bash`)로 허용됩니다.
+
+use crate::skill::split_frontmatter;
+use forge_foundation::{Error, Result};
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+// ============================================================================
+// SubagentFileConfig - YAML frontmatter
+// ============================================================================
+
+/// 서브에이전트 정의 파일의 YAML frontmatter
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SubagentFileConfig {
+    /// 서브에이전트 이름 (디스패치 키)
+    #[serde(default)]
+    pub name: String,
+
+    /// 설명 (언제 사용할지)
+    #[serde(default)]
+    pub description: String,
+
+    /// 허용 도구 (None이면 에이전트 타입 기본값)
+    #[serde(default, deserialize_with = "deserialize_tools")]
+    pub tools: Option<Vec<String>>,
+
+    /// 모델 (sonnet, opus, haiku, inherit 또는 모델 ID)
+    pub model: Option<String>,
+}
+
+/// `tools: Read, Grep` 와 `tools: [Read, Grep]` 모두 허용 (ForgeCode 도구 이름으로 정규화)
+fn deserialize_tools<'de, D>(deserializer: D) -> std::result::Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(deserialize_tool_patterns(deserializer)?
+        .map(|tools| dedup(tools.iter().map(|tool| normalize_tool_name(tool)))))
+}
+
+/// 도구 목록을 쓰여진 그대로 읽음 (스킬의 `allowed-tools`, `Bash(git log:*)` 유지)
+pub(crate) fn deserialize_tool_patterns<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Tools {
+        Csv(String),
+        List(Vec<String>),
+    }
+
+    let tools = match Option::<Tools>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(Tools::Csv(csv)) => csv.split(',').map(str::to_string).collect(),
+        Some(Tools::List(list)) => list,
+    };
+    Ok(Some(dedup(
+        tools
+            .iter()
+            .map(|t| t.trim())
+            .filter(|t| !t.is_empty())
+            .map(str::to_string),
+    )))
+}
+
+/// 순서를 유지하며 중복 제거
+pub(crate) fn dedup(tools: impl Iterator<Item = String>) -> Vec<String> {
+    tools.fold(Vec::new(), |mut tools, tool| {
+        if !tools.contains(&tool) {
+            tools.push(tool);
+        }
+        tools
+    })
+}
+
+/// Claude Code 도구 이름을 ForgeCode 이름으로 변환
+pub(crate) fn normalize_tool_name(name: &str) -> String {
+    // `Bash(git add:*)` → `Bash` (인자 패턴은 도구 정책으로 표현할 수 없음)
+    let name = name.split_once('(').map_or(name, |(tool, _)| tool).trim();
+    if let Some(rest) = name.strip_prefix("mcp__") {
+        return format!("mcp_{}", rest.replace("__", "_"));
+    }
+
+    let mut normalized = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 && !normalized.ends_with('_') {
+                normalized.push('_');
+            }
+            normalized.push(c.to_ascii_lowercase());
+        } else {
+            normalized.push(c);
+        }
+    }
+    normalized
+}
+
+// ============================================================================
+// FileBasedSubagent
+// ============================================================================
+
+/// 파일에서 로드된 서브에이전트 정의
+#[derive(Debug, Clone)]
+pub struct FileBasedSubagent {
+    /// frontmatter 설정
+    config: SubagentFileConfig,
+
+    /// 시스템 프롬프트 (본문)
+    prompt: String,
+
+    /// 원본 파일 경로
+    source_path: PathBuf,
+}
+
+impl FileBasedSubagent {
+    /// 파일에서 로드
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content, path.to_path_buf())
+    }
+
+    /// 내용 파싱 (이름이 없으면 파일 이름 사용)
+    pub fn parse(content: &str, source_path: PathBuf) -> Result<Self> {
+        let (mut config, prompt): (SubagentFileConfig, String) = split_frontmatter(content)?;
+
+        if config.name.trim().is_empty() {
+            config.name = source_path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+        }
+        config.name = config.name.trim().to_string();
+        if config.name.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "Subagent definition has no name: {}",
+                source_path.display()
+            )));
+        }
+        if prompt.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "Subagent '{}' has no system prompt",
+                config.name
+            )));
+        }
+
+        Ok(Self {
+            config,
+            prompt,
+            source_path,
+        })
+    }
+
+    /// frontmatter 설정
+    pub fn config(&self) -> &SubagentFileConfig {
+        &self.config
+    }
+
+    /// 이름
+    pub fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    /// 설명
+    pub fn description(&self) -> &str {
+        &self.config.description
+    }
+
+    /// 시스템 프롬프트
+    pub fn prompt(&self) -> &str {
+        &self.prompt
+    }
+
+    /// 허용 도구 (ForgeCode 이름, None이면 기본값)
+    pub fn tools(&self) -> Option<&[String]> {
+        self.config.tools.as_deref()
+    }
+
+    /// 모델
+    pub fn model(&self) -> Option<&str> {
+        self.config.model.as_deref()
+    }
+
+    /// 원본 파일 경로
+    pub fn source_path(&self) -> &Path {
+        &self.source_path
+    }
+}
+
+// ============================================================================
+// SubagentLoader
+// ============================================================================
+
+/// 파일 시스템에서 서브에이전트 정의를 검색하고 로드
+pub struct SubagentLoader {
+    /// 검색 경로
+    search_paths: Vec<PathBuf>,
+}
+
+impl SubagentLoader {
+    /// 새 로더 생성 (기본 검색 경로)
+    ///
+    /// ## 검색 경로 (우선순위 역순, 나중이 높음)
+    /// 1. `~/.claude/agents/` - Claude Code 사용자 레벨
+    /// 2. `~/.forgecode/agents/` - ForgeCode 사용자 레벨
+    /// 3. `.claude/agents/` - Claude Code 프로젝트 레벨
+    /// 4. `.forgecode/agents/` - ForgeCode 프로젝트 레벨
+    pub fn new(working_dir: &Path) -> Self {
+        let mut paths = Vec::new();
+
+        if let Some(home) = dirs::home_dir() {
+            paths.push(home.join(".claude/agents"));
+            paths.push(home.join(".forgecode/agents"));
+        }
+
+        paths.push(working_dir.join(".claude/agents"));
+        paths.push(working_dir.join(".forgecode/agents"));
+
+        Self {
+            search_paths: paths,
+        }
+    }
+
+    /// 커스텀 검색 경로로 생성
+    pub fn with_paths(paths: Vec<PathBuf>) -> Self {
+        Self {
+            search_paths: paths,
+        }
+    }
+
+    /// 검색 경로 추가
+    pub fn add_path(&mut self, path: PathBuf) {
+        self.search_paths.push(path);
+    }
+
+    /// 검색 경로
+    pub fn search_paths(&self) -> &[PathBuf] {
+        &self.search_paths
+    }
+
+    /// 모든 서브에이전트 로드 (같은 이름은 나중 경로가 우선, 이름순 정렬)
+    pub fn load_all(&self) -> Vec<FileBasedSubagent> {
+        let mut agents: HashMap<String, FileBasedSubagent> = HashMap::new();
+
+        for search_path in &self.search_paths {
+            let Ok(entries) = std::fs::read_dir(search_path) else {
+                continue;
+            };
+            debug!("Searching for subagents in: {}", search_path.display());
+
+            let mut files: Vec<PathBuf> = entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "md"))
+                .collect();
+            files.sort();
+
+            for file in files {
+                match FileBasedSubagent::from_file(&file) {
+                    Ok(agent) => {
+                        info!("Loaded subagent '{}' from {}", agent.name(), file.display());
+                        agents.insert(agent.name().to_string(), agent);
+                    }
+                    Err(e) => {
+                        warn!("Failed to load subagent from {}: {}", file.display(), e);
+                    }
+                }
+            }
+        }
+
+        let mut agents: Vec<FileBasedSubagent> = agents.into_values().collect();
+        agents.sort_by(|a, b| a.name().cmp(b.name()));
+        agents
+    }
+
+    /// 특정 이름의 서브에이전트 로드
+    pub fn load_by_name(&self, name: &str) -> Option<FileBasedSubagent> {
+        self.load_all().into_iter().find(|a| a.name() == name)
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_AGENT: &str = r#"---
+name: code-reviewer
+description: Reviews code changes for quality
+tools: Read, Grep, Glob, WebFetch, mcp__github__get_pull_request
+model: sonnet
+---
+
+You are a senior code reviewer.
+"#;
+
+    #[test]
+    fn test_parse_agent() {
+        let agent = FileBasedSubagent::parse(SAMPLE_AGENT, PathBuf::from("reviewer.md")).unwrap();
+
+        assert_eq!(agent.name(), "code-reviewer");
+        assert_eq!(agent.description(), "Reviews code changes for quality");
+        assert_eq!(agent.prompt(), "You are a senior code reviewer.");
+        assert_eq!(agent.model(), Some("sonnet"));
+        assert_eq!(
+            agent.tools().unwrap(),
+            [
+                "read",
+                "grep",
+                "glob",
+                "web_fetch",
+                "mcp_github_get_pull_request"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_name_defaults_to_file_stem() {
+        let content = "---\ntools: [Bash]\n---\nRun the build.";
+        let agent = FileBasedSubagent::parse(content, PathBuf::from("agents/builder.md")).unwrap();
+
+        assert_eq!(agent.name(), "builder");
+        assert_eq!(agent.tools().unwrap(), ["bash"]);
+
+        // 인자 패턴은 도구 단위로 허용
+        let content = "---\ntools: Bash(git add:*), Bash(git status:*), Read\n---\nCommit.";
+        let agent = FileBasedSubagent::parse(content, PathBuf::from("agents/git.md")).unwrap();
+        assert_eq!(agent.tools().unwrap(), ["bash", "read"]);
+        assert!(agent.model().is_none());
+
+        let empty = "---\nname: empty\n---\n";
+        assert!(FileBasedSubagent::parse(empty, PathBuf::from("empty.md")).is_err());
+    }
+
+    #[test]
+    fn test_loader_priority() {
+        let temp = tempfile::tempdir().unwrap();
+        let user = temp.path().join("user");
+        let project = temp.path().join("project");
+        std::fs::create_dir_all(&user).unwrap();
+        std::fs::create_dir_all(&project).unwrap();
+
+        std::fs::write(
+            user.join("reviewer.md"),
+            "---\nname: reviewer\n---\nUser prompt",
+        )
+        .unwrap();
+        std::fs::write(user.join("writer.md"), "Write docs.").unwrap();
+        std::fs::write(
+            project.join("reviewer.md"),
+            "---\nname: reviewer\n---\nProject prompt",
+        )
+        .unwrap();
+        std::fs::write(project.join("notes.txt"), "not an agent").unwrap();
+
+        let agents = SubagentLoader::with_paths(vec![user, project]).load_all();
+        let names: Vec<&str> = agents.iter().map(|a| a.name()).collect();
+        assert_eq!(names, ["reviewer", "writer"]);
+        assert_eq!(agents[0].prompt(), "Project prompt");
+    }
+}
@@ -0,0 +1,910 @@
+//! Archive Formats - ZIP/TAR 읽기 및 생성
+//!
+//! `archive` 도구와 DOCX 추출이 사용하는 경량 구현입니다. 외부 프로그램 없이 동작합니다.
+//! - ZIP: stored/deflate, central directory 기반 (ZIP64, 암호화 엔트리 미지원)
+//! - TAR: ustar + GNU 긴 이름(`L`) + pax `path`/`linkpath`/`size`
+//! - TAR.GZ: gzip으로 감싼 TAR (`.tar.gz`, `.tgz`)
+//!
+//! 엔트리 이름은 아카이브에 적힌 그대로 반환합니다.
+//! 디스크에 쓰기 전에 `safe_entry_path`로 zip-slip 여부를 확인해야 합니다.
+
+use flate2::read::{DeflateDecoder, GzDecoder};
+use flate2::write::{DeflateEncoder, GzEncoder};
+use flate2::Compression;
+use forge_foundation::{Error, Result};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// TAR.GZ 목록 조회 시 최대 해제 크기
+const MAX_LIST_UNPACKED_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// ZIP 심볼릭 링크 대상 최대 크기
+const MAX_LINK_TARGET_BYTES: u64 = 4096;
+
+/// TAR 블록 크기
+const TAR_BLOCK: usize = 512;
+
+/// ZIP 시그니처
+const ZIP_LOCAL_HEADER: [u8; 4] = [0x50, 0x4b, 0x03, 0x04];
+const ZIP_CENTRAL_HEADER: [u8; 4] = [0x50, 0x4b, 0x01, 0x02];
+const ZIP_END_OF_CENTRAL: [u8; 4] = [0x50, 0x4b, 0x05, 0x06];
+
+/// DOS 날짜 1980-01-01 (엔트리 시간은 기록하지 않음)
+const ZIP_DOS_DATE: u16 = (1 << 5) | 1;
+
+/// 아카이브 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// 확장자로 형식 판별
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(Self::Tar)
+        } else if [".zip", ".jar", ".war", ".whl", ".nupkg", ".vsix"]
+            .iter()
+            .any(|ext| name.ends_with(ext))
+        {
+            Some(Self::Zip)
+        } else {
+            None
+        }
+    }
+
+    /// 내용으로 형식 판별 (매직 바이트)
+    pub fn sniff(data: &[u8]) -> Option<Self> {
+        if data.starts_with(&ZIP_LOCAL_HEADER) || data.starts_with(&ZIP_END_OF_CENTRAL) {
+            Some(Self::Zip)
+        } else if data.starts_with(&[0x1f, 0x8b]) {
+            Some(Self::TarGz)
+        } else if data.get(257..262) == Some(b"ustar") {
+            Some(Self::Tar)
+        } else {
+            None
+        }
+    }
+
+    /// 이름으로 형식 지정 ("zip", "tar", "tar.gz", "tgz")
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().trim_start_matches('.').to_lowercase().as_str() {
+            "zip" => Some(Self::Zip),
+            "tar" => Some(Self::Tar),
+            "tar.gz" | "tgz" | "gz" => Some(Self::TarGz),
+            _ => None,
+        }
+    }
+
+    /// 표시 이름
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Zip => "zip",
+            Self::Tar => "tar",
+            Self::TarGz => "tar.gz",
+        }
+    }
+}
+
+/// 엔트리 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    File,
+    Directory,
+    Symlink,
+    /// 하드 링크, 장치 파일 등 (추출하지 않음)
+    Other,
+}
+
+/// 아카이브 엔트리
+#[derive(Debug, Clone)]
+pub struct ArchiveEntry {
+    /// 아카이브 내 경로 (디렉토리는 '/'로 끝날 수 있음)
+    pub name: String,
+    /// 종류
+    pub kind: EntryKind,
+    /// 원본 크기
+    pub size: u64,
+    /// 압축된 크기 (ZIP만)
+    pub compressed_size: Option<u64>,
+    /// 유닉스 권한 비트 (기록된 경우)
+    pub mode: Option<u32>,
+    /// 링크 대상 (심볼릭/하드 링크)
+    pub link_target: Option<String>,
+}
+
+/// 엔트리 목록 조회 (내용은 해제하지 않음)
+pub fn list_entries(data: &[u8], format: ArchiveFormat) -> Result<Vec<ArchiveEntry>> {
+    match format {
+        ArchiveFormat::Zip => Ok(zip_records(data)?
+            .into_iter()
+            .map(|record| record.entry)
+            .collect()),
+        ArchiveFormat::Tar => Ok(tar_records(data)?
+            .into_iter()
+            .map(|(entry, _)| entry)
+            .collect()),
+        ArchiveFormat::TarGz => {
+            let tar = gunzip(data, MAX_LIST_UNPACKED_BYTES)?;
+            list_entries(&tar, ArchiveFormat::Tar)
+        }
+    }
+}
+
+/// 선택한 파일 엔트리의 내용 읽기
+///
+/// 해제된 크기 합계가 `max_bytes`를 넘으면 에러를 반환합니다 (압축 폭탄 방지).
+/// 디렉토리/링크 엔트리는 내용 없이(빈 Vec) 함께 반환됩니다.
+pub fn read_entries(
+    data: &[u8],
+    format: ArchiveFormat,
+    mut select: impl FnMut(&ArchiveEntry) -> bool,
+    max_bytes: u64,
+) -> Result<Vec<(ArchiveEntry, Vec<u8>)>> {
+    let mut total = 0u64;
+    let mut budget = |entry: &ArchiveEntry| -> Result<()> {
+        total += entry.size;
+        if total > max_bytes {
+            return Err(Error::InvalidInput(format!(
+                "Archive expands beyond the {} byte limit",
+                max_bytes
+            )));
+        }
+        Ok(())
+    };
+
+    let mut out = Vec::new();
+    match format {
+        ArchiveFormat::Zip => {
+            for record in zip_records(data)? {
+                if !select(&record.entry) {
+                    continue;
+                }
+                let content = if record.entry.kind == EntryKind::Directory {
+                    Vec::new()
+                } else {
+                    budget(&record.entry)?;
+                    zip_record_data(data, &record, max_bytes)?
+                };
+                out.push((record.entry, content));
+            }
+        }
+        ArchiveFormat::Tar => {
+            for (entry, range) in tar_records(data)? {
+                if !select(&entry) {
+                    continue;
+                }
+                let content = if entry.kind == EntryKind::File {
+                    budget(&entry)?;
+                    data[range].to_vec()
+                } else {
+                    Vec::new()
+                };
+                out.push((entry, content));
+            }
+        }
+        ArchiveFormat::TarGz => {
+            // TAR 헤더 분량만큼 여유를 둠
+            let tar = gunzip(data, max_bytes.saturating_add(max_bytes / 8 + 1024 * 1024))?;
+            return read_entries(&tar, ArchiveFormat::Tar, select, max_bytes);
+        }
+    }
+    Ok(out)
+}
+
+/// ZIP 아카이브에서 엔트리 하나 읽기
+///
+/// 해제된 크기가 `max_bytes`를 넘는 엔트리는 풀지 않고 에러를 반환합니다.
+pub fn zip_entry(data: &[u8], name: &str, max_bytes: u64) -> Result<Option<Vec<u8>>> {
+    zip_records(data)?
+        .into_iter()
+        .find(|record| record.entry.name == name)
+        .map(|record| zip_record_data(data, &record, max_bytes))
+        .transpose()
+}
+
+/// 디스크에 쓸 상대 경로 (zip-slip 방지)
+///
+/// 절대 경로, `..`, 드라이브 접두사(`C:`)가 있으면 None을 반환합니다.
+pub fn safe_entry_path(name: &str) -> Option<PathBuf> {
+    let name = name.replace('\\', "/");
+    if name.starts_with('/') {
+        return None;
+    }
+    let mut path = PathBuf::new();
+    for part in name.split('/') {
+        match part {
+            "" | "." => continue,
+            ".." => return None,
+            part if part.contains(':') || part.contains('\0') => return None,
+            part => path.push(part),
+        }
+    }
+    (!path.as_os_str().is_empty()).then_some(path)
+}
+
+// ============================================================================
+// 생성
+// ============================================================================
+
+/// 아카이브 생성기
+///
+/// ```ignore
+/// let mut builder = ArchiveBuilder::new(ArchiveFormat::Zip);
+/// builder.add_directory("src");
+/// builder.add_file("src/main.rs", b"fn main() {}".to_vec(), 0o644);
+/// let bytes = builder.finish()?;
+/// ```
+pub struct ArchiveBuilder {
+    format: ArchiveFormat,
+    entries: Vec<(String, EntryKind, Vec<u8>, u32)>,
+}
+
+impl ArchiveBuilder {
+    pub fn new(format: ArchiveFormat) -> Self {
+        Self {
+            format,
+            entries: Vec::new(),
+        }
+    }
+
+    /// 파일 추가 ('/' 구분 상대 경로)
+    pub fn add_file(&mut self, name: impl Into<String>, data: Vec<u8>, mode: u32) {
+        self.entries
+            .push((name.into(), EntryKind::File, data, mode & 0o7777));
+    }
+
+    /// 디렉토리 추가
+    pub fn add_directory(&mut self, name: impl Into<String>) {
+        let mut name = name.into();
+        if !name.ends_with('/') {
+            name.push('/');
+        }
+        self.entries
+            .push((name, EntryKind::Directory, Vec::new(), 0o755));
+    }
+
+    /// 엔트리 수
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 비어있는지
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 아카이브 바이트 생성
+    pub fn finish(self) -> Result<Vec<u8>> {
+        match self.format {
+            ArchiveFormat::Zip => write_zip(&self.entries),
+            ArchiveFormat::Tar => write_tar(&self.entries),
+            ArchiveFormat::TarGz => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(&write_tar(&self.entries)?)?;
+                Ok(encoder.finish()?)
+            }
+        }
+    }
+}
+
+// ============================================================================
+// ZIP
+// ============================================================================
+
+/// central directory 레코드
+struct ZipRecord {
+    entry: ArchiveEntry,
+    method: u16,
+    crc: u32,
+    encrypted: bool,
+    local_offset: usize,
+}
+
+fn zip_error(msg: &str) -> Error {
+    Error::InvalidInput(format!("Invalid ZIP archive: {}", msg))
+}
+
+fn u16_at(data: &[u8], pos: usize) -> Option<u16> {
+    data.get(pos..pos + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn u32_at(data: &[u8], pos: usize) -> Option<u32> {
+    data.get(pos..pos + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn zip_records(data: &[u8]) -> Result<Vec<ZipRecord>> {
+    let truncated = || zip_error("truncated");
+
+    // End of central directory
+    let eocd = (0..data.len().saturating_sub(21))
+        .rev()
+        .find(|&i| data[i..].starts_with(&ZIP_END_OF_CENTRAL))
+        .ok_or_else(|| zip_error("end of central directory not found"))?;
+    let count = u16_at(data, eocd + 10).ok_or_else(truncated)? as usize;
+    let mut pos = u32_at(data, eocd + 16).ok_or_else(truncated)? as usize;
+    if count == 0xFFFF || pos == 0xFFFF_FFFF {
+        return Err(zip_error("ZIP64 archives are not supported"));
+    }
+
+    let mut records = Vec::with_capacity(count);
+    for _ in 0..count {
+        if !data
+            .get(pos..)
+            .is_some_and(|d| d.starts_with(&ZIP_CENTRAL_HEADER))
+        {
+            return Err(zip_error("corrupt central directory"));
+        }
+        let header = (|| {
+            Some((
+                u16_at(data, pos + 4)?,
+                u16_at(data, pos + 8)?,
+                u16_at(data, pos + 10)?,
+                u32_at(data, pos + 16)?,
+                u32_at(data, pos + 20)?,
+                u32_at(data, pos + 24)?,
+                u16_at(data, pos + 28)? as usize,
+                u16_at(data, pos + 30)? as usize,
+                u16_at(data, pos + 32)? as usize,
+                u32_at(data, pos + 38)?,
+                u32_at(data, pos + 42)?,
+            ))
+        })();
+        let (
+            made_by,
+            flags,
+            method,
+            crc,
+            compressed_size,
+            size,
+            name_len,
+            extra_len,
+            comment_len,
+            external_attrs,
+            local_offset,
+        ) = header.ok_or_else(truncated)?;
+        if compressed_size == 0xFFFF_FFFF || size == 0xFFFF_FFFF || local_offset == 0xFFFF_FFFF {
+            return Err(zip_error("ZIP64 archives are not supported"));
+        }
+        let name = data
+            .get(pos + 46..pos + 46 + name_len)
+            .ok_or_else(truncated)?;
+        let name = String::from_utf8_lossy(name).into_owned();
+
+        // 유닉스에서 만든 아카이브는 external attributes 상위 16비트가 st_mode
+        let mode = (made_by >> 8 == 3)
+            .then_some(external_attrs >> 16)
+            .filter(|m| *m != 0);
+        let kind = if name.ends_with('/') || mode.is_some_and(|m| m & 0o170000 == 0o040000) {
+            EntryKind::Directory
+        } else if mode.is_some_and(|m| m & 0o170000 == 0o120000) {
+            EntryKind::Symlink
+        } else {
+            EntryKind::File
+        };
+
+        records.push(ZipRecord {
+            entry: ArchiveEntry {
+                name,
+                kind,
+                size: size as u64,
+                compressed_size: Some(compressed_size as u64),
+                mode: mode.map(|m| m & 0o7777),
+                link_target: None,
+            },
+            method,
+            crc,
+            encrypted: flags & 1 != 0,
+            local_offset: local_offset as usize,
+        });
+        pos += 46 + name_len + extra_len + comment_len;
+    }
+
+    // 심볼릭 링크 대상은 엔트리 내용
+    for record in &mut records {
+        if record.entry.kind == EntryKind::Symlink {
+            let target = zip_record_data(data, record, MAX_LINK_TARGET_BYTES)?;
+            record.entry.link_target = Some(String::from_utf8_lossy(&target).into_owned());
+        }
+    }
+    Ok(records)
+}
+
+/// 엔트리 내용 (기록된 해제 크기가 `max_bytes`를 넘으면 풀지 않음)
+fn zip_record_data(data: &[u8], record: &ZipRecord, max_bytes: u64) -> Result<Vec<u8>> {
+    let truncated = || zip_error("truncated entry");
+    if record.encrypted {
+        return Err(zip_error(&format!(
+            "encrypted entry '{}' is not supported",
+            record.entry.name
+        )));
+    }
+    if record.entry.size > max_bytes {
+        return Err(Error::InvalidInput(format!(
+            "'{}' expands beyond the {} byte limit",
+            record.entry.name, max_bytes
+        )));
+    }
+
+    let offset = record.local_offset;
+    if !data
+        .get(offset..)
+        .is_some_and(|d| d.starts_with(&ZIP_LOCAL_HEADER))
+    {
+        return Err(zip_error("corrupt local header"));
+    }
+    let local_name_len = u16_at(data, offset + 26).ok_or_else(truncated)? as usize;
+    let local_extra_len = u16_at(data, offset + 28).ok_or_else(truncated)? as usize;
+    let start = offset + 30 + local_name_len + local_extra_len;
+    let compressed_size = record.entry.compressed_size.unwrap_or(0) as usize;
+    let raw = data
+        .get(start..start + compressed_size)
+        .ok_or_else(truncated)?;
+
+    let content = match record.method {
+        0 => raw.to_vec(),
+        8 => {
+            // 기록된 크기보다 많이 풀리면 손상/폭탄으로 간주
+            let mut out = Vec::with_capacity(record.entry.size.min(64 * 1024 * 1024) as usize);
+            DeflateDecoder::new(raw)
+                .take(record.entry.size + 1)
+                .read_to_end(&mut out)
+                .map_err(|e| zip_error(&e.to_string()))?;
+            out
+        }
+        other => {
+            return Err(zip_error(&format!(
+                "unsupported compression method {}",
+                other
+            )))
+        }
+    };
+
+    if content.len() as u64 != record.entry.size {
+        return Err(zip_error(&format!(
+            "size mismatch in '{}'",
+            record.entry.name
+        )));
+    }
+    if crc32fast::hash(&content) != record.crc {
+        return Err(zip_error(&format!(
+            "checksum mismatch in '{}'",
+            record.entry.name
+        )));
+    }
+    Ok(content)
+}
+
+fn write_zip(entries: &[(String, EntryKind, Vec<u8>, u32)]) -> Result<Vec<u8>> {
+    if entries.len() >= 0xFFFF {
+        return Err(Error::InvalidInput(format!(
+            "Too many entries for ZIP: {}",
+            entries.len()
+        )));
+    }
+
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, kind, data, mode) in entries {
+        let crc = crc32fast::hash(data);
+        let deflated = if data.is_empty() {
+            None
+        } else {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(data)?;
+            Some(encoder.finish()?).filter(|d| d.len() < data.len())
+        };
+        let (method, body): (u16, &[u8]) = match &deflated {
+            Some(deflated) => (8, deflated),
+            None => (0, data),
+        };
+        let offset = out.len();
+        if offset as u64 + body.len() as u64 >= 0xFFFF_FFFF {
+            return Err(Error::InvalidInput(
+                "Archive is too large for ZIP (ZIP64 is not supported)".to_string(),
+            ));
+        }
+        let external = match kind {
+            EntryKind::Directory => ((0o040000 | mode) << 16) | 0x10,
+            _ => (0o100000 | mode) << 16,
+        };
+
+        // 공통 필드: version, flags(UTF-8), method, time, date, crc, sizes, name length
+        let mut common = Vec::with_capacity(26);
+        common.extend_from_slice(&20u16.to_le_bytes());
+        common.extend_from_slice(&0x0800u16.to_le_bytes());
+        common.extend_from_slice(&method.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&ZIP_DOS_DATE.to_le_bytes());
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        common.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        common.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes()); // extra
+
+        out.extend_from_slice(&ZIP_LOCAL_HEADER);
+        out.extend_from_slice(&common);
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(body);
+
+        central.extend_from_slice(&ZIP_CENTRAL_HEADER);
+        central.extend_from_slice(&((3u16 << 8) | 20).to_le_bytes()); // made by: unix
+        central.extend_from_slice(&common);
+        central.extend_from_slice(&[0; 6]); // comment, disk, internal attrs
+        central.extend_from_slice(&external.to_le_bytes());
+        central.extend_from_slice(&(offset as u32).to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+
+    let central_offset = out.len() as u32;
+    out.extend_from_slice(&central);
+    out.extend_from_slice(&ZIP_END_OF_CENTRAL);
+    out.extend_from_slice(&[0; 4]); // disk numbers
+    out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&[0, 0]); // comment
+    Ok(out)
+}
+
+// ============================================================================
+// TAR
+// ============================================================================
+
+fn tar_error(msg: &str) -> Error {
+    Error::InvalidInput(format!("Invalid TAR archive: {}", msg))
+}
+
+fn gunzip(data: &[u8], max_bytes: u64) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    GzDecoder::new(data)
+        .take(max_bytes + 1)
+        .read_to_end(&mut out)
+        .map_err(|e| Error::InvalidInput(format!("Invalid gzip stream: {}", e)))?;
+    if out.len() as u64 > max_bytes {
+        return Err(Error::InvalidInput(format!(
+            "Archive expands beyond the {} byte limit",
+            max_bytes
+        )));
+    }
+    Ok(out)
+}
+
+/// NUL 종료 문자열 필드
+fn tar_str(field: &[u8]) -> String {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+/// 8진수 숫자 필드 (GNU base-256 포함)
+fn tar_number(field: &[u8]) -> Option<u64> {
+    if field.first().is_some_and(|b| b & 0x80 != 0) {
+        return field[1..]
+            .iter()
+            .try_fold((field[0] & 0x7f) as u64, |acc, &b| {
+                acc.checked_mul(256).map(|v| v + b as u64)
+            });
+    }
+    let text = tar_str(field);
+    let text = text.trim_matches(|c: char| c == ' ' || c == '\0');
+    if text.is_empty() {
+        return Some(0);
+    }
+    u64::from_str_radix(text, 8).ok()
+}
+
+/// pax 확장 헤더 레코드 ("<len> key=value\n")
+fn parse_pax(data: &[u8]) -> Vec<(String, String)> {
+    let mut records = Vec::new();
+    let mut rest = data;
+    while let Some(space) = rest.iter().position(|&b| b == b' ') {
+        let Some(len) = std::str::from_utf8(&rest[..space])
+            .ok()
+            .and_then(|n| n.parse::<usize>().ok())
+        else {
+            break;
+        };
+        if len <= space || len > rest.len() {
+            break;
+        }
+        let record = String::from_utf8_lossy(&rest[space + 1..len]);
+        if let Some((key, value)) = record.trim_end_matches('\n').split_once('=') {
+            records.push((key.to_string(), value.to_string()));
+        }
+        rest = &rest[len..];
+    }
+    records
+}
+
+fn tar_records(data: &[u8]) -> Result<Vec<(ArchiveEntry, std::ops::Range<usize>)>> {
+    let mut records = Vec::new();
+    let mut pos = 0;
+    let mut long_name: Option<String> = None;
+    let mut long_link: Option<String> = None;
+    let mut pax: Vec<(String, String)> = Vec::new();
+
+    while pos + TAR_BLOCK <= data.len() {
+        let header = &data[pos..pos + TAR_BLOCK];
+        if header.iter().all(|&b| b == 0) {
+            break;
+        }
+
+        let stored: u32 = header
+            .iter()
+            .enumerate()
+            .map(|(i, &b)| {
+                if (148..156).contains(&i) {
+                    b' ' as u32
+                } else {
+                    b as u32
+                }
+            })
+            .sum();
+        if tar_number(&header[148..156]) != Some(stored as u64) {
+            return Err(tar_error(&format!("bad header checksum at offset {}", pos)));
+        }
+
+        let typeflag = header[156];
+        let mut size = tar_number(&header[124..136]).ok_or_else(|| tar_error("bad size"))?;
+        if typeflag != b'x' && typeflag != b'g' {
+            if let Some((_, value)) = pax.iter().find(|(key, _)| key == "size") {
+                size = value.parse().map_err(|_| tar_error("bad pax size"))?;
+            }
+        }
+        let start = pos + TAR_BLOCK;
+        let end = usize::try_from(size)
+            .ok()
+            .and_then(|size| start.checked_add(size))
+            .filter(|&end| end <= data.len())
+            .ok_or_else(|| tar_error("truncated entry"))?;
+        let body = &data[start..end];
+        pos = start + (end - start).div_ceil(TAR_BLOCK) * TAR_BLOCK;
+
+        match typeflag {
+            // GNU 긴 이름/링크
+            b'L' => {
+                long_name = Some(tar_str(body));
+                continue;
+            }
+            b'K' => {
+                long_link = Some(tar_str(body));
+                continue;
+            }
+            // pax 확장 헤더 (다음 엔트리용) / 전역 헤더 (무시)
+            b'x' => {
+                pax = parse_pax(body);
+                continue;
+            }
+            b'g' => continue,
+            _ => {}
+        }
+
+        let pax_value = |key: &str| {
+            pax.iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value.clone())
+        };
+        let name = pax_value("path").or(long_name.take()).unwrap_or_else(|| {
+            let name = tar_str(&header[0..100]);
+            let prefix = if &header[257..262] == b"ustar" {
+                tar_str(&header[345..500])
+            } else {
+                String::new()
+            };
+            if prefix.is_empty() {
+                name
+            } else {
+                format!("{}/{}", prefix, name)
+            }
+        });
+        let link = pax_value("linkpath")
+            .or(long_link.take())
+            .unwrap_or_else(|| tar_str(&header[157..257]));
+        pax.clear();
+
+        let kind = match typeflag {
+            b'0' | b'\0' | b'7' => EntryKind::File,
+            b'5' => EntryKind::Directory,
+            b'2' => EntryKind::Symlink,
+            _ => EntryKind::Other,
+        };
+        let kind = if kind == EntryKind::File && name.ends_with('/') {
+            EntryKind::Directory
+        } else {
+            kind
+        };
+
+        records.push((
+            ArchiveEntry {
+                name,
+                kind,
+                size: if kind == EntryKind::File { size } else { 0 },
+                compressed_size: None,
+                mode: tar_number(&header[100..108]).map(|m| m as u32 & 0o7777),
+                link_target: (!link.is_empty()).then_some(link),
+            },
+            start..end,
+        ));
+    }
+
+    Ok(records)
+}
+
+/// 8진수 필드 쓰기 (NUL 종료)
+fn put_octal(field: &mut [u8], value: u64) {
+    let digits = field.len() - 1;
+    let text = format!("{:0width$o}", value, width = digits);
+    field[..digits].copy_from_slice(&text.as_bytes()[text.len() - digits..]);
+    field[digits] = 0;
+}
+
+fn tar_header(name: &[u8], typeflag: u8, size: u64, mode: u32) -> [u8; TAR_BLOCK] {
+    let mut header = [0u8; TAR_BLOCK];
+    let name_len = name.len().min(100);
+    header[..name_len].copy_from_slice(&name[..name_len]);
+    put_octal(&mut header[100..108], mode as u64);
+    put_octal(&mut header[108..116], 0); // uid
+    put_octal(&mut header[116..124], 0); // gid
+    put_octal(&mut header[124..136], size);
+    put_octal(&mut header[136..148], 0); // mtime
+    header[156] = typeflag;
+    header[257..265].copy_from_slice(b"ustar  \0"); // GNU magic
+
+    header[148..156].fill(b' ');
+    let checksum: u32 = header.iter().map(|&b| b as u32).sum();
+    put_octal(&mut header[148..155], checksum as u64);
+    header[155] = b' ';
+    header
+}
+
+fn write_tar(entries: &[(String, EntryKind, Vec<u8>, u32)]) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut push = |header: [u8; TAR_BLOCK], body: &[u8]| {
+        out.extend_from_slice(&header);
+        out.extend_from_slice(body);
+        let padding = body.len().div_ceil(TAR_BLOCK) * TAR_BLOCK - body.len();
+        out.resize(out.len() + padding, 0);
+    };
+
+    for (name, kind, data, mode) in entries {
+        let typeflag = match kind {
+            EntryKind::Directory => b'5',
+            _ => b'0',
+        };
+        if name.len() > 100 {
+            let mut long = name.as_bytes().to_vec();
+            long.push(0);
+            push(
+                tar_header(b"././@LongLink", b'L', long.len() as u64, 0),
+                &long,
+            );
+        }
+        push(
+            tar_header(name.as_bytes(), typeflag, data.len() as u64, *mode),
+            data,
+        );
+    }
+
+    out.resize(out.len() + TAR_BLOCK * 2, 0);
+    Ok(out)
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(format: ArchiveFormat) -> Vec<u8> {
+        let mut builder = ArchiveBuilder::new(format);
+        builder.add_directory("src");
+        builder.add_file("src/main.rs", b"fn main() {}\n".repeat(20), 0o644);
+        builder.add_file(
+            format!("deep/{}/run.sh", "d".repeat(120)),
+            b"echo hi\n".to_vec(),
+            0o755,
+        );
+        builder.add_file("empty.txt", Vec::new(), 0o644);
+        builder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for format in [ArchiveFormat::Zip, ArchiveFormat::Tar, ArchiveFormat::TarGz] {
+            let data = sample(format);
+            assert_eq!(ArchiveFormat::sniff(&data), Some(format));
+
+            let entries = list_entries(&data, format).unwrap();
+            let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+            assert_eq!(names[0], "src/");
+            assert_eq!(names[1], "src/main.rs");
+            assert!(names[2].ends_with("/run.sh") && names[2].len() > 100);
+            assert_eq!(entries[0].kind, EntryKind::Directory);
+            assert_eq!(entries[1].size, 260);
+            assert_eq!(entries[2].mode, Some(0o755));
+
+            let files = read_entries(&data, format, |e| e.name.ends_with(".rs"), 1024).unwrap();
+            assert_eq!(files.len(), 1);
+            assert_eq!(files[0].1, b"fn main() {}\n".repeat(20));
+
+            let limited = read_entries(&data, format, |_| true, 100);
+            assert!(
+                limited.is_err(),
+                "{} should enforce the size limit",
+                format.label()
+            );
+        }
+    }
+
+    #[test]
+    fn test_zip_entry_and_corruption() {
+        let data = sample(ArchiveFormat::Zip);
+        assert_eq!(zip_entry(&data, "empty.txt", 0).unwrap(), Some(Vec::new()));
+        assert_eq!(zip_entry(&data, "missing", 0).unwrap(), None);
+
+        // 기록된 해제 크기가 상한을 넘으면 풀지 않음
+        assert_eq!(zip_entry(&data, "src/main.rs", 260).unwrap().unwrap().len(), 260);
+        assert!(zip_entry(&data, "src/main.rs", 259).is_err());
+
+        // 내용 손상은 CRC로 감지
+        let mut corrupt = data.clone();
+        let pos = corrupt.windows(4).position(|w| w == b"echo").unwrap();
+        corrupt[pos] = b'E';
+        assert!(zip_entry(
+            &corrupt,
+            &list_entries(&data, ArchiveFormat::Zip).unwrap()[2].name,
+            1024
+        )
+        .is_err());
+
+        assert!(list_entries(b"not an archive", ArchiveFormat::Zip).is_err());
+    }
+
+    #[test]
+    fn test_format_detection() {
+        assert_eq!(
+            ArchiveFormat::from_path(Path::new("a/B.TAR.GZ")),
+            Some(ArchiveFormat::TarGz)
+        );
+        assert_eq!(
+            ArchiveFormat::from_path(Path::new("x.tgz")),
+            Some(ArchiveFormat::TarGz)
+        );
+        assert_eq!(
+            ArchiveFormat::from_path(Path::new("x.tar")),
+            Some(ArchiveFormat::Tar)
+        );
+        assert_eq!(
+            ArchiveFormat::from_path(Path::new("lib.jar")),
+            Some(ArchiveFormat::Zip)
+        );
+        assert_eq!(ArchiveFormat::from_path(Path::new("x.rs")), None);
+        assert_eq!(ArchiveFormat::parse(".tar.gz"), Some(ArchiveFormat::TarGz));
+        assert_eq!(ArchiveFormat::parse("rar"), None);
+    }
+
+    #[test]
+    fn test_safe_entry_path() {
+        assert_eq!(
+            safe_entry_path("src/./main.rs"),
+            Some(PathBuf::from("src/main.rs"))
+        );
+        assert_eq!(safe_entry_path("dir/"), Some(PathBuf::from("dir")));
+        assert_eq!(safe_entry_path("../evil"), None);
+        assert_eq!(safe_entry_path("a/../../evil"), None);
+        assert_eq!(safe_entry_path("/etc/passwd"), None);
+        assert_eq!(safe_entry_path("..\\evil.dll"), None);
+        assert_eq!(safe_entry_path("C:/Windows/evil.dll"), None);
+        assert_eq!(safe_entry_path("./"), None);
+    }
+}
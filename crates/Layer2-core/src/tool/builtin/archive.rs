@@ -0,0 +1,824 @@
+//! Archive Tool - zip/tar 조회, 추출, 생성
+//!
+//! `unzip`/`tar`를 bash로 부르는 대신 쓰는 도구입니다.
+//! - `list` - 추출 없이 엔트리 목록 (크기, 종류, 링크 대상)
+//! - `extract` - 선택한 엔트리만 대상 디렉토리에 추출
+//! - `create` - 워크스페이스 파일/디렉토리로 아카이브 생성 (`.gitignore` 존중)
+//!
+//! 추출은 zip-slip을 막기 위해 모든 엔트리를 먼저 검사합니다.
+//! 절대 경로나 `..`이 있는 엔트리가 하나라도 있으면 아무것도 쓰지 않고,
+//! 쓰기 직전에는 `PathValidator`로 대상 디렉토리 밖(심볼릭 링크 경유 포함)을 차단합니다.
+//! 링크 엔트리는 추출하지 않습니다.
+
+use super::list_directory::format_size;
+use super::walk::workspace_walker;
+use crate::tool::archive::{
+    list_entries, read_entries, safe_entry_path, ArchiveBuilder, ArchiveEntry, ArchiveFormat,
+    EntryKind,
+};
+use crate::tool::security::{is_sensitive_path, PathValidator};
+use async_trait::async_trait;
+use forge_foundation::{
+    PermissionAction, PermissionStatus, Result, Tool, ToolContext, ToolMeta, ToolResult,
+};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+/// 읽을 수 있는 최대 아카이브 크기
+const MAX_ARCHIVE_BYTES: u64 = 512 * 1024 * 1024;
+
+/// 추출 시 최대 해제 크기 (압축 폭탄 방지)
+const MAX_EXTRACT_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// 목록 출력 최대 엔트리 수
+const MAX_LISTED: usize = 500;
+
+/// 아카이브 도구
+pub struct ArchiveTool;
+
+impl ArchiveTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "archive";
+
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 쓰기 대상 경로 (extract → 대상 디렉토리, create → 아카이브)
+    fn target_path(input: &Value) -> Option<String> {
+        let path = input.get("path")?.as_str()?;
+        match input.get("action")?.as_str()? {
+            "extract" => Some(
+                input["destination"]
+                    .as_str()
+                    .map(String::from)
+                    .unwrap_or_else(|| default_destination(Path::new(path)).display().to_string()),
+            ),
+            "create" => Some(path.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ArchiveTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 아카이브 이름에서 확장자를 뗀 디렉토리 (`dist.tar.gz` → `dist`)
+fn default_destination(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let lower = name.to_lowercase();
+    let stem_len = [".tar.gz", ".tgz", ".tar"]
+        .iter()
+        .find(|ext| lower.ends_with(*ext))
+        .map(|ext| name.len() - ext.len())
+        .or_else(|| name.rfind('.'))
+        .filter(|&len| len > 0)
+        .unwrap_or(name.len());
+    let stem = if stem_len == name.len() {
+        format!("{}_extracted", name)
+    } else {
+        name[..stem_len].to_string()
+    };
+    path.with_file_name(stem)
+}
+
+/// 작업 디렉토리 기준 경로 해석 + 보안 검증
+fn resolve_path(context: &dyn ToolContext, path: &str) -> std::result::Result<PathBuf, String> {
+    let input_path = Path::new(path);
+    let resolved = if input_path.is_absolute() {
+        input_path.to_path_buf()
+    } else {
+        context.working_dir().join(input_path)
+    };
+
+    let validation = PathValidator::new()
+        .with_allowed_root(context.working_dir())
+        .validate(&resolved);
+    match validation.error_message() {
+        Some(msg) => Err(format!("Path security check failed: {}", msg)),
+        None => Ok(resolved),
+    }
+}
+
+/// 엔트리 선택 (정확한 이름, 디렉토리 접두사, glob)
+fn matches_selection(entry: &ArchiveEntry, selection: &[String]) -> bool {
+    if selection.is_empty() {
+        return true;
+    }
+    let name = entry.name.trim_end_matches('/');
+    selection.iter().any(|pattern| {
+        let prefix = pattern.trim_end_matches('/');
+        name == prefix
+            || name
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('/'))
+            || glob::Pattern::new(pattern).is_ok_and(|glob| glob.matches(name))
+    })
+}
+
+/// 아카이브 읽기 (크기 제한 + 형식 판별)
+async fn load_archive(
+    path: &Path,
+    format: Option<ArchiveFormat>,
+) -> std::result::Result<(Vec<u8>, ArchiveFormat), String> {
+    let metadata = tokio::fs::metadata(path)
+        .await
+        .map_err(|e| format!("Cannot read archive {}: {}", path.display(), e))?;
+    if metadata.len() > MAX_ARCHIVE_BYTES {
+        return Err(format!(
+            "Archive is {} which exceeds the limit of {}",
+            format_size(metadata.len()),
+            format_size(MAX_ARCHIVE_BYTES)
+        ));
+    }
+    let data = tokio::fs::read(path)
+        .await
+        .map_err(|e| format!("Cannot read archive {}: {}", path.display(), e))?;
+    let format = format
+        .or_else(|| ArchiveFormat::sniff(&data))
+        .or_else(|| ArchiveFormat::from_path(path))
+        .ok_or_else(|| {
+            format!(
+                "Unsupported archive format: {} (supported: zip, tar, tar.gz)",
+                path.display()
+            )
+        })?;
+    Ok((data, format))
+}
+
+/// 이미 존재하는 가장 가까운 상위 경로가 root 밖으로 해석되면 그 경로
+fn resolved_outside(dir: &Path, root: &Path) -> Option<PathBuf> {
+    let existing = dir
+        .ancestors()
+        .find(|p| p.symlink_metadata().is_ok())
+        .unwrap_or(root);
+    match existing.canonicalize() {
+        Ok(resolved) if resolved.starts_with(root) => None,
+        Ok(resolved) => Some(resolved),
+        Err(_) => Some(existing.to_path_buf()),
+    }
+}
+
+/// 추출 결과
+#[derive(Default)]
+struct ExtractReport {
+    files: Vec<String>,
+    directories: usize,
+    bytes: u64,
+    existing: Vec<String>,
+    links: Vec<String>,
+}
+
+/// 선택한 엔트리를 destination에 추출 (blocking)
+fn extract(
+    data: &[u8],
+    format: ArchiveFormat,
+    destination: &Path,
+    selection: &[String],
+    overwrite: bool,
+) -> std::result::Result<ExtractReport, String> {
+    let entries = read_entries(
+        data,
+        format,
+        |entry| matches_selection(entry, selection),
+        MAX_EXTRACT_BYTES,
+    )
+    .map_err(|e| e.to_string())?;
+    if entries.is_empty() {
+        return Err("No entries matched the selection".to_string());
+    }
+
+    // zip-slip: 하나라도 밖으로 나가면 아무것도 쓰지 않음
+    let unsafe_entries: Vec<&str> = entries
+        .iter()
+        .filter(|(entry, _)| safe_entry_path(&entry.name).is_none())
+        .map(|(entry, _)| entry.name.as_str())
+        .collect();
+    if !unsafe_entries.is_empty() {
+        return Err(format!(
+            "Refusing to extract: {} entries would escape the destination: {}",
+            unsafe_entries.len(),
+            unsafe_entries.join(", ")
+        ));
+    }
+
+    std::fs::create_dir_all(destination)
+        .map_err(|e| format!("Failed to create {}: {}", destination.display(), e))?;
+    let root = destination
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", destination.display(), e))?;
+    let validator = PathValidator::new().with_allowed_root(&root);
+
+    let mut report = ExtractReport::default();
+    for (entry, content) in entries {
+        let Some(relative) = safe_entry_path(&entry.name) else {
+            continue;
+        };
+        if matches!(entry.kind, EntryKind::Symlink | EntryKind::Other) {
+            report.links.push(entry.name);
+            continue;
+        }
+
+        let target = root.join(&relative);
+        if let Some(msg) = validator.validate(&target).error_message() {
+            return Err(format!("Blocked entry '{}': {}", entry.name, msg));
+        }
+        let dir = if entry.kind == EntryKind::Directory {
+            target.as_path()
+        } else {
+            target.parent().unwrap_or(&root)
+        };
+        // 이미 있던 심볼릭 링크 디렉토리를 통한 탈출 (디렉토리를 만들기 전에 확인)
+        if let Some(resolved) = resolved_outside(dir, &root) {
+            return Err(format!(
+                "Blocked entry '{}': resolves outside the destination ({})",
+                entry.name,
+                resolved.display()
+            ));
+        }
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+        if entry.kind == EntryKind::Directory {
+            report.directories += 1;
+            continue;
+        }
+        if let Ok(metadata) = target.symlink_metadata() {
+            if !overwrite || metadata.file_type().is_symlink() || metadata.is_dir() {
+                report.existing.push(entry.name);
+                continue;
+            }
+        }
+
+        std::fs::write(&target, &content)
+            .map_err(|e| format!("Failed to write {}: {}", target.display(), e))?;
+        #[cfg(unix)]
+        if let Some(mode) = entry.mode.filter(|mode| mode & 0o111 != 0) {
+            use std::os::unix::fs::PermissionsExt;
+            let _ =
+                std::fs::set_permissions(&target, std::fs::Permissions::from_mode(mode & 0o755));
+        }
+        report.bytes += content.len() as u64;
+        report
+            .files
+            .push(relative.to_string_lossy().replace('\\', "/"));
+    }
+    Ok(report)
+}
+
+/// 워크스페이스 경로들로 아카이브 생성 (blocking)
+///
+/// 엔트리 이름은 작업 디렉토리 기준 상대 경로입니다.
+fn build_archive(
+    working_dir: &Path,
+    sources: &[PathBuf],
+    output: &Path,
+    format: ArchiveFormat,
+) -> std::result::Result<(Vec<u8>, usize, u64, Vec<String>), String> {
+    let mut builder = ArchiveBuilder::new(format);
+    let mut files = 0;
+    let mut total = 0u64;
+    let mut skipped = Vec::new();
+
+    let name_of = |path: &Path| {
+        path.strip_prefix(working_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+    let mut add_file = |builder: &mut ArchiveBuilder,
+                        path: &Path|
+     -> std::result::Result<(), String> {
+        let name = name_of(path);
+        if path == output || is_sensitive_path(&name) {
+            skipped.push(name);
+            return Ok(());
+        }
+        let data = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", name, e))?;
+        total += data.len() as u64;
+        if total > MAX_ARCHIVE_BYTES {
+            return Err(format!(
+                "Archive contents exceed the limit of {}",
+                format_size(MAX_ARCHIVE_BYTES)
+            ));
+        }
+        #[cfg(unix)]
+        let mode = {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::metadata(path)
+                .map(|m| m.permissions().mode())
+                .unwrap_or(0o644)
+        };
+        #[cfg(not(unix))]
+        let mode = 0o644;
+        builder.add_file(name, data, mode);
+        files += 1;
+        Ok(())
+    };
+
+    for source in sources {
+        if source.is_dir() {
+            let walker = workspace_walker(source).build();
+            for entry in walker.filter_map(|entry| entry.ok()) {
+                let path = entry.path();
+                if name_of(path).is_empty() {
+                    continue;
+                }
+                if entry.file_type().is_some_and(|t| t.is_dir()) {
+                    builder.add_directory(name_of(path));
+                } else if entry.file_type().is_some_and(|t| t.is_file()) {
+                    add_file(&mut builder, path)?;
+                }
+            }
+        } else if source.is_file() {
+            add_file(&mut builder, source)?;
+        } else {
+            return Err(format!("Not found: {}", name_of(source)));
+        }
+    }
+
+    if files == 0 {
+        return Err("No files to archive".to_string());
+    }
+    let data = builder.finish().map_err(|e| e.to_string())?;
+    Ok((data, files, total, skipped))
+}
+
+/// 목록 출력
+fn format_listing(path: &str, format: ArchiveFormat, entries: &[ArchiveEntry]) -> String {
+    let files = entries.iter().filter(|e| e.kind == EntryKind::File).count();
+    let total: u64 = entries.iter().map(|e| e.size).sum();
+    let mut lines = vec![format!(
+        "{} ({}, {} files, {} entries, {} unpacked)",
+        path,
+        format.label(),
+        files,
+        entries.len(),
+        format_size(total)
+    )];
+
+    for entry in entries.iter().take(MAX_LISTED) {
+        let size = match entry.kind {
+            EntryKind::File => format_size(entry.size),
+            EntryKind::Directory => "dir".to_string(),
+            EntryKind::Symlink => "link".to_string(),
+            EntryKind::Other => "other".to_string(),
+        };
+        let mut line = format!("{:>10}  {}", size, entry.name);
+        if let Some(target) = &entry.link_target {
+            line.push_str(&format!(" -> {}", target));
+        }
+        if safe_entry_path(&entry.name).is_none() {
+            line.push_str("  [unsafe path]");
+        }
+        lines.push(line);
+    }
+    if entries.len() > MAX_LISTED {
+        lines.push(format!("... {} more entries", entries.len() - MAX_LISTED));
+    }
+    lines.join("\n")
+}
+
+#[async_trait]
+impl Tool for ArchiveTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Archive")
+            .description(
+                "Inspect, extract and create zip/tar/tar.gz archives. Use action \"list\" to see contents without extracting, \
+                 \"extract\" to unpack selected entries into a directory inside the workspace, and \"create\" to pack workspace files. \
+                 Entries with absolute paths or '..' are refused.",
+            )
+            .category("filesystem")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["list", "extract", "create"],
+                    "description": "What to do with the archive"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Archive file path (relative to the working directory)"
+                },
+                "entries": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "extract: entry names, directory prefixes or glob patterns to extract (default: all). create: files or directories to add (required)"
+                },
+                "destination": {
+                    "type": "string",
+                    "description": "extract: target directory (default: archive name without extension, next to the archive)"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["zip", "tar", "tar.gz"],
+                    "description": "Archive format (default: detected from content or extension)"
+                },
+                "overwrite": {
+                    "type": "boolean",
+                    "description": "Replace existing files (default: false)"
+                }
+            },
+            "required": ["action", "path"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        Self::target_path(input).map(|path| PermissionAction::FileWrite { path })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let (Some(action), Some(path_str)) = (input["action"].as_str(), input["path"].as_str())
+        else {
+            return Ok(ToolResult::error(
+                "Missing required parameters: action, path",
+            ));
+        };
+        if !matches!(action, "list" | "extract" | "create") {
+            return Ok(ToolResult::error(format!(
+                "Unknown action '{}': expected list, extract or create",
+                action
+            )));
+        }
+        let format = match input["format"].as_str() {
+            Some(name) => match ArchiveFormat::parse(name) {
+                Some(format) => Some(format),
+                None => {
+                    return Ok(ToolResult::error(format!(
+                        "Unsupported archive format '{}' (supported: zip, tar, tar.gz)",
+                        name
+                    )))
+                }
+            },
+            None => None,
+        };
+        let selection: Vec<String> = input["entries"]
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|v| v.as_str())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let overwrite = input["overwrite"].as_bool().unwrap_or(false);
+
+        let path = match resolve_path(context, path_str) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        if action == "list" {
+            let (data, format) = match load_archive(&path, format).await {
+                Ok(v) => v,
+                Err(e) => return Ok(ToolResult::error(e)),
+            };
+            let entries = match tokio::task::spawn_blocking(move || list_entries(&data, format))
+                .await
+                .map_err(|e| forge_foundation::Error::Internal(format!("Archive failed: {}", e)))?
+            {
+                Ok(entries) => entries,
+                Err(e) => return Ok(ToolResult::error(e.to_string())),
+            };
+            return Ok(
+                ToolResult::success(format_listing(path_str, format, &entries))
+                    .with_metadata("format", json!(format.label()))
+                    .with_metadata("entries", json!(entries.len())),
+            );
+        }
+
+        // 권한 확인
+        let target = Self::target_path(&input).unwrap_or_default();
+        if let Some(action) = self.required_permission(&input) {
+            let status = context.check_permission(Self::NAME, &action).await;
+            match status {
+                PermissionStatus::Denied => {
+                    return Ok(ToolResult::error("Permission denied for file write"));
+                }
+                PermissionStatus::Unknown => {
+                    let granted = context
+                        .request_permission(Self::NAME, &format!("Write: {}", target), action)
+                        .await?;
+                    if !granted {
+                        return Ok(ToolResult::error("Permission denied by user"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if action == "extract" {
+            let destination = match resolve_path(context, &target) {
+                Ok(path) => path,
+                Err(e) => return Ok(ToolResult::error(e)),
+            };
+            let (data, format) = match load_archive(&path, format).await {
+                Ok(v) => v,
+                Err(e) => return Ok(ToolResult::error(e)),
+            };
+            let dest = destination.clone();
+            let report = match tokio::task::spawn_blocking(move || {
+                extract(&data, format, &dest, &selection, overwrite)
+            })
+            .await
+            .map_err(|e| forge_foundation::Error::Internal(format!("Archive failed: {}", e)))?
+            {
+                Ok(report) => report,
+                Err(e) => return Ok(ToolResult::error(e)),
+            };
+
+            let mut lines = vec![format!(
+                "Extracted {} files ({}) to {}",
+                report.files.len(),
+                format_size(report.bytes),
+                target
+            )];
+            lines.extend(
+                report
+                    .files
+                    .iter()
+                    .take(MAX_LISTED)
+                    .map(|f| format!("  {}", f)),
+            );
+            if report.files.len() > MAX_LISTED {
+                lines.push(format!("  ... {} more", report.files.len() - MAX_LISTED));
+            }
+            if !report.existing.is_empty() {
+                lines.push(format!(
+                    "Skipped {} existing files (set overwrite: true to replace): {}",
+                    report.existing.len(),
+                    report.existing.join(", ")
+                ));
+            }
+            if !report.links.is_empty() {
+                lines.push(format!(
+                    "Skipped {} links: {}",
+                    report.links.len(),
+                    report.links.join(", ")
+                ));
+            }
+            return Ok(ToolResult::success(lines.join("\n"))
+                .with_metadata("files", json!(report.files))
+                .with_metadata("directories", json!(report.directories))
+                .with_metadata("bytes", json!(report.bytes)));
+        }
+
+        // create
+        if selection.is_empty() {
+            return Ok(ToolResult::error(
+                "Missing entries: list the files or directories to add",
+            ));
+        }
+        let Some(format) = format.or_else(|| ArchiveFormat::from_path(&path)) else {
+            return Ok(ToolResult::error(format!(
+                "Cannot infer archive format from '{}': use a .zip/.tar/.tar.gz name or set format",
+                path_str
+            )));
+        };
+        if path.exists() && !overwrite {
+            return Ok(ToolResult::error(format!(
+                "File already exists: {} (set overwrite: true to replace it)",
+                path_str
+            )));
+        }
+        let mut sources = Vec::new();
+        for source in &selection {
+            match resolve_path(context, source) {
+                Ok(source) => sources.push(source),
+                Err(e) => return Ok(ToolResult::error(e)),
+            }
+        }
+
+        let working_dir = context.working_dir().to_path_buf();
+        let output = path.clone();
+        let built = tokio::task::spawn_blocking(move || {
+            build_archive(&working_dir, &sources, &output, format)
+        })
+        .await
+        .map_err(|e| forge_foundation::Error::Internal(format!("Archive failed: {}", e)))?;
+        let (data, files, total, skipped) = match built {
+            Ok(v) => v,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = tokio::fs::create_dir_all(parent).await {
+                return Ok(ToolResult::error(format!(
+                    "Failed to create directory {}: {}",
+                    parent.display(),
+                    e
+                )));
+            }
+        }
+        if let Err(e) = tokio::fs::write(&path, &data).await {
+            return Ok(ToolResult::error(format!("Failed to write archive: {}", e)));
+        }
+
+        let mut output = format!(
+            "Created {} ({}, {} files, {} → {})",
+            path_str,
+            format.label(),
+            files,
+            format_size(total),
+            format_size(data.len() as u64)
+        );
+        if !skipped.is_empty() {
+            output.push_str(&format!(
+                "\nSkipped {} files: {}",
+                skipped.len(),
+                skipped.join(", ")
+            ));
+        }
+        Ok(ToolResult::success(output)
+            .with_metadata("files", json!(files))
+            .with_metadata("bytes", json!(data.len())))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::fs;
+    use std::sync::Arc;
+
+    fn context(dir: &Path) -> RuntimeContext {
+        RuntimeContext::new(
+            "test-session",
+            dir.to_path_buf(),
+            Arc::new(PermissionService::with_auto_approve()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_list_extract() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("project/src")).unwrap();
+        fs::write(dir.path().join("project/src/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(dir.path().join("project/README.md"), "# Project\n").unwrap();
+        fs::write(dir.path().join("project/.env"), "SECRET=1\n").unwrap();
+        let ctx = context(dir.path());
+        let tool = ArchiveTool::new();
+
+        for name in ["out.zip", "out.tar.gz"] {
+            let result = tool
+                .execute(
+                    json!({ "action": "create", "path": name, "entries": ["project"] }),
+                    &ctx,
+                )
+                .await
+                .unwrap();
+            assert!(result.success, "{:?}", result.error);
+            assert!(result.output.contains("2 files"), "{}", result.output);
+            assert!(result.output.contains("Skipped 1 files: project/.env"));
+
+            let result = tool
+                .execute(json!({ "action": "list", "path": name }), &ctx)
+                .await
+                .unwrap();
+            assert!(result.success);
+            assert!(result.output.contains("project/src/main.rs"));
+            assert!(!result.output.contains(".env"));
+
+            // 선택 추출 (기본 대상: 확장자를 뗀 디렉토리)
+            let result = tool
+                .execute(
+                    json!({ "action": "extract", "path": name, "entries": ["project/src"] }),
+                    &ctx,
+                )
+                .await
+                .unwrap();
+            assert!(result.success, "{:?}", result.error);
+            assert_eq!(
+                fs::read_to_string(dir.path().join("out/project/src/main.rs")).unwrap(),
+                "fn main() {}\n"
+            );
+            assert!(!dir.path().join("out/project/README.md").exists());
+
+            // 기존 파일은 건너뜀
+            let result = tool
+                .execute(json!({ "action": "extract", "path": name }), &ctx)
+                .await
+                .unwrap();
+            assert!(result.output.contains("Skipped 1 existing files"));
+            assert!(dir.path().join("out/project/README.md").exists());
+            fs::remove_dir_all(dir.path().join("out")).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_extract_blocks_zip_slip() {
+        let dir = tempfile::tempdir().unwrap();
+        let workspace = dir.path().join("ws");
+        fs::create_dir_all(&workspace).unwrap();
+        let ctx = context(&workspace);
+        let tool = ArchiveTool::new();
+
+        let mut builder = ArchiveBuilder::new(ArchiveFormat::Zip);
+        builder.add_file("ok.txt", b"ok".to_vec(), 0o644);
+        builder.add_file("../../evil.txt", b"pwned".to_vec(), 0o644);
+        fs::write(workspace.join("evil.zip"), builder.finish().unwrap()).unwrap();
+
+        let result = tool
+            .execute(json!({ "action": "list", "path": "evil.zip" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result.output.contains("../../evil.txt  [unsafe path]"));
+
+        let result = tool
+            .execute(json!({ "action": "extract", "path": "evil.zip" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap()
+            .contains("would escape the destination"));
+        assert!(!workspace.join("evil/ok.txt").exists());
+        assert!(!dir.path().join("evil.txt").exists());
+
+        // 대상 디렉토리가 워크스페이스 밖
+        let result = tool
+            .execute(
+                json!({ "action": "extract", "path": "evil.zip", "destination": "../out", "entries": ["ok.txt"] }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Path security check failed"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_extract_blocks_symlink_escape() {
+        let dir = tempfile::tempdir().unwrap();
+        let workspace = dir.path().join("ws");
+        let outside = dir.path().join("outside");
+        fs::create_dir_all(workspace.join("dest")).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, workspace.join("dest/link")).unwrap();
+        let ctx = context(&workspace);
+
+        let mut builder = ArchiveBuilder::new(ArchiveFormat::Tar);
+        builder.add_file("link/sub/pwned.txt", b"pwned".to_vec(), 0o644);
+        fs::write(workspace.join("a.tar"), builder.finish().unwrap()).unwrap();
+
+        let result = ArchiveTool::new()
+            .execute(
+                json!({ "action": "extract", "path": "a.tar", "destination": "dest" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap()
+            .contains("Blocked entry 'link/sub/pwned.txt'"));
+        assert!(!outside.join("sub").exists());
+    }
+
+    #[test]
+    fn test_required_permission_and_destination() {
+        let tool = ArchiveTool::new();
+        assert!(tool
+            .required_permission(&json!({ "action": "list", "path": "a.zip" }))
+            .is_none());
+        assert_eq!(
+            tool.required_permission(&json!({ "action": "extract", "path": "dl/pkg-1.0.tar.gz" })),
+            Some(PermissionAction::FileWrite {
+                path: "dl/pkg-1.0".into()
+            })
+        );
+        assert_eq!(
+            default_destination(Path::new("dist.zip")),
+            PathBuf::from("dist")
+        );
+        assert_eq!(
+            default_destination(Path::new("bundle")),
+            PathBuf::from("bundle_extracted")
+        );
+    }
+}
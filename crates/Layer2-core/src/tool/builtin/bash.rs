@@ -5,17 +5,18 @@
 //! - 금지 명령어 자동 차단
 //! - 타임아웃 지원
 //! - 작업 디렉토리 유지
+//! - 실행 중 출력 스트리밍 (ToolOutputSink, 줄 단위)
 
 use async_trait::async_trait;
 use forge_foundation::{
-    command_analyzer, CommandRisk, PermissionAction, PermissionDef, PermissionStatus, Result,
-    Tool, ToolContext, ToolMeta, ToolResult,
+    command_analyzer, CommandRisk, OutputControl, PermissionAction, PermissionDef,
+    PermissionStatus, Result, Tool, ToolContext, ToolMeta, ToolResult,
 };
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::process::Stdio;
 use std::time::Duration;
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::process::Command;
 use tokio::time::timeout;
 
@@ -114,6 +115,18 @@ impl BashTool {
     }
 }
 
+/// 한 줄 읽기 - EOF/에러면 reader를 닫고 None
+async fn read_line<R: AsyncRead + Unpin>(reader: &mut Option<BufReader<R>>) -> Option<Vec<u8>> {
+    let mut line = Vec::new();
+    match reader.as_mut()?.read_until(b'\n', &mut line).await {
+        Ok(n) if n > 0 => Some(line),
+        _ => {
+            *reader = None;
+            None
+        }
+    }
+}
+
 impl Default for BashTool {
     fn default() -> Self {
         Self::new()
@@ -322,29 +335,56 @@ impl Tool for BashTool {
         };
 
         // 타임아웃과 함께 실행
+        let sink = context.output_sink();
         let result = timeout(Duration::from_millis(timeout_ms), async {
             let mut stdout_buf = Vec::new();
             let mut stderr_buf = Vec::new();
+            let mut stdout = child.stdout.take().map(BufReader::new);
+            let mut stderr = child.stderr.take().map(BufReader::new);
+            let mut aborted = None;
+
+            // stdout/stderr를 줄 단위로 동시에 읽으며 수신자에 전달 (취소 시 중단)
+            while aborted.is_none() && (stdout.is_some() || stderr.is_some()) {
+                let (line, is_stderr) = tokio::select! {
+                    line = read_line(&mut stdout), if stdout.is_some() => (line, false),
+                    line = read_line(&mut stderr), if stderr.is_some() => (line, true),
+                    _ = context.cancelled() => {
+                        aborted = Some("cancelled".to_string());
+                        continue;
+                    }
+                };
+                let Some(line) = line else {
+                    continue;
+                };
+
+                if let Some(sink) = sink {
+                    let chunk = String::from_utf8_lossy(&line);
+                    if let OutputControl::Abort(reason) = sink.on_output(&chunk, is_stderr) {
+                        aborted = Some(reason);
+                    }
+                }
 
-            // stdout 읽기
-            if let Some(mut stdout) = child.stdout.take() {
-                let _ = stdout.read_to_end(&mut stdout_buf).await;
+                if is_stderr {
+                    stderr_buf.extend_from_slice(&line);
+                } else {
+                    stdout_buf.extend_from_slice(&line);
+                }
             }
 
-            // stderr 읽기
-            if let Some(mut stderr) = child.stderr.take() {
-                let _ = stderr.read_to_end(&mut stderr_buf).await;
+            // 중단 요청 시 프로세스 강제 종료
+            if aborted.is_some() {
+                let _ = child.kill().await;
             }
 
             // 프로세스 종료 대기
             let status = child.wait().await;
 
-            (status, stdout_buf, stderr_buf)
+            (status, stdout_buf, stderr_buf, aborted)
         })
         .await;
 
         match result {
-            Ok((status_result, stdout_buf, stderr_buf)) => {
+            Ok((status_result, stdout_buf, stderr_buf, aborted)) => {
                 let status = match status_result {
                     Ok(s) => s,
                     Err(e) => {
@@ -380,7 +420,12 @@ impl Tool for BashTool {
                 // 종료 코드 확인
                 let exit_code = status.code().unwrap_or(-1);
 
-                if status.success() {
+                if let Some(reason) = aborted {
+                    Ok(ToolResult::error(format!(
+                        "Command aborted: {}\n{}",
+                        reason, output
+                    )))
+                } else if status.success() {
                     if output.is_empty() {
                         Ok(ToolResult::success("[Command completed successfully with no output]"))
                     } else {
@@ -419,6 +464,38 @@ impl Tool for BashTool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tool::{DefaultShellConfig, RuntimeContext};
+    use forge_foundation::{PermissionService, ShellType, ToolOutputSink};
+    use std::sync::{Arc, Mutex};
+
+    /// 받은 줄을 기록하고 `abort_on`이 포함되면 중단
+    #[derive(Default)]
+    struct RecordingSink {
+        lines: Mutex<Vec<String>>,
+        abort_on: Option<&'static str>,
+    }
+
+    impl ToolOutputSink for RecordingSink {
+        fn on_output(&self, chunk: &str, _is_stderr: bool) -> OutputControl {
+            self.lines.lock().unwrap().push(chunk.to_string());
+            match self.abort_on {
+                Some(pattern) if chunk.contains(pattern) => {
+                    OutputControl::Abort(format!("matched '{}'", pattern))
+                }
+                _ => OutputControl::Continue,
+            }
+        }
+    }
+
+    fn context(sink: Arc<RecordingSink>) -> RuntimeContext {
+        RuntimeContext::new(
+            "test-session",
+            std::env::temp_dir(),
+            Arc::new(PermissionService::with_auto_approve()),
+        )
+        .with_shell_config(DefaultShellConfig::new().with_shell_type(ShellType::Bash))
+        .with_output_sink(Some(sink))
+    }
 
     #[test]
     fn test_meta() {
@@ -463,4 +540,71 @@ mod tests {
         let analysis = command_analyzer().analyze("vim file.txt");
         assert_eq!(analysis.risk, CommandRisk::Interactive);
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_cancellation_kills_process() {
+        let cancel = forge_foundation::CancellationToken::new();
+        let ctx = context(Arc::new(RecordingSink::default())).with_cancellation(cancel.clone());
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            cancel.cancel();
+        });
+
+        let start = std::time::Instant::now();
+        let result = BashTool::new()
+            .execute(json!({ "command": "sleep 30" }), &ctx)
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("aborted: cancelled"));
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_streams_output_lines() {
+        let sink = Arc::new(RecordingSink::default());
+        let result = BashTool::new()
+            .execute(
+                json!({ "command": "echo one; echo two >&2; echo three" }),
+                &context(sink.clone()),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(result.output.contains("one\nthree"));
+        assert!(result.output.contains("[stderr]\ntwo"));
+
+        let mut lines = sink.lines.lock().unwrap().clone();
+        lines.sort();
+        assert_eq!(lines, vec!["one\n", "three\n", "two\n"]);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_sink_aborts_command() {
+        let sink = Arc::new(RecordingSink {
+            abort_on: Some("error:"),
+            ..Default::default()
+        });
+        let start = std::time::Instant::now();
+        let result = BashTool::new()
+            .execute(
+                json!({ "command": "echo building; echo 'error: boom'; sleep 10; echo done" }),
+                &context(sink.clone()),
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let error = result.error.unwrap();
+        assert!(error.starts_with("Command aborted: matched 'error:'"));
+        assert!(error.contains("error: boom"));
+        let lines = sink.lines.lock().unwrap();
+        assert!(!lines.iter().any(|l| l.contains("done")));
+    }
 }
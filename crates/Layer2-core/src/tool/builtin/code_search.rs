@@ -0,0 +1,184 @@
+//! Code Search Tool - repomap 임베딩 기반 의미 검색
+//!
+//! 질의를 임베딩해 심볼/청크 인덱스에서 가까운 코드를 찾습니다.
+//! "인증은 어디서 처리하나"처럼 정확한 이름을 모를 때 `grep`보다 적합합니다.
+//!
+//! ```text
+//! 1. src/auth/session.rs:42-61  fn verify_session  (0.61)
+//!    pub fn verify_session(token: &str) -> Result<Session> {
+//!    ...
+//! ```
+
+use crate::repomap::{EmbeddingIndex, HashingEmbedder, RepoAnalyzer};
+use async_trait::async_trait;
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use serde_json::{json, Value};
+
+/// 기본 결과 수
+const DEFAULT_LIMIT: usize = 10;
+
+/// 최대 결과 수
+const MAX_LIMIT: usize = 50;
+
+/// 의미 기반 코드 검색 도구
+pub struct CodeSearchTool;
+
+impl CodeSearchTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CodeSearchTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for CodeSearchTool {
+    fn name(&self) -> &str {
+        "code_search"
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new("code_search")
+            .display_name("Code Search")
+            .description("Semantic search over the workspace's symbols and code chunks. Describe what you are looking for in words (e.g. \"where is auth handled\") and get ranked snippets with file:line references. Use grep instead for exact strings.")
+            .category("filesystem")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "Natural language description of the code to find"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Only search under this relative path prefix (e.g. \"src/auth\")"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of results (default: 10, max: 50)"
+                }
+            },
+            "required": ["query"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let query = match input["query"].as_str().map(str::trim) {
+            Some(query) if !query.is_empty() => query.to_string(),
+            _ => return Ok(ToolResult::error("Missing required parameter: query")),
+        };
+        let path = input["path"].as_str().map(String::from);
+        let limit = input["limit"]
+            .as_u64()
+            .map(|n| n as usize)
+            .unwrap_or(DEFAULT_LIMIT)
+            .clamp(1, MAX_LIMIT);
+
+        let map = RepoAnalyzer::with_defaults(ctx.working_dir())
+            .analyze()
+            .await?;
+        let (hits, indexed) = tokio::task::spawn_blocking(move || {
+            let embedder = HashingEmbedder::default();
+            let index = EmbeddingIndex::build(&map, &embedder);
+            let hits = index.search(&query, &embedder, path.as_deref(), limit);
+            (hits, index.len())
+        })
+        .await
+        .map_err(|e| forge_foundation::Error::Internal(format!("Code search failed: {}", e)))?;
+
+        if hits.is_empty() {
+            return Ok(ToolResult::success(format!(
+                "No matching code found ({} chunks indexed)",
+                indexed
+            )));
+        }
+
+        let mut output = Vec::new();
+        for (i, hit) in hits.iter().enumerate() {
+            let chunk = &hit.chunk;
+            let symbol = match (&chunk.kind, &chunk.symbol) {
+                (Some(kind), Some(symbol)) => format!("  {} {}", kind, symbol),
+                _ => String::new(),
+            };
+            output.push(format!(
+                "{}. {}:{}-{}{}  ({:.2})",
+                i + 1,
+                chunk.file,
+                chunk.line,
+                chunk.end_line,
+                symbol,
+                hit.score
+            ));
+            for line in chunk.snippet.lines() {
+                output.push(format!("   {}", line));
+            }
+            output.push(String::new());
+        }
+
+        let output = output.join("\n").trim_end().to_string();
+        Ok(ToolResult::success(output)
+            .with_metadata("indexed", json!(indexed))
+            .with_metadata("results", json!(hits)))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_code_search() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/auth.rs"),
+            "pub fn authenticate_user(password: &str) -> bool {\n    check_password(password)\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("src/table.rs"),
+            "pub fn render_table(rows: &[Row]) {\n    draw(rows)\n}\n",
+        )
+        .unwrap();
+
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let tool = CodeSearchTool::new();
+
+        let result = tool
+            .execute(json!({ "query": "where is authentication handled" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result
+            .output
+            .starts_with("1. src/auth.rs:1-3  fn authenticate_user"));
+        assert!(result
+            .output
+            .contains("   pub fn authenticate_user(password: &str) -> bool {"));
+
+        let result = tool.execute(json!({ "query": " " }), &ctx).await.unwrap();
+        assert!(!result.success);
+    }
+}
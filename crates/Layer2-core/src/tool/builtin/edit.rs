@@ -11,8 +11,8 @@
 
 use async_trait::async_trait;
 use forge_foundation::{
-    PermissionAction, PermissionDef, PermissionStatus, Result, Tool, ToolContext, ToolMeta,
-    ToolResult,
+    ConfirmationPrompt, PermissionAction, PermissionDef, PermissionStatus, PromptPreview, Result,
+    Tool, ToolContext, ToolMeta, ToolResult,
 };
 use serde::Deserialize;
 use serde_json::{json, Value};
@@ -20,6 +20,7 @@ use std::fs;
 use std::path::Path;
 use tracing::{debug, warn};
 
+use crate::tool::format::format_written_file;
 use crate::tool::security::{is_sensitive_path, PathValidator};
 
 /// Edit 도구 입력
@@ -128,7 +129,7 @@ impl EditTool {
     }
 
     /// 간단한 unified diff 생성
-    fn generate_diff(old_content: &str, new_content: &str, file_path: &str) -> String {
+    pub(crate) fn generate_diff(old_content: &str, new_content: &str, file_path: &str) -> String {
         let old_lines: Vec<&str> = old_content.lines().collect();
         let new_lines: Vec<&str> = new_content.lines().collect();
 
@@ -315,29 +316,6 @@ impl Tool for EditTool {
             )));
         }
 
-        // 권한 확인
-        if let Some(action) = self.required_permission(&input) {
-            let status = context.check_permission(Self::NAME, &action).await;
-            match status {
-                PermissionStatus::Denied => {
-                    return Ok(ToolResult::error("Permission denied for file edit"));
-                }
-                PermissionStatus::Unknown => {
-                    let granted = context
-                        .request_permission(
-                            Self::NAME,
-                            &format!("Edit file: {}", parsed.file_path),
-                            action,
-                        )
-                        .await?;
-                    if !granted {
-                        return Ok(ToolResult::error("Permission denied by user"));
-                    }
-                }
-                _ => {}
-            }
-        }
-
         // 파일 읽기
         let content = match fs::read_to_string(path) {
             Ok(c) => c,
@@ -411,6 +389,29 @@ impl Tool for EditTool {
         // Diff 생성 (dry_run이거나 항상)
         let diff_preview = Self::generate_diff(&content, &new_content, &parsed.file_path);
 
+        // 권한 확인 (diff를 미리보기로 표시)
+        if let Some(action) = self.required_permission(&input) {
+            let status = context.check_permission(Self::NAME, &action).await;
+            match status {
+                PermissionStatus::Denied => {
+                    return Ok(ToolResult::error("Permission denied for file edit"));
+                }
+                PermissionStatus::Unknown => {
+                    let prompt = ConfirmationPrompt::for_action(
+                        Self::NAME,
+                        &action,
+                        &format!("Edit file: {}", parsed.file_path),
+                        5,
+                    )
+                    .with_preview(PromptPreview::diff(&parsed.file_path, &diff_preview));
+                    if !context.request_confirmation(prompt).await? {
+                        return Ok(ToolResult::error("Permission denied by user"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
         // dry_run 모드면 diff만 반환
         if parsed.dry_run {
             return Ok(ToolResult::success(format!(
@@ -459,6 +460,18 @@ impl Tool for EditTool {
                 // 변경 내용 요약 추가
                 result_msg.push_str(&format!("\n\nChanges:\n{}", diff_preview));
 
+                // 프로젝트 설정의 자동 포맷
+                let (new_content, format_note) =
+                    format_written_file(context, path, &new_content).await;
+                if let Some(note) = format_note {
+                    result_msg.push_str(&format!("\n\n{}", note));
+                }
+
+                // 실행 중인 언어 서버에 변경 알림
+                if let Some(sync) = context.document_sync() {
+                    sync.file_changed(path, &new_content).await;
+                }
+
                 Ok(ToolResult::success(result_msg))
             }
             Err(e) => {
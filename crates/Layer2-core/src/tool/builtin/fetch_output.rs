@@ -0,0 +1,171 @@
+//! Fetch Full Output Tool - 잘린 도구 출력 전체 조회
+//!
+//! `OutputGovernor`가 잘라낸 도구 결과의 전체 내용을 줄 단위로 다시 읽습니다.
+//! 마커에 표시된 ID(`out-N`)를 사용합니다.
+
+use crate::tool::governor::{full_output, FETCH_FULL_OUTPUT_TOOL};
+use async_trait::async_trait;
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use serde_json::{json, Value};
+
+/// 기본 조회 줄 수
+const DEFAULT_LIMIT: usize = 500;
+
+/// 한 번에 반환할 최대 문자 수
+const MAX_CHARS: usize = 25_000;
+
+/// 잘린 출력 전체 조회 도구
+pub struct FetchFullOutputTool;
+
+impl FetchFullOutputTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for FetchFullOutputTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for FetchFullOutputTool {
+    fn name(&self) -> &str {
+        FETCH_FULL_OUTPUT_TOOL
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(FETCH_FULL_OUTPUT_TOOL)
+            .display_name("Fetch Full Output")
+            .description("Retrieve the full text of a truncated tool result by its id (shown in the truncation marker), paginated by line.")
+            .category("filesystem")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "string",
+                    "description": "Output id from the truncation marker (e.g. \"out-3\")"
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "Line number to start from (1-based, default: 1)"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Number of lines to return (default: 500)"
+                }
+            },
+            "required": ["id"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, _ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let Some(id) = input["id"].as_str() else {
+            return Ok(ToolResult::error("Missing required parameter: id"));
+        };
+        let Some(output) = full_output(id) else {
+            return Ok(ToolResult::error(format!(
+                "Output not found: {} (only recent outputs are kept)",
+                id
+            )));
+        };
+
+        let offset = input["offset"]
+            .as_u64()
+            .map(|n| n as usize)
+            .unwrap_or(1)
+            .max(1);
+        let limit = input["limit"]
+            .as_u64()
+            .map(|n| n as usize)
+            .unwrap_or(DEFAULT_LIMIT)
+            .max(1);
+
+        let total = output.lines().count();
+        let mut body = String::new();
+        let mut last = offset - 1;
+        for (index, line) in output.lines().enumerate().skip(offset - 1).take(limit) {
+            if body.len() + line.len() > MAX_CHARS && !body.is_empty() {
+                break;
+            }
+            body.push_str(&format!("{:>6}\t{}\n", index + 1, line));
+            last = index + 1;
+        }
+
+        let mut result = format!("[{}] lines {}-{} of {}\n\n", id, offset, last, total);
+        if last < offset {
+            result.push_str("(no lines in range)");
+        } else {
+            result.push_str(&body);
+            if last < total {
+                result.push_str(&format!(
+                    "\n... {} more lines. Continue with offset={}",
+                    total - last,
+                    last + 1
+                ));
+            }
+        }
+
+        Ok(ToolResult::success(result)
+            .with_metadata("total_lines", json!(total))
+            .with_metadata("last_line", json!(last)))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::governor::OutputGovernor;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn ctx() -> RuntimeContext {
+        RuntimeContext::new(
+            "test",
+            PathBuf::from("."),
+            Arc::new(PermissionService::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fetch_truncated_output() {
+        let output: String = (1..=2000).map(|i| format!("row {}\n", i)).collect();
+        let truncated = OutputGovernor::new(1000).apply("bash", output);
+        let start = truncated.find("(id=\"").unwrap() + 5;
+        let id = &truncated[start..start + truncated[start..].find('"').unwrap()];
+
+        let tool = FetchFullOutputTool::new();
+        let result = tool
+            .execute(json!({ "id": id, "offset": 1000, "limit": 2 }), &ctx())
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("lines 1000-1001 of 2000"));
+        assert!(result.output.contains("  1000\trow 1000\n"));
+        assert!(result.output.contains("Continue with offset=1002"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_unknown_id() {
+        let tool = FetchFullOutputTool::new();
+        let result = tool
+            .execute(json!({ "id": "out-missing" }), &ctx())
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+}
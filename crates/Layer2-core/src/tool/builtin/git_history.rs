@@ -0,0 +1,384 @@
+//! Git History Tools - blame / log 조회
+//!
+//! 코드를 고치기 전에 "왜 이렇게 되어 있는지"를 확인할 수 있도록
+//! 줄/범위별 작성자, 날짜, 커밋 제목을 구조화해 돌려줍니다.
+//! 의도된 변경(버그 수정, 우회 코드)을 모르고 되돌리는 일을 줄입니다.
+//!
+//! - `git_blame`: 파일(또는 줄 범위)의 마지막 변경 커밋을 범위 단위로 묶어 표시
+//! - `git_log`: 저장소/파일/줄 범위의 커밋 이력 (본문 포함)
+//!
+//! 두 도구 모두 읽기 전용이라 권한이 필요 없습니다.
+
+use crate::git::{group_blame, GitOps, LogEntry};
+use async_trait::async_trait;
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use serde_json::{json, Value};
+
+/// `git_log` 기본 커밋 수
+const DEFAULT_LOG_LIMIT: usize = 10;
+
+/// `git_log` 최대 커밋 수
+const MAX_LOG_LIMIT: usize = 100;
+
+/// 커밋 본문 최대 줄 수
+const MAX_BODY_LINES: usize = 12;
+
+/// `git_blame` 최대 범위 수
+const MAX_BLAME_RANGES: usize = 200;
+
+/// `start_line`/`end_line` 입력 → 줄 범위 (둘 다 없으면 None)
+fn line_range(input: &Value) -> std::result::Result<Option<(usize, usize)>, String> {
+    let start = input["start_line"].as_u64().map(|n| n as usize);
+    let end = input["end_line"].as_u64().map(|n| n as usize);
+    let (start, end) = match (start, end) {
+        (None, None) => return Ok(None),
+        (Some(start), None) => (start, start),
+        (None, Some(end)) => (1, end),
+        (Some(start), Some(end)) => (start, end),
+    };
+    if start == 0 || end < start {
+        return Err(format!("Invalid line range {}-{}", start, end));
+    }
+    Ok(Some((start, end)))
+}
+
+/// 범위 표시 (`src/lib.rs:10-20`)
+fn describe_target(path: &str, lines: Option<(usize, usize)>) -> String {
+    match lines {
+        Some((start, end)) if start == end => format!("{}:{}", path, start),
+        Some((start, end)) => format!("{}:{}-{}", path, start, end),
+        None => path.to_string(),
+    }
+}
+
+/// 커밋 한 개 표시 (본문은 들여쓰기)
+fn render_commit(entry: &LogEntry) -> String {
+    let mut out = format!(
+        "{} {} {}: {}",
+        entry.short_hash,
+        entry.date.get(..10).unwrap_or(&entry.date),
+        entry.author_name,
+        entry.message
+    );
+    let body: Vec<&str> = entry.body.lines().collect();
+    for line in body.iter().take(MAX_BODY_LINES) {
+        out.push_str("\n    ");
+        out.push_str(line);
+    }
+    if body.len() > MAX_BODY_LINES {
+        out.push_str("\n    …");
+    }
+    out
+}
+
+// ============================================================================
+// git_blame
+// ============================================================================
+
+/// 줄별 마지막 변경 커밋 조회 도구
+pub struct GitBlameTool;
+
+impl GitBlameTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "git_blame";
+
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GitBlameTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for GitBlameTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Git Blame")
+            .description("Show who last changed each part of a file and why: ranges of lines with commit, date, author and commit subject. Use before changing code that looks odd or intentional; follow up with git_log on a range to read the full commit messages.")
+            .category("git")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "File to blame (relative to the working directory)"
+                },
+                "start_line": {
+                    "type": "integer",
+                    "description": "First line (1-based, default: start of file)"
+                },
+                "end_line": {
+                    "type": "integer",
+                    "description": "Last line (inclusive, default: start_line or end of file)"
+                }
+            },
+            "required": ["path"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let Some(path) = input["path"].as_str().filter(|p| !p.is_empty()) else {
+            return Ok(ToolResult::error("path is required"));
+        };
+        let lines = match line_range(&input) {
+            Ok(lines) => lines,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        let blame = GitOps::new(context.working_dir())
+            .and_then(|git| git.blame(&context.working_dir().join(path), lines));
+        let blame = match blame {
+            Ok(blame) => blame,
+            Err(e) => return Ok(ToolResult::error(e.to_string())),
+        };
+        if blame.is_empty() {
+            return Ok(ToolResult::success(format!(
+                "No lines to blame in {}",
+                describe_target(path, lines)
+            )));
+        }
+
+        let ranges = group_blame(&blame);
+        let mut commits: Vec<&str> = ranges.iter().map(|r| r.commit.as_str()).collect();
+        commits.sort_unstable();
+        commits.dedup();
+
+        let mut output = format!(
+            "{} ({} line{}, {} commit{})\n",
+            describe_target(path, lines),
+            blame.len(),
+            if blame.len() == 1 { "" } else { "s" },
+            commits.len(),
+            if commits.len() == 1 { "" } else { "s" }
+        );
+        for range in ranges.iter().take(MAX_BLAME_RANGES) {
+            output.push('\n');
+            output.push_str(&range.summary_line());
+        }
+        if ranges.len() > MAX_BLAME_RANGES {
+            output.push_str(&format!(
+                "\n\n... {} more ranges (narrow with start_line/end_line)",
+                ranges.len() - MAX_BLAME_RANGES
+            ));
+        }
+
+        let shown = &ranges[..ranges.len().min(MAX_BLAME_RANGES)];
+        Ok(ToolResult::success(output).with_metadata("ranges", json!(shown)))
+    }
+}
+
+// ============================================================================
+// git_log
+// ============================================================================
+
+/// 커밋 이력 조회 도구
+pub struct GitLogTool;
+
+impl GitLogTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "git_log";
+
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GitLogTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for GitLogTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Git Log")
+            .description("Show commit history with full commit messages: of the repository, of a file (following renames), or of a line range of a file (traces how those lines evolved). Use to understand why code is the way it is before changing it.")
+            .category("git")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Only commits touching this file (relative to the working directory)"
+                },
+                "start_line": {
+                    "type": "integer",
+                    "description": "With path: only commits that changed lines from here (1-based)"
+                },
+                "end_line": {
+                    "type": "integer",
+                    "description": "With path: last line of the range (inclusive)"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of commits (default: 10, max: 100)"
+                }
+            }
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let path = input["path"].as_str().filter(|p| !p.is_empty());
+        let lines = match line_range(&input) {
+            Ok(lines) => lines,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+        if lines.is_some() && path.is_none() {
+            return Ok(ToolResult::error("start_line/end_line require a path"));
+        }
+        let limit = input["limit"]
+            .as_u64()
+            .map_or(DEFAULT_LOG_LIMIT, |n| n as usize)
+            .clamp(1, MAX_LOG_LIMIT);
+
+        let log = GitOps::new(context.working_dir()).and_then(|git| match path {
+            Some(path) => git.file_log(&context.working_dir().join(path), lines, limit),
+            None => git.log(limit),
+        });
+        let log = match log {
+            Ok(log) => log,
+            Err(e) => return Ok(ToolResult::error(e.to_string())),
+        };
+
+        let target = path
+            .map(|p| describe_target(p, lines))
+            .unwrap_or_else(|| "repository".to_string());
+        if log.is_empty() {
+            return Ok(ToolResult::success(format!("No commits for {}", target)));
+        }
+
+        let mut output = format!(
+            "{} commit{} for {} (newest first)\n",
+            log.len(),
+            if log.len() == 1 { "" } else { "s" },
+            target
+        );
+        for entry in &log {
+            output.push('\n');
+            output.push_str(&render_commit(entry));
+        }
+
+        Ok(ToolResult::success(output).with_metadata("commits", json!(log)))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::process::Command;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_line_range() {
+        assert_eq!(line_range(&json!({})).unwrap(), None);
+        assert_eq!(line_range(&json!({"start_line": 4})).unwrap(), Some((4, 4)));
+        assert_eq!(line_range(&json!({"end_line": 3})).unwrap(), Some((1, 3)));
+        assert!(line_range(&json!({"start_line": 5, "end_line": 2})).is_err());
+        assert!(line_range(&json!({"start_line": 0})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_blame_and_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args([
+                    "-c",
+                    "user.name=Alice",
+                    "-c",
+                    "user.email=alice@example.com",
+                ])
+                .args(args)
+                .current_dir(dir.path())
+                .status()
+                .is_ok_and(|s| s.success())
+        };
+        if !git(&["init", "-q"]) {
+            return;
+        }
+        std::fs::write(dir.path().join("a.rs"), "fn a() {}\nfn b() {}\n").unwrap();
+        git(&["add", "a.rs"]);
+        git(&["commit", "-q", "-m", "Add a and b"]);
+        std::fs::write(dir.path().join("a.rs"), "fn a() {}\nfn b() { 1 }\n").unwrap();
+        git(&[
+            "commit",
+            "-q",
+            "-am",
+            "Return 1 from b",
+            "-m",
+            "Callers rely on it being non-zero.",
+        ]);
+
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let result = GitBlameTool::new()
+            .execute(json!({"path": "a.rs"}), &ctx)
+            .await
+            .unwrap();
+        let output = result.output;
+        assert!(
+            output.starts_with("a.rs (2 lines, 2 commits)"),
+            "{}",
+            output
+        );
+        assert!(output.contains("Alice: Add a and b"));
+        assert!(output.contains("L2 "));
+
+        let result = GitLogTool::new()
+            .execute(json!({"path": "a.rs", "start_line": 2}), &ctx)
+            .await
+            .unwrap();
+        let output = result.output;
+        assert!(output.starts_with("2 commits for a.rs:2"), "{}", output);
+        assert!(output.contains("Alice: Return 1 from b\n    Callers rely on it being non-zero."));
+
+        let result = GitLogTool::new()
+            .execute(json!({"limit": 1}), &ctx)
+            .await
+            .unwrap();
+        assert!(result.output.starts_with("1 commit for repository"));
+
+        let result = GitLogTool::new()
+            .execute(json!({"start_line": 1}), &ctx)
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("require a path"));
+    }
+}
@@ -1,13 +1,13 @@
 //! Glob Tool - 파일 패턴 검색 도구
 //!
 //! 글로브 패턴으로 파일을 검색합니다.
-//! - gitignore 존중
+//! - `.gitignore` / `.forgeignore` 존중 (`walk` 모듈 참고)
 //! - 수정 시간 정렬
 //! - 결과 제한
 
+use super::walk::workspace_walker;
 use async_trait::async_trait;
 use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
-use ignore::WalkBuilder;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::path::Path;
@@ -172,13 +172,8 @@ impl Tool for GlobTool {
 
         let limit = parsed.limit.unwrap_or(Self::DEFAULT_LIMIT);
 
-        // ignore 라이브러리로 gitignore 존중하면서 검색
-        let walker = WalkBuilder::new(&search_path)
-            .hidden(false) // 숨김 파일도 검색
-            .git_ignore(true) // .gitignore 존중
-            .git_global(true) // 전역 gitignore 존중
-            .git_exclude(true) // .git/info/exclude 존중
-            .build();
+        // ignore 라이브러리로 .gitignore/.forgeignore 존중하면서 검색
+        let walker = workspace_walker(&search_path).build();
 
         let mut matches: Vec<(String, Option<SystemTime>)> = Vec::new();
 
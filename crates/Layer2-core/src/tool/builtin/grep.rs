@@ -1,22 +1,26 @@
 //! Grep Tool - 고성능 병렬 내용 검색 도구
 //!
 //! 정규식으로 파일 내용을 검색합니다.
-//! - **rayon 병렬 처리**: 멀티코어 활용으로 4-8배 성능 향상
-//! - ripgrep 스타일 출력
+//! - **ripgrep 우선**: `rg`가 설치되어 있으면 `rg --json`으로 검색, 없으면 내장 검색
+//! - **rayon 병렬 처리**: 내장 검색은 멀티코어 활용으로 4-8배 성능 향상
+//! - `.gitignore` / `.forgeignore` 존중 (`walk` 모듈 참고)
+//! - ripgrep 스타일 출력, `json` 모드는 구조화된 매치 (file, line, column, snippet)
+//! - 파일당 매치 수 제한 (`max_per_file`) - 모노레포에서 한 파일이 결과를 채우지 않도록
 //! - 컨텍스트 라인 지원
 //! - 파일 타입 필터
 
+use super::walk::{workspace_walker, FORGE_IGNORE_FILENAME};
 use async_trait::async_trait;
 use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
-use ignore::WalkBuilder;
 use rayon::prelude::*;
 use regex::Regex;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use parking_lot::Mutex;
 
 /// Grep 도구 입력
@@ -66,6 +70,10 @@ pub struct GrepInput {
     /// 멀티라인 모드 (기본: false)
     #[serde(default)]
     pub multiline: bool,
+
+    /// 파일당 최대 매치 수 (기본: 20, content/json 모드)
+    #[serde(default)]
+    pub max_per_file: Option<usize>,
 }
 
 fn default_output_mode() -> String {
@@ -78,7 +86,8 @@ struct MatchResult {
     file_path: String,
     line_num: usize,
     line_content: String,
-    is_match: bool, // 실제 매치인지 컨텍스트인지
+    is_match: bool,        // 실제 매치인지 컨텍스트인지
+    column: Option<usize>, // 첫 매치의 열 (1부터, 바이트 기준)
 }
 
 /// 파일별 검색 결과
@@ -87,6 +96,40 @@ struct FileSearchResult {
     file_path: String,
     matches: Vec<MatchResult>,
     match_count: usize,
+    /// `max_per_file` 때문에 생략된 매치가 있는지
+    capped: bool,
+}
+
+/// 검색 엔진
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchEngine {
+    Ripgrep,
+    Internal,
+}
+
+impl SearchEngine {
+    fn name(&self) -> &'static str {
+        match self {
+            SearchEngine::Ripgrep => "ripgrep",
+            SearchEngine::Internal => "internal",
+        }
+    }
+}
+
+/// 검색 옵션 (두 엔진 공용)
+struct SearchOptions<'a> {
+    regex: &'a Regex,
+    pattern: &'a str,
+    ignore_case: bool,
+    multiline: bool,
+    file_type: Option<&'a str>,
+    glob: Option<&'a str>,
+    before: usize,
+    after: usize,
+    /// 최대 파일 수
+    limit: usize,
+    /// 파일당 최대 매치 수
+    max_per_file: usize,
 }
 
 /// Grep 도구
@@ -104,15 +147,22 @@ impl GrepTool {
     /// 기본 결과 제한
     const DEFAULT_HEAD_LIMIT: usize = 100;
 
+    /// 기본 파일당 매치 제한
+    const DEFAULT_MAX_PER_FILE: usize = 20;
+
     /// 최대 파일 크기 (50MB) - 큰 파일은 건너뜀
     const MAX_FILE_SIZE: u64 = 50 * 1024 * 1024;
 
+    /// json 모드 snippet 최대 길이
+    const MAX_SNIPPET_CHARS: usize = 200;
+
     /// 파일 검색 (단일 파일)
     fn search_file(
         path: &Path,
         regex: &Regex,
         before: usize,
         after: usize,
+        max_per_file: usize,
     ) -> Option<FileSearchResult> {
         // 파일 크기 확인
         if let Ok(metadata) = fs::metadata(path) {
@@ -131,10 +181,15 @@ impl GrepTool {
         let mut results = Vec::new();
         let mut context_lines: std::collections::HashSet<usize> = std::collections::HashSet::new();
 
-        // 먼저 매치되는 라인 찾기
+        // 먼저 매치되는 라인 찾기 (파일당 제한 이후는 개수만 셈)
         let mut match_lines: Vec<usize> = Vec::new();
+        let mut match_count = 0;
         for (i, line) in lines.iter().enumerate() {
             if regex.is_match(line) {
+                match_count += 1;
+                if match_lines.len() >= max_per_file {
+                    continue;
+                }
                 match_lines.push(i);
                 // 컨텍스트 라인 추가
                 let start = i.saturating_sub(before);
@@ -150,16 +205,22 @@ impl GrepTool {
         }
 
         let file_path = path.display().to_string();
-        let match_count = match_lines.len();
+        let capped = match_count > match_lines.len();
 
         // 결과 생성
         for i in 0..lines.len() {
             if context_lines.contains(&i) {
+                let is_match = match_lines.contains(&i);
                 results.push(MatchResult {
                     file_path: file_path.clone(),
                     line_num: i + 1,
                     line_content: lines[i].to_string(),
-                    is_match: match_lines.contains(&i),
+                    is_match,
+                    column: if is_match {
+                        regex.find(lines[i]).map(|m| m.start() + 1)
+                    } else {
+                        None
+                    },
                 });
             }
         }
@@ -168,9 +229,154 @@ impl GrepTool {
             file_path,
             matches: results,
             match_count,
+            capped,
+        })
+    }
+
+    /// ripgrep 사용 가능 여부 (한 번만 확인)
+    fn ripgrep_available() -> bool {
+        static AVAILABLE: OnceLock<bool> = OnceLock::new();
+        *AVAILABLE.get_or_init(|| {
+            Command::new("rg")
+                .arg("--version")
+                .output()
+                .map(|output| output.status.success())
+                .unwrap_or(false)
         })
     }
 
+    /// ripgrep 검색 (`rg --json`) - 실행 실패 시 None (내장 검색으로 대체)
+    fn ripgrep_search(
+        search_path: &Path,
+        options: &SearchOptions<'_>,
+    ) -> Option<(Vec<FileSearchResult>, bool)> {
+        let mut cmd = Command::new("rg");
+        cmd.args(["--json", "--hidden", "--no-require-git", "--glob", "!.git"]);
+        cmd.arg("--max-count").arg(options.max_per_file.to_string());
+        if options.ignore_case {
+            cmd.arg("--ignore-case");
+        }
+        if options.multiline {
+            cmd.args(["--multiline", "--multiline-dotall"]);
+        }
+        if options.before > 0 {
+            cmd.arg("--before-context").arg(options.before.to_string());
+        }
+        if options.after > 0 {
+            cmd.arg("--after-context").arg(options.after.to_string());
+        }
+        if let Some(file_type) = options.file_type {
+            cmd.arg("--glob").arg(format!("*.{}", file_type));
+        }
+        if let Some(glob) = options.glob {
+            cmd.arg("--glob").arg(glob);
+        }
+
+        // rg에는 사용자 정의 무시 파일 이름 옵션이 없어 루트의 .forgeignore만 전달
+        let root = if search_path.is_dir() {
+            search_path
+        } else {
+            search_path.parent().unwrap_or(search_path)
+        };
+        let forge_ignore = root.join(FORGE_IGNORE_FILENAME);
+        if forge_ignore.is_file() {
+            cmd.arg("--ignore-file").arg(forge_ignore);
+        }
+
+        cmd.arg("--regexp")
+            .arg(options.pattern)
+            .arg("--")
+            .arg(search_path);
+
+        let output = cmd.current_dir(root).output().ok()?;
+        // 0: 매치 있음, 1: 매치 없음, 2: 오류 (일부 파일 읽기 실패도 2)
+        if output.status.code() == Some(2) && output.stdout.is_empty() {
+            tracing::debug!(
+                "ripgrep failed, falling back: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            return None;
+        }
+
+        Some(Self::parse_ripgrep_json(
+            &String::from_utf8_lossy(&output.stdout),
+            options.limit,
+            options.max_per_file,
+        ))
+    }
+
+    /// `rg --json` 출력 파싱 (파일 수가 limit을 넘으면 잘림 표시)
+    fn parse_ripgrep_json(
+        output: &str,
+        limit: usize,
+        max_per_file: usize,
+    ) -> (Vec<FileSearchResult>, bool) {
+        let mut results: Vec<FileSearchResult> = Vec::new();
+        let mut truncated = false;
+
+        for line in output.lines() {
+            let Ok(message) = serde_json::from_str::<Value>(line) else {
+                continue;
+            };
+            let kind = message["type"].as_str().unwrap_or("");
+            let data = &message["data"];
+            let Some(file_path) = data["path"]["text"].as_str() else {
+                continue;
+            };
+
+            match kind {
+                "begin" => {
+                    if results.len() >= limit {
+                        truncated = true;
+                        break;
+                    }
+                    results.push(FileSearchResult {
+                        file_path: file_path.to_string(),
+                        matches: Vec::new(),
+                        match_count: 0,
+                        capped: false,
+                    });
+                }
+                "match" | "context" => {
+                    let Some(current) = results.last_mut() else {
+                        continue;
+                    };
+                    let is_match = kind == "match";
+                    let first_line = data["line_number"].as_u64().unwrap_or(0) as usize;
+                    let text = data["lines"]["text"].as_str().unwrap_or("");
+                    if is_match {
+                        current.match_count += 1;
+                    }
+                    // 멀티라인 매치는 여러 줄로 분리 (첫 줄에만 열 표시)
+                    for (offset, content) in text.lines().enumerate() {
+                        current.matches.push(MatchResult {
+                            file_path: file_path.to_string(),
+                            line_num: first_line + offset,
+                            line_content: content.to_string(),
+                            is_match,
+                            column: if is_match && offset == 0 {
+                                data["submatches"][0]["start"]
+                                    .as_u64()
+                                    .map(|start| start as usize + 1)
+                            } else {
+                                None
+                            },
+                        });
+                    }
+                }
+                "end" => {
+                    // --max-count에 걸리면 rg가 읽기를 멈추므로 정확한 총 개수는 알 수 없음
+                    if let Some(current) = results.last_mut() {
+                        current.capped = current.match_count >= max_per_file;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        (results, truncated)
+    }
+
     /// 확장자가 매칭되는지 확인
     fn matches_type(path: &Path, file_type: &str) -> bool {
         path.extension()
@@ -185,23 +391,28 @@ impl GrepTool {
         pattern.matches(&path_str) || pattern.matches(&path_str.replace('\\', "/"))
     }
 
+    /// json 모드 snippet (앞뒤 공백 제거, 길이 제한)
+    fn snippet(line: &str) -> String {
+        let line = line.trim();
+        if line.chars().count() <= Self::MAX_SNIPPET_CHARS {
+            return line.to_string();
+        }
+        let truncated: String = line.chars().take(Self::MAX_SNIPPET_CHARS).collect();
+        format!("{}...", truncated)
+    }
+
     /// 병렬 디렉토리 검색
     fn parallel_search(
         search_path: &Path,
-        regex: &Regex,
-        file_type: Option<&str>,
-        glob_pattern: Option<&glob::Pattern>,
-        before: usize,
-        after: usize,
-        limit: usize,
+        options: &SearchOptions<'_>,
     ) -> (Vec<FileSearchResult>, bool) {
+        let glob_pattern = options.glob.and_then(|g| glob::Pattern::new(g).ok());
+        let glob_pattern = glob_pattern.as_ref();
+        let file_type = options.file_type;
+        let limit = options.limit;
+
         // 먼저 파일 목록 수집
-        let walker = WalkBuilder::new(search_path)
-            .hidden(false)
-            .git_ignore(true)
-            .git_global(true)
-            .git_exclude(true)
-            .build();
+        let walker = workspace_walker(search_path).build();
 
         let files: Vec<PathBuf> = walker
             .filter_map(|entry| entry.ok())
@@ -237,7 +448,13 @@ impl GrepTool {
                 return;
             }
 
-            if let Some(result) = Self::search_file(path, regex, before, after) {
+            if let Some(result) = Self::search_file(
+                path,
+                options.regex,
+                options.before,
+                options.after,
+                options.max_per_file,
+            ) {
                 let mut results_guard = results.lock();
                 if found_count.load(Ordering::Relaxed) < limit {
                     found_count.fetch_add(1, Ordering::Relaxed);
@@ -313,8 +530,8 @@ impl Tool for GrepTool {
                 },
                 "output_mode": {
                     "type": "string",
-                    "enum": ["content", "files_with_matches", "count"],
-                    "description": "Output mode: 'content' shows matching lines, 'files_with_matches' shows file paths (default), 'count' shows match counts"
+                    "enum": ["content", "files_with_matches", "count", "json"],
+                    "description": "Output mode: 'content' shows matching lines, 'files_with_matches' shows file paths (default), 'count' shows match counts, 'json' returns structured matches (file, line, column, snippet)"
                 },
                 "head_limit": {
                     "type": "number",
@@ -323,6 +540,10 @@ impl Tool for GrepTool {
                 "multiline": {
                     "type": "boolean",
                     "description": "Enable multiline mode where . matches newlines"
+                },
+                "max_per_file": {
+                    "type": "number",
+                    "description": "Maximum matches to show per file in content/json modes (default: 20)"
                 }
             },
             "required": ["pattern"]
@@ -350,6 +571,7 @@ impl Tool for GrepTool {
                 output_mode: "files_with_matches".to_string(),
                 head_limit: None,
                 multiline: false,
+                max_per_file: None,
             },
             // 객체 입력
             Value::Object(obj) => {
@@ -373,6 +595,7 @@ impl Tool for GrepTool {
                             output_mode: obj.get("output_mode").and_then(|v| v.as_str()).unwrap_or("files_with_matches").to_string(),
                             head_limit: obj.get("head_limit").and_then(|v| v.as_u64().map(|n| n as usize)),
                             multiline: obj.get("multiline").and_then(|v| v.as_bool()).unwrap_or(false),
+                            max_per_file: obj.get("max_per_file").and_then(|v| v.as_u64().map(|n| n as usize)),
                         }
                     } else {
                         return Ok(ToolResult::error("Invalid input: please provide a 'pattern' field with a regex pattern. Example: {\"pattern\": \"TODO\"}"));
@@ -429,41 +652,56 @@ impl Tool for GrepTool {
             )));
         }
 
-        // 글로브 패턴 컴파일
-        let glob_pattern = parsed
-            .glob
-            .as_ref()
-            .and_then(|g| glob::Pattern::new(g).ok());
-
         // 컨텍스트 라인 수 결정
         let before = parsed.before.or(parsed.context).unwrap_or(0);
         let after = parsed.after.or(parsed.context).unwrap_or(0);
         let limit = parsed.head_limit.unwrap_or(Self::DEFAULT_HEAD_LIMIT);
 
-        let file_results: Vec<FileSearchResult>;
-        let mut truncated = false;
+        // 파일당 매치 제한 (파일 목록은 첫 매치, 개수는 전체가 필요)
+        let max_per_file = match parsed.output_mode.as_str() {
+            "files_with_matches" => 1,
+            "count" => usize::MAX,
+            _ => parsed
+                .max_per_file
+                .unwrap_or(Self::DEFAULT_MAX_PER_FILE)
+                .max(1),
+        };
 
-        // 단일 파일 vs 디렉토리
-        if search_path.is_file() {
-            if let Some(result) = Self::search_file(&search_path, &regex, before, after) {
-                file_results = vec![result];
-            } else {
-                file_results = vec![];
-            }
+        let options = SearchOptions {
+            regex: &regex,
+            pattern: &parsed.pattern,
+            ignore_case: parsed.ignore_case,
+            multiline: parsed.multiline,
+            file_type: parsed.file_type.as_deref(),
+            glob: parsed.glob.as_deref(),
+            before,
+            after,
+            limit,
+            max_per_file,
+        };
+
+        let rg_results = if Self::ripgrep_available() {
+            Self::ripgrep_search(&search_path, &options)
         } else {
-            // 병렬 디렉토리 검색
-            let (results, was_limited) = Self::parallel_search(
-                &search_path,
-                &regex,
-                parsed.file_type.as_deref(),
-                glob_pattern.as_ref(),
-                before,
-                after,
-                limit,
-            );
-            file_results = results;
-            truncated = was_limited;
-        }
+            None
+        };
+
+        let (file_results, mut truncated, engine) = match rg_results {
+            Some((results, truncated)) => (results, truncated, SearchEngine::Ripgrep),
+            // 단일 파일 vs 디렉토리
+            None if search_path.is_file() => {
+                let results =
+                    Self::search_file(&search_path, &regex, before, after, max_per_file)
+                        .into_iter()
+                        .collect();
+                (results, false, SearchEngine::Internal)
+            }
+            None => {
+                // 병렬 디렉토리 검색
+                let (results, was_limited) = Self::parallel_search(&search_path, &options);
+                (results, was_limited, SearchEngine::Internal)
+            }
+        };
 
         // 출력 모드에 따라 결과 포맷
         let output = match parsed.output_mode.as_str() {
@@ -499,6 +737,13 @@ impl Tool for GrepTool {
                         total_lines += 1;
                     }
 
+                    if file_result.capped {
+                        output_lines.push(format!(
+                            "{}: match limit reached, more matches may exist (max_per_file={})",
+                            file_result.file_path, max_per_file
+                        ));
+                    }
+
                     if !output_lines.is_empty() {
                         output_lines.push("".to_string()); // 파일 간 빈 줄
                     }
@@ -506,6 +751,27 @@ impl Tool for GrepTool {
 
                 output_lines.join("\n")
             }
+            "json" => {
+                // 구조화된 매치 (컨텍스트 라인 제외)
+                let matches: Vec<Value> = file_results
+                    .iter()
+                    .flat_map(|r| r.matches.iter().filter(|m| m.is_match))
+                    .take(limit * 10)
+                    .map(|m| {
+                        json!({
+                            "file": m.file_path,
+                            "line": m.line_num,
+                            "column": m.column.unwrap_or(1),
+                            "snippet": Self::snippet(&m.line_content),
+                        })
+                    })
+                    .collect();
+                if matches.is_empty() {
+                    String::new()
+                } else {
+                    serde_json::to_string_pretty(&matches).unwrap_or_default()
+                }
+            }
             "count" => {
                 // 파일별 매치 수
                 let mut counts: Vec<_> = file_results
@@ -545,7 +811,9 @@ impl Tool for GrepTool {
                     limit
                 ));
             }
-            Ok(ToolResult::success(result))
+            Ok(ToolResult::success(result)
+                .with_metadata("engine", json!(engine.name()))
+                .with_metadata("files", json!(file_results.len())))
         }
     }
 }
@@ -601,4 +869,86 @@ mod tests {
         let content = "fn test() {\n    // body\n}";
         assert!(regex.is_match(content));
     }
+
+    #[test]
+    fn test_search_file_max_per_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        let content: String = (0..50)
+            .map(|i| format!("let x{} = todo!();\n", i))
+            .collect();
+        fs::write(&path, content).unwrap();
+
+        let regex = Regex::new("todo").unwrap();
+        let result = GrepTool::search_file(&path, &regex, 0, 0, 5).unwrap();
+        assert_eq!(result.match_count, 50);
+        assert_eq!(result.matches.len(), 5);
+        assert!(result.capped);
+        assert_eq!(result.matches[0].column, Some(10));
+    }
+
+    #[test]
+    fn test_parse_ripgrep_json() {
+        let output = [
+            r#"{"type":"begin","data":{"path":{"text":"src/a.rs"}}}"#,
+            r#"{"type":"context","data":{"path":{"text":"src/a.rs"},"lines":{"text":"fn main() {\n"},"line_number":1,"submatches":[]}}"#,
+            r#"{"type":"match","data":{"path":{"text":"src/a.rs"},"lines":{"text":"    // TODO: fix\n"},"line_number":2,"submatches":[{"match":{"text":"TODO"},"start":7,"end":11}]}}"#,
+            r#"{"type":"end","data":{"path":{"text":"src/a.rs"},"stats":{}}}"#,
+            r#"{"type":"begin","data":{"path":{"text":"src/b.rs"}}}"#,
+            r#"{"type":"match","data":{"path":{"text":"src/b.rs"},"lines":{"text":"TODO\n"},"line_number":9,"submatches":[{"match":{"text":"TODO"},"start":0,"end":4}]}}"#,
+            r#"{"type":"end","data":{"path":{"text":"src/b.rs"},"stats":{}}}"#,
+            r#"{"type":"summary","data":{"stats":{}}}"#,
+        ]
+        .join("\n");
+
+        let (results, truncated) = GrepTool::parse_ripgrep_json(&output, 10, 1);
+        assert!(!truncated);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].match_count, 1);
+        assert_eq!(results[0].matches.len(), 2);
+        assert!(!results[0].matches[0].is_match);
+        assert_eq!(results[0].matches[1].line_num, 2);
+        assert_eq!(results[0].matches[1].column, Some(8));
+        assert!(results[0].capped);
+
+        // 파일 수 제한
+        let (results, truncated) = GrepTool::parse_ripgrep_json(&output, 1, 20);
+        assert!(truncated);
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].capped);
+    }
+
+    #[tokio::test]
+    async fn test_json_output_respects_forgeignore() {
+        use crate::tool::RuntimeContext;
+        use forge_foundation::PermissionService;
+
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("vendor")).unwrap();
+        fs::write(dir.path().join(FORGE_IGNORE_FILENAME), "vendor/\n").unwrap();
+        fs::write(
+            dir.path().join("main.rs"),
+            "fn main() {\n    // needle\n}\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("vendor/dep.rs"), "// needle\n").unwrap();
+
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let result = GrepTool::new()
+            .execute(json!({ "pattern": "needle", "output_mode": "json" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let matches: Vec<Value> = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert!(matches[0]["file"].as_str().unwrap().ends_with("main.rs"));
+        assert_eq!(matches[0]["line"], 2);
+        assert_eq!(matches[0]["column"], 8);
+        assert_eq!(matches[0]["snippet"], "// needle");
+    }
 }
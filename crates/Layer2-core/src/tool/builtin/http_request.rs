@@ -0,0 +1,660 @@
+//! HTTP Request Tool - API 요청 도구
+//!
+//! 임의의 HTTP 요청을 보내 응답을 확인합니다 (API 탐색용).
+//! - GET/POST/PUT/PATCH/DELETE/HEAD + 헤더/본문
+//! - JSON 응답 자동 pretty-print
+//! - 응답 크기/시간 제한
+//! - `network.request` 권한 + 도메인별 허용/거부 규칙
+//! - 리다이렉트마다 전역 egress 정책(`security.network`)과 도메인 거부 규칙을 검사하고,
+//!   권한이 필요한 다른 호스트로의 리다이렉트는 따라가지 않고 `location`을 반환
+//!
+//! 권한은 도메인 단위로 요청됩니다 (`PermissionAction::Network { url: <host> }`).
+//! 따라서 `permissions.json`의 `http_request` grant/deny 패턴에
+//! `api.github.com`, `*.internal.corp` 같은 도메인 규칙을 사용할 수 있습니다.
+
+use async_trait::async_trait;
+use forge_foundation::permission::domain_matches;
+use forge_foundation::{
+    check_egress, egress_client, PermissionAction, PermissionDef, PermissionStatus, Result, Tool,
+    ToolContext, ToolMeta, ToolResult,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// HTTP 요청 도구 입력
+#[derive(Debug, Deserialize)]
+pub struct HttpRequestInput {
+    /// 요청 URL (http/https)
+    pub url: String,
+
+    /// HTTP 메서드 (기본: GET)
+    #[serde(default)]
+    pub method: Option<String>,
+
+    /// 요청 헤더
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+
+    /// 요청 본문 (문자열 또는 JSON)
+    #[serde(default)]
+    pub body: Option<Value>,
+
+    /// 타임아웃 (밀리초)
+    #[serde(default)]
+    pub timeout: Option<u64>,
+}
+
+/// HTTP 요청 도구 설정
+#[derive(Debug, Clone)]
+pub struct HttpRequestConfig {
+    /// 기본 타임아웃
+    pub default_timeout: Duration,
+
+    /// 최대 타임아웃
+    pub max_timeout: Duration,
+
+    /// 최대 응답 크기 (bytes) - 초과분은 잘림
+    pub max_response_bytes: usize,
+
+    /// 권한 확인 없이 허용할 도메인 패턴
+    pub allowed_domains: Vec<String>,
+
+    /// 항상 거부할 도메인 패턴
+    pub denied_domains: Vec<String>,
+}
+
+impl Default for HttpRequestConfig {
+    fn default() -> Self {
+        Self {
+            default_timeout: Duration::from_secs(30),
+            max_timeout: Duration::from_secs(120),
+            max_response_bytes: 100_000,
+            allowed_domains: Vec::new(),
+            denied_domains: Vec::new(),
+        }
+    }
+}
+
+/// HTTP 요청 도구
+pub struct HttpRequestTool {
+    config: HttpRequestConfig,
+    client: reqwest::Client,
+}
+
+impl HttpRequestTool {
+    /// 새 인스턴스 생성
+    pub fn new() -> Self {
+        Self::with_config(HttpRequestConfig::default())
+    }
+
+    /// 설정과 함께 생성
+    pub fn with_config(config: HttpRequestConfig) -> Self {
+        let client = egress_client()
+            .user_agent("ForgeCode/1.0 (AI Coding Assistant)")
+            .redirect(Self::redirect_policy(&config))
+            .build()
+            .unwrap_or_default();
+
+        Self { config, client }
+    }
+
+    /// 최대 리다이렉트 수
+    const MAX_REDIRECTS: usize = 5;
+
+    /// 리다이렉트 정책
+    ///
+    /// 거부된 도메인과 egress 정책이 막는 대상은 오류, 처음 요청한 호스트가 아니고
+    /// `allowed_domains`에도 없는 호스트(권한 확인이 필요한 대상)는 따라가지 않습니다.
+    fn redirect_policy(config: &HttpRequestConfig) -> reqwest::redirect::Policy {
+        let allowed = config.allowed_domains.clone();
+        let denied = config.denied_domains.clone();
+
+        reqwest::redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() > Self::MAX_REDIRECTS {
+                return attempt.error(format!(
+                    "Too many redirects (max {})",
+                    Self::MAX_REDIRECTS
+                ));
+            }
+            let Some(host) = attempt.url().host_str().map(trim_host) else {
+                return attempt.error("Redirect target has no host");
+            };
+            if matches_any(&denied, &host) {
+                return attempt.error(format!(
+                    "Redirect to '{}' is denied by configuration",
+                    host
+                ));
+            }
+            if let Err(e) = check_egress(attempt.url().as_str()) {
+                return attempt.error(e);
+            }
+
+            let origin = attempt
+                .previous()
+                .first()
+                .and_then(|url| url.host_str())
+                .map(trim_host);
+            if origin.as_deref() != Some(host.as_str()) && !matches_any(&allowed, &host) {
+                return attempt.stop();
+            }
+            attempt.follow()
+        })
+    }
+
+    /// 도구 이름
+    pub const NAME: &'static str = "http_request";
+
+    /// 지원 메서드
+    const METHODS: [&'static str; 6] = ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD"];
+
+    /// 출력에 포함할 응답 헤더
+    const SHOWN_HEADERS: [&'static str; 5] = [
+        "content-type",
+        "content-length",
+        "location",
+        "retry-after",
+        "www-authenticate",
+    ];
+
+    /// URL 검증 후 호스트 반환
+    pub(crate) fn parse_url(url: &str) -> std::result::Result<(url::Url, String), String> {
+        let parsed = url::Url::parse(url).map_err(|e| format!("Invalid URL '{}': {}", url, e))?;
+
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(format!(
+                "Only http/https URLs are allowed, got: {}",
+                parsed.scheme()
+            ));
+        }
+
+        let host = parsed
+            .host_str()
+            .map(trim_host)
+            .ok_or_else(|| format!("URL has no host: {}", url))?;
+
+        Ok((parsed, host))
+    }
+
+    fn is_denied(&self, host: &str) -> bool {
+        matches_any(&self.config.denied_domains, host)
+    }
+
+    fn is_allowed(&self, host: &str) -> bool {
+        matches_any(&self.config.allowed_domains, host)
+    }
+
+    /// 응답 본문 포맷 (JSON이면 pretty-print)
+    fn format_body(content_type: &str, body: &str) -> String {
+        let looks_like_json = content_type.contains("json")
+            || body.trim_start().starts_with('{')
+            || body.trim_start().starts_with('[');
+
+        if looks_like_json {
+            if let Ok(value) = serde_json::from_str::<Value>(body) {
+                if let Ok(pretty) = serde_json::to_string_pretty(&value) {
+                    return pretty;
+                }
+            }
+        }
+        body.to_string()
+    }
+
+    /// 크기 제한을 지키며 본문 읽기
+    async fn read_body(
+        &self,
+        mut response: reqwest::Response,
+    ) -> std::result::Result<(Vec<u8>, bool), reqwest::Error> {
+        let limit = self.config.max_response_bytes;
+        let mut buf = Vec::new();
+
+        while let Some(chunk) = response.chunk().await? {
+            let remaining = limit.saturating_sub(buf.len());
+            if chunk.len() > remaining {
+                buf.extend_from_slice(&chunk[..remaining]);
+                return Ok((buf, true));
+            }
+            buf.extend_from_slice(&chunk);
+        }
+
+        Ok((buf, false))
+    }
+}
+
+/// URL 호스트에서 IPv6 괄호 제거
+fn trim_host(host: &str) -> String {
+    host.trim_start_matches('[').trim_end_matches(']').to_string()
+}
+
+/// 오류와 원인 전체 (리다이렉트 거부 사유 등)
+fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+/// 도메인 패턴 중 하나와 일치하는지
+fn matches_any(patterns: &[String], host: &str) -> bool {
+    patterns.iter().any(|pattern| domain_matches(pattern, host))
+}
+
+impl Default for HttpRequestTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for HttpRequestTool {
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("HTTP Request")
+            .description(
+                "Send an HTTP request (GET/POST/PUT/PATCH/DELETE/HEAD) with optional headers and body, \
+                 and return the status, key headers and response body. JSON responses are pretty-printed. \
+                 Use for exploring and testing APIs.",
+            )
+            .category("network")
+            .permission(
+                PermissionDef::new("network.request", "network")
+                    .risk_level(5)
+                    .description("Send HTTP request to a domain")
+                    .requires_confirmation(true),
+            )
+    }
+
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The http(s) URL to request"
+                },
+                "method": {
+                    "type": "string",
+                    "enum": Self::METHODS,
+                    "description": "HTTP method (default: GET)"
+                },
+                "headers": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                    "description": "Request headers"
+                },
+                "body": {
+                    "description": "Request body. Objects/arrays are sent as JSON, strings as-is"
+                },
+                "timeout": {
+                    "type": "number",
+                    "description": "Optional timeout in milliseconds (max 120000)"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        let url = input.get("url")?.as_str()?;
+        let (_, host) = Self::parse_url(url).ok()?;
+
+        if self.is_allowed(&host) && !self.is_denied(&host) {
+            return None;
+        }
+
+        Some(PermissionAction::Network { url: host })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let parsed: HttpRequestInput = serde_json::from_value(input.clone()).map_err(|e| {
+            forge_foundation::Error::InvalidInput(format!("Invalid input: {}", e))
+        })?;
+
+        let (url, host) = match Self::parse_url(&parsed.url) {
+            Ok(v) => v,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+
+        let method = parsed
+            .method
+            .as_deref()
+            .unwrap_or("GET")
+            .to_ascii_uppercase();
+        if !Self::METHODS.contains(&method.as_str()) {
+            return Ok(ToolResult::error(format!(
+                "Unsupported method '{}'. Use one of: {}",
+                method,
+                Self::METHODS.join(", ")
+            )));
+        }
+
+        // 도메인 거부 규칙
+        if self.is_denied(&host) {
+            return Ok(ToolResult::error(format!(
+                "Requests to '{}' are denied by configuration",
+                host
+            )));
+        }
+        if let Err(e) = check_egress(&host) {
+            return Ok(ToolResult::error(e.to_string()));
+        }
+
+        // 권한 확인
+        if let Some(action) = self.required_permission(&input) {
+            match context.check_permission(Self::NAME, &action).await {
+                PermissionStatus::Denied => {
+                    return Ok(ToolResult::error(format!(
+                        "Permission denied for network request to '{}'",
+                        host
+                    )));
+                }
+                PermissionStatus::Unknown => {
+                    let granted = context
+                        .request_permission(
+                            Self::NAME,
+                            &format!("{} {}", method, url),
+                            action,
+                        )
+                        .await?;
+                    if !granted {
+                        return Ok(ToolResult::error("Permission denied by user"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // 요청 구성
+        let timeout = parsed
+            .timeout
+            .map(Duration::from_millis)
+            .unwrap_or(self.config.default_timeout)
+            .min(self.config.max_timeout);
+
+        let reqwest_method = reqwest::Method::from_bytes(method.as_bytes())
+            .unwrap_or(reqwest::Method::GET);
+        let mut request = self
+            .client
+            .request(reqwest_method, url.clone())
+            .timeout(timeout);
+
+        for (key, value) in &parsed.headers {
+            request = request.header(key, value);
+        }
+
+        request = match parsed.body {
+            None | Some(Value::Null) => request,
+            Some(Value::String(text)) => request.body(text),
+            Some(value) => request.json(&value),
+        };
+
+        // 요청 실행
+        let start = Instant::now();
+        let response = match request.send().await {
+            Ok(r) => r,
+            Err(e) if e.is_timeout() => {
+                return Ok(ToolResult::error(format!(
+                    "Request timed out after {}ms",
+                    timeout.as_millis()
+                )));
+            }
+            Err(e) => {
+                return Ok(ToolResult::error(format!(
+                    "Request failed: {}",
+                    error_chain(&e)
+                )))
+            }
+        };
+
+        let status = response.status();
+        let redirect_not_followed =
+            status.is_redirection() && response.headers().contains_key("location");
+        let final_url = response.url().to_string();
+        let header_lines: Vec<String> = Self::SHOWN_HEADERS
+            .iter()
+            .filter_map(|name| {
+                response
+                    .headers()
+                    .get(*name)
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| format!("{}: {}", name, v))
+            })
+            .collect();
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("")
+            .to_string();
+
+        let (body, truncated) = match self.read_body(response).await {
+            Ok(v) => v,
+            Err(e) => return Ok(ToolResult::error(format!("Failed to read response: {}", e))),
+        };
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        // 출력 구성
+        let mut output = format!(
+            "HTTP {} {}\n",
+            status.as_u16(),
+            status.canonical_reason().unwrap_or("")
+        );
+        if final_url != url.as_str() {
+            output.push_str(&format!("url: {}\n", final_url));
+        }
+        for line in &header_lines {
+            output.push_str(line);
+            output.push('\n');
+        }
+        if redirect_not_followed {
+            output.push_str(
+                "(redirect to another host not followed - request the location directly)\n",
+            );
+        }
+
+        if !body.is_empty() {
+            let text = String::from_utf8_lossy(&body);
+            let formatted = if truncated {
+                text.to_string()
+            } else {
+                Self::format_body(&content_type, &text)
+            };
+            output.push('\n');
+            output.push_str(&formatted);
+        }
+
+        if truncated {
+            output.push_str(&format!(
+                "\n\n... (response truncated at {} bytes)",
+                self.config.max_response_bytes
+            ));
+        }
+
+        Ok(ToolResult::success(output)
+            .with_metadata("status", json!(status.as_u16()))
+            .with_metadata("content_type", json!(content_type))
+            .with_metadata("bytes", json!(body.len()))
+            .with_metadata("truncated", json!(truncated))
+            .with_metadata("duration_ms", json!(duration_ms)))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::io::{Read, Write};
+    use std::sync::Arc;
+
+    /// 단일 응답을 반환하는 로컬 HTTP 서버
+    fn serve_once(content_type: &str, body: &str) -> String {
+        serve_raw(format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            content_type,
+            body.len(),
+            body
+        ))
+    }
+
+    /// `location`으로 리다이렉트하는 로컬 HTTP 서버
+    fn serve_redirect(location: &str) -> String {
+        serve_raw(format!(
+            "HTTP/1.1 302 Found\r\nlocation: {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+            location
+        ))
+    }
+
+    fn serve_raw(response: String) -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        format!("http://{}/api", addr)
+    }
+
+    fn context() -> RuntimeContext {
+        RuntimeContext::new(
+            "test-session",
+            std::env::temp_dir(),
+            Arc::new(PermissionService::with_auto_approve()),
+        )
+    }
+
+    #[test]
+    fn test_meta() {
+        let tool = HttpRequestTool::new();
+        let meta = tool.meta();
+        assert_eq!(meta.name, "http_request");
+        assert_eq!(meta.category, "network");
+        assert_eq!(meta.permissions[0].name, "network.request");
+    }
+
+    #[test]
+    fn test_required_permission_per_domain() {
+        let tool = HttpRequestTool::with_config(HttpRequestConfig {
+            allowed_domains: vec!["*.trusted.dev".to_string()],
+            ..Default::default()
+        });
+
+        assert!(tool
+            .required_permission(&json!({ "url": "https://api.trusted.dev/v1" }))
+            .is_none());
+
+        let perm = tool.required_permission(&json!({ "url": "https://api.github.com/repos" }));
+        assert!(
+            matches!(&perm, Some(PermissionAction::Network { url }) if url == "api.github.com"),
+            "Expected Network permission for host, got {:?}",
+            perm
+        );
+    }
+
+    #[tokio::test]
+    async fn test_denied_domain_and_invalid_url() {
+        let tool = HttpRequestTool::with_config(HttpRequestConfig {
+            denied_domains: vec!["*.blocked.dev".to_string()],
+            ..Default::default()
+        });
+        let ctx = context();
+
+        let result = tool
+            .execute(json!({ "url": "https://x.blocked.dev/" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("denied"));
+
+        let result = tool
+            .execute(json!({ "url": "file:///etc/passwd" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn test_json_response_pretty_printed() {
+        let url = serve_once("application/json", r#"{"ok":true,"items":[1,2]}"#);
+        let tool = HttpRequestTool::new();
+
+        let result = tool.execute(json!({ "url": url }), &context()).await.unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.starts_with("HTTP 200 OK"));
+        assert!(result.output.contains("\"ok\": true"));
+        assert_eq!(result.metadata["status"], json!(200));
+        assert_eq!(result.metadata["truncated"], json!(false));
+    }
+
+    #[tokio::test]
+    async fn test_redirects_checked_per_hop() {
+        // 같은 호스트로의 리다이렉트는 따라감
+        let target = serve_once("text/plain", "moved here");
+        let tool = HttpRequestTool::new();
+        let result = tool
+            .execute(json!({ "url": serve_redirect(&target) }), &context())
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("moved here"));
+
+        // 권한이 필요한 다른 호스트는 따라가지 않고 location 반환
+        let other = target.replace("127.0.0.1", "localhost");
+        let result = tool
+            .execute(json!({ "url": serve_redirect(&other) }), &context())
+            .await
+            .unwrap();
+        assert_eq!(result.metadata["status"], json!(302));
+        assert!(result.output.contains(&format!("location: {}", other)));
+
+        // 거부된 도메인으로의 리다이렉트는 오류
+        let tool = HttpRequestTool::with_config(HttpRequestConfig {
+            denied_domains: vec!["*.blocked.dev".to_string()],
+            ..Default::default()
+        });
+        let result = tool
+            .execute(
+                json!({ "url": serve_redirect("http://x.blocked.dev/") }),
+                &context(),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("denied"));
+    }
+
+    #[tokio::test]
+    async fn test_response_truncated_at_limit() {
+        let url = serve_once("text/plain", &"x".repeat(500));
+        let tool = HttpRequestTool::with_config(HttpRequestConfig {
+            max_response_bytes: 100,
+            ..Default::default()
+        });
+
+        let result = tool
+            .execute(json!({ "url": url, "method": "post", "body": {"q": 1} }), &context())
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.metadata["bytes"], json!(100));
+        assert!(result.output.contains("response truncated"));
+    }
+}
@@ -0,0 +1,382 @@
+//! List Directory Tool - 디렉토리 트리 조회
+//!
+//! `ls -la`/`find` 대신 쓰는 압축된 트리 출력입니다.
+//! - 깊이 제한 (기본 2)
+//! - `.gitignore` / `.forgeignore` 존중 (`walk` 모듈 참고)
+//! - 파일 크기, 언어 표시
+//!
+//! ```text
+//! crates/Layer2-core (depth 2)
+//! ├── src/  42 files, 812.4 KB
+//! │   ├── tool/ …
+//! │   └── lib.rs  12.1 KB  rust
+//! └── Cargo.toml  1.9 KB  toml
+//!
+//! 2 directories, 43 files
+//! ```
+
+use super::walk::workspace_walker;
+use async_trait::async_trait;
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// 기본 깊이
+const DEFAULT_DEPTH: usize = 2;
+
+/// 최대 깊이
+const MAX_DEPTH: usize = 8;
+
+/// 기본 최대 항목 수
+const DEFAULT_MAX_ENTRIES: usize = 300;
+
+/// 트리 노드
+#[derive(Default)]
+struct Node {
+    is_dir: bool,
+    size: u64,
+    /// 깊이 제한 때문에 펼치지 않은 디렉토리
+    collapsed: bool,
+    children: BTreeMap<String, Node>,
+}
+
+impl Node {
+    /// 하위 파일 수와 총 크기 (펼친 범위 내)
+    fn totals(&self) -> (usize, u64) {
+        self.children.values().fold((0, 0), |(files, size), child| {
+            if child.is_dir {
+                let (f, s) = child.totals();
+                (files + f, size + s)
+            } else {
+                (files + 1, size + child.size)
+            }
+        })
+    }
+
+    /// 하위 디렉토리 수
+    fn dir_count(&self) -> usize {
+        self.children
+            .values()
+            .filter(|child| child.is_dir)
+            .map(|child| 1 + child.dir_count())
+            .sum()
+    }
+
+    fn render(&self, prefix: &str, lines: &mut Vec<String>) {
+        // 디렉토리 먼저, 그 다음 파일 (각각 이름순)
+        let mut children: Vec<_> = self.children.iter().collect();
+        children.sort_by_key(|(name, child)| (!child.is_dir, name.to_lowercase()));
+
+        let count = children.len();
+        for (index, (name, child)) in children.into_iter().enumerate() {
+            let last = index + 1 == count;
+            let branch = if last { "└── " } else { "├── " };
+
+            let line = if !child.is_dir {
+                match detect_language(Path::new(name)) {
+                    Some(language) => format!(
+                        "{}{}{}  {}  {}",
+                        prefix,
+                        branch,
+                        name,
+                        format_size(child.size),
+                        language
+                    ),
+                    None => format!("{}{}{}  {}", prefix, branch, name, format_size(child.size)),
+                }
+            } else if child.collapsed {
+                format!("{}{}{}/ …", prefix, branch, name)
+            } else {
+                let (files, size) = child.totals();
+                format!(
+                    "{}{}{}/  {} file{}, {}",
+                    prefix,
+                    branch,
+                    name,
+                    files,
+                    if files == 1 { "" } else { "s" },
+                    format_size(size)
+                )
+            };
+            lines.push(line);
+
+            if child.is_dir && !child.collapsed {
+                let next = format!("{}{}", prefix, if last { "    " } else { "│   " });
+                child.render(&next, lines);
+            }
+        }
+    }
+}
+
+/// 확장자로 언어 감지
+fn detect_language(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+    let language = match extension.as_str() {
+        "rs" => "rust",
+        "ts" | "tsx" | "mts" | "cts" => "typescript",
+        "js" | "jsx" | "mjs" | "cjs" => "javascript",
+        "py" | "pyi" => "python",
+        "go" => "go",
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+        "swift" => "swift",
+        "c" | "h" => "c",
+        "cpp" | "cc" | "cxx" | "hpp" | "hh" => "cpp",
+        "cs" => "csharp",
+        "rb" => "ruby",
+        "php" => "php",
+        "sh" | "bash" | "zsh" => "shell",
+        "sql" => "sql",
+        "html" | "htm" => "html",
+        "css" | "scss" => "css",
+        "md" | "mdx" => "markdown",
+        "json" => "json",
+        "toml" => "toml",
+        "yaml" | "yml" => "yaml",
+        "xml" => "xml",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// 크기 표시
+pub(crate) fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// 디렉토리 트리 도구
+pub struct ListDirectoryTool;
+
+impl ListDirectoryTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 트리 생성 (항목 수 제한에 걸리면 true)
+    fn build_tree(root: &Path, depth: usize, max_entries: usize) -> (Node, bool) {
+        let mut tree = Node {
+            is_dir: true,
+            ..Node::default()
+        };
+        let mut entries = 0;
+        let mut truncated = false;
+
+        let walker = workspace_walker(root).max_depth(Some(depth)).build();
+        for entry in walker.filter_map(|entry| entry.ok()) {
+            if entry.depth() == 0 {
+                continue;
+            }
+            if entries >= max_entries {
+                truncated = true;
+                break;
+            }
+            entries += 1;
+
+            let Ok(relative) = entry.path().strip_prefix(root) else {
+                continue;
+            };
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+
+            let mut node = &mut tree;
+            for component in relative.components() {
+                node = node
+                    .children
+                    .entry(component.as_os_str().to_string_lossy().to_string())
+                    .or_insert_with(|| Node {
+                        is_dir: true,
+                        ..Node::default()
+                    });
+            }
+            node.is_dir = is_dir;
+            node.collapsed = is_dir && entry.depth() == depth;
+            if !is_dir {
+                node.size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            }
+        }
+
+        (tree, truncated)
+    }
+}
+
+impl Default for ListDirectoryTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ListDirectoryTool {
+    fn name(&self) -> &str {
+        "list_directory"
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new("list_directory")
+            .display_name("List Directory")
+            .description("Show a compact directory tree with file sizes and languages, honoring .gitignore/.forgeignore. Use this instead of `ls -la` or `find` in bash.")
+            .category("filesystem")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Directory to list (default: working directory)"
+                },
+                "depth": {
+                    "type": "integer",
+                    "description": "How many levels to expand (default: 2, max: 8)"
+                },
+                "max_entries": {
+                    "type": "integer",
+                    "description": "Maximum number of entries to return (default: 300)"
+                }
+            }
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let root = match input["path"].as_str() {
+            Some(p) if Path::new(p).is_absolute() => Path::new(p).to_path_buf(),
+            Some(p) => ctx.working_dir().join(p),
+            None => ctx.working_dir().to_path_buf(),
+        };
+        if !root.is_dir() {
+            return Ok(ToolResult::error(format!(
+                "Not a directory: {}",
+                root.display()
+            )));
+        }
+
+        let depth = input["depth"]
+            .as_u64()
+            .map(|n| n as usize)
+            .unwrap_or(DEFAULT_DEPTH)
+            .clamp(1, MAX_DEPTH);
+        let max_entries = input["max_entries"]
+            .as_u64()
+            .map(|n| n as usize)
+            .unwrap_or(DEFAULT_MAX_ENTRIES)
+            .max(1);
+
+        let walk_root = root.clone();
+        let (tree, truncated) = tokio::task::spawn_blocking(move || {
+            ListDirectoryTool::build_tree(&walk_root, depth, max_entries)
+        })
+        .await
+        .map_err(|e| forge_foundation::Error::Internal(format!("Directory walk failed: {}", e)))?;
+
+        let mut lines = vec![format!("{} (depth {})", root.display(), depth)];
+        if tree.children.is_empty() {
+            lines.push("(empty)".to_string());
+        }
+        tree.render("", &mut lines);
+
+        let (files, _) = tree.totals();
+        let dirs = tree.dir_count();
+        lines.push(String::new());
+        lines.push(format!(
+            "{} director{}, {} file{}",
+            dirs,
+            if dirs == 1 { "y" } else { "ies" },
+            files,
+            if files == 1 { "" } else { "s" }
+        ));
+        if truncated {
+            lines.push(format!(
+                "(truncated at {} entries - list a subdirectory or lower depth)",
+                max_entries
+            ));
+        }
+
+        Ok(ToolResult::success(lines.join("\n"))
+            .with_metadata("files", json!(files))
+            .with_metadata("directories", json!(dirs))
+            .with_metadata("truncated", json!(truncated)))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::fs;
+    use std::sync::Arc;
+
+    fn ctx(dir: &Path) -> RuntimeContext {
+        RuntimeContext::new(
+            "test",
+            dir.to_path_buf(),
+            Arc::new(PermissionService::new()),
+        )
+    }
+
+    #[test]
+    fn test_detect_language_and_size() {
+        assert_eq!(detect_language(Path::new("lib.rs")), Some("rust"));
+        assert_eq!(detect_language(Path::new("README.MD")), Some("markdown"));
+        assert_eq!(detect_language(Path::new("LICENSE")), None);
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+    }
+
+    #[tokio::test]
+    async fn test_tree_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/tool/builtin")).unwrap();
+        fs::create_dir_all(root.join("target")).unwrap();
+        fs::write(root.join(".gitignore"), "target/\n").unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]\n").unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod tool;\n").unwrap();
+        fs::write(root.join("src/tool/builtin/ls.rs"), "").unwrap();
+        fs::write(root.join("target/debug.bin"), "").unwrap();
+
+        let result = ListDirectoryTool::new()
+            .execute(json!({ "depth": 2 }), &ctx(root))
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let output = result.output;
+        assert!(output.contains("├── src/  1 file, 14 B"));
+        assert!(output.contains("│   ├── tool/ …"));
+        assert!(output.contains("│   └── lib.rs  14 B  rust"));
+        assert!(output.contains("└── Cargo.toml  10 B  toml"));
+        assert!(output.contains(".gitignore  8 B"));
+        assert!(!output.contains("target"));
+        assert!(output.ends_with("2 directories, 3 files"));
+    }
+
+    #[tokio::test]
+    async fn test_max_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        for i in 0..10 {
+            fs::write(dir.path().join(format!("f{}.txt", i)), "").unwrap();
+        }
+        let result = ListDirectoryTool::new()
+            .execute(json!({ "max_entries": 3 }), &ctx(dir.path()))
+            .await
+            .unwrap();
+        assert!(result.output.contains("truncated at 3 entries"));
+        assert_eq!(result.metadata["files"], json!(3));
+    }
+}
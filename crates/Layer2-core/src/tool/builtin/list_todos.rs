@@ -0,0 +1,179 @@
+//! List TODOs Tool - TODO/FIXME/HACK 백로그 조회
+//!
+//! repomap 분석기가 추출한 작업 주석을 담당자/경과 일수와 함께 보여줍니다.
+//! 번호는 `/todos --select`에서 그대로 사용됩니다.
+
+use crate::repomap::{RepoAnalyzer, TodoBacklog, TodoFilter, TodoKind};
+use async_trait::async_trait;
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use serde_json::{json, Value};
+
+/// 기본 결과 수
+const DEFAULT_LIMIT: usize = 50;
+
+/// 입력에서 필터 생성 (`/todos` 스킬과 공용)
+pub(crate) fn todo_filter(input: &Value) -> std::result::Result<TodoFilter, String> {
+    let kind = match input["kind"].as_str() {
+        Some(kind) => Some(
+            TodoKind::parse(kind)
+                .ok_or_else(|| format!("Invalid kind '{}': use todo, fixme or hack", kind))?,
+        ),
+        None => None,
+    };
+
+    Ok(TodoFilter {
+        kind,
+        owner: input["owner"].as_str().map(String::from),
+        path: input["path"].as_str().map(String::from),
+        min_age_days: input["min_age_days"].as_u64(),
+    })
+}
+
+/// TODO 백로그 조회 도구
+pub struct ListTodosTool;
+
+impl ListTodosTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ListTodosTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ListTodosTool {
+    fn name(&self) -> &str {
+        "list_todos"
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new("list_todos")
+            .display_name("List TODOs")
+            .description("List TODO/FIXME/HACK comments in the workspace as a backlog with owners and ages (FIXME first, oldest first). Filter by kind, owner, path or age.")
+            .category("filesystem")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "kind": {
+                    "type": "string",
+                    "enum": ["todo", "fixme", "hack"],
+                    "description": "Only list this kind of annotation"
+                },
+                "owner": {
+                    "type": "string",
+                    "description": "Only list items owned by this person (TODO(name), @name or git blame author)"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Only list items under this relative path prefix (e.g. \"src/agent\")"
+                },
+                "min_age_days": {
+                    "type": "integer",
+                    "description": "Only list items at least this many days old"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of items to return (default: 50)"
+                }
+            }
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let filter = match todo_filter(&input) {
+            Ok(filter) => filter,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+        let limit = input["limit"]
+            .as_u64()
+            .map(|n| n as usize)
+            .unwrap_or(DEFAULT_LIMIT)
+            .max(1);
+
+        let backlog = RepoAnalyzer::with_defaults(ctx.working_dir())
+            .todo_backlog()
+            .await?;
+        let items = backlog.filter(&filter);
+        if items.is_empty() {
+            return Ok(ToolResult::success("No TODO/FIXME/HACK comments found"));
+        }
+
+        let shown = &items[..items.len().min(limit)];
+        let mut output = format!("{} of {} items\n\n", shown.len(), items.len());
+        output.push_str(&TodoBacklog::render(shown));
+        if items.len() > shown.len() {
+            output.push_str(&format!(
+                "\n\n... {} more (narrow with kind/owner/path or raise limit)",
+                items.len() - shown.len()
+            ));
+        }
+
+        Ok(ToolResult::success(output)
+            .with_metadata("total", json!(items.len()))
+            .with_metadata("items", json!(shown)))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_todo_filter() {
+        let filter = todo_filter(&json!({ "kind": "FIXME", "owner": "bob" })).unwrap();
+        assert_eq!(filter.kind, Some(TodoKind::Fixme));
+        assert_eq!(filter.owner.as_deref(), Some("bob"));
+        assert!(todo_filter(&json!({ "kind": "note" })).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_todos() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.rs"),
+            "// TODO(alice): split module\nfn a() {}\n// FIXME: overflow on empty input\n",
+        )
+        .unwrap();
+
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let tool = ListTodosTool::new();
+
+        let result = tool.execute(json!({}), &ctx).await.unwrap();
+        assert!(result.success);
+        assert!(result.output.starts_with("2 of 2 items"));
+        assert!(result
+            .output
+            .contains("1. FIXME src/lib.rs:3 overflow on empty input"));
+
+        let result = tool
+            .execute(json!({ "owner": "alice" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result
+            .output
+            .contains("1. TODO src/lib.rs:1 split module (@alice)"));
+    }
+}
@@ -10,10 +10,37 @@
 //! - `edit` - 파일 편집 (문자열 치환)
 //! - `glob` - 파일 패턴 검색
 //! - `grep` - 내용 검색 (정규식)
+//! - `list_directory` - 디렉토리 트리 (깊이 제한, 크기/언어 표시)
+//! - `list_todos` - TODO/FIXME/HACK 백로그 (담당자, 경과 일수)
+//! - `code_search` - 의미 기반 코드 검색 (repomap 임베딩)
+//! - `archive` - zip/tar 조회, 선택 추출, 생성 (zip-slip 차단)
 //!
 //! ### 실행 (Execute)
 //! - `bash` - Shell 명령 실행
 //!
+//! ### Git
+//! - `pull_request` - GitHub PR / GitLab MR 조회, diff, 생성, 리뷰 코멘트 (게시는 `git.publish` 권한)
+//! - `resolve_conflict` - 머지 충돌 ours/base/theirs hunk 조회, hunk 단위 해결 (`git.resolve` 권한)
+//! - `git_blame` - 줄 범위별 마지막 변경 커밋 (작성자, 날짜, 커밋 제목)
+//! - `git_log` - 저장소/파일/줄 범위의 커밋 이력 (본문 포함)
+//!
+//! ### Task
+//! - `task_spawn` / `task_wait` / `task_logs` / `task_stop` / `task_send` / `task_list` / `task_status` - Task 실행 및 상호작용
+//! - `task_artifact` - Task 산출물 목록/검사/복원 (`.forgecode/artifacts`, 이후 턴에서도 조회)
+//!
+//! ### 프로세스 (Process)
+//! - `process_start` / `process_status` / `process_logs` / `process_stop` - 백그라운드 프로세스 관리 (dev 서버, watch)
+//!
+//! ### 출력 (Output)
+//! - `fetch_full_output` - `OutputGovernor`가 잘라낸 도구 출력 전체 조회
+//!
+//! ### 네트워크 (Network)
+//! - `http_request` - HTTP 요청 (API 탐색, `network.request` 권한)
+//! - `download` / `upload` - 파일 전송 (최대 크기, sha256 검증)
+//!
+//! ### 데이터베이스 (Database)
+//! - `sql_query` - SQL 실행 (SQLite/Postgres/MySQL, 쓰기는 `database.write` 권한)
+//!
 //! ### 웹 (Web)
 //! - `web_search` - 웹 검색 (Brave, DuckDuckGo, Google, Tavily)
 //! - `web_fetch` - URL 콘텐츠 가져오기 (HTML → Markdown 변환)
@@ -25,17 +52,43 @@
 //! - `CommandAnalyzer`로 위험 명령어 분석
 
 // Filesystem tools
+pub mod archive;
+pub mod code_search;
 pub mod edit;
 pub mod glob;
 pub mod grep;
+pub mod list_directory;
+pub mod list_todos;
 pub mod read;
 pub mod write;
 
+// Shared directory walking (.gitignore/.forgeignore)
+mod walk;
+
 // Execute tools
 pub mod bash;
 
+// Git tools
+pub mod git_history;
+pub mod pull_request;
+pub mod resolve_conflict;
+
 // Task tools
 pub mod task;
+pub mod task_artifact;
+
+// Process tools (long-running background processes)
+pub mod process;
+
+// Output tools
+pub mod fetch_output;
+
+// Network tools
+pub mod http_request;
+pub mod transfer;
+
+// Database tools
+pub mod sql_query;
 
 // Web tools (temporarily disabled due to API mismatch)
 // TODO: Fix web_fetch and web_search to match Layer1 Tool trait
@@ -43,17 +96,32 @@ pub mod task;
 // pub mod web_search;
 
 // Re-exports
+pub use archive::ArchiveTool;
 pub use bash::BashTool;
+pub use code_search::CodeSearchTool;
 pub use edit::EditTool;
+pub use fetch_output::FetchFullOutputTool;
+pub use git_history::{GitBlameTool, GitLogTool};
 pub use glob::GlobTool;
 pub use grep::GrepTool;
+pub use http_request::{HttpRequestConfig, HttpRequestTool};
+pub use list_directory::ListDirectoryTool;
+pub use list_todos::ListTodosTool;
+pub use pull_request::PullRequestTool;
 pub use read::ReadTool;
+pub use resolve_conflict::ResolveConflictTool;
+pub use sql_query::{SqlQueryConfig, SqlQueryTool};
+pub use transfer::{DownloadTool, TransferConfig, UploadTool};
 // pub use web_fetch::WebFetchTool;
 // pub use web_search::WebSearchTool;
 pub use write::WriteTool;
 
 // Task tools
 pub use task::task_tools;
+pub use task_artifact::TaskArtifactTool;
+
+// Process tools
+pub use process::process_tools;
 
 use forge_foundation::Tool;
 use std::sync::Arc;
@@ -67,8 +135,25 @@ pub fn all_tools() -> Vec<Arc<dyn Tool>> {
         Arc::new(EditTool::new()),
         Arc::new(GlobTool::new()),
         Arc::new(GrepTool::new()),
+        Arc::new(ListDirectoryTool::new()),
+        Arc::new(ListTodosTool::new()),
+        Arc::new(CodeSearchTool::new()),
+        Arc::new(ArchiveTool::new()),
         // Execute
         Arc::new(BashTool::new()),
+        // Git
+        Arc::new(PullRequestTool::new()),
+        Arc::new(ResolveConflictTool::new()),
+        Arc::new(GitBlameTool::new()),
+        Arc::new(GitLogTool::new()),
+        // Output
+        Arc::new(FetchFullOutputTool::new()),
+        // Network
+        Arc::new(HttpRequestTool::new()),
+        Arc::new(DownloadTool::new()),
+        Arc::new(UploadTool::new()),
+        // Database
+        Arc::new(SqlQueryTool::new()),
         // Web (temporarily disabled)
         // Arc::new(WebSearchTool::new()),
         // Arc::new(WebFetchTool::new()),
@@ -77,6 +162,9 @@ pub fn all_tools() -> Vec<Arc<dyn Tool>> {
     // Task tools
     tools.extend(task_tools());
 
+    // Process tools
+    tools.extend(process_tools());
+
     tools
 }
 
@@ -113,8 +201,8 @@ mod tests {
     #[test]
     fn test_all_tools() {
         let tools = all_tools();
-        // 6 core tools + 7 task tools = 13 (web_search and web_fetch temporarily disabled)
-        assert_eq!(tools.len(), 13);
+        // 19 core tools + 8 task tools + 4 process tools = 31 (web_search and web_fetch temporarily disabled)
+        assert_eq!(tools.len(), 31);
 
         let names: Vec<_> = tools.iter().map(|t| t.name()).collect();
         assert!(names.contains(&"read"));
@@ -122,7 +210,20 @@ mod tests {
         assert!(names.contains(&"edit"));
         assert!(names.contains(&"glob"));
         assert!(names.contains(&"grep"));
+        assert!(names.contains(&"list_directory"));
+        assert!(names.contains(&"list_todos"));
+        assert!(names.contains(&"code_search"));
+        assert!(names.contains(&"archive"));
         assert!(names.contains(&"bash"));
+        assert!(names.contains(&"pull_request"));
+        assert!(names.contains(&"resolve_conflict"));
+        assert!(names.contains(&"git_blame"));
+        assert!(names.contains(&"git_log"));
+        assert!(names.contains(&"fetch_full_output"));
+        assert!(names.contains(&"http_request"));
+        assert!(names.contains(&"download"));
+        assert!(names.contains(&"upload"));
+        assert!(names.contains(&"sql_query"));
         // Task tools
         assert!(names.contains(&"task_spawn"));
         assert!(names.contains(&"task_wait"));
@@ -131,6 +232,12 @@ mod tests {
         assert!(names.contains(&"task_send"));
         assert!(names.contains(&"task_list"));
         assert!(names.contains(&"task_status"));
+        assert!(names.contains(&"task_artifact"));
+        // Process tools
+        assert!(names.contains(&"process_start"));
+        assert!(names.contains(&"process_status"));
+        assert!(names.contains(&"process_logs"));
+        assert!(names.contains(&"process_stop"));
         // Temporarily disabled
         // assert!(names.contains(&"web_search"));
         // assert!(names.contains(&"web_fetch"));
@@ -0,0 +1,880 @@
+//! Process Tools - 장시간 실행 프로세스 관리 도구
+//!
+//! dev 서버, watch 명령처럼 끝나지 않는 프로세스를 bash를 막지 않고
+//! 백그라운드에서 실행/확인/종료합니다. `task` 모듈의 Orchestrator(PTY 모드)를
+//! 그대로 사용하므로 `task_*` 도구와 ID를 공유합니다.
+//!
+//! ## 제공 도구
+//!
+//! - `process_start` - 프로세스 시작 (선택적으로 준비 패턴까지 대기)
+//! - `process_status` - 상태 조회 (인자 없으면 전체 목록)
+//! - `process_logs` - 출력 tail + grep
+//! - `process_stop` - 프로세스 종료
+//!
+//! ## 사용 예시
+//!
+//! ```ignore
+//! process_start.execute(json!({
+//!     "command": "npm run dev",
+//!     "name": "web",
+//!     "ready_pattern": "Local:.*http://"
+//! })).await;
+//!
+//! process_logs.execute(json!({ "process": "web", "grep": "error" })).await;
+//! process_stop.execute(json!({ "process": "web" })).await;
+//! ```
+
+use super::task::{get_orchestrator, register_task_name, resolve_task_id};
+use async_trait::async_trait;
+use forge_foundation::{
+    command_analyzer, CommandRisk, PermissionAction, Result, Tool, ToolContext, ToolMeta,
+    ToolResult,
+};
+use forge_task::{ExecutionMode, LogLevel, Task, TaskId, TaskOrchestrator, TaskState};
+use regex::Regex;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::info;
+
+/// 프로세스 최대 실행 시간 (이후 자동 종료)
+const MAX_RUNTIME: Duration = Duration::from_secs(8 * 60 * 60);
+
+/// 준비 패턴이 없을 때 즉시 실패를 감지하기 위한 대기 시간
+const STARTUP_GRACE: Duration = Duration::from_millis(1500);
+
+/// 상태 확인 간격
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 기본 준비 대기 시간 (초)
+const DEFAULT_READY_TIMEOUT_SECS: u64 = 30;
+
+/// 최대 준비 대기 시간 (초)
+const MAX_READY_TIMEOUT_SECS: u64 = 300;
+
+/// 기본 로그 tail 줄 수
+const DEFAULT_TAIL: usize = 50;
+
+/// 최대 로그 tail 줄 수
+const MAX_TAIL: usize = 1000;
+
+/// 시작/종료 결과에 포함할 최근 출력 줄 수
+const SUMMARY_LINES: usize = 15;
+
+// ============================================================================
+// Process Registry
+// ============================================================================
+
+/// `process_start`로 시작한 프로세스 기록
+#[derive(Debug, Clone)]
+struct ProcessRecord {
+    name: String,
+    task_id: TaskId,
+    command: String,
+    working_dir: PathBuf,
+    started_at: Instant,
+}
+
+lazy_static::lazy_static! {
+    static ref PROCESSES: Arc<RwLock<Vec<ProcessRecord>>> = Arc::new(RwLock::new(Vec::new()));
+}
+
+/// 이름 또는 ID로 프로세스 기록 조회
+async fn find_process(id_or_name: &str) -> Option<ProcessRecord> {
+    let processes = PROCESSES.read().await;
+    if let Some(record) = processes.iter().rev().find(|p| p.name == id_or_name) {
+        return Some(record.clone());
+    }
+
+    let task_id = resolve_task_id(id_or_name).await?;
+    processes.iter().find(|p| p.task_id == task_id).cloned()
+}
+
+/// 프로세스 상태
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProcessState {
+    Running,
+    Exited { code: Option<i32> },
+    TimedOut,
+    Stopped,
+    Unknown,
+}
+
+impl ProcessState {
+    fn is_running(&self) -> bool {
+        matches!(self, ProcessState::Running)
+    }
+
+    fn label(&self) -> String {
+        match self {
+            ProcessState::Running => "running".to_string(),
+            ProcessState::Exited { code: Some(code) } => format!("exited (code {})", code),
+            ProcessState::Exited { code: None } => "exited".to_string(),
+            ProcessState::TimedOut => "killed (max runtime exceeded)".to_string(),
+            ProcessState::Stopped => "stopped".to_string(),
+            ProcessState::Unknown => "unknown".to_string(),
+        }
+    }
+}
+
+/// 현재 상태 조회 (PTY 세션 상태 우선)
+async fn process_state(orchestrator: &TaskOrchestrator, task_id: TaskId) -> ProcessState {
+    let pty = orchestrator.task_manager().pty_executor();
+    let id = task_id.to_string();
+
+    match pty.get_status(&id).await {
+        Some(TaskState::Running | TaskState::Pending | TaskState::Queued) => ProcessState::Running,
+        Some(TaskState::Completed(_) | TaskState::Failed(_)) => ProcessState::Exited {
+            code: pty.get_exit_code(&id).await,
+        },
+        Some(TaskState::Timeout) => ProcessState::TimedOut,
+        Some(TaskState::Cancelled) => ProcessState::Stopped,
+        None => match orchestrator.status(task_id).await.map(|s| s.state) {
+            Some(TaskState::Cancelled) => ProcessState::Stopped,
+            Some(TaskState::Failed(_)) => ProcessState::Exited { code: None },
+            _ => ProcessState::Unknown,
+        },
+    }
+}
+
+/// 출력 로그를 줄 단위로 정리 (PTY 출력은 청크 단위로 기록됨)
+///
+/// `include_system`이면 Orchestrator의 시스템 메시지도 `[system]` 접두어로 포함
+async fn output_lines(
+    orchestrator: &TaskOrchestrator,
+    task_id: TaskId,
+    include_system: bool,
+) -> Vec<String> {
+    let entries = orchestrator.get_task_logs(task_id).await.unwrap_or_default();
+    let mut lines = Vec::new();
+    let mut partial = String::new();
+
+    for entry in entries {
+        if entry.level == LogLevel::System {
+            if !include_system {
+                continue;
+            }
+            if !partial.is_empty() {
+                lines.push(std::mem::take(&mut partial));
+            }
+            lines.push(format!("[system] {}", entry.content.trim_end()));
+            continue;
+        }
+
+        partial.push_str(&entry.content.replace("\r\n", "\n").replace('\r', "\n"));
+        while let Some(pos) = partial.find('\n') {
+            let line: String = partial.drain(..=pos).collect();
+            lines.push(line.trim_end_matches('\n').to_string());
+        }
+    }
+
+    if !partial.is_empty() {
+        lines.push(partial);
+    }
+    lines
+}
+
+/// 마지막 N줄
+fn tail(lines: &[String], n: usize) -> &[String] {
+    &lines[lines.len().saturating_sub(n)..]
+}
+
+/// 경과 시간 표시
+fn format_uptime(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {}s", secs / 60, secs % 60),
+        _ => format!("{}h {}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
+
+/// 프로세스 식별자 입력 읽기
+fn process_arg(input: &Value) -> Result<&str> {
+    input["process"].as_str().ok_or_else(|| {
+        forge_foundation::Error::InvalidInput("process (name or id) is required".to_string())
+    })
+}
+
+// ============================================================================
+// ProcessStartTool - 프로세스 시작
+// ============================================================================
+
+/// 프로세스 시작 도구
+pub struct ProcessStartTool;
+
+impl ProcessStartTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ProcessStartTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ProcessStartTool {
+    fn name(&self) -> &str {
+        "process_start"
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new("process_start")
+            .display_name("Process Start")
+            .description("Start a long-running background process (dev server, watcher: `npm run dev`, `cargo watch -x run`) \
+                         without blocking. Optionally waits until output matches `ready_pattern` to verify it booted. \
+                         Check it with process_status/process_logs and shut it down with process_stop.")
+            .category("task")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "command": {
+                    "type": "string",
+                    "description": "Command to run in the background"
+                },
+                "name": {
+                    "type": "string",
+                    "description": "Friendly name used by process_status/process_logs/process_stop (e.g. 'web')"
+                },
+                "working_dir": {
+                    "type": "string",
+                    "description": "Working directory (default: project root)"
+                },
+                "env": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                    "description": "Additional environment variables"
+                },
+                "ready_pattern": {
+                    "type": "string",
+                    "description": "Regex that signals the process is ready (e.g. 'Listening on|ready in')"
+                },
+                "ready_timeout_secs": {
+                    "type": "integer",
+                    "description": "Seconds to wait for ready_pattern (default: 30, max: 300)"
+                }
+            },
+            "required": ["command"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        let command = input["command"].as_str().unwrap_or("");
+        Some(PermissionAction::Execute {
+            command: command.to_string(),
+        })
+    }
+
+    async fn execute(&self, input: Value, ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let command = input["command"].as_str().ok_or_else(|| {
+            forge_foundation::Error::InvalidInput("command is required".to_string())
+        })?;
+
+        // bash와 같은 기준으로 금지/대화형 명령어 차단
+        let analysis = command_analyzer().analyze(command);
+        if analysis.risk == CommandRisk::Forbidden {
+            return Ok(ToolResult::error(format!(
+                "Command blocked: {}. Reason: {}",
+                command,
+                analysis.reason.unwrap_or_else(|| "Forbidden command".to_string())
+            )));
+        }
+        if analysis.risk == CommandRisk::Interactive {
+            return Ok(ToolResult::error(format!(
+                "Interactive commands are not supported: {}. Use non-interactive alternatives.",
+                command
+            )));
+        }
+
+        let ready_pattern = match input["ready_pattern"].as_str() {
+            Some(pattern) => match Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(e) => {
+                    return Ok(ToolResult::error(format!("Invalid ready_pattern: {}", e)));
+                }
+            },
+            None => None,
+        };
+        let ready_timeout = Duration::from_secs(
+            input["ready_timeout_secs"]
+                .as_u64()
+                .unwrap_or(DEFAULT_READY_TIMEOUT_SECS)
+                .min(MAX_READY_TIMEOUT_SECS),
+        );
+
+        let working_dir = match input["working_dir"].as_str() {
+            Some(dir) => ctx.working_dir().join(dir),
+            None => ctx.working_dir().to_path_buf(),
+        };
+        if !working_dir.is_dir() {
+            return Ok(ToolResult::error(format!(
+                "Working directory not found: {}",
+                working_dir.display()
+            )));
+        }
+
+        let orchestrator = get_orchestrator().await;
+
+        // 같은 이름의 프로세스가 실행 중이면 거부
+        if let Some(name) = input["name"].as_str() {
+            if let Some(existing) = find_process(name).await {
+                if process_state(&orchestrator, existing.task_id).await.is_running() {
+                    return Ok(ToolResult::error(format!(
+                        "Process '{}' is already running (id: {}). Stop it first or use a different name.",
+                        name, existing.task_id
+                    )));
+                }
+            }
+        }
+
+        let env: HashMap<String, String> = input["env"]
+            .as_object()
+            .map(|obj| {
+                obj.iter()
+                    .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let task = Task::new(
+            ctx.session_id(),
+            "process_start",
+            command,
+            json!({ "working_dir": working_dir.to_string_lossy() }),
+        )
+        .with_execution_mode(ExecutionMode::Pty)
+        .with_timeout(MAX_RUNTIME)
+        .with_env(env);
+
+        let task_id = orchestrator.spawn(task).await.map_err(|e| {
+            forge_foundation::Error::Task(format!("Failed to start process: {}", e))
+        })?;
+
+        let name = input["name"]
+            .as_str()
+            .map(String::from)
+            .unwrap_or_else(|| task_id.to_string());
+        register_task_name(&name, task_id).await;
+        PROCESSES.write().await.push(ProcessRecord {
+            name: name.clone(),
+            task_id,
+            command: command.to_string(),
+            working_dir: working_dir.clone(),
+            started_at: Instant::now(),
+        });
+
+        info!("Started process '{}' ({}): {}", name, task_id, command);
+
+        // 준비 패턴 또는 즉시 실패 대기
+        let wait = if ready_pattern.is_some() {
+            ready_timeout
+        } else {
+            STARTUP_GRACE
+        };
+        let deadline = Instant::now() + wait;
+        let mut ready_line = None;
+
+        let state = loop {
+            let state = process_state(&orchestrator, task_id).await;
+            if let Some(re) = &ready_pattern {
+                ready_line = output_lines(&orchestrator, task_id, false)
+                    .await
+                    .into_iter()
+                    .find(|line| re.is_match(line));
+                if ready_line.is_some() {
+                    break state;
+                }
+            }
+            if !state.is_running() || Instant::now() >= deadline {
+                break state;
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        };
+
+        let lines = output_lines(&orchestrator, task_id, true).await;
+        let recent = tail(&lines, SUMMARY_LINES).join("\n");
+
+        if !state.is_running() {
+            return Ok(ToolResult::error(format!(
+                "Process '{}' {} shortly after start.\n\ncommand: {}\n\nOutput:\n{}",
+                name,
+                state.label(),
+                command,
+                recent
+            )));
+        }
+
+        let readiness = match (&ready_pattern, &ready_line) {
+            (Some(_), Some(line)) => format!("ready (matched: {})", line.trim()),
+            (Some(_), None) => format!(
+                "running, but ready_pattern not seen after {}s",
+                ready_timeout.as_secs()
+            ),
+            (None, _) => "running".to_string(),
+        };
+
+        let mut output = format!(
+            "Process '{}' started (id: {})\nstatus: {}\ncommand: {}\nworking_dir: {}\n",
+            name,
+            task_id,
+            readiness,
+            command,
+            working_dir.display()
+        );
+        if !recent.is_empty() {
+            output.push_str(&format!("\nRecent output:\n{}\n", recent));
+        }
+        output.push_str(&format!(
+            "\nUse process_logs / process_stop with process '{}'.",
+            name
+        ));
+
+        Ok(ToolResult::success(output)
+            .with_metadata("process", json!(name))
+            .with_metadata("task_id", json!(task_id.to_string()))
+            .with_metadata("ready", json!(ready_line.is_some())))
+    }
+}
+
+// ============================================================================
+// ProcessStatusTool - 프로세스 상태 조회
+// ============================================================================
+
+/// 프로세스 상태 조회 도구
+pub struct ProcessStatusTool;
+
+impl ProcessStatusTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ProcessStatusTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ProcessStatusTool {
+    fn name(&self) -> &str {
+        "process_status"
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new("process_status")
+            .display_name("Process Status")
+            .description("Show the state of background processes started with process_start. \
+                         Omit `process` to list all of them.")
+            .category("task")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "process": {
+                    "type": "string",
+                    "description": "Process name or id (omit to list all)"
+                }
+            }
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, _ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let orchestrator = get_orchestrator().await;
+
+        if let Some(id) = input["process"].as_str() {
+            let Some(record) = find_process(id).await else {
+                return Ok(ToolResult::error(format!("Process not found: {}", id)));
+            };
+
+            let state = process_state(&orchestrator, record.task_id).await;
+            let lines = output_lines(&orchestrator, record.task_id, true).await;
+            let mut output = format!(
+                "Process '{}' (id: {})\nstatus: {}\nuptime: {}\ncommand: {}\nworking_dir: {}\noutput lines: {}\n",
+                record.name,
+                record.task_id,
+                state.label(),
+                format_uptime(record.started_at.elapsed()),
+                record.command,
+                record.working_dir.display(),
+                lines.len()
+            );
+            if !lines.is_empty() {
+                output.push_str(&format!(
+                    "\nRecent output:\n{}",
+                    tail(&lines, SUMMARY_LINES / 3).join("\n")
+                ));
+            }
+
+            return Ok(ToolResult::success(output)
+                .with_metadata("running", json!(state.is_running())));
+        }
+
+        let processes = PROCESSES.read().await.clone();
+        if processes.is_empty() {
+            return Ok(ToolResult::success("No background processes started"));
+        }
+
+        let mut output = String::from("| name | id | status | uptime | command |\n|---|---|---|---|---|\n");
+        let mut running = 0;
+        for record in &processes {
+            let state = process_state(&orchestrator, record.task_id).await;
+            if state.is_running() {
+                running += 1;
+            }
+            output.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                record.name,
+                record.task_id,
+                state.label(),
+                format_uptime(record.started_at.elapsed()),
+                record.command.replace('|', "\\|")
+            ));
+        }
+        output.push_str(&format!("\n{} of {} running", running, processes.len()));
+
+        Ok(ToolResult::success(output).with_metadata("running", json!(running)))
+    }
+}
+
+// ============================================================================
+// ProcessLogsTool - 프로세스 출력 조회
+// ============================================================================
+
+/// 프로세스 출력 조회 도구
+pub struct ProcessLogsTool;
+
+impl ProcessLogsTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ProcessLogsTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ProcessLogsTool {
+    fn name(&self) -> &str {
+        "process_logs"
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new("process_logs")
+            .display_name("Process Logs")
+            .description("Show the last lines of a background process's output, optionally filtered by a regex (grep).")
+            .category("task")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "process": {
+                    "type": "string",
+                    "description": "Process name or id"
+                },
+                "tail": {
+                    "type": "integer",
+                    "description": "Number of lines to return (default: 50, max: 1000)"
+                },
+                "grep": {
+                    "type": "string",
+                    "description": "Only return lines matching this regex (applied before tail)"
+                }
+            },
+            "required": ["process"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // Read-only
+    }
+
+    async fn execute(&self, input: Value, _ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let id = process_arg(&input)?;
+        let Some(record) = find_process(id).await else {
+            return Ok(ToolResult::error(format!("Process not found: {}", id)));
+        };
+
+        let n = input["tail"]
+            .as_u64()
+            .map(|n| n as usize)
+            .unwrap_or(DEFAULT_TAIL)
+            .clamp(1, MAX_TAIL);
+        let grep = match input["grep"].as_str() {
+            Some(pattern) => match Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(e) => return Ok(ToolResult::error(format!("Invalid grep pattern: {}", e))),
+            },
+            None => None,
+        };
+
+        let orchestrator = get_orchestrator().await;
+        let state = process_state(&orchestrator, record.task_id).await;
+        let mut lines = output_lines(&orchestrator, record.task_id, true).await;
+        let total = lines.len();
+        if let Some(re) = &grep {
+            lines.retain(|line| re.is_match(line));
+        }
+        let shown = tail(&lines, n);
+
+        let mut output = format!(
+            "[{}] {} - showing {} of {} {}lines\n",
+            record.name,
+            state.label(),
+            shown.len(),
+            if grep.is_some() { lines.len() } else { total },
+            if grep.is_some() { "matching " } else { "" }
+        );
+        if shown.is_empty() {
+            output.push_str("\n(no output)");
+        } else {
+            output.push('\n');
+            output.push_str(&shown.join("\n"));
+        }
+
+        Ok(ToolResult::success(output)
+            .with_metadata("lines", json!(shown.len()))
+            .with_metadata("running", json!(state.is_running())))
+    }
+}
+
+// ============================================================================
+// ProcessStopTool - 프로세스 종료
+// ============================================================================
+
+/// 프로세스 종료 도구
+pub struct ProcessStopTool;
+
+impl ProcessStopTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ProcessStopTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ProcessStopTool {
+    fn name(&self) -> &str {
+        "process_stop"
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new("process_stop")
+            .display_name("Process Stop")
+            .description("Stop a background process started with process_start and show its final output.")
+            .category("task")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "process": {
+                    "type": "string",
+                    "description": "Process name or id"
+                }
+            },
+            "required": ["process"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None // 자신이 시작한 프로세스만 종료
+    }
+
+    async fn execute(&self, input: Value, _ctx: &dyn ToolContext) -> Result<ToolResult> {
+        let id = process_arg(&input)?;
+        let Some(record) = find_process(id).await else {
+            return Ok(ToolResult::error(format!("Process not found: {}", id)));
+        };
+
+        let orchestrator = get_orchestrator().await;
+        let before = process_state(&orchestrator, record.task_id).await;
+        let uptime = format_uptime(record.started_at.elapsed());
+        let lines = output_lines(&orchestrator, record.task_id, true).await;
+        let recent = tail(&lines, SUMMARY_LINES).join("\n");
+
+        if !before.is_running() {
+            return Ok(ToolResult::success(format!(
+                "Process '{}' is not running ({}).\n\nLast output:\n{}",
+                record.name,
+                before.label(),
+                recent
+            )));
+        }
+
+        orchestrator.stop(record.task_id).await.map_err(|e| {
+            forge_foundation::Error::Task(format!("Failed to stop process: {}", e))
+        })?;
+        info!("Stopped process '{}' ({})", record.name, record.task_id);
+
+        Ok(ToolResult::success(format!(
+            "Stopped process '{}' (ran {}).\n\nLast output:\n{}",
+            record.name, uptime, recent
+        )))
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// 모든 Process 도구 생성
+pub fn process_tools() -> Vec<Arc<dyn Tool>> {
+    vec![
+        Arc::new(ProcessStartTool::new()) as Arc<dyn Tool>,
+        Arc::new(ProcessStatusTool::new()),
+        Arc::new(ProcessLogsTool::new()),
+        Arc::new(ProcessStopTool::new()),
+    ]
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+
+    fn context() -> RuntimeContext {
+        RuntimeContext::new(
+            "test-session",
+            std::env::temp_dir(),
+            Arc::new(PermissionService::with_auto_approve()),
+        )
+    }
+
+    #[test]
+    fn test_process_tools() {
+        let tools = process_tools();
+        let names: Vec<_> = tools.iter().map(|t| t.name()).collect();
+        assert_eq!(
+            names,
+            vec!["process_start", "process_status", "process_logs", "process_stop"]
+        );
+        assert!(ProcessStartTool::new()
+            .required_permission(&json!({ "command": "npm run dev" }))
+            .is_some());
+    }
+
+    #[test]
+    fn test_tail_and_uptime() {
+        let lines: Vec<String> = (1..=5).map(|i| i.to_string()).collect();
+        assert_eq!(tail(&lines, 2), &["4".to_string(), "5".to_string()]);
+        assert_eq!(tail(&lines, 10).len(), 5);
+
+        assert_eq!(format_uptime(Duration::from_secs(42)), "42s");
+        assert_eq!(format_uptime(Duration::from_secs(125)), "2m 5s");
+        assert_eq!(format_uptime(Duration::from_secs(7260)), "2h 1m");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_process_lifecycle() {
+        let ctx = context();
+
+        let result = ProcessStartTool::new()
+            .execute(
+                json!({
+                    "command": "echo booting; echo 'listening on 4321'; sleep 30",
+                    "name": "test-lifecycle-server",
+                    "ready_pattern": "listening on \\d+",
+                    "ready_timeout_secs": 10
+                }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("ready (matched: listening on 4321)"), "{}", result.output);
+
+        let result = ProcessLogsTool::new()
+            .execute(
+                json!({ "process": "test-lifecycle-server", "grep": "^boot" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(result.output.contains("booting"));
+        assert!(!result.output.contains("listening on"));
+
+        let result = ProcessStatusTool::new()
+            .execute(json!({}), &ctx)
+            .await
+            .unwrap();
+        assert!(result.output.contains("test-lifecycle-server"));
+
+        let result = ProcessStopTool::new()
+            .execute(json!({ "process": "test-lifecycle-server" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.starts_with("Stopped process 'test-lifecycle-server'"));
+
+        let orchestrator = get_orchestrator().await;
+        let state = process_state(
+            &orchestrator,
+            find_process("test-lifecycle-server").await.unwrap().task_id,
+        )
+        .await;
+        assert!(!state.is_running());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_process_start_reports_early_exit() {
+        let result = ProcessStartTool::new()
+            .execute(
+                json!({ "command": "echo 'port in use'; exit 3", "name": "test-early-exit" }),
+                &context(),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        let error = result.error.unwrap();
+        assert!(error.contains("exited"), "{}", error);
+        assert!(error.contains("port in use"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_process_start_blocks_like_bash() {
+        let tool = ProcessStartTool::new();
+        for (command, expected) in [("echo .444", "blocked"), ("vim notes.txt", "Interactive")] {
+            let result = tool
+                .execute(json!({ "command": command }), &context())
+                .await
+                .unwrap();
+            assert!(!result.success, "{}", command);
+            let error = result.error.unwrap();
+            assert!(error.contains(expected), "{}", error);
+        }
+    }
+}
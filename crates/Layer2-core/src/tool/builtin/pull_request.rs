@@ -0,0 +1,365 @@
+//! Pull Request Tool - PR/MR 생성, 조회, 리뷰 코멘트
+//!
+//! `git::forge`의 `CodeHost`(gh/glab CLI)로 GitHub PR / GitLab MR 작업을 수행합니다.
+//! `/commit` 후 PR 생성, `/review-pr`의 diff 조회와 리뷰 코멘트 작성을
+//! bash 명령 조합 없이 한 도구로 처리합니다.
+//!
+//! - 조회 (`list`, `view`, `diff`): 권한 불필요
+//! - 게시 (`create`, `comment`): `git.publish` 권한 (원격 저장소에 공개됨)
+
+use crate::git::{CodeHost, GitError, GitOps, NewPullRequest, PrState, PullRequest, ReviewComment};
+use async_trait::async_trait;
+use forge_foundation::{
+    PermissionAction, PermissionDef, Result, Tool, ToolContext, ToolMeta, ToolResult,
+};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+/// 기본 목록 크기
+const DEFAULT_LIMIT: usize = 20;
+
+/// PR 생성 전 브랜치를 올릴 원격
+const REMOTE: &str = "origin";
+
+/// PR/MR 도구
+pub struct PullRequestTool;
+
+impl PullRequestTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "pull_request";
+
+    /// 게시 권한 이름
+    pub const PUBLISH_PERMISSION: &'static str = "git.publish";
+
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PullRequestTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// gh/glab 호출은 동기 프로세스 실행이므로 blocking 스레드에서 실행
+async fn blocking<T, F>(working_dir: &Path, f: F) -> std::result::Result<T, GitError>
+where
+    T: Send + 'static,
+    F: FnOnce(&Path) -> std::result::Result<T, GitError> + Send + 'static,
+{
+    let dir: PathBuf = working_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || f(&dir))
+        .await
+        .map_err(|e| GitError::HostCommandFailed(e.to_string()))?
+}
+
+/// 번호가 없으면 현재 브랜치의 열린 PR
+fn resolve_pr(
+    host: &CodeHost,
+    dir: &Path,
+    number: Option<u64>,
+) -> std::result::Result<u64, GitError> {
+    if let Some(number) = number {
+        return Ok(number);
+    }
+    let branch = GitOps::new(dir)?.current_branch()?;
+    host.pr_for_branch(&branch)?
+        .map(|pr| pr.number)
+        .ok_or_else(|| {
+            GitError::HostCommandFailed(format!(
+                "No open {} for branch '{}'; pass a number",
+                host.kind().request_term(),
+                branch
+            ))
+        })
+}
+
+/// 현재 브랜치를 올리고 PR 생성 (이미 있으면 기존 PR 반환)
+fn create_for_current_branch(
+    dir: &Path,
+    request: NewPullRequest,
+) -> std::result::Result<(PullRequest, bool), GitError> {
+    let host = CodeHost::detect(dir)?;
+    let repo = GitOps::new(dir)?;
+    let branch = repo.current_branch()?;
+    if branch == "HEAD" {
+        return Err(GitError::BranchNotFound(
+            "detached HEAD; check out a branch first".to_string(),
+        ));
+    }
+    if request.base.as_deref() == Some(branch.as_str()) {
+        return Err(GitError::HostCommandFailed(format!(
+            "'{}' is the target branch; commit to a feature branch first",
+            branch
+        )));
+    }
+
+    repo.push_branch(REMOTE, &branch)?;
+    if let Some(existing) = host.pr_for_branch(&branch)? {
+        return Ok((existing, false));
+    }
+    let pr = host.create_pr(&request.with_head(branch))?;
+    Ok((pr, true))
+}
+
+fn render_pr(pr: &PullRequest) -> String {
+    if pr.url.is_empty() {
+        pr.summary()
+    } else {
+        format!("{}\n  {}", pr.summary(), pr.url)
+    }
+}
+
+#[async_trait]
+impl Tool for PullRequestTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Pull Request")
+            .description("Work with GitHub pull requests / GitLab merge requests of this repository (via gh/glab): list, view, diff, create (pushes the current branch) and comment (general or inline on path:line). Without a number, view/diff/comment use the PR of the current branch.")
+            .category("git")
+            .permission(
+                PermissionDef::new(Self::PUBLISH_PERMISSION, "git")
+                    .risk_level(6)
+                    .description("Push a branch, open a pull request or post a review comment")
+                    .requires_confirmation(true),
+            )
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["list", "view", "diff", "create", "comment"],
+                    "description": "What to do"
+                },
+                "number": {
+                    "type": "integer",
+                    "description": "PR/MR number (view, diff, comment; default: PR of the current branch)"
+                },
+                "state": {
+                    "type": "string",
+                    "enum": ["open", "closed", "merged", "all"],
+                    "description": "list: state filter (default: open)"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "list: maximum results (default: 20)"
+                },
+                "title": {
+                    "type": "string",
+                    "description": "create: PR title"
+                },
+                "body": {
+                    "type": "string",
+                    "description": "create: PR description; comment: comment text"
+                },
+                "base": {
+                    "type": "string",
+                    "description": "create: target branch (default: repository default branch)"
+                },
+                "draft": {
+                    "type": "boolean",
+                    "description": "create: open as draft"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "comment: file for an inline comment"
+                },
+                "line": {
+                    "type": "integer",
+                    "description": "comment: line in the new version of `path`"
+                }
+            },
+            "required": ["action"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        let details = match input.get("action")?.as_str()? {
+            "create" => format!(
+                "create pull request: {}",
+                input["title"].as_str().unwrap_or_default()
+            ),
+            "comment" => match input["number"].as_u64() {
+                Some(number) => format!("comment on #{}", number),
+                None => "comment on the current branch's pull request".to_string(),
+            },
+            _ => return None,
+        };
+        Some(PermissionAction::Custom {
+            name: Self::PUBLISH_PERMISSION.to_string(),
+            details,
+        })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let dir = context.working_dir();
+        let number = input["number"].as_u64();
+        let text = |key: &str| input[key].as_str().map(String::from);
+
+        let result = match input["action"].as_str().unwrap_or_default() {
+            "list" => {
+                let state = match input["state"].as_str() {
+                    Some(s) => match PrState::parse(s) {
+                        Some(state) => state,
+                        None => return Ok(ToolResult::error(format!("Invalid state '{}'", s))),
+                    },
+                    None => PrState::Open,
+                };
+                let limit = input["limit"]
+                    .as_u64()
+                    .map_or(DEFAULT_LIMIT, |n| n as usize);
+                blocking(dir, move |dir| {
+                    let host = CodeHost::detect(dir)?;
+                    let prs = host.list_prs(state, limit)?;
+                    if prs.is_empty() {
+                        return Ok(format!("No {}s found", host.kind().request_term()));
+                    }
+                    Ok(prs.iter().map(render_pr).collect::<Vec<_>>().join("\n"))
+                })
+                .await
+            }
+            "view" => {
+                blocking(dir, move |dir| {
+                    let host = CodeHost::detect(dir)?;
+                    let number = resolve_pr(&host, dir, number)?;
+                    Ok(render_pr(&host.get_pr(number)?))
+                })
+                .await
+            }
+            "diff" => {
+                blocking(dir, move |dir| {
+                    let host = CodeHost::detect(dir)?;
+                    let number = resolve_pr(&host, dir, number)?;
+                    let diff = host.get_pr_diff(number)?;
+                    Ok(if diff.trim().is_empty() {
+                        format!("#{} has no changes", number)
+                    } else {
+                        diff
+                    })
+                })
+                .await
+            }
+            "create" => {
+                let Some(title) = text("title").filter(|t| !t.trim().is_empty()) else {
+                    return Ok(ToolResult::error("create requires a title"));
+                };
+                let mut request = NewPullRequest::new(title)
+                    .with_body(text("body").unwrap_or_default())
+                    .with_draft(input["draft"].as_bool().unwrap_or(false));
+                if let Some(base) = text("base") {
+                    request = request.with_base(base);
+                }
+                blocking(dir, move |dir| {
+                    let (pr, created) = create_for_current_branch(dir, request)?;
+                    let verb = if created { "Created" } else { "Already open:" };
+                    Ok(format!("{} {}", verb, render_pr(&pr)))
+                })
+                .await
+            }
+            "comment" => {
+                let Some(body) = text("body").filter(|b| !b.trim().is_empty()) else {
+                    return Ok(ToolResult::error("comment requires a body"));
+                };
+                let comment = match (text("path"), input["line"].as_u64()) {
+                    (Some(path), Some(line)) => ReviewComment::inline(path, line as u32, body),
+                    (Some(path), None) => ReviewComment {
+                        path: Some(path),
+                        ..ReviewComment::general(body)
+                    },
+                    _ => ReviewComment::general(body),
+                };
+                blocking(dir, move |dir| {
+                    let host = CodeHost::detect(dir)?;
+                    let number = resolve_pr(&host, dir, number)?;
+                    host.post_review_comment(number, &comment)?;
+                    Ok(format!(
+                        "Commented on {} #{}",
+                        host.kind().request_term(),
+                        number
+                    ))
+                })
+                .await
+            }
+            other => {
+                return Ok(ToolResult::error(format!(
+                    "Unknown action '{}': use list, view, diff, create or comment",
+                    other
+                )))
+            }
+        };
+
+        Ok(match result {
+            Ok(output) => ToolResult::success(output),
+            Err(e) => ToolResult::error(e.to_string()),
+        })
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_publish_actions_need_permission() {
+        let tool = PullRequestTool::new();
+        assert!(tool
+            .required_permission(&json!({"action": "list"}))
+            .is_none());
+        assert!(tool
+            .required_permission(&json!({"action": "diff", "number": 3}))
+            .is_none());
+
+        match tool.required_permission(&json!({"action": "create", "title": "Add X"})) {
+            Some(PermissionAction::Custom { name, details }) => {
+                assert_eq!(name, PullRequestTool::PUBLISH_PERMISSION);
+                assert_eq!(details, "create pull request: Add X");
+            }
+            other => panic!("unexpected permission: {:?}", other),
+        }
+        assert!(tool
+            .required_permission(&json!({"action": "comment", "number": 4, "body": "x"}))
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let tool = PullRequestTool::new();
+
+        let result = tool
+            .execute(json!({"action": "merge"}), &ctx)
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("Unknown action"));
+
+        let result = tool
+            .execute(json!({"action": "create"}), &ctx)
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("requires a title"));
+
+        // git 저장소가 아님
+        let result = tool.execute(json!({"action": "list"}), &ctx).await.unwrap();
+        assert!(result.error.unwrap().contains("Not a git repository"));
+    }
+}
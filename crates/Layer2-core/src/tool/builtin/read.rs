@@ -3,7 +3,9 @@
 //! 파일 내용을 읽어서 반환합니다.
 //! - 줄 번호 포함 (cat -n 스타일)
 //! - offset/limit 지원 (대용량 파일 처리)
-//! - 이미지/PDF 등 바이너리 파일 감지
+//! - PDF/DOCX 텍스트 추출 (페이지 범위 선택, 토큰 단위 분할)
+//! - 이미지는 vision 모델용 첨부로 반환 (metadata `images`)
+//! - 그 외 바이너리 파일 감지
 //! - 경로 보안 검증 (path traversal 방지)
 
 use async_trait::async_trait;
@@ -16,6 +18,9 @@ use std::fs;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
+use crate::tool::document::{
+    chunk_by_tokens, extract_docx_text, extract_pdf_pages, load_image, DocumentKind, PageRange,
+};
 use crate::tool::security::{is_sensitive_path, PathValidator};
 
 /// Read 도구 입력
@@ -32,6 +37,14 @@ pub struct ReadInput {
     /// 최대 읽을 줄 수 (optional, 기본: 2000)
     #[serde(default)]
     pub limit: Option<u32>,
+
+    /// PDF 페이지 범위 (예: "1-5", "2,4-6")
+    #[serde(default)]
+    pub pages: Option<String>,
+
+    /// 문서 청크 번호 (1-based, 기본: 1)
+    #[serde(default)]
+    pub chunk: Option<u32>,
 }
 
 /// Read 도구
@@ -52,6 +65,8 @@ impl ReadTool {
     /// 최대 줄 길이 (이 이상은 잘림)
     const MAX_LINE_LENGTH: usize = 2000;
 
+    /// 문서 청크당 최대 토큰 수
+    const DOCUMENT_CHUNK_TOKENS: usize = 10_000;
 
     /// 바이너리 파일인지 확인
     fn is_binary_file(path: &Path) -> bool {
@@ -107,6 +122,123 @@ impl ReadTool {
 
         Ok(output)
     }
+
+    /// 이미지 파일 읽기 - 첨부 이미지를 metadata `images`로 반환
+    fn read_image(path: &Path, display_path: &str) -> Result<ToolResult> {
+        let image = load_image(path)?;
+        let size_kb = fs::metadata(path).map(|m| m.len() / 1024).unwrap_or(0);
+
+        Ok(ToolResult::success(format!(
+            "[Image: {} ({}, {} KB) - attached for vision-capable models]",
+            display_path, image.media_type, size_kb
+        ))
+        .with_metadata("images", json!([image])))
+    }
+
+    /// PDF/DOCX 문서 읽기 - 텍스트 추출 후 청크 단위로 반환
+    fn read_document(path: &Path, kind: DocumentKind, input: &ReadInput) -> Result<ToolResult> {
+        let data = fs::read(path)?;
+
+        let (text, scope, total_pages) = match kind {
+            DocumentKind::Pdf => {
+                let pages = extract_pdf_pages(&data)?;
+                let total = pages.len();
+                let selected = match &input.pages {
+                    Some(spec) => PageRange::parse(spec)?.select(total),
+                    None => (1..=total).collect(),
+                };
+                if selected.is_empty() {
+                    return Ok(ToolResult::error(format!(
+                        "No pages selected: document has {} pages",
+                        total
+                    )));
+                }
+
+                let text = selected
+                    .iter()
+                    .map(|p| format!("--- Page {} ---\n{}\n", p, pages[p - 1]))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let scope = format!("pages {} of {}", describe_pages(&selected), total);
+                (text, scope, Some(total))
+            }
+            DocumentKind::Docx => {
+                let scope = if input.pages.is_some() {
+                    "full document (page ranges are not available for DOCX)".to_string()
+                } else {
+                    "full document".to_string()
+                };
+                (extract_docx_text(&data)?, scope, None)
+            }
+            DocumentKind::Image(_) => unreachable!("images are handled by read_image"),
+        };
+
+        if text.trim().is_empty() {
+            return Ok(ToolResult::success(format!(
+                "[{}: {} - no extractable text (scanned or image-only document?)]",
+                kind.label(),
+                input.file_path
+            )));
+        }
+
+        let chunks = chunk_by_tokens(&text, Self::DOCUMENT_CHUNK_TOKENS);
+        let index = input.chunk.unwrap_or(1) as usize;
+        if index == 0 || index > chunks.len() {
+            return Ok(ToolResult::error(format!(
+                "Invalid chunk {}: document has {} chunk(s)",
+                index,
+                chunks.len()
+            )));
+        }
+
+        let mut output = format!(
+            "[{}: {} - {}, chunk {}/{}]\n\n{}",
+            kind.label(),
+            input.file_path,
+            scope,
+            index,
+            chunks.len(),
+            chunks[index - 1].trim_end()
+        );
+        if index < chunks.len() {
+            output.push_str(&format!(
+                "\n\n[Document continues - read again with chunk: {}]",
+                index + 1
+            ));
+        }
+
+        let mut result = ToolResult::success(output)
+            .with_metadata("format", json!(kind.label().to_lowercase()))
+            .with_metadata("chunk", json!(index))
+            .with_metadata("chunks", json!(chunks.len()));
+        if let Some(total) = total_pages {
+            result = result.with_metadata("total_pages", json!(total));
+        }
+        Ok(result)
+    }
+}
+
+/// 페이지 목록을 범위 문자열로 (예: [1,2,3,5] → "1-3, 5")
+fn describe_pages(pages: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &page in pages {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == page => *end = page,
+            _ => ranges.push((page, page)),
+        }
+    }
+
+    ranges
+        .iter()
+        .map(|(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{}-{}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 impl Default for ReadTool {
@@ -120,7 +252,7 @@ impl Tool for ReadTool {
     fn meta(&self) -> ToolMeta {
         ToolMeta::new(Self::NAME)
             .display_name("Read File")
-            .description("Read file contents with line numbers")
+            .description("Read file contents with line numbers, extract text from PDF/DOCX, or attach images")
             .category("filesystem")
             .permission(
                 PermissionDef::new("file.read.sensitive", "filesystem")
@@ -149,6 +281,14 @@ impl Tool for ReadTool {
                 "limit": {
                     "type": "integer",
                     "description": "Maximum lines to read (default: 2000). Only provide if the file is too large to read at once."
+                },
+                "pages": {
+                    "type": "string",
+                    "description": "PDF page range to extract (e.g., \"1-5\", \"2,4-6\", \"10-\"). Defaults to all pages."
+                },
+                "chunk": {
+                    "type": "integer",
+                    "description": "Chunk number (1-based) for long PDF/DOCX text. The output says when more chunks are available."
                 }
             },
             "required": ["file_path"]
@@ -176,6 +316,8 @@ impl Tool for ReadTool {
                 file_path: path.clone(),
                 offset: None,
                 limit: None,
+                pages: None,
+                chunk: None,
             },
             // 객체 입력
             Value::Object(obj) => {
@@ -190,6 +332,8 @@ impl Tool for ReadTool {
                             file_path: path.clone(),
                             offset: obj.get("offset").and_then(|v| v.as_u64().map(|n| n as u32)),
                             limit: obj.get("limit").and_then(|v| v.as_u64().map(|n| n as u32)),
+                            pages: obj.get("pages").and_then(|v| v.as_str().map(String::from)),
+                            chunk: obj.get("chunk").and_then(|v| v.as_u64().map(|n| n as u32)),
                         }
                     } else {
                         return Ok(ToolResult::error("Invalid input: please provide a 'file_path' field with the file to read. Example: {\"file_path\": \"src/main.rs\"}"));
@@ -265,6 +409,17 @@ impl Tool for ReadTool {
             }
         }
 
+        // 문서/이미지 처리
+        if let Some(kind) = DocumentKind::from_path(path) {
+            let result = match kind {
+                DocumentKind::Image(_) => Self::read_image(path, &parsed.file_path),
+                _ => Self::read_document(path, kind, &parsed),
+            };
+            return Ok(result.unwrap_or_else(|e| {
+                ToolResult::error(format!("Failed to read {}: {}", kind.label(), e))
+            }));
+        }
+
         // 바이너리 파일 체크
         if Self::is_binary_file(path) {
             let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
@@ -347,4 +502,74 @@ mod tests {
         let perm = tool.required_permission(&input);
         assert!(perm.is_none());
     }
+
+    #[test]
+    fn test_describe_pages() {
+        assert_eq!(describe_pages(&[1, 2, 3, 5, 7, 8]), "1-3, 5, 7-8");
+        assert_eq!(describe_pages(&[4]), "4");
+    }
+
+    fn context(dir: &Path) -> crate::tool::RuntimeContext {
+        crate::tool::RuntimeContext::new(
+            "test-session",
+            dir.to_path_buf(),
+            std::sync::Arc::new(forge_foundation::PermissionService::with_auto_approve()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_read_image_attaches_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("shot.png"), b"\x89PNG\r\n\x1a\ndata").unwrap();
+
+        let result = ReadTool::new()
+            .execute(json!({ "file_path": "shot.png" }), &context(dir.path()))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(result.output.contains("image/png"));
+        let images = result.metadata["images"].as_array().unwrap();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0]["media_type"], "image/png");
+    }
+
+    #[tokio::test]
+    async fn test_read_pdf_page_range() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut pdf = String::from("%PDF-1.4\n1 0 obj << /Type /Catalog /Pages 2 0 R >> endobj\n");
+        pdf.push_str("2 0 obj << /Type /Pages /Kids [3 0 R 5 0 R] >> endobj\n");
+        for (page, content, text) in [(3, 4, "First page"), (5, 6, "Second page")] {
+            let stream = format!("BT 72 720 Td ({}) Tj ET", text);
+            pdf.push_str(&format!(
+                "{} 0 obj << /Type /Page /Contents {} 0 R >> endobj\n{} 0 obj << /Length {} >>\nstream\n{}\nendstream\nendobj\n",
+                page, content, content, stream.len(), stream
+            ));
+        }
+        fs::write(dir.path().join("doc.pdf"), pdf).unwrap();
+        let ctx = context(dir.path());
+        let tool = ReadTool::new();
+
+        let result = tool
+            .execute(json!({ "file_path": "doc.pdf", "pages": "2" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("pages 2 of 2"));
+        assert!(result.output.contains("Second page"));
+        assert!(!result.output.contains("First page"));
+        assert_eq!(result.metadata["total_pages"], 2);
+
+        let result = tool
+            .execute(json!({ "file_path": "doc.pdf", "pages": "3-4" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+
+        let result = tool
+            .execute(json!({ "file_path": "doc.pdf", "chunk": 2 }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
 }
@@ -0,0 +1,370 @@
+//! Resolve Conflict Tool - 머지 충돌 hunk 단위 해결
+//!
+//! merge/rebase/cherry-pick/stash pop 후 남은 충돌을 파일별 ours/base/theirs
+//! hunk로 보여주고, 한 hunk씩 해결합니다. 해결마다 `git.resolve` 권한을
+//! 요청하므로 사용자가 제안된 해결을 hunk 단위로 승인합니다.
+//! 파일의 마지막 hunk가 해결되면 자동으로 `git add` 됩니다.
+
+use crate::git::{resolve_hunk, ConflictResolution, GitOps};
+use async_trait::async_trait;
+use forge_foundation::{
+    PermissionAction, PermissionDef, Result, Tool, ToolContext, ToolMeta, ToolResult,
+};
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// 머지 충돌 해결 도구
+pub struct ResolveConflictTool;
+
+impl ResolveConflictTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "resolve_conflict";
+
+    /// 해결 권한 이름
+    pub const RESOLVE_PERMISSION: &'static str = "git.resolve";
+
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 충돌 목록
+    fn list(git: &GitOps, path: Option<&str>) -> ToolResult {
+        let conflicts = match git.conflicts() {
+            Ok(conflicts) => conflicts,
+            Err(e) => return ToolResult::error(e.to_string()),
+        };
+        let operation = git.conflict_operation().ok().flatten();
+        if conflicts.is_empty() {
+            return ToolResult::success(match operation {
+                Some(op) => format!(
+                    "No conflicts left. Finish with `git {} --continue`.",
+                    op.command()
+                ),
+                None => "No merge conflicts".to_string(),
+            });
+        }
+
+        let shown: Vec<_> = conflicts
+            .iter()
+            .filter(|f| path.is_none_or(|p| f.path == Path::new(p)))
+            .collect();
+        if shown.is_empty() {
+            return ToolResult::error(format!("'{}' has no conflicts", path.unwrap_or_default()));
+        }
+
+        let hunks: usize = conflicts.iter().map(|f| f.hunks.len()).sum();
+        let mut output = format!(
+            "{} conflicted file{}, {} hunk{}{}\n\n",
+            conflicts.len(),
+            if conflicts.len() == 1 { "" } else { "s" },
+            hunks,
+            if hunks == 1 { "" } else { "s" },
+            operation
+                .map(|op| format!(" (during {})", op.command()))
+                .unwrap_or_default()
+        );
+        for file in &shown {
+            output.push_str(&file.render());
+            output.push('\n');
+        }
+        ToolResult::success(output.trim_end())
+            .with_metadata("conflicts", json!(shown))
+            .with_metadata("operation", json!(operation))
+    }
+
+    /// hunk 하나 해결 (마지막 hunk면 `git add`)
+    fn resolve(
+        git: &GitOps,
+        path: &str,
+        hunk: usize,
+        resolution: &ConflictResolution,
+    ) -> ToolResult {
+        let conflicted = match git.conflicted_files() {
+            Ok(files) => files,
+            Err(e) => return ToolResult::error(e.to_string()),
+        };
+        // 충돌 중인 파일만 수정 (저장소 밖 경로 차단)
+        if !conflicted.iter().any(|f| f == Path::new(path)) {
+            return ToolResult::error(format!("'{}' is not a conflicted file", path));
+        }
+
+        let full_path = git.root().join(path);
+        let content = match std::fs::read_to_string(&full_path) {
+            Ok(content) => content,
+            Err(e) => return ToolResult::error(format!("Cannot read {}: {}", path, e)),
+        };
+        let resolved = match resolve_hunk(&content, hunk.saturating_sub(1), resolution) {
+            Ok(resolved) => resolved,
+            Err(e) => return ToolResult::error(e.to_string()),
+        };
+        if let Err(e) = std::fs::write(&full_path, &resolved) {
+            return ToolResult::error(format!("Cannot write {}: {}", path, e));
+        }
+
+        let remaining = crate::git::parse_conflicts(&resolved).len();
+        if remaining > 0 {
+            return ToolResult::success(format!(
+                "Resolved hunk {} of {} with {}. {} hunk{} left in this file (renumbered from 1).",
+                hunk,
+                path,
+                resolution.describe(),
+                remaining,
+                if remaining == 1 { "" } else { "s" }
+            ));
+        }
+
+        if let Err(e) = git.mark_resolved(Path::new(path)) {
+            return ToolResult::error(format!("Resolved {} but git add failed: {}", path, e));
+        }
+        let left = git.conflicted_files().map(|f| f.len()).unwrap_or_default();
+        let next = match (left, git.conflict_operation().ok().flatten()) {
+            (0, Some(op)) => format!(
+                " All conflicts resolved; run the tests, then `git {} --continue`.",
+                op.command()
+            ),
+            (0, None) => " All conflicts resolved.".to_string(),
+            (n, _) => format!(
+                " {} conflicted file{} left.",
+                n,
+                if n == 1 { "" } else { "s" }
+            ),
+        };
+        ToolResult::success(format!(
+            "Resolved hunk {} of {} with {}; file marked resolved.{}",
+            hunk,
+            path,
+            resolution.describe(),
+            next
+        ))
+    }
+}
+
+impl Default for ResolveConflictTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ResolveConflictTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Resolve Conflict")
+            .description("List merge conflicts as ours/base/theirs hunks per file, or resolve one hunk (ours, theirs, both, base, or custom text). Hunks are numbered from 1 and renumbered after each resolution; a file is marked resolved (git add) when its last hunk is done.")
+            .category("git")
+            .permission(
+                PermissionDef::new(Self::RESOLVE_PERMISSION, "git")
+                    .risk_level(5)
+                    .description("Apply a proposed resolution to a merge-conflict hunk")
+                    .requires_confirmation(true),
+            )
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["list", "resolve"],
+                    "description": "list conflicts, or resolve one hunk"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Conflicted file (relative to the repository root)"
+                },
+                "hunk": {
+                    "type": "integer",
+                    "description": "resolve: hunk number in the file (from 1)"
+                },
+                "resolution": {
+                    "type": "string",
+                    "enum": ["ours", "theirs", "both", "base", "custom"],
+                    "description": "resolve: which side to keep (both = ours then theirs; base needs diff3 markers)"
+                },
+                "content": {
+                    "type": "string",
+                    "description": "resolve: merged text replacing the hunk (resolution = custom)"
+                }
+            },
+            "required": ["action"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        if input.get("action")?.as_str()? != "resolve" {
+            return None;
+        }
+        let resolution = ConflictResolution::parse(
+            input["resolution"].as_str().unwrap_or_default(),
+            input["content"].as_str(),
+        );
+        let details = match (&resolution, input["content"].as_str()) {
+            (Some(ConflictResolution::Custom(_)), Some(content)) => {
+                format!("{}\n{}", hunk_label(input), content)
+            }
+            (Some(resolution), _) => format!("{} → {}", hunk_label(input), resolution.describe()),
+            (None, _) => hunk_label(input),
+        };
+        Some(PermissionAction::Custom {
+            name: Self::RESOLVE_PERMISSION.to_string(),
+            details,
+        })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let git = match GitOps::new(context.working_dir()) {
+            Ok(git) => git,
+            Err(e) => return Ok(ToolResult::error(e.to_string())),
+        };
+        let path = input["path"].as_str();
+
+        match input["action"].as_str().unwrap_or_default() {
+            "list" => Ok(Self::list(&git, path)),
+            "resolve" => {
+                let Some(path) = path else {
+                    return Ok(ToolResult::error("resolve requires a path"));
+                };
+                let Some(hunk) = input["hunk"].as_u64().filter(|&n| n > 0) else {
+                    return Ok(ToolResult::error("resolve requires a hunk number (from 1)"));
+                };
+                let Some(resolution) = ConflictResolution::parse(
+                    input["resolution"].as_str().unwrap_or_default(),
+                    input["content"].as_str(),
+                ) else {
+                    return Ok(ToolResult::error(
+                        "resolution must be ours, theirs, both, base, or custom with content",
+                    ));
+                };
+                Ok(Self::resolve(&git, path, hunk as usize, &resolution))
+            }
+            other => Ok(ToolResult::error(format!(
+                "Unknown action '{}': use list or resolve",
+                other
+            ))),
+        }
+    }
+}
+
+/// 권한 요청에 표시할 hunk 위치 (`src/a.rs hunk 2`)
+fn hunk_label(input: &Value) -> String {
+    format!(
+        "{} hunk {}",
+        input["path"].as_str().unwrap_or("?"),
+        input["hunk"].as_u64().unwrap_or(0)
+    )
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::process::Command;
+    use std::sync::Arc;
+
+    /// main과 feature가 같은 줄을 바꾼 뒤 merge해 충돌을 만듦
+    fn conflicted_repo(dir: &Path) -> bool {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .is_ok_and(|o| o.status.success())
+        };
+        let write = |content: &str| std::fs::write(dir.join("a.txt"), content).is_ok();
+        git(&["init", "-q", "-b", "main"])
+            && git(&["config", "user.email", "forge@example.com"])
+            && git(&["config", "user.name", "Forge"])
+            && write("start\nvalue = 0\nend\n")
+            && git(&["add", "a.txt"])
+            && git(&["commit", "-qm", "add a"])
+            && git(&["checkout", "-qb", "feature"])
+            && write("start\nvalue = 2\nend\n")
+            && git(&["commit", "-qam", "feature"])
+            && git(&["checkout", "-q", "main"])
+            && write("start\nvalue = 1\nend\n")
+            && git(&["commit", "-qam", "main"])
+    }
+
+    #[test]
+    fn test_resolve_permission_shows_proposal() {
+        let tool = ResolveConflictTool::new();
+        assert!(tool
+            .required_permission(&json!({"action": "list"}))
+            .is_none());
+
+        let action = tool.required_permission(&json!({
+            "action": "resolve", "path": "a.txt", "hunk": 1, "resolution": "theirs"
+        }));
+        match action {
+            Some(PermissionAction::Custom { name, details }) => {
+                assert_eq!(name, ResolveConflictTool::RESOLVE_PERMISSION);
+                assert_eq!(details, "a.txt hunk 1 → theirs");
+            }
+            other => panic!("unexpected permission: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_and_resolve_merge_conflict() {
+        let dir = tempfile::tempdir().unwrap();
+        if !conflicted_repo(dir.path()) {
+            return;
+        }
+        let git = GitOps::new(dir.path()).unwrap();
+        assert!(matches!(
+            git.merge("feature"),
+            Err(crate::git::GitError::MergeConflict)
+        ));
+
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        );
+        let tool = ResolveConflictTool::new();
+
+        let listed = tool.execute(json!({"action": "list"}), &ctx).await.unwrap();
+        assert!(listed.success);
+        assert!(listed
+            .output
+            .contains("1 conflicted file, 1 hunk (during merge)"));
+        assert!(listed.output.contains("ours (HEAD):\n```\nvalue = 1\n```"));
+        assert!(listed
+            .output
+            .contains("theirs (feature):\n```\nvalue = 2\n```"));
+
+        let bad = tool
+            .execute(
+                json!({"action": "resolve", "path": "../etc/passwd", "hunk": 1, "resolution": "ours"}),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(bad.error.unwrap().contains("not a conflicted file"));
+
+        let resolved = tool
+            .execute(
+                json!({"action": "resolve", "path": "a.txt", "hunk": 1, "resolution": "custom", "content": "value = 3"}),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(resolved.success, "{:?}", resolved.error);
+        assert!(resolved.output.contains("git merge --continue"));
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("a.txt")).unwrap(),
+            "start\nvalue = 3\nend\n"
+        );
+        assert!(git.conflicted_files().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,1062 @@
+//! SQL Query Tool - 데이터베이스 조회 도구
+//!
+//! SQLite 파일 또는 설정된 연결 문자열로 SQL을 실행하고
+//! 결과를 Markdown 테이블로 반환합니다.
+//! - SQLite: 내장 (rusqlite), 파일 경로 또는 `sqlite://path`
+//! - Postgres: `postgres://...` - `psql` 클라이언트 필요
+//! - MySQL: `mysql://...` - `mysql` 클라이언트 필요
+//!
+//! 기본은 읽기 전용입니다. 데이터를 바꾸는 쿼리는 `database.write` 권한이
+//! 필요하며, 읽기 쿼리는 DB 수준에서도 읽기 전용으로 실행됩니다
+//! (SQLite read-only open, Postgres `default_transaction_read_only`,
+//! MySQL `SET SESSION TRANSACTION READ ONLY`). Postgres/MySQL은 클라이언트를
+//! 거치므로 단일 문장만 받고, 클라이언트 명령어(`\! cmd`, `system` 등)는 거부합니다.
+//!
+//! 연결 이름은 `ForgeConfig::databases`에서 조회됩니다:
+//!
+//! ```json
+//! { "databases": { "app": "postgres://dev@localhost/app", "local": "data/dev.sqlite" } }
+//! ```
+
+use crate::config::ConfigLoader;
+use async_trait::async_trait;
+use forge_foundation::{
+    PermissionAction, PermissionDef, PermissionStatus, Result, Tool, ToolContext, ToolMeta,
+    ToolResult,
+};
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// SQL 쿼리 도구 입력
+#[derive(Debug, Deserialize)]
+pub struct SqlQueryInput {
+    /// SQLite 파일 경로, 연결 문자열, 또는 설정된 연결 이름
+    pub database: String,
+
+    /// 실행할 SQL (단일 문장)
+    pub query: String,
+
+    /// 최대 반환 행 수
+    #[serde(default)]
+    pub max_rows: Option<usize>,
+}
+
+/// SQL 쿼리 도구 설정
+#[derive(Debug, Clone)]
+pub struct SqlQueryConfig {
+    /// 이름 → 연결 문자열 (설정 파일의 `databases`보다 우선)
+    pub connections: HashMap<String, String>,
+
+    /// 기본 최대 행 수
+    pub default_max_rows: usize,
+
+    /// 최대 행 수 상한
+    pub max_rows_limit: usize,
+
+    /// 셀 최대 문자 수 - 초과분은 잘림
+    pub max_cell_chars: usize,
+
+    /// 쿼리 타임아웃
+    pub timeout: Duration,
+}
+
+impl Default for SqlQueryConfig {
+    fn default() -> Self {
+        Self {
+            connections: HashMap::new(),
+            default_max_rows: 100,
+            max_rows_limit: 1000,
+            max_cell_chars: 200,
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// 연결 대상
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DatabaseTarget {
+    Sqlite(PathBuf),
+    Postgres(String),
+    Mysql(String),
+}
+
+impl DatabaseTarget {
+    /// 연결 문자열 해석 (상대 경로는 작업 디렉토리 기준)
+    fn parse(connection: &str, working_dir: &Path) -> Self {
+        if connection.starts_with("postgres://") || connection.starts_with("postgresql://") {
+            return DatabaseTarget::Postgres(connection.to_string());
+        }
+        if connection.starts_with("mysql://") {
+            return DatabaseTarget::Mysql(connection.to_string());
+        }
+
+        let path = connection
+            .strip_prefix("sqlite://")
+            .or_else(|| connection.strip_prefix("sqlite:"))
+            .unwrap_or(connection);
+        let path = Path::new(path);
+        if path.is_absolute() {
+            DatabaseTarget::Sqlite(path.to_path_buf())
+        } else {
+            DatabaseTarget::Sqlite(working_dir.join(path))
+        }
+    }
+
+    fn backend(&self) -> &'static str {
+        match self {
+            DatabaseTarget::Sqlite(_) => "sqlite",
+            DatabaseTarget::Postgres(_) => "postgres",
+            DatabaseTarget::Mysql(_) => "mysql",
+        }
+    }
+}
+
+/// 쿼리 결과
+#[derive(Debug, Default, PartialEq, Eq)]
+struct QueryOutput {
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+    /// 행 제한으로 잘렸는지
+    truncated: bool,
+    /// 결과 행이 없는 문장의 영향받은 행 수 / 상태 메시지
+    status: Option<String>,
+}
+
+impl QueryOutput {
+    /// Markdown 테이블로 변환
+    fn to_markdown(&self, max_rows: usize) -> String {
+        if self.columns.is_empty() {
+            return self
+                .status
+                .clone()
+                .unwrap_or_else(|| "Query executed".to_string());
+        }
+
+        let mut out = String::new();
+        out.push_str(&format!("| {} |\n", self.columns.join(" | ")));
+        out.push_str(&format!(
+            "|{}\n",
+            self.columns.iter().map(|_| "---|").collect::<String>()
+        ));
+        for row in &self.rows {
+            out.push_str(&format!("| {} |\n", row.join(" | ")));
+        }
+
+        if self.truncated {
+            out.push_str(&format!(
+                "\n(showing first {} rows; more rows available - narrow the query or raise max_rows)",
+                max_rows
+            ));
+        } else {
+            let n = self.rows.len();
+            out.push_str(&format!("\n({} row{})", n, if n == 1 { "" } else { "s" }));
+        }
+        out
+    }
+}
+
+/// SQL 쿼리 도구
+pub struct SqlQueryTool {
+    config: SqlQueryConfig,
+}
+
+impl SqlQueryTool {
+    /// 새 인스턴스 생성
+    pub fn new() -> Self {
+        Self::with_config(SqlQueryConfig::default())
+    }
+
+    /// 설정과 함께 생성
+    pub fn with_config(config: SqlQueryConfig) -> Self {
+        Self { config }
+    }
+
+    /// 도구 이름
+    pub const NAME: &'static str = "sql_query";
+
+    /// 권한 이름
+    const WRITE_PERMISSION: &'static str = "database.write";
+
+    /// 데이터를 읽기만 하는 문장의 첫 키워드
+    const READ_KEYWORDS: [&'static str; 8] = [
+        "SELECT", "WITH", "EXPLAIN", "SHOW", "DESCRIBE", "DESC", "PRAGMA", "VALUES",
+    ];
+
+    /// 키워드 기준 읽기 전용 판별 (보수적 - 모르는 문장은 쓰기로 취급)
+    ///
+    /// 여러 문장(뒤의 문장이 읽기 전용 설정을 풀 수 있음)과 클라이언트 명령어
+    /// (SQL 밖에서 실행됨)는 항상 쓰기로 취급합니다.
+    fn is_read_only_sql(query: &str) -> bool {
+        if has_multiple_statements(query) || has_client_command(query) {
+            return false;
+        }
+        let sql = strip_leading_comments(query);
+        let keyword: String = sql
+            .chars()
+            .take_while(|c| c.is_ascii_alphabetic())
+            .collect::<String>()
+            .to_ascii_uppercase();
+
+        if !Self::READ_KEYWORDS.contains(&keyword.as_str()) {
+            return false;
+        }
+
+        // PRAGMA x = y 는 설정 변경
+        if keyword == "PRAGMA" && sql.contains('=') {
+            return false;
+        }
+
+        // WITH ... INSERT/UPDATE/DELETE (CTE 쓰기)
+        if keyword == "WITH" {
+            let upper = sql.to_ascii_uppercase();
+            return !upper
+                .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .any(|word| ["INSERT", "UPDATE", "DELETE", "MERGE"].contains(&word));
+        }
+
+        true
+    }
+
+    /// 연결 이름/문자열 해석
+    fn resolve(&self, database: &str, working_dir: &Path) -> DatabaseTarget {
+        let configured = self.config.connections.get(database).cloned().or_else(|| {
+            ConfigLoader::new(working_dir)
+                .load_all()
+                .ok()
+                .and_then(|config| config.databases.get(database).cloned())
+        });
+
+        DatabaseTarget::parse(configured.as_deref().unwrap_or(database), working_dir)
+    }
+
+    /// 셀 값 정리 (파이프/개행 이스케이프, 길이 제한)
+    fn clean_cell(&self, value: &str) -> String {
+        let mut cell: String = value
+            .replace('|', "\\|")
+            .replace("\r\n", " ")
+            .replace(['\n', '\r'], " ");
+        if cell.chars().count() > self.config.max_cell_chars {
+            cell = cell.chars().take(self.config.max_cell_chars).collect();
+            cell.push('…');
+        }
+        cell
+    }
+
+    /// 권한 확인 (쓰기 쿼리)
+    async fn check_write_permission(
+        &self,
+        database: &str,
+        query: &str,
+        context: &dyn ToolContext,
+    ) -> Result<Option<ToolResult>> {
+        let action = PermissionAction::Custom {
+            name: Self::WRITE_PERMISSION.to_string(),
+            details: database.to_string(),
+        };
+
+        match context.check_permission(Self::NAME, &action).await {
+            PermissionStatus::Denied => Ok(Some(ToolResult::error(format!(
+                "Permission denied for write query on '{}'",
+                database
+            )))),
+            PermissionStatus::Unknown => {
+                let granted = context
+                    .request_permission(
+                        Self::NAME,
+                        &format!("Write to database '{}': {}", database, query.trim()),
+                        action,
+                    )
+                    .await?;
+                if granted {
+                    Ok(None)
+                } else {
+                    Ok(Some(ToolResult::error("Permission denied by user")))
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// SQLite 문장이 읽기 전용인지 (sqlite3_stmt_readonly)
+    fn sqlite_statement_is_read_only(path: &Path, query: &str) -> std::result::Result<bool, String> {
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        let stmt = conn.prepare(query).map_err(|e| format!("SQL error: {}", e))?;
+        Ok(stmt.readonly())
+    }
+
+    /// SQLite 실행
+    fn run_sqlite(
+        &self,
+        path: &Path,
+        query: &str,
+        write: bool,
+        max_rows: usize,
+    ) -> std::result::Result<QueryOutput, String> {
+        let flags = if write {
+            OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE
+        } else {
+            OpenFlags::SQLITE_OPEN_READ_ONLY
+        };
+        let conn = Connection::open_with_flags(path, flags)
+            .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        conn.busy_timeout(self.config.timeout)
+            .map_err(|e| format!("Failed to configure connection: {}", e))?;
+
+        let mut stmt = conn.prepare(query).map_err(|e| format!("SQL error: {}", e))?;
+
+        if stmt.column_count() == 0 {
+            let affected = stmt.execute([]).map_err(|e| format!("SQL error: {}", e))?;
+            return Ok(QueryOutput {
+                status: Some(format!("{} row(s) affected", affected)),
+                ..Default::default()
+            });
+        }
+
+        let columns: Vec<String> = stmt
+            .column_names()
+            .iter()
+            .map(|c| self.clean_cell(c))
+            .collect();
+        let column_count = columns.len();
+
+        let mut rows = stmt.query([]).map_err(|e| format!("SQL error: {}", e))?;
+        let mut output = QueryOutput {
+            columns,
+            ..Default::default()
+        };
+
+        while let Some(row) = rows.next().map_err(|e| format!("SQL error: {}", e))? {
+            if output.rows.len() == max_rows {
+                output.truncated = true;
+                break;
+            }
+
+            let mut cells = Vec::with_capacity(column_count);
+            for i in 0..column_count {
+                let value = row.get_ref(i).map_err(|e| format!("SQL error: {}", e))?;
+                let cell = match value {
+                    ValueRef::Null => "NULL".to_string(),
+                    ValueRef::Integer(n) => n.to_string(),
+                    ValueRef::Real(f) => f.to_string(),
+                    ValueRef::Text(t) => self.clean_cell(&String::from_utf8_lossy(t)),
+                    ValueRef::Blob(b) => format!("<blob {} bytes>", b.len()),
+                };
+                cells.push(cell);
+            }
+            output.rows.push(cells);
+        }
+
+        Ok(output)
+    }
+
+    /// 외부 클라이언트(psql/mysql)로 실행
+    async fn run_client(
+        &self,
+        target: &DatabaseTarget,
+        query: &str,
+        write: bool,
+        max_rows: usize,
+    ) -> std::result::Result<QueryOutput, String> {
+        let (program, mut command) = match target {
+            DatabaseTarget::Postgres(url) => {
+                let mut cmd = tokio::process::Command::new("psql");
+                let (url, password) = split_pg_password(url);
+                if let Some(password) = password {
+                    cmd.env("PGPASSWORD", password);
+                }
+                cmd.args(["-X", "--csv", "-v", "ON_ERROR_STOP=1", "-d", &url, "-c", query]);
+                if !write {
+                    cmd.env("PGOPTIONS", "-c default_transaction_read_only=on");
+                }
+                ("psql", cmd)
+            }
+            DatabaseTarget::Mysql(url) => {
+                let mut cmd = tokio::process::Command::new("mysql");
+                let parsed = url::Url::parse(url).map_err(|e| format!("Invalid MySQL URL: {}", e))?;
+                if let Some(host) = parsed.host_str() {
+                    cmd.args(["-h", host]);
+                }
+                if let Some(port) = parsed.port() {
+                    cmd.args(["-P", &port.to_string()]);
+                }
+                if !parsed.username().is_empty() {
+                    cmd.args(["-u", parsed.username()]);
+                }
+                if let Some(password) = parsed.password() {
+                    let password = urlencoding::decode(password)
+                        .map(|p| p.into_owned())
+                        .unwrap_or_else(|_| password.to_string());
+                    cmd.env("MYSQL_PWD", password);
+                }
+                let db = parsed.path().trim_start_matches('/');
+                if !db.is_empty() {
+                    cmd.arg(db);
+                }
+
+                let statement = query.trim().trim_end_matches(';');
+                let script = if write {
+                    format!("{};\nSELECT ROW_COUNT() AS rows_affected;", statement)
+                } else {
+                    format!("SET SESSION TRANSACTION READ ONLY;\n{};", statement)
+                };
+                cmd.args(["--batch", "-e", &script]);
+                ("mysql", cmd)
+            }
+            DatabaseTarget::Sqlite(_) => unreachable!("sqlite is executed in-process"),
+        };
+
+        if which::which(program).is_err() {
+            return Err(format!(
+                "'{}' client not found - install it to query {} databases",
+                program,
+                target.backend()
+            ));
+        }
+
+        command.kill_on_drop(true);
+        let output = tokio::time::timeout(self.config.timeout, command.output())
+            .await
+            .map_err(|_| format!("Query timed out after {}s", self.config.timeout.as_secs()))?
+            .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let records = match target {
+            DatabaseTarget::Postgres(_) => parse_csv(&stdout),
+            _ => parse_mysql_batch(&stdout),
+        };
+
+        // psql은 결과 행이 없는 문장에 대해 명령 태그(예: "INSERT 0 3")만 출력
+        if records.len() == 1 && records[0].len() == 1 && !Self::is_read_only_sql(query) {
+            return Ok(QueryOutput {
+                status: Some(records[0][0].clone()),
+                ..Default::default()
+            });
+        }
+
+        let mut records = records.into_iter();
+        let Some(header) = records.next() else {
+            return Ok(QueryOutput {
+                status: Some("Query executed".to_string()),
+                ..Default::default()
+            });
+        };
+
+        let mut result = QueryOutput {
+            columns: header.iter().map(|c| self.clean_cell(c)).collect(),
+            ..Default::default()
+        };
+        for record in records {
+            if result.rows.len() == max_rows {
+                result.truncated = true;
+                break;
+            }
+            result
+                .rows
+                .push(record.iter().map(|c| self.clean_cell(c)).collect());
+        }
+
+        Ok(result)
+    }
+}
+
+impl Default for SqlQueryTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for SqlQueryTool {
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("SQL Query")
+            .description(
+                "Run a single SQL statement against a SQLite file or a configured database \
+                 (postgres:// and mysql:// connection strings, or a name from the `databases` config). \
+                 Results are returned as a markdown table with a row limit. Queries are read-only by \
+                 default; INSERT/UPDATE/DELETE/DDL require the database.write permission.",
+            )
+            .category("database")
+            .permission(
+                PermissionDef::new(Self::WRITE_PERMISSION, "database")
+                    .risk_level(7)
+                    .description("Modify data or schema in a database")
+                    .requires_confirmation(true),
+            )
+    }
+
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "database": {
+                    "type": "string",
+                    "description": "SQLite file path, postgres:// or mysql:// connection string, or a configured database name"
+                },
+                "query": {
+                    "type": "string",
+                    "description": "A single SQL statement"
+                },
+                "max_rows": {
+                    "type": "number",
+                    "description": "Maximum rows to return (default: 100, max: 1000)"
+                }
+            },
+            "required": ["database", "query"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        let database = input.get("database")?.as_str()?;
+        let query = input.get("query")?.as_str()?;
+
+        if Self::is_read_only_sql(query) {
+            return None;
+        }
+
+        Some(PermissionAction::Custom {
+            name: Self::WRITE_PERMISSION.to_string(),
+            details: database.to_string(),
+        })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let parsed: SqlQueryInput = serde_json::from_value(input).map_err(|e| {
+            forge_foundation::Error::InvalidInput(format!("Invalid input: {}", e))
+        })?;
+
+        if parsed.query.trim().is_empty() {
+            return Ok(ToolResult::error("Query is empty"));
+        }
+
+        let target = self.resolve(&parsed.database, context.working_dir());
+        if !matches!(target, DatabaseTarget::Sqlite(_)) && has_multiple_statements(&parsed.query)
+        {
+            return Ok(ToolResult::error(
+                "Only a single SQL statement is allowed - remove the ';' separators",
+            ));
+        }
+        if !matches!(target, DatabaseTarget::Sqlite(_)) && has_client_command(&parsed.query) {
+            return Ok(ToolResult::error(
+                "psql/mysql client commands ('\\' commands, system, source, ...) are not allowed",
+            ));
+        }
+        let max_rows = parsed
+            .max_rows
+            .unwrap_or(self.config.default_max_rows)
+            .clamp(1, self.config.max_rows_limit);
+
+        // 쓰기 여부 판별 (SQLite는 준비된 문장으로 정확히 판별)
+        let mut write = !Self::is_read_only_sql(&parsed.query);
+        if let DatabaseTarget::Sqlite(path) = &target {
+            if !path.exists() {
+                if !write {
+                    return Ok(ToolResult::error(format!(
+                        "Database file not found: {}",
+                        path.display()
+                    )));
+                }
+            } else if !write {
+                match Self::sqlite_statement_is_read_only(path, &parsed.query) {
+                    Ok(read_only) => write = !read_only,
+                    Err(e) => return Ok(ToolResult::error(e)),
+                }
+            }
+        }
+
+        if write {
+            if let Some(denied) = self
+                .check_write_permission(&parsed.database, &parsed.query, context)
+                .await?
+            {
+                return Ok(denied);
+            }
+        }
+
+        let start = Instant::now();
+        let result = match &target {
+            DatabaseTarget::Sqlite(path) => {
+                let path = path.clone();
+                let query = parsed.query.clone();
+                let tool = SqlQueryTool::with_config(self.config.clone());
+                tokio::task::spawn_blocking(move || tool.run_sqlite(&path, &query, write, max_rows))
+                    .await
+                    .map_err(|e| forge_foundation::Error::Internal(e.to_string()))?
+            }
+            _ => self.run_client(&target, &parsed.query, write, max_rows).await,
+        };
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        match result {
+            Ok(output) => Ok(ToolResult::success(output.to_markdown(max_rows))
+                .with_metadata("backend", json!(target.backend()))
+                .with_metadata("rows", json!(output.rows.len()))
+                .with_metadata("truncated", json!(output.truncated))
+                .with_metadata("read_only", json!(!write))
+                .with_metadata("duration_ms", json!(duration_ms))),
+            Err(e) => Ok(ToolResult::error(e)),
+        }
+    }
+}
+
+// ============================================================================
+// 출력 파싱
+// ============================================================================
+
+/// 선행 공백/주석 제거
+fn strip_leading_comments(query: &str) -> &str {
+    let mut sql = query.trim_start();
+    loop {
+        if let Some(rest) = sql.strip_prefix("--") {
+            sql = rest.split_once('\n').map(|(_, r)| r).unwrap_or("").trim_start();
+        } else if let Some(rest) = sql.strip_prefix("/*") {
+            sql = rest.split_once("*/").map(|(_, r)| r).unwrap_or("").trim_start();
+        } else {
+            return sql;
+        }
+    }
+}
+
+/// 따옴표/주석 밖에 문장 구분자(`;`) 뒤로 다른 문장이 있는지
+///
+/// 따옴표와 주석 규칙이 Postgres와 MySQL에서 다르므로 (`\\` 이스케이프, `#` 주석,
+/// `$tag$` 문자열) 두 방언 중 하나라도 구분자를 찾으면 여러 문장으로 봅니다.
+fn has_multiple_statements(query: &str) -> bool {
+    find_statement_separator(query, false) || find_statement_separator(query, true)
+}
+
+/// 한 방언 기준으로 따옴표/주석 밖의 문장 구분자 검색
+fn find_statement_separator(query: &str, mysql: bool) -> bool {
+    let bytes = query.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if let Some((end, _)) = skip_quoted(query, i, mysql) {
+            i = end + 1;
+            continue;
+        }
+        if bytes[i] == b';' {
+            let rest = query[i + 1..].trim_matches(|c: char| c.is_whitespace() || c == ';');
+            if !strip_leading_comments(rest).trim_matches(';').trim().is_empty() {
+                return true;
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+/// mysql 클라이언트가 줄 첫 단어로 받으면 SQL 대신 직접 실행하는 명령어
+const MYSQL_CLIENT_COMMANDS: [&str; 7] =
+    ["system", "source", "pager", "tee", "edit", "connect", "delimiter"];
+
+/// psql/mysql 클라이언트 명령어가 들어 있는지
+///
+/// 두 클라이언트 모두 따옴표 밖의 `\`를 메타 명령어로 해석하고 (`\! cmd`는 셸 실행),
+/// mysql은 줄 첫 단어의 `system`, `source` 등도 SQL로 보내지 않고 직접 실행합니다.
+/// 주석 안의 `\`나 방언에 따라 따옴표 밖이 되는 `\`도 명령어로 봅니다.
+fn has_client_command(query: &str) -> bool {
+    let line_command = query.lines().any(|line| {
+        let word: String = line
+            .trim_start()
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect();
+        MYSQL_CLIENT_COMMANDS.contains(&word.to_ascii_lowercase().as_str())
+    });
+    line_command || find_unquoted_backslash(query, false) || find_unquoted_backslash(query, true)
+}
+
+/// 한 방언 기준으로 문자열 밖의 `\` 검색 (주석 안 포함)
+fn find_unquoted_backslash(query: &str, mysql: bool) -> bool {
+    let bytes = query.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if let Some((end, comment)) = skip_quoted(query, i, mysql) {
+            if comment && query[i..(end + 1).min(bytes.len())].contains('\\') {
+                return true;
+            }
+            i = end + 1;
+            continue;
+        }
+        if bytes[i] == b'\\' {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// `i`에서 시작하는 문자열/주석의 마지막 위치와 주석 여부 (시작하지 않으면 None)
+fn skip_quoted(query: &str, i: usize, mysql: bool) -> Option<(usize, bool)> {
+    let bytes = query.as_bytes();
+    let line_end = |from: usize| query[from..].find('\n').map_or(bytes.len(), |n| from + n);
+    match bytes[i] {
+        quote @ (b'\'' | b'"' | b'`') => {
+            let mut j = i + 1;
+            while j < bytes.len() && bytes[j] != quote {
+                if mysql && bytes[j] == b'\\' {
+                    j += 1;
+                }
+                j += 1;
+            }
+            Some((j, false))
+        }
+        b'-' if bytes.get(i + 1) == Some(&b'-')
+            && (!mysql || !matches!(bytes.get(i + 2), Some(c) if !c.is_ascii_whitespace())) =>
+        {
+            Some((line_end(i), true))
+        }
+        b'#' if mysql => Some((line_end(i), true)),
+        b'/' if bytes.get(i + 1) == Some(&b'*') => Some((
+            query[i + 2..].find("*/").map_or(bytes.len(), |n| i + 2 + n + 1),
+            true,
+        )),
+        b'$' if !mysql => {
+            let n = query[i + 1..]
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .filter(|&n| bytes.get(i + 1 + n) == Some(&b'$'))?;
+            let tag = &query[i..i + n + 2];
+            let body = i + tag.len();
+            let end = query[body..]
+                .find(tag)
+                .map_or(bytes.len(), |m| body + m + tag.len() - 1);
+            Some((end, false))
+        }
+        _ => None,
+    }
+}
+
+/// Postgres URL에서 비밀번호 분리 (프로세스 인자에 남지 않도록 `PGPASSWORD`로 전달)
+///
+/// URL 형식이 아니면(`host=... dbname=...`) 그대로 반환합니다.
+fn split_pg_password(url: &str) -> (String, Option<String>) {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return (url.to_string(), None);
+    };
+    let mut password = parsed.password().map(|p| {
+        urlencoding::decode(p)
+            .map(|p| p.into_owned())
+            .unwrap_or_else(|_| p.to_string())
+    });
+    let _ = parsed.set_password(None);
+
+    let pairs: Vec<(String, String)> = parsed.query_pairs().into_owned().collect();
+    if let Some((_, value)) = pairs.iter().find(|(key, _)| key == "password") {
+        password = password.or_else(|| Some(value.clone()));
+        let rest: Vec<&(String, String)> =
+            pairs.iter().filter(|(key, _)| key != "password").collect();
+        if rest.is_empty() {
+            parsed.set_query(None);
+        } else {
+            parsed.query_pairs_mut().clear().extend_pairs(rest);
+        }
+    }
+
+    (parsed.to_string(), password)
+}
+
+/// CSV 파싱 (psql --csv, 따옴표 안의 개행 지원)
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => in_quotes = true,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            '\r' if !in_quotes => {}
+            _ => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+/// mysql --batch 출력 파싱 (탭 구분, `\t` `\n` `\\` 이스케이프)
+fn parse_mysql_batch(text: &str) -> Vec<Vec<String>> {
+    text.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split('\t')
+                .map(|field| {
+                    field
+                        .replace("\\t", "\t")
+                        .replace("\\n", "\n")
+                        .replace("\\\\", "\\")
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::sync::Arc;
+
+    fn context(dir: &Path, auto_approve: bool) -> RuntimeContext {
+        let permissions = if auto_approve {
+            PermissionService::with_auto_approve()
+        } else {
+            PermissionService::new()
+        };
+        RuntimeContext::new("test-session", dir.to_path_buf(), Arc::new(permissions))
+    }
+
+    #[test]
+    fn test_read_only_detection() {
+        assert!(SqlQueryTool::is_read_only_sql("SELECT * FROM users"));
+        assert!(SqlQueryTool::is_read_only_sql("  -- count\n select count(*) from t"));
+        assert!(SqlQueryTool::is_read_only_sql("/* x */ WITH a AS (SELECT 1) SELECT * FROM a"));
+        assert!(SqlQueryTool::is_read_only_sql("PRAGMA table_info(users)"));
+
+        assert!(!SqlQueryTool::is_read_only_sql("DELETE FROM users"));
+        assert!(!SqlQueryTool::is_read_only_sql("drop table users"));
+        assert!(!SqlQueryTool::is_read_only_sql("PRAGMA journal_mode = WAL"));
+        assert!(!SqlQueryTool::is_read_only_sql(
+            "WITH old AS (SELECT id FROM t) DELETE FROM t WHERE id IN old"
+        ));
+        assert!(!SqlQueryTool::is_read_only_sql(
+            "WITH old AS (SELECT id FROM t) DELETE\nFROM t WHERE id IN old"
+        ));
+        assert!(!SqlQueryTool::is_read_only_sql(
+            "WITH x AS (SELECT 1) INSERT(a) SELECT * FROM x"
+        ));
+        assert!(SqlQueryTool::is_read_only_sql(
+            "WITH a AS (SELECT inserted_at FROM t) SELECT * FROM a"
+        ));
+
+        // 여러 문장 (읽기 전용 설정을 풀고 쓰기)
+        assert!(!SqlQueryTool::is_read_only_sql(
+            "SELECT 1; SET SESSION TRANSACTION READ WRITE; DELETE FROM users"
+        ));
+        assert!(!SqlQueryTool::is_read_only_sql(
+            "SELECT 1; SET default_transaction_read_only=off; COMMIT; DELETE FROM t"
+        ));
+        assert!(SqlQueryTool::is_read_only_sql("SELECT 1;  -- done\n"));
+        assert!(SqlQueryTool::is_read_only_sql("SELECT ';' AS a /* ; */"));
+        assert!(!find_statement_separator("SELECT $x$;$x$", false));
+        assert!(has_multiple_statements("SELECT 'a'';' ; DELETE FROM t"));
+        // 방언마다 해석이 다른 구문은 어느 쪽이든 구분자가 보이면 거부
+        assert!(has_multiple_statements("SELECT 'C:\\'; DELETE FROM t; -- '"));
+        assert!(has_multiple_statements("SELECT 1 # 2; DELETE FROM t"));
+        assert!(has_multiple_statements("SELECT 1 --1; DELETE FROM t"));
+
+        // 클라이언트 명령어는 SQL 밖에서 실행되므로 읽기 전용이 아님
+        assert!(!SqlQueryTool::is_read_only_sql("SELECT 1 \\! touch /tmp/x"));
+        assert!(!SqlQueryTool::is_read_only_sql("\\! id"));
+        assert!(!SqlQueryTool::is_read_only_sql("SELECT 1\nsystem touch /tmp/x"));
+        assert!(!SqlQueryTool::is_read_only_sql("SELECT 1 -- it's\n\\! id"));
+        assert!(!SqlQueryTool::is_read_only_sql("SELECT 'C:\\' \\! id '"));
+        assert!(!SqlQueryTool::is_read_only_sql("SELECT E'\\'' \\! id '"));
+        assert!(SqlQueryTool::is_read_only_sql("SELECT 'C:\\\\dir' AS path"));
+        assert!(SqlQueryTool::is_read_only_sql("SELECT a,\n  system_id FROM t"));
+
+        let tool = SqlQueryTool::new();
+        assert!(tool
+            .required_permission(&json!({ "database": "a.db", "query": "SELECT 1" }))
+            .is_none());
+        assert!(matches!(
+            tool.required_permission(&json!({ "database": "a.db", "query": "UPDATE t SET x = 1" })),
+            Some(PermissionAction::Custom { name, details }) if name == "database.write" && details == "a.db"
+        ));
+    }
+
+    #[test]
+    fn test_target_parsing() {
+        let dir = Path::new("/work");
+        assert_eq!(
+            DatabaseTarget::parse("data/dev.sqlite", dir),
+            DatabaseTarget::Sqlite(PathBuf::from("/work/data/dev.sqlite"))
+        );
+        assert_eq!(
+            DatabaseTarget::parse("sqlite:///tmp/x.db", dir),
+            DatabaseTarget::Sqlite(PathBuf::from("/tmp/x.db"))
+        );
+        assert_eq!(
+            DatabaseTarget::parse("postgresql://u@h/db", dir).backend(),
+            "postgres"
+        );
+        assert_eq!(DatabaseTarget::parse("mysql://u@h/db", dir).backend(), "mysql");
+
+        let tool = SqlQueryTool::with_config(SqlQueryConfig {
+            connections: HashMap::from([("local".to_string(), "data/dev.sqlite".to_string())]),
+            ..Default::default()
+        });
+        assert_eq!(
+            tool.resolve("local", dir),
+            DatabaseTarget::Sqlite(PathBuf::from("/work/data/dev.sqlite"))
+        );
+    }
+
+    #[test]
+    fn test_split_pg_password() {
+        assert_eq!(
+            split_pg_password("postgres://app:p%40ss@db:5432/main?sslmode=require"),
+            (
+                "postgres://app@db:5432/main?sslmode=require".to_string(),
+                Some("p@ss".to_string())
+            )
+        );
+        assert_eq!(
+            split_pg_password("postgresql://db/main?password=secret&sslmode=disable"),
+            (
+                "postgresql://db/main?sslmode=disable".to_string(),
+                Some("secret".to_string())
+            )
+        );
+        assert_eq!(
+            split_pg_password("postgres://db/main?password=secret"),
+            ("postgres://db/main".to_string(), Some("secret".to_string()))
+        );
+        assert_eq!(
+            split_pg_password("host=db dbname=main"),
+            ("host=db dbname=main".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_output_parsing() {
+        assert_eq!(
+            parse_csv("id,name\n1,\"a, \"\"b\"\"\"\n2,\"multi\nline\"\n"),
+            vec![
+                vec!["id".to_string(), "name".to_string()],
+                vec!["1".to_string(), "a, \"b\"".to_string()],
+                vec!["2".to_string(), "multi\nline".to_string()],
+            ]
+        );
+        assert_eq!(
+            parse_mysql_batch("id\tnote\n1\ta\\tb\n"),
+            vec![
+                vec!["id".to_string(), "note".to_string()],
+                vec!["1".to_string(), "a\tb".to_string()],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_commands_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool = SqlQueryTool::new();
+        let approve = context(dir.path(), true);
+
+        for (database, query) in [
+            ("postgres://dev@localhost/app", "\\! touch pwned"),
+            ("postgres://dev@localhost/app", "SELECT 1 \\! touch pwned"),
+            ("mysql://dev@localhost/app", "SELECT 1 \\! touch pwned"),
+            ("mysql://dev@localhost/app", "SELECT 1\nsystem touch pwned"),
+        ] {
+            let result = tool
+                .execute(json!({ "database": database, "query": query }), &approve)
+                .await
+                .unwrap();
+            assert!(!result.success, "{}", query);
+            assert!(result.error.unwrap().contains("client commands"));
+        }
+        assert!(!dir.path().join("pwned").exists());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_query_and_write_permission() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool = SqlQueryTool::new();
+        let approve = context(dir.path(), true);
+
+        for query in [
+            "CREATE TABLE users (id INTEGER, name TEXT, bio TEXT)",
+            "INSERT INTO users VALUES (1, 'ann', 'a|b'), (2, 'bob', NULL), (3, 'cy', 'x')",
+        ] {
+            let result = tool
+                .execute(json!({ "database": "app.db", "query": query }), &approve)
+                .await
+                .unwrap();
+            assert!(result.success, "{:?}", result.error);
+        }
+
+        let result = tool
+            .execute(
+                json!({ "database": "app.db", "query": "SELECT * FROM users ORDER BY id", "max_rows": 2 }),
+                &approve,
+            )
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.starts_with("| id | name | bio |\n|---|---|---|\n"));
+        assert!(result.output.contains("| 1 | ann | a\\|b |"));
+        assert!(result.output.contains("| 2 | bob | NULL |"));
+        assert!(!result.output.contains("cy"));
+        assert!(result.output.contains("showing first 2 rows"));
+
+        // 권한 없이 쓰기 시도 → 거부
+        let deny = context(dir.path(), false);
+        let result = tool
+            .execute(
+                json!({ "database": "app.db", "query": "DELETE FROM users" }),
+                &deny,
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+
+        // 읽기 쿼리는 권한 없이 실행
+        let result = tool
+            .execute(
+                json!({ "database": "app.db", "query": "SELECT count(*) AS n FROM users" }),
+                &deny,
+            )
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("| 3 |"));
+
+        // 없는 파일은 생성하지 않음
+        let result = tool
+            .execute(
+                json!({ "database": "missing.db", "query": "SELECT 1" }),
+                &deny,
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(!dir.path().join("missing.db").exists());
+    }
+}
@@ -9,6 +9,7 @@
 //! - `task_send` - Task에 입력 전송 (PTY stdin)
 //! - `task_list` - 실행 중인 Task 목록
 //! - `task_status` - Task 상태 조회
+//! - `task_artifact` - Task 산출물 조회 (`task_artifact.rs`)
 //!
 //! ## 사용 예시
 //!
@@ -39,8 +40,10 @@ use forge_foundation::{
     PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult,
 };
 use forge_task::{
-    ExecutionMode, OrchestratorConfig, Task, TaskId, TaskOrchestrator, WaitCondition, WaitResult,
+    ArtifactSpec, ExecutionMode, OrchestratorConfig, Task, TaskId, TaskOrchestrator,
+    WaitCondition, WaitResult,
 };
+use super::task_artifact::TaskArtifactTool;
 use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -59,7 +62,7 @@ lazy_static::lazy_static! {
 }
 
 /// Orchestrator 초기화 (처음 사용 시 자동 호출)
-async fn get_orchestrator() -> Arc<TaskOrchestrator> {
+pub(crate) async fn get_orchestrator() -> Arc<TaskOrchestrator> {
     // Read lock으로 먼저 확인
     {
         let guard = GLOBAL_ORCHESTRATOR.read().await;
@@ -78,7 +81,7 @@ async fn get_orchestrator() -> Arc<TaskOrchestrator> {
 }
 
 /// Task ID 조회 (이름 또는 ID)
-async fn resolve_task_id(id_or_name: &str) -> Option<TaskId> {
+pub(crate) async fn resolve_task_id(id_or_name: &str) -> Option<TaskId> {
     // UUID 형식이면 직접 파싱
     if let Ok(uuid) = uuid::Uuid::parse_str(id_or_name) {
         return Some(TaskId(uuid));
@@ -90,7 +93,7 @@ async fn resolve_task_id(id_or_name: &str) -> Option<TaskId> {
 }
 
 /// Task 이름 등록
-async fn register_task_name(name: &str, task_id: TaskId) {
+pub(crate) async fn register_task_name(name: &str, task_id: TaskId) {
     let mut map = TASK_NAME_MAP.write().await;
     map.insert(name.to_string(), task_id);
     // 짧은 ID도 등록
@@ -141,10 +144,14 @@ impl Tool for TaskSpawnTool {
                 },
                 "mode": {
                     "type": "string",
-                    "enum": ["local", "pty", "container"],
-                    "description": "Execution mode: local (simple), pty (interactive/servers), container (isolated)",
+                    "enum": ["local", "pty", "container", "custom"],
+                    "description": "Execution mode: local (simple), pty (interactive/servers), container (isolated), custom (executor registered by a plugin)",
                     "default": "local"
                 },
+                "executor": {
+                    "type": "string",
+                    "description": "Executor name for mode 'custom'"
+                },
                 "name": {
                     "type": "string",
                     "description": "Optional friendly name (the returned task_id is what you must use for other task_* tools)"
@@ -161,6 +168,24 @@ impl Tool for TaskSpawnTool {
                 "env": {
                     "type": "object",
                     "description": "Additional environment variables"
+                },
+                "artifacts": {
+                    "type": "array",
+                    "description": "Output files to keep after the task finishes (built binaries, coverage reports, generated files). \
+                                    Each item is a path/glob relative to the working directory, or {\"name\", \"path\"}. Retrieve them later with task_artifact.",
+                    "items": {
+                        "oneOf": [
+                            { "type": "string" },
+                            {
+                                "type": "object",
+                                "properties": {
+                                    "name": { "type": "string" },
+                                    "path": { "type": "string" }
+                                },
+                                "required": ["path"]
+                            }
+                        ]
+                    }
                 }
             },
             "required": ["command"]
@@ -206,11 +231,21 @@ impl Tool for TaskSpawnTool {
                     volumes: vec![],
                 }
             }
+            "custom" => {
+                let executor = input["executor"].as_str().ok_or_else(|| {
+                    forge_foundation::Error::InvalidInput(
+                        "executor is required for mode 'custom'".to_string(),
+                    )
+                })?;
+                ExecutionMode::Custom {
+                    executor: executor.to_string(),
+                }
+            }
             _ => ExecutionMode::Local,
         };
 
         // Task 생성
-        let task = Task::new(
+        let mut task = Task::new(
             ctx.session_id(),
             "task_spawn",
             command,
@@ -219,6 +254,18 @@ impl Tool for TaskSpawnTool {
         .with_execution_mode(execution_mode)
         .with_timeout(Duration::from_secs(timeout_secs));
 
+        // 산출물 선언 ("path" 또는 {"name", "path"})
+        for spec in input["artifacts"].as_array().into_iter().flatten() {
+            let (name, path) = match spec {
+                Value::String(path) => (path.as_str(), path.as_str()),
+                _ => match spec["path"].as_str() {
+                    Some(path) => (spec["name"].as_str().unwrap_or(path), path),
+                    None => continue,
+                },
+            };
+            task = task.with_artifact(ArtifactSpec::new(name, path));
+        }
+
         // Orchestrator에서 실행
         let orchestrator = get_orchestrator().await;
         let task_id = orchestrator.spawn(task).await.map_err(|e| {
@@ -886,6 +933,7 @@ pub fn task_tools() -> Vec<Arc<dyn Tool>> {
         Arc::new(TaskSendTool::new()),
         Arc::new(TaskListTool::new()),
         Arc::new(TaskStatusTool::new()),
+        Arc::new(TaskArtifactTool::new()),
     ]
 }
 
@@ -916,7 +964,7 @@ mod tests {
     #[test]
     fn test_task_tools_count() {
         let tools = task_tools();
-        assert_eq!(tools.len(), 7);
+        assert_eq!(tools.len(), 8);
     }
 
     #[test]
@@ -930,5 +978,6 @@ mod tests {
         assert!(names.contains(&"task_send"));
         assert!(names.contains(&"task_list"));
         assert!(names.contains(&"task_status"));
+        assert!(names.contains(&"task_artifact"));
     }
 }
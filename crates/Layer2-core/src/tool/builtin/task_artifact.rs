@@ -0,0 +1,533 @@
+//! Task Artifact Tool - Task 산출물 조회/검사/복사
+//!
+//! `task_spawn`의 `artifacts`로 선언한 산출물(빌드 바이너리, 커버리지 리포트, 생성 파일)은
+//! Task 종료 후 `.forgecode/artifacts`에 내용 주소(sha256)로 저장됩니다.
+//! 작업 디렉토리가 바뀌거나 세션이 재시작되어도 이후 턴에서 다시 조회할 수 있습니다.
+//!
+//! - `list` - Task의 산출물 목록 (이름, 경로, 크기, 다이제스트)
+//! - `read` - 텍스트는 줄 번호와 함께, 아카이브는 엔트리 목록, 바이너리는 형식 + hex dump
+//! - `copy` - 산출물을 작업 디렉토리로 복원 (`FileWrite` 권한)
+
+use super::list_directory::format_size;
+use super::task::{get_orchestrator, resolve_task_id};
+use crate::tool::archive::{list_entries, ArchiveFormat};
+use crate::tool::security::PathValidator;
+use async_trait::async_trait;
+use forge_foundation::{
+    PermissionAction, PermissionStatus, Result, Tool, ToolContext, ToolMeta, ToolResult,
+};
+use forge_task::{Artifact, ArtifactStore, TaskId};
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// 기본 조회 줄 수
+const DEFAULT_LIMIT: usize = 500;
+
+/// 한 번에 반환할 최대 문자 수
+const MAX_CHARS: usize = 25_000;
+
+/// 바이너리 hex dump 크기
+const HEX_DUMP_BYTES: usize = 256;
+
+/// 아카이브 엔트리 표시 개수
+const MAX_ARCHIVE_ENTRIES: usize = 200;
+
+/// Task 산출물 도구
+pub struct TaskArtifactTool {
+    /// 저장소 (None이면 전역 TaskManager의 저장소 사용)
+    store: Option<ArtifactStore>,
+}
+
+impl TaskArtifactTool {
+    pub const NAME: &'static str = "task_artifact";
+
+    pub fn new() -> Self {
+        Self { store: None }
+    }
+
+    /// 특정 저장소 사용 (테스트, 다른 프로젝트)
+    pub fn with_store(store: ArtifactStore) -> Self {
+        Self { store: Some(store) }
+    }
+
+    async fn store(&self) -> ArtifactStore {
+        match &self.store {
+            Some(store) => store.clone(),
+            None => get_orchestrator()
+                .await
+                .task_manager()
+                .artifact_store()
+                .as_ref()
+                .clone(),
+        }
+    }
+}
+
+impl Default for TaskArtifactTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for TaskArtifactTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Task Artifact")
+            .description(
+                "List, inspect or restore artifacts a finished task produced (declared via task_spawn 'artifacts'). \
+                 Artifacts are kept after the task ends, so earlier builds, coverage reports and generated files \
+                 can be examined in later turns. 'read' shows text with line numbers, archive entries, or a hex dump \
+                 for binaries; 'copy' writes the artifact into the working directory.",
+            )
+            .category("task")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "task_id": {
+                    "type": "string",
+                    "description": "task_id returned from task_spawn (the 8-character id also works for earlier sessions)"
+                },
+                "action": {
+                    "type": "string",
+                    "enum": ["list", "read", "copy"],
+                    "description": "list: show the task's artifacts (default), read: inspect one artifact, copy: restore it to 'destination'",
+                    "default": "list"
+                },
+                "artifact": {
+                    "type": "string",
+                    "description": "Artifact name or path (required for read/copy; use the path when a name covers several files)"
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "read: line number to start from (1-based, default: 1)"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "read: number of lines to return (default: 500)"
+                },
+                "destination": {
+                    "type": "string",
+                    "description": "copy: target file path (relative to the working directory)"
+                }
+            },
+            "required": ["task_id"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        if input["action"].as_str() != Some("copy") {
+            return None; // Read-only
+        }
+        input["destination"]
+            .as_str()
+            .map(|path| PermissionAction::FileWrite {
+                path: path.to_string(),
+            })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let Some(id) = input["task_id"].as_str() else {
+            return Ok(ToolResult::error("Missing required parameter: task_id"));
+        };
+        let action = input["action"].as_str().unwrap_or("list");
+        if !matches!(action, "list" | "read" | "copy") {
+            return Ok(ToolResult::error(format!(
+                "Unknown action '{}': expected list, read or copy",
+                action
+            )));
+        }
+
+        let store = self.store().await;
+        let task_id = match resolve_task_id(id).await {
+            Some(task_id) => task_id,
+            None => match store.find_task(id)? {
+                Some(task_id) => task_id,
+                None => {
+                    return Ok(ToolResult::error(format!(
+                        "No artifacts recorded for task: {}",
+                        id
+                    )))
+                }
+            },
+        };
+
+        if action == "list" {
+            let artifacts = store.list(task_id)?;
+            return Ok(ToolResult::success(format_list(task_id, &artifacts))
+                .with_metadata("count", json!(artifacts.len())));
+        }
+
+        let Some(query) = input["artifact"].as_str() else {
+            return Ok(ToolResult::error(format!(
+                "Missing required parameter for {}: artifact",
+                action
+            )));
+        };
+        let Some(artifact) = store.get(task_id, query)? else {
+            return Ok(ToolResult::error(format!(
+                "Artifact not found: {} (use action=list to see the task's artifacts)",
+                query
+            )));
+        };
+        let data = store.read(&artifact)?;
+
+        if action == "read" {
+            let offset = input["offset"]
+                .as_u64()
+                .map(|n| n as usize)
+                .unwrap_or(1)
+                .max(1);
+            let limit = input["limit"]
+                .as_u64()
+                .map(|n| n as usize)
+                .unwrap_or(DEFAULT_LIMIT)
+                .max(1);
+            return Ok(
+                ToolResult::success(inspect(&artifact, &data, offset, limit))
+                    .with_metadata("sha256", json!(artifact.digest))
+                    .with_metadata("size", json!(artifact.size)),
+            );
+        }
+
+        // copy
+        let Some(destination) = input["destination"].as_str() else {
+            return Ok(ToolResult::error(
+                "Missing required parameter for copy: destination",
+            ));
+        };
+        let target = if Path::new(destination).is_absolute() {
+            Path::new(destination).to_path_buf()
+        } else {
+            context.working_dir().join(destination)
+        };
+        let validation = PathValidator::new()
+            .with_allowed_root(context.working_dir())
+            .validate(&target);
+        if let Some(msg) = validation.error_message() {
+            return Ok(ToolResult::error(format!(
+                "Path security check failed: {}",
+                msg
+            )));
+        }
+
+        // 권한 확인
+        if let Some(permission) = self.required_permission(&input) {
+            match context.check_permission(Self::NAME, &permission).await {
+                PermissionStatus::Denied => {
+                    return Ok(ToolResult::error("Permission denied for file write"));
+                }
+                PermissionStatus::Unknown => {
+                    let granted = context
+                        .request_permission(
+                            Self::NAME,
+                            &format!("Write: {}", destination),
+                            permission,
+                        )
+                        .await?;
+                    if !granted {
+                        return Ok(ToolResult::error("Permission denied by user"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(parent) = target.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&target, &data).await?;
+
+        Ok(ToolResult::success(format!(
+            "Copied {} ({}, sha256:{}) from task {} to {}",
+            artifact.path,
+            format_size(artifact.size),
+            artifact.short_digest(),
+            task_id,
+            target.display()
+        ))
+        .with_metadata("path", json!(target.display().to_string())))
+    }
+}
+
+/// 산출물 목록 포맷
+fn format_list(task_id: TaskId, artifacts: &[Artifact]) -> String {
+    if artifacts.is_empty() {
+        return format!("Task {} has no artifacts", task_id);
+    }
+
+    let total: u64 = artifacts.iter().map(|a| a.size).sum();
+    let name_width = artifacts.iter().map(|a| a.name.len()).max().unwrap_or(0);
+    let path_width = artifacts.iter().map(|a| a.path.len()).max().unwrap_or(0);
+
+    let mut out = format!(
+        "Artifacts for task {} ({} files, {}):\n",
+        task_id,
+        artifacts.len(),
+        format_size(total)
+    );
+    for artifact in artifacts {
+        out.push_str(&format!(
+            "  {:<name_width$}  {:<path_width$}  {:>9}  sha256:{}\n",
+            artifact.name,
+            artifact.path,
+            format_size(artifact.size),
+            artifact.short_digest(),
+        ));
+    }
+    out
+}
+
+/// 산출물 내용 검사 (텍스트 / 아카이브 / 바이너리)
+fn inspect(artifact: &Artifact, data: &[u8], offset: usize, limit: usize) -> String {
+    let header = format!(
+        "[{}] {} ({}, sha256:{})\n\n",
+        artifact.name,
+        artifact.path,
+        format_size(artifact.size),
+        artifact.digest
+    );
+
+    if let Some(format) = ArchiveFormat::sniff(data) {
+        if let Ok(entries) = list_entries(data, format) {
+            let mut out = format!(
+                "{}{} archive, {} entries\n",
+                header,
+                format.label(),
+                entries.len()
+            );
+            for entry in entries.iter().take(MAX_ARCHIVE_ENTRIES) {
+                out.push_str(&format!(
+                    "  {:>9}  {}\n",
+                    format_size(entry.size),
+                    entry.name
+                ));
+            }
+            if entries.len() > MAX_ARCHIVE_ENTRIES {
+                out.push_str(&format!(
+                    "  ... {} more entries\n",
+                    entries.len() - MAX_ARCHIVE_ENTRIES
+                ));
+            }
+            return out;
+        }
+    }
+
+    match std::str::from_utf8(data) {
+        Ok(text) if !text.contains('\0') => {
+            format!("{}{}", header, numbered_lines(text, offset, limit))
+        }
+        _ => format!(
+            "{}binary: {}\n\n{}",
+            header,
+            binary_kind(data),
+            hex_dump(&data[..data.len().min(HEX_DUMP_BYTES)])
+        ),
+    }
+}
+
+/// 줄 번호 포함 텍스트 (페이지 단위)
+fn numbered_lines(text: &str, offset: usize, limit: usize) -> String {
+    let total = text.lines().count();
+    let mut body = String::new();
+    let mut last = offset - 1;
+    for (index, line) in text.lines().enumerate().skip(offset - 1).take(limit) {
+        if body.len() + line.len() > MAX_CHARS && !body.is_empty() {
+            break;
+        }
+        body.push_str(&format!("{:>6}\t{}\n", index + 1, line));
+        last = index + 1;
+    }
+
+    if last < offset {
+        return format!(
+            "lines {}-{} of {}\n(no lines in range)",
+            offset, last, total
+        );
+    }
+    let mut out = format!("lines {}-{} of {}\n{}", offset, last, total, body);
+    if last < total {
+        out.push_str(&format!(
+            "\n... {} more lines. Continue with offset={}",
+            total - last,
+            last + 1
+        ));
+    }
+    out
+}
+
+/// 매직 바이트로 바이너리 형식 추정
+fn binary_kind(data: &[u8]) -> &'static str {
+    const KINDS: &[(&[u8], &str)] = &[
+        (b"\x7fELF", "ELF executable/library"),
+        (b"MZ", "PE executable (Windows)"),
+        (b"\xcf\xfa\xed\xfe", "Mach-O executable (64-bit)"),
+        (b"\xca\xfe\xba\xbe", "Mach-O universal binary / Java class"),
+        (b"\0asm", "WebAssembly module"),
+        (b"\x89PNG", "PNG image"),
+        (b"\xff\xd8\xff", "JPEG image"),
+        (b"GIF8", "GIF image"),
+        (b"%PDF", "PDF document"),
+        (b"SQLite format 3\0", "SQLite database"),
+        (b"!<arch>", "static library (ar archive)"),
+    ];
+    KINDS
+        .iter()
+        .find(|(magic, _)| data.starts_with(magic))
+        .map(|(_, kind)| *kind)
+        .unwrap_or("unknown format")
+}
+
+/// hex dump (16바이트 단위, ASCII 병기)
+fn hex_dump(data: &[u8]) -> String {
+    let mut out = String::new();
+    for (row, chunk) in data.chunks(16).enumerate() {
+        let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+        let ascii: String = chunk
+            .iter()
+            .map(|&b| {
+                if b.is_ascii_graphic() || b == b' ' {
+                    b as char
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        out.push_str(&format!(
+            "{:08x}  {:<47}  |{}|\n",
+            row * 16,
+            hex.join(" "),
+            ascii
+        ));
+    }
+    out
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use forge_task::{ArtifactSpec, Task};
+    use std::fs;
+    use std::sync::Arc;
+
+    fn context(dir: &Path) -> RuntimeContext {
+        RuntimeContext::new(
+            "test-session",
+            dir.to_path_buf(),
+            Arc::new(PermissionService::with_auto_approve()),
+        )
+    }
+
+    /// 빌드 결과물과 리포트를 가진 Task를 저장소에 기록
+    fn collected_task(dir: &Path) -> (ArtifactStore, Task) {
+        let workdir = dir.join("work");
+        fs::create_dir_all(workdir.join("target")).unwrap();
+        fs::write(workdir.join("target/app"), b"\x7fELF\x02\x01\x01\0binary").unwrap();
+        fs::write(workdir.join("report.txt"), "passed: 10\nfailed: 0\n").unwrap();
+
+        let store = ArtifactStore::new(dir.join("store"));
+        let task = Task::new("session", "task_spawn", "make", json!({}))
+            .with_artifact(ArtifactSpec::new("binary", "target/app"))
+            .with_artifact(ArtifactSpec::new("report", "report.txt"));
+        store.collect(&task, &workdir).unwrap();
+        (store, task)
+    }
+
+    #[tokio::test]
+    async fn test_list_and_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let (store, task) = collected_task(dir.path());
+        let tool = TaskArtifactTool::with_store(store);
+        let ctx = context(dir.path());
+
+        let result = tool
+            .execute(json!({ "task_id": task.id.to_string() }), &ctx)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("2 files"));
+        assert!(result.output.contains("target/app"));
+
+        let result = tool
+            .execute(
+                json!({ "task_id": task.id.to_string(), "action": "read", "artifact": "report" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(result.output.contains("lines 1-2 of 2"));
+        assert!(result.output.contains("     2\tfailed: 0"));
+
+        let result = tool
+            .execute(
+                json!({ "task_id": task.id.to_string(), "action": "read", "artifact": "target/app" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(result.output.contains("ELF executable"));
+        assert!(result.output.contains("00000000  7f 45 4c 46"));
+    }
+
+    #[tokio::test]
+    async fn test_copy_to_working_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let (store, task) = collected_task(dir.path());
+        let tool = TaskArtifactTool::with_store(store);
+        let ctx = context(dir.path());
+
+        let input = json!({
+            "task_id": task.id.to_string(),
+            "action": "copy",
+            "artifact": "report",
+            "destination": "restored/report.txt"
+        });
+        assert!(matches!(
+            tool.required_permission(&input),
+            Some(PermissionAction::FileWrite { .. })
+        ));
+
+        let result = tool.execute(input, &ctx).await.unwrap();
+        assert!(result.success, "{}", result.output);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("restored/report.txt")).unwrap(),
+            "passed: 10\nfailed: 0\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_task_and_artifact() {
+        let dir = tempfile::tempdir().unwrap();
+        let (store, task) = collected_task(dir.path());
+        let tool = TaskArtifactTool::with_store(store);
+        let ctx = context(dir.path());
+
+        let result = tool
+            .execute(json!({ "task_id": "00000000" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+
+        let result = tool
+            .execute(
+                json!({ "task_id": task.id.to_string(), "action": "read", "artifact": "coverage" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+}
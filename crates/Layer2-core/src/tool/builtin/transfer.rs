@@ -0,0 +1,989 @@
+//! Transfer Tools - 파일 다운로드/업로드
+//!
+//! 데이터셋, 릴리스 바이너리, 스키마 파일 등을 작업 중에 받아오거나
+//! 빌드 결과물을 올릴 때 사용합니다.
+//! - `download` - URL → 파일 (진행률, 최대 크기, sha256 검증)
+//! - `upload` - 파일 → URL (PUT/POST 원본 본문, sha256 보고)
+//!
+//! 권한은 `http_request`와 같이 도메인 단위(`network.request`)로 요청되고,
+//! 다운로드는 저장 경로에 대한 `FileWrite` 권한도 함께 확인합니다.
+//! 다운로드는 `<path>.part`에 받은 뒤 검증이 끝나야 최종 경로로 옮깁니다.
+//!
+//! 리다이렉트는 reqwest에 맡기지 않습니다. 다운로드는 다른 호스트로 넘어갈 때마다
+//! 도메인 규칙과 권한을 다시 확인하고, 업로드는 본문이 다른 호스트로 재전송되지
+//! 않도록 리다이렉트를 따라가지 않습니다.
+
+use super::http_request::HttpRequestTool;
+use super::list_directory::format_size;
+use crate::tool::security::{is_sensitive_path, PathValidator};
+use async_trait::async_trait;
+use forge_foundation::permission::domain_matches;
+use forge_foundation::{
+    check_egress, egress_client, PermissionAction, PermissionDef, PermissionStatus, Result, Tool,
+    ToolContext, ToolMeta, ToolResult,
+};
+use ring::digest::{Context as DigestContext, SHA256};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+
+/// 전송 도구 설정
+#[derive(Debug, Clone)]
+pub struct TransferConfig {
+    /// 최대 다운로드 크기 (bytes)
+    pub max_download_bytes: u64,
+
+    /// 최대 업로드 크기 (bytes)
+    pub max_upload_bytes: u64,
+
+    /// 전송 타임아웃
+    pub timeout: Duration,
+
+    /// 권한 확인 없이 허용할 도메인 패턴
+    pub allowed_domains: Vec<String>,
+
+    /// 항상 거부할 도메인 패턴
+    pub denied_domains: Vec<String>,
+}
+
+impl Default for TransferConfig {
+    fn default() -> Self {
+        Self {
+            max_download_bytes: 500 * 1024 * 1024,
+            max_upload_bytes: 100 * 1024 * 1024,
+            timeout: Duration::from_secs(600),
+            allowed_domains: Vec::new(),
+            denied_domains: Vec::new(),
+        }
+    }
+}
+
+impl TransferConfig {
+    fn is_denied(&self, host: &str) -> bool {
+        self.denied_domains
+            .iter()
+            .any(|pattern| domain_matches(pattern, host))
+    }
+
+    fn is_allowed(&self, host: &str) -> bool {
+        self.allowed_domains
+            .iter()
+            .any(|pattern| domain_matches(pattern, host))
+    }
+
+    /// 도메인 권한 (허용 목록이면 None)
+    fn network_permission(&self, url: &str) -> Option<PermissionAction> {
+        let (_, host) = HttpRequestTool::parse_url(url).ok()?;
+        if self.is_allowed(&host) && !self.is_denied(&host) {
+            return None;
+        }
+        Some(PermissionAction::Network { url: host })
+    }
+
+    /// HTTP 클라이언트 (리다이렉트는 따라가지 않음)
+    fn client(&self) -> reqwest::Client {
+        egress_client()
+            .user_agent("ForgeCode/1.0 (AI Coding Assistant)")
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(self.timeout)
+            .build()
+            .unwrap_or_default()
+    }
+}
+
+/// 권한 확인 (거부되면 false)
+async fn ensure_permission(
+    context: &dyn ToolContext,
+    tool: &str,
+    description: &str,
+    action: PermissionAction,
+) -> Result<bool> {
+    match context.check_permission(tool, &action).await {
+        PermissionStatus::Denied => Ok(false),
+        PermissionStatus::Unknown => context.request_permission(tool, description, action).await,
+        _ => Ok(true),
+    }
+}
+
+/// 작업 디렉토리 기준 경로 해석 + 보안 검증
+fn resolve_path(context: &dyn ToolContext, path: &str) -> std::result::Result<PathBuf, String> {
+    let input_path = Path::new(path);
+    let resolved = if input_path.is_absolute() {
+        input_path.to_path_buf()
+    } else {
+        context.working_dir().join(input_path)
+    };
+
+    let validation = PathValidator::new()
+        .with_allowed_root(context.working_dir())
+        .validate(&resolved);
+    if let Some(msg) = validation.error_message() {
+        return Err(format!("Path security check failed: {}", msg));
+    }
+    if is_sensitive_path(path) {
+        return Err(format!("Refusing to transfer sensitive file: {}", path));
+    }
+    Ok(resolved)
+}
+
+/// sha256 hex 문자열
+fn hex_digest(context: DigestContext) -> String {
+    context
+        .finish()
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// 기대 체크섬 정규화 ("sha256:" 접두사 허용)
+fn normalize_checksum(checksum: &str) -> std::result::Result<String, String> {
+    let checksum = checksum.trim();
+    let checksum = checksum
+        .strip_prefix("sha256:")
+        .unwrap_or(checksum)
+        .to_ascii_lowercase();
+    if checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!(
+            "Invalid sha256 '{}': expected 64 hex characters",
+            checksum
+        ));
+    }
+    Ok(checksum)
+}
+
+// ============================================================================
+// DownloadTool
+// ============================================================================
+
+/// 파일 다운로드 도구
+pub struct DownloadTool {
+    config: TransferConfig,
+}
+
+impl DownloadTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "download";
+
+    /// 진행률 보고 간격 (알 수 없는 크기일 때)
+    const PROGRESS_STEP_BYTES: u64 = 8 * 1024 * 1024;
+
+    /// 최대 리다이렉트 수
+    const MAX_REDIRECTS: usize = 5;
+
+    pub fn new() -> Self {
+        Self::with_config(TransferConfig::default())
+    }
+
+    /// 설정과 함께 생성
+    pub fn with_config(config: TransferConfig) -> Self {
+        Self { config }
+    }
+
+    /// GET 요청 (리다이렉트마다 다른 호스트면 도메인 규칙과 권한을 다시 확인)
+    async fn get(
+        &self,
+        url: url::Url,
+        context: &dyn ToolContext,
+    ) -> Result<std::result::Result<reqwest::Response, String>> {
+        let client = self.config.client();
+        let mut url = url;
+        let mut redirects = 0;
+        loop {
+            let response = match client.get(url.clone()).send().await {
+                Ok(r) => r,
+                Err(e) => return Ok(Err(format!("Download failed: {}", e))),
+            };
+            let location = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .filter(|_| response.status().is_redirection())
+                .map(str::to_string);
+            let Some(location) = location else {
+                return Ok(Ok(response));
+            };
+
+            redirects += 1;
+            if redirects > Self::MAX_REDIRECTS {
+                return Ok(Err(format!(
+                    "Download failed: too many redirects (max {})",
+                    Self::MAX_REDIRECTS
+                )));
+            }
+            let next = match url.join(&location) {
+                Ok(next) => next,
+                Err(e) => return Ok(Err(format!("Invalid redirect '{}': {}", location, e))),
+            };
+            let (next, host) = match HttpRequestTool::parse_url(next.as_str()) {
+                Ok(v) => v,
+                Err(e) => return Ok(Err(e)),
+            };
+
+            if next.host_str() != url.host_str() {
+                if self.config.is_denied(&host) {
+                    return Ok(Err(format!("Redirect to '{}' is denied by configuration", host)));
+                }
+                if let Err(e) = check_egress(&host) {
+                    return Ok(Err(e.to_string()));
+                }
+                if let Some(action) = self.config.network_permission(next.as_str()) {
+                    let description = format!("Download {} (redirected from {})", next, url);
+                    if !ensure_permission(context, Self::NAME, &description, action).await? {
+                        return Ok(Err(format!(
+                            "Permission denied for download from '{}'",
+                            host
+                        )));
+                    }
+                }
+            }
+            url = next;
+        }
+    }
+}
+
+impl Default for DownloadTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for DownloadTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Download")
+            .description(
+                "Download a URL to a file in the workspace (datasets, release binaries, schema files). \
+                 Streams to disk with a size limit and verifies the sha256 checksum when given; \
+                 the file is only written if the checksum matches.",
+            )
+            .category("network")
+            .permission(
+                PermissionDef::new("network.request", "network")
+                    .risk_level(5)
+                    .description("Download a file from a domain")
+                    .requires_confirmation(true),
+            )
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The http(s) URL to download"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Destination file path (relative to the working directory)"
+                },
+                "sha256": {
+                    "type": "string",
+                    "description": "Expected sha256 checksum (hex). The download fails if it does not match"
+                },
+                "max_bytes": {
+                    "type": "integer",
+                    "description": "Maximum download size in bytes (default and max: 500 MB)"
+                },
+                "overwrite": {
+                    "type": "boolean",
+                    "description": "Replace an existing file (default: false)"
+                }
+            },
+            "required": ["url", "path"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        self.config.network_permission(input.get("url")?.as_str()?)
+    }
+
+    fn required_permissions(&self, input: &Value) -> Vec<PermissionAction> {
+        // 도메인 → 저장 경로 (execute와 같은 순서)
+        let mut actions: Vec<_> = self.required_permission(input).into_iter().collect();
+        if let Some(path) = input.get("path").and_then(|p| p.as_str()) {
+            actions.push(PermissionAction::FileWrite {
+                path: path.to_string(),
+            });
+        }
+        actions
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let (Some(url_str), Some(path_str)) = (input["url"].as_str(), input["path"].as_str())
+        else {
+            return Ok(ToolResult::error("Missing required parameters: url, path"));
+        };
+        let (url, host) = match HttpRequestTool::parse_url(url_str) {
+            Ok(v) => v,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+        if self.config.is_denied(&host) {
+            return Ok(ToolResult::error(format!(
+                "Downloads from '{}' are denied by configuration",
+                host
+            )));
+        }
+        if let Err(e) = check_egress(&host) {
+            return Ok(ToolResult::error(e.to_string()));
+        }
+
+        let expected = match input["sha256"].as_str().map(normalize_checksum) {
+            Some(Ok(checksum)) => Some(checksum),
+            Some(Err(e)) => return Ok(ToolResult::error(e)),
+            None => None,
+        };
+        let max_bytes = input["max_bytes"]
+            .as_u64()
+            .unwrap_or(self.config.max_download_bytes)
+            .min(self.config.max_download_bytes);
+
+        let path = match resolve_path(context, path_str) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+        if path.exists() && !input["overwrite"].as_bool().unwrap_or(false) {
+            return Ok(ToolResult::error(format!(
+                "File already exists: {} (set overwrite: true to replace it)",
+                path_str
+            )));
+        }
+
+        // 권한 확인 (도메인 → 저장 경로)
+        if let Some(action) = self.required_permission(&input) {
+            let description = format!("Download {}", url);
+            if !ensure_permission(context, Self::NAME, &description, action).await? {
+                return Ok(ToolResult::error(format!(
+                    "Permission denied for download from '{}'",
+                    host
+                )));
+            }
+        }
+        let write = PermissionAction::FileWrite {
+            path: path_str.to_string(),
+        };
+        let description = format!("Write file: {}", path_str);
+        if !ensure_permission(context, Self::NAME, &description, write).await? {
+            return Ok(ToolResult::error("Permission denied for file write"));
+        }
+
+        // 요청
+        let start = Instant::now();
+        let mut response = match self.get(url.clone(), context).await? {
+            Ok(r) => r,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+        let status = response.status();
+        if !status.is_success() {
+            return Ok(ToolResult::error(format!(
+                "Download failed: HTTP {} {}",
+                status.as_u16(),
+                status.canonical_reason().unwrap_or("")
+            )));
+        }
+        let total = response.content_length();
+        if let Some(total) = total.filter(|&total| total > max_bytes) {
+            return Ok(ToolResult::error(format!(
+                "File is {} which exceeds the limit of {}",
+                format_size(total),
+                format_size(max_bytes)
+            )));
+        }
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = tokio::fs::create_dir_all(parent).await {
+                return Ok(ToolResult::error(format!(
+                    "Failed to create directory {}: {}",
+                    parent.display(),
+                    e
+                )));
+            }
+        }
+        let part_path = path.with_extension(match path.extension() {
+            Some(ext) => format!("{}.part", ext.to_string_lossy()),
+            None => "part".to_string(),
+        });
+        let mut file = match tokio::fs::File::create(&part_path).await {
+            Ok(file) => file,
+            Err(e) => return Ok(ToolResult::error(format!("Failed to create file: {}", e))),
+        };
+
+        // 스트리밍 저장 + 해시
+        let mut digest = DigestContext::new(&SHA256);
+        let mut received: u64 = 0;
+        // 진행률 보고 단위 (10% 또는 8 MB)
+        let step = match total {
+            Some(total) if total > 0 => (total / 10).max(1),
+            _ => Self::PROGRESS_STEP_BYTES,
+        };
+        let mut next_report = step;
+        let failure = loop {
+            let chunk = match response.chunk().await {
+                Ok(Some(chunk)) => chunk,
+                Ok(None) => break None,
+                Err(e) => break Some(format!("Download interrupted: {}", e)),
+            };
+            received += chunk.len() as u64;
+            if received > max_bytes {
+                break Some(format!(
+                    "Download exceeded the limit of {}",
+                    format_size(max_bytes)
+                ));
+            }
+            digest.update(&chunk);
+            if let Err(e) = file.write_all(&chunk).await {
+                break Some(format!("Failed to write file: {}", e));
+            }
+
+            if received >= next_report {
+                next_report = (received / step + 1) * step;
+                if let Some(sink) = context.output_sink() {
+                    let line = match total {
+                        Some(total) if total > 0 => format!(
+                            "downloaded {} / {} ({}%)\n",
+                            format_size(received),
+                            format_size(total),
+                            received.min(total) * 100 / total
+                        ),
+                        _ => format!("downloaded {}\n", format_size(received)),
+                    };
+                    let _ = sink.on_output(&line, false);
+                }
+            }
+        };
+        let flushed = file.flush().await;
+        drop(file);
+
+        let failure = failure.or_else(|| {
+            flushed
+                .err()
+                .map(|e| format!("Failed to write file: {}", e))
+        });
+        if let Some(error) = failure {
+            let _ = tokio::fs::remove_file(&part_path).await;
+            return Ok(ToolResult::error(error));
+        }
+
+        // 체크섬 검증
+        let actual = hex_digest(digest);
+        if let Some(expected) = &expected {
+            if *expected != actual {
+                let _ = tokio::fs::remove_file(&part_path).await;
+                return Ok(ToolResult::error(format!(
+                    "Checksum mismatch for {}: expected sha256 {}, got {}. The file was not saved.",
+                    url, expected, actual
+                )));
+            }
+        }
+
+        if let Err(e) = tokio::fs::rename(&part_path, &path).await {
+            let _ = tokio::fs::remove_file(&part_path).await;
+            return Ok(ToolResult::error(format!("Failed to save file: {}", e)));
+        }
+
+        let verified = expected.is_some();
+        let output = format!(
+            "Downloaded {} to {} in {:.1}s\nsha256: {}{}",
+            format_size(received),
+            path_str,
+            start.elapsed().as_secs_f64(),
+            actual,
+            if verified {
+                " (verified)"
+            } else {
+                " (not verified - pass sha256 to check integrity)"
+            }
+        );
+
+        Ok(ToolResult::success(output)
+            .with_metadata("path", json!(path.display().to_string()))
+            .with_metadata("bytes", json!(received))
+            .with_metadata("sha256", json!(actual))
+            .with_metadata("verified", json!(verified)))
+    }
+}
+
+// ============================================================================
+// UploadTool
+// ============================================================================
+
+/// 파일 업로드 도구 (원본 본문 PUT/POST)
+pub struct UploadTool {
+    config: TransferConfig,
+}
+
+impl UploadTool {
+    /// 도구 이름
+    pub const NAME: &'static str = "upload";
+
+    pub fn new() -> Self {
+        Self::with_config(TransferConfig::default())
+    }
+
+    /// 설정과 함께 생성
+    pub fn with_config(config: TransferConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for UploadTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for UploadTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Upload")
+            .description(
+                "Upload a workspace file as the raw request body (PUT or POST) to an artifact store \
+                 or pre-signed URL. Reports the HTTP status and the file's sha256 checksum.",
+            )
+            .category("network")
+            .permission(
+                PermissionDef::new("network.request", "network")
+                    .risk_level(6)
+                    .description("Upload a file to a domain")
+                    .requires_confirmation(true),
+            )
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "File to upload (relative to the working directory)"
+                },
+                "url": {
+                    "type": "string",
+                    "description": "The http(s) URL to upload to"
+                },
+                "method": {
+                    "type": "string",
+                    "enum": ["PUT", "POST"],
+                    "description": "HTTP method (default: PUT)"
+                },
+                "headers": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                    "description": "Request headers (e.g. authorization, content-type)"
+                }
+            },
+            "required": ["path", "url"]
+        })
+    }
+
+    fn required_permission(&self, input: &Value) -> Option<PermissionAction> {
+        // 업로드는 외부로 데이터를 내보내므로 허용 도메인이어도 확인
+        let (_, host) = HttpRequestTool::parse_url(input.get("url")?.as_str()?).ok()?;
+        Some(PermissionAction::Network { url: host })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let (Some(path_str), Some(url_str)) = (input["path"].as_str(), input["url"].as_str())
+        else {
+            return Ok(ToolResult::error("Missing required parameters: path, url"));
+        };
+        let (url, host) = match HttpRequestTool::parse_url(url_str) {
+            Ok(v) => v,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+        if self.config.is_denied(&host) {
+            return Ok(ToolResult::error(format!(
+                "Uploads to '{}' are denied by configuration",
+                host
+            )));
+        }
+        if let Err(e) = check_egress(&host) {
+            return Ok(ToolResult::error(e.to_string()));
+        }
+        let method = input["method"]
+            .as_str()
+            .unwrap_or("PUT")
+            .to_ascii_uppercase();
+        let method = match method.as_str() {
+            "PUT" => reqwest::Method::PUT,
+            "POST" => reqwest::Method::POST,
+            other => {
+                return Ok(ToolResult::error(format!(
+                    "Unsupported method '{}'. Use PUT or POST",
+                    other
+                )))
+            }
+        };
+        let headers: HashMap<String, String> =
+            serde_json::from_value(input["headers"].clone()).unwrap_or_default();
+
+        let path = match resolve_path(context, path_str) {
+            Ok(path) => path,
+            Err(e) => return Ok(ToolResult::error(e)),
+        };
+        let size = match tokio::fs::metadata(&path).await {
+            Ok(meta) if meta.is_file() => meta.len(),
+            Ok(_) => return Ok(ToolResult::error(format!("Not a file: {}", path_str))),
+            Err(e) => {
+                return Ok(ToolResult::error(format!(
+                    "Cannot read {}: {}",
+                    path_str, e
+                )))
+            }
+        };
+        if size > self.config.max_upload_bytes {
+            return Ok(ToolResult::error(format!(
+                "File is {} which exceeds the upload limit of {}",
+                format_size(size),
+                format_size(self.config.max_upload_bytes)
+            )));
+        }
+
+        if let Some(action) = self.required_permission(&input) {
+            let description = format!("Upload {} to {}", path_str, url);
+            if !ensure_permission(context, Self::NAME, &description, action).await? {
+                return Ok(ToolResult::error(format!(
+                    "Permission denied for upload to '{}'",
+                    host
+                )));
+            }
+        }
+
+        let body = match tokio::fs::read(&path).await {
+            Ok(body) => body,
+            Err(e) => {
+                return Ok(ToolResult::error(format!(
+                    "Cannot read {}: {}",
+                    path_str, e
+                )))
+            }
+        };
+        let mut digest = DigestContext::new(&SHA256);
+        digest.update(&body);
+        let checksum = hex_digest(digest);
+
+        let mut request = self
+            .config
+            .client()
+            .request(method.clone(), url.clone())
+            .header("content-type", "application/octet-stream");
+        for (key, value) in &headers {
+            request = request.header(key, value);
+        }
+
+        let start = Instant::now();
+        let response = match request.body(body).send().await {
+            Ok(r) => r,
+            Err(e) => return Ok(ToolResult::error(format!("Upload failed: {}", e))),
+        };
+        let status = response.status();
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .filter(|_| status.is_redirection())
+            .map(|location| format!("\nredirect not followed: {}", location))
+            .unwrap_or_default();
+        let response_body = response.text().await.unwrap_or_default();
+        let response_preview: String = response_body.chars().take(500).collect();
+
+        let output = format!(
+            "{} {} → HTTP {} {}{}\nuploaded {} in {:.1}s\nsha256: {}{}",
+            method,
+            url,
+            status.as_u16(),
+            status.canonical_reason().unwrap_or(""),
+            location,
+            format_size(size),
+            start.elapsed().as_secs_f64(),
+            checksum,
+            if response_preview.trim().is_empty() {
+                String::new()
+            } else {
+                format!("\n\n{}", response_preview.trim())
+            }
+        );
+
+        let result = if status.is_success() {
+            ToolResult::success(output)
+        } else {
+            ToolResult::error(output)
+        };
+        Ok(result
+            .with_metadata("status", json!(status.as_u16()))
+            .with_metadata("bytes", json!(size))
+            .with_metadata("sha256", json!(checksum)))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::io::{Read, Write};
+    use std::sync::{Arc, Mutex};
+
+    /// "hello world"의 sha256
+    const HELLO_SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+    /// 단일 응답을 반환하는 로컬 HTTP 서버 (받은 요청을 기록)
+    fn serve_once(body: &str) -> (String, Arc<Mutex<Vec<u8>>>) {
+        serve_response(format!(
+            "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ))
+    }
+
+    /// `location`으로 리다이렉트하는 로컬 HTTP 서버
+    fn serve_redirect(status: &str, location: &str) -> (String, Arc<Mutex<Vec<u8>>>) {
+        serve_response(format!(
+            "HTTP/1.1 {}\r\nlocation: {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+            status, location
+        ))
+    }
+
+    fn serve_response(response: String) -> (String, Arc<Mutex<Vec<u8>>>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let captured = Arc::clone(&received);
+
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                stream
+                    .set_read_timeout(Some(Duration::from_millis(200)))
+                    .unwrap();
+                let mut buf = [0u8; 4096];
+                while let Ok(n) = stream.read(&mut buf) {
+                    if n == 0 {
+                        break;
+                    }
+                    captured.lock().unwrap().extend_from_slice(&buf[..n]);
+                }
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        (format!("http://{}/file", addr), received)
+    }
+
+    fn context(dir: &Path) -> RuntimeContext {
+        RuntimeContext::new(
+            "test-session",
+            dir.to_path_buf(),
+            Arc::new(PermissionService::with_auto_approve()),
+        )
+    }
+
+    #[test]
+    fn test_normalize_checksum() {
+        assert_eq!(
+            normalize_checksum(&format!("sha256:{}", HELLO_SHA256.to_uppercase())).unwrap(),
+            HELLO_SHA256
+        );
+        assert!(normalize_checksum("abc").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_download_verified() {
+        let dir = tempfile::tempdir().unwrap();
+        let (url, _) = serve_once("hello world");
+
+        let result = DownloadTool::new()
+            .execute(
+                json!({ "url": url, "path": "data/hello.txt", "sha256": HELLO_SHA256 }),
+                &context(dir.path()),
+            )
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("(verified)"));
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("data/hello.txt")).unwrap(),
+            "hello world"
+        );
+        assert!(!dir.path().join("data/hello.txt.part").exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_checksum_mismatch_and_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool = DownloadTool::new();
+
+        let (url, _) = serve_once("tampered");
+        let result = tool
+            .execute(
+                json!({ "url": url, "path": "hello.txt", "sha256": HELLO_SHA256 }),
+                &context(dir.path()),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Checksum mismatch"));
+        assert!(!dir.path().join("hello.txt").exists());
+        assert!(!dir.path().join("hello.txt.part").exists());
+
+        let (url, _) = serve_once("hello world");
+        let result = tool
+            .execute(
+                json!({ "url": url, "path": "hello.txt", "max_bytes": 4 }),
+                &context(dir.path()),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("exceeds the limit"));
+    }
+
+    #[tokio::test]
+    async fn test_download_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("hello.txt"), "old").unwrap();
+
+        let result = DownloadTool::new()
+            .execute(
+                json!({ "url": "http://127.0.0.1:9/file", "path": "hello.txt" }),
+                &context(dir.path()),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("already exists"));
+    }
+
+    #[tokio::test]
+    async fn test_upload() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("artifact.bin"), "hello world").unwrap();
+        let (url, received) = serve_once("stored");
+
+        let result = UploadTool::new()
+            .execute(
+                json!({ "path": "artifact.bin", "url": url }),
+                &context(dir.path()),
+            )
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.metadata["sha256"], json!(HELLO_SHA256));
+        assert!(result.output.contains("stored"));
+
+        let request = String::from_utf8_lossy(&received.lock().unwrap()).to_string();
+        assert!(request.starts_with("PUT /file"));
+        assert!(request.ends_with("hello world"));
+    }
+
+    #[tokio::test]
+    async fn test_redirects_recheck_permission() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("artifact.bin"), "hello world").unwrap();
+        let permissions = Arc::new(PermissionService::new());
+        let ctx = RuntimeContext::new(
+            "test-session",
+            dir.path().to_path_buf(),
+            Arc::clone(&permissions),
+        );
+        let network = |host: &str| PermissionAction::Network {
+            url: host.to_string(),
+        };
+        permissions.grant_session(DownloadTool::NAME, network("127.0.0.1"));
+        permissions.grant_session(
+            DownloadTool::NAME,
+            PermissionAction::FileWrite {
+                path: "hello.txt".to_string(),
+            },
+        );
+        permissions.grant_session(UploadTool::NAME, network("127.0.0.1"));
+
+        // 승인되지 않은 다른 호스트로의 리다이렉트는 다시 확인 (거부)
+        let (target, _) = serve_once("hello world");
+        let (url, _) = serve_redirect("302 Found", &target.replace("127.0.0.1", "localhost"));
+        let result = DownloadTool::new()
+            .execute(json!({ "url": url, "path": "hello.txt" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("'localhost'"));
+        assert!(!dir.path().join("hello.txt").exists());
+
+        // 업로드는 307이어도 본문을 다시 보내지 않음
+        let (target, received) = serve_once("stored");
+        let (url, _) = serve_redirect("307 Temporary Redirect", &target);
+        let result = UploadTool::new()
+            .execute(json!({ "path": "artifact.bin", "url": url }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("redirect not followed"));
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_upload_always_asks_for_network_permission() {
+        let tool = UploadTool::with_config(TransferConfig {
+            allowed_domains: vec!["*.trusted.dev".to_string()],
+            ..Default::default()
+        });
+        let perm = tool.required_permission(
+            &json!({ "path": "a.bin", "url": "https://cdn.trusted.dev/a.bin" }),
+        );
+        assert!(
+            matches!(perm, Some(PermissionAction::Network { url }) if url == "cdn.trusted.dev")
+        );
+
+        let download = DownloadTool::with_config(TransferConfig {
+            allowed_domains: vec!["*.trusted.dev".to_string()],
+            ..Default::default()
+        });
+        assert!(download
+            .required_permission(&json!({ "url": "https://cdn.trusted.dev/a.bin" }))
+            .is_none());
+    }
+
+    #[test]
+    fn test_download_required_permissions() {
+        let download = DownloadTool::with_config(TransferConfig {
+            allowed_domains: vec!["*.trusted.dev".to_string()],
+            ..Default::default()
+        });
+        let actions = download.required_permissions(
+            &json!({ "url": "https://example.com/a.bin", "path": "dl/a.bin" }),
+        );
+        assert_eq!(
+            actions,
+            vec![
+                PermissionAction::Network {
+                    url: "example.com".to_string()
+                },
+                PermissionAction::FileWrite {
+                    path: "dl/a.bin".to_string()
+                },
+            ]
+        );
+
+        // 허용 도메인이어도 저장 경로 쓰기는 필요
+        let actions = download.required_permissions(
+            &json!({ "url": "https://cdn.trusted.dev/a.bin", "path": "dl/a.bin" }),
+        );
+        assert_eq!(actions.len(), 1);
+    }
+}
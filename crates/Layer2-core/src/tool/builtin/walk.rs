@@ -0,0 +1,72 @@
+//! Workspace Walk - glob/grep 공용 디렉토리 순회
+//!
+//! 두 도구가 같은 무시 규칙을 쓰도록 `WalkBuilder` 설정을 한 곳에 모읍니다.
+//! - `.gitignore` / 전역 gitignore / `.git/info/exclude` (git 저장소가 아니어도 적용)
+//! - `.forgeignore` - Forge 전용 무시 파일 (gitignore 문법)
+//! - 숨김 파일은 검색하되 `.git` 디렉토리는 제외
+
+use ignore::WalkBuilder;
+use std::path::Path;
+
+/// Forge 전용 무시 파일 이름
+pub const FORGE_IGNORE_FILENAME: &str = ".forgeignore";
+
+/// 무시 규칙이 적용된 순회기 생성
+pub(crate) fn workspace_walker(root: &Path) -> WalkBuilder {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .hidden(false) // 숨김 파일도 검색
+        .git_ignore(true) // .gitignore 존중
+        .git_global(true) // 전역 gitignore 존중
+        .git_exclude(true) // .git/info/exclude 존중
+        .require_git(false) // git 저장소 밖에서도 .gitignore 적용
+        .add_custom_ignore_filename(FORGE_IGNORE_FILENAME)
+        .filter_entry(|entry| entry.file_name() != ".git");
+    builder
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_ignore_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(root.join("target")).unwrap();
+        fs::create_dir_all(root.join("fixtures")).unwrap();
+        fs::write(root.join(".git/config"), "").unwrap();
+        fs::write(root.join(".gitignore"), "target/\n").unwrap();
+        fs::write(root.join(FORGE_IGNORE_FILENAME), "fixtures/\n").unwrap();
+        fs::write(root.join("target/out.rs"), "").unwrap();
+        fs::write(root.join("fixtures/big.json"), "").unwrap();
+        fs::write(root.join("main.rs"), "").unwrap();
+        fs::write(root.join(".env.example"), "").unwrap();
+
+        let files: Vec<String> = workspace_walker(root)
+            .build()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .map(|entry| {
+                entry
+                    .path()
+                    .strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+
+        assert!(files.contains(&"main.rs".to_string()));
+        assert!(files.contains(&".env.example".to_string()));
+        assert!(!files.iter().any(|f| f.starts_with(".git/")));
+        assert!(!files.iter().any(|f| f.starts_with("target/")));
+        assert!(!files.iter().any(|f| f.starts_with("fixtures/")));
+    }
+}
@@ -92,10 +92,10 @@ impl WebFetchTool {
 
     /// Create with custom config
     pub fn with_config(config: WebFetchConfig) -> Self {
-        let client = Client::builder()
+        let client = forge_foundation::egress_client()
             .timeout(config.timeout)
             .redirect(if config.follow_redirects {
-                reqwest::redirect::Policy::limited(config.max_redirects)
+                forge_foundation::egress_redirect_policy(config.max_redirects)
             } else {
                 reqwest::redirect::Policy::none()
             })
@@ -116,7 +116,7 @@ impl WebSearchTool {
 
     /// Create with custom config
     pub fn with_config(config: WebSearchConfig) -> Self {
-        let client = Client::builder()
+        let client = forge_foundation::egress_client()
             .timeout(config.timeout)
             .user_agent("ForgeCode/1.0")
             .build()
@@ -8,14 +8,16 @@
 
 use async_trait::async_trait;
 use forge_foundation::{
-    PermissionAction, PermissionDef, PermissionStatus, Result, Tool, ToolContext, ToolMeta,
-    ToolResult,
+    ConfirmationPrompt, PermissionAction, PermissionDef, PermissionStatus, PromptPreview, Result,
+    Tool, ToolContext, ToolMeta, ToolResult,
 };
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::fs;
 use std::path::Path;
 
+use super::EditTool;
+use crate::tool::format::format_written_file;
 use crate::tool::security::{is_sensitive_path, PathValidator};
 
 /// Write 도구 입력
@@ -183,14 +185,18 @@ impl Tool for WriteTool {
                     return Ok(ToolResult::error("Permission denied for file write"));
                 }
                 PermissionStatus::Unknown => {
-                    let granted = context
-                        .request_permission(
-                            Self::NAME,
-                            &format!("Write file: {}", parsed.file_path),
-                            action,
-                        )
-                        .await?;
-                    if !granted {
+                    // 기존 파일이면 변경 내용, 새 파일이면 전체 내용을 diff로 표시
+                    let old_content = fs::read_to_string(path).unwrap_or_default();
+                    let diff =
+                        EditTool::generate_diff(&old_content, &parsed.content, &parsed.file_path);
+                    let prompt = ConfirmationPrompt::for_action(
+                        Self::NAME,
+                        &action,
+                        &format!("Write file: {}", parsed.file_path),
+                        5,
+                    )
+                    .with_preview(PromptPreview::diff(&parsed.file_path, diff));
+                    if !context.request_confirmation(prompt).await? {
                         return Ok(ToolResult::error("Permission denied by user"));
                     }
                 }
@@ -228,10 +234,23 @@ impl Tool for WriteTool {
                 let lines = parsed.content.lines().count();
                 let action = if existed { "Updated" } else { "Created" };
 
-                Ok(ToolResult::success(format!(
+                // 프로젝트 설정의 자동 포맷
+                let (content, format_note) =
+                    format_written_file(context, path, &parsed.content).await;
+
+                // 실행 중인 언어 서버에 변경 알림
+                if let Some(sync) = context.document_sync() {
+                    sync.file_changed(path, &content).await;
+                }
+
+                let mut result_msg = format!(
                     "{} {} ({} bytes, {} lines)",
                     action, parsed.file_path, bytes, lines
-                )))
+                );
+                if let Some(note) = format_note {
+                    result_msg.push_str(&format!("\n{}", note));
+                }
+                Ok(ToolResult::success(result_msg))
             }
             Err(e) => Ok(ToolResult::error(format!("Failed to write file: {}", e))),
         }
@@ -277,6 +296,108 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_write_notifies_document_sync() {
+        use crate::tool::RuntimeContext;
+        use forge_foundation::{DocumentSync, PermissionService};
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<(std::path::PathBuf, String)>>);
+
+        #[async_trait]
+        impl DocumentSync for Recorder {
+            async fn file_changed(&self, path: &Path, content: &str) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((path.to_path_buf(), content.to_string()));
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Arc::new(Recorder::default());
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        )
+        .with_document_sync(Some(recorder.clone() as Arc<dyn DocumentSync>));
+        ctx.grant_session(
+            WriteTool::NAME,
+            PermissionAction::FileWrite {
+                path: "main.rs".to_string(),
+            },
+        );
+
+        let result = WriteTool::new()
+            .execute(json!({ "file_path": "main.rs", "content": "fn main() {}\n" }), &ctx)
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let changes = recorder.0.lock().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].0, dir.path().join("main.rs"));
+        assert_eq!(changes[0].1, "fn main() {}\n");
+    }
+
+    #[tokio::test]
+    async fn test_write_applies_formatter() {
+        use crate::tool::RuntimeContext;
+        use forge_foundation::{DocumentFormatter, PermissionService, Result};
+        use std::sync::Arc;
+
+        struct BraceFormatter;
+
+        #[async_trait]
+        impl DocumentFormatter for BraceFormatter {
+            async fn format(&self, path: &Path, content: &str) -> Result<Option<String>> {
+                Ok((path.extension().is_some_and(|e| e == "rs"))
+                    .then(|| content.replace("{}", "{ }")))
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = RuntimeContext::new(
+            "test",
+            dir.path().to_path_buf(),
+            Arc::new(PermissionService::new()),
+        )
+        .with_formatter(Some(Arc::new(BraceFormatter) as Arc<dyn DocumentFormatter>));
+        for path in ["main.rs", "notes.txt"] {
+            ctx.grant_session(
+                WriteTool::NAME,
+                PermissionAction::FileWrite {
+                    path: path.to_string(),
+                },
+            );
+        }
+
+        let result = WriteTool::new()
+            .execute(
+                json!({ "file_path": "main.rs", "content": "fn main() {}\n" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(result.output.ends_with("\nAuto-formatted"));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("main.rs")).unwrap(),
+            "fn main() { }\n"
+        );
+
+        let result = WriteTool::new()
+            .execute(json!({ "file_path": "notes.txt", "content": "{}" }), &ctx)
+            .await
+            .unwrap();
+        assert!(!result.output.contains("Auto-formatted"));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("notes.txt")).unwrap(),
+            "{}"
+        );
+    }
+
     #[test]
     fn test_sensitive_path_detection() {
         use crate::tool::security::is_sensitive_path;
@@ -4,6 +4,11 @@
 //! - PermissionService 연동
 //! - PermissionDelegate 연동 (대화형 권한 승인)
 //! - ShellConfig 연동
+//! - ToolOutputSink 연동 (실행 중 출력 스트리밍)
+//! - CancellationToken 연동 (타임아웃/중단 시 협력적 취소)
+//! - AuditLogger 연동 (대화형 권한 결정 기록)
+//! - DocumentSync 연동 (파일 변경을 언어 서버에 알림)
+//! - DocumentFormatter 연동 (Write/Edit 후 자동 포맷)
 //!
 //! ## 대화형 권한 승인
 //!
@@ -19,14 +24,17 @@
 //! ```
 
 use async_trait::async_trait;
+use forge_foundation::audit::{AuditEntry, AuditLogger};
 use forge_foundation::{
-    PermissionAction, PermissionDelegate, PermissionResponse,
-    PermissionService, PermissionStatus, Result, ShellConfig, ShellType, ToolContext,
+    CancellationToken, ConfirmationPrompt, DocumentFormatter, DocumentSync, PermissionAction,
+    PermissionDelegate, PermissionResponse, PermissionService, PermissionStatus, Result,
+    ShellConfig, ShellType, ToolContext, ToolOutputSink,
 };
+use serde_json::Value;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::debug;
+use tracing::{debug, warn};
 
 // ============================================================================
 // DefaultShellConfig - 기본 Shell 설정
@@ -125,6 +133,9 @@ impl ShellConfig for DefaultShellConfig {
 /// - 권한 서비스
 /// - 권한 델리게이트 (대화형 권한 승인)
 /// - Shell 설정
+/// - 출력 수신자 (실행 중 출력 스트리밍)
+/// - 취소 토큰 (타임아웃/중단)
+/// - 감사 로거 (권한 결정 기록)
 pub struct RuntimeContext {
     session_id: String,
     working_dir: PathBuf,
@@ -132,6 +143,11 @@ pub struct RuntimeContext {
     permissions: Arc<PermissionService>,
     permission_delegate: Option<Arc<dyn PermissionDelegate>>,
     shell_config: Box<dyn ShellConfig>,
+    output_sink: Option<Arc<dyn ToolOutputSink>>,
+    cancellation: Option<CancellationToken>,
+    audit_logger: Option<Arc<AuditLogger>>,
+    document_sync: Option<Arc<dyn DocumentSync>>,
+    formatter: Option<Arc<dyn DocumentFormatter>>,
 }
 
 impl RuntimeContext {
@@ -148,6 +164,11 @@ impl RuntimeContext {
             permissions,
             permission_delegate: None,
             shell_config: Box::new(DefaultShellConfig::new().with_working_dir(working_dir)),
+            output_sink: None,
+            cancellation: None,
+            audit_logger: None,
+            document_sync: None,
+            formatter: None,
         }
     }
 
@@ -172,6 +193,36 @@ impl RuntimeContext {
         self
     }
 
+    /// 출력 수신자 설정 (실행 중 출력 스트리밍용)
+    pub fn with_output_sink(mut self, sink: Option<Arc<dyn ToolOutputSink>>) -> Self {
+        self.output_sink = sink;
+        self
+    }
+
+    /// 취소 토큰 설정 (도구가 `ToolContext::cancelled()`로 감지)
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// 감사 로거 설정 (델리게이트의 권한 결정을 기록)
+    pub fn with_audit_logger(mut self, logger: Option<Arc<AuditLogger>>) -> Self {
+        self.audit_logger = logger;
+        self
+    }
+
+    /// 문서 동기화 핸들 설정 (편집/쓰기 후 언어 서버 알림)
+    pub fn with_document_sync(mut self, sync: Option<Arc<dyn DocumentSync>>) -> Self {
+        self.document_sync = sync;
+        self
+    }
+
+    /// 자동 포맷터 설정 (편집/쓰기 직후 적용)
+    pub fn with_formatter(mut self, formatter: Option<Arc<dyn DocumentFormatter>>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
     /// 권한 서비스 접근
     pub fn permission_service(&self) -> &PermissionService {
         &self.permissions
@@ -212,6 +263,29 @@ impl ToolContext for RuntimeContext {
         description: &str,
         action: PermissionAction,
     ) -> Result<bool> {
+        // 위험도 계산 (action 타입에 따라)
+        let risk_score = match &action {
+            PermissionAction::Execute { .. } => 7,
+            PermissionAction::FileDelete { .. } => 8,
+            PermissionAction::FileWrite { .. } => 5,
+            PermissionAction::FileReadSensitive { .. } => 4,
+            PermissionAction::Network { .. } => 6,
+            PermissionAction::Custom { .. } => 5,
+        };
+
+        self.request_confirmation(ConfirmationPrompt::for_action(
+            tool,
+            &action,
+            description,
+            risk_score,
+        ))
+        .await
+    }
+
+    async fn request_confirmation(&self, prompt: ConfirmationPrompt) -> Result<bool> {
+        let tool = prompt.tool.as_str();
+        let action = prompt.action.clone();
+
         // 1. 이미 허용된 경우 바로 반환
         let status = self.permissions.check(tool, &action);
         match status {
@@ -230,19 +304,16 @@ impl ToolContext for RuntimeContext {
         if let Some(ref delegate) = self.permission_delegate {
             debug!("Requesting permission via delegate for {}: {:?}", tool, action);
 
-            // 위험도 계산 (action 타입에 따라)
-            let risk_score = match &action {
-                PermissionAction::Execute { .. } => 7,
-                PermissionAction::FileDelete { .. } => 8,
-                PermissionAction::FileWrite { .. } => 5,
-                PermissionAction::FileReadSensitive { .. } => 4,
-                PermissionAction::Network { .. } => 6,
-                PermissionAction::Custom { .. } => 5,
-            };
-
-            let response = delegate
-                .request_permission(tool, &action, description, risk_score)
-                .await;
+            let response = delegate.confirm(&prompt).await;
+            if let Some(ref logger) = self.audit_logger {
+                let entry = AuditEntry::permission_decision(
+                    tool,
+                    &action,
+                    response.is_allowed(),
+                    response.as_str(),
+                );
+                record_audit(logger, entry, &self.session_id, &self.working_dir).await;
+            }
 
             match response {
                 PermissionResponse::AllowOnce => {
@@ -284,6 +355,45 @@ impl ToolContext for RuntimeContext {
     fn shell_config(&self) -> &dyn ShellConfig {
         self.shell_config.as_ref()
     }
+
+    fn output_sink(&self) -> Option<&dyn ToolOutputSink> {
+        self.output_sink.as_deref()
+    }
+
+    fn cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    fn document_sync(&self) -> Option<&dyn DocumentSync> {
+        self.document_sync.as_deref()
+    }
+
+    fn formatter(&self) -> Option<&dyn DocumentFormatter> {
+        self.formatter.as_deref()
+    }
+}
+
+/// 세션과 작업 디렉토리(`data.workingDir`)를 붙여 감사 로그에 기록
+///
+/// 작업 디렉토리는 리포트에서 상대 경로를 해석하고 저장소 밖 수정을 찾는 데 쓰입니다.
+/// 기록 실패는 도구 실행을 막지 않습니다.
+pub(crate) async fn record_audit(
+    logger: &AuditLogger,
+    mut entry: AuditEntry,
+    session_id: &str,
+    working_dir: &Path,
+) {
+    let working_dir = Value::String(working_dir.display().to_string());
+    match entry.data {
+        Value::Object(ref mut data) => {
+            data.insert("workingDir".to_string(), working_dir);
+        }
+        _ => entry.data = serde_json::json!({ "workingDir": working_dir }),
+    }
+
+    if let Err(e) = logger.log(entry.with_session(session_id)).await {
+        warn!("Failed to record audit entry: {}", e);
+    }
 }
 
 // ============================================================================
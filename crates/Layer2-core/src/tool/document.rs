@@ -0,0 +1,1662 @@
+//! Document Extraction - PDF/DOCX 텍스트 추출 및 이미지 로드
+//!
+//! Read 도구가 바이너리 문서를 처리할 때 사용하는 경량 추출기입니다.
+//! 외부 프로그램 없이 동작하며, 일반적인 문서에서 본문 텍스트를 얻는 것이 목표입니다.
+//!
+//! - PDF: 페이지 트리 순서, FlateDecode 스트림, 객체 스트림(ObjStm), 폰트 ToUnicode CMap 지원
+//!   (ToUnicode가 없는 CID 폰트/커스텀 인코딩 문서는 텍스트가 깨질 수 있음)
+//! - DOCX: ZIP(stored/deflate) 안의 `word/document.xml` 본문
+//! - 이미지: png/jpeg/gif/webp → base64 `ImageAttachment` (vision 모델용)
+//! - 페이지 범위 선택 (`PageRange`)과 토큰 기준 분할 (`chunk_by_tokens`)
+
+use super::archive;
+use base64::Engine;
+use flate2::read::ZlibDecoder;
+use forge_foundation::{Error, ImageAttachment, Result, TiktokenEstimator, Tokenizer};
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::path::Path;
+
+/// 이미지 최대 크기 (프로바이더 제한 고려)
+pub const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+/// PDF 스트림 / DOCX 본문 하나의 최대 압축 해제 크기 (압축 폭탄 방지)
+const MAX_DECODED_STREAM: u64 = 16 * 1024 * 1024;
+
+// ============================================================================
+// DocumentKind
+// ============================================================================
+
+/// 추출을 지원하는 문서 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentKind {
+    /// PDF 문서
+    Pdf,
+    /// Word 문서 (OOXML)
+    Docx,
+    /// 이미지 (MIME 타입)
+    Image(&'static str),
+}
+
+impl DocumentKind {
+    /// 확장자로 문서 종류 판별
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_lowercase();
+        match ext.as_str() {
+            "pdf" => Some(Self::Pdf),
+            "docx" => Some(Self::Docx),
+            "png" => Some(Self::Image("image/png")),
+            "jpg" | "jpeg" => Some(Self::Image("image/jpeg")),
+            "gif" => Some(Self::Image("image/gif")),
+            "webp" => Some(Self::Image("image/webp")),
+            _ => None,
+        }
+    }
+
+    /// 표시용 레이블
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pdf => "PDF",
+            Self::Docx => "DOCX",
+            Self::Image(_) => "Image",
+        }
+    }
+}
+
+// ============================================================================
+// PageRange
+// ============================================================================
+
+/// 페이지 범위 (1-based, 양끝 포함)
+///
+/// `"3"`, `"1-5"`, `"2,4-6"`, `"10-"` (끝까지) 형식을 지원합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRange {
+    ranges: Vec<(usize, usize)>,
+}
+
+impl PageRange {
+    /// 범위 문자열 파싱
+    pub fn parse(spec: &str) -> Result<Self> {
+        let invalid = || Error::InvalidInput(format!("Invalid page range: '{}'", spec));
+        let parse_page = |s: &str| -> Result<usize> {
+            s.trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|p| *p >= 1)
+                .ok_or_else(invalid)
+        };
+
+        let mut ranges = Vec::new();
+        for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let range = match part.split_once('-') {
+                Some((start, end)) if end.trim().is_empty() => (parse_page(start)?, usize::MAX),
+                Some((start, end)) => (parse_page(start)?, parse_page(end)?),
+                None => {
+                    let page = parse_page(part)?;
+                    (page, page)
+                }
+            };
+            if range.0 > range.1 {
+                return Err(invalid());
+            }
+            ranges.push(range);
+        }
+
+        if ranges.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self { ranges })
+    }
+
+    /// 페이지 포함 여부
+    pub fn contains(&self, page: usize) -> bool {
+        self.ranges.iter().any(|(start, end)| page >= *start && page <= *end)
+    }
+
+    /// 전체 페이지 수 중 선택된 페이지 목록 (오름차순)
+    pub fn select(&self, total: usize) -> Vec<usize> {
+        (1..=total).filter(|p| self.contains(*p)).collect()
+    }
+}
+
+// ============================================================================
+// Token-aware chunking
+// ============================================================================
+
+/// 텍스트를 토큰 수 기준으로 분할
+///
+/// 줄 단위로 나누며, 한 줄이 제한을 넘으면 문자 단위로 자릅니다.
+pub fn chunk_by_tokens(text: &str, max_tokens: usize) -> Vec<String> {
+    let tokenizer = TiktokenEstimator::cl100k();
+    let max_tokens = max_tokens.max(1);
+
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_tokens = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_tokens = tokenizer.count(line).total;
+
+        if line_tokens > max_tokens {
+            if !current.is_empty() {
+                chunks.push(std::mem::take(&mut current));
+                current_tokens = 0;
+            }
+            let mut rest = line;
+            while !rest.is_empty() {
+                let piece = tokenizer.truncate(rest, max_tokens);
+                let len = if piece.is_empty() {
+                    rest.chars().next().map(char::len_utf8).unwrap_or(rest.len())
+                } else {
+                    piece.len()
+                };
+                chunks.push(rest[..len].to_string());
+                rest = &rest[len..];
+            }
+            continue;
+        }
+
+        if current_tokens + line_tokens > max_tokens && !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+            current_tokens = 0;
+        }
+        current.push_str(line);
+        current_tokens += line_tokens;
+    }
+
+    if !current.is_empty() || chunks.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+// ============================================================================
+// Images
+// ============================================================================
+
+/// 매직 바이트로 이미지 MIME 타입 판별
+pub fn sniff_image_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+/// 이미지 파일을 첨부 형식으로 로드
+pub fn load_image(path: &Path) -> Result<ImageAttachment> {
+    let data = std::fs::read(path)?;
+
+    if data.len() > MAX_IMAGE_BYTES {
+        return Err(Error::InvalidInput(format!(
+            "Image too large: {} bytes (max {} bytes)",
+            data.len(),
+            MAX_IMAGE_BYTES
+        )));
+    }
+
+    // 확장자가 아닌 실제 내용으로 판별 (잘못된 MIME 타입은 프로바이더가 거부함)
+    let media_type = sniff_image_type(&data).ok_or_else(|| {
+        Error::InvalidInput(format!("Unsupported or corrupt image: {}", path.display()))
+    })?;
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+    Ok(ImageAttachment::new(media_type, encoded).with_source(path.display().to_string()))
+}
+
+// ============================================================================
+// DOCX
+// ============================================================================
+
+/// DOCX 본문 텍스트 추출 (문단마다 줄바꿈)
+pub fn extract_docx_text(data: &[u8]) -> Result<String> {
+    let xml = archive::zip_entry(data, "word/document.xml", MAX_DECODED_STREAM)?
+        .ok_or_else(|| Error::InvalidInput("Not a Word document: word/document.xml missing".into()))?;
+
+    Ok(docx_xml_to_text(&String::from_utf8_lossy(&xml)))
+}
+
+fn docx_xml_to_text(xml: &str) -> String {
+    let mut out = String::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        let after = &rest[start + end + 1..];
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("");
+
+        match name {
+            "w:t" if !tag.starts_with('/') && !tag.ends_with('/') => {
+                let text_end = after.find("</w:t>").unwrap_or(after.len());
+                out.push_str(&unescape_xml(&after[..text_end]));
+                rest = &after[text_end..];
+                continue;
+            }
+            "w:tab" => out.push('\t'),
+            "w:br" | "w:cr" => out.push('\n'),
+            "w:p" if tag.starts_with('/') || tag.ends_with('/') => out.push('\n'),
+            _ => {}
+        }
+        rest = after;
+    }
+
+    out.trim_end().to_string()
+}
+
+fn unescape_xml(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let entity_end = rest[amp..].find(';').map(|i| amp + i);
+        let decoded = entity_end.and_then(|end| match &rest[amp + 1..end] {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            entity => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                .and_then(char::from_u32),
+        });
+
+        match (decoded, entity_end) {
+            (Some(c), Some(end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[amp + 1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// ============================================================================
+// PDF
+// ============================================================================
+
+/// PDF 페이지별 텍스트 추출
+pub fn extract_pdf_pages(data: &[u8]) -> Result<Vec<String>> {
+    if !data.starts_with(b"%PDF") {
+        return Err(Error::InvalidInput("Not a PDF file (missing %PDF header)".into()));
+    }
+
+    let doc = PdfDocument::parse(data);
+    let pages = doc.page_order();
+    if pages.is_empty() {
+        return Err(Error::InvalidInput("No pages found in PDF".into()));
+    }
+
+    Ok(pages
+        .into_iter()
+        .map(|page| extract_content_text(&doc.page_content(page), &doc.page_fonts(page)))
+        .collect())
+}
+
+/// PDF 객체 (딕셔너리 텍스트 + 디코딩된 스트림)
+struct PdfObject {
+    body: String,
+    stream: Option<Vec<u8>>,
+}
+
+struct PdfDocument {
+    objects: HashMap<u32, PdfObject>,
+}
+
+impl PdfDocument {
+    fn parse(data: &[u8]) -> Self {
+        let mut objects = HashMap::new();
+        let mut pos = 0;
+
+        while let Some(found) = find(data, b"obj", pos) {
+            pos = found + 3;
+
+            // "endobj"나 "objstm" 같은 단어는 건너뜀
+            if data.get(pos).is_some_and(|b| b.is_ascii_alphanumeric())
+                || (found >= 3 && &data[found - 3..found] == b"end")
+            {
+                continue;
+            }
+            let Some(num) = object_number_before(data, found) else {
+                continue;
+            };
+
+            let end = find(data, b"endobj", pos).unwrap_or(data.len());
+            let stream_kw = find(data, b"stream", pos).filter(|s| *s < end);
+
+            let (body, stream) = match stream_kw {
+                Some(kw) => {
+                    let dict = String::from_utf8_lossy(&data[pos..kw]).into_owned();
+                    let mut start = kw + 6;
+                    if data.get(start) == Some(&b'\r') {
+                        start += 1;
+                    }
+                    if data.get(start) == Some(&b'\n') {
+                        start += 1;
+                    }
+
+                    let declared = dict_int(&dict, "Length")
+                        .map(|len| start + len as usize)
+                        .filter(|e| *e <= data.len());
+                    let stream_end = declared
+                        .filter(|e| {
+                            let rest = &data[*e..];
+                            let ws = rest.iter().take_while(|b| b.is_ascii_whitespace()).count();
+                            rest[ws..].starts_with(b"endstream")
+                        })
+                        .or_else(|| find(data, b"endstream", start))
+                        .unwrap_or(end);
+                    let raw = &data[start..stream_end.max(start)];
+                    let stream = decode_stream(&dict, raw);
+                    pos = stream_end;
+                    (dict, stream)
+                }
+                None => {
+                    let body = String::from_utf8_lossy(&data[pos..end]).into_owned();
+                    pos = end;
+                    (body, None)
+                }
+            };
+
+            // 증분 업데이트: 뒤에 나온 정의가 우선
+            objects.insert(num, PdfObject { body, stream });
+        }
+
+        let mut doc = Self { objects };
+        doc.expand_object_streams();
+        doc
+    }
+
+    /// 객체 스트림(ObjStm) 안의 객체 풀기
+    fn expand_object_streams(&mut self) {
+        let mut extracted = Vec::new();
+
+        for obj in self.objects.values() {
+            if dict_name(&obj.body, "Type").as_deref() != Some("ObjStm") {
+                continue;
+            }
+            let (Some(data), Some(count), Some(first)) = (
+                &obj.stream,
+                dict_int(&obj.body, "N"),
+                dict_int(&obj.body, "First"),
+            ) else {
+                continue;
+            };
+            let first = first as usize;
+            let Some(header) = data.get(..first) else {
+                continue;
+            };
+
+            let numbers: Vec<usize> = String::from_utf8_lossy(header)
+                .split_whitespace()
+                .filter_map(|n| n.parse().ok())
+                .collect();
+            let pairs: Vec<(usize, usize)> = numbers
+                .chunks_exact(2)
+                .take(count as usize)
+                .map(|p| (p[0], p[1]))
+                .collect();
+
+            for (i, (num, offset)) in pairs.iter().enumerate() {
+                let start = first + offset;
+                let end = pairs
+                    .get(i + 1)
+                    .map(|(_, next)| first + next)
+                    .unwrap_or(data.len());
+                if let Some(body) = data.get(start..end.max(start)) {
+                    extracted.push((*num as u32, String::from_utf8_lossy(body).into_owned()));
+                }
+            }
+        }
+
+        for (num, body) in extracted {
+            self.objects
+                .entry(num)
+                .or_insert(PdfObject { body, stream: None });
+        }
+    }
+
+    /// 페이지 객체 번호 (문서 순서)
+    fn page_order(&self) -> Vec<u32> {
+        let root = self
+            .objects
+            .values()
+            .find(|o| dict_name(&o.body, "Type").as_deref() == Some("Catalog"))
+            .and_then(|catalog| dict_refs(&catalog.body, "Pages").into_iter().next());
+
+        let mut pages = Vec::new();
+        if let Some(root) = root {
+            self.collect_pages(root, &mut pages, &mut HashSet::new());
+        }
+
+        if pages.is_empty() {
+            // 페이지 트리가 손상된 경우: /Type /Page 객체를 번호 순으로
+            pages = self
+                .objects
+                .iter()
+                .filter(|(_, o)| dict_name(&o.body, "Type").as_deref() == Some("Page"))
+                .map(|(num, _)| *num)
+                .collect();
+            pages.sort_unstable();
+        }
+        pages
+    }
+
+    fn collect_pages(&self, num: u32, pages: &mut Vec<u32>, visited: &mut HashSet<u32>) {
+        if !visited.insert(num) {
+            return;
+        }
+        let Some(obj) = self.objects.get(&num) else {
+            return;
+        };
+
+        match dict_name(&obj.body, "Type").as_deref() {
+            Some("Pages") => {
+                for kid in dict_refs(&obj.body, "Kids") {
+                    self.collect_pages(kid, pages, visited);
+                }
+            }
+            Some("Page") => pages.push(num),
+            _ => {}
+        }
+    }
+
+    /// 페이지 콘텐츠 스트림 (여러 개면 이어붙임)
+    fn page_content(&self, page: u32) -> Vec<u8> {
+        let mut content = Vec::new();
+        let Some(obj) = self.objects.get(&page) else {
+            return content;
+        };
+
+        for num in dict_refs(&obj.body, "Contents") {
+            match self.objects.get(&num) {
+                Some(PdfObject { stream: Some(data), .. }) => {
+                    content.extend_from_slice(data);
+                    content.push(b'\n');
+                }
+                // 간접 참조된 배열: [4 0 R 5 0 R]
+                Some(PdfObject { body, stream: None }) => {
+                    for inner in parse_refs(body) {
+                        if let Some(data) = self.objects.get(&inner).and_then(|o| o.stream.as_ref())
+                        {
+                            content.extend_from_slice(data);
+                            content.push(b'\n');
+                        }
+                    }
+                }
+                None => {}
+            }
+        }
+        content
+    }
+
+    /// 페이지 리소스의 폰트 이름 → ToUnicode CMap
+    fn page_fonts(&self, page: u32) -> HashMap<String, ToUnicode> {
+        let mut fonts = HashMap::new();
+
+        // Resources는 상위 Pages 노드에서 상속될 수 있음
+        let mut node = Some(page);
+        let mut visited = HashSet::new();
+        let resources = loop {
+            let Some(obj) = node
+                .filter(|num| visited.insert(*num))
+                .and_then(|num| self.objects.get(&num))
+            else {
+                break None;
+            };
+            if let Some(resources) = self.dict_entry(&obj.body, "Resources") {
+                break Some(resources);
+            }
+            node = dict_refs(&obj.body, "Parent").into_iter().next();
+        };
+
+        let Some(font_dict) = resources.and_then(|r| self.dict_entry(r, "Font")) else {
+            return fonts;
+        };
+        for (name, num) in named_refs(font_dict) {
+            let cmap = self
+                .objects
+                .get(&num)
+                .and_then(|font| dict_refs(&font.body, "ToUnicode").into_iter().next())
+                .and_then(|cmap| self.objects.get(&cmap))
+                .and_then(|cmap| cmap.stream.as_deref())
+                .and_then(ToUnicode::parse);
+            if let Some(cmap) = cmap {
+                fonts.insert(name, cmap);
+            }
+        }
+        fonts
+    }
+
+    /// 딕셔너리 값 (인라인 `<< ... >>` 또는 간접 참조된 객체)
+    fn dict_entry<'a>(&'a self, dict: &'a str, key: &str) -> Option<&'a str> {
+        let value = dict_value(dict, key)?.trim_start();
+        if value.starts_with("<<") {
+            return Some(inline_dict(value));
+        }
+        let num = parse_refs(value).into_iter().next()?;
+        self.objects.get(&num).map(|obj| obj.body.as_str())
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|p| p + from)
+}
+
+/// `12 0 obj`의 객체 번호 읽기 (키워드 위치에서 역방향)
+fn object_number_before(data: &[u8], keyword: usize) -> Option<u32> {
+    let mut i = keyword;
+    let skip_ws = |i: &mut usize| {
+        while *i > 0 && data[*i - 1].is_ascii_whitespace() {
+            *i -= 1;
+        }
+    };
+    let take_digits = |i: &mut usize| -> Option<usize> {
+        let end = *i;
+        while *i > 0 && data[*i - 1].is_ascii_digit() {
+            *i -= 1;
+        }
+        (*i < end).then_some(*i)
+    };
+
+    skip_ws(&mut i);
+    take_digits(&mut i)?;
+    let gen_start = i;
+    skip_ws(&mut i);
+    if i == gen_start {
+        return None;
+    }
+    let num_end = i;
+    let num_start = take_digits(&mut i)?;
+    std::str::from_utf8(&data[num_start..num_end]).ok()?.parse().ok()
+}
+
+fn decode_stream(dict: &str, raw: &[u8]) -> Option<Vec<u8>> {
+    let filter = dict_value(dict, "Filter").unwrap_or("");
+    let filter = filter.trim_start();
+
+    if !filter.starts_with('/') && !filter.starts_with('[') {
+        return Some(raw.to_vec());
+    }
+
+    let names: Vec<&str> = filter
+        .trim_start_matches('[')
+        .split([']', '>', '\n'])
+        .next()
+        .unwrap_or("")
+        .split('/')
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .take_while(|n| n.chars().all(|c| c.is_ascii_alphanumeric()))
+        .collect();
+
+    if names.is_empty() || names.iter().any(|n| *n != "FlateDecode" && *n != "Fl") {
+        return None;
+    }
+
+    // 잘린 스트림도 디코딩된 부분까지는 사용, 상한을 넘으면 포기
+    let mut out = Vec::new();
+    let mut decoder = ZlibDecoder::new(raw).take(MAX_DECODED_STREAM + 1);
+    let _ = decoder.read_to_end(&mut out);
+    if out.len() as u64 > MAX_DECODED_STREAM {
+        return None;
+    }
+    (!out.is_empty()).then_some(out)
+}
+
+/// 최상위 딕셔너리에서 키 바로 뒤의 값 텍스트
+fn dict_value<'a>(dict: &'a str, key: &str) -> Option<&'a str> {
+    let bytes = dict.as_bytes();
+    let mut depth = 0usize;
+    let mut paren = 0usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if paren > 0 {
+            match b {
+                b'\\' => i += 1,
+                b'(' => paren += 1,
+                b')' => paren -= 1,
+                _ => {}
+            }
+        } else if b == b'(' {
+            paren = 1;
+        } else if bytes[i..].starts_with(b"<<") {
+            depth += 1;
+            i += 1;
+        } else if bytes[i..].starts_with(b">>") {
+            depth = depth.saturating_sub(1);
+            i += 1;
+        } else if b == b'/' && depth == 1 {
+            let name_start = i + 1;
+            let name_end = bytes[name_start..]
+                .iter()
+                .position(|c| !c.is_ascii_alphanumeric() && *c != b'.' && *c != b'_')
+                .map(|p| name_start + p)
+                .unwrap_or(bytes.len());
+            if &dict[name_start..name_end] == key {
+                return Some(&dict[name_end..]);
+            }
+            i = name_end;
+            continue;
+        }
+        i += 1;
+    }
+    None
+}
+
+fn dict_name(dict: &str, key: &str) -> Option<String> {
+    let value = dict_value(dict, key)?.trim_start().strip_prefix('/')?;
+    let end = value
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(value.len());
+    Some(value[..end].to_string())
+}
+
+/// 직접 정수 값 (간접 참조면 None)
+fn dict_int(dict: &str, key: &str) -> Option<i64> {
+    let mut tokens = dict_value(dict, key)?.split_whitespace();
+    let first = tokens.next()?;
+    let digits_end = first
+        .find(|c: char| !c.is_ascii_digit() && c != '-')
+        .unwrap_or(first.len());
+    let value = first[..digits_end].parse().ok()?;
+
+    if digits_end == first.len() {
+        if let (Some(generation), Some(r)) = (tokens.next(), tokens.next()) {
+            if generation.chars().all(|c| c.is_ascii_digit()) && r.starts_with('R') {
+                return None;
+            }
+        }
+    }
+    Some(value)
+}
+
+/// 간접 참조 값 (단일 참조 또는 참조 배열)
+fn dict_refs(dict: &str, key: &str) -> Vec<u32> {
+    let Some(value) = dict_value(dict, key) else {
+        return Vec::new();
+    };
+    let value = value.trim_start();
+
+    match value.strip_prefix('[') {
+        Some(array) => parse_refs(&array[..array.find(']').unwrap_or(array.len())]),
+        None => parse_refs(value).into_iter().take(1).collect(),
+    }
+}
+
+/// `<< ... >>`로 시작하는 텍스트에서 짝이 맞는 딕셔너리 부분만
+fn inline_dict(text: &str) -> &str {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut i = 0;
+
+    while i + 1 < bytes.len() {
+        if bytes[i..].starts_with(b"<<") {
+            depth += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b">>") {
+            depth = depth.saturating_sub(1);
+            i += 2;
+            if depth == 0 {
+                return &text[..i];
+            }
+        } else {
+            i += 1;
+        }
+    }
+    text
+}
+
+/// `<< /F1 5 0 R /F2 6 0 R >>` 형태의 이름 → 참조 목록
+fn named_refs(dict: &str) -> Vec<(String, u32)> {
+    let inner = dict.trim().trim_start_matches("<<").trim_end_matches(">>");
+
+    inner
+        .split('/')
+        .skip(1)
+        .filter_map(|entry| {
+            let end = entry
+                .find(|c: char| c.is_whitespace() || c == '<' || c == '[')
+                .unwrap_or(entry.len());
+            let num = parse_refs(&entry[end..]).into_iter().next()?;
+            Some((entry[..end].to_string(), num))
+        })
+        .collect()
+}
+
+/// `N G R` 형태의 참조 목록
+fn parse_refs(text: &str) -> Vec<u32> {
+    let tokens: Vec<&str> = text
+        .split(|c: char| c.is_whitespace() || c == '[' || c == ']' || c == '/' || c == '>')
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    tokens
+        .windows(3)
+        .filter(|w| w[2] == "R" && w[1].chars().all(|c| c.is_ascii_digit()))
+        .filter_map(|w| w[0].parse().ok())
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// ToUnicode CMap
+// ----------------------------------------------------------------------------
+
+/// 폰트의 `/ToUnicode` CMap (문자 코드 → 유니코드)
+struct ToUnicode {
+    /// 코드 공간 범위 (코드 길이는 범위 경계의 바이트 수)
+    codespace: Vec<(Vec<u8>, Vec<u8>)>,
+    chars: HashMap<Vec<u8>, String>,
+    ranges: Vec<BfRange>,
+}
+
+/// `beginbfrange` 항목
+struct BfRange {
+    low: Vec<u8>,
+    high: Vec<u8>,
+    target: BfTarget,
+}
+
+enum BfTarget {
+    /// 시작 값에서 코드 오프셋만큼 마지막 UTF-16 단위를 증가
+    Offset(Vec<u16>),
+    /// 코드별 개별 값
+    Array(Vec<String>),
+}
+
+enum CMapToken {
+    Hex(Vec<u8>),
+    Open,
+    Close,
+    Word(Vec<u8>),
+}
+
+impl ToUnicode {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let tokens = cmap_tokens(data);
+        let mut cmap = Self {
+            codespace: Vec::new(),
+            chars: HashMap::new(),
+            ranges: Vec::new(),
+        };
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let CMapToken::Word(word) = &tokens[i] else {
+                i += 1;
+                continue;
+            };
+            i += 1;
+
+            match word.as_slice() {
+                b"begincodespacerange" => {
+                    while let [CMapToken::Hex(low), CMapToken::Hex(high), ..] = &tokens[i..] {
+                        cmap.codespace.push((low.clone(), high.clone()));
+                        i += 2;
+                    }
+                }
+                b"beginbfchar" => {
+                    while let [CMapToken::Hex(src), CMapToken::Hex(dst), ..] = &tokens[i..] {
+                        cmap.chars.insert(src.clone(), utf16_be(dst));
+                        i += 2;
+                    }
+                }
+                b"beginbfrange" => {
+                    while let [CMapToken::Hex(low), CMapToken::Hex(high), rest @ ..] = &tokens[i..] {
+                        let (target, used) = match rest {
+                            [CMapToken::Hex(dst), ..] => (BfTarget::Offset(utf16_units(dst)), 1),
+                            [CMapToken::Open, ..] => {
+                                let values: Vec<String> = rest[1..]
+                                    .iter()
+                                    .map_while(|t| match t {
+                                        CMapToken::Hex(dst) => Some(utf16_be(dst)),
+                                        _ => None,
+                                    })
+                                    .collect();
+                                let len = values.len() + 1;
+                                let closed = matches!(rest.get(len), Some(CMapToken::Close));
+                                (BfTarget::Array(values), len + usize::from(closed))
+                            }
+                            _ => break,
+                        };
+                        cmap.ranges.push(BfRange {
+                            low: low.clone(),
+                            high: high.clone(),
+                            target,
+                        });
+                        i += 2 + used;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        (!cmap.chars.is_empty() || !cmap.ranges.is_empty()).then_some(cmap)
+    }
+
+    /// 문자열 바이트를 코드 단위로 나눠 유니코드로 변환
+    fn decode(&self, bytes: &[u8]) -> String {
+        let mut out = String::new();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let width = self.code_width(&bytes[i..]);
+            let code = &bytes[i..(i + width).min(bytes.len())];
+            i += width;
+
+            match self.lookup(code) {
+                Some(text) => out.extend(text.chars().filter(|c| !c.is_control())),
+                // 매핑이 없는 1바이트 코드는 Latin-1로
+                None if width == 1 => {
+                    let c = code[0] as char;
+                    if !c.is_control() {
+                        out.push(c);
+                    }
+                }
+                None => {}
+            }
+        }
+        out
+    }
+
+    fn code_width(&self, rest: &[u8]) -> usize {
+        let in_range = |(low, high): &&(Vec<u8>, Vec<u8>)| {
+            rest.len() >= low.len()
+                && low.len() == high.len()
+                && (0..low.len()).all(|k| low[k] <= rest[k] && rest[k] <= high[k])
+        };
+        if let Some((low, _)) = self.codespace.iter().find(in_range) {
+            return low.len();
+        }
+
+        // 코드 공간이 없거나 벗어나면 가장 짧은 코드 길이
+        self.codespace
+            .iter()
+            .map(|(low, _)| low.len())
+            .chain(self.chars.keys().map(Vec::len))
+            .chain(self.ranges.iter().map(|r| r.low.len()))
+            .filter(|len| *len > 0)
+            .min()
+            .unwrap_or(1)
+    }
+
+    fn lookup(&self, code: &[u8]) -> Option<String> {
+        if let Some(text) = self.chars.get(code) {
+            return Some(text.clone());
+        }
+
+        let value = code_value(code);
+        self.ranges.iter().find_map(|range| {
+            if range.low.len() != code.len() {
+                return None;
+            }
+            let offset = value.checked_sub(code_value(&range.low))?;
+            if value > code_value(&range.high) {
+                return None;
+            }
+            match &range.target {
+                BfTarget::Offset(units) => {
+                    let mut units = units.clone();
+                    let last = units.last_mut()?;
+                    *last = last.wrapping_add(offset as u16);
+                    Some(String::from_utf16_lossy(&units))
+                }
+                BfTarget::Array(values) => values.get(offset as usize).cloned(),
+            }
+        })
+    }
+}
+
+fn code_value(code: &[u8]) -> u32 {
+    code.iter().take(4).fold(0, |acc, b| (acc << 8) | u32::from(*b))
+}
+
+fn utf16_units(bytes: &[u8]) -> Vec<u16> {
+    bytes
+        .chunks(2)
+        .map(|p| u16::from_be_bytes([p[0], p.get(1).copied().unwrap_or(0)]))
+        .collect()
+}
+
+fn utf16_be(bytes: &[u8]) -> String {
+    String::from_utf16_lossy(&utf16_units(bytes))
+}
+
+/// CMap 토큰화 (16진 문자열, 배열 괄호, 연산자 단어만 남김)
+fn cmap_tokens(data: &[u8]) -> Vec<CMapToken> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < data.len() {
+        match data[i] {
+            b if b.is_ascii_whitespace() => i += 1,
+            b'%' => {
+                while i < data.len() && data[i] != b'\n' && data[i] != b'\r' {
+                    i += 1;
+                }
+            }
+            b'(' => i = read_literal_string(data, i + 1).1,
+            b'<' | b'>' if data.get(i + 1) == Some(&data[i]) => i += 2,
+            b'<' => {
+                let end = data[i..]
+                    .iter()
+                    .position(|c| *c == b'>')
+                    .map(|p| i + p)
+                    .unwrap_or(data.len());
+                tokens.push(CMapToken::Hex(decode_hex_string(&data[i + 1..end])));
+                i = end + 1;
+            }
+            b'[' => {
+                tokens.push(CMapToken::Open);
+                i += 1;
+            }
+            b']' => {
+                tokens.push(CMapToken::Close);
+                i += 1;
+            }
+            _ => {
+                let start = i;
+                i += 1;
+                while i < data.len() && !is_delimiter(data[i]) {
+                    i += 1;
+                }
+                if data[start] != b'/' {
+                    tokens.push(CMapToken::Word(data[start..i].to_vec()));
+                }
+            }
+        }
+    }
+    tokens
+}
+
+// ----------------------------------------------------------------------------
+// Content stream text
+// ----------------------------------------------------------------------------
+
+enum Operand {
+    Str(Vec<u8>),
+    Num(f64),
+    Name(String),
+    Array(Vec<Operand>),
+    Other,
+}
+
+/// 콘텐츠 스트림의 텍스트 연산자(Tj, TJ, ', ")에서 텍스트 추출
+///
+/// `fonts`에 ToUnicode CMap이 있는 폰트(`Tf`로 선택)는 CMap으로, 나머지는
+/// `decode_pdf_string`으로 디코딩합니다.
+fn extract_content_text(content: &[u8], fonts: &HashMap<String, ToUnicode>) -> String {
+    let mut out = String::new();
+    let mut stack: Vec<Operand> = Vec::new();
+    let mut arrays: Vec<Vec<Operand>> = Vec::new();
+    let mut last_y: Option<f64> = None;
+    let mut font: Option<&ToUnicode> = None;
+    let mut i = 0;
+
+    let decode = |font: Option<&ToUnicode>, s: &[u8]| match font {
+        Some(cmap) => cmap.decode(s),
+        None => decode_pdf_string(s),
+    };
+
+    let push = |stack: &mut Vec<Operand>, arrays: &mut Vec<Vec<Operand>>, op: Operand| {
+        match arrays.last_mut() {
+            Some(array) => array.push(op),
+            None => stack.push(op),
+        }
+    };
+
+    while i < content.len() {
+        let b = content[i];
+        match b {
+            b if b.is_ascii_whitespace() => i += 1,
+            b'%' => {
+                while i < content.len() && content[i] != b'\n' && content[i] != b'\r' {
+                    i += 1;
+                }
+            }
+            b'(' => {
+                let (s, next) = read_literal_string(content, i + 1);
+                push(&mut stack, &mut arrays, Operand::Str(s));
+                i = next;
+            }
+            b'<' if content.get(i + 1) == Some(&b'<') => {
+                push(&mut stack, &mut arrays, Operand::Other);
+                i += 2;
+            }
+            b'>' => i += 1,
+            b'<' => {
+                let end = content[i..]
+                    .iter()
+                    .position(|c| *c == b'>')
+                    .map(|p| i + p)
+                    .unwrap_or(content.len());
+                let s = decode_hex_string(&content[i + 1..end]);
+                push(&mut stack, &mut arrays, Operand::Str(s));
+                i = end + 1;
+            }
+            b'[' => {
+                arrays.push(Vec::new());
+                i += 1;
+            }
+            b']' => {
+                if let Some(array) = arrays.pop() {
+                    push(&mut stack, &mut arrays, Operand::Array(array));
+                }
+                i += 1;
+            }
+            b'/' => {
+                let start = i + 1;
+                i += 1;
+                while i < content.len() && !is_delimiter(content[i]) {
+                    i += 1;
+                }
+                let name = String::from_utf8_lossy(&content[start..i]).into_owned();
+                push(&mut stack, &mut arrays, Operand::Name(name));
+            }
+            b'+' | b'-' | b'.' | b'0'..=b'9' => {
+                let start = i;
+                i += 1;
+                while i < content.len() && matches!(content[i], b'.' | b'0'..=b'9') {
+                    i += 1;
+                }
+                let num = std::str::from_utf8(&content[start..i])
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0.0);
+                push(&mut stack, &mut arrays, Operand::Num(num));
+            }
+            _ => {
+                let start = i;
+                while i < content.len() && !is_delimiter(content[i]) {
+                    i += 1;
+                }
+                if i == start {
+                    i += 1;
+                    continue;
+                }
+                let op = &content[start..i];
+
+                match op {
+                    b"BT" => ensure_separator(&mut out, ' '),
+                    b"Tf" => {
+                        if let [.., Operand::Name(name), Operand::Num(_)] = stack.as_slice() {
+                            font = fonts.get(name);
+                        }
+                    }
+                    b"Tj" => {
+                        if let Some(Operand::Str(s)) = stack.last() {
+                            out.push_str(&decode(font, s));
+                        }
+                    }
+                    b"'" | b"\"" => {
+                        ensure_separator(&mut out, '\n');
+                        if let Some(Operand::Str(s)) = stack.last() {
+                            out.push_str(&decode(font, s));
+                        }
+                    }
+                    b"TJ" => {
+                        if let Some(Operand::Array(items)) = stack.last() {
+                            for item in items {
+                                match item {
+                                    Operand::Str(s) => out.push_str(&decode(font, s)),
+                                    // 단어 간격(보통 폰트 크기의 1/4 이상)만 공백으로, 커닝은 무시
+                                    Operand::Num(n) if *n <= -150.0 => ensure_separator(&mut out, ' '),
+                                    _ => {}
+                                }
+                            }
+                        }
+                    }
+                    b"Td" | b"TD" => {
+                        let ty = match stack.as_slice() {
+                            [.., Operand::Num(_), Operand::Num(ty)] => *ty,
+                            _ => 0.0,
+                        };
+                        ensure_separator(&mut out, if ty.abs() > 0.01 { '\n' } else { ' ' });
+                    }
+                    b"Tm" => {
+                        let y = match stack.as_slice() {
+                            [.., Operand::Num(y)] => Some(*y),
+                            _ => None,
+                        };
+                        let new_line = matches!((last_y, y), (Some(a), Some(b)) if (a - b).abs() > 0.01);
+                        ensure_separator(&mut out, if new_line { '\n' } else { ' ' });
+                        last_y = y;
+                    }
+                    b"T*" => ensure_separator(&mut out, '\n'),
+                    b"BI" => {
+                        // 인라인 이미지 데이터 건너뛰기
+                        i = find(content, b"EI", i).map(|p| p + 2).unwrap_or(content.len());
+                    }
+                    _ => {}
+                }
+                stack.clear();
+                arrays.clear();
+            }
+        }
+    }
+
+    normalize_whitespace(&out)
+}
+
+fn is_delimiter(b: u8) -> bool {
+    b.is_ascii_whitespace() || b"()<>[]{}/%".contains(&b)
+}
+
+fn ensure_separator(out: &mut String, sep: char) {
+    match out.chars().last() {
+        None => {}
+        Some('\n') => {}
+        Some(' ') if sep == '\n' => {
+            out.pop();
+            out.push('\n');
+        }
+        Some(' ') => {}
+        Some(_) => out.push(sep),
+    }
+}
+
+fn read_literal_string(content: &[u8], mut i: usize) -> (Vec<u8>, usize) {
+    let mut out = Vec::new();
+    let mut depth = 1;
+
+    while i < content.len() {
+        let b = content[i];
+        i += 1;
+        match b {
+            b'\\' => {
+                let Some(&next) = content.get(i) else {
+                    break;
+                };
+                i += 1;
+                match next {
+                    b'n' => out.push(b'\n'),
+                    b'r' => out.push(b'\r'),
+                    b't' => out.push(b'\t'),
+                    b'b' => out.push(0x08),
+                    b'f' => out.push(0x0c),
+                    b'\r' => {
+                        if content.get(i) == Some(&b'\n') {
+                            i += 1;
+                        }
+                    }
+                    b'\n' => {}
+                    b'0'..=b'7' => {
+                        let mut value = (next - b'0') as u32;
+                        for _ in 0..2 {
+                            match content.get(i) {
+                                Some(d @ b'0'..=b'7') => {
+                                    value = value * 8 + (d - b'0') as u32;
+                                    i += 1;
+                                }
+                                _ => break,
+                            }
+                        }
+                        out.push(value as u8);
+                    }
+                    other => out.push(other),
+                }
+            }
+            b'(' => {
+                depth += 1;
+                out.push(b);
+            }
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+                out.push(b);
+            }
+            _ => out.push(b),
+        }
+    }
+    (out, i)
+}
+
+fn decode_hex_string(hex: &[u8]) -> Vec<u8> {
+    let digits: Vec<u8> = hex
+        .iter()
+        .filter_map(|c| (*c as char).to_digit(16).map(|d| d as u8))
+        .collect();
+    digits
+        .chunks(2)
+        .map(|pair| (pair[0] << 4) | pair.get(1).copied().unwrap_or(0))
+        .collect()
+}
+
+/// PDF 문자열 디코딩 (UTF-16BE BOM 또는 Latin-1)
+fn decode_pdf_string(bytes: &[u8]) -> String {
+    if let Some(utf16) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = utf16
+            .chunks_exact(2)
+            .map(|p| u16::from_be_bytes([p[0], p[1]]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+
+    bytes
+        .iter()
+        .map(|b| *b as char)
+        .filter(|c| !c.is_control() || *c == '\n' || *c == '\t')
+        .collect()
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank_lines = 0;
+
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out.trim().to_string()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::{DeflateEncoder, ZlibEncoder};
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// 두 페이지짜리 PDF (두 번째 페이지는 FlateDecode)
+    fn sample_pdf() -> Vec<u8> {
+        let page1 = b"BT /F1 12 Tf 72 720 Td (Hello PDF) Tj 0 -14 Td [(Sec) -10 (ond) -300 (line)] TJ ET";
+        let page2 = zlib(b"BT /F1 12 Tf 72 720 Td (Page \\(two\\)) Tj ET");
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        pdf.extend_from_slice(b"1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n");
+        pdf.extend_from_slice(b"2 0 obj\n<< /Type /Pages /Kids [3 0 R 5 0 R] /Count 2 >>\nendobj\n");
+        pdf.extend_from_slice(
+            b"3 0 obj\n<< /Type /Page /Parent 2 0 R /Resources << /Font << /F1 7 0 R >> >> /Contents 4 0 R >>\nendobj\n",
+        );
+        pdf.extend_from_slice(format!("4 0 obj\n<< /Length {} >>\nstream\n", page1.len()).as_bytes());
+        pdf.extend_from_slice(page1);
+        pdf.extend_from_slice(b"\nendstream\nendobj\n");
+        pdf.extend_from_slice(b"5 0 obj\n<< /Type /Page /Parent 2 0 R /Contents [6 0 R] >>\nendobj\n");
+        pdf.extend_from_slice(
+            format!("6 0 obj\n<< /Length {} /Filter /FlateDecode >>\nstream\n", page2.len()).as_bytes(),
+        );
+        pdf.extend_from_slice(&page2);
+        pdf.extend_from_slice(b"\nendstream\nendobj\n");
+        pdf.extend_from_slice(b"7 0 obj\n<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>\nendobj\n");
+        pdf.extend_from_slice(b"trailer\n<< /Root 1 0 R >>\n%%EOF\n");
+        pdf
+    }
+
+    /// pdfTeX 1.40.22가 ptmr8r(Times, TeXBase1 인코딩) 폰트에 출력한 ToUnicode CMap 원문
+    const PDFTEX_PTMR8R_CMAP: &str = r"%!PS-Adobe-3.0 Resource-CMap
+%%DocumentNeededResources: ProcSet (CIDInit)
+%%IncludeResource: ProcSet (CIDInit)
+%%BeginResource: CMap (TeX-ptmr8r-8r-0)
+%%Title: (TeX-ptmr8r-8r-0 TeX ptmr8r-8r 0)
+%%Version: 1.000
+%%EndComments
+/CIDInit /ProcSet findresource begin
+12 dict begin
+begincmap
+/CIDSystemInfo
+<< /Registry (TeX)
+/Ordering (ptmr8r-8r)
+/Supplement 0
+>> def
+/CMapName /TeX-ptmr8r-8r-0 def
+/CMapType 2 def
+1 begincodespacerange
+<00> <FF>
+endcodespacerange
+11 beginbfrange
+<06> <07> <0141>
+<0E> <0F> <017D>
+<18> <19> <2264>
+<20> <26> <0020>
+<28> <5F> <0028>
+<61> <7E> <0061>
+<86> <87> <2020>
+<93> <94> <201C>
+<96> <97> <2013>
+<A1> <AC> <00A1>
+<AE> <FF> <00AE>
+endbfrange
+49 beginbfchar
+<01> <02D9>
+<02> <00660069>
+<03> <0066006C>
+<04> <2044>
+<05> <02DD>
+<08> <02DB>
+<09> <02DA>
+<0B> <02D8>
+<0C> <2212>
+<10> <02C7>
+<11> <0131>
+<12> <0237>
+<13> <00660066>
+<14> <006600660069>
+<15> <00660066006C>
+<16> <2260>
+<17> <221E>
+<1A> <2202>
+<1B> <2211>
+<1C> <220F>
+<1D> <03C0>
+<1E> <0060>
+<1F> <0027>
+<27> <2019>
+<60> <2018>
+<80> <20AC>
+<81> <222B>
+<82> <201A>
+<83> <0192>
+<84> <201E>
+<85> <2026>
+<88> <02C6>
+<89> <2030>
+<8A> <0160>
+<8B> <2039>
+<8C> <0152>
+<8D> <2126>
+<8E> <221A>
+<8F> <2248>
+<95> <2022>
+<98> <02DC>
+<99> <2122>
+<9A> <0161>
+<9B> <203A>
+<9C> <0153>
+<9D> <2206>
+<9E> <25CA>
+<9F> <0178>
+<AD> <002D>
+endbfchar
+endcmap
+CMapName currentdict /CMap defineresource pop
+end
+end
+%%EndResource
+%%EOF";
+
+    /// pdfTeX 출력 구조를 따른 PDF: 페이지 Resources와 폰트가 객체 스트림 안에 있고,
+    /// 합자(fi)와 따옴표는 8r 인코딩 코드로 찍힘
+    fn pdftex_style_pdf() -> Vec<u8> {
+        let content = zlib(
+            b"BT\n/F47 9.9626 Tf 72 700 Td [(The)-250(speci\\002cation)-250(de\\002nes)-250(\\223ligatures\\224)]TJ\n/F50 9.9626 Tf 0 -12 Td [(plain)-250(\\223text\\224)]TJ\nET",
+        );
+        let cmap = zlib(PDFTEX_PTMR8R_CMAP.as_bytes());
+        let objects = [
+            (7, "<< /Font << /F47 9 0 R /F50 10 0 R >> /ProcSet [ /PDF /Text ] >>"),
+            (9, "<< /Type /Font /Subtype /Type1 /BaseFont /CJPHTH+NimbusRomNo9L-Regu /FirstChar 2 /LastChar 148 /Encoding 12 0 R /ToUnicode 11 0 R >>"),
+            (10, "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>"),
+        ];
+        let mut header = String::new();
+        let mut body = String::new();
+        for (num, object) in objects {
+            header.push_str(&format!("{} {} ", num, body.len()));
+            body.push_str(object);
+            body.push('\n');
+        }
+        let objstm = zlib(format!("{}{}", header, body).as_bytes());
+
+        let mut pdf = b"%PDF-1.5\n%\xd0\xd4\xc5\xd8\n".to_vec();
+        let stream = |pdf: &mut Vec<u8>, num: u32, dict: &str, data: &[u8]| {
+            pdf.extend_from_slice(
+                format!("{} 0 obj\n<<\n/Length {} \n/Filter /FlateDecode\n{}>>\nstream\n", num, data.len(), dict)
+                    .as_bytes(),
+            );
+            pdf.extend_from_slice(data);
+            pdf.extend_from_slice(b"\nendstream\nendobj\n");
+        };
+        stream(&mut pdf, 4, "", &content);
+        pdf.extend_from_slice(b"3 0 obj\n<<\n/Type /Page\n/Contents 4 0 R\n/Resources 7 0 R\n/MediaBox [0 0 612 792]\n/Parent 8 0 R\n>>\nendobj\n");
+        stream(&mut pdf, 11, "", &cmap);
+        stream(
+            &mut pdf,
+            13,
+            &format!("/Type /ObjStm\n/N 3\n/First {}\n", header.len()),
+            &objstm,
+        );
+        pdf.extend_from_slice(b"8 0 obj\n<<\n/Type /Pages\n/Count 1\n/Kids [3 0 R]\n>>\nendobj\n");
+        pdf.extend_from_slice(b"1 0 obj\n<<\n/Type /Catalog\n/Pages 8 0 R\n>>\nendobj\n");
+        pdf.extend_from_slice(b"%%EOF\n");
+        pdf
+    }
+
+    /// stored/deflate 엔트리를 가진 최소 ZIP 작성
+    fn sample_zip(entries: &[(&str, &[u8], bool)]) -> Vec<u8> {
+        let mut zip = Vec::new();
+        let mut central = Vec::new();
+
+        for (name, content, deflate) in entries {
+            let data = if *deflate {
+                let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(content).unwrap();
+                encoder.finish().unwrap()
+            } else {
+                content.to_vec()
+            };
+            let method: u16 = if *deflate { 8 } else { 0 };
+            let offset = zip.len() as u32;
+
+            zip.extend_from_slice(&[0x50, 0x4b, 0x03, 0x04, 20, 0, 0, 0]);
+            zip.extend_from_slice(&method.to_le_bytes());
+            zip.extend_from_slice(&[0; 4]); // time, date
+            zip.extend_from_slice(&crc32fast::hash(content).to_le_bytes());
+            zip.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            zip.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            zip.extend_from_slice(&[0, 0]);
+            zip.extend_from_slice(name.as_bytes());
+            zip.extend_from_slice(&data);
+
+            central.extend_from_slice(&[0x50, 0x4b, 0x01, 0x02, 20, 0, 20, 0, 0, 0]);
+            central.extend_from_slice(&method.to_le_bytes());
+            central.extend_from_slice(&[0; 4]);
+            central.extend_from_slice(&crc32fast::hash(content).to_le_bytes());
+            central.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            central.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            central.extend_from_slice(&[0; 12]);
+            central.extend_from_slice(&offset.to_le_bytes());
+            central.extend_from_slice(name.as_bytes());
+        }
+
+        let central_offset = zip.len() as u32;
+        zip.extend_from_slice(&central);
+        zip.extend_from_slice(&[0x50, 0x4b, 0x05, 0x06, 0, 0, 0, 0]);
+        zip.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&(central.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&central_offset.to_le_bytes());
+        zip.extend_from_slice(&[0, 0]);
+        zip
+    }
+
+    #[test]
+    fn test_document_kind() {
+        assert_eq!(DocumentKind::from_path(Path::new("a.PDF")), Some(DocumentKind::Pdf));
+        assert_eq!(DocumentKind::from_path(Path::new("a.docx")), Some(DocumentKind::Docx));
+        assert_eq!(
+            DocumentKind::from_path(Path::new("a.jpg")),
+            Some(DocumentKind::Image("image/jpeg"))
+        );
+        assert_eq!(DocumentKind::from_path(Path::new("a.rs")), None);
+    }
+
+    #[test]
+    fn test_page_range() {
+        let range = PageRange::parse("2, 4-5, 9-").unwrap();
+        assert_eq!(range.select(10), vec![2, 4, 5, 9, 10]);
+        assert_eq!(PageRange::parse("3").unwrap().select(2), Vec::<usize>::new());
+
+        assert!(PageRange::parse("").is_err());
+        assert!(PageRange::parse("0").is_err());
+        assert!(PageRange::parse("5-2").is_err());
+        assert!(PageRange::parse("a-b").is_err());
+    }
+
+    #[test]
+    fn test_extract_pdf_pages() {
+        let pages = extract_pdf_pages(&sample_pdf()).unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0], "Hello PDF\nSecond line");
+        assert_eq!(pages[1], "Page (two)");
+
+        assert!(extract_pdf_pages(b"not a pdf").is_err());
+    }
+
+    #[test]
+    fn test_extract_pdf_to_unicode() {
+        let pages = extract_pdf_pages(&pdftex_style_pdf()).unwrap();
+        assert_eq!(pages, vec!["The specification defines \u{201c}ligatures\u{201d}\nplain text"]);
+    }
+
+    #[test]
+    fn test_to_unicode_cmap() {
+        let cmap = ToUnicode::parse(PDFTEX_PTMR8R_CMAP.as_bytes()).unwrap();
+        assert_eq!(cmap.decode(b"\x02nd \x13\x85 \x80"), "find ff\u{2026} \u{20ac}");
+
+        // Identity-H CID 폰트: 2바이트 코드, 배열형 bfrange
+        let cid = ToUnicode::parse(
+            b"begincmap 1 begincodespacerange <0000> <FFFF> endcodespacerange\n\
+              2 beginbfchar <0003> <0020> <01F4> <D83DDE00> endbfchar\n\
+              2 beginbfrange <0024> <0026> <0041> <0050> <0051> [<00E9> <00660069>] endbfrange endcmap",
+        )
+        .unwrap();
+        assert_eq!(
+            cid.decode(&[0x00, 0x24, 0x00, 0x26, 0x00, 0x03, 0x00, 0x50, 0x00, 0x51, 0x01, 0xF4, 0x09, 0x99]),
+            "AC \u{e9}fi\u{1f600}"
+        );
+
+        assert!(ToUnicode::parse(b"begincmap endcmap").is_none());
+    }
+
+    /// 실행: FORGE_TEST_PDF=/path/to/file.pdf cargo test -p forge-core document -- --ignored --nocapture
+    #[test]
+    #[ignore] // 실제 PDF 파일 필요 - 수동 실행
+    fn test_extract_real_pdf() {
+        let Ok(path) = std::env::var("FORGE_TEST_PDF") else {
+            println!("⚠ FORGE_TEST_PDF not set, skipping");
+            return;
+        };
+        let data = std::fs::read(&path).expect("Failed to read PDF");
+        let pages = extract_pdf_pages(&data).unwrap();
+
+        assert!(!pages.is_empty());
+        assert!(pages.iter().any(|p| !p.is_empty()));
+        for page in &pages {
+            assert!(!page.chars().any(|c| c.is_control() && c != '\n' && c != '\t'));
+        }
+        println!("{} pages\n{}", pages.len(), pages[0]);
+    }
+
+    #[test]
+    fn test_decode_stream_cap() {
+        let dict = "<< /Filter /FlateDecode >>";
+        assert_eq!(decode_stream(dict, &zlib(b"BT ET")).unwrap(), b"BT ET");
+
+        let bomb = zlib(&vec![0u8; MAX_DECODED_STREAM as usize + 1]);
+        assert!(decode_stream(dict, &bomb).is_none());
+    }
+
+    #[test]
+    fn test_extract_docx_text() {
+        let xml = br#"<?xml version="1.0"?><w:document><w:body><w:p><w:r><w:t>Hello</w:t></w:r><w:r><w:t xml:space="preserve"> &amp; welcome</w:t></w:r></w:p><w:p><w:r><w:t>Second</w:t><w:tab/><w:t>para</w:t></w:r></w:p></w:body></w:document>"#;
+
+        for deflate in [false, true] {
+            let zip = sample_zip(&[
+                ("[Content_Types].xml", b"<Types/>", false),
+                ("word/document.xml", xml, deflate),
+            ]);
+            assert_eq!(extract_docx_text(&zip).unwrap(), "Hello & welcome\nSecond\tpara");
+        }
+
+        let not_docx = sample_zip(&[("other.txt", b"x", false)]);
+        assert!(extract_docx_text(&not_docx).is_err());
+
+        let bomb = vec![b' '; MAX_DECODED_STREAM as usize + 1];
+        let zip = sample_zip(&[("word/document.xml", &bomb, true)]);
+        assert!(extract_docx_text(&zip).is_err());
+    }
+
+    #[test]
+    fn test_load_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pixel.png");
+        std::fs::write(&path, b"\x89PNG\r\n\x1a\nrest").unwrap();
+
+        let image = load_image(&path).unwrap();
+        assert_eq!(image.media_type, "image/png");
+        assert_eq!(
+            base64::engine::general_purpose::STANDARD.decode(&image.data).unwrap(),
+            b"\x89PNG\r\n\x1a\nrest"
+        );
+        assert!(image.source.unwrap().ends_with("pixel.png"));
+
+        let bogus = dir.path().join("bogus.png");
+        std::fs::write(&bogus, b"nope").unwrap();
+        assert!(load_image(&bogus).is_err());
+    }
+
+    #[test]
+    fn test_chunk_by_tokens() {
+        let text: String = (0..200).map(|i| format!("line number {}\n", i)).collect();
+        let chunks = chunk_by_tokens(&text, 100);
+
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), text);
+
+        let tokenizer = TiktokenEstimator::cl100k();
+        assert!(chunks.iter().all(|c| tokenizer.count(c).total <= 100));
+
+        assert_eq!(chunk_by_tokens("", 100), vec![String::new()]);
+    }
+}
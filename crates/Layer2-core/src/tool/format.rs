@@ -0,0 +1,241 @@
+//! Auto Formatter - Write/Edit 후 자동 포맷
+//!
+//! 프로젝트 설정(`format`)이 켜져 있으면 도구가 쓴 파일을 바로 포맷해
+//! 에이전트 출력이 추가 턴 없이 프로젝트 스타일과 맞도록 합니다.
+//!
+//! 1. 확장자별 외부 포맷터 (`rustfmt`, `prettier`, `black` 등, stdin → stdout)
+//! 2. 없으면 실행 중인 언어 서버의 `textDocument/formatting`
+//!
+//! 포맷 실패는 파일 쓰기를 되돌리지 않고 결과 메시지에만 표시합니다.
+
+use crate::config::{ConfigLoader, FormatConfig, FormatterCommand};
+use crate::lsp::LspManager;
+use async_trait::async_trait;
+use forge_foundation::{DocumentFormatter, Error, Result, ToolContext};
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::debug;
+
+/// 외부 포맷터 실행 제한 시간
+const FORMATTER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 설정 기반 포맷터 (외부 명령 → 언어 서버)
+pub struct AutoFormatter {
+    config: FormatConfig,
+    lsp: Option<Arc<LspManager>>,
+}
+
+impl AutoFormatter {
+    pub fn new(config: FormatConfig) -> Self {
+        Self { config, lsp: None }
+    }
+
+    /// 언어 서버 포맷 사용 (실행 중인 서버만)
+    pub fn with_lsp(mut self, lsp: Option<Arc<LspManager>>) -> Self {
+        self.lsp = lsp;
+        self
+    }
+
+    /// 프로젝트 설정에서 생성 (`format.enabled`가 꺼져 있으면 None)
+    pub fn from_project(working_dir: &Path) -> Option<Self> {
+        let config = ConfigLoader::new(working_dir).load_all().ok()?.format;
+        config.enabled.then(|| Self::new(config))
+    }
+
+    /// 포맷 설정
+    pub fn config(&self) -> &FormatConfig {
+        &self.config
+    }
+
+    /// 외부 포맷터 실행 (stdin으로 내용 전달, stdout이 결과)
+    async fn run_external(
+        &self,
+        formatter: &FormatterCommand,
+        path: &Path,
+        content: &str,
+    ) -> Result<String> {
+        let mut command = Command::new(&formatter.command);
+        command
+            .args(formatter.resolved_args(path))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        // 포맷터가 rustfmt.toml, .prettierrc 등을 찾도록 파일 위치에서 실행
+        if let Some(parent) = path.parent().filter(|p| p.is_dir()) {
+            command.current_dir(parent);
+        }
+
+        let mut child = command.spawn().map_err(|e| {
+            Error::Tool(format!(
+                "Formatter '{}' could not be started: {}",
+                formatter.command, e
+            ))
+        })?;
+        if let Some(mut stdin) = child.stdin.take() {
+            let input = content.to_string();
+            // 출력과 동시에 쓰도록 분리 (입력을 다 읽기 전에 끝나면 종료 코드/출력으로 판단)
+            tokio::spawn(async move {
+                let _ = stdin.write_all(input.as_bytes()).await;
+            });
+        }
+
+        let output = tokio::time::timeout(FORMATTER_TIMEOUT, child.wait_with_output())
+            .await
+            .map_err(|_| {
+                Error::Timeout(format!(
+                    "Formatter '{}' timed out after {}s",
+                    formatter.command,
+                    FORMATTER_TIMEOUT.as_secs()
+                ))
+            })??;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr
+                .lines()
+                .find(|l| !l.trim().is_empty())
+                .unwrap_or("no output");
+            return Err(Error::Tool(format!(
+                "Formatter '{}' failed: {}",
+                formatter.command, reason
+            )));
+        }
+
+        let formatted = String::from_utf8(output.stdout).map_err(|_| {
+            Error::Tool(format!(
+                "Formatter '{}' produced invalid UTF-8",
+                formatter.command
+            ))
+        })?;
+        // 빈 출력으로 파일을 지우지 않음
+        if formatted.is_empty() && !content.trim().is_empty() {
+            return Err(Error::Tool(format!(
+                "Formatter '{}' produced no output",
+                formatter.command
+            )));
+        }
+        Ok(formatted)
+    }
+}
+
+#[async_trait]
+impl DocumentFormatter for AutoFormatter {
+    async fn format(&self, path: &Path, content: &str) -> Result<Option<String>> {
+        if !self.config.enabled {
+            return Ok(None);
+        }
+        if let Some(formatter) = self.config.formatter_for(path) {
+            return self.run_external(formatter, path, content).await.map(Some);
+        }
+        match &self.lsp {
+            Some(lsp) if self.config.lsp => {
+                lsp.format_document(
+                    path,
+                    content,
+                    self.config.tab_size,
+                    self.config.insert_spaces,
+                )
+                .await
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// 쓴 파일에 포맷터 적용 (결과가 다르면 다시 씀)
+///
+/// 최종 내용과, 결과 메시지에 덧붙일 한 줄(포맷했거나 실패한 경우)을 반환합니다.
+pub(crate) async fn format_written_file(
+    context: &dyn ToolContext,
+    path: &Path,
+    content: &str,
+) -> (String, Option<String>) {
+    let Some(formatter) = context.formatter() else {
+        return (content.to_string(), None);
+    };
+
+    match formatter.format(path, content).await {
+        Ok(Some(formatted)) if formatted != content => {
+            match tokio::fs::write(path, &formatted).await {
+                Ok(()) => (formatted, Some("Auto-formatted".to_string())),
+                Err(e) => (
+                    content.to_string(),
+                    Some(format!("Auto-format not applied: {}", e)),
+                ),
+            }
+        }
+        Ok(_) => (content.to_string(), None),
+        Err(e) => {
+            debug!("Formatting {} failed: {}", path.display(), e);
+            (
+                content.to_string(),
+                Some(format!("Auto-format skipped: {}", e)),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn formatter(command: &str, args: &[&str]) -> AutoFormatter {
+        AutoFormatter::new(FormatConfig {
+            enabled: true,
+            formatters: HashMap::from([(
+                "txt".to_string(),
+                FormatterCommand {
+                    command: command.to_string(),
+                    args: Some(args.iter().map(|s| s.to_string()).collect()),
+                },
+            )]),
+            ..Default::default()
+        })
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_external_formatter() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        let formatter = formatter("tr", &["a-z", "A-Z"]);
+
+        let formatted = formatter.format(&path, "hello\n").await.unwrap();
+        assert_eq!(formatted.as_deref(), Some("HELLO\n"));
+
+        // 설정에 없는 확장자, LSP 없음
+        let other = formatter
+            .format(&dir.path().join("main.rs"), "fn main() {}")
+            .await
+            .unwrap();
+        assert!(other.is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_formatter_failures() {
+        let path = Path::new("notes.txt");
+
+        let missing = formatter("forge-no-such-formatter", &[]);
+        let err = missing.format(path, "x").await.unwrap_err();
+        assert!(err.to_string().contains("could not be started"));
+
+        let failing = formatter("sh", &["-c", "echo 'syntax error' >&2; exit 1"]);
+        let err = failing.format(path, "x").await.unwrap_err();
+        assert!(err.to_string().contains("failed: syntax error"));
+
+        let silent = formatter("true", &[]);
+        let err = silent.format(path, "x").await.unwrap_err();
+        assert!(err.to_string().contains("produced no output"));
+
+        let mut disabled = formatter("tr", &["a-z", "A-Z"]);
+        disabled.config.enabled = false;
+        assert!(disabled.format(path, "x").await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,267 @@
+//! Output Governor - 도구 출력 크기 제한
+//!
+//! 거대한 도구 결과(빌드 로그, 큰 grep 결과 등)가 컨텍스트를 채우지 않도록
+//! 모든 도구 실행 결과에 공통으로 적용되는 출력 제한기입니다.
+//!
+//! ## 잘라내기 방식
+//!
+//! 1. 앞부분(head)과 뒷부분(tail)은 그대로 유지
+//! 2. 생략된 중간 부분에서 error/warning/panic 등이 포함된 줄만 추출 (grep 방식)
+//! 3. 생략 지점에 마커를 넣고, 전체 출력은 저장소에 보관
+//!
+//! ```text
+//! <head>
+//! ... [1520 lines omitted (184302 chars total). Full output: fetch_full_output(id="out-3")] ...
+//! [relevant lines from omitted output]
+//! L812: error[E0308]: mismatched types
+//! <tail>
+//! ```
+//!
+//! 모델은 `fetch_full_output` 도구로 전체 출력을 페이지 단위로 다시 읽을 수 있습니다.
+
+use regex::Regex;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+/// 제한을 적용하지 않는 도구 (전체 출력 조회 자체)
+pub const FETCH_FULL_OUTPUT_TOOL: &str = "fetch_full_output";
+
+/// 보관하는 전체 출력 개수 (오래된 것부터 제거)
+const MAX_STORED_OUTPUTS: usize = 32;
+
+/// 추출한 관련 줄의 최대 길이
+const MAX_RELEVANT_LINE_CHARS: usize = 200;
+
+lazy_static::lazy_static! {
+    static ref FULL_OUTPUTS: RwLock<VecDeque<(String, String)>> = RwLock::new(VecDeque::new());
+    static ref RELEVANT_LINE: Regex = Regex::new(
+        r"(?i)\b(error|errors|failed|failure|fail|panic|panicked|fatal|exception|warning|traceback)\b"
+    )
+    .unwrap();
+}
+
+static NEXT_OUTPUT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// 전체 출력 저장 후 ID 반환
+fn store_full_output(output: &str) -> String {
+    let id = format!("out-{}", NEXT_OUTPUT_ID.fetch_add(1, Ordering::Relaxed));
+    let mut outputs = FULL_OUTPUTS.write().unwrap_or_else(|e| e.into_inner());
+    if outputs.len() >= MAX_STORED_OUTPUTS {
+        outputs.pop_front();
+    }
+    outputs.push_back((id.clone(), output.to_string()));
+    id
+}
+
+/// 저장된 전체 출력 조회
+pub fn full_output(id: &str) -> Option<String> {
+    let outputs = FULL_OUTPUTS.read().unwrap_or_else(|e| e.into_inner());
+    outputs
+        .iter()
+        .find(|(stored, _)| stored == id)
+        .map(|(_, output)| output.clone())
+}
+
+// ============================================================================
+// OutputGovernor
+// ============================================================================
+
+/// 도구 출력 크기 제한기
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputGovernor {
+    /// 이 길이(문자 수)를 넘으면 잘라냄
+    pub max_chars: usize,
+    /// 유지할 앞부분 길이
+    pub head_chars: usize,
+    /// 유지할 뒷부분 길이
+    pub tail_chars: usize,
+    /// 생략된 부분에서 추출할 관련 줄 수
+    pub max_relevant_lines: usize,
+}
+
+impl Default for OutputGovernor {
+    fn default() -> Self {
+        Self {
+            max_chars: 30_000,
+            head_chars: 8_000,
+            tail_chars: 12_000,
+            max_relevant_lines: 40,
+        }
+    }
+}
+
+impl OutputGovernor {
+    /// 최대 길이 지정 (head/tail은 비율로 조정)
+    pub fn new(max_chars: usize) -> Self {
+        Self {
+            max_chars,
+            head_chars: max_chars * 4 / 15,
+            tail_chars: max_chars * 2 / 5,
+            ..Self::default()
+        }
+    }
+
+    /// 제한 없음
+    pub fn unlimited() -> Self {
+        Self {
+            max_chars: usize::MAX,
+            ..Self::default()
+        }
+    }
+
+    /// 도구 출력에 제한 적용 (제한 이내면 그대로 반환)
+    pub fn apply(&self, tool_name: &str, output: String) -> String {
+        if output.len() <= self.max_chars || tool_name == FETCH_FULL_OUTPUT_TOOL {
+            return output;
+        }
+
+        let head_end = head_end(&output, self.head_chars);
+        let tail_start = tail_start(&output, self.tail_chars).max(head_end);
+        let omitted = &output[head_end..tail_start];
+        let first_omitted_line = output[..head_end].matches('\n').count() + 1;
+
+        let id = store_full_output(&output);
+
+        let mut result = String::with_capacity(self.max_chars);
+        result.push_str(&output[..head_end]);
+        if !result.ends_with('\n') {
+            result.push('\n');
+        }
+        result.push_str(&format!(
+            "... [{} lines omitted ({} chars total). Full output: {}(id=\"{}\")] ...\n",
+            omitted.lines().count(),
+            output.len(),
+            FETCH_FULL_OUTPUT_TOOL,
+            id
+        ));
+
+        let relevant: Vec<_> = omitted
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| RELEVANT_LINE.is_match(line))
+            .take(self.max_relevant_lines)
+            .collect();
+        if !relevant.is_empty() {
+            result.push_str("[relevant lines from omitted output]\n");
+            for (index, line) in relevant {
+                result.push_str(&format!(
+                    "L{}: {}\n",
+                    first_omitted_line + index,
+                    truncate_line(line)
+                ));
+            }
+            result.push_str("[end of relevant lines]\n");
+        }
+
+        result.push_str(&output[tail_start..]);
+        result
+    }
+}
+
+/// 앞부분 끝 위치 (가능하면 줄 경계)
+fn head_end(s: &str, max: usize) -> usize {
+    let mut end = max.min(s.len());
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    match s[..end].rfind('\n') {
+        Some(pos) if pos >= end / 2 => pos + 1,
+        _ => end,
+    }
+}
+
+/// 뒷부분 시작 위치 (가능하면 줄 경계)
+fn tail_start(s: &str, max: usize) -> usize {
+    let mut start = s.len().saturating_sub(max);
+    while !s.is_char_boundary(start) {
+        start += 1;
+    }
+    match s[start..].find('\n') {
+        Some(pos) if pos < max / 2 => start + pos + 1,
+        _ => start,
+    }
+}
+
+/// 관련 줄 길이 제한
+fn truncate_line(line: &str) -> String {
+    if line.chars().count() <= MAX_RELEVANT_LINE_CHARS {
+        return line.to_string();
+    }
+    let truncated: String = line.chars().take(MAX_RELEVANT_LINE_CHARS).collect();
+    format!("{}...", truncated)
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered_lines(count: usize) -> String {
+        (1..=count).map(|i| format!("line {}\n", i)).collect()
+    }
+
+    fn marker_id(result: &str) -> String {
+        let start = result.find("(id=\"").unwrap() + 5;
+        let end = start + result[start..].find('"').unwrap();
+        result[start..end].to_string()
+    }
+
+    #[test]
+    fn test_small_output_untouched() {
+        let governor = OutputGovernor::default();
+        let output = numbered_lines(10);
+        assert_eq!(governor.apply("bash", output.clone()), output);
+    }
+
+    #[test]
+    fn test_head_tail_truncation() {
+        let governor = OutputGovernor::new(1500);
+        let output = numbered_lines(1000);
+        let result = governor.apply("bash", output.clone());
+
+        assert!(result.len() < output.len());
+        assert!(result.starts_with("line 1\n"));
+        assert!(result.ends_with("line 1000\n"));
+        assert!(result.contains("lines omitted"));
+        assert!(!result.contains("[relevant lines"));
+
+        // 전체 출력은 저장소에서 다시 조회 가능
+        assert_eq!(full_output(&marker_id(&result)), Some(output));
+    }
+
+    #[test]
+    fn test_relevant_lines_extracted() {
+        let governor = OutputGovernor::new(1500);
+        let mut output = numbered_lines(500);
+        output.push_str("error[E0308]: mismatched types\n");
+        output.push_str(&numbered_lines(500));
+        let result = governor.apply("bash", output);
+
+        assert!(result.contains("[relevant lines from omitted output]"));
+        assert!(result.contains("L501: error[E0308]: mismatched types"));
+    }
+
+    #[test]
+    fn test_single_long_line() {
+        let governor = OutputGovernor::new(1000);
+        let output = "가".repeat(2000);
+        let result = governor.apply("read", output);
+        assert!(result.contains("lines omitted"));
+        assert!(result.len() < 2000 * 3);
+    }
+
+    #[test]
+    fn test_fetch_tool_exempt() {
+        let governor = OutputGovernor::new(100);
+        let output = numbered_lines(100);
+        assert_eq!(
+            governor.apply(FETCH_FULL_OUTPUT_TOOL, output.clone()),
+            output
+        );
+        assert!(full_output("out-unknown").is_none());
+    }
+}
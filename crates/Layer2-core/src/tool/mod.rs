@@ -9,7 +9,10 @@
 //! │  ToolRegistry                                                │
 //! │  ├── register(tool) - 도구 등록                              │
 //! │  ├── get(name) - 도구 조회                                   │
-//! │  └── schemas() - MCP 호환 스키마                             │
+//! │  ├── schemas() - MCP 호환 스키마                             │
+//! │  ├── MiddlewareChain - before/after 훅 (가리기, 감사 로그)   │
+//! │  ├── SecretScanner - 비밀값 탐지 ([REDACTED:종류])           │
+//! │  └── OutputGovernor - 거대한 결과 잘라내기 (head+tail)       │
 //! ├─────────────────────────────────────────────────────────────┤
 //! │  RuntimeContext (ToolContext 구현)                           │
 //! │  ├── check_permission() - 권한 검사                          │
@@ -46,10 +49,16 @@
 //! }
 //! ```
 
+pub mod archive;
 pub mod builtin;
 mod context;
+pub mod document;
+pub mod format;
+pub mod governor;
+pub mod middleware;
 pub mod parallel;
 mod registry;
+pub mod secrets;
 pub mod security;
 
 // Re-exports: Tool trait from Layer1
@@ -57,17 +66,36 @@ pub use forge_foundation::{Tool, ToolContext};
 
 // Re-exports: Tools
 pub use builtin::{
-    all_tools, core_tools, filesystem_tools, BashTool, EditTool, GlobTool, GrepTool, ReadTool,
-    WriteTool,
+    all_tools, core_tools, filesystem_tools, ArchiveTool, BashTool, CodeSearchTool, DownloadTool,
+    EditTool, FetchFullOutputTool, GitBlameTool, GitLogTool, GlobTool, GrepTool, HttpRequestConfig,
+    HttpRequestTool, ListDirectoryTool, ListTodosTool, PullRequestTool, ReadTool,
+    ResolveConflictTool, SqlQueryConfig, SqlQueryTool, TransferConfig, UploadTool, WriteTool,
 };
 
+// Re-exports: Output governor
+pub use governor::{full_output, OutputGovernor};
+
+// Re-exports: Middleware
+pub use middleware::{
+    oversight_record, redaction_record, AuditEntry, AuditLogMiddleware, MiddlewareAction,
+    MiddlewareChain, OversightMiddleware, RedactSecretsMiddleware, ToolMiddleware,
+};
+
+// Re-exports: Secret scanning
+pub use secrets::{redaction_marker, shannon_entropy, Redaction, SecretScanner};
+
+// Re-exports: Auto formatting
+pub use format::AutoFormatter;
+
 // Re-exports: Context
 pub use context::{DefaultShellConfig, RuntimeContext};
+pub(crate) use context::record_audit;
 
 // Re-exports: Registry
 pub use registry::{
-    ParallelExecutionConfig, ParallelExecutionStats, ParallelToolCall, ToolDefinition,
-    ToolExecuteResult, ToolParameters, ToolRegistry,
+    execute_with_deadline, ParallelExecutionConfig, ParallelExecutionStats, ParallelToolCall,
+    ToolDefinition, ToolExecuteResult, ToolOutcome, ToolParameters, ToolRegistry, ToolTimeouts,
+    DEFAULT_TOOL_TIMEOUT,
 };
 
 // Re-exports: Security
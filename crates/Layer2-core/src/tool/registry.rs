@@ -7,6 +7,9 @@
 //! - Builtin 도구 자동 등록
 //! - MCP 도구 통합 (McpBridge 연동)
 //! - 카테고리별 그룹화
+//! - 도구별 실행 타임아웃 (`tools.timeout`/`tools.timeouts` 설정)
+//! - 미들웨어 체인 (`add_middleware`, 비밀값 가리기/감사 로그 등)
+//! - Sub-agent용 필터링 뷰 (`for_subagent`, 허용된 도구만 노출/실행)
 //!
 //! ## Layer1 연동
 //! - `Tool` trait으로 모든 도구 통합
@@ -22,9 +25,18 @@
 //! // 또는 개별 서버의 도구만
 //! registry.add_mcp_tools("notion", mcp_tools);
 //! ```
+//!
+//! ## 타임아웃과 취소
+//!
+//! 타임아웃이 지나면 컨텍스트의 `CancellationToken`을 취소하고, 도구가 정리할 수 있도록
+//! 잠시 기다린 뒤 실행을 포기합니다 (`execute_with_deadline`).
 
 use super::builtin;
-use forge_foundation::Tool;
+use super::governor::OutputGovernor;
+use super::middleware::{MiddlewareChain, ToolMiddleware};
+use crate::config::ToolsConfigSection;
+use forge_foundation::{Result, Tool, ToolContext, ToolResult};
+use forge_task::SubAgentConfig;
 use futures::future::join_all;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -49,6 +61,12 @@ use tracing::{debug, info, warn};
 /// ```
 pub struct ToolRegistry {
     tools: HashMap<String, Arc<dyn Tool>>,
+    /// 도구 출력 크기 제한
+    governor: OutputGovernor,
+    /// 도구별 실행 타임아웃
+    timeouts: ToolTimeouts,
+    /// 모든 도구 실행에 적용되는 미들웨어
+    middleware: MiddlewareChain,
 }
 
 impl ToolRegistry {
@@ -56,6 +74,9 @@ impl ToolRegistry {
     pub fn new() -> Self {
         Self {
             tools: HashMap::new(),
+            governor: OutputGovernor::default(),
+            timeouts: ToolTimeouts::default(),
+            middleware: MiddlewareChain::new(),
         }
     }
 
@@ -71,6 +92,46 @@ impl ToolRegistry {
         registry
     }
 
+    /// 도구 출력 크기 제한 설정
+    pub fn set_output_governor(&mut self, governor: OutputGovernor) {
+        self.governor = governor;
+    }
+
+    /// 도구 실행 타임아웃 설정
+    pub fn set_timeouts(&mut self, timeouts: ToolTimeouts) {
+        self.timeouts = timeouts;
+    }
+
+    /// 도구 실행 타임아웃 설정
+    pub fn timeouts(&self) -> &ToolTimeouts {
+        &self.timeouts
+    }
+
+    /// 도구에 적용할 실행 타임아웃 (None이면 제한 없음)
+    pub fn timeout_for(&self, name: &str) -> Option<Duration> {
+        self.timeouts.for_tool(name)
+    }
+
+    /// 미들웨어 추가 (등록 순서대로 `before`, 역순으로 `after` 실행)
+    pub fn add_middleware(&mut self, middleware: Arc<dyn ToolMiddleware>) {
+        self.middleware.push(middleware);
+    }
+
+    /// 미들웨어 체인 교체
+    pub fn set_middleware(&mut self, middleware: MiddlewareChain) {
+        self.middleware = middleware;
+    }
+
+    /// 이름으로 미들웨어 제거
+    pub fn remove_middleware(&mut self, name: &str) -> bool {
+        self.middleware.remove(name)
+    }
+
+    /// 미들웨어 체인
+    pub fn middleware(&self) -> &MiddlewareChain {
+        &self.middleware
+    }
+
     /// 도구 등록
     pub fn register(&mut self, tool: Arc<dyn Tool>) {
         let name = tool.name().to_string();
@@ -237,6 +298,43 @@ impl ToolRegistry {
             .collect()
     }
 
+    // ========================================================================
+    // Filtered View (Sub-agent)
+    // ========================================================================
+
+    /// 조건을 통과한 도구만 담은 레지스트리 뷰
+    ///
+    /// 도구 인스턴스와 출력 제한/타임아웃/미들웨어는 원본과 공유합니다.
+    /// 뷰에 없는 도구는 `definitions()`에 노출되지 않고 `execute()`도 "Tool not found"로 실패합니다.
+    pub fn filtered(&self, allow: impl Fn(&str) -> bool) -> ToolRegistry {
+        ToolRegistry {
+            tools: self
+                .tools
+                .iter()
+                .filter(|(name, _)| allow(name))
+                .map(|(name, tool)| (name.clone(), Arc::clone(tool)))
+                .collect(),
+            governor: self.governor,
+            timeouts: self.timeouts.clone(),
+            middleware: self.middleware.clone(),
+        }
+    }
+
+    /// Sub-agent 설정의 허용/금지 목록을 적용한 레지스트리 뷰
+    ///
+    /// 프롬프트에 의존하지 않고, 허용되지 않은 도구는 아예 제공하지 않습니다.
+    pub fn for_subagent(&self, config: &SubAgentConfig) -> ToolRegistry {
+        let policy = config.tool_policy();
+        let view = self.filtered(|name| policy.is_allowed(name));
+        debug!(
+            "Sub-agent tool view ({}): {}/{} tools",
+            config.agent_type.name(),
+            view.len(),
+            self.len()
+        );
+        view
+    }
+
     // ========================================================================
     // Agent Integration
     // ========================================================================
@@ -270,6 +368,8 @@ impl ToolRegistry {
     }
 
     /// 도구 실행 (Agent용)
+    ///
+    /// 미들웨어 체인과 도구별 타임아웃이 적용되며, 타임아웃 시 `ctx`의 취소 토큰이 취소됩니다.
     pub async fn execute(
         &self,
         name: &str,
@@ -279,16 +379,16 @@ impl ToolRegistry {
         let start = Instant::now();
 
         let result = match self.get(name) {
-            Some(tool) => match tool.execute(args, ctx).await {
-                Ok(result) => ToolExecuteResult {
+            Some(tool) => match self.middleware.execute(tool.as_ref(), args, ctx, self.timeout_for(name)).await {
+                ToolOutcome::Finished(Ok(result)) => ToolExecuteResult {
                     success: result.success,
-                    content: result.output,
+                    content: self.governor.apply(name, result.output),
                     error: result.error,
                     duration_ms: Some(start.elapsed().as_millis() as u64),
                     tool_name: Some(name.to_string()),
                     call_id: None,
                 },
-                Err(e) => ToolExecuteResult {
+                ToolOutcome::Finished(Err(e)) => ToolExecuteResult {
                     success: false,
                     content: String::new(),
                     error: Some(e.to_string()),
@@ -296,6 +396,14 @@ impl ToolRegistry {
                     tool_name: Some(name.to_string()),
                     call_id: None,
                 },
+                outcome => ToolExecuteResult {
+                    success: false,
+                    content: String::new(),
+                    error: outcome.interruption(),
+                    duration_ms: Some(start.elapsed().as_millis() as u64),
+                    tool_name: Some(name.to_string()),
+                    call_id: None,
+                },
             },
             None => ToolExecuteResult {
                 success: false,
@@ -348,6 +456,7 @@ impl ToolRegistry {
 
         // 세마포어로 동시 실행 수 제한
         let semaphore = Arc::new(Semaphore::new(config.max_concurrency));
+        let governor = self.governor;
 
         // 각 호출에 대한 Future 생성
         let futures: Vec<_> = calls
@@ -369,7 +478,7 @@ impl ToolRegistry {
                             match tokio::time::timeout(timeout, t.execute(call.args, ctx)).await {
                                 Ok(Ok(res)) => ToolExecuteResult {
                                     success: res.success,
-                                    content: res.output,
+                                    content: governor.apply(&call.tool_name, res.output),
                                     error: res.error,
                                     duration_ms: Some(call_start.elapsed().as_millis() as u64),
                                     tool_name: Some(call.tool_name.clone()),
@@ -597,6 +706,129 @@ impl ToolRegistry {
     }
 }
 
+// ============================================================================
+// Timeouts
+// ============================================================================
+
+/// 기본 도구 실행 타임아웃
+pub const DEFAULT_TOOL_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// 취소 후 도구가 정리할 때까지 기다리는 시간
+const CANCEL_GRACE: Duration = Duration::from_secs(2);
+
+/// 도구별 실행 타임아웃
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolTimeouts {
+    /// 기본 타임아웃 (None이면 제한 없음)
+    pub default: Option<Duration>,
+    /// 도구별 타임아웃 (None이면 제한 없음)
+    pub per_tool: HashMap<String, Option<Duration>>,
+}
+
+impl Default for ToolTimeouts {
+    fn default() -> Self {
+        Self {
+            default: Some(DEFAULT_TOOL_TIMEOUT),
+            per_tool: HashMap::new(),
+        }
+    }
+}
+
+impl ToolTimeouts {
+    /// 설정 파일의 `tools` 섹션에서 생성 (초 단위, 0이면 제한 없음)
+    pub fn from_config(config: &ToolsConfigSection) -> Self {
+        let seconds = |secs: u64| (secs > 0).then(|| Duration::from_secs(secs));
+        Self {
+            default: seconds(config.timeout),
+            per_tool: config
+                .timeouts
+                .iter()
+                .map(|(name, secs)| (name.clone(), seconds(*secs)))
+                .collect(),
+        }
+    }
+
+    /// 도구별 타임아웃 지정
+    pub fn with_tool(mut self, name: impl Into<String>, timeout: Option<Duration>) -> Self {
+        self.per_tool.insert(name.into(), timeout);
+        self
+    }
+
+    /// 도구에 적용할 타임아웃
+    pub fn for_tool(&self, name: &str) -> Option<Duration> {
+        self.per_tool.get(name).copied().unwrap_or(self.default)
+    }
+}
+
+/// 타임아웃/취소를 적용한 도구 실행 결과
+#[derive(Debug)]
+pub enum ToolOutcome {
+    /// 도구가 끝까지 실행됨 (도구 자체의 성공/실패 포함)
+    Finished(Result<ToolResult>),
+    /// 타임아웃으로 중단됨
+    TimedOut(Duration),
+    /// 취소 토큰으로 중단됨
+    Cancelled,
+}
+
+impl ToolOutcome {
+    /// 중단된 경우 에러 메시지
+    pub fn interruption(&self) -> Option<String> {
+        match self {
+            ToolOutcome::Finished(_) => None,
+            ToolOutcome::TimedOut(timeout) => {
+                Some(format!("Timeout after {}ms", timeout.as_millis()))
+            }
+            ToolOutcome::Cancelled => Some("Cancelled".to_string()),
+        }
+    }
+}
+
+/// 타임아웃과 취소를 적용해 도구 실행
+///
+/// 타임아웃이 지나거나 `ctx`의 취소 토큰이 취소되면 토큰을 취소하고,
+/// 도구가 자식 프로세스 등을 정리할 수 있도록 잠시 기다린 뒤 실행을 포기합니다.
+pub async fn execute_with_deadline(
+    tool: &dyn Tool,
+    input: serde_json::Value,
+    ctx: &dyn ToolContext,
+    timeout: Option<Duration>,
+) -> ToolOutcome {
+    let execution = tool.execute(input, ctx);
+    tokio::pin!(execution);
+    let deadline = async {
+        match timeout {
+            Some(timeout) => {
+                tokio::time::sleep(timeout).await;
+                timeout
+            }
+            None => std::future::pending().await,
+        }
+    };
+
+    // 취소된 도구는 곧바로 끝날 수 있으므로 취소/타임아웃을 먼저 확인
+    let outcome = tokio::select! {
+        biased;
+        _ = ctx.cancelled() => ToolOutcome::Cancelled,
+        timeout = deadline => ToolOutcome::TimedOut(timeout),
+        result = &mut execution => return ToolOutcome::Finished(result),
+    };
+
+    match ctx.cancellation_token() {
+        Some(token) => {
+            token.cancel();
+            if tokio::time::timeout(CANCEL_GRACE, &mut execution).await.is_err() {
+                warn!("Tool '{}' did not stop after cancellation", tool.name());
+            }
+        }
+        None => warn!("Tool '{}' has no cancellation token; abandoning it", tool.name()),
+    }
+    if let ToolOutcome::TimedOut(timeout) = outcome {
+        warn!("Tool '{}' timed out after {:?}", tool.name(), timeout);
+    }
+    outcome
+}
+
 /// 도구 정의 (Agent용)
 #[derive(Debug, Clone)]
 pub struct ToolDefinition {
@@ -753,4 +985,53 @@ mod tests {
         // filesystem 카테고리가 있어야 함
         assert!(by_cat.contains_key("filesystem"));
     }
+
+    #[test]
+    fn test_timeouts_from_config() {
+        let registry = ToolRegistry::new();
+        assert_eq!(registry.timeout_for("read"), Some(DEFAULT_TOOL_TIMEOUT));
+
+        let config = ToolsConfigSection {
+            timeout: 0,
+            timeouts: HashMap::from([
+                ("bash".to_string(), 30),
+                ("web_fetch".to_string(), 0),
+            ]),
+        };
+        let timeouts = ToolTimeouts::from_config(&config);
+        assert_eq!(timeouts.for_tool("bash"), Some(Duration::from_secs(30)));
+        assert_eq!(timeouts.for_tool("web_fetch"), None);
+        assert_eq!(timeouts.for_tool("read"), None);
+
+        let timeouts = ToolTimeouts::from_config(&ToolsConfigSection::default());
+        assert_eq!(timeouts, ToolTimeouts::default());
+    }
+
+    #[tokio::test]
+    async fn test_subagent_view() {
+        use forge_task::{SubAgentType, ToolPolicy};
+
+        let registry = ToolRegistry::with_builtins();
+        let config = SubAgentConfig::for_type(SubAgentType::Researcher)
+            .with_tool_policy(ToolPolicy::new().allow("read").allow("grep").allow("git_*"));
+        let view = registry.for_subagent(&config);
+
+        assert!(view.contains("read"));
+        assert!(view.contains("git_log"));
+        assert!(!view.contains("bash"));
+        assert!(!view.contains("write"));
+        assert!(view.definitions().iter().all(|d| d.name != "bash"));
+
+        // 뷰에 없는 도구는 실행할 수 없음
+        let ctx = crate::tool::RuntimeContext::new(
+            "test",
+            std::env::temp_dir(),
+            std::sync::Arc::new(forge_foundation::permission::PermissionService::new()),
+        );
+        let result = view
+            .execute("bash", &ctx, serde_json::json!({"command": "echo hi"}))
+            .await;
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Tool not found"));
+    }
 }
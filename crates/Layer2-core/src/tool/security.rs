@@ -71,6 +71,9 @@ pub struct PathValidator {
 
     /// path traversal 검사 여부
     check_traversal: bool,
+
+    /// 상대 경로의 기준 디렉토리 (없으면 프로세스 cwd)
+    base_dir: Option<PathBuf>,
 }
 
 impl PathValidator {
@@ -81,6 +84,7 @@ impl PathValidator {
             dangerous_patterns: default_dangerous_patterns(),
             check_symlinks: true,
             check_traversal: true,
+            base_dir: None,
         }
     }
 
@@ -96,6 +100,12 @@ impl PathValidator {
         self
     }
 
+    /// 상대 경로의 기준 디렉토리 설정 (예: 셸 세션의 현재 디렉토리)
+    pub fn with_base_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.base_dir = Some(dir.into());
+        self
+    }
+
     /// 위험 패턴 추가
     pub fn with_dangerous_pattern(mut self, pattern: impl Into<String>) -> Self {
         self.dangerous_patterns.push(pattern.into());
@@ -117,16 +127,12 @@ impl PathValidator {
     /// 경로 검증
     pub fn validate(&self, path: &Path) -> PathValidation {
         // 1. 절대 경로로 변환
-        let path = if path.is_absolute() {
-            path.to_path_buf()
-        } else {
-            match std::env::current_dir() {
-                Ok(cwd) => cwd.join(path),
-                Err(_) => return PathValidation::DangerousPath {
-                    path: path.to_path_buf(),
-                    reason: "Cannot resolve relative path".to_string(),
-                },
-            }
+        let path = match self.absolute(path) {
+            Some(path) => path,
+            None => return PathValidation::DangerousPath {
+                path: path.to_path_buf(),
+                reason: "Cannot resolve relative path".to_string(),
+            },
         };
 
         // 2. 정규화된 경로 얻기 (canonicalize 없이 수동 정규화)
@@ -210,6 +216,22 @@ impl PathValidator {
     pub fn is_valid(&self, path: &Path) -> bool {
         self.validate(path).is_valid()
     }
+
+    /// 기준 디렉토리 기준 절대 경로로 변환 후 정규화
+    pub fn resolve(&self, path: &Path) -> Option<PathBuf> {
+        self.absolute(path).map(|path| normalize_path(&path))
+    }
+
+    fn absolute(&self, path: &Path) -> Option<PathBuf> {
+        if path.is_absolute() {
+            return Some(path.to_path_buf());
+        }
+        let base = match &self.base_dir {
+            Some(dir) => dir.clone(),
+            None => std::env::current_dir().ok()?,
+        };
+        Some(base.join(path))
+    }
 }
 
 impl Default for PathValidator {
@@ -442,4 +464,18 @@ mod tests {
         };
         assert!(result.error_message().unwrap().contains("Dangerous"));
     }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_base_dir() {
+        let validator = PathValidator::new().with_base_dir("/work/project");
+        assert_eq!(
+            validator.resolve(Path::new("src/../Cargo.toml")),
+            Some(PathBuf::from("/work/project/Cargo.toml"))
+        );
+        assert_eq!(
+            validator.resolve(Path::new("/etc/hosts")),
+            Some(PathBuf::from("/etc/hosts"))
+        );
+    }
 }
@@ -135,6 +135,23 @@ impl ProviderError {
             _ => ProviderError::Unknown(format!("HTTP {}: {}", status, body)),
         }
     }
+
+    /// Prompt size reported by a context-length error, if the message includes one
+    ///
+    /// Providers phrase this differently ("your messages resulted in 130536 tokens",
+    /// "prompt is too long: 210000 tokens > 200000 maximum"), but the largest number
+    /// in the message is the actual request size in every known format.
+    pub fn reported_prompt_tokens(&self) -> Option<usize> {
+        let ProviderError::ContextLengthExceeded(message) = self else {
+            return None;
+        };
+
+        // Keep thousands separators ("128,000") inside a number
+        message
+            .split(|c: char| !c.is_ascii_digit() && c != ',')
+            .filter_map(|word| word.replace(',', "").parse::<usize>().ok())
+            .max()
+    }
 }
 
 /// Try to extract retry-after value from error body (in milliseconds)
@@ -216,3 +233,30 @@ impl From<ProviderError> for FoundationError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reported_prompt_tokens() {
+        let openai = ProviderError::ContextLengthExceeded(
+            "This model's maximum context length is 128,000 tokens. However, your messages \
+             resulted in 130536 tokens."
+                .to_string(),
+        );
+        assert_eq!(openai.reported_prompt_tokens(), Some(130_536));
+
+        let anthropic = ProviderError::ContextLengthExceeded(
+            "prompt is too long: 210000 tokens > 200000 maximum".to_string(),
+        );
+        assert_eq!(anthropic.reported_prompt_tokens(), Some(210_000));
+
+        let vague = ProviderError::ContextLengthExceeded("input too long".to_string());
+        assert_eq!(vague.reported_prompt_tokens(), None);
+        assert_eq!(
+            ProviderError::ServerError("500".to_string()).reported_prompt_tokens(),
+            None
+        );
+    }
+}
@@ -11,11 +11,22 @@ use crate::{
     retry::{with_retry, RetryConfig},
     Message, Provider, ProviderResponse, ToolDef,
 };
-use forge_foundation::{Error, ProviderConfig, ProviderType, Result};
+use forge_foundation::{check_egress, provider_store, Error, ProviderConfig, ProviderType, Result};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Whether the egress policy (`security.network`) allows the provider's base URL
+fn egress_allowed(name: &str, provider_config: &provider_store::Provider) -> bool {
+    match check_egress(provider_config.effective_base_url()) {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::warn!(provider = %name, error = %e, "Skipping provider");
+            false
+        }
+    }
+}
+
 /// Gateway that manages multiple LLM providers
 pub struct Gateway {
     providers: HashMap<String, Arc<dyn Provider>>,
@@ -29,6 +40,10 @@ impl Gateway {
         let mut providers: HashMap<String, Arc<dyn Provider>> = HashMap::new();
 
         for (name, provider_config) in config.list_enabled() {
+            if !egress_allowed(name, provider_config) {
+                continue;
+            }
+
             let provider: Arc<dyn Provider> = match provider_config.provider_type {
                 ProviderType::Anthropic => {
                     let api_key = provider_config.api_key.as_deref().unwrap_or("");
@@ -108,6 +123,10 @@ impl Gateway {
         let mut providers: HashMap<String, Arc<dyn Provider>> = HashMap::new();
 
         for (name, provider_config) in config.list_enabled() {
+            if !egress_allowed(name, provider_config) {
+                continue;
+            }
+
             let provider: Arc<dyn Provider> = match provider_config.provider_type {
                 ProviderType::Anthropic => {
                     let api_key = provider_config.api_key.as_deref().unwrap_or("");
@@ -398,4 +417,27 @@ mod tests {
         let result = gateway.set_default("nonexistent").await;
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_from_config_skips_blocked_provider() {
+        use forge_foundation::{set_egress_policy, EgressPolicy};
+
+        set_egress_policy(EgressPolicy::new(
+            vec![],
+            vec!["blocked-llm.test".to_string()],
+        ));
+        let mut config = ProviderConfig::default();
+        config.add(
+            "local",
+            provider_store::Provider::new(ProviderType::Ollama)
+                .base_url("http://blocked-llm.test:11434"),
+        );
+        let result = Gateway::from_config(&config);
+        set_egress_policy(EgressPolicy::unrestricted());
+
+        let Err(err) = result else {
+            panic!("blocked provider should be skipped");
+        };
+        assert!(err.to_string().contains("No LLM providers configured"));
+    }
 }
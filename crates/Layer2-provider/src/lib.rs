@@ -33,8 +33,8 @@ pub mod r#trait;
 pub use gateway::Gateway;
 pub use message::{Message, MessageRole, ToolCall, ToolResult};
 pub use r#trait::{
-    FinishReason, ModelInfo, Provider, ProviderMetadata, ProviderResponse, StreamEvent, TokenCount,
-    TokenUsage,
+    FinishReason, ModelInfo, Provider, ProviderMetadata, ProviderResponse, RequestOptions,
+    StreamEvent, TokenCount, TokenUsage,
 };
 pub use tool_def::ToolDef;
 
@@ -46,6 +46,7 @@ pub use retry::RetryConfig;
 pub use providers::anthropic::AnthropicProvider;
 pub use providers::gemini::GeminiProvider;
 pub use providers::groq::GroqProvider;
+pub use providers::mock::{MockProvider, MockRequest, MockTurn};
 pub use providers::ollama::{OllamaModelDetails, OllamaModelInfo, OllamaProvider};
 pub use providers::openai::OpenAiProvider;
 
@@ -4,12 +4,13 @@ use crate::{
     error::ProviderError,
     r#trait::{
         ConfigKey, FinishReason, ModelInfo, Provider, ProviderMetadata, ProviderResponse,
-        StreamEvent, TokenUsage,
+        RequestOptions, StreamEvent, TokenUsage,
     },
     retry::{with_retry, RetryConfig},
     Message, MessageRole, ToolCall, ToolDef,
 };
 use async_trait::async_trait;
+use forge_foundation::egress_client;
 use futures::{Stream, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -42,7 +43,9 @@ impl AnthropicProvider {
             .unwrap_or_else(|| ModelInfo::new(&model_id, "anthropic"));
 
         Self {
-            client: Client::new(),
+            client: egress_client()
+                .build()
+                .expect("Failed to create HTTP client"),
             api_key,
             metadata: ProviderMetadata {
                 id: "anthropic".to_string(),
@@ -139,6 +142,7 @@ impl AnthropicProvider {
         tools: &[ToolDef],
         system_prompt: Option<&str>,
         stream: bool,
+        options: &RequestOptions,
     ) -> AnthropicRequest {
         // Pre-allocate with capacity hint
         let message_count = messages.iter().filter(|m| m.role != MessageRole::System).count();
@@ -156,8 +160,9 @@ impl AnthropicProvider {
         }
 
         AnthropicRequest {
-            model: self.current_model.id.clone(),
-            max_tokens: self.max_tokens,
+            model: options.model_id(&self.current_model).to_string(),
+            max_tokens: options.resolve_max_tokens(self.max_tokens, &self.current_model),
+            stop_sequences: options.stop(),
             system: system_prompt.map(|s| s.to_string()),
             messages: api_messages,
             tools: if api_tools.is_empty() {
@@ -234,7 +239,18 @@ impl Provider for AnthropicProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), true);
+        self.stream_with_options(messages, tools, system_prompt, &RequestOptions::default())
+    }
+
+    fn stream_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
+        let request =
+            self.build_request(&messages, &tools, system_prompt.as_deref(), true, options);
 
         Box::pin(async_stream::stream! {
             // Make request
@@ -367,7 +383,19 @@ impl Provider for AnthropicProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Result<ProviderResponse, ProviderError> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), false);
+        self.complete_with_options(messages, tools, system_prompt, &RequestOptions::default())
+            .await
+    }
+
+    async fn complete_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Result<ProviderResponse, ProviderError> {
+        let request =
+            self.build_request(&messages, &tools, system_prompt.as_deref(), false, options);
 
         // Execute with retry
         let response = with_retry(&self.retry_config, "anthropic_complete", || async {
@@ -452,6 +480,8 @@ struct AnthropicRequest {
     model: String,
     max_tokens: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
+    stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     system: Option<String>,
     messages: Vec<AnthropicMessage>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -479,6 +509,8 @@ enum ContentBlock {
     Text { text: String },
     #[serde(rename = "thinking")]
     Thinking { thinking: String },
+    #[serde(rename = "image")]
+    Image { source: ImageSource },
     #[serde(rename = "tool_use")]
     ToolUse {
         id: String,
@@ -494,6 +526,14 @@ enum ContentBlock {
     },
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct ImageSource {
+    #[serde(rename = "type")]
+    source_type: String,
+    media_type: String,
+    data: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 enum ContentDelta {
@@ -632,6 +672,32 @@ impl From<&Message> for AnthropicMessage {
             };
         }
 
+        // Message with attached images
+        if !msg.images.is_empty() {
+            let mut blocks: Vec<ContentBlock> = msg
+                .images
+                .iter()
+                .map(|image| ContentBlock::Image {
+                    source: ImageSource {
+                        source_type: "base64".to_string(),
+                        media_type: image.media_type.clone(),
+                        data: image.data.clone(),
+                    },
+                })
+                .collect();
+
+            if !msg.content.is_empty() {
+                blocks.push(ContentBlock::Text {
+                    text: msg.content.clone(),
+                });
+            }
+
+            return AnthropicMessage {
+                role: role.to_string(),
+                content: AnthropicContent::Blocks(blocks),
+            };
+        }
+
         // Simple text message
         AnthropicMessage {
             role: role.to_string(),
@@ -4,11 +4,12 @@ use crate::{
     error::ProviderError,
     r#trait::{
         ConfigKey, FinishReason, ModelInfo, Provider, ProviderMetadata, ProviderResponse,
-        StreamEvent, TokenUsage,
+        RequestOptions, StreamEvent, TokenUsage,
     },
     Message, MessageRole, ToolCall, ToolDef,
 };
 use async_trait::async_trait;
+use forge_foundation::egress_client;
 use futures::{Stream, TryStreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -37,7 +38,7 @@ impl GeminiProvider {
         let model_info = Self::get_model_info(&model_id);
 
         Self {
-            client: Client::builder()
+            client: egress_client()
                 .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
                 .build()
                 .expect("Failed to create HTTP client"),
@@ -51,7 +52,7 @@ impl GeminiProvider {
 
     /// Set custom timeout
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
-        self.client = Client::builder()
+        self.client = egress_client()
             .timeout(timeout)
             .build()
             .expect("Failed to create HTTP client");
@@ -166,7 +167,7 @@ impl GeminiProvider {
         }
     }
 
-    fn generate_url(&self, stream: bool) -> String {
+    fn generate_url(&self, model: &str, stream: bool) -> String {
         let action = if stream {
             "streamGenerateContent"
         } else {
@@ -174,7 +175,7 @@ impl GeminiProvider {
         };
         format!(
             "{}/models/{}:{}?key={}",
-            self.base_url, self.model_info.id, action, self.api_key
+            self.base_url, model, action, self.api_key
         )
     }
 
@@ -183,6 +184,7 @@ impl GeminiProvider {
         messages: &[Message],
         tools: &[ToolDef],
         system_prompt: Option<&str>,
+        options: &RequestOptions,
     ) -> GeminiRequest {
         let mut contents: Vec<GeminiContent> = vec![];
 
@@ -210,12 +212,15 @@ impl GeminiProvider {
             }],
         });
 
+        let max_output_tokens = options.resolve_max_tokens(self.max_tokens, &self.model_info);
+
         GeminiRequest {
             contents,
             tools: gemini_tools,
             system_instruction,
             generation_config: Some(GeminiGenerationConfig {
-                max_output_tokens: Some(self.max_tokens),
+                max_output_tokens: Some(max_output_tokens),
+                stop_sequences: options.stop(),
                 temperature: None,
                 top_p: None,
                 top_k: None,
@@ -268,8 +273,18 @@ impl Provider for GeminiProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref());
-        let url = self.generate_url(true);
+        self.stream_with_options(messages, tools, system_prompt, &RequestOptions::default())
+    }
+
+    fn stream_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
+        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), options);
+        let url = self.generate_url(options.model_id(&self.model_info), true);
 
         Box::pin(async_stream::stream! {
             let response = match self
@@ -380,8 +395,19 @@ impl Provider for GeminiProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Result<ProviderResponse, ProviderError> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref());
-        let url = self.generate_url(false);
+        self.complete_with_options(messages, tools, system_prompt, &RequestOptions::default())
+            .await
+    }
+
+    async fn complete_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Result<ProviderResponse, ProviderError> {
+        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), options);
+        let url = self.generate_url(options.model_id(&self.model_info), false);
 
         let response = self
             .client
@@ -495,6 +521,8 @@ struct GeminiGenerationConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     max_output_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     top_p: Option<f32>,
@@ -522,6 +550,17 @@ enum GeminiPart {
         #[serde(rename = "functionResponse")]
         function_response: GeminiFunctionResponse,
     },
+    InlineData {
+        #[serde(rename = "inlineData")]
+        inline_data: GeminiInlineData,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiInlineData {
+    mime_type: String,
+    data: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -632,6 +671,16 @@ impl From<&Message> for GeminiContent {
             });
         }
 
+        // Add attached images
+        for image in &msg.images {
+            parts.push(GeminiPart::InlineData {
+                inline_data: GeminiInlineData {
+                    mime_type: image.media_type.clone(),
+                    data: image.data.clone(),
+                },
+            });
+        }
+
         // Add tool calls as function calls
         if let Some(ref tool_calls) = msg.tool_calls {
             for tc in tool_calls {
@@ -686,8 +735,12 @@ mod tests {
     #[test]
     fn test_generate_url() {
         let provider = GeminiProvider::new("test-key", "gemini-2.0-flash", 8192);
-        let url = provider.generate_url(false);
+        let url = provider.generate_url(&provider.model_info.id, false);
         assert!(url.contains("generateContent"));
         assert!(url.contains("gemini-2.0-flash"));
+
+        let options = RequestOptions::new().with_model("gemini-1.5-pro");
+        let url = provider.generate_url(options.model_id(&provider.model_info), true);
+        assert!(url.contains("/models/gemini-1.5-pro:streamGenerateContent"));
     }
 }
@@ -7,11 +7,12 @@ use crate::{
     error::ProviderError,
     r#trait::{
         ConfigKey, FinishReason, ModelInfo, Provider, ProviderMetadata, ProviderResponse,
-        StreamEvent, TokenUsage,
+        RequestOptions, StreamEvent, TokenUsage,
     },
     Message, MessageRole, ToolCall, ToolDef,
 };
 use async_trait::async_trait;
+use forge_foundation::egress_client;
 use futures::{Stream, TryStreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -40,7 +41,7 @@ impl GroqProvider {
         let model_info = Self::get_model_info(&model_id);
 
         Self {
-            client: Client::builder()
+            client: egress_client()
                 .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
                 .build()
                 .expect("Failed to create HTTP client"),
@@ -54,7 +55,7 @@ impl GroqProvider {
 
     /// Set custom timeout
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
-        self.client = Client::builder()
+        self.client = egress_client()
             .timeout(timeout)
             .build()
             .expect("Failed to create HTTP client");
@@ -156,6 +157,7 @@ impl GroqProvider {
         tools: &[ToolDef],
         system_prompt: Option<&str>,
         stream: bool,
+        options: &RequestOptions,
     ) -> GroqRequest {
         let mut api_messages: Vec<GroqMessage> = vec![];
 
@@ -180,9 +182,10 @@ impl GroqProvider {
         let api_tools: Vec<GroqTool> = tools.iter().map(|t| t.into()).collect();
 
         GroqRequest {
-            model: self.model_info.id.clone(),
+            model: options.model_id(&self.model_info).to_string(),
             messages: api_messages,
-            max_tokens: Some(self.max_tokens),
+            max_tokens: Some(options.resolve_max_tokens(self.max_tokens, &self.model_info)),
+            stop: options.stop(),
             tools: if api_tools.is_empty() {
                 None
             } else {
@@ -236,7 +239,18 @@ impl Provider for GroqProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), true);
+        self.stream_with_options(messages, tools, system_prompt, &RequestOptions::default())
+    }
+
+    fn stream_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
+        let request =
+            self.build_request(&messages, &tools, system_prompt.as_deref(), true, options);
 
         Box::pin(async_stream::stream! {
             let response = match self
@@ -392,7 +406,19 @@ impl Provider for GroqProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Result<ProviderResponse, ProviderError> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), false);
+        self.complete_with_options(messages, tools, system_prompt, &RequestOptions::default())
+            .await
+    }
+
+    async fn complete_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Result<ProviderResponse, ProviderError> {
+        let request =
+            self.build_request(&messages, &tools, system_prompt.as_deref(), false, options);
 
         let response = self
             .client
@@ -492,6 +518,8 @@ struct GroqRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     max_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<GroqTool>>,
     stream: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,317 @@
+//! Scripted mock provider
+//!
+//! Plays back a fixed script of responses without any network access, so an
+//! agent can be embedded and exercised offline (examples, tests, demos).
+//! Each request consumes the next scripted turn, and every request is recorded
+//! so callers can assert on what the agent sent.
+//!
+//! ```ignore
+//! let provider = MockProvider::new()
+//!     .with_turn(MockTurn::tool_call("call-1", "word_count", json!({ "text": "a b" })))
+//!     .with_turn(MockTurn::text("That text has two words."));
+//!
+//! let mut gateway = Gateway::new();
+//! gateway.add_provider("mock", Arc::new(provider));
+//! ```
+
+use crate::{
+    error::ProviderError,
+    r#trait::{
+        FinishReason, ModelInfo, Provider, ProviderMetadata, ProviderResponse, RequestOptions,
+        StreamEvent, TokenUsage,
+    },
+    Message, ToolCall, ToolDef,
+};
+use async_trait::async_trait;
+use futures::Stream;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+const MOCK_MODEL: &str = "mock-model";
+
+/// One scripted assistant response
+#[derive(Debug, Clone, Default)]
+pub struct MockTurn {
+    /// Text content
+    pub text: String,
+
+    /// Tool calls requested in this turn
+    pub tool_calls: Vec<ToolCall>,
+
+    /// Reported token usage
+    pub usage: TokenUsage,
+}
+
+impl MockTurn {
+    /// A plain text response
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// A response that calls a single tool
+    pub fn tool_call(id: impl Into<String>, name: impl Into<String>, arguments: Value) -> Self {
+        Self::default().with_tool_call(id, name, arguments)
+    }
+
+    /// Add another tool call to this turn
+    pub fn with_tool_call(
+        mut self,
+        id: impl Into<String>,
+        name: impl Into<String>,
+        arguments: Value,
+    ) -> Self {
+        self.tool_calls.push(ToolCall::new(id, name, arguments));
+        self
+    }
+
+    /// Set the reported token usage
+    pub fn with_usage(mut self, input_tokens: u32, output_tokens: u32) -> Self {
+        self.usage = TokenUsage {
+            input_tokens,
+            output_tokens,
+            ..TokenUsage::default()
+        };
+        self
+    }
+}
+
+/// A request received by the mock provider
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// Conversation sent to the provider
+    pub messages: Vec<Message>,
+
+    /// Names of the tools offered to the model
+    pub tools: Vec<String>,
+
+    /// System prompt
+    pub system_prompt: Option<String>,
+
+    /// Model the request was sent to (`RequestOptions::model` or the mock's model)
+    pub model: String,
+}
+
+/// Provider that replays scripted turns
+pub struct MockProvider {
+    metadata: ProviderMetadata,
+    model_info: ModelInfo,
+    turns: Mutex<VecDeque<MockTurn>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockProvider {
+    /// Create a provider with an empty script
+    pub fn new() -> Self {
+        let model_info = ModelInfo::new(MOCK_MODEL, "mock");
+        Self {
+            metadata: ProviderMetadata {
+                id: "mock".to_string(),
+                display_name: "Mock".to_string(),
+                models: vec![model_info.clone()],
+                default_model: MOCK_MODEL.to_string(),
+                config_keys: Vec::new(),
+                base_url: None,
+            },
+            model_info,
+            turns: Mutex::new(VecDeque::new()),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Append a scripted turn
+    pub fn with_turn(self, turn: MockTurn) -> Self {
+        self.push_turn(turn);
+        self
+    }
+
+    /// Append a scripted turn (e.g. while the provider is shared)
+    pub fn push_turn(&self, turn: MockTurn) {
+        self.turns
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push_back(turn);
+    }
+
+    /// Number of scripted turns not yet played
+    pub fn remaining_turns(&self) -> usize {
+        self.turns.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Requests received so far
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Record a request and take the next turn
+    fn next_turn(
+        &self,
+        messages: Vec<Message>,
+        tools: &[ToolDef],
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Result<MockTurn, ProviderError> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(MockRequest {
+                messages,
+                tools: tools.iter().map(|tool| tool.name.clone()).collect(),
+                system_prompt,
+                model: options.model_id(&self.model_info).to_string(),
+            });
+        self.turns
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop_front()
+            .ok_or_else(|| ProviderError::InvalidRequest("mock script exhausted".to_string()))
+    }
+}
+
+impl Default for MockProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Provider for MockProvider {
+    fn metadata(&self) -> &ProviderMetadata {
+        &self.metadata
+    }
+
+    fn model(&self) -> &ModelInfo {
+        &self.model_info
+    }
+
+    fn stream(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+    ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
+        self.stream_with_options(messages, tools, system_prompt, &RequestOptions::default())
+    }
+
+    fn stream_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
+        let turn = self.next_turn(messages, &tools, system_prompt, options);
+
+        Box::pin(async_stream::stream! {
+            let turn = match turn {
+                Ok(turn) => turn,
+                Err(e) => {
+                    yield StreamEvent::Error(e);
+                    return;
+                }
+            };
+
+            if !turn.text.is_empty() {
+                yield StreamEvent::Text(turn.text);
+            }
+            for tool_call in turn.tool_calls {
+                yield StreamEvent::ToolCall(tool_call);
+            }
+            yield StreamEvent::Usage(turn.usage);
+            yield StreamEvent::Done;
+        })
+    }
+
+    async fn complete(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+    ) -> Result<ProviderResponse, ProviderError> {
+        self.complete_with_options(messages, tools, system_prompt, &RequestOptions::default())
+            .await
+    }
+
+    async fn complete_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Result<ProviderResponse, ProviderError> {
+        let turn = self.next_turn(messages, &tools, system_prompt, options)?;
+        let finish_reason = if turn.tool_calls.is_empty() {
+            FinishReason::Stop
+        } else {
+            FinishReason::ToolUse
+        };
+
+        Ok(ProviderResponse {
+            content: turn.text,
+            tool_calls: turn.tool_calls,
+            usage: turn.usage,
+            finish_reason,
+            model: options.model_id(&self.model_info).to_string(),
+        })
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn set_model(&mut self, model_id: &str) -> Result<(), ProviderError> {
+        self.model_info = ModelInfo::new(model_id, "mock");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_replays_script() {
+        let provider = MockProvider::new()
+            .with_turn(MockTurn::tool_call(
+                "call-1",
+                "read",
+                json!({ "path": "a" }),
+            ))
+            .with_turn(MockTurn::text("done").with_usage(10, 2));
+
+        let events: Vec<StreamEvent> = provider
+            .stream(vec![Message::user("hi")], Vec::new(), None)
+            .collect()
+            .await;
+        assert!(matches!(&events[0], StreamEvent::ToolCall(call) if call.name == "read"));
+        assert!(matches!(events.last(), Some(StreamEvent::Done)));
+
+        let response = provider
+            .complete(vec![Message::user("again")], Vec::new(), Some("sys".into()))
+            .await
+            .unwrap();
+        assert_eq!(response.content, "done");
+        assert_eq!(response.finish_reason, FinishReason::Stop);
+        assert_eq!(response.usage.input_tokens, 10);
+
+        let requests = provider.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].system_prompt.as_deref(), Some("sys"));
+        assert_eq!(provider.remaining_turns(), 0);
+
+        let exhausted: Vec<StreamEvent> = provider
+            .stream(Vec::new(), Vec::new(), None)
+            .collect()
+            .await;
+        assert!(matches!(&exhausted[0], StreamEvent::Error(_)));
+    }
+}
@@ -3,5 +3,6 @@
 pub mod anthropic;
 pub mod gemini;
 pub mod groq;
+pub mod mock;
 pub mod ollama;
 pub mod openai;
@@ -4,11 +4,12 @@ use crate::{
     error::ProviderError,
     r#trait::{
         ConfigKey, FinishReason, ModelInfo, Provider, ProviderMetadata, ProviderResponse,
-        StreamEvent, TokenUsage,
+        RequestOptions, StreamEvent, TokenUsage,
     },
     Message, MessageRole, ToolCall, ToolDef,
 };
 use async_trait::async_trait;
+use forge_foundation::egress_client;
 use futures::{Stream, TryStreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -40,7 +41,7 @@ impl OllamaProvider {
         let base_url = base_url.into();
 
         Self {
-            client: Client::builder()
+            client: egress_client()
                 .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
                 .build()
                 .expect("Failed to create HTTP client"),
@@ -52,7 +53,7 @@ impl OllamaProvider {
 
     /// Set custom timeout
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
-        self.client = Client::builder()
+        self.client = egress_client()
             .timeout(timeout)
             .build()
             .expect("Failed to create HTTP client");
@@ -129,6 +130,7 @@ impl OllamaProvider {
         tools: &[ToolDef],
         system_prompt: Option<&str>,
         stream: bool,
+        options: &RequestOptions,
     ) -> OllamaRequest {
         let mut api_messages: Vec<OllamaMessage> = vec![];
 
@@ -138,6 +140,7 @@ impl OllamaProvider {
                 role: "system".to_string(),
                 content: system.to_string(),
                 tool_calls: None,
+                images: None,
             });
         }
 
@@ -151,8 +154,14 @@ impl OllamaProvider {
 
         let api_tools: Vec<OllamaTool> = tools.iter().map(|t| t.into()).collect();
 
+        // Ollama has no default output limit, so only send num_predict when requested
+        let num_predict = options.max_tokens.map(|_| {
+            options.resolve_max_tokens(self.model_info.max_output_tokens, &self.model_info)
+        });
+        let stop = options.stop();
+
         OllamaRequest {
-            model: self.model_info.id.clone(),
+            model: options.model_id(&self.model_info).to_string(),
             messages: api_messages,
             tools: if api_tools.is_empty() {
                 None
@@ -160,6 +169,11 @@ impl OllamaProvider {
                 Some(api_tools)
             },
             stream,
+            options: if num_predict.is_some() || stop.is_some() {
+                Some(OllamaOptions { num_predict, stop })
+            } else {
+                None
+            },
         }
     }
 
@@ -370,7 +384,18 @@ impl Provider for OllamaProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), true);
+        self.stream_with_options(messages, tools, system_prompt, &RequestOptions::default())
+    }
+
+    fn stream_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
+        let request =
+            self.build_request(&messages, &tools, system_prompt.as_deref(), true, options);
 
         Box::pin(async_stream::stream! {
             let response = match self
@@ -478,7 +503,19 @@ impl Provider for OllamaProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Result<ProviderResponse, ProviderError> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), false);
+        self.complete_with_options(messages, tools, system_prompt, &RequestOptions::default())
+            .await
+    }
+
+    async fn complete_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Result<ProviderResponse, ProviderError> {
+        let request =
+            self.build_request(&messages, &tools, system_prompt.as_deref(), false, options);
 
         let response = self
             .client
@@ -570,6 +607,16 @@ struct OllamaRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<OllamaTool>>,
     stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<OllamaOptions>,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_predict: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -578,6 +625,8 @@ struct OllamaMessage {
     content: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     tool_calls: Option<Vec<OllamaToolCall>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    images: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -635,6 +684,7 @@ impl From<&Message> for OllamaMessage {
                 role: "tool".to_string(),
                 content: tool_result.content.clone(),
                 tool_calls: None,
+                images: None,
             };
         }
 
@@ -656,10 +706,17 @@ impl From<&Message> for OllamaMessage {
                 .collect()
         });
 
+        let images = if msg.images.is_empty() {
+            None
+        } else {
+            Some(msg.images.iter().map(|image| image.data.clone()).collect())
+        };
+
         OllamaMessage {
             role: role.to_string(),
             content: msg.content.clone(),
             tool_calls,
+            images,
         }
     }
 }
@@ -699,4 +756,26 @@ mod tests {
         assert_eq!(provider.model().id, "codellama:7b");
         assert_eq!(provider.model().context_window, 16384);
     }
+
+    #[test]
+    fn test_request_options() {
+        let provider = OllamaProvider::new("http://localhost:11434", "llama2");
+        let messages = vec![Message::user("hi")];
+
+        let request =
+            provider.build_request(&messages, &[], None, false, &RequestOptions::default());
+        assert!(serde_json::to_value(request)
+            .unwrap()
+            .get("options")
+            .is_none());
+
+        let options = RequestOptions::new()
+            .with_max_tokens(256)
+            .with_stop_sequences(["```"]);
+        let json =
+            serde_json::to_value(provider.build_request(&messages, &[], None, false, &options))
+                .unwrap();
+        assert_eq!(json["options"]["num_predict"], 256);
+        assert_eq!(json["options"]["stop"][0], "```");
+    }
 }
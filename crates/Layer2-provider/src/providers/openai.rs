@@ -4,11 +4,12 @@ use crate::{
     error::ProviderError,
     r#trait::{
         ConfigKey, FinishReason, ModelInfo, Provider, ProviderMetadata, ProviderResponse,
-        StreamEvent, TokenUsage,
+        RequestOptions, StreamEvent, TokenUsage,
     },
     Message, MessageRole, ToolCall, ToolDef,
 };
 use async_trait::async_trait;
+use forge_foundation::egress_client;
 use futures::{Stream, TryStreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -37,7 +38,7 @@ impl OpenAiProvider {
         let model_info = Self::get_model_info(&model_id);
 
         Self {
-            client: Client::builder()
+            client: egress_client()
                 .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
                 .build()
                 .expect("Failed to create HTTP client"),
@@ -57,7 +58,7 @@ impl OpenAiProvider {
 
     /// Set custom timeout
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
-        self.client = Client::builder()
+        self.client = egress_client()
             .timeout(timeout)
             .build()
             .expect("Failed to create HTTP client");
@@ -241,6 +242,7 @@ impl OpenAiProvider {
         tools: &[ToolDef],
         system_prompt: Option<&str>,
         stream: bool,
+        options: &RequestOptions,
     ) -> OpenAiRequest {
         let mut api_messages: Vec<OpenAiMessage> = vec![];
 
@@ -266,9 +268,10 @@ impl OpenAiProvider {
         let api_tools: Vec<OpenAiTool> = tools.iter().map(|t| t.into()).collect();
 
         OpenAiRequest {
-            model: self.model_info.id.clone(),
+            model: options.model_id(&self.model_info).to_string(),
             messages: api_messages,
-            max_tokens: Some(self.max_tokens),
+            max_tokens: Some(options.resolve_max_tokens(self.max_tokens, &self.model_info)),
+            stop: options.stop(),
             tools: if api_tools.is_empty() {
                 None
             } else {
@@ -325,7 +328,18 @@ impl Provider for OpenAiProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), true);
+        self.stream_with_options(messages, tools, system_prompt, &RequestOptions::default())
+    }
+
+    fn stream_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
+        let request =
+            self.build_request(&messages, &tools, system_prompt.as_deref(), true, options);
 
         Box::pin(async_stream::stream! {
             let response = match self
@@ -476,7 +490,19 @@ impl Provider for OpenAiProvider {
         tools: Vec<ToolDef>,
         system_prompt: Option<String>,
     ) -> Result<ProviderResponse, ProviderError> {
-        let request = self.build_request(&messages, &tools, system_prompt.as_deref(), false);
+        self.complete_with_options(messages, tools, system_prompt, &RequestOptions::default())
+            .await
+    }
+
+    async fn complete_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Result<ProviderResponse, ProviderError> {
+        let request =
+            self.build_request(&messages, &tools, system_prompt.as_deref(), false, options);
 
         let response = self
             .client
@@ -593,6 +619,8 @@ struct OpenAiRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     max_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<OpenAiTool>>,
     stream: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -780,7 +808,21 @@ impl From<&Message> for OpenAiMessage {
         });
 
         // Build content
-        let content = if msg.content.is_empty() {
+        let content = if !msg.images.is_empty() {
+            let mut parts = Vec::with_capacity(msg.images.len() + 1);
+            if !msg.content.is_empty() {
+                parts.push(OpenAiContentPart::Text {
+                    text: msg.content.clone(),
+                });
+            }
+            parts.extend(msg.images.iter().map(|image| OpenAiContentPart::ImageUrl {
+                image_url: ImageUrl {
+                    url: image.data_url(),
+                    detail: None,
+                },
+            }));
+            Some(OpenAiContent::Parts(parts))
+        } else if msg.content.is_empty() {
             None
         } else {
             Some(OpenAiContent::Text(msg.content.clone()))
@@ -837,4 +879,52 @@ mod tests {
         assert_eq!(tool_call.id, "call_123");
         assert_eq!(tool_call.name, "read_file");
     }
+
+    #[test]
+    fn test_user_message_with_images() {
+        let msg = Message::user_with_images(
+            "What is in this screenshot?",
+            vec![forge_foundation::ImageAttachment::new("image/png", "aGVsbG8=")],
+        );
+
+        let json = serde_json::to_value(OpenAiMessage::from(&msg)).unwrap();
+        let parts = json["content"].as_array().unwrap();
+        assert_eq!(parts[0]["type"], "text");
+        assert_eq!(parts[1]["type"], "image_url");
+        assert_eq!(parts[1]["image_url"]["url"], "data:image/png;base64,aGVsbG8=");
+    }
+
+    #[test]
+    fn test_request_options() {
+        let provider = OpenAiProvider::new("sk-test", "gpt-4o", 4096);
+        let messages = vec![Message::user("hi")];
+
+        let json = serde_json::to_value(provider.build_request(
+            &messages,
+            &[],
+            None,
+            false,
+            &RequestOptions::default(),
+        ))
+        .unwrap();
+        assert_eq!(json["max_tokens"], 4096);
+        assert!(json.get("stop").is_none());
+
+        // ModelRegistry: gpt-4o max output 16,384
+        let options = RequestOptions::new()
+            .with_max_tokens(100_000)
+            .with_stop_sequences(["</answer>", ""]);
+        let json =
+            serde_json::to_value(provider.build_request(&messages, &[], None, false, &options))
+                .unwrap();
+        assert_eq!(json["max_tokens"], 16_384);
+        assert_eq!(json["stop"], serde_json::json!(["</answer>"]));
+        assert_eq!(json["model"], "gpt-4o");
+
+        let options = RequestOptions::new().with_model("gpt-4o-mini");
+        let json =
+            serde_json::to_value(provider.build_request(&messages, &[], None, false, &options))
+                .unwrap();
+        assert_eq!(json["model"], "gpt-4o-mini");
+    }
 }
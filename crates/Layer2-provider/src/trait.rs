@@ -104,6 +104,86 @@ impl ModelInfo {
     }
 }
 
+/// Per-request generation options
+///
+/// Lets callers constrain a single request without reconfiguring the provider:
+/// cap verbose models with `max_tokens`, stop structured output at a delimiter,
+/// or send one request to another model of the same provider.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestOptions {
+    /// Max output tokens (None = provider default)
+    pub max_tokens: Option<u32>,
+
+    /// Custom stop sequences
+    pub stop_sequences: Vec<String>,
+
+    /// Model ID override (None = the provider's current model)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+impl RequestOptions {
+    /// Create empty options (provider defaults)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set max output tokens
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Set stop sequences
+    pub fn with_stop_sequences<I, S>(mut self, sequences: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stop_sequences = sequences.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the model for this request
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Model ID to send: the override, or the provider's current model
+    pub fn model_id<'a>(&'a self, current: &'a ModelInfo) -> &'a str {
+        self.model.as_deref().unwrap_or(&current.id)
+    }
+
+    /// Resolve max output tokens for a model
+    ///
+    /// Uses the requested value (or `default`) capped to the output limit of the
+    /// model that will be used (see `model_id`) from the ModelRegistry.
+    /// Models not in the registry are not capped.
+    pub fn resolve_max_tokens(&self, default: u32, model: &ModelInfo) -> u32 {
+        let requested = self.max_tokens.unwrap_or(default).max(1);
+        match forge_foundation::model_registry().get(self.model_id(model)) {
+            Some(info) if info.max_output_tokens > 0 => requested.min(info.max_output_tokens),
+            _ => requested,
+        }
+    }
+
+    /// Non-empty stop sequences (None if none are set)
+    pub fn stop(&self) -> Option<Vec<String>> {
+        let sequences: Vec<String> = self
+            .stop_sequences
+            .iter()
+            .filter(|s| !s.is_empty())
+            .cloned()
+            .collect();
+        if sequences.is_empty() {
+            None
+        } else {
+            Some(sequences)
+        }
+    }
+}
+
 /// Provider configuration keys
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConfigKey {
@@ -220,6 +300,34 @@ pub trait Provider: Send + Sync {
         system_prompt: Option<String>,
     ) -> Result<ProviderResponse, ProviderError>;
 
+    /// Streaming request with per-request options (max tokens, stop sequences, model)
+    ///
+    /// The default implementation ignores the options.
+    fn stream_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Pin<Box<dyn Stream<Item = StreamEvent> + Send + '_>> {
+        let _ = options;
+        self.stream(messages, tools, system_prompt)
+    }
+
+    /// Non-streaming request with per-request options (max tokens, stop sequences, model)
+    ///
+    /// The default implementation ignores the options.
+    async fn complete_with_options(
+        &self,
+        messages: Vec<Message>,
+        tools: Vec<ToolDef>,
+        system_prompt: Option<String>,
+        options: &RequestOptions,
+    ) -> Result<ProviderResponse, ProviderError> {
+        let _ = options;
+        self.complete(messages, tools, system_prompt).await
+    }
+
     /// Check if the provider is available (e.g., API key is set)
     fn is_available(&self) -> bool;
 
@@ -0,0 +1,442 @@
+//! Task artifacts - files a task produces that should outlive its working directory
+//!
+//! Tasks declare their outputs up front with `Task::with_artifact` (built binaries,
+//! coverage reports, generated files). After a task finishes, the `TaskManager` asks
+//! the executor where those files live on the host (`Executor::artifact_dir`) and
+//! copies every match into an `ArtifactStore`:
+//!
+//! ```text
+//! .forgecode/artifacts/
+//! ├── objects/3f/3fa9…        # file content, addressed by sha256
+//! └── tasks/<task-uuid>.json  # manifest: name, path, digest, size per artifact
+//! ```
+//!
+//! Identical content produced by different tasks is stored once. The manifests are
+//! what later turns (and the `task_artifact` tool) use to find a task's outputs.
+
+use crate::task::{Task, TaskId};
+use chrono::{DateTime, Utc};
+use forge_foundation::{Error, Result};
+use ring::digest::{Context, SHA256};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+/// Default store location, relative to the project root
+pub const DEFAULT_ARTIFACT_DIR: &str = ".forgecode/artifacts";
+
+/// An output a task declares before it runs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactSpec {
+    /// Name used to look the artifact up later (e.g. "binary", "coverage")
+    pub name: String,
+
+    /// File, directory or glob pattern, relative to the task's working directory
+    pub path: String,
+}
+
+impl ArtifactSpec {
+    /// Declare an artifact
+    pub fn new(name: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            path: path.into(),
+        }
+    }
+}
+
+/// A collected artifact
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Artifact {
+    /// Task that produced the artifact
+    pub task_id: TaskId,
+
+    /// Declared name (`ArtifactSpec::name`)
+    pub name: String,
+
+    /// Path relative to the task's working directory ('/' separated)
+    pub path: String,
+
+    /// sha256 of the content (hex)
+    pub digest: String,
+
+    /// Size in bytes
+    pub size: u64,
+
+    /// When the artifact was collected
+    pub collected_at: DateTime<Utc>,
+}
+
+impl Artifact {
+    /// Abbreviated digest for display
+    pub fn short_digest(&self) -> &str {
+        &self.digest[..self.digest.len().min(12)]
+    }
+}
+
+/// Content-addressed artifact storage
+#[derive(Debug, Clone)]
+pub struct ArtifactStore {
+    root: PathBuf,
+}
+
+impl ArtifactStore {
+    /// Create a store rooted at `root`
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Store under `<project_dir>/.forgecode/artifacts`
+    pub fn for_project(project_dir: &Path) -> Self {
+        Self::new(project_dir.join(DEFAULT_ARTIFACT_DIR))
+    }
+
+    /// Store root directory
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Collect `task.artifacts` from `working_dir` and record the task's manifest
+    ///
+    /// Specs that match nothing are skipped; compare the returned names with
+    /// `task.artifacts` to report them.
+    pub fn collect(&self, task: &Task, working_dir: &Path) -> Result<Vec<Artifact>> {
+        let mut artifacts = Vec::new();
+        for spec in &task.artifacts {
+            for (source, relative) in resolve_spec(spec, working_dir)? {
+                artifacts.push(self.store_file(task.id, &spec.name, &relative, &source)?);
+            }
+        }
+
+        if !artifacts.is_empty() {
+            self.record(task.id, &artifacts)?;
+        }
+        Ok(artifacts)
+    }
+
+    /// Copy one file into the store
+    pub fn store_file(
+        &self,
+        task_id: TaskId,
+        name: &str,
+        relative: &str,
+        source: &Path,
+    ) -> Result<Artifact> {
+        let digest = hash_file(source)?;
+        let object = self.object_path(&digest);
+
+        if !object.exists() {
+            if let Some(parent) = object.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            // Copy then rename so a concurrent reader never sees a partial object
+            let partial = object.with_extension(format!("partial-{}", uuid::Uuid::new_v4()));
+            std::fs::copy(source, &partial)?;
+            if let Err(e) = std::fs::rename(&partial, &object) {
+                let _ = std::fs::remove_file(&partial);
+                return Err(e.into());
+            }
+        }
+
+        Ok(Artifact {
+            task_id,
+            name: name.to_string(),
+            path: relative.to_string(),
+            size: std::fs::metadata(&object)?.len(),
+            digest,
+            collected_at: Utc::now(),
+        })
+    }
+
+    /// Write the manifest for a task (replaces any previous one)
+    pub fn record(&self, task_id: TaskId, artifacts: &[Artifact]) -> Result<()> {
+        let path = self.manifest_path(task_id);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_vec_pretty(artifacts)?)?;
+        Ok(())
+    }
+
+    /// Artifacts recorded for a task (empty if none)
+    pub fn list(&self, task_id: TaskId) -> Result<Vec<Artifact>> {
+        match std::fs::read(self.manifest_path(task_id)) {
+            Ok(data) => Ok(serde_json::from_slice(&data)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Tasks with recorded artifacts, most recent first
+    pub fn tasks(&self) -> Result<Vec<TaskId>> {
+        let dir = self.root.join("tasks");
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut tasks = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(uuid) = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| uuid::Uuid::parse_str(s).ok())
+            else {
+                continue;
+            };
+            let modified = entry.metadata().and_then(|m| m.modified()).ok();
+            tasks.push((modified, TaskId(uuid)));
+        }
+
+        tasks.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+        Ok(tasks.into_iter().map(|(_, id)| id).collect())
+    }
+
+    /// Find a task by full id or id prefix (e.g. the 8-character display id)
+    pub fn find_task(&self, prefix: &str) -> Result<Option<TaskId>> {
+        let prefix = prefix.trim().to_lowercase();
+        if prefix.is_empty() {
+            return Ok(None);
+        }
+
+        let matches: Vec<TaskId> = self
+            .tasks()?
+            .into_iter()
+            .filter(|id| id.0.to_string().starts_with(&prefix))
+            .collect();
+        match matches.as_slice() {
+            [] => Ok(None),
+            [id] => Ok(Some(*id)),
+            _ => Err(Error::InvalidInput(format!(
+                "Task id '{}' is ambiguous ({} tasks match)",
+                prefix,
+                matches.len()
+            ))),
+        }
+    }
+
+    /// Look up an artifact by path, or by name when the name matches a single file
+    pub fn get(&self, task_id: TaskId, query: &str) -> Result<Option<Artifact>> {
+        let artifacts = self.list(task_id)?;
+        if let Some(artifact) = artifacts.iter().find(|a| a.path == query) {
+            return Ok(Some(artifact.clone()));
+        }
+
+        let named: Vec<&Artifact> = artifacts.iter().filter(|a| a.name == query).collect();
+        match named.as_slice() {
+            [] => Ok(None),
+            [artifact] => Ok(Some((*artifact).clone())),
+            _ => Err(Error::InvalidInput(format!(
+                "Artifact '{}' has {} files; pass one of their paths: {}",
+                query,
+                named.len(),
+                named
+                    .iter()
+                    .map(|a| a.path.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
+    /// Stored content path for a digest
+    pub fn object_path(&self, digest: &str) -> PathBuf {
+        let prefix = &digest[..digest.len().min(2)];
+        self.root.join("objects").join(prefix).join(digest)
+    }
+
+    /// Read an artifact's content
+    pub fn read(&self, artifact: &Artifact) -> Result<Vec<u8>> {
+        match std::fs::read(self.object_path(&artifact.digest)) {
+            Ok(data) => Ok(data),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(Error::NotFound(format!(
+                "Artifact content missing from store: {} ({})",
+                artifact.path, artifact.digest
+            ))),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn manifest_path(&self, task_id: TaskId) -> PathBuf {
+        self.root.join("tasks").join(format!("{}.json", task_id.0))
+    }
+}
+
+/// Files matched by a spec, as (absolute path, path relative to `working_dir`)
+fn resolve_spec(spec: &ArtifactSpec, working_dir: &Path) -> Result<Vec<(PathBuf, String)>> {
+    let relative = Path::new(&spec.path);
+    if spec.path.is_empty()
+        || relative
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(Error::InvalidInput(format!(
+            "Artifact '{}' path must stay inside the working directory: {}",
+            spec.name, spec.path
+        )));
+    }
+
+    let mut roots = Vec::new();
+    if spec.path.contains(['*', '?', '[']) {
+        let pattern = format!(
+            "{}/{}",
+            glob::Pattern::escape(&working_dir.to_string_lossy()),
+            spec.path
+        );
+        let paths = glob::glob(&pattern).map_err(|e| {
+            Error::InvalidInput(format!("Invalid artifact pattern '{}': {}", spec.path, e))
+        })?;
+        roots.extend(paths.flatten());
+    } else {
+        let path = working_dir.join(relative);
+        if path.exists() {
+            roots.push(path);
+        }
+    }
+
+    let mut files = Vec::new();
+    for root in roots {
+        collect_files(&root, &mut files)?;
+    }
+    files.sort();
+    files.dedup();
+
+    Ok(files
+        .into_iter()
+        .filter_map(|path| {
+            let relative = path.strip_prefix(working_dir).ok()?;
+            let relative = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            Some((path, relative))
+        })
+        .collect())
+}
+
+/// Files at `path` (the path itself, or everything below a directory)
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let metadata = std::fs::metadata(path)?;
+    if metadata.is_file() {
+        files.push(path.to_path_buf());
+    } else if metadata.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            // Symlinked directories are not followed (loops)
+            if entry.file_type()?.is_symlink() && entry.path().is_dir() {
+                continue;
+            }
+            collect_files(&entry.path(), files)?;
+        }
+    }
+    Ok(())
+}
+
+/// sha256 of a file (hex)
+fn hash_file(path: &Path) -> Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut context = Context::new(&SHA256);
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        context.update(&buf[..n]);
+    }
+    Ok(context
+        .finish()
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("forge-artifact-{}-{}", name, uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_collect_and_lookup() {
+        let workdir = temp_dir("work");
+        let store = ArtifactStore::new(temp_dir("store"));
+        write(&workdir, "target/release/app", "\x7fELF binary");
+        write(&workdir, "coverage/index.html", "<html>coverage</html>");
+        write(&workdir, "coverage/lcov.info", "TN:\n");
+        write(&workdir, "gen/a.rs", "// a");
+        write(&workdir, "gen/b.rs", "// a");
+
+        let task = Task::new("session", "bash", "cargo build", serde_json::json!({}))
+            .with_artifact(ArtifactSpec::new("binary", "target/release/app"))
+            .with_artifact(ArtifactSpec::new("coverage", "coverage"))
+            .with_artifact(ArtifactSpec::new("generated", "gen/*.rs"))
+            .with_artifact(ArtifactSpec::new("logs", "*.log"));
+
+        let artifacts = store.collect(&task, &workdir).unwrap();
+        let paths: Vec<_> = artifacts.iter().map(|a| a.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "target/release/app",
+                "coverage/index.html",
+                "coverage/lcov.info",
+                "gen/a.rs",
+                "gen/b.rs"
+            ]
+        );
+        assert!(!artifacts.iter().any(|a| a.name == "logs"));
+
+        // Identical content is stored once
+        assert_eq!(artifacts[3].digest, artifacts[4].digest);
+        let objects: usize = std::fs::read_dir(store.root().join("objects"))
+            .unwrap()
+            .map(|d| std::fs::read_dir(d.unwrap().path()).unwrap().count())
+            .sum();
+        assert_eq!(objects, 4);
+
+        assert_eq!(store.list(task.id).unwrap(), artifacts);
+        assert_eq!(
+            store.find_task(&task.id.to_string()).unwrap(),
+            Some(task.id)
+        );
+
+        let binary = store.get(task.id, "binary").unwrap().unwrap();
+        assert_eq!(store.read(&binary).unwrap(), b"\x7fELF binary");
+        assert_eq!(binary.size, 11);
+        assert!(store.get(task.id, "coverage").is_err());
+        assert!(store.get(task.id, "coverage/lcov.info").unwrap().is_some());
+        assert!(store.get(task.id, "missing").unwrap().is_none());
+
+        let _ = std::fs::remove_dir_all(&workdir);
+        let _ = std::fs::remove_dir_all(store.root());
+    }
+
+    #[test]
+    fn test_paths_must_stay_in_working_dir() {
+        let workdir = temp_dir("escape");
+        for path in ["../secret", "/etc/passwd", ""] {
+            let spec = ArtifactSpec::new("bad", path);
+            assert!(resolve_spec(&spec, &workdir).is_err(), "{}", path);
+        }
+        let _ = std::fs::remove_dir_all(&workdir);
+    }
+}
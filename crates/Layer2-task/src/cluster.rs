@@ -5,10 +5,12 @@
 //! Features:
 //! - Multi-server management
 //! - API health checking and validation
-//! - Load balancing strategies
+//! - Load balancing strategies (weighted, latency-aware)
+//! - Sticky sessions (keep a sub-agent on the server holding its session state)
 //! - Automatic failover
 //! - Server discovery
 
+use crate::subagent::SubAgentId;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -57,6 +59,13 @@ pub struct ServerInfo {
     pub capabilities: Vec<String>,
     /// Server metadata
     pub metadata: HashMap<String, String>,
+    /// Relative capacity for weighted strategies (default: 1)
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+}
+
+fn default_weight() -> u32 {
+    1
 }
 
 impl ServerInfo {
@@ -69,6 +78,7 @@ impl ServerInfo {
             api_key: None,
             capabilities: vec![],
             metadata: HashMap::new(),
+            weight: default_weight(),
         }
     }
 
@@ -86,6 +96,12 @@ impl ServerInfo {
         self.capabilities.push(cap.into());
         self
     }
+
+    /// Set the routing weight (0 is treated as 1)
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight.max(1);
+        self
+    }
 }
 
 /// Health check result
@@ -124,6 +140,8 @@ pub struct ServerState {
     pub total_requests: AtomicU64,
     pub active_requests: AtomicU64,
     pub total_errors: AtomicU64,
+    /// Smoothed request latency in microseconds (0 = not measured yet)
+    pub latency_ewma_us: AtomicU64,
     pub added_at: Instant,
 }
 
@@ -137,6 +155,7 @@ impl ServerState {
             total_requests: AtomicU64::new(0),
             active_requests: AtomicU64::new(0),
             total_errors: AtomicU64::new(0),
+            latency_ewma_us: AtomicU64::new(0),
             added_at: Instant::now(),
         }
     }
@@ -158,8 +177,38 @@ impl ServerState {
             .map(|l| l as f64)
             .unwrap_or(0.0)
     }
+
+    /// Observed latency: smoothed request latency, else the last health check
+    pub fn observed_latency(&self) -> Option<Duration> {
+        match self.latency_ewma_us.load(Ordering::Relaxed) {
+            0 => self.last_health_check.as_ref().map(|h| h.latency),
+            us => Some(Duration::from_micros(us)),
+        }
+    }
+
+    /// Fold a request latency into the moving average
+    fn record_latency(&self, latency: Duration) {
+        let sample = (latency.as_micros() as u64).max(1);
+        let _ =
+            self.latency_ewma_us
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                    Some(match current {
+                        0 => sample,
+                        _ => (LATENCY_EWMA_ALPHA * sample as f64
+                            + (1.0 - LATENCY_EWMA_ALPHA) * current as f64)
+                            .max(1.0) as u64,
+                    })
+                });
+    }
+
+    fn weight(&self) -> u32 {
+        self.info.weight.max(1)
+    }
 }
 
+/// Smoothing factor for request latency (higher = reacts faster)
+const LATENCY_EWMA_ALPHA: f64 = 0.3;
+
 /// Load balancing strategy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LoadBalanceStrategy {
@@ -171,9 +220,13 @@ pub enum LoadBalanceStrategy {
     LeastLatency,
     /// Select server with lowest load
     LeastLoad,
-    /// Weighted random selection
+    /// Weighted random selection (`ServerInfo::weight` scaled by spare load)
     WeightedRandom,
-    /// Hash-based sticky selection
+    /// Round-robin proportional to `ServerInfo::weight`
+    WeightedRoundRobin,
+    /// Lowest observed latency x in-flight requests, per unit of weight
+    LatencyAware,
+    /// Hash-based sticky selection (weighted rendezvous hashing on the sticky key)
     Sticky,
 }
 
@@ -200,6 +253,8 @@ pub struct ClusterConfig {
     pub retry_count: u32,
     /// Retry delay
     pub retry_delay: Duration,
+    /// How long an idle sticky session keeps its server
+    pub sticky_ttl: Duration,
 }
 
 impl Default for ClusterConfig {
@@ -212,6 +267,7 @@ impl Default for ClusterConfig {
             auto_failover: true,
             retry_count: 2,
             retry_delay: Duration::from_millis(500),
+            sticky_ttl: Duration::from_secs(30 * 60),
         }
     }
 }
@@ -381,6 +437,24 @@ impl HealthChecker for HttpHealthChecker {
     }
 }
 
+/// Server assignment for a sticky key
+#[derive(Debug, Clone)]
+struct StickySession {
+    server_id: String,
+    last_used: Instant,
+}
+
+/// Weighted rendezvous (highest random weight) score of a server for a key
+fn rendezvous_score(key: &str, server: &ServerState) -> f64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    key.hash(&mut hasher);
+    server.info.id.hash(&mut hasher);
+    // Map the hash into (0, 1)
+    let unit = (hasher.finish() as f64 + 1.0) / (u64::MAX as f64 + 2.0);
+    server.weight() as f64 / -unit.ln()
+}
+
 /// Task Server Cluster
 pub struct TaskCluster {
     /// Cluster configuration
@@ -391,6 +465,8 @@ pub struct TaskCluster {
     health_checker: Arc<dyn HealthChecker>,
     /// Round-robin counter
     rr_counter: AtomicUsize,
+    /// Sticky key -> assigned server
+    sticky_sessions: RwLock<HashMap<String, StickySession>>,
     /// Health check task handle
     health_check_handle: RwLock<Option<tokio::task::JoinHandle<()>>>,
 }
@@ -404,6 +480,7 @@ impl TaskCluster {
             servers: Arc::new(RwLock::new(HashMap::new())),
             health_checker,
             rr_counter: AtomicUsize::new(0),
+            sticky_sessions: RwLock::new(HashMap::new()),
             health_check_handle: RwLock::new(None),
         }
     }
@@ -453,6 +530,11 @@ impl TaskCluster {
             .remove(server_id)
             .ok_or_else(|| ClusterError::ServerNotFound(server_id.to_string()))?;
 
+        self.sticky_sessions
+            .write()
+            .await
+            .retain(|_, s| s.server_id != server_id);
+
         info!("Removed server {} from cluster", server_id);
         Ok(state.info)
     }
@@ -489,40 +571,113 @@ impl TaskCluster {
     }
 
     /// Select a server using the configured strategy
+    ///
+    /// With a sticky key, the first selection is remembered and later calls return the
+    /// same server while it stays available and the session is used within
+    /// `ClusterConfig::sticky_ttl`. If that server goes away the key is reassigned.
     pub async fn select_server(&self, sticky_key: Option<&str>) -> Option<ServerInfo> {
+        self.select(sticky_key, None, &[]).await
+    }
+
+    /// Select a server with capability requirement
+    pub async fn select_server_with_capability(&self, capability: &str) -> Option<ServerInfo> {
+        self.select(None, Some(capability), &[]).await
+    }
+
+    /// Server currently bound to a sticky key
+    pub async fn sticky_server(&self, key: &str) -> Option<String> {
+        let sessions = self.sticky_sessions.read().await;
+        sessions
+            .get(key)
+            .filter(|s| s.last_used.elapsed() < self.config.sticky_ttl)
+            .map(|s| s.server_id.clone())
+    }
+
+    /// Forget a sticky session (e.g. when a sub-agent finishes)
+    pub async fn release_sticky(&self, key: &str) {
+        self.sticky_sessions.write().await.remove(key);
+    }
+
+    /// Select among available servers, skipping `exclude`
+    async fn select(
+        &self,
+        sticky_key: Option<&str>,
+        capability: Option<&str>,
+        exclude: &[String],
+    ) -> Option<ServerInfo> {
         let servers = self.servers.read().await;
-        let available: Vec<_> = servers
-            .values()
-            .filter(|s| s.status.is_available())
-            .collect();
+        let eligible = |s: &ServerState| {
+            s.status.is_available()
+                && !exclude.contains(&s.info.id)
+                && capability.is_none_or(|cap| s.info.capabilities.iter().any(|c| c == cap))
+        };
+        let mut available: Vec<&ServerState> = servers.values().filter(|s| eligible(s)).collect();
+        // HashMap order is arbitrary; keep rotation and weighting deterministic
+        available.sort_by(|a, b| a.info.id.cmp(&b.info.id));
+
+        let Some(key) = sticky_key else {
+            return self.pick(&available, None);
+        };
+
+        let now = Instant::now();
+        let mut sessions = self.sticky_sessions.write().await;
+        sessions.retain(|_, s| now.duration_since(s.last_used) < self.config.sticky_ttl);
+
+        if let Some(session) = sessions.get_mut(key) {
+            if let Some(state) = servers.get(&session.server_id).filter(|s| eligible(s)) {
+                session.last_used = now;
+                return Some(state.info.clone());
+            }
+            debug!(
+                "Sticky session {} moving off server {}",
+                key, session.server_id
+            );
+        }
+
+        let selected = self.pick(&available, Some(key))?;
+        sessions.insert(
+            key.to_string(),
+            StickySession {
+                server_id: selected.id.clone(),
+                last_used: now,
+            },
+        );
+        Some(selected)
+    }
 
+    /// Apply the load balancing strategy
+    fn pick(&self, available: &[&ServerState], sticky_key: Option<&str>) -> Option<ServerInfo> {
         if available.is_empty() {
             return None;
         }
 
+        let least_connections = || {
+            available
+                .iter()
+                .min_by_key(|s| s.active_requests.load(Ordering::Relaxed))
+        };
+
         let selected = match self.config.strategy {
             LoadBalanceStrategy::RoundRobin => {
                 let idx = self.rr_counter.fetch_add(1, Ordering::Relaxed) % available.len();
                 available.get(idx)
             }
-            LoadBalanceStrategy::LeastConnections => available
+            LoadBalanceStrategy::LeastConnections => least_connections(),
+            LoadBalanceStrategy::LeastLatency => available
                 .iter()
-                .min_by_key(|s| s.active_requests.load(Ordering::Relaxed)),
-            LoadBalanceStrategy::LeastLatency => available.iter().min_by_key(|s| {
-                s.last_health_check
-                    .as_ref()
-                    .map(|h| h.latency)
-                    .unwrap_or(Duration::MAX)
-            }),
+                .min_by_key(|s| s.observed_latency().unwrap_or(Duration::MAX)),
             LoadBalanceStrategy::LeastLoad => available.iter().min_by(|a, b| {
                 a.current_load()
                     .partial_cmp(&b.current_load())
                     .unwrap_or(std::cmp::Ordering::Equal)
             }),
             LoadBalanceStrategy::WeightedRandom => {
-                // Simple weighted random based on inverse load
+                // Weight scaled by spare capacity
                 use rand::Rng;
-                let weights: Vec<f64> = available.iter().map(|s| 1.0 - s.current_load()).collect();
+                let weights: Vec<f64> = available
+                    .iter()
+                    .map(|s| s.weight() as f64 * (1.0 - s.current_load()).max(0.0))
+                    .collect();
                 let total: f64 = weights.iter().sum();
 
                 if total > 0.0 {
@@ -540,44 +695,46 @@ impl TaskCluster {
                     available.first()
                 }
             }
-            LoadBalanceStrategy::Sticky => {
-                if let Some(key) = sticky_key {
-                    // Hash the key to select a server
-                    use std::hash::{Hash, Hasher};
-                    let mut hasher = std::collections::hash_map::DefaultHasher::new();
-                    key.hash(&mut hasher);
-                    let hash = hasher.finish() as usize;
-                    available.get(hash % available.len())
-                } else {
-                    available.first()
-                }
+            LoadBalanceStrategy::WeightedRoundRobin => {
+                let total: u64 = available.iter().map(|s| s.weight() as u64).sum();
+                let mut slot = self.rr_counter.fetch_add(1, Ordering::Relaxed) as u64 % total;
+                available.iter().find(|s| {
+                    let weight = s.weight() as u64;
+                    if slot < weight {
+                        true
+                    } else {
+                        slot -= weight;
+                        false
+                    }
+                })
             }
+            LoadBalanceStrategy::LatencyAware => {
+                // Unmeasured servers score 0 so they get probed first
+                let score = |s: &ServerState| {
+                    let latency = s.observed_latency().unwrap_or(Duration::ZERO).as_secs_f64();
+                    let in_flight = s.active_requests.load(Ordering::Relaxed) as f64 + 1.0;
+                    latency * in_flight / s.weight() as f64
+                };
+                available.iter().min_by(|a, b| {
+                    score(a)
+                        .partial_cmp(&score(b))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+            }
+            LoadBalanceStrategy::Sticky => match sticky_key {
+                // Weighted rendezvous hashing: only keys of a removed server move
+                Some(key) => available.iter().max_by(|a, b| {
+                    rendezvous_score(key, a)
+                        .partial_cmp(&rendezvous_score(key, b))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                }),
+                None => least_connections(),
+            },
         };
 
         selected.map(|s| s.info.clone())
     }
 
-    /// Select a server with capability requirement
-    pub async fn select_server_with_capability(&self, capability: &str) -> Option<ServerInfo> {
-        let servers = self.servers.read().await;
-        let available: Vec<_> = servers
-            .values()
-            .filter(|s| {
-                s.status.is_available() && s.info.capabilities.contains(&capability.to_string())
-            })
-            .collect();
-
-        if available.is_empty() {
-            return None;
-        }
-
-        // Use least connections for capability-based selection
-        available
-            .iter()
-            .min_by_key(|s| s.active_requests.load(Ordering::Relaxed))
-            .map(|s| s.info.clone())
-    }
-
     /// Record a request to a server
     pub async fn record_request(&self, server_id: &str) {
         let servers = self.servers.read().await;
@@ -598,6 +755,18 @@ impl TaskCluster {
         }
     }
 
+    /// Record request completion with its latency (feeds latency-aware routing)
+    pub async fn record_response(&self, server_id: &str, success: bool, latency: Duration) {
+        let servers = self.servers.read().await;
+        if let Some(state) = servers.get(server_id) {
+            state.active_requests.fetch_sub(1, Ordering::Relaxed);
+            if !success {
+                state.total_errors.fetch_add(1, Ordering::Relaxed);
+            }
+            state.record_latency(latency);
+        }
+    }
+
     /// Run health check on all servers
     pub async fn check_health(&self) {
         let server_ids: Vec<String> = {
@@ -740,6 +909,14 @@ impl TaskCluster {
             total_errors += state.total_errors.load(Ordering::Relaxed);
         }
 
+        let sticky_sessions = self
+            .sticky_sessions
+            .read()
+            .await
+            .values()
+            .filter(|s| s.last_used.elapsed() < self.config.sticky_ttl)
+            .count();
+
         ClusterStats {
             total_servers: total,
             healthy_servers: healthy,
@@ -753,6 +930,7 @@ impl TaskCluster {
             } else {
                 0.0
             },
+            sticky_sessions,
         }
     }
 }
@@ -768,6 +946,7 @@ pub struct ClusterStats {
     pub active_requests: u64,
     pub total_errors: u64,
     pub error_rate: f64,
+    pub sticky_sessions: usize,
 }
 
 /// Cluster error types
@@ -832,6 +1011,17 @@ impl RequestContext {
         self.preferred_server = Some(server.into());
         self
     }
+
+    /// Keep all requests of a sub-agent on one server (server-side session state,
+    /// e.g. vLLM prefix caching)
+    pub fn for_subagent(self, id: &SubAgentId) -> Self {
+        self.with_sticky_key(Self::subagent_key(id))
+    }
+
+    /// Sticky key used by `for_subagent` (pass to `TaskCluster::release_sticky`)
+    pub fn subagent_key(id: &SubAgentId) -> String {
+        format!("subagent:{}", id.0)
+    }
 }
 
 /// Cluster-aware task executor
@@ -859,6 +1049,8 @@ impl ClusterExecutor {
     {
         let mut attempts = 0;
         let mut last_error: Option<String> = None;
+        // Servers that failed this request; retries (and sticky sessions) move elsewhere
+        let mut failed: Vec<String> = Vec::new();
 
         while attempts <= self.config.retry_count {
             // Select server
@@ -868,10 +1060,16 @@ impl ClusterExecutor {
                     .into_iter()
                     .find(|(s, status)| s.id == *preferred && status.is_available())
                     .map(|(s, _)| s)
-            } else if let Some(ref cap) = ctx.required_capability {
-                self.cluster.select_server_with_capability(cap).await
             } else {
-                self.cluster.select_server(ctx.sticky_key.as_deref()).await
+                let sticky_key = ctx.sticky_key.as_deref();
+                let capability = ctx.required_capability.as_deref();
+                match self.cluster.select(sticky_key, capability, &failed).await {
+                    // Only one candidate left: retry it rather than give up
+                    None if !failed.is_empty() => {
+                        self.cluster.select(sticky_key, capability, &[]).await
+                    }
+                    server => server,
+                }
             };
 
             let server = match server {
@@ -888,15 +1086,24 @@ impl ClusterExecutor {
             self.cluster.record_request(&server.id).await;
 
             // Execute
+            let started = Instant::now();
             let result = task(&server).await;
+            let latency = started.elapsed();
 
             match result {
                 Ok(value) => {
-                    self.cluster.record_completion(&server.id, true).await;
+                    self.cluster
+                        .record_response(&server.id, true, latency)
+                        .await;
                     return Ok(value);
                 }
                 Err(e) => {
-                    self.cluster.record_completion(&server.id, false).await;
+                    self.cluster
+                        .record_response(&server.id, false, latency)
+                        .await;
+                    if !failed.contains(&server.id) {
+                        failed.push(server.id.clone());
+                    }
                     last_error = Some(e.to_string());
                     warn!(
                         "Task failed on server {}: {} (attempt {})",
@@ -1012,6 +1219,160 @@ mod tests {
         assert_eq!(stats.total_errors, 1);
     }
 
+    fn cluster_with(strategy: LoadBalanceStrategy) -> Arc<TaskCluster> {
+        Arc::new(TaskCluster::new(ClusterConfig {
+            strategy,
+            retry_delay: Duration::from_millis(1),
+            ..Default::default()
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_weighted_round_robin() {
+        let cluster = cluster_with(LoadBalanceStrategy::WeightedRoundRobin);
+        cluster
+            .add_server(ServerInfo::new("big", "http://localhost:8081").with_weight(3))
+            .await
+            .unwrap();
+        cluster
+            .add_server(ServerInfo::new("small", "http://localhost:8082"))
+            .await
+            .unwrap();
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for _ in 0..8 {
+            let server = cluster.select_server(None).await.unwrap();
+            *counts.entry(server.id).or_default() += 1;
+        }
+        assert_eq!(counts["big"], 6);
+        assert_eq!(counts["small"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_latency_aware_routing() {
+        let cluster = cluster_with(LoadBalanceStrategy::LatencyAware);
+        for id in ["fast", "slow"] {
+            cluster
+                .add_server(ServerInfo::new(id, "http://localhost:8080"))
+                .await
+                .unwrap();
+        }
+
+        for (id, ms) in [("fast", 5), ("slow", 200)] {
+            cluster.record_request(id).await;
+            cluster
+                .record_response(id, true, Duration::from_millis(ms))
+                .await;
+        }
+        assert_eq!(cluster.select_server(None).await.unwrap().id, "fast");
+
+        // In-flight requests make a fast server look busier
+        for _ in 0..50 {
+            cluster.record_request("fast").await;
+        }
+        assert_eq!(cluster.select_server(None).await.unwrap().id, "slow");
+    }
+
+    #[tokio::test]
+    async fn test_sticky_sessions() {
+        let cluster = cluster_with(LoadBalanceStrategy::RoundRobin);
+        for id in ["server-1", "server-2", "server-3"] {
+            cluster
+                .add_server(ServerInfo::new(id, "http://localhost:8080"))
+                .await
+                .unwrap();
+        }
+
+        let agent = SubAgentId::new();
+        let key = RequestContext::subagent_key(&agent);
+        let bound = cluster.select_server(Some(&key)).await.unwrap().id;
+        for _ in 0..5 {
+            // Unrelated traffic keeps rotating
+            cluster.select_server(None).await.unwrap();
+            assert_eq!(cluster.select_server(Some(&key)).await.unwrap().id, bound);
+        }
+        assert_eq!(cluster.sticky_server(&key).await, Some(bound.clone()));
+        assert_eq!(cluster.stats().await.sticky_sessions, 1);
+
+        // Draining moves the session, which then stays on its new server
+        cluster.drain_server(&bound).await.unwrap();
+        let moved = cluster.select_server(Some(&key)).await.unwrap().id;
+        assert_ne!(moved, bound);
+        assert_eq!(cluster.select_server(Some(&key)).await.unwrap().id, moved);
+
+        cluster.release_sticky(&key).await;
+        assert_eq!(cluster.sticky_server(&key).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_sticky_ttl_expires() {
+        let cluster = TaskCluster::new(ClusterConfig {
+            sticky_ttl: Duration::ZERO,
+            ..Default::default()
+        });
+        cluster
+            .add_server(ServerInfo::new("server-1", "http://localhost:8080"))
+            .await
+            .unwrap();
+
+        cluster.select_server(Some("session")).await.unwrap();
+        assert_eq!(cluster.sticky_server("session").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_rendezvous_hashing_is_stable() {
+        let three = cluster_with(LoadBalanceStrategy::Sticky);
+        let two = cluster_with(LoadBalanceStrategy::Sticky);
+        for id in ["server-1", "server-2", "server-3"] {
+            let info = ServerInfo::new(id, "http://localhost:8080");
+            three.add_server(info.clone()).await.unwrap();
+            if id != "server-3" {
+                two.add_server(info).await.unwrap();
+            }
+        }
+
+        for n in 0..50 {
+            let key = format!("session-{}", n);
+            let before = three.select_server(Some(&key)).await.unwrap().id;
+            let after = two.select_server(Some(&key)).await.unwrap().id;
+            if before != "server-3" {
+                assert_eq!(before, after, "{} moved", key);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_executor_failover_moves_sticky_session() {
+        let cluster = cluster_with(LoadBalanceStrategy::LeastConnections);
+        for id in ["server-1", "server-2"] {
+            cluster
+                .add_server(ServerInfo::new(id, "http://localhost:8080"))
+                .await
+                .unwrap();
+        }
+
+        let agent = SubAgentId::new();
+        let key = RequestContext::subagent_key(&agent);
+        let bound = cluster.select_server(Some(&key)).await.unwrap().id;
+
+        let executor = ClusterExecutor::new(Arc::clone(&cluster));
+        let failing = bound.clone();
+        let served = executor
+            .execute(RequestContext::new().for_subagent(&agent), move |server| {
+                let result = if server.id == failing {
+                    Err("connection reset")
+                } else {
+                    Ok(server.id.clone())
+                };
+                Box::pin(async move { result })
+            })
+            .await
+            .unwrap();
+
+        assert_ne!(served, bound);
+        assert_eq!(cluster.sticky_server(&key).await, Some(served));
+    }
+
     #[test]
     fn test_server_status() {
         assert!(ServerStatus::Healthy.is_available());
@@ -11,6 +11,7 @@ use bollard::Docker;
 use forge_foundation::{Error, Result};
 use futures::StreamExt;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tokio::time::timeout;
@@ -20,8 +21,8 @@ const DEFAULT_IMAGE: &str = "ubuntu:22.04";
 
 /// Container executor that runs tasks in Docker containers
 pub struct ContainerExecutor {
-    /// Docker client
-    docker: Arc<Docker>,
+    /// Docker client (None if the Docker client could not be configured)
+    docker: Option<Arc<Docker>>,
 
     /// Running container IDs by task ID
     containers: Arc<Mutex<HashMap<String, String>>>,
@@ -33,21 +34,14 @@ pub struct ContainerExecutor {
 impl ContainerExecutor {
     /// Create a new container executor
     pub async fn new() -> Self {
-        let docker = Docker::connect_with_local_defaults();
-
-        let (docker, available) = match docker {
+        // Docker가 없어도 Local/PTY 실행은 가능해야 하므로 실패 시 비활성화만 함
+        let (docker, available) = match Docker::connect_with_local_defaults() {
             Ok(d) => {
                 // Test connection
                 let available = d.ping().await.is_ok();
-                (Arc::new(d), available)
+                (Some(Arc::new(d)), available)
             }
-            Err(_) => (
-                Arc::new(Docker::connect_with_local_defaults().unwrap_or_else(|_| {
-                    // Create a dummy client (will fail on use)
-                    Docker::connect_with_local_defaults().unwrap()
-                })),
-                false,
-            ),
+            Err(_) => (None, false),
         };
 
         Self {
@@ -57,6 +51,13 @@ impl ContainerExecutor {
         }
     }
 
+    /// Docker client (unavailable → error)
+    fn docker(&self) -> Result<&Docker> {
+        self.docker
+            .as_deref()
+            .ok_or_else(|| Error::Task("Docker is not available".to_string()))
+    }
+
     /// Create a container for the task
     async fn create_container(&self, task: &Task) -> Result<String> {
         let (image, workdir, env, volumes) = match &task.execution_mode {
@@ -102,13 +103,13 @@ impl ContainerExecutor {
 
         // Create container
         let response = self
-            .docker
+            .docker()?
             .create_container(Some(options), config)
             .await
             .map_err(|e| Error::Task(format!("Failed to create container: {}", e)))?;
 
         // Start container
-        self.docker
+        self.docker()?
             .start_container(&response.id, None::<StartContainerOptions<String>>)
             .await
             .map_err(|e| Error::Task(format!("Failed to start container: {}", e)))?;
@@ -126,13 +127,13 @@ impl ContainerExecutor {
         };
 
         let exec = self
-            .docker
+            .docker()?
             .create_exec(container_id, exec_options)
             .await
             .map_err(|e| Error::Task(format!("Failed to create exec: {}", e)))?;
 
         let output = self
-            .docker
+            .docker()?
             .start_exec(&exec.id, None)
             .await
             .map_err(|e| Error::Task(format!("Failed to start exec: {}", e)))?;
@@ -156,7 +157,7 @@ impl ContainerExecutor {
 
         // Get exit code
         let inspect = self
-            .docker
+            .docker()?
             .inspect_exec(&exec.id)
             .await
             .map_err(|e| Error::Task(format!("Failed to inspect exec: {}", e)))?;
@@ -181,7 +182,7 @@ impl ContainerExecutor {
             ..Default::default()
         };
 
-        self.docker
+        self.docker()?
             .remove_container(container_id, Some(options))
             .await
             .map_err(|e| Error::Task(format!("Failed to remove container: {}", e)))?;
@@ -255,4 +256,20 @@ impl Executor for ContainerExecutor {
     fn name(&self) -> &'static str {
         "container"
     }
+
+    fn artifact_dir(&self, task: &Task) -> Option<PathBuf> {
+        // Only visible on the host when the workdir is a bind mount
+        let ExecutionMode::Container {
+            workdir: Some(workdir),
+            volumes,
+            ..
+        } = &task.execution_mode
+        else {
+            return None;
+        };
+        volumes
+            .iter()
+            .find(|(_, container)| container.trim_end_matches('/') == workdir.trim_end_matches('/'))
+            .map(|(host, _)| PathBuf::from(host))
+    }
 }
@@ -0,0 +1,841 @@
+//! Kubernetes executor - runs tasks as Kubernetes Jobs
+//!
+//! Offloads heavy tasks (full builds, test suites) to a cluster:
+//! - One `batch/v1` Job per task, built from a `ContainerConfig` template
+//!   (image, resource limits, volume mounts, env, security profile)
+//! - Pod logs streamed into `TaskLogManager` while the Job runs
+//! - Exit code taken from the terminated container
+//! - Job deleted on completion, timeout or cancellation
+//!
+//! Talks to the cluster through `kubectl`, so the current kubeconfig
+//! (or `K8sConfig::kubeconfig`/`context`) decides which cluster is used.
+//!
+//! Volume mounts become `hostPath` volumes: the host path must exist on the
+//! node that runs the pod (e.g. a shared cache directory), not on this machine.
+
+use crate::container::{ContainerConfig, NetworkMode};
+use crate::executor::Executor;
+use crate::log::{LogLevel, TaskLogManager};
+use crate::task::{ExecutionMode, Task, TaskResult};
+use async_trait::async_trait;
+use forge_foundation::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+
+/// Label identifying Jobs created by ForgeCode
+pub const K8S_MANAGED_BY_LABEL: &str = "app.kubernetes.io/managed-by";
+
+/// Label holding the task ID
+pub const K8S_TASK_LABEL: &str = "forgecode.dev/task-id";
+
+/// Container name inside the Job pod
+const CONTAINER_NAME: &str = "task";
+
+/// Kubernetes executor configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct K8sConfig {
+    /// Namespace to create Jobs in
+    pub namespace: String,
+    /// kubectl context (default: current context)
+    pub context: Option<String>,
+    /// kubeconfig file (default: KUBECONFIG / ~/.kube/config)
+    pub kubeconfig: Option<PathBuf>,
+    /// kubectl binary
+    pub kubectl: String,
+    /// Pod template: image, limits, volumes, env, working dir, security
+    pub container: ContainerConfig,
+    /// Service account for the pod
+    pub service_account: Option<String>,
+    /// Node selector labels
+    pub node_selector: HashMap<String, String>,
+    /// How long to wait for the pod to start (image pull, scheduling)
+    pub pod_start_timeout: Duration,
+    /// Job status polling interval
+    pub poll_interval: Duration,
+    /// Let the cluster delete finished Jobs after this many seconds
+    /// (fallback if the executor is stopped before cleanup)
+    pub ttl_seconds_after_finished: Option<u32>,
+    /// Delete the Job when the task finishes
+    pub cleanup: bool,
+}
+
+impl Default for K8sConfig {
+    fn default() -> Self {
+        Self {
+            namespace: "default".to_string(),
+            context: None,
+            kubeconfig: None,
+            kubectl: "kubectl".to_string(),
+            container: ContainerConfig::default(),
+            service_account: None,
+            node_selector: HashMap::new(),
+            pod_start_timeout: Duration::from_secs(300),
+            poll_interval: Duration::from_secs(2),
+            ttl_seconds_after_finished: Some(600),
+            cleanup: true,
+        }
+    }
+}
+
+impl K8sConfig {
+    pub fn new(container: ContainerConfig) -> Self {
+        Self {
+            container,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    pub fn with_kubeconfig(mut self, path: impl Into<PathBuf>) -> Self {
+        self.kubeconfig = Some(path.into());
+        self
+    }
+
+    pub fn with_kubectl(mut self, kubectl: impl Into<String>) -> Self {
+        self.kubectl = kubectl.into();
+        self
+    }
+
+    pub fn with_service_account(mut self, account: impl Into<String>) -> Self {
+        self.service_account = Some(account.into());
+        self
+    }
+
+    pub fn with_node_selector(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.node_selector.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_cleanup(mut self, cleanup: bool) -> Self {
+        self.cleanup = cleanup;
+        self
+    }
+}
+
+/// Job state from `.status.succeeded` / `.status.failed`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JobPhase {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl JobPhase {
+    /// Parse `kubectl get job -o jsonpath={.status.succeeded}/{.status.failed}`
+    fn parse(output: &str) -> Self {
+        let (succeeded, failed) = output.trim().split_once('/').unwrap_or((output.trim(), ""));
+        let count = |s: &str| s.trim().parse::<u32>().unwrap_or(0);
+        if count(succeeded) > 0 {
+            Self::Succeeded
+        } else if count(failed) > 0 {
+            Self::Failed
+        } else {
+            Self::Running
+        }
+    }
+}
+
+/// Kubernetes executor that runs each task as a Job
+pub struct K8sExecutor {
+    /// Configuration
+    config: K8sConfig,
+
+    /// Running Job names by task ID
+    jobs: Arc<Mutex<HashMap<String, String>>>,
+
+    /// Log manager
+    log_manager: Arc<TaskLogManager>,
+
+    /// Whether kubectl is available
+    available: bool,
+}
+
+impl K8sExecutor {
+    /// Create a new Kubernetes executor
+    pub async fn new(config: K8sConfig) -> Self {
+        Self::with_log_manager(config, Arc::new(TaskLogManager::new())).await
+    }
+
+    /// Create with custom log manager
+    pub async fn with_log_manager(config: K8sConfig, log_manager: Arc<TaskLogManager>) -> Self {
+        // kubectl이 없어도 다른 실행기는 동작해야 하므로 실패 시 비활성화만 함
+        let available = Command::new(&config.kubectl)
+            .args(["version", "--client"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .map(|s| s.success())
+            .unwrap_or(false);
+
+        Self {
+            config,
+            jobs: Arc::new(Mutex::new(HashMap::new())),
+            log_manager,
+            available,
+        }
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &K8sConfig {
+        &self.config
+    }
+
+    /// Get the log manager
+    pub fn log_manager(&self) -> Arc<TaskLogManager> {
+        Arc::clone(&self.log_manager)
+    }
+
+    /// Running Job names
+    pub async fn running_jobs(&self) -> Vec<String> {
+        self.jobs.lock().await.values().cloned().collect()
+    }
+
+    /// Job name for a task (DNS-1123 label)
+    pub fn job_name(task: &Task) -> String {
+        format!("forgecode-{}", task.id.0)
+    }
+
+    /// Build the Job manifest for a task
+    pub fn job_manifest(&self, task: &Task) -> Value {
+        let container = &self.config.container;
+        let image = match &task.execution_mode {
+            ExecutionMode::Kubernetes { image: Some(image) } => image.clone(),
+            _ => container.image.clone(),
+        };
+
+        let mut labels: Map<String, Value> = container
+            .labels
+            .iter()
+            .map(|(k, v)| (k.clone(), json!(v)))
+            .collect();
+        labels.insert(K8S_MANAGED_BY_LABEL.to_string(), json!("forgecode"));
+        labels.insert(K8S_TASK_LABEL.to_string(), json!(task.id.0.to_string()));
+
+        // Container env, then task env (task wins)
+        let mut env: Vec<(&String, &String)> = container.env.iter().collect();
+        env.retain(|(k, _)| !task.env.contains_key(*k));
+        env.extend(task.env.iter());
+        env.sort();
+        let env: Vec<Value> = env
+            .into_iter()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect();
+
+        let mut spec = json!({
+            "name": CONTAINER_NAME,
+            "image": image,
+            "command": ["sh", "-c", task.command],
+            "env": env,
+            "resources": self.resources(),
+            "securityContext": self.container_security_context(),
+        });
+        if let Some(dir) = &container.working_dir {
+            spec["workingDir"] = json!(dir.to_string_lossy());
+        }
+
+        let mut volumes = Vec::new();
+        let mut mounts = Vec::new();
+        for (i, volume) in container.volumes.iter().enumerate() {
+            let name = format!("volume-{}", i);
+            volumes.push(json!({
+                "name": name,
+                "hostPath": { "path": volume.host_path.to_string_lossy() },
+            }));
+            mounts.push(json!({
+                "name": name,
+                "mountPath": volume.container_path.to_string_lossy(),
+                "readOnly": volume.read_only,
+            }));
+        }
+        if !mounts.is_empty() {
+            spec["volumeMounts"] = json!(mounts);
+        }
+
+        let mut pod_spec = json!({
+            "restartPolicy": "Never",
+            "containers": [spec],
+        });
+        if !volumes.is_empty() {
+            pod_spec["volumes"] = json!(volumes);
+        }
+        if let Some(account) = &self.config.service_account {
+            pod_spec["serviceAccountName"] = json!(account);
+        }
+        if !self.config.node_selector.is_empty() {
+            pod_spec["nodeSelector"] = json!(self.config.node_selector);
+        }
+        match &container.network {
+            NetworkMode::Host => pod_spec["hostNetwork"] = json!(true),
+            NetworkMode::Bridge => {}
+            // 파드 단위 네트워크 차단은 NetworkPolicy로만 가능
+            other => debug!("Network mode {:?} is not applied to Kubernetes pods", other),
+        }
+        if let Some(context) = self.pod_security_context() {
+            pod_spec["securityContext"] = context;
+        }
+
+        let mut job_spec = json!({
+            "backoffLimit": 0,
+            "template": {
+                "metadata": { "labels": labels },
+                "spec": pod_spec,
+            },
+        });
+        if !task.timeout.is_zero() {
+            job_spec["activeDeadlineSeconds"] = json!(task.timeout.as_secs().max(1));
+        }
+        if let Some(ttl) = self.config.ttl_seconds_after_finished {
+            job_spec["ttlSecondsAfterFinished"] = json!(ttl);
+        }
+
+        json!({
+            "apiVersion": "batch/v1",
+            "kind": "Job",
+            "metadata": {
+                "name": Self::job_name(task),
+                "namespace": self.config.namespace,
+                "labels": labels,
+            },
+            "spec": job_spec,
+        })
+    }
+
+    /// CPU/memory limits (requests = limits so the scheduler reserves them)
+    fn resources(&self) -> Value {
+        let limits = &self.config.container.limits;
+        let mut quantities = Map::new();
+        if let Some(cpus) = limits.cpus {
+            quantities.insert("cpu".to_string(), json!(cpus.to_string()));
+        }
+        if let Some(memory) = &limits.memory {
+            quantities.insert("memory".to_string(), json!(memory_quantity(memory)));
+        }
+        if quantities.is_empty() {
+            json!({})
+        } else {
+            json!({ "limits": quantities, "requests": quantities })
+        }
+    }
+
+    fn container_security_context(&self) -> Value {
+        let security = &self.config.container.security;
+        let mut context = json!({
+            "readOnlyRootFilesystem": self.config.container.limits.read_only,
+            "allowPrivilegeEscalation": !security.no_new_privileges,
+        });
+        if !security.drop_caps.is_empty() || !security.add_caps.is_empty() {
+            context["capabilities"] = json!({
+                "drop": security.drop_caps,
+                "add": security.add_caps,
+            });
+        }
+        context
+    }
+
+    fn pod_security_context(&self) -> Option<Value> {
+        let security = &self.config.container.security;
+        let mut context = Map::new();
+
+        if let Some(user) = &security.user {
+            let (uid, gid) = user.split_once(':').unwrap_or((user.as_str(), ""));
+            match uid.parse::<u32>() {
+                Ok(uid) => {
+                    context.insert("runAsUser".to_string(), json!(uid));
+                    context.insert("runAsNonRoot".to_string(), json!(uid != 0));
+                }
+                Err(_) => warn!("Kubernetes needs a numeric user, ignoring '{}'", user),
+            }
+            if let Ok(gid) = gid.parse::<u32>() {
+                context.insert("runAsGroup".to_string(), json!(gid));
+            }
+        }
+
+        match security.seccomp_profile.as_deref() {
+            Some("default") => {
+                context.insert(
+                    "seccompProfile".to_string(),
+                    json!({ "type": "RuntimeDefault" }),
+                );
+            }
+            Some(profile) => {
+                context.insert(
+                    "seccompProfile".to_string(),
+                    json!({ "type": "Localhost", "localhostProfile": profile }),
+                );
+            }
+            None => {}
+        }
+
+        if context.is_empty() {
+            None
+        } else {
+            Some(Value::Object(context))
+        }
+    }
+
+    /// Global kubectl arguments (kubeconfig, context, namespace)
+    pub fn kubectl_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(kubeconfig) = &self.config.kubeconfig {
+            args.push("--kubeconfig".to_string());
+            args.push(kubeconfig.to_string_lossy().to_string());
+        }
+        if let Some(context) = &self.config.context {
+            args.push("--context".to_string());
+            args.push(context.clone());
+        }
+        args.push("--namespace".to_string());
+        args.push(self.config.namespace.clone());
+        args
+    }
+
+    fn command(&self) -> Command {
+        let mut cmd = Command::new(&self.config.kubectl);
+        cmd.args(self.kubectl_args()).kill_on_drop(true);
+        cmd
+    }
+
+    /// Run kubectl and return stdout (stdin is written if given)
+    async fn kubectl(&self, args: &[&str], input: Option<&str>) -> Result<String> {
+        let mut child = self
+            .command()
+            .args(args)
+            .stdin(if input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| Error::Task(format!("Failed to run kubectl: {}", e)))?;
+
+        if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+            stdin.write_all(input.as_bytes()).await?;
+        }
+
+        let output = child.wait_with_output().await?;
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Err(Error::Task(format!(
+                "kubectl {} failed: {}",
+                args.first().copied().unwrap_or_default(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )))
+        }
+    }
+
+    /// Stream pod logs into the log manager until the container exits
+    async fn stream_logs(&self, task_id: &str, job: &str) -> Result<()> {
+        let pod_timeout = format!("{}s", self.config.pod_start_timeout.as_secs().max(1));
+        let mut child = self
+            .command()
+            .args([
+                "logs",
+                "--follow",
+                "--pod-running-timeout",
+                &pod_timeout,
+                &format!("job/{}", job),
+            ])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| Error::Task(format!("Failed to run kubectl: {}", e)))?;
+
+        // 파드 로그는 stdout/stderr가 합쳐져서 오므로 stdout으로 기록
+        if let Some(stdout) = child.stdout.take() {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                self.log_manager.push_stdout(task_id, line).await;
+            }
+        }
+
+        let mut stderr = String::new();
+        if let Some(mut pipe) = child.stderr.take() {
+            let _ = pipe.read_to_string(&mut stderr).await;
+        }
+        if !child.wait().await?.success() && !stderr.trim().is_empty() {
+            self.log_manager
+                .push_system(task_id, format!("kubectl logs: {}", stderr.trim()))
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Wait until the Job succeeds or fails
+    async fn wait_for_job(&self, job: &str) -> Result<JobPhase> {
+        loop {
+            let status = self
+                .kubectl(
+                    &[
+                        "get",
+                        "job",
+                        job,
+                        "-o",
+                        "jsonpath={.status.succeeded}/{.status.failed}",
+                    ],
+                    None,
+                )
+                .await?;
+            match JobPhase::parse(&status) {
+                JobPhase::Running => tokio::time::sleep(self.config.poll_interval).await,
+                phase => return Ok(phase),
+            }
+        }
+    }
+
+    /// Exit code of the Job's container
+    async fn exit_code(&self, job: &str) -> Option<i32> {
+        self.kubectl(
+            &[
+                "get",
+                "pods",
+                "-l",
+                &format!("job-name={}", job),
+                "-o",
+                "jsonpath={.items[0].status.containerStatuses[0].state.terminated.exitCode}",
+            ],
+            None,
+        )
+        .await
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+    }
+
+    /// Stream logs, wait for the Job and collect the result
+    async fn run_job(&self, task_id: &str, job: &str) -> Result<TaskResult> {
+        self.stream_logs(task_id, job).await?;
+        let phase = self.wait_for_job(job).await?;
+        let exit_code = match (self.exit_code(job).await, phase) {
+            (Some(code), _) => code,
+            (None, JobPhase::Succeeded) => 0,
+            (None, _) => -1,
+        };
+
+        if exit_code != 0 {
+            self.log_manager
+                .push_system(
+                    task_id,
+                    format!("Job {} failed with exit code {}", job, exit_code),
+                )
+                .await;
+        }
+
+        let output = self
+            .log_manager
+            .get_buffer(task_id)
+            .await
+            .map(|buffer| {
+                buffer
+                    .entries()
+                    .filter(|e| e.level == LogLevel::Stdout)
+                    .map(|e| e.content.clone())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .unwrap_or_default();
+
+        Ok(TaskResult::with_exit_code(output, exit_code))
+    }
+
+    /// Delete a Job and its pods
+    async fn delete_job(&self, job: &str) -> Result<()> {
+        self.kubectl(
+            &[
+                "delete",
+                "job",
+                job,
+                "--ignore-not-found",
+                "--wait=false",
+                "--cascade=background",
+            ],
+            None,
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+#[async_trait]
+impl Executor for K8sExecutor {
+    async fn execute(&self, task: &Task) -> Result<TaskResult> {
+        if !self.available {
+            return Err(Error::Task(format!(
+                "Kubernetes is not available ({} not found)",
+                self.config.kubectl
+            )));
+        }
+
+        // Ensure task is for Kubernetes execution
+        if !matches!(task.execution_mode, ExecutionMode::Kubernetes { .. }) {
+            return Err(Error::Task(
+                "K8sExecutor can only execute Kubernetes tasks".to_string(),
+            ));
+        }
+
+        let task_id = task.id.to_string();
+        let job = Self::job_name(task);
+        let manifest = serde_json::to_string(&self.job_manifest(task))?;
+
+        let _log_rx = self
+            .log_manager
+            .create_buffer(&task_id, Some(&task.command))
+            .await;
+        self.log_manager
+            .push_system(
+                &task_id,
+                format!("Creating Job {}/{}", self.config.namespace, job),
+            )
+            .await;
+
+        if let Err(e) = self.kubectl(&["create", "-f", "-"], Some(&manifest)).await {
+            self.log_manager.push_system(&task_id, e.to_string()).await;
+            self.log_manager.mark_ended(&task_id).await;
+            return Err(e);
+        }
+        info!("Created Kubernetes Job {} for task {}", job, task_id);
+
+        self.jobs.lock().await.insert(task_id.clone(), job.clone());
+
+        // activeDeadlineSeconds가 클러스터 쪽 제한, 여기서는 파드 시작 대기를 포함한 안전망
+        let result = if task.timeout.is_zero() {
+            self.run_job(&task_id, &job).await
+        } else {
+            let limit = task.timeout + self.config.pod_start_timeout;
+            match tokio::time::timeout(limit, self.run_job(&task_id, &job)).await {
+                Ok(result) => result,
+                Err(_) => {
+                    self.log_manager
+                        .push_system(&task_id, "Task timed out, deleting Job")
+                        .await;
+                    Err(Error::Timeout("Kubernetes Job timed out".to_string()))
+                }
+            }
+        };
+
+        // 취소된 경우 cancel()이 이미 삭제함
+        let tracked = self.jobs.lock().await.remove(&task_id).is_some();
+        if tracked && self.config.cleanup {
+            if let Err(e) = self.delete_job(&job).await {
+                warn!("Failed to delete Job {}: {}", job, e);
+            }
+        }
+
+        self.log_manager.mark_ended(&task_id).await;
+        result
+    }
+
+    async fn cancel(&self, task: &Task) -> Result<()> {
+        let task_id = task.id.to_string();
+        let job = self.jobs.lock().await.remove(&task_id);
+
+        if let Some(job) = job {
+            self.delete_job(&job).await?;
+            self.log_manager
+                .push_system(&task_id, "Task cancelled by user, Job deleted")
+                .await;
+            info!("Cancelled Kubernetes Job {}", job);
+        }
+
+        Ok(())
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+
+    fn name(&self) -> &'static str {
+        "kubernetes"
+    }
+}
+
+/// Convert a Docker memory size (`512m`, `2g`) to a Kubernetes quantity (`512Mi`, `2Gi`)
+///
+/// Kubernetes reads a bare `m` suffix as "milli", so Docker sizes must be converted.
+pub fn memory_quantity(size: &str) -> String {
+    let size = size.trim();
+    if size.ends_with('i') {
+        return size.to_string();
+    }
+
+    let lower = size.to_ascii_lowercase();
+    let lower = lower.strip_suffix('b').unwrap_or(&lower);
+    let (number, suffix) = match lower.char_indices().last() {
+        Some((i, c)) if c.is_ascii_alphabetic() => (&lower[..i], Some(c)),
+        _ => (lower, None),
+    };
+    match suffix {
+        Some('k') => format!("{}Ki", number),
+        Some('m') => format!("{}Mi", number),
+        Some('g') => format!("{}Gi", number),
+        Some('t') => format!("{}Ti", number),
+        _ => number.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::{ResourceLimits, SecurityProfile, VolumeMount};
+
+    fn k8s_task(command: &str) -> Task {
+        Task::new("session-1", "task_spawn", command, serde_json::json!({}))
+            .with_execution_mode(ExecutionMode::Kubernetes { image: None })
+            .with_timeout(Duration::from_secs(600))
+            .with_env_var("CARGO_TERM_COLOR", "never")
+    }
+
+    async fn executor(config: K8sConfig) -> K8sExecutor {
+        K8sExecutor::new(config.with_kubectl("/nonexistent/kubectl")).await
+    }
+
+    #[test]
+    fn test_memory_quantity() {
+        assert_eq!(memory_quantity("512m"), "512Mi");
+        assert_eq!(memory_quantity("2g"), "2Gi");
+        assert_eq!(memory_quantity("2GB"), "2Gi");
+        assert_eq!(memory_quantity("1024k"), "1024Ki");
+        assert_eq!(memory_quantity("4Gi"), "4Gi");
+        assert_eq!(memory_quantity("1048576"), "1048576");
+    }
+
+    #[test]
+    fn test_job_phase() {
+        assert_eq!(JobPhase::parse("/"), JobPhase::Running);
+        assert_eq!(JobPhase::parse("1/"), JobPhase::Succeeded);
+        assert_eq!(JobPhase::parse("/1"), JobPhase::Failed);
+        assert_eq!(JobPhase::parse(""), JobPhase::Running);
+    }
+
+    #[tokio::test]
+    async fn test_job_manifest() {
+        let container = ContainerConfig::new("rust:1.75")
+            .with_working_dir("/workspace")
+            .with_env("CARGO_TERM_COLOR", "always")
+            .with_env("RUST_LOG", "info")
+            .with_volume(VolumeMount::new("/mnt/cache", "/usr/local/cargo/registry").read_only())
+            .with_limits(ResourceLimits::generous())
+            .with_network(NetworkMode::Host)
+            .with_security(SecurityProfile::strict());
+        let executor = executor(
+            K8sConfig::new(container)
+                .with_namespace("builds")
+                .with_service_account("builder")
+                .with_node_selector("pool", "large"),
+        )
+        .await;
+
+        let task = k8s_task("cargo build --release");
+        let manifest = executor.job_manifest(&task);
+
+        assert_eq!(manifest["kind"], "Job");
+        assert_eq!(manifest["metadata"]["namespace"], "builds");
+        assert_eq!(manifest["metadata"]["name"], K8sExecutor::job_name(&task));
+        assert_eq!(
+            manifest["metadata"]["labels"][K8S_MANAGED_BY_LABEL],
+            "forgecode"
+        );
+        assert_eq!(manifest["spec"]["backoffLimit"], 0);
+        assert_eq!(manifest["spec"]["activeDeadlineSeconds"], 600);
+
+        let pod = &manifest["spec"]["template"]["spec"];
+        assert_eq!(pod["restartPolicy"], "Never");
+        assert_eq!(pod["serviceAccountName"], "builder");
+        assert_eq!(pod["nodeSelector"]["pool"], "large");
+        assert_eq!(pod["hostNetwork"], true);
+        assert_eq!(pod["securityContext"]["runAsUser"], 65534);
+        assert_eq!(
+            pod["securityContext"]["seccompProfile"]["type"],
+            "RuntimeDefault"
+        );
+        assert_eq!(pod["volumes"][0]["hostPath"]["path"], "/mnt/cache");
+
+        let container = &pod["containers"][0];
+        assert_eq!(container["image"], "rust:1.75");
+        assert_eq!(container["command"][2], "cargo build --release");
+        assert_eq!(container["workingDir"], "/workspace");
+        assert_eq!(container["resources"]["limits"]["cpu"], "4");
+        assert_eq!(container["resources"]["limits"]["memory"], "8Gi");
+        assert_eq!(container["resources"]["requests"]["memory"], "8Gi");
+        assert_eq!(container["volumeMounts"][0]["readOnly"], true);
+        assert_eq!(
+            container["securityContext"]["allowPrivilegeEscalation"],
+            false
+        );
+        assert_eq!(
+            container["securityContext"]["capabilities"]["drop"][0],
+            "ALL"
+        );
+
+        // 태스크 환경 변수가 컨테이너 설정보다 우선
+        let env = container["env"].as_array().unwrap();
+        assert_eq!(env.len(), 2);
+        assert_eq!(env[0]["name"], "CARGO_TERM_COLOR");
+        assert_eq!(env[0]["value"], "never");
+    }
+
+    #[tokio::test]
+    async fn test_image_override_and_kubectl_args() {
+        let executor = executor(
+            K8sConfig::default()
+                .with_context("build-cluster")
+                .with_kubeconfig("/etc/kube/config"),
+        )
+        .await;
+
+        let task = k8s_task("make").with_execution_mode(ExecutionMode::Kubernetes {
+            image: Some("gcc:13".to_string()),
+        });
+        let manifest = executor.job_manifest(&task);
+        assert_eq!(
+            manifest["spec"]["template"]["spec"]["containers"][0]["image"],
+            "gcc:13"
+        );
+        assert!(manifest["spec"]["template"]["spec"]["volumes"].is_null());
+
+        assert_eq!(
+            executor.kubectl_args(),
+            vec![
+                "--kubeconfig",
+                "/etc/kube/config",
+                "--context",
+                "build-cluster",
+                "--namespace",
+                "default"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_without_kubectl() {
+        let executor = executor(K8sConfig::default()).await;
+        assert!(!executor.is_available());
+        assert_eq!(executor.name(), "kubernetes");
+
+        let err = executor.execute(&k8s_task("true")).await.unwrap_err();
+        assert!(err.to_string().contains("not available"));
+        assert!(executor.running_jobs().await.is_empty());
+    }
+}
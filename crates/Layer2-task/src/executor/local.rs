@@ -7,7 +7,10 @@
 //! - Exit code tracking
 //! - Advanced timeout handling (soft/hard)
 //! - Graceful shutdown with SIGTERM -> SIGKILL escalation
+//! - Per-task CPU/memory quotas (`Task::resource_limits`) with peak usage
+//!   reported in `TaskResult::resource_usage`
 
+use crate::executor::resource_monitor::{ProcessResourceLimits, ResourceMonitor};
 use crate::executor::Executor;
 use crate::log::{LogEntry, TaskLogManager};
 use crate::task::{ExecutionMode, Task, TaskResult};
@@ -15,6 +18,7 @@ use async_trait::async_trait;
 use forge_foundation::{Error, Result};
 use futures::FutureExt;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::process::Stdio;
 use std::sync::Arc;
 use std::time::Duration;
@@ -163,35 +167,28 @@ pub struct LocalExecutor {
 
     /// Configuration
     config: LocalExecutorConfig,
+
+    /// Resource usage tracking and quota enforcement
+    resource_monitor: Arc<ResourceMonitor>,
 }
 
 impl LocalExecutor {
     /// Create a new local executor
     pub fn new() -> Self {
-        Self {
-            // Pre-allocate for typical concurrent task count
-            processes: Arc::new(RwLock::new(HashMap::with_capacity(16))),
-            log_manager: Arc::new(TaskLogManager::new()),
-            config: LocalExecutorConfig::default(),
-        }
+        Self::with_config_and_log_manager(
+            LocalExecutorConfig::default(),
+            Arc::new(TaskLogManager::new()),
+        )
     }
 
     /// Create with configuration
     pub fn with_config(config: LocalExecutorConfig) -> Self {
-        Self {
-            processes: Arc::new(RwLock::new(HashMap::with_capacity(16))),
-            log_manager: Arc::new(TaskLogManager::new()),
-            config,
-        }
+        Self::with_config_and_log_manager(config, Arc::new(TaskLogManager::new()))
     }
 
     /// Create with custom log manager
     pub fn with_log_manager(log_manager: Arc<TaskLogManager>) -> Self {
-        Self {
-            processes: Arc::new(RwLock::new(HashMap::with_capacity(16))),
-            log_manager,
-            config: LocalExecutorConfig::default(),
-        }
+        Self::with_config_and_log_manager(LocalExecutorConfig::default(), log_manager)
     }
 
     /// Create with config and log manager
@@ -199,18 +196,36 @@ impl LocalExecutor {
         config: LocalExecutorConfig,
         log_manager: Arc<TaskLogManager>,
     ) -> Self {
+        // Measure only; tasks opt into quotas via `Task::resource_limits`
+        let resource_monitor = Arc::new(
+            ResourceMonitor::new(ProcessResourceLimits::unlimited())
+                .with_log_manager(Arc::clone(&log_manager)),
+        );
         Self {
+            // Pre-allocate for typical concurrent task count
             processes: Arc::new(RwLock::new(HashMap::with_capacity(16))),
             log_manager,
             config,
+            resource_monitor,
         }
     }
 
+    /// Use a shared resource monitor (default limits, violation events)
+    pub fn with_resource_monitor(mut self, monitor: Arc<ResourceMonitor>) -> Self {
+        self.resource_monitor = monitor;
+        self
+    }
+
     /// Get the log manager
     pub fn log_manager(&self) -> Arc<TaskLogManager> {
         Arc::clone(&self.log_manager)
     }
 
+    /// Get the resource monitor
+    pub fn resource_monitor(&self) -> Arc<ResourceMonitor> {
+        Arc::clone(&self.resource_monitor)
+    }
+
     /// Get running processes
     pub async fn running_processes(&self) -> Vec<String> {
         self.processes.read().await.keys().cloned().collect()
@@ -295,12 +310,15 @@ impl LocalExecutor {
     #[cfg(unix)]
     #[allow(dead_code)]
     async fn send_sigterm(child: &Child) -> Result<()> {
-        use nix::sys::signal::{kill, Signal};
-        use nix::unistd::Pid;
-
         if let Some(pid) = child.id() {
-            kill(Pid::from_raw(pid as i32), Signal::SIGTERM)
-                .map_err(|e| Error::Task(format!("Failed to send SIGTERM: {}", e)))?;
+            // SAFETY: kill(2) has no memory-safety preconditions
+            let ret = unsafe { libc::kill(pid as libc::pid_t, libc::SIGTERM) };
+            if ret != 0 {
+                return Err(Error::Task(format!(
+                    "Failed to send SIGTERM: {}",
+                    std::io::Error::last_os_error()
+                )));
+            }
         }
         Ok(())
     }
@@ -498,6 +516,16 @@ impl Executor for LocalExecutor {
             .spawn()
             .map_err(|e| Error::Task(format!("Failed to spawn process: {}", e)))?;
 
+        // Start resource monitoring
+        let resource_watch = match child.id() {
+            Some(pid) => Some(
+                self.resource_monitor
+                    .watch(&task_id, pid, task.resource_limits.clone())
+                    .await,
+            ),
+            None => None,
+        };
+
         // Get stdout/stderr handles
         let stdout = child.stdout.take();
         let stderr = child.stderr.take();
@@ -628,8 +656,20 @@ impl Executor for LocalExecutor {
                 }
             };
 
-            match wait_result {
-                Ok(Ok(status)) => {
+            // Stop resource monitoring
+            let enforced = resource_watch.as_ref().and_then(|watch| watch.enforced());
+            let resource_usage = match resource_watch {
+                Some(watch) => watch.finish().await,
+                None => None,
+            };
+
+            match (wait_result, enforced) {
+                // Killed for exceeding its resource quota
+                (_, Some(violation)) => Err(Error::Task(format!(
+                    "Resource limit exceeded - {}",
+                    violation
+                ))),
+                (Ok(Ok(status)), None) => {
                     // Mark as completed
                     {
                         let mut info = process_info.lock().await;
@@ -670,10 +710,11 @@ impl Executor for LocalExecutor {
                             .await;
                     }
 
-                    Ok(TaskResult::with_exit_code(output, exit_code))
+                    Ok(TaskResult::with_exit_code(output, exit_code)
+                        .with_resource_usage(resource_usage))
                 }
-                Ok(Err(e)) => Err(Error::Task(format!("Process error: {}", e))),
-                Err(_) => {
+                (Ok(Err(e)), None) => Err(Error::Task(format!("Process error: {}", e))),
+                (Err(_), None) => {
                     // Task join error or timeout
                     let timeout_state = {
                         let info = process_info.lock().await;
@@ -757,6 +798,11 @@ impl Executor for LocalExecutor {
     fn name(&self) -> &'static str {
         "local"
     }
+
+    fn artifact_dir(&self, _task: &Task) -> Option<PathBuf> {
+        // Local tasks run in the current directory
+        std::env::current_dir().ok()
+    }
 }
 
 #[cfg(test)]
@@ -800,6 +846,45 @@ mod tests {
         assert!(!logs.is_empty());
     }
 
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_resource_limits_enforced() {
+        use crate::executor::resource_monitor::{LimitExceededAction, ViolationType};
+
+        let executor = LocalExecutor::new();
+        let mut events = executor.resource_monitor().subscribe();
+
+        // Usage is reported even without a quota
+        let task = Task::new("session-1", "bash", "sleep 0.2", serde_json::json!({}));
+        let result = executor.execute(&task).await.unwrap();
+        let usage = result.resource_usage.expect("resource usage");
+        assert!(usage.peak_memory_bytes > 0);
+        assert!(usage.violations.is_empty());
+
+        let task = Task::new("session-1", "bash", "sleep 30", serde_json::json!({}))
+            .with_resource_limits(
+                ProcessResourceLimits::unlimited()
+                    .with_memory_limit("1k")
+                    .with_action(LimitExceededAction::Kill),
+            );
+        let started = std::time::Instant::now();
+        let err = executor.execute(&task).await.unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(err.to_string().contains("Memory limit exceeded"), "{}", err);
+
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.task_id, task.id.to_string());
+        assert_eq!(
+            event.violation.violation_type,
+            ViolationType::MemoryExceeded
+        );
+        assert!(executor
+            .get_logs(&task.id.to_string(), None)
+            .await
+            .iter()
+            .any(|entry| entry.content.contains("Resource limit")));
+    }
+
     #[tokio::test]
     async fn test_running_processes() {
         let executor = LocalExecutor::new();
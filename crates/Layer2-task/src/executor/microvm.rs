@@ -0,0 +1,683 @@
+//! MicroVM sandbox backend
+//!
+//! Runs each command in a throwaway cloud-hypervisor microVM, for agent
+//! workloads that must not share a kernel with the host.
+//!
+//! cloud-hypervisor is used rather than Firecracker because it supports
+//! virtio-fs, so the project directory is shared without copying it into
+//! a disk image.
+//!
+//! ## Guest contract
+//!
+//! - `MicroVmConfig::kernel`: uncompressed kernel with virtio-blk/virtio-fs
+//! - `MicroVmConfig::rootfs`: minimal ext4 image, attached read-only as
+//!   `/dev/vda`, containing `/workspace`, `/mnt` and `/sbin/forge-init`
+//!   (the script in [`GUEST_INIT`])
+//!
+//! ## Policy mapping (same `SandboxConfig` surface as Seatbelt/Landlock)
+//!
+//! | `SandboxConfig` | MicroVM |
+//! |-----------------|---------|
+//! | working directory | virtio-fs `/workspace`, read-only unless `project_writable` |
+//! | `allowed_read_paths` | read-only virtio-fs at `/mnt/host/<path>` |
+//! | `allowed_write_paths` | writable virtio-fs at `/mnt/host/<path>` |
+//! | `allow_network` | NIC on `tap_device` (no NIC otherwise) |
+//! | `env_passthrough` | exported before the command runs |
+//! | `timeout_ms` | VM is killed on timeout (includes boot) |
+//!
+//! Host filtering (`allowed_hosts`) must be applied on the tap device;
+//! process spawning is unrestricted inside the VM.
+
+use super::sandbox::{SandboxConfig, SandboxResult, SandboxType};
+use forge_foundation::{Error, Result};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::process::{Child, Command};
+use tracing::{debug, warn};
+
+/// Guest init script (`/sbin/forge-init` in the rootfs)
+///
+/// Mounts the shares listed in `/mnt/job/mounts`, runs `/mnt/job/run.sh` and
+/// writes `stdout`, `stderr` and `exit_code` back to the job share.
+pub const GUEST_INIT: &str = r#"#!/bin/sh
+# forge-init: ForgeCode microVM guest init
+mount -t proc proc /proc
+mount -t sysfs sys /sys
+mount -t devtmpfs dev /dev 2>/dev/null
+mount -t tmpfs tmpfs /tmp
+mount -t tmpfs tmpfs /mnt
+mkdir -p /mnt/job
+mount -t virtiofs job /mnt/job
+while read -r tag path mode; do
+    mkdir -p "$path"
+    mount -t virtiofs -o "$mode" "$tag" "$path"
+done < /mnt/job/mounts
+if [ -f /mnt/job/network ]; then
+    ip link set lo up
+    ip link set eth0 up
+    udhcpc -i eth0 -q -n >/dev/null 2>&1
+fi
+sh /mnt/job/run.sh > /mnt/job/stdout 2> /mnt/job/stderr
+echo $? > /mnt/job/exit_code
+sync
+poweroff -f
+"#;
+
+/// Kernel command line for the guest
+const KERNEL_CMDLINE: &str = "console=hvc0 root=/dev/vda ro init=/sbin/forge-init quiet";
+
+/// Guest mount point for the project directory
+const GUEST_WORKSPACE: &str = "/workspace";
+
+/// Guest prefix for extra read/write paths
+const GUEST_HOST_PREFIX: &str = "/mnt/host";
+
+/// Largest result file (`stdout`, `stderr`, `exit_code`) read back from the job share
+const MAX_RESULT_BYTES: u64 = 8 * 1024 * 1024;
+
+/// How long to wait for virtiofsd sockets
+const DAEMON_START_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// MicroVM configuration
+#[derive(Debug, Clone)]
+pub struct MicroVmConfig {
+    /// cloud-hypervisor binary
+    pub hypervisor: PathBuf,
+
+    /// virtiofsd binary
+    pub virtiofsd: PathBuf,
+
+    /// Guest kernel image
+    pub kernel: PathBuf,
+
+    /// Guest root filesystem image (attached read-only)
+    pub rootfs: PathBuf,
+
+    /// Number of vCPUs
+    pub vcpus: u8,
+
+    /// Guest memory (MiB)
+    pub memory_mib: u32,
+
+    /// Mount the project directory read-write (default: read-only)
+    pub project_writable: bool,
+
+    /// Host tap device for network access (requires `allow_network`)
+    pub tap_device: Option<String>,
+}
+
+impl Default for MicroVmConfig {
+    fn default() -> Self {
+        let dir = dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".forgecode")
+            .join("microvm");
+        Self::new(dir.join("vmlinux"), dir.join("rootfs.ext4"))
+    }
+}
+
+impl MicroVmConfig {
+    /// Create a config for the given kernel and rootfs images
+    pub fn new(kernel: impl Into<PathBuf>, rootfs: impl Into<PathBuf>) -> Self {
+        Self {
+            hypervisor: PathBuf::from("cloud-hypervisor"),
+            virtiofsd: PathBuf::from("virtiofsd"),
+            kernel: kernel.into(),
+            rootfs: rootfs.into(),
+            vcpus: 1,
+            memory_mib: 512,
+            project_writable: false,
+            tap_device: None,
+        }
+    }
+
+    /// Set vCPU count and memory
+    pub fn with_resources(mut self, vcpus: u8, memory_mib: u32) -> Self {
+        self.vcpus = vcpus.max(1);
+        self.memory_mib = memory_mib;
+        self
+    }
+
+    /// Mount the project directory read-write
+    pub fn with_writable_project(mut self, writable: bool) -> Self {
+        self.project_writable = writable;
+        self
+    }
+
+    /// Attach a NIC on the given host tap device
+    pub fn with_tap_device(mut self, tap: impl Into<String>) -> Self {
+        self.tap_device = Some(tap.into());
+        self
+    }
+
+    /// Set hypervisor and virtiofsd binaries
+    pub fn with_binaries(
+        mut self,
+        hypervisor: impl Into<PathBuf>,
+        virtiofsd: impl Into<PathBuf>,
+    ) -> Self {
+        self.hypervisor = hypervisor.into();
+        self.virtiofsd = virtiofsd.into();
+        self
+    }
+
+    /// Check that KVM, the binaries and the guest images are present
+    pub fn check_available(&self) -> Result<()> {
+        if !cfg!(target_os = "linux") {
+            return Err(unavailable("requires Linux with KVM"));
+        }
+        if !Path::new("/dev/kvm").exists() {
+            return Err(unavailable("/dev/kvm not found"));
+        }
+        for binary in [&self.hypervisor, &self.virtiofsd] {
+            if find_executable(binary).is_none() {
+                return Err(unavailable(&format!("{} not found", binary.display())));
+            }
+        }
+        for image in [&self.kernel, &self.rootfs] {
+            if !image.is_file() {
+                return Err(unavailable(&format!(
+                    "guest image {} not found",
+                    image.display()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the microVM backend can run on this host
+    pub fn is_available(&self) -> bool {
+        self.check_available().is_ok()
+    }
+}
+
+/// A directory shared with the guest over virtio-fs
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Share {
+    tag: String,
+    host: PathBuf,
+    guest: String,
+    writable: bool,
+}
+
+/// Execute a command in a fresh microVM
+///
+/// Fails closed: an unavailable backend is an error, never a silent
+/// fallback to unsandboxed execution.
+pub(crate) async fn execute(
+    config: &SandboxConfig,
+    command: &str,
+    working_dir: &Path,
+) -> Result<SandboxResult> {
+    let vm = &config.microvm;
+    vm.check_available()?;
+
+    let run_dir = std::env::temp_dir().join(format!("forge-microvm-{}", uuid::Uuid::new_v4()));
+    let result = run(config, command, working_dir, &run_dir).await;
+    if let Err(e) = tokio::fs::remove_dir_all(&run_dir).await {
+        debug!("Failed to remove {}: {}", run_dir.display(), e);
+    }
+    result
+}
+
+async fn run(
+    config: &SandboxConfig,
+    command: &str,
+    working_dir: &Path,
+    run_dir: &Path,
+) -> Result<SandboxResult> {
+    let vm = &config.microvm;
+    let job_dir = run_dir.join("job");
+    let socket_dir = run_dir.join("sockets");
+    tokio::fs::create_dir_all(&job_dir).await?;
+    tokio::fs::create_dir_all(&socket_dir).await?;
+
+    let shares = shares(config, working_dir, &job_dir);
+    let network = network_device(config);
+
+    // Job files read by the guest init
+    tokio::fs::write(job_dir.join("run.sh"), run_script(config, command)).await?;
+    tokio::fs::write(job_dir.join("mounts"), mounts_file(&shares)).await?;
+    if network.is_some() {
+        tokio::fs::write(job_dir.join("network"), "").await?;
+    }
+
+    // One virtiofsd per share
+    let mut daemons = Vec::with_capacity(shares.len());
+    for share in &shares {
+        daemons.push(spawn_virtiofsd(vm, share, &socket_dir)?);
+    }
+    wait_for_sockets(&shares, &socket_dir).await?;
+
+    let console_log = run_dir.join("console.log");
+    let vmm_log = run_dir.join("vmm.log");
+    let args = hypervisor_args(vm, &shares, &socket_dir, network, &console_log);
+    debug!(
+        "Starting microVM: {} {}",
+        vm.hypervisor.display(),
+        args.join(" ")
+    );
+
+    let started = Instant::now();
+    let mut vmm = Command::new(&vm.hypervisor)
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(std::fs::File::create(&vmm_log)?)
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| Error::Task(format!("Failed to start cloud-hypervisor: {}", e)))?;
+
+    let timeout = Duration::from_millis(config.timeout_ms);
+    let status = match tokio::time::timeout(timeout, vmm.wait()).await {
+        Ok(status) => status?,
+        Err(_) => {
+            let _ = vmm.kill().await;
+            stop_daemons(daemons).await;
+            return Err(Error::Timeout("MicroVM command timed out".to_string()));
+        }
+    };
+    stop_daemons(daemons).await;
+    debug!(
+        "MicroVM exited with {} after {:.1}s",
+        status,
+        started.elapsed().as_secs_f64()
+    );
+
+    let read = |name: &str| read_job_file(&job_dir, name);
+    let exit_code = read("exit_code").and_then(|code| code.trim().parse::<i32>().ok());
+
+    let Some(exit_code) = exit_code else {
+        // Guest never reported back (bad image, kernel panic, VMM error)
+        let mut stderr = String::from("sandbox: microVM exited without a result");
+        if let Some(vmm_output) = read_tail(&vmm_log, 20) {
+            stderr.push('\n');
+            stderr.push_str(&vmm_output);
+        }
+        if let Some(console) = read_tail(&console_log, 20) {
+            stderr.push_str("\n--- guest console ---\n");
+            stderr.push_str(&console);
+        }
+        return Ok(SandboxResult {
+            stdout: String::new(),
+            stderr,
+            exit_code: -1,
+            sandboxed: true,
+            sandbox_type: SandboxType::MicroVm,
+        });
+    };
+
+    Ok(SandboxResult {
+        stdout: read("stdout").unwrap_or_default(),
+        stderr: read("stderr").unwrap_or_default(),
+        exit_code,
+        sandboxed: true,
+        sandbox_type: SandboxType::MicroVm,
+    })
+}
+
+/// Shares for the job directory, the project and the extra paths
+fn shares(config: &SandboxConfig, working_dir: &Path, job_dir: &Path) -> Vec<Share> {
+    let mut shares = vec![
+        Share {
+            tag: "job".to_string(),
+            host: job_dir.to_path_buf(),
+            guest: "/mnt/job".to_string(),
+            writable: true,
+        },
+        Share {
+            tag: "project".to_string(),
+            host: working_dir.to_path_buf(),
+            guest: GUEST_WORKSPACE.to_string(),
+            writable: config.microvm.project_writable,
+        },
+    ];
+
+    let extra = config
+        .allowed_read_paths
+        .iter()
+        .map(|path| (path, false))
+        .chain(config.allowed_write_paths.iter().map(|path| (path, true)));
+    for (index, (path, writable)) in extra.enumerate() {
+        shares.push(Share {
+            tag: format!("host{}", index),
+            host: path.clone(),
+            guest: format!(
+                "{}/{}",
+                GUEST_HOST_PREFIX,
+                path.to_string_lossy().trim_start_matches('/')
+            ),
+            writable,
+        });
+    }
+
+    shares
+}
+
+/// Tap device to attach, if network is allowed and configured
+fn network_device(config: &SandboxConfig) -> Option<&str> {
+    if !config.allow_network {
+        return None;
+    }
+    match config.microvm.tap_device.as_deref() {
+        Some(tap) => {
+            if !config.allowed_hosts.is_empty() {
+                warn!(
+                    "MicroVM sandbox cannot filter hosts; restrict {} on the host instead",
+                    tap
+                );
+            }
+            Some(tap)
+        }
+        None => {
+            warn!("Network allowed but no tap device configured; microVM runs without network");
+            None
+        }
+    }
+}
+
+/// `/mnt/job/mounts`: `tag guest_path ro|rw` per line (the job share is mounted by init)
+fn mounts_file(shares: &[Share]) -> String {
+    shares
+        .iter()
+        .filter(|share| share.tag != "job")
+        .map(|share| {
+            format!(
+                "{} {} {}\n",
+                share.tag,
+                share.guest,
+                if share.writable { "rw" } else { "ro" }
+            )
+        })
+        .collect()
+}
+
+/// `/mnt/job/run.sh`: passthrough environment, then the command in `/workspace`
+fn run_script(config: &SandboxConfig, command: &str) -> String {
+    let mut script = String::from("#!/bin/sh\n");
+    for var in &config.env_passthrough {
+        if let Ok(value) = std::env::var(var) {
+            script.push_str(&format!("export {}={}\n", var, shell_quote(&value)));
+        }
+    }
+    script.push_str(&format!("cd {} || exit 1\n", GUEST_WORKSPACE));
+    script.push_str(command);
+    script.push('\n');
+    script
+}
+
+fn hypervisor_args(
+    vm: &MicroVmConfig,
+    shares: &[Share],
+    socket_dir: &Path,
+    network: Option<&str>,
+    console_log: &Path,
+) -> Vec<String> {
+    let mut args = vec![
+        "--kernel".to_string(),
+        vm.kernel.display().to_string(),
+        "--cmdline".to_string(),
+        KERNEL_CMDLINE.to_string(),
+        "--disk".to_string(),
+        format!("path={},readonly=on", vm.rootfs.display()),
+        "--cpus".to_string(),
+        format!("boot={}", vm.vcpus),
+        // virtio-fs needs shared guest memory
+        "--memory".to_string(),
+        format!("size={}M,shared=on", vm.memory_mib),
+    ];
+
+    for share in shares {
+        args.push("--fs".to_string());
+        args.push(format!(
+            "tag={},socket={}",
+            share.tag,
+            socket_path(socket_dir, share).display()
+        ));
+    }
+
+    if let Some(tap) = network {
+        args.push("--net".to_string());
+        args.push(format!("tap={}", tap));
+    }
+
+    args.push("--serial".to_string());
+    args.push("off".to_string());
+    args.push("--console".to_string());
+    args.push(format!("file={}", console_log.display()));
+    args
+}
+
+fn socket_path(socket_dir: &Path, share: &Share) -> PathBuf {
+    socket_dir.join(format!("{}.sock", share.tag))
+}
+
+fn spawn_virtiofsd(vm: &MicroVmConfig, share: &Share, socket_dir: &Path) -> Result<Child> {
+    let mut cmd = Command::new(&vm.virtiofsd);
+    cmd.arg(format!(
+        "--socket-path={}",
+        socket_path(socket_dir, share).display()
+    ))
+    .arg(format!("--shared-dir={}", share.host.display()))
+    .arg("--cache=never")
+    .stdin(Stdio::null())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .kill_on_drop(true);
+    if !share.writable {
+        cmd.arg("--readonly");
+    }
+    cmd.spawn()
+        .map_err(|e| Error::Task(format!("Failed to start virtiofsd: {}", e)))
+}
+
+async fn wait_for_sockets(shares: &[Share], socket_dir: &Path) -> Result<()> {
+    let deadline = Instant::now() + DAEMON_START_TIMEOUT;
+    for share in shares {
+        let socket = socket_path(socket_dir, share);
+        while !socket.exists() {
+            if Instant::now() >= deadline {
+                return Err(Error::Timeout(format!(
+                    "virtiofsd did not start for {}",
+                    share.host.display()
+                )));
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    }
+    Ok(())
+}
+
+async fn stop_daemons(daemons: Vec<Child>) {
+    for mut daemon in daemons {
+        let _ = daemon.kill().await;
+    }
+}
+
+/// Read a result file the guest wrote to the job share
+///
+/// The share is guest-writable, so only regular files are read (a symlink to
+/// `~/.ssh/id_rsa` or a FIFO is ignored) and at most `MAX_RESULT_BYTES`.
+fn read_job_file(job_dir: &Path, name: &str) -> Option<String> {
+    use std::io::Read;
+
+    let path = job_dir.join(name);
+    let metadata = std::fs::symlink_metadata(&path).ok()?;
+    if !metadata.file_type().is_file() {
+        warn!("Ignoring microVM result {}: not a regular file", name);
+        return None;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.read(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.custom_flags(libc::O_NOFOLLOW);
+    }
+    let file = options.open(&path).ok()?;
+
+    let mut content = Vec::new();
+    file.take(MAX_RESULT_BYTES + 1)
+        .read_to_end(&mut content)
+        .ok()?;
+    let truncated = content.len() as u64 > MAX_RESULT_BYTES;
+    content.truncate(MAX_RESULT_BYTES as usize);
+
+    let mut text = String::from_utf8_lossy(&content).into_owned();
+    if truncated {
+        text.push_str("\n[output truncated]");
+    }
+    Some(text)
+}
+
+fn read_tail(path: &Path, lines: usize) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let all: Vec<&str> = content.lines().collect();
+    let tail = all[all.len().saturating_sub(lines)..].join("\n");
+    (!tail.trim().is_empty()).then_some(tail)
+}
+
+fn find_executable(binary: &Path) -> Option<PathBuf> {
+    if binary.components().count() > 1 {
+        return binary.is_file().then(|| binary.to_path_buf());
+    }
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join(binary))
+        .find(|path| path.is_file())
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+fn unavailable(reason: &str) -> Error {
+    Error::Task(format!("MicroVM sandbox unavailable: {}", reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> SandboxConfig {
+        SandboxConfig::microvm(
+            MicroVmConfig::new("/images/vmlinux", "/images/rootfs.ext4").with_resources(2, 1024),
+        )
+        .allow_read("/opt/data")
+        .allow_write("/var/cache/build")
+    }
+
+    #[test]
+    fn test_shares_follow_policy() {
+        let config = test_config();
+        let shares = shares(
+            &config,
+            Path::new("/home/user/project"),
+            Path::new("/run/job"),
+        );
+
+        let project = shares.iter().find(|s| s.tag == "project").unwrap();
+        assert_eq!(project.guest, "/workspace");
+        assert!(!project.writable, "project must be read-only by default");
+
+        assert_eq!(
+            mounts_file(&shares),
+            "project /workspace ro\n\
+             host0 /mnt/host/opt/data ro\n\
+             host1 /mnt/host/var/cache/build rw\n"
+        );
+
+        let writable = SandboxConfig::microvm(MicroVmConfig::default().with_writable_project(true));
+        let shares = shares_for(&writable);
+        assert!(shares.iter().find(|s| s.tag == "project").unwrap().writable);
+    }
+
+    fn shares_for(config: &SandboxConfig) -> Vec<Share> {
+        shares(config, Path::new("/p"), Path::new("/j"))
+    }
+
+    #[test]
+    fn test_hypervisor_args() {
+        let config = test_config();
+        let shares = shares_for(&config);
+        let args = hypervisor_args(
+            &config.microvm,
+            &shares,
+            Path::new("/run/sockets"),
+            None,
+            Path::new("/run/console.log"),
+        );
+        let joined = args.join(" ");
+
+        assert!(joined.contains("--disk path=/images/rootfs.ext4,readonly=on"));
+        assert!(joined.contains("--cpus boot=2"));
+        assert!(joined.contains("--memory size=1024M,shared=on"));
+        assert!(joined.contains("--fs tag=project,socket=/run/sockets/project.sock"));
+        assert!(joined.contains("--fs tag=host1,socket=/run/sockets/host1.sock"));
+        assert!(!joined.contains("--net"));
+
+        // Network only with allow_network and a tap device
+        let mut config = config;
+        assert_eq!(network_device(&config), None);
+        config.allow_network = true;
+        assert_eq!(network_device(&config), None);
+        config.microvm.tap_device = Some("forge-tap0".to_string());
+        assert_eq!(network_device(&config), Some("forge-tap0"));
+    }
+
+    #[test]
+    fn test_run_script() {
+        let mut config = test_config();
+        config.env_passthrough = vec!["FORGE_MICROVM_TEST".to_string()];
+        std::env::set_var("FORGE_MICROVM_TEST", "it's");
+
+        let script = run_script(&config, "cargo test");
+        assert!(script.contains("export FORGE_MICROVM_TEST='it'\\''s'\n"));
+        assert!(script.ends_with("cd /workspace || exit 1\ncargo test\n"));
+        std::env::remove_var("FORGE_MICROVM_TEST");
+    }
+
+    #[test]
+    fn test_read_job_file_rejects_links() {
+        let job_dir =
+            std::env::temp_dir().join(format!("forge-microvm-job-{}", std::process::id()));
+        std::fs::create_dir_all(&job_dir).unwrap();
+        std::fs::write(job_dir.join("exit_code"), "0\n").unwrap();
+        std::fs::write(job_dir.join("host-secret"), "PRIVATE KEY").unwrap();
+
+        assert_eq!(read_job_file(&job_dir, "exit_code").as_deref(), Some("0\n"));
+        assert_eq!(read_job_file(&job_dir, "stderr"), None);
+
+        // A guest-planted symlink to a host file is not followed
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(job_dir.join("host-secret"), job_dir.join("stdout"))
+                .unwrap();
+            assert_eq!(read_job_file(&job_dir, "stdout"), None);
+        }
+
+        // Oversized output is capped
+        let big = std::fs::File::create(job_dir.join("big")).unwrap();
+        big.set_len(MAX_RESULT_BYTES + 10).unwrap();
+        let text = read_job_file(&job_dir, "big").unwrap();
+        assert!(text.ends_with("[output truncated]"));
+        assert!(text.len() < MAX_RESULT_BYTES as usize + 32);
+
+        std::fs::remove_dir_all(&job_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_fails_closed() {
+        let config = SandboxConfig::microvm(MicroVmConfig::new(
+            "/nonexistent/vmlinux",
+            "/nonexistent/rootfs.ext4",
+        ));
+        assert!(!config.microvm.is_available());
+
+        let err = execute(&config, "echo hi", &std::env::temp_dir())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("MicroVM sandbox unavailable"));
+    }
+}
@@ -4,7 +4,8 @@
 //! - `LocalExecutor` - Simple process execution with log streaming
 //! - `PtyExecutor` - Full PTY support for interactive commands
 //! - `ContainerExecutor` - Docker-based isolated execution
-//! - `SandboxExecutor` - Platform-native sandboxed execution (Seatbelt/Landlock)
+//! - `K8sExecutor` - Kubernetes Job execution for heavy remote tasks
+//! - `SandboxExecutor` - Platform-native sandboxed execution (Seatbelt/Landlock/microVM)
 //!
 //! ## Security
 //! - `ShellPolicy` - Command-level permission control for shell commands
@@ -13,9 +14,12 @@
 //! ## Resource Monitoring
 //! - `ResourceMonitor` - CPU/Memory usage tracking for processes
 //! - `ProcessResourceLimits` - Per-process resource limits
+//! - `ResourceWatch` - Per-task quota enforcement (kill/throttle) and peak usage
 
 pub mod container;
+pub mod kubernetes;
 pub mod local;
+pub mod microvm;
 pub mod pty;
 pub mod resource_monitor;
 pub mod sandbox;
@@ -23,12 +27,15 @@ pub mod shell_policy;
 pub mod r#trait;
 
 pub use container::ContainerExecutor;
+pub use kubernetes::{K8sConfig, K8sExecutor};
 pub use local::{LocalExecutor, LocalExecutorConfig, TimeoutPolicy, TimeoutState};
+pub use microvm::MicroVmConfig;
 pub use pty::{PtyEnvSecurityConfig, PtyExecutor, PtyExecutorConfig, PtySizeConfig};
 pub use r#trait::Executor;
 pub use sandbox::{SandboxConfig, SandboxExecutor, SandboxPolicy, SandboxResult, SandboxType};
 pub use shell_policy::{PolicyResult, RiskLevel, ShellPolicy, TaskShellPolicy};
 pub use resource_monitor::{
     LimitExceededAction, ProcessResourceLimits, ProcessResourceTracker,
-    ResourceMonitor, ResourceSnapshot, ResourceViolation, ViolationType,
+    ResourceMonitor, ResourceSnapshot, ResourceUsage, ResourceViolation,
+    ResourceViolationEvent, ResourceWatch, ViolationType,
 };
@@ -22,14 +22,21 @@
 //! - Logs are collected asynchronously in background
 //! - Use `get_logs()` to retrieve output
 //! - Use `force_kill()` to terminate
+//!
+//! ## Resource Quotas
+//!
+//! Both modes watch the process tree with `ResourceMonitor`:
+//! `Task::resource_limits` is enforced and peak usage is reported in
+//! `TaskResult::resource_usage`.
 
+use crate::executor::resource_monitor::{ProcessResourceLimits, ResourceMonitor, ResourceWatch};
 use crate::executor::Executor;
 use crate::log::{LogEntry, TaskLogManager};
 use crate::state::TaskState;
 use crate::task::{Task, TaskResult};
 use async_trait::async_trait;
 use forge_foundation::{Error, Result};
-use portable_pty::{native_pty_system, CommandBuilder, PtyPair, PtySize};
+use portable_pty::{native_pty_system, CommandBuilder, MasterPty, PtySize};
 use std::collections::HashMap;
 use std::io::Read;
 use std::path::PathBuf;
@@ -58,8 +65,8 @@ impl Default for PtySizeConfig {
 /// PTY session state
 #[allow(dead_code)]
 struct PtySessionState {
-    /// PTY pair (master + slave)
-    pty: Option<PtyPair>,
+    /// PTY master (slave is dropped after spawn so the reader sees EOF on exit)
+    master: Option<Box<dyn MasterPty + Send>>,
 
     /// Child process handle
     child: Option<Box<dyn portable_pty::Child + Send + Sync>>,
@@ -84,6 +91,9 @@ struct PtySessionState {
 
     /// Background log collector handle
     log_collector_handle: Option<tokio::task::JoinHandle<()>>,
+
+    /// Resource usage watch (finished by the background log collector)
+    resource_watch: Option<ResourceWatch>,
 }
 
 /// Environment security configuration for PTY
@@ -326,23 +336,24 @@ pub struct PtyExecutor {
 
     /// Configuration
     config: PtyExecutorConfig,
+
+    /// Resource usage tracking and quota enforcement
+    resource_monitor: Arc<ResourceMonitor>,
 }
 
 impl PtyExecutor {
     /// Create a new PTY executor
     pub fn new() -> Self {
-        Self {
-            sessions: Arc::new(RwLock::new(HashMap::new())),
-            log_manager: Arc::new(TaskLogManager::new()),
-            config: PtyExecutorConfig::default(),
-        }
+        Self::with_config(PtyExecutorConfig::default())
     }
 
     /// Create with custom configuration
     pub fn with_config(config: PtyExecutorConfig) -> Self {
+        let log_manager = Arc::new(TaskLogManager::new());
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
-            log_manager: Arc::new(TaskLogManager::new()),
+            resource_monitor: Self::default_resource_monitor(&log_manager),
+            log_manager,
             config,
         }
     }
@@ -351,16 +362,50 @@ impl PtyExecutor {
     pub fn with_log_manager(log_manager: Arc<TaskLogManager>) -> Self {
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
+            resource_monitor: Self::default_resource_monitor(&log_manager),
             log_manager,
             config: PtyExecutorConfig::default(),
         }
     }
 
+    /// Use a shared resource monitor (default limits, violation events)
+    pub fn with_resource_monitor(mut self, monitor: Arc<ResourceMonitor>) -> Self {
+        self.resource_monitor = monitor;
+        self
+    }
+
+    /// Measure only; tasks opt into quotas via `Task::resource_limits`
+    fn default_resource_monitor(log_manager: &Arc<TaskLogManager>) -> Arc<ResourceMonitor> {
+        Arc::new(
+            ResourceMonitor::new(ProcessResourceLimits::unlimited())
+                .with_log_manager(Arc::clone(log_manager)),
+        )
+    }
+
     /// Get the log manager
     pub fn log_manager(&self) -> Arc<TaskLogManager> {
         Arc::clone(&self.log_manager)
     }
 
+    /// Get the resource monitor
+    pub fn resource_monitor(&self) -> Arc<ResourceMonitor> {
+        Arc::clone(&self.resource_monitor)
+    }
+
+    /// Start watching a spawned PTY child
+    async fn watch_resources(
+        &self,
+        task: &Task,
+        child: &(dyn portable_pty::Child + Send + Sync),
+    ) -> Option<ResourceWatch> {
+        let pid = child.process_id()?;
+        Some(
+            self.resource_monitor
+                .watch(&task.id.to_string(), pid, task.resource_limits.clone())
+                .await,
+        )
+    }
+
     /// Validate command against shell policy
     ///
     /// Returns Ok(()) if command is allowed, Err with reason if denied.
@@ -554,6 +599,8 @@ impl PtyExecutor {
             .slave
             .spawn_command(cmd)
             .map_err(|e| Error::Task(format!("Failed to spawn PTY command: {}", e)))?;
+        drop(pty.slave);
+        let resource_watch = self.watch_resources(task, child.as_ref()).await;
 
         // Get reader for log collection
         let reader = pty
@@ -565,7 +612,7 @@ impl PtyExecutor {
 
         // Store session state
         let session_state = Arc::new(Mutex::new(PtySessionState {
-            pty: Some(pty),
+            master: Some(pty.master),
             child: Some(child),
             task_id: task_id.clone(),
             command: task.command.clone(),
@@ -574,6 +621,7 @@ impl PtyExecutor {
             status: TaskState::Running,
             exit_code: None,
             log_collector_handle: None,
+            resource_watch,
         }));
 
         // Spawn background log collector
@@ -727,6 +775,18 @@ impl PtyExecutor {
         if matches!(state.status, TaskState::Running) {
             state.status = TaskState::Completed(TaskResult::success(accumulated_output));
         }
+
+        // Stop resource monitoring
+        if let Some(watch) = state.resource_watch.take() {
+            let enforced = watch.enforced();
+            let usage = watch.finish().await;
+            if let Some(violation) = enforced {
+                state.status =
+                    TaskState::Failed(format!("Resource limit exceeded - {}", violation));
+            } else if let TaskState::Completed(result) = &mut state.status {
+                result.resource_usage = usage;
+            }
+        }
     }
 
     /// Get logs for a task
@@ -850,6 +910,8 @@ impl PtyExecutor {
             .slave
             .spawn_command(cmd)
             .map_err(|e| Error::Task(format!("Failed to spawn PTY command: {}", e)))?;
+        drop(pty.slave);
+        let resource_watch = self.watch_resources(task, child.as_ref()).await;
 
         // Get reader
         let mut reader = pty
@@ -861,7 +923,7 @@ impl PtyExecutor {
 
         // Store session state
         let session_state = Arc::new(Mutex::new(PtySessionState {
-            pty: Some(pty),
+            master: Some(pty.master),
             child: Some(child),
             task_id: task_id.clone(),
             command: task.command.clone(),
@@ -870,6 +932,7 @@ impl PtyExecutor {
             status: TaskState::Running,
             exit_code: None,
             log_collector_handle: None,
+            resource_watch: None,
         }));
 
         {
@@ -944,6 +1007,13 @@ impl PtyExecutor {
             sessions.remove(&task_id);
         }
 
+        // Stop resource monitoring
+        let enforced = resource_watch.as_ref().and_then(|watch| watch.enforced());
+        let resource_usage = match resource_watch {
+            Some(watch) => watch.finish().await,
+            None => None,
+        };
+
         // Process output
         let stdout = String::from_utf8_lossy(&output).to_string();
         let stdout_clean = strip_ansi(&stdout);
@@ -959,11 +1029,20 @@ impl PtyExecutor {
         self.log_manager.push_stdout(&task_id, &stdout_masked).await;
         self.log_manager.mark_ended(&task_id).await;
 
+        if let Some(violation) = enforced {
+            return Err(Error::Task(format!(
+                "Resource limit exceeded - {}",
+                violation
+            )));
+        }
         if was_killed {
             return Err(Error::Task("Process was killed".to_string()));
         }
 
-        Ok(TaskResult::with_exit_code(stdout_masked, exit_code))
+        Ok(
+            TaskResult::with_exit_code(stdout_masked, exit_code)
+                .with_resource_usage(resource_usage),
+        )
     }
 
     /// Wait for a background task to complete
@@ -1039,6 +1118,15 @@ impl Executor for PtyExecutor {
     fn name(&self) -> &'static str {
         "pty"
     }
+
+    fn artifact_dir(&self, task: &Task) -> Option<PathBuf> {
+        // Same precedence as the PTY command's cwd
+        ["cwd", "working_dir"]
+            .iter()
+            .find_map(|key| task.input.get(*key)?.as_str())
+            .map(PathBuf::from)
+            .or_else(|| std::env::current_dir().ok())
+    }
 }
 
 /// Check if a pattern matches a string (simple glob-like matching)
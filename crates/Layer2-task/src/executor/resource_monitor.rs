@@ -5,19 +5,32 @@
 //! ## 기능
 //! - CPU 사용률 추적
 //! - 메모리 사용량 추적
-//! - 리소스 제한 초과 시 경고/종료
+//! - 리소스 제한 초과 시 경고/일시정지/속도 제한/종료
 //! - 리소스 사용 기록 (히스토리)
+//! - 위반 이벤트 발행 (`ResourceMonitor::subscribe`)
+//!
+//! ## Task별 제한
+//! executor는 프로세스 시작 직후 `ResourceMonitor::watch`로 감시를 시작하고,
+//! 종료 후 `ResourceWatch::finish`로 피크 사용량(`ResourceUsage`)을 받아
+//! `TaskResult::resource_usage`에 기록합니다.
 //!
 //! ## 플랫폼 지원
-//! - Windows: WMI/PDH API를 통한 프로세스 모니터링
-//! - Unix: /proc 파일시스템 또는 sysinfo를 통한 모니터링
+//! - Linux: /proc 파일시스템으로 프로세스 트리(자식 포함) 측정
+//! - 그 외: 측정하지 않음 (제한 미적용, `resource_usage`는 None)
 
-use std::collections::HashMap;
+use crate::log::TaskLogManager;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
 use tracing::{debug, warn};
 
+/// 위반 이벤트 채널 크기
+const EVENT_CAPACITY: usize = 64;
+
 /// 리소스 사용량 스냅샷
 #[derive(Debug, Clone)]
 pub struct ResourceSnapshot {
@@ -102,7 +115,7 @@ fn format_bytes(bytes: u64) -> String {
 }
 
 /// 프로세스 리소스 제한
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessResourceLimits {
     /// 최대 CPU 사용률 (%)
     pub max_cpu_percent: Option<f64>,
@@ -209,18 +222,49 @@ fn parse_memory_string(s: &str) -> Option<u64> {
 }
 
 /// 제한 초과 시 동작
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LimitExceededAction {
     /// 경고만 로깅
     Warn,
+    /// CPU 속도 제한 (한 측정 주기 동안 SIGSTOP 후 SIGCONT)
+    Throttle,
     /// 프로세스 일시 중지 (SIGSTOP)
     Pause,
-    /// 프로세스 종료 (SIGTERM)
+    /// 프로세스 종료 (SIGTERM, 계속 초과하면 SIGKILL)
     Terminate,
     /// 강제 종료 (SIGKILL)
     Kill,
 }
 
+impl LimitExceededAction {
+    /// 위반 종류에 실제로 적용할 동작
+    ///
+    /// 속도 제한은 CPU에만 의미가 있으므로 메모리/시간 초과는 강제 종료합니다.
+    pub fn effective_for(self, violation_type: ViolationType) -> Self {
+        match (self, violation_type) {
+            (Self::Throttle, ViolationType::CpuExceeded) => Self::Throttle,
+            (Self::Throttle, _) => Self::Kill,
+            (action, _) => action,
+        }
+    }
+
+    /// 프로세스를 끝내는 동작인지
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Terminate | Self::Kill)
+    }
+
+    fn past_tense(self) -> &'static str {
+        match self {
+            Self::Warn => "warned",
+            Self::Throttle => "throttled",
+            Self::Pause => "paused",
+            Self::Terminate => "terminated",
+            Self::Kill => "killed",
+        }
+    }
+}
+
 /// 리소스 위반 이벤트
 #[derive(Debug, Clone)]
 pub struct ResourceViolation {
@@ -236,8 +280,65 @@ pub struct ResourceViolation {
     pub action_taken: LimitExceededAction,
 }
 
+impl std::fmt::Display for ResourceViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (current, limit) = match self.violation_type {
+            ViolationType::CpuExceeded => (
+                format!("{:.1}%", self.current_value),
+                format!("{:.1}%", self.limit_value),
+            ),
+            ViolationType::MemoryExceeded | ViolationType::VirtualMemoryExceeded => (
+                format_bytes(self.current_value as u64),
+                format_bytes(self.limit_value as u64),
+            ),
+            ViolationType::DurationExceeded => (
+                format!("{:.1}s", self.current_value),
+                format!("{:.1}s", self.limit_value),
+            ),
+        };
+        write!(
+            f,
+            "{}: {} > {} (process {})",
+            self.violation_type,
+            current,
+            limit,
+            self.action_taken.past_tense()
+        )
+    }
+}
+
+/// 위반 이벤트 (`ResourceMonitor::subscribe`)
+#[derive(Debug, Clone)]
+pub struct ResourceViolationEvent {
+    /// 위반한 Task ID
+    pub task_id: String,
+    /// 감시 중인 프로세스 ID
+    pub pid: u32,
+    /// 위반 내용
+    pub violation: ResourceViolation,
+}
+
+/// Task 실행 중 리소스 사용량 요약 (`TaskResult::resource_usage`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    /// 피크 CPU 사용률 (%)
+    pub peak_cpu_percent: f64,
+    /// 피크 메모리 (bytes)
+    pub peak_memory_bytes: u64,
+    /// 평균 CPU 사용률 (%)
+    pub average_cpu_percent: f64,
+    /// 평균 메모리 (bytes)
+    pub average_memory_bytes: u64,
+    /// 측정 횟수
+    pub samples: usize,
+    /// 발생한 위반 종류
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<ViolationType>,
+}
+
 /// 위반 종류
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ViolationType {
     CpuExceeded,
     MemoryExceeded,
@@ -282,6 +383,12 @@ pub struct ProcessResourceTracker {
 
     /// 피크 메모리
     pub peak_memory_bytes: u64,
+
+    /// 전체 측정 횟수 (히스토리 크기와 무관)
+    sample_count: usize,
+
+    /// 직전 CPU 누적 시간 (clock ticks, 측정 시각)
+    last_cpu: Option<(u64, Instant)>,
 }
 
 impl ProcessResourceTracker {
@@ -295,11 +402,67 @@ impl ProcessResourceTracker {
             violations: Vec::new(),
             peak_cpu_percent: 0.0,
             peak_memory_bytes: 0,
+            sample_count: 0,
+            last_cpu: None,
         }
     }
 
+    /// 프로세스 트리(자식 포함)의 현재 사용량 측정 및 기록
+    ///
+    /// 프로세스가 종료되었거나 지원하지 않는 플랫폼이면 None
+    #[cfg(target_os = "linux")]
+    pub fn sample(&mut self) -> Option<ResourceSnapshot> {
+        let pids = procfs::process_tree(self.pid);
+        let stats: Vec<procfs::ProcStat> = pids
+            .iter()
+            .filter_map(|pid| procfs::read_stat(*pid))
+            .collect();
+        if stats.is_empty() {
+            return None;
+        }
+
+        let now = Instant::now();
+        let cpu_ticks: u64 = stats.iter().map(|s| s.cpu_ticks).sum();
+        let cpu_percent = match self.last_cpu {
+            Some((last_ticks, last_at)) => {
+                let elapsed = now.duration_since(last_at).as_secs_f64();
+                if elapsed > 0.0 {
+                    cpu_ticks.saturating_sub(last_ticks) as f64
+                        / procfs::clock_ticks() as f64
+                        / elapsed
+                        * 100.0
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+        self.last_cpu = Some((cpu_ticks, now));
+
+        let snapshot = ResourceSnapshot {
+            cpu_percent,
+            memory_bytes: stats.iter().map(|s| s.rss_pages).sum::<u64>() * procfs::page_size(),
+            virtual_memory_bytes: stats.iter().map(|s| s.vsize).sum(),
+            thread_count: stats.iter().map(|s| s.threads).sum(),
+            timestamp: now,
+            ..Default::default()
+        };
+        self.record_snapshot(snapshot.clone());
+        Some(snapshot)
+    }
+
+    /// 프로세스 트리(자식 포함)의 현재 사용량 측정 및 기록
+    ///
+    /// 프로세스가 종료되었거나 지원하지 않는 플랫폼이면 None
+    #[cfg(not(target_os = "linux"))]
+    pub fn sample(&mut self) -> Option<ResourceSnapshot> {
+        None
+    }
+
     /// 리소스 스냅샷 기록
     pub fn record_snapshot(&mut self, snapshot: ResourceSnapshot) {
+        self.sample_count += 1;
+
         // 피크 업데이트
         if snapshot.cpu_percent > self.peak_cpu_percent {
             self.peak_cpu_percent = snapshot.cpu_percent;
@@ -325,7 +488,10 @@ impl ProcessResourceTracker {
                     current_value: snapshot.cpu_percent,
                     limit_value: max_cpu,
                     timestamp: Instant::now(),
-                    action_taken: self.limits.on_limit_exceeded,
+                    action_taken: self
+                        .limits
+                        .on_limit_exceeded
+                        .effective_for(ViolationType::CpuExceeded),
                 };
                 warn!(
                     "PID {}: CPU usage {:.1}% exceeds limit {:.1}%",
@@ -344,7 +510,10 @@ impl ProcessResourceTracker {
                     current_value: snapshot.memory_bytes as f64,
                     limit_value: max_mem as f64,
                     timestamp: Instant::now(),
-                    action_taken: self.limits.on_limit_exceeded,
+                    action_taken: self
+                        .limits
+                        .on_limit_exceeded
+                        .effective_for(ViolationType::MemoryExceeded),
                 };
                 warn!(
                     "PID {}: Memory {} exceeds limit {}",
@@ -365,7 +534,10 @@ impl ProcessResourceTracker {
                     current_value: snapshot.virtual_memory_bytes as f64,
                     limit_value: max_vmem as f64,
                     timestamp: Instant::now(),
-                    action_taken: self.limits.on_limit_exceeded,
+                    action_taken: self
+                        .limits
+                        .on_limit_exceeded
+                        .effective_for(ViolationType::VirtualMemoryExceeded),
                 };
                 warn!(
                     "PID {}: Virtual memory {} exceeds limit {}",
@@ -387,7 +559,10 @@ impl ProcessResourceTracker {
                     current_value: elapsed.as_secs_f64(),
                     limit_value: max_dur.as_secs_f64(),
                     timestamp: Instant::now(),
-                    action_taken: self.limits.on_limit_exceeded,
+                    action_taken: self
+                        .limits
+                        .on_limit_exceeded
+                        .effective_for(ViolationType::DurationExceeded),
                 };
                 warn!(
                     "PID {}: Duration {:.1}s exceeds limit {:.1}s",
@@ -436,6 +611,25 @@ impl ProcessResourceTracker {
         self.started_at.elapsed()
     }
 
+    /// 사용량 요약 (`TaskResult::resource_usage`)
+    pub fn usage(&self) -> ResourceUsage {
+        let mut violations = Vec::new();
+        for violation in &self.violations {
+            if !violations.contains(&violation.violation_type) {
+                violations.push(violation.violation_type);
+            }
+        }
+
+        ResourceUsage {
+            peak_cpu_percent: self.peak_cpu_percent,
+            peak_memory_bytes: self.peak_memory_bytes,
+            average_cpu_percent: self.average_cpu(),
+            average_memory_bytes: self.average_memory(),
+            samples: self.sample_count,
+            violations,
+        }
+    }
+
     /// 요약 리포트 생성
     pub fn summary_report(&self) -> String {
         format!(
@@ -461,6 +655,12 @@ pub struct ResourceMonitor {
 
     /// 모니터링 간격
     poll_interval: Duration,
+
+    /// 위반 이벤트 발행
+    events: broadcast::Sender<ResourceViolationEvent>,
+
+    /// 위반 내용을 Task 로그에 남길 로그 관리자
+    log_manager: Option<Arc<TaskLogManager>>,
 }
 
 impl Default for ResourceMonitor {
@@ -471,10 +671,13 @@ impl Default for ResourceMonitor {
 
 impl ResourceMonitor {
     pub fn new(default_limits: ProcessResourceLimits) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
         Self {
             trackers: Arc::new(RwLock::new(HashMap::new())),
             default_limits,
             poll_interval: Duration::from_millis(500),
+            events,
+            log_manager: None,
         }
     }
 
@@ -484,6 +687,63 @@ impl ResourceMonitor {
         self
     }
 
+    /// 위반 내용을 Task 로그에 기록
+    pub fn with_log_manager(mut self, log_manager: Arc<TaskLogManager>) -> Self {
+        self.log_manager = Some(log_manager);
+        self
+    }
+
+    /// Task별 제한이 없을 때 적용할 기본 제한
+    pub fn default_limits(&self) -> &ProcessResourceLimits {
+        &self.default_limits
+    }
+
+    /// 위반 이벤트 구독
+    pub fn subscribe(&self) -> broadcast::Receiver<ResourceViolationEvent> {
+        self.events.subscribe()
+    }
+
+    /// Task 프로세스 감시 시작
+    ///
+    /// 주기적으로 프로세스 트리를 측정해 제한을 확인하고, 초과 시
+    /// `on_limit_exceeded` 동작을 적용한 뒤 이벤트를 발행합니다.
+    /// 같은 종류의 위반은 처음 한 번만 발행/로깅합니다.
+    pub async fn watch(
+        &self,
+        task_id: &str,
+        pid: u32,
+        limits: Option<ProcessResourceLimits>,
+    ) -> ResourceWatch {
+        self.track(pid, limits).await;
+
+        // 짧은 작업도 사용량이 남도록 시작 직후 한 번 측정
+        if let Some(tracker) = self.trackers.write().await.get_mut(&pid) {
+            tracker.sample();
+        }
+
+        let enforced = Arc::new(std::sync::Mutex::new(None));
+        let throttled = Arc::new(AtomicBool::new(false));
+        let watcher = Watcher {
+            task_id: task_id.to_string(),
+            pid,
+            trackers: Arc::clone(&self.trackers),
+            events: self.events.clone(),
+            log_manager: self.log_manager.clone(),
+            poll_interval: self.poll_interval,
+            enforced: Arc::clone(&enforced),
+            throttled: Arc::clone(&throttled),
+        };
+        let handle = tokio::spawn(watcher.run());
+
+        ResourceWatch {
+            pid,
+            trackers: Arc::clone(&self.trackers),
+            enforced,
+            throttled,
+            handle,
+        }
+    }
+
     /// 프로세스 추가
     pub async fn track(&self, pid: u32, limits: Option<ProcessResourceLimits>) {
         let limits = limits.unwrap_or_else(|| self.default_limits.clone());
@@ -511,14 +771,13 @@ impl ResourceMonitor {
     ///
     /// 실제 구현은 플랫폼별로 달라야 하지만, 여기서는 기본 구조만 제공
     pub async fn collect_snapshots(&self) -> Vec<(u32, ResourceSnapshot)> {
-        let trackers = self.trackers.read().await;
+        let mut trackers = self.trackers.write().await;
         let mut snapshots = Vec::new();
 
-        for pid in trackers.keys() {
-            // 실제 구현: sysinfo 크레이트 또는 플랫폼별 API 사용
-            // 여기서는 더미 스냅샷 생성
-            let snapshot = ResourceSnapshot::new();
-            snapshots.push((*pid, snapshot));
+        for (pid, tracker) in trackers.iter_mut() {
+            if let Some(snapshot) = tracker.sample() {
+                snapshots.push((*pid, snapshot));
+            }
         }
 
         snapshots
@@ -533,8 +792,6 @@ impl ResourceMonitor {
 
         for (pid, snapshot) in snapshots {
             if let Some(tracker) = trackers.get_mut(&pid) {
-                tracker.record_snapshot(snapshot.clone());
-
                 if let Some(violation) = tracker.check_limits(&snapshot) {
                     violations.push((pid, violation));
                 }
@@ -572,6 +829,271 @@ impl ResourceMonitor {
     }
 }
 
+/// 실행 중인 Task 프로세스 감시 (`ResourceMonitor::watch`)
+///
+/// drop되면 감시를 중단하고 속도 제한 중이던 프로세스를 재개합니다.
+pub struct ResourceWatch {
+    pid: u32,
+    trackers: Arc<RwLock<HashMap<u32, ProcessResourceTracker>>>,
+    enforced: Arc<std::sync::Mutex<Option<ResourceViolation>>>,
+    throttled: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+impl ResourceWatch {
+    /// 감시 중인 프로세스 ID
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// 프로세스를 종료시킨 위반 (Terminate/Kill)
+    pub fn enforced(&self) -> Option<ResourceViolation> {
+        self.enforced.lock().ok().and_then(|v| v.clone())
+    }
+
+    /// 감시 종료 후 사용량 반환 (측정값이 없으면 None)
+    pub async fn finish(self) -> Option<ResourceUsage> {
+        self.stop();
+        let tracker = self.trackers.write().await.remove(&self.pid)?;
+        debug!("{}", tracker.summary_report());
+        (tracker.sample_count > 0).then(|| tracker.usage())
+    }
+
+    fn stop(&self) {
+        self.handle.abort();
+        if self.throttled.swap(false, Ordering::SeqCst) {
+            signal_tree(self.pid, Signal::Continue);
+        }
+    }
+}
+
+impl Drop for ResourceWatch {
+    fn drop(&mut self) {
+        self.stop();
+        if let Ok(mut trackers) = self.trackers.try_write() {
+            trackers.remove(&self.pid);
+        }
+    }
+}
+
+/// 감시 루프 상태
+struct Watcher {
+    task_id: String,
+    pid: u32,
+    trackers: Arc<RwLock<HashMap<u32, ProcessResourceTracker>>>,
+    events: broadcast::Sender<ResourceViolationEvent>,
+    log_manager: Option<Arc<TaskLogManager>>,
+    poll_interval: Duration,
+    enforced: Arc<std::sync::Mutex<Option<ResourceViolation>>>,
+    throttled: Arc<AtomicBool>,
+}
+
+impl Watcher {
+    async fn run(self) {
+        let mut reported = HashSet::new();
+
+        loop {
+            tokio::time::sleep(self.poll_interval).await;
+
+            let violation = {
+                let mut trackers = self.trackers.write().await;
+                let Some(tracker) = trackers.get_mut(&self.pid) else {
+                    break;
+                };
+                match tracker.sample() {
+                    Some(snapshot) => tracker.check_limits(&snapshot),
+                    // 프로세스 종료 또는 측정 불가
+                    None => break,
+                }
+            };
+            let Some(violation) = violation else {
+                continue;
+            };
+
+            if reported.insert(violation.violation_type) {
+                self.report(&violation).await;
+            }
+
+            match violation.action_taken {
+                LimitExceededAction::Warn => {}
+                LimitExceededAction::Throttle => {
+                    self.throttled.store(true, Ordering::SeqCst);
+                    signal_tree(self.pid, Signal::Stop);
+                    tokio::time::sleep(self.poll_interval).await;
+                    signal_tree(self.pid, Signal::Continue);
+                    self.throttled.store(false, Ordering::SeqCst);
+                }
+                LimitExceededAction::Pause => signal_tree(self.pid, Signal::Stop),
+                LimitExceededAction::Terminate => {
+                    let mut enforced = self.enforced.lock().unwrap_or_else(|e| e.into_inner());
+                    // SIGTERM을 무시하면 다음 주기에 강제 종료
+                    let signal = if enforced.is_some() {
+                        Signal::Kill
+                    } else {
+                        Signal::Terminate
+                    };
+                    enforced.get_or_insert(violation);
+                    signal_tree(self.pid, signal);
+                }
+                LimitExceededAction::Kill => {
+                    self.enforced
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .get_or_insert(violation);
+                    signal_tree(self.pid, Signal::Kill);
+                }
+            }
+        }
+    }
+
+    async fn report(&self, violation: &ResourceViolation) {
+        if let Some(log_manager) = &self.log_manager {
+            log_manager
+                .push_system(&self.task_id, format!("Resource limit: {}", violation))
+                .await;
+        }
+        // 구독자가 없어도 무시
+        let _ = self.events.send(ResourceViolationEvent {
+            task_id: self.task_id.clone(),
+            pid: self.pid,
+            violation: violation.clone(),
+        });
+    }
+}
+
+/// 제한 초과 시 보내는 시그널
+#[derive(Debug, Clone, Copy)]
+enum Signal {
+    Stop,
+    Continue,
+    Terminate,
+    Kill,
+}
+
+/// 프로세스와 모든 자식 프로세스에 시그널 전송
+#[cfg(unix)]
+fn signal_tree(pid: u32, signal: Signal) {
+    let signal = match signal {
+        Signal::Stop => libc::SIGSTOP,
+        Signal::Continue => libc::SIGCONT,
+        Signal::Terminate => libc::SIGTERM,
+        Signal::Kill => libc::SIGKILL,
+    };
+
+    #[cfg(target_os = "linux")]
+    let pids = procfs::process_tree(pid);
+    #[cfg(not(target_os = "linux"))]
+    let pids = vec![pid];
+
+    for pid in pids {
+        // SAFETY: kill(2) has no memory-safety preconditions
+        unsafe {
+            libc::kill(pid as libc::pid_t, signal);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn signal_tree(pid: u32, signal: Signal) {
+    debug!(
+        "Signal {:?} to PID {} not supported on this platform",
+        signal, pid
+    );
+}
+
+/// /proc 기반 프로세스 측정
+#[cfg(target_os = "linux")]
+mod procfs {
+    use std::collections::HashMap;
+
+    /// /proc/<pid>/stat 중 필요한 값
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub(super) struct ProcStat {
+        /// R, S, D, Z, T ...
+        pub state: char,
+        pub ppid: u32,
+        /// utime + stime + cutime + cstime (clock ticks)
+        pub cpu_ticks: u64,
+        pub threads: u32,
+        /// 가상 메모리 (bytes)
+        pub vsize: u64,
+        /// 상주 메모리 (pages)
+        pub rss_pages: u64,
+    }
+
+    /// 살아 있는 프로세스의 stat (좀비는 이미 종료된 것으로 취급)
+    pub(super) fn read_stat(pid: u32) -> Option<ProcStat> {
+        parse_stat(&std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?)
+            .filter(|stat| stat.state != 'Z')
+    }
+
+    /// `pid (comm) state ppid ...` 파싱 (comm에 공백/괄호가 있을 수 있음)
+    pub(super) fn parse_stat(content: &str) -> Option<ProcStat> {
+        let rest = &content[content.rfind(')')? + 1..];
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        // fields[0]은 state (stat의 3번째 필드)
+        let field = |n: usize| fields.get(n - 3).and_then(|v| v.parse::<u64>().ok());
+
+        Some(ProcStat {
+            state: fields.first()?.chars().next()?,
+            ppid: field(4)? as u32,
+            cpu_ticks: field(14)? + field(15)? + field(16)? + field(17)?,
+            threads: field(20)? as u32,
+            vsize: field(23)?,
+            rss_pages: field(24)?,
+        })
+    }
+
+    /// 루트 프로세스와 모든 자손 (루트가 없으면 빈 목록)
+    pub(super) fn process_tree(root: u32) -> Vec<u32> {
+        if read_stat(root).is_none() {
+            return Vec::new();
+        }
+
+        let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+        if let Ok(entries) = std::fs::read_dir("/proc") {
+            for entry in entries.flatten() {
+                let Some(pid) = entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|n| n.parse::<u32>().ok())
+                else {
+                    continue;
+                };
+                if let Some(stat) = read_stat(pid) {
+                    children.entry(stat.ppid).or_default().push(pid);
+                }
+            }
+        }
+
+        let mut tree = vec![root];
+        let mut index = 0;
+        while index < tree.len() {
+            if let Some(pids) = children.get(&tree[index]) {
+                tree.extend(pids.iter().copied().filter(|pid| *pid != root));
+            }
+            index += 1;
+        }
+        tree
+    }
+
+    pub(super) fn clock_ticks() -> u64 {
+        // SAFETY: sysconf has no memory-safety preconditions
+        match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+            ticks if ticks > 0 => ticks as u64,
+            _ => 100,
+        }
+    }
+
+    pub(super) fn page_size() -> u64 {
+        // SAFETY: sysconf has no memory-safety preconditions
+        match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+            size if size > 0 => size as u64,
+            _ => 4096,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -658,4 +1180,95 @@ mod tests {
         let pids = monitor.tracked_pids().await;
         assert_eq!(pids.len(), 1);
     }
+
+    #[test]
+    fn test_violation_action_and_usage() {
+        let throttle = LimitExceededAction::Throttle;
+        assert_eq!(
+            throttle.effective_for(ViolationType::CpuExceeded),
+            LimitExceededAction::Throttle
+        );
+        assert_eq!(
+            throttle.effective_for(ViolationType::MemoryExceeded),
+            LimitExceededAction::Kill
+        );
+
+        let limits = ProcessResourceLimits::unlimited()
+            .with_memory_limit("100m")
+            .with_action(LimitExceededAction::Throttle);
+        let mut tracker = ProcessResourceTracker::new(1234, limits);
+        let snapshot = ResourceSnapshot {
+            cpu_percent: 40.0,
+            memory_bytes: 150 * 1024 * 1024,
+            ..Default::default()
+        };
+        tracker.record_snapshot(snapshot.clone());
+        let violation = tracker.check_limits(&snapshot).unwrap();
+        assert_eq!(violation.action_taken, LimitExceededAction::Kill);
+        assert_eq!(
+            violation.to_string(),
+            "Memory limit exceeded: 150.00 MB > 100.00 MB (process killed)"
+        );
+
+        let usage = tracker.usage();
+        assert_eq!(usage.samples, 1);
+        assert_eq!(usage.peak_memory_bytes, 150 * 1024 * 1024);
+        assert_eq!(usage.violations, vec![ViolationType::MemoryExceeded]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_proc_stat() {
+        let stat = "4242 (my (odd) cmd) S 1 4242 4242 0 -1 4194560 100 0 0 0 \
+                    30 12 5 3 20 0 3 0 12345 10485760 256 18446744073709551615";
+        let parsed = procfs::parse_stat(stat).unwrap();
+        assert_eq!(parsed.state, 'S');
+        assert_eq!(parsed.ppid, 1);
+        assert_eq!(parsed.cpu_ticks, 30 + 12 + 5 + 3);
+        assert_eq!(parsed.threads, 3);
+        assert_eq!(parsed.vsize, 10485760);
+        assert_eq!(parsed.rss_pages, 256);
+
+        assert!(procfs::parse_stat("garbage").is_none());
+        assert!(procfs::read_stat(std::process::id()).is_some());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_watch_kills_process_over_limit() {
+        let monitor = ResourceMonitor::new(ProcessResourceLimits::unlimited())
+            .with_poll_interval(Duration::from_millis(50));
+        let mut events = monitor.subscribe();
+
+        let mut child = tokio::process::Command::new("sleep")
+            .arg("30")
+            .kill_on_drop(true)
+            .spawn()
+            .unwrap();
+        let limits = ProcessResourceLimits::unlimited()
+            .with_memory_limit("1k")
+            .with_action(LimitExceededAction::Kill);
+        let watch = monitor
+            .watch("task-1", child.id().unwrap(), Some(limits))
+            .await;
+
+        let status = tokio::time::timeout(Duration::from_secs(5), child.wait())
+            .await
+            .expect("process should be killed")
+            .unwrap();
+        assert!(!status.success());
+
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.task_id, "task-1");
+        assert_eq!(event.violation.violation_type, ViolationType::MemoryExceeded);
+        assert_eq!(
+            watch.enforced().map(|v| v.action_taken),
+            Some(LimitExceededAction::Kill)
+        );
+
+        let usage = watch.finish().await.unwrap();
+        assert!(usage.peak_memory_bytes > 1024);
+        assert_eq!(usage.violations, vec![ViolationType::MemoryExceeded]);
+        assert!(monitor.tracked_pids().await.is_empty());
+    }
 }
@@ -6,6 +6,7 @@
 //!
 //! - **macOS**: Seatbelt (sandbox-exec) - Apple's built-in sandbox
 //! - **Linux**: Landlock LSM + seccomp BPF - Kernel-level restrictions
+//! - **Linux + KVM**: cloud-hypervisor microVM - separate kernel for untrusted workloads
 //! - **Fallback**: Docker container isolation
 //!
 //! ## Security Model
@@ -24,6 +25,7 @@
 //! let result = executor.execute("ls -la", &working_dir).await?;
 //! ```
 
+use super::microvm::{self, MicroVmConfig};
 use forge_foundation::Result;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
@@ -50,6 +52,9 @@ pub enum SandboxType {
 
     /// Strict mode (most restrictive)
     Strict,
+
+    /// Throwaway microVM (see `MicroVmConfig`, Linux + KVM only)
+    MicroVm,
 }
 
 /// Sandbox policy for specific operation types
@@ -94,6 +99,9 @@ pub struct SandboxConfig {
 
     /// Environment variables to pass through
     pub env_passthrough: Vec<String>,
+
+    /// MicroVM settings (used by `SandboxType::MicroVm`)
+    pub microvm: MicroVmConfig,
 }
 
 impl Default for SandboxConfig {
@@ -114,6 +122,7 @@ impl Default for SandboxConfig {
                 "LANG".to_string(),
                 "TERM".to_string(),
             ],
+            microvm: MicroVmConfig::default(),
         }
     }
 }
@@ -140,6 +149,20 @@ impl SandboxConfig {
         }
     }
 
+    /// Create a microVM config (for untrusted agent workloads)
+    ///
+    /// The timeout includes guest boot time.
+    pub fn microvm(microvm: MicroVmConfig) -> Self {
+        Self {
+            sandbox_type: SandboxType::MicroVm,
+            allow_network: false,
+            allow_spawn: true,
+            timeout_ms: 120_000,
+            microvm,
+            ..Default::default()
+        }
+    }
+
     /// Add a trusted command that bypasses sandbox
     pub fn trust_command(mut self, cmd: &str) -> Self {
         self.trusted_commands.insert(cmd.to_string());
@@ -205,6 +228,7 @@ impl SandboxExecutor {
             SandboxType::Native => self.execute_native_sandbox(command, working_dir).await,
             SandboxType::Container => self.execute_container_sandbox(command, working_dir).await,
             SandboxType::Strict => self.execute_strict_sandbox(command, working_dir).await,
+            SandboxType::MicroVm => microvm::execute(&self.config, command, working_dir).await,
         }
     }
 
@@ -726,6 +750,15 @@ mod tests {
         assert_eq!(config.timeout_ms, 10_000);
     }
 
+    #[test]
+    fn test_sandbox_config_microvm() {
+        let config = SandboxConfig::microvm(MicroVmConfig::default()).allow_read("/opt/data");
+        assert_eq!(config.sandbox_type, SandboxType::MicroVm);
+        assert!(!config.allow_network);
+        assert!(!config.microvm.project_writable);
+        assert_eq!(config.allowed_read_paths, vec![PathBuf::from("/opt/data")]);
+    }
+
     #[test]
     fn test_trusted_command() {
         let config = SandboxConfig::default().trust_command("git");
@@ -3,6 +3,7 @@
 use crate::task::{Task, TaskResult};
 use async_trait::async_trait;
 use forge_foundation::Result;
+use std::path::PathBuf;
 
 /// Executor trait - implement to add new execution backends
 #[async_trait]
@@ -18,4 +19,11 @@ pub trait Executor: Send + Sync {
 
     /// Get executor name
     fn name(&self) -> &'static str;
+
+    /// Host directory the task's declared artifacts are collected from
+    ///
+    /// `None` when the task's files are not visible on the host (e.g. remote pods).
+    fn artifact_dir(&self, _task: &Task) -> Option<PathBuf> {
+        None
+    }
 }
@@ -6,37 +6,43 @@
 //! ## Features
 //!
 //! - Task management and scheduling
-//! - Multiple execution backends (Local, Container)
+//! - Multiple execution backends (Local, Container, Kubernetes)
+//! - Content-addressed output artifacts (binaries, coverage reports)
 //! - Sub-agent orchestration for specialized tasks
 //! - Background execution with output streaming
+//! - Cron-style scheduled and recurring tasks
 //! - Context isolation and knowledge sharing
 //! - **Real-time log streaming and access**
 //! - **Task termination and control**
 //! - **LLM log analysis for debugging**
 
+pub mod artifact;
 pub mod cluster;
 pub mod container;
 pub mod executor;
 pub mod log;
 pub mod manager;
 pub mod orchestrator;
+pub mod schedule;
 pub mod state;
 pub mod subagent;
 pub mod task;
 
 // Task system
 pub use executor::{
-    ContainerExecutor, Executor, LocalExecutor, PtyEnvSecurityConfig, PtyExecutor,
+    ContainerExecutor, Executor, K8sConfig, K8sExecutor, LocalExecutor, MicroVmConfig, PtyEnvSecurityConfig, PtyExecutor,
     PtyExecutorConfig, PtySizeConfig, SandboxConfig, SandboxExecutor, SandboxPolicy, SandboxResult,
     SandboxType,
     // Shell command policy
     PolicyResult, RiskLevel, ShellPolicy, TaskShellPolicy,
     // Resource monitoring
     LimitExceededAction, ProcessResourceLimits, ProcessResourceTracker,
-    ResourceMonitor, ResourceSnapshot, ResourceViolation, ViolationType,
+    ResourceMonitor, ResourceSnapshot, ResourceUsage, ResourceViolation,
+    ResourceViolationEvent, ResourceWatch, ViolationType,
 };
 pub use manager::{ProgressHint, ResourceStats, TaskManager, TaskManagerConfig, TaskProgressReport, TaskStatus};
 pub use state::TaskState;
+pub use artifact::{Artifact, ArtifactSpec, ArtifactStore, DEFAULT_ARTIFACT_DIR};
 pub use task::{ExecutionMode, Task, TaskId, TaskResult};
 
 // Log system
@@ -60,10 +66,14 @@ pub use cluster::{
 pub use subagent::{
     ContextMessage, ContextStore, ContextToolResult, ContextWindowConfig, ContextWindowStatus,
     Discovery, DiscoveryId, EffectiveTokenBudget, ModelSelection, PermissionMode, SubAgent,
-    SubAgentConfig, SubAgentContext, SubAgentId, SubAgentManager, SubAgentState, SubAgentType,
-    TokenBudgetConfig, TokenBudgetSource, TokenReport,
+    SubAgentConfig, SubAgentContext, SubAgentId, SubAgentManager, SubAgentState,
+    SubAgentToolPolicies, SubAgentType, TokenBudgetConfig, TokenBudgetSource, TokenReport,
+    ToolPolicy,
 };
 
+// Scheduled tasks (cron)
+pub use schedule::{CronSchedule, Schedule, ScheduleOutput, ScheduleRunner, TaskScheduler};
+
 // Orchestrator system (Task 간 통신 및 조율)
 pub use orchestrator::{
     InteractionAction, InteractionLog, OrchestratorConfig, TaskGroup, TaskGroupId, TaskMessage,
@@ -216,8 +216,8 @@ impl TaskLogBuffer {
     pub fn entries_filtered(&self, level: Option<LogLevel>, search: Option<&str>) -> Vec<&LogEntry> {
         self.entries
             .iter()
-            .filter(|e| level.map_or(true, |l| e.level == l))
-            .filter(|e| search.map_or(true, |s| e.content.contains(s)))
+            .filter(|e| level.is_none_or(|l| e.level == l))
+            .filter(|e| search.is_none_or(|s| e.content.contains(s)))
             .collect()
     }
 
@@ -5,13 +5,19 @@
 //! - Real-time log access
 //! - Task termination
 //! - LLM log analysis integration
+//! - Output artifact collection
 
-use crate::executor::{ContainerExecutor, Executor, LocalExecutor, PtyExecutor};
+use crate::artifact::{Artifact, ArtifactStore, DEFAULT_ARTIFACT_DIR};
+use crate::executor::{
+    ContainerExecutor, Executor, K8sConfig, K8sExecutor, LocalExecutor, ProcessResourceLimits,
+    PtyExecutor, ResourceMonitor,
+};
 use crate::log::{LogAnalysisReport, LogEntry, TaskLogManager};
 use crate::state::TaskState;
 use crate::task::{ExecutionMode, Task, TaskId, TaskResult};
 use forge_foundation::{Error, Result};
 use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::{broadcast, Mutex, RwLock};
 use tracing::{debug, info, warn};
@@ -33,6 +39,15 @@ pub struct TaskManagerConfig {
 
     /// Auto cleanup settings
     pub auto_cleanup: AutoCleanupConfig,
+
+    /// Kubernetes Job execution (disabled when None)
+    pub kubernetes: Option<K8sConfig>,
+
+    /// Default quota for Local/PTY tasks without `Task::resource_limits`
+    pub resource_limits: ProcessResourceLimits,
+
+    /// Artifact store root (default: `.forgecode/artifacts`)
+    pub artifact_dir: PathBuf,
 }
 
 /// Auto cleanup configuration for completed tasks
@@ -66,6 +81,9 @@ impl Default for TaskManagerConfig {
             max_log_entries: 10000,
             persist_logs: false,
             auto_cleanup: AutoCleanupConfig::default(),
+            kubernetes: None,
+            resource_limits: ProcessResourceLimits::unlimited(),
+            artifact_dir: PathBuf::from(DEFAULT_ARTIFACT_DIR),
         }
     }
 }
@@ -109,9 +127,21 @@ pub struct TaskManager {
     /// Container executor
     container_executor: Arc<ContainerExecutor>,
 
+    /// Kubernetes executor (only when configured)
+    k8s_executor: Option<Arc<K8sExecutor>>,
+
+    /// Executors registered at runtime, by name (`ExecutionMode::Custom`)
+    custom_executors: Arc<RwLock<HashMap<String, Arc<dyn Executor>>>>,
+
     /// Shared log manager
     log_manager: Arc<TaskLogManager>,
 
+    /// Resource monitor shared by the Local/PTY executors
+    resource_monitor: Arc<ResourceMonitor>,
+
+    /// Store for collected task artifacts
+    artifact_store: Arc<ArtifactStore>,
+
     /// Configuration
     config: Arc<TaskManagerConfig>,
 }
@@ -127,16 +157,38 @@ impl TaskManager {
 
         let auto_cleanup = config.auto_cleanup.clone();
 
+        let resource_monitor = Arc::new(
+            ResourceMonitor::new(config.resource_limits.clone())
+                .with_log_manager(Arc::clone(&log_manager)),
+        );
+
+        let k8s_executor = match &config.kubernetes {
+            Some(k8s) => Some(Arc::new(
+                K8sExecutor::with_log_manager(k8s.clone(), Arc::clone(&log_manager)).await,
+            )),
+            None => None,
+        };
+
         let manager = Self {
             // Pre-allocate HashMap with expected capacity
             tasks: Arc::new(RwLock::new(HashMap::with_capacity(config.max_concurrent * 4))),
             queue: Arc::new(Mutex::new(VecDeque::with_capacity(config.max_concurrent * 2))),
             // Atomic counter for lock-free reads
             running_count: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
-            local_executor: Arc::new(LocalExecutor::with_log_manager(Arc::clone(&log_manager))),
-            pty_executor: Arc::new(PtyExecutor::with_log_manager(Arc::clone(&log_manager))),
+            local_executor: Arc::new(
+                LocalExecutor::with_log_manager(Arc::clone(&log_manager))
+                    .with_resource_monitor(Arc::clone(&resource_monitor)),
+            ),
+            pty_executor: Arc::new(
+                PtyExecutor::with_log_manager(Arc::clone(&log_manager))
+                    .with_resource_monitor(Arc::clone(&resource_monitor)),
+            ),
             container_executor: Arc::new(ContainerExecutor::new().await),
+            k8s_executor,
+            custom_executors: Arc::new(RwLock::new(HashMap::new())),
             log_manager,
+            resource_monitor,
+            artifact_store: Arc::new(ArtifactStore::new(config.artifact_dir.clone())),
             config: Arc::new(config),
         };
 
@@ -180,6 +232,21 @@ impl TaskManager {
         Arc::clone(&self.log_manager)
     }
 
+    /// Get resource monitor (subscribe for `ResourceViolationEvent`s)
+    pub fn resource_monitor(&self) -> Arc<ResourceMonitor> {
+        Arc::clone(&self.resource_monitor)
+    }
+
+    /// Get artifact store
+    pub fn artifact_store(&self) -> Arc<ArtifactStore> {
+        Arc::clone(&self.artifact_store)
+    }
+
+    /// Artifacts collected for a task (also available after restarts)
+    pub fn artifacts(&self, task_id: TaskId) -> Result<Vec<Artifact>> {
+        self.artifact_store.list(task_id)
+    }
+
     /// Submit a new task
     pub async fn submit(&self, mut task: Task) -> TaskId {
         let task_id = task.id;
@@ -297,11 +364,40 @@ impl TaskManager {
                     self.local_executor.clone()
                 }
             }
+            ExecutionMode::Kubernetes { .. } => match &self.k8s_executor {
+                Some(executor) => executor.clone(),
+                None => {
+                    // 원격 실행용 작업이므로 로컬로 대체하지 않음
+                    let mut tasks = self.tasks.write().await;
+                    if let Some(t) = tasks.get_mut(&task_id) {
+                        t.fail("Kubernetes execution is not configured".to_string());
+                    }
+                    self.running_count.fetch_sub(1, Ordering::AcqRel);
+                    return;
+                }
+            },
+            ExecutionMode::Custom { executor } => match self.custom_executor(executor).await {
+                Some(executor) => executor,
+                None => {
+                    // Same as Kubernetes: never silently run on the host instead
+                    let mut tasks = self.tasks.write().await;
+                    if let Some(t) = tasks.get_mut(&task_id) {
+                        t.fail(format!("Executor '{}' is not registered", executor));
+                    }
+                    self.running_count.fetch_sub(1, Ordering::AcqRel);
+                    return;
+                }
+            },
             ExecutionMode::Pty => unreachable!(), // Handled above
         };
 
         // Execute synchronously
-        let result = executor.execute(&task).await;
+        let result = match executor.execute(&task).await {
+            Ok(result) if !task.artifacts.is_empty() => Ok(self
+                .collect_artifacts(&task, executor.as_ref(), result)
+                .await),
+            other => other,
+        };
 
         // Update task state
         {
@@ -320,6 +416,66 @@ impl TaskManager {
         // Note: Next task will be picked up by the loop in process_queue
     }
 
+    /// Copy the task's declared artifacts into the artifact store
+    ///
+    /// Collection problems are logged to the task; they never fail the task itself.
+    async fn collect_artifacts(
+        &self,
+        task: &Task,
+        executor: &dyn Executor,
+        result: TaskResult,
+    ) -> TaskResult {
+        let task_id = task.id.to_string();
+        let Some(dir) = executor.artifact_dir(task) else {
+            warn!(
+                "Task {}: {} executor cannot collect artifacts",
+                task_id,
+                executor.name()
+            );
+            self.log_manager
+                .push_system(
+                    &task_id,
+                    format!(
+                        "Artifacts not collected: {} executor has no host working directory",
+                        executor.name()
+                    ),
+                )
+                .await;
+            return result;
+        };
+
+        let store = Arc::clone(&self.artifact_store);
+        let owned = task.clone();
+        let collected = tokio::task::spawn_blocking(move || store.collect(&owned, &dir))
+            .await
+            .map_err(|e| Error::Task(format!("Artifact collection panicked: {}", e)))
+            .and_then(|result| result);
+
+        match collected {
+            Ok(artifacts) => {
+                for spec in &task.artifacts {
+                    if !artifacts.iter().any(|a| a.name == spec.name) {
+                        self.log_manager
+                            .push_system(
+                                &task_id,
+                                format!("Artifact '{}' not found: {}", spec.name, spec.path),
+                            )
+                            .await;
+                    }
+                }
+                debug!("Task {}: collected {} artifacts", task_id, artifacts.len());
+                result.with_artifacts(artifacts)
+            }
+            Err(e) => {
+                warn!("Task {}: artifact collection failed: {}", task_id, e);
+                self.log_manager
+                    .push_system(&task_id, format!("Artifact collection failed: {}", e))
+                    .await;
+                result
+            }
+        }
+    }
+
     /// Get the PTY executor for direct access (for wait operations)
     pub fn pty_executor(&self) -> Arc<PtyExecutor> {
         Arc::clone(&self.pty_executor)
@@ -423,6 +579,14 @@ impl TaskManager {
                 ExecutionMode::Local => self.local_executor.clone(),
                 ExecutionMode::Pty => self.pty_executor.clone(),
                 ExecutionMode::Container { .. } => self.container_executor.clone(),
+                ExecutionMode::Kubernetes { .. } => match &self.k8s_executor {
+                    Some(executor) => executor.clone(),
+                    None => self.local_executor.clone(),
+                },
+                ExecutionMode::Custom { executor } => self
+                    .custom_executor(executor)
+                    .await
+                    .unwrap_or_else(|| self.local_executor.clone()),
             };
 
             executor.cancel(&task).await?;
@@ -487,6 +651,47 @@ impl TaskManager {
         self.container_executor.is_available()
     }
 
+    /// Check if Kubernetes execution is configured and available
+    pub fn kubernetes_available(&self) -> bool {
+        self.k8s_executor
+            .as_ref()
+            .is_some_and(|executor| executor.is_available())
+    }
+
+    /// Register an executor for `ExecutionMode::Custom { executor: name }`
+    ///
+    /// Replaces (and returns) any executor previously registered under the same name.
+    pub async fn register_executor(
+        &self,
+        executor: Arc<dyn Executor>,
+    ) -> Option<Arc<dyn Executor>> {
+        let name = executor.name().to_string();
+        info!("Registered executor: {}", name);
+        self.custom_executors.write().await.insert(name, executor)
+    }
+
+    /// Remove a registered executor
+    pub async fn unregister_executor(&self, name: &str) -> Option<Arc<dyn Executor>> {
+        self.custom_executors.write().await.remove(name)
+    }
+
+    /// Names of registered executors (sorted)
+    pub async fn custom_executor_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.custom_executors.read().await.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Look up an available registered executor
+    async fn custom_executor(&self, name: &str) -> Option<Arc<dyn Executor>> {
+        self.custom_executors
+            .read()
+            .await
+            .get(name)
+            .filter(|executor| executor.is_available())
+            .cloned()
+    }
+
     /// Check if PTY execution is available
     pub fn pty_available(&self) -> bool {
         self.pty_executor.is_available()
@@ -1036,4 +1241,55 @@ mod tests {
         let running = manager.get_running_tasks().await;
         assert!(running.is_empty());
     }
+
+    struct EchoExecutor;
+
+    #[async_trait::async_trait]
+    impl Executor for EchoExecutor {
+        async fn execute(&self, task: &Task) -> Result<TaskResult> {
+            Ok(TaskResult::success(format!("echo: {}", task.command)))
+        }
+
+        async fn cancel(&self, _task: &Task) -> Result<()> {
+            Ok(())
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn name(&self) -> &'static str {
+            "echo"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_executor() {
+        let manager = TaskManager::default().await;
+        manager.register_executor(Arc::new(EchoExecutor)).await;
+        assert_eq!(manager.custom_executor_names().await, ["echo"]);
+
+        let custom = |command: &str| {
+            Task::new("session-1", "bash", command, serde_json::json!({})).with_execution_mode(
+                ExecutionMode::Custom {
+                    executor: "echo".into(),
+                },
+            )
+        };
+        let task_id = manager.submit(custom("hello")).await;
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        match manager.get(task_id).await.unwrap().state {
+            TaskState::Completed(result) => assert_eq!(result.output, "echo: hello"),
+            other => panic!("unexpected state: {:?}", other),
+        }
+
+        // Unregistered executors fail instead of falling back to local
+        manager.unregister_executor("echo").await;
+        let task_id = manager.submit(custom("hello")).await;
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        assert!(matches!(
+            manager.get(task_id).await.unwrap().state,
+            TaskState::Failed(_)
+        ));
+    }
 }
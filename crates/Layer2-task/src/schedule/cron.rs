@@ -0,0 +1,326 @@
+//! Cron expression parsing and next-run calculation
+//!
+//! Supports the standard 5-field format (`minute hour day-of-month month day-of-week`):
+//! - `*`, lists (`1,15`), ranges (`1-5`) and steps (`*/15`, `0-30/10`)
+//! - Month and weekday names (`jan`-`dec`, `sun`-`sat`), Sunday as `0` or `7`
+//! - Macros: `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight`, `@hourly`
+//!
+//! As in cron, when both day-of-month and day-of-week are restricted a day
+//! matches if either field matches.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use forge_foundation::{Error, Result};
+use std::fmt;
+use std::str::FromStr;
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// How far ahead to search before giving up (e.g. `0 0 30 2 *`)
+const MAX_SEARCH_YEARS: i32 = 5;
+
+/// Parsed cron schedule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// Day-of-month field was `*`
+    any_day_of_month: bool,
+    /// Day-of-week field was `*`
+    any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    /// Parse a cron expression
+    pub fn parse(expression: &str) -> Result<Self> {
+        let expression = expression.trim();
+        let expanded = match expression.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other if other.starts_with('@') => {
+                return Err(invalid(expression, "unknown macro"));
+            }
+            _ => expression,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(invalid(
+                expression,
+                &format!("expected 5 fields, got {}", fields.len()),
+            ));
+        }
+
+        let field = |index: usize, min: u32, max: u32, names: &[&str]| {
+            parse_field(fields[index], min, max, names)
+                .map_err(|reason| invalid(expression, &reason))
+        };
+
+        let mut days_of_week = field(4, 0, 7, &WEEKDAY_NAMES)?;
+        // 7 is Sunday too
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            expression: expression.to_string(),
+            minutes: field(0, 0, 59, &[])?,
+            hours: field(1, 0, 23, &[])?,
+            days_of_month: field(2, 1, 31, &[])?,
+            months: field(3, 1, 12, &MONTH_NAMES)?,
+            days_of_week,
+            any_day_of_month: fields[2] == "*",
+            any_day_of_week: fields[4] == "*",
+        })
+    }
+
+    /// Original expression
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Whether the schedule fires at the given (local) minute
+    pub fn matches(&self, time: &NaiveDateTime) -> bool {
+        bit(self.minutes, time.minute())
+            && bit(self.hours, time.hour())
+            && bit(self.months, time.month())
+            && self.matches_day(&time.date())
+    }
+
+    fn matches_day(&self, date: &NaiveDate) -> bool {
+        let dom = bit(self.days_of_month, date.day());
+        let dow = bit(self.days_of_week, date.weekday().num_days_from_sunday());
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (true, true) => true,
+            (true, false) => dow,
+            (false, true) => dom,
+            (false, false) => dom || dow,
+        }
+    }
+
+    /// Next fire time strictly after `after`, in the same time zone
+    ///
+    /// Local times skipped by a DST change are not fired; ambiguous times
+    /// fire at the earlier instant.
+    pub fn next_after<Tz: TimeZone>(&self, after: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let tz = after.timezone();
+        let start = after.naive_local();
+        let limit_year = start.year() + MAX_SEARCH_YEARS;
+
+        // Next whole minute
+        let mut time = start.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+
+        while time.year() <= limit_year {
+            if !bit(self.months, time.month()) {
+                // First day of next month
+                let (year, month) = if time.month() == 12 {
+                    (time.year() + 1, 1)
+                } else {
+                    (time.year(), time.month() + 1)
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.matches_day(&time.date()) {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !bit(self.hours, time.hour()) {
+                time = time.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if !bit(self.minutes, time.minute()) {
+                time += Duration::minutes(1);
+                continue;
+            }
+
+            if let Some(fire) = tz.from_local_datetime(&time).earliest() {
+                return Some(fire);
+            }
+            time += Duration::minutes(1);
+        }
+
+        None
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+fn invalid(expression: &str, reason: &str) -> Error {
+    Error::InvalidInput(format!(
+        "Invalid cron expression '{}': {}",
+        expression, reason
+    ))
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+/// Parse one field into a bitmask of allowed values
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+) -> std::result::Result<u64, String> {
+    let mut mask = 0u64;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("invalid step '{}'", step))?;
+                if step == 0 {
+                    return Err("step must be greater than 0".to_string());
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (value(start, min, max, names)?, value(end, min, max, names)?)
+        } else {
+            let start = value(range, min, max, names)?;
+            // `5/15` means "from 5 to the end, every 15"
+            (start, if step > 1 { max } else { start })
+        };
+        if start > end {
+            return Err(format!("invalid range '{}'", range));
+        }
+
+        for v in (start..=end).step_by(step as usize) {
+            mask |= 1 << v;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn value(s: &str, min: u32, max: u32, names: &[&str]) -> std::result::Result<u32, String> {
+    let lower = s.to_ascii_lowercase();
+    let v = match names.iter().position(|name| *name == lower) {
+        // Names start at the field minimum (jan = 1, sun = 0)
+        Some(index) => index as u32 + min,
+        None => s.parse().map_err(|_| format!("invalid value '{}'", s))?,
+    };
+    if v < min || v > max {
+        return Err(format!("value {} out of range {}-{}", v, min, max));
+    }
+    Ok(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn next(expr: &str, after: &str) -> String {
+        CronSchedule::parse(expr)
+            .unwrap()
+            .next_after(&at(after))
+            .unwrap()
+            .to_rfc3339()
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let cron = CronSchedule::parse("*/15 9-17 * jan,jul mon-fri").unwrap();
+        assert_eq!(cron.minutes, (1 << 0) | (1 << 15) | (1 << 30) | (1 << 45));
+        assert!(bit(cron.hours, 9) && bit(cron.hours, 17) && !bit(cron.hours, 18));
+        assert!(bit(cron.months, 1) && bit(cron.months, 7) && !bit(cron.months, 2));
+        assert!(bit(cron.days_of_week, 1) && bit(cron.days_of_week, 5));
+        assert!(!bit(cron.days_of_week, 0));
+
+        // 7 = 일요일
+        let sunday = CronSchedule::parse("0 0 * * 7").unwrap();
+        assert_eq!(sunday.days_of_week, 1);
+        assert_eq!(
+            CronSchedule::parse("@daily").unwrap().expression(),
+            "@daily"
+        );
+
+        for bad in [
+            "* * * *",
+            "60 * * * *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "@sometimes",
+            "0 0 * foo *",
+        ] {
+            assert!(CronSchedule::parse(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_next_after() {
+        // Nightly at 03:00
+        assert_eq!(
+            next("0 3 * * *", "2026-03-10T02:59:30Z"),
+            "2026-03-10T03:00:00+00:00"
+        );
+        assert_eq!(
+            next("0 3 * * *", "2026-03-10T03:00:00Z"),
+            "2026-03-11T03:00:00+00:00"
+        );
+        // Every 15 minutes
+        assert_eq!(
+            next("*/15 * * * *", "2026-03-10T10:16:00Z"),
+            "2026-03-10T10:30:00+00:00"
+        );
+        // Weekdays at 09:30 (2026-03-13 is a Friday)
+        assert_eq!(
+            next("30 9 * * mon-fri", "2026-03-13T10:00:00Z"),
+            "2026-03-16T09:30:00+00:00"
+        );
+        // Month rollover and year rollover
+        assert_eq!(
+            next("@monthly", "2026-12-15T00:00:00Z"),
+            "2027-01-01T00:00:00+00:00"
+        );
+        // Leap day
+        assert_eq!(
+            next("0 0 29 2 *", "2026-03-01T00:00:00Z"),
+            "2028-02-29T00:00:00+00:00"
+        );
+        // Day-of-month OR day-of-week (1st of the month or any Monday)
+        assert_eq!(
+            next("0 0 1 * mon", "2026-03-10T12:00:00Z"),
+            "2026-03-16T00:00:00+00:00"
+        );
+        // Never fires
+        assert!(CronSchedule::parse("0 0 30 2 *")
+            .unwrap()
+            .next_after(&at("2026-01-01T00:00:00Z"))
+            .is_none());
+    }
+}
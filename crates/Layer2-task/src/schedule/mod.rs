@@ -0,0 +1,17 @@
+//! Scheduled tasks
+//!
+//! Recurring agent runs driven by cron expressions, e.g. a nightly
+//! "update dependencies and open a PR" job:
+//! - `CronSchedule` - 5-field cron expressions and next-run calculation
+//! - `Schedule` - persisted spec (cron + prompt or `/skill` invocation)
+//! - `TaskScheduler` - fires due schedules through a `ScheduleRunner`
+//!   and records every run in `Storage`
+//!
+//! Layer2 has no agent, so the runner is supplied by the caller
+//! (the CLI runs each schedule through the agent runner).
+
+pub mod cron;
+pub mod scheduler;
+
+pub use cron::CronSchedule;
+pub use scheduler::{Schedule, ScheduleOutput, ScheduleRunner, TaskScheduler};
@@ -0,0 +1,460 @@
+//! Task scheduler - persists schedules and fires them when due
+//!
+//! Schedules and their run history live in `Storage` (`scheduled_tasks`,
+//! `schedule_runs`), so they survive restarts. Cron expressions are evaluated
+//! in local time.
+//!
+//! Missed runs (machine asleep, scheduler not running) fire once when the
+//! scheduler next checks, then the schedule continues from the current time.
+//! A schedule never overlaps with its own previous run.
+
+use super::cron::CronSchedule;
+use async_trait::async_trait;
+use chrono::{DateTime, Local, Utc};
+use forge_foundation::{Error, Result, ScheduleRunRecord, ScheduledTaskRecord, Storage};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Default interval between due-schedule checks
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A recurring task spec
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    pub id: String,
+    /// Unique, user-facing name
+    pub name: String,
+    pub cron: CronSchedule,
+    /// Prompt for the agent, or a skill invocation (`/update-deps --major`)
+    pub prompt: String,
+    /// Directory the agent runs in (default: the runner's)
+    pub working_dir: Option<PathBuf>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub next_run_at: Option<DateTime<Utc>>,
+}
+
+impl Schedule {
+    /// Create an enabled schedule
+    pub fn new(name: impl Into<String>, cron: CronSchedule, prompt: impl Into<String>) -> Self {
+        let now = Utc::now();
+        let next_run_at = next_run(&cron, now);
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.into(),
+            cron,
+            prompt: prompt.into(),
+            working_dir: None,
+            enabled: true,
+            created_at: now,
+            last_run_at: None,
+            next_run_at,
+        }
+    }
+
+    /// Set the working directory
+    pub fn with_working_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.working_dir = Some(dir.into());
+        self
+    }
+
+    /// Whether the prompt invokes a skill
+    pub fn is_skill(&self) -> bool {
+        self.prompt.trim_start().starts_with('/')
+    }
+
+    /// Whether the schedule should fire at `now`
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.enabled && self.next_run_at.is_some_and(|next| next <= now)
+    }
+
+    /// Convert to a storage record
+    pub fn to_record(&self) -> ScheduledTaskRecord {
+        ScheduledTaskRecord {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            cron: self.cron.expression().to_string(),
+            prompt: self.prompt.clone(),
+            working_directory: self
+                .working_dir
+                .as_ref()
+                .map(|dir| dir.to_string_lossy().to_string()),
+            enabled: self.enabled,
+            created_at: self.created_at.to_rfc3339(),
+            last_run_at: self.last_run_at.map(|t| t.to_rfc3339()),
+            next_run_at: self.next_run_at.map(|t| t.to_rfc3339()),
+        }
+    }
+
+    /// Load from a storage record
+    pub fn from_record(record: ScheduledTaskRecord) -> Result<Self> {
+        Ok(Self {
+            cron: CronSchedule::parse(&record.cron)?,
+            id: record.id,
+            name: record.name,
+            prompt: record.prompt,
+            working_dir: record.working_directory.map(PathBuf::from),
+            enabled: record.enabled,
+            created_at: parse_time(&record.created_at).unwrap_or_else(Utc::now),
+            last_run_at: record.last_run_at.as_deref().and_then(parse_time),
+            next_run_at: record.next_run_at.as_deref().and_then(parse_time),
+        })
+    }
+}
+
+/// Result of one scheduled run
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleOutput {
+    /// Final agent response
+    pub output: String,
+    /// Session the run was recorded under
+    pub session_id: Option<String>,
+}
+
+/// Runs a schedule's prompt (implemented by the agent layer)
+#[async_trait]
+pub trait ScheduleRunner: Send + Sync {
+    async fn run(&self, schedule: &Schedule) -> Result<ScheduleOutput>;
+}
+
+/// Scheduler for recurring tasks
+pub struct TaskScheduler {
+    storage: Storage,
+    runner: Arc<dyn ScheduleRunner>,
+    poll_interval: Duration,
+    /// IDs of schedules with a run in progress
+    running: Arc<Mutex<HashSet<String>>>,
+}
+
+impl TaskScheduler {
+    /// Create a scheduler backed by `storage`
+    pub fn new(storage: Storage, runner: Arc<dyn ScheduleRunner>) -> Self {
+        Self {
+            storage,
+            runner,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            running: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Set the interval between due-schedule checks
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Add a schedule (names must be unique)
+    pub fn add(&self, schedule: Schedule) -> Result<Schedule> {
+        if self.storage.get_scheduled_task(&schedule.name)?.is_some() {
+            return Err(Error::InvalidInput(format!(
+                "Schedule '{}' already exists",
+                schedule.name
+            )));
+        }
+        self.storage.save_scheduled_task(&schedule.to_record())?;
+        info!("Added schedule '{}' ({})", schedule.name, schedule.cron);
+        Ok(schedule)
+    }
+
+    /// Get a schedule by ID or name
+    pub fn get(&self, id_or_name: &str) -> Result<Option<Schedule>> {
+        self.storage
+            .get_scheduled_task(id_or_name)?
+            .map(Schedule::from_record)
+            .transpose()
+    }
+
+    /// All schedules (by name); entries with an invalid cron expression are skipped
+    pub fn list(&self) -> Result<Vec<Schedule>> {
+        Ok(self
+            .storage
+            .get_scheduled_tasks()?
+            .into_iter()
+            .filter_map(|record| match Schedule::from_record(record) {
+                Ok(schedule) => Some(schedule),
+                Err(e) => {
+                    warn!("Skipping schedule: {}", e);
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Remove a schedule and its run history
+    pub fn remove(&self, id_or_name: &str) -> Result<Schedule> {
+        let schedule = self.require(id_or_name)?;
+        self.storage.delete_scheduled_task(&schedule.id)?;
+        Ok(schedule)
+    }
+
+    /// Enable or disable a schedule
+    ///
+    /// Enabling recomputes the next run from now, so runs missed while
+    /// disabled are not fired.
+    pub fn set_enabled(&self, id_or_name: &str, enabled: bool) -> Result<Schedule> {
+        let mut schedule = self.require(id_or_name)?;
+        schedule.enabled = enabled;
+        if enabled {
+            schedule.next_run_at = next_run(&schedule.cron, Utc::now());
+        }
+        self.storage.save_scheduled_task(&schedule.to_record())?;
+        Ok(schedule)
+    }
+
+    /// Recent runs of a schedule (newest first)
+    pub fn runs(&self, id_or_name: &str, limit: u32) -> Result<Vec<ScheduleRunRecord>> {
+        let schedule = self.require(id_or_name)?;
+        self.storage.get_schedule_runs(&schedule.id, limit)
+    }
+
+    /// Schedules due at `now`
+    pub fn due(&self, now: DateTime<Utc>) -> Result<Vec<Schedule>> {
+        Ok(self
+            .list()?
+            .into_iter()
+            .filter(|schedule| schedule.is_due(now))
+            .collect())
+    }
+
+    /// Fire every schedule due at `now` and wait for the runs
+    pub async fn tick(&self, now: DateTime<Utc>) -> Result<Vec<ScheduleRunRecord>> {
+        let due = self.due(now)?;
+        let runs = futures::future::join_all(due.into_iter().map(|s| self.fire(s, now))).await;
+        Ok(runs.into_iter().flatten().collect())
+    }
+
+    /// Run a schedule immediately (its next run time is unchanged)
+    pub async fn run_now(&self, id_or_name: &str) -> Result<ScheduleRunRecord> {
+        let schedule = self.require(id_or_name)?;
+        if !self.running.lock().await.insert(schedule.id.clone()) {
+            return Err(Error::Task(format!(
+                "Schedule '{}' is already running",
+                schedule.name
+            )));
+        }
+        let run = self.execute(&schedule).await;
+        self.running.lock().await.remove(&schedule.id);
+        run
+    }
+
+    /// Check for due schedules every poll interval until the handle is aborted
+    pub fn start(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.poll_interval);
+            loop {
+                interval.tick().await;
+                // 실행이 길어져도 다음 확인이 밀리지 않도록 분리
+                let scheduler = Arc::clone(&self);
+                tokio::spawn(async move {
+                    if let Err(e) = scheduler.tick(Utc::now()).await {
+                        warn!("Schedule check failed: {}", e);
+                    }
+                });
+            }
+        })
+    }
+
+    /// Advance the schedule, then run it (skipped if its previous run is still going)
+    async fn fire(&self, mut schedule: Schedule, now: DateTime<Utc>) -> Option<ScheduleRunRecord> {
+        if !self.running.lock().await.insert(schedule.id.clone()) {
+            return None;
+        }
+
+        // 실행 전에 다음 시각을 저장해 중단되더라도 같은 회차가 반복되지 않게 함
+        schedule.next_run_at = next_run(&schedule.cron, now);
+        let run = match self.storage.save_scheduled_task(&schedule.to_record()) {
+            Ok(()) => self.execute(&schedule).await,
+            Err(e) => Err(e),
+        };
+
+        self.running.lock().await.remove(&schedule.id);
+        match run {
+            Ok(run) => Some(run),
+            Err(e) => {
+                warn!("Failed to run schedule '{}': {}", schedule.name, e);
+                None
+            }
+        }
+    }
+
+    /// Run through the runner and record the result
+    async fn execute(&self, schedule: &Schedule) -> Result<ScheduleRunRecord> {
+        let started_at = Utc::now();
+        info!("Running schedule '{}'", schedule.name);
+
+        let result = self.runner.run(schedule).await;
+
+        let mut run = ScheduleRunRecord {
+            id: None,
+            schedule_id: schedule.id.clone(),
+            session_id: None,
+            status: String::new(),
+            output_text: None,
+            error_message: None,
+            started_at: started_at.to_rfc3339(),
+            completed_at: Some(Utc::now().to_rfc3339()),
+        };
+        match result {
+            Ok(output) => {
+                run.status = "success".to_string();
+                run.output_text = Some(output.output);
+                run.session_id = output.session_id;
+            }
+            Err(e) => {
+                warn!("Schedule '{}' failed: {}", schedule.name, e);
+                run.status = "error".to_string();
+                run.error_message = Some(e.to_string());
+            }
+        }
+        run.id = Some(self.storage.record_schedule_run(&run)?);
+
+        // 다른 프로세스가 수정했을 수 있으므로 다시 읽어서 실행 시각만 갱신
+        if let Some(mut latest) = self.get(&schedule.id)? {
+            latest.last_run_at = Some(started_at);
+            self.storage.save_scheduled_task(&latest.to_record())?;
+        }
+
+        Ok(run)
+    }
+
+    fn require(&self, id_or_name: &str) -> Result<Schedule> {
+        self.get(id_or_name)?
+            .ok_or_else(|| Error::NotFound(format!("Schedule '{}' not found", id_or_name)))
+    }
+}
+
+/// Next fire time after `after`, evaluated in local time
+fn next_run(cron: &CronSchedule, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    cron.next_after(&after.with_timezone(&Local))
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn parse_time(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Fails prompts containing "fail"
+    struct MockRunner {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ScheduleRunner for MockRunner {
+        async fn run(&self, schedule: &Schedule) -> Result<ScheduleOutput> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if schedule.prompt.contains("fail") {
+                return Err(Error::Agent("provider unavailable".to_string()));
+            }
+            Ok(ScheduleOutput {
+                output: format!("done: {}", schedule.prompt),
+                session_id: Some("session-1".to_string()),
+            })
+        }
+    }
+
+    fn scheduler() -> (TaskScheduler, Arc<MockRunner>) {
+        let runner = Arc::new(MockRunner {
+            calls: AtomicUsize::new(0),
+        });
+        let scheduler = TaskScheduler::new(Storage::in_memory().unwrap(), runner.clone());
+        (scheduler, runner)
+    }
+
+    fn hourly(name: &str, prompt: &str) -> Schedule {
+        Schedule::new(name, CronSchedule::parse("@hourly").unwrap(), prompt)
+    }
+
+    #[test]
+    fn test_add_list_remove() {
+        let (scheduler, _) = scheduler();
+        let added = scheduler
+            .add(hourly("nightly-deps", "/update-deps").with_working_dir("/repo"))
+            .unwrap();
+        assert!(added.is_skill());
+        assert!(added.next_run_at.unwrap() > Utc::now());
+        assert!(scheduler.add(hourly("nightly-deps", "other")).is_err());
+
+        let loaded = scheduler.get("nightly-deps").unwrap().unwrap();
+        assert_eq!(loaded.id, added.id);
+        assert_eq!(loaded.cron.expression(), "@hourly");
+        assert_eq!(loaded.working_dir, Some(PathBuf::from("/repo")));
+        assert_eq!(
+            loaded.next_run_at.map(|t| t.timestamp()),
+            added.next_run_at.map(|t| t.timestamp())
+        );
+
+        let disabled = scheduler.set_enabled("nightly-deps", false).unwrap();
+        assert!(!disabled.enabled);
+        assert!(!disabled.is_due(Utc::now() + chrono::Duration::days(1)));
+
+        scheduler.remove(&added.id).unwrap();
+        assert!(scheduler.list().unwrap().is_empty());
+        assert!(scheduler.remove("nightly-deps").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tick_fires_due_schedules() {
+        let (scheduler, runner) = scheduler();
+        let ok = scheduler.add(hourly("ok", "update deps")).unwrap();
+        scheduler.add(hourly("broken", "fail please")).unwrap();
+
+        // Not due yet
+        assert!(scheduler.tick(Utc::now()).await.unwrap().is_empty());
+
+        let now = ok.next_run_at.unwrap() + chrono::Duration::seconds(5);
+        let mut runs = scheduler.tick(now).await.unwrap();
+        runs.sort_by(|a, b| a.status.cmp(&b.status));
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runner.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(runs[0].status, "error");
+        assert_eq!(
+            runs[0].error_message.as_deref(),
+            Some("Agent error: provider unavailable")
+        );
+        assert_eq!(runs[1].status, "success");
+        assert_eq!(runs[1].output_text.as_deref(), Some("done: update deps"));
+
+        // Advanced past `now`, so the same tick does not fire again
+        let reloaded = scheduler.get("ok").unwrap().unwrap();
+        assert!(reloaded.next_run_at.unwrap() > now);
+        assert!(reloaded.last_run_at.is_some());
+        assert!(scheduler.tick(now).await.unwrap().is_empty());
+
+        let history = scheduler.runs("ok", 10).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].session_id.as_deref(), Some("session-1"));
+    }
+
+    #[tokio::test]
+    async fn test_run_now() {
+        let (scheduler, runner) = scheduler();
+        let added = scheduler
+            .add(hourly("manual", "summarize open issues"))
+            .unwrap();
+
+        let run = scheduler.run_now("manual").await.unwrap();
+        assert_eq!(run.status, "success");
+        assert_eq!(runner.calls.load(Ordering::SeqCst), 1);
+
+        // Manual runs keep the regular schedule
+        let reloaded = scheduler.get("manual").unwrap().unwrap();
+        assert_eq!(
+            reloaded.next_run_at.map(|t| t.timestamp()),
+            added.next_run_at.map(|t| t.timestamp())
+        );
+        assert!(scheduler.run_now("missing").await.is_err());
+    }
+}
@@ -9,9 +9,24 @@
 //! let config = SubAgentConfig::for_type(SubAgentType::Explore)
 //!     .with_token_budget(TokenBudgetConfig::from_parent(parent_budget, 0.3)); // 30% of parent
 //! ```
+//!
+//! ## Tool Policies
+//!
+//! Tool access is declared per agent type as allow/deny lists and enforced by
+//! handing the sub-agent a filtered tool registry, so disallowed tools are never
+//! offered to (or executable by) the model:
+//!
+//! ```ignore
+//! let policies: SubAgentToolPolicies = serde_json::from_value(json!({
+//!     "researcher": { "allow": ["read", "grep", "web_*"] },
+//!     "builder": { "allow": ["bash", "write", "edit", "read"], "deny": ["mcp_*"] }
+//! }))?;
+//! let config = SubAgentConfig::for_type(SubAgentType::Researcher).with_tool_policies(&policies);
+//! ```
 
 use crate::subagent::SubAgentType;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Model selection for sub-agent
@@ -47,6 +62,23 @@ impl ModelSelection {
             Self::Opus => "claude-opus-4-20250514".to_string(),
         }
     }
+
+    /// Parse a model alias (`inherit`, `haiku`, `sonnet`, `opus`) or a model ID
+    /// containing one of those family names. Returns `None` for unknown models.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        if name == "inherit" {
+            Some(Self::Inherit)
+        } else if name.contains("haiku") {
+            Some(Self::Haiku)
+        } else if name.contains("sonnet") {
+            Some(Self::Sonnet)
+        } else if name.contains("opus") {
+            Some(Self::Opus)
+        } else {
+            None
+        }
+    }
 }
 
 /// Permission mode for sub-agent
@@ -72,6 +104,103 @@ impl Default for PermissionMode {
     }
 }
 
+// ============================================================================
+// Tool Policy
+// ============================================================================
+
+/// Declarative tool allow/deny lists
+///
+/// Entries are tool names or glob patterns (`web_*`, `mcp_github_*`, `*`).
+/// Deny entries take precedence, and an empty allow list permits nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolPolicy {
+    /// Allowed tools or patterns
+    #[serde(default)]
+    pub allow: Vec<String>,
+
+    /// Denied tools or patterns (takes precedence over allow)
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl ToolPolicy {
+    /// Create an empty policy (denies everything)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a policy that allows every tool
+    pub fn allow_all() -> Self {
+        Self::new().allow("*")
+    }
+
+    /// Builder: add allowed tool or pattern
+    pub fn allow(mut self, pattern: impl Into<String>) -> Self {
+        self.allow.push(pattern.into());
+        self
+    }
+
+    /// Builder: add denied tool or pattern
+    pub fn deny(mut self, pattern: impl Into<String>) -> Self {
+        self.deny.push(pattern.into());
+        self
+    }
+
+    /// Check if a tool passes this policy
+    pub fn is_allowed(&self, tool_name: &str) -> bool {
+        if self.deny.iter().any(|p| tool_matches(p, tool_name)) {
+            return false;
+        }
+        self.allow.iter().any(|p| tool_matches(p, tool_name))
+    }
+}
+
+/// Match a tool name against a name or glob pattern
+fn tool_matches(pattern: &str, tool_name: &str) -> bool {
+    if pattern.contains(['*', '?', '[']) {
+        glob::Pattern::new(pattern).is_ok_and(|p| p.matches(tool_name))
+    } else {
+        pattern == tool_name
+    }
+}
+
+/// Tool policies per agent type, keyed by `SubAgentType::name()`
+///
+/// Types without an entry keep their built-in defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubAgentToolPolicies {
+    policies: HashMap<String, ToolPolicy>,
+}
+
+impl SubAgentToolPolicies {
+    /// Create an empty policy set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder: set the policy for an agent type
+    pub fn with_policy(mut self, agent_type: &SubAgentType, policy: ToolPolicy) -> Self {
+        self.policies.insert(agent_type.name().to_string(), policy);
+        self
+    }
+
+    /// Get the declared policy for an agent type
+    pub fn get(&self, agent_type: &SubAgentType) -> Option<&ToolPolicy> {
+        self.policies.get(agent_type.name())
+    }
+
+    /// Number of declared policies
+    pub fn len(&self) -> usize {
+        self.policies.len()
+    }
+
+    /// Whether no policies are declared
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+}
+
 // ============================================================================
 // Token Budget Configuration
 // ============================================================================
@@ -307,10 +436,10 @@ pub struct SubAgentConfig {
     /// System prompt override (if any)
     pub system_prompt: Option<String>,
 
-    /// Allowed tools (empty = use type defaults)
+    /// Allowed tools or patterns (empty = use type defaults)
     pub allowed_tools: Vec<String>,
 
-    /// Disallowed tools (takes precedence over allowed)
+    /// Disallowed tools or patterns (takes precedence over allowed)
     pub disallowed_tools: Vec<String>,
 
     /// Model to use
@@ -392,6 +521,16 @@ impl SubAgentConfig {
                 20,
                 TokenBudgetConfig::for_bash_agent(),
             ),
+            SubAgentType::Researcher => (
+                ModelSelection::Sonnet,
+                30,
+                TokenBudgetConfig::for_explore_agent(),
+            ),
+            SubAgentType::Builder => (
+                ModelSelection::Inherit,
+                50,
+                TokenBudgetConfig::for_general_agent(),
+            ),
             SubAgentType::Custom(_) => (ModelSelection::Inherit, 50, TokenBudgetConfig::Default),
         };
 
@@ -424,6 +563,21 @@ impl SubAgentConfig {
         self
     }
 
+    /// Builder: replace allowed/disallowed tools with a policy
+    pub fn with_tool_policy(mut self, policy: ToolPolicy) -> Self {
+        self.allowed_tools = policy.allow;
+        self.disallowed_tools = policy.deny;
+        self
+    }
+
+    /// Builder: apply the declared policy for this agent's type (if any)
+    pub fn with_tool_policies(self, policies: &SubAgentToolPolicies) -> Self {
+        match policies.get(&self.agent_type).cloned() {
+            Some(policy) => self.with_tool_policy(policy),
+            None => self,
+        }
+    }
+
     /// Builder: set model
     pub fn with_model(mut self, model: ModelSelection) -> Self {
         self.model = model;
@@ -499,25 +653,25 @@ impl SubAgentConfig {
         )
     }
 
-    /// Check if a tool is allowed for this agent
-    pub fn is_tool_allowed(&self, tool_name: &str) -> bool {
-        // Disallowed takes precedence
-        if self.disallowed_tools.contains(&tool_name.to_string()) {
-            return false;
-        }
-
+    /// Effective tool policy for this agent
+    pub fn tool_policy(&self) -> ToolPolicy {
         // If allowed list is empty, use type defaults
-        if self.allowed_tools.is_empty() {
-            let defaults = self.agent_type.default_allowed_tools();
-            if defaults.is_empty() {
-                // Custom type with no config - deny all
-                return false;
-            }
-            return defaults.contains(&tool_name.to_string());
+        // (Custom type with no config - deny all)
+        let allow = if self.allowed_tools.is_empty() {
+            self.agent_type.default_allowed_tools()
+        } else {
+            self.allowed_tools.clone()
+        };
+
+        ToolPolicy {
+            allow,
+            deny: self.disallowed_tools.clone(),
         }
+    }
 
-        // Check allowed list
-        self.allowed_tools.contains(&tool_name.to_string())
+    /// Check if a tool is allowed for this agent
+    pub fn is_tool_allowed(&self, tool_name: &str) -> bool {
+        self.tool_policy().is_allowed(tool_name)
     }
 
     /// Get the effective system prompt
@@ -548,6 +702,17 @@ impl SubAgentConfig {
                  and scripts. Focus on executing commands correctly and handling their output."
                     .to_string()
             }
+            SubAgentType::Researcher => {
+                "You are a research agent. Your task is to gather information from the \
+                 codebase and the web. You cannot modify any files or run commands. \
+                 Cite the files and sources your findings come from."
+                    .to_string()
+            }
+            SubAgentType::Builder => {
+                "You are a build agent. Your task is to implement changes, run builds and \
+                 fix failures. Keep changes focused on the assigned task."
+                    .to_string()
+            }
             SubAgentType::Custom(name) => {
                 format!(
                     "You are a custom agent: {}. Complete the assigned task.",
@@ -611,6 +776,45 @@ mod tests {
         assert!(!config.is_tool_allowed("bash"));
     }
 
+    #[test]
+    fn test_tool_policy_patterns() {
+        let policy = ToolPolicy::new()
+            .allow("read")
+            .allow("web_*")
+            .deny("web_fetch");
+
+        assert!(policy.is_allowed("read"));
+        assert!(policy.is_allowed("web_search"));
+        assert!(!policy.is_allowed("web_fetch"));
+        assert!(!policy.is_allowed("bash"));
+        assert!(!ToolPolicy::new().is_allowed("read"));
+        assert!(ToolPolicy::allow_all().is_allowed("mcp_github_create_issue"));
+    }
+
+    #[test]
+    fn test_declared_tool_policies() {
+        let policies: SubAgentToolPolicies = serde_json::from_value(serde_json::json!({
+            "researcher": { "allow": ["read", "grep", "web_*"] },
+            "builder": { "allow": ["bash", "write"] }
+        }))
+        .unwrap();
+
+        let researcher =
+            SubAgentConfig::for_type(SubAgentType::Researcher).with_tool_policies(&policies);
+        assert!(researcher.is_tool_allowed("web_fetch"));
+        assert!(!researcher.is_tool_allowed("glob"));
+        assert!(!researcher.is_tool_allowed("bash"));
+
+        let builder = SubAgentConfig::for_type(SubAgentType::Builder).with_tool_policies(&policies);
+        assert!(builder.is_tool_allowed("write"));
+        assert!(!builder.is_tool_allowed("read"));
+
+        // Types without a declared policy keep their defaults
+        let explore = SubAgentConfig::for_type(SubAgentType::Explore).with_tool_policies(&policies);
+        assert!(explore.is_tool_allowed("read"));
+        assert!(!explore.is_tool_allowed("write"));
+    }
+
     #[test]
     fn test_model_selection() {
         let haiku = ModelSelection::Haiku;
@@ -618,6 +822,20 @@ mod tests {
 
         let inherit = ModelSelection::Inherit;
         assert_eq!(inherit.model_id("claude-sonnet"), "claude-sonnet");
+
+        assert_eq!(
+            ModelSelection::from_name("Opus"),
+            Some(ModelSelection::Opus)
+        );
+        assert_eq!(
+            ModelSelection::from_name("claude-3-5-haiku-20241022"),
+            Some(ModelSelection::Haiku)
+        );
+        assert_eq!(
+            ModelSelection::from_name("inherit"),
+            Some(ModelSelection::Inherit)
+        );
+        assert_eq!(ModelSelection::from_name("gpt-4o"), None);
     }
 
     #[test]
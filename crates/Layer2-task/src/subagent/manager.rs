@@ -1,7 +1,8 @@
 //! Sub-agent manager - orchestrates sub-agent lifecycle
 
 use crate::subagent::{
-    Discovery, SubAgent, SubAgentConfig, SubAgentId, SubAgentState, SubAgentType,
+    Discovery, SubAgent, SubAgentConfig, SubAgentId, SubAgentState, SubAgentToolPolicies,
+    SubAgentType,
 };
 use forge_foundation::{Error, Result};
 use std::collections::{HashMap, VecDeque};
@@ -39,6 +40,9 @@ pub struct SubAgentManagerConfig {
 
     /// Queue timeout in seconds (0 = no timeout)
     pub queue_timeout_secs: u64,
+
+    /// Declared tool policies per agent type (applied on `spawn`)
+    pub tool_policies: SubAgentToolPolicies,
 }
 
 impl Default for SubAgentManagerConfig {
@@ -50,6 +54,7 @@ impl Default for SubAgentManagerConfig {
             enable_queue: true,
             max_queue_size: 16,
             queue_timeout_secs: 300, // 5 minutes
+            tool_policies: SubAgentToolPolicies::default(),
         }
     }
 }
@@ -166,7 +171,7 @@ impl SubAgentManager {
         prompt: &str,
         description: &str,
     ) -> Result<SubAgentId> {
-        let config = SubAgentConfig::for_type(agent_type);
+        let config = self.config_for_type(agent_type);
         self.spawn_with_priority(
             parent_session_id,
             config,
@@ -177,6 +182,11 @@ impl SubAgentManager {
         .await
     }
 
+    /// Default configuration for an agent type with declared tool policies applied
+    pub fn config_for_type(&self, agent_type: SubAgentType) -> SubAgentConfig {
+        SubAgentConfig::for_type(agent_type).with_tool_policies(&self.config.tool_policies)
+    }
+
     /// Spawn a sub-agent with custom configuration
     pub async fn spawn_with_config(
         &self,
@@ -410,7 +420,7 @@ impl SubAgentManager {
         prompt: &str,
         description: &str,
     ) -> Result<SubAgentId> {
-        let config = SubAgentConfig::for_type(agent_type).run_in_background();
+        let config = self.config_for_type(agent_type).run_in_background();
         self.spawn_with_config(parent_session_id, config, prompt, description)
             .await
     }
@@ -749,6 +759,7 @@ impl SubAgentManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subagent::ToolPolicy;
 
     #[tokio::test]
     async fn test_spawn_agent() {
@@ -828,6 +839,26 @@ mod tests {
         assert!(!manager.is_tool_allowed(agent_id, "bash").await);
     }
 
+    #[tokio::test]
+    async fn test_declared_tool_policies_applied_on_spawn() {
+        let config = SubAgentManagerConfig {
+            tool_policies: SubAgentToolPolicies::new().with_policy(
+                &SubAgentType::Builder,
+                ToolPolicy::new().allow("bash").allow("write"),
+            ),
+            ..Default::default()
+        };
+        let manager = SubAgentManager::new(config);
+
+        let agent_id = manager
+            .spawn("session-1", SubAgentType::Builder, "Build", "Build")
+            .await
+            .unwrap();
+
+        assert!(manager.is_tool_allowed(agent_id, "write").await);
+        assert!(!manager.is_tool_allowed(agent_id, "edit").await);
+    }
+
     #[tokio::test]
     async fn test_queue_priority() {
         let config = SubAgentManagerConfig {
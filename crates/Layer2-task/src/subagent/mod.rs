@@ -7,7 +7,7 @@
 //!
 //! - **Context Window Management**: Token tracking with auto-summarization
 //! - **Discovery Sharing**: Knowledge transfer between agents
-//! - **Tool Access Control**: Per-agent tool filtering
+//! - **Tool Access Control**: Declarative per-type allow/deny lists (`ToolPolicy`)
 //! - **Log Integration**: Task log access for debugging
 //! - **Handoff System**: Clean session transitions (Amp-style)
 
@@ -18,8 +18,8 @@ pub mod manager;
 pub mod types;
 
 pub use config::{
-    EffectiveTokenBudget, ModelSelection, PermissionMode, SubAgentConfig, TokenBudgetConfig,
-    TokenBudgetSource,
+    EffectiveTokenBudget, ModelSelection, PermissionMode, SubAgentConfig, SubAgentToolPolicies,
+    TokenBudgetConfig, TokenBudgetSource, ToolPolicy,
 };
 pub use context::{
     CompressionCheckpoint, CompressionStats, ContextMessage, ContextStore, ContextToolResult,
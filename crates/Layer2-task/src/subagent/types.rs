@@ -1,6 +1,6 @@
 //! Sub-agent type definitions
 
-use crate::subagent::{SubAgentConfig, SubAgentContext};
+use crate::subagent::{SubAgentConfig, SubAgentContext, ToolPolicy};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -57,6 +57,15 @@ pub enum SubAgentType {
     /// Disallowed: Write, Edit (should use Bash for file ops)
     Bash,
 
+    /// Research specialist for code and web lookups
+    /// Allowed: Read, Grep, Glob, code search, web search/fetch
+    /// Disallowed: Write, Edit, Bash
+    Researcher,
+
+    /// Build/implementation specialist
+    /// Allowed: Bash, Write, Edit, Read, Grep, Glob
+    Builder,
+
     /// Custom agent with user-defined configuration
     Custom(String),
 }
@@ -89,6 +98,23 @@ impl SubAgentType {
                 "read".into(),
                 "forgecmd".into(),
             ],
+            Self::Researcher => vec![
+                "read".into(),
+                "grep".into(),
+                "glob".into(),
+                "code_search".into(),
+                "web_search".into(),
+                "web_fetch".into(),
+            ],
+            Self::Builder => vec![
+                "bash".into(),
+                "write".into(),
+                "edit".into(),
+                "read".into(),
+                "grep".into(),
+                "glob".into(),
+                "forgecmd".into(),
+            ],
             Self::Custom(_) => vec![], // Must be explicitly configured
         }
     }
@@ -113,10 +139,25 @@ impl SubAgentType {
                 "write".into(),
                 "edit".into(),
             ],
+            Self::Researcher => vec![
+                "write".into(),
+                "edit".into(),
+                "bash".into(),
+                "forgecmd".into(),
+            ],
+            Self::Builder => vec![],
             Self::Custom(_) => vec![], // Must be explicitly configured
         }
     }
 
+    /// Get the default tool policy for this agent type
+    pub fn default_tool_policy(&self) -> ToolPolicy {
+        ToolPolicy {
+            allow: self.default_allowed_tools(),
+            deny: self.default_disallowed_tools(),
+        }
+    }
+
     /// Get a human-readable description
     pub fn description(&self) -> &'static str {
         match self {
@@ -124,10 +165,38 @@ impl SubAgentType {
             Self::Plan => "Architecture planning without modifications",
             Self::General => "General purpose with full tool access",
             Self::Bash => "Command execution specialist",
+            Self::Researcher => "Code and web research without modifications",
+            Self::Builder => "Build and implementation specialist",
             Self::Custom(_) => "Custom user-defined agent",
         }
     }
 
+    /// Get the configuration key (`explore`, `researcher`, custom name, ...)
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Explore => "explore",
+            Self::Plan => "plan",
+            Self::General => "general",
+            Self::Bash => "bash",
+            Self::Researcher => "researcher",
+            Self::Builder => "builder",
+            Self::Custom(name) => name,
+        }
+    }
+
+    /// Parse a type name; unknown names become `Custom`
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "explore" => Self::Explore,
+            "plan" => Self::Plan,
+            "general" | "general-purpose" => Self::General,
+            "bash" => Self::Bash,
+            "researcher" => Self::Researcher,
+            "builder" => Self::Builder,
+            _ => Self::Custom(name.trim().to_string()),
+        }
+    }
+
     /// Get the display name
     pub fn display_name(&self) -> String {
         match self {
@@ -135,6 +204,8 @@ impl SubAgentType {
             Self::Plan => "Plan".into(),
             Self::General => "General".into(),
             Self::Bash => "Bash".into(),
+            Self::Researcher => "Researcher".into(),
+            Self::Builder => "Builder".into(),
             Self::Custom(name) => name.clone(),
         }
     }
@@ -396,6 +467,22 @@ mod tests {
         assert!(general.default_disallowed_tools().is_empty());
     }
 
+    #[test]
+    fn test_subagent_type_names() {
+        for agent_type in [
+            SubAgentType::Researcher,
+            SubAgentType::Builder,
+            SubAgentType::Plan,
+        ] {
+            assert_eq!(SubAgentType::from_name(agent_type.name()), agent_type);
+        }
+        assert_eq!(SubAgentType::from_name("general-purpose"), SubAgentType::General);
+        assert_eq!(
+            SubAgentType::from_name("reviewer"),
+            SubAgentType::Custom("reviewer".into())
+        );
+    }
+
     #[test]
     fn test_subagent_lifecycle() {
         let config = SubAgentConfig::for_type(SubAgentType::Explore);
@@ -1,5 +1,7 @@
 //! Task definition and types
 
+use crate::artifact::{Artifact, ArtifactSpec};
+use crate::executor::resource_monitor::{ProcessResourceLimits, ResourceUsage};
 use crate::state::TaskState;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -53,6 +55,19 @@ pub enum ExecutionMode {
         /// Volumes to mount (host:container)
         volumes: Vec<(String, String)>,
     },
+
+    /// Execute as a Kubernetes Job (see `K8sExecutor`)
+    Kubernetes {
+        /// Image override (default: `K8sConfig::container.image`)
+        image: Option<String>,
+    },
+
+    /// Execute with an executor registered via `TaskManager::register_executor`
+    /// (e.g. one provided by a plugin)
+    Custom {
+        /// Registered executor name (`Executor::name`)
+        executor: String,
+    },
 }
 
 impl Default for ExecutionMode {
@@ -92,6 +107,14 @@ pub struct Task {
     #[serde(default)]
     pub env: std::collections::HashMap<String, String>,
 
+    /// CPU/memory quota for Local/PTY execution (default: executor's monitor limits)
+    #[serde(default)]
+    pub resource_limits: Option<ProcessResourceLimits>,
+
+    /// Output files to keep after the task finishes (see `ArtifactStore`)
+    #[serde(default)]
+    pub artifacts: Vec<ArtifactSpec>,
+
     /// When the task was created
     pub created_at: DateTime<Utc>,
 
@@ -123,6 +146,8 @@ impl Task {
             execution_mode: ExecutionMode::default(),
             timeout: Duration::from_secs(120),
             env: std::collections::HashMap::new(),
+            resource_limits: None,
+            artifacts: Vec::new(),
             created_at: Utc::now(),
             started_at: None,
             completed_at: None,
@@ -154,6 +179,18 @@ impl Task {
         self
     }
 
+    /// Set resource quota (enforced by the Local/PTY executors)
+    pub fn with_resource_limits(mut self, limits: ProcessResourceLimits) -> Self {
+        self.resource_limits = Some(limits);
+        self
+    }
+
+    /// Declare an output artifact to collect after execution
+    pub fn with_artifact(mut self, spec: ArtifactSpec) -> Self {
+        self.artifacts.push(spec);
+        self
+    }
+
     /// Mark task as running
     pub fn start(&mut self) {
         self.state = TaskState::Running;
@@ -208,6 +245,14 @@ pub struct TaskResult {
 
     /// Additional metadata
     pub metadata: Option<serde_json::Value>,
+
+    /// Peak/average CPU and memory usage (Local/PTY on Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_usage: Option<ResourceUsage>,
+
+    /// Collected output artifacts (see `Task::artifacts`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<Artifact>,
 }
 
 impl TaskResult {
@@ -217,6 +262,8 @@ impl TaskResult {
             output: output.into(),
             exit_code: Some(0),
             metadata: None,
+            resource_usage: None,
+            artifacts: Vec::new(),
         }
     }
 
@@ -226,6 +273,8 @@ impl TaskResult {
             output: output.into(),
             exit_code: Some(exit_code),
             metadata: None,
+            resource_usage: None,
+            artifacts: Vec::new(),
         }
     }
 
@@ -234,4 +283,16 @@ impl TaskResult {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Attach measured resource usage
+    pub fn with_resource_usage(mut self, usage: Option<ResourceUsage>) -> Self {
+        self.resource_usage = usage;
+        self
+    }
+
+    /// Attach collected artifacts
+    pub fn with_artifacts(mut self, artifacts: Vec<Artifact>) -> Self {
+        self.artifacts = artifacts;
+        self
+    }
 }
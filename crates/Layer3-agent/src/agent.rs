@@ -26,22 +26,33 @@
 //! → Return Response
 //! ```
 
+use crate::auto_commit::{check_feedback, try_auto_commit, AutoCommitOutcome};
 use crate::compressor::{CompressorConfig, ContextCompressor};
 use crate::context::AgentContext;
+use crate::event_channel::AgentEventSender;
 use crate::history::MessageHistory;
 use crate::hook::{AgentHook, HookManager, HookResult, ToolResult, TurnInfo};
 use crate::parallel::ExecutionPlanner;
 use crate::recovery::{ErrorRecovery, RecoveryAction, RecoveryContext};
 use crate::steering::{AgentState, Steerable, SteeringChecker, SteeringHandle, SteeringQueue};
-use forge_foundation::{Error, Result};
-use forge_provider::{StreamEvent, ToolCall};
+use crate::tool_output::ToolOutputForwarder;
+use crate::tool_stats::{ToolAttempts, ToolExecutionRecorder};
+use crate::turn_summary::{record_ghost_commit, TurnChangeTracker};
+use forge_core::{AutoCommitConfig, Skill};
+use forge_foundation::{
+    tokenizer_factory, CalibrationEvent, Error, Result, ToolOutputSink, ToolProgress,
+};
+use forge_provider::{Message, ModelInfo, ProviderError, RequestOptions, StreamEvent, ToolCall};
+use forge_task::ModelSelection;
 use futures::StreamExt;
 use serde_json::Value;
 use std::sync::Arc;
 use std::time::Instant;
-use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
+/// 시스템 프롬프트에 넣는 MCP 리소스 목록의 토큰 예산
+const MCP_RESOURCE_CONTEXT_TOKENS: usize = 1000;
+
 // ============================================================================
 // Agent Events
 // ============================================================================
@@ -61,6 +72,20 @@ pub enum AgentEvent {
         tool_call_id: String,
     },
 
+    /// Live output chunk (one line of stdout/stderr) from a running tool
+    ToolOutput {
+        tool_name: String,
+        tool_call_id: String,
+        chunk: String,
+    },
+
+    /// Progress update from a long-running tool (e.g. MCP `notifications/progress`)
+    ToolProgress {
+        tool_name: String,
+        tool_call_id: String,
+        progress: ToolProgress,
+    },
+
     /// Tool execution completed
     ToolComplete {
         tool_name: String,
@@ -95,6 +120,18 @@ pub enum AgentEvent {
     /// Turn completed
     TurnComplete { turn: u32 },
 
+    /// Files were modified during this run (e.g. "modified 3 files: +120 −45; tests not yet run")
+    TurnSummary { summary: String },
+
+    /// The run's changes were recorded as timeline turn `turn` (see `/rewind`)
+    GhostCommit { turn: u32 },
+
+    /// The run's changes were auto-committed
+    AutoCommitted { hash: String },
+
+    /// Pre-commit checks blocked the auto-commit (`retrying`: sent back to the agent to fix)
+    PreCommitFailed { summary: String, retrying: bool },
+
     /// Agent paused
     Paused,
 
@@ -127,6 +164,30 @@ pub struct AgentConfig {
     /// 병렬 도구 실행 활성화
     /// read, glob, grep 등 독립적인 도구들을 동시에 실행
     pub parallel_tools: bool,
+
+    /// 파일을 수정한 턴이 끝나면 git diff 요약을 히스토리에 추가
+    pub turn_summary: bool,
+
+    /// 파일을 수정한 턴마다 작업 트리를 숨은 ref에 고스트 커밋으로 기록
+    /// (`turn_summary`가 켜져 있어야 동작)
+    pub ghost_commits: bool,
+
+    /// LLM 응답 최대 토큰 (None이면 Provider 기본값, 모델 한도로 제한됨)
+    pub max_tokens: Option<u32>,
+
+    /// 커스텀 stop sequence
+    pub stop_sequences: Vec<String>,
+
+    /// 요청에 사용할 모델 ID (None이면 Provider의 현재 모델)
+    pub model: Option<String>,
+
+    /// 도구 출력 줄과 일치하면 명령을 즉시 중단하는 정규식
+    pub kill_patterns: Vec<String>,
+
+    /// 파일을 수정한 실행이 끝나면 자동 커밋 (None이면 비활성)
+    /// pre-commit 검사가 실패하면 에이전트가 고친 뒤 재시도
+    /// (`turn_summary`가 켜져 있어야 동작)
+    pub auto_commit: Option<AutoCommitConfig>,
 }
 
 impl Default for AgentConfig {
@@ -137,6 +198,13 @@ impl Default for AgentConfig {
             auto_compress: true,
             streaming: true,
             parallel_tools: true, // 기본 활성화
+            turn_summary: true,
+            ghost_commits: true,
+            max_tokens: None,
+            stop_sequences: Vec::new(),
+            model: None,
+            kill_patterns: Vec::new(),
+            auto_commit: None,
         }
     }
 }
@@ -150,6 +218,13 @@ impl AgentConfig {
             auto_compress: true,
             streaming: true,
             parallel_tools: true,
+            turn_summary: true,
+            ghost_commits: true,
+            max_tokens: None,
+            stop_sequences: Vec::new(),
+            model: None,
+            kill_patterns: Vec::new(),
+            auto_commit: None,
         }
     }
 
@@ -161,6 +236,13 @@ impl AgentConfig {
             auto_compress: true,
             streaming: true,
             parallel_tools: true,
+            turn_summary: true,
+            ghost_commits: true,
+            max_tokens: None,
+            stop_sequences: Vec::new(),
+            model: None,
+            kill_patterns: Vec::new(),
+            auto_commit: None,
         }
     }
 
@@ -171,6 +253,75 @@ impl AgentConfig {
             ..Self::default()
         }
     }
+
+    /// 응답 최대 토큰 설정
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// stop sequence 설정
+    pub fn with_stop_sequences<I, S>(mut self, sequences: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stop_sequences = sequences.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// kill pattern 설정 (정규식, 출력 줄 단위로 검사)
+    pub fn with_kill_patterns<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.kill_patterns = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 자동 커밋 설정 (`enabled`가 false면 비활성)
+    pub fn with_auto_commit(mut self, config: AutoCommitConfig) -> Self {
+        self.auto_commit = config.enabled.then_some(config);
+        self
+    }
+
+    /// 모델 설정 (`haiku`/`sonnet`/`opus` 별칭은 Claude 모델 ID로, `inherit`은 현재 모델 유지)
+    pub fn with_model(mut self, model: &str) -> Self {
+        let model = model.trim();
+        match model.to_ascii_lowercase().as_str() {
+            "" | "inherit" => {}
+            alias @ ("haiku" | "sonnet" | "opus") => {
+                self.model = ModelSelection::from_name(alias).map(|m| m.model_id(model));
+            }
+            _ => self.model = Some(model.to_string()),
+        }
+        self
+    }
+
+    /// 스킬 설정(max-tokens, stop-sequences, model) 적용 - 스킬에 지정된 값이 우선
+    pub fn with_skill(mut self, skill: &dyn Skill) -> Self {
+        if let Some(max_tokens) = skill.max_tokens() {
+            self.max_tokens = Some(max_tokens);
+        }
+        let stop_sequences = skill.stop_sequences();
+        if !stop_sequences.is_empty() {
+            self.stop_sequences = stop_sequences;
+        }
+        match skill.model() {
+            Some(model) => self.with_model(&model),
+            None => self,
+        }
+    }
+
+    /// LLM 요청 옵션
+    pub fn request_options(&self) -> RequestOptions {
+        RequestOptions {
+            max_tokens: self.max_tokens,
+            stop_sequences: self.stop_sequences.clone(),
+            model: self.model.clone(),
+        }
+    }
 }
 
 // ============================================================================
@@ -201,6 +352,9 @@ pub struct Agent {
 
     /// Steering checker (stored for Steerable trait)
     steering_checker: SteeringChecker,
+
+    /// Tool execution recorder (for `forge stats tools`)
+    tool_recorder: Option<Arc<ToolExecutionRecorder>>,
 }
 
 impl Agent {
@@ -217,6 +371,7 @@ impl Agent {
             hooks: HookManager::new(),
             steering_queue,
             steering_checker,
+            tool_recorder: None,
         }
     }
 
@@ -232,6 +387,7 @@ impl Agent {
             hooks: HookManager::new(),
             steering_queue,
             steering_checker,
+            tool_recorder: None,
         }
     }
 
@@ -259,6 +415,12 @@ impl Agent {
         self
     }
 
+    /// Record every tool execution (including parallel ones)
+    pub fn with_tool_recorder(mut self, recorder: ToolExecutionRecorder) -> Self {
+        self.tool_recorder = Some(Arc::new(recorder));
+        self
+    }
+
     /// Get steering handle for external control
     pub fn steering_handle(&self) -> SteeringHandle {
         self.steering_queue.handle()
@@ -275,7 +437,7 @@ impl Agent {
         session_id: &str,
         history: &mut MessageHistory,
         user_message: &str,
-        event_tx: mpsc::Sender<AgentEvent>,
+        event_tx: AgentEventSender,
     ) -> Result<String> {
         let steering = self.steering_checker();
         steering.set_state(AgentState::Running).await;
@@ -285,7 +447,11 @@ impl Agent {
 
         // Set system prompt if not set
         if history.system_prompt().is_none() {
-            history.set_system_prompt(&self.ctx.system_prompt);
+            match self.ctx.mcp_resource_context(MCP_RESOURCE_CONTEXT_TOKENS).await {
+                Some(resources) => history
+                    .set_system_prompt(format!("{}\n\n{}", self.ctx.system_prompt, resources)),
+                None => history.set_system_prompt(&self.ctx.system_prompt),
+            }
         }
 
         // Run before_agent hooks
@@ -314,6 +480,15 @@ impl Agent {
         let mut total_input_tokens = 0u32;
         let mut total_output_tokens = 0u32;
         let mut tools_used = Vec::with_capacity(8); // Typical tool count
+        let mut changes = if self.config.turn_summary {
+            TurnChangeTracker::new(&self.ctx.working_dir)
+        } else {
+            TurnChangeTracker::disabled()
+        };
+        let request_options = self.config.request_options();
+        // Part of full_response already added to the history (pre-commit fix rounds)
+        let mut recorded_len = 0;
+        let mut fix_attempts = 0u32;
 
         loop {
             // Check max iterations
@@ -404,11 +579,27 @@ impl Agent {
             // Note: to_messages() currently clones, but provider API requires ownership
             // TODO: Consider modifying Provider trait to accept &[Message] for zero-copy
             let provider = self.ctx.gateway.get_default_provider_for_stream().await?;
-            let system_prompt = history.system_prompt().map(String::from);
-            let stream = provider.stream(history.to_messages(), tools, system_prompt);
-
-            // Process stream
-            let (response_text, tool_calls, usage) = self.process_stream(stream, &event_tx).await?;
+            let mut overflow_retried = false;
+            let (response_text, tool_calls, usage) = loop {
+                let system_prompt = history.system_prompt().map(String::from);
+                let stream = provider.stream_with_options(
+                    self.ctx.redact_outgoing(history.to_messages()),
+                    tools.clone(),
+                    system_prompt,
+                    &request_options,
+                );
+
+                // Process stream - on a context-length error, compress harder and retry once
+                match self.process_stream(stream, &event_tx).await {
+                    Ok(output) => break output,
+                    Err(e @ ProviderError::ContextLengthExceeded(_)) if !overflow_retried => {
+                        overflow_retried = true;
+                        self.recover_context_overflow(history, provider.model(), &e, &event_tx)
+                            .await?;
+                    }
+                    Err(e) => return Err(Error::Provider(e.to_string())),
+                }
+            };
 
             // Accumulate response text
             if !response_text.is_empty() {
@@ -429,8 +620,8 @@ impl Agent {
 
             // If no tool calls, we're done
             if tool_calls.is_empty() {
-                if !full_response.is_empty() {
-                    history.add_assistant(&full_response);
+                if full_response.len() > recorded_len {
+                    history.add_assistant(&full_response[recorded_len..]);
                 }
 
                 // Run after_turn hook
@@ -438,12 +629,43 @@ impl Agent {
                     .run_after_turn(history, turn, &full_response)
                     .await?;
                 let _ = event_tx.send(AgentEvent::TurnComplete { turn }).await;
+
+                // Commit the run's changes; failed pre-commit checks go back to the agent
+                let auto_commit = self
+                    .config
+                    .auto_commit
+                    .as_ref()
+                    .filter(|_| changes.has_modifications());
+                if let Some(config) = auto_commit {
+                    match try_auto_commit(&self.ctx.working_dir, config) {
+                        AutoCommitOutcome::Committed(hash) => {
+                            let _ = event_tx.send(AgentEvent::AutoCommitted { hash }).await;
+                        }
+                        AutoCommitOutcome::ChecksFailed(report) => {
+                            let retrying = fix_attempts < config.max_fix_attempts;
+                            let _ = event_tx
+                                .send(AgentEvent::PreCommitFailed {
+                                    summary: report.to_string(),
+                                    retrying,
+                                })
+                                .await;
+                            history.add_user(check_feedback(&report, retrying));
+                            if retrying {
+                                fix_attempts += 1;
+                                recorded_len = full_response.len();
+                                continue;
+                            }
+                        }
+                        AutoCommitOutcome::Skipped | AutoCommitOutcome::Failed(_) => {}
+                    }
+                }
                 break;
             }
 
             // Add assistant message with tool calls
             history.add_assistant_with_tools(&response_text, tool_calls.clone());
             steering.set_state(AgentState::ExecutingTool).await;
+            changes.before_tools(&tool_calls);
 
             // Execute tool calls (parallel or sequential based on config)
             let tool_results = self
@@ -452,9 +674,28 @@ impl Agent {
 
             // Add tool results to history
             for (tool_call_id, content, is_error) in tool_results {
+                if let Some(tool_call) = tool_calls.iter().find(|tc| tc.id == tool_call_id) {
+                    changes.record_result(tool_call, is_error);
+                }
                 history.add_tool_result(&tool_call_id, &content, is_error);
             }
 
+            // Attach images returned by tools (e.g. read on a .png) for vision models
+            let images = self.ctx.take_pending_images();
+            if !images.is_empty() {
+                if provider.model().supports_vision {
+                    history.add(Message::user_with_images(
+                        format!("[{} image(s) from tool results]", images.len()),
+                        images,
+                    ));
+                } else {
+                    history.add_user(format!(
+                        "[{} image(s) from tool results were not attached: the current model does not support vision]",
+                        images.len()
+                    ));
+                }
+            }
+
             // Run after_turn hook
             self.hooks
                 .run_after_turn(history, turn, &response_text)
@@ -464,6 +705,20 @@ impl Agent {
             // Continue loop to get next LLM response
         }
 
+        // Summarize file changes made during this run
+        if let Some(summary) = changes.finish() {
+            if self.config.ghost_commits {
+                let recorded =
+                    record_ghost_commit(&self.ctx.working_dir, session_id, &summary, user_message);
+                if let Some(turn) = recorded {
+                    let _ = event_tx.send(AgentEvent::GhostCommit { turn }).await;
+                }
+            }
+            let summary = summary.to_string();
+            history.add_user(format!("[Turn summary]: {}", summary));
+            let _ = event_tx.send(AgentEvent::TurnSummary { summary }).await;
+        }
+
         // Create turn info for after_agent hook
         let turn_info = TurnInfo {
             turn,
@@ -489,12 +744,58 @@ impl Agent {
         Ok(full_response)
     }
 
+    /// Recover from a context-length error the local estimate did not predict
+    ///
+    /// Records a calibration event so later estimates for this model are corrected,
+    /// then compresses the history with a stricter target.
+    async fn recover_context_overflow(
+        &self,
+        history: &mut MessageHistory,
+        model: &ModelInfo,
+        error: &ProviderError,
+        event_tx: &AgentEventSender,
+    ) -> Result<()> {
+        let estimated = history.estimate_tokens();
+        let actual = error
+            .reported_prompt_tokens()
+            .unwrap_or(model.context_window as usize);
+        let calibration = CalibrationEvent::new(&model.id, estimated, actual);
+        let factor = tokenizer_factory().record_calibration(&calibration);
+        warn!(
+            "Context overflow on {}: estimated {} tokens, provider reported {} (correction x{:.2})",
+            model.id, estimated, actual, factor
+        );
+
+        self.hooks.run_before_compress(history).await?;
+        let result = self.compressor.compress_strict(history)?;
+        if !result.compressed {
+            return Err(Error::Provider(error.to_string()));
+        }
+
+        let _ = event_tx
+            .send(AgentEvent::Compressed {
+                tokens_before: result.tokens_before,
+                tokens_after: result.tokens_after,
+                tokens_saved: result.tokens_saved,
+            })
+            .await;
+        info!(
+            "Retrying after strict compression: {} -> {} tokens",
+            result.tokens_before, result.tokens_after
+        );
+        self.hooks
+            .run_after_compress(history, result.tokens_saved)
+            .await?;
+
+        Ok(())
+    }
+
     /// Process LLM stream and extract response
     async fn process_stream(
         &self,
         stream: std::pin::Pin<Box<dyn futures::Stream<Item = StreamEvent> + Send + '_>>,
-        event_tx: &mpsc::Sender<AgentEvent>,
-    ) -> Result<(String, Vec<ToolCall>, Option<(u32, u32)>)> {
+        event_tx: &AgentEventSender,
+    ) -> std::result::Result<(String, Vec<ToolCall>, Option<(u32, u32)>), ProviderError> {
         let mut response_text = String::with_capacity(2048); // Pre-allocate for typical response
         let mut tool_calls = Vec::with_capacity(4); // Typical tool call count
         let mut usage = None;
@@ -516,7 +817,7 @@ impl Agent {
                     usage = Some((u.input_tokens, u.output_tokens));
                 }
                 StreamEvent::Error(e) => {
-                    return Err(Error::Provider(e.to_string()));
+                    return Err(e);
                 }
                 StreamEvent::Done => {
                     break;
@@ -544,7 +845,7 @@ impl Agent {
         session_id: &str,
         tool_calls: &[ToolCall],
         history: &mut MessageHistory,
-        event_tx: &mpsc::Sender<AgentEvent>,
+        event_tx: &AgentEventSender,
         tools_used: &mut Vec<String>,
     ) -> Result<Vec<(String, String, bool)>> {
         let steering = self.steering_checker();
@@ -599,6 +900,8 @@ impl Agent {
                         let sid = session_id.to_string();
                         let ctx = Arc::clone(&self.ctx);
                         let tx = event_tx.clone();
+                        let sink = self.output_sink(&tc.name, &tc.id, event_tx);
+                        let recorder = self.tool_recorder.clone();
 
                         handles.push(tokio::spawn(async move {
                             let _tool_ctx = ctx.tool_context(&sid);
@@ -611,18 +914,32 @@ impl Agent {
                                 })
                                 .await;
 
-                            let result = ctx.execute_tool(&tc.name, tc.arguments.clone()).await;
+                            let result = ctx
+                                .execute_tool_streaming(&tc.name, tc.arguments.clone(), Some(sink))
+                                .await;
                             let duration_ms = start.elapsed().as_millis() as u64;
 
+                            let mut attempts = ToolAttempts::default();
                             let (content, is_error) = match result {
                                 Ok(exec_result) if exec_result.success => {
                                     (exec_result.output, false)
                                 }
                                 Ok(exec_result) => {
+                                    attempts.timed_out = exec_result.is_timed_out();
                                     (exec_result.error.unwrap_or_else(|| exec_result.output), true)
                                 }
                                 Err(e) => (e.to_string(), true),
                             };
+                            if let Some(recorder) = recorder {
+                                recorder.record(
+                                    &sid,
+                                    &tc,
+                                    &content,
+                                    !is_error,
+                                    attempts,
+                                    duration_ms,
+                                );
+                            }
 
                             let _ = tx
                                 .send(AgentEvent::ToolComplete {
@@ -681,7 +998,7 @@ impl Agent {
         session_id: &str,
         tool_call: &ToolCall,
         history: &mut MessageHistory,
-        event_tx: &mpsc::Sender<AgentEvent>,
+        event_tx: &AgentEventSender,
         tools_used: &mut Vec<String>,
     ) -> Result<(String, String, bool)> {
         let steering = self.steering_checker();
@@ -740,7 +1057,7 @@ impl Agent {
         &self,
         session_id: &str,
         tool_call: &ToolCall,
-        event_tx: &mpsc::Sender<AgentEvent>,
+        event_tx: &AgentEventSender,
     ) -> Result<String> {
         info!("Executing tool: {}", tool_call.name);
 
@@ -757,19 +1074,35 @@ impl Agent {
         let tool_ctx = self.ctx.tool_context(session_id);
 
         // Execute tool with recovery
+        let mut attempts = ToolAttempts::default();
         let result = self
             .execute_tool_with_recovery(
-                session_id,
                 &tool_call.name,
                 &tool_call.id,
                 tool_call.arguments.clone(),
                 &tool_ctx,
                 event_tx,
+                &mut attempts,
             )
             .await;
 
         let duration_ms = start.elapsed().as_millis() as u64;
 
+        if let Some(recorder) = &self.tool_recorder {
+            let (content, success) = match &result {
+                Ok(content) => (content.clone(), true),
+                Err(e) => (e.to_string(), false),
+            };
+            recorder.record(
+                session_id,
+                tool_call,
+                &content,
+                success,
+                attempts,
+                duration_ms,
+            );
+        }
+
         // Send completion event
         match &result {
             Ok(content) => {
@@ -799,15 +1132,30 @@ impl Agent {
         result
     }
 
+    /// Output sink forwarding live tool output as `AgentEvent::ToolOutput`
+    fn output_sink(
+        &self,
+        tool_name: &str,
+        tool_call_id: &str,
+        event_tx: &AgentEventSender,
+    ) -> Arc<dyn ToolOutputSink> {
+        Arc::new(ToolOutputForwarder::new(
+            tool_name,
+            tool_call_id,
+            event_tx.clone(),
+            &self.config.kill_patterns,
+        ))
+    }
+
     /// Execute a tool with automatic error recovery
     async fn execute_tool_with_recovery(
         &self,
-        _session_id: &str,
         tool_name: &str,
-        _tool_call_id: &str,
+        tool_call_id: &str,
         arguments: Value,
         _tool_ctx: &dyn forge_core::ToolContext,
-        _event_tx: &mpsc::Sender<AgentEvent>,
+        event_tx: &AgentEventSender,
+        attempts: &mut ToolAttempts,
     ) -> Result<String> {
         let mut recovery_ctx = RecoveryContext {
             cwd: self.ctx.working_dir.to_string_lossy().to_string(),
@@ -823,15 +1171,26 @@ impl Agent {
 
         loop {
             // Execute tool via AgentContext (delegates to Layer2-core)
+            let sink = self.output_sink(&current_tool, tool_call_id, event_tx);
             let result = self
                 .ctx
-                .execute_tool(&current_tool, current_args.clone())
+                .execute_tool_streaming(&current_tool, current_args.clone(), Some(sink))
                 .await;
 
             match result {
                 Ok(exec_result) if exec_result.success => {
                     return Ok(exec_result.output);
                 }
+                Ok(exec_result) if exec_result.is_timed_out() => {
+                    // Registry-level timeout already waited the full budget; retrying would stall
+                    attempts.timed_out = true;
+                    let error_msg = exec_result.error.unwrap_or_else(|| "Timeout".to_string());
+                    warn!("Tool '{}' timed out: {}", current_tool, error_msg);
+                    return Err(Error::Tool(format!(
+                        "Tool '{}' timed out ({}). It was cancelled; try a narrower request.",
+                        current_tool, error_msg
+                    )));
+                }
                 Ok(exec_result) => {
                     // Tool executed but failed - attempt recovery
                     let error_msg = exec_result.error.unwrap_or_else(|| "Unknown error".to_string());
@@ -864,12 +1223,14 @@ impl Agent {
                                 tokio::time::sleep(d).await;
                             }
                             recovery_ctx.retry_count += 1;
+                            attempts.retries += 1;
                         }
 
                         RecoveryAction::UseFallback { tool, input } => {
                             info!("Recovery: Using fallback tool '{}'", tool);
                             current_tool = tool;
                             current_args = input;
+                            attempts.retries += 1;
                         }
 
                         RecoveryAction::Skip { reason } => {
@@ -938,4 +1299,48 @@ mod tests {
         let config = AgentConfig::long_session();
         assert_eq!(config.max_iterations, 100);
     }
+    #[test]
+    fn test_agent_config_request_options() {
+        let config = AgentConfig::default();
+        assert_eq!(config.request_options(), RequestOptions::default());
+
+        let config = AgentConfig::fast()
+            .with_max_tokens(1024)
+            .with_stop_sequences(["</plan>"]);
+        let options = config.request_options();
+        assert_eq!(options.max_tokens, Some(1024));
+        assert_eq!(options.stop_sequences, vec!["</plan>".to_string()]);
+    }
+
+    #[test]
+    fn test_agent_config_with_skill() {
+        let skill = forge_core::FileBasedSkill::parse(
+            "---\nname: plan\nmax-tokens: 512\nstop-sequences: [\"</plan>\"]\n---\nPlan it",
+            std::path::PathBuf::from("plan/SKILL.md"),
+        )
+        .unwrap();
+
+        let config = AgentConfig::default()
+            .with_max_tokens(4096)
+            .with_stop_sequences(["END"])
+            .with_skill(&skill);
+        assert_eq!(config.max_tokens, Some(512));
+        assert_eq!(config.stop_sequences, vec!["</plan>".to_string()]);
+        assert_eq!(config.model, None);
+    }
+
+    #[test]
+    fn test_agent_config_with_model() {
+        let config = AgentConfig::default().with_model("gpt-4o-mini");
+        assert_eq!(
+            config.request_options().model.as_deref(),
+            Some("gpt-4o-mini")
+        );
+
+        // 별칭은 Claude 모델 ID로, inherit은 기존 값 유지
+        let config = config.with_model("inherit");
+        assert_eq!(config.model.as_deref(), Some("gpt-4o-mini"));
+        let config = config.with_model("Haiku");
+        assert!(config.model.unwrap().contains("haiku"));
+    }
 }
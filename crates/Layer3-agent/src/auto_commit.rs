@@ -0,0 +1,105 @@
+//! Auto Commit - 실행 종료 시 자동 커밋과 pre-commit 피드백 루프
+//!
+//! 파일을 수정한 실행이 끝나면 변경을 커밋합니다. 커밋 전에 저장소의
+//! pre-commit hook과 설정된 린터를 실행하고, 실패하면 출력을 구조화된
+//! 피드백으로 히스토리에 넣어 에이전트가 고친 뒤 다시 커밋을 시도합니다.
+//!
+//! ```text
+//! 응답 완료 → 검사 실행 ─ 통과 → 커밋
+//!                 │
+//!                 └ 실패 → [Pre-commit feedback] → 에이전트 수정 → 다시 검사
+//!                          (최대 max_fix_attempts회, 이후 커밋하지 않고 종료)
+//! ```
+//!
+//! git 저장소가 아니거나 커밋할 변경이 없으면 아무것도 하지 않습니다.
+
+use forge_core::{AutoCommitConfig, CommitGenerator, GitError, PreCommitReport};
+use std::path::Path;
+use tracing::{debug, warn};
+
+/// 자동 커밋 시도 결과
+#[derive(Debug, Clone)]
+pub enum AutoCommitOutcome {
+    /// 커밋됨 (short hash)
+    Committed(String),
+    /// 커밋할 변경 없음 (또는 git 저장소 아님)
+    Skipped,
+    /// pre-commit 검사 실패 - 아무것도 커밋되지 않음
+    ChecksFailed(PreCommitReport),
+    /// 그 외 git 오류
+    Failed(String),
+}
+
+/// 작업 디렉토리의 변경을 자동 커밋
+pub fn try_auto_commit(working_dir: &Path, config: &AutoCommitConfig) -> AutoCommitOutcome {
+    let generator = match CommitGenerator::new(working_dir, config.clone()) {
+        Ok(generator) => generator,
+        Err(e) => {
+            debug!("Auto-commit skipped: {}", e);
+            return AutoCommitOutcome::Skipped;
+        }
+    };
+
+    match generator.auto_commit(None) {
+        Ok(Some(hash)) => AutoCommitOutcome::Committed(hash),
+        Ok(None) | Err(GitError::NothingToCommit) => AutoCommitOutcome::Skipped,
+        Err(GitError::PreCommitFailed(report)) => AutoCommitOutcome::ChecksFailed(report),
+        Err(e) => {
+            warn!("Auto-commit failed: {}", e);
+            AutoCommitOutcome::Failed(e.to_string())
+        }
+    }
+}
+
+/// 검사 실패를 에이전트에게 전달할 메시지
+///
+/// `retrying`이면 고친 뒤 다시 커밋한다고 알리고, 아니면 커밋을 포기했다고 알립니다.
+pub fn check_feedback(report: &PreCommitReport, retrying: bool) -> String {
+    let next = if retrying {
+        "Fix these problems; the commit is retried with the same checks when you finish."
+    } else {
+        "Giving up on the auto-commit; the changes are left uncommitted. Tell the user which checks still fail."
+    };
+    format!(
+        "[Pre-commit feedback]: The auto-commit was blocked and nothing was committed.\n\n{}\n{}",
+        report.render().trim_end(),
+        next
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge_core::PreCommitCheck;
+
+    #[test]
+    fn test_check_feedback() {
+        let report = PreCommitReport {
+            checks: vec![PreCommitCheck {
+                name: "cargo fmt --check".to_string(),
+                success: false,
+                exit_code: Some(1),
+                output: "Diff in src/lib.rs".to_string(),
+            }],
+        };
+        let feedback = check_feedback(&report, true);
+        assert!(feedback.starts_with("[Pre-commit feedback]: "));
+        assert!(feedback.contains("1 of 1 pre-commit check failed: cargo fmt --check"));
+        assert!(feedback.contains("Diff in src/lib.rs"));
+        assert!(feedback.ends_with("retried with the same checks when you finish."));
+        assert!(check_feedback(&report, false).contains("Giving up"));
+    }
+
+    #[test]
+    fn test_skipped_outside_repo() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            try_auto_commit(dir.path(), &AutoCommitConfig::default()),
+            AutoCommitOutcome::Skipped
+        ));
+    }
+}
@@ -0,0 +1,125 @@
+//! Command Explainer - LLM 기반 명령어 설명
+//!
+//! 위험한 명령어를 승인하기 전에 "이 명령이 무엇을 하는지"를 한두 문장으로
+//! 보여주기 위한 `CommandExplainer` 구현입니다.
+//!
+//! 설명 전용이므로 도구 없이 짧은 요청 하나만 보냅니다. 비용을 줄이려면
+//! 저렴한 모델을 쓰는 프로바이더를 `with_provider`로 지정하세요.
+
+use async_trait::async_trait;
+use forge_core::{CommandExplainer, RiskAnalysis};
+use forge_foundation::{Error, Result};
+use forge_provider::{Gateway, Message};
+use std::sync::Arc;
+
+/// 설명 최대 길이 (문자 수)
+const MAX_EXPLANATION_CHARS: usize = 400;
+
+const EXPLAIN_SYSTEM_PROMPT: &str = "You explain shell commands to a user who must approve them. \
+In one or two short sentences, say what the command will do: which files it creates, changes \
+or deletes, whether it accesses the network, and whether the change can be undone. \
+Do not suggest alternatives and do not use markdown.";
+
+/// Gateway를 통해 명령어를 설명하는 explainer
+pub struct GatewayCommandExplainer {
+    gateway: Arc<Gateway>,
+    /// 사용할 프로바이더 (None이면 기본 프로바이더)
+    provider: Option<String>,
+}
+
+impl GatewayCommandExplainer {
+    /// 기본 프로바이더를 사용하는 explainer 생성
+    pub fn new(gateway: Arc<Gateway>) -> Self {
+        Self {
+            gateway,
+            provider: None,
+        }
+    }
+
+    /// 설명에 사용할 프로바이더 지정
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+}
+
+#[async_trait]
+impl CommandExplainer for GatewayCommandExplainer {
+    async fn explain(&self, command: &str, analysis: &RiskAnalysis) -> Result<String> {
+        let mut request = format!(
+            "Command:\n{}\n\nStatic analysis: {:?}, risk {}/10",
+            command, analysis.category, analysis.risk_score
+        );
+        if let Some(reason) = &analysis.reason {
+            request.push_str(&format!(" ({})", reason));
+        }
+
+        let messages = vec![Message::user(request)];
+        let system = Some(EXPLAIN_SYSTEM_PROMPT.to_string());
+        let response = match &self.provider {
+            Some(name) => {
+                self.gateway
+                    .complete_with_provider(name, messages, Vec::new(), system)
+                    .await?
+            }
+            None => self.gateway.complete(messages, Vec::new(), system).await?,
+        };
+
+        let text = response.content.trim();
+        if text.is_empty() {
+            return Err(Error::Provider("Empty command explanation".to_string()));
+        }
+        Ok(truncate_chars(text, MAX_EXPLANATION_CHARS))
+    }
+}
+
+/// 문자 경계에서 자르기
+fn truncate_chars(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((index, _)) => format!("{}…", text[..index].trim_end()),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge_core::ForgeCmdBuilder;
+    use forge_provider::{MockProvider, MockTurn};
+
+    #[tokio::test]
+    async fn test_gateway_explainer() {
+        let provider = Arc::new(MockProvider::new().with_turn(MockTurn::text(
+            "Deletes the target directory. This cannot be undone.",
+        )));
+        let mut gateway = Gateway::new();
+        gateway.add_provider("mock", provider.clone());
+
+        let forge_cmd = ForgeCmdBuilder::new()
+            .explainer(Arc::new(
+                GatewayCommandExplainer::new(Arc::new(gateway)).with_provider("mock"),
+            ))
+            .build()
+            .unwrap();
+
+        let prompt = forge_cmd.build_explained_prompt("rm -rf target").await;
+        assert_eq!(
+            prompt.explanation.as_deref(),
+            Some("Deletes the target directory. This cannot be undone.")
+        );
+
+        let requests = provider.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].tools.is_empty());
+        assert_eq!(
+            requests[0].system_prompt.as_deref(),
+            Some(EXPLAIN_SYSTEM_PROMPT)
+        );
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        assert_eq!(truncate_chars("short", 10), "short");
+        assert_eq!(truncate_chars("가나다라", 2), "가나…");
+    }
+}
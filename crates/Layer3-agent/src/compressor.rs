@@ -2,12 +2,18 @@
 //!
 //! Claude Code 스타일의 자동 컨텍스트 압축 시스템입니다.
 //! 토큰 사용량이 임계값(기본 92%)을 초과하면 자동으로 대화 히스토리를 요약합니다.
+//!
+//! 로컬 추정과 달리 Provider가 context-length 에러를 반환하면
+//! `compress_strict`로 더 낮은 목표까지 강제 압축합니다.
 
 use crate::history::MessageHistory;
 use forge_foundation::Result;
 use forge_provider::{Message, MessageRole};
 use std::sync::atomic::{AtomicU64, Ordering};
 
+/// 강제 압축 목표 배율 (`target_usage_after_compress` 대비)
+const STRICT_TARGET_FACTOR: f32 = 0.5;
+
 // ============================================================================
 // Compressor Configuration
 // ============================================================================
@@ -417,6 +423,60 @@ impl ContextCompressor {
             return Ok(CompressionResult::not_needed(tokens_before));
         }
 
+        let keep_start = total_messages.saturating_sub(self.config.keep_recent_messages);
+        let result = self.rebuild(history, messages, keep_start, tokens_before, false);
+        self.record(&result);
+
+        Ok(result)
+    }
+
+    /// 강제 압축 (context-length 에러 복구용)
+    ///
+    /// 임계값과 무관하게 압축하며, 목표 사용률의 절반 이하가 될 때까지
+    /// 유지할 최근 메시지 수를 줄입니다. 도구 결과는 항상 요약됩니다.
+    pub fn compress_strict(&self, history: &mut MessageHistory) -> Result<CompressionResult> {
+        let tokens_before = history.estimate_tokens();
+        let target_tokens = (self.config.max_context_tokens as f32
+            * self.config.target_usage_after_compress
+            * STRICT_TARGET_FACTOR) as usize;
+
+        let messages = history.to_messages();
+        if messages.len() <= 1 {
+            return Ok(CompressionResult::not_needed(tokens_before));
+        }
+
+        let mut keep = (self.config.keep_recent_messages / 2).max(1);
+        loop {
+            let keep_start = strict_keep_start(&messages, keep);
+            let mut candidate = history.clone();
+            let result = self.rebuild(
+                &mut candidate,
+                messages.clone(),
+                keep_start,
+                tokens_before,
+                true,
+            );
+
+            if result.tokens_after <= target_tokens || keep == 1 {
+                *history = candidate;
+                self.record(&result);
+                return Ok(result);
+            }
+            keep /= 2;
+        }
+    }
+
+    /// 요약 + 최근 메시지(`keep_start`부터)로 히스토리 교체
+    fn rebuild(
+        &self,
+        history: &mut MessageHistory,
+        messages: Vec<Message>,
+        keep_start: usize,
+        tokens_before: usize,
+        force_summarize_tools: bool,
+    ) -> CompressionResult {
+        let total_messages = messages.len();
+
         // 요약 생성 (LLM 없이 기본 요약)
         let summary = self.create_basic_summary(&messages, total_messages);
 
@@ -430,9 +490,8 @@ impl ContextCompressor {
         )));
 
         // 최근 메시지 유지
-        let keep_start = total_messages.saturating_sub(self.config.keep_recent_messages);
         for msg in messages.into_iter().skip(keep_start) {
-            new_messages.push(self.maybe_compress_message(msg));
+            new_messages.push(self.maybe_compress_message(msg, force_summarize_tools));
         }
 
         // 히스토리 교체
@@ -446,19 +505,21 @@ impl ContextCompressor {
         let tokens_after = history.estimate_tokens();
         let tokens_saved = tokens_before.saturating_sub(tokens_after);
 
-        // 통계 업데이트
-        self.compression_count.fetch_add(1, Ordering::Relaxed);
-        self.total_tokens_saved
-            .fetch_add(tokens_saved as u64, Ordering::Relaxed);
-
-        Ok(CompressionResult {
+        CompressionResult {
             compressed: true,
             tokens_before,
             tokens_after,
             tokens_saved,
             messages_removed,
             summary: Some(summary),
-        })
+        }
+    }
+
+    /// 통계 업데이트
+    fn record(&self, result: &CompressionResult) {
+        self.compression_count.fetch_add(1, Ordering::Relaxed);
+        self.total_tokens_saved
+            .fetch_add(result.tokens_saved as u64, Ordering::Relaxed);
     }
 
     /// 기본 요약 생성 (LLM 없이)
@@ -517,8 +578,8 @@ impl ContextCompressor {
     }
 
     /// 메시지 압축 (필요시)
-    fn maybe_compress_message(&self, mut message: Message) -> Message {
-        if !self.config.summarize_tool_results {
+    fn maybe_compress_message(&self, mut message: Message, force: bool) -> Message {
+        if !force && !self.config.summarize_tool_results {
             return message;
         }
 
@@ -556,6 +617,16 @@ impl ContextCompressor {
     }
 }
 
+/// 최근 `keep`개 메시지의 시작 위치 - 도구 결과가 호출한 assistant 메시지와
+/// 분리되지 않도록 앞쪽으로 조정
+fn strict_keep_start(messages: &[Message], keep: usize) -> usize {
+    let mut start = messages.len().saturating_sub(keep);
+    while start > 0 && messages[start].role == MessageRole::Tool {
+        start -= 1;
+    }
+    start
+}
+
 impl Default for ContextCompressor {
     fn default() -> Self {
         Self::default_config()
@@ -682,6 +753,48 @@ mod tests {
         assert!(result.messages_removed > 0);
     }
 
+    #[test]
+    fn test_compress_strict() {
+        // 임계값 아래여도 강제로 압축
+        let config = CompressorConfig {
+            threshold: 0.92,
+            max_context_tokens: 100_000,
+            keep_recent_messages: 8,
+            ..Default::default()
+        };
+        let compressor = ContextCompressor::new(config);
+
+        let mut history = create_test_history(20);
+        let tool_call = forge_provider::ToolCall::new("1", "bash", serde_json::json!({}));
+        history.add_assistant_with_tools("", vec![tool_call]);
+        history.add_tool_result("1", "x".repeat(5_000), false);
+        assert!(!compressor.needs_compression(&history));
+
+        let result = compressor.compress_strict(&mut history).unwrap();
+        assert!(result.compressed);
+        assert!(result.tokens_after < result.tokens_before);
+        assert_eq!(compressor.stats().compression_count, 1);
+
+        // 도구 결과는 요약되고, 호출한 assistant 메시지와 함께 유지
+        let messages = history.messages();
+        let tool_result = messages.last().unwrap().tool_result.as_ref().unwrap();
+        assert!(tool_result.content.starts_with("[Truncated"));
+        assert_eq!(messages[messages.len() - 2].role, MessageRole::Assistant);
+    }
+
+    #[test]
+    fn test_strict_keep_start() {
+        let messages = vec![
+            Message::user("run it"),
+            Message::assistant("ok"),
+            Message::tool("1", "a", false),
+            Message::tool("2", "b", false),
+        ];
+        assert_eq!(strict_keep_start(&messages, 1), 1);
+        assert_eq!(strict_keep_start(&messages, 3), 1);
+        assert_eq!(strict_keep_start(&messages, 10), 0);
+    }
+
     #[test]
     fn test_current_usage() {
         let config = CompressorConfig {
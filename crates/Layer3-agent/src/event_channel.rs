@@ -0,0 +1,417 @@
+//! Event Channel - 우선순위 레인이 있는 AgentEvent 채널
+//!
+//! 토큰이 쏟아질 때 bounded mpsc는 에이전트를 막거나(send) 이벤트를 버립니다(try_send).
+//! 이 채널은 이벤트를 레인으로 나눠 압박 상황에서도 UI가 반응하도록 합니다.
+//!
+//! - `EventLane::Control` - 제어/권한/완료 이벤트. 절대 버리지 않고 막지도 않음
+//! - `EventLane::Stream` - 스트리밍 텍스트/도구 출력/진행 상황. 스트림 레인이 가득 차면
+//!   연속된 `Text`는 하나로 합치고, 실시간 `ToolOutput`은 합치거나 오래된 것부터 버리며,
+//!   연속된 `ToolProgress`는 최신 값만 남김
+//!
+//! 이벤트 순서는 레인과 관계없이 발행 순서 그대로 유지됩니다.
+//! 합치기/버림 횟수는 `ChannelMetrics`로 확인할 수 있습니다.
+//!
+//! ```ignore
+//! let (tx, mut rx) = agent_event_channel(100);
+//! tokio::spawn(async move { agent.run(session_id, &mut history, "Hello", tx).await });
+//!
+//! while let Some(event) = rx.recv().await {
+//!     // ...
+//! }
+//! let metrics = rx.metrics();
+//! ```
+
+use crate::agent::AgentEvent;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::mpsc::error::{SendError, TryRecvError};
+use tokio::sync::Notify;
+
+/// 이벤트 레인
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventLane {
+    /// 버리지 않는 제어 이벤트
+    Control,
+    /// 압박 시 합치거나 버릴 수 있는 스트리밍 이벤트
+    Stream,
+}
+
+impl AgentEvent {
+    /// 이벤트가 속한 레인
+    pub fn lane(&self) -> EventLane {
+        match self {
+            AgentEvent::Text(_)
+            | AgentEvent::ToolOutput { .. }
+            | AgentEvent::ToolProgress { .. } => EventLane::Stream,
+            _ => EventLane::Control,
+        }
+    }
+}
+
+/// 채널 overflow 지표
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelMetrics {
+    /// 보낸 이벤트 수
+    pub sent: u64,
+    /// 앞 이벤트에 합쳐진 스트리밍 이벤트 수
+    pub coalesced: u64,
+    /// 버려진 도구 출력 수
+    pub dropped: u64,
+    /// 가장 많이 쌓였던 이벤트 수
+    pub max_depth: usize,
+}
+
+impl ChannelMetrics {
+    /// 압박(합치기/버림)이 있었는지
+    pub fn under_pressure(&self) -> bool {
+        self.coalesced > 0 || self.dropped > 0
+    }
+}
+
+/// 송수신자가 공유하는 상태
+struct State {
+    queue: VecDeque<AgentEvent>,
+    /// 큐에 있는 스트림 레인 이벤트 수
+    stream_len: usize,
+    metrics: ChannelMetrics,
+    receiver_closed: bool,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    /// 스트림 레인 용량
+    capacity: usize,
+    senders: AtomicUsize,
+    available: Notify,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 우선순위 레인 채널 생성 (`capacity`는 스트림 레인 용량)
+pub fn agent_event_channel(capacity: usize) -> (AgentEventSender, AgentEventReceiver) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            stream_len: 0,
+            metrics: ChannelMetrics::default(),
+            receiver_closed: false,
+        }),
+        capacity: capacity.max(1),
+        senders: AtomicUsize::new(1),
+        available: Notify::new(),
+    });
+
+    (
+        AgentEventSender {
+            shared: Arc::clone(&shared),
+        },
+        AgentEventReceiver { shared },
+    )
+}
+
+/// AgentEvent 송신자
+///
+/// `mpsc::Sender`와 같은 `send`/`try_send`를 제공하지만 어느 쪽도 막히지 않습니다.
+pub struct AgentEventSender {
+    shared: Arc<Shared>,
+}
+
+impl AgentEventSender {
+    /// 이벤트 전송 (수신자가 닫혔으면 에러)
+    pub async fn send(&self, event: AgentEvent) -> Result<(), SendError<AgentEvent>> {
+        self.try_send(event)
+    }
+
+    /// 동기 전송 (도구 출력 콜백 등)
+    pub fn try_send(&self, event: AgentEvent) -> Result<(), SendError<AgentEvent>> {
+        let mut state = self.shared.lock();
+        if state.receiver_closed {
+            return Err(SendError(event));
+        }
+        state.metrics.sent += 1;
+
+        if event.lane() == EventLane::Control || state.stream_len < self.shared.capacity {
+            push(&mut state, event);
+        } else {
+            push_under_pressure(&mut state, event);
+        }
+
+        state.metrics.max_depth = state.metrics.max_depth.max(state.queue.len());
+        drop(state);
+        self.shared.available.notify_one();
+        Ok(())
+    }
+
+    /// 현재까지의 지표
+    pub fn metrics(&self) -> ChannelMetrics {
+        self.shared.lock().metrics
+    }
+
+    /// 수신자가 닫혔는지
+    pub fn is_closed(&self) -> bool {
+        self.shared.lock().receiver_closed
+    }
+}
+
+impl Clone for AgentEventSender {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::SeqCst);
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl Drop for AgentEventSender {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // 마지막 송신자 - 대기 중인 recv를 깨움
+            self.shared.available.notify_one();
+        }
+    }
+}
+
+fn push(state: &mut State, event: AgentEvent) {
+    if event.lane() == EventLane::Stream {
+        state.stream_len += 1;
+    }
+    state.queue.push_back(event);
+}
+
+/// 스트림 레인이 가득 찼을 때 (합치기 → 버림 → 그래도 텍스트는 추가)
+fn push_under_pressure(state: &mut State, event: AgentEvent) {
+    match (state.queue.back_mut(), event) {
+        // 연속된 텍스트는 하나로
+        (Some(AgentEvent::Text(pending)), AgentEvent::Text(text)) => {
+            pending.push_str(&text);
+            state.metrics.coalesced += 1;
+        }
+        // 같은 도구의 연속된 출력은 하나로
+        (
+            Some(AgentEvent::ToolOutput {
+                tool_call_id: pending_id,
+                chunk: pending,
+                ..
+            }),
+            AgentEvent::ToolOutput {
+                tool_call_id,
+                chunk,
+                ..
+            },
+        ) if *pending_id == tool_call_id => {
+            pending.push_str(&chunk);
+            state.metrics.coalesced += 1;
+        }
+        // 같은 도구의 연속된 진행 상황은 최신 값만
+        (
+            Some(AgentEvent::ToolProgress {
+                tool_call_id: pending_id,
+                progress: pending,
+                ..
+            }),
+            AgentEvent::ToolProgress {
+                tool_call_id,
+                progress,
+                ..
+            },
+        ) if *pending_id == tool_call_id => {
+            *pending = progress;
+            state.metrics.coalesced += 1;
+        }
+        // 도구 출력/진행 상황은 표시용 - 가장 오래된 것을 버리고 추가
+        (_, event @ (AgentEvent::ToolOutput { .. } | AgentEvent::ToolProgress { .. })) => {
+            let oldest = state.queue.iter().position(|e| {
+                matches!(
+                    e,
+                    AgentEvent::ToolOutput { .. } | AgentEvent::ToolProgress { .. }
+                )
+            });
+            // 버릴 이전 출력이 없으면 새 출력을 버림
+            if let Some(index) = oldest {
+                state.queue.remove(index);
+                state.stream_len -= 1;
+                push(state, event);
+            }
+            state.metrics.dropped += 1;
+        }
+        // 응답 텍스트는 버리지 않음 (제어 이벤트 사이에서만 생기므로 제한됨)
+        (_, event) => push(state, event),
+    }
+}
+
+/// AgentEvent 수신자
+pub struct AgentEventReceiver {
+    shared: Arc<Shared>,
+}
+
+impl AgentEventReceiver {
+    /// 다음 이벤트 (모든 송신자가 사라지고 비었으면 None)
+    pub async fn recv(&mut self) -> Option<AgentEvent> {
+        let shared = Arc::clone(&self.shared);
+        loop {
+            let available = shared.available.notified();
+            tokio::pin!(available);
+            available.as_mut().enable();
+
+            match self.try_recv() {
+                Ok(event) => return Some(event),
+                Err(TryRecvError::Disconnected) => return None,
+                Err(TryRecvError::Empty) => available.await,
+            }
+        }
+    }
+
+    /// 기다리지 않고 다음 이벤트
+    pub fn try_recv(&mut self) -> Result<AgentEvent, TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.queue.pop_front() {
+            Some(event) => {
+                if event.lane() == EventLane::Stream {
+                    state.stream_len -= 1;
+                }
+                Ok(event)
+            }
+            None if self.shared.senders.load(Ordering::SeqCst) == 0 => {
+                Err(TryRecvError::Disconnected)
+            }
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// 현재까지의 지표
+    pub fn metrics(&self) -> ChannelMetrics {
+        self.shared.lock().metrics
+    }
+
+    /// 대기 중인 이벤트 수
+    pub fn len(&self) -> usize {
+        self.shared.lock().queue.len()
+    }
+
+    /// 대기 중인 이벤트가 없는지
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Drop for AgentEventReceiver {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receiver_closed = true;
+        state.queue.clear();
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(id: &str, chunk: &str) -> AgentEvent {
+        AgentEvent::ToolOutput {
+            tool_name: "bash".into(),
+            tool_call_id: id.into(),
+            chunk: chunk.into(),
+        }
+    }
+
+    #[test]
+    fn test_text_coalesced_under_pressure() {
+        let (tx, mut rx) = agent_event_channel(2);
+        for token in ["a", "b", "c", "d"] {
+            tx.try_send(AgentEvent::Text(token.into())).unwrap();
+        }
+        tx.try_send(AgentEvent::Done {
+            full_response: "abcd".into(),
+        })
+        .unwrap();
+
+        assert!(matches!(rx.try_recv().unwrap(), AgentEvent::Text(t) if t == "a"));
+        assert!(matches!(rx.try_recv().unwrap(), AgentEvent::Text(t) if t == "bcd"));
+        assert!(matches!(rx.try_recv().unwrap(), AgentEvent::Done { .. }));
+
+        let metrics = rx.metrics();
+        assert_eq!(metrics.sent, 5);
+        assert_eq!(metrics.coalesced, 2);
+        assert_eq!(metrics.dropped, 0);
+        assert!(metrics.under_pressure());
+    }
+
+    #[test]
+    fn test_control_never_dropped_and_order_kept() {
+        let (tx, mut rx) = agent_event_channel(1);
+        tx.try_send(output("call-1", "line 1\n")).unwrap();
+        for turn in 1..=50 {
+            tx.try_send(AgentEvent::TurnStart { turn }).unwrap();
+        }
+        // 스트림 레인이 가득 차도 제어 이벤트 뒤의 텍스트는 유지
+        tx.try_send(AgentEvent::Text("hi".into())).unwrap();
+        tx.try_send(AgentEvent::Stopped {
+            reason: "user".into(),
+        })
+        .unwrap();
+
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            AgentEvent::ToolOutput { .. }
+        ));
+        for expected in 1..=50 {
+            assert!(
+                matches!(rx.try_recv().unwrap(), AgentEvent::TurnStart { turn } if turn == expected)
+            );
+        }
+        assert!(matches!(rx.try_recv().unwrap(), AgentEvent::Text(t) if t == "hi"));
+        assert!(matches!(rx.try_recv().unwrap(), AgentEvent::Stopped { .. }));
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Empty)));
+    }
+
+    #[test]
+    fn test_tool_output_coalesced_then_dropped() {
+        let (tx, mut rx) = agent_event_channel(1);
+        tx.try_send(output("call-1", "1\n")).unwrap();
+        tx.try_send(output("call-1", "2\n")).unwrap();
+        tx.try_send(AgentEvent::Usage {
+            input_tokens: 1,
+            output_tokens: 1,
+        })
+        .unwrap();
+        // 뒤가 제어 이벤트라 합칠 수 없음 - 가장 오래된 출력을 버림
+        tx.try_send(output("call-1", "3\n")).unwrap();
+
+        assert!(matches!(rx.try_recv().unwrap(), AgentEvent::Usage { .. }));
+        match rx.try_recv().unwrap() {
+            AgentEvent::ToolOutput { chunk, .. } => assert_eq!(chunk, "3\n"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let metrics = rx.metrics();
+        assert_eq!(metrics.coalesced, 1);
+        assert_eq!(metrics.dropped, 1);
+    }
+
+    #[tokio::test]
+    async fn test_recv_closes_after_senders_dropped() {
+        let (tx, mut rx) = agent_event_channel(4);
+        let tx2 = tx.clone();
+        tokio::spawn(async move {
+            tx2.send(AgentEvent::Thinking).await.unwrap();
+        });
+        drop(tx);
+
+        assert!(matches!(rx.recv().await, Some(AgentEvent::Thinking)));
+        assert!(rx.recv().await.is_none());
+
+        let (tx, rx) = agent_event_channel(4);
+        drop(rx);
+        assert!(tx.send(AgentEvent::Thinking).await.is_err());
+        assert!(tx.is_closed());
+    }
+}
@@ -14,7 +14,10 @@
 
 #![allow(dead_code)]
 
+use forge_core::{format_diagnostics, DiagnosticsStore};
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// 피드백 유형
@@ -282,6 +285,8 @@ pub struct FeedbackLoop {
     analyzer: FeedbackAnalyzer,
     history: Vec<Feedback>,
     max_history: usize,
+    /// LSP 진단 버퍼 (편집 후 컴파일 에러 확인)
+    diagnostics: Option<Arc<DiagnosticsStore>>,
 }
 
 impl Default for FeedbackLoop {
@@ -296,9 +301,40 @@ impl FeedbackLoop {
             analyzer: FeedbackAnalyzer::new(),
             history: Vec::with_capacity(100),
             max_history: 100,
+            diagnostics: None,
         }
     }
 
+    /// LSP 진단 버퍼 연결
+    pub fn with_diagnostics(mut self, store: Arc<DiagnosticsStore>) -> Self {
+        self.diagnostics = Some(store);
+        self
+    }
+
+    /// 편집 후 피드백
+    ///
+    /// 편집한 파일에 버퍼된 LSP 에러가 있으면 `BuildFailure` 피드백을 만듭니다.
+    /// 경고만 있거나 진단 버퍼가 없으면 `None`.
+    pub fn post_edit_feedback(&self, tool_name: &str, path: &Path) -> Option<Feedback> {
+        let errors: Vec<_> = self
+            .diagnostics
+            .as_ref()?
+            .for_path(path)
+            .into_iter()
+            .filter(|d| d.is_error())
+            .collect();
+        if errors.is_empty() {
+            return None;
+        }
+        let display = path.display().to_string();
+        Some(Feedback::failure(
+            FeedbackType::BuildFailure,
+            tool_name,
+            display.clone(),
+            format_diagnostics(&display, &errors),
+        ))
+    }
+
     /// 피드백 기록 및 분석
     pub fn record(&mut self, feedback: Feedback) -> RetryStrategy {
         // 히스토리에 추가
@@ -413,4 +449,43 @@ mod tests {
         let strategy = analyzer.analyze(&feedback);
         assert!(matches!(strategy, RetryStrategy::GiveUp { .. }));
     }
+
+    #[test]
+    fn test_post_edit_feedback() {
+        let path = std::env::temp_dir().join("feedback_lib.rs");
+        let store = Arc::new(DiagnosticsStore::new());
+        let mut feedback_loop = FeedbackLoop::new();
+        assert!(feedback_loop.post_edit_feedback("edit", &path).is_none());
+
+        feedback_loop = feedback_loop.with_diagnostics(Arc::clone(&store));
+        let diagnostic = |severity: u8, message: &str| {
+            serde_json::from_value(serde_json::json!({
+                "range": { "start": { "line": 2, "character": 4 }, "end": { "line": 2, "character": 5 } },
+                "severity": severity,
+                "message": message
+            }))
+            .unwrap()
+        };
+        let uri = forge_core::path_to_uri(&path);
+        store.publish(&uri, Some(1), vec![diagnostic(2, "unused variable")]);
+        assert!(feedback_loop.post_edit_feedback("edit", &path).is_none());
+
+        store.publish(
+            &uri,
+            Some(2),
+            vec![
+                diagnostic(2, "unused variable"),
+                diagnostic(1, "mismatched types"),
+            ],
+        );
+        let feedback = feedback_loop.post_edit_feedback("edit", &path).unwrap();
+        assert_eq!(feedback.feedback_type, FeedbackType::BuildFailure);
+        let error = feedback.error_message.clone().unwrap();
+        assert!(error.ends_with(":3:5: error: mismatched types"));
+        assert!(!error.contains("unused variable"));
+        assert!(matches!(
+            feedback_loop.record(feedback),
+            RetryStrategy::ModifyAndRetry { .. }
+        ));
+    }
 }
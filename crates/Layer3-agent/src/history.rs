@@ -4,13 +4,19 @@
 //! - Token estimation caching (thread-safe with atomics)
 //! - Efficient message access (no cloning)
 //! - Arc-based history sharing
+//! - Tool-result deduplication (identical results become back-references)
 
 use forge_provider::{Message, MessageRole, ToolCall};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Tool results shorter than this are never deduplicated (the marker would save little)
+const DEDUP_MIN_CHARS: usize = 256;
+
 /// Message history for a session
 ///
 /// Performance optimizations:
@@ -26,6 +32,12 @@ pub struct MessageHistory {
     /// System prompt
     system_prompt: Option<String>,
 
+    /// Tool result content hash → tool call id of the first identical result
+    tool_result_hashes: HashMap<u64, String>,
+
+    /// Number of tool results replaced with a back-reference
+    deduplicated_results: usize,
+
     /// Cached token count (0 = invalid, needs recalculation)
     #[cfg(not(feature = "no-cache"))]
     cached_tokens: AtomicUsize,
@@ -41,6 +53,8 @@ impl Clone for MessageHistory {
         Self {
             messages: self.messages.clone(),
             system_prompt: self.system_prompt.clone(),
+            tool_result_hashes: self.tool_result_hashes.clone(),
+            deduplicated_results: self.deduplicated_results,
             #[cfg(not(feature = "no-cache"))]
             cached_tokens: AtomicUsize::new(self.cached_tokens.load(Ordering::Relaxed)),
             #[cfg(not(feature = "no-cache"))]
@@ -60,6 +74,8 @@ impl MessageHistory {
         Self {
             messages: Vec::with_capacity(capacity),
             system_prompt: None,
+            tool_result_hashes: HashMap::new(),
+            deduplicated_results: 0,
             #[cfg(not(feature = "no-cache"))]
             cached_tokens: AtomicUsize::new(0),
             #[cfg(not(feature = "no-cache"))]
@@ -72,6 +88,8 @@ impl MessageHistory {
         Self {
             messages: Vec::with_capacity(32), // Typical conversation size
             system_prompt: Some(prompt.into()),
+            tool_result_hashes: HashMap::new(),
+            deduplicated_results: 0,
             #[cfg(not(feature = "no-cache"))]
             cached_tokens: AtomicUsize::new(0),
             #[cfg(not(feature = "no-cache"))]
@@ -118,18 +136,63 @@ impl MessageHistory {
     }
 
     /// Add a tool result
+    ///
+    /// A result identical to an earlier one still in the history is replaced
+    /// with a short back-reference (see [`Self::add`]).
     pub fn add_tool_result(&mut self, tool_call_id: impl Into<String>, content: impl Into<String>, is_error: bool) {
-        self.messages
-            .push(Message::tool(tool_call_id, content, is_error));
-        self.invalidate_cache();
+        self.add(Message::tool(tool_call_id, content, is_error));
     }
 
     /// Add a message directly
-    pub fn add(&mut self, message: Message) {
+    ///
+    /// Successful tool results whose content is identical (by hash) to an earlier
+    /// tool result still in the history are replaced with a back-reference marker,
+    /// so repeated grep/read output is not re-sent on every turn.
+    pub fn add(&mut self, mut message: Message) {
+        if let Some(result) = message.tool_result.as_mut() {
+            if !result.is_error {
+                if let Some(marker) = self.dedup_tool_result(&result.tool_call_id, &result.content) {
+                    result.content = marker;
+                }
+            }
+        }
         self.messages.push(message);
         self.invalidate_cache();
     }
 
+    /// Back-reference marker if `content` duplicates an earlier tool result
+    fn dedup_tool_result(&mut self, tool_call_id: &str, content: &str) -> Option<String> {
+        if content.len() < DEDUP_MIN_CHARS {
+            return None;
+        }
+
+        let hash = content_hash(content);
+        if let Some(original_id) = self.tool_result_hashes.get(&hash) {
+            // The original may have been compressed or dropped since; only refer to it if still intact
+            let intact = self.messages.iter().any(|m| {
+                m.tool_result
+                    .as_ref()
+                    .is_some_and(|r| &r.tool_call_id == original_id && r.content == content)
+            });
+            if intact {
+                self.deduplicated_results += 1;
+                return Some(format!(
+                    "[Identical to the result of tool call {} above ({} chars); not repeated]",
+                    original_id,
+                    content.len()
+                ));
+            }
+        }
+
+        self.tool_result_hashes.insert(hash, tool_call_id.to_string());
+        None
+    }
+
+    /// Number of tool results replaced with a back-reference
+    pub fn deduplicated_results(&self) -> usize {
+        self.deduplicated_results
+    }
+
     /// Get all messages as a slice (no allocation)
     #[inline]
     pub fn messages(&self) -> &[Message] {
@@ -149,6 +212,7 @@ impl MessageHistory {
     /// Use this when you need owned messages and will discard the history.
     pub fn take_messages(&mut self) -> Vec<Message> {
         self.invalidate_cache();
+        self.tool_result_hashes.clear();
         std::mem::take(&mut self.messages)
     }
 
@@ -167,6 +231,13 @@ impl MessageHistory {
     /// Clear all messages
     pub fn clear(&mut self) {
         self.messages.clear();
+        self.tool_result_hashes.clear();
+        self.invalidate_cache();
+    }
+
+    /// Keep only the first `len` messages (e.g. to rewind to an earlier turn)
+    pub fn truncate(&mut self, len: usize) {
+        self.messages.truncate(len);
         self.invalidate_cache();
     }
 
@@ -256,6 +327,7 @@ impl MessageHistory {
 
         // Keep system prompt, clear messages, add summary as context
         self.messages.clear();
+        self.tool_result_hashes.clear();
         self.messages.push(Message::user(format!(
             "Previous conversation summary:\n{}\n\nPlease continue from here.",
             summary
@@ -269,6 +341,14 @@ impl MessageHistory {
     }
 }
 
+/// Hash of tool result content (for deduplication)
+#[inline]
+fn content_hash(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Estimate tokens for a JSON value without serialization
 #[inline]
 fn estimate_json_tokens(value: &serde_json::Value) -> usize {
@@ -462,6 +542,20 @@ mod tests {
         assert!(history.is_empty());
     }
 
+    #[test]
+    fn test_truncate() {
+        let mut history = MessageHistory::new();
+        history.add_user("Hello");
+        history.add_assistant("Hi");
+        let tokens = history.estimate_tokens();
+
+        history.truncate(1);
+        assert_eq!(history.len(), 1);
+        assert!(history.estimate_tokens() < tokens);
+        history.truncate(5);
+        assert_eq!(history.len(), 1);
+    }
+
     #[test]
     fn test_json_token_estimation() {
         let simple = serde_json::json!({"key": "value"});
@@ -476,6 +570,47 @@ mod tests {
         assert!(nested_tokens > tokens);
     }
 
+    #[test]
+    fn test_tool_result_dedup() {
+        let output = "src/main.rs:1: fn main() {}\n".repeat(20);
+        let mut history = MessageHistory::new();
+        history.add_tool_result("call-1", output.clone(), false);
+        let before = history.estimate_tokens();
+        history.add_tool_result("call-2", output.clone(), false);
+
+        let marker = &history.last().unwrap().tool_result.as_ref().unwrap().content;
+        assert!(marker.contains("call-1"));
+        assert!(marker.len() < output.len());
+        assert!(history.estimate_tokens() - before < output.len() / 8);
+        assert_eq!(history.deduplicated_results(), 1);
+
+        // Short results and errors are kept verbatim
+        history.add_tool_result("call-3", "ok", false);
+        history.add_tool_result("call-4", "ok", false);
+        history.add_tool_result("call-5", output.clone(), true);
+        assert_eq!(history.deduplicated_results(), 1);
+        assert_eq!(history.last().unwrap().tool_result.as_ref().unwrap().content, output);
+    }
+
+    #[test]
+    fn test_tool_result_dedup_requires_intact_original() {
+        let output = "x".repeat(DEDUP_MIN_CHARS);
+        let mut history = MessageHistory::new();
+        history.add_tool_result("call-1", output.clone(), false);
+        history.summarize("earlier work");
+
+        // Original is gone after summarization, so the result is kept in full
+        history.add_tool_result("call-2", output.clone(), false);
+        assert_eq!(history.last().unwrap().tool_result.as_ref().unwrap().content, output);
+
+        // Original content changed (e.g. compressed) → not referenced
+        let mut rebuilt = MessageHistory::new();
+        rebuilt.add_tool_result("call-2", output.clone(), false);
+        rebuilt.messages[0].tool_result.as_mut().unwrap().content = "pruned".into();
+        rebuilt.add_tool_result("call-3", output.clone(), false);
+        assert_eq!(rebuilt.deduplicated_results(), 0);
+    }
+
     #[tokio::test]
     async fn test_history_manager() {
         let manager = HistoryManager::new();
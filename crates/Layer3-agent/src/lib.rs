@@ -32,7 +32,7 @@
 //! let handle = agent.steering_handle();
 //!
 //! // 실행
-//! let (tx, mut rx) = tokio::sync::mpsc::channel(100);
+//! let (tx, mut rx) = forge_agent::agent_event_channel(100);
 //! let response = agent.run(session_id, &mut history, "Hello", tx).await?;
 //!
 //! // 외부에서 중단
@@ -41,6 +41,7 @@
 
 // Core modules
 pub mod agent;
+pub mod auto_commit;
 pub mod context;
 pub mod history;
 pub mod recovery;
@@ -48,6 +49,7 @@ pub mod session;
 
 // New simplified system
 pub mod compressor;
+pub mod command_explainer;
 pub mod hook;
 pub mod provider_bridge;
 pub mod steering;
@@ -76,6 +78,13 @@ pub mod verification;
 // Long-running agent support (Claude Code style)
 pub mod todo;
 pub mod progress;
+pub mod turn_summary;
+pub mod tool_output;
+pub mod tool_stats;
+pub mod skill_run;
+pub mod subagent;
+pub mod runner;
+pub mod event_channel;
 
 // Research-based enhancements (2025)
 // Based on: AI Agentic Programming Survey, ReAct, SWE-agent, OpenDevin
@@ -129,6 +138,9 @@ pub mod variant;
 // ============================================================================
 
 pub use agent::{Agent, AgentConfig, AgentEvent};
+pub use event_channel::{
+    agent_event_channel, AgentEventReceiver, AgentEventSender, ChannelMetrics, EventLane,
+};
 pub use context::{AgentContext, ProviderInfo};
 pub use history::MessageHistory;
 pub use session::{Session, SessionManager};
@@ -144,6 +156,9 @@ pub use compressor::{
     SmartCompressor, TokenUsageInfo,
 };
 
+// Command explanations (confirmation prompts)
+pub use command_explainer::GatewayCommandExplainer;
+
 // Steering
 pub use steering::{
     AgentState, AgentStatus, Steerable, SteeringChecker, SteeringCommand, SteeringError,
@@ -163,6 +178,17 @@ pub use recovery::{
 // Long-running agent support (Claude Code style)
 pub use todo::{TodoItem, TodoManager, TodoStats, TodoStatus, Priority};
 pub use progress::{ProgressTracker, ProgressEntry, ProgressAction, Feature, FeatureList};
+pub use turn_summary::{record_ghost_commit, TestStatus, TurnChangeTracker, TurnSummary};
+pub use auto_commit::{check_feedback, try_auto_commit, AutoCommitOutcome};
+pub use tool_output::ToolOutputForwarder;
+pub use tool_stats::{ToolAttempts, ToolExecutionRecorder};
+pub use skill_run::{
+    execute_pipeline, execute_plan, load_skills, register_mcp_prompts, SkillInvocation,
+};
+pub use subagent::{
+    load_subagents, register_subagents, subagent_config, subagent_definitions, SubagentTool,
+};
+pub use runner::{AgentRun, AgentRunner, ScheduledAgentRunner};
 
 // Research-based enhancements (2025)
 pub use feedback::{Feedback, FeedbackAnalyzer, FeedbackLoop, FeedbackType, RetryStrategy};
@@ -113,6 +113,7 @@ impl PlanStep {
             "bash" => "⚡",
             "glob" => "🔍",
             "grep" => "🔎",
+            "http_request" => "🌐",
             "task_spawn" => "🚀",
             "task_wait" => "⏳",
             "task_logs" => "📋",
@@ -20,6 +20,7 @@
 
 use crate::agent::{Agent, AgentConfig, AgentEvent};
 use crate::context::AgentContext;
+use crate::event_channel::agent_event_channel;
 use crate::history::MessageHistory;
 use crate::session::SessionManager;
 use async_stream::stream;
@@ -30,7 +31,7 @@ use forge_provider::{
 };
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::RwLock;
 use tracing::{debug, info};
 
 // ============================================================================
@@ -142,6 +143,9 @@ impl ForgeNativeProvider {
                 arguments: serde_json::Value::Null,
             }),
 
+            // Live output is for local display only; the full result arrives with ToolComplete
+            AgentEvent::ToolOutput { .. } | AgentEvent::ToolProgress { .. } => None,
+
             AgentEvent::ToolComplete {
                 tool_name,
                 tool_call_id,
@@ -181,6 +185,29 @@ impl ForgeNativeProvider {
                 None
             }
 
+            AgentEvent::TurnSummary { summary } => {
+                info!("Turn summary: {}", summary);
+                None
+            }
+
+            AgentEvent::GhostCommit { turn } => {
+                debug!("Recorded ghost commit for turn {}", turn);
+                None
+            }
+
+            AgentEvent::AutoCommitted { hash } => {
+                debug!("Auto-committed {}", hash);
+                None
+            }
+
+            AgentEvent::PreCommitFailed { summary, retrying } => {
+                debug!(
+                    "Pre-commit checks failed (retrying: {}): {}",
+                    retrying, summary
+                );
+                None
+            }
+
             AgentEvent::Compressed {
                 tokens_before,
                 tokens_after,
@@ -279,10 +306,15 @@ impl AgentProvider for ForgeNativeProvider {
             config.max_iterations = max_turns as usize;
         }
 
+        // 전달된 서브에이전트 정의는 `subagent` 도구로 디스패치
+        if !options.subagents.is_empty() {
+            crate::subagent::register_subagents(&self.ctx, options.subagents.clone()).await;
+        }
+
         let agent = Agent::with_config(self.ctx.clone(), config);
 
         // Create event channel
-        let (event_tx, mut event_rx) = mpsc::channel::<AgentEvent>(100);
+        let (event_tx, mut event_rx) = agent_event_channel(100);
 
         // Clone for async move
         let prompt = prompt.to_string();
@@ -0,0 +1,220 @@
+//! Agent Runner - 임베딩용 에이전트 실행기
+//!
+//! CLI 없이 코드에서 에이전트를 실행하기 위한 최소 API입니다.
+//! 세션 ID와 대화 기록을 유지하면서 메시지를 보내고, 실행 중 발생한
+//! `AgentEvent`를 모아 `AgentRun`으로 반환합니다.
+//!
+//! ```ignore
+//! let ctx = AgentContext::builder()
+//!     .gateway(Arc::new(gateway))
+//!     .tool(Arc::new(MyTool))
+//!     .system_prompt("You are a helpful assistant.")
+//!     .build()?;
+//!
+//! let mut runner = AgentRunner::new(Arc::new(ctx));
+//! let run = runner.send("Count the words in 'hello world'").await?;
+//! println!("{}", run.response);
+//! ```
+//!
+//! `ScheduledAgentRunner`는 같은 실행기로 `TaskScheduler`의 예약 작업을 실행합니다.
+
+use crate::agent::{Agent, AgentConfig, AgentEvent};
+use crate::context::AgentContext;
+use crate::event_channel::agent_event_channel;
+use crate::history::MessageHistory;
+use crate::skill_run::{execute_pipeline, load_skills, register_mcp_prompts, SkillInvocation};
+use async_trait::async_trait;
+use forge_foundation::{Error, Result};
+use forge_task::{Schedule, ScheduleOutput, ScheduleRunner};
+use std::sync::Arc;
+
+/// 이벤트 채널 크기
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// 한 번의 메시지 실행 결과
+#[derive(Debug, Clone)]
+pub struct AgentRun {
+    /// 최종 응답
+    pub response: String,
+
+    /// 실행 중 발생한 이벤트 (발생 순서)
+    pub events: Vec<AgentEvent>,
+}
+
+impl AgentRun {
+    /// 완료된 도구 호출 (도구 이름, 성공 여부)
+    pub fn tool_calls(&self) -> Vec<(&str, bool)> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                AgentEvent::ToolComplete {
+                    tool_name, success, ..
+                } => Some((tool_name.as_str(), *success)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 발생한 에러 이벤트
+    pub fn errors(&self) -> Vec<&str> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                AgentEvent::Error(message) => Some(message.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// 대화 상태를 유지하는 에이전트 실행기
+pub struct AgentRunner {
+    agent: Agent,
+    history: MessageHistory,
+    session_id: String,
+}
+
+impl AgentRunner {
+    /// 기본 설정으로 생성
+    pub fn new(ctx: Arc<AgentContext>) -> Self {
+        Self::with_config(ctx, AgentConfig::default())
+    }
+
+    /// 지정한 설정으로 생성
+    pub fn with_config(ctx: Arc<AgentContext>, config: AgentConfig) -> Self {
+        Self {
+            agent: Agent::with_config(ctx, config),
+            history: MessageHistory::new(),
+            session_id: uuid::Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// 세션 ID 지정
+    pub fn with_session_id(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+
+    /// 세션 ID
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// 대화 기록
+    pub fn history(&self) -> &MessageHistory {
+        &self.history
+    }
+
+    /// 에이전트 (steering handle 등)
+    pub fn agent(&self) -> &Agent {
+        &self.agent
+    }
+
+    /// 메시지를 보내고 에이전트 루프가 끝날 때까지 실행
+    ///
+    /// 에이전트 에러는 `Err`로 반환되며, 그때까지의 이벤트는 버려집니다.
+    pub async fn send(&mut self, message: &str) -> Result<AgentRun> {
+        let (tx, mut rx) = agent_event_channel(EVENT_CHANNEL_CAPACITY);
+
+        let run = self
+            .agent
+            .run(&self.session_id, &mut self.history, message, tx);
+        let collect = async {
+            let mut events = Vec::new();
+            while let Some(event) = rx.recv().await {
+                events.push(event);
+            }
+            events
+        };
+        let (response, events) = tokio::join!(run, collect);
+
+        Ok(AgentRun {
+            response: response?,
+            events,
+        })
+    }
+}
+
+/// 예약 작업별 에이전트 컨텍스트 생성 함수
+type ContextFactory = dyn Fn(&Schedule) -> Result<Arc<AgentContext>> + Send + Sync;
+
+/// 예약 작업 실행기 (`TaskScheduler`용)
+///
+/// 실행마다 새 컨텍스트와 세션에서 `AgentRunner`로 프롬프트를 보냅니다.
+/// `/skill` 프롬프트는 스킬 호출로 처리하며(단계를 선언한 스킬은 파이프라인으로 실행),
+/// 승인할 사람이 없으므로 `--dry-run`과 알 수 없는 스킬은 에러입니다.
+pub struct ScheduledAgentRunner {
+    context_for: Box<ContextFactory>,
+    config: AgentConfig,
+}
+
+impl ScheduledAgentRunner {
+    /// 예약 작업의 작업 디렉토리 등으로 컨텍스트를 만드는 함수로 생성
+    pub fn new(
+        context_for: impl Fn(&Schedule) -> Result<Arc<AgentContext>> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            context_for: Box::new(context_for),
+            config: AgentConfig::default(),
+        }
+    }
+
+    /// 에이전트 설정 지정
+    pub fn with_config(mut self, config: AgentConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+#[async_trait]
+impl ScheduleRunner for ScheduledAgentRunner {
+    async fn run(&self, schedule: &Schedule) -> Result<ScheduleOutput> {
+        let ctx = (self.context_for)(schedule)?;
+        let session_id = uuid::Uuid::new_v4().to_string();
+
+        let mut skills = load_skills(&ctx.working_dir);
+        register_mcp_prompts(&mut skills, &ctx).await;
+        let (ctx, config, message) = match SkillInvocation::parse(&schedule.prompt, &skills) {
+            Some(skill) if skill.is_dry_run() => {
+                return Err(Error::InvalidInput(format!(
+                    "Scheduled /{} cannot use --dry-run",
+                    skill.name()
+                )));
+            }
+            Some(skill) if skill.pipeline().is_some() => {
+                let (tx, mut rx) = agent_event_channel(EVENT_CHANNEL_CAPACITY);
+                let run = execute_pipeline(&ctx, &skills, &skill, &self.config, &session_id, tx);
+                let drain = async { while rx.recv().await.is_some() {} };
+                let (report, ()) = tokio::join!(run, drain);
+                return Ok(ScheduleOutput {
+                    output: report?.summary(),
+                    session_id: Some(session_id),
+                });
+            }
+            Some(skill) => (
+                skill.agent_context(&ctx),
+                skill.agent_config(self.config.clone()),
+                skill.prompt(&ctx, &session_id).await?,
+            ),
+            None if schedule.is_skill() => {
+                return Err(Error::NotFound(format!(
+                    "Skill for '{}'",
+                    schedule.prompt.trim()
+                )));
+            }
+            None => (ctx, self.config.clone(), schedule.prompt.clone()),
+        };
+
+        let mut runner = AgentRunner::with_config(ctx, config).with_session_id(&session_id);
+        let run = runner.send(&message).await?;
+        let errors = run.errors();
+        if !errors.is_empty() {
+            return Err(Error::Agent(errors.join("; ")));
+        }
+
+        Ok(ScheduleOutput {
+            output: run.response,
+            session_id: Some(session_id),
+        })
+    }
+}
@@ -1,7 +1,7 @@
 //! Session management
 
 use chrono::{DateTime, Utc};
-use forge_foundation::{Error, Result, SessionRecord, Storage};
+use forge_foundation::{Error, Result, SessionRecord, StorageBackend};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -79,7 +79,7 @@ pub struct SessionManager {
     current_session_id: Arc<RwLock<Option<String>>>,
 
     /// Storage for persistence
-    storage: Option<Arc<Storage>>,
+    storage: Option<Arc<dyn StorageBackend>>,
 }
 
 impl SessionManager {
@@ -93,7 +93,7 @@ impl SessionManager {
     }
 
     /// Create with storage for persistence
-    pub fn with_storage(storage: Arc<Storage>) -> Self {
+    pub fn with_storage(storage: Arc<dyn StorageBackend>) -> Self {
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             current_session_id: Arc::new(RwLock::new(None)),
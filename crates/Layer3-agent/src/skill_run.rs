@@ -0,0 +1,875 @@
+//! Skill Invocation - 스킬 호출과 dry-run
+//!
+//! CLI(`forge -p "/commit --dry-run"`)와 TUI(`/commit --dry-run`)에서 공통으로 사용하는
+//! 스킬 호출 흐름입니다.
+//!
+//! 1. `SkillInvocation::parse` - 슬래시 명령어를 스킬로 해석하고 `--dry-run` 분리
+//! 2. `SkillInvocation::prompt` - 스킬이 만든 프롬프트로 에이전트 실행
+//! 3. dry-run이면 `begin_dry_run`/`finish_dry_run`으로 실행되지 않은 호출을 `SkillPlan`으로 수집
+//! 4. 사용자가 체크리스트를 승인하면 `execute_plan`으로 기록된 호출을 그대로 실행
+//!
+//! 실행 단계(`steps`)를 선언한 스킬은 에이전트 대신 `execute_pipeline`으로 단계를 순서대로
+//! 실행합니다. 스킬 단계는 중첩 파이프라인이거나 `AgentRunner`로 실행되는 일반 스킬입니다.
+//!
+//! frontmatter로 도구(`allowed-tools`)나 모델(`model`)을 제한하거나 `context: fork`를 지정한
+//! 스킬은 `agent_context`가 만든 서브에이전트 컨텍스트에서 그 제약만 적용된 채 실행되고,
+//! `fork`면 대화 기록 없이 시작합니다 (`forks_context`).
+//!
+//! 연결된 MCP 서버의 프롬프트는 `register_mcp_prompts`로 `/<server>:<prompt>` 스킬이 됩니다.
+//! 빠진 필수 인자는 `missing_arguments`로 확인해 `with_argument`로 채우고,
+//! 실행 전에 인자 스키마(타입, 선택지, 기본값)로 검증합니다.
+
+use crate::agent::{AgentConfig, AgentEvent};
+use crate::context::AgentContext;
+use crate::event_channel::AgentEventSender;
+use crate::runner::AgentRunner;
+use forge_core::mcp::MCP_SKILL_CATEGORY;
+use forge_core::{
+    split_dry_run, DryRunRecorder, FailurePolicy, PipelineAction, PipelineReport, PlanStep,
+    RulePattern, Skill, SkillArgument, SkillContext, SkillInput, SkillLoader, SkillPipeline,
+    SkillPlan, SkillRegistry, StepOutcome,
+};
+use forge_foundation::{Error, Result};
+use forge_task::{SubAgentConfig, SubAgentType, ToolPolicy};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::path::Path;
+use std::sync::Arc;
+
+/// 스킬 단계로 중첩 호출할 수 있는 파이프라인 깊이
+const MAX_PIPELINE_DEPTH: usize = 4;
+
+/// builtin 스킬 + 파일 기반 스킬(SKILL.md) 레지스트리
+pub fn load_skills(working_dir: &Path) -> SkillRegistry {
+    let mut skills = SkillRegistry::with_builtins();
+    for skill in SkillLoader::new(working_dir).load_all() {
+        skills.register(Arc::new(skill));
+    }
+    skills
+}
+
+/// 연결된 MCP 서버의 프롬프트를 스킬로 등록 (이전에 등록한 MCP 스킬은 교체)
+///
+/// 등록한 스킬 수를 반환합니다.
+pub async fn register_mcp_prompts(skills: &mut SkillRegistry, ctx: &AgentContext) -> usize {
+    skills.unregister_category(MCP_SKILL_CATEGORY);
+    let prompts = ctx.mcp_prompt_skills().await;
+    let count = prompts.len();
+    for skill in prompts {
+        skills.register(Arc::new(skill));
+    }
+    count
+}
+
+/// 해석된 스킬 호출
+#[derive(Clone)]
+pub struct SkillInvocation {
+    skill: Arc<dyn Skill>,
+    /// `--dry-run`을 제거한 명령어
+    command: String,
+    dry_run: bool,
+}
+
+impl std::fmt::Debug for SkillInvocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SkillInvocation")
+            .field("skill", &self.name())
+            .field("command", &self.command)
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
+}
+
+impl SkillInvocation {
+    /// 슬래시 명령어를 스킬 호출로 해석 (스킬이 아니면 None)
+    pub fn parse(input: &str, skills: &SkillRegistry) -> Option<Self> {
+        let (command, dry_run) = split_dry_run(input.trim());
+        let skill = skills.find_for_input(&command)?;
+        Some(Self {
+            skill,
+            command,
+            dry_run,
+        })
+    }
+
+    /// 스킬 이름
+    pub fn name(&self) -> String {
+        self.skill.definition().name
+    }
+
+    /// 실행할 명령어 (`--dry-run` 제외)
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// dry-run 호출 여부
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// 명령어에 빠진 필수 인자
+    pub fn missing_arguments(&self) -> Vec<SkillArgument> {
+        let input = self.skill.parse_input(&self.command);
+        self.skill.missing_arguments(&input)
+    }
+
+    /// 인자 스키마로 명령어 검증 (타입, 선택지, 필수 인자)
+    ///
+    /// `allowed-tools`의 인자 제한은 bash 명령어에만 강제되므로, 다른 도구를 인자로 제한한
+    /// 스킬(`Read(src/**)`)도 거부합니다.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(RulePattern::Tool { tool, .. }) = self.unenforceable_rules().first() {
+            return Err(Error::InvalidInput(format!(
+                "/{}: allowed-tools can only restrict Bash by argument, not {}",
+                self.name(),
+                tool
+            )));
+        }
+        self.input().map(|_| ())
+    }
+
+    /// `allowed-tools`에서 강제할 수 없는 인자 제한 (bash 외 도구)
+    fn unenforceable_rules(&self) -> Vec<RulePattern> {
+        self.skill
+            .allowed_tool_rules()
+            .into_iter()
+            .filter(|rule| !rule.applies_to("bash"))
+            .collect()
+    }
+
+    /// 검증된 스킬 입력
+    fn input(&self) -> Result<SkillInput> {
+        self.skill
+            .validate_input(self.skill.parse_input(&self.command))
+    }
+
+    /// 스킬이 선언한 실행 단계 (있으면 `execute_pipeline`으로 실행)
+    pub fn pipeline(&self) -> Option<SkillPipeline> {
+        self.skill.pipeline()
+    }
+
+    /// 인자를 `--<name> <value>`로 추가 (공백이 있으면 따옴표로 묶음)
+    pub fn with_argument(mut self, name: &str, value: &str) -> Self {
+        let value = shlex::try_quote(value)
+            .map(|quoted| quoted.into_owned())
+            .unwrap_or_else(|_| value.to_string());
+        self.command = format!("{} --{} {}", self.command, name, value);
+        self
+    }
+
+    /// 서브에이전트에서 실행되는지 (도구/모델 제한 또는 `context: fork`)
+    pub fn runs_in_subagent(&self) -> bool {
+        self.skill.forks_context()
+            || self.skill.allowed_tools().is_some()
+            || self.skill.model().is_some()
+    }
+
+    /// 대화 기록 없이 시작하는지 (`context: fork`)
+    pub fn forks_context(&self) -> bool {
+        self.skill.forks_context()
+    }
+
+    /// 스킬을 실행할 에이전트 컨텍스트
+    ///
+    /// 서브에이전트에서 실행되는 스킬은 부모의 시스템 프롬프트를 쓰되 `allowed-tools`에
+    /// 있는 도구만 보는 자식 컨텍스트를, 그 외에는 부모 컨텍스트를 그대로 반환합니다.
+    /// `Bash(git log:*)`처럼 제한된 bash는 그 명령어만 실행할 수 있고, 강제할 수 없는
+    /// 인자 제한이 붙은 도구는 보이지 않습니다. 모델은 `agent_config`가 요청 옵션으로 적용합니다.
+    pub fn agent_context(&self, ctx: &Arc<AgentContext>) -> Arc<AgentContext> {
+        if !self.runs_in_subagent() {
+            return ctx.clone();
+        }
+        let unenforceable = self.unenforceable_rules();
+        let policy = match self.skill.allowed_tools() {
+            Some(tools) => ToolPolicy {
+                allow: tools
+                    .into_iter()
+                    .filter(|tool| !unenforceable.iter().any(|rule| rule.applies_to(tool)))
+                    .collect(),
+                deny: Vec::new(),
+            },
+            None => ToolPolicy::allow_all(),
+        };
+        let command_rules = self
+            .skill
+            .allowed_tool_rules()
+            .into_iter()
+            .filter(|rule| rule.applies_to("bash"))
+            .collect();
+        let agent_type = SubAgentType::Custom(format!("skill:{}", self.name()));
+        let config = SubAgentConfig::for_type(agent_type)
+            .with_system_prompt(ctx.system_prompt.clone())
+            .with_tool_policy(policy);
+        Arc::new(ctx.for_subagent(&config).with_command_rules(command_rules))
+    }
+
+    /// 스킬 설정을 반영한 에이전트 설정 (반복 횟수 상한은 입력에 따라 결정)
+    pub fn agent_config(&self, base: AgentConfig) -> AgentConfig {
+        let mut config = base.with_skill(self.skill.as_ref());
+        let max_iterations = self
+            .input()
+            .ok()
+            .and_then(|input| self.skill.max_iterations(&input));
+        if let Some(max_iterations) = max_iterations {
+            config.max_iterations = max_iterations;
+        }
+        config
+    }
+
+    /// 에이전트에 보낼 메시지 생성 (스킬 시스템 프롬프트 + 작업 프롬프트)
+    pub async fn prompt(&self, ctx: &AgentContext, session_id: &str) -> Result<String> {
+        let tool_ctx = ctx.tool_context(session_id);
+        let skill_ctx = SkillContext::new(&tool_ctx, session_id);
+        let input = self.input()?;
+        let output = self.skill.execute(&skill_ctx, input).await?;
+        if !output.success {
+            return Err(Error::Internal(format!(
+                "Skill /{} failed: {}",
+                self.name(),
+                output.message
+            )));
+        }
+
+        Ok(match self.skill.system_prompt() {
+            Some(system) if self.skill.requires_agent_loop() => {
+                format!("{}\n\n---\n\n{}", system.trim(), output.message)
+            }
+            _ => output.message,
+        })
+    }
+
+    /// dry-run 시작 (이후 권한이 필요한 도구 호출은 기록만 됨)
+    pub fn begin_dry_run(&self, ctx: &AgentContext) -> Arc<DryRunRecorder> {
+        let recorder = Arc::new(DryRunRecorder::new());
+        ctx.core_context().set_dry_run(Some(recorder.clone()));
+        recorder
+    }
+
+    /// dry-run 종료 후 계획 생성
+    pub fn finish_dry_run(
+        &self,
+        ctx: &AgentContext,
+        recorder: &DryRunRecorder,
+        prompt: &str,
+    ) -> SkillPlan {
+        ctx.core_context().set_dry_run(None);
+        let task = prompt.rsplit("\n\n---\n\n").next().unwrap_or(prompt);
+        SkillPlan::new(self.name())
+            .with_step(PlanStep::prompt(task))
+            .with_steps(recorder.take())
+    }
+}
+
+/// 승인된 계획의 도구 호출을 순서대로 실행
+///
+/// 앞 단계가 실패하면 이후 단계는 실행하지 않습니다.
+/// 진행 상황은 에이전트와 같은 이벤트(`ToolStart`/`ToolComplete`/`Done`)로 전달됩니다.
+/// 반환값은 성공한 단계 수입니다.
+pub async fn execute_plan(
+    ctx: &AgentContext,
+    plan: &SkillPlan,
+    event_tx: AgentEventSender,
+) -> Result<usize> {
+    let total = plan.executable_steps().count();
+    let mut completed = 0;
+
+    for (index, step) in plan.executable_steps().enumerate() {
+        let Some(tool) = step.tool.as_deref() else {
+            continue;
+        };
+        let tool_call_id = format!("plan-{}", index + 1);
+        let _ = event_tx
+            .send(AgentEvent::ToolStart {
+                tool_name: tool.to_string(),
+                tool_call_id: tool_call_id.clone(),
+            })
+            .await;
+
+        let (success, result, duration_ms) = match ctx.execute_tool(tool, step.input.clone()).await
+        {
+            Ok(r) => {
+                let text = r.error.clone().unwrap_or_else(|| r.output.clone());
+                (r.success, text, r.duration_ms)
+            }
+            Err(e) => (false, e.to_string(), 0),
+        };
+        let _ = event_tx
+            .send(AgentEvent::ToolComplete {
+                tool_name: tool.to_string(),
+                tool_call_id,
+                result: result.clone(),
+                success,
+                duration_ms,
+            })
+            .await;
+
+        if !success {
+            let message = format!(
+                "Plan step {}/{} failed ({}): {}",
+                index + 1,
+                total,
+                step.summary,
+                result
+            );
+            let _ = event_tx.send(AgentEvent::Error(message.clone())).await;
+            return Err(Error::Internal(message));
+        }
+        completed += 1;
+    }
+
+    let _ = event_tx
+        .send(AgentEvent::Done {
+            full_response: format!(
+                "Executed {} planned step{} for /{}.",
+                completed,
+                if completed == 1 { "" } else { "s" },
+                plan.skill
+            ),
+        })
+        .await;
+    Ok(completed)
+}
+
+/// 스킬 파이프라인 실행
+///
+/// 단계마다 `ToolStart`/`ToolComplete` 이벤트를 보내고, 끝나면 단계별 요약을
+/// `Text`와 `Done`으로 전달합니다. 실패한 단계는 `on-failure` 정책을 따르며,
+/// 중단되면 `Error` 이벤트와 함께 `Err`를 반환합니다.
+/// 스킬 단계의 에이전트는 `config`에 해당 스킬 설정을 반영해 실행됩니다.
+pub async fn execute_pipeline(
+    ctx: &Arc<AgentContext>,
+    skills: &SkillRegistry,
+    invocation: &SkillInvocation,
+    config: &AgentConfig,
+    session_id: &str,
+    event_tx: AgentEventSender,
+) -> Result<PipelineReport> {
+    let run = PipelineRun {
+        ctx,
+        skills,
+        config,
+        session_id,
+        event_tx: &event_tx,
+    };
+    let result = run.run(invocation, vec![invocation.name()]).await;
+
+    match result {
+        Ok(ref report) => {
+            let summary = report.summary();
+            let _ = event_tx.send(AgentEvent::Text(summary.clone())).await;
+            let _ = event_tx
+                .send(AgentEvent::Done {
+                    full_response: summary,
+                })
+                .await;
+        }
+        Err(ref e) => {
+            let _ = event_tx.send(AgentEvent::Error(e.to_string())).await;
+        }
+    }
+    result
+}
+
+/// 파이프라인 실행 상태 (중첩 파이프라인이 공유)
+struct PipelineRun<'a> {
+    ctx: &'a Arc<AgentContext>,
+    skills: &'a SkillRegistry,
+    config: &'a AgentConfig,
+    session_id: &'a str,
+    event_tx: &'a AgentEventSender,
+}
+
+impl PipelineRun<'_> {
+    /// `invocation`의 단계를 순서대로 실행 (`stack`: 실행 중인 스킬 이름들)
+    fn run<'s>(
+        &'s self,
+        invocation: &'s SkillInvocation,
+        stack: Vec<String>,
+    ) -> BoxFuture<'s, Result<PipelineReport>> {
+        async move {
+            let name = invocation.name();
+            let pipeline = invocation
+                .pipeline()
+                .ok_or_else(|| Error::InvalidInput(format!("/{} has no steps", name)))?;
+            pipeline
+                .validate()
+                .map_err(|e| Error::InvalidInput(format!("/{}: {}", name, e)))?;
+
+            let definition = invocation.skill.definition();
+            invocation.validate()?;
+            let vars = SkillPipeline::variables(
+                &definition,
+                &invocation.skill.parse_input(&invocation.command),
+            );
+
+            let total = pipeline.steps.len();
+            let mut report = PipelineReport::new(&name);
+            for (index, step) in pipeline.steps.iter().enumerate() {
+                let label = step.label();
+                let mut attempts = 0;
+                let (success, output) = loop {
+                    attempts += 1;
+                    let tool_call_id = format!("{}-{}-{}", stack.join("/"), index + 1, attempts);
+                    let _ = self
+                        .event_tx
+                        .send(AgentEvent::ToolStart {
+                            tool_name: label.clone(),
+                            tool_call_id: tool_call_id.clone(),
+                        })
+                        .await;
+
+                    let started = std::time::Instant::now();
+                    let (success, output) = match step.action(&vars) {
+                        Ok(action) => self.run_action(action, &stack).await,
+                        Err(e) => (false, e.to_string()),
+                    };
+                    let _ = self
+                        .event_tx
+                        .send(AgentEvent::ToolComplete {
+                            tool_name: label.clone(),
+                            tool_call_id,
+                            result: output.clone(),
+                            success,
+                            duration_ms: started.elapsed().as_millis() as u64,
+                        })
+                        .await;
+
+                    if success || attempts >= step.max_attempts() {
+                        break (success, output);
+                    }
+                };
+
+                report.steps.push(StepOutcome {
+                    step: label.clone(),
+                    success,
+                    output: output.clone(),
+                    attempts,
+                });
+                if !success && step.on_failure != FailurePolicy::Continue {
+                    return Err(Error::Internal(format!(
+                        "Pipeline /{} step {}/{} failed ({}): {}",
+                        name,
+                        index + 1,
+                        total,
+                        label,
+                        output
+                    )));
+                }
+            }
+            Ok(report)
+        }
+        .boxed()
+    }
+
+    /// 단계 동작 실행 (성공 여부, 출력)
+    async fn run_action(&self, action: PipelineAction, stack: &[String]) -> (bool, String) {
+        match action {
+            PipelineAction::Tool { name, input } => match self.ctx.execute_tool(&name, input).await
+            {
+                Ok(r) => {
+                    let text = r.error.clone().unwrap_or_else(|| r.output.clone());
+                    (r.success, text)
+                }
+                Err(e) => (false, e.to_string()),
+            },
+            PipelineAction::Skill(command) => self.run_skill(&command, stack).await,
+        }
+    }
+
+    /// 스킬 단계 실행 (중첩 파이프라인 또는 에이전트)
+    async fn run_skill(&self, command: &str, stack: &[String]) -> (bool, String) {
+        let Some(invocation) = SkillInvocation::parse(command, self.skills) else {
+            return (false, format!("Unknown skill: {}", command));
+        };
+        let name = invocation.name();
+
+        if invocation.pipeline().is_some() {
+            if stack.contains(&name) {
+                return (false, format!("/{} calls itself", name));
+            }
+            if stack.len() >= MAX_PIPELINE_DEPTH {
+                return (
+                    false,
+                    format!("Pipelines nested deeper than {}", MAX_PIPELINE_DEPTH),
+                );
+            }
+            let mut stack = stack.to_vec();
+            stack.push(name);
+            return match self.run(&invocation, stack).await {
+                // `on-failure: continue`로 넘어간 실패는 중첩 파이프라인이 허용한 것
+                Ok(report) => (true, report.summary()),
+                Err(e) => (false, e.to_string()),
+            };
+        }
+
+        let message = match invocation.prompt(self.ctx, self.session_id).await {
+            Ok(message) => message,
+            Err(e) => return (false, e.to_string()),
+        };
+        let config = invocation.agent_config(self.config.clone());
+        let mut runner = AgentRunner::with_config(invocation.agent_context(self.ctx), config)
+            .with_session_id(self.session_id);
+        match runner.send(&message).await {
+            Ok(run) if run.errors().is_empty() => (true, run.response),
+            Ok(run) => (false, run.errors().join("; ")),
+            Err(e) => (false, e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_channel::agent_event_channel;
+    use forge_core::ToolRegistry;
+    use forge_foundation::{PermissionAction, PermissionService};
+    use forge_provider::{Gateway, MockProvider, MockTurn};
+    use serde_json::json;
+
+    fn context(dir: &std::path::Path) -> AgentContext {
+        AgentContext::new(
+            Arc::new(Gateway::new()),
+            Arc::new(ToolRegistry::new()),
+            Arc::new(PermissionService::with_auto_approve()),
+            dir.to_path_buf(),
+        )
+    }
+
+    #[test]
+    fn test_parse_invocation() {
+        let skills = SkillRegistry::with_builtins();
+        let invocation = SkillInvocation::parse("/commit --dry-run --all", &skills).unwrap();
+        assert!(invocation.is_dry_run());
+        assert_eq!(invocation.command(), "/commit --all");
+        assert_eq!(invocation.name(), "commit");
+
+        assert!(!SkillInvocation::parse("/review-pr 42", &skills)
+            .unwrap()
+            .is_dry_run());
+        assert!(SkillInvocation::parse("/no-such-skill", &skills).is_none());
+    }
+
+    #[test]
+    fn test_validate_invocation() {
+        let skills = SkillRegistry::with_builtins();
+        let invocation = SkillInvocation::parse("/review-pr 42 --output yaml", &skills).unwrap();
+        let error = invocation.validate().unwrap_err().to_string();
+        assert!(error.contains("expected one of markdown, json, github"));
+
+        let invocation = SkillInvocation::parse("/review-pr 42 -o JSON", &skills).unwrap();
+        assert!(invocation.validate().is_ok());
+        assert_eq!(
+            invocation
+                .agent_config(AgentConfig::default())
+                .max_iterations,
+            AgentConfig::default().max_iterations
+        );
+
+        // /fix는 --attempts 예산으로 반복 횟수 상한을 정함
+        let iterations = |command: &str| {
+            SkillInvocation::parse(command, &skills)
+                .unwrap()
+                .agent_config(AgentConfig::default())
+                .max_iterations
+        };
+        assert_eq!(iterations("/fix -n 4"), 2 * iterations("/fix -n 2"));
+        assert!(iterations("/fix --attempts 1") < AgentConfig::default().max_iterations);
+    }
+
+    #[test]
+    fn test_mcp_prompt_arguments() {
+        let mut skills = SkillRegistry::with_builtins();
+        let prompt = forge_core::McpPrompt {
+            name: "summarize-page".into(),
+            description: None,
+            arguments: vec![forge_core::McpPromptArgument {
+                name: "page".into(),
+                description: Some("Page title".into()),
+                required: true,
+            }],
+        };
+        let bridge = Arc::new(tokio::sync::RwLock::new(forge_core::McpBridge::new()));
+        skills.register(Arc::new(forge_core::McpPromptSkill::new("notion", prompt, bridge)));
+
+        let invocation = SkillInvocation::parse("/notion:summarize-page", &skills).unwrap();
+        assert_eq!(invocation.missing_arguments()[0].name, "page");
+
+        let invocation = invocation.with_argument("page", "Weekly notes");
+        assert_eq!(invocation.command(), "/notion:summarize-page --page 'Weekly notes'");
+        assert!(invocation.missing_arguments().is_empty());
+
+        assert!(invocation.validate().is_ok());
+
+        assert_eq!(skills.unregister_category(MCP_SKILL_CATEGORY), 1);
+        assert!(SkillInvocation::parse("/notion:summarize-page", &skills).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_then_execute_plan() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = context(dir.path());
+        let skills = SkillRegistry::with_builtins();
+        let invocation = SkillInvocation::parse("/commit --dry-run", &skills).unwrap();
+
+        let prompt = invocation.prompt(&ctx, "test").await.unwrap();
+        assert!(prompt.contains("Git commit assistant"));
+
+        // 에이전트가 호출했을 도구를 흉내냄
+        let recorder = invocation.begin_dry_run(&ctx);
+        let target = dir.path().join("planned.txt");
+        let input = json!({ "file_path": target.to_string_lossy(), "content": "planned" });
+        let result = ctx.execute_tool("write", input).await.unwrap();
+        assert!(result.output.starts_with("[dry-run]"));
+        assert!(!target.exists());
+
+        let plan = invocation.finish_dry_run(&ctx, &recorder, &prompt);
+        assert!(!ctx.core_context().is_dry_run());
+        assert_eq!(plan.steps.len(), 2);
+        assert!(!plan.steps[0].summary.contains("Git commit assistant"));
+        assert!(plan.render_checklist().contains("**write**"));
+
+        let (tx, mut rx) = agent_event_channel(16);
+        let completed = execute_plan(&ctx, &plan, tx).await.unwrap();
+        assert_eq!(completed, 1);
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "planned");
+
+        let mut saw_done = false;
+        while let Some(event) = rx.recv().await {
+            saw_done |= matches!(event, AgentEvent::Done { .. });
+        }
+        assert!(saw_done);
+    }
+
+    #[tokio::test]
+    async fn test_execute_plan_stops_on_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = context(dir.path());
+        let failing = PlanStep::from_tool_call(
+            "no_such_tool",
+            &json!({}),
+            &PermissionAction::Custom {
+                name: "x".into(),
+                details: "y".into(),
+            },
+        );
+        let target = dir.path().join("never.txt");
+        let write = PlanStep::from_tool_call(
+            "write",
+            &json!({ "file_path": target.to_string_lossy(), "content": "x" }),
+            &PermissionAction::FileWrite {
+                path: target.to_string_lossy().into(),
+            },
+        );
+        let plan = SkillPlan::new("test").with_steps([failing, write]);
+
+        let (tx, _rx) = agent_event_channel(16);
+        assert!(execute_plan(&ctx, &plan, tx).await.is_err());
+        assert!(!target.exists());
+    }
+
+    fn pipeline_skill(content: &str) -> Arc<dyn Skill> {
+        Arc::new(forge_core::FileBasedSkill::parse(content, "pipeline/SKILL.md".into()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_execute_pipeline() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = Arc::new(context(dir.path()));
+        let notes = dir.path().join("notes.txt");
+        let inner = dir.path().join("inner.txt");
+
+        let mut skills = SkillRegistry::with_builtins();
+        skills.register(pipeline_skill(&format!(
+            "---\nname: inner\nsteps:\n  - tool: write\n    input: {{file_path: '{}', content: inner}}\n---\n",
+            inner.display()
+        )));
+        skills.register(pipeline_skill(&format!(
+            r#"---
+name: outer
+arguments:
+  - {{name: topic, required: true}}
+steps:
+  - name: notes
+    tool: write
+    input: {{file_path: '{}', content: "about ${{topic}}"}}
+  - tool: no_such_tool
+    on-failure: continue
+  - skill: /inner
+---
+"#,
+            notes.display()
+        )));
+
+        let invocation = SkillInvocation::parse("/outer pipelines", &skills).unwrap();
+        assert!(invocation.pipeline().is_some());
+
+        let (tx, mut rx) = agent_event_channel(64);
+        let report = execute_pipeline(
+            &ctx,
+            &skills,
+            &invocation,
+            &AgentConfig::default(),
+            "test",
+            tx,
+        )
+        .await
+        .unwrap();
+        assert_eq!(report.steps.len(), 3);
+        assert!(!report.steps[1].success);
+        assert!(report.steps[2].success);
+        assert_eq!(std::fs::read_to_string(&notes).unwrap(), "about pipelines");
+        assert_eq!(std::fs::read_to_string(&inner).unwrap(), "inner");
+
+        let mut starts = Vec::new();
+        let mut done = None;
+        while let Some(event) = rx.recv().await {
+            match event {
+                AgentEvent::ToolStart { tool_name, .. } => starts.push(tool_name),
+                AgentEvent::Done { full_response } => done = Some(full_response),
+                _ => {}
+            }
+        }
+        assert_eq!(starts, ["notes", "no_such_tool", "/inner", "write"]);
+        assert!(done
+            .unwrap()
+            .starts_with("Ran 3 steps for /outer (1 failed)."));
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_failure_policies() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = Arc::new(context(dir.path()));
+        let target = dir.path().join("never.txt");
+
+        let mut skills = SkillRegistry::with_builtins();
+        skills.register(pipeline_skill(&format!(
+            "---\nname: flaky\nsteps:\n  - {{tool: no_such_tool, on-failure: retry, retries: 1}}\n  - tool: write\n    input: {{file_path: '{}', content: x}}\n---\n",
+            target.display()
+        )));
+        skills.register(pipeline_skill(
+            "---\nname: loop\nsteps:\n  - skill: /loop\n---\n",
+        ));
+
+        let invocation = SkillInvocation::parse("/flaky", &skills).unwrap();
+        let (tx, mut rx) = agent_event_channel(64);
+        let error = execute_pipeline(
+            &ctx,
+            &skills,
+            &invocation,
+            &AgentConfig::default(),
+            "test",
+            tx,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("step 1/2 failed"));
+        assert!(!target.exists());
+
+        let mut starts = 0;
+        let mut saw_error = false;
+        while let Some(event) = rx.recv().await {
+            starts += matches!(event, AgentEvent::ToolStart { .. }) as usize;
+            saw_error |= matches!(event, AgentEvent::Error(_));
+        }
+        assert_eq!(starts, 2);
+        assert!(saw_error);
+
+        let invocation = SkillInvocation::parse("/loop", &skills).unwrap();
+        let (tx, _rx) = agent_event_channel(64);
+        let error = execute_pipeline(
+            &ctx,
+            &skills,
+            &invocation,
+            &AgentConfig::default(),
+            "test",
+            tx,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("/loop calls itself"));
+    }
+
+    #[tokio::test]
+    async fn test_subagent_skill() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = Arc::new(MockProvider::new().with_turn(MockTurn::text("No issues found.")));
+        let mut gateway = Gateway::new();
+        gateway.add_provider("mock", provider.clone());
+        let ctx = Arc::new(AgentContext::new(
+            Arc::new(gateway),
+            Arc::new(ToolRegistry::with_builtins()),
+            Arc::new(PermissionService::with_auto_approve()),
+            dir.path().to_path_buf(),
+        ));
+
+        let mut skills = SkillRegistry::with_builtins();
+        skills.register(pipeline_skill(
+            "---\nname: audit\nallowed-tools: Read, Grep\nmodel: claude-3-5-haiku-latest\ncontext: fork\n---\nAudit the code.\n",
+        ));
+        let invocation = SkillInvocation::parse("/audit", &skills).unwrap();
+        assert!(invocation.runs_in_subagent());
+        assert!(invocation.forks_context());
+
+        let config = invocation.agent_config(AgentConfig::default());
+        let mut runner = AgentRunner::with_config(invocation.agent_context(&ctx), config);
+        let prompt = invocation.prompt(&ctx, "test").await.unwrap();
+        let run = runner.send(&prompt).await.unwrap();
+        assert_eq!(run.response, "No issues found.");
+
+        // 허용 도구만 보이고 요청은 지정한 모델로 감
+        let request = &provider.requests()[0];
+        assert_eq!(request.model, "claude-3-5-haiku-latest");
+        let mut tools = request.tools.clone();
+        tools.sort();
+        assert_eq!(tools, ["grep", "read"]);
+
+        // 제약이 없는 스킬은 부모 컨텍스트에서 실행
+        let commit = SkillInvocation::parse("/commit", &skills).unwrap();
+        assert!(!commit.runs_in_subagent());
+        assert!(Arc::ptr_eq(&commit.agent_context(&ctx), &ctx));
+    }
+
+    #[tokio::test]
+    async fn test_allowed_tools_restrict_arguments() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = Arc::new(AgentContext::new(
+            Arc::new(Gateway::new()),
+            Arc::new(ToolRegistry::with_builtins()),
+            Arc::new(PermissionService::with_auto_approve()),
+            dir.path().to_path_buf(),
+        ));
+        let mut skills = SkillRegistry::new();
+        skills.register(pipeline_skill(
+            "---\nname: history\nallowed-tools: Bash(git log:*), Read\n---\nSummarize history.\n",
+        ));
+        skills.register(pipeline_skill(
+            "---\nname: docs\nallowed-tools: Read(docs/**), Grep\n---\nRead the docs.\n",
+        ));
+
+        // `Bash(git log:*)`는 bash 도구 전체가 아니라 그 명령어만 허용
+        let history = SkillInvocation::parse("/history", &skills).unwrap();
+        assert!(history.validate().is_ok());
+        let child = history.agent_context(&ctx);
+        for command in ["rm -rf src", "git log && rm -rf src", "git push"] {
+            let result = child
+                .execute_tool("bash", json!({ "command": command }))
+                .await
+                .unwrap();
+            assert!(!result.success, "{}", command);
+            assert!(result.error.unwrap().contains("allowed-tools"));
+        }
+        let result = child
+            .execute_tool("bash", json!({ "command": "git log --oneline" }))
+            .await
+            .unwrap();
+        assert!(!result.error.unwrap_or_default().contains("allowed-tools"));
+
+        // bash 외 도구의 인자 제한은 강제할 수 없으므로 거부하고 도구도 숨김
+        let docs = SkillInvocation::parse("/docs", &skills).unwrap();
+        let error = docs.validate().unwrap_err().to_string();
+        assert!(error.contains("Read"), "{}", error);
+        let child = docs.agent_context(&ctx);
+        assert!(!child.allows_tool("read"));
+        assert!(child.allows_tool("grep"));
+    }
+}
@@ -130,6 +130,8 @@ pub enum ContextItemKind {
     ConversationHistory,
     /// 도구 결과
     ToolResult,
+    /// MCP 서버 리소스
+    McpResource { server: String },
 }
 
 /// Smart Context Manager
@@ -157,6 +159,13 @@ impl SmartContextManager {
         self.items.insert(item.id.clone(), item);
     }
 
+    /// MCP 리소스 목록 추가 (내용 없이 요약만, 필요하면 `resource_read`로 가져옴)
+    pub fn add_mcp_resources(&mut self, resources: &[(String, forge_core::McpResource)]) {
+        for (server, resource) in resources {
+            self.add_item(context_from_mcp_resource(server, resource, None));
+        }
+    }
+
     /// 현재 포커스 설정 (관련성 계산에 사용)
     pub fn set_focus(&mut self, focus: Vec<String>) {
         self.current_focus = focus;
@@ -220,6 +229,7 @@ impl SmartContextManager {
         // 관련성 점수로 정렬 (높은 순)
         items.sort_by(|a, b| {
             b.relevance.total.partial_cmp(&a.relevance.total).unwrap()
+                .then_with(|| a.id.cmp(&b.id))
         });
 
         let mut slice = ContextSlice::new(self.max_tokens);
@@ -301,7 +311,12 @@ impl ContextSlice {
 
     /// 프롬프트 형식으로 변환
     pub fn format_for_prompt(&self) -> String {
-        let mut output = String::from("## Project Context\n\n");
+        self.format_with_title("Project Context")
+    }
+
+    /// 주어진 제목으로 프롬프트 형식 변환
+    pub fn format_with_title(&self, title: &str) -> String {
+        let mut output = format!("## {}\n\n", title);
 
         for (item, level) in &self.items {
             output.push_str(&format!("### {}\n", item.id));
@@ -404,6 +419,43 @@ pub fn context_from_file(path: &str, content: &str) -> ContextItem {
     }
 }
 
+/// MCP 리소스에서 컨텍스트 아이템 생성
+///
+/// `content`가 없으면 요약만 담기고, 에이전트가 `resource_read`로 내용을 가져옵니다.
+pub fn context_from_mcp_resource(
+    server: &str,
+    resource: &forge_core::McpResource,
+    content: Option<&str>,
+) -> ContextItem {
+    let mut summary = format!("{} (server: {}", resource.name, server);
+    if let Some(mime) = &resource.mime_type {
+        summary.push_str(&format!(", {}", mime));
+    }
+    summary.push(')');
+    if let Some(description) = resource.description.as_deref().filter(|d| !d.is_empty()) {
+        summary.push_str(&format!(": {}", description));
+    }
+
+    // 토큰 추정 (대략 4자 = 1토큰)
+    let sum_tokens = summary.len() / 4;
+    let full_tokens = content.map(|c| c.len() / 4).unwrap_or(sum_tokens);
+
+    ContextItem {
+        id: resource.uri.clone(),
+        kind: ContextItemKind::McpResource { server: server.to_string() },
+        summary,
+        signatures: Vec::new(),
+        full_content: content.map(str::to_string),
+        estimated_tokens: TokenCounts {
+            summary: sum_tokens,
+            signatures: 0,
+            full: full_tokens,
+        },
+        last_accessed: Utc::now(),
+        relevance: RelevanceScore::default(),
+    }
+}
+
 /// 요약 추출
 fn extract_summary(lines: &[&str]) -> String {
     // 문서 주석 찾기
@@ -578,6 +630,31 @@ impl Agent {
         assert_eq!(slice.items.len(), 1);
     }
 
+    #[test]
+    fn test_mcp_resource_context() {
+        let resource = |uri: &str, name: &str| forge_core::McpResource {
+            uri: uri.to_string(),
+            name: name.to_string(),
+            description: Some("Team docs".to_string()),
+            mime_type: Some("text/markdown".to_string()),
+        };
+
+        let mut manager = SmartContextManager::new(1000);
+        manager.add_mcp_resources(&[
+            ("wiki".to_string(), resource("wiki://b", "B")),
+            ("wiki".to_string(), resource("wiki://a", "A")),
+        ]);
+
+        let prompt = manager.get_optimal_slice().format_with_title("MCP Resources");
+        assert!(prompt.starts_with("## MCP Resources"));
+        assert!(prompt.contains("### wiki://a\nA (server: wiki, text/markdown): Team docs"));
+        assert!(prompt.find("wiki://a").unwrap() < prompt.find("wiki://b").unwrap());
+
+        let item = context_from_mcp_resource("wiki", &resource("wiki://a", "A"), Some("# Body"));
+        assert_eq!(item.kind, ContextItemKind::McpResource { server: "wiki".to_string() });
+        assert_eq!(item.full_content.as_deref(), Some("# Body"));
+    }
+
     #[test]
     fn test_efficiency_stats() {
         let slice = ContextSlice {
@@ -0,0 +1,292 @@
+//! Subagent Dispatch - 파일 기반 서브에이전트 등록과 실행
+//!
+//! `.forgecode/agents/*.md`, `.claude/agents/*.md` 정의를 `SubagentDefinition`으로 변환해
+//! 두 실행 경로에 등록합니다.
+//!
+//! - **Native agent**: `register_subagents`로 `subagent` 도구 등록. 모델이 `subagent_type`을
+//!   지정해 호출하면 정의의 시스템 프롬프트와 도구 정책을 적용한 자식 에이전트를 실행하고
+//!   최종 응답을 도구 결과로 돌려줍니다.
+//! - **Agent provider**: `load_subagents` 결과를 `AgentQueryOptions::subagents`로 전달
+//!   (`ForgeNativeProvider`는 받은 정의로 같은 `subagent` 도구를 등록).
+//!
+//! 자식 에이전트는 부모 컨텍스트를 공유하되 `AgentContext::for_subagent`로 허용된 도구만
+//! 보고 실행할 수 있으며, `subagent` 도구는 제외되어 중첩 호출은 불가능합니다.
+//! native 경로에서 `model`은 `SubAgentConfig`에 기록만 되고 현재 프로바이더 모델로 실행됩니다.
+//!
+//! ```ignore
+//! let agents = load_subagents(&working_dir);
+//! register_subagents(&ctx, agents.clone()).await;
+//!
+//! let options = AgentQueryOptions { subagents: agents, ..Default::default() };
+//! ```
+
+use crate::agent::AgentConfig;
+use crate::context::AgentContext;
+use crate::runner::AgentRunner;
+use async_trait::async_trait;
+use forge_core::{FileBasedSubagent, SubagentLoader};
+use forge_foundation::{PermissionAction, Result, Tool, ToolContext, ToolMeta, ToolResult};
+use forge_provider::SubagentDefinition;
+use forge_task::{ModelSelection, SubAgentConfig, SubAgentType, ToolPolicy};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::{Arc, Weak};
+use tracing::info;
+
+/// 파일 기반 서브에이전트 정의 로드 (이름 → 정의)
+pub fn load_subagents(working_dir: &Path) -> HashMap<String, SubagentDefinition> {
+    subagent_definitions(&SubagentLoader::new(working_dir).load_all())
+}
+
+/// 로드된 파일을 프로바이더 공용 정의로 변환 (`tools`가 없으면 빈 목록)
+pub fn subagent_definitions(agents: &[FileBasedSubagent]) -> HashMap<String, SubagentDefinition> {
+    agents
+        .iter()
+        .map(|agent| {
+            let definition = SubagentDefinition {
+                description: agent.description().to_string(),
+                prompt: agent.prompt().to_string(),
+                tools: agent.tools().map(<[String]>::to_vec).unwrap_or_default(),
+                model: agent.model().map(str::to_string),
+            };
+            (agent.name().to_string(), definition)
+        })
+        .collect()
+}
+
+/// 정의를 Sub-agent 설정으로 변환
+///
+/// 이름이 builtin 타입(`researcher`, `builder` 등)이면 그 타입의 기본값 위에 적용합니다.
+/// `tools`가 비어 있으면 builtin 타입은 기본 도구를, 커스텀 타입은 모든 도구를 허용합니다.
+pub fn subagent_config(name: &str, definition: &SubagentDefinition) -> SubAgentConfig {
+    let agent_type = SubAgentType::from_name(name);
+    let is_custom = matches!(agent_type, SubAgentType::Custom(_));
+
+    let mut config =
+        SubAgentConfig::for_type(agent_type).with_system_prompt(definition.prompt.clone());
+    if !definition.tools.is_empty() {
+        config = config.with_tool_policy(ToolPolicy {
+            allow: definition.tools.clone(),
+            deny: Vec::new(),
+        });
+    } else if is_custom {
+        config = config.with_tool_policy(ToolPolicy::allow_all());
+    }
+    if let Some(model) = definition
+        .model
+        .as_deref()
+        .and_then(ModelSelection::from_name)
+    {
+        config = config.with_model(model);
+    }
+    config
+}
+
+/// 서브에이전트 정의를 native agent에 `subagent` 도구로 등록
+///
+/// 이전에 등록한 `subagent` 도구는 교체되며, 정의가 없으면 등록하지 않습니다.
+/// 등록한 정의 수를 반환합니다.
+pub async fn register_subagents(
+    ctx: &Arc<AgentContext>,
+    agents: HashMap<String, SubagentDefinition>,
+) -> usize {
+    let count = agents.len();
+    if count > 0 {
+        let tool = SubagentTool::new(ctx, agents);
+        ctx.core_context().register_tool(Arc::new(tool)).await;
+        info!("Registered {} subagents", count);
+    }
+    count
+}
+
+// ============================================================================
+// subagent
+// ============================================================================
+
+/// 서브에이전트 디스패치 도구
+///
+/// 부모 컨텍스트는 `Weak`로 보관합니다 (도구는 부모 컨텍스트의 레지스트리에 등록되므로).
+pub struct SubagentTool {
+    ctx: Weak<AgentContext>,
+    /// 이름순 정렬 (스키마와 설명이 항상 같도록)
+    agents: BTreeMap<String, SubagentDefinition>,
+}
+
+impl SubagentTool {
+    pub const NAME: &'static str = "subagent";
+
+    /// 부모 컨텍스트와 정의로 생성
+    pub fn new(ctx: &Arc<AgentContext>, agents: HashMap<String, SubagentDefinition>) -> Self {
+        Self {
+            ctx: Arc::downgrade(ctx),
+            agents: agents.into_iter().collect(),
+        }
+    }
+
+    /// 등록된 서브에이전트 이름
+    pub fn names(&self) -> Vec<&str> {
+        self.agents.keys().map(String::as_str).collect()
+    }
+}
+
+#[async_trait]
+impl Tool for SubagentTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        let mut description = String::from(
+            "Delegate a self-contained task to a specialized subagent. The subagent runs with \
+             its own system prompt and restricted tools, and returns its final answer.\n\n\
+             Available subagents:",
+        );
+        for (name, definition) in &self.agents {
+            description.push_str(&format!("\n- {}: {}", name, definition.description));
+        }
+
+        ToolMeta::new(Self::NAME)
+            .display_name("Subagent")
+            .description(description)
+            .category("agent")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "subagent_type": {
+                    "type": "string",
+                    "enum": self.names(),
+                    "description": "Subagent to run"
+                },
+                "prompt": {
+                    "type": "string",
+                    "description": "Complete task for the subagent, with all context it needs"
+                },
+                "description": {
+                    "type": "string",
+                    "description": "Short (3-5 word) summary of the task"
+                }
+            },
+            "required": ["subagent_type", "prompt"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        // 자식 에이전트의 도구 호출이 각각 권한 검사를 받음
+        None
+    }
+
+    async fn execute(&self, input: Value, _context: &dyn ToolContext) -> Result<ToolResult> {
+        let Some(name) = input.get("subagent_type").and_then(|v| v.as_str()) else {
+            return Ok(ToolResult::error(
+                "Missing required parameter: subagent_type",
+            ));
+        };
+        let Some(prompt) = input.get("prompt").and_then(|v| v.as_str()) else {
+            return Ok(ToolResult::error("Missing required parameter: prompt"));
+        };
+        let Some(definition) = self.agents.get(name) else {
+            return Ok(ToolResult::error(format!(
+                "Unknown subagent '{}'. Available: {}",
+                name,
+                self.names().join(", ")
+            )));
+        };
+        let Some(parent) = self.ctx.upgrade() else {
+            return Ok(ToolResult::error("Agent context is no longer available"));
+        };
+
+        let config = subagent_config(name, definition);
+        let child = Arc::new(parent.for_subagent(&config));
+        let agent_config = AgentConfig {
+            max_iterations: config.max_turns as usize,
+            turn_summary: false,
+            ghost_commits: false,
+            auto_commit: None,
+            ..AgentConfig::default()
+        };
+
+        info!("Dispatching subagent '{}'", name);
+        let mut runner = AgentRunner::with_config(child, agent_config);
+        match runner.send(prompt).await {
+            Ok(run) => {
+                let errors = run.errors();
+                if run.response.trim().is_empty() && !errors.is_empty() {
+                    return Ok(ToolResult::error(format!(
+                        "Subagent '{}' failed: {}",
+                        name,
+                        errors.join("; ")
+                    )));
+                }
+                let tools: Vec<&str> = run.tool_calls().iter().map(|(tool, _)| *tool).collect();
+                Ok(ToolResult::success(run.response.clone())
+                    .with_metadata("subagent", json!(name))
+                    .with_metadata("tool_calls", json!(tools)))
+            }
+            Err(e) => Ok(ToolResult::error(format!(
+                "Subagent '{}' failed: {}",
+                name, e
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(tools: &[&str]) -> SubagentDefinition {
+        SubagentDefinition {
+            description: "test".into(),
+            prompt: "You are a test agent.".into(),
+            tools: tools.iter().map(|t| t.to_string()).collect(),
+            model: Some("haiku".into()),
+        }
+    }
+
+    #[test]
+    fn test_subagent_config_from_definition() {
+        let config = subagent_config("reviewer", &definition(&["read", "grep"]));
+        assert_eq!(config.agent_type, SubAgentType::Custom("reviewer".into()));
+        assert_eq!(
+            config.system_prompt.as_deref(),
+            Some("You are a test agent.")
+        );
+        assert_eq!(config.model, ModelSelection::Haiku);
+        assert!(config.is_tool_allowed("grep"));
+        assert!(!config.is_tool_allowed("bash"));
+
+        // 도구 미지정: 커스텀 타입은 전체 허용, builtin 타입은 기본값
+        let config = subagent_config("reviewer", &definition(&[]));
+        assert!(config.is_tool_allowed("bash"));
+        let config = subagent_config("researcher", &definition(&[]));
+        assert!(config.is_tool_allowed("read"));
+        assert!(!config.is_tool_allowed("bash"));
+    }
+
+    #[test]
+    fn test_definitions_from_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let agents_dir = temp.path().join(".forgecode/agents");
+        std::fs::create_dir_all(&agents_dir).unwrap();
+        std::fs::write(
+            agents_dir.join("reviewer.md"),
+            "---\ndescription: Reviews diffs\ntools: Read, Grep\nmodel: sonnet\n---\nReview the diff.",
+        )
+        .unwrap();
+
+        let agents = subagent_definitions(&SubagentLoader::with_paths(vec![agents_dir]).load_all());
+        let reviewer = &agents["reviewer"];
+        assert_eq!(reviewer.description, "Reviews diffs");
+        assert_eq!(reviewer.prompt, "Review the diff.");
+        assert_eq!(reviewer.tools, ["read", "grep"]);
+        assert_eq!(reviewer.model.as_deref(), Some("sonnet"));
+    }
+}
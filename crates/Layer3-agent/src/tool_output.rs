@@ -0,0 +1,165 @@
+//! Tool Output - 실행 중 도구 출력 스트리밍
+//!
+//! bash처럼 오래 걸리는 도구의 stdout/stderr를 줄 단위로 받아
+//! `AgentEvent::ToolOutput`으로 전달합니다. TUI는 이를 실시간으로 표시합니다.
+//! MCP 도구의 진행 상황은 `AgentEvent::ToolProgress`로 전달합니다.
+//!
+//! ## Kill patterns
+//!
+//! `AgentConfig::kill_patterns`의 정규식 중 하나가 출력 줄과 일치하면
+//! 명령을 즉시 중단합니다. 명백히 실패한 명령이 끝날 때까지 기다리지 않습니다.
+//!
+//! ```ignore
+//! let config = AgentConfig::default()
+//!     .with_kill_patterns(vec![r"^error(\[E\d+\])?: could not compile".to_string()]);
+//! ```
+
+use crate::agent::AgentEvent;
+use crate::event_channel::AgentEventSender;
+use forge_foundation::{OutputControl, ToolOutputSink, ToolProgress};
+use regex::Regex;
+use tracing::warn;
+
+/// 도구 출력을 `AgentEvent::ToolOutput`으로 전달하는 수신자
+pub struct ToolOutputForwarder {
+    tool_name: String,
+    tool_call_id: String,
+    event_tx: AgentEventSender,
+    kill_patterns: Vec<Regex>,
+}
+
+impl ToolOutputForwarder {
+    /// 생성 (잘못된 kill pattern은 경고 후 무시)
+    pub fn new(
+        tool_name: impl Into<String>,
+        tool_call_id: impl Into<String>,
+        event_tx: AgentEventSender,
+        kill_patterns: &[String],
+    ) -> Self {
+        let kill_patterns = kill_patterns
+            .iter()
+            .filter_map(|pattern| match Regex::new(pattern) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    warn!("Ignoring invalid kill pattern '{}': {}", pattern, e);
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            tool_name: tool_name.into(),
+            tool_call_id: tool_call_id.into(),
+            event_tx,
+            kill_patterns,
+        }
+    }
+}
+
+impl ToolOutputSink for ToolOutputForwarder {
+    fn on_output(&self, chunk: &str, _is_stderr: bool) -> OutputControl {
+        // 채널이 밀리면 표시용 출력만 합치거나 버림 (도구 결과에는 전체 출력이 남음)
+        let _ = self.event_tx.try_send(AgentEvent::ToolOutput {
+            tool_name: self.tool_name.clone(),
+            tool_call_id: self.tool_call_id.clone(),
+            chunk: chunk.to_string(),
+        });
+
+        let line = chunk.trim_end();
+        match self.kill_patterns.iter().find(|p| p.is_match(line)) {
+            Some(pattern) => OutputControl::Abort(format!(
+                "output matched kill pattern '{}'",
+                pattern.as_str()
+            )),
+            None => OutputControl::Continue,
+        }
+    }
+
+    fn on_progress(&self, progress: &ToolProgress) -> OutputControl {
+        let _ = self.event_tx.try_send(AgentEvent::ToolProgress {
+            tool_name: self.tool_name.clone(),
+            tool_call_id: self.tool_call_id.clone(),
+            progress: progress.clone(),
+        });
+        OutputControl::Continue
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_channel::agent_event_channel;
+
+    #[test]
+    fn test_forwards_chunks() {
+        let (tx, mut rx) = agent_event_channel(4);
+        let forwarder = ToolOutputForwarder::new("bash", "call-1", tx, &[]);
+
+        assert_eq!(
+            forwarder.on_output("Compiling forge\n", false),
+            OutputControl::Continue
+        );
+        match rx.try_recv().unwrap() {
+            AgentEvent::ToolOutput {
+                tool_name,
+                tool_call_id,
+                chunk,
+            } => {
+                assert_eq!(tool_name, "bash");
+                assert_eq!(tool_call_id, "call-1");
+                assert_eq!(chunk, "Compiling forge\n");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_forwards_progress() {
+        let (tx, mut rx) = agent_event_channel(4);
+        let forwarder = ToolOutputForwarder::new("mcp_notion_export", "call-2", tx, &[]);
+        let progress = ToolProgress {
+            progress: 1.0,
+            total: Some(4.0),
+            message: None,
+        };
+
+        assert_eq!(forwarder.on_progress(&progress), OutputControl::Continue);
+        match rx.try_recv().unwrap() {
+            AgentEvent::ToolProgress {
+                tool_call_id,
+                progress: received,
+                ..
+            } => {
+                assert_eq!(tool_call_id, "call-2");
+                assert_eq!(received, progress);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_kill_patterns() {
+        let (tx, _rx) = agent_event_channel(1);
+        let forwarder = ToolOutputForwarder::new(
+            "bash",
+            "call-1",
+            tx,
+            &["^error: could not compile".to_string(), "(".to_string()],
+        );
+        assert_eq!(forwarder.kill_patterns.len(), 1);
+
+        assert_eq!(
+            forwarder.on_output("warning: unused variable\n", true),
+            OutputControl::Continue
+        );
+        // 채널이 가득 차도 kill pattern 검사는 계속됨
+        assert_eq!(
+            forwarder.on_output("error: could not compile `forge`\n", true),
+            OutputControl::Abort("output matched kill pattern '^error: could not compile'".into())
+        );
+    }
+}
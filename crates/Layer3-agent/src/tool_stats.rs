@@ -0,0 +1,162 @@
+//! Tool Stats - 도구 실행 기록
+//!
+//! 에이전트가 실행한 모든 도구 호출을 `ToolExecutionRecord`로 저장소에 남깁니다.
+//! 도구 이름, 소요 시간, 성공 여부, 출력 크기, 복구 재시도 횟수를 기록하며
+//! `forge stats tools`가 이를 집계해 실패가 잦은 도구를 보여줍니다.
+//!
+//! ```ignore
+//! let agent = Agent::new(ctx).with_tool_recorder(ToolExecutionRecorder::new(storage));
+//! ```
+
+use forge_foundation::{StorageBackend, ToolExecutionRecord};
+use forge_provider::ToolCall;
+use std::sync::Arc;
+use tracing::warn;
+
+/// 저장할 출력/에러 최대 문자 수 (크기는 `output_bytes`에 전체 기준으로 기록)
+const RECORDED_OUTPUT_CHARS: usize = 2000;
+
+/// 한 번의 도구 호출에서 일어난 시도 정보
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ToolAttempts {
+    /// 복구(재시도/대체 도구) 횟수
+    pub retries: u32,
+
+    /// 타임아웃으로 중단되었는지
+    pub timed_out: bool,
+}
+
+/// 도구 실행 기록기
+pub struct ToolExecutionRecorder {
+    storage: Arc<dyn StorageBackend>,
+}
+
+impl ToolExecutionRecorder {
+    pub fn new(storage: Arc<dyn StorageBackend>) -> Self {
+        Self { storage }
+    }
+
+    /// 완료된 도구 호출 기록 (실패해도 에이전트 실행에는 영향 없음)
+    ///
+    /// 저장소에 없는 세션이면 세션 없이 기록합니다 (`sessions` 외래 키).
+    pub fn record(
+        &self,
+        session_id: &str,
+        tool_call: &ToolCall,
+        content: &str,
+        success: bool,
+        attempts: ToolAttempts,
+        duration_ms: u64,
+    ) {
+        let session_id = match self.storage.get_session(session_id) {
+            Ok(Some(_)) => Some(session_id),
+            _ => None,
+        };
+        let record = build_record(
+            session_id,
+            tool_call,
+            content,
+            success,
+            attempts,
+            duration_ms,
+        );
+        if let Err(e) = self.storage.record_tool_execution(&record) {
+            warn!(
+                "Failed to record tool execution '{}': {}",
+                tool_call.name, e
+            );
+        }
+    }
+}
+
+fn build_record(
+    session_id: Option<&str>,
+    tool_call: &ToolCall,
+    content: &str,
+    success: bool,
+    attempts: ToolAttempts,
+    duration_ms: u64,
+) -> ToolExecutionRecord {
+    let status = match (success, attempts.timed_out) {
+        (true, _) => "success",
+        (false, true) => "timeout",
+        (false, false) => "error",
+    };
+    let preview: String = content.chars().take(RECORDED_OUTPUT_CHARS).collect();
+    let (output_text, error_message) = if success {
+        (Some(preview), None)
+    } else {
+        (None, Some(preview))
+    };
+
+    ToolExecutionRecord {
+        id: None,
+        session_id: session_id.map(str::to_string),
+        message_id: None,
+        tool_name: tool_call.name.clone(),
+        tool_call_id: tool_call.id.clone(),
+        input_json: tool_call.arguments.to_string(),
+        output_text,
+        status: status.to_string(),
+        error_message,
+        duration_ms: Some(duration_ms as i64),
+        output_bytes: Some(content.len() as i64),
+        retries: attempts.retries as i64,
+        created_at: None,
+        completed_at: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge_foundation::{SessionRecord, Storage};
+    use serde_json::json;
+
+    #[test]
+    fn test_records_executions() {
+        let storage = Arc::new(Storage::in_memory().unwrap());
+        storage
+            .create_session(&SessionRecord {
+                id: "s1".to_string(),
+                ..Default::default()
+            })
+            .unwrap();
+        let recorder = ToolExecutionRecorder::new(storage.clone());
+        let call = ToolCall::new("call-1", "bash", json!({ "command": "cargo test" }));
+
+        let long_output = "x".repeat(RECORDED_OUTPUT_CHARS + 500);
+        recorder.record("s1", &call, &long_output, true, ToolAttempts::default(), 42);
+        recorder.record(
+            "s1",
+            &call,
+            "timed out",
+            false,
+            ToolAttempts {
+                retries: 2,
+                timed_out: true,
+            },
+            120_000,
+        );
+
+        let records = storage.get_tool_executions("s1").unwrap();
+        assert_eq!(records.len(), 2);
+
+        let success = records.iter().find(|r| r.status == "success").unwrap();
+        assert_eq!(success.output_bytes, Some(long_output.len() as i64));
+        assert_eq!(
+            success.output_text.as_ref().unwrap().len(),
+            RECORDED_OUTPUT_CHARS
+        );
+        assert!(success.input_json.contains("cargo test"));
+
+        let timeout = records.iter().find(|r| r.status == "timeout").unwrap();
+        assert_eq!(timeout.retries, 2);
+        assert_eq!(timeout.error_message.as_deref(), Some("timed out"));
+        assert_eq!(timeout.output_text, None);
+
+        // 저장되지 않은 세션도 집계에는 포함됨
+        recorder.record("unsaved", &call, "ok", true, ToolAttempts::default(), 5);
+        assert_eq!(storage.get_recent_tool_executions(10).unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,413 @@
+//! Turn Summary - 턴별 변경 요약
+//!
+//! 파일을 수정한 턴이 끝나면 git diff 통계로 한 줄 요약을 만들어
+//! 히스토리와 TUI에 남깁니다.
+//!
+//! ```text
+//! modified 3 files: +120 −45; tests not yet run (src/lib.rs, src/agent.rs, README.md)
+//! ```
+//!
+//! 긴 세션을 훑어보기 쉽게 하고, 모델이 자신이 실제로 바꾼 내용을
+//! 정확히 알 수 있게 합니다.
+//!
+//! ## 동작
+//!
+//! 1. 파일을 바꿀 수 있는 도구가 처음 호출되기 직전에 작업 트리를 스냅샷
+//!    (임시 인덱스 사용 - 사용자의 staging 영역은 건드리지 않음)
+//! 2. 도구 결과로 테스트 실행 여부/결과 추적
+//! 3. 턴 종료 시 다시 스냅샷하여 `git diff --numstat`으로 비교
+//! 4. 두 스냅샷을 세션 타임라인에 고스트 커밋으로 기록 (`/rewind`,
+//!    [`record_ghost_commit`])
+//!
+//! git 저장소가 아니면 아무것도 하지 않습니다.
+
+use forge_core::{CheckpointManager, DiffStat, GitOps};
+use forge_provider::ToolCall;
+use std::fmt;
+use std::path::Path;
+use tracing::debug;
+
+/// 파일을 수정하지 않는 도구
+const READ_ONLY_TOOLS: &[&str] = &[
+    "read",
+    "glob",
+    "grep",
+    "list_directory",
+    "list_todos",
+    "code_search",
+    "web_fetch",
+    "web_search",
+    "http_request",
+    "task_wait",
+    "task_logs",
+    "task_list",
+    "task_status",
+    "process_status",
+    "process_logs",
+    "fetch_full_output",
+    "git_blame",
+    "git_log",
+];
+
+/// 테스트 실행으로 간주하는 명령 패턴
+const TEST_COMMANDS: &[&str] = &[
+    "cargo test",
+    "cargo nextest",
+    "npm test",
+    "npm run test",
+    "yarn test",
+    "pnpm test",
+    "bun test",
+    "pytest",
+    "python -m pytest",
+    "go test",
+    "jest",
+    "vitest",
+    "mvn test",
+    "gradle test",
+    "./gradlew test",
+    "make test",
+];
+
+/// 요약에 나열할 최대 파일 수
+const MAX_LISTED_FILES: usize = 5;
+
+/// 고스트 커밋 설명(프롬프트 첫 줄) 최대 길이
+const MAX_DESCRIPTION_CHARS: usize = 72;
+
+// ============================================================================
+// TestStatus
+// ============================================================================
+
+/// 마지막 변경 이후 테스트 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestStatus {
+    /// 마지막 변경 이후 테스트를 실행하지 않음
+    NotRun,
+    /// 테스트 통과
+    Passed,
+    /// 테스트 실패
+    Failed,
+}
+
+impl fmt::Display for TestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TestStatus::NotRun => write!(f, "tests not yet run"),
+            TestStatus::Passed => write!(f, "tests passed"),
+            TestStatus::Failed => write!(f, "tests failed"),
+        }
+    }
+}
+
+// ============================================================================
+// TurnSummary
+// ============================================================================
+
+/// 한 턴의 변경 요약
+#[derive(Debug, Clone)]
+pub struct TurnSummary {
+    /// 파일별 diff 통계
+    pub stat: DiffStat,
+    /// 테스트 상태
+    pub tests: TestStatus,
+    /// 첫 수정 직전의 작업 트리 (tree id)
+    pub before_tree: String,
+    /// 턴 종료 시점의 작업 트리 (tree id)
+    pub after_tree: String,
+}
+
+impl fmt::Display for TurnSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = self.stat.files.len();
+        write!(
+            f,
+            "modified {} file{}: +{} −{}; {}",
+            count,
+            if count == 1 { "" } else { "s" },
+            self.stat.additions(),
+            self.stat.deletions(),
+            self.tests
+        )?;
+
+        let listed: Vec<_> = self
+            .stat
+            .files
+            .iter()
+            .take(MAX_LISTED_FILES)
+            .map(|file| file.path.as_str())
+            .collect();
+        write!(f, " ({}", listed.join(", "))?;
+        if count > MAX_LISTED_FILES {
+            write!(f, ", +{} more", count - MAX_LISTED_FILES)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// ============================================================================
+// TurnChangeTracker
+// ============================================================================
+
+/// 한 번의 `Agent::run` 동안 파일 변경과 테스트 실행을 추적
+pub struct TurnChangeTracker {
+    /// git 저장소가 아니면 None
+    git: Option<GitOps>,
+    /// 첫 수정 직전의 작업 트리 스냅샷
+    baseline: Option<String>,
+    /// 테스트 상태 (수정 도구가 호출되면 NotRun으로 초기화)
+    tests: TestStatus,
+}
+
+impl TurnChangeTracker {
+    /// 작업 디렉토리 기준으로 생성
+    pub fn new(working_dir: &Path) -> Self {
+        Self {
+            git: GitOps::new(working_dir).ok(),
+            baseline: None,
+            tests: TestStatus::NotRun,
+        }
+    }
+
+    /// 비활성 트래커 (요약 생성 안 함)
+    pub fn disabled() -> Self {
+        Self {
+            git: None,
+            baseline: None,
+            tests: TestStatus::NotRun,
+        }
+    }
+
+    /// 도구 실행 직전 호출 - 수정 도구가 처음 나오면 스냅샷
+    pub fn before_tools(&mut self, tool_calls: &[ToolCall]) {
+        let Some(git) = &self.git else {
+            return;
+        };
+        if self.baseline.is_some() || !tool_calls.iter().any(may_modify) {
+            return;
+        }
+
+        match git.snapshot_tree() {
+            Ok(tree) => self.baseline = Some(tree),
+            Err(e) => {
+                debug!("Turn summary disabled, snapshot failed: {}", e);
+                self.git = None;
+            }
+        }
+    }
+
+    /// 도구 실행 결과 기록
+    pub fn record_result(&mut self, tool_call: &ToolCall, is_error: bool) {
+        if is_test_command(tool_call) {
+            self.tests = if is_error {
+                TestStatus::Failed
+            } else {
+                TestStatus::Passed
+            };
+        } else if may_modify(tool_call) {
+            self.tests = TestStatus::NotRun;
+        }
+    }
+
+    /// 수정 도구가 실행되었는지 (스냅샷이 있을 때만)
+    pub fn has_modifications(&self) -> bool {
+        self.git.is_some() && self.baseline.is_some()
+    }
+
+    /// 턴 종료 - 변경된 파일이 있으면 요약 반환
+    pub fn finish(&mut self) -> Option<TurnSummary> {
+        let git = self.git.as_ref()?;
+        let baseline = self.baseline.take()?;
+
+        let current = git
+            .snapshot_tree()
+            .map_err(|e| debug!("Turn summary snapshot failed: {}", e))
+            .ok()?;
+        let stat = git
+            .diff_stat(&baseline, &current)
+            .map_err(|e| debug!("Turn summary diff failed: {}", e))
+            .ok()?;
+
+        if stat.is_empty() {
+            return None;
+        }
+
+        Some(TurnSummary {
+            stat,
+            tests: self.tests,
+            before_tree: baseline,
+            after_tree: current,
+        })
+    }
+}
+
+/// 변경된 턴을 세션 타임라인에 고스트 커밋으로 기록하고 턴 번호 반환
+///
+/// 실패해도 턴 진행에는 영향이 없도록 로그만 남깁니다.
+pub fn record_ghost_commit(
+    working_dir: &Path,
+    session_id: &str,
+    summary: &TurnSummary,
+    prompt: &str,
+) -> Option<u32> {
+    let first_line = prompt.lines().next().unwrap_or_default().trim();
+    let mut description: String = first_line.chars().take(MAX_DESCRIPTION_CHARS).collect();
+    if description.len() < first_line.len() {
+        description.push('…');
+    }
+
+    let entry = CheckpointManager::new(working_dir)
+        .and_then(|manager| {
+            manager.record_turn(
+                session_id,
+                &summary.before_tree,
+                &summary.after_tree,
+                &description,
+            )
+        })
+        .map_err(|e| debug!("Ghost commit failed: {}", e))
+        .ok()??;
+    Some(entry.turn)
+}
+
+/// 파일을 수정할 수 있는 도구 호출인지
+fn may_modify(tool_call: &ToolCall) -> bool {
+    !READ_ONLY_TOOLS.contains(&tool_call.name.as_str()) && !is_test_command(tool_call)
+}
+
+/// 테스트 실행 명령인지 (bash)
+fn is_test_command(tool_call: &ToolCall) -> bool {
+    if tool_call.name != "bash" {
+        return false;
+    }
+
+    let Some(command) = tool_call.arguments.get("command").and_then(|c| c.as_str()) else {
+        return false;
+    };
+    let command = command.trim();
+
+    TEST_COMMANDS.iter().any(|pattern| {
+        command.starts_with(pattern)
+            || command.contains(&format!("&& {}", pattern))
+            || command.contains(&format!("; {}", pattern))
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge_core::FileDiffStat;
+    use serde_json::json;
+
+    fn bash(command: &str) -> ToolCall {
+        ToolCall::new("1", "bash", json!({ "command": command }))
+    }
+
+    fn file(path: &str, additions: usize, deletions: usize) -> FileDiffStat {
+        FileDiffStat {
+            path: path.to_string(),
+            additions,
+            deletions,
+            binary: false,
+        }
+    }
+
+    #[test]
+    fn test_summary_format() {
+        let summary = TurnSummary {
+            stat: DiffStat {
+                files: vec![
+                    file("src/lib.rs", 100, 40),
+                    file("src/agent.rs", 15, 5),
+                    file("README.md", 5, 0),
+                ],
+            },
+            tests: TestStatus::NotRun,
+            before_tree: String::new(),
+            after_tree: String::new(),
+        };
+        assert_eq!(
+            summary.to_string(),
+            "modified 3 files: +120 −45; tests not yet run (src/lib.rs, src/agent.rs, README.md)"
+        );
+
+        let single = TurnSummary {
+            stat: DiffStat {
+                files: (0..7).map(|i| file(&format!("f{}.rs", i), 1, 0)).collect(),
+            },
+            tests: TestStatus::Passed,
+            before_tree: String::new(),
+            after_tree: String::new(),
+        };
+        assert!(single
+            .to_string()
+            .ends_with("tests passed (f0.rs, f1.rs, f2.rs, f3.rs, f4.rs, +2 more)"));
+    }
+
+    #[test]
+    fn test_test_command_detection() {
+        assert!(is_test_command(&bash("cargo test -p forge-core")));
+        assert!(is_test_command(&bash("cd web && npm test")));
+        assert!(!is_test_command(&bash("cargo build")));
+        assert!(!is_test_command(&ToolCall::new(
+            "1",
+            "read",
+            json!({ "command": "cargo test" })
+        )));
+
+        assert!(may_modify(&bash("sed -i s/a/b/ x.rs")));
+        assert!(!may_modify(&bash("cargo test")));
+        assert!(!may_modify(&ToolCall::new("1", "grep", json!({}))));
+    }
+
+    #[test]
+    fn test_tracker_test_status() {
+        let mut tracker = TurnChangeTracker::disabled();
+        tracker.record_result(&ToolCall::new("1", "edit", json!({})), false);
+        assert_eq!(tracker.tests, TestStatus::NotRun);
+
+        tracker.record_result(&bash("cargo test"), true);
+        assert_eq!(tracker.tests, TestStatus::Failed);
+
+        tracker.record_result(&bash("cargo test"), false);
+        assert_eq!(tracker.tests, TestStatus::Passed);
+
+        tracker.record_result(&ToolCall::new("1", "write", json!({})), false);
+        assert_eq!(tracker.tests, TestStatus::NotRun);
+        assert!(tracker.finish().is_none());
+    }
+
+    #[test]
+    fn test_ghost_commit_per_turn() {
+        let dir = tempfile::tempdir().unwrap();
+        if !std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(dir.path())
+            .status()
+            .is_ok_and(|s| s.success())
+        {
+            return;
+        }
+        std::fs::write(dir.path().join("a.txt"), "one\n").unwrap();
+
+        let mut tracker = TurnChangeTracker::new(dir.path());
+        tracker.before_tools(&[ToolCall::new("1", "edit", json!({}))]);
+        std::fs::write(dir.path().join("a.txt"), "two\n").unwrap();
+        let summary = tracker.finish().unwrap();
+
+        let prompt = format!("{}\nsecond line", "x".repeat(100));
+        assert_eq!(
+            record_ghost_commit(dir.path(), "s1", &summary, &prompt),
+            Some(1)
+        );
+        let timeline = CheckpointManager::new(dir.path())
+            .unwrap()
+            .timeline("s1")
+            .unwrap();
+        assert_eq!(timeline[0].description, format!("{}…", "x".repeat(72)));
+    }
+}
@@ -0,0 +1,223 @@
+//! Permission audit report
+//!
+//! `forge audit report` - `AuditLogger` 기록을 기간별로 집계해 권한 승인/거부,
+//! 위험 명령 실행, 저장소 밖 파일 수정, 차단된 호출을 보여주고 JSON/HTML로 내보냅니다.
+//!
+//! 기록은 설정의 `security.audit.enabled`가 켜진 세션에서만 쌓입니다.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+use forge_foundation::audit::{AuditLogger, AuditReport, AuditReportOptions, ReportItem};
+use std::path::{Path, PathBuf};
+
+/// 기본 조회 기간
+pub const DEFAULT_SINCE: &str = "7d";
+
+/// 리포트 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    Html,
+}
+
+impl ReportFormat {
+    /// `--format` 값, 없으면 출력 파일 확장자로 결정
+    pub fn resolve(format: Option<&str>, output: Option<&Path>) -> Result<Self> {
+        let format = match format {
+            Some(format) => format.to_ascii_lowercase(),
+            None => match output.and_then(|p| p.extension()).and_then(|e| e.to_str()) {
+                Some("json") => "json".to_string(),
+                Some("html") | Some("htm") => "html".to_string(),
+                _ => "text".to_string(),
+            },
+        };
+
+        match format.as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "html" => Ok(Self::Html),
+            other => bail!(
+                "Unknown report format '{}' (expected text, json or html)",
+                other
+            ),
+        }
+    }
+}
+
+/// `--since`/`--until` 값 해석: 기간(`7d`, `12h`, `2w`) 또는 날짜(YYYY-MM-DD, 로컬 자정)
+pub fn parse_time(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+        return Ok(Local
+            .from_local_datetime(&midnight)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&midnight)));
+    }
+
+    let unit_len = value.chars().last().map_or(0, char::len_utf8);
+    let (amount, unit) = value.split_at(value.len() - unit_len);
+    let amount: i64 = amount.parse().with_context(|| {
+        format!(
+            "Invalid time '{}' (expected e.g. 7d, 12h, 2w or YYYY-MM-DD)",
+            value
+        )
+    })?;
+    let duration = match unit {
+        "m" => Duration::minutes(amount),
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        "w" => Duration::weeks(amount),
+        _ => bail!("Invalid time unit in '{}' (expected m, h, d or w)", value),
+    };
+    Ok(now - duration)
+}
+
+/// `forge audit report`
+pub async fn report_cmd(
+    since: &str,
+    until: Option<&str>,
+    format: Option<&str>,
+    output: Option<&Path>,
+    repo: Option<PathBuf>,
+    min_risk: Option<u8>,
+) -> Result<()> {
+    let now = Utc::now();
+    let mut options = AuditReportOptions::since(parse_time(since, now)?);
+    if let Some(until) = until {
+        options = options.with_until(parse_time(until, now)?);
+    }
+    if options.until.is_some_and(|until| until < options.since) {
+        bail!("--since must not be after --until");
+    }
+    if let Some(repo) = repo {
+        let repo = if repo.is_relative() {
+            std::env::current_dir()?.join(repo)
+        } else {
+            repo
+        };
+        options = options.with_repo_root(repo);
+    }
+    if let Some(level) = min_risk {
+        options = options.with_min_command_risk(level);
+    }
+    let format = ReportFormat::resolve(format, output)?;
+
+    let logger = AuditLogger::new()?;
+    let report = logger.report(&options).await?;
+
+    let rendered = match format {
+        ReportFormat::Text => render_text(&report),
+        ReportFormat::Json => report.to_json()?,
+        ReportFormat::Html => report.to_html(),
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, rendered)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Audit report written to {}", path.display());
+        }
+        None => println!("{}", rendered),
+    }
+    Ok(())
+}
+
+/// 터미널용 요약
+pub fn render_text(report: &AuditReport) -> String {
+    let mut out = format!(
+        "🛡️  Permission audit: {} → {}\n\n",
+        report.since.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+        report.until.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+    );
+
+    if report.total_entries == 0 {
+        out.push_str("No audit entries recorded in this period.\n");
+        out.push_str("Enable recording with \"security\": { \"audit\": { \"enabled\": true } } in settings.json\n");
+        return out;
+    }
+
+    out.push_str(&format!(
+        "  {} entries · {} granted · {} denied\n",
+        report.total_entries, report.permissions.granted, report.permissions.denied
+    ));
+
+    if !report.permissions.by_tool.is_empty() {
+        out.push_str(&format!(
+            "\n{:<24} {:>8} {:>8}\n",
+            "Tool", "Granted", "Denied"
+        ));
+        out.push_str(&format!("{}\n", "-".repeat(42)));
+        for count in &report.permissions.by_tool {
+            out.push_str(&format!(
+                "{:<24} {:>8} {:>8}\n",
+                count.tool, count.granted, count.denied
+            ));
+        }
+    }
+
+    render_items(&mut out, "Risky commands executed", &report.risky_commands);
+    render_items(
+        &mut out,
+        "Files modified outside the repository",
+        &report.outside_repo_files,
+    );
+    render_items(&mut out, "Blocked calls", &report.blocked);
+
+    if report.is_clean() {
+        out.push_str("\n✓ No denials, risky commands, outside-repo changes or blocked calls.\n");
+    }
+    out
+}
+
+fn render_items(out: &mut String, title: &str, items: &[ReportItem]) {
+    if items.is_empty() {
+        return;
+    }
+
+    out.push_str(&format!("\n{} ({})\n", title, items.len()));
+    for item in items {
+        out.push_str(&format!(
+            "  {}  {:<10} risk {:>2}  {}\n",
+            item.timestamp.with_timezone(&Local).format("%m-%d %H:%M"),
+            item.actor,
+            item.risk_level,
+            item.target
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time() {
+        let now = Utc::now();
+        assert_eq!(parse_time("7d", now).unwrap(), now - Duration::days(7));
+        assert_eq!(parse_time("12h", now).unwrap(), now - Duration::hours(12));
+        assert_eq!(parse_time("2w", now).unwrap(), now - Duration::weeks(2));
+        assert!(parse_time("2026-01-05", now).unwrap() < now);
+        assert!(parse_time("7x", now).is_err());
+        assert!(parse_time("d", now).is_err());
+    }
+
+    #[test]
+    fn test_report_format() {
+        assert_eq!(
+            ReportFormat::resolve(None, None).unwrap(),
+            ReportFormat::Text
+        );
+        assert_eq!(
+            ReportFormat::resolve(None, Some(Path::new("audit.html"))).unwrap(),
+            ReportFormat::Html
+        );
+        assert_eq!(
+            ReportFormat::resolve(Some("JSON"), Some(Path::new("audit.html"))).unwrap(),
+            ReportFormat::Json
+        );
+        assert!(ReportFormat::resolve(Some("pdf"), None).is_err());
+    }
+}
@@ -3,24 +3,62 @@
 //! 단일 프롬프트를 처리하는 비대화형 모드입니다.
 //! Layer3 Agent의 새로운 이벤트 시스템을 완전히 지원합니다.
 
-use forge_agent::{Agent, AgentConfig, AgentContext, AgentEvent, MessageHistory};
-use forge_core::ToolRegistry;
-use forge_foundation::{PermissionService, ProviderConfig, Result};
+use crate::clipboard_tool::register_clipboard_tools;
+use crate::stats;
+use forge_agent::{
+    agent_event_channel, execute_pipeline, execute_plan, load_skills, load_subagents,
+    register_mcp_prompts, register_subagents, Agent, AgentConfig, AgentContext, AgentEvent,
+    AgentEventReceiver, MessageHistory, SkillInvocation, ToolExecutionRecorder,
+};
+use forge_core::{render_plan_approvals, ToolRegistry};
+use forge_foundation::permission::{RemoteDelegate, RemoteEndpoint};
+use forge_foundation::{PermissionDelegate, PermissionService, ProviderConfig, Result};
 use forge_provider::Gateway;
 use forge_task::TaskManager;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::sync::Arc;
-use tokio::sync::mpsc;
+
+/// Remote approval delegate from `--permission-endpoint` or `FORGE_PERMISSION_ENDPOINT`
+pub fn remote_approvals(
+    endpoint: Option<&str>,
+    timeout_secs: Option<u64>,
+) -> Result<Option<RemoteDelegate>> {
+    let delegate = match endpoint {
+        Some(spec) => {
+            let mut delegate = RemoteDelegate::new(RemoteEndpoint::parse(spec)?);
+            if let Ok(token) = std::env::var(forge_foundation::permission::PERMISSION_TOKEN_ENV) {
+                delegate = delegate.with_token(token);
+            }
+            Some(delegate)
+        }
+        None => RemoteDelegate::from_env()?,
+    };
+    Ok(delegate.map(|d| match timeout_secs {
+        Some(secs) => d.with_timeout(std::time::Duration::from_secs(secs)),
+        None => d,
+    }))
+}
 
 /// Run a single prompt in non-interactive mode
-pub async fn run_once(config: &ProviderConfig, prompt: &str) -> Result<()> {
+///
+/// With `approvals`, the saved permission policy applies and every other
+/// permission request goes to the remote endpoint; otherwise actions are
+/// auto-approved. With `read_only`, every write, execute and network action
+/// is denied.
+pub async fn run_once(
+    config: &ProviderConfig,
+    prompt: &str,
+    approvals: Option<RemoteDelegate>,
+    read_only: bool,
+) -> Result<()> {
     // Print header
     eprintln!("ForgeCode - Processing...\n");
-
-    // Initialize components
-    let gateway = Arc::new(Gateway::from_config(config)?);
-    let tools = Arc::new(ToolRegistry::with_builtins());
-    let permissions = Arc::new(PermissionService::with_auto_approve());
+    if read_only {
+        eprintln!("Read-only mode: write, execute and network actions are denied\n");
+    }
+    if let Some(delegate) = &approvals {
+        eprintln!("Permission requests go to {}\n", delegate.endpoint());
+    }
 
     let working_dir = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
 
@@ -28,22 +66,146 @@ pub async fn run_once(config: &ProviderConfig, prompt: &str) -> Result<()> {
     let task_manager = Arc::new(TaskManager::new(forge_task::TaskManagerConfig::default()).await);
 
     // Create agent context with task manager
-    let ctx = Arc::new(
-        AgentContext::new(gateway, tools, permissions, working_dir.clone())
-            .with_task_manager(task_manager)
-    );
+    let ctx = agent_context(config, working_dir.clone(), approvals, read_only)?
+        .with_task_manager(task_manager);
+    let ctx = Arc::new(ctx);
+    register_subagents(&ctx, load_subagents(&working_dir)).await;
 
-    // Create agent with config
-    let agent = Agent::with_config(ctx, AgentConfig::default());
+    if let Some(summary) = ctx.capabilities().summary() {
+        eprintln!("⚠ Reduced capability: {}\n", summary);
+    }
+
+    let session_id = uuid::Uuid::new_v4().to_string();
+
+    // Skill invocation (`/commit --dry-run` etc.)
+    let mut skills = load_skills(&working_dir);
+    register_mcp_prompts(&mut skills, &ctx).await;
+    let invocation = SkillInvocation::parse(prompt, &skills);
+    // Skills that declare steps run them in order instead of prompting the agent
+    let pipeline = invocation
+        .as_ref()
+        .filter(|skill| skill.pipeline().is_some());
+    let (agent_config, message) = match &invocation {
+        Some(skill) if pipeline.is_some() => (AgentConfig::default(), skill.command().to_string()),
+        Some(skill) => (
+            skill.agent_config(AgentConfig::default()),
+            skill.prompt(&ctx, &session_id).await?,
+        ),
+        None => (AgentConfig::default(), prompt.to_string()),
+    };
+    let recorder = invocation
+        .as_ref()
+        .filter(|skill| skill.is_dry_run())
+        .map(|skill| skill.begin_dry_run(&ctx));
+
+    // Create agent with config (tool executions feed `forge stats tools`);
+    // skills that restrict tools or model run in their own sub-agent context
+    let agent_ctx = match &invocation {
+        Some(skill) => skill.agent_context(&ctx),
+        None => ctx.clone(),
+    };
+    let mut agent = Agent::with_config(agent_ctx, agent_config);
+    if let Ok(storage) = stats::open_storage() {
+        agent = agent.with_tool_recorder(ToolExecutionRecorder::new(storage));
+    }
 
     // Create message history
     let mut history = MessageHistory::new();
 
-    // Create event channel
-    let (tx, mut rx) = mpsc::channel(100);
+    // Create event channel and spawn event handler
+    let (tx, rx) = agent_event_channel(100);
+    let event_handle = spawn_event_printer(rx);
+
+    // Run agent (or the skill's steps)
+    let result = match pipeline {
+        Some(skill) => {
+            let config = AgentConfig::default();
+            execute_pipeline(&ctx, &skills, skill, &config, &session_id, tx)
+                .await
+                .map(|report| report.summary())
+        }
+        None => agent.run(&session_id, &mut history, &message, tx).await,
+    };
+
+    // Wait for event handler to finish
+    let _ = event_handle.await;
+
+    // Handle result
+    if let Err(e) = result {
+        eprintln!("[Error] Agent failed: {}", e);
+    }
+
+    // Dry run: show the plan and optionally execute it
+    if let (Some(skill), Some(recorder)) = (&invocation, recorder) {
+        let plan = skill.finish_dry_run(&ctx, &recorder, &message);
+        println!("\n{}", plan.render_checklist());
+        let simulations = ctx.core_context().simulate_plan(&plan).await;
+        if let Some(approvals) = render_plan_approvals(&simulations) {
+            println!("{}", approvals);
+        }
+
+        if plan.has_actions() && confirm_plan(plan.executable_steps().count())? {
+            let (tx, rx) = agent_event_channel(100);
+            let event_handle = spawn_event_printer(rx);
+            let result = execute_plan(&ctx, &plan, tx).await;
+            let _ = event_handle.await;
+            if let Err(e) = result {
+                eprintln!("[Error] {}", e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Agent context for a non-interactive run in `working_dir`
+///
+/// Same permission rules as `run_once`: the saved policy plus `approvals`
+/// when given, otherwise auto-approve; `read_only` denies every write,
+/// execute and network action.
+pub fn agent_context(
+    config: &ProviderConfig,
+    working_dir: std::path::PathBuf,
+    approvals: Option<RemoteDelegate>,
+    read_only: bool,
+) -> Result<AgentContext> {
+    let gateway = Arc::new(Gateway::from_config(config)?);
+    let mut tools = ToolRegistry::with_builtins();
+    register_clipboard_tools(&mut tools);
+    let tools = Arc::new(tools);
+    let permissions = Arc::new(match approvals {
+        Some(_) => PermissionService::load()?,
+        None => PermissionService::with_auto_approve(),
+    });
+    if read_only {
+        permissions.set_read_only(true);
+    }
+
+    let mut ctx = AgentContext::new(gateway, tools, permissions, working_dir);
+    if let Some(delegate) = approvals {
+        let delegate: Arc<dyn PermissionDelegate> = Arc::new(delegate);
+        ctx = ctx.with_permission_delegate(delegate);
+    }
+    Ok(ctx)
+}
+
+/// Ask whether to execute a dry-run plan (declines when stdin is not a terminal)
+fn confirm_plan(steps: usize) -> Result<bool> {
+    if !io::stdin().is_terminal() {
+        eprintln!("Re-run without --dry-run to execute.");
+        return Ok(false);
+    }
+    eprint!("Execute {} step{} for real? [y/N] ", steps, if steps == 1 { "" } else { "s" });
+    io::stderr().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
 
-    // Spawn event handler
-    let event_handle = tokio::spawn(async move {
+/// Print agent events to stdout/stderr until the channel closes
+fn spawn_event_printer(mut rx: AgentEventReceiver) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
         let mut stdout = io::stdout();
         let mut current_turn = 0u32;
         let mut total_input = 0u32;
@@ -69,6 +231,15 @@ pub async fn run_once(config: &ProviderConfig, prompt: &str) -> Result<()> {
                     eprint!("\r[{}] Running...    ", tool_name);
                     let _ = io::stderr().flush();
                 }
+                AgentEvent::ToolOutput { chunk, .. } => {
+                    // Pad so the "[tool] Running..." line is fully overwritten
+                    eprintln!("\r  | {:<30}", chunk.trim_end());
+                }
+                AgentEvent::ToolProgress { progress, .. } => {
+                    // Rewrite the same line so progress updates don't scroll
+                    eprint!("\r  | {:<30}", progress.to_string());
+                    let _ = io::stderr().flush();
+                }
                 AgentEvent::ToolComplete {
                     tool_name,
                     success,
@@ -85,6 +256,19 @@ pub async fn run_once(config: &ProviderConfig, prompt: &str) -> Result<()> {
                         duration_ms
                     );
                 }
+                AgentEvent::TurnSummary { summary } => {
+                    eprintln!("\r[Changes] {}", summary);
+                }
+                AgentEvent::GhostCommit { turn } => {
+                    eprintln!("[Checkpoint] Recorded turn {}", turn);
+                }
+                AgentEvent::AutoCommitted { hash } => {
+                    eprintln!("[Commit] {}", hash);
+                }
+                AgentEvent::PreCommitFailed { summary, retrying } => {
+                    let next = if retrying { "fixing" } else { "not committed" };
+                    eprintln!("[Pre-commit] {} ({})", summary, next);
+                }
                 AgentEvent::Compressed {
                     tokens_before,
                     tokens_after,
@@ -123,21 +307,17 @@ pub async fn run_once(config: &ProviderConfig, prompt: &str) -> Result<()> {
                 }
             }
         }
-    });
-
-    // Run agent
-    let session_id = uuid::Uuid::new_v4().to_string();
-    let result = agent.run(&session_id, &mut history, prompt, tx).await;
-
-    // Wait for event handler to finish
-    let _ = event_handle.await;
-
-    // Handle result
-    if let Err(e) = result {
-        eprintln!("[Error] Agent failed: {}", e);
-    }
 
-    Ok(())
+        let metrics = rx.metrics();
+        if metrics.under_pressure() {
+            tracing::debug!(
+                coalesced = metrics.coalesced,
+                dropped = metrics.dropped,
+                max_depth = metrics.max_depth,
+                "Agent event channel was under pressure"
+            );
+        }
+    })
 }
 
 /// Truncate a string for display
@@ -20,6 +20,11 @@ impl ClipboardManager {
         Self { clipboard }
     }
 
+    /// 시스템 클립보드에 연결하지 않은 매니저 (항상 사용 불가)
+    pub fn detached() -> Self {
+        Self { clipboard: None }
+    }
+
     /// 전역 인스턴스 가져오기
     pub fn global() -> &'static std::sync::Mutex<ClipboardManager> {
         CLIPBOARD.get_or_init(|| std::sync::Mutex::new(ClipboardManager::new()))
@@ -0,0 +1,351 @@
+//! Clipboard Tools - 에이전트용 클립보드 도구
+//!
+//! `clipboard_read` / `clipboard_write` 도구로 `ClipboardManager`를 에이전트에 노출합니다.
+//! 생성한 스니펫이나 명령어를 사용자 클립보드에 올려둘 때 사용합니다.
+//!
+//! 클립보드 내용에는 비밀번호 등 민감 정보가 있을 수 있으므로 읽기/쓰기 모두
+//! 권한(`clipboard.read`, `clipboard.write`)이 필요합니다.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use forge_core::ToolRegistry;
+use forge_foundation::{
+    PermissionAction, PermissionStatus, Result, Tool, ToolContext, ToolMeta, ToolResult,
+};
+use serde_json::{json, Value};
+
+use crate::clipboard::ClipboardManager;
+
+/// 읽기 권한 이름
+const READ_PERMISSION: &str = "clipboard.read";
+
+/// 쓰기 권한 이름
+const WRITE_PERMISSION: &str = "clipboard.write";
+
+/// 쓰기 가능한 최대 바이트 수
+const MAX_WRITE_BYTES: usize = 1024 * 1024;
+
+/// 읽기 결과 최대 문자 수
+const MAX_READ_CHARS: usize = 25_000;
+
+/// 권한 요청에 표시할 미리보기 길이
+const PREVIEW_CHARS: usize = 80;
+
+/// 클립보드 도구 등록
+///
+/// 두 도구는 하나의 `ClipboardManager`를 공유합니다.
+pub fn register_clipboard_tools(registry: &mut ToolRegistry) {
+    let clipboard = Arc::new(Mutex::new(ClipboardManager::new()));
+    registry.register(Arc::new(ClipboardReadTool::with_manager(clipboard.clone())));
+    registry.register(Arc::new(ClipboardWriteTool::with_manager(clipboard)));
+}
+
+/// 권한 요청용 한 줄 미리보기
+fn preview(text: &str) -> String {
+    let line = text.lines().next().unwrap_or("");
+    let mut preview: String = line.chars().take(PREVIEW_CHARS).collect();
+    if preview.len() < text.len() {
+        preview.push('…');
+    }
+    preview
+}
+
+/// 권한 확인 후 거부 시 에러 결과 반환
+async fn check_permission(
+    tool: &str,
+    action: PermissionAction,
+    description: &str,
+    context: &dyn ToolContext,
+) -> Result<Option<ToolResult>> {
+    match context.check_permission(tool, &action).await {
+        PermissionStatus::Denied => Ok(Some(ToolResult::error(format!(
+            "Permission denied for {}",
+            tool
+        )))),
+        PermissionStatus::Unknown => {
+            if context
+                .request_permission(tool, description, action)
+                .await?
+            {
+                Ok(None)
+            } else {
+                Ok(Some(ToolResult::error("Permission denied by user")))
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+// ============================================================================
+// clipboard_read
+// ============================================================================
+
+/// 클립보드 읽기 도구
+pub struct ClipboardReadTool {
+    clipboard: Arc<Mutex<ClipboardManager>>,
+}
+
+impl ClipboardReadTool {
+    pub const NAME: &'static str = "clipboard_read";
+
+    pub fn new() -> Self {
+        Self::with_manager(Arc::new(Mutex::new(ClipboardManager::new())))
+    }
+
+    /// 지정한 클립보드 매니저 사용
+    pub fn with_manager(clipboard: Arc<Mutex<ClipboardManager>>) -> Self {
+        Self { clipboard }
+    }
+}
+
+impl Default for ClipboardReadTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ClipboardReadTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Clipboard Read")
+            .description(
+                "Read the text currently on the user's clipboard. Requires the user's permission.",
+            )
+            .category("clipboard")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {}
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        Some(PermissionAction::Custom {
+            name: READ_PERMISSION.to_string(),
+            details: "read clipboard".to_string(),
+        })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        if let Some(action) = self.required_permission(&input) {
+            if let Some(denied) =
+                check_permission(Self::NAME, action, "Read clipboard contents", context).await?
+            {
+                return Ok(denied);
+            }
+        }
+
+        let text = match self.clipboard.lock() {
+            Ok(mut clipboard) => clipboard.paste(),
+            Err(_) => return Ok(ToolResult::error("Clipboard is unavailable")),
+        };
+        let text = match text {
+            Ok(text) => text,
+            Err(e) => return Ok(ToolResult::error(e.to_string())),
+        };
+
+        let total = text.chars().count();
+        if text.is_empty() {
+            return Ok(ToolResult::success("(clipboard is empty)").with_metadata("chars", json!(0)));
+        }
+        let mut output: String = text.chars().take(MAX_READ_CHARS).collect();
+        if total > MAX_READ_CHARS {
+            output.push_str(&format!(
+                "\n\n... truncated ({} of {} characters shown)",
+                MAX_READ_CHARS, total
+            ));
+        }
+
+        Ok(ToolResult::success(output).with_metadata("chars", json!(total)))
+    }
+}
+
+// ============================================================================
+// clipboard_write
+// ============================================================================
+
+/// 클립보드 쓰기 도구
+pub struct ClipboardWriteTool {
+    clipboard: Arc<Mutex<ClipboardManager>>,
+}
+
+impl ClipboardWriteTool {
+    pub const NAME: &'static str = "clipboard_write";
+
+    pub fn new() -> Self {
+        Self::with_manager(Arc::new(Mutex::new(ClipboardManager::new())))
+    }
+
+    /// 지정한 클립보드 매니저 사용
+    pub fn with_manager(clipboard: Arc<Mutex<ClipboardManager>>) -> Self {
+        Self { clipboard }
+    }
+}
+
+impl Default for ClipboardWriteTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for ClipboardWriteTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Clipboard Write")
+            .description("Place text (a snippet, command, etc.) on the user's clipboard so they can paste it. Replaces the current clipboard contents and requires the user's permission.")
+            .category("clipboard")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "text": {
+                    "type": "string",
+                    "description": "Text to copy to the clipboard"
+                }
+            },
+            "required": ["text"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        // 세션 허용이 이후 쓰기에도 적용되도록 내용은 details에 넣지 않음
+        Some(PermissionAction::Custom {
+            name: WRITE_PERMISSION.to_string(),
+            details: "write clipboard".to_string(),
+        })
+    }
+
+    async fn execute(&self, input: Value, context: &dyn ToolContext) -> Result<ToolResult> {
+        let Some(text) = input["text"].as_str() else {
+            return Ok(ToolResult::error("Missing required parameter: text"));
+        };
+        if text.len() > MAX_WRITE_BYTES {
+            return Ok(ToolResult::error(format!(
+                "Text too large for clipboard: {} bytes (max {})",
+                text.len(),
+                MAX_WRITE_BYTES
+            )));
+        }
+
+        if let Some(action) = self.required_permission(&input) {
+            let description = format!("Copy to clipboard: {}", preview(text));
+            if let Some(denied) =
+                check_permission(Self::NAME, action, &description, context).await?
+            {
+                return Ok(denied);
+            }
+        }
+
+        let copied = match self.clipboard.lock() {
+            Ok(mut clipboard) => clipboard.copy(text),
+            Err(_) => return Ok(ToolResult::error("Clipboard is unavailable")),
+        };
+        if let Err(e) = copied {
+            return Ok(ToolResult::error(e.to_string()));
+        }
+
+        let lines = text.lines().count();
+        Ok(ToolResult::success(format!(
+            "Copied {} characters ({} lines) to the clipboard",
+            text.chars().count(),
+            lines
+        ))
+        .with_metadata("bytes", json!(text.len())))
+    }
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge_core::RuntimeContext;
+    use forge_foundation::PermissionService;
+    use std::path::PathBuf;
+
+    fn ctx(permissions: PermissionService) -> RuntimeContext {
+        RuntimeContext::new("test", PathBuf::from("."), Arc::new(permissions))
+    }
+
+    fn detached() -> Arc<Mutex<ClipboardManager>> {
+        Arc::new(Mutex::new(ClipboardManager::detached()))
+    }
+
+    #[tokio::test]
+    async fn test_write_requires_permission() {
+        let tool = ClipboardWriteTool::with_manager(detached());
+        let result = tool
+            .execute(
+                json!({ "text": "cargo test" }),
+                &ctx(PermissionService::new()),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Permission denied"));
+
+        let read = ClipboardReadTool::with_manager(detached());
+        let result = read
+            .execute(json!({}), &ctx(PermissionService::new()))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Permission denied"));
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_clipboard() {
+        let tool = ClipboardWriteTool::with_manager(detached());
+        let result = tool
+            .execute(
+                json!({ "text": "cargo test" }),
+                &ctx(PermissionService::with_auto_approve()),
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("not available"));
+
+        let missing = tool
+            .execute(json!({}), &ctx(PermissionService::with_auto_approve()))
+            .await
+            .unwrap();
+        assert!(missing.error.unwrap().contains("text"));
+    }
+
+    #[test]
+    fn test_permission_preview() {
+        let tool = ClipboardWriteTool::with_manager(detached());
+        let first = tool.required_permission(&json!({ "text": "ls" }));
+        let second = tool.required_permission(&json!({ "text": "pwd" }));
+        assert_eq!(first, second);
+        assert!(matches!(
+            first,
+            Some(PermissionAction::Custom { ref name, .. }) if name == WRITE_PERMISSION
+        ));
+
+        let long = format!("{}\nsecond line", "x".repeat(200));
+        let shown = preview(&long);
+        assert_eq!(shown.chars().count(), PREVIEW_CHARS + 1);
+        assert!(shown.ends_with('…'));
+        assert_eq!(preview("ls -la"), "ls -la");
+        assert_eq!(preview("a\nb"), "a…");
+    }
+}
@@ -0,0 +1,203 @@
+//! Config schema commands
+//!
+//! `forge config schema` - 설정 파일 JSON Schema 생성
+//!
+//! 스키마는 각 설정 파일을 읽어들이는 Rust 타입에서 schemars로 생성합니다.
+//! 생성된 스키마는 `schemas/` 디렉토리에도 포함되어 있으며, 에디터에서
+//! `"$schema"` 키로 참조하면 자동 완성과 검증을 받을 수 있습니다.
+
+use std::path::{Path, PathBuf};
+
+use schemars::{schema_for, Schema};
+
+/// 스키마를 제공하는 설정 파일 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaKind {
+    /// `.forgecode/settings.json` (forge-core ForgeConfig)
+    Settings,
+    /// `~/.forgecode/config.json` (forge-foundation ForgeConfig)
+    Config,
+    /// `hooks.json`
+    Hooks,
+    /// `mcp.json`
+    Mcp,
+    /// `permissions.json`
+    Permissions,
+}
+
+impl SchemaKind {
+    /// 모든 종류
+    pub const ALL: [SchemaKind; 5] = [
+        SchemaKind::Settings,
+        SchemaKind::Config,
+        SchemaKind::Hooks,
+        SchemaKind::Mcp,
+        SchemaKind::Permissions,
+    ];
+
+    /// 이름으로 찾기 (`settings`, `hooks.json` 등)
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        let name = name.strip_suffix(".json").unwrap_or(&name);
+        Self::ALL.iter().copied().find(|kind| kind.name() == name)
+    }
+
+    /// 종류 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            SchemaKind::Settings => "settings",
+            SchemaKind::Config => "config",
+            SchemaKind::Hooks => "hooks",
+            SchemaKind::Mcp => "mcp",
+            SchemaKind::Permissions => "permissions",
+        }
+    }
+
+    /// 스키마가 기술하는 설정 파일명
+    pub fn config_file(&self) -> &'static str {
+        match self {
+            SchemaKind::Settings => "settings.json",
+            SchemaKind::Config => forge_foundation::FORGE_CONFIG_FILE,
+            SchemaKind::Hooks => "hooks.json",
+            SchemaKind::Mcp => forge_foundation::MCP_FILE,
+            SchemaKind::Permissions => forge_foundation::PERMISSIONS_FILE,
+        }
+    }
+
+    /// 스키마 파일명 (`schemas/` 내)
+    pub fn schema_file(&self) -> String {
+        format!("{}.schema.json", self.name())
+    }
+
+    /// Rust 타입에서 스키마 생성
+    pub fn generate(&self) -> Schema {
+        let mut schema = match self {
+            SchemaKind::Settings => schema_for!(forge_core::ForgeConfig),
+            SchemaKind::Config => schema_for!(forge_foundation::ForgeConfig),
+            SchemaKind::Hooks => schema_for!(forge_core::HookConfig),
+            SchemaKind::Mcp => schema_for!(forge_foundation::McpConfigFile),
+            SchemaKind::Permissions => schema_for!(forge_foundation::PermissionSettings),
+        };
+        schema.insert(
+            "title".to_string(),
+            format!("ForgeCode {}", self.config_file()).into(),
+        );
+        schema
+    }
+
+    /// 스키마 JSON 문자열 (끝 개행 포함)
+    pub fn render(&self) -> String {
+        let mut json = serde_json::to_string_pretty(&self.generate())
+            .expect("JSON Schema serialization cannot fail");
+        json.push('\n');
+        json
+    }
+}
+
+fn unknown_kind(name: &str) -> anyhow::Error {
+    let names: Vec<_> = SchemaKind::ALL.iter().map(|k| k.name()).collect();
+    anyhow::anyhow!(
+        "Unknown config kind '{}' (expected one of: {})",
+        name,
+        names.join(", ")
+    )
+}
+
+/// 기본 출력 디렉토리 (`.forgecode/schemas`)
+fn default_output_dir() -> PathBuf {
+    PathBuf::from(".forgecode").join("schemas")
+}
+
+fn write_schema(kind: SchemaKind, path: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, kind.render())?;
+    println!("  {} → {}", kind.config_file(), path.display());
+    Ok(())
+}
+
+/// `forge config schema`
+///
+/// - 종류 지정 + 출력 없음: 표준 출력으로 스키마 출력
+/// - 종류 지정 + 출력: 해당 파일에 저장
+/// - 종류 없음: 모든 스키마를 출력 디렉토리에 저장
+pub fn schema_cmd(kind: Option<&str>, output: Option<&Path>) -> anyhow::Result<()> {
+    match kind {
+        Some(name) => {
+            let kind = SchemaKind::parse(name).ok_or_else(|| unknown_kind(name))?;
+            match output {
+                Some(path) => write_schema(kind, path),
+                None => {
+                    print!("{}", kind.render());
+                    Ok(())
+                }
+            }
+        }
+        None => {
+            let dir = output
+                .map(Path::to_path_buf)
+                .unwrap_or_else(default_output_dir);
+            println!("\n📐 Writing config schemas to {}\n", dir.display());
+            for kind in SchemaKind::ALL {
+                write_schema(kind, &dir.join(kind.schema_file()))?;
+            }
+            println!(
+                "\nAdd \"$schema\": \"<path to schema>\" to a config file for editor autocomplete.\n"
+            );
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kind() {
+        assert_eq!(SchemaKind::parse("settings"), Some(SchemaKind::Settings));
+        assert_eq!(SchemaKind::parse("Hooks.json"), Some(SchemaKind::Hooks));
+        assert_eq!(
+            SchemaKind::parse("permissions"),
+            Some(SchemaKind::Permissions)
+        );
+        assert_eq!(SchemaKind::parse("bogus"), None);
+    }
+
+    #[test]
+    fn test_schema_describes_fields() {
+        let settings: serde_json::Value =
+            serde_json::from_str(&SchemaKind::Settings.render()).unwrap();
+        let props = settings["properties"].as_object().unwrap();
+        assert!(props.contains_key("model"));
+        assert!(props.contains_key("permissions"));
+
+        let mcp: serde_json::Value = serde_json::from_str(&SchemaKind::Mcp.render()).unwrap();
+        assert!(mcp["properties"]["mcpServers"].is_object());
+    }
+
+    /// 포함된 스키마가 타입과 일치하는지 확인
+    ///
+    /// 갱신: `FORGE_UPDATE_SCHEMAS=1 cargo test -p forge-cli shipped_schemas`
+    #[test]
+    fn test_shipped_schemas_up_to_date() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("schemas");
+        let update = std::env::var_os("FORGE_UPDATE_SCHEMAS").is_some();
+
+        for kind in SchemaKind::ALL {
+            let generated = kind.render();
+            if update {
+                std::fs::write(dir.join(kind.schema_file()), &generated).unwrap();
+            } else {
+                let shipped = std::fs::read_to_string(dir.join(kind.schema_file())).unwrap();
+                assert_eq!(
+                    shipped.replace("\r\n", "\n"),
+                    generated,
+                    "schemas/{} is stale; rerun with FORGE_UPDATE_SCHEMAS=1",
+                    kind.schema_file()
+                );
+            }
+        }
+    }
+}
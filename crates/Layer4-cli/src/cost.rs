@@ -263,14 +263,28 @@ impl CostTracker {
         output_tokens: u64,
         cached_tokens: u64,
     ) -> f64 {
+        self.pricing_for(model)
+            .map(|p| p.calculate(input_tokens, output_tokens, cached_tokens))
+            .unwrap_or(0.0)
+    }
+
+    /// 캐시 절감액 (캐시 토큰을 일반 입력 가격으로 냈을 경우와의 차이)
+    pub fn cache_savings(&self, model: &str, cached_tokens: u64) -> f64 {
+        self.pricing_for(model)
+            .and_then(|p| {
+                p.cached_price
+                    .map(|cached| (cached_tokens as f64 / 1_000_000.0) * (p.input_price - cached))
+            })
+            .unwrap_or(0.0)
+    }
+
+    fn pricing_for(&self, model: &str) -> Option<&ModelPricing> {
         // 모델 ID 정규화 (예: claude-3-sonnet-20240229 -> claude-3-sonnet)
         let normalized = self.normalize_model_id(model);
 
         self.pricing
             .get(&normalized)
             .or_else(|| self.pricing.get(model))
-            .map(|p| p.calculate(input_tokens, output_tokens, cached_tokens))
-            .unwrap_or(0.0)
     }
 
     fn normalize_model_id(&self, model: &str) -> String {
@@ -578,4 +592,15 @@ mod tests {
         assert_eq!(tracker.format_cost(0.1), "$0.100");
         assert_eq!(tracker.format_cost(1.5), "$1.50");
     }
+
+    #[test]
+    fn test_cache_savings() {
+        let tracker = CostTracker::new();
+
+        // claude-opus-4: $15 input vs $1.5 cached per 1M
+        let savings = tracker.cache_savings("claude-opus-4-20250514", 1_000_000);
+        assert!((savings - 13.5).abs() < 0.001);
+        assert_eq!(tracker.cache_savings("gemini-1.5-pro", 1_000_000), 0.0);
+        assert_eq!(tracker.cache_savings("unknown-model", 1_000_000), 0.0);
+    }
 }
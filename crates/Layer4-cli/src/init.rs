@@ -101,6 +101,7 @@ Create a git commit with a well-formatted message.
     println!("\n✓ ForgeCode initialized successfully!");
     println!("\nNext steps:");
     println!("  1. Edit .forgecode/FORGE.md with your project instructions");
+    println!("     (existing AGENTS.md, CLAUDE.md and .cursorrules are also loaded)");
     println!("  2. Configure .forgecode/settings.json for your provider");
     println!("  3. Run 'forge' to start the assistant");
 
@@ -1,19 +1,30 @@
 //! ForgeCode CLI - Main entry point
 
+mod audit;
 mod auto_config;
 mod cli;
 mod clipboard;
+mod clipboard_tool;
+mod config_schema;
 mod cost;
 mod diff;
 mod history;
 mod hooks;
 mod init;
 mod markdown;
+mod mcp_install;
+mod mcp_serve;
+mod permissions;
 mod project;
+mod registry;
+mod repomap;
+mod schedule;
 mod session;
 mod setup;
+mod stats;
 mod syntax;
 mod tui;
+mod worktree;
 
 // Re-exports
 pub use clipboard::ClipboardManager;
@@ -26,6 +37,7 @@ pub use session::SessionManager;
 pub use syntax::SyntaxHighlighter;
 
 use clap::{Parser, Subcommand};
+use forge_core::ConfigLoader;
 use forge_foundation::{provider_store, ProviderConfig, ProviderType};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -76,6 +88,24 @@ struct Args {
     /// Skip auto-initialization check
     #[arg(long)]
     no_init: bool,
+
+    /// Forward permission prompts to an approval endpoint (https://... or unix:/path);
+    /// with --prompt, requests that get no decision are denied
+    #[arg(long, value_name = "URL")]
+    permission_endpoint: Option<String>,
+
+    /// Seconds to wait for a decision from --permission-endpoint (default: 300)
+    #[arg(long, value_name = "SECS")]
+    permission_timeout: Option<u64>,
+
+    /// Safe mode: deny every write, execute and network action, whatever the grants
+    #[arg(long)]
+    read_only: bool,
+
+    /// Run the agent in an isolated git worktree on a new branch;
+    /// changes are left on that branch for review (diff/merge/PR)
+    #[arg(long)]
+    worktree: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -94,6 +124,301 @@ enum Command {
     },
     /// Continue the most recent session
     Continue,
+    /// Show usage statistics (sessions, tokens, cost, top tools)
+    Stats {
+        #[command(subcommand)]
+        view: Option<StatsCommand>,
+
+        /// Number of days to include (ending today or at --until)
+        #[arg(short, long, default_value_t = stats::DEFAULT_DAYS, global = true)]
+        days: u32,
+
+        /// Start date (YYYY-MM-DD)
+        #[arg(long, global = true)]
+        since: Option<String>,
+
+        /// End date, inclusive (YYYY-MM-DD)
+        #[arg(long, global = true)]
+        until: Option<String>,
+    },
+    /// Inspect and roll back dynamic tool/skill registry changes
+    Registry {
+        #[command(subcommand)]
+        action: RegistryCommand,
+    },
+    /// Configuration file utilities
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommand,
+    },
+    /// Permission audit log utilities
+    Audit {
+        #[command(subcommand)]
+        action: AuditCommand,
+    },
+    /// Share permission presets as signed bundles
+    Permissions {
+        #[command(subcommand)]
+        action: PermissionsCommand,
+    },
+    /// Model Context Protocol utilities
+    Mcp {
+        #[command(subcommand)]
+        action: McpCommand,
+    },
+    /// Print the repository map or export it as JSON, Graphviz DOT or Mermaid
+    Repomap {
+        /// Repository root (default: current directory)
+        path: Option<std::path::PathBuf>,
+
+        /// Output format: text, json, dot or mermaid (default: from --output extension, else text)
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Write the map to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+
+        /// Token budget for the text format: most relevant symbol signatures across files
+        /// (default: whole map)
+        #[arg(short, long)]
+        tokens: Option<usize>,
+
+        /// Rank files by relevance to this query as well as by the dependency graph
+        #[arg(short, long)]
+        query: Option<String>,
+    },
+    /// Recurring prompts and skills on a cron schedule
+    Schedule {
+        #[command(subcommand)]
+        action: ScheduleCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum StatsCommand {
+    /// Per-tool success rate, timeouts, retries and output size (most failures first)
+    Tools,
+}
+
+#[derive(Subcommand, Debug)]
+enum RegistryCommand {
+    /// Show snapshot history with diffs between consecutive states
+    History {
+        /// Registry to inspect (tools, skills)
+        #[arg(short, long, default_value = "tools")]
+        registry: String,
+
+        /// Number of entries to show
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+    },
+    /// Roll back to a snapshot by disabling plugins added or replaced after it
+    Rollback {
+        /// Snapshot ID or '#' index from `forge registry history` (default: previous state)
+        target: Option<String>,
+
+        /// Registry to roll back (tools, skills)
+        #[arg(short, long, default_value = "tools")]
+        registry: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ConfigCommand {
+    /// Emit JSON Schema for config files (settings, config, hooks, mcp, permissions)
+    Schema {
+        /// Config kind to emit (default: write all to the output directory)
+        kind: Option<String>,
+
+        /// Output file, or directory when no kind is given (default: .forgecode/schemas)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum AuditCommand {
+    /// Summarize permission decisions, risky commands and outside-repo file changes
+    Report {
+        /// Start of the period: 7d, 12h, 2w or YYYY-MM-DD
+        #[arg(long, default_value = audit::DEFAULT_SINCE)]
+        since: String,
+
+        /// End of the period (default: now)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Output format: text, json or html (default: from --output extension, else text)
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Write the report to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+
+        /// Repository root for outside-repo detection (default: each session's working directory)
+        #[arg(long)]
+        repo: Option<std::path::PathBuf>,
+
+        /// Minimum risk level (0-10) of commands listed as risky (default: 7)
+        #[arg(long)]
+        min_risk: Option<u8>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum PermissionsCommand {
+    /// Export permission settings as a signed bundle
+    Export {
+        /// Bundle file to write
+        output: std::path::PathBuf,
+
+        /// Preset name (e.g. "frontend-dev")
+        #[arg(short, long)]
+        name: String,
+
+        /// Preset description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Settings layer to export: user, project, local or merged
+        #[arg(long, default_value = "merged")]
+        from: String,
+
+        /// Signing key file (default: generated in the user config directory)
+        #[arg(long)]
+        key: Option<std::path::PathBuf>,
+    },
+    /// Verify a signed bundle and apply it to a settings layer
+    Import {
+        /// Bundle file to read
+        input: std::path::PathBuf,
+
+        /// Settings layer to write: user, project or local
+        #[arg(long, default_value = "project")]
+        to: String,
+
+        /// Trust this signer public key (base64) before importing
+        #[arg(long)]
+        trust: Option<String>,
+
+        /// Replace the layer instead of merging into it
+        #[arg(long)]
+        replace: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum McpCommand {
+    /// Serve builtin tools and the repository map to MCP clients; tool calls follow
+    /// the permission settings (use --read-only / --permission-endpoint before `mcp`)
+    Serve {
+        /// Transport: stdio or sse
+        #[arg(short, long, default_value = "stdio")]
+        transport: String,
+
+        /// Address to bind for the sse transport
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// Port for the sse transport
+        #[arg(short, long, default_value_t = mcp_serve::DEFAULT_PORT)]
+        port: u16,
+
+        /// Comma-separated tools to expose (default: read,grep,glob,edit,write,bash)
+        #[arg(long)]
+        tools: Option<String>,
+    },
+    /// List the curated MCP servers that `forge mcp add` can install
+    Catalog,
+    /// Install a curated MCP server: write mcp.json, verify the handshake and allow
+    /// its read-only tools
+    Add {
+        /// Server name from `forge mcp catalog`
+        name: String,
+
+        /// Name to save the server under (default: the catalog name)
+        #[arg(long = "as")]
+        alias: Option<String>,
+
+        /// Config to write: project (.forgecode/mcp.json) or user
+        #[arg(short, long, default_value = "project")]
+        scope: String,
+
+        /// Environment variable for the server (KEY=VALUE, repeatable)
+        #[arg(short, long)]
+        env: Vec<String>,
+
+        /// Allow every tool of the server instead of only read-only tools
+        #[arg(long)]
+        allow_all: bool,
+
+        /// Save without starting the server
+        #[arg(long)]
+        no_verify: bool,
+
+        /// Replace an existing entry with the same name
+        #[arg(short, long)]
+        force: bool,
+
+        /// Server arguments replacing the catalog defaults (after `--`)
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ScheduleCommand {
+    /// Add a schedule that runs a prompt or skill (e.g. "/update-deps") in a directory
+    Add {
+        /// Unique schedule name
+        name: String,
+
+        /// Prompt for the agent, or a skill invocation starting with '/'
+        prompt: String,
+
+        /// Cron expression ("0 3 * * *", "*/30 9-17 * * mon-fri", @daily, @hourly, ...)
+        #[arg(short, long)]
+        cron: String,
+
+        /// Directory to run in (default: current directory)
+        #[arg(short, long)]
+        dir: Option<std::path::PathBuf>,
+    },
+    /// List schedules with their next and last run
+    List,
+    /// Remove a schedule and its run history
+    Remove {
+        /// Schedule name or ID
+        name: String,
+    },
+    /// Resume a disabled schedule from now on
+    Enable {
+        /// Schedule name or ID
+        name: String,
+    },
+    /// Stop a schedule from firing without removing it
+    Disable {
+        /// Schedule name or ID
+        name: String,
+    },
+    /// Run a schedule once now
+    Run {
+        /// Schedule name or ID
+        name: String,
+    },
+    /// Show recent runs of a schedule
+    Runs {
+        /// Schedule name or ID
+        name: String,
+
+        /// Number of runs to show
+        #[arg(short, long, default_value = "10")]
+        limit: u32,
+    },
+    /// Run schedules when they are due until interrupted (Ctrl-C)
+    Daemon,
 }
 
 #[tokio::main]
@@ -111,10 +436,7 @@ async fn main() -> anyhow::Result<()> {
             }
             Command::Continue => {
                 // Continue most recent session
-                let data_dir = dirs::home_dir()
-                    .unwrap_or_else(|| std::path::PathBuf::from("."))
-                    .join(".forgecode");
-                if let Ok(storage) = forge_foundation::Storage::new(&data_dir) {
+                if let Ok(storage) = stats::open_storage() {
                     if let Ok(sessions) = storage.get_sessions(Some(1)) {
                         if let Some(session) = sessions.first() {
                             println!("Continuing session: {}", &session.id[..8.min(session.id.len())]);
@@ -126,6 +448,163 @@ async fn main() -> anyhow::Result<()> {
                 }
                 // Fall through to TUI
             }
+            Command::Stats {
+                view,
+                days,
+                since,
+                until,
+            } => {
+                return match view {
+                    Some(StatsCommand::Tools) => {
+                        stats::tools_cmd(days, since.as_deref(), until.as_deref())
+                    }
+                    None => stats::stats_cmd(days, since.as_deref(), until.as_deref()),
+                };
+            }
+            Command::Registry { action } => {
+                return match action {
+                    RegistryCommand::History { registry, limit } => {
+                        registry::history_cmd(&registry, limit)
+                    }
+                    RegistryCommand::Rollback { target, registry } => {
+                        registry::rollback_cmd(&registry, target.as_deref()).await
+                    }
+                };
+            }
+            Command::Config { action } => {
+                return match action {
+                    ConfigCommand::Schema { kind, output } => {
+                        config_schema::schema_cmd(kind.as_deref(), output.as_deref())
+                    }
+                };
+            }
+            Command::Audit { action } => {
+                return match action {
+                    AuditCommand::Report {
+                        since,
+                        until,
+                        format,
+                        output,
+                        repo,
+                        min_risk,
+                    } => {
+                        audit::report_cmd(
+                            &since,
+                            until.as_deref(),
+                            format.as_deref(),
+                            output.as_deref(),
+                            repo,
+                            min_risk,
+                        )
+                        .await
+                    }
+                };
+            }
+            Command::Permissions { action } => {
+                return match action {
+                    PermissionsCommand::Export {
+                        output,
+                        name,
+                        description,
+                        from,
+                        key,
+                    } => permissions::export_cmd(
+                        &output,
+                        &name,
+                        description.as_deref(),
+                        &from,
+                        key.as_deref(),
+                    ),
+                    PermissionsCommand::Import {
+                        input,
+                        to,
+                        trust,
+                        replace,
+                    } => permissions::import_cmd(&input, &to, trust.as_deref(), replace),
+                };
+            }
+            Command::Mcp { action } => {
+                return match action {
+                    McpCommand::Serve {
+                        transport,
+                        host,
+                        port,
+                        tools,
+                    } => {
+                        mcp_serve::serve_cmd(
+                            &transport,
+                            &host,
+                            port,
+                            tools.as_deref(),
+                            args.read_only,
+                            args.permission_endpoint.as_deref(),
+                            args.permission_timeout,
+                        )
+                        .await
+                    }
+                    McpCommand::Catalog => mcp_install::catalog_cmd(),
+                    McpCommand::Add {
+                        name,
+                        alias,
+                        scope,
+                        env,
+                        allow_all,
+                        no_verify,
+                        force,
+                        args,
+                    } => {
+                        mcp_install::add_cmd(
+                            &name,
+                            alias.as_deref(),
+                            &scope,
+                            &args,
+                            &env,
+                            allow_all,
+                            no_verify,
+                            force,
+                        )
+                        .await
+                    }
+                };
+            }
+            Command::Repomap {
+                path,
+                format,
+                output,
+                tokens,
+                query,
+            } => {
+                return repomap::repomap_cmd(
+                    path,
+                    format.as_deref(),
+                    output.as_deref(),
+                    tokens,
+                    query.as_deref(),
+                )
+                .await;
+            }
+            Command::Schedule { action } => {
+                let options = schedule::RunOptions {
+                    permission_endpoint: args.permission_endpoint.clone(),
+                    permission_timeout: args.permission_timeout,
+                    read_only: args.read_only,
+                };
+                return match action {
+                    ScheduleCommand::Add {
+                        name,
+                        prompt,
+                        cron,
+                        dir,
+                    } => schedule::add_cmd(&name, &cron, &prompt, dir),
+                    ScheduleCommand::List => schedule::list_cmd(),
+                    ScheduleCommand::Remove { name } => schedule::remove_cmd(&name),
+                    ScheduleCommand::Enable { name } => schedule::set_enabled_cmd(&name, true),
+                    ScheduleCommand::Disable { name } => schedule::set_enabled_cmd(&name, false),
+                    ScheduleCommand::Run { name } => schedule::run_cmd(&name, options).await,
+                    ScheduleCommand::Runs { name, limit } => schedule::runs_cmd(&name, limit),
+                    ScheduleCommand::Daemon => schedule::daemon_cmd(options).await,
+                };
+            }
         }
     }
 
@@ -231,24 +710,49 @@ async fn main() -> anyhow::Result<()> {
         tracing::info!("Using API key from command line for provider: anthropic");
     }
 
+    // Network egress rules (`security.network`) for tools, MCP servers and providers
+    if let Ok(settings) = ConfigLoader::new(&std::env::current_dir()?).load_all() {
+        forge_foundation::set_egress_policy(settings.security.network.egress_policy());
+    }
+
+    // Isolated worktree: the agent works on its own branch, the user's tree stays as is
+    let worktree_run = if args.worktree {
+        let task = args
+            .prompt
+            .as_deref()
+            .unwrap_or("forge: interactive session");
+        Some(worktree::WorktreeRun::start(task)?)
+    } else {
+        None
+    };
+
     // Run based on mode
-    if let Some(prompt) = args.prompt {
+    let result = if let Some(prompt) = args.prompt {
         // Non-interactive mode
-        cli::run_once(&config, &prompt).await?;
+        let approvals =
+            cli::remote_approvals(args.permission_endpoint.as_deref(), args.permission_timeout);
+        match approvals {
+            Ok(approvals) => cli::run_once(&config, &prompt, approvals, args.read_only)
+                .await
+                .map_err(anyhow::Error::from),
+            Err(e) => Err(e.into()),
+        }
     } else {
         // Interactive TUI mode
-        tui::run(&config).await?;
+        tui::run(&config, args.read_only).await
+    };
+
+    // Keep whatever the agent did, even when the run failed
+    if let Some(run) = worktree_run {
+        run.finish()?;
     }
 
-    Ok(())
+    result
 }
 
 /// List recent sessions
 fn list_sessions_cmd(limit: usize) -> anyhow::Result<()> {
-    let data_dir = dirs::home_dir()
-        .unwrap_or_else(|| std::path::PathBuf::from("."))
-        .join(".forgecode");
-    let storage = forge_foundation::Storage::new(&data_dir)?;
+    let storage = stats::open_storage()?;
     let sessions = storage.get_sessions(Some(limit as u32))?;
 
     if sessions.is_empty() {
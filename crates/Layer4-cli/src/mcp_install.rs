@@ -0,0 +1,464 @@
+//! MCP server installer
+//!
+//! `forge mcp catalog` - 큐레이션된 MCP 서버 목록
+//! `forge mcp add <name>` - 카탈로그의 실행 사양(npx/uvx/docker)으로 `mcp.json` 항목을
+//! 작성하고, 핸드셰이크로 서버가 실제로 뜨는지 확인한 뒤 권한을 등록
+//!
+//! 비밀 값(API 토큰 등)은 `mcp.json`에 쓰지 않고 `${VAR}` 참조로 남깁니다.
+//! 권한은 조회성 도구(`get_*`, `list_*` ...)만 허용하고, 나머지는 계속 확인을 거칩니다.
+//! `--allow-all`이면 서버 전체(`mcp.<server>.*`)를 허용합니다.
+
+use anyhow::{bail, Context, Result};
+use forge_core::{McpClient, McpTransportConfig};
+use forge_foundation::permission::{
+    mcp_action, mcp_permission_name, mcp_tool_risk, PermissionActionType, PermissionGrant,
+    PermissionSettings,
+};
+use forge_foundation::{McpConfig, McpServer};
+use std::time::Duration;
+
+use crate::permissions::Layer;
+
+/// 조회성 도구로 보는 최대 위험도 (`mcp_tool_risk`)
+const READ_ONLY_RISK: u8 = 2;
+
+/// 서버 실행 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Launcher {
+    /// npm 패키지 (`npx -y <package>`)
+    Npm,
+    /// Python 패키지 (`uvx <package>`)
+    Uvx,
+    /// 컨테이너 이미지 (`docker run -i --rm <image>`)
+    Docker,
+}
+
+impl Launcher {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Npm => "npm",
+            Self::Uvx => "uvx",
+            Self::Docker => "docker",
+        }
+    }
+}
+
+/// 카탈로그 항목
+#[derive(Debug, Clone, Copy)]
+pub struct CatalogEntry {
+    /// 서버 이름 (`mcp.json` 키)
+    pub name: &'static str,
+    /// 설명
+    pub description: &'static str,
+    /// 실행 방식
+    pub launcher: Launcher,
+    /// 패키지 또는 이미지
+    pub package: &'static str,
+    /// 기본 인자 (`--` 뒤 인자로 대체)
+    pub args: &'static [&'static str],
+    /// 필요한 환경 변수
+    pub env: &'static [&'static str],
+}
+
+/// 큐레이션된 MCP 서버 목록
+pub const CATALOG: &[CatalogEntry] = &[
+    CatalogEntry {
+        name: "filesystem",
+        description: "Read and write files under the given directories",
+        launcher: Launcher::Npm,
+        package: "@modelcontextprotocol/server-filesystem",
+        args: &["."],
+        env: &[],
+    },
+    CatalogEntry {
+        name: "memory",
+        description: "Knowledge-graph memory that persists across sessions",
+        launcher: Launcher::Npm,
+        package: "@modelcontextprotocol/server-memory",
+        args: &[],
+        env: &[],
+    },
+    CatalogEntry {
+        name: "sequential-thinking",
+        description: "Step-by-step problem solving with revisable thoughts",
+        launcher: Launcher::Npm,
+        package: "@modelcontextprotocol/server-sequential-thinking",
+        args: &[],
+        env: &[],
+    },
+    CatalogEntry {
+        name: "playwright",
+        description: "Drive a browser: navigate, click, fill forms, take snapshots",
+        launcher: Launcher::Npm,
+        package: "@playwright/mcp@latest",
+        args: &[],
+        env: &[],
+    },
+    CatalogEntry {
+        name: "brave-search",
+        description: "Web and local search through the Brave Search API",
+        launcher: Launcher::Npm,
+        package: "@modelcontextprotocol/server-brave-search",
+        args: &[],
+        env: &["BRAVE_API_KEY"],
+    },
+    CatalogEntry {
+        name: "fetch",
+        description: "Fetch web pages and convert them to markdown",
+        launcher: Launcher::Uvx,
+        package: "mcp-server-fetch",
+        args: &[],
+        env: &[],
+    },
+    CatalogEntry {
+        name: "git",
+        description: "Inspect and operate on a local git repository",
+        launcher: Launcher::Uvx,
+        package: "mcp-server-git",
+        args: &["--repository", "."],
+        env: &[],
+    },
+    CatalogEntry {
+        name: "time",
+        description: "Current time and timezone conversion",
+        launcher: Launcher::Uvx,
+        package: "mcp-server-time",
+        args: &[],
+        env: &[],
+    },
+    CatalogEntry {
+        name: "github",
+        description: "GitHub repositories, issues and pull requests",
+        launcher: Launcher::Docker,
+        package: "ghcr.io/github/github-mcp-server",
+        args: &[],
+        env: &["GITHUB_PERSONAL_ACCESS_TOKEN"],
+    },
+];
+
+/// 카탈로그 조회 (대소문자 무시)
+pub fn find(name: &str) -> Option<&'static CatalogEntry> {
+    CATALOG.iter().find(|e| e.name.eq_ignore_ascii_case(name))
+}
+
+impl CatalogEntry {
+    /// `mcp.json`에 쓸 서버 설정
+    ///
+    /// `args`가 비어 있으면 기본 인자를 사용합니다. 필요한 환경 변수는
+    /// `env`로 값을 주지 않으면 `${VAR}` 참조로 남깁니다.
+    pub fn server(&self, args: &[String], env: &[(String, String)]) -> McpServer {
+        let args: Vec<String> = if args.is_empty() {
+            self.args.iter().map(|a| a.to_string()).collect()
+        } else {
+            args.to_vec()
+        };
+
+        let mut server = match self.launcher {
+            Launcher::Npm => McpServer::stdio("npx").arg("-y").arg(self.package),
+            Launcher::Uvx => McpServer::stdio("uvx").arg(self.package),
+            Launcher::Docker => {
+                // 컨테이너에는 이름만 넘기고 값은 서버 프로세스 환경에서 상속
+                let mut server = McpServer::stdio("docker").arg("run").arg("-i").arg("--rm");
+                for var in self.env {
+                    server = server.arg("-e").arg(*var);
+                }
+                server.arg(self.package)
+            }
+        };
+        for arg in args {
+            server = server.arg(arg);
+        }
+
+        for var in self.env {
+            server = server.env(*var, format!("${{{}}}", var));
+        }
+        for (key, value) in env {
+            server = server.env(key.clone(), value.clone());
+        }
+        server
+    }
+
+    /// 설정되지 않은 필수 환경 변수
+    pub fn missing_env(&self, env: &[(String, String)]) -> Vec<&'static str> {
+        self.env
+            .iter()
+            .copied()
+            .filter(|var| !env.iter().any(|(key, _)| key == var))
+            .filter(|var| std::env::var(var).map_or(true, |v| v.is_empty()))
+            .collect()
+    }
+}
+
+/// `KEY=VALUE` 파싱
+pub fn parse_env(values: &[String]) -> Result<Vec<(String, String)>> {
+    values
+        .iter()
+        .map(|value| match value.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.to_string()))
+            }
+            _ => bail!("Invalid --env '{}' (expected KEY=VALUE)", value),
+        })
+        .collect()
+}
+
+/// 핸드셰이크 확인용 전송 설정 (`${VAR}` 확장)
+fn transport_config(server: &McpServer) -> McpTransportConfig {
+    McpTransportConfig::Stdio {
+        command: server.command.clone().unwrap_or_default(),
+        args: server.args.clone(),
+        env: server.expand_env(),
+    }
+}
+
+/// 서버를 띄워 핸드셰이크 후 도구 목록 반환
+async fn verify(name: &str, server: &McpServer) -> Result<Vec<(String, Option<String>)>> {
+    let mut client = McpClient::new(name);
+    let config = transport_config(server);
+    // 첫 실행은 패키지/이미지 다운로드가 포함되므로 넉넉하게
+    let timeout = Duration::from_secs(server.timeout_secs.max(1) * 4);
+
+    tokio::time::timeout(timeout, client.connect(&config))
+        .await
+        .map_err(|_| anyhow::anyhow!("handshake timed out after {}s", timeout.as_secs()))??;
+
+    let tools = client
+        .tools()
+        .await
+        .into_iter()
+        .map(|tool| (tool.name, tool.description))
+        .collect();
+    let _ = client.disconnect().await;
+    Ok(tools)
+}
+
+/// 등록할 허용 항목 (`allow_all`이면 서버 전체, 아니면 조회성 도구만)
+pub fn permission_grants(
+    server: &str,
+    tools: &[(String, Option<String>)],
+    allow_all: bool,
+) -> PermissionSettings {
+    let mut settings = PermissionSettings::new();
+    if allow_all {
+        settings.allow_mcp_server(server);
+        return settings;
+    }
+
+    for (tool, _) in tools {
+        if mcp_tool_risk(tool) <= READ_ONLY_RISK {
+            settings.add_grant(PermissionGrant {
+                tool: mcp_permission_name(server, tool),
+                action_type: PermissionActionType::from(&mcp_action(server, tool)),
+                pattern: None,
+            });
+        }
+    }
+    settings
+}
+
+/// `forge mcp catalog`
+pub fn catalog_cmd() -> Result<()> {
+    let width = CATALOG.iter().map(|e| e.name.len()).max().unwrap_or(0);
+    println!("Available MCP servers (install with `forge mcp add <name>`):\n");
+    for entry in CATALOG {
+        println!(
+            "  {:<width$}  {:<6}  {}",
+            entry.name,
+            entry.launcher.as_str(),
+            entry.description
+        );
+        if !entry.env.is_empty() {
+            println!(
+                "  {:<width$}          requires {}",
+                "",
+                entry.env.join(", ")
+            );
+        }
+    }
+    Ok(())
+}
+
+/// `forge mcp add`
+#[allow(clippy::too_many_arguments)]
+pub async fn add_cmd(
+    name: &str,
+    alias: Option<&str>,
+    scope: &str,
+    args: &[String],
+    env: &[String],
+    allow_all: bool,
+    no_verify: bool,
+    force: bool,
+) -> Result<()> {
+    let Some(entry) = find(name) else {
+        let names: Vec<_> = CATALOG.iter().map(|e| e.name).collect();
+        bail!(
+            "Unknown MCP server '{}' (available: {})",
+            name,
+            names.join(", ")
+        );
+    };
+    let server_name = alias.unwrap_or(entry.name);
+    let layer = match Layer::parse(scope)? {
+        Layer::Merged | Layer::Local => {
+            bail!("Unknown scope '{}' (expected project or user)", scope)
+        }
+        layer => layer,
+    };
+
+    let mut config = match layer {
+        Layer::User => McpConfig::load_global()?,
+        _ => McpConfig::load_project()?,
+    };
+    if config.contains(server_name) && !force {
+        bail!(
+            "MCP server '{}' is already configured; use --force to replace it",
+            server_name
+        );
+    }
+
+    let env = parse_env(env)?;
+    let server = entry.server(args, &env);
+    let missing = entry.missing_env(&env);
+
+    let tools = if no_verify {
+        None
+    } else if !missing.is_empty() {
+        bail!(
+            "{} requires {}; export it or pass --env {}=<value> (or skip the check with --no-verify)",
+            entry.name,
+            missing.join(", "),
+            missing[0]
+        );
+    } else {
+        println!("Starting {} to verify the handshake...", server_name);
+        let tools = verify(server_name, &server).await.with_context(|| {
+            format!(
+                "{} did not complete the MCP handshake (is `{}` installed? skip the check with --no-verify)",
+                server_name,
+                server.command.as_deref().unwrap_or_default()
+            )
+        })?;
+        println!("✓ Handshake ok ({} tools)", tools.len());
+        Some(tools)
+    };
+
+    config.add(server_name, server);
+    match layer {
+        Layer::User => config.save_global()?,
+        _ => config.save_project()?,
+    }
+    println!("✓ Added '{}' to {:?} mcp.json", server_name, layer);
+
+    let grants = permission_grants(server_name, tools.as_deref().unwrap_or_default(), allow_all);
+    if !grants.grants.is_empty() {
+        let mut settings = layer.load()?;
+        let count = grants.grants.len();
+        settings.merge(grants);
+        layer.save(&settings)?;
+        if allow_all {
+            println!("✓ Allowed every tool of '{}'", server_name);
+        } else {
+            println!(
+                "✓ Allowed {} read-only tools; other tools still ask first",
+                count
+            );
+        }
+    } else if !allow_all {
+        println!("  Tools still ask before running; use --allow-all to trust the whole server");
+    }
+    if no_verify && !missing.is_empty() {
+        println!("  Set {} before starting forge", missing.join(", "));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("GitHub").unwrap().launcher, Launcher::Docker);
+        assert!(find("nonexistent").is_none());
+    }
+
+    #[test]
+    fn test_server_launch_specs() {
+        let fs = find("filesystem").unwrap().server(&[], &[]);
+        assert_eq!(fs.command.as_deref(), Some("npx"));
+        assert_eq!(
+            fs.args,
+            vec!["-y", "@modelcontextprotocol/server-filesystem", "."]
+        );
+
+        let git = find("git")
+            .unwrap()
+            .server(&["--repository".into(), "/repo".into()], &[]);
+        assert_eq!(git.command.as_deref(), Some("uvx"));
+        assert_eq!(git.args, vec!["mcp-server-git", "--repository", "/repo"]);
+
+        let github = find("github").unwrap().server(&[], &[]);
+        assert_eq!(
+            github.args,
+            vec![
+                "run",
+                "-i",
+                "--rm",
+                "-e",
+                "GITHUB_PERSONAL_ACCESS_TOKEN",
+                "ghcr.io/github/github-mcp-server"
+            ]
+        );
+        // 비밀 값은 참조로만
+        assert_eq!(
+            github.env["GITHUB_PERSONAL_ACCESS_TOKEN"],
+            "${GITHUB_PERSONAL_ACCESS_TOKEN}"
+        );
+        assert!(github.validate().is_ok());
+    }
+
+    #[test]
+    fn test_parse_env_and_missing() {
+        let env = parse_env(&["BRAVE_API_KEY=abc=1".to_string()]).unwrap();
+        assert_eq!(
+            env,
+            vec![("BRAVE_API_KEY".to_string(), "abc=1".to_string())]
+        );
+        assert!(parse_env(&["=x".to_string()]).is_err());
+
+        let brave = find("brave-search").unwrap();
+        assert!(brave.missing_env(&env).is_empty());
+        assert_eq!(brave.server(&[], &env).env["BRAVE_API_KEY"], "abc=1");
+    }
+
+    #[test]
+    fn test_permission_grants() {
+        let tools = vec![
+            ("search_issues".to_string(), None),
+            ("get_issue".to_string(), None),
+            ("create_issue".to_string(), None),
+            ("delete_repo".to_string(), None),
+        ];
+
+        let grants = permission_grants("github", &tools, false);
+        let mut names: Vec<_> = grants.grants.iter().map(|g| g.tool.as_str()).collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec!["mcp.github.get_issue", "mcp.github.search_issues"]
+        );
+        assert!(grants.is_granted("mcp_github_get_issue", &mcp_action("github", "get_issue")));
+        assert!(!grants.is_granted(
+            "mcp_github_create_issue",
+            &mcp_action("github", "create_issue")
+        ));
+
+        let all = permission_grants("github", &tools, true);
+        assert_eq!(all.grants.len(), 1);
+        assert!(all.is_granted(
+            "mcp_github_delete_repo",
+            &mcp_action("github", "delete_repo")
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+//! MCP server mode
+//!
+//! `forge mcp serve` - builtin 도구(read/grep/edit/bash ...)와 Repository Map을
+//! MCP로 노출해 Claude Desktop, IDE 같은 MCP 클라이언트가 ForgeCode를 백엔드로 사용
+//!
+//! 도구 호출은 대화형 세션과 같은 권한 설정을 따릅니다. 확인이 필요한 호출은
+//! `--permission-endpoint`로 보낼 수 있고, 없으면 거부됩니다. `--read-only`로
+//! 쓰기/실행/네트워크를 모두 막을 수 있습니다.
+//!
+//! SSE 모드는 시작할 때 출력하는 bearer 토큰이 있어야 접속할 수 있습니다.
+//!
+//! stdio 모드에서는 stdout이 프로토콜 전용이므로 로그는 stderr로만 출력합니다.
+
+use anyhow::{bail, Context, Result};
+use forge_core::mcp::{generate_sse_token, DEFAULT_SERVED_TOOLS};
+use forge_core::{AgentContext, ConfigLoader, McpServer, ToolTimeouts};
+use forge_foundation::audit::AuditLogger;
+use forge_foundation::permission::PermissionService;
+use forge_foundation::PermissionDelegate;
+use std::sync::Arc;
+
+use crate::cli;
+use crate::stats;
+
+/// 기본 SSE 포트
+pub const DEFAULT_PORT: u16 = 8765;
+
+/// MCP 전송 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    Stdio,
+    Sse,
+}
+
+impl Transport {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "stdio" => Ok(Self::Stdio),
+            "sse" | "http" => Ok(Self::Sse),
+            other => bail!("Unknown MCP transport '{}' (expected stdio or sse)", other),
+        }
+    }
+}
+
+/// `--tools` 값 파싱 (쉼표 구분, 없으면 기본 도구)
+pub fn parse_tools(tools: Option<&str>) -> Vec<String> {
+    match tools {
+        Some(list) => list
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(String::from)
+            .collect(),
+        None => DEFAULT_SERVED_TOOLS.iter().map(|t| t.to_string()).collect(),
+    }
+}
+
+/// `forge mcp serve`
+#[allow(clippy::too_many_arguments)]
+pub async fn serve_cmd(
+    transport: &str,
+    host: &str,
+    port: u16,
+    tools: Option<&str>,
+    read_only: bool,
+    permission_endpoint: Option<&str>,
+    permission_timeout: Option<u64>,
+) -> Result<()> {
+    let transport = Transport::parse(transport)?;
+    let working_dir = std::env::current_dir()?;
+    let config = ConfigLoader::new(&working_dir)
+        .load_all()
+        .unwrap_or_default();
+
+    let permissions = PermissionService::load()?;
+    if read_only {
+        permissions.set_read_only(true);
+    }
+
+    let mut builder = AgentContext::builder()
+        .working_directory(working_dir.clone())
+        .session_id(format!("mcp-{}", uuid::Uuid::new_v4()))
+        .with_permission_service(Arc::new(permissions))
+        .with_tool_timeouts(ToolTimeouts::from_config(&config.tools));
+    if config.security.audit.enabled {
+        match AuditLogger::new() {
+            Ok(mut logger) => {
+                // 공유 저장소(Postgres)가 설정되어 있으면 감사 기록을 복제
+                match stats::open_shared_storage() {
+                    Ok(Some(storage)) => logger = logger.with_backend(storage),
+                    Ok(None) => {}
+                    Err(e) => eprintln!("Warning: Failed to open shared storage: {}", e),
+                }
+                builder = builder.with_audit_logger(Arc::new(logger));
+            }
+            Err(e) => eprintln!("Warning: Failed to open the audit log: {}", e),
+        }
+    }
+    if let Some(delegate) = cli::remote_approvals(permission_endpoint, permission_timeout)? {
+        eprintln!("Permission requests go to {}", delegate.endpoint());
+        let delegate: Arc<dyn PermissionDelegate> = Arc::new(delegate);
+        builder = builder.with_permission_delegate(delegate);
+    }
+
+    let context = Arc::new(builder.build());
+    let server = McpServer::new(context).with_tools(parse_tools(tools));
+    for name in server.tool_names() {
+        if !server.context().has_tool(name).await {
+            bail!("Unknown tool '{}'", name);
+        }
+    }
+    let server = Arc::new(server);
+
+    eprintln!(
+        "ForgeCode MCP server ({}) in {}{}",
+        server.tool_names().join(", "),
+        working_dir.display(),
+        if read_only { " [read-only]" } else { "" }
+    );
+    match transport {
+        Transport::Stdio => server.serve_stdio().await?,
+        Transport::Sse => {
+            let listener = tokio::net::TcpListener::bind((host, port))
+                .await
+                .with_context(|| format!("Failed to listen on {}:{}", host, port))?;
+            let token = generate_sse_token();
+            eprintln!("Listening on http://{}/sse", listener.local_addr()?);
+            eprintln!("Authorization: Bearer {}", token);
+            server.serve_sse(listener, token).await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(Transport::parse("SSE").unwrap(), Transport::Sse);
+        assert!(Transport::parse("websocket").is_err());
+        assert_eq!(parse_tools(Some("read, grep,")), vec!["read", "grep"]);
+        assert_eq!(parse_tools(None).len(), DEFAULT_SERVED_TOOLS.len());
+    }
+}
@@ -0,0 +1,144 @@
+//! Permission preset bundles
+//!
+//! `forge permissions export` - 권한 설정을 서명된 번들로 내보내기
+//! `forge permissions import` - 번들 서명/서명자 확인 후 설정 레이어에 적용
+//!
+//! 서명 키는 처음 내보낼 때 `~/.config/forgecode/bundle-signing.key`에 생성되며,
+//! 받는 쪽은 출력된 공개 키를 `--trust`로 한 번 등록하면 됩니다.
+
+use anyhow::{bail, Context, Result};
+use forge_foundation::permission::{BundleSigningKey, PermissionSettings, TrustedBundleKeys};
+use std::path::Path;
+
+/// 설정 레이어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer {
+    /// 사용자 + 프로젝트 + 로컬 병합 (내보내기 전용)
+    Merged,
+    User,
+    Project,
+    Local,
+}
+
+impl Layer {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "merged" | "all" => Ok(Self::Merged),
+            "user" | "global" => Ok(Self::User),
+            "project" => Ok(Self::Project),
+            "local" => Ok(Self::Local),
+            other => bail!(
+                "Unknown permission layer '{}' (expected user, project, local or merged)",
+                other
+            ),
+        }
+    }
+
+    pub(crate) fn load(self) -> Result<PermissionSettings> {
+        Ok(match self {
+            Self::Merged => PermissionSettings::load()?,
+            Self::User => PermissionSettings::load_global()?,
+            Self::Project => PermissionSettings::load_project()?,
+            Self::Local => PermissionSettings::load_local()?,
+        })
+    }
+
+    pub(crate) fn save(self, settings: &PermissionSettings) -> Result<()> {
+        match self {
+            Self::Merged => {
+                bail!("Cannot import into merged settings; choose user, project or local")
+            }
+            Self::User => settings.save_global()?,
+            Self::Project => settings.save_project()?,
+            Self::Local => settings.save_local()?,
+        }
+        Ok(())
+    }
+}
+
+/// `forge permissions export`
+pub fn export_cmd(
+    output: &Path,
+    name: &str,
+    description: Option<&str>,
+    from: &str,
+    key: Option<&Path>,
+) -> Result<()> {
+    let settings = Layer::parse(from)?.load()?;
+    let key_path = match key {
+        Some(path) => path.to_path_buf(),
+        None => BundleSigningKey::default_path()?,
+    };
+    let key = BundleSigningKey::load_or_generate(&key_path)?;
+
+    let bundle = settings.export_bundle(name, description.unwrap_or_default(), &key)?;
+    std::fs::write(output, bundle.to_json()?)
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+
+    println!(
+        "✓ Exported '{}' ({} grants, {} denies) to {}",
+        bundle.name,
+        settings.grants.len(),
+        settings.denies.len(),
+        output.display()
+    );
+    println!("  Signing key: {}", key_path.display());
+    println!("  Public key:  {}", key.public_key());
+    println!(
+        "  Recipients trust it once with: forge permissions import <file> --trust <public key>"
+    );
+    Ok(())
+}
+
+/// `forge permissions import`
+pub fn import_cmd(input: &Path, to: &str, trust: Option<&str>, replace: bool) -> Result<()> {
+    let layer = Layer::parse(to)?;
+    let json = std::fs::read_to_string(input)
+        .with_context(|| format!("Failed to read {}", input.display()))?;
+
+    let mut trusted = TrustedBundleKeys::load()?;
+    if let Some(key) = trust {
+        if trusted.trust(key) {
+            trusted.save()?;
+            println!("✓ Trusted bundle key {}", key.trim());
+        }
+    }
+
+    let bundle = PermissionSettings::import_bundle(&json, &trusted)?;
+
+    let settings = if replace {
+        bundle.settings.clone()
+    } else {
+        let mut settings = layer.load()?;
+        settings.merge(bundle.settings.clone());
+        settings
+    };
+    layer.save(&settings)?;
+
+    println!(
+        "✓ {} '{}' into {:?} permissions ({} grants, {} denies)",
+        if replace { "Applied" } else { "Merged" },
+        bundle.name,
+        layer,
+        settings.grants.len(),
+        settings.denies.len()
+    );
+    if !bundle.description.is_empty() {
+        println!("  {}", bundle.description);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_layer() {
+        assert_eq!(Layer::parse("project").unwrap(), Layer::Project);
+        assert_eq!(Layer::parse("Global").unwrap(), Layer::User);
+        assert_eq!(Layer::parse("merged").unwrap(), Layer::Merged);
+        assert!(Layer::parse("managed").is_err());
+        assert!(Layer::Merged.save(&PermissionSettings::new()).is_err());
+    }
+}
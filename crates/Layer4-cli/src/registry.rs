@@ -0,0 +1,111 @@
+//! Registry history commands
+//!
+//! `forge registry history` / `forge registry rollback` - 동적 레지스트리 저널 조회 및 롤백
+//!
+//! 세션 중 레지스트리 상태는 메모리에 있으므로, CLI 롤백은 저널에 기록된
+//! 제공자(plugin ID) 정보를 이용해 문제의 시점 이후 추가/교체된 플러그인을 비활성화합니다.
+//! 다음 세션부터 해당 플러그인은 로드되지 않습니다.
+
+use forge_core::plugin::PluginStore;
+use forge_core::{SnapshotJournal, SnapshotRecord};
+
+/// 레지스트리 이름 검증 후 저널 반환
+fn journal_for(registry: &str) -> anyhow::Result<SnapshotJournal> {
+    if registry != "tools" && registry != "skills" {
+        anyhow::bail!("Unknown registry '{}' (expected 'tools' or 'skills')", registry);
+    }
+
+    SnapshotJournal::for_registry(registry)
+        .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))
+}
+
+/// 스냅샷 ID 또는 `history` 목록의 번호로 레코드 찾기
+fn resolve_target<'a>(records: &'a [SnapshotRecord], target: &str) -> Option<&'a SnapshotRecord> {
+    if let Ok(index) = target.trim_start_matches('#').parse::<usize>() {
+        if let Some(record) = records.get(index) {
+            return Some(record);
+        }
+    }
+    records.iter().find(|r| r.info.id == target)
+}
+
+/// `forge registry history`
+pub fn history_cmd(registry: &str, limit: usize) -> anyhow::Result<()> {
+    let journal = journal_for(registry)?;
+    let records = journal.load()?;
+
+    if records.is_empty() {
+        println!("No history recorded for '{}' registry.", registry);
+        println!("(journal: {})", journal.path().display());
+        return Ok(());
+    }
+
+    println!("\n🕘 Registry History ({})\n", registry);
+    println!("{:<4} {:<28} {:<8} {}", "#", "Snapshot", "Entries", "Change");
+    println!("{}", "-".repeat(80));
+
+    let start = records.len().saturating_sub(limit);
+    for (i, record) in records.iter().enumerate().skip(start) {
+        let action = record.info.description.as_deref().unwrap_or("-");
+        let change = match i.checked_sub(1).and_then(|p| records.get(p)) {
+            Some(prev) => prev.diff(record).summary(),
+            None => "(first recorded state)".to_string(),
+        };
+        println!(
+            "{:<4} {:<28} {:<8} {} — {}",
+            i, record.info.id, record.info.entry_count, action, change
+        );
+    }
+
+    println!("\nUse 'forge registry rollback <#|ID>' to disable plugins added after a snapshot.\n");
+    Ok(())
+}
+
+/// `forge registry rollback`
+pub async fn rollback_cmd(registry: &str, target: Option<&str>) -> anyhow::Result<()> {
+    let journal = journal_for(registry)?;
+    let records = journal.load()?;
+
+    let latest = records
+        .last()
+        .ok_or_else(|| anyhow::anyhow!("No history recorded for '{}' registry", registry))?;
+
+    let target_record = match target {
+        Some(t) => resolve_target(&records, t)
+            .ok_or_else(|| anyhow::anyhow!("Snapshot '{}' not found in history", t))?,
+        None => records
+            .len()
+            .checked_sub(2)
+            .and_then(|i| records.get(i))
+            .ok_or_else(|| anyhow::anyhow!("Not enough history to roll back"))?,
+    };
+
+    let diff = target_record.diff(latest);
+    if diff.is_empty() {
+        println!("Registry already matches snapshot {}.", target_record.info.id);
+        return Ok(());
+    }
+
+    println!("Rolling back '{}' to {}: {}", registry, target_record.info.id, diff.summary());
+
+    let providers = target_record.providers_changed_since(latest);
+    if providers.is_empty() {
+        println!("No plugin-provided entries changed since that snapshot; nothing to disable.");
+        return Ok(());
+    }
+
+    let store = PluginStore::user_store()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
+    store.load().await?;
+
+    for provider in &providers {
+        if store.set_enabled(provider, false).await? {
+            println!("  ✓ Disabled plugin: {}", provider);
+        } else {
+            println!("  - Plugin '{}' is not installed (skipped)", provider);
+        }
+    }
+
+    println!("\nRestart the session to apply. Re-enable plugins with their installer once fixed.");
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Repository map commands
+//!
+//! `forge repomap` - 코드베이스 구조 요약을 출력하거나 JSON, Graphviz DOT,
+//! Mermaid 형식으로 내보냅니다 (다른 도구/문서에 넣기 위한 용도).
+//!
+//! 분석 결과는 `.forgecode/cache/repomap.db`에 캐시되어 바뀐 파일만 다시 파싱합니다.
+
+use anyhow::{bail, Context, Result};
+use forge_core::{HashingEmbedder, RepoAnalyzer, RepoMapExportFormat};
+use std::path::{Path, PathBuf};
+
+/// 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepomapFormat {
+    /// 사람이 읽는 요약 (LLM 컨텍스트와 같은 형식)
+    Text,
+    /// 내보내기 형식
+    Export(RepoMapExportFormat),
+}
+
+impl RepomapFormat {
+    /// `--format` 값, 없으면 출력 파일 확장자로 결정
+    pub fn resolve(format: Option<&str>, output: Option<&Path>) -> Result<Self> {
+        let name = match format {
+            Some(format) => format,
+            None => match output.and_then(|p| p.extension()).and_then(|e| e.to_str()) {
+                Some(ext) if RepoMapExportFormat::parse(ext).is_some() => ext,
+                _ => "text",
+            },
+        };
+
+        if name.eq_ignore_ascii_case("text") {
+            return Ok(Self::Text);
+        }
+        match RepoMapExportFormat::parse(name) {
+            Some(format) => Ok(Self::Export(format)),
+            None => bail!(
+                "Unknown repomap format '{}' (expected text, json, dot or mermaid)",
+                name
+            ),
+        }
+    }
+}
+
+/// `forge repomap`
+pub async fn repomap_cmd(
+    path: Option<PathBuf>,
+    format: Option<&str>,
+    output: Option<&Path>,
+    tokens: Option<usize>,
+    query: Option<&str>,
+) -> Result<()> {
+    let format = RepomapFormat::resolve(format, output)?;
+    let root = match path {
+        Some(path) if path.is_relative() => std::env::current_dir()?.join(path),
+        Some(path) => path,
+        None => std::env::current_dir()?,
+    };
+    if !root.is_dir() {
+        bail!("Not a directory: {}", root.display());
+    }
+
+    let analyzer = RepoAnalyzer::with_defaults(&root).with_project_cache();
+    let mut map = analyzer
+        .analyze()
+        .await
+        .with_context(|| format!("Failed to analyze {}", root.display()))?;
+    if let Some(query) = query {
+        analyzer.rank_for_query(&mut map, query, &HashingEmbedder::default());
+    }
+
+    let rendered = match format {
+        RepomapFormat::Text => match tokens {
+            Some(tokens) => map.to_symbols_within_budget(tokens),
+            None => map.to_full_string(),
+        },
+        RepomapFormat::Export(format) => map.export(format),
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, rendered)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!(
+                "Repository map ({} files) written to {}",
+                map.files.len(),
+                path.display()
+            );
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repomap_format() {
+        assert_eq!(
+            RepomapFormat::resolve(None, None).unwrap(),
+            RepomapFormat::Text
+        );
+        assert_eq!(
+            RepomapFormat::resolve(None, Some(Path::new("deps.mmd"))).unwrap(),
+            RepomapFormat::Export(RepoMapExportFormat::Mermaid)
+        );
+        assert_eq!(
+            RepomapFormat::resolve(None, Some(Path::new("map.txt"))).unwrap(),
+            RepomapFormat::Text
+        );
+        assert_eq!(
+            RepomapFormat::resolve(Some("DOT"), Some(Path::new("map.json"))).unwrap(),
+            RepomapFormat::Export(RepoMapExportFormat::Dot)
+        );
+        assert!(RepomapFormat::resolve(Some("svg"), None).is_err());
+    }
+}
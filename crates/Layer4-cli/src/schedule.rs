@@ -0,0 +1,258 @@
+//! Scheduled task commands
+//!
+//! `forge schedule` - cron 표현식으로 반복 실행할 프롬프트/스킬을 등록하고 관리합니다.
+//! 예: 매일 밤 "의존성 업데이트 후 PR 생성"
+//!
+//! ```text
+//! forge schedule add nightly-deps --cron "0 3 * * *" "/update-deps"
+//! forge schedule daemon        # 예약 작업 실행 (Ctrl-C로 종료)
+//! forge schedule runs nightly-deps
+//! ```
+//!
+//! 예약 작업과 실행 기록은 `~/.forgecode/forgecode.db`에 저장되고,
+//! 각 실행은 등록한 디렉토리에서 새 세션으로 에이전트를 실행합니다.
+//! 권한은 `forge -p`와 같습니다 (`--permission-endpoint`, `--read-only` 적용).
+
+use crate::{cli, stats};
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, Utc};
+use forge_agent::ScheduledAgentRunner;
+use forge_core::ConfigLoader;
+use forge_foundation::{ProviderConfig, ScheduleRunRecord};
+use forge_task::{CronSchedule, Schedule, TaskScheduler};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// 목록에 표시할 프롬프트 길이
+const PROMPT_WIDTH: usize = 40;
+
+/// 권한 옵션 (전역 CLI 인자)
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub permission_endpoint: Option<String>,
+    pub permission_timeout: Option<u64>,
+    pub read_only: bool,
+}
+
+/// `forge schedule add`
+pub fn add_cmd(name: &str, cron: &str, prompt: &str, dir: Option<PathBuf>) -> Result<()> {
+    let cron = CronSchedule::parse(cron)?;
+    let dir = match dir {
+        Some(dir) if dir.is_relative() => std::env::current_dir()?.join(dir),
+        Some(dir) => dir,
+        None => std::env::current_dir()?,
+    };
+    if !dir.is_dir() {
+        bail!("Not a directory: {}", dir.display());
+    }
+
+    let schedule = open_scheduler(RunOptions::default())?
+        .add(Schedule::new(name, cron, prompt).with_working_dir(dir))?;
+
+    println!("Added schedule '{}' ({})", schedule.name, schedule.cron);
+    println!("  Next run: {}", format_time(schedule.next_run_at));
+    println!("Run `forge schedule daemon` to execute schedules.");
+    Ok(())
+}
+
+/// `forge schedule list`
+pub fn list_cmd() -> Result<()> {
+    let schedules = open_scheduler(RunOptions::default())?.list()?;
+    if schedules.is_empty() {
+        println!("No schedules. Add one with `forge schedule add <name> --cron <expr> <prompt>`.");
+        return Ok(());
+    }
+
+    println!(
+        "{:<20} {:<16} {:<18} {:<18} {}",
+        "NAME", "CRON", "NEXT RUN", "LAST RUN", "PROMPT"
+    );
+    for schedule in schedules {
+        let next = if schedule.enabled {
+            format_time(schedule.next_run_at)
+        } else {
+            "(disabled)".to_string()
+        };
+        println!(
+            "{:<20} {:<16} {:<18} {:<18} {}",
+            schedule.name,
+            schedule.cron.expression(),
+            next,
+            format_time(schedule.last_run_at),
+            truncate(&schedule.prompt, PROMPT_WIDTH)
+        );
+    }
+    Ok(())
+}
+
+/// `forge schedule remove`
+pub fn remove_cmd(name: &str) -> Result<()> {
+    let schedule = open_scheduler(RunOptions::default())?.remove(name)?;
+    println!("Removed schedule '{}'", schedule.name);
+    Ok(())
+}
+
+/// `forge schedule enable` / `forge schedule disable`
+pub fn set_enabled_cmd(name: &str, enabled: bool) -> Result<()> {
+    let schedule = open_scheduler(RunOptions::default())?.set_enabled(name, enabled)?;
+    if enabled {
+        println!(
+            "Enabled schedule '{}' (next run: {})",
+            schedule.name,
+            format_time(schedule.next_run_at)
+        );
+    } else {
+        println!("Disabled schedule '{}'", schedule.name);
+    }
+    Ok(())
+}
+
+/// `forge schedule runs`
+pub fn runs_cmd(name: &str, limit: u32) -> Result<()> {
+    let runs = open_scheduler(RunOptions::default())?.runs(name, limit)?;
+    if runs.is_empty() {
+        println!("No runs recorded for '{}'.", name);
+        return Ok(());
+    }
+
+    for run in &runs {
+        print_run(run);
+    }
+    Ok(())
+}
+
+/// `forge schedule run` - 지금 바로 실행
+pub async fn run_cmd(name: &str, options: RunOptions) -> Result<()> {
+    apply_egress_policy();
+    let scheduler = open_scheduler(options)?;
+    eprintln!("Running schedule '{}'...", name);
+    let run = scheduler.run_now(name).await?;
+    print_run(&run);
+    if let Some(output) = &run.output_text {
+        println!("\n{}", output.trim_end());
+    }
+    Ok(())
+}
+
+/// `forge schedule daemon` - 예약 시각마다 실행 (Ctrl-C로 종료)
+pub async fn daemon_cmd(options: RunOptions) -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .with_target(false)
+        .init();
+    apply_egress_policy();
+
+    let scheduler = Arc::new(open_scheduler(options)?);
+    let enabled = scheduler.list()?.into_iter().filter(|s| s.enabled).count();
+    eprintln!(
+        "Scheduler started with {} enabled schedule{} (Ctrl-C to stop)",
+        enabled,
+        if enabled == 1 { "" } else { "s" }
+    );
+
+    let handle = Arc::clone(&scheduler).start();
+    tokio::signal::ctrl_c().await?;
+    handle.abort();
+    eprintln!("Scheduler stopped");
+    Ok(())
+}
+
+/// 에이전트 실행기가 연결된 스케줄러
+fn open_scheduler(options: RunOptions) -> Result<TaskScheduler> {
+    let storage = stats::open_local_storage()?;
+    let config = ProviderConfig::load().unwrap_or_else(|e| {
+        eprintln!("Warning: Failed to load config: {}", e);
+        ProviderConfig::default()
+    });
+    let fallback_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+
+    let runner = ScheduledAgentRunner::new(move |schedule: &Schedule| {
+        let working_dir = schedule
+            .working_dir
+            .clone()
+            .unwrap_or_else(|| fallback_dir.clone());
+        let approvals = cli::remote_approvals(
+            options.permission_endpoint.as_deref(),
+            options.permission_timeout,
+        )?;
+        let ctx = cli::agent_context(&config, working_dir, approvals, options.read_only)?;
+        Ok(Arc::new(ctx))
+    });
+
+    Ok(TaskScheduler::new(storage, Arc::new(runner)))
+}
+
+/// 네트워크 egress 규칙 (`security.network`) 적용
+fn apply_egress_policy() {
+    let Ok(dir) = std::env::current_dir() else {
+        return;
+    };
+    if let Ok(settings) = ConfigLoader::new(&dir).load_all() {
+        forge_foundation::set_egress_policy(settings.security.network.egress_policy());
+    }
+}
+
+fn print_run(run: &ScheduleRunRecord) {
+    let started = DateTime::parse_from_rfc3339(&run.started_at)
+        .ok()
+        .map(|t| t.with_timezone(&Utc));
+    let duration = run
+        .completed_at
+        .as_deref()
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+        .zip(started)
+        .map(|(end, start)| format!("{}s", (end.with_timezone(&Utc) - start).num_seconds()))
+        .unwrap_or_else(|| "-".to_string());
+    let status = if run.status == "success" {
+        "✓"
+    } else {
+        "✗"
+    };
+    let summary = match (&run.error_message, &run.output_text) {
+        (Some(error), _) => error.clone(),
+        (None, Some(output)) => output.lines().next().unwrap_or_default().to_string(),
+        (None, None) => String::new(),
+    };
+
+    println!(
+        "{} {}  {:>6}  {}",
+        status,
+        format_time(started),
+        duration,
+        truncate(&summary, 60)
+    );
+}
+
+/// 로컬 시각 (`2026-03-10 03:00`)
+fn format_time(time: Option<DateTime<Utc>>) -> String {
+    time.map(|t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    let text = text.lines().next().unwrap_or_default();
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        format!("{}...", text.chars().take(max - 3).collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_and_format() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(
+            truncate("update dependencies\nand open a PR", 40),
+            "update dependencies"
+        );
+        assert_eq!(truncate("abcdefghijkl", 8), "abcde...");
+        assert_eq!(format_time(None), "-");
+    }
+}
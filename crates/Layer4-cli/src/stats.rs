@@ -0,0 +1,627 @@
+//! Usage Stats - 사용량 통계 대시보드
+//!
+//! `forge stats` 명령과 TUI `/stats` 명령에서 공통으로 사용합니다.
+//! SQLite에 기록된 `token_usage` / `tool_executions`를 기간별로 집계하여
+//! 세션 수, 토큰, 모델별 비용, 캐시 절감액, 자주 쓴 도구, 평균 턴 지연을 보여줍니다.
+//!
+//! `forge stats tools`는 도구별 성공률, 타임아웃, 재시도, 출력 크기를 실패가 많은
+//! 순서로 보여주어 도구 설정(타임아웃 등)을 조정할 수 있게 합니다.
+//!
+//! 저장소는 `config.json`의 `storage` 설정을 따르므로 Postgres를 쓰면 팀 전체 기록을 집계합니다.
+
+use crate::cost::CostTracker;
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Local, NaiveDate, TimeZone, Utc};
+use forge_foundation::{
+    open_backend, ForgeConfig, ModelUsageStats, Storage, StorageBackend, StorageConfig,
+    ToolMetrics, ToolUsageStats,
+};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// 기본 조회 기간 (일)
+pub const DEFAULT_DAYS: u32 = 30;
+
+/// 표시할 도구 수
+const TOP_TOOLS: u32 = 10;
+
+/// 런타임 데이터 디렉토리 (~/.forgecode)
+fn data_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".forgecode")
+}
+
+/// `config.json`의 `storage` 설정
+fn storage_config() -> Option<StorageConfig> {
+    ForgeConfig::load()
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load config, using local storage: {}", e);
+            ForgeConfig::default()
+        })
+        .storage
+}
+
+/// 설정된 런타임 저장소 열기 (`storage` 설정, 기본 ~/.forgecode/forgecode.db)
+pub fn open_storage() -> Result<Arc<dyn StorageBackend>> {
+    Ok(open_backend(storage_config().as_ref(), &data_dir())?)
+}
+
+/// 공유 저장소 열기 (`storage`가 로컬 SQLite가 아닐 때만)
+pub fn open_shared_storage() -> Result<Option<Arc<dyn StorageBackend>>> {
+    match storage_config().filter(|config| !config.is_local()) {
+        Some(config) => Ok(Some(open_backend(Some(&config), &data_dir())?)),
+        None => Ok(None),
+    }
+}
+
+/// 로컬 런타임 데이터베이스 열기 (~/.forgecode/forgecode.db)
+///
+/// 예약 작업처럼 머신별 데이터는 `storage` 설정과 관계없이 로컬 SQLite에 둡니다.
+pub fn open_local_storage() -> Result<Storage> {
+    Ok(Storage::new(&data_dir())?)
+}
+
+// ============================================================================
+// StatsRange
+// ============================================================================
+
+/// 조회 기간 (로컬 날짜 기준, 양 끝 포함)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatsRange {
+    pub since: NaiveDate,
+    pub until: NaiveDate,
+}
+
+impl StatsRange {
+    /// 오늘을 포함한 최근 N일
+    pub fn last_days(days: u32) -> Self {
+        let until = Local::now().date_naive();
+        Self {
+            since: until - Duration::days(days.max(1) as i64 - 1),
+            until,
+        }
+    }
+
+    /// CLI 인자로 생성 (`since`/`until`은 YYYY-MM-DD, 없으면 오늘 기준 N일)
+    pub fn from_args(days: u32, since: Option<&str>, until: Option<&str>) -> Result<Self> {
+        let until = match until {
+            Some(date) => parse_date(date)?,
+            None => Local::now().date_naive(),
+        };
+        let since = match since {
+            Some(date) => parse_date(date)?,
+            None => until - Duration::days(days.max(1) as i64 - 1),
+        };
+
+        if since > until {
+            bail!("--since ({}) must not be after --until ({})", since, until);
+        }
+        Ok(Self { since, until })
+    }
+
+    /// 기간 일수
+    pub fn days(&self) -> i64 {
+        (self.until - self.since).num_days() + 1
+    }
+
+    /// 저장소 조회용 `[since 00:00, until 다음날 00:00)` (UTC RFC3339)
+    fn bounds(&self) -> (String, String) {
+        (
+            local_midnight_utc(self.since),
+            local_midnight_utc(self.until + Duration::days(1)),
+        )
+    }
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .with_context(|| format!("Invalid date '{}' (expected YYYY-MM-DD)", date))
+}
+
+fn local_midnight_utc(date: NaiveDate) -> String {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+        .to_rfc3339()
+}
+
+// ============================================================================
+// UsageDashboard
+// ============================================================================
+
+/// 모델별 사용량 + 비용
+#[derive(Debug, Clone)]
+pub struct ModelRow {
+    pub stats: ModelUsageStats,
+    pub cost_usd: f64,
+    pub cache_savings_usd: f64,
+}
+
+/// 기간별 사용량 대시보드
+#[derive(Debug, Clone)]
+pub struct UsageDashboard {
+    pub range: StatsRange,
+    pub sessions: i64,
+    pub models: Vec<ModelRow>,
+    pub tools: Vec<ToolUsageStats>,
+}
+
+impl UsageDashboard {
+    /// 저장소에서 집계
+    pub fn load(storage: &dyn StorageBackend, range: StatsRange) -> Result<Self> {
+        let (since, until) = range.bounds();
+        let sessions = storage.count_active_sessions_between(&since, &until)?;
+        let models = storage.get_model_usage_between(&since, &until)?;
+        let tools = storage.get_tool_usage_between(&since, &until, TOP_TOOLS)?;
+
+        Ok(Self::from_stats(range, sessions, models, tools, &CostTracker::new()))
+    }
+
+    fn from_stats(
+        range: StatsRange,
+        sessions: i64,
+        models: Vec<ModelUsageStats>,
+        tools: Vec<ToolUsageStats>,
+        pricing: &CostTracker,
+    ) -> Self {
+        let models = models
+            .into_iter()
+            .map(|stats| {
+                // 가격표에 없는 모델은 기록된 비용 사용
+                let estimated = pricing.calculate_cost(
+                    &stats.model,
+                    stats.input_tokens.max(0) as u64,
+                    stats.output_tokens.max(0) as u64,
+                    stats.cache_read_tokens.max(0) as u64,
+                );
+                let cost_usd = if estimated > 0.0 {
+                    estimated
+                } else {
+                    stats.cost_cents as f64 / 100.0
+                };
+                let cache_savings_usd =
+                    pricing.cache_savings(&stats.model, stats.cache_read_tokens.max(0) as u64);
+
+                ModelRow {
+                    stats,
+                    cost_usd,
+                    cache_savings_usd,
+                }
+            })
+            .collect();
+
+        Self {
+            range,
+            sessions,
+            models,
+            tools,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty() && self.tools.is_empty()
+    }
+
+    pub fn requests(&self) -> i64 {
+        self.models.iter().map(|m| m.stats.request_count).sum()
+    }
+
+    pub fn input_tokens(&self) -> i64 {
+        self.models.iter().map(|m| m.stats.input_tokens).sum()
+    }
+
+    pub fn output_tokens(&self) -> i64 {
+        self.models.iter().map(|m| m.stats.output_tokens).sum()
+    }
+
+    pub fn cache_read_tokens(&self) -> i64 {
+        self.models.iter().map(|m| m.stats.cache_read_tokens).sum()
+    }
+
+    pub fn cost_usd(&self) -> f64 {
+        self.models.iter().map(|m| m.cost_usd).sum()
+    }
+
+    pub fn cache_savings_usd(&self) -> f64 {
+        self.models.iter().map(|m| m.cache_savings_usd).sum()
+    }
+
+    /// 모델 전체 평균 턴 지연 (기록된 요청 기준 가중 평균)
+    pub fn avg_latency_ms(&self) -> Option<f64> {
+        let (total, samples) = self
+            .models
+            .iter()
+            .filter_map(|m| {
+                m.stats
+                    .avg_latency_ms
+                    .map(|avg| (avg * m.stats.latency_samples as f64, m.stats.latency_samples))
+            })
+            .fold((0.0, 0), |(total, samples), (sum, n)| (total + sum, samples + n));
+
+        (samples > 0).then(|| total / samples as f64)
+    }
+
+    /// 텍스트 표로 렌더링
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "Usage statistics: {} → {} ({} days)\n\n",
+            self.range.since,
+            self.range.until,
+            self.range.days()
+        );
+
+        if self.is_empty() {
+            out.push_str("No usage recorded in this period.\n");
+            return out;
+        }
+
+        let avg_latency = self
+            .avg_latency_ms()
+            .map(format_duration_ms)
+            .unwrap_or_else(|| "-".to_string());
+
+        out.push_str(&format!("  Sessions      {}\n", self.sessions));
+        out.push_str(&format!("  Requests      {}\n", self.requests()));
+        out.push_str(&format!(
+            "  Tokens        {} in / {} out\n",
+            format_tokens(self.input_tokens()),
+            format_tokens(self.output_tokens())
+        ));
+        out.push_str(&format!(
+            "  Cache reads   {} (saved {})\n",
+            format_tokens(self.cache_read_tokens()),
+            format_usd(self.cache_savings_usd())
+        ));
+        out.push_str(&format!("  Cost          {}\n", format_usd(self.cost_usd())));
+        out.push_str(&format!("  Avg turn      {}\n", avg_latency));
+
+        if !self.models.is_empty() {
+            out.push_str(&format!(
+                "\n{:<12} {:<28} {:>8} {:>9} {:>9} {:>10} {:>9} {:>9}\n",
+                "Provider", "Model", "Requests", "Input", "Output", "Cache", "Cost", "Latency"
+            ));
+            out.push_str(&format!("{}\n", "-".repeat(101)));
+            for row in &self.models {
+                let stats = &row.stats;
+                out.push_str(&format!(
+                    "{:<12} {:<28} {:>8} {:>9} {:>9} {:>10} {:>9} {:>9}\n",
+                    truncate(&stats.provider, 12),
+                    truncate(&stats.model, 28),
+                    stats.request_count,
+                    format_tokens(stats.input_tokens),
+                    format_tokens(stats.output_tokens),
+                    format_tokens(stats.cache_read_tokens),
+                    format_usd(row.cost_usd),
+                    stats
+                        .avg_latency_ms
+                        .map(format_duration_ms)
+                        .unwrap_or_else(|| "-".to_string()),
+                ));
+            }
+        }
+
+        if !self.tools.is_empty() {
+            out.push_str(&format!(
+                "\n{:<28} {:>8} {:>8} {:>10}\n",
+                "Top tools", "Calls", "Errors", "Avg time"
+            ));
+            out.push_str(&format!("{}\n", "-".repeat(57)));
+            for tool in &self.tools {
+                out.push_str(&format!(
+                    "{:<28} {:>8} {:>8} {:>10}\n",
+                    truncate(&tool.tool_name, 28),
+                    tool.call_count,
+                    tool.error_count,
+                    tool.avg_duration_ms
+                        .map(format_duration_ms)
+                        .unwrap_or_else(|| "-".to_string()),
+                ));
+            }
+        }
+
+        out
+    }
+}
+
+/// `forge stats` 명령
+pub fn stats_cmd(days: u32, since: Option<&str>, until: Option<&str>) -> Result<()> {
+    let range = StatsRange::from_args(days, since, until)?;
+    let storage = open_storage()?;
+    let dashboard = UsageDashboard::load(storage.as_ref(), range)?;
+
+    println!("\n📊 {}", dashboard.render());
+    Ok(())
+}
+
+// ============================================================================
+// ToolStatsReport
+// ============================================================================
+
+/// 도구별 성공률 리포트
+#[derive(Debug, Clone)]
+pub struct ToolStatsReport {
+    pub range: StatsRange,
+    /// 실패가 많은 순서
+    pub tools: Vec<ToolMetrics>,
+}
+
+impl ToolStatsReport {
+    /// 저장소에서 집계
+    pub fn load(storage: &dyn StorageBackend, range: StatsRange) -> Result<Self> {
+        let (since, until) = range.bounds();
+        let tools = storage.get_tool_metrics_between(&since, &until)?;
+        Ok(Self { range, tools })
+    }
+
+    /// 전체 호출 수
+    pub fn calls(&self) -> i64 {
+        self.tools.iter().map(|t| t.call_count).sum()
+    }
+
+    /// 전체 실패 수 (에러 + 타임아웃)
+    pub fn failures(&self) -> i64 {
+        self.tools.iter().map(|t| t.failure_count()).sum()
+    }
+
+    /// 텍스트 표로 렌더링
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "Tool statistics: {} → {} ({} days)\n\n",
+            self.range.since,
+            self.range.until,
+            self.range.days()
+        );
+
+        if self.tools.is_empty() {
+            out.push_str("No tool executions recorded in this period.\n");
+            return out;
+        }
+
+        out.push_str(&format!(
+            "  {} calls, {} failed ({:.1}%)\n",
+            self.calls(),
+            self.failures(),
+            self.failures() as f64 / self.calls().max(1) as f64 * 100.0
+        ));
+
+        out.push_str(&format!(
+            "\n{:<24} {:>7} {:>8} {:>7} {:>8} {:>7} {:>9} {:>9} {:>9}\n",
+            "Tool", "Calls", "Success", "Errors", "Timeouts", "Retries", "Avg time", "Max time",
+            "Avg out"
+        ));
+        out.push_str(&format!("{}\n", "-".repeat(97)));
+        for tool in &self.tools {
+            out.push_str(&format!(
+                "{:<24} {:>7} {:>7.1}% {:>7} {:>8} {:>7} {:>9} {:>9} {:>9}\n",
+                truncate(&tool.tool_name, 24),
+                tool.call_count,
+                tool.success_rate() * 100.0,
+                tool.error_count,
+                tool.timeout_count,
+                tool.retry_count,
+                tool.avg_duration_ms
+                    .map(format_duration_ms)
+                    .unwrap_or_else(|| "-".to_string()),
+                tool.max_duration_ms
+                    .map(|ms| format_duration_ms(ms as f64))
+                    .unwrap_or_else(|| "-".to_string()),
+                tool.avg_output_bytes
+                    .map(format_bytes)
+                    .unwrap_or_else(|| "-".to_string()),
+            ));
+        }
+
+        let failures: Vec<_> = self
+            .tools
+            .iter()
+            .filter_map(|t| t.last_error.as_deref().map(|e| (&t.tool_name, e)))
+            .collect();
+        if !failures.is_empty() {
+            out.push_str("\nLast errors\n");
+            for (tool, error) in failures {
+                let line = error.lines().next().unwrap_or("");
+                out.push_str(&format!("  {:<22} {}\n", truncate(tool, 22), truncate(line, 72)));
+            }
+        }
+
+        if self.tools.iter().any(|t| t.timeout_count > 0) {
+            out.push_str(
+                "\nTip: raise a tool's limit with \"tools\": { \"timeouts\": { \"<tool>\": <seconds> } } in settings.json\n",
+            );
+        }
+
+        out
+    }
+}
+
+/// `forge stats tools` 명령
+pub fn tools_cmd(days: u32, since: Option<&str>, until: Option<&str>) -> Result<()> {
+    let range = StatsRange::from_args(days, since, until)?;
+    let storage = open_storage()?;
+    let report = ToolStatsReport::load(storage.as_ref(), range)?;
+
+    println!("\n🔧 {}", report.render());
+    Ok(())
+}
+
+// ============================================================================
+// Formatting
+// ============================================================================
+
+fn format_tokens(tokens: i64) -> String {
+    match tokens {
+        t if t >= 1_000_000 => format!("{:.1}M", t as f64 / 1_000_000.0),
+        t if t >= 1_000 => format!("{:.1}K", t as f64 / 1_000.0),
+        t => t.to_string(),
+    }
+}
+
+fn format_usd(cost: f64) -> String {
+    if cost > 0.0 && cost < 0.01 {
+        format!("${:.4}", cost)
+    } else {
+        format!("${:.2}", cost)
+    }
+}
+
+fn format_duration_ms(ms: f64) -> String {
+    if ms < 1000.0 {
+        format!("{:.0}ms", ms)
+    } else {
+        format!("{:.1}s", ms / 1000.0)
+    }
+}
+
+fn format_bytes(bytes: f64) -> String {
+    match bytes {
+        b if b >= 1024.0 * 1024.0 => format!("{:.1}MB", b / (1024.0 * 1024.0)),
+        b if b >= 1024.0 => format!("{:.1}KB", b / 1024.0),
+        b => format!("{:.0}B", b),
+    }
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() > max {
+        let head: String = s.chars().take(max - 1).collect();
+        format!("{}…", head)
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(name: &str, input: i64, cache_read: i64, latency: Option<(f64, i64)>) -> ModelUsageStats {
+        ModelUsageStats {
+            provider: "anthropic".to_string(),
+            model: name.to_string(),
+            input_tokens: input,
+            output_tokens: input / 10,
+            cache_read_tokens: cache_read,
+            cache_write_tokens: 0,
+            cost_cents: 0,
+            request_count: latency.map(|(_, n)| n).unwrap_or(1),
+            avg_latency_ms: latency.map(|(avg, _)| avg),
+            latency_samples: latency.map(|(_, n)| n).unwrap_or(0),
+        }
+    }
+
+    #[test]
+    fn test_range_from_args() {
+        let range = StatsRange::from_args(30, Some("2026-01-01"), Some("2026-01-31")).unwrap();
+        assert_eq!(range.days(), 31);
+
+        let range = StatsRange::from_args(7, None, Some("2026-01-31")).unwrap();
+        assert_eq!(range.since, NaiveDate::from_ymd_opt(2026, 1, 25).unwrap());
+        assert_eq!(StatsRange::last_days(30).days(), 30);
+
+        assert!(StatsRange::from_args(30, Some("2026-02-01"), Some("2026-01-01")).is_err());
+        assert!(StatsRange::from_args(30, Some("yesterday"), None).is_err());
+    }
+
+    #[test]
+    fn test_dashboard_totals() {
+        let range = StatsRange::from_args(30, None, Some("2026-01-31")).unwrap();
+        let dashboard = UsageDashboard::from_stats(
+            range,
+            3,
+            vec![
+                model("claude-opus-4", 1_000_000, 1_000_000, Some((1000.0, 1))),
+                model("claude-3-haiku", 2_000, 0, Some((5000.0, 3))),
+            ],
+            vec![ToolUsageStats {
+                tool_name: "bash".to_string(),
+                call_count: 12,
+                error_count: 2,
+                avg_duration_ms: Some(350.0),
+            }],
+            &CostTracker::new(),
+        );
+
+        assert_eq!(dashboard.requests(), 4);
+        assert!((dashboard.cache_savings_usd() - 13.5).abs() < 0.001);
+        assert_eq!(dashboard.avg_latency_ms(), Some(4000.0));
+
+        let rendered = dashboard.render();
+        assert!(rendered.starts_with("Usage statistics: 2026-01-02 → 2026-01-31 (30 days)"));
+        assert!(rendered.contains("claude-opus-4"));
+        assert!(rendered.contains("Avg turn      4.0s"));
+        assert!(rendered.contains("bash"));
+    }
+
+    #[test]
+    fn test_empty_dashboard() {
+        let dashboard = UsageDashboard::from_stats(
+            StatsRange::last_days(DEFAULT_DAYS),
+            0,
+            Vec::new(),
+            Vec::new(),
+            &CostTracker::new(),
+        );
+        assert!(dashboard.render().contains("No usage recorded"));
+    }
+
+    #[test]
+    fn test_tool_report() {
+        let storage = Storage::in_memory().unwrap();
+        for (tool, status, error, retries) in [
+            ("bash", "error", Some("exit code 101\nmore"), 1),
+            ("bash", "timeout", Some("timed out"), 0),
+            ("read", "success", None, 0),
+        ] {
+            storage
+                .record_tool_execution(&forge_foundation::ToolExecutionRecord {
+                    id: None,
+                    session_id: None,
+                    message_id: None,
+                    tool_name: tool.to_string(),
+                    tool_call_id: "call".to_string(),
+                    input_json: "{}".to_string(),
+                    output_text: None,
+                    status: status.to_string(),
+                    error_message: error.map(str::to_string),
+                    duration_ms: Some(1500),
+                    output_bytes: Some(100),
+                    retries,
+                    created_at: None,
+                    completed_at: None,
+                })
+                .unwrap();
+        }
+
+        let report = ToolStatsReport::load(&storage, StatsRange::last_days(1)).unwrap();
+        assert_eq!(report.calls(), 3);
+        assert_eq!(report.failures(), 2);
+        assert_eq!(report.tools[0].tool_name, "bash");
+
+        let rendered = report.render();
+        assert!(rendered.contains("3 calls, 2 failed (66.7%)"));
+        assert!(rendered.contains("Last errors"));
+        assert!(!rendered.contains("more"));
+        assert!(rendered.contains("\"timeouts\""));
+
+        let empty = ToolStatsReport {
+            range: StatsRange::last_days(DEFAULT_DAYS),
+            tools: Vec::new(),
+        };
+        assert!(empty.render().contains("No tool executions"));
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(format_tokens(999), "999");
+        assert_eq!(format_tokens(12_345), "12.3K");
+        assert_eq!(format_tokens(1_200_000), "1.2M");
+        assert_eq!(format_duration_ms(850.0), "850ms");
+        assert_eq!(format_usd(0.001), "$0.0010");
+        assert_eq!(format_bytes(512.0), "512B");
+        assert_eq!(format_bytes(2048.0), "2.0KB");
+        assert_eq!(truncate("claude-sonnet-4-20250514", 10), "claude-so…");
+    }
+}
@@ -16,10 +16,11 @@ use crossterm::{
 use forge_foundation::ProviderConfig;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
-use tokio::sync::mpsc;
 
 /// Run the TUI application
-pub async fn run(config: &ProviderConfig) -> anyhow::Result<()> {
+///
+/// With `read_only`, the agent may not write, execute or use the network.
+pub async fn run(config: &ProviderConfig, read_only: bool) -> anyhow::Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -31,7 +32,7 @@ pub async fn run(config: &ProviderConfig) -> anyhow::Result<()> {
     let mut app = App::new();
 
     // Initialize with config
-    if let Err(e) = app.chat.init(config).await {
+    if let Err(e) = app.chat.init(config, read_only).await {
         // Show error but continue
         eprintln!("Initialization warning: {}", e);
     }
@@ -41,7 +42,7 @@ pub async fn run(config: &ProviderConfig) -> anyhow::Result<()> {
     EventHandler::start(event_tx);
 
     // Channel for agent events
-    let mut agent_rx: Option<mpsc::Receiver<forge_agent::AgentEvent>> = None;
+    let mut agent_rx: Option<forge_agent::AgentEventReceiver> = None;
 
     // Main loop
     let result = loop {
@@ -84,6 +85,7 @@ pub async fn run(config: &ProviderConfig) -> anyhow::Result<()> {
                         if key.modifiers.contains(crossterm::event::KeyModifiers::CONTROL)
                             && key.code == crossterm::event::KeyCode::Char('s')
                         {
+                            app.settings.set_active_grants(&app.chat.permission_grants());
                             app.settings.show();
                             continue;
                         }
@@ -92,10 +94,14 @@ pub async fn run(config: &ProviderConfig) -> anyhow::Result<()> {
                         if let Some(action) = app.chat.handle_key(key) {
                             match action {
                                 ChatAction::SendMessage(content) => {
-                                    agent_rx = Some(app.chat.send_message(content).await);
+                                    if let Some(rx) = app.chat.send_message(content).await {
+                                        agent_rx = Some(rx);
+                                    }
                                 }
                                 ChatAction::SlashCommand(cmd) => {
-                                    app.chat.handle_slash_command(&cmd);
+                                    if let Some(rx) = app.chat.run_slash_command(&cmd).await {
+                                        agent_rx = Some(rx);
+                                    }
                                 }
                                 ChatAction::TogglePause => {
                                     app.chat.toggle_pause().await;
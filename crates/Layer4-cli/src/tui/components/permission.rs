@@ -2,10 +2,17 @@
 //!
 //! Displays a modal dialog for permission requests.
 //! Implements Layer1's PermissionDelegate trait for TUI.
+//!
+//! The modal renders a structured `ConfirmationPrompt` (risk factors,
+//! command/diff preview, alternatives), the same object the CLI prints.
+//! Attach a `PromptCatalog` to show translated text.
 
 #![allow(dead_code)]
 
-use forge_foundation::permission::{PermissionAction, PermissionScope};
+use forge_foundation::permission::{
+    ConfirmOption, ConfirmationPrompt, PermissionAction, PermissionScope, PromptCatalog,
+    PromptPreview, PromptRisk,
+};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
@@ -49,6 +56,18 @@ impl PermissionResponse {
     }
 }
 
+impl From<ConfirmOption> for PermissionResponse {
+    fn from(option: ConfirmOption) -> Self {
+        match option {
+            ConfirmOption::AllowOnce => Self::AllowOnce,
+            ConfirmOption::AllowSession => Self::AllowSession,
+            ConfirmOption::AllowPermanent => Self::AllowPermanent,
+            ConfirmOption::Deny => Self::Deny,
+            ConfirmOption::DenyPermanent => Self::DenyPermanent,
+        }
+    }
+}
+
 /// Option in the permission modal
 #[derive(Clone)]
 struct PermissionOption {
@@ -62,16 +81,31 @@ struct PermissionOption {
     style: Style,
 }
 
+impl PermissionOption {
+    /// TUI shortcut and color for a prompt option
+    fn from_prompt(option: ConfirmOption, catalog: &PromptCatalog) -> Self {
+        let (key, color) = match option {
+            ConfirmOption::AllowOnce => ('o', Color::Green),
+            ConfirmOption::AllowSession => ('s', Color::Cyan),
+            ConfirmOption::AllowPermanent => ('p', Color::Blue),
+            ConfirmOption::Deny => ('d', Color::Yellow),
+            ConfirmOption::DenyPermanent => ('n', Color::Red),
+        };
+        Self {
+            label: catalog.text(option.key(), option.label()),
+            key,
+            response: option.into(),
+            style: Style::default().fg(color),
+        }
+    }
+}
+
 /// Permission Modal widget
 pub struct PermissionModal {
-    /// Tool requesting permission
-    tool_name: String,
-    /// Action being requested
-    action: PermissionAction,
-    /// Human-readable description
-    description: String,
-    /// Risk score (0-10)
-    risk_score: u8,
+    /// Structured prompt being shown
+    prompt: ConfirmationPrompt,
+    /// Translations for prompt text
+    catalog: PromptCatalog,
     /// Available options
     options: Vec<PermissionOption>,
     /// Currently selected option index
@@ -88,50 +122,34 @@ impl PermissionModal {
         description: &str,
         risk_score: u8,
     ) -> Self {
-        let options = vec![
-            PermissionOption {
-                label: "Allow Once".to_string(),
-                key: 'o',
-                response: PermissionResponse::AllowOnce,
-                style: Style::default().fg(Color::Green),
-            },
-            PermissionOption {
-                label: "Allow Session".to_string(),
-                key: 's',
-                response: PermissionResponse::AllowSession,
-                style: Style::default().fg(Color::Cyan),
-            },
-            PermissionOption {
-                label: "Allow Permanent".to_string(),
-                key: 'p',
-                response: PermissionResponse::AllowPermanent,
-                style: Style::default().fg(Color::Blue),
-            },
-            PermissionOption {
-                label: "Deny".to_string(),
-                key: 'd',
-                response: PermissionResponse::Deny,
-                style: Style::default().fg(Color::Yellow),
-            },
-            PermissionOption {
-                label: "Deny Permanent".to_string(),
-                key: 'n',
-                response: PermissionResponse::DenyPermanent,
-                style: Style::default().fg(Color::Red),
-            },
-        ];
+        Self::from_prompt(
+            ConfirmationPrompt::for_action(tool_name, &action, description, risk_score),
+            PromptCatalog::default(),
+        )
+    }
+
+    /// Create a modal for a structured prompt
+    pub fn from_prompt(prompt: ConfirmationPrompt, catalog: PromptCatalog) -> Self {
+        let options = prompt
+            .options
+            .iter()
+            .map(|option| PermissionOption::from_prompt(*option, &catalog))
+            .collect();
 
         Self {
-            tool_name: tool_name.to_string(),
-            action,
-            description: description.to_string(),
-            risk_score,
+            prompt,
+            catalog,
             options,
             selected: 0,
             visible: true,
         }
     }
 
+    /// The prompt being shown
+    pub fn prompt(&self) -> &ConfirmationPrompt {
+        &self.prompt
+    }
+
     /// Show the modal
     pub fn show(&mut self) {
         self.visible = true;
@@ -198,24 +216,82 @@ impl PermissionModal {
 
     /// Get risk level style based on score
     fn risk_style(&self) -> Style {
-        match self.risk_score {
-            0..=3 => Style::default().fg(Color::Green),
-            4..=6 => Style::default().fg(Color::Yellow),
-            7..=8 => Style::default().fg(Color::Rgb(255, 165, 0)), // Orange
-            9..=10 => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            _ => Style::default().fg(Color::Red),
+        match self.prompt.risk {
+            PromptRisk::Low => Style::default().fg(Color::Green),
+            PromptRisk::Medium => Style::default().fg(Color::Yellow),
+            PromptRisk::High => Style::default().fg(Color::Rgb(255, 165, 0)), // Orange
+            PromptRisk::Critical => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         }
     }
 
     /// Get risk level text
-    fn risk_text(&self) -> &'static str {
-        match self.risk_score {
-            0..=3 => "Low Risk",
-            4..=6 => "Medium Risk",
-            7..=8 => "High Risk",
-            9..=10 => "DANGEROUS",
-            _ => "Unknown",
+    fn risk_text(&self) -> String {
+        self.catalog
+            .text(self.prompt.risk.key(), self.prompt.risk.label())
+    }
+
+    /// Details section: description, explanation, risk factors, preview, alternatives
+    fn detail_lines(&self) -> Vec<Line<'_>> {
+        let prompt = &self.prompt;
+        let mut lines = vec![Line::from(Span::styled(
+            prompt.headline(&self.catalog),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ))];
+        if !prompt.description.is_empty() {
+            lines.push(Line::from(prompt.description.clone()));
+        }
+        if let Some(explanation) = &prompt.explanation {
+            lines.extend(explanation.lines().map(|text| {
+                Line::from(Span::styled(
+                    format!("ℹ {}", text),
+                    Style::default().fg(Color::Gray),
+                ))
+            }));
+        }
+
+        for factor in &prompt.risk_factors {
+            lines.push(Line::from(Span::styled(
+                format!("⚠ {}", self.catalog.message(factor)),
+                Style::default().fg(Color::Yellow),
+            )));
         }
+
+        if let Some(preview) = &prompt.preview {
+            lines.push(Line::from(""));
+            let body = preview.body().lines().map(|text| {
+                let style = match preview {
+                    PromptPreview::Command { .. } => Style::default().fg(Color::Cyan),
+                    PromptPreview::Diff { .. }
+                        if text.starts_with("+++") || text.starts_with("---") =>
+                    {
+                        Style::default().fg(Color::DarkGray)
+                    }
+                    PromptPreview::Diff { .. } if text.starts_with('+') => {
+                        Style::default().fg(Color::Green)
+                    }
+                    PromptPreview::Diff { .. } if text.starts_with('-') => {
+                        Style::default().fg(Color::Red)
+                    }
+                    PromptPreview::Diff { .. } => Style::default().fg(Color::Gray),
+                };
+                Line::from(Span::styled(text.to_string(), style))
+            });
+            lines.extend(body);
+        }
+
+        if !prompt.alternatives.is_empty() {
+            lines.push(Line::from(""));
+            for alternative in &prompt.alternatives {
+                lines.push(Line::from(Span::styled(
+                    format!("💡 {}", self.catalog.message(alternative)),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+
+        lines
     }
 
     /// Create a centered rect for the modal
@@ -245,14 +321,18 @@ impl PermissionModal {
             return;
         }
 
-        // Calculate modal area (60% width, 50% height)
-        let modal_area = self.centered_rect(60, 50, area);
+        // Calculate modal area (60% width, 50% height; larger with a preview)
+        let modal_area = if self.prompt.preview.is_some() {
+            self.centered_rect(70, 70, area)
+        } else {
+            self.centered_rect(60, 50, area)
+        };
 
         // Clear the area behind modal
         frame.render_widget(Clear, modal_area);
 
         // Modal border with risk-colored title
-        let title = format!(" Permission Required: {} ", self.tool_name);
+        let title = format!(" Permission Required: {} ", self.prompt.tool);
         let block = Block::default()
             .title(title)
             .title_style(self.risk_style().add_modifier(Modifier::BOLD))
@@ -269,12 +349,12 @@ impl PermissionModal {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(2), // Risk indicator
-                Constraint::Length(1), // Spacer
-                Constraint::Length(3), // Action description
-                Constraint::Length(1), // Spacer
-                Constraint::Min(5),    // Options
-                Constraint::Length(2), // Help text
+                Constraint::Length(2),                         // Risk indicator
+                Constraint::Length(1),                         // Spacer
+                Constraint::Min(3),                            // Prompt details
+                Constraint::Length(1),                         // Spacer
+                Constraint::Length(self.options.len() as u16), // Options
+                Constraint::Length(2),                         // Help text
             ])
             .split(inner);
 
@@ -282,19 +362,18 @@ impl PermissionModal {
         let risk_line = Line::from(vec![
             Span::raw("Risk Level: "),
             Span::styled(
-                format!("{}/10 - {}", self.risk_score, self.risk_text()),
+                format!("{}/10 - {}", self.prompt.risk_score, self.risk_text()),
                 self.risk_style().add_modifier(Modifier::BOLD),
             ),
         ]);
         let risk_para = Paragraph::new(risk_line).alignment(Alignment::Center);
         frame.render_widget(risk_para, chunks[0]);
 
-        // Action description
-        let action_text = format!("{}\n\n{}", self.action.description(), self.description);
-        let action_para = Paragraph::new(action_text)
-            .wrap(Wrap { trim: true })
+        // Prompt details
+        let details_para = Paragraph::new(self.detail_lines())
+            .wrap(Wrap { trim: false })
             .style(Style::default().fg(Color::White));
-        frame.render_widget(action_para, chunks[2]);
+        frame.render_widget(details_para, chunks[2]);
 
         // Options
         let options_area = chunks[4];
@@ -333,7 +412,11 @@ impl PermissionModal {
         }
 
         // Help text
-        let help_text = "↑↓/jk: Navigate  Enter: Select  o/s/p/d/n: Quick select  Esc: Deny";
+        let keys: Vec<String> = self.options.iter().map(|o| o.key.to_string()).collect();
+        let help_text = format!(
+            "↑↓/jk: Navigate  Enter: Select  {}: Quick select  Esc: Deny",
+            keys.join("/")
+        );
         let help_para = Paragraph::new(help_text)
             .style(Style::default().fg(Color::DarkGray))
             .alignment(Alignment::Center);
@@ -370,15 +453,22 @@ impl PermissionModalManager {
         action: PermissionAction,
         description: &str,
         risk_score: u8,
+    ) -> tokio::sync::oneshot::Receiver<PermissionResponse> {
+        self.show_prompt(
+            ConfirmationPrompt::for_action(tool_name, &action, description, risk_score),
+            PromptCatalog::default(),
+        )
+    }
+
+    /// Show a structured prompt and return a receiver for the response
+    pub fn show_prompt(
+        &mut self,
+        prompt: ConfirmationPrompt,
+        catalog: PromptCatalog,
     ) -> tokio::sync::oneshot::Receiver<PermissionResponse> {
         let (tx, rx) = tokio::sync::oneshot::channel();
 
-        self.current = Some(PermissionModal::new(
-            tool_name,
-            action,
-            description,
-            risk_score,
-        ));
+        self.current = Some(PermissionModal::from_prompt(prompt, catalog));
         self.response_tx = Some(tx);
 
         rx
@@ -455,7 +545,7 @@ mod tests {
         let modal = PermissionModal::new("bash", action, "Delete temporary files", 5);
 
         assert!(modal.is_visible());
-        assert_eq!(modal.risk_score, 5);
+        assert_eq!(modal.prompt().risk_score, 5);
         assert_eq!(modal.selected, 0);
     }
 
@@ -525,4 +615,32 @@ mod tests {
         let dangerous = PermissionModal::new("bash", action, "", 10);
         assert_eq!(dangerous.risk_text(), "DANGEROUS");
     }
+
+    #[test]
+    fn test_structured_prompt() {
+        let action = PermissionAction::FileWrite {
+            path: "src/lib.rs".to_string(),
+        };
+        let prompt = ConfirmationPrompt::for_action("edit", &action, "Edit file: src/lib.rs", 5)
+            .with_preview(PromptPreview::diff(
+                "src/lib.rs",
+                "--- a/src/lib.rs\n-old\n+new",
+            ))
+            .with_options([ConfirmOption::AllowOnce, ConfirmOption::Deny]);
+        let catalog = PromptCatalog::new().with("permission.option.deny", "거부");
+        let mut modal = PermissionModal::from_prompt(prompt, catalog);
+
+        assert_eq!(modal.options.len(), 2);
+        assert_eq!(modal.options[1].label, "거부");
+        assert_eq!(modal.handle_key('d'), Some(PermissionResponse::Deny));
+        assert_eq!(modal.handle_key('s'), None);
+
+        let lines: Vec<String> = modal
+            .detail_lines()
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(lines[0], "Write file: src/lib.rs");
+        assert!(lines.contains(&"+new".to_string()));
+    }
 }
@@ -10,6 +10,7 @@
 
 #![allow(dead_code)]
 
+use forge_foundation::permission::{GrantCoverage, Permission, PermissionScope};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -98,10 +99,45 @@ impl SettingItem {
             SettingValue::Choice { selected, options } => {
                 options.get(*selected).cloned().unwrap_or_default()
             }
+            SettingValue::Info(s) => s.clone(),
         }
     }
+
+    /// Read-only row for an active permission grant
+    ///
+    /// Shows what the grant covers and when it expires ("expires in 29m").
+    pub fn from_grant(permission: &Permission) -> Self {
+        let target = match &permission.coverage {
+            GrantCoverage::Exact => permission.action.description(),
+            GrantCoverage::Tool => "all actions".to_string(),
+            GrantCoverage::Path(dir) => format!("under {}", dir.display()),
+        };
+        let expiry = match permission.scope {
+            PermissionScope::Timed { .. } => {
+                let minutes = permission
+                    .scope
+                    .remaining()
+                    .unwrap_or_default()
+                    .as_secs()
+                    .div_ceil(60);
+                format!("expires in {}m", minutes)
+            }
+            PermissionScope::Permanent => "permanent".to_string(),
+            PermissionScope::Session | PermissionScope::Once => "this session".to_string(),
+        };
+
+        Self::new(
+            format!("{}{}", GRANT_KEY_PREFIX, permission.tool_name),
+            format!("{}: {}", permission.tool_name, target),
+        )
+        .with_description("Active permission grant")
+        .with_value(SettingValue::Info(expiry))
+    }
 }
 
+/// Key prefix of active grant rows in the Permissions tab
+const GRANT_KEY_PREFIX: &str = "grant.";
+
 /// Setting value types
 #[derive(Debug, Clone)]
 pub enum SettingValue {
@@ -112,6 +148,8 @@ pub enum SettingValue {
         selected: usize,
         options: Vec<String>,
     },
+    /// Read-only information (not editable)
+    Info(String),
 }
 
 impl SettingValue {
@@ -323,6 +361,23 @@ impl SettingsPage {
         self.has_changes
     }
 
+    /// Show active permission grants in the Permissions tab
+    ///
+    /// Replaces previously shown grants; expired grants are left out.
+    pub fn set_active_grants(&mut self, grants: &[Permission]) {
+        self.permission_settings
+            .retain(|item| !item.key.starts_with(GRANT_KEY_PREFIX));
+        self.permission_settings.extend(
+            grants
+                .iter()
+                .filter(|grant| !grant.is_expired())
+                .map(SettingItem::from_grant),
+        );
+        let max = self.current_settings().len().saturating_sub(1);
+        self.selected_item = self.selected_item.min(max);
+        self.list_state.select(Some(self.selected_item));
+    }
+
     /// Get current settings for the active tab
     fn current_settings(&self) -> &[SettingItem] {
         match self.current_tab {
@@ -417,8 +472,8 @@ impl SettingsPage {
                     item.modified = true;
                     self.has_changes = true;
                 }
-                SettingValue::String(_) | SettingValue::Number(_) => {
-                    // String handled above, Number not implemented
+                SettingValue::String(_) | SettingValue::Number(_) | SettingValue::Info(_) => {
+                    // String handled above, Number not implemented, Info is read-only
                 }
             }
         }
@@ -773,6 +828,43 @@ mod tests {
         assert!(page.has_unsaved_changes());
     }
 
+    #[test]
+    fn test_active_grants() {
+        use forge_foundation::permission::PermissionAction;
+        use std::time::Duration;
+
+        let mut page = SettingsPage::new();
+        let base = page.permission_settings.len();
+        let bash = Permission::new(
+            "bash",
+            PermissionAction::Execute {
+                command: "*".to_string(),
+            },
+            PermissionScope::for_duration(Duration::from_secs(30 * 60)),
+        )
+        .for_any();
+        let write = Permission::new(
+            "write",
+            PermissionAction::FileWrite {
+                path: "src".to_string(),
+            },
+            PermissionScope::Session,
+        )
+        .under("/work/src");
+
+        page.set_active_grants(&[bash.clone(), write]);
+        let grants = &page.permission_settings[base..];
+        assert_eq!(grants.len(), 2);
+        assert_eq!(grants[0].label, "bash: all actions");
+        assert_eq!(grants[0].display_value(), "expires in 30m");
+        assert_eq!(grants[1].label, "write: under /work/src");
+        assert_eq!(grants[1].display_value(), "this session");
+
+        // Refreshing replaces the previous rows
+        page.set_active_grants(&[bash]);
+        assert_eq!(page.permission_settings.len(), base + 1);
+    }
+
     #[test]
     fn test_setting_value_display() {
         let item = SettingItem::new("api_key", "API Key")
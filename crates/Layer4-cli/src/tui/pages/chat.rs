@@ -9,27 +9,41 @@
 
 #![allow(dead_code)]
 
+use crate::clipboard_tool::register_clipboard_tools;
 use crate::cost::CostTracker;
 use crate::session::SessionManager;
+use crate::stats::{self, StatsRange, UsageDashboard};
 use crate::tui::components::{ModelSwitcher, ModelSwitcherAction, PermissionModalManager};
 use crate::tui::widgets::{
     AgentStatus, ChatMessage, ChatView, ChatViewState, Header, HeaderState, InputArea, InputState,
-    MessageRole, SpinnerState, StatusBar, StatusBarState, ToolBlock, ToolExecutionState,
+    McpPanel, MessageRole, SpinnerState, StatusBar, StatusBarState, ToolBlock, ToolExecutionState,
     WelcomeScreen,
 };
 use crate::tui::{current_theme, HelpOverlay, Theme};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use forge_agent::{Agent, AgentContext, AgentEvent, MessageHistory, SteeringHandle};
-use forge_core::ToolRegistry;
-use forge_foundation::{PermissionService, ProviderConfig};
+use forge_agent::{
+    agent_event_channel, execute_pipeline, execute_plan, load_skills, load_subagents,
+    register_mcp_prompts, register_subagents, Agent, AgentConfig, AgentContext, AgentEvent,
+    AgentEventReceiver, MessageHistory, SkillInvocation, SteeringHandle, ToolExecutionRecorder,
+};
+use forge_core::{
+    CheckpointManager, DryRunRecorder, ServerStatus, SkillPlan, SkillRegistry, ToolRegistry,
+    GHOST_REF_PREFIX,
+};
+use forge_foundation::permission::Permission;
+use forge_foundation::{
+    PermissionService, ProviderConfig, SessionRecord, StorageBackend, TokenUsageRecord,
+};
 use forge_provider::Gateway;
 use forge_task::TaskManager;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use std::time::Instant;
+use tokio::sync::watch;
 
 /// Chat page state - Claude Code 스타일 UI
 pub struct ChatPage {
@@ -64,16 +78,44 @@ pub struct ChatPage {
     // === UI 상태 ===
     /// Permission modal manager
     permission_modal: PermissionModalManager,
+    /// Permission service shared with the agent (for the settings panel)
+    permissions: Option<Arc<PermissionService>>,
     /// Show help overlay
     show_help: bool,
     /// Model switcher component
     model_switcher: ModelSwitcher,
+    /// Show MCP server status panel (`/mcp`)
+    show_mcp_panel: bool,
+    /// MCP server status stream
+    mcp_status: Option<watch::Receiver<Vec<ServerStatus>>>,
+    /// Latest MCP server statuses
+    mcp_statuses: Vec<ServerStatus>,
 
     // === 새로운 기능 ===
     /// Cost tracker
     cost_tracker: CostTracker,
     /// Session manager
     session_manager: SessionManager,
+    /// 사용량 통계 저장소 (`forge stats`)
+    storage: Option<Arc<dyn StorageBackend>>,
+    /// 현재 턴의 LLM 요청 시작 시각 (지연 측정용)
+    turn_started_at: Option<Instant>,
+
+    // === 되감기 (/rewind) ===
+    /// 마지막 실행 직전의 (채팅 메시지 수, 히스토리 길이)
+    run_start: (usize, usize),
+    /// 타임라인 턴 → 그 턴을 시작하기 직전의 (채팅 메시지 수, 히스토리 길이)
+    rewind_points: HashMap<u32, (usize, usize)>,
+
+    // === 스킬 ===
+    /// 슬래시 명령어로 호출 가능한 스킬 (builtin + SKILL.md + MCP 프롬프트)
+    skills: SkillRegistry,
+    /// 필수 인자를 입력받는 중인 스킬 호출 (다음 입력이 인자 값)
+    pending_skill: Option<SkillInvocation>,
+    /// 진행 중인 dry-run (호출, 레코더, 에이전트에 보낸 메시지)
+    dry_run: Option<(SkillInvocation, Arc<DryRunRecorder>, String)>,
+    /// 승인 대기 중인 계획 (`/approve`, `/reject`)
+    pending_plan: Option<SkillPlan>,
 }
 
 impl ChatPage {
@@ -96,15 +138,29 @@ impl ChatPage {
             paused: false,
             steering_handle: None,
             permission_modal: PermissionModalManager::new(),
+            permissions: None,
             show_help: false,
             model_switcher: ModelSwitcher::new(),
+            show_mcp_panel: false,
+            mcp_status: None,
+            mcp_statuses: Vec::new(),
             cost_tracker: CostTracker::new(),
             session_manager: SessionManager::new(),
+            storage: stats::open_storage().ok(),
+            turn_started_at: None,
+            run_start: (0, 0),
+            rewind_points: HashMap::new(),
+            skills: SkillRegistry::with_builtins(),
+            pending_skill: None,
+            dry_run: None,
+            pending_plan: None,
         }
     }
 
     /// Initialize with configuration
-    pub async fn init(&mut self, config: &ProviderConfig) -> Result<(), String> {
+    ///
+    /// With `read_only`, every write, execute and network action is denied.
+    pub async fn init(&mut self, config: &ProviderConfig, read_only: bool) -> Result<(), String> {
         // Create gateway
         let gateway =
             Gateway::from_config(config).map_err(|e| format!("Failed to initialize LLM: {}", e))?;
@@ -118,30 +174,61 @@ impl ChatPage {
         }
 
         // Create tools
-        let tools = ToolRegistry::with_builtins();
+        let mut tools = ToolRegistry::with_builtins();
+        register_clipboard_tools(&mut tools);
 
         // Create permissions (with auto-approve for now, will integrate modal later)
-        let permissions = PermissionService::with_auto_approve();
+        let permissions = Arc::new(PermissionService::with_auto_approve());
+        permissions.set_read_only(read_only);
+        self.permissions = Some(permissions.clone());
 
         // Get working directory
         let working_dir = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
         self.header.cwd = working_dir.to_string_lossy().to_string();
+        self.skills = load_skills(&working_dir);
 
         // Create task manager for long-running commands (servers, PTY)
         let task_manager = Arc::new(TaskManager::new(forge_task::TaskManagerConfig::default()).await);
 
         // Create context with task manager
-        self.ctx = Some(Arc::new(
-            AgentContext::new(
-                Arc::new(gateway),
-                Arc::new(tools),
-                Arc::new(permissions),
-                working_dir,
-            ).with_task_manager(task_manager)
-        ));
+        let ctx = AgentContext::new(
+            Arc::new(gateway),
+            Arc::new(tools),
+            permissions,
+            working_dir,
+        )
+        .with_task_manager(task_manager);
+
+        // MCP server prompts become `/<server>:<prompt>` skills
+        register_mcp_prompts(&mut self.skills, &ctx).await;
+
+        // MCP server health (restarts crashed stdio servers, feeds the `/mcp` panel)
+        self.mcp_status = Some(ctx.mcp_status_stream().await);
+        ctx.start_mcp_health_monitor();
+        // Re-register tools when a server sends `notifications/tools/list_changed`
+        ctx.start_mcp_tool_sync();
+
+        // Optional subsystems that failed to initialize (agent keeps running without them)
+        let capabilities = ctx.capabilities();
+        self.status_bar.set_degraded(
+            capabilities
+                .degraded()
+                .into_iter()
+                .map(|(capability, _)| capability.name().to_string())
+                .collect(),
+        );
+
+        // `.forgecode/agents/*.md` sub-agents become the `subagent` tool
+        let ctx = Arc::new(ctx);
+        register_subagents(&ctx, load_subagents(&ctx.working_dir)).await;
+        self.ctx = Some(ctx);
 
         self.header.agent_status = AgentStatus::Ready;
-        self.status_bar.info("Connected");
+        match capabilities.summary() {
+            Some(summary) => self.status_bar.warning(format!("Reduced capability: {}", summary)),
+            None if read_only => self.status_bar.info("Connected (read-only)"),
+            None => self.status_bar.info("Connected"),
+        }
 
         Ok(())
     }
@@ -176,6 +263,12 @@ impl ChatPage {
             return None;
         }
 
+        // Handle MCP panel
+        if self.show_mcp_panel {
+            self.show_mcp_panel = false;
+            return None;
+        }
+
         // Global shortcuts
         match (key.modifiers, key.code) {
             // Ctrl+C: Quit
@@ -244,8 +337,8 @@ impl ChatPage {
             KeyCode::Enter => {
                 let content = self.input.take(); // take() already adds to history
                 if !content.is_empty() {
-                    // Check slash command
-                    if content.starts_with('/') {
+                    // Check slash command (a pending skill argument may start with '/')
+                    if content.starts_with('/') && self.pending_skill.is_none() {
                         return Some(ChatAction::SlashCommand(content));
                     }
                     return Some(ChatAction::SendMessage(content));
@@ -313,8 +406,379 @@ impl ChatPage {
         None
     }
 
-    /// Handle slash commands
-    pub fn handle_slash_command(&mut self, cmd: &str) {
+    /// Run a slash command: built-in commands, plan approval, then skills
+    ///
+    /// Returns an event receiver when the command started agent (or plan) execution.
+    pub async fn run_slash_command(&mut self, cmd: &str) -> Option<AgentEventReceiver> {
+        if self.handle_slash_command(cmd) {
+            return None;
+        }
+
+        let command = cmd.split_whitespace().next().unwrap_or_default().to_lowercase();
+        match command.as_str() {
+            "/approve" => return self.approve_plan(),
+            "/reject" => {
+                let message = match self.pending_plan.take() {
+                    Some(plan) => format!("Discarded plan for /{}.", plan.skill),
+                    None => "No plan awaiting approval.".to_string(),
+                };
+                self.chat.push(ChatMessage::system(message));
+                return None;
+            }
+            _ => {}
+        }
+
+        let Some(invocation) = SkillInvocation::parse(cmd, &self.skills) else {
+            self.chat
+                .push(ChatMessage::system(format!("Unknown command: {}", command)));
+            return None;
+        };
+        if !invocation.missing_arguments().is_empty() {
+            self.ask_skill_argument(invocation);
+            return None;
+        }
+        if let Err(e) = invocation.validate() {
+            self.chat.push(ChatMessage::system(format!("Error: {}", e)));
+            return None;
+        }
+        self.run_skill(cmd, invocation).await
+    }
+
+    /// Ask for the next missing required argument of a skill
+    fn ask_skill_argument(&mut self, invocation: SkillInvocation) {
+        let Some(argument) = invocation.missing_arguments().into_iter().next() else {
+            return;
+        };
+        let hint = argument
+            .hint()
+            .map(|hint| format!(" ({})", hint))
+            .unwrap_or_default();
+        self.chat.push(ChatMessage::system(format!(
+            "/{} needs {}: {}{}\nType a value, or /cancel.",
+            invocation.name(),
+            argument.name,
+            argument.description,
+            hint
+        )));
+        self.status_bar
+            .info(format!("/{}: enter {}", invocation.name(), argument.name));
+        self.pending_skill = Some(invocation);
+    }
+
+    /// Fill the pending skill's next argument with `value`, running the skill once complete
+    async fn answer_skill_argument(
+        &mut self,
+        invocation: SkillInvocation,
+        value: &str,
+    ) -> Option<AgentEventReceiver> {
+        if value.trim() == "/cancel" {
+            self.chat
+                .push(ChatMessage::system(format!("Cancelled /{}.", invocation.name())));
+            return None;
+        }
+
+        let argument = invocation.missing_arguments().into_iter().next()?;
+        let value = match argument.validate(value) {
+            Ok(value) => value,
+            Err(e) => {
+                self.chat.push(ChatMessage::system(format!(
+                    "Invalid {}: {}",
+                    argument.name, e
+                )));
+                self.ask_skill_argument(invocation);
+                return None;
+            }
+        };
+        let invocation = invocation.with_argument(&argument.name, &value);
+        if !invocation.missing_arguments().is_empty() {
+            self.ask_skill_argument(invocation);
+            return None;
+        }
+        if let Err(e) = invocation.validate() {
+            self.chat.push(ChatMessage::system(format!("Error: {}", e)));
+            return None;
+        }
+        let cmd = invocation.command().to_string();
+        self.run_skill(&cmd, invocation).await
+    }
+
+    /// Run a skill through the agent (recording a plan instead when `--dry-run` is given)
+    async fn run_skill(
+        &mut self,
+        cmd: &str,
+        invocation: SkillInvocation,
+    ) -> Option<AgentEventReceiver> {
+        let Some(ctx) = self.ctx.clone() else {
+            self.chat.push(ChatMessage::system("Not connected to an LLM provider."));
+            return None;
+        };
+        if invocation.pipeline().is_some() {
+            return Some(self.start_pipeline(cmd, invocation, ctx));
+        }
+
+        let message = match invocation.prompt(&ctx, &self.session_id).await {
+            Ok(message) => message,
+            Err(e) => {
+                self.chat.push(ChatMessage::system(format!("Error: {}", e)));
+                return None;
+            }
+        };
+
+        // 새 dry-run을 시작하면 이전 계획은 무효
+        self.pending_plan = None;
+        if invocation.is_dry_run() {
+            let recorder = invocation.begin_dry_run(&ctx);
+            self.dry_run = Some((invocation.clone(), recorder, message.clone()));
+            self.status_bar
+                .info(format!("Dry run: /{} (changes are planned, not executed)", invocation.name()));
+        }
+
+        // Skills that restrict tools or model run in their own sub-agent context;
+        // `context: fork` skills also start without the conversation history
+        let config = invocation.agent_config(AgentConfig::default());
+        let skill_ctx = invocation.agent_context(&ctx);
+        Some(self.start_agent_in(
+            cmd.to_string(),
+            message,
+            config,
+            Some(skill_ctx),
+            invocation.forks_context(),
+        ))
+    }
+
+    /// Run a skill's declared steps, showing each step as a tool block
+    fn start_pipeline(
+        &mut self,
+        cmd: &str,
+        invocation: SkillInvocation,
+        ctx: Arc<AgentContext>,
+    ) -> AgentEventReceiver {
+        self.pending_plan = None;
+        if invocation.is_dry_run() {
+            let recorder = invocation.begin_dry_run(&ctx);
+            self.dry_run = Some((invocation.clone(), recorder, cmd.to_string()));
+            self.status_bar.info(format!(
+                "Dry run: /{} (changes are planned, not executed)",
+                invocation.name()
+            ));
+        }
+
+        self.run_start = (self.chat.messages.len(), self.history.len());
+        self.chat.push(ChatMessage::user(cmd.to_string()));
+        self.history.add_user(cmd.to_string());
+        // 도구 블록은 어시스턴트 메시지에 붙으므로 먼저 추가
+        let steps = invocation
+            .pipeline()
+            .map_or(0, |pipeline| pipeline.steps.len());
+        self.chat.push(ChatMessage::assistant(format!(
+            "Running {} step{} for /{}.",
+            steps,
+            if steps == 1 { "" } else { "s" },
+            invocation.name()
+        )));
+        self.set_running();
+
+        let (tx, rx) = agent_event_channel(100);
+        let skills = self.skills.clone();
+        let session_id = self.session_id.clone();
+        tokio::spawn(async move {
+            let config = AgentConfig::default();
+            let _ = execute_pipeline(&ctx, &skills, &invocation, &config, &session_id, tx).await;
+        });
+        rx
+    }
+
+    /// Execute the plan awaiting approval
+    fn approve_plan(&mut self) -> Option<AgentEventReceiver> {
+        let Some(plan) = self.pending_plan.take() else {
+            self.chat
+                .push(ChatMessage::system("No plan awaiting approval."));
+            return None;
+        };
+        let Some(ctx) = self.ctx.clone() else {
+            self.chat.push(ChatMessage::system("Not connected to an LLM provider."));
+            return None;
+        };
+
+        self.chat.push(ChatMessage::user("/approve"));
+        // 도구 블록은 어시스턴트 메시지에 붙으므로 먼저 추가
+        let steps = plan.executable_steps().count();
+        self.chat.push(ChatMessage::assistant(format!(
+            "Executing {} planned step{} for /{}.",
+            steps,
+            if steps == 1 { "" } else { "s" },
+            plan.skill
+        )));
+        self.set_running();
+
+        let (tx, rx) = agent_event_channel(100);
+        tokio::spawn(async move {
+            let _ = execute_plan(&ctx, &plan, tx).await;
+        });
+        Some(rx)
+    }
+
+    /// Close an in-progress dry run and show its plan as a checklist
+    fn finish_dry_run(&mut self) {
+        let Some((invocation, recorder, message)) = self.dry_run.take() else {
+            return;
+        };
+        let Some(ctx) = self.ctx.clone() else {
+            return;
+        };
+
+        let plan = invocation.finish_dry_run(&ctx, &recorder, &message);
+        self.chat.push(ChatMessage::system(plan.render_checklist()));
+        if plan.has_actions() {
+            self.chat.push(ChatMessage::system(
+                "Type /approve to execute these steps, or /reject to discard them.",
+            ));
+            self.pending_plan = Some(plan);
+        }
+    }
+
+    /// `/roots [add|remove <dir>]` - list or change the project roots shared with MCP servers
+    fn handle_roots_command(&mut self, args: &[&str]) {
+        let Some(ctx) = self.ctx.clone() else {
+            self.status_bar.warning("Agent is not ready yet");
+            return;
+        };
+        let mut roots = ctx.mcp_roots();
+
+        let (action, dir) = match args {
+            [] => {
+                let message = if roots.is_empty() {
+                    "No MCP roots configured.".to_string()
+                } else {
+                    let mut info = String::from("📁 **MCP Roots**\n");
+                    for root in &roots {
+                        info.push_str(&format!("• {}\n", root.uri));
+                    }
+                    info
+                };
+                self.chat.push(ChatMessage::system(message));
+                return;
+            }
+            [action, dir @ ..] if !dir.is_empty() => (*action, dir.join(" ")),
+            _ => {
+                self.status_bar.warning("Usage: /roots [add|remove <dir>]");
+                return;
+            }
+        };
+
+        let path = std::path::Path::new(&self.header.cwd).join(&dir);
+        let root = forge_core::McpRoot::from_path(&path);
+        match action {
+            "add" if !path.is_dir() => {
+                self.status_bar.error(format!("Not a directory: {}", dir));
+                return;
+            }
+            "add" if roots.contains(&root) => {
+                self.status_bar.info(format!("Already a root: {}", dir));
+                return;
+            }
+            "add" => roots.push(root),
+            "remove" => {
+                let before = roots.len();
+                roots.retain(|r| r.uri != root.uri);
+                if roots.len() == before {
+                    self.status_bar.warning(format!("Not a root: {}", dir));
+                    return;
+                }
+            }
+            _ => {
+                self.status_bar.warning("Usage: /roots [add|remove <dir>]");
+                return;
+            }
+        }
+
+        let message = format!("MCP roots updated ({})", roots.len());
+        tokio::spawn(async move {
+            ctx.set_mcp_roots(roots).await;
+        });
+        self.status_bar.success(message);
+    }
+
+    /// `/rewind [turn]` - list the session's timeline, or restore the workspace
+    /// (and conversation) to just before a turn
+    fn handle_rewind_command(&mut self, turn: Option<&str>) {
+        if self.running {
+            self.status_bar.warning("Stop the agent before rewinding");
+            return;
+        }
+        let manager = match CheckpointManager::new(&self.header.cwd) {
+            Ok(manager) => manager,
+            Err(e) => {
+                self.status_bar
+                    .error(format!("Rewind needs a git repository: {}", e));
+                return;
+            }
+        };
+
+        let Some(turn) = turn else {
+            let message = match manager.timeline(&self.session_id) {
+                Ok(timeline) if timeline.is_empty() => {
+                    "No file changes recorded in this session yet.".to_string()
+                }
+                Ok(timeline) => {
+                    let mut info = String::from("⏪ **Timeline**\n");
+                    for entry in &timeline {
+                        info.push_str(&format!("• {}\n", entry.summary()));
+                    }
+                    info.push_str(
+                        "Type /rewind <turn> to restore the state from just before that turn.",
+                    );
+                    info
+                }
+                Err(e) => format!("Failed to read the timeline: {}", e),
+            };
+            self.chat.push(ChatMessage::system(message));
+            return;
+        };
+        let Ok(turn) = turn.trim_start_matches('#').parse::<u32>() else {
+            self.status_bar.warning("Usage: /rewind [turn]");
+            return;
+        };
+
+        let entry = match manager.rewind(&self.session_id, turn) {
+            Ok(entry) => entry,
+            Err(e) => {
+                self.status_bar.error(format!("Rewind failed: {}", e));
+                return;
+            }
+        };
+
+        // 이 프로세스에서 기록된 턴이면 대화도 그 턴 직전으로 되돌림
+        let conversation = match self.rewind_points.get(&turn).copied() {
+            Some((chat_len, history_len)) => {
+                self.chat.messages.truncate(chat_len);
+                self.chat.invalidate_layout();
+                self.chat.scroll_to_bottom();
+                self.history.truncate(history_len);
+                self.rewind_points.retain(|t, _| *t < turn);
+                " and conversation"
+            }
+            None => "",
+        };
+        self.chat.push(ChatMessage::system(format!(
+            "Rewound workspace{} to before #{} {} ({} file{} restored). \
+             The replaced state is kept in {}/{}/rewound.",
+            conversation,
+            entry.turn,
+            entry.description,
+            entry.files.len(),
+            if entry.files.len() == 1 { "" } else { "s" },
+            GHOST_REF_PREFIX,
+            self.session_id
+        )));
+        self.status_bar
+            .success(format!("Rewound to before turn {}", turn));
+    }
+
+    /// Handle built-in slash commands
+    ///
+    /// Returns false when the command is not built in.
+    pub fn handle_slash_command(&mut self, cmd: &str) -> bool {
         let parts: Vec<&str> = cmd.trim().split_whitespace().collect();
         let command = parts.first().map(|s| s.to_lowercase()).unwrap_or_default();
 
@@ -333,6 +797,7 @@ impl ChatPage {
                 self.history = MessageHistory::new();
                 self.session_id = uuid::Uuid::new_v4().to_string();
                 self.header.session_id = self.session_id[..8].to_string();
+                self.rewind_points.clear();
                 self.header.tokens = (0, 0);
                 self.header.context_usage = 0.0;
                 self.header.current_turn = 0;
@@ -341,6 +806,12 @@ impl ChatPage {
             "/model" => {
                 self.model_switcher.show();
             }
+            "/mcp" => {
+                self.update_mcp_status();
+                self.show_mcp_panel = true;
+            }
+            "/roots" => self.handle_roots_command(&parts[1..]),
+            "/rewind" => self.handle_rewind_command(parts.get(1).copied()),
             "/status" => {
                 let status = format!(
                     "Provider: {} | Model: {} | Tokens: {}↓ {}↑ | Context: {:.0}%",
@@ -369,6 +840,20 @@ impl ChatPage {
                 );
                 self.chat.push(ChatMessage::system(cost_info));
             }
+            "/stats" => {
+                let days = parts
+                    .get(1)
+                    .and_then(|d| d.parse().ok())
+                    .unwrap_or(stats::DEFAULT_DAYS);
+                let message = match &self.storage {
+                    Some(storage) => match UsageDashboard::load(storage.as_ref(), StatsRange::last_days(days)) {
+                        Ok(dashboard) => format!("📊 **Usage**\n```\n{}```", dashboard.render()),
+                        Err(e) => format!("Failed to load usage stats: {}", e),
+                    },
+                    None => "Usage database is not available.".to_string(),
+                };
+                self.chat.push(ChatMessage::system(message));
+            }
             "/sessions" => {
                 let sessions = self.session_manager.list_sessions();
                 if sessions.is_empty() {
@@ -384,39 +869,76 @@ impl ChatPage {
                     self.chat.push(ChatMessage::system(info));
                 }
             }
-            _ => {
-                self.chat
-                    .push(ChatMessage::system(format!("Unknown command: {}", command)));
-            }
+            _ => return false,
         }
+        true
     }
 
-    /// Send a message to the agent
-    pub async fn send_message(&mut self, content: String) -> mpsc::Receiver<AgentEvent> {
-        // Add user message to display
-        self.chat.push(ChatMessage::user(content.clone()));
-
-        // Add to history
-        self.history.add_user(content.clone());
+    /// Send a message to the agent (or answer a pending skill argument)
+    pub async fn send_message(&mut self, content: String) -> Option<AgentEventReceiver> {
+        if let Some(invocation) = self.pending_skill.take() {
+            return self.answer_skill_argument(invocation, &content).await;
+        }
+        Some(self.start_agent(content.clone(), content, AgentConfig::default()))
+    }
 
-        // Set running state
+    /// Mark the page as running (input disabled until Done/Error/Stopped)
+    fn set_running(&mut self) {
         self.running = true;
         self.input.disable("Agent running...");
         self.header.agent_status = AgentStatus::Thinking;
         self.status_bar.set_running_mode();
+    }
 
-        // Create channel for events
-        let (tx, rx) = mpsc::channel(100);
-
-        // Clone context
+    /// Start the agent with `message`, showing `display` as the user message
+    fn start_agent(
+        &mut self,
+        display: String,
+        message: String,
+        config: AgentConfig,
+    ) -> AgentEventReceiver {
         let ctx = self.ctx.clone();
+        self.start_agent_in(display, message, config, ctx, false)
+    }
+
+    /// Start the agent in the given context (`fork`: without the conversation history)
+    fn start_agent_in(
+        &mut self,
+        display: String,
+        message: String,
+        config: AgentConfig,
+        ctx: Option<Arc<AgentContext>>,
+        fork: bool,
+    ) -> AgentEventReceiver {
+        // Remember where this run starts (for /rewind)
+        self.run_start = (self.chat.messages.len(), self.history.len());
+
+        // Add user message to display
+        self.chat.push(ChatMessage::user(display.clone()));
+
+        // Add to history
+        self.history.add_user(display);
+
+        // Set running state
+        self.set_running();
+
+        // Create channel for events (streaming text is coalesced under pressure)
+        let (tx, rx) = agent_event_channel(100);
+
         let session_id = self.session_id.clone();
-        let mut history = self.history.clone();
-        let user_message = content.clone();
+        let mut history = if fork {
+            MessageHistory::new()
+        } else {
+            self.history.clone()
+        };
+        let user_message = message;
 
         // Create agent and get steering handle
         if let Some(ref ctx) = ctx {
-            let agent = Agent::new(ctx.clone());
+            let mut agent = Agent::with_config(ctx.clone(), config);
+            if let Some(ref storage) = self.storage {
+                agent = agent.with_tool_recorder(ToolExecutionRecorder::new(storage.clone()));
+            }
             self.steering_handle = Some(agent.steering_handle());
 
             // Spawn agent task
@@ -457,6 +979,7 @@ impl ChatPage {
         self.header.agent_status = AgentStatus::Ready;
         self.status_bar.set_normal_mode();
         self.status_bar.warning("Agent stopped");
+        self.finish_dry_run();
     }
 
     /// Handle agent event
@@ -485,6 +1008,12 @@ impl ChatPage {
                 let block = ToolBlock::new(&tool_name);
                 self.chat.add_tool_block(block);
             }
+            AgentEvent::ToolOutput { chunk, .. } => {
+                self.chat.append_last_tool_output(&chunk);
+            }
+            AgentEvent::ToolProgress { progress, .. } => {
+                self.chat.set_last_tool_progress(progress);
+            }
             AgentEvent::ToolComplete {
                 result,
                 success,
@@ -503,6 +1032,7 @@ impl ChatPage {
             }
             AgentEvent::TurnStart { turn } => {
                 self.header.current_turn = turn;
+                self.turn_started_at = Some(Instant::now());
             }
             AgentEvent::TurnComplete { .. } => {
                 // Finish streaming on last message
@@ -510,6 +1040,27 @@ impl ChatPage {
                     last.streaming = false;
                 }
             }
+            AgentEvent::TurnSummary { summary } => {
+                self.chat.push(ChatMessage::system(summary));
+            }
+            AgentEvent::GhostCommit { turn } => {
+                self.rewind_points.insert(turn, self.run_start);
+                self.status_bar.info(format!(
+                    "Checkpoint #{} recorded (/rewind {} to undo)",
+                    turn, turn
+                ));
+            }
+            AgentEvent::AutoCommitted { hash } => {
+                self.status_bar.info(format!("Committed {}", hash));
+            }
+            AgentEvent::PreCommitFailed { summary, retrying } => {
+                let next = if retrying {
+                    "agent is fixing"
+                } else {
+                    "changes left uncommitted"
+                };
+                self.status_bar.warning(format!("{} ({})", summary, next));
+            }
             AgentEvent::Compressed {
                 tokens_before,
                 tokens_after,
@@ -538,6 +1089,7 @@ impl ChatPage {
                 self.header.agent_status = AgentStatus::Ready;
                 self.status_bar.set_normal_mode();
                 self.status_bar.warning(&format!("Stopped: {}", reason));
+                self.finish_dry_run();
             }
             AgentEvent::Done { .. } => {
                 self.running = false;
@@ -551,6 +1103,7 @@ impl ChatPage {
                 if let Some(last) = self.chat.messages.last_mut() {
                     last.streaming = false;
                 }
+                self.finish_dry_run();
             }
             AgentEvent::Error(e) => {
                 self.running = false;
@@ -562,6 +1115,7 @@ impl ChatPage {
                 self.status_bar.error(&truncate(&e, 50));
                 self.chat
                     .push(ChatMessage::system(format!("Error: {}", e)));
+                self.finish_dry_run();
             }
             AgentEvent::Usage {
                 input_tokens,
@@ -572,13 +1126,16 @@ impl ChatPage {
                 self.header.context_usage = (self.header.tokens.0 as f32) / 200_000.0;
 
                 // 비용 추적
-                let _cost = self.cost_tracker.record_usage(
+                let cost = self.cost_tracker.record_usage(
                     &self.header.model,
                     input_tokens as u64,
                     output_tokens as u64,
                     0, // cached tokens
                 );
 
+                // 통계 기록 (forge stats)
+                self.record_usage_stats(input_tokens, output_tokens, cost);
+
                 // 예산 경고 확인
                 if let Some(warning) = self.cost_tracker.check_budget() {
                     self.status_bar.warning(&warning.message());
@@ -587,10 +1144,92 @@ impl ChatPage {
         }
     }
 
+    /// 사용량을 통계 저장소에 기록
+    fn record_usage_stats(&mut self, input_tokens: u32, output_tokens: u32, cost: f64) {
+        let Some(ref storage) = self.storage else {
+            return;
+        };
+
+        // token_usage는 sessions를 참조하므로 세션 레코드가 먼저 있어야 함
+        if !matches!(storage.get_session(&self.session_id), Ok(Some(_))) {
+            let _ = storage.create_session(&SessionRecord {
+                id: self.session_id.clone(),
+                working_directory: Some(self.header.cwd.clone()),
+                provider: Some(self.header.provider.clone()),
+                model: Some(self.header.model.clone()),
+                ..Default::default()
+            });
+        }
+
+        let latency_ms = self
+            .turn_started_at
+            .take()
+            .map(|started| started.elapsed().as_millis() as i64);
+
+        let _ = storage.record_usage(&TokenUsageRecord {
+            session_id: Some(self.session_id.clone()),
+            provider: self.header.provider.clone(),
+            model: self.header.model.clone(),
+            input_tokens: input_tokens as i64,
+            output_tokens: output_tokens as i64,
+            cache_read_tokens: 0,
+            cache_write_tokens: 0,
+            thinking_tokens: 0,
+            cost_cents: (cost * 100.0).round() as i64,
+            latency_ms,
+        });
+    }
+
     /// Tick for animations
     pub fn tick(&mut self) {
         self.spinner.tick();
         self.status_bar.check_timeout();
+        self.update_mcp_status();
+        if let Some(permissions) = &self.permissions {
+            for expired in permissions.prune_expired() {
+                self.status_bar
+                    .info(format!("Permission expired: {}", expired.tool_name));
+            }
+        }
+    }
+
+    /// Pull the latest MCP server statuses and notify when a server goes down or recovers
+    fn update_mcp_status(&mut self) {
+        let Some(rx) = &mut self.mcp_status else {
+            return;
+        };
+        if !rx.has_changed().unwrap_or(false) {
+            return;
+        }
+
+        let statuses = rx.borrow_and_update().clone();
+        for status in &statuses {
+            let was_healthy = self
+                .mcp_statuses
+                .iter()
+                .find(|previous| previous.name == status.name)
+                .map_or(true, |previous| previous.healthy);
+            if was_healthy && !status.healthy {
+                self.status_bar
+                    .warning(format!("MCP server '{}' is down (/mcp)", status.name));
+            } else if !was_healthy && status.healthy {
+                self.status_bar
+                    .success(format!("MCP server '{}' restarted", status.name));
+            }
+        }
+        self.mcp_statuses = statuses;
+    }
+
+    /// Active session and permanent permission grants
+    pub fn permission_grants(&self) -> Vec<Permission> {
+        self.permissions
+            .as_ref()
+            .map(|p| {
+                let mut grants = p.session_grants();
+                grants.extend(p.permanent_grants());
+                grants
+            })
+            .unwrap_or_default()
     }
 
     /// Render the chat page with new widgets
@@ -638,7 +1277,11 @@ impl ChatPage {
         frame.render_widget(status_bar, chunks[3]);
 
         // Set cursor position if not running
-        if !self.running && !self.show_help && !self.model_switcher.is_visible() {
+        if !self.running
+            && !self.show_help
+            && !self.show_mcp_panel
+            && !self.model_switcher.is_visible()
+        {
             let cursor_x = chunks[2].x + 3 + self.input.cursor as u16;
             let cursor_y = chunks[2].y + 1;
             frame.set_cursor_position((cursor_x.min(chunks[2].right() - 2), cursor_y));
@@ -651,6 +1294,10 @@ impl ChatPage {
         if self.show_help {
             frame.render_widget(HelpOverlay::new(), area);
         }
+        if self.show_mcp_panel {
+            let panel = McpPanel::new(&self.mcp_statuses).with_theme(self.theme.clone());
+            frame.render_widget(panel, area);
+        }
     }
 }
 
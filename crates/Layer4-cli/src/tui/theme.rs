@@ -8,7 +8,7 @@
 use ratatui::style::{Color, Modifier, Style};
 
 /// ForgeCode 테마
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Theme {
     /// 배경색
     pub bg: Color,
@@ -30,7 +30,10 @@ use ratatui::{
 
 use crate::tui::theme::{current_theme, icons, Theme};
 use crate::syntax::SyntaxHighlighter;
-use std::sync::OnceLock;
+use forge_foundation::ToolProgress;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
 
 /// 전역 구문 강조기
 static HIGHLIGHTER: OnceLock<SyntaxHighlighter> = OnceLock::new();
@@ -67,6 +70,8 @@ pub struct ToolBlock {
     pub content: String,
     /// 접힌 상태
     pub collapsed: bool,
+    /// 마지막 진행 상황 (실행 중에만 표시)
+    pub progress: Option<ToolProgress>,
 }
 
 impl ToolBlock {
@@ -76,6 +81,7 @@ impl ToolBlock {
             state: ToolExecutionState::Running,
             content: String::new(),
             collapsed: false,
+            progress: None,
         }
     }
 
@@ -170,6 +176,8 @@ pub struct ChatViewState {
     pub scroll_offset: usize,
     /// 자동 스크롤 활성화
     pub auto_scroll: bool,
+    /// 메시지별 렌더링 결과 캐시
+    layout: LayoutCacheCell,
 }
 
 impl ChatViewState {
@@ -178,6 +186,7 @@ impl ChatViewState {
             messages: Vec::new(),
             scroll_offset: 0,
             auto_scroll: true,
+            layout: LayoutCacheCell::default(),
         }
     }
 
@@ -206,6 +215,15 @@ impl ChatViewState {
     pub fn clear(&mut self) {
         self.messages.clear();
         self.scroll_offset = 0;
+        self.invalidate_layout();
+    }
+
+    /// 레이아웃 캐시 비우기
+    ///
+    /// 캐시는 메시지 끝에 추가된 내용만 다시 렌더링합니다.
+    /// `messages`의 내용을 중간부터 직접 고쳤다면 호출해야 합니다.
+    pub fn invalidate_layout(&mut self) {
+        *self.layout.get() = LayoutCache::default();
     }
 
     /// 마지막 어시스턴트 메시지에 텍스트 추가 (스트리밍용)
@@ -226,6 +244,43 @@ impl ChatViewState {
         }
     }
 
+    /// 실행 중인 마지막 도구 블록에 출력 추가 (최근 출력만 유지)
+    pub fn append_last_tool_output(&mut self, chunk: &str) {
+        const MAX_LIVE_OUTPUT: usize = 4_000;
+
+        let Some(last_tool) = self
+            .messages
+            .last_mut()
+            .and_then(|msg| msg.tool_blocks.last_mut())
+        else {
+            return;
+        };
+        if !matches!(last_tool.state, ToolExecutionState::Running) {
+            return;
+        }
+
+        last_tool.content.push_str(chunk);
+        if last_tool.content.len() > MAX_LIVE_OUTPUT {
+            let mut cut = last_tool.content.len() - MAX_LIVE_OUTPUT;
+            while !last_tool.content.is_char_boundary(cut) {
+                cut += 1;
+            }
+            last_tool.content.drain(..cut);
+        }
+    }
+
+    /// 실행 중인 마지막 도구 블록의 진행 상황 갱신
+    pub fn set_last_tool_progress(&mut self, progress: ToolProgress) {
+        if let Some(last_tool) = self
+            .messages
+            .last_mut()
+            .and_then(|msg| msg.tool_blocks.last_mut())
+            .filter(|tool| matches!(tool.state, ToolExecutionState::Running))
+        {
+            last_tool.progress = Some(progress);
+        }
+    }
+
     /// 마지막 도구 블록 업데이트
     pub fn update_last_tool(&mut self, state: ToolExecutionState, content: Option<String>) {
         if let Some(last_msg) = self.messages.last_mut() {
@@ -245,6 +300,127 @@ impl Default for ChatViewState {
     }
 }
 
+// ============================================================================
+// 레이아웃 캐시
+// ============================================================================
+
+/// 캐시 검증에 쓰는 내용 구간 크기 (바이트)
+const HASH_WINDOW: usize = 64;
+
+/// 렌더링 중에 갱신되는 캐시 (`ChatView`는 `&ChatViewState`만 받음)
+#[derive(Default)]
+struct LayoutCacheCell(Mutex<LayoutCache>);
+
+impl LayoutCacheCell {
+    fn get(&self) -> std::sync::MutexGuard<'_, LayoutCache> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Clone for LayoutCacheCell {
+    /// 복제본은 빈 캐시로 시작 (메시지가 따로 바뀔 수 있음)
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl std::fmt::Debug for LayoutCacheCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LayoutCache")
+            .field("entries", &self.get().entries.len())
+            .finish()
+    }
+}
+
+/// 너비/테마별 메시지 레이아웃
+#[derive(Default)]
+struct LayoutCache {
+    width: u16,
+    theme: Option<Theme>,
+    entries: Vec<Option<MessageLayout>>,
+}
+
+impl LayoutCache {
+    /// 너비나 테마가 바뀌면 모두 버리고, 사라진 메시지의 항목 정리
+    fn prepare(&mut self, width: u16, theme: Theme, messages: usize) {
+        if self.width != width || self.theme != Some(theme) {
+            self.entries.clear();
+            self.width = width;
+            self.theme = Some(theme);
+        }
+        self.entries.resize_with(messages, || None);
+    }
+}
+
+/// 메시지 하나의 렌더링 결과 (스피너 제외)
+struct MessageLayout {
+    role: MessageRole,
+    timestamp: Option<chrono::DateTime<chrono::Local>>,
+    /// 헤더 + 마크다운 본문
+    body: Vec<Line<'static>>,
+    /// 이후 추가돼도 바뀌지 않는 본문 라인 수
+    stable_lines: usize,
+    /// stable_lines까지 렌더링한 내용 길이 (바이트)
+    stable_offset: usize,
+    /// stable_offset 직전 구간 해시
+    stable_hash: u64,
+    /// 렌더링한 내용 길이
+    content_len: usize,
+    /// 내용 마지막 구간 해시
+    tail_hash: u64,
+    /// 도구 블록 해시 (실행 중인 도구가 있으면 None → 매 프레임 렌더링)
+    tools_key: Option<u64>,
+    /// 도구 블록 라인
+    tools: Vec<Line<'static>>,
+}
+
+impl MessageLayout {
+    /// 구분선 제외 라인 수
+    fn height(&self) -> usize {
+        self.body.len() + self.tools.len() + 1
+    }
+
+    /// index번째 라인 (스트리밍 중이면 본문 끝에 스피너)
+    fn line(&self, index: usize, spinner: Option<&Span<'static>>) -> Line<'static> {
+        if index < self.body.len() {
+            let mut line = self.body[index].clone();
+            if let Some(spinner) = spinner.filter(|_| index + 1 == self.body.len()) {
+                line.spans.push(spinner.clone());
+            }
+            line
+        } else if index < self.body.len() + self.tools.len() {
+            self.tools[index - self.body.len()].clone()
+        } else {
+            Line::from("") // 메시지 후 빈 줄
+        }
+    }
+}
+
+/// content[end - HASH_WINDOW..end] 해시
+fn window_hash(content: &str, end: usize) -> u64 {
+    let bytes = content.as_bytes();
+    let end = end.min(bytes.len());
+    let mut hasher = DefaultHasher::new();
+    bytes[end.saturating_sub(HASH_WINDOW)..end].hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 완료된 도구 블록 해시 (실행 중이면 스피너 때문에 None)
+fn tools_key(tools: &[ToolBlock]) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    for tool in tools {
+        tool.name.hash(&mut hasher);
+        tool.content.hash(&mut hasher);
+        tool.collapsed.hash(&mut hasher);
+        match &tool.state {
+            ToolExecutionState::Running => return None,
+            ToolExecutionState::Success { duration_ms } => duration_ms.hash(&mut hasher),
+            ToolExecutionState::Error { message } => message.hash(&mut hasher),
+        }
+    }
+    Some(hasher.finish())
+}
+
 /// 채팅 뷰 위젯
 pub struct ChatView<'a> {
     state: &'a ChatViewState,
@@ -272,11 +448,91 @@ impl<'a> ChatView<'a> {
         self
     }
 
-    /// 메시지를 렌더링 가능한 라인으로 변환
+    /// 메시지를 렌더링 가능한 라인으로 변환 (캐시 없이)
     fn render_message(&self, msg: &ChatMessage, width: u16) -> Vec<Line<'static>> {
-        let mut lines = Vec::new();
+        let layout = self.layout_message(None, msg, width);
+        let spinner = self.streaming_spinner(msg);
+        (0..layout.height())
+            .map(|i| layout.line(i, spinner.as_ref()))
+            .collect()
+    }
+
+    /// 스트리밍 커서
+    fn streaming_spinner(&self, msg: &ChatMessage) -> Option<Span<'static>> {
+        msg.streaming.then(|| {
+            let spinner = icons::SPINNER[self.spinner_frame % icons::SPINNER.len()];
+            Span::styled(format!(" {}", spinner), self.theme.text_accent())
+        })
+    }
+
+    /// 캐시된 레이아웃을 메시지에 맞게 갱신
+    ///
+    /// 내용이 뒤에 추가되기만 했다면 마지막으로 완결된 줄(코드 블록 밖) 이후만
+    /// 다시 렌더링하므로, 긴 응답을 스트리밍해도 프레임 비용이 일정합니다.
+    fn layout_message(
+        &self,
+        cached: Option<MessageLayout>,
+        msg: &ChatMessage,
+        width: u16,
+    ) -> MessageLayout {
         let content_width = width.saturating_sub(4) as usize;
+        let content = msg.content.as_str();
+        let tail_hash = window_hash(content, content.len());
+
+        let mut layout = match cached {
+            Some(layout) if layout.role == msg.role && layout.timestamp == msg.timestamp => layout,
+            _ => MessageLayout {
+                role: msg.role.clone(),
+                timestamp: msg.timestamp,
+                body: vec![self.render_header(msg, content_width)],
+                stable_lines: 1,
+                stable_offset: 0,
+                stable_hash: window_hash(content, 0),
+                content_len: usize::MAX,
+                tail_hash: 0,
+                tools_key: None,
+                tools: Vec::new(),
+            },
+        };
+
+        if layout.content_len != content.len() || layout.tail_hash != tail_hash {
+            let appended = layout.content_len < content.len()
+                && content.is_char_boundary(layout.stable_offset)
+                && window_hash(content, layout.stable_offset) == layout.stable_hash;
+            if !appended {
+                layout.stable_lines = 1;
+                layout.stable_offset = 0;
+            }
+
+            layout.body.truncate(layout.stable_lines);
+            let (lines, stable_end, stable_count) = self.render_markdown_from(
+                &content[layout.stable_offset..],
+                content_width,
+                msg.role == MessageRole::System,
+            );
+            layout.body.extend(lines);
+            layout.stable_lines += stable_count;
+            layout.stable_offset += stable_end;
+            layout.stable_hash = window_hash(content, layout.stable_offset);
+            layout.content_len = content.len();
+            layout.tail_hash = tail_hash;
+        }
 
+        let key = tools_key(&msg.tool_blocks);
+        if key.is_none() || key != layout.tools_key {
+            layout.tools.clear();
+            for tool in &msg.tool_blocks {
+                layout.tools.push(Line::from("")); // 빈 줄
+                layout.tools.extend(self.render_tool_block(tool, content_width as u16));
+            }
+            layout.tools_key = key;
+        }
+
+        layout
+    }
+
+    /// 역할 라벨 + 타임스탬프
+    fn render_header(&self, msg: &ChatMessage, content_width: usize) -> Line<'static> {
         // 역할 라벨 + 타임스탬프
         let (icon, label, label_style) = match msg.role {
             MessageRole::User => (icons::USER, "You", self.theme.user_label()),
@@ -289,48 +545,46 @@ impl<'a> ChatView<'a> {
         let padding = content_width
             .saturating_sub(label.len() + icon.len() + time_str.len() + 4);
 
-        lines.push(Line::from(vec![
+        Line::from(vec![
             Span::raw(" "),
             Span::styled(format!("{} {}", icon, label), label_style),
             Span::raw(" ".repeat(padding)),
             Span::styled(time_str, self.theme.text_muted()),
             Span::raw(" "),
-        ]));
-
-        // 메시지 내용 (마크다운 파싱)
-        lines.extend(self.render_markdown_content(&msg.content, content_width, msg.role == MessageRole::System));
-
-        // 스트리밍 커서
-        if msg.streaming {
-            let spinner = icons::SPINNER[self.spinner_frame % icons::SPINNER.len()];
-            if let Some(last) = lines.last_mut() {
-                last.spans.push(Span::styled(
-                    format!(" {}", spinner),
-                    self.theme.text_accent(),
-                ));
-            }
-        }
-
-        // 도구 블록 렌더링
-        for tool in &msg.tool_blocks {
-            lines.push(Line::from("")); // 빈 줄
-            lines.extend(self.render_tool_block(tool, content_width as u16));
-        }
-
-        // 메시지 후 빈 줄
-        lines.push(Line::from(""));
-
-        lines
+        ])
     }
 
     /// 마크다운 내용 렌더링 (코드 블록 포함)
     fn render_markdown_content(&self, content: &str, width: usize, is_system: bool) -> Vec<Line<'static>> {
+        self.render_markdown_from(content, width, is_system).0
+    }
+
+    /// 마크다운 렌더링 + 안정 구간
+    ///
+    /// 코드 블록 밖의 완결된 줄까지는 뒤에 내용이 붙어도 결과가 같으므로
+    /// (라인, 안정 구간 바이트, 안정 구간 라인 수)를 반환합니다.
+    fn render_markdown_from(
+        &self,
+        content: &str,
+        width: usize,
+        is_system: bool,
+    ) -> (Vec<Line<'static>>, usize, usize) {
         let mut lines = Vec::new();
         let mut in_code_block = false;
         let mut code_lang = String::new();
         let mut code_buffer = String::new();
+        let mut offset = 0;
+        let mut stable = (0, 0);
+
+        for raw in content.split_inclusive('\n') {
+            offset += raw.len();
+            // str::lines()와 같은 규칙으로 줄 끝 제거
+            let complete = raw.ends_with('\n');
+            let line = match raw.strip_suffix('\n') {
+                Some(line) => line.strip_suffix('\r').unwrap_or(line),
+                None => raw,
+            };
 
-        for line in content.lines() {
             if line.starts_with("```") {
                 if in_code_block {
                     // 코드 블록 종료 - 하이라이트 적용
@@ -349,6 +603,10 @@ impl<'a> ChatView<'a> {
                 // 일반 텍스트 (인라인 마크다운 처리)
                 lines.extend(self.render_text_line(line, width, is_system));
             }
+
+            if complete && !in_code_block {
+                stable = (offset, lines.len());
+            }
         }
 
         // 닫히지 않은 코드 블록
@@ -356,7 +614,7 @@ impl<'a> ChatView<'a> {
             lines.extend(self.render_code_block(&code_buffer, &code_lang, width));
         }
 
-        lines
+        (lines, stable.0, stable.1)
     }
 
     /// 코드 블록 렌더링 (구문 강조 포함)
@@ -598,7 +856,11 @@ impl<'a> ChatView<'a> {
         let (status_icon, status_style, status_text) = match &tool.state {
             ToolExecutionState::Running => {
                 let spinner = icons::SPINNER[self.spinner_frame % icons::SPINNER.len()];
-                (spinner, self.theme.tool_running(), "running".to_string())
+                let text = match tool.progress.as_ref().and_then(ToolProgress::percent) {
+                    Some(percent) => format!("{}%", percent),
+                    None => "running".to_string(),
+                };
+                (spinner, self.theme.tool_running(), text)
             }
             ToolExecutionState::Success { duration_ms } => (
                 icons::CHECK,
@@ -625,9 +887,23 @@ impl<'a> ChatView<'a> {
             Span::styled("─┐", self.theme.border()),
         ]));
 
-        // 내용 (최대 5줄)
-        let content_lines: Vec<&str> = tool.content.lines().take(5).collect();
-        for content_line in &content_lines {
+        // 내용 (최대 5줄, 실행 중이면 최근 출력 5줄과 진행 상황)
+        let running = matches!(tool.state, ToolExecutionState::Running);
+        let all_lines: Vec<&str> = tool.content.lines().collect();
+        let mut content_lines = if running {
+            all_lines[all_lines.len().saturating_sub(5)..].to_vec()
+        } else {
+            all_lines[..all_lines.len().min(5)].to_vec()
+        };
+        let progress_line = tool
+            .progress
+            .as_ref()
+            .filter(|_| running)
+            .map(|progress| format!("progress {}", progress));
+        if let Some(line) = &progress_line {
+            content_lines.push(line);
+        }
+        for content_line in content_lines {
             let truncated = if content_line.len() > inner_width {
                 format!("{}...", &content_line[..inner_width - 3])
             } else {
@@ -645,7 +921,7 @@ impl<'a> ChatView<'a> {
         }
 
         // 더 많은 내용이 있으면 ... 표시
-        if tool.content.lines().count() > 5 {
+        if all_lines.len() > 5 {
             let more_text = "... (more)";
             let padding = inner_width.saturating_sub(more_text.len());
             lines.push(Line::from(vec![
@@ -688,20 +964,22 @@ impl Widget for ChatView<'_> {
         let inner = block.inner(area);
         block.render(area, buf);
 
-        // 모든 메시지를 라인으로 변환
-        let mut all_lines: Vec<Line<'static>> = Vec::new();
-
-        for (i, msg) in self.state.messages.iter().enumerate() {
-            // 메시지 사이에 구분선 (유저 메시지 전에)
-            if i > 0 && msg.role == MessageRole::User {
-                all_lines.push(self.render_separator(inner.width));
-            }
+        // 바뀐 메시지만 다시 레이아웃
+        let messages = &self.state.messages;
+        let mut cache = self.state.layout.get();
+        cache.prepare(inner.width, self.theme, messages.len());
 
-            all_lines.extend(self.render_message(msg, inner.width));
+        let mut total_lines = 0;
+        for (msg, entry) in messages.iter().zip(cache.entries.iter_mut()) {
+            let layout = self.layout_message(entry.take(), msg, inner.width);
+            total_lines += layout.height();
+            *entry = Some(layout);
         }
+        // 메시지 사이에 구분선 (유저 메시지 전에)
+        let has_separator = |i: usize| i > 0 && messages[i].role == MessageRole::User;
+        total_lines += (0..messages.len()).filter(|&i| has_separator(i)).count();
 
         // 스크롤 계산
-        let total_lines = all_lines.len();
         let visible_lines = inner.height as usize;
         let max_scroll = total_lines.saturating_sub(visible_lines);
         
@@ -711,12 +989,34 @@ impl Widget for ChatView<'_> {
             self.state.scroll_offset
         };
 
-        // 보이는 라인만 렌더링
-        let visible: Vec<Line<'static>> = all_lines
-            .into_iter()
-            .skip(scroll_offset)
-            .take(visible_lines)
-            .collect();
+        // 보이는 라인만 복사
+        let mut visible: Vec<Line<'static>> = Vec::with_capacity(visible_lines);
+        let mut start = 0;
+        for (i, (msg, entry)) in messages.iter().zip(cache.entries.iter()).enumerate() {
+            if visible.len() >= visible_lines {
+                break;
+            }
+            let Some(layout) = entry else { continue };
+            let separator = usize::from(has_separator(i));
+            let end = start + separator + layout.height();
+            if end <= scroll_offset {
+                start = end;
+                continue;
+            }
+
+            let spinner = self.streaming_spinner(msg);
+            for line in scroll_offset.saturating_sub(start)..end - start {
+                if visible.len() >= visible_lines {
+                    break;
+                }
+                visible.push(if line < separator {
+                    self.render_separator(inner.width)
+                } else {
+                    layout.line(line - separator, spinner.as_ref())
+                });
+            }
+            start = end;
+        }
 
         let text = Text::from(visible);
         Paragraph::new(text).render(inner, buf);
@@ -781,6 +1081,46 @@ mod tests {
         assert!(matches!(tool.state, ToolExecutionState::Success { duration_ms: 350 }));
     }
 
+    #[test]
+    fn test_append_live_tool_output() {
+        let mut state = ChatViewState::new();
+        state.push(ChatMessage::assistant("Running tests"));
+        state.add_tool_block(ToolBlock::new("bash"));
+
+        state.append_last_tool_output("running 3 tests\n");
+        state.append_last_tool_output(&"x".repeat(5_000));
+        let content = &state.messages[0].tool_blocks[0].content;
+        assert_eq!(content.len(), 4_000);
+        assert!(content.ends_with('x'));
+
+        // 완료된 블록에는 추가하지 않음
+        let done = ToolExecutionState::Success { duration_ms: 10 };
+        state.update_last_tool(done, Some("ok".into()));
+        state.append_last_tool_output("late\n");
+        assert_eq!(state.messages[0].tool_blocks[0].content, "ok");
+    }
+
+    #[test]
+    fn test_tool_progress() {
+        let mut state = ChatViewState::new();
+        state.push(ChatMessage::assistant("Exporting"));
+        state.add_tool_block(ToolBlock::new("mcp_notion_export"));
+        state.set_last_tool_progress(ToolProgress {
+            progress: 2.0,
+            total: Some(8.0),
+            message: Some("page 2".into()),
+        });
+
+        let view = ChatView::new(&state);
+        let text: String = view
+            .render_tool_block(&state.messages[0].tool_blocks[0], 60)
+            .iter()
+            .flat_map(|line| line.spans.iter().map(|span| span.content.to_string()))
+            .collect();
+        assert!(text.contains("25%"));
+        assert!(text.contains("progress 25% (2/8) - page 2"));
+    }
+
     #[test]
     fn test_chat_view_state() {
         let mut state = ChatViewState::new();
@@ -790,6 +1130,119 @@ mod tests {
         assert_eq!(state.messages.len(), 2);
     }
 
+    fn render_frame(state: &ChatViewState, area: Rect) -> Buffer {
+        let mut buf = Buffer::empty(area);
+        ChatView::new(state)
+            .with_theme(Theme::dark())
+            .render(area, &mut buf);
+        buf
+    }
+
+    fn buffer_text(buf: &Buffer) -> String {
+        buf.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn test_incremental_layout_matches_full_render() {
+        let mut state = ChatViewState::new();
+        state.push(ChatMessage::user("Explain"));
+        state.push(ChatMessage::assistant("").streaming());
+
+        let chunks = [
+            "# Plan\nFirst **bold** li",
+            "ne\n- item\n```rust\nfn main() {",
+            "}\n``",
+            "`\nDone with a very long line that needs to be wrapped across several rows of the view\n",
+            "tail",
+        ];
+        let area = Rect::new(0, 0, 40, 10);
+        for chunk in chunks {
+            state.append_to_last(chunk);
+            render_frame(&state, area);
+
+            let view = ChatView::new(&state).with_theme(Theme::dark());
+            let msg = &state.messages[1];
+            let expected = view.render_message(msg, area.width - 2);
+            let cache = state.layout.get();
+            let layout = cache.entries[1].as_ref().unwrap();
+            let spinner = view.streaming_spinner(msg);
+            let cached: Vec<_> = (0..layout.height())
+                .map(|i| layout.line(i, spinner.as_ref()))
+                .collect();
+            assert_eq!(cached, expected);
+        }
+    }
+
+    #[test]
+    fn test_layout_cache_invalidation() {
+        let mut state = ChatViewState::new();
+        state.push(ChatMessage::assistant("alpha"));
+        let area = Rect::new(0, 0, 30, 5);
+        assert!(buffer_text(&render_frame(&state, area)).contains("alpha"));
+
+        // 같은 길이로 내용이 바뀌어도 다시 렌더링
+        state.messages[0].content = "omega".into();
+        assert!(buffer_text(&render_frame(&state, area)).contains("omega"));
+
+        // 도구 블록 완료
+        state.add_tool_block(ToolBlock::new("bash"));
+        state.update_last_tool(ToolExecutionState::Success { duration_ms: 10 }, None);
+        let text = buffer_text(&render_frame(&state, Rect::new(0, 0, 30, 8)));
+        assert!(text.contains("bash"));
+        assert!(text.contains("0.0s"));
+    }
+
+    #[test]
+    fn test_virtualized_scroll() {
+        let mut state = ChatViewState::new();
+        for i in 0..50 {
+            state.push(ChatMessage::user(format!("question {}", i)));
+            state.push(ChatMessage::assistant(format!("answer {}", i)));
+        }
+        let area = Rect::new(0, 0, 40, 6);
+        let text = buffer_text(&render_frame(&state, area));
+        assert!(text.contains("answer 49"));
+        assert!(!text.contains("answer 48"));
+
+        state.scroll_up(usize::MAX);
+        let text = buffer_text(&render_frame(&state, area));
+        assert!(text.contains("question 0"));
+        assert!(!text.contains("question 1"));
+    }
+
+    /// 벤치마크: cargo test --release -p forge-cli frame_time -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_frame_time_1mb_transcript() {
+        let paragraph = "The agent reads `main.rs`, applies the **patch** and runs the tests again.\n";
+        let code = "```rust\nfn main() {\n    println!(\"hello\");\n}\n```\n";
+        let mut state = ChatViewState::new();
+        let mut size = 0;
+        while size < 1_000_000 {
+            let reply = format!("{}{}{}", paragraph.repeat(40), code, paragraph.repeat(20));
+            size += reply.len();
+            state.push(ChatMessage::user("Continue"));
+            state.push(ChatMessage::assistant(reply));
+        }
+        state.push(ChatMessage::assistant("").streaming());
+
+        let area = Rect::new(0, 0, 160, 50);
+        render_frame(&state, area); // 첫 프레임은 전체 레이아웃
+
+        let frames = 200;
+        let started = std::time::Instant::now();
+        for frame in 0..frames {
+            state.append_to_last("streamed token ");
+            if frame % 10 == 0 {
+                state.append_to_last("\n");
+            }
+            render_frame(&state, area);
+        }
+        let per_frame = started.elapsed() / frames;
+        println!("{} bytes, {:?} per frame", size, per_frame);
+        assert!(per_frame < std::time::Duration::from_millis(5));
+    }
+
     #[test]
     fn test_parse_heading() {
         assert_eq!(parse_heading("# Title"), (1, "Title"));
@@ -0,0 +1,204 @@
+//! MCP Panel Widget - MCP 서버 상태 패널 (`/mcp`)
+//!
+//! ```text
+//! ┌─ MCP Servers ───────────────────────────────────────────┐
+//! │ ● github   ok · handshake 42ms · 1 restart              │
+//! │ ↻ notion   restarting in 4s · 2 failures                │
+//! │            Process spawn failed: ...                    │
+//! └─────────────────────────────────────────────────────────┘
+//! ```
+
+#![allow(dead_code)]
+
+use forge_core::ServerStatus;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::tui::theme::{current_theme, Theme};
+
+/// 서버 상태 요약 (아이콘 제외)
+pub fn describe(status: &ServerStatus) -> String {
+    let mut parts = Vec::new();
+    if status.healthy {
+        parts.push("ok".to_string());
+    } else if let Some(wait) = status.next_restart_in {
+        parts.push(format!(
+            "restarting in {}s",
+            wait.as_secs_f64().ceil() as u64
+        ));
+    } else if status.auto_restart {
+        parts.push("restarting".to_string());
+    } else {
+        parts.push("down".to_string());
+    }
+
+    if let Some(latency) = status.handshake_latency {
+        parts.push(format!("handshake {}ms", latency.as_millis()));
+    }
+    if status.consecutive_failures > 0 {
+        parts.push(plural(status.consecutive_failures, "failure"));
+    }
+    if status.restarts > 0 {
+        parts.push(plural(status.restarts, "restart"));
+    }
+    parts.join(" · ")
+}
+
+fn plural(count: u32, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+/// MCP 서버 상태 패널 (오버레이)
+pub struct McpPanel<'a> {
+    statuses: &'a [ServerStatus],
+    theme: Theme,
+}
+
+impl<'a> McpPanel<'a> {
+    pub fn new(statuses: &'a [ServerStatus]) -> Self {
+        Self {
+            statuses,
+            theme: current_theme(),
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        if self.statuses.is_empty() {
+            return vec![Line::from(Span::styled(
+                " No MCP servers connected",
+                self.theme.text_muted(),
+            ))];
+        }
+
+        let width = self
+            .statuses
+            .iter()
+            .map(|s| s.name.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut lines = Vec::new();
+        for status in self.statuses {
+            let (icon, style) = if status.healthy {
+                ("●", self.theme.success())
+            } else if status.auto_restart {
+                ("↻", self.theme.warning())
+            } else {
+                ("✗", self.theme.error())
+            };
+            lines.push(Line::from(vec![
+                Span::raw(" "),
+                Span::styled(icon, style),
+                Span::raw(" "),
+                Span::styled(format!("{:<width$}", status.name), Style::default()),
+                Span::raw("  "),
+                Span::styled(describe(status), self.theme.text_muted()),
+            ]));
+            if let Some(error) = status.last_error.as_deref().filter(|_| !status.healthy) {
+                lines.push(Line::from(vec![
+                    Span::raw(" ".repeat(width + 5)),
+                    Span::styled(error.to_string(), self.theme.error()),
+                ]));
+            }
+        }
+        lines
+    }
+}
+
+impl Widget for McpPanel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines = self.lines();
+        let width = 70.min(area.width.saturating_sub(4));
+        let height = (lines.len() as u16 + 3).min(area.height.saturating_sub(4));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let popup_area = Rect::new(x, y, width, height);
+
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .title(" MCP Servers ")
+            .title_bottom(Line::from(Span::styled(
+                " any key to close ",
+                self.theme.text_muted(),
+            )))
+            .borders(Borders::ALL)
+            .border_style(self.theme.border());
+
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .render(popup_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn status(name: &str, healthy: bool) -> ServerStatus {
+        ServerStatus {
+            name: name.to_string(),
+            connected: healthy,
+            healthy,
+            last_used: Duration::ZERO,
+            reconnect_attempts: 0,
+            handshake_latency: Some(Duration::from_millis(42)),
+            consecutive_failures: 0,
+            last_error: None,
+            restarts: 0,
+            next_restart_in: None,
+            auto_restart: true,
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        let mut github = status("github", true);
+        github.restarts = 1;
+        assert_eq!(describe(&github), "ok · handshake 42ms · 1 restart");
+
+        let mut notion = status("notion", false);
+        notion.consecutive_failures = 2;
+        notion.next_restart_in = Some(Duration::from_millis(3500));
+        assert_eq!(
+            describe(&notion),
+            "restarting in 4s · handshake 42ms · 2 failures"
+        );
+
+        notion.auto_restart = false;
+        notion.next_restart_in = None;
+        assert!(describe(&notion).starts_with("down"));
+    }
+
+    #[test]
+    fn test_render_lists_errors() {
+        let mut notion = status("notion", false);
+        notion.last_error = Some("Server disconnected".to_string());
+        let statuses = vec![status("github", true), notion];
+
+        let area = Rect::new(0, 0, 80, 20);
+        let mut buf = Buffer::empty(area);
+        McpPanel::new(&statuses).render(area, &mut buf);
+
+        let text: String = buf.content.iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("MCP Servers"));
+        assert!(text.contains("github"));
+        assert!(text.contains("Server disconnected"));
+    }
+}
@@ -6,6 +6,7 @@ pub mod chat_view;
 pub mod code_block;
 pub mod header;
 pub mod input_area;
+pub mod mcp_panel;
 pub mod status_bar;
 pub mod welcome;
 
@@ -13,5 +14,6 @@ pub mod welcome;
 pub use chat_view::{ChatMessage, ChatView, ChatViewState, MessageRole, ToolBlock, ToolExecutionState};
 pub use header::{AgentStatus, Header, HeaderState, SpinnerState};
 pub use input_area::{InputArea, InputState};
+pub use mcp_panel::McpPanel;
 pub use status_bar::{StatusBar, StatusBarState};
 pub use welcome::WelcomeScreen;
@@ -55,6 +55,8 @@ pub struct StatusBarState {
     pub notification: Option<(String, NotificationType)>,
     /// 알림 타임아웃
     pub notification_timeout: Option<std::time::Instant>,
+    /// 기능 저하된 서브시스템 (예: "lsp", "git")
+    pub degraded: Vec<String>,
 }
 
 /// 알림 타입
@@ -73,6 +75,7 @@ impl StatusBarState {
             right_items: vec![StatusItem::new("?", "help")],
             notification: None,
             notification_timeout: None,
+            degraded: Vec::new(),
         }
     }
 
@@ -127,6 +130,11 @@ impl StatusBarState {
         }
     }
 
+    /// 기능 저하된 서브시스템 설정
+    pub fn set_degraded(&mut self, degraded: Vec<String>) {
+        self.degraded = degraded;
+    }
+
     /// 에이전트 실행 중 모드
     pub fn set_running_mode(&mut self) {
         self.left_items = vec![
@@ -261,9 +269,16 @@ impl Widget for StatusBar<'_> {
             .alignment(Alignment::Left)
             .render(chunks[0], buf);
 
-        // 오른쪽 아이템들
-        let right_spans = self.render_items(&self.state.right_items);
-        let mut right_vec: Vec<Span<'static>> = right_spans;
+        // 오른쪽 아이템들 (기능 저하 표시 포함)
+        let mut right_vec: Vec<Span<'static>> = Vec::new();
+        if !self.state.degraded.is_empty() {
+            right_vec.push(Span::styled(
+                format!("⚠ {} degraded", self.state.degraded.join(", ")),
+                self.theme.warning(),
+            ));
+            right_vec.push(Span::styled(" │ ", self.theme.text_muted()));
+        }
+        right_vec.extend(self.render_items(&self.state.right_items));
         right_vec.push(Span::raw(" "));
         let right_line = Line::from(right_vec);
         Paragraph::new(right_line)
@@ -336,4 +351,15 @@ mod tests {
         state.set_normal_mode();
         assert_eq!(state.left_items.len(), normal_count);
     }
+
+    #[test]
+    fn test_degraded_survives_mode_switch() {
+        let mut state = StatusBarState::new();
+        assert!(state.degraded.is_empty());
+
+        state.set_degraded(vec!["lsp".to_string(), "git".to_string()]);
+        state.set_running_mode();
+        state.set_normal_mode();
+        assert_eq!(state.degraded, vec!["lsp", "git"]);
+    }
 }
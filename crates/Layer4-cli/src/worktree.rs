@@ -0,0 +1,82 @@
+//! Isolated agent runs (`--worktree`)
+//!
+//! 에이전트를 사용자의 작업 트리 대신 별도 git worktree + 새 브랜치에서 실행합니다.
+//! 실행이 끝나면 변경을 그 브랜치에 커밋하고 리뷰/머지/PR 명령을 보여주며,
+//! 사용자가 체크아웃한 트리는 건드리지 않습니다.
+
+use anyhow::{Context, Result};
+use forge_core::AgentWorkspace;
+use std::path::PathBuf;
+
+/// 커밋 메시지 첫 줄 최대 길이
+const MAX_SUBJECT_CHARS: usize = 72;
+
+/// 진행 중인 worktree 실행
+pub struct WorktreeRun {
+    workspace: AgentWorkspace,
+    original_dir: PathBuf,
+    subject: String,
+}
+
+impl WorktreeRun {
+    /// worktree를 만들고 현재 디렉토리를 그 안으로 옮김
+    ///
+    /// 에이전트, 도구, 설정 로더는 모두 현재 디렉토리를 작업 디렉토리로 씁니다.
+    pub fn start(task: &str) -> Result<Self> {
+        let original_dir = std::env::current_dir()?;
+        let workspace = AgentWorkspace::create(&original_dir, task)
+            .context("--worktree needs a git repository with at least one commit")?;
+        std::env::set_current_dir(workspace.path())?;
+
+        eprintln!(
+            "Working in isolated worktree {} (branch {})\n",
+            workspace.path().display(),
+            workspace.branch()
+        );
+        Ok(Self {
+            workspace,
+            original_dir,
+            subject: commit_subject(task),
+        })
+    }
+
+    /// 원래 디렉토리로 돌아가 변경을 커밋하고 결과 브랜치를 출력
+    pub fn finish(self) -> Result<()> {
+        std::env::set_current_dir(&self.original_dir)?;
+        let outcome = self.workspace.finish(&self.subject)?;
+        println!("\n{}", outcome.summary());
+        Ok(())
+    }
+}
+
+/// 작업 설명에서 커밋 제목 생성 (첫 줄, 최대 72자)
+fn commit_subject(task: &str) -> String {
+    let line = task.lines().map(str::trim).find(|l| !l.is_empty());
+    let Some(line) = line else {
+        return "forge: agent run".to_string();
+    };
+    if line.chars().count() <= MAX_SUBJECT_CHARS {
+        return line.to_string();
+    }
+    let mut subject: String = line.chars().take(MAX_SUBJECT_CHARS - 1).collect();
+    subject.push('…');
+    subject
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_subject() {
+        assert_eq!(
+            commit_subject("\n  Fix the login redirect\nDetails..."),
+            "Fix the login redirect"
+        );
+        assert_eq!(commit_subject("   "), "forge: agent run");
+
+        let long = commit_subject(&"word ".repeat(30));
+        assert_eq!(long.chars().count(), MAX_SUBJECT_CHARS);
+        assert!(long.ends_with('…'));
+    }
+}
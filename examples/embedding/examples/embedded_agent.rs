@@ -0,0 +1,39 @@
+//! 에이전트 임베딩 예제
+//!
+//! 대본 대화를 실행하고 발생한 이벤트를 출력합니다. 네트워크나 설정 파일 없이 동작합니다.
+//!
+//! 실행: `cargo run -p forge-examples --example embedded_agent`
+
+use std::sync::Arc;
+
+use forge_agent::{AgentEvent, AgentRunner};
+use forge_examples::{build_context, demo_provider, Notes, DEMO_MESSAGES};
+
+#[tokio::main]
+async fn main() -> forge_foundation::Result<()> {
+    let notes = Notes::default();
+    let ctx = build_context(Arc::new(demo_provider()), notes.clone())?;
+    let mut runner = AgentRunner::new(Arc::new(ctx));
+
+    for message in DEMO_MESSAGES {
+        println!("> {}", message);
+        let run = runner.send(message).await?;
+        for event in &run.events {
+            match event {
+                AgentEvent::ToolStart { tool_name, .. } => println!("  [tool] {}", tool_name),
+                AgentEvent::ToolComplete {
+                    tool_name,
+                    result,
+                    success,
+                    ..
+                } => println!("  [done] {} ok={} → {}", tool_name, success, result),
+                AgentEvent::Error(message) => println!("  [error] {}", message),
+                _ => {}
+            }
+        }
+        println!("{}\n", run.response);
+    }
+
+    println!("Notes: {:?}", notes.lock().unwrap());
+    Ok(())
+}
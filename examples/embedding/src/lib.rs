@@ -0,0 +1,257 @@
+//! # forge-examples
+//!
+//! ForgeCode 에이전트를 다른 프로그램에 임베딩하는 최소 예제입니다.
+//!
+//! - 설정 파일 없이 코드만으로 `AgentContext` 구성
+//! - 커스텀 도구 두 개 등록 (`word_count`, `add_note`)
+//! - `MockProvider`로 대본대로 대화를 진행하고 `AgentEvent` 확인
+//!
+//! 실행: `cargo run -p forge-examples --example embedded_agent`
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use forge_agent::AgentContext;
+use forge_foundation::{
+    PermissionAction, PermissionService, Result, Tool, ToolContext, ToolMeta, ToolResult,
+};
+use forge_provider::{Gateway, MockProvider, MockTurn};
+use serde_json::{json, Value};
+
+/// 예제 에이전트의 시스템 프롬프트
+///
+/// 직접 지정하므로 프로젝트 규칙 파일(AGENTS.md 등)을 읽지 않습니다.
+pub const SYSTEM_PROMPT: &str =
+    "You are an embedded assistant. Use word_count to count words and add_note to remember facts.";
+
+/// 대본 대화의 사용자 메시지
+pub const DEMO_MESSAGES: [&str; 2] = [
+    "How many words are in 'the quick brown fox'?",
+    "Remember that for later.",
+];
+
+/// 공유 메모장
+pub type Notes = Arc<Mutex<Vec<String>>>;
+
+// ============================================================================
+// word_count
+// ============================================================================
+
+/// 단어 수 세기 도구 (상태 없음)
+pub struct WordCountTool;
+
+impl WordCountTool {
+    pub const NAME: &'static str = "word_count";
+}
+
+#[async_trait]
+impl Tool for WordCountTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Word Count")
+            .description("Count the whitespace-separated words in a piece of text.")
+            .category("example")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "text": {
+                    "type": "string",
+                    "description": "Text to count words in"
+                }
+            },
+            "required": ["text"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        None
+    }
+
+    async fn execute(&self, input: Value, _context: &dyn ToolContext) -> Result<ToolResult> {
+        let Some(text) = input["text"].as_str() else {
+            return Ok(ToolResult::error("Missing required parameter: text"));
+        };
+        let words = text.split_whitespace().count();
+        Ok(ToolResult::success(words.to_string()).with_metadata("words", json!(words)))
+    }
+}
+
+// ============================================================================
+// add_note
+// ============================================================================
+
+/// 메모 추가 도구 (호스트 프로그램과 메모장을 공유)
+pub struct AddNoteTool {
+    notes: Notes,
+}
+
+impl AddNoteTool {
+    pub const NAME: &'static str = "add_note";
+
+    /// 지정한 메모장에 기록
+    pub fn new(notes: Notes) -> Self {
+        Self { notes }
+    }
+}
+
+#[async_trait]
+impl Tool for AddNoteTool {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn meta(&self) -> ToolMeta {
+        ToolMeta::new(Self::NAME)
+            .display_name("Add Note")
+            .description("Save a short note in the host application's notebook.")
+            .category("example")
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "note": {
+                    "type": "string",
+                    "description": "Note to save"
+                }
+            },
+            "required": ["note"]
+        })
+    }
+
+    fn required_permission(&self, _input: &Value) -> Option<PermissionAction> {
+        // 호스트 메모리에만 기록하므로 권한 불필요
+        None
+    }
+
+    async fn execute(&self, input: Value, _context: &dyn ToolContext) -> Result<ToolResult> {
+        let Some(note) = input["note"].as_str() else {
+            return Ok(ToolResult::error("Missing required parameter: note"));
+        };
+        let count = match self.notes.lock() {
+            Ok(mut notes) => {
+                notes.push(note.to_string());
+                notes.len()
+            }
+            Err(_) => return Ok(ToolResult::error("Notebook is unavailable")),
+        };
+        Ok(ToolResult::success(format!("Saved note #{}", count)))
+    }
+}
+
+// ============================================================================
+// 구성
+// ============================================================================
+
+/// 코드만으로 에이전트 컨텍스트 구성
+///
+/// 도구가 파일을 다루지 않으므로 작업 디렉토리는 임시 디렉토리로 둡니다.
+pub fn build_context(provider: Arc<MockProvider>, notes: Notes) -> Result<AgentContext> {
+    let mut gateway = Gateway::new();
+    gateway.add_provider("mock", provider);
+
+    AgentContext::builder()
+        .gateway(Arc::new(gateway))
+        .working_directory(std::env::temp_dir())
+        .permissions(Arc::new(PermissionService::new()))
+        .system_prompt(SYSTEM_PROMPT)
+        .tool(Arc::new(WordCountTool))
+        .tool(Arc::new(AddNoteTool::new(notes)))
+        .build()
+}
+
+/// `DEMO_MESSAGES`에 맞춘 응답 대본
+///
+/// 메시지마다 도구 호출 한 번과 텍스트 응답 한 번으로 구성됩니다.
+pub fn demo_provider() -> MockProvider {
+    MockProvider::new()
+        .with_turn(MockTurn::tool_call(
+            "call-1",
+            WordCountTool::NAME,
+            json!({ "text": "the quick brown fox" }),
+        ))
+        .with_turn(MockTurn::text("'the quick brown fox' has 4 words.").with_usage(120, 12))
+        .with_turn(MockTurn::tool_call(
+            "call-2",
+            AddNoteTool::NAME,
+            json!({ "note": "'the quick brown fox' has 4 words" }),
+        ))
+        .with_turn(MockTurn::text("Noted.").with_usage(160, 3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge_agent::{AgentEvent, AgentRunner};
+    use forge_provider::MessageRole;
+
+    #[tokio::test]
+    async fn test_scripted_conversation() {
+        let provider = Arc::new(demo_provider());
+        let notes = Notes::default();
+        let ctx = build_context(provider.clone(), notes.clone()).unwrap();
+        let mut runner = AgentRunner::new(Arc::new(ctx));
+
+        let first = runner.send(DEMO_MESSAGES[0]).await.unwrap();
+        assert_eq!(first.response, "'the quick brown fox' has 4 words.");
+        assert_eq!(first.tool_calls(), vec![(WordCountTool::NAME, true)]);
+        assert!(first.errors().is_empty());
+        assert!(first.events.iter().any(|e| matches!(
+            e,
+            AgentEvent::ToolStart { tool_name, .. } if tool_name == WordCountTool::NAME
+        )));
+        assert!(matches!(
+            first.events.last(),
+            Some(AgentEvent::Done { full_response }) if full_response == &first.response
+        ));
+
+        let second = runner.send(DEMO_MESSAGES[1]).await.unwrap();
+        assert_eq!(second.response, "Noted.");
+        assert_eq!(second.tool_calls(), vec![(AddNoteTool::NAME, true)]);
+        assert_eq!(
+            *notes.lock().unwrap(),
+            vec!["'the quick brown fox' has 4 words".to_string()]
+        );
+        assert_eq!(provider.remaining_turns(), 0);
+
+        // 도구 결과가 다음 요청에 전달되고, 커스텀 도구가 모델에 노출됨
+        let requests = provider.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].system_prompt.as_deref(), Some(SYSTEM_PROMPT));
+        assert!(requests[0].tools.iter().any(|t| t == WordCountTool::NAME));
+        assert!(requests[0].tools.iter().any(|t| t == AddNoteTool::NAME));
+        let tool_result = requests[1]
+            .messages
+            .iter()
+            .find(|m| m.role == MessageRole::Tool)
+            .and_then(|m| m.tool_result.as_ref())
+            .unwrap();
+        assert_eq!(tool_result.content, "4");
+        assert!(requests[2]
+            .messages
+            .iter()
+            .any(|m| m.content == DEMO_MESSAGES[0]));
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_script_is_reported() {
+        let provider = Arc::new(MockProvider::new());
+        let ctx = build_context(provider, Notes::default()).unwrap();
+        let mut runner = AgentRunner::new(Arc::new(ctx));
+
+        let outcome = runner.send("hello").await;
+        let reported = match outcome {
+            Ok(run) => !run.errors().is_empty(),
+            Err(_) => true,
+        };
+        assert!(reported);
+    }
+}